@@ -75,6 +75,10 @@ enum Command {
         /// Flag that allows to roll back already executed blocks. It's ultra dangerous and required only for fixing external nodes.
         #[arg(long)]
         allow_executed_block_reversion: bool,
+        /// Only prints the rollback plan (numbers of removed Postgres rows, rolled back RocksDB instances, removed
+        /// snapshots etc.) as JSON without changing anything.
+        #[arg(long)]
+        dry_run: bool,
     },
 
     /// Clears failed L1 transactions.
@@ -128,6 +132,8 @@ async fn main() -> anyhow::Result<()> {
     .await
     .context("failed to build a connection pool")?;
     let mut block_reverter = BlockReverter::new(NodeRole::Main, connection_pool);
+    let user = std::env::var("USER").unwrap_or_else(|_| "unknown".to_owned());
+    block_reverter.set_audit_actor(format!("cli:{user}"));
 
     match command {
         Command::Display {
@@ -192,8 +198,9 @@ async fn main() -> anyhow::Result<()> {
             rollback_sk_cache,
             rollback_snapshots,
             allow_executed_block_reversion,
+            dry_run,
         } => {
-            if !rollback_tree && rollback_postgres {
+            if !rollback_tree && rollback_postgres && !dry_run {
                 println!("You want to roll back Postgres DB without rolling back tree.");
                 println!(
                     "If the tree is not yet rolled back to this L1 batch, then the only way \
@@ -209,14 +216,16 @@ async fn main() -> anyhow::Result<()> {
             }
 
             if allow_executed_block_reversion {
-                println!("You want to roll back already executed blocks. It's impossible to restore them for the main node");
-                println!("Make sure you are doing it ONLY for external node");
-                println!("Are you sure? Print y/n");
+                if !dry_run {
+                    println!("You want to roll back already executed blocks. It's impossible to restore them for the main node");
+                    println!("Make sure you are doing it ONLY for external node");
+                    println!("Are you sure? Print y/n");
 
-                let mut input = [0u8];
-                io::stdin().read_exact(&mut input).await.unwrap();
-                if input[0] != b'y' && input[0] != b'Y' {
-                    std::process::exit(0);
+                    let mut input = [0u8];
+                    io::stdin().read_exact(&mut input).await.unwrap();
+                    if input[0] != b'y' && input[0] != b'Y' {
+                        std::process::exit(0);
+                    }
                 }
                 block_reverter.allow_rolling_back_executed_batches();
             }
@@ -241,6 +250,17 @@ async fn main() -> anyhow::Result<()> {
                     .enable_rolling_back_state_keeper_cache(db_config.state_keeper_db_path);
             }
 
+            let plan = block_reverter
+                .plan_rollback(L1BatchNumber(l1_batch_number))
+                .await?;
+            if dry_run {
+                println!("{}", serde_json::to_string_pretty(&plan)?);
+                return Ok(());
+            }
+            println!(
+                "Rolling back according to plan: {}",
+                serde_json::to_string_pretty(&plan)?
+            );
             block_reverter
                 .roll_back(L1BatchNumber(l1_batch_number))
                 .await?;
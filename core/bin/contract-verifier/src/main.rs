@@ -6,117 +6,14 @@ use prometheus_exporter::PrometheusExporterConfig;
 use structopt::StructOpt;
 use tokio::sync::watch;
 use zksync_config::{
-    configs::{ObservabilityConfig, PrometheusConfig},
+    configs::{DatabaseSecrets, ObservabilityConfig, PrometheusConfig},
     ApiConfig, ContractVerifierConfig,
 };
-use zksync_dal::{ConnectionPool, Core, CoreDal};
+use zksync_contract_verifier_lib::{update_compiler_versions, ContractVerifier};
+use zksync_dal::{ConnectionPool, Core};
 use zksync_env_config::FromEnv;
 use zksync_queued_job_processor::JobProcessor;
-use zksync_utils::{wait_for_tasks::ManagedTasks, workspace_dir_or_current_dir};
-
-use crate::verifier::ContractVerifier;
-
-pub mod error;
-mod metrics;
-pub mod verifier;
-pub mod zksolc_utils;
-pub mod zkvyper_utils;
-
-async fn update_compiler_versions(connection_pool: &ConnectionPool<Core>) {
-    let mut storage = connection_pool.connection().await.unwrap();
-    let mut transaction = storage.start_transaction().await.unwrap();
-
-    let zksync_home = workspace_dir_or_current_dir();
-
-    let zksolc_path = zksync_home.join("etc/zksolc-bin/");
-    let zksolc_versions: Vec<String> = std::fs::read_dir(zksolc_path)
-        .unwrap()
-        .filter_map(|file| {
-            let file = file.unwrap();
-            let Ok(file_type) = file.file_type() else {
-                return None;
-            };
-            if file_type.is_dir() {
-                file.file_name().into_string().ok()
-            } else {
-                None
-            }
-        })
-        .collect();
-    transaction
-        .contract_verification_dal()
-        .set_zksolc_versions(zksolc_versions)
-        .await
-        .unwrap();
-
-    let solc_path = zksync_home.join("etc/solc-bin/");
-    let solc_versions: Vec<String> = std::fs::read_dir(solc_path)
-        .unwrap()
-        .filter_map(|file| {
-            let file = file.unwrap();
-            let Ok(file_type) = file.file_type() else {
-                return None;
-            };
-            if file_type.is_dir() {
-                file.file_name().into_string().ok()
-            } else {
-                None
-            }
-        })
-        .collect();
-    transaction
-        .contract_verification_dal()
-        .set_solc_versions(solc_versions)
-        .await
-        .unwrap();
-
-    let zkvyper_path = zksync_home.join("etc/zkvyper-bin/");
-    let zkvyper_versions: Vec<String> = std::fs::read_dir(zkvyper_path)
-        .unwrap()
-        .filter_map(|file| {
-            let file = file.unwrap();
-            let Ok(file_type) = file.file_type() else {
-                return None;
-            };
-            if file_type.is_dir() {
-                file.file_name().into_string().ok()
-            } else {
-                None
-            }
-        })
-        .collect();
-    transaction
-        .contract_verification_dal()
-        .set_zkvyper_versions(zkvyper_versions)
-        .await
-        .unwrap();
-
-    let vyper_path = zksync_home.join("etc/vyper-bin/");
-    let vyper_versions: Vec<String> = std::fs::read_dir(vyper_path)
-        .unwrap()
-        .filter_map(|file| {
-            let file = file.unwrap();
-            let Ok(file_type) = file.file_type() else {
-                return None;
-            };
-            if file_type.is_dir() {
-                file.file_name().into_string().ok()
-            } else {
-                None
-            }
-        })
-        .collect();
-
-    transaction
-        .contract_verification_dal()
-        .set_vyper_versions(vyper_versions)
-        .await
-        .unwrap();
-
-    transaction.commit().await.unwrap();
-}
-
-use zksync_config::configs::DatabaseSecrets;
+use zksync_utils::wait_for_tasks::ManagedTasks;
 
 #[derive(StructOpt)]
 #[structopt(name = "zkSync contract code verifier", author = "Matter Labs")]
@@ -178,7 +75,9 @@ async fn main() -> anyhow::Result<()> {
         .expect("Error setting Ctrl+C handler");
     }
 
-    update_compiler_versions(&pool).await;
+    update_compiler_versions(&pool)
+        .await
+        .context("failed updating compiler versions")?;
 
     let contract_verifier = ContractVerifier::new(verifier_config, pool);
     let tasks = vec![
@@ -2,27 +2,38 @@ use std::{
     env,
     ffi::OsString,
     num::{NonZeroU32, NonZeroU64, NonZeroUsize},
+    path::Path,
     time::Duration,
 };
 
 use anyhow::Context;
+use schemars::JsonSchema;
+use secrecy::{ExposeSecret as _, Secret};
 use serde::Deserialize;
 use zksync_config::{
     configs::{
         api::{MaxResponseSize, MaxResponseSizeOverrides},
         consensus::{ConsensusConfig, ConsensusSecrets},
+        da_client::DAClientConfig,
+        database::MerkleTreeMode,
+        vm_runner::BasicWitnessInputProducerConfig,
+        ExperimentalDBConfig,
     },
-    ObjectStoreConfig,
+    ContractVerifierConfig, ObjectStoreConfig, SnapshotsCreatorConfig,
 };
 use zksync_core_leftovers::temp_config_store::decode_yaml_repr;
-#[cfg(test)]
 use zksync_dal::{ConnectionPool, Core};
-use zksync_metadata_calculator::MetadataCalculatorRecoveryConfig;
+use zksync_db_connection::connection_pool::ConnectionPoolBuilder;
+use zksync_metadata_calculator::{MetadataCalculatorConfig, MetadataCalculatorRecoveryConfig};
 use zksync_node_api_server::{
-    tx_sender::TxSenderConfig,
-    web3::{state::InternalApiConfig, Namespace},
+    tx_sender::{proxy::TxProxyQueueConfig, TxSenderConfig},
+    web3::{
+        namespaces::DebugNamespaceConfig, state::InternalApiConfig, ClientRateLimit, MethodFilter,
+        Namespace,
+    },
 };
-use zksync_protobuf_config::proto;
+use zksync_protobuf_config::{interpolation::read_yaml_config, proto};
+use zksync_secrets::{SecretRef, SecretsResolver};
 use zksync_snapshots_applier::SnapshotsApplierConfig;
 use zksync_types::{
     api::BridgeAddresses, commitment::L1BatchCommitmentMode, url::SensitiveUrl, Address,
@@ -35,11 +46,19 @@ use zksync_web3_decl::{
     namespaces::{EnNamespaceClient, ZksNamespaceClient},
 };
 
+use self::layered::{ConfigReport, LayeredConfigSource};
+pub(crate) use self::{
+    layered::config_file_schema,
+    profiles::{NetworkProfile, NodeProfile},
+};
 use crate::config::observability::ObservabilityENConfig;
 
+mod layered;
 pub(crate) mod observability;
+mod profiles;
 #[cfg(test)]
 mod tests;
+pub(crate) mod validation;
 
 const BYTES_IN_MEGABYTE: usize = 1_024 * 1_024;
 
@@ -50,6 +69,12 @@ trait ConfigurationSource: 'static {
     fn vars(&self) -> Self::Vars<'_>;
 
     fn var(&self, name: &str) -> Option<String>;
+
+    /// Returns all variables with UTF-8 names and values.
+    fn utf8_vars(&self) -> impl Iterator<Item = (String, String)> + '_ {
+        self.vars()
+            .filter_map(|(name, value)| Some((name.into_string().ok()?, value.into_string().ok()?)))
+    }
 }
 
 #[derive(Debug)]
@@ -201,7 +226,7 @@ pub(crate) enum BlockFetcher {
 /// This part of the external node config is completely optional to provide.
 /// It can tweak limits of the API, delay intervals of certain components, etc.
 /// If any of the fields are not provided, the default values will be used.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, JsonSchema)]
 pub(crate) struct OptionalENConfig {
     // User-facing API limits
     /// Max possible limit of filters to be in the API state at once.
@@ -210,6 +235,13 @@ pub(crate) struct OptionalENConfig {
     /// Max possible limit of subscriptions to be in the API state at once.
     #[serde(default = "OptionalENConfig::default_subscriptions_limit")]
     pub subscriptions_limit: usize,
+    /// Max number of subscriptions a single WebSocket connection can have open at once.
+    #[serde(default = "OptionalENConfig::default_subscriptions_per_connection_limit")]
+    pub subscriptions_per_connection_limit: usize,
+    /// Number of recent notifications of each subscription type retained so that WebSocket subscriptions
+    /// can be resumed with `zks_resubscribe` after a reconnect. 0 disables resumption.
+    #[serde(default = "OptionalENConfig::default_subscription_backlog_size")]
+    pub subscription_backlog_size: usize,
     /// Max possible limit of entities to be requested via API at once.
     #[serde(default = "OptionalENConfig::default_req_entities_limit")]
     pub req_entities_limit: usize,
@@ -222,17 +254,28 @@ pub(crate) struct OptionalENConfig {
     /// Max number of cache misses during one VM execution. If the number of cache misses exceeds this value, the API server panics.
     /// This is a temporary solution to mitigate API request resulting in thousands of DB queries.
     pub vm_execution_cache_misses_limit: Option<usize>,
+    /// Max number of `eth_call` results on historical blocks cached by the API server. Calls on the pending
+    /// or latest block are never cached. If set to 0 (the default), results are not cached.
+    #[serde(default)]
+    pub eth_call_cache_size: usize,
+    /// Time-to-live of cached `eth_call` results in seconds. The default value is 60 seconds.
+    #[serde(default = "OptionalENConfig::default_eth_call_cache_ttl_sec")]
+    eth_call_cache_ttl_sec: u64,
     /// Limit for fee history block range.
     #[serde(default = "OptionalENConfig::default_fee_history_limit")]
     pub fee_history_limit: u64,
+    /// Max number of L2 blocks that can be queried by a single `eth_getLogs` call. If not set, the block range
+    /// is unlimited (the number of returned logs is still limited by `req_entities_limit`).
+    pub max_logs_block_range: Option<u32>,
     /// Maximum number of requests in a single batch JSON RPC request. Default is 500.
     #[serde(default = "OptionalENConfig::default_max_batch_request_size")]
     pub max_batch_request_size: usize,
     /// Maximum response body size in MiBs. Default is 10 MiB.
     #[serde(default = "OptionalENConfig::default_max_response_body_size_mb")]
     pub max_response_body_size_mb: usize,
-    /// Method-specific overrides in MiBs for the maximum response body size.
+    /// Method-specific overrides in MiBs for the maximum response body size, e.g. `eth_call=2,zks_getProof=None`.
     #[serde(default = "MaxResponseSizeOverrides::empty")]
+    #[schemars(with = "String")]
     max_response_body_size_overrides_mb: MaxResponseSizeOverrides,
 
     // Other API config settings
@@ -260,7 +303,26 @@ pub(crate) struct OptionalENConfig {
     #[serde(default = "OptionalENConfig::default_latest_values_cache_size_mb")]
     latest_values_cache_size_mb: usize,
     /// Enabled JSON RPC API namespaces.
+    #[schemars(with = "Option<Vec<String>>")]
     api_namespaces: Option<Vec<Namespace>>,
+    /// If set, only JSON-RPC methods matching these patterns are served by the HTTP and WS API servers. A pattern is either
+    /// a full method name (e.g., `eth_call`) or a prefix followed by `*` (e.g., `eth_*`). Methods must additionally belong
+    /// to one of enabled [namespaces](Self::api_namespaces).
+    api_method_allowlist: Option<Vec<String>>,
+    /// JSON-RPC methods that are not served by the HTTP and WS API servers, specified using the same patterns
+    /// as in `api_method_allowlist` (e.g., `debug_*`). Takes precedence over the allowlist.
+    #[serde(default)]
+    api_method_denylist: Vec<String>,
+    /// Maximum number of requests per minute accepted by the HTTP API server from a single client IP address. The address
+    /// is taken from the `X-Forwarded-For` or `X-Real-IP` header, which must be set by a reverse proxy; requests without
+    /// these headers are not limited by IP. If not set, requests are not limited by IP.
+    api_requests_per_minute_per_ip_limit: Option<NonZeroU32>,
+    /// Maximum number of requests per minute accepted by the HTTP API server with a single API key supplied
+    /// in the [`api_key_header`](Self::api_key_header). If not set, requests are not limited by API key.
+    api_requests_per_minute_per_api_key_limit: Option<NonZeroU32>,
+    /// Name of the HTTP header containing the API key used for rate limiting.
+    #[serde(default = "OptionalENConfig::default_api_key_header")]
+    api_key_header: String,
     /// Whether to support HTTP methods that install filters and query filter changes.
     /// WS methods are unaffected.
     ///
@@ -285,6 +347,23 @@ pub(crate) struct OptionalENConfig {
     /// (hundreds or thousands RPS).
     #[serde(default = "OptionalENConfig::default_extended_api_tracing")]
     pub extended_rpc_tracing: bool,
+    /// Maximum number of opcodes recorded by `debug_traceCall.opcodeTracer`. Only used if the `debug` namespace is enabled.
+    #[serde(default = "OptionalENConfig::default_api_debug_max_opcode_trace_steps")]
+    pub api_debug_max_opcode_trace_steps: usize,
+    /// Whether the chain runs the EVM emulator alongside EraVM. Should match the main node setting;
+    /// if disabled, transactions deploying EVM bytecode are rejected by the API server.
+    #[serde(default)]
+    pub evm_emulator_enabled: bool,
+    /// Delay before the first resubmission of a proxied transaction to the main node. Transactions are resubmitted
+    /// until they are included into a block, rejected by the main node, or expire; the delay is doubled after each attempt.
+    #[serde(default = "OptionalENConfig::default_tx_proxy_resubmission_interval_ms")]
+    tx_proxy_resubmission_interval_ms: u64,
+    /// Upper bound for the delay between resubmissions of a proxied transaction.
+    #[serde(default = "OptionalENConfig::default_tx_proxy_max_resubmission_interval_ms")]
+    tx_proxy_max_resubmission_interval_ms: u64,
+    /// Time after which a proxied transaction that isn't included into a block is dropped from the resubmission queue.
+    #[serde(default = "OptionalENConfig::default_tx_proxy_tx_expiration_sec")]
+    tx_proxy_tx_expiration_sec: u64,
 
     // Health checks
     /// Time limit in milliseconds to mark a health check as slow and log the corresponding warning.
@@ -329,6 +408,9 @@ pub(crate) struct OptionalENConfig {
     /// but the effects vary wildly depending on the setup (e.g., the filesystem used).
     #[serde(default = "OptionalENConfig::default_merkle_tree_multi_get_chunk_size")]
     pub merkle_tree_multi_get_chunk_size: usize,
+    /// Number of threads in a dedicated thread pool used for parallel hash computations in the Merkle tree.
+    /// 0 means the number of logical CPUs. If not specified, the global thread pool is used.
+    pub merkle_tree_thread_pool_size: Option<usize>,
     /// Capacity of the block cache for the Merkle tree RocksDB. Reasonable values range from ~100 MiB to several GiB.
     /// The default value is 128 MiB.
     #[serde(default = "OptionalENConfig::default_merkle_tree_block_cache_size_mb")]
@@ -352,6 +434,36 @@ pub(crate) struct OptionalENConfig {
     database_long_connection_threshold_ms: Option<u64>,
     /// Threshold in milliseconds to denote a DB query as "slow" and log its details. If not specified, such logging will be disabled.
     database_slow_query_threshold_ms: Option<u64>,
+    /// Acquire timeout in seconds for a single connection attempt for the core connection pools (i.e., all pools
+    /// except for the one used by the API servers). There are multiple attempts (currently 3) before acquiring
+    /// a connection fails. If not specified, the `sqlx` default (30 seconds) will be used.
+    database_acquire_timeout_sec: Option<u64>,
+    /// Statement timeout in seconds for the core connection pools. If not specified, statements are not limited.
+    database_statement_timeout_sec: Option<u64>,
+    /// Maximum number of connections in the connection pool used by the API servers. If not specified,
+    /// the size of the core pool (`DATABASE_POOL_SIZE`) will be used.
+    api_database_pool_size: Option<u32>,
+    /// Acquire timeout in seconds for a single connection attempt for the connection pool used by the API servers.
+    /// If not specified, the `sqlx` default (30 seconds) will be used.
+    api_database_acquire_timeout_sec: Option<u64>,
+    /// Statement timeout in seconds for the connection pool used by the API servers. Setting it is recommended
+    /// so that runaway API queries don't hold connections indefinitely. If not specified, statements are not limited.
+    api_database_statement_timeout_sec: Option<u64>,
+    /// Overrides `database_long_connection_threshold_ms` for the connection pool used by the API servers.
+    api_database_long_connection_threshold_ms: Option<u64>,
+    /// Overrides `database_slow_query_threshold_ms` for the connection pool used by the API servers.
+    api_database_slow_query_threshold_ms: Option<u64>,
+    /// URLs of read replicas of the node database used by the API servers. If specified, API connections are distributed
+    /// among the replicas in the round-robin fashion, so that heavy RPC load doesn't contend with writes by the sync pipeline.
+    /// Replicas lagging by more than `api_database_replica_max_lag_ms` are skipped; if all replicas are lagging
+    /// or unavailable, connections are acquired from the main database. Each replica gets its own pool
+    /// with `api_database_pool_size` connections.
+    #[serde(default)]
+    #[schemars(with = "Vec<String>")]
+    api_database_replica_urls: Vec<SensitiveUrl>,
+    /// Maximum replication lag in milliseconds for an API read replica to be used. The default value is 2,000 ms.
+    #[serde(default = "OptionalENConfig::default_api_database_replica_max_lag_ms")]
+    api_database_replica_max_lag_ms: u64,
 
     // Other config settings
     /// Capacity of the queue for asynchronous L2 block sealing. Once this many L2 blocks are queued,
@@ -372,12 +484,29 @@ pub(crate) struct OptionalENConfig {
     /// by commit transactions. If not set, it will not be verified.
     // This is intentionally not a part of `RemoteENConfig` because fetching this info from the main node would defeat
     // its purpose; the consistency checker assumes that the main node may provide false information.
+    #[schemars(with = "Option<String>")]
     pub contracts_diamond_proxy_addr: Option<Address>,
     /// Number of requests per second allocated for the main node HTTP client. Default is 100 requests.
     #[serde(default = "OptionalENConfig::default_main_node_rate_limit_rps")]
     pub main_node_rate_limit_rps: NonZeroUsize,
+    /// Fallback main node URLs, listed in the priority order. If specified, the main node client fails over
+    /// to these URLs if the main URL (`EN_MAIN_NODE_URL`) is unavailable or rate-limits requests. Each endpoint
+    /// is rate-limited separately according to `main_node_rate_limit_rps`.
+    #[serde(default)]
+    #[schemars(with = "Vec<String>")]
+    pub main_node_fallback_urls: Vec<SensitiveUrl>,
+    /// Fallback addresses of the Ethereum node API, listed in the priority order. If specified, the L1 client
+    /// fails over to these addresses if the main address (`EN_ETH_CLIENT_URL`) is unavailable or rate-limits requests.
+    #[serde(default)]
+    #[schemars(with = "Vec<String>")]
+    pub eth_client_fallback_urls: Vec<SensitiveUrl>,
+    /// TTL for cached L1 responses for immutable data (blocks queried by hash or number, transaction receipts),
+    /// in milliseconds. The default value is 10,000 ms; 0 disables caching.
+    #[serde(default = "OptionalENConfig::default_eth_client_response_cache_ttl_ms")]
+    eth_client_response_cache_ttl_ms: u64,
 
     #[serde(default)]
+    #[schemars(with = "String")]
     pub l1_batch_commit_data_generator_mode: L1BatchCommitmentMode,
     /// Enables application-level snapshot recovery. Required to start a node that was recovered from a snapshot,
     /// or to initialize a node from a snapshot. Has no effect if a node that was initialized from a Postgres dump
@@ -394,6 +523,7 @@ pub(crate) struct OptionalENConfig {
 
     /// Enables pruning of the historical node state (Postgres and Merkle tree). The node will retain
     /// recent state and will continuously remove (prune) old enough parts of the state in the background.
+    /// Postgres data is pruned by the `pruning` component, and Merkle tree data by the `tree` component.
     #[serde(default)]
     pub pruning_enabled: bool,
     /// Number of L1 batches pruned at a time.
@@ -410,9 +540,62 @@ pub(crate) struct OptionalENConfig {
     /// If set to 0, L1 batches will not be retained based on their timestamp. The default value is 1 hour.
     #[serde(default = "OptionalENConfig::default_pruning_data_retention_sec")]
     pruning_data_retention_sec: u64,
+    /// Same as `pruning_data_retention_sec`, but specified in hours, which is more convenient for typical
+    /// retention periods (e.g., 168 for 7 days). If set, takes precedence over `pruning_data_retention_sec`.
+    pruning_data_retention_hours: Option<u64>,
+
+    /// Port of the contract verification API server run by the `contract_verification` component.
+    /// The default value is 3070.
+    #[serde(default = "OptionalENConfig::default_contract_verification_api_port")]
+    pub contract_verification_api_port: u16,
+    /// Max time of a single contract compilation performed by the contract verifier, in seconds.
+    /// The default value is 30 seconds.
+    #[serde(default = "OptionalENConfig::default_contract_verification_compilation_timeout_sec")]
+    contract_verification_compilation_timeout_sec: NonZeroU64,
+    /// Interval between polling Postgres for new verification requests, in milliseconds. The default value is 1,000 ms.
+    #[serde(default = "OptionalENConfig::default_contract_verification_polling_interval_ms")]
+    contract_verification_polling_interval_ms: u64,
+    /// Enables caching of compilation artifacts in the object store configured with
+    /// `EN_CONTRACT_VERIFIER_OBJECT_STORE_` env variables, so that verifying a contract with the same sources
+    /// and compiler settings doesn't require recompilation.
+    #[serde(default)]
+    pub contract_verification_artifacts_cache_enabled: bool,
+
+    /// Interval between snapshot creation attempts by the `snapshots_creator` component, in seconds.
+    /// The default value is 1 hour.
+    #[serde(default = "OptionalENConfig::default_snapshots_creator_interval_sec")]
+    snapshots_creator_interval_sec: u64,
+    /// Expected number of storage logs in a single snapshot chunk. The default value is 1,000,000.
+    #[serde(default = "OptionalENConfig::default_snapshots_creator_storage_logs_chunk_size")]
+    snapshots_creator_storage_logs_chunk_size: u64,
+    /// Max number of storage log chunks processed concurrently by the snapshot creator. The default value is 25.
+    #[serde(default = "OptionalENConfig::default_snapshots_creator_concurrent_queries_count")]
+    snapshots_creator_concurrent_queries_count: u32,
+
+    /// Path to the RocksDB directory serving as the state cache for the `vm_runner_bwip` component.
+    /// Required if the component is enabled; must differ from the state keeper cache path.
+    pub bwip_state_cache_path: Option<String>,
+    /// Max number of L1 batches re-executed concurrently by the `vm_runner_bwip` component. The default value is 3.
+    #[serde(default = "OptionalENConfig::default_bwip_window_size")]
+    bwip_window_size: u32,
+    /// L1 batches up to this one (inclusive) are not processed by the `vm_runner_bwip` component. The default value is 0.
+    #[serde(default)]
+    bwip_first_processed_batch: u32,
+
+    /// Enables automatic rollback if the reorg detector finds a divergence with the main node while the node is running.
+    /// In this case, the node stops all components, reverts Postgres, the Merkle tree and the state keeper cache
+    /// to the last correct L1 batch, and restarts, provided that at most this many L1 batches are rolled back.
+    /// Deeper reorgs (including ones detected on node start) are not rolled back and require manual intervention.
+    /// If not set, the node exits once a reorg is detected, and rolls back an unbounded number of L1 batches
+    /// on the next start.
+    pub reorg_auto_rollback_max_depth: Option<NonZeroU32>,
 }
 
 impl OptionalENConfig {
+    fn default_api_key_header() -> String {
+        ClientRateLimit::DEFAULT_API_KEY_HEADER.to_owned()
+    }
+
     const fn default_filters_limit() -> usize {
         10_000
     }
@@ -421,6 +604,14 @@ impl OptionalENConfig {
         10_000
     }
 
+    const fn default_subscriptions_per_connection_limit() -> usize {
+        1_024
+    }
+
+    const fn default_subscription_backlog_size() -> usize {
+        1_024
+    }
+
     const fn default_req_entities_limit() -> usize {
         1_024
     }
@@ -520,18 +711,74 @@ impl OptionalENConfig {
         10_000
     }
 
+    const fn default_tx_proxy_resubmission_interval_ms() -> u64 {
+        10_000
+    }
+
+    const fn default_tx_proxy_max_resubmission_interval_ms() -> u64 {
+        300_000
+    }
+
+    const fn default_tx_proxy_tx_expiration_sec() -> u64 {
+        3_600 // 1 hour
+    }
+
     const fn default_extended_api_tracing() -> bool {
         true
     }
 
+    fn default_api_debug_max_opcode_trace_steps() -> usize {
+        DebugNamespaceConfig::default().max_opcode_trace_steps
+    }
+
     fn default_main_node_rate_limit_rps() -> NonZeroUsize {
         NonZeroUsize::new(100).unwrap()
     }
 
+    const fn default_eth_client_response_cache_ttl_ms() -> u64 {
+        10_000
+    }
+
+    const fn default_api_database_replica_max_lag_ms() -> u64 {
+        2_000
+    }
+
     fn default_snapshots_recovery_postgres_max_concurrency() -> NonZeroUsize {
         SnapshotsApplierConfig::default().max_concurrency
     }
 
+    const fn default_eth_call_cache_ttl_sec() -> u64 {
+        60
+    }
+
+    const fn default_contract_verification_api_port() -> u16 {
+        3070
+    }
+
+    fn default_contract_verification_compilation_timeout_sec() -> NonZeroU64 {
+        NonZeroU64::new(30).unwrap()
+    }
+
+    const fn default_contract_verification_polling_interval_ms() -> u64 {
+        1_000
+    }
+
+    const fn default_snapshots_creator_interval_sec() -> u64 {
+        3_600
+    }
+
+    const fn default_snapshots_creator_storage_logs_chunk_size() -> u64 {
+        1_000_000
+    }
+
+    const fn default_snapshots_creator_concurrent_queries_count() -> u32 {
+        25
+    }
+
+    const fn default_bwip_window_size() -> u32 {
+        3
+    }
+
     const fn default_pruning_chunk_size() -> u32 {
         10
     }
@@ -544,9 +791,9 @@ impl OptionalENConfig {
         3_600 // 1 hour
     }
 
-    fn from_env() -> anyhow::Result<Self> {
+    fn new(source: &impl ConfigurationSource) -> anyhow::Result<Self> {
         envy::prefixed("EN_")
-            .from_env()
+            .from_iter(source.utf8_vars())
             .context("could not load external node config")
     }
 
@@ -598,12 +845,73 @@ impl OptionalENConfig {
             .map(Duration::from_millis)
     }
 
+    pub fn database_acquire_timeout(&self) -> Option<Duration> {
+        self.database_acquire_timeout_sec.map(Duration::from_secs)
+    }
+
+    pub fn database_statement_timeout(&self) -> Option<Duration> {
+        self.database_statement_timeout_sec.map(Duration::from_secs)
+    }
+
+    pub fn api_database_acquire_timeout(&self) -> Option<Duration> {
+        self.api_database_acquire_timeout_sec
+            .map(Duration::from_secs)
+    }
+
+    pub fn api_database_statement_timeout(&self) -> Option<Duration> {
+        self.api_database_statement_timeout_sec
+            .map(Duration::from_secs)
+    }
+
+    pub fn api_database_long_connection_threshold(&self) -> Option<Duration> {
+        self.api_database_long_connection_threshold_ms
+            .map(Duration::from_millis)
+    }
+
+    pub fn api_database_slow_query_threshold(&self) -> Option<Duration> {
+        self.api_database_slow_query_threshold_ms
+            .map(Duration::from_millis)
+    }
+
+    pub fn api_database_replica_max_lag(&self) -> Duration {
+        Duration::from_millis(self.api_database_replica_max_lag_ms)
+    }
+
     pub fn api_namespaces(&self) -> Vec<Namespace> {
         self.api_namespaces
             .clone()
             .unwrap_or_else(|| Namespace::DEFAULT.to_vec())
     }
 
+    pub fn api_method_filter(&self) -> MethodFilter {
+        let filter = match &self.api_method_allowlist {
+            Some(allowlist) => MethodFilter::allow_only(allowlist),
+            None => MethodFilter::default(),
+        };
+        filter.deny(&self.api_method_denylist)
+    }
+
+    pub fn api_client_rate_limit(&self) -> anyhow::Result<ClientRateLimit> {
+        let mut rate_limit =
+            ClientRateLimit::default().with_api_key_header(&self.api_key_header)?;
+        if let Some(limit) = self.api_requests_per_minute_per_ip_limit {
+            rate_limit = rate_limit.per_ip(limit);
+        }
+        if let Some(limit) = self.api_requests_per_minute_per_api_key_limit {
+            rate_limit = rate_limit.per_api_key(limit);
+        }
+        Ok(rate_limit)
+    }
+
+    /// Returns the `debug` namespace config if the namespace is enabled.
+    pub fn debug_namespace_config(&self) -> Option<DebugNamespaceConfig> {
+        self.api_namespaces()
+            .contains(&Namespace::Debug)
+            .then(|| DebugNamespaceConfig {
+                max_opcode_trace_steps: self.api_debug_max_opcode_trace_steps,
+            })
+    }
+
     pub fn max_response_body_size(&self) -> MaxResponseSize {
         let scale = NonZeroUsize::new(BYTES_IN_MEGABYTE).unwrap();
         MaxResponseSize {
@@ -626,12 +934,73 @@ impl OptionalENConfig {
         Duration::from_millis(self.mempool_cache_update_interval_ms)
     }
 
+    pub fn tx_proxy_queue_config(&self) -> TxProxyQueueConfig {
+        TxProxyQueueConfig {
+            resubmission_interval: Duration::from_millis(self.tx_proxy_resubmission_interval_ms),
+            max_resubmission_interval: Duration::from_millis(
+                self.tx_proxy_max_resubmission_interval_ms,
+            ),
+            tx_expiration: Duration::from_secs(self.tx_proxy_tx_expiration_sec),
+            ..TxProxyQueueConfig::default()
+        }
+    }
+
+    pub fn eth_call_cache_ttl(&self) -> Duration {
+        Duration::from_secs(self.eth_call_cache_ttl_sec)
+    }
+
+    /// Returns the config for the contract verification API server and the contract verifier.
+    pub fn contract_verifier_config(&self) -> ContractVerifierConfig {
+        ContractVerifierConfig {
+            compilation_timeout: self.contract_verification_compilation_timeout_sec.get(),
+            polling_interval: Some(self.contract_verification_polling_interval_ms),
+            // Metrics are exported by the node's Prometheus exporter; the port is not used.
+            prometheus_port: 0,
+            threads_per_server: None,
+            port: self.contract_verification_api_port,
+            url: format!("http://127.0.0.1:{}", self.contract_verification_api_port),
+        }
+    }
+
+    /// Returns the TTL for cached L1 responses, or `None` if caching is disabled.
+    pub fn eth_client_response_cache_ttl(&self) -> Option<Duration> {
+        (self.eth_client_response_cache_ttl_ms > 0)
+            .then(|| Duration::from_millis(self.eth_client_response_cache_ttl_ms))
+    }
+
+    /// Returns the config for the snapshot creator. The object store for snapshots is configured separately.
+    pub fn snapshots_creator_config(&self) -> SnapshotsCreatorConfig {
+        SnapshotsCreatorConfig {
+            storage_logs_chunk_size: self.snapshots_creator_storage_logs_chunk_size,
+            concurrent_queries_count: self.snapshots_creator_concurrent_queries_count,
+            object_store: None,
+            creation_interval_sec: Some(self.snapshots_creator_interval_sec),
+        }
+    }
+
+    /// Returns the config for the basic witness input producer, or `None` if its state cache path is not set.
+    /// The object store for produced inputs is configured separately.
+    pub fn basic_witness_input_producer_config(&self) -> Option<BasicWitnessInputProducerConfig> {
+        Some(BasicWitnessInputProducerConfig {
+            db_path: self.bwip_state_cache_path.clone()?,
+            window_size: self.bwip_window_size,
+            first_processed_batch: L1BatchNumber(self.bwip_first_processed_batch),
+        })
+    }
+
     pub fn pruning_removal_delay(&self) -> Duration {
         Duration::from_secs(self.pruning_removal_delay_sec.get())
     }
 
-    pub fn pruning_data_retention(&self) -> Duration {
-        Duration::from_secs(self.pruning_data_retention_sec)
+    pub fn pruning_data_retention(&self) -> anyhow::Result<Duration> {
+        if let Some(hours) = self.pruning_data_retention_hours {
+            let secs = hours
+                .checked_mul(3_600)
+                .with_context(|| format!("`pruning_data_retention_hours` is too large: {hours}"))?;
+            Ok(Duration::from_secs(secs))
+        } else {
+            Ok(Duration::from_secs(self.pruning_data_retention_sec))
+        }
     }
 
     #[cfg(test)]
@@ -642,13 +1011,15 @@ impl OptionalENConfig {
 }
 
 /// This part of the external node config is required for its operation.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, JsonSchema)]
 pub(crate) struct RequiredENConfig {
     /// L1 chain ID (e.g., 9 for Ethereum mainnet). This ID will be checked against the `eth_client_url` RPC provider on initialization
     /// to ensure that there's no mismatch between the expected and actual L1 network.
+    #[schemars(with = "u64")]
     pub l1_chain_id: L1ChainId,
     /// L2 chain ID (e.g., 270 for zkSync Era mainnet). This ID will be checked against the `main_node_url` RPC provider on initialization
     /// to ensure that there's no mismatch between the expected and actual L2 network.
+    #[schemars(with = "u64")]
     pub l2_chain_id: L2ChainId,
 
     /// Port on which the HTTP RPC server is listening.
@@ -658,8 +1029,10 @@ pub(crate) struct RequiredENConfig {
     /// Port on which the healthcheck REST server is listening.
     pub healthcheck_port: u16,
     /// Address of the Ethereum node API.
+    #[schemars(with = "String")]
     pub eth_client_url: SensitiveUrl,
     /// Main node URL - used by external node to proxy transactions to, query state from, etc.
+    #[schemars(with = "String")]
     pub main_node_url: SensitiveUrl,
     /// Path to the database data directory that serves state cache.
     pub state_cache_path: String,
@@ -668,9 +1041,9 @@ pub(crate) struct RequiredENConfig {
 }
 
 impl RequiredENConfig {
-    fn from_env() -> anyhow::Result<Self> {
+    fn new(source: &impl ConfigurationSource) -> anyhow::Result<Self> {
         envy::prefixed("EN_")
-            .from_env()
+            .from_iter(source.utf8_vars())
             .context("could not load external node config")
     }
 
@@ -700,20 +1073,25 @@ impl RequiredENConfig {
 /// While also mandatory, it historically used different naming scheme for corresponding
 /// environment variables.
 /// Thus it is kept separately for backward compatibility and ease of deserialization.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, JsonSchema)]
 pub(crate) struct PostgresConfig {
+    /// URL of the Postgres database (`DATABASE_URL` env variable).
+    #[schemars(with = "String")]
     database_url: SensitiveUrl,
+    /// Maximum number of connections in the connection pool (`DATABASE_POOL_SIZE` env variable).
     pub max_connections: u32,
 }
 
 impl PostgresConfig {
-    fn from_env() -> anyhow::Result<Self> {
+    fn new(source: &impl ConfigurationSource) -> anyhow::Result<Self> {
         Ok(Self {
-            database_url: env::var("DATABASE_URL")
+            database_url: source
+                .var("DATABASE_URL")
                 .context("DATABASE_URL env variable is not set")?
                 .parse()
                 .context("DATABASE_URL env variable is not a valid Postgres URL")?,
-            max_connections: env::var("DATABASE_POOL_SIZE")
+            max_connections: source
+                .var("DATABASE_POOL_SIZE")
                 .context("DATABASE_POOL_SIZE env variable is not set")?
                 .parse()
                 .context("Unable to parse DATABASE_POOL_SIZE env variable")?,
@@ -744,6 +1122,15 @@ pub(crate) struct ExperimentalENConfig {
     /// Maximum number of files concurrently opened by state keeper cache RocksDB. Useful to fit into OS limits; can be used
     /// as a rudimentary way to control RAM usage of the cache.
     pub state_keeper_db_max_open_files: Option<NonZeroU32>,
+    /// Soft limit on the total size of SST files in the state keeper cache RocksDB. If exceeded, the cache is compacted
+    /// immediately. If not set, the cache size is not limited.
+    state_keeper_db_max_size_mb: Option<usize>,
+    /// Interval between manual compactions of the state keeper cache RocksDB. If not set, the cache is not
+    /// compacted manually (unless it exceeds `state_keeper_db_max_size_mb`).
+    state_keeper_db_compaction_interval_sec: Option<u64>,
+    /// SST files of the state keeper cache RocksDB not compacted for longer than this period will be compacted
+    /// by RocksDB in background. If not set, default RocksDB behavior is used.
+    state_keeper_db_compaction_ttl_sec: Option<u64>,
 
     // Snapshot recovery
     /// L1 batch number of the snapshot to use during recovery. Specifying this parameter is mostly useful for testing.
@@ -761,6 +1148,9 @@ pub(crate) struct ExperimentalENConfig {
     /// If not set, parallel persistence will be disabled.
     #[serde(default)] // Temporarily use a conservative option (sequential recovery) as default
     pub snapshots_recovery_tree_parallel_persistence_buffer: Option<NonZeroUsize>,
+    /// Maximum number of snapshot chunks loaded from Postgres concurrently during Merkle tree recovery.
+    /// If not set, `snapshots_recovery_postgres_max_concurrency` is used.
+    pub snapshots_recovery_tree_max_concurrency: Option<NonZeroUsize>,
 
     // Commitment generator
     /// Maximum degree of parallelism during commitment generation, i.e., the maximum number of L1 batches being processed in parallel.
@@ -783,9 +1173,13 @@ impl ExperimentalENConfig {
             state_keeper_db_block_cache_capacity_mb:
                 Self::default_state_keeper_db_block_cache_capacity_mb(),
             state_keeper_db_max_open_files: None,
+            state_keeper_db_max_size_mb: None,
+            state_keeper_db_compaction_interval_sec: None,
+            state_keeper_db_compaction_ttl_sec: None,
             snapshots_recovery_l1_batch: None,
             snapshots_recovery_tree_chunk_size: Self::default_snapshots_recovery_tree_chunk_size(),
             snapshots_recovery_tree_parallel_persistence_buffer: None,
+            snapshots_recovery_tree_max_concurrency: None,
             commitment_generator_max_parallelism: None,
         }
     }
@@ -794,29 +1188,99 @@ impl ExperimentalENConfig {
     pub fn state_keeper_db_block_cache_capacity(&self) -> usize {
         self.state_keeper_db_block_cache_capacity_mb * BYTES_IN_MEGABYTE
     }
+
+    /// Returns the soft limit on the state keeper RocksDB cache size in bytes.
+    pub fn state_keeper_db_max_size(&self) -> Option<u64> {
+        self.state_keeper_db_max_size_mb
+            .map(|size| (size * BYTES_IN_MEGABYTE) as u64)
+    }
+
+    pub fn state_keeper_db_compaction_interval(&self) -> Option<Duration> {
+        self.state_keeper_db_compaction_interval_sec
+            .map(Duration::from_secs)
+    }
+
+    pub fn state_keeper_db_compaction_ttl(&self) -> Option<Duration> {
+        self.state_keeper_db_compaction_ttl_sec
+            .map(Duration::from_secs)
+    }
+
+    /// Returns the state keeper RocksDB cache configuration in the format used by the node framework.
+    pub fn state_keeper_db_config(&self) -> ExperimentalDBConfig {
+        ExperimentalDBConfig {
+            state_keeper_db_block_cache_capacity_mb: self.state_keeper_db_block_cache_capacity_mb,
+            state_keeper_db_max_open_files: self.state_keeper_db_max_open_files,
+            state_keeper_db_max_size_mb: self.state_keeper_db_max_size_mb,
+            state_keeper_db_compaction_interval_sec: self.state_keeper_db_compaction_interval_sec,
+            state_keeper_db_compaction_ttl_sec: self.state_keeper_db_compaction_ttl_sec,
+        }
+    }
 }
 
-pub(crate) fn read_consensus_secrets() -> anyhow::Result<Option<ConsensusSecrets>> {
+async fn read_consensus_secrets(
+    resolver: &SecretsResolver,
+) -> anyhow::Result<Option<ConsensusSecrets>> {
     let Ok(path) = env::var("EN_CONSENSUS_SECRETS_PATH") else {
         return Ok(None);
     };
-    let cfg = std::fs::read_to_string(&path).context(path)?;
+    let cfg = read_yaml_config(path.as_ref())?;
+    let cfg = resolver
+        .resolve_yaml(&cfg)
+        .await
+        .context("failed resolving consensus secrets")?;
     Ok(Some(
         decode_yaml_repr::<proto::secrets::ConsensusSecrets>(&cfg)
             .context("failed decoding YAML")?,
     ))
 }
 
+/// Reads the admin revert token from `EN_ADMIN_REVERT_TOKEN`, which may be a reference to a secret manager.
+async fn read_admin_revert_token(
+    resolver: &SecretsResolver,
+) -> anyhow::Result<Option<Secret<String>>> {
+    let Ok(token) = env::var("EN_ADMIN_REVERT_TOKEN") else {
+        return Ok(None);
+    };
+    let token = match SecretRef::parse(&token)? {
+        Some(reference) => resolver.resolve(&reference).await?,
+        None => Secret::new(token),
+    };
+    validate_admin_revert_token(&token)?;
+    Ok(Some(token))
+}
+
+/// Ensures that the admin revert token isn't empty; an empty token would make the revert endpoint
+/// accessible with an empty bearer token.
+fn validate_admin_revert_token(token: &Secret<String>) -> anyhow::Result<()> {
+    anyhow::ensure!(
+        !token.expose_secret().trim().is_empty(),
+        "EN_ADMIN_REVERT_TOKEN must not be empty or consist only of whitespace"
+    );
+    Ok(())
+}
+
 pub(crate) fn read_consensus_config() -> anyhow::Result<Option<ConsensusConfig>> {
     let Ok(path) = env::var("EN_CONSENSUS_CONFIG_PATH") else {
         return Ok(None);
     };
-    let cfg = std::fs::read_to_string(&path).context(path)?;
+    let cfg = read_yaml_config(path.as_ref())?;
     Ok(Some(
         decode_yaml_repr::<proto::consensus::Config>(&cfg).context("failed decoding YAML")?,
     ))
 }
 
+/// Reads the configuration of the DA client used by the DA inclusion verifier.
+pub(crate) fn read_da_client_config() -> anyhow::Result<Option<DAClientConfig>> {
+    let Ok(path) = env::var("EN_DA_CLIENT_CONFIG_PATH") else {
+        return Ok(None);
+    };
+    let cfg = read_yaml_config(path.as_ref())?;
+    Ok(Some(
+        decode_yaml_repr::<proto::da_client::DataAvailabilityClient>(&cfg)
+            .context("failed decoding YAML")?,
+    ))
+}
+
 /// Configuration for snapshot recovery. Should be loaded optionally, only if snapshot recovery is enabled.
 pub(crate) fn snapshot_recovery_object_store_config() -> anyhow::Result<ObjectStoreConfig> {
     envy::prefixed("EN_SNAPSHOTS_OBJECT_STORE_")
@@ -824,6 +1288,32 @@ pub(crate) fn snapshot_recovery_object_store_config() -> anyhow::Result<ObjectSt
         .context("failed loading snapshot object store config from env variables")
 }
 
+/// Configuration of the object store caching contract compilation artifacts. Should be loaded optionally,
+/// only if artifacts caching is enabled.
+pub(crate) fn contract_verifier_object_store_config() -> anyhow::Result<ObjectStoreConfig> {
+    envy::prefixed("EN_CONTRACT_VERIFIER_OBJECT_STORE_")
+        .from_env::<ObjectStoreConfig>()
+        .context("failed loading contract verifier object store config from env variables")
+}
+
+/// Configuration of the object store for snapshots created by the node. Should be loaded optionally,
+/// only if the snapshot creator is run.
+pub(crate) fn snapshots_creator_object_store_config() -> anyhow::Result<ObjectStoreConfig> {
+    envy::prefixed("EN_SNAPSHOTS_CREATOR_OBJECT_STORE_")
+        .from_env::<ObjectStoreConfig>()
+        .context("failed loading snapshot creator object store config from env variables")
+}
+
+/// Configuration of the object store for basic witness inputs produced by the node. Should be loaded optionally,
+/// only if the basic witness input producer is run.
+pub(crate) fn bwip_object_store_config() -> anyhow::Result<ObjectStoreConfig> {
+    envy::prefixed("EN_BWIP_OBJECT_STORE_")
+        .from_env::<ObjectStoreConfig>()
+        .context(
+            "failed loading basic witness input producer object store config from env variables",
+        )
+}
+
 #[derive(Debug, Deserialize)]
 pub struct ApiComponentConfig {
     /// Address of the tree API used by this EN in case it does not have a
@@ -847,33 +1337,120 @@ pub(crate) struct ExternalNodeConfig<R = RemoteENConfig> {
     pub observability: ObservabilityENConfig,
     pub experimental: ExperimentalENConfig,
     pub consensus: Option<ConsensusConfig>,
+    /// Consensus secrets. Loaded by [`ExternalNodeConfig::resolve_secrets()`].
+    pub consensus_secrets: Option<ConsensusSecrets>,
+    /// Bearer token authorizing requests to the revert endpoint of the admin server. The endpoint is disabled
+    /// if the token is not set. Loaded by [`ExternalNodeConfig::resolve_secrets()`].
+    pub admin_revert_token: Option<Secret<String>>,
+    pub da_client: Option<DAClientConfig>,
     pub api_component: ApiComponentConfig,
     pub tree_component: TreeComponentConfig,
+    /// Node profile the config was prefilled from, if any. Loaded by [`ExternalNodeConfig::load()`].
+    pub node_profile: Option<NodeProfile>,
     pub remote: R,
 }
 
 impl ExternalNodeConfig<()> {
-    /// Parses the local part of node configuration from the environment.
-    pub fn new() -> anyhow::Result<Self> {
+    /// Parses the local part of node configuration from the environment, optional network and node profiles,
+    /// an optional YAML config file and command-line overrides. See [`LayeredConfigSource`] for the precedence
+    /// of these sources.
+    ///
+    /// Returns the config together with the report on sources of the effective parameter values.
+    pub fn load(
+        network: Option<NetworkProfile>,
+        node_profile: Option<NodeProfile>,
+        config_path: Option<&Path>,
+        overrides: &[String],
+    ) -> anyhow::Result<(Self, ConfigReport)> {
+        let mut source = LayeredConfigSource::new(&Environment);
+        if let Some(node_profile) = node_profile {
+            source = source.with_node_profile(node_profile)?;
+        }
+        if let Some(network) = network {
+            source = source.with_profile(network)?;
+        }
+        if let Some(path) = config_path {
+            source = source.with_file(path)?;
+        }
+        let source = source.with_overrides(overrides)?;
+        let mut config = Self::from_source(&source)?;
+        config.node_profile = node_profile;
+        Ok((config, source.report()))
+    }
+
+    fn from_source(source: &impl ConfigurationSource) -> anyhow::Result<Self> {
         Ok(Self {
-            required: RequiredENConfig::from_env()?,
-            postgres: PostgresConfig::from_env()?,
-            optional: OptionalENConfig::from_env()?,
-            observability: ObservabilityENConfig::from_env()?,
+            required: RequiredENConfig::new(source)?,
+            postgres: PostgresConfig::new(source)?,
+            optional: OptionalENConfig::new(source)?,
+            observability: ObservabilityENConfig::new(source)
+                .context("could not load external node config (observability params)")?,
             experimental: envy::prefixed("EN_EXPERIMENTAL_")
                 .from_env::<ExperimentalENConfig>()
                 .context("could not load external node config (experimental params)")?,
             consensus: read_consensus_config().context("read_consensus_config()")?,
+            consensus_secrets: None,
+            admin_revert_token: None,
+            da_client: read_da_client_config().context("read_da_client_config()")?,
             api_component: envy::prefixed("EN_API_")
                 .from_env::<ApiComponentConfig>()
                 .context("could not load external node config (API component params)")?,
             tree_component: envy::prefixed("EN_TREE_")
                 .from_env::<TreeComponentConfig>()
                 .context("could not load external node config (tree component params)")?,
+            node_profile: None,
             remote: (),
         })
     }
 
+    /// Resolves secrets specified as references to secret managers (Postgres URLs and L1 client URLs)
+    /// and loads consensus secrets and the admin revert token, resolving references in them as well.
+    pub async fn resolve_secrets(mut self, resolver: &SecretsResolver) -> anyhow::Result<Self> {
+        self.postgres.database_url = resolver
+            .resolve_url(self.postgres.database_url)
+            .await
+            .context("failed resolving Postgres URL")?;
+        self.required.eth_client_url = resolver
+            .resolve_url(self.required.eth_client_url)
+            .await
+            .context("failed resolving L1 client URL")?;
+        let mut fallback_urls = Vec::with_capacity(self.optional.eth_client_fallback_urls.len());
+        for (i, url) in self
+            .optional
+            .eth_client_fallback_urls
+            .into_iter()
+            .enumerate()
+        {
+            let url = resolver
+                .resolve_url(url)
+                .await
+                .with_context(|| format!("failed resolving fallback L1 client URL #{i}"))?;
+            fallback_urls.push(url);
+        }
+        self.optional.eth_client_fallback_urls = fallback_urls;
+        let mut replica_urls = Vec::with_capacity(self.optional.api_database_replica_urls.len());
+        for (i, url) in self
+            .optional
+            .api_database_replica_urls
+            .into_iter()
+            .enumerate()
+        {
+            let url = resolver
+                .resolve_url(url)
+                .await
+                .with_context(|| format!("failed resolving API database replica URL #{i}"))?;
+            replica_urls.push(url);
+        }
+        self.optional.api_database_replica_urls = replica_urls;
+        self.consensus_secrets = read_consensus_secrets(resolver)
+            .await
+            .context("read_consensus_secrets()")?;
+        self.admin_revert_token = read_admin_revert_token(resolver)
+            .await
+            .context("read_admin_revert_token()")?;
+        Ok(self)
+    }
+
     /// Fetches contracts addresses from the main node, completing the configuration.
     pub async fn fetch_remote(
         self,
@@ -889,14 +1466,99 @@ impl ExternalNodeConfig<()> {
             observability: self.observability,
             experimental: self.experimental,
             consensus: self.consensus,
+            consensus_secrets: self.consensus_secrets,
+            admin_revert_token: self.admin_revert_token,
+            da_client: self.da_client,
             tree_component: self.tree_component,
             api_component: self.api_component,
+            node_profile: self.node_profile,
             remote,
         })
     }
 }
 
+impl<R> ExternalNodeConfig<R> {
+    /// Checks whether the node prunes historical data in Postgres and the Merkle tree. If a node profile is selected,
+    /// it defines whether data is pruned (archive nodes never prune data, while other nodes always do), and
+    /// `optional.pruning_enabled` is ignored.
+    pub fn pruning_enabled(&self) -> bool {
+        match self.node_profile {
+            Some(NodeProfile::Archive) => false,
+            Some(NodeProfile::Full | NodeProfile::Minimal) => true,
+            None => self.optional.pruning_enabled,
+        }
+    }
+}
+
 impl ExternalNodeConfig {
+    /// Returns the configuration of the Merkle tree run by the node.
+    pub fn metadata_calculator_config(&self) -> MetadataCalculatorConfig {
+        MetadataCalculatorConfig {
+            db_path: self.required.merkle_tree_path.clone(),
+            max_open_files: self.optional.merkle_tree_max_open_files,
+            mode: MerkleTreeMode::Lightweight,
+            delay_interval: self.optional.merkle_tree_processing_delay(),
+            max_l1_batches_per_iter: self.optional.merkle_tree_max_l1_batches_per_iter,
+            multi_get_chunk_size: self.optional.merkle_tree_multi_get_chunk_size,
+            block_cache_capacity: self.optional.merkle_tree_block_cache_size(),
+            include_indices_and_filters_in_block_cache: self
+                .optional
+                .merkle_tree_include_indices_and_filters_in_block_cache,
+            memtable_capacity: self.optional.merkle_tree_memtable_capacity(),
+            stalled_writes_timeout: self.optional.merkle_tree_stalled_writes_timeout(),
+            thread_pool_size: self.optional.merkle_tree_thread_pool_size,
+            recovery: MetadataCalculatorRecoveryConfig {
+                desired_chunk_size: self.experimental.snapshots_recovery_tree_chunk_size,
+                parallel_persistence_buffer: self
+                    .experimental
+                    .snapshots_recovery_tree_parallel_persistence_buffer,
+                concurrency_limit: self.experimental.snapshots_recovery_tree_max_concurrency,
+            },
+            // The tree is lightweight, so it doesn't need protective reads.
+            sealed_batches_have_protective_reads: true,
+        }
+    }
+
+    /// Returns the size of the connection pool used to recover the Merkle tree from a snapshot.
+    pub fn tree_recovery_pool_size(&self) -> anyhow::Result<u32> {
+        let max_concurrency = self
+            .experimental
+            .snapshots_recovery_tree_max_concurrency
+            .unwrap_or(self.optional.snapshots_recovery_postgres_max_concurrency)
+            .get();
+        let max_concurrency = u32::try_from(max_concurrency).with_context(|| {
+            format!("snapshot recovery max concurrency ({max_concurrency}) is too large")
+        })?;
+        Ok(max_concurrency.min(self.postgres.max_connections))
+    }
+
+    /// Returns a builder for core connection pools with the specified maximum size.
+    pub fn pool_builder(&self, max_size: u32) -> ConnectionPoolBuilder<Core> {
+        let mut builder = ConnectionPool::builder(self.postgres.database_url(), max_size);
+        builder
+            .set_acquire_timeout(self.optional.database_acquire_timeout())
+            .set_statement_timeout(self.optional.database_statement_timeout());
+        builder
+    }
+
+    /// Returns a builder for the connection pool used by the API servers. If API read replicas are configured,
+    /// the pool distributes connections among them, so it must only be used for reads.
+    pub fn api_pool_builder(&self) -> ConnectionPoolBuilder<Core> {
+        let max_size = self
+            .optional
+            .api_database_pool_size
+            .unwrap_or(self.postgres.max_connections);
+        let mut builder = ConnectionPool::builder(self.postgres.database_url(), max_size);
+        builder
+            .set_acquire_timeout(self.optional.api_database_acquire_timeout())
+            .set_statement_timeout(self.optional.api_database_statement_timeout())
+            .set_long_connection_threshold(self.optional.api_database_long_connection_threshold())
+            .set_slow_query_threshold(self.optional.api_database_slow_query_threshold())
+            .set_replica_urls(self.optional.api_database_replica_urls.clone())
+            .set_max_replica_lag(Some(self.optional.api_database_replica_max_lag()));
+        builder
+    }
+
     #[cfg(test)]
     pub(crate) fn mock(temp_dir: &tempfile::TempDir, test_pool: &ConnectionPool<Core>) -> Self {
         Self {
@@ -907,10 +1569,14 @@ impl ExternalNodeConfig {
             observability: ObservabilityENConfig::default(),
             experimental: ExperimentalENConfig::mock(),
             consensus: None,
+            consensus_secrets: None,
+            admin_revert_token: None,
+            da_client: None,
             api_component: ApiComponentConfig {
                 tree_api_remote_url: None,
             },
             tree_component: TreeComponentConfig { api_port: None },
+            node_profile: None,
         }
     }
 }
@@ -940,10 +1606,12 @@ impl From<&ExternalNodeConfig> for InternalApiConfig {
             l2_testnet_paymaster_addr: config.remote.l2_testnet_paymaster_addr,
             req_entities_limit: config.optional.req_entities_limit,
             fee_history_limit: config.optional.fee_history_limit,
+            max_logs_block_range: config.optional.max_logs_block_range,
             base_token_address: Some(config.remote.base_token_addr),
             filters_disabled: config.optional.filters_disabled,
             dummy_verifier: config.remote.dummy_verifier,
             l1_batch_commit_data_generator_mode: config.remote.l1_batch_commit_data_generator_mode,
+            evm_emulator_enabled: config.optional.evm_emulator_enabled,
         }
     }
 }
@@ -966,6 +1634,14 @@ impl From<&ExternalNodeConfig> for TxSenderConfig {
             chain_id: config.required.l2_chain_id,
             // Does not matter for EN.
             whitelisted_tokens_for_aa: Default::default(),
+            evm_emulator_enabled: config.optional.evm_emulator_enabled,
+            // Mempool admission rules are enforced by the main node; the EN doesn't have a mempool
+            // and its DB doesn't contain pending transactions.
+            max_pending_txs_per_initiator: None,
+            min_max_fee_per_gas: None,
+            denied_initiators: Default::default(),
+            eth_call_cache_size: config.optional.eth_call_cache_size,
+            eth_call_cache_ttl: config.optional.eth_call_cache_ttl(),
         }
     }
 }
@@ -1,21 +1,28 @@
 use std::{collections::HashMap, time::Duration};
 
 use anyhow::Context as _;
-use prometheus_exporter::PrometheusExporterConfig;
+use prometheus_exporter::{push_gateway_endpoint, PrometheusExporterConfig};
+use schemars::JsonSchema;
 use serde::Deserialize;
 use vlog::LogFormat;
+use zksync_config::configs::SloConfig;
 
-use super::{ConfigurationSource, Environment};
+use super::ConfigurationSource;
 
 /// Observability part of the node configuration.
-#[derive(Debug, Default, Deserialize)]
+#[derive(Debug, Default, Deserialize, JsonSchema)]
 pub(crate) struct ObservabilityENConfig {
     /// Port to bind the Prometheus exporter server to. If not specified, the server will not be launched.
     /// If the push gateway URL is specified, it will prevail.
     pub prometheus_port: Option<u16>,
-    /// Prometheus push gateway to push metrics to. Overrides `prometheus_port`. A full URL must be specified
-    /// including `job_id` and other path segments; it will be used verbatim as the URL to push data to.
+    /// Prometheus push gateway to push metrics to. Overrides `prometheus_port`. If `prometheus_push_job_name` is not specified,
+    /// a full URL must be specified including `job_id` and other path segments; it will be used verbatim as the URL to push data to.
     pub prometheus_pushgateway_url: Option<String>,
+    /// Name of the job used in the grouping key of pushed metrics. If specified, `prometheus_pushgateway_url` is treated
+    /// as the base push gateway URL, and the grouping key path segments are appended to it.
+    pub prometheus_push_job_name: Option<String>,
+    /// Value of the `instance` label used in the grouping key of pushed metrics. Only used together with `prometheus_push_job_name`.
+    pub prometheus_push_instance: Option<String>,
     /// Interval between pushing metrics to the Prometheus push gateway.
     #[serde(default = "ObservabilityENConfig::default_prometheus_push_interval_ms")]
     pub prometheus_push_interval_ms: u64,
@@ -23,9 +30,42 @@ pub(crate) struct ObservabilityENConfig {
     pub sentry_url: Option<String>,
     /// Environment to use when sending data to Sentry.
     pub sentry_environment: Option<String>,
+    /// Release name to use when sending data to Sentry. If not specified, the release is derived from the node version.
+    pub sentry_release: Option<String>,
     /// Log format to use: either `plain` (default) or `json`.
     #[serde(default)]
+    #[schemars(with = "String")]
     pub log_format: LogFormat,
+    /// Per-target log sampling directives, e.g. `zksync_node_sync=100,zksync_eth_client=10s`. `{n}` logs one of `n` events
+    /// from the same callsite, and `{n}s` logs at most one event from the same callsite per `n` seconds. If not specified,
+    /// logs are not sampled.
+    pub log_sampling_directives: Option<String>,
+    /// Level of spans exported via OpenTelemetry: `off`, `info`, `debug` or `trace`. Spans are only exported
+    /// if both this level and `otlp_endpoint` are set.
+    pub opentelemetry_level: Option<String>,
+    /// OpenTelemetry HTTP collector endpoint.
+    pub otlp_endpoint: Option<String>,
+    /// Ratio of traces exported via OpenTelemetry, from 0 to 1. If not set, all traces are exported.
+    pub opentelemetry_sampling_ratio: Option<f64>,
+    /// Additional resource attributes attached to exported spans as comma-separated `key=value` pairs,
+    /// e.g. `deployment.environment=testnet,service.instance.id=en-0`.
+    pub opentelemetry_resource_attributes: Option<String>,
+    /// Port of the admin server allowing to change log directives at runtime. The server is bound
+    /// to the loopback interface. If not specified, the server will not be launched.
+    pub admin_port: Option<u16>,
+    /// Enables CPU profiling and async task dump endpoints on the admin server.
+    #[serde(default)]
+    pub admin_profiling_enabled: bool,
+    /// Interval between evaluations of SLO thresholds in milliseconds. If not specified, SLOs are evaluated every 10 seconds.
+    pub slo_check_interval_ms: Option<u64>,
+    /// Maximum lag behind the main node in L2 blocks. If exceeded, the `slo_sync_lag` health component is degraded.
+    pub slo_max_sync_lag: Option<u32>,
+    /// Maximum share of used connections in the main DB connection pool, from 0 to 1. If exceeded,
+    /// the `slo_pool_saturation` health component is degraded.
+    pub slo_max_pool_saturation: Option<f64>,
+    /// Maximum 99th percentile of API call latency over the last 5 minutes in milliseconds. If exceeded,
+    /// the `slo_api_p99_latency` health component is degraded.
+    pub slo_max_api_p99_latency_ms: Option<u64>,
 }
 
 impl ObservabilityENConfig {
@@ -33,10 +73,6 @@ impl ObservabilityENConfig {
         10_000
     }
 
-    pub(super) fn from_env() -> envy::Result<Self> {
-        Self::new(&Environment)
-    }
-
     pub(super) fn new(source: &impl ConfigurationSource) -> envy::Result<Self> {
         const OBSOLETE_VAR_NAMES: &[(&str, &str)] = &[
             ("MISC_SENTRY_URL", "EN_SENTRY_URL"),
@@ -71,22 +107,64 @@ impl ObservabilityENConfig {
                     tracing::info!("Both Prometheus port and push gateway URLs are specified; the push gateway URL will be used");
                 }
                 let push_interval = Duration::from_millis(self.prometheus_push_interval_ms);
-                Some(PrometheusExporterConfig::push(url.clone(), push_interval))
+                let endpoint = match &self.prometheus_push_job_name {
+                    Some(job_name) => {
+                        let labels = self
+                            .prometheus_push_instance
+                            .as_deref()
+                            .map(|instance| ("instance", instance));
+                        push_gateway_endpoint(url, job_name, labels)
+                    }
+                    None => url.clone(),
+                };
+                Some(PrometheusExporterConfig::push(endpoint, push_interval))
             }
             (Some(port), None) => Some(PrometheusExporterConfig::pull(port)),
             (None, None) => None,
         }
     }
 
+    pub fn slo(&self) -> SloConfig {
+        SloConfig {
+            check_interval_ms: self.slo_check_interval_ms,
+            max_sync_lag: self.slo_max_sync_lag,
+            max_pool_saturation: self.slo_max_pool_saturation,
+            max_api_p99_latency_ms: self.slo_max_api_p99_latency_ms,
+        }
+    }
+
     pub fn build_observability(&self) -> anyhow::Result<vlog::ObservabilityGuard> {
         let mut builder = vlog::ObservabilityBuilder::new().with_log_format(self.log_format);
+        if let Some(directives) = &self.log_sampling_directives {
+            let directives = directives
+                .parse()
+                .context("Invalid log sampling directives")?;
+            builder = builder.with_log_sampling(directives);
+        }
         // Some legacy deployments use `unset` as an equivalent of `None`.
         let sentry_url = self.sentry_url.as_deref().filter(|&url| url != "unset");
         if let Some(sentry_url) = sentry_url {
             builder = builder
                 .with_sentry_url(sentry_url)
                 .context("Invalid Sentry URL")?
-                .with_sentry_environment(self.sentry_environment.clone());
+                .with_sentry_environment(self.sentry_environment.clone())
+                .with_sentry_release(Some(self.sentry_release.clone().unwrap_or_else(|| {
+                    concat!(env!("CARGO_PKG_NAME"), "@", env!("CARGO_PKG_VERSION")).to_owned()
+                })));
+        }
+        if let (Some(level), Some(endpoint)) = (&self.opentelemetry_level, &self.otlp_endpoint) {
+            builder = builder
+                .with_opentelemetry(level, endpoint.clone(), "zksync-external-node".into())
+                .context("Invalid OpenTelemetry config")?;
+            if let Some(sampling_ratio) = self.opentelemetry_sampling_ratio {
+                builder = builder.with_opentelemetry_sampling_ratio(sampling_ratio);
+            }
+            if let Some(attributes) = &self.opentelemetry_resource_attributes {
+                let attributes = attributes
+                    .parse()
+                    .context("Invalid OpenTelemetry resource attributes")?;
+                builder = builder.with_opentelemetry_resource_attributes(attributes);
+            }
         }
         let guard = builder.build();
 
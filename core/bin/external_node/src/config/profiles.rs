@@ -0,0 +1,84 @@
+//! Built-in configuration profiles for well-known networks and node roles.
+
+use std::fmt;
+
+/// Built-in configuration profile prefilling chain IDs, main node URL, contract addresses and tuned defaults
+/// for a well-known network. Profiles have the same format as the YAML config file and the lowest precedence
+/// among the configuration sources (see [`LayeredConfigSource`](super::layered::LayeredConfigSource)).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub(crate) enum NetworkProfile {
+    /// ZKsync Era mainnet.
+    EraMainnet,
+    /// ZKsync Era Sepolia testnet.
+    EraTestnet,
+    /// Mantle chains (validium mode); chain IDs and URLs must be specified separately.
+    Mantle,
+    /// Local development setup started with `zk init`.
+    LocalDev,
+}
+
+impl NetworkProfile {
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::EraMainnet => "era-mainnet",
+            Self::EraTestnet => "era-testnet",
+            Self::Mantle => "mantle",
+            Self::LocalDev => "local-dev",
+        }
+    }
+
+    /// Returns the YAML contents of the profile.
+    pub fn yaml(self) -> &'static str {
+        match self {
+            Self::EraMainnet => include_str!("profiles/era-mainnet.yaml"),
+            Self::EraTestnet => include_str!("profiles/era-testnet.yaml"),
+            Self::Mantle => include_str!("profiles/mantle.yaml"),
+            Self::LocalDev => include_str!("profiles/local-dev.yaml"),
+        }
+    }
+}
+
+impl fmt::Display for NetworkProfile {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(self.name())
+    }
+}
+
+/// Built-in configuration profile defining the role of the node: which part of the chain history it retains and which
+/// historical RPC methods it serves. Like [`NetworkProfile`]s, node profiles have the same format as the YAML config file
+/// and only fill in parameters not set by the config file, env variables or command-line overrides. Node profile values
+/// take precedence over network profile ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub(crate) enum NodeProfile {
+    /// Syncs from genesis, never prunes data and serves all RPC methods including the `debug` namespace.
+    Archive,
+    /// Can be recovered from a snapshot; retains a week of history.
+    Full,
+    /// Recovered from a snapshot; retains only the recent state and doesn't serve logs, filters or the `debug` namespace.
+    Minimal,
+}
+
+impl NodeProfile {
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Archive => "archive",
+            Self::Full => "full",
+            Self::Minimal => "minimal",
+        }
+    }
+
+    /// Returns the YAML contents of the profile.
+    pub fn yaml(self) -> &'static str {
+        match self {
+            Self::Archive => include_str!("profiles/node-archive.yaml"),
+            Self::Full => include_str!("profiles/node-full.yaml"),
+            Self::Minimal => include_str!("profiles/node-minimal.yaml"),
+        }
+    }
+}
+
+impl fmt::Display for NodeProfile {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(self.name())
+    }
+}
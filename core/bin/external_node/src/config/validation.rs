@@ -0,0 +1,383 @@
+//! Validation of the node configuration performed by the `config validate` command.
+
+use std::{collections::HashSet, future::Future, time::Duration};
+
+use anyhow::Context as _;
+use serde::Serialize;
+use zksync_dal::{ConnectionPool, Core};
+use zksync_eth_client::EthInterface;
+use zksync_types::{L1ChainId, L2ChainId};
+use zksync_web3_decl::{
+    client::{Client, L1, L2},
+    error::ClientRpcContext,
+    namespaces::{EthNamespaceClient, ZksNamespaceClient},
+};
+
+use super::{snapshot_recovery_object_store_config, ExternalNodeConfig, NodeProfile};
+use crate::Component;
+
+/// Timeout for each probe of an external dependency.
+const PROBE_TIMEOUT: Duration = Duration::from_secs(10);
+/// Name of the check wiring the node for the validated set of components.
+const WIRING_CHECK: &str = "wiring";
+
+/// Status of a single validation check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum CheckStatus {
+    /// The check is not applicable to the configuration or was not requested.
+    Skipped,
+    Ok,
+    /// The configuration is usable, but is likely to be unintended.
+    Warning,
+    /// The node will not start or will not work correctly with the configuration.
+    Error,
+}
+
+/// Result of a single validation check.
+#[derive(Debug, Serialize)]
+pub(crate) struct CheckResult {
+    pub name: &'static str,
+    pub status: CheckStatus,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub messages: Vec<String>,
+}
+
+impl CheckResult {
+    fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            status: CheckStatus::Ok,
+            messages: vec![],
+        }
+    }
+
+    fn skipped(name: &'static str, reason: impl Into<String>) -> Self {
+        Self {
+            name,
+            status: CheckStatus::Skipped,
+            messages: vec![reason.into()],
+        }
+    }
+
+    fn warn(&mut self, message: impl Into<String>) {
+        self.status = self.status.max(CheckStatus::Warning);
+        self.messages.push(message.into());
+    }
+
+    fn fail(&mut self, message: impl Into<String>) {
+        self.status = CheckStatus::Error;
+        self.messages.push(message.into());
+    }
+}
+
+/// Machine-readable outcome of config validation. Serialized as JSON by the `config validate` command.
+#[derive(Debug, Default, Serialize)]
+pub(crate) struct ValidationReport {
+    valid: bool,
+    checks: Vec<CheckResult>,
+    /// IDs of tasks that would be started by the node for the validated set of components. Only resolved
+    /// for the node framework, since tasks are resolved by wiring the node.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    tasks: Vec<String>,
+}
+
+impl ValidationReport {
+    /// Creates a report for a config that could not be parsed.
+    pub fn parse_error(err: &anyhow::Error) -> Self {
+        let mut check = CheckResult::new("parse");
+        check.fail(format!("{err:#}"));
+        let mut this = Self::default();
+        this.push(check);
+        this
+    }
+
+    /// Returns `false` if any of the checks has failed. Warnings do not invalidate the config.
+    pub fn is_valid(&self) -> bool {
+        self.valid
+    }
+
+    #[cfg(test)]
+    pub fn check(&self, name: &str) -> Option<&CheckResult> {
+        self.checks.iter().find(|check| check.name == name)
+    }
+
+    /// Adds the outcome of wiring the node for the validated set of components, together with the IDs
+    /// of the tasks that would be started.
+    pub fn push_wiring(&mut self, result: anyhow::Result<Vec<String>>) {
+        let mut check = CheckResult::new(WIRING_CHECK);
+        match result {
+            Ok(tasks) => self.tasks = tasks,
+            Err(err) => check.fail(format!("{err:#}")),
+        }
+        self.push(check);
+    }
+
+    /// Marks wiring the node as skipped for the specified reason.
+    pub fn skip_wiring(&mut self, reason: &str) {
+        self.push(CheckResult::skipped(WIRING_CHECK, reason));
+    }
+
+    fn push(&mut self, check: CheckResult) {
+        self.checks.push(check);
+        self.valid = self
+            .checks
+            .iter()
+            .all(|check| check.status != CheckStatus::Error);
+    }
+}
+
+impl<R> ExternalNodeConfig<R> {
+    /// Runs offline cross-field checks of this config for the specified set of components.
+    pub fn validate(&self, components: &HashSet<Component>) -> ValidationReport {
+        let mut report = ValidationReport::default();
+        report.push(CheckResult::new("parse"));
+        report.push(self.check_ports(components));
+        report.push(self.check_pruning());
+        report.push(self.check_node_profile());
+        report.push(self.check_components(components));
+        report.push(self.check_consensus());
+        report.push(self.check_snapshot_recovery());
+        report
+    }
+
+    fn check_ports(&self, components: &HashSet<Component>) -> CheckResult {
+        let mut check = CheckResult::new("ports");
+        let mut ports = vec![("required.healthcheck_port", self.required.healthcheck_port)];
+        if components.contains(&Component::HttpApi) {
+            ports.push(("required.http_port", self.required.http_port));
+        }
+        if components.contains(&Component::WsApi) {
+            ports.push(("required.ws_port", self.required.ws_port));
+        }
+        if components.contains(&Component::TreeApi) {
+            if let Some(port) = self.tree_component.api_port {
+                ports.push(("EN_TREE_API_PORT", port));
+            }
+        }
+        if components.contains(&Component::ContractVerification) {
+            ports.push((
+                "optional.contract_verification_api_port",
+                self.optional.contract_verification_api_port,
+            ));
+        }
+        let observability = &self.observability;
+        if let (Some(port), None) = (
+            observability.prometheus_port,
+            &observability.prometheus_pushgateway_url,
+        ) {
+            ports.push(("observability.prometheus_port", port));
+        }
+        if let Some(port) = observability.admin_port {
+            ports.push(("observability.admin_port", port));
+        }
+        if let Some(consensus) = &self.consensus {
+            ports.push(("consensus.server_addr", consensus.server_addr.port()));
+        }
+
+        // Port 0 means that the port is assigned by the OS, so such ports never collide.
+        ports.retain(|&(_, port)| port != 0);
+        for (i, &(name, port)) in ports.iter().enumerate() {
+            for &(other_name, other_port) in &ports[..i] {
+                if port == other_port {
+                    check.fail(format!("`{other_name}` and `{name}` both use port {port}"));
+                }
+            }
+        }
+        check
+    }
+
+    fn check_pruning(&self) -> CheckResult {
+        let optional = &self.optional;
+        if !self.pruning_enabled() {
+            return CheckResult::skipped("pruning", "pruning is disabled");
+        }
+
+        let mut check = CheckResult::new("pruning");
+        if optional.pruning_chunk_size == 0 {
+            check.fail("`optional.pruning_chunk_size` must be positive");
+        }
+        let retention = match optional.pruning_data_retention() {
+            Ok(retention) => retention,
+            Err(err) => {
+                check.fail(format!("{err:#}"));
+                return check;
+            }
+        };
+        let removal_delay = optional.pruning_removal_delay();
+        if !retention.is_zero() && retention < removal_delay {
+            check.fail(format!(
+                "pruning data retention ({retention:?}) is less than the removal delay ({removal_delay:?}); \
+                 data could be hard-removed while API servers are still allowed to serve it"
+            ));
+        }
+        check
+    }
+
+    /// Checks that parameters defining the node role weren't overridden in a way contradicting the selected node profile.
+    fn check_node_profile(&self) -> CheckResult {
+        let Some(profile) = self.node_profile else {
+            return CheckResult::skipped("node_profile", "node profile is not selected");
+        };
+
+        let mut check = CheckResult::new("node_profile");
+        let optional = &self.optional;
+        match profile {
+            NodeProfile::Archive => {
+                if optional.pruning_enabled {
+                    check.warn("`optional.pruning_enabled` is set for an archive node; it is ignored, and historical data will not be pruned");
+                }
+                if optional.snapshots_recovery_enabled {
+                    check.warn(
+                        "`optional.snapshots_recovery_enabled` is set for an archive node; if the node is recovered \
+                         from a snapshot, it won't have history preceding the snapshot",
+                    );
+                }
+            }
+            NodeProfile::Full | NodeProfile::Minimal => {
+                if !optional.pruning_enabled {
+                    check.warn(format!(
+                        "`optional.pruning_enabled` is unset for a {profile} node; it is ignored, and historical data will be pruned"
+                    ));
+                }
+            }
+        }
+        check
+    }
+
+    /// Checks component-specific configuration. Dependencies among components are checked by wiring the node;
+    /// see [`ValidationReport::push_wiring()`].
+    fn check_components(&self, components: &HashSet<Component>) -> CheckResult {
+        let mut check = CheckResult::new("components");
+        if components.contains(&Component::TreeApi) && self.tree_component.api_port.is_none() {
+            check.fail("Merkle tree API is enabled, but `EN_TREE_API_PORT` is not set");
+        }
+        if self.pruning_enabled() && !components.contains(&Component::Pruning) {
+            check.warn(
+                "pruning is enabled, but the `pruning` component is not run; Postgres data will not be pruned \
+                 by this node",
+            );
+        }
+        if components.contains(&Component::Tree) && self.api_component.tree_api_remote_url.is_some()
+        {
+            check.warn("tree component is run locally; `EN_API_TREE_API_REMOTE_URL` is ignored");
+        }
+        check
+    }
+
+    fn check_consensus(&self) -> CheckResult {
+        if self.consensus.is_none() {
+            return CheckResult::skipped("consensus", "consensus is disabled");
+        }
+
+        let mut check = CheckResult::new("consensus");
+        if self.consensus_secrets.is_none() {
+            check.fail("consensus config is specified, but secrets are missing (`EN_CONSENSUS_SECRETS_PATH`)");
+        }
+        check
+    }
+
+    fn check_snapshot_recovery(&self) -> CheckResult {
+        if !self.optional.snapshots_recovery_enabled {
+            return CheckResult::skipped("snapshot_recovery", "snapshot recovery is disabled");
+        }
+
+        let mut check = CheckResult::new("snapshot_recovery");
+        if let Err(err) = snapshot_recovery_object_store_config() {
+            // The object store is only accessed if the node storage is empty.
+            check.warn(format!(
+                "{err:#}; the node will fail to start if it needs to be recovered from a snapshot"
+            ));
+        }
+        check
+    }
+
+    /// Probes external dependencies (Postgres, L1 and main node) and adds the results to `report`.
+    /// If `enabled` is `false`, the probes are marked as skipped.
+    pub async fn probe_dependencies(&self, report: &mut ValidationReport, enabled: bool) {
+        const PROBES: [&str; 3] = ["postgres", "l1_client", "main_node_client"];
+
+        if !enabled {
+            for name in PROBES {
+                report.push(CheckResult::skipped(
+                    name,
+                    "probes are disabled; use `--probe`",
+                ));
+            }
+            return;
+        }
+
+        let database_url = self.postgres.database_url();
+        report.push(
+            probe(PROBES[0], async {
+                let pool = ConnectionPool::<Core>::singleton(database_url)
+                    .build()
+                    .await
+                    .context("failed building connection pool")?;
+                pool.connection()
+                    .await
+                    .context("failed connecting to Postgres")?;
+                Ok(())
+            })
+            .await,
+        );
+
+        let l1_chain_id = self.required.l1_chain_id;
+        let eth_client_url = self.required.eth_client_url.clone();
+        report.push(
+            probe(PROBES[1], async {
+                let client = Client::<L1>::http(eth_client_url)
+                    .context("failed creating JSON-RPC client for Ethereum")?
+                    .for_network(l1_chain_id.into())
+                    .build();
+                let chain_id = client.fetch_chain_id().await?;
+                anyhow::ensure!(
+                    chain_id == l1_chain_id,
+                    "Ethereum node has L1 chain ID {chain_id}, while {l1_chain_id} is configured"
+                );
+                Ok(())
+            })
+            .await,
+        );
+
+        let l2_chain_id = self.required.l2_chain_id;
+        let main_node_url = self.required.main_node_url.clone();
+        report.push(
+            probe(PROBES[2], async {
+                let client = Client::<L2>::http(main_node_url)
+                    .context("failed creating JSON-RPC client for main node")?
+                    .for_network(l2_chain_id.into())
+                    .build();
+                let chain_id = client.chain_id().rpc_context("chain_id").await?;
+                let chain_id = L2ChainId::try_from(chain_id.as_u64()).map_err(|err| {
+                    anyhow::anyhow!("invalid chain ID supplied by main node: {err}")
+                })?;
+                anyhow::ensure!(
+                    chain_id == l2_chain_id,
+                    "main node has L2 chain ID {chain_id:?}, while {l2_chain_id:?} is configured"
+                );
+                let chain_id = client.l1_chain_id().rpc_context("l1_chain_id").await?;
+                let chain_id = L1ChainId(chain_id.as_u64());
+                anyhow::ensure!(
+                    chain_id == l1_chain_id,
+                    "main node has L1 chain ID {chain_id}, while {l1_chain_id} is configured"
+                );
+                Ok(())
+            })
+            .await,
+        );
+    }
+}
+
+async fn probe(
+    name: &'static str,
+    action: impl Future<Output = anyhow::Result<()>>,
+) -> CheckResult {
+    let mut check = CheckResult::new(name);
+    match tokio::time::timeout(PROBE_TIMEOUT, action).await {
+        Ok(Ok(())) => { /* OK */ }
+        Ok(Err(err)) => check.fail(format!("{err:#}")),
+        Err(_) => check.fail(format!("timed out after {PROBE_TIMEOUT:?}")),
+    }
+    check
+}
@@ -1,10 +1,14 @@
-use std::{collections::HashSet, net::Ipv4Addr, str::FromStr, sync::Arc, time::Duration};
+use std::{
+    collections::HashSet, iter, net::Ipv4Addr, num::NonZeroU32, path::PathBuf, str::FromStr,
+    sync::Arc, time::Duration,
+};
 
 use anyhow::Context as _;
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use metrics::EN_METRICS;
+use serde::Serialize;
 use tokio::{
-    sync::{oneshot, watch, RwLock},
+    sync::{oneshot, watch},
     task::{self, JoinHandle},
 };
 use zksync_block_reverter::{BlockReverter, NodeRole};
@@ -12,60 +16,83 @@ use zksync_commitment_generator::{
     validation_task::L1BatchCommitmentModeValidationTask, CommitmentGenerator,
 };
 use zksync_concurrency::{ctx, scope};
-use zksync_config::configs::{api::MerkleTreeApiConfig, database::MerkleTreeMode};
+use zksync_config::configs::{api::MerkleTreeApiConfig, SloConfig};
 use zksync_consistency_checker::ConsistencyChecker;
+use zksync_contract_verifier_lib::ContractVerifier;
 use zksync_core_leftovers::setup_sigint_handler;
-use zksync_dal::{metrics::PostgresMetrics, ConnectionPool, Core};
+use zksync_da_verifier::DAInclusionVerifier;
+use zksync_dal::{metrics::PostgresMetrics, ConnectionPool, Core, CoreDal};
 use zksync_db_connection::{
     connection_pool::ConnectionPoolBuilder, healthcheck::ConnectionPoolHealthCheck,
 };
-use zksync_health_check::{AppHealthCheck, HealthStatus, ReactiveHealthCheck};
+use zksync_health_check::{AppHealthCheck, HealthStatus, ReactiveHealthCheck, SloMonitor};
 use zksync_metadata_calculator::{
     api_server::{TreeApiClient, TreeApiHttpClient},
-    MetadataCalculator, MetadataCalculatorConfig, MetadataCalculatorRecoveryConfig,
+    MetadataCalculator,
 };
 use zksync_node_api_server::{
+    admin::{AdminServer, RevertHandler},
     execution_sandbox::VmConcurrencyLimiter,
     healthcheck::HealthCheckHandle,
-    tx_sender::{proxy::TxProxy, ApiContracts, TxSenderBuilder},
-    web3::{mempool_cache::MempoolCache, ApiBuilder, Namespace},
+    tx_sender::{
+        proxy::TxProxy, whitelisted_tokens_for_aa::WhitelistedTokensForAaUpdater, ApiContracts,
+        TxSenderBuilder,
+    },
+    web3::{mempool_cache::MempoolCache, recent_call_latency_p99, ApiBuilder, Namespace},
 };
 use zksync_node_consensus as consensus;
 use zksync_node_db_pruner::{DbPruner, DbPrunerConfig};
 use zksync_node_fee_model::l1_gas_price::MainNodeFeeParamsFetcher;
 use zksync_node_sync::{
     batch_status_updater::BatchStatusUpdater, external_io::ExternalIO,
-    tree_data_fetcher::TreeDataFetcher, validate_chain_ids_task::ValidateChainIdsTask, ActionQueue,
-    MainNodeHealthCheck, SyncState,
+    storage_init::SnapshotRecoveryConfig, tree_data_fetcher::TreeDataFetcher,
+    validate_chain_ids_task::ValidateChainIdsTask, ActionQueue, MainNodeHealthCheck,
+    ProtocolVersionsTracker, SyncState,
 };
+use zksync_object_store::ObjectStoreFactory;
 use zksync_reorg_detector::ReorgDetector;
+use zksync_secrets::SecretsResolver;
 use zksync_shared_metrics::rustc::RUST_METRICS;
-use zksync_state::{PostgresStorageCaches, RocksdbStorageOptions};
+use zksync_snapshots_creator::{SnapshotCreator, MIN_CHUNK_COUNT};
+use zksync_state::{PostgresStorageCaches, RocksdbMaintenanceConfig, RocksdbStorageOptions};
 use zksync_state_keeper::{
     seal_criteria::NoopSealer, AsyncRocksdbCache, BatchExecutor, MainBatchExecutor, OutputHandler,
     StateKeeperPersistence, TreeWritesPersistence, ZkSyncStateKeeper,
 };
 use zksync_storage::RocksDB;
-use zksync_types::L2ChainId;
+use zksync_types::{L1BatchNumber, L2ChainId};
 use zksync_utils::wait_for_tasks::ManagedTasks;
-use zksync_web3_decl::{
-    client::{Client, DynClient, L1, L2},
-    jsonrpsee,
-    namespaces::EnNamespaceClient,
-};
+use zksync_vm_runner::BasicWitnessInputProducer;
+use zksync_web3_decl::client::{Client, DynClient, FailoverClient, FailoverClientProber, L1, L2};
 
 use crate::{
-    config::ExternalNodeConfig,
-    init::{ensure_storage_initialized, SnapshotRecoveryConfig},
+    config::{
+        bwip_object_store_config, config_file_schema, contract_verifier_object_store_config,
+        snapshots_creator_object_store_config, validation::ValidationReport, ExternalNodeConfig,
+        NetworkProfile, NodeProfile,
+    },
+    init::ensure_storage_initialized,
+    node_builder::ExternalNodeBuilder,
 };
 
 mod config;
 mod init;
 mod metadata;
 mod metrics;
+mod node_builder;
 #[cfg(test)]
 mod tests;
 
+#[cfg(not(target_env = "msvc"))]
+#[global_allocator]
+static GLOBAL: tikv_jemallocator::Jemalloc = tikv_jemallocator::Jemalloc;
+/// Enables jemalloc heap profiling, which can be activated at runtime via the admin server.
+/// Allocations are not sampled until then, so this has negligible overhead.
+#[cfg(not(target_env = "msvc"))]
+#[allow(non_upper_case_globals)]
+#[export_name = "_rjem_malloc_conf"]
+pub static malloc_conf: &[u8] = b"prof:true,prof_active:false,lg_prof_sample:19\0";
+
 /// Creates the state keeper configured to work in the external node mode.
 #[allow(clippy::too_many_arguments)]
 async fn build_state_keeper(
@@ -85,9 +112,16 @@ async fn build_state_keeper(
     let cache_options = RocksdbStorageOptions {
         block_cache_capacity: config.experimental.state_keeper_db_block_cache_capacity(),
         max_open_files: config.experimental.state_keeper_db_max_open_files,
+        periodic_compaction_ttl: config.experimental.state_keeper_db_compaction_ttl(),
     };
     let (storage_factory, task) =
         AsyncRocksdbCache::new(connection_pool.clone(), state_keeper_db_path, cache_options);
+    let maintenance_task = storage_factory.maintenance_task(RocksdbMaintenanceConfig {
+        compaction_interval: config.experimental.state_keeper_db_compaction_interval(),
+        max_size_bytes: config.experimental.state_keeper_db_max_size(),
+        ..RocksdbMaintenanceConfig::default()
+    });
+    task_handles.push(tokio::spawn(maintenance_task.run(stop_receiver.clone())));
     let mut stop_receiver_clone = stop_receiver.clone();
     task_handles.push(tokio::spawn(async move {
         let result = task.run(stop_receiver_clone.clone()).await;
@@ -124,41 +158,13 @@ async fn run_tree(
     stop_receiver: watch::Receiver<bool>,
     tree_pool: ConnectionPool<Core>,
 ) -> anyhow::Result<Arc<dyn TreeApiClient>> {
-    let metadata_calculator_config = MetadataCalculatorConfig {
-        db_path: config.required.merkle_tree_path.clone(),
-        max_open_files: config.optional.merkle_tree_max_open_files,
-        mode: MerkleTreeMode::Lightweight,
-        delay_interval: config.optional.merkle_tree_processing_delay(),
-        max_l1_batches_per_iter: config.optional.merkle_tree_max_l1_batches_per_iter,
-        multi_get_chunk_size: config.optional.merkle_tree_multi_get_chunk_size,
-        block_cache_capacity: config.optional.merkle_tree_block_cache_size(),
-        include_indices_and_filters_in_block_cache: config
-            .optional
-            .merkle_tree_include_indices_and_filters_in_block_cache,
-        memtable_capacity: config.optional.merkle_tree_memtable_capacity(),
-        stalled_writes_timeout: config.optional.merkle_tree_stalled_writes_timeout(),
-        recovery: MetadataCalculatorRecoveryConfig {
-            desired_chunk_size: config.experimental.snapshots_recovery_tree_chunk_size,
-            parallel_persistence_buffer: config
-                .experimental
-                .snapshots_recovery_tree_parallel_persistence_buffer,
-        },
-    };
+    let metadata_calculator_config = config.metadata_calculator_config();
 
-    let max_concurrency = config
-        .optional
-        .snapshots_recovery_postgres_max_concurrency
-        .get();
-    let max_concurrency = u32::try_from(max_concurrency).with_context(|| {
-        format!("snapshot recovery max concurrency ({max_concurrency}) is too large")
-    })?;
-    let recovery_pool = ConnectionPool::builder(
-        tree_pool.database_url().clone(),
-        max_concurrency.min(config.postgres.max_connections),
-    )
-    .build()
-    .await
-    .context("failed creating DB pool for Merkle tree recovery")?;
+    let recovery_pool = config
+        .pool_builder(config.tree_recovery_pool_size()?)
+        .build()
+        .await
+        .context("failed creating DB pool for Merkle tree recovery")?;
 
     let mut metadata_calculator =
         MetadataCalculator::new(metadata_calculator_config, None, tree_pool)
@@ -169,7 +175,7 @@ async fn run_tree(
     let tree_reader = Arc::new(metadata_calculator.tree_reader());
     app_health.insert_custom_component(Arc::new(metadata_calculator.tree_health_check()))?;
 
-    if config.optional.pruning_enabled {
+    if config.pruning_enabled() {
         tracing::warn!("Proceeding with node state pruning for the Merkle tree. This is an experimental feature; use at your own risk");
 
         let pruning_task =
@@ -251,10 +257,7 @@ async fn run_core(
     .await?;
 
     task_handles.push(tokio::spawn({
-        let config = config.consensus.clone();
-        let secrets =
-            config::read_consensus_secrets().context("config::read_consensus_secrets()")?;
-        let cfg = match (config, secrets) {
+        let cfg = match (config.consensus.clone(), config.consensus_secrets.clone()) {
             (Some(cfg), Some(secrets)) => Some((cfg, secrets)),
             (Some(_), None) => {
                 anyhow::bail!("Consensus config is specified, but secrets are missing")
@@ -265,6 +268,20 @@ async fn run_core(
                 None
             }
         };
+        let attester = match &cfg {
+            Some((cfg, secrets)) => consensus::Attester::new(connection_pool.clone(), secrets)
+                .context("consensus::Attester::new()")?
+                .map(|attester| attester.with_registry(cfg, main_node_client.clone())),
+            None => None,
+        };
+        if let Some(attester) = &attester {
+            app_health.insert_component(attester.health_check())?;
+        }
+        let (consensus_health_check, consensus_health_updater) =
+            ReactiveHealthCheck::new("consensus");
+        if cfg.is_some() {
+            app_health.insert_component(consensus_health_check)?;
+        }
 
         let pool = connection_pool.clone();
         let sync_state = sync_state.clone();
@@ -278,6 +295,9 @@ async fn run_core(
             // but we only need to wait for stop signal once, and it will be propagated to all child contexts.
             let ctx = ctx::root();
             scope::run!(&ctx, |ctx, s| async move {
+                if let Some(attester) = attester {
+                    s.spawn_bg(attester.run(ctx));
+                }
                 s.spawn_bg(consensus::era::run_en(
                     ctx,
                     cfg,
@@ -285,6 +305,7 @@ async fn run_core(
                     sync_state,
                     main_node_client,
                     action_queue_sender,
+                    consensus_health_updater,
                 ));
                 ctx.wait(stop_receiver.wait_for(|stop| *stop)).await??;
                 Ok(())
@@ -294,25 +315,6 @@ async fn run_core(
         }
     }));
 
-    if config.optional.pruning_enabled {
-        tracing::warn!("Proceeding with node state pruning for Postgres. This is an experimental feature; use at your own risk");
-
-        let minimum_l1_batch_age = config.optional.pruning_data_retention();
-        tracing::info!(
-            "Configured pruning of batches after they become {minimum_l1_batch_age:?} old"
-        );
-        let db_pruner = DbPruner::new(
-            DbPrunerConfig {
-                removal_delay: config.optional.pruning_removal_delay(),
-                pruned_batch_chunk_size: config.optional.pruning_chunk_size,
-                minimum_l1_batch_age,
-            },
-            connection_pool.clone(),
-        );
-        app_health.insert_component(db_pruner.health_check())?;
-        task_handles.push(tokio::spawn(db_pruner.run(stop_receiver.clone())));
-    }
-
     let sk_handle = task::spawn(state_keeper.run());
     let remote_diamond_proxy_addr = config.remote.diamond_proxy_addr;
     let diamond_proxy_addr = if let Some(addr) = config.optional.contracts_diamond_proxy_addr {
@@ -363,13 +365,23 @@ async fn run_core(
     );
     app_health.insert_component(batch_status_updater.health_check())?;
 
+    // Commitment generation is CPU-heavy and processes L1 batches concurrently, so we provide it
+    // with a dedicated pool sized according to the configured parallelism.
+    let commitment_generator_parallelism = config
+        .experimental
+        .commitment_generator_max_parallelism
+        .unwrap_or_else(CommitmentGenerator::default_parallelism);
+    let commitment_generator_pool = singleton_pool_builder
+        .clone()
+        .set_max_size(commitment_generator_parallelism.get())
+        .build()
+        .await
+        .context("failed to build a connection pool for CommitmentGenerator")?;
     let mut commitment_generator = CommitmentGenerator::new(
-        connection_pool.clone(),
+        commitment_generator_pool,
         config.optional.l1_batch_commit_data_generator_mode,
     );
-    if let Some(parallelism) = config.experimental.commitment_generator_max_parallelism {
-        commitment_generator.set_max_parallelism(parallelism);
-    }
+    commitment_generator.set_max_parallelism(commitment_generator_parallelism);
     app_health.insert_component(commitment_generator.health_check())?;
     let commitment_generator_handle = tokio::spawn(commitment_generator.run(stop_receiver.clone()));
 
@@ -421,7 +433,10 @@ async fn run_api(
         );
     }
 
-    let tx_proxy = TxProxy::new(main_node_client.clone());
+    let tx_proxy = TxProxy::new(main_node_client.clone()).with_resubmission_queue(
+        connection_pool.clone(),
+        config.optional.tx_proxy_queue_config(),
+    );
     let proxy_cache_updater_pool = singleton_pool_builder
         .build()
         .await
@@ -430,13 +445,26 @@ async fn run_api(
         proxy_cache_updater_pool.clone(),
         stop_receiver.clone(),
     )));
+    let tx_resubmission_task = tx_proxy
+        .resubmission_task()
+        .context("tx proxy resubmission queue is not enabled")?;
+    app_health.insert_component(tx_resubmission_task.health_check())?;
+    task_handles.push(tokio::spawn(
+        tx_resubmission_task.run(stop_receiver.clone()),
+    ));
 
     let fee_params_fetcher_handle =
         tokio::spawn(fee_params_fetcher.clone().run(stop_receiver.clone()));
     task_handles.push(fee_params_fetcher_handle);
 
+    // API servers use a separate pool so that their queries can be limited independently of the core components.
+    let api_pool = config
+        .api_pool_builder()
+        .build()
+        .await
+        .context("failed to build an API connection pool")?;
     let tx_sender_builder =
-        TxSenderBuilder::new(config.into(), connection_pool.clone(), Arc::new(tx_proxy));
+        TxSenderBuilder::new(config.into(), api_pool.clone(), Arc::new(tx_proxy));
 
     let max_concurrency = config.optional.vm_concurrency_limit;
     let (vm_concurrency_limiter, vm_barrier) = VmConcurrencyLimiter::new(max_concurrency);
@@ -454,32 +482,11 @@ async fn run_api(
     });
     task_handles.extend(cache_update_handle);
 
-    let whitelisted_tokens_for_aa_cache = Arc::new(RwLock::new(Vec::new()));
-    let whitelisted_tokens_for_aa_cache_clone = whitelisted_tokens_for_aa_cache.clone();
-    let mut stop_receiver_for_task = stop_receiver.clone();
-    task_handles.push(task::spawn(async move {
-        while !*stop_receiver_for_task.borrow_and_update() {
-            match main_node_client.whitelisted_tokens_for_aa().await {
-                Ok(tokens) => {
-                    *whitelisted_tokens_for_aa_cache_clone.write().await = tokens;
-                }
-                Err(jsonrpsee::core::client::Error::Call(error))
-                    if error.code() == jsonrpsee::types::error::METHOD_NOT_FOUND_CODE =>
-                {
-                    // Method is not supported by the main node, do nothing.
-                }
-                Err(err) => {
-                    tracing::error!("Failed to query `whitelisted_tokens_for_aa`, error: {err:?}");
-                }
-            }
-
-            // Error here corresponds to a timeout w/o `stop_receiver` changed; we're OK with this.
-            tokio::time::timeout(Duration::from_secs(60), stop_receiver_for_task.changed())
-                .await
-                .ok();
-        }
-        Ok(())
-    }));
+    let whitelisted_tokens_for_aa_updater = WhitelistedTokensForAaUpdater::new(main_node_client);
+    let whitelisted_tokens_for_aa_cache = whitelisted_tokens_for_aa_updater.cache();
+    task_handles.push(tokio::spawn(
+        whitelisted_tokens_for_aa_updater.run(stop_receiver.clone()),
+    ));
 
     let tx_sender = tx_sender_builder
         .with_whitelisted_tokens_for_aa(whitelisted_tokens_for_aa_cache)
@@ -504,7 +511,7 @@ async fn run_api(
     let pruning_info_refresh_interval = config.optional.pruning_removal_delay() / 5;
 
     if components.contains(&Component::HttpApi) {
-        let mut builder = ApiBuilder::jsonrpsee_backend(config.into(), connection_pool.clone())
+        let mut builder = ApiBuilder::jsonrpsee_backend(config.into(), api_pool.clone())
             .http(config.required.http_port)
             .with_filter_limit(config.optional.filters_limit)
             .with_batch_request_size_limit(config.optional.max_batch_request_size)
@@ -515,10 +522,20 @@ async fn run_api(
             .with_sync_state(sync_state.clone())
             .with_mempool_cache(mempool_cache.clone())
             .with_extended_tracing(config.optional.extended_rpc_tracing)
-            .enable_api_namespaces(config.optional.api_namespaces());
+            .enable_api_namespaces(config.optional.api_namespaces())
+            .with_method_filter(config.optional.api_method_filter())
+            .with_client_rate_limit(
+                config
+                    .optional
+                    .api_client_rate_limit()
+                    .context("invalid API rate limit config")?,
+            );
         if let Some(tree_reader) = &tree_reader {
             builder = builder.with_tree_api(tree_reader.clone());
         }
+        if let Some(debug_config) = config.optional.debug_namespace_config() {
+            builder = builder.with_debug_namespace(debug_config);
+        }
 
         let http_server_handles = builder
             .build()
@@ -531,10 +548,14 @@ async fn run_api(
     }
 
     if components.contains(&Component::WsApi) {
-        let mut builder = ApiBuilder::jsonrpsee_backend(config.into(), connection_pool.clone())
+        let mut builder = ApiBuilder::jsonrpsee_backend(config.into(), api_pool.clone())
             .ws(config.required.ws_port)
             .with_filter_limit(config.optional.filters_limit)
             .with_subscriptions_limit(config.optional.subscriptions_limit)
+            .with_subscriptions_per_connection_limit(
+                config.optional.subscriptions_per_connection_limit,
+            )
+            .with_subscription_backlog_size(config.optional.subscription_backlog_size)
             .with_batch_request_size_limit(config.optional.max_batch_request_size)
             .with_response_body_size_limit(config.optional.max_response_body_size())
             .with_polling_interval(config.optional.polling_interval())
@@ -544,10 +565,14 @@ async fn run_api(
             .with_sync_state(sync_state)
             .with_mempool_cache(mempool_cache)
             .with_extended_tracing(config.optional.extended_rpc_tracing)
-            .enable_api_namespaces(config.optional.api_namespaces());
+            .enable_api_namespaces(config.optional.api_namespaces())
+            .with_method_filter(config.optional.api_method_filter());
         if let Some(tree_reader) = tree_reader {
             builder = builder.with_tree_api(tree_reader);
         }
+        if let Some(debug_config) = config.optional.debug_namespace_config() {
+            builder = builder.with_debug_namespace(debug_config);
+        }
 
         let ws_server_handles = builder
             .build()
@@ -584,12 +609,6 @@ async fn init_tasks(
         .await
         .context("failed to build a tree_pool")?;
 
-    if !components.contains(&Component::Tree) {
-        anyhow::ensure!(
-            !components.contains(&Component::TreeApi),
-            "Merkle tree API cannot be started without a tree component"
-        );
-    }
     // Create a tree reader. If the list of requested components has the tree itself, then
     // we can get this tree's reader and use it right away. Otherwise, if configuration has
     // specified address of another instance hosting tree API, create a tree reader to that
@@ -631,6 +650,117 @@ async fn init_tasks(
         task_handles.push(tokio::spawn(fetcher.run(stop_receiver.clone())));
     }
 
+    if components.contains(&Component::Pruning) && config.pruning_enabled() {
+        tracing::warn!("Proceeding with node state pruning for Postgres. This is an experimental feature; use at your own risk");
+
+        // Pruning conditions make sure that only data finalized on L1 and already processed by the Merkle tree
+        // (which implies that snapshot recovery is complete) is pruned.
+        let minimum_l1_batch_age = config.optional.pruning_data_retention()?;
+        tracing::info!(
+            "Configured pruning of batches after they become {minimum_l1_batch_age:?} old"
+        );
+        let db_pruner = DbPruner::new(
+            DbPrunerConfig {
+                removal_delay: config.optional.pruning_removal_delay(),
+                pruned_batch_chunk_size: config.optional.pruning_chunk_size,
+                minimum_l1_batch_age,
+            },
+            connection_pool.clone(),
+        );
+        app_health.insert_component(db_pruner.health_check())?;
+        task_handles.push(tokio::spawn(db_pruner.run(stop_receiver.clone())));
+    }
+
+    if components.contains(&Component::DAVerifier) {
+        let da_client_config = config.da_client.clone().context(
+            "DA inclusion verifier requires DA client config (EN_DA_CLIENT_CONFIG_PATH)",
+        )?;
+        let da_client = zksync_da_clients::create_client(da_client_config)
+            .await
+            .context("failed creating DA client")?;
+        let verifier = DAInclusionVerifier::new(
+            eth_client.clone(),
+            da_client,
+            10,
+            singleton_pool_builder
+                .build()
+                .await
+                .context("failed to build connection pool for DAInclusionVerifier")?,
+        );
+        app_health.insert_component(verifier.health_check().clone())?;
+        task_handles.push(tokio::spawn(verifier.run(stop_receiver.clone())));
+    }
+
+    if components.contains(&Component::ContractVerification) {
+        run_contract_verification(
+            config,
+            connection_pool.clone(),
+            task_handles,
+            stop_receiver.clone(),
+        )
+        .await?;
+    }
+
+    if components.contains(&Component::SnapshotsCreator) {
+        let object_store_config = snapshots_creator_object_store_config()?;
+        let blob_store = ObjectStoreFactory::new(object_store_config)
+            .create_store()
+            .await?;
+        let master_pool = singleton_pool_builder
+            .build()
+            .await
+            .context("failed to build connection pool for snapshot creator")?;
+        let creator = SnapshotCreator::new(blob_store, master_pool, connection_pool.clone());
+        task_handles.push(tokio::spawn(creator.run_periodically(
+            config.optional.snapshots_creator_config(),
+            MIN_CHUNK_COUNT,
+            stop_receiver.clone(),
+        )));
+    }
+
+    if components.contains(&Component::VmRunnerBwip) {
+        let bwip_config = config
+            .optional
+            .basic_witness_input_producer_config()
+            .context("`vm_runner_bwip` component requires `EN_BWIP_STATE_CACHE_PATH` to be set")?;
+        let object_store_config = bwip_object_store_config()?;
+        let object_store = ObjectStoreFactory::new(object_store_config)
+            .create_store()
+            .await?;
+        // One connection for the storage sync task, one for the output handler factory task / VM runner,
+        // and `window_size` connections for output handlers.
+        let pool = config
+            .pool_builder(bwip_config.window_size + 2)
+            .build()
+            .await
+            .context("failed to build connection pool for basic witness input producer")?;
+        let (producer, tasks) = BasicWitnessInputProducer::new(
+            pool,
+            object_store,
+            bwip_config.db_path,
+            config.required.l2_chain_id,
+            bwip_config.first_processed_batch,
+            bwip_config.window_size,
+        )
+        .await?;
+        task_handles.push(tokio::spawn(tasks.loader_task.run(stop_receiver.clone())));
+        task_handles.push(tokio::spawn(
+            tasks.output_handler_factory_task.run(stop_receiver.clone()),
+        ));
+        let stop_receiver = stop_receiver.clone();
+        task_handles.push(tokio::spawn(
+            async move { producer.run(&stop_receiver).await },
+        ));
+    }
+
+    if components.contains(&Component::ProtocolVersionsTracker) {
+        let tracker = ProtocolVersionsTracker::new(
+            Box::new(main_node_client.for_component("protocol_versions_tracker")),
+            connection_pool.clone(),
+        );
+        task_handles.push(tokio::spawn(tracker.run(stop_receiver.clone())));
+    }
+
     let sync_state = if components.contains(&Component::Core) {
         run_core(
             config,
@@ -655,6 +785,18 @@ async fn init_tasks(
         sync_state
     };
 
+    let slo_config = config.observability.slo();
+    if !slo_config.is_empty() {
+        run_slo_monitor(
+            &slo_config,
+            sync_state.clone(),
+            connection_pool.clone(),
+            task_handles,
+            app_health,
+            stop_receiver.clone(),
+        )?;
+    }
+
     if components.contains(&Component::HttpApi) || components.contains(&Component::WsApi) {
         let fee_params_fetcher = Arc::new(MainNodeFeeParamsFetcher::new(main_node_client.clone()));
         run_api(
@@ -676,6 +818,78 @@ async fn init_tasks(
     Ok(())
 }
 
+/// Runs the contract verification API server together with the contract verifier processing submitted requests.
+async fn run_contract_verification(
+    config: &ExternalNodeConfig,
+    connection_pool: ConnectionPool<Core>,
+    task_handles: &mut Vec<JoinHandle<anyhow::Result<()>>>,
+    stop_receiver: watch::Receiver<bool>,
+) -> anyhow::Result<()> {
+    let verifier_config = config.optional.contract_verifier_config();
+    let mut verifier = ContractVerifier::new(verifier_config.clone(), connection_pool.clone());
+    if config
+        .optional
+        .contract_verification_artifacts_cache_enabled
+    {
+        verifier = verifier
+            .with_artifacts_cache_config(contract_verifier_object_store_config()?)
+            .await?;
+    }
+
+    task_handles.push(tokio::spawn(
+        zksync_contract_verification_server::start_server(
+            connection_pool.clone(),
+            connection_pool,
+            verifier_config,
+            stop_receiver.clone(),
+        ),
+    ));
+    task_handles.push(tokio::spawn(
+        verifier.update_compiler_versions_and_run(stop_receiver),
+    ));
+    Ok(())
+}
+
+fn run_slo_monitor(
+    config: &SloConfig,
+    sync_state: SyncState,
+    connection_pool: ConnectionPool<Core>,
+    task_handles: &mut Vec<JoinHandle<anyhow::Result<()>>>,
+    app_health: &AppHealthCheck,
+    stop_receiver: watch::Receiver<bool>,
+) -> anyhow::Result<()> {
+    let mut monitor = SloMonitor::new(config.check_interval());
+    if let Some(max_sync_lag) = config.max_sync_lag {
+        let health_check = monitor.add_check("slo_sync_lag", max_sync_lag.into(), move || {
+            let lag = sync_state
+                .get_main_node_block()
+                .0
+                .saturating_sub(sync_state.get_local_block().0);
+            Some(lag.into())
+        });
+        app_health.insert_component(health_check)?;
+    }
+    if let Some(max_saturation) = config.max_pool_saturation {
+        let health_check = monitor.add_check("slo_pool_saturation", max_saturation, move || {
+            Some(connection_pool.saturation())
+        });
+        app_health.insert_component(health_check)?;
+    }
+    if let Some(max_latency) = config.max_api_p99_latency() {
+        let health_check =
+            monitor.add_check("slo_api_p99_latency", max_latency.as_secs_f64(), || {
+                recent_call_latency_p99().map(|latency| latency.as_secs_f64())
+            });
+        app_health.insert_component(health_check)?;
+    }
+
+    task_handles.push(tokio::spawn(async move {
+        monitor.run(stop_receiver).await;
+        Ok(())
+    }));
+    Ok(())
+}
+
 async fn shutdown_components(
     tasks: ManagedTasks,
     healthcheck_handle: HealthCheckHandle,
@@ -695,15 +909,74 @@ async fn shutdown_components(
 struct Cli {
     /// Enables consensus-based syncing instead of JSON-RPC based one. This is an experimental and incomplete feature;
     /// do not use unless you know what you're doing.
-    #[arg(long)]
+    #[arg(long, global = true)]
     enable_consensus: bool,
 
     /// Comma-separated list of components to launch.
-    #[arg(long, default_value = "all")]
+    #[arg(long, global = true, default_value = "all")]
     components: ComponentsToRun,
+
+    /// Built-in configuration profile for a well-known network, prefilling chain IDs, the main node URL, contract
+    /// addresses and tuned defaults. Profile values have the lowest precedence and can be overridden by environment
+    /// variables, the config file or command-line overrides.
+    #[arg(long, global = true, value_enum)]
+    network: Option<NetworkProfile>,
+
+    /// Built-in node profile defining which part of the chain history the node retains (pruning, snapshot recovery)
+    /// and which historical RPC methods it serves. Takes precedence over the network profile, but can be overridden
+    /// by environment variables, the config file or command-line overrides.
+    #[arg(long, global = true, value_enum)]
+    node_profile: Option<NodeProfile>,
+
+    /// Path to a YAML file with node configuration. Parameters not specified in the file are read
+    /// from environment variables.
+    #[arg(long, global = true)]
+    config_path: Option<PathBuf>,
+
+    /// Overrides a configuration parameter, e.g. `--config-override optional.filters_limit=1000`. Overrides
+    /// take precedence over the config file and environment variables. Can be specified multiple times.
+    #[arg(
+        long = "config-override",
+        global = true,
+        value_name = "SECTION.KEY=VALUE"
+    )]
+    config_overrides: Vec<String>,
+
+    /// Run the node using the node framework. Only a subset of the node components is supported;
+    /// this is an experimental feature; do not use unless you know what you're doing.
+    #[arg(long)]
+    use_node_framework: bool,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Configuration-related commands.
+    #[command(subcommand)]
+    Config(ConfigCommand),
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Hash, Eq)]
+#[derive(Debug, Subcommand)]
+enum ConfigCommand {
+    /// Parses node configuration and runs cross-field checks on it without starting the node. With
+    /// `--use-node-framework --probe`, additionally wires the node for `--components` without starting
+    /// any tasks. Outputs check results and tasks that would be started as JSON to stdout; exits with
+    /// a non-zero code if any of the checks has failed.
+    Validate {
+        /// Additionally checks that Postgres, the Ethereum node and the main node are reachable and have
+        /// the expected chain IDs.
+        #[arg(long)]
+        probe: bool,
+    },
+    /// Outputs JSON Schema for the config file supplied via `--config-path`, with descriptions, default values
+    /// and units of measurement of all parameters. The schema can be used for IDE validation and config linting.
+    Schema,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Hash, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum Component {
     HttpApi,
     WsApi,
@@ -711,6 +984,12 @@ pub enum Component {
     TreeApi,
     TreeFetcher,
     Core,
+    DAVerifier,
+    Pruning,
+    ContractVerification,
+    SnapshotsCreator,
+    ProtocolVersionsTracker,
+    VmRunnerBwip,
 }
 
 impl Component {
@@ -722,12 +1001,20 @@ impl Component {
             "tree" => Ok(&[Component::Tree]),
             "tree_api" => Ok(&[Component::TreeApi]),
             "tree_fetcher" => Ok(&[Component::TreeFetcher]),
-            "core" => Ok(&[Component::Core]),
+            // Pruning used to be a part of the core component, so it's kept with it for backward compatibility.
+            "core" => Ok(&[Component::Core, Component::Pruning]),
+            "da_verifier" => Ok(&[Component::DAVerifier]),
+            "pruning" => Ok(&[Component::Pruning]),
+            "contract_verification" => Ok(&[Component::ContractVerification]),
+            "snapshots_creator" => Ok(&[Component::SnapshotsCreator]),
+            "protocol_versions_tracker" => Ok(&[Component::ProtocolVersionsTracker]),
+            "vm_runner_bwip" => Ok(&[Component::VmRunnerBwip]),
             "all" => Ok(&[
                 Component::HttpApi,
                 Component::WsApi,
                 Component::Tree,
                 Component::Core,
+                Component::Pruning,
             ]),
             other => Err(anyhow::anyhow!("{other} is not a valid component name")),
         }
@@ -756,34 +1043,89 @@ impl FromStr for ComponentsToRun {
 async fn main() -> anyhow::Result<()> {
     // Initial setup.
     let opt = Cli::parse();
+    match &opt.command {
+        Some(Command::Config(ConfigCommand::Validate { probe })) => {
+            return validate_config(&opt, *probe).await;
+        }
+        Some(Command::Config(ConfigCommand::Schema)) => {
+            let schema = serde_json::to_string_pretty(&config_file_schema())
+                .context("failed serializing config schema")?;
+            println!("{schema}");
+            return Ok(());
+        }
+        None => { /* run the node */ }
+    }
 
-    let mut config = ExternalNodeConfig::new().context("Failed to load node configuration")?;
+    let (mut config, config_report) = ExternalNodeConfig::load(
+        opt.network,
+        opt.node_profile,
+        opt.config_path.as_deref(),
+        &opt.config_overrides,
+    )
+    .context("Failed to load node configuration")?;
     if !opt.enable_consensus {
         config.consensus = None;
     }
     let _guard = config.observability.build_observability()?;
+    let secrets_resolver =
+        SecretsResolver::from_env().context("failed initializing secrets resolver")?;
+    let config = config
+        .resolve_secrets(&secrets_resolver)
+        .await
+        .context("failed resolving secrets in node configuration")?;
+    tracing::info!("Loaded node configuration:\n{config_report}");
+    let unused_config_keys = config_report.unused_keys();
+    if !unused_config_keys.is_empty() {
+        tracing::warn!(
+            "Node config file contains keys that do not correspond to any parameter and were ignored: {unused_config_keys:?}"
+        );
+    }
+    vlog::set_sentry_tag("l1_chain_id", config.required.l1_chain_id.0);
+    vlog::set_sentry_tag("l2_chain_id", config.required.l2_chain_id.as_u64());
 
     // Build L1 and L2 clients.
     let main_node_url = &config.required.main_node_url;
     tracing::info!("Main node URL is: {main_node_url:?}");
-    let main_node_client = Client::http(main_node_url.clone())
-        .context("failed creating JSON-RPC client for main node")?
-        .for_network(config.required.l2_chain_id.into())
-        .with_allowed_requests_per_second(config.optional.main_node_rate_limit_rps)
-        .build();
-    let main_node_client = Box::new(main_node_client) as Box<DynClient<L2>>;
-
-    let eth_client_url = &config.required.eth_client_url;
-    let eth_client = Client::http(eth_client_url.clone())
-        .context("failed creating JSON-RPC client for Ethereum")?
-        .for_network(config.required.l1_chain_id.into())
-        .build();
-    let eth_client = Box::new(eth_client);
+    let main_node_rate_limit_rps = config.optional.main_node_rate_limit_rps;
+    let main_node_endpoints = iter::once(main_node_url)
+        .chain(&config.optional.main_node_fallback_urls)
+        .map(|url| (url.clone(), Some(main_node_rate_limit_rps)));
+    let main_node_failover_client =
+        FailoverClient::http(config.required.l2_chain_id.into(), main_node_endpoints)
+            .context("failed creating JSON-RPC client for main node")?;
+    let main_node_client = Box::new(main_node_failover_client.clone()) as Box<DynClient<L2>>;
+
+    let eth_client_urls = iter::once(&config.required.eth_client_url)
+        .chain(&config.optional.eth_client_fallback_urls)
+        .map(|url| (url.clone(), None));
+    // A failover client with a single endpoint behaves identically to the endpoint client.
+    let eth_failover_client =
+        FailoverClient::<L1>::http(config.required.l1_chain_id.into(), eth_client_urls)
+            .context("failed creating JSON-RPC client for Ethereum")?;
+    let eth_client = eth_failover_client
+        .clone()
+        .into_boxed(config.optional.eth_client_response_cache_ttl());
 
     let config = config
         .fetch_remote(main_node_client.as_ref())
         .await
         .context("failed fetching remote part of node config from main node")?;
+    RUST_METRICS.initialize();
+    EN_METRICS.observe_config(&config);
+
+    if opt.use_node_framework {
+        let components = opt.components.0.into_iter().collect();
+        // We run the node from a different thread, since the current thread is in tokio context.
+        std::thread::spawn(move || -> anyhow::Result<()> {
+            let node = ExternalNodeBuilder::new(config).build(components)?;
+            node.run()?;
+            Ok(())
+        })
+        .join()
+        .expect("Failed to run the node")?;
+        return Ok(());
+    }
+
     if let Some(threshold) = config.optional.slow_query_threshold() {
         ConnectionPool::<Core>::global_config().set_slow_query_threshold(threshold)?;
     }
@@ -791,28 +1133,126 @@ async fn main() -> anyhow::Result<()> {
         ConnectionPool::<Core>::global_config().set_long_connection_threshold(threshold)?;
     }
 
-    RUST_METRICS.initialize();
-    EN_METRICS.observe_config(&config);
+    let singleton_pool_builder = config.pool_builder(1);
+    let connection_pool = config
+        .pool_builder(config.postgres.max_connections)
+        .build()
+        .await
+        .context("failed to build a connection_pool")?;
+
+    let mut env = ProcessEnvironment::default();
+    loop {
+        // Probers are recreated for each run, so that they are stopped together with other node tasks.
+        let client_probers = ClientProbers {
+            main_node: main_node_failover_client.prober("main_node_client"),
+            eth: eth_failover_client.prober("l1_client"),
+        };
+        let exit = run_node(
+            &mut env,
+            &opt,
+            &config,
+            connection_pool.clone(),
+            singleton_pool_builder.clone(),
+            main_node_client.clone(),
+            eth_client.clone(),
+            client_probers,
+        )
+        .await?;
+        match exit {
+            NodeExit::Stopped => return Ok(()),
+            NodeExit::ReorgDetected(last_correct_l1_batch) => {
+                tracing::warn!(
+                    "Restarting the node to roll back to the last correct L1 batch #{last_correct_l1_batch}"
+                );
+            }
+            NodeExit::RollbackRequested(last_l1_batch_to_keep) => {
+                tracing::warn!("Rolling back node state to L1 batch #{last_l1_batch_to_keep}");
+                create_block_reverter(
+                    &config,
+                    connection_pool.clone(),
+                    "external_node:admin_server",
+                )
+                .roll_back(last_l1_batch_to_keep)
+                .await?;
+                tracing::info!("Revert successfully completed; restarting the node");
+            }
+        }
+    }
+}
 
-    let singleton_pool_builder = ConnectionPool::singleton(config.postgres.database_url());
-    let connection_pool = ConnectionPool::<Core>::builder(
-        config.postgres.database_url(),
-        config.postgres.max_connections,
-    )
-    .build()
-    .await
-    .context("failed to build a connection_pool")?;
+/// Runs the `config validate` command. Unlike the node itself, doesn't initialize observability
+/// so that the command output is not mixed with logs.
+async fn validate_config(opt: &Cli, probe: bool) -> anyhow::Result<()> {
+    let config = match ExternalNodeConfig::load(
+        opt.network,
+        opt.node_profile,
+        opt.config_path.as_deref(),
+        &opt.config_overrides,
+    ) {
+        Ok((config, _)) => match SecretsResolver::from_env() {
+            Ok(secrets_resolver) => config
+                .resolve_secrets(&secrets_resolver)
+                .await
+                .context("failed resolving secrets"),
+            Err(err) => Err(err.context("failed initializing secrets resolver")),
+        },
+        Err(err) => Err(err),
+    };
+    let report = match config {
+        Ok(mut config) => {
+            if !opt.enable_consensus {
+                config.consensus = None;
+            }
+            let mut report = config.validate(&opt.components.0);
+            config.probe_dependencies(&mut report, probe).await;
+            if !opt.use_node_framework {
+                report.skip_wiring(
+                    "wiring is only checked for the node framework; use `--use-node-framework`",
+                );
+            } else if !probe {
+                report.skip_wiring(
+                    "wiring requires the remote config fetched from the main node; use `--probe`",
+                );
+            } else {
+                let components = opt.components.0.iter().copied().collect();
+                report.push_wiring(validate_wiring(config, components).await);
+            }
+            report
+        }
+        Err(err) => ValidationReport::parse_error(&err),
+    };
 
-    run_node(
-        (),
-        &opt,
-        &config,
-        connection_pool,
-        singleton_pool_builder,
-        main_node_client,
-        eth_client,
-    )
-    .await
+    let output =
+        serde_json::to_string_pretty(&report).context("failed serializing validation report")?;
+    println!("{output}");
+    if !report.is_valid() {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+/// Wires the node framework for the specified components without starting any tasks. Returns IDs of the tasks
+/// that would be started.
+async fn validate_wiring(
+    config: ExternalNodeConfig<()>,
+    components: Vec<Component>,
+) -> anyhow::Result<Vec<String>> {
+    let main_node_client = Client::http(config.required.main_node_url.clone())
+        .context("failed creating JSON-RPC client for main node")?
+        .for_network(config.required.l2_chain_id.into())
+        .build();
+    let main_node_client = Box::new(main_node_client) as Box<DynClient<L2>>;
+    let config = config
+        .fetch_remote(main_node_client.as_ref())
+        .await
+        .context("failed fetching remote part of node config from main node")?;
+
+    // We wire the node from a different thread, since the current thread is in tokio context.
+    let task_ids =
+        std::thread::spawn(move || ExternalNodeBuilder::new(config).validate(components))
+            .join()
+            .expect("Failed to wire the node")?;
+    Ok(task_ids.iter().map(ToString::to_string).collect())
 }
 
 /// Environment for the node encapsulating its interactions. Used in EN tests to mock signal sending etc.
@@ -824,9 +1264,58 @@ trait NodeEnvironment {
     fn set_app_health(&mut self, health: Arc<AppHealthCheck>);
 }
 
-impl NodeEnvironment for () {
+impl<E: NodeEnvironment> NodeEnvironment for &mut E {
     fn setup_sigint_handler(&mut self) -> oneshot::Receiver<()> {
-        setup_sigint_handler()
+        (**self).setup_sigint_handler()
+    }
+
+    fn set_app_health(&mut self, health: Arc<AppHealthCheck>) {
+        (**self).set_app_health(health);
+    }
+}
+
+/// Tasks probing endpoints of failover clients used by the node.
+#[derive(Debug, Default)]
+struct ClientProbers {
+    main_node: Option<FailoverClientProber<L2>>,
+    eth: Option<FailoverClientProber<L1>>,
+}
+
+/// Environment of the node process. Supports running the node several times (e.g., to restart the node
+/// after an automatic rollback); the SIGINT handler is installed once and is shared among runs.
+#[derive(Debug, Default)]
+struct ProcessEnvironment {
+    sigint_receiver: Option<watch::Receiver<bool>>,
+}
+
+impl NodeEnvironment for ProcessEnvironment {
+    fn setup_sigint_handler(&mut self) -> oneshot::Receiver<()> {
+        let mut sigint_receiver = self
+            .sigint_receiver
+            .get_or_insert_with(|| {
+                let (sender, receiver) = watch::channel(false);
+                let sigint_receiver = setup_sigint_handler();
+                tokio::spawn(async move {
+                    if sigint_receiver.await.is_ok() {
+                        sender.send_replace(true);
+                    }
+                    // Keep the sender alive so that receivers don't treat its drop as a signal.
+                    sender.closed().await;
+                });
+                receiver
+            })
+            .clone();
+
+        let (sender, receiver) = oneshot::channel();
+        tokio::spawn(async move {
+            while !*sigint_receiver.borrow_and_update() {
+                if sigint_receiver.changed().await.is_err() {
+                    return;
+                }
+            }
+            sender.send(()).ok();
+        });
+        receiver
     }
 
     fn set_app_health(&mut self, _health: Arc<AppHealthCheck>) {
@@ -834,6 +1323,20 @@ impl NodeEnvironment for () {
     }
 }
 
+/// Reason for a node run to finish.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NodeExit {
+    /// The node was stopped by a signal or because one of its components has stopped.
+    Stopped,
+    /// The reorg detector has found a divergence with the main node, and automatic rollback is enabled.
+    /// The node should be restarted to roll back to the specified L1 batch.
+    ReorgDetected(L1BatchNumber),
+    /// Rolling back the node state to the specified L1 batch was requested via the admin server.
+    /// The node state should be rolled back before restarting the node.
+    RollbackRequested(L1BatchNumber),
+}
+
+#[allow(clippy::too_many_arguments)]
 async fn run_node(
     mut env: impl NodeEnvironment,
     opt: &Cli,
@@ -842,7 +1345,8 @@ async fn run_node(
     singleton_pool_builder: ConnectionPoolBuilder<Core>,
     main_node_client: Box<DynClient<L2>>,
     eth_client: Box<DynClient<L1>>,
-) -> anyhow::Result<()> {
+    client_probers: ClientProbers,
+) -> anyhow::Result<NodeExit> {
     tracing::warn!("The external node is in the alpha phase, and should be used with caution.");
     tracing::info!("Started the external node");
     let (stop_sender, mut stop_receiver) = watch::channel(false);
@@ -907,6 +1411,32 @@ async fn run_node(
 
     let mut task_handles = vec![metrics_task, validate_chain_ids_task];
     task_handles.extend(prometheus_task);
+    if let Some(prober) = client_probers.main_node {
+        app_health.insert_component(prober.health_check())?;
+        task_handles.push(tokio::spawn(prober.run(stop_receiver.clone())));
+    }
+    if let Some(prober) = client_probers.eth {
+        app_health.insert_component(prober.health_check())?;
+        task_handles.push(tokio::spawn(prober.run(stop_receiver.clone())));
+    }
+    let (revert_request_sender, mut revert_request_receiver) = watch::channel(None);
+    if let Some(port) = config.observability.admin_port {
+        let mut admin_server = AdminServer::new(([127, 0, 0, 1], port).into())
+            .with_profiling(config.observability.admin_profiling_enabled)
+            .with_audit_log(connection_pool.clone());
+        if let Some(token) = config.admin_revert_token.clone() {
+            let handler = AdminRevertHandler {
+                reverter: create_block_reverter(
+                    config,
+                    connection_pool.clone(),
+                    "external_node:admin_server",
+                ),
+                request_sender: revert_request_sender,
+            };
+            admin_server = admin_server.with_revert_handler(Arc::new(handler), token);
+        }
+        task_handles.push(tokio::spawn(admin_server.run(stop_receiver.clone())));
+    }
 
     // Make sure that the node storage is initialized either via genesis or snapshot recovery.
     let recovery_config =
@@ -919,9 +1449,10 @@ async fn run_node(
     ensure_storage_initialized(
         connection_pool.clone(),
         main_node_client.clone(),
-        &app_health,
+        app_health.clone(),
         config.required.l2_chain_id,
         recovery_config,
+        stop_receiver.clone(),
     )
     .await?;
     let sigint_receiver = env.setup_sigint_handler();
@@ -937,13 +1468,11 @@ async fn run_node(
     });
 
     // Revert the storage if needed.
-    let mut reverter = BlockReverter::new(NodeRole::External, connection_pool.clone());
-    // Reverting executed batches is more-or-less safe for external nodes.
-    let reverter = reverter
-        .allow_rolling_back_executed_batches()
-        .enable_rolling_back_postgres()
-        .enable_rolling_back_merkle_tree(config.required.merkle_tree_path.clone())
-        .enable_rolling_back_state_keeper_cache(config.required.state_cache_path.clone());
+    let reverter = create_block_reverter(
+        config,
+        connection_pool.clone(),
+        "external_node:reorg_detector",
+    );
 
     let mut reorg_detector = ReorgDetector::new(main_node_client.clone(), connection_pool.clone());
     // We're checking for the reorg in the beginning because we expect that if reorg is detected during
@@ -954,27 +1483,37 @@ async fn run_node(
         Ok(()) if *stop_receiver.borrow() => {
             tracing::info!("Stop signal received during initial reorg detection; shutting down");
             healthcheck_handle.stop().await;
-            return Ok(());
+            return Ok(NodeExit::Stopped);
         }
         Ok(()) => {
             tracing::info!("Successfully checked no reorg compared to the main node");
         }
         Err(zksync_reorg_detector::Error::ReorgDetected(last_correct_l1_batch)) => {
-            tracing::info!("Reverting to l1 batch number {last_correct_l1_batch}");
-            reverter.roll_back(last_correct_l1_batch).await?;
-            tracing::info!("Revert successfully completed");
+            roll_back_after_reorg(
+                &reverter,
+                &connection_pool,
+                last_correct_l1_batch,
+                config.optional.reorg_auto_rollback_max_depth,
+            )
+            .await?;
         }
         Err(err) => return Err(err).context("reorg_detector.check_consistency()"),
     }
 
     app_health.insert_component(reorg_detector.health_check().clone())?;
+    let (reorg_sender, mut reorg_receiver) = oneshot::channel();
+    let auto_rollback_enabled = config.optional.reorg_auto_rollback_max_depth.is_some();
     task_handles.push(tokio::spawn({
         let stop = stop_receiver.clone();
         async move {
-            reorg_detector
-                .run(stop)
-                .await
-                .context("reorg_detector.run()")
+            let result = reorg_detector.run(stop).await;
+            if let Err(zksync_reorg_detector::Error::ReorgDetected(last_correct_l1_batch)) = &result
+            {
+                if auto_rollback_enabled {
+                    reorg_sender.send(*last_correct_l1_batch).ok();
+                }
+            }
+            result.context("reorg_detector.run()")
         }
     }));
 
@@ -1000,6 +1539,10 @@ async fn run_node(
         biased;
 
         _ = stop_receiver.changed() => {},
+        // If the admin server doesn't handle reverts, the sender is dropped, and this branch is disabled.
+        Ok(()) = revert_request_receiver.changed() => {
+            tracing::info!("Stopping the node to roll back its state as requested via the admin server");
+        },
         () = tasks.wait_single() => {},
     }
 
@@ -1008,5 +1551,75 @@ async fn run_node(
     stop_sender.send_replace(true);
     shutdown_components(tasks, healthcheck_handle).await?;
     tracing::info!("Stopped");
+    let revert_request = *revert_request_receiver.borrow();
+    Ok(match (revert_request, reorg_receiver.try_recv()) {
+        (Some(last_l1_batch_to_keep), _) => NodeExit::RollbackRequested(last_l1_batch_to_keep),
+        (None, Ok(last_correct_l1_batch)) => NodeExit::ReorgDetected(last_correct_l1_batch),
+        (None, Err(_)) => NodeExit::Stopped,
+    })
+}
+
+/// Creates a block reverter rolling back all node state (Postgres, the Merkle tree and the state keeper cache).
+fn create_block_reverter(
+    config: &ExternalNodeConfig,
+    pool: ConnectionPool<Core>,
+    audit_actor: &str,
+) -> BlockReverter {
+    let mut reverter = BlockReverter::new(NodeRole::External, pool);
+    // Reverting executed batches is more-or-less safe for external nodes.
+    reverter
+        .allow_rolling_back_executed_batches()
+        .enable_rolling_back_postgres()
+        .enable_rolling_back_merkle_tree(config.required.merkle_tree_path.clone())
+        .enable_rolling_back_state_keeper_cache(config.required.state_cache_path.clone())
+        .set_audit_actor(audit_actor);
+    reverter
+}
+
+/// Handles reverts requested via the admin server. Since the node state cannot be rolled back while the node
+/// is running, a revert request stops the node; the state is rolled back before restarting the node.
+#[derive(Debug)]
+struct AdminRevertHandler {
+    reverter: BlockReverter,
+    request_sender: watch::Sender<Option<L1BatchNumber>>,
+}
+
+#[async_trait::async_trait]
+impl RevertHandler for AdminRevertHandler {
+    async fn plan(
+        &self,
+        last_l1_batch_to_keep: L1BatchNumber,
+    ) -> anyhow::Result<serde_json::Value> {
+        let plan = self.reverter.plan_rollback(last_l1_batch_to_keep).await?;
+        Ok(serde_json::to_value(plan)?)
+    }
+
+    async fn revert(
+        &self,
+        last_l1_batch_to_keep: L1BatchNumber,
+    ) -> anyhow::Result<serde_json::Value> {
+        // Check that the rollback is possible before stopping the node.
+        let plan = self.plan(last_l1_batch_to_keep).await?;
+        self.request_sender
+            .send_replace(Some(last_l1_batch_to_keep));
+        Ok(plan)
+    }
+}
+
+/// Rolls back the node state after a reorg. If `max_depth` is specified, checks that at most this many L1 batches
+/// are rolled back.
+async fn roll_back_after_reorg(
+    reverter: &BlockReverter,
+    pool: &ConnectionPool<Core>,
+    last_correct_l1_batch: L1BatchNumber,
+    max_depth: Option<NonZeroU32>,
+) -> anyhow::Result<()> {
+    if let Some(max_depth) = max_depth {
+        zksync_reorg_detector::check_rollback_depth(pool, last_correct_l1_batch, max_depth).await?;
+    }
+
+    tracing::info!("Reverting to l1 batch number {last_correct_l1_batch}");
+    reverter.roll_back(last_correct_l1_batch).await?;
+    tracing::info!("Revert successfully completed");
     Ok(())
 }
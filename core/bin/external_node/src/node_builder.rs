@@ -0,0 +1,585 @@
+//! This module provides a "builder" for the external node,
+//! as well as an interface to run the node with the specified components.
+
+use anyhow::Context as _;
+use zksync_block_reverter::NodeRole;
+use zksync_config::{
+    configs::{
+        api::{HealthCheckConfig, MerkleTreeApiConfig},
+        database::MerkleTreeConfig,
+        DatabaseSecrets, PostgresConfig,
+    },
+    DBConfig,
+};
+use zksync_node_api_server::{tx_sender::ApiContracts, web3::Namespace};
+use zksync_node_framework::{
+    implementations::layers::{
+        batch_status_updater::BatchStatusUpdaterLayer,
+        block_reverter::BlockReverterLayer,
+        commitment_generator::CommitmentGeneratorLayer,
+        consensus::{ConsensusLayer, Mode},
+        consistency_checker::ConsistencyCheckerLayer,
+        contract_verification_api::ContractVerificationApiLayer,
+        contract_verifier::ContractVerifierLayer,
+        healtcheck_server::HealthCheckLayer,
+        l1_batch_commitment_mode_validation::L1BatchCommitmentModeValidationLayer,
+        main_node_client::MainNodeClientLayer,
+        main_node_fee_params_fetcher::MainNodeFeeParamsFetcherLayer,
+        metadata_calculator::MetadataCalculatorLayer,
+        object_store::ObjectStoreLayer,
+        pools_layer::PoolsLayerBuilder,
+        prometheus_exporter::PrometheusExporterLayer,
+        pruning::PruningLayer,
+        query_eth_client::QueryEthClientLayer,
+        reorg_detector::ReorgDetectorLayer,
+        sigint::SigintHandlerLayer,
+        snapshot_recovery::{SnapshotRecoveryConfig, SnapshotRecoveryLayer},
+        state_keeper::{
+            external_io::ExternalIOLayer, main_batch_executor::MainBatchExecutorLayer,
+            StateKeeperLayer,
+        },
+        sync_state::SyncStateLayer,
+        tree_data_fetcher::TreeDataFetcherLayer,
+        web3_api::{
+            caches::MempoolCacheLayer,
+            debug::DebugNamespaceLayer,
+            server::{Web3ServerLayer, Web3ServerOptionalConfig},
+            tree_api_client::TreeApiClientLayer,
+            tx_proxy::TxProxyLayer,
+            tx_sender::{PostgresStorageCachesConfig, TxSenderLayer},
+        },
+    },
+    service::{ZkStackService, ZkStackServiceBuilder},
+    task::TaskId,
+};
+use zksync_types::Address;
+
+use crate::{
+    config::{
+        contract_verifier_object_store_config, snapshot_recovery_object_store_config,
+        ExternalNodeConfig,
+    },
+    Component,
+};
+
+/// Builder of the external node based on the node framework. Only a subset of the node components
+/// is supported at the moment; building a node with an unsupported component fails.
+pub(crate) struct ExternalNodeBuilder {
+    node: ZkStackServiceBuilder,
+    config: ExternalNodeConfig,
+    /// Components using RocksDB instances together with the instance paths.
+    rocksdb_users: Vec<(&'static str, String)>,
+}
+
+impl ExternalNodeBuilder {
+    pub fn new(config: ExternalNodeConfig) -> Self {
+        Self {
+            node: ZkStackServiceBuilder::new(),
+            config,
+            rocksdb_users: vec![],
+        }
+    }
+
+    fn add_sigint_handler_layer(mut self) -> anyhow::Result<Self> {
+        self.node.add_layer(SigintHandlerLayer);
+        Ok(self)
+    }
+
+    fn add_pools_layer(mut self) -> anyhow::Result<Self> {
+        // The Postgres config only defines global connection thresholds; pools are configured in the same way
+        // as for the node run without the node framework. The replica pool is used by the API servers,
+        // so it gets API-specific settings and read replicas.
+        let optional = &self.config.optional;
+        let config = PostgresConfig {
+            max_connections: Some(self.config.postgres.max_connections),
+            max_connections_master: Some(self.config.postgres.max_connections),
+            acquire_timeout_sec: optional
+                .database_acquire_timeout()
+                .map(|timeout| timeout.as_secs()),
+            statement_timeout_sec: optional
+                .database_statement_timeout()
+                .map(|timeout| timeout.as_secs()),
+            long_connection_threshold_ms: optional
+                .long_connection_threshold()
+                .map(|threshold| threshold.as_millis() as u64),
+            slow_query_threshold_ms: optional
+                .slow_query_threshold()
+                .map(|threshold| threshold.as_millis() as u64),
+            api_replica_max_lag_ms: None,
+            migrations_mode: None,
+            test_server_url: None,
+            test_prover_url: None,
+        };
+        let secrets = DatabaseSecrets {
+            server_url: Some(self.config.postgres.database_url()),
+            server_replica_url: Some(self.config.postgres.database_url()),
+            // API read replicas are set by the replica pool builder.
+            server_api_replica_urls: vec![],
+            prover_url: None,
+        };
+        let pools_layer = PoolsLayerBuilder::empty(config, secrets)
+            .with_master(true)
+            .with_replica(true)
+            .with_custom_master_pool(
+                self.config
+                    .pool_builder(self.config.postgres.max_connections),
+            )
+            .with_custom_replica_pool(self.config.api_pool_builder())
+            .build();
+        self.node.add_layer(pools_layer);
+        Ok(self)
+    }
+
+    fn add_prometheus_exporter_layer(mut self) -> anyhow::Result<Self> {
+        if let Some(prom_config) = self.config.observability.prometheus() {
+            self.node.add_layer(PrometheusExporterLayer(prom_config));
+        } else {
+            tracing::info!("No configuration for prometheus exporter, skipping");
+        }
+        Ok(self)
+    }
+
+    fn add_healthcheck_layer(mut self) -> anyhow::Result<Self> {
+        let optional = &self.config.optional;
+        let healthcheck_config = HealthCheckConfig {
+            port: self.config.required.healthcheck_port,
+            slow_time_limit_ms: optional
+                .healthcheck_slow_time_limit()
+                .map(|limit| limit.as_millis() as u64),
+            hard_time_limit_ms: optional
+                .healthcheck_hard_time_limit()
+                .map(|limit| limit.as_millis() as u64),
+        };
+        self.node.add_layer(HealthCheckLayer(healthcheck_config));
+        Ok(self)
+    }
+
+    fn add_main_node_client_layer(mut self) -> anyhow::Result<Self> {
+        let optional = &self.config.optional;
+        let mut layer = MainNodeClientLayer::new(
+            self.config.required.main_node_url.clone(),
+            optional.main_node_rate_limit_rps,
+            self.config.required.l2_chain_id,
+        );
+        for url in &optional.main_node_fallback_urls {
+            layer = layer.with_fallback_endpoint(url.clone(), optional.main_node_rate_limit_rps);
+        }
+        self.node.add_layer(layer);
+        Ok(self)
+    }
+
+    /// Adds storage initialization (genesis or snapshot recovery, depending on `EN_SNAPSHOTS_RECOVERY_ENABLED`).
+    /// Initialization is a precondition, so that all other tasks start only once the storage is initialized.
+    fn add_storage_initialization_layer(mut self) -> anyhow::Result<Self> {
+        let mut layer = SnapshotRecoveryLayer::new(self.config.required.l2_chain_id);
+        if self.config.optional.snapshots_recovery_enabled {
+            let object_store_config = snapshot_recovery_object_store_config()?;
+            self.node
+                .add_layer(ObjectStoreLayer::new(object_store_config));
+            layer = layer.with_snapshot_recovery(SnapshotRecoveryConfig {
+                snapshot_l1_batch_override: self.config.experimental.snapshots_recovery_l1_batch,
+            });
+        }
+        self.node.add_layer(layer);
+        Ok(self)
+    }
+
+    fn add_block_reverter_layer(mut self) -> anyhow::Result<Self> {
+        // Reverting executed batches is more-or-less safe for external nodes.
+        let layer = BlockReverterLayer::new(NodeRole::External)
+            .allow_rolling_back_executed_batches()
+            .enable_rolling_back_merkle_tree(self.config.required.merkle_tree_path.clone())
+            .enable_rolling_back_state_keeper_cache(self.config.required.state_cache_path.clone());
+        self.node.add_layer(layer);
+        Ok(self)
+    }
+
+    /// Adds reorg detection. Uses the block reverter (so it must be added after [`Self::add_block_reverter_layer()`])
+    /// to roll back the node state on startup if a reorg is detected.
+    fn add_reorg_detector_layer(mut self) -> anyhow::Result<Self> {
+        let mut layer = ReorgDetectorLayer::new();
+        if let Some(max_depth) = self.config.optional.reorg_auto_rollback_max_depth {
+            layer = layer.with_auto_rollback_max_depth(max_depth);
+        }
+        self.node.add_layer(layer);
+        Ok(self)
+    }
+
+    fn add_query_eth_client_layer(mut self) -> anyhow::Result<Self> {
+        let optional = &self.config.optional;
+        let mut layer = QueryEthClientLayer::new(
+            self.config.required.l1_chain_id,
+            self.config.required.eth_client_url.clone(),
+        )
+        .with_fallback_urls(optional.eth_client_fallback_urls.iter().cloned());
+        if let Some(ttl) = optional.eth_client_response_cache_ttl() {
+            layer = layer.with_response_cache(ttl);
+        }
+        self.node.add_layer(layer);
+        Ok(self)
+    }
+
+    fn add_state_keeper_layer(mut self) -> anyhow::Result<Self> {
+        let state_cache_path = self.config.required.state_cache_path.clone();
+        self.use_rocksdb("state keeper", &state_cache_path)?;
+        let optional = &self.config.optional;
+        let l2_shared_bridge_addr = self
+            .config
+            .remote
+            .l2_shared_bridge_addr
+            .context("L2 shared bridge address is not set")?;
+        let mut io_layer = ExternalIOLayer::new(
+            self.config.required.l2_chain_id,
+            l2_shared_bridge_addr,
+            optional.l2_block_seal_queue_capacity,
+        );
+        if !optional.protective_reads_persistence_enabled {
+            // **Important:** Disabling protective reads persistence is only sound if the node will never
+            // run a full Merkle tree.
+            io_layer = io_layer.without_protective_reads();
+        }
+
+        // We only need call traces on the external node if the `debug_` namespace is enabled.
+        let save_call_traces = optional.api_namespaces().contains(&Namespace::Debug);
+        let executor_layer = MainBatchExecutorLayer::with_call_traces(save_call_traces)
+            .with_optional_bytecode_compression();
+
+        let db_config = DBConfig {
+            state_keeper_db_path: state_cache_path,
+            // Not used by the state keeper.
+            merkle_tree: MerkleTreeConfig::default(),
+            experimental: self.config.experimental.state_keeper_db_config(),
+        };
+
+        self.node
+            .add_layer(SyncStateLayer::default())
+            .add_layer(io_layer)
+            .add_layer(executor_layer)
+            .add_layer(StateKeeperLayer::new(db_config));
+        Ok(self)
+    }
+
+    fn add_consensus_layer(mut self) -> anyhow::Result<Self> {
+        let layer = ConsensusLayer {
+            mode: Mode::External,
+            config: self.config.consensus.clone(),
+            secrets: self.config.consensus_secrets.clone(),
+            registry_rpc_url: None,
+        };
+        self.node.add_layer(layer);
+        Ok(self)
+    }
+
+    fn diamond_proxy_addr(&self) -> anyhow::Result<Address> {
+        let remote_diamond_proxy_addr = self.config.remote.diamond_proxy_addr;
+        if let Some(addr) = self.config.optional.contracts_diamond_proxy_addr {
+            anyhow::ensure!(
+                addr == remote_diamond_proxy_addr,
+                "Diamond proxy address {addr:?} specified in config doesn't match one returned \
+                 by main node ({remote_diamond_proxy_addr:?})"
+            );
+        }
+        Ok(remote_diamond_proxy_addr)
+    }
+
+    fn add_l1_batch_commitment_mode_validation_layer(mut self) -> anyhow::Result<Self> {
+        let layer = L1BatchCommitmentModeValidationLayer::new(
+            self.diamond_proxy_addr()?,
+            self.config.optional.l1_batch_commit_data_generator_mode,
+        );
+        self.node.add_layer(layer);
+        Ok(self)
+    }
+
+    fn add_consistency_checker_layer(mut self) -> anyhow::Result<Self> {
+        let max_batches_to_recheck = 10; // TODO (BFT-97): Make it a part of a proper EN config
+        let layer = ConsistencyCheckerLayer::new(
+            self.diamond_proxy_addr()?,
+            max_batches_to_recheck,
+            self.config.optional.l1_batch_commit_data_generator_mode,
+        );
+        self.node.add_layer(layer);
+        Ok(self)
+    }
+
+    fn add_commitment_generator_layer(mut self) -> anyhow::Result<Self> {
+        let mut layer =
+            CommitmentGeneratorLayer::new(self.config.optional.l1_batch_commit_data_generator_mode);
+        if let Some(max_parallelism) = self
+            .config
+            .experimental
+            .commitment_generator_max_parallelism
+        {
+            layer = layer.with_max_parallelism(max_parallelism);
+        }
+        self.node.add_layer(layer);
+        Ok(self)
+    }
+
+    fn add_batch_status_updater_layer(mut self) -> anyhow::Result<Self> {
+        self.node.add_layer(BatchStatusUpdaterLayer);
+        Ok(self)
+    }
+
+    fn add_metadata_calculator_layer(mut self, with_tree_api: bool) -> anyhow::Result<Self> {
+        let merkle_tree_path = self.config.required.merkle_tree_path.clone();
+        self.use_rocksdb("Merkle tree", &merkle_tree_path)?;
+
+        let mut layer = MetadataCalculatorLayer::new(self.config.metadata_calculator_config())
+            .with_recovery_pool_size(self.config.tree_recovery_pool_size()?);
+        if with_tree_api {
+            let port = self
+                .config
+                .tree_component
+                .api_port
+                .context("Merkle tree API is enabled, but `EN_TREE_API_PORT` is not set")?;
+            layer = layer.with_tree_api_config(MerkleTreeApiConfig { port });
+        }
+        if self.config.pruning_enabled() {
+            tracing::warn!("Proceeding with node state pruning for the Merkle tree. This is an experimental feature; use at your own risk");
+            layer = layer.with_pruning_config(self.config.optional.pruning_removal_delay());
+        }
+        self.node.add_layer(layer);
+        Ok(self)
+    }
+
+    fn add_tree_data_fetcher_layer(mut self) -> anyhow::Result<Self> {
+        let layer = TreeDataFetcherLayer::new(Some(self.diamond_proxy_addr()?));
+        self.node.add_layer(layer);
+        Ok(self)
+    }
+
+    fn add_pruning_layer(mut self) -> anyhow::Result<Self> {
+        let optional = &self.config.optional;
+        if self.config.pruning_enabled() {
+            tracing::warn!("Proceeding with node state pruning for Postgres. This is an experimental feature; use at your own risk");
+            let layer = PruningLayer::new(
+                optional.pruning_removal_delay(),
+                optional.pruning_chunk_size,
+                optional.pruning_data_retention()?,
+            );
+            self.node.add_layer(layer);
+        } else {
+            tracing::info!("Pruning is disabled, skipping");
+        }
+        Ok(self)
+    }
+
+    fn add_contract_verification_layers(mut self) -> anyhow::Result<Self> {
+        let verifier_config = self.config.optional.contract_verifier_config();
+        let mut verifier_layer = ContractVerifierLayer::new(verifier_config.clone());
+        if self
+            .config
+            .optional
+            .contract_verification_artifacts_cache_enabled
+        {
+            verifier_layer =
+                verifier_layer.with_artifacts_cache(contract_verifier_object_store_config()?);
+        }
+        self.node
+            .add_layer(ContractVerificationApiLayer(verifier_config))
+            .add_layer(verifier_layer);
+        Ok(self)
+    }
+
+    /// Adds the Web3 API servers for the specified components, together with the layers they depend on.
+    /// Transactions submitted to the API are proxied to the main node.
+    fn add_api_layers(mut self, components: &[Component]) -> anyhow::Result<Self> {
+        let optional = &self.config.optional;
+        if !components.contains(&Component::Core) {
+            // Without the state keeper, nothing else updates the sync state.
+            self.node
+                .add_layer(SyncStateLayer::default().with_updater());
+        }
+
+        let tree_api_url = self.config.api_component.tree_api_remote_url.clone();
+        if components.contains(&Component::Tree) {
+            if let Some(url) = &tree_api_url {
+                tracing::warn!(
+                    "Tree component is run locally; the specified tree API URL {url} is ignored"
+                );
+            }
+        }
+
+        let postgres_storage_caches_config = PostgresStorageCachesConfig {
+            factory_deps_cache_size: optional.factory_deps_cache_size() as u64,
+            initial_writes_cache_size: optional.initial_writes_cache_size() as u64,
+            latest_values_cache_size: optional.latest_values_cache_size() as u64,
+        };
+        let tx_sender_layer = TxSenderLayer::new(
+            (&self.config).into(),
+            postgres_storage_caches_config,
+            optional.vm_concurrency_limit,
+            ApiContracts::load_from_disk_blocking(), // TODO (BFT-138): Allow to dynamically reload API contracts
+        )
+        .with_whitelisted_tokens_for_aa_updates();
+
+        // The order of layers is important: the tree API client (local or remote) must be provided
+        // before the API servers are wired.
+        self.node
+            .add_layer(MainNodeFeeParamsFetcherLayer)
+            .add_layer(TxProxyLayer::new(optional.tx_proxy_queue_config()))
+            .add_layer(tx_sender_layer)
+            .add_layer(MempoolCacheLayer::new(
+                optional.mempool_cache_size,
+                optional.mempool_cache_update_interval(),
+            ))
+            .add_layer(TreeApiClientLayer::http(tree_api_url));
+        if let Some(debug_config) = optional.debug_namespace_config() {
+            self.node.add_layer(DebugNamespaceLayer::new(debug_config));
+        }
+
+        // The refresh interval should be several times lower than the pruning removal delay, so that
+        // soft-pruning will timely propagate to the API server.
+        let pruning_info_refresh_interval = optional.pruning_removal_delay() / 5;
+
+        if components.contains(&Component::HttpApi) {
+            let optional_config = Web3ServerOptionalConfig {
+                namespaces: Some(optional.api_namespaces()),
+                method_filter: Some(optional.api_method_filter()),
+                filters_limit: Some(optional.filters_limit),
+                batch_request_size_limit: Some(optional.max_batch_request_size),
+                response_body_size_limit: Some(optional.max_response_body_size()),
+                pruning_info_refresh_interval: Some(pruning_info_refresh_interval),
+                client_rate_limit: Some(
+                    optional
+                        .api_client_rate_limit()
+                        .context("invalid API rate limit config")?,
+                ),
+                extended_tracing: optional.extended_rpc_tracing,
+                ..Default::default()
+            };
+            self.node.add_layer(Web3ServerLayer::http(
+                self.config.required.http_port,
+                (&self.config).into(),
+                optional_config,
+            ));
+        }
+
+        if components.contains(&Component::WsApi) {
+            let optional_config = Web3ServerOptionalConfig {
+                namespaces: Some(optional.api_namespaces()),
+                method_filter: Some(optional.api_method_filter()),
+                filters_limit: Some(optional.filters_limit),
+                subscriptions_limit: Some(optional.subscriptions_limit),
+                subscriptions_per_connection_limit: Some(
+                    optional.subscriptions_per_connection_limit,
+                ),
+                subscription_backlog_size: Some(optional.subscription_backlog_size),
+                batch_request_size_limit: Some(optional.max_batch_request_size),
+                response_body_size_limit: Some(optional.max_response_body_size()),
+                polling_interval: Some(optional.polling_interval()),
+                pruning_info_refresh_interval: Some(pruning_info_refresh_interval),
+                extended_tracing: optional.extended_rpc_tracing,
+                ..Default::default()
+            };
+            self.node.add_layer(Web3ServerLayer::ws(
+                self.config.required.ws_port,
+                (&self.config).into(),
+                optional_config,
+            ));
+        }
+        Ok(self)
+    }
+
+    /// Adds layers for the specified components. Checks that don't require wiring layers (e.g., that components
+    /// don't share RocksDB instances) are performed here; resource wiring is checked once the service is wired.
+    fn add_layers(mut self, components: &[Component]) -> anyhow::Result<Self> {
+        // Add "base" layers.
+        self = self
+            .add_sigint_handler_layer()?
+            .add_healthcheck_layer()?
+            .add_prometheus_exporter_layer()?
+            .add_pools_layer()?
+            .add_main_node_client_layer()?
+            .add_storage_initialization_layer()?
+            .add_block_reverter_layer()?
+            .add_reorg_detector_layer()?;
+
+        // Add layers for the requested components.
+        for component in components {
+            match component {
+                Component::Core => {
+                    // The state keeper and the consensus fetcher must be added in this order,
+                    // since the fetcher requests the action queue sender provided by the state keeper I/O.
+                    self = self
+                        .add_query_eth_client_layer()?
+                        .add_state_keeper_layer()?
+                        .add_consensus_layer()?
+                        .add_l1_batch_commitment_mode_validation_layer()?
+                        .add_consistency_checker_layer()?
+                        .add_commitment_generator_layer()?
+                        .add_batch_status_updater_layer()?;
+                }
+                Component::Tree => {
+                    let with_tree_api = components.contains(&Component::TreeApi);
+                    self = self.add_metadata_calculator_layer(with_tree_api)?;
+                }
+                Component::TreeApi => {
+                    anyhow::ensure!(
+                        components.contains(&Component::Tree),
+                        "Merkle tree API cannot be started without a tree component"
+                    );
+                    // Do nothing, will be handled by the `Tree` component.
+                }
+                Component::TreeFetcher => {
+                    // The L1 client is shared with the `Core` component if it's run as well.
+                    self = self
+                        .add_query_eth_client_layer()?
+                        .add_tree_data_fetcher_layer()?;
+                }
+                Component::Pruning => {
+                    self = self.add_pruning_layer()?;
+                }
+                Component::ContractVerification => {
+                    self = self.add_contract_verification_layers()?;
+                }
+                Component::HttpApi | Component::WsApi => {
+                    // Do nothing, will be handled after all other components, since API servers use
+                    // resources provided by other components (e.g., the Merkle tree API client).
+                }
+                _ => anyhow::bail!(
+                    "Component {component:?} is not supported by the node framework yet; \
+                     run the node without `--use-node-framework`"
+                ),
+            }
+        }
+
+        if components.contains(&Component::HttpApi) || components.contains(&Component::WsApi) {
+            self = self.add_api_layers(components)?;
+        }
+
+        if self.config.pruning_enabled() && !components.contains(&Component::Pruning) {
+            tracing::warn!(
+                "Pruning is enabled, but the `pruning` component is not run; Postgres data will not be pruned by this node"
+            );
+        }
+        Ok(self)
+    }
+
+    /// Marks the RocksDB instance at `path` as used by `user`. RocksDB instances are exclusive, so this fails
+    /// if the instance is already used by another component.
+    fn use_rocksdb(&mut self, user: &'static str, path: &str) -> anyhow::Result<()> {
+        if let Some((other_user, _)) = self
+            .rocksdb_users
+            .iter()
+            .find(|(_, other_path)| other_path == path)
+        {
+            anyhow::bail!("{other_user} and {user} both use RocksDB at `{path}`");
+        }
+        self.rocksdb_users.push((user, path.to_owned()));
+        Ok(())
+    }
+
+    pub fn build(self, components: Vec<Component>) -> anyhow::Result<ZkStackService> {
+        let mut this = self.add_layers(&components)?;
+        Ok(this.node.build()?)
+    }
+
+    /// Wires layers for the specified components without starting any tasks, and returns IDs of the tasks
+    /// that would be started. All wiring errors (e.g., a component requiring a resource provided
+    /// by another component that is not run) are reported at once.
+    pub fn validate(self, components: Vec<Component>) -> anyhow::Result<Vec<TaskId>> {
+        Ok(self.build(components)?.validate()?)
+    }
+}
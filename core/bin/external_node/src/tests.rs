@@ -100,6 +100,205 @@ fn expected_health_components(components: &ComponentsToRun) -> Vec<&'static str>
     output
 }
 
+#[test]
+fn core_component_includes_pruning() {
+    let components: ComponentsToRun = "core".parse().unwrap();
+    assert!(components.0.contains(&Component::Pruning));
+    let components: ComponentsToRun = "api,tree".parse().unwrap();
+    assert!(!components.0.contains(&Component::Pruning));
+}
+
+#[tokio::test]
+async fn validating_node_framework_wiring() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let connection_pool = ConnectionPool::test_pool().await;
+    let config = ExternalNodeConfig::mock(&temp_dir, &connection_pool);
+    let unsupported_config = ExternalNodeConfig::mock(&temp_dir, &connection_pool);
+
+    // The node framework manages its own runtime, so it cannot be used from the test runtime.
+    let (task_ids, err) = std::thread::spawn(move || {
+        let task_ids = ExternalNodeBuilder::new(config)
+            .validate(vec![Component::Core, Component::Pruning])
+            .unwrap();
+        let err = ExternalNodeBuilder::new(unsupported_config)
+            .validate(vec![Component::Core, Component::SnapshotsCreator])
+            .unwrap_err();
+        (task_ids, err)
+    })
+    .join()
+    .unwrap();
+
+    let task_ids: Vec<_> = task_ids.iter().map(ToString::to_string).collect();
+    assert!(
+        task_ids.iter().any(|id| id == "state_keeper"),
+        "{task_ids:?}"
+    );
+    let err = err.to_string();
+    assert!(err.contains("not supported by the node framework"), "{err}");
+}
+
+#[tokio::test]
+async fn validating_node_profile_wiring() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let connection_pool = ConnectionPool::test_pool().await;
+    let mut archive_config = ExternalNodeConfig::mock(&temp_dir, &connection_pool);
+    archive_config.optional.pruning_enabled = true;
+    archive_config.node_profile = Some(NodeProfile::Archive);
+    let mut full_config = ExternalNodeConfig::mock(&temp_dir, &connection_pool);
+    full_config.optional.pruning_enabled = false;
+    full_config.node_profile = Some(NodeProfile::Full);
+
+    let (archive_task_ids, full_task_ids) = std::thread::spawn(move || {
+        let components = vec![Component::Core, Component::Pruning];
+        let archive_task_ids = ExternalNodeBuilder::new(archive_config)
+            .validate(components.clone())
+            .unwrap();
+        let full_task_ids = ExternalNodeBuilder::new(full_config)
+            .validate(components)
+            .unwrap();
+        (archive_task_ids, full_task_ids)
+    })
+    .join()
+    .unwrap();
+
+    // The node profile takes precedence over `pruning_enabled`.
+    let archive_task_ids: Vec<_> = archive_task_ids.iter().map(ToString::to_string).collect();
+    assert!(
+        !archive_task_ids.iter().any(|id| id == "db_pruner"),
+        "{archive_task_ids:?}"
+    );
+    let full_task_ids: Vec<_> = full_task_ids.iter().map(ToString::to_string).collect();
+    assert!(
+        full_task_ids.iter().any(|id| id == "db_pruner"),
+        "{full_task_ids:?}"
+    );
+}
+
+#[tokio::test]
+async fn validating_api_wiring() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let connection_pool = ConnectionPool::test_pool().await;
+    let config = ExternalNodeConfig::mock(&temp_dir, &connection_pool);
+    let api_only_config = ExternalNodeConfig::mock(&temp_dir, &connection_pool);
+
+    let (task_ids, api_only_task_ids) = std::thread::spawn(move || {
+        let task_ids = ExternalNodeBuilder::new(config)
+            .validate(vec![Component::Core, Component::HttpApi, Component::WsApi])
+            .unwrap();
+        let api_only_task_ids = ExternalNodeBuilder::new(api_only_config)
+            .validate(vec![Component::HttpApi])
+            .unwrap();
+        (task_ids, api_only_task_ids)
+    })
+    .join()
+    .unwrap();
+
+    let task_ids: Vec<_> = task_ids.iter().map(ToString::to_string).collect();
+    for expected_id in [
+        "tx_proxy_nonce_sweeper",
+        "tx_proxy_resubmission",
+        "main_node_fee_params_fetcher",
+        "whitelisted_tokens_for_aa_updater",
+        "web3_http_server",
+        "web3_ws_server",
+    ] {
+        assert!(task_ids.iter().any(|id| id == expected_id), "{task_ids:?}");
+    }
+    // The sync state is updated by the state keeper.
+    assert!(
+        !task_ids.iter().any(|id| id == "sync_state_updater"),
+        "{task_ids:?}"
+    );
+
+    let api_only_task_ids: Vec<_> = api_only_task_ids.iter().map(ToString::to_string).collect();
+    for expected_id in [
+        "tx_proxy_nonce_sweeper",
+        "sync_state_updater",
+        "web3_http_server",
+    ] {
+        assert!(
+            api_only_task_ids.iter().any(|id| id == expected_id),
+            "{api_only_task_ids:?}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn validating_contract_verification_wiring() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let connection_pool = ConnectionPool::test_pool().await;
+    let config = ExternalNodeConfig::mock(&temp_dir, &connection_pool);
+
+    let task_ids = std::thread::spawn(move || {
+        ExternalNodeBuilder::new(config)
+            .validate(vec![Component::ContractVerification])
+            .unwrap()
+    })
+    .join()
+    .unwrap();
+
+    let task_ids: Vec<_> = task_ids.iter().map(ToString::to_string).collect();
+    for expected_id in ["contract_verification_api", "contract_verifier"] {
+        assert!(task_ids.iter().any(|id| id == expected_id), "{task_ids:?}");
+    }
+}
+
+#[tokio::test]
+async fn validating_tree_fetcher_wiring() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let connection_pool = ConnectionPool::test_pool().await;
+    let config = ExternalNodeConfig::mock(&temp_dir, &connection_pool);
+    let config_with_core = ExternalNodeConfig::mock(&temp_dir, &connection_pool);
+
+    let (task_ids, task_ids_with_core) = std::thread::spawn(move || {
+        let task_ids = ExternalNodeBuilder::new(config)
+            .validate(vec![Component::TreeFetcher])
+            .unwrap();
+        let task_ids_with_core = ExternalNodeBuilder::new(config_with_core)
+            .validate(vec![Component::TreeFetcher, Component::Core])
+            .unwrap();
+        (task_ids, task_ids_with_core)
+    })
+    .join()
+    .unwrap();
+
+    for task_ids in [task_ids, task_ids_with_core] {
+        let task_ids: Vec<_> = task_ids.iter().map(ToString::to_string).collect();
+        assert!(
+            task_ids.iter().any(|id| id == "tree_data_fetcher"),
+            "{task_ids:?}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn validating_tree_api_wiring() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let connection_pool = ConnectionPool::test_pool().await;
+    let mut config = ExternalNodeConfig::mock(&temp_dir, &connection_pool);
+    config.tree_component.api_port = Some(3072);
+    let config_without_tree = ExternalNodeConfig::mock(&temp_dir, &connection_pool);
+
+    let (task_ids, err) = std::thread::spawn(move || {
+        let task_ids = ExternalNodeBuilder::new(config)
+            .validate(vec![Component::Tree, Component::TreeApi])
+            .unwrap();
+        let err = ExternalNodeBuilder::new(config_without_tree)
+            .validate(vec![Component::TreeApi])
+            .unwrap_err();
+        (task_ids, err)
+    })
+    .join()
+    .unwrap();
+
+    let task_ids: Vec<_> = task_ids.iter().map(ToString::to_string).collect();
+    for expected_id in ["metadata_calculator", "tree_api"] {
+        assert!(task_ids.iter().any(|id| id == expected_id), "{task_ids:?}");
+    }
+    let err = err.to_string();
+    assert!(err.contains("without a tree component"), "{err}");
+}
+
 fn mock_eth_client(diamond_proxy_addr: Address) -> MockClient<L1> {
     let mock = MockEthereum::builder().with_call_handler(move |call, _| {
         tracing::info!("L1 call: {call:?}");
@@ -156,6 +355,12 @@ async fn external_node_basics(components_str: &'static str) {
     let opt = Cli {
         enable_consensus: false,
         components,
+        network: None,
+        node_profile: None,
+        config_path: None,
+        config_overrides: vec![],
+        use_node_framework: false,
+        command: None,
     };
     let mut config = ExternalNodeConfig::mock(&temp_dir, &connection_pool);
     if opt.components.0.contains(&Component::TreeApi) {
@@ -202,6 +407,7 @@ async fn external_node_basics(components_str: &'static str) {
             singleton_pool_builder,
             l2_client,
             eth_client,
+            ClientProbers::default(),
         )
         .await
     });
@@ -264,6 +470,12 @@ async fn node_reacts_to_stop_signal_during_initial_reorg_detection() {
     let opt = Cli {
         enable_consensus: false,
         components: "core".parse().unwrap(),
+        network: None,
+        node_profile: None,
+        config_path: None,
+        config_overrides: vec![],
+        use_node_framework: false,
+        command: None,
     };
     let mut config = ExternalNodeConfig::mock(&temp_dir, &connection_pool);
     if opt.components.0.contains(&Component::TreeApi) {
@@ -296,6 +508,7 @@ async fn node_reacts_to_stop_signal_during_initial_reorg_detection() {
             singleton_pool_builder,
             l2_client,
             eth_client,
+            ClientProbers::default(),
         )
         .await
     });
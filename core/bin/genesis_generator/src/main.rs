@@ -2,17 +2,22 @@
 /// This tool generates the new correct genesis file that could be used for the new chain
 /// Please note, this tool update only yaml file, if you still use env based configuration,
 /// update env values correspondingly
-use std::fs;
+///
+/// The tool can also export the full genesis state to a portable file (`export` subcommand)
+/// and initialize a fresh database from such a file (`import` subcommand).
+use std::{fs, path::PathBuf};
 
 use anyhow::Context as _;
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use serde_yaml::Serializer;
 use zksync_config::{configs::DatabaseSecrets, GenesisConfig};
 use zksync_contracts::BaseSystemContracts;
 use zksync_core_leftovers::temp_config_store::decode_yaml_repr;
 use zksync_dal::{ConnectionPool, Core, CoreDal};
 use zksync_env_config::FromEnv;
-use zksync_node_genesis::{insert_genesis_batch, GenesisParams};
+use zksync_node_genesis::{
+    ensure_genesis_state, insert_genesis_batch, GenesisExport, GenesisParams,
+};
 use zksync_protobuf::{
     build::{prost_reflect, prost_reflect::ReflectMessage},
     ProtoRepr,
@@ -28,15 +33,45 @@ const DEFAULT_GENESIS_FILE_PATH: &str = "./etc/env/file_based/genesis.yaml";
 #[command(author = "Matter Labs", version, about = "Genesis config generator", long_about = None)]
 struct Cli {
     #[arg(long)]
-    config_path: Option<std::path::PathBuf>,
+    config_path: Option<PathBuf>,
     #[arg(long, default_value = "false")]
     check: bool,
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Exports the full genesis state (chain params, system contracts and initial storage logs)
+    /// to a portable JSON file.
+    Export {
+        #[arg(long)]
+        output: PathBuf,
+    },
+    /// Initializes a fresh database from a genesis state file produced by the `export` subcommand.
+    Import {
+        #[arg(long)]
+        input: PathBuf,
+    },
 }
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     let opt = Cli::parse();
 
+    let yaml = std::fs::read_to_string(DEFAULT_GENESIS_FILE_PATH)
+        .with_context(|| DEFAULT_GENESIS_FILE_PATH.to_string())?;
+    let original_genesis = decode_yaml_repr::<Genesis>(&yaml)?;
+    if let Some(Command::Export { output }) = &opt.command {
+        let params = GenesisParams::load_genesis_params(original_genesis)?;
+        params.export().write_to_file(output)?;
+        println!(
+            "Genesis state successfully exported to {}",
+            output.display()
+        );
+        return Ok(());
+    }
+
     let database_secrets = match opt.config_path {
         None => DatabaseSecrets::from_env()?,
         Some(path) => {
@@ -47,11 +82,17 @@ async fn main() -> anyhow::Result<()> {
             config.database.context("Database secrets must exist")?
         }
     };
-
-    let yaml = std::fs::read_to_string(DEFAULT_GENESIS_FILE_PATH)
-        .with_context(|| DEFAULT_GENESIS_FILE_PATH.to_string())?;
-    let original_genesis = decode_yaml_repr::<Genesis>(&yaml)?;
     let db_url = database_secrets.master_url()?;
+    if let Some(Command::Import { input }) = &opt.command {
+        let export = GenesisExport::read_from_file(input)?;
+        import_genesis(db_url, export).await?;
+        println!(
+            "Genesis state successfully imported from {}",
+            input.display()
+        );
+        return Ok(());
+    }
+
     let new_genesis = generate_new_config(db_url, original_genesis.clone()).await?;
     if opt.check {
         assert_eq!(&original_genesis, &new_genesis);
@@ -106,6 +147,23 @@ async fn generate_new_config(
     Ok(updated_genesis)
 }
 
+async fn import_genesis(db_url: SensitiveUrl, export: GenesisExport) -> anyhow::Result<()> {
+    let pool = ConnectionPool::<Core>::singleton(db_url)
+        .build()
+        .await
+        .context("failed to build connection_pool")?;
+    let mut storage = pool.connection().await.context("connection()")?;
+    if !storage.blocks_dal().is_genesis_needed().await? {
+        anyhow::bail!("Database is not empty; genesis can only be imported into a fresh database");
+    }
+
+    let params = GenesisParams::import(export)?;
+    // Checks the resulting root hash, commitment and leaf index against the imported config.
+    let root_hash = ensure_genesis_state(&mut storage, &params).await?;
+    println!("Imported genesis with root hash {root_hash:?}");
+    Ok(())
+}
+
 /// Encodes a generated proto message to json for arbitrary `ProtoFmt`.
 pub(crate) fn encode_yaml<T: ReflectMessage>(x: &T) -> anyhow::Result<String> {
     let mut serializer = Serializer::new(vec![]);
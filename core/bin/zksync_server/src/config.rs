@@ -1,27 +1,94 @@
 use anyhow::Context as _;
-use zksync_config::configs::consensus::{ConsensusConfig, ConsensusSecrets};
+use zksync_config::configs::{
+    consensus::{ConsensusConfig, ConsensusSecrets},
+    DatabaseSecrets, L1Secrets, ReloadableConfig,
+};
 use zksync_core_leftovers::temp_config_store::decode_yaml_repr;
-use zksync_protobuf_config::proto;
+use zksync_protobuf_config::{
+    interpolation::{interpolate_env_vars, read_yaml_config},
+    proto,
+};
+use zksync_secrets::SecretsResolver;
+use zksync_types::url::SensitiveUrl;
 
-pub(crate) fn read_consensus_secrets() -> anyhow::Result<Option<ConsensusSecrets>> {
+pub(crate) async fn read_consensus_secrets(
+    resolver: &SecretsResolver,
+) -> anyhow::Result<Option<ConsensusSecrets>> {
     // Read public config.
     let Ok(path) = std::env::var("CONSENSUS_SECRETS_PATH") else {
         return Ok(None);
     };
-    let secrets = std::fs::read_to_string(&path).context(path)?;
+    let secrets = read_yaml_config(path.as_ref())?;
+    let secrets = resolver
+        .resolve_yaml(&secrets)
+        .await
+        .context("failed resolving consensus secrets")?;
     Ok(Some(
         decode_yaml_repr::<proto::secrets::ConsensusSecrets>(&secrets)
             .context("failed decoding YAML")?,
     ))
 }
 
+/// Parses the reloadable part of the general YAML config. Used to hot-reload the config.
+pub(crate) fn parse_reloadable_config(yaml: &str) -> anyhow::Result<ReloadableConfig> {
+    let yaml = interpolate_env_vars(yaml).context("failed interpolating env variables")?;
+    let config = decode_yaml_repr::<proto::general::GeneralConfig>(&yaml)
+        .context("failed decoding general YAML config")?;
+    Ok(ReloadableConfig::from_general(&config))
+}
+
 pub(crate) fn read_consensus_config() -> anyhow::Result<Option<ConsensusConfig>> {
     // Read public config.
     let Ok(path) = std::env::var("CONSENSUS_CONFIG_PATH") else {
         return Ok(None);
     };
-    let cfg = std::fs::read_to_string(&path).context(path)?;
+    let cfg = read_yaml_config(path.as_ref())?;
     Ok(Some(
         decode_yaml_repr::<proto::consensus::Config>(&cfg).context("failed decoding YAML")?,
     ))
 }
+
+/// Resolves secret references in database URLs loaded from env variables.
+pub(crate) async fn resolve_database_secrets(
+    resolver: &SecretsResolver,
+    secrets: DatabaseSecrets,
+) -> anyhow::Result<DatabaseSecrets> {
+    Ok(DatabaseSecrets {
+        server_url: resolve_optional_url(resolver, secrets.server_url).await?,
+        prover_url: resolve_optional_url(resolver, secrets.prover_url).await?,
+        server_replica_url: resolve_optional_url(resolver, secrets.server_replica_url).await?,
+        server_api_replica_urls: resolve_urls(resolver, secrets.server_api_replica_urls).await?,
+    })
+}
+
+/// Resolves secret references in L1 RPC URLs loaded from env variables.
+pub(crate) async fn resolve_l1_secrets(
+    resolver: &SecretsResolver,
+    secrets: L1Secrets,
+) -> anyhow::Result<L1Secrets> {
+    Ok(L1Secrets {
+        l1_rpc_url: resolver.resolve_url(secrets.l1_rpc_url).await?,
+        fallback_l1_rpc_urls: resolve_urls(resolver, secrets.fallback_l1_rpc_urls).await?,
+    })
+}
+
+async fn resolve_urls(
+    resolver: &SecretsResolver,
+    urls: Vec<SensitiveUrl>,
+) -> anyhow::Result<Vec<SensitiveUrl>> {
+    let mut resolved_urls = Vec::with_capacity(urls.len());
+    for url in urls {
+        resolved_urls.push(resolver.resolve_url(url).await?);
+    }
+    Ok(resolved_urls)
+}
+
+async fn resolve_optional_url(
+    resolver: &SecretsResolver,
+    url: Option<SensitiveUrl>,
+) -> anyhow::Result<Option<SensitiveUrl>> {
+    Ok(match url {
+        Some(url) => Some(resolver.resolve_url(url).await?),
+        None => None,
+    })
+}
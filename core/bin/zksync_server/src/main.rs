@@ -11,21 +11,28 @@ use zksync_config::{
         },
         fri_prover_group::FriProverGroupConfig,
         house_keeper::HouseKeeperConfig,
-        ContractsConfig, DatabaseSecrets, FriProofCompressorConfig, FriProverConfig,
-        FriProverGatewayConfig, FriWitnessGeneratorConfig, FriWitnessVectorGeneratorConfig,
-        L1Secrets, ObservabilityConfig, PrometheusConfig, ProofDataHandlerConfig,
-        ProtectiveReadsWriterConfig, Secrets,
+        BasicWitnessInputProducerConfig, CommitmentGeneratorConfig, ContractsConfig,
+        DatabaseSecrets, FriProofCompressorConfig, FriProverConfig, FriProverGatewayConfig,
+        FriWitnessGeneratorConfig, FriWitnessVectorGeneratorConfig, L1Secrets, ObservabilityConfig,
+        PrometheusConfig, ProofDataHandlerConfig, ProtectiveReadsWriterConfig, Secrets,
+        VmPlaygroundConfig,
     },
-    ApiConfig, ContractVerifierConfig, DBConfig, EthConfig, EthWatchConfig, GasAdjusterConfig,
-    GenesisConfig, ObjectStoreConfig, PostgresConfig, SnapshotsCreatorConfig,
+    ApiConfig, BaseTokenPriceConfig, ContractVerifierConfig, DAClientConfig, DADispatcherConfig,
+    DBConfig, EthConfig, EthWatchConfig, GasAdjusterConfig, GenesisConfig, ObjectStoreConfig,
+    PostgresConfig, SnapshotsCreatorConfig,
 };
 use zksync_core_leftovers::{
     genesis_init, initialize_components, is_genesis_needed, setup_sigint_handler,
     temp_config_store::{decode_yaml_repr, TempConfigStore},
     Component, Components,
 };
-use zksync_env_config::FromEnv;
+use zksync_env_config::{object_store::SnapshotsObjectStoreConfig, FromEnv};
 use zksync_eth_client::clients::Client;
+use zksync_protobuf_config::{
+    interpolation::read_yaml_config,
+    schema::{json_schema, ConfigFile},
+};
+use zksync_secrets::SecretsResolver;
 use zksync_storage::RocksDB;
 use zksync_utils::wait_for_tasks::ManagedTasks;
 
@@ -37,6 +44,13 @@ mod node_builder;
 #[cfg(not(target_env = "msvc"))]
 #[global_allocator]
 static GLOBAL: tikv_jemallocator::Jemalloc = tikv_jemallocator::Jemalloc;
+/// Enables jemalloc heap profiling, which can be activated at runtime via the admin server.
+/// Allocations are not sampled until then, so this has negligible overhead.
+#[cfg(not(target_env = "msvc"))]
+#[allow(non_upper_case_globals)]
+#[export_name = "_rjem_malloc_conf"]
+pub static malloc_conf: &[u8] = b"prof:true,prof_active:false,lg_prof_sample:19\0";
+
 #[derive(Debug, Parser)]
 #[command(author = "Matter Labs", version, about = "zkSync operator node", long_about = None)]
 struct Cli {
@@ -70,6 +84,10 @@ struct Cli {
     /// Run the node using the node framework.
     #[arg(long)]
     use_node_framework: bool,
+    /// Output the JSON Schema for the specified YAML config file (one of `general`, `secrets`, `wallets`,
+    /// `contracts`, `genesis` or `consensus`) and exit.
+    #[arg(long)]
+    config_schema: Option<ConfigFile>,
 }
 
 #[derive(Debug, Clone)]
@@ -92,14 +110,19 @@ impl FromStr for ComponentsToRun {
 async fn main() -> anyhow::Result<()> {
     let opt = Cli::parse();
 
+    if let Some(file) = opt.config_schema {
+        let schema = json_schema(file).context("failed generating config schema")?;
+        println!("{}", serde_json::to_string_pretty(&schema)?);
+        return Ok(());
+    }
+
     // Load env config and use it if file config is not provided
     let tmp_config = load_env_config()?;
 
-    let configs = match opt.config_path {
+    let configs = match &opt.config_path {
         None => tmp_config.general(),
         Some(path) => {
-            let yaml =
-                std::fs::read_to_string(&path).with_context(|| path.display().to_string())?;
+            let yaml = read_yaml_config(path)?;
             decode_yaml_repr::<zksync_protobuf_config::proto::general::GeneralConfig>(&yaml)
                 .context("failed decoding general YAML config")?
         }
@@ -119,12 +142,43 @@ async fn main() -> anyhow::Result<()> {
     if let Some(log_directives) = observability_config.log_directives {
         builder = builder.with_log_directives(log_directives);
     }
+    if let Some(log_sampling_directives) = &observability_config.log_sampling_directives {
+        let directives = log_sampling_directives
+            .parse()
+            .context("Invalid log sampling directives")?;
+        builder = builder.with_log_sampling(directives);
+    }
 
     if let Some(sentry_url) = &observability_config.sentry_url {
         builder = builder
             .with_sentry_url(sentry_url)
             .expect("Invalid Sentry URL")
-            .with_sentry_environment(observability_config.sentry_environment);
+            .with_sentry_environment(observability_config.sentry_environment)
+            .with_sentry_release(Some(observability_config.sentry_release.unwrap_or_else(
+                || concat!(env!("CARGO_PKG_NAME"), "@", env!("CARGO_PKG_VERSION")).to_owned(),
+            )));
+    }
+    // Some deployments use `unset` as an equivalent of `None`.
+    let opentelemetry = observability_config
+        .opentelemetry
+        .filter(|opentelemetry| opentelemetry.endpoint != "unset");
+    if let Some(opentelemetry) = &opentelemetry {
+        builder = builder
+            .with_opentelemetry(
+                &opentelemetry.level,
+                opentelemetry.endpoint.clone(),
+                "zksync-server".into(),
+            )
+            .context("Invalid OpenTelemetry config")?;
+        if let Some(sampling_ratio) = opentelemetry.sampling_ratio {
+            builder = builder.with_opentelemetry_sampling_ratio(sampling_ratio);
+        }
+        if let Some(resource_attributes) = &opentelemetry.resource_attributes {
+            let attributes = resource_attributes
+                .parse()
+                .context("Invalid OpenTelemetry resource attributes")?;
+            builder = builder.with_opentelemetry_resource_attributes(attributes);
+        }
     }
     let _guard = builder.build();
 
@@ -134,12 +188,26 @@ async fn main() -> anyhow::Result<()> {
     } else {
         tracing::info!("No sentry URL was provided");
     }
+    if let Some(opentelemetry) = &opentelemetry {
+        tracing::info!(
+            "OpenTelemetry traces are exported to {} with level `{}`",
+            opentelemetry.endpoint,
+            opentelemetry.level
+        );
+    }
+
+    // Secrets can be specified as references to secret managers, which are resolved here.
+    let secrets_resolver =
+        SecretsResolver::from_env().context("failed initializing secrets resolver")?;
 
     let wallets = match opt.wallets_path {
         None => tmp_config.wallets(),
         Some(path) => {
-            let yaml =
-                std::fs::read_to_string(&path).with_context(|| path.display().to_string())?;
+            let yaml = read_yaml_config(&path)?;
+            let yaml = secrets_resolver
+                .resolve_yaml(&yaml)
+                .await
+                .context("failed resolving secrets in wallets YAML config")?;
             decode_yaml_repr::<zksync_protobuf_config::proto::wallets::Wallets>(&yaml)
                 .context("failed decoding wallets YAML config")?
         }
@@ -147,15 +215,34 @@ async fn main() -> anyhow::Result<()> {
 
     let secrets: Secrets = match opt.secrets_path {
         Some(path) => {
-            let yaml =
-                std::fs::read_to_string(&path).with_context(|| path.display().to_string())?;
+            let yaml = read_yaml_config(&path)?;
+            let yaml = secrets_resolver
+                .resolve_yaml(&yaml)
+                .await
+                .context("failed resolving secrets in secrets YAML config")?;
             decode_yaml_repr::<zksync_protobuf_config::proto::secrets::Secrets>(&yaml)
                 .context("failed decoding secrets YAML config")?
         }
         None => Secrets {
-            consensus: config::read_consensus_secrets().context("read_consensus_secrets()")?,
-            database: DatabaseSecrets::from_env().ok(),
-            l1: L1Secrets::from_env().ok(),
+            consensus: config::read_consensus_secrets(&secrets_resolver)
+                .await
+                .context("read_consensus_secrets()")?,
+            database: match DatabaseSecrets::from_env().ok() {
+                Some(secrets) => Some(
+                    config::resolve_database_secrets(&secrets_resolver, secrets)
+                        .await
+                        .context("failed resolving database secrets")?,
+                ),
+                None => None,
+            },
+            l1: match L1Secrets::from_env().ok() {
+                Some(secrets) => Some(
+                    config::resolve_l1_secrets(&secrets_resolver, secrets)
+                        .await
+                        .context("failed resolving L1 secrets")?,
+                ),
+                None => None,
+            },
         },
     };
 
@@ -164,8 +251,7 @@ async fn main() -> anyhow::Result<()> {
     let contracts_config = match opt.contracts_config_path {
         None => ContractsConfig::from_env().context("contracts_config")?,
         Some(path) => {
-            let yaml =
-                std::fs::read_to_string(&path).with_context(|| path.display().to_string())?;
+            let yaml = read_yaml_config(&path)?;
             decode_yaml_repr::<zksync_protobuf_config::proto::contracts::Contracts>(&yaml)
                 .context("failed decoding contracts YAML config")?
         }
@@ -174,13 +260,15 @@ async fn main() -> anyhow::Result<()> {
     let genesis = match opt.genesis_path {
         None => GenesisConfig::from_env().context("Genesis config")?,
         Some(path) => {
-            let yaml =
-                std::fs::read_to_string(&path).with_context(|| path.display().to_string())?;
+            let yaml = read_yaml_config(&path)?;
             decode_yaml_repr::<zksync_protobuf_config::proto::genesis::Genesis>(&yaml)
                 .context("failed decoding genesis YAML config")?
         }
     };
 
+    vlog::set_sentry_tag("l1_chain_id", genesis.l1_chain_id.0);
+    vlog::set_sentry_tag("l2_chain_id", genesis.l2_chain_id.as_u64());
+
     let database_secrets = secrets.database.clone().context("DatabaseSecrets")?;
 
     if opt.genesis || is_genesis_needed(&database_secrets).await {
@@ -219,15 +307,19 @@ async fn main() -> anyhow::Result<()> {
     if opt.use_node_framework {
         // We run the node from a different thread, since the current thread is in tokio context.
         std::thread::spawn(move || -> anyhow::Result<()> {
-            let node = MainNodeBuilder::new(
+            let mut builder = MainNodeBuilder::new(
                 configs,
                 wallets,
                 genesis,
                 contracts_config,
                 secrets,
                 consensus,
-            )
-            .build(components)?;
+            );
+            if let Some(config_path) = opt.config_path {
+                builder = builder.with_config_path(config_path);
+            }
+            builder = builder.with_secrets_resolver(secrets_resolver);
+            let node = builder.build(components)?;
             node.run()?;
             Ok(())
         })
@@ -305,8 +397,19 @@ fn load_env_config() -> anyhow::Result<TempConfigStore> {
         eth_watch_config: EthWatchConfig::from_env().ok(),
         gas_adjuster_config: GasAdjusterConfig::from_env().ok(),
         observability: ObservabilityConfig::from_env().ok(),
-        snapshot_creator: SnapshotsCreatorConfig::from_env().ok(),
+        snapshot_creator: SnapshotsCreatorConfig::from_env().ok().map(|mut config| {
+            config.object_store = SnapshotsObjectStoreConfig::from_env()
+                .ok()
+                .map(|config| config.0);
+            config
+        }),
         protective_reads_writer_config: ProtectiveReadsWriterConfig::from_env().ok(),
+        basic_witness_input_producer_config: BasicWitnessInputProducerConfig::from_env().ok(),
+        vm_playground_config: VmPlaygroundConfig::from_env().ok(),
         core_object_store: ObjectStoreConfig::from_env().ok(),
+        da_dispatcher_config: DADispatcherConfig::from_env().ok(),
+        da_client_config: DAClientConfig::from_env().ok(),
+        base_token_price_config: BaseTokenPriceConfig::from_env().ok(),
+        commitment_generator_config: CommitmentGeneratorConfig::from_env().ok(),
     })
 }
@@ -1,10 +1,15 @@
 //! This module provides a "builder" for the main node,
 //! as well as an interface to run the node with the specified components.
 
+use std::{path::PathBuf, time::Duration};
+
 use anyhow::Context;
-use prometheus_exporter::PrometheusExporterConfig;
+use prometheus_exporter::{push_gateway_endpoint, PrometheusExporterConfig};
 use zksync_config::{
-    configs::{consensus::ConsensusConfig, wallets::Wallets, GeneralConfig, Secrets},
+    configs::{
+        consensus::ConsensusConfig, da_client::DAClient, eth_sender::PubdataSendingMode,
+        wallets::Wallets, GeneralConfig, ReloadableConfig, Secrets,
+    },
     ContractsConfig, GenesisConfig,
 };
 use zksync_core_leftovers::Component;
@@ -15,31 +20,49 @@ use zksync_node_api_server::{
 };
 use zksync_node_framework::{
     implementations::layers::{
+        admin_server::AdminServerLayer,
+        base_token_ratio_persister::BaseTokenRatioPersisterLayer,
+        base_token_ratio_provider::BaseTokenRatioProviderLayer,
         circuit_breaker_checker::CircuitBreakerCheckerLayer,
         commitment_generator::CommitmentGeneratorLayer,
+        config_reloader::ConfigReloaderLayer,
         consensus::{ConsensusLayer, Mode as ConsensusMode},
         contract_verification_api::ContractVerificationApiLayer,
+        da_clients::{
+            avail::AvailWiringLayer, celestia::CelestiaWiringLayer, eigen::EigenWiringLayer,
+            mantle::MantleWiringLayer, object_store::ObjectStorageClientWiringLayer,
+            shadow::ShadowDAClientWiringLayer,
+        },
+        da_dispatcher::DataAvailabilityDispatcherLayer,
         eth_sender::{EthTxAggregatorLayer, EthTxManagerLayer},
         eth_watch::EthWatchLayer,
         healtcheck_server::HealthCheckLayer,
         house_keeper::HouseKeeperLayer,
         l1_gas::SequencerL1GasLayer,
         metadata_calculator::MetadataCalculatorLayer,
+        migrations::MigrationsLayer,
         object_store::ObjectStoreLayer,
         pk_signing_eth_client::PKSigningEthClientLayer,
         pools_layer::PoolsLayerBuilder,
         prometheus_exporter::PrometheusExporterLayer,
         proof_data_handler::ProofDataHandlerLayer,
         query_eth_client::QueryEthClientLayer,
+        secrets_rotation::SecretsRotationLayer,
         sigint::SigintHandlerLayer,
+        slo_monitor::SloMonitorLayer,
+        snapshots_creator::SnapshotsCreatorLayer,
         state_keeper::{
             main_batch_executor::MainBatchExecutorLayer, mempool_io::MempoolIOLayer,
             StateKeeperLayer,
         },
         tee_verifier_input_producer::TeeVerifierInputProducerLayer,
-        vm_runner::protective_reads::ProtectiveReadsWriterLayer,
+        vm_runner::{
+            bwip::VmRunnerBwipLayer, playground::VmPlaygroundLayer,
+            protective_reads::ProtectiveReadsWriterLayer,
+        },
         web3_api::{
             caches::MempoolCacheLayer,
+            debug::DebugNamespaceLayer,
             server::{Web3ServerLayer, Web3ServerOptionalConfig},
             tree_api_client::TreeApiClientLayer,
             tx_sender::{PostgresStorageCachesConfig, TxSenderLayer},
@@ -48,6 +71,13 @@ use zksync_node_framework::{
     },
     service::{ZkStackService, ZkStackServiceBuilder},
 };
+use zksync_secrets::SecretsResolver;
+
+/// Extra time given to API server tasks on shutdown on top of the configured drain timeout.
+const API_SHUTDOWN_TIMEOUT_MARGIN: Duration = Duration::from_secs(10);
+/// TTL for cached L1 responses for immutable data (blocks, receipts). Kept short to bound the staleness
+/// of cached data in case of an L1 reorg.
+const L1_CLIENT_RESPONSE_CACHE_TTL: Duration = Duration::from_secs(10);
 
 /// Macro that looks into a path to fetch an optional config,
 /// and clones it into a variable.
@@ -65,6 +95,8 @@ pub struct MainNodeBuilder {
     contracts_config: ContractsConfig,
     secrets: Secrets,
     consensus_config: Option<ConsensusConfig>,
+    config_path: Option<PathBuf>,
+    secrets_resolver: Option<SecretsResolver>,
 }
 
 impl MainNodeBuilder {
@@ -84,9 +116,25 @@ impl MainNodeBuilder {
             contracts_config,
             secrets,
             consensus_config,
+            config_path: None,
+            secrets_resolver: None,
         }
     }
 
+    /// Sets the path to the general YAML config. If set, the reloadable part of the config is updated at runtime
+    /// when the file changes.
+    pub fn with_config_path(mut self, config_path: PathBuf) -> Self {
+        self.config_path = Some(config_path);
+        self
+    }
+
+    /// Sets the resolver used to fetch secrets on node start. If rotation tracking is enabled for the resolver,
+    /// the node is stopped once any of the fetched secrets is rotated.
+    pub fn with_secrets_resolver(mut self, resolver: SecretsResolver) -> Self {
+        self.secrets_resolver = Some(resolver);
+        self
+    }
+
     fn add_sigint_handler_layer(mut self) -> anyhow::Result<Self> {
         self.node.add_layer(SigintHandlerLayer);
         Ok(self)
@@ -104,10 +152,52 @@ impl MainNodeBuilder {
         Ok(self)
     }
 
+    fn add_migrations_layer(mut self) -> anyhow::Result<Self> {
+        let config = try_load_config!(self.configs.postgres_config);
+        if let Some(mode) = config.migrations_mode {
+            self.node.add_layer(MigrationsLayer::new(mode));
+        }
+        Ok(self)
+    }
+
+    fn add_config_reloader_layer(mut self) -> anyhow::Result<Self> {
+        let Some(config_path) = self.config_path.clone() else {
+            return Ok(self);
+        };
+        let initial_config = ReloadableConfig::from_general(&self.configs);
+        self.node.add_layer(ConfigReloaderLayer::new(
+            config_path,
+            initial_config,
+            crate::config::parse_reloadable_config,
+        ));
+        Ok(self)
+    }
+
+    fn add_secrets_rotation_layer(mut self) -> anyhow::Result<Self> {
+        let Some(resolver) = self.secrets_resolver.clone() else {
+            return Ok(self);
+        };
+        if resolver.rotation_interval().is_some() {
+            self.node.add_layer(SecretsRotationLayer::new(resolver));
+        }
+        Ok(self)
+    }
+
     fn add_prometheus_exporter_layer(mut self) -> anyhow::Result<Self> {
         let prom_config = try_load_config!(self.configs.prometheus_config);
-        let prom_config = PrometheusExporterConfig::pull(prom_config.listener_port);
-        self.node.add_layer(PrometheusExporterLayer(prom_config));
+        let exporter_config = if prom_config.push_gateway_enabled {
+            let instance = prom_config.push_instance();
+            let endpoint = push_gateway_endpoint(
+                &prom_config.pushgateway_url,
+                prom_config.push_job_name(),
+                [("instance", instance.as_str())],
+            );
+            PrometheusExporterConfig::push(endpoint, prom_config.push_interval())
+        } else {
+            PrometheusExporterConfig::pull(prom_config.listener_port)
+        };
+        self.node
+            .add_layer(PrometheusExporterLayer(exporter_config));
         Ok(self)
     }
 
@@ -127,7 +217,9 @@ impl MainNodeBuilder {
         let genesis = self.genesis_config.clone();
         let eth_config = try_load_config!(self.secrets.l1);
         let query_eth_client_layer =
-            QueryEthClientLayer::new(genesis.l1_chain_id, eth_config.l1_rpc_url);
+            QueryEthClientLayer::new(genesis.l1_chain_id, eth_config.l1_rpc_url)
+                .with_fallback_urls(eth_config.fallback_l1_rpc_urls)
+                .with_response_cache(L1_CLIENT_RESPONSE_CACHE_TTL);
         self.node.add_layer(query_eth_client_layer);
         Ok(self)
     }
@@ -148,6 +240,22 @@ impl MainNodeBuilder {
         Ok(self)
     }
 
+    fn add_base_token_ratio_persister_layer(mut self) -> anyhow::Result<Self> {
+        let config = try_load_config!(self.configs.base_token_price_config);
+        self.node
+            .add_layer(BaseTokenRatioPersisterLayer::new(config));
+        Ok(self)
+    }
+
+    /// Adds the base token ratio provider if the chain uses a non-ETH base token (i.e., the base token price config is present).
+    fn add_base_token_ratio_provider_layer(mut self) -> anyhow::Result<Self> {
+        if let Some(config) = self.configs.base_token_price_config.clone() {
+            self.node
+                .add_layer(BaseTokenRatioProviderLayer::new(config));
+        }
+        Ok(self)
+    }
+
     fn add_object_store_layer(mut self) -> anyhow::Result<Self> {
         let object_store_config = try_load_config!(self.configs.core_object_store);
         self.node
@@ -159,10 +267,15 @@ impl MainNodeBuilder {
         let merkle_tree_env_config = try_load_config!(self.configs.db_config).merkle_tree;
         let operations_manager_env_config =
             try_load_config!(self.configs.operations_manager_config);
-        let metadata_calculator_config = MetadataCalculatorConfig::for_main_node(
+        let mut metadata_calculator_config = MetadataCalculatorConfig::for_main_node(
             &merkle_tree_env_config,
             &operations_manager_env_config,
         );
+        metadata_calculator_config.sealed_batches_have_protective_reads = self
+            .configs
+            .state_keeper_config
+            .as_ref()
+            .map_or(true, |config| config.protective_reads_persistence_enabled);
         let mut layer = MetadataCalculatorLayer::new(metadata_calculator_config);
         if with_tree_api {
             let merkle_tree_api_config = try_load_config!(self.configs.api_config).merkle_tree;
@@ -215,6 +328,33 @@ impl MainNodeBuilder {
         Ok(self)
     }
 
+    fn add_admin_server_layer(mut self) -> anyhow::Result<Self> {
+        let Some(observability_config) = &self.configs.observability else {
+            return Ok(self);
+        };
+        if let Some(port) = observability_config.admin_port {
+            let layer = AdminServerLayer::new(port)
+                .with_profiling(observability_config.admin_profiling_enabled)
+                .with_config_diff(self.configs.clone());
+            self.node.add_layer(layer);
+        }
+        Ok(self)
+    }
+
+    fn add_slo_monitor_layer(mut self) -> anyhow::Result<Self> {
+        let Some(observability_config) = &self.configs.observability else {
+            return Ok(self);
+        };
+        let mut slo_config = observability_config.slo.clone();
+        if slo_config.max_sync_lag.take().is_some() {
+            tracing::warn!("Sync lag SLO is only applicable to external nodes; ignoring it");
+        }
+        if !slo_config.is_empty() {
+            self.node.add_layer(SloMonitorLayer::new(slo_config));
+        }
+        Ok(self)
+    }
+
     fn add_tx_sender_layer(mut self) -> anyhow::Result<Self> {
         let sk_config = try_load_config!(self.configs.state_keeper_config);
         let rpc_config = try_load_config!(self.configs.api_config).web3_json_rpc;
@@ -263,10 +403,11 @@ impl MainNodeBuilder {
         let state_keeper_config = try_load_config!(self.configs.state_keeper_config);
         let with_debug_namespace = state_keeper_config.save_call_traces;
 
-        let mut namespaces = Namespace::DEFAULT.to_vec();
         if with_debug_namespace {
-            namespaces.push(Namespace::Debug)
+            // The debug namespace is enabled on the server if the layer is present.
+            self.node.add_layer(DebugNamespaceLayer::default());
         }
+        let mut namespaces = Namespace::DEFAULT.to_vec();
         namespaces.push(Namespace::Snapshots);
 
         let optional_config = Web3ServerOptionalConfig {
@@ -275,6 +416,7 @@ impl MainNodeBuilder {
             subscriptions_limit: Some(rpc_config.subscriptions_limit()),
             batch_request_size_limit: Some(rpc_config.max_batch_request_size()),
             response_body_size_limit: Some(rpc_config.max_response_body_size()),
+            shutdown_drain_timeout: Some(rpc_config.shutdown_drain_timeout()),
             ..Default::default()
         };
         self.node.add_layer(Web3ServerLayer::http(
@@ -292,22 +434,29 @@ impl MainNodeBuilder {
         let circuit_breaker_config = try_load_config!(self.configs.circuit_breaker_config);
         let with_debug_namespace = state_keeper_config.save_call_traces;
 
-        let mut namespaces = Namespace::DEFAULT.to_vec();
         if with_debug_namespace {
-            namespaces.push(Namespace::Debug)
+            // The debug namespace is enabled on the server if the layer is present.
+            self.node.add_layer(DebugNamespaceLayer::default());
         }
+        let mut namespaces = Namespace::DEFAULT.to_vec();
         namespaces.push(Namespace::Snapshots);
 
         let optional_config = Web3ServerOptionalConfig {
             namespaces: Some(namespaces),
             filters_limit: Some(rpc_config.filters_limit()),
             subscriptions_limit: Some(rpc_config.subscriptions_limit()),
+            subscriptions_per_connection_limit: Some(
+                rpc_config.subscriptions_per_connection_limit(),
+            ),
+            subscription_backlog_size: Some(rpc_config.subscription_backlog_size()),
             batch_request_size_limit: Some(rpc_config.max_batch_request_size()),
             response_body_size_limit: Some(rpc_config.max_response_body_size()),
             websocket_requests_per_minute_limit: Some(
                 rpc_config.websocket_requests_per_minute_limit(),
             ),
+            shutdown_drain_timeout: Some(rpc_config.shutdown_drain_timeout()),
             replication_lag_limit: circuit_breaker_config.replication_lag_limit(),
+            ..Default::default()
         };
         self.node.add_layer(Web3ServerLayer::ws(
             rpc_config.ws_port,
@@ -359,9 +508,17 @@ impl MainNodeBuilder {
     }
 
     fn add_commitment_generator_layer(mut self) -> anyhow::Result<Self> {
-        self.node.add_layer(CommitmentGeneratorLayer::new(
-            self.genesis_config.l1_batch_commit_data_generator_mode,
-        ));
+        let mut layer =
+            CommitmentGeneratorLayer::new(self.genesis_config.l1_batch_commit_data_generator_mode);
+        if let Some(max_parallelism) = self
+            .configs
+            .commitment_generator_config
+            .as_ref()
+            .and_then(|config| config.max_parallelism)
+        {
+            layer = layer.with_max_parallelism(max_parallelism);
+        }
+        self.node.add_layer(layer);
 
         Ok(self)
     }
@@ -385,6 +542,11 @@ impl MainNodeBuilder {
             mode: ConsensusMode::Main,
             config: self.consensus_config.clone(),
             secrets: self.secrets.consensus.clone(),
+            registry_rpc_url: self
+                .configs
+                .api_config
+                .as_ref()
+                .map(|api| api.web3_json_rpc.http_url.clone()),
         });
 
         Ok(self)
@@ -409,16 +571,102 @@ impl MainNodeBuilder {
         Ok(self)
     }
 
+    fn add_vm_runner_bwip_layer(mut self) -> anyhow::Result<Self> {
+        let basic_witness_input_producer_config =
+            try_load_config!(self.configs.basic_witness_input_producer_config);
+        self.node.add_layer(VmRunnerBwipLayer::new(
+            basic_witness_input_producer_config,
+            self.genesis_config.l2_chain_id,
+        ));
+
+        Ok(self)
+    }
+
+    fn add_vm_playground_layer(mut self) -> anyhow::Result<Self> {
+        let vm_playground_config = try_load_config!(self.configs.vm_playground_config);
+        self.node.add_layer(VmPlaygroundLayer::new(
+            vm_playground_config,
+            self.genesis_config.l2_chain_id,
+        ));
+
+        Ok(self)
+    }
+
+    fn add_da_client_layer(mut self) -> anyhow::Result<Self> {
+        let da_client_config = try_load_config!(self.configs.da_client_config);
+        match da_client_config.client {
+            DAClient::Mantle(config) => {
+                self.node.add_layer(MantleWiringLayer::new(config));
+            }
+            DAClient::EigenDA(config) => {
+                self.node.add_layer(EigenWiringLayer::new(config));
+            }
+            DAClient::Celestia(config) => {
+                self.node.add_layer(CelestiaWiringLayer::new(config));
+            }
+            DAClient::Avail(config) => {
+                self.node.add_layer(AvailWiringLayer::new(config));
+            }
+            DAClient::ObjectStore(config) => {
+                self.node
+                    .add_layer(ObjectStorageClientWiringLayer::new(config));
+            }
+        }
+        if let Some(shadow_client) = da_client_config.shadow_client {
+            self.node
+                .add_layer(ShadowDAClientWiringLayer::new(shadow_client));
+        }
+
+        Ok(self)
+    }
+
+    fn add_da_dispatcher_layer(mut self) -> anyhow::Result<Self> {
+        let eth_sender_config = try_load_config!(self.configs.eth);
+        if let Some(sender_config) = eth_sender_config.sender {
+            anyhow::ensure!(
+                sender_config.pubdata_sending_mode == PubdataSendingMode::Custom,
+                "DA dispatcher requires the custom pubdata sending mode"
+            );
+        }
+
+        let state_keeper_config = try_load_config!(self.configs.state_keeper_config);
+        let da_config = try_load_config!(self.configs.da_dispatcher_config);
+        self.node.add_layer(DataAvailabilityDispatcherLayer::new(
+            state_keeper_config,
+            da_config,
+        ));
+
+        Ok(self)
+    }
+
+    fn add_snapshots_creator_layer(mut self) -> anyhow::Result<Self> {
+        let config = try_load_config!(self.configs.snapshot_creator);
+        self.node.add_layer(SnapshotsCreatorLayer::new(config));
+        Ok(self)
+    }
+
     pub fn build(mut self, mut components: Vec<Component>) -> anyhow::Result<ZkStackService> {
         // Add "base" layers (resources and helper tasks).
         self = self
             .add_sigint_handler_layer()?
             .add_pools_layer()?
+            .add_migrations_layer()?
+            .add_config_reloader_layer()?
+            .add_secrets_rotation_layer()?
             .add_object_store_layer()?
             .add_circuit_breaker_checker_layer()?
             .add_healthcheck_layer()?
+            .add_admin_server_layer()?
+            .add_slo_monitor_layer()?
             .add_prometheus_exporter_layer()?
-            .add_query_eth_client_layer()?
+            .add_query_eth_client_layer()?;
+        // The base token ratio is used by the fee model, so the persister (which stores the initial ratio during wiring)
+        // and the provider must be added before the L1 gas layer.
+        if components.contains(&Component::BaseTokenRatioPersister) {
+            self = self.add_base_token_ratio_persister_layer()?;
+        }
+        self = self
+            .add_base_token_ratio_provider_layer()?
             .add_sequencer_l1_gas_layer()?;
 
         // Sort the components, so that the components they may depend on each other are added in the correct order.
@@ -429,6 +677,17 @@ impl MainNodeBuilder {
             _ => 0,
         });
 
+        if components
+            .iter()
+            .any(|component| matches!(component, Component::HttpApi | Component::WsApi))
+        {
+            // API servers may take up to the drain timeout to shut down, plus some time to stop VM execution.
+            let rpc_config = try_load_config!(self.configs.api_config).web3_json_rpc;
+            self.node.with_task_shutdown_timeout(
+                rpc_config.shutdown_drain_timeout() + API_SHUTDOWN_TIMEOUT_MARGIN,
+            );
+        }
+
         // Add "component-specific" layers.
         // Note that the layers are added only once, so it's fine to add the same layer multiple times.
         for component in &components {
@@ -473,6 +732,21 @@ impl MainNodeBuilder {
                     self = self.add_eth_tx_manager_layer()?;
                 }
                 Component::StateKeeper => {
+                    let protective_reads_persistence_enabled = self
+                        .configs
+                        .state_keeper_config
+                        .as_ref()
+                        .map_or(true, |config| config.protective_reads_persistence_enabled);
+                    if !protective_reads_persistence_enabled
+                        && !components.contains(&Component::VmRunnerProtectiveReads)
+                    {
+                        // The protective reads writer may be run by a separate server instance, so this isn't an error.
+                        tracing::warn!(
+                            "Protective reads persistence is disabled for state keeper, but the protective reads writer \
+                             component is not enabled for this server; make sure it runs elsewhere, since the Merkle tree \
+                             waits for protective reads to be written"
+                        );
+                    }
                     self = self.add_state_keeper_layer()?;
                 }
                 Component::TeeVerifierInputProducer => {
@@ -493,6 +767,21 @@ impl MainNodeBuilder {
                 Component::VmRunnerProtectiveReads => {
                     self = self.add_vm_runner_protective_reads_layer()?;
                 }
+                Component::VmRunnerBwip => {
+                    self = self.add_vm_runner_bwip_layer()?;
+                }
+                Component::VmPlayground => {
+                    self = self.add_vm_playground_layer()?;
+                }
+                Component::DADispatcher => {
+                    self = self.add_da_client_layer()?.add_da_dispatcher_layer()?;
+                }
+                Component::BaseTokenRatioPersister => {
+                    // Do nothing, will be handled together with the base layers.
+                }
+                Component::SnapshotsCreator => {
+                    self = self.add_snapshots_creator_layer()?;
+                }
             }
         }
         Ok(self.node.build()?)
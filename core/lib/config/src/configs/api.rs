@@ -158,6 +158,11 @@ pub struct Web3JsonRpcConfig {
     pub filters_limit: Option<u32>,
     /// Max possible limit of subscriptions to be in the state at once.
     pub subscriptions_limit: Option<u32>,
+    /// Max number of WebSocket subscriptions a single connection can have open at once. Default is 1024.
+    pub subscriptions_per_connection_limit: Option<u32>,
+    /// Number of recent notifications of each subscription type retained by the WebSocket server so that
+    /// subscriptions can be resumed with `zks_resubscribe` after a reconnect. Default is 1024; 0 disables resumption.
+    pub subscription_backlog_size: Option<u32>,
     /// Interval between polling db for pubsub (in ms).
     pub pubsub_polling_interval: Option<u64>,
     /// Tx nonce: how far ahead from the committed nonce can it be.
@@ -191,6 +196,9 @@ pub struct Web3JsonRpcConfig {
     pub latest_values_cache_size_mb: Option<usize>,
     /// Limit for fee history block range.
     pub fee_history_limit: Option<u64>,
+    /// Max number of L2 blocks that can be queried by a single `eth_getLogs` call.
+    /// If not set, the block range is not limited (the number of returned logs is still limited by `req_entities_limit`).
+    pub max_logs_block_range: Option<u32>,
     /// Maximum number of requests in a single batch JSON RPC request. Default is 500.
     pub max_batch_request_size: Option<usize>,
     /// Maximum response body size in MiBs. Default is 10 MiB.
@@ -213,6 +221,28 @@ pub struct Web3JsonRpcConfig {
     /// (additionally to natively bridged tokens).
     #[serde(default)]
     pub whitelisted_tokens_for_aa: Vec<Address>,
+    /// Whether the chain runs the EVM emulator alongside EraVM. If disabled, transactions deploying EVM bytecode
+    /// are rejected by the API server. Default is `false`.
+    #[serde(default)]
+    pub evm_emulator_enabled: bool,
+    /// Maximum number of pending (i.e., not included into an L2 block) transactions per initiator account.
+    /// Transactions exceeding this limit are rejected before insertion into the mempool. If not set, the number
+    /// of pending transactions is only limited by `max_nonce_ahead`.
+    pub max_pending_txs_per_initiator: Option<u32>,
+    /// Minimum `max_fee_per_gas` (in wei) accepted for L2 transactions, in addition to the fair L2 gas price
+    /// required by the fee model. If not set, only the fee model requirement applies.
+    pub min_max_fee_per_gas: Option<u64>,
+    /// Initiator addresses whose transactions are rejected by the API server.
+    #[serde(default)]
+    pub denied_initiators: Vec<Address>,
+    /// Max number of `eth_call` results on historical blocks cached by the API server. Calls on the pending
+    /// or latest block are never cached. If not set or set to 0, results are not cached.
+    pub eth_call_cache_size: Option<usize>,
+    /// Time-to-live of cached `eth_call` results (in seconds). The default value is 60 seconds.
+    pub eth_call_cache_ttl_sec: Option<u64>,
+    /// Maximum period (in seconds) the API servers wait on shutdown for the traffic to stop and for in-flight
+    /// requests and WebSocket sessions to complete. The default value is 30 seconds.
+    pub shutdown_drain_timeout_sec: Option<u64>,
 }
 
 impl Web3JsonRpcConfig {
@@ -229,6 +259,8 @@ impl Web3JsonRpcConfig {
             filters_disabled: false,
             filters_limit: Some(10000),
             subscriptions_limit: Some(10000),
+            subscriptions_per_connection_limit: Default::default(),
+            subscription_backlog_size: Default::default(),
             pubsub_polling_interval: Some(200),
             max_nonce_ahead: 50,
             gas_price_scale_factor: 1.2,
@@ -243,6 +275,7 @@ impl Web3JsonRpcConfig {
             initial_writes_cache_size_mb: Default::default(),
             latest_values_cache_size_mb: Default::default(),
             fee_history_limit: Default::default(),
+            max_logs_block_range: Default::default(),
             max_batch_request_size: Default::default(),
             max_response_body_size_mb: Default::default(),
             max_response_body_size_overrides_mb: MaxResponseSizeOverrides::empty(),
@@ -251,6 +284,13 @@ impl Web3JsonRpcConfig {
             mempool_cache_size: Default::default(),
             tree_api_url: None,
             whitelisted_tokens_for_aa: Default::default(),
+            evm_emulator_enabled: false,
+            max_pending_txs_per_initiator: None,
+            min_max_fee_per_gas: None,
+            denied_initiators: vec![],
+            eth_call_cache_size: None,
+            eth_call_cache_ttl_sec: None,
+            shutdown_drain_timeout_sec: None,
         }
     }
 
@@ -274,6 +314,14 @@ impl Web3JsonRpcConfig {
         self.subscriptions_limit.unwrap_or(10000) as usize
     }
 
+    pub fn subscriptions_per_connection_limit(&self) -> usize {
+        self.subscriptions_per_connection_limit.unwrap_or(1024) as usize
+    }
+
+    pub fn subscription_backlog_size(&self) -> usize {
+        self.subscription_backlog_size.unwrap_or(1024) as usize
+    }
+
     pub fn pubsub_interval(&self) -> Duration {
         Duration::from_millis(self.pubsub_polling_interval.unwrap_or(200))
     }
@@ -308,6 +356,14 @@ impl Web3JsonRpcConfig {
         self.latest_values_cache_size_mb.unwrap_or(128) * super::BYTES_IN_MEGABYTE
     }
 
+    pub fn eth_call_cache_ttl(&self) -> Duration {
+        Duration::from_secs(self.eth_call_cache_ttl_sec.unwrap_or(60))
+    }
+
+    pub fn shutdown_drain_timeout(&self) -> Duration {
+        Duration::from_secs(self.shutdown_drain_timeout_sec.unwrap_or(30))
+    }
+
     pub fn fee_history_limit(&self) -> u64 {
         self.fee_history_limit.unwrap_or(1024)
     }
@@ -0,0 +1,145 @@
+use std::time::Duration;
+
+use serde::Deserialize;
+use zksync_basic_types::Address;
+
+pub const DEFAULT_POLLING_INTERVAL_MS: u64 = 30_000;
+pub const DEFAULT_RATIO_CACHE_UPDATE_INTERVAL_MS: u64 = 5_000;
+pub const DEFAULT_MAX_DEVIATION_BPS: u32 = 500;
+pub const DEFAULT_MAX_STALENESS_SECS: u64 = 600;
+pub const DEFAULT_MIN_AGREEING_SOURCES: u32 = 1;
+pub const DEFAULT_BASE_TOKEN_DECIMALS: u8 = 18;
+pub const DEFAULT_COINGECKO_API_URL: &str = "https://api.coingecko.com";
+pub const DEFAULT_COINGECKO_PLATFORM_ID: &str = "ethereum";
+pub const DEFAULT_TWAP_WINDOW_SECS: u32 = 1_800;
+
+/// Configuration of the base token price fetcher, which maintains the ratio used to convert fees
+/// from ETH to the base token for chains with a non-ETH base token.
+///
+/// Each of the sources is optional, but at least one must be configured. Quotes from all sources
+/// are cross-checked against their median; quotes deviating too much are discarded.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct BaseTokenPriceConfig {
+    /// Interval between the price fetcher's iterations.
+    pub polling_interval_ms: Option<u64>,
+    /// Interval at which components using the base token ratio reload the ratio persisted in Postgres.
+    pub ratio_cache_update_interval_ms: Option<u64>,
+    /// Maximum deviation of a single source quote from the median of all quotes, in basis points.
+    pub max_deviation_bps: Option<u32>,
+    /// Maximum age of a quote (and of the currently used ratio) for it to be considered fresh.
+    pub max_staleness_secs: Option<u64>,
+    /// Minimum number of sources that must agree within `max_deviation_bps` for the ratio to be updated.
+    pub min_agreeing_sources: Option<u32>,
+    /// Number of decimals of the base token; used to convert prices of whole tokens into the ratio
+    /// between the smallest units of the base token and wei.
+    pub base_token_decimals: Option<u8>,
+    #[serde(skip)]
+    pub fixed: Option<FixedRatioSourceConfig>,
+    #[serde(skip)]
+    pub coingecko: Option<CoingeckoSourceConfig>,
+    #[serde(skip)]
+    pub uniswap_v3_twap: Option<UniswapV3TwapSourceConfig>,
+}
+
+impl BaseTokenPriceConfig {
+    pub fn for_tests() -> Self {
+        Self {
+            polling_interval_ms: Some(DEFAULT_POLLING_INTERVAL_MS),
+            ratio_cache_update_interval_ms: Some(DEFAULT_RATIO_CACHE_UPDATE_INTERVAL_MS),
+            max_deviation_bps: Some(DEFAULT_MAX_DEVIATION_BPS),
+            max_staleness_secs: Some(DEFAULT_MAX_STALENESS_SECS),
+            min_agreeing_sources: Some(DEFAULT_MIN_AGREEING_SOURCES),
+            base_token_decimals: Some(DEFAULT_BASE_TOKEN_DECIMALS),
+            fixed: Some(FixedRatioSourceConfig {
+                numerator: 1,
+                denominator: 1,
+            }),
+            coingecko: None,
+            uniswap_v3_twap: None,
+        }
+    }
+
+    pub fn polling_interval(&self) -> Duration {
+        Duration::from_millis(
+            self.polling_interval_ms
+                .unwrap_or(DEFAULT_POLLING_INTERVAL_MS),
+        )
+    }
+
+    pub fn ratio_cache_update_interval(&self) -> Duration {
+        Duration::from_millis(
+            self.ratio_cache_update_interval_ms
+                .unwrap_or(DEFAULT_RATIO_CACHE_UPDATE_INTERVAL_MS),
+        )
+    }
+
+    pub fn max_deviation_bps(&self) -> u32 {
+        self.max_deviation_bps.unwrap_or(DEFAULT_MAX_DEVIATION_BPS)
+    }
+
+    pub fn max_staleness(&self) -> Duration {
+        Duration::from_secs(
+            self.max_staleness_secs
+                .unwrap_or(DEFAULT_MAX_STALENESS_SECS),
+        )
+    }
+
+    pub fn min_agreeing_sources(&self) -> u32 {
+        self.min_agreeing_sources
+            .unwrap_or(DEFAULT_MIN_AGREEING_SOURCES)
+    }
+
+    pub fn base_token_decimals(&self) -> u8 {
+        self.base_token_decimals
+            .unwrap_or(DEFAULT_BASE_TOKEN_DECIMALS)
+    }
+}
+
+/// Fixed ratio between the smallest units of the base token and wei, e.g. for tests or for tokens
+/// pegged to ETH.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct FixedRatioSourceConfig {
+    pub numerator: u64,
+    pub denominator: u64,
+}
+
+/// Coingecko-compatible price API returning the price of the base token in ETH.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct CoingeckoSourceConfig {
+    /// Base URL of the API. Defaults to the public Coingecko API.
+    pub api_url: Option<String>,
+    /// API key sent in the `x-cg-pro-api-key` header, if any.
+    pub api_key: Option<String>,
+    /// Platform on which `token_address` is deployed. Defaults to `ethereum`.
+    pub platform_id: Option<String>,
+    /// Address of the base token on the platform.
+    pub token_address: Address,
+}
+
+impl CoingeckoSourceConfig {
+    pub fn api_url(&self) -> &str {
+        self.api_url.as_deref().unwrap_or(DEFAULT_COINGECKO_API_URL)
+    }
+
+    pub fn platform_id(&self) -> &str {
+        self.platform_id
+            .as_deref()
+            .unwrap_or(DEFAULT_COINGECKO_PLATFORM_ID)
+    }
+}
+
+/// On-chain time-weighted average price from a Uniswap V3 pool of the base token and WETH on L1.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct UniswapV3TwapSourceConfig {
+    pub pool_address: Address,
+    /// Whether the base token is `token0` of the pool (i.e., has the lower address of the pair).
+    pub base_token_is_token0: bool,
+    /// Window over which the price is averaged.
+    pub window_secs: Option<u32>,
+}
+
+impl UniswapV3TwapSourceConfig {
+    pub fn window_secs(&self) -> u32 {
+        self.window_secs.unwrap_or(DEFAULT_TWAP_WINDOW_SECS)
+    }
+}
@@ -120,6 +120,11 @@ pub struct StateKeeperConfig {
     /// the recursion layers' circuits.
     pub max_circuits_per_batch: usize,
 
+    /// Whether the state keeper should persist protective reads when sealing L1 batches. If disabled,
+    /// protective reads are expected to be computed asynchronously by the protective reads writer (VM runner) component.
+    #[serde(default = "StateKeeperConfig::default_protective_reads_persistence_enabled")]
+    pub protective_reads_persistence_enabled: bool,
+
     // Base system contract hashes, required only for generating genesis config.
     // #PLA-811
     #[deprecated(note = "Use GenesisConfig::bootloader_hash instead")]
@@ -163,11 +168,16 @@ impl StateKeeperConfig {
             validation_computational_gas_limit: 300000,
             save_call_traces: true,
             max_circuits_per_batch: 24100,
+            protective_reads_persistence_enabled: true,
             bootloader_hash: None,
             default_aa_hash: None,
             l1_batch_commit_data_generator_mode: L1BatchCommitmentMode::Rollup,
         }
     }
+
+    pub const fn default_protective_reads_persistence_enabled() -> bool {
+        true
+    }
 }
 
 #[derive(Debug, Deserialize, Clone, PartialEq)]
@@ -0,0 +1,11 @@
+use std::num::NonZeroU32;
+
+use serde::Deserialize;
+
+/// Configuration for the commitment generator on the main node.
+#[derive(Debug, Clone, PartialEq, Default, Deserialize)]
+pub struct CommitmentGeneratorConfig {
+    /// Maximum number of L1 batches processed by the generator concurrently. If not set, it's determined
+    /// based on the number of CPU cores available. Also determines the size of the connection pool used by the generator.
+    pub max_parallelism: Option<NonZeroU32>,
+}
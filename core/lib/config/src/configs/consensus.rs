@@ -1,7 +1,10 @@
-use std::collections::{BTreeMap, BTreeSet};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::PathBuf,
+};
 
 use secrecy::{ExposeSecret as _, Secret};
-use zksync_basic_types::L2ChainId;
+use zksync_basic_types::{Address, L2ChainId};
 
 /// `zksync_consensus_crypto::TextFmt` representation of `zksync_consensus_roles::validator::PublicKey`.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -19,18 +22,47 @@ pub struct NodePublicKey(pub String);
 #[derive(Debug, Clone)]
 pub struct NodeSecretKey(pub Secret<String>);
 
+/// Hex representation of the secp256k1 secret key used by the attester to sign L1 batches.
+#[derive(Debug, Clone)]
+pub struct AttesterSecretKey(pub Secret<String>);
+
 impl PartialEq for ValidatorSecretKey {
     fn eq(&self, other: &Self) -> bool {
         self.0.expose_secret().eq(other.0.expose_secret())
     }
 }
 
+impl PartialEq for AttesterSecretKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.expose_secret().eq(other.0.expose_secret())
+    }
+}
+
 impl PartialEq for NodeSecretKey {
     fn eq(&self, other: &Self) -> bool {
         self.0.expose_secret().eq(other.0.expose_secret())
     }
 }
 
+/// Encrypted keystore holding the consensus secret keys, so that they are not stored
+/// in plaintext at rest. The keystore password is never stored in the config: it is read
+/// from `password_file` or, if it's not set, from the [`Self::PASSWORD_ENV_VAR`] env variable.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConsensusKeystore {
+    /// Path to the keystore file.
+    pub path: PathBuf,
+    /// Keystore files to fail over to (in order) if the primary keystore cannot be read or decrypted.
+    /// They must hold the same keys as the primary keystore.
+    pub fallback_paths: Vec<PathBuf>,
+    /// Path to the file containing the password to decrypt the keystore with.
+    pub password_file: Option<PathBuf>,
+}
+
+impl ConsensusKeystore {
+    /// Env variable to read the keystore password from if `password_file` is not set.
+    pub const PASSWORD_ENV_VAR: &'static str = "CONSENSUS_KEYSTORE_PASSWORD";
+}
+
 /// Copy-paste of `zksync_consensus_roles::validator::WeightedValidator`.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct WeightedValidator {
@@ -65,6 +97,26 @@ pub struct GenesisSpec {
     pub leader: ValidatorPublicKey,
 }
 
+/// Per-peer limits on the inbound gossip traffic. Limits are enforced on the TCP level
+/// in front of the gossip server, so peers are identified by their IP address.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GossipRateLimitConfig {
+    /// Maximal sustained inbound traffic from a single peer.
+    pub max_inbound_bytes_per_sec: u64,
+    /// Maximal inbound burst from a single peer.
+    pub max_inbound_burst_bytes: u64,
+    /// Maximal number of concurrent inbound connections from a single peer.
+    pub max_connections_per_peer: usize,
+    /// Duration of the ban of a peer whose score has dropped to zero.
+    pub ban_duration_sec: u64,
+}
+
+impl GossipRateLimitConfig {
+    pub fn ban_duration(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(self.ban_duration_sec)
+    }
+}
+
 /// Config (shared between main node and external node).
 #[derive(Clone, Debug, PartialEq)]
 pub struct ConsensusConfig {
@@ -87,6 +139,16 @@ pub struct ConsensusConfig {
     /// establish and maintain.
     pub gossip_static_outbound: BTreeMap<NodePublicKey, Host>,
 
+    /// Address of the consensus registry contract on L2.
+    /// If set, validator and attester committees are read from the registry
+    /// at L1 batch boundaries rather than taken from the static config.
+    pub registry_address: Option<Address>,
+    /// Local socket address of the peer management admin API.
+    /// The API is not started if not set. It should not be exposed publicly.
+    pub admin_addr: Option<std::net::SocketAddr>,
+    /// Per-peer limits on the inbound gossip traffic. No limits are enforced if not set.
+    pub gossip_rate_limit: Option<GossipRateLimitConfig>,
+
     /// MAIN NODE ONLY: consensus genesis specification.
     /// Used to (re)initialize genesis if needed.
     /// External nodes fetch the genesis from the main node.
@@ -98,4 +160,8 @@ pub struct ConsensusConfig {
 pub struct ConsensusSecrets {
     pub validator_key: Option<ValidatorSecretKey>,
     pub node_key: Option<NodeSecretKey>,
+    pub attester_key: Option<AttesterSecretKey>,
+    /// Keystore to read the secret keys from. Keys present in the keystore
+    /// must not be set directly in the secrets.
+    pub keystore: Option<ConsensusKeystore>,
 }
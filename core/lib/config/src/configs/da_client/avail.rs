@@ -0,0 +1,32 @@
+use std::time::Duration;
+
+use serde::Deserialize;
+
+/// Configuration of the Avail client.
+///
+/// Blobs are submitted via the HTTP API of an Avail light client; data proofs are fetched
+/// from the Avail bridge API once the Avail block is bridged to L1.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct AvailConfig {
+    /// URL of the Avail light client HTTP API, e.g. `http://localhost:7007`.
+    pub api_node_url: String,
+    /// URL of the Avail bridge API used to fetch data proofs.
+    pub bridge_api_url: String,
+    /// Application ID the data is submitted with. Must match the app ID the light client is configured with.
+    pub app_id: u32,
+    /// Timeout for a single request to the light client or the bridge API.
+    #[serde(default = "AvailConfig::default_request_timeout_ms")]
+    pub request_timeout_ms: u64,
+    /// Maximum size of a single blob accepted by the Avail network.
+    pub max_blob_size_bytes: Option<usize>,
+}
+
+impl AvailConfig {
+    pub const fn default_request_timeout_ms() -> u64 {
+        60_000
+    }
+
+    pub fn request_timeout(&self) -> Duration {
+        Duration::from_millis(self.request_timeout_ms)
+    }
+}
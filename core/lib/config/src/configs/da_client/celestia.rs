@@ -0,0 +1,37 @@
+use std::time::Duration;
+
+use serde::Deserialize;
+
+/// Configuration of the Celestia client.
+///
+/// Blobs are submitted to a Celestia light or bridge node via its JSON-RPC API.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct CelestiaConfig {
+    /// URL of the celestia-node JSON-RPC API, e.g. `http://localhost:26658`.
+    pub api_node_url: String,
+    /// Auth token for the celestia-node API (with at least `write` permissions).
+    pub auth_token: Option<String>,
+    /// Hex-encoded ID of the version 0 namespace the blobs are submitted to (up to 10 bytes).
+    pub namespace: String,
+    /// Gas price (in utia) used for blob submission transactions. If not set, the node estimates the gas price.
+    pub gas_price: Option<f64>,
+    /// Number of Celestia blocks on top of the inclusion block required before the blob is considered included.
+    #[serde(default)]
+    pub inclusion_confirmations: u64,
+    /// Timeout for a single request to the node. Blob submission waits for the transaction
+    /// to be included into a block, so the timeout should exceed the Celestia block time.
+    #[serde(default = "CelestiaConfig::default_request_timeout_ms")]
+    pub request_timeout_ms: u64,
+    /// Maximum size of a single blob accepted by the Celestia network.
+    pub max_blob_size_bytes: Option<usize>,
+}
+
+impl CelestiaConfig {
+    pub const fn default_request_timeout_ms() -> u64 {
+        60_000
+    }
+
+    pub fn request_timeout(&self) -> Duration {
+        Duration::from_millis(self.request_timeout_ms)
+    }
+}
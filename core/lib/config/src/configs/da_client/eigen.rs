@@ -0,0 +1,37 @@
+use std::time::Duration;
+
+use serde::Deserialize;
+
+/// Configuration of the EigenDA client.
+///
+/// Blobs are dispersed through the EigenDA disperser gRPC API; the blob status is then polled
+/// until the blob batch is confirmed on L1 by the EigenDA service manager.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct EigenDAConfig {
+    /// URL of the EigenDA disperser gRPC endpoint, e.g. `https://disperser-holesky.eigenda.xyz:443`.
+    pub disperser_rpc: String,
+    /// Timeout for a single request to the disperser.
+    #[serde(default = "EigenDAConfig::default_request_timeout_ms")]
+    pub request_timeout_ms: u64,
+    /// Custom quorums the blob should be dispersed to, in addition to the required ones.
+    #[serde(default)]
+    pub custom_quorum_numbers: Vec<u32>,
+    /// Account ID used for authenticated dispersal; if not set, dispersal is unauthenticated.
+    pub account_id: Option<String>,
+    /// Whether to wait for the blob batch to be finalized on L1 before considering the blob included.
+    /// If not set, confirmation is enough.
+    #[serde(default)]
+    pub wait_for_finalization: bool,
+    /// Maximum size of a single blob (before encoding) accepted by the disperser.
+    pub max_blob_size_bytes: Option<usize>,
+}
+
+impl EigenDAConfig {
+    pub const fn default_request_timeout_ms() -> u64 {
+        60_000
+    }
+
+    pub fn request_timeout(&self) -> Duration {
+        Duration::from_millis(self.request_timeout_ms)
+    }
+}
@@ -0,0 +1,32 @@
+use std::time::Duration;
+
+use serde::Deserialize;
+
+/// Configuration of the Mantle DA client.
+///
+/// Blobs are submitted through the Mantle DA proxy, which disperses them to the DA network
+/// and reports their confirmation status together with the blob certificate.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct MantleDAConfig {
+    /// URL of the Mantle DA proxy.
+    pub api_node_url: String,
+    /// Timeout for a single request to the proxy.
+    #[serde(default = "MantleDAConfig::default_request_timeout_ms")]
+    pub request_timeout_ms: u64,
+    /// Whether to wait for the blob batch to be finalized on the DA network before
+    /// considering the blob included. If not set, confirmation is enough.
+    #[serde(default)]
+    pub wait_for_finalization: bool,
+    /// Maximum size of a single blob accepted by the DA network.
+    pub max_blob_size_bytes: Option<usize>,
+}
+
+impl MantleDAConfig {
+    pub const fn default_request_timeout_ms() -> u64 {
+        30_000
+    }
+
+    pub fn request_timeout(&self) -> Duration {
+        Duration::from_millis(self.request_timeout_ms)
+    }
+}
@@ -0,0 +1,40 @@
+use serde::Deserialize;
+
+use crate::{
+    configs::da_client::{
+        avail::AvailConfig, celestia::CelestiaConfig, eigen::EigenDAConfig, mantle::MantleDAConfig,
+    },
+    ObjectStoreConfig,
+};
+
+pub mod avail;
+pub mod celestia;
+pub mod eigen;
+pub mod mantle;
+
+pub const MANTLE_CLIENT_CONFIG_NAME: &str = "Mantle";
+pub const EIGEN_CLIENT_CONFIG_NAME: &str = "EigenDA";
+pub const CELESTIA_CLIENT_CONFIG_NAME: &str = "Celestia";
+pub const AVAIL_CLIENT_CONFIG_NAME: &str = "Avail";
+pub const OBJECT_STORE_CLIENT_CONFIG_NAME: &str = "ObjectStore";
+
+/// Configuration of the client used by the DA dispatcher to send pubdata to the DA layer.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct DAClientConfig {
+    pub client: DAClient,
+    /// Optional shadow DA layer. If set, the DA dispatcher dispatches pubdata to both layers; only the primary
+    /// layer (`client`) is used for the inclusion data committed to L1. This allows migrating to another DA layer
+    /// after checking that it keeps up with the primary one.
+    #[serde(default)]
+    pub shadow_client: Option<DAClient>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub enum DAClient {
+    Mantle(MantleDAConfig),
+    EigenDA(EigenDAConfig),
+    Celestia(CelestiaConfig),
+    Avail(AvailConfig),
+    /// Stores blobs in an object store instead of an actual DA layer. Intended for local development only.
+    ObjectStore(ObjectStoreConfig),
+}
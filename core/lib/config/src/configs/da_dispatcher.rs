@@ -0,0 +1,98 @@
+use std::time::Duration;
+
+use serde::Deserialize;
+
+pub const DEFAULT_POLLING_INTERVAL_MS: u32 = 5000;
+pub const DEFAULT_MAX_ROWS_TO_DISPATCH: u32 = 100;
+pub const DEFAULT_MAX_RETRIES: u16 = 5;
+pub const DEFAULT_INITIAL_RETRY_BACKOFF_MS: u64 = 1_000;
+pub const DEFAULT_MAX_RETRY_BACKOFF_MS: u64 = 128_000;
+pub const DEFAULT_MAX_L1_BATCH_AGE_SECS: u64 = 3_600;
+pub const DEFAULT_COST_BUDGET_PERIOD_SECS: u64 = 86_400;
+
+/// Configuration for the DA dispatcher, which sends pubdata to the DA layer and polls for its inclusion.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct DADispatcherConfig {
+    /// The interval between the DA dispatcher's iterations.
+    pub polling_interval_ms: Option<u32>,
+    /// The maximum number of rows to query from the database in a single query.
+    pub max_rows_to_dispatch: Option<u32>,
+    /// The maximum number of retries for a single call to the DA client.
+    pub max_retries: Option<u16>,
+    /// Back-off before the first retry of a DA client call; it's doubled after each subsequent retry.
+    pub initial_retry_backoff_ms: Option<u64>,
+    /// Upper bound for the back-off between retries of a DA client call.
+    pub max_retry_backoff_ms: Option<u64>,
+    /// Maximum time an L1 batch may await dispatch (since it was sealed) or inclusion (since it was dispatched).
+    /// L1 batches exceeding it are flagged as critical in logs, metrics and the dispatcher health check.
+    pub max_l1_batch_age_secs: Option<u64>,
+    /// Sliding period over which the DA costs are checked against the budget thresholds.
+    pub cost_budget_period_secs: Option<u64>,
+    /// Maximum number of pubdata bytes dispatched to a DA layer over the budget period. If exceeded,
+    /// the dispatcher health check is marked as affected. No limit if not set.
+    pub max_dispatched_bytes_per_period: Option<u64>,
+    /// Maximum total fee paid to a DA layer over the budget period, in the smallest units of the layer's
+    /// native token. If exceeded, the dispatcher health check is marked as affected. No limit if not set.
+    pub max_fee_per_period: Option<u64>,
+}
+
+impl DADispatcherConfig {
+    pub fn for_tests() -> Self {
+        Self {
+            polling_interval_ms: Some(DEFAULT_POLLING_INTERVAL_MS),
+            max_rows_to_dispatch: Some(DEFAULT_MAX_ROWS_TO_DISPATCH),
+            max_retries: Some(DEFAULT_MAX_RETRIES),
+            initial_retry_backoff_ms: Some(DEFAULT_INITIAL_RETRY_BACKOFF_MS),
+            max_retry_backoff_ms: Some(DEFAULT_MAX_RETRY_BACKOFF_MS),
+            max_l1_batch_age_secs: Some(DEFAULT_MAX_L1_BATCH_AGE_SECS),
+            cost_budget_period_secs: Some(DEFAULT_COST_BUDGET_PERIOD_SECS),
+            max_dispatched_bytes_per_period: None,
+            max_fee_per_period: None,
+        }
+    }
+
+    pub fn polling_interval(&self) -> Duration {
+        Duration::from_millis(
+            self.polling_interval_ms
+                .unwrap_or(DEFAULT_POLLING_INTERVAL_MS)
+                .into(),
+        )
+    }
+
+    pub fn max_rows_to_dispatch(&self) -> u32 {
+        self.max_rows_to_dispatch
+            .unwrap_or(DEFAULT_MAX_ROWS_TO_DISPATCH)
+    }
+
+    pub fn max_retries(&self) -> u16 {
+        self.max_retries.unwrap_or(DEFAULT_MAX_RETRIES)
+    }
+
+    pub fn initial_retry_backoff(&self) -> Duration {
+        Duration::from_millis(
+            self.initial_retry_backoff_ms
+                .unwrap_or(DEFAULT_INITIAL_RETRY_BACKOFF_MS),
+        )
+    }
+
+    pub fn max_retry_backoff(&self) -> Duration {
+        Duration::from_millis(
+            self.max_retry_backoff_ms
+                .unwrap_or(DEFAULT_MAX_RETRY_BACKOFF_MS),
+        )
+    }
+
+    pub fn max_l1_batch_age(&self) -> Duration {
+        Duration::from_secs(
+            self.max_l1_batch_age_secs
+                .unwrap_or(DEFAULT_MAX_L1_BATCH_AGE_SECS),
+        )
+    }
+
+    pub fn cost_budget_period(&self) -> Duration {
+        Duration::from_secs(
+            self.cost_budget_period_secs
+                .unwrap_or(DEFAULT_COST_BUDGET_PERIOD_SECS),
+        )
+    }
+}
@@ -20,6 +20,19 @@ pub enum MerkleTreeMode {
     Lightweight,
 }
 
+/// Handling of Postgres migrations on node startup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MigrationsMode {
+    /// Check that all migrations known to the node are applied, and that the database doesn't contain migrations
+    /// unknown to the node. The node refuses to start otherwise.
+    Check,
+    /// Apply pending migrations. Migrations are coordinated among concurrently starting nodes using
+    /// a Postgres advisory lock. As with [`Self::Check`], the node refuses to start if the database contains
+    /// migrations unknown to it.
+    Apply,
+}
+
 #[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct MerkleTreeConfig {
     /// Path to the RocksDB data directory for Merkle tree.
@@ -46,6 +59,9 @@ pub struct MerkleTreeConfig {
     /// Maximum number of L1 batches to be processed by the Merkle tree at a time.
     #[serde(default = "MerkleTreeConfig::default_max_l1_batches_per_iter")]
     pub max_l1_batches_per_iter: usize,
+    /// Number of threads in a dedicated thread pool used for parallel hash computations in the Merkle tree.
+    /// 0 means the number of logical CPUs. If not specified, the global thread pool is used.
+    pub thread_pool_size: Option<usize>,
 }
 
 impl Default for MerkleTreeConfig {
@@ -58,6 +74,7 @@ impl Default for MerkleTreeConfig {
             memtable_capacity_mb: Self::default_memtable_capacity_mb(),
             stalled_writes_timeout_sec: Self::default_stalled_writes_timeout_sec(),
             max_l1_batches_per_iter: Self::default_max_l1_batches_per_iter(),
+            thread_pool_size: None,
         }
     }
 }
@@ -145,6 +162,11 @@ pub struct PostgresConfig {
     pub long_connection_threshold_ms: Option<u64>,
     /// Threshold in milliseconds to denote a DB query as "slow" and log its details.
     pub slow_query_threshold_ms: Option<u64>,
+    /// Maximum replication lag in milliseconds for an API read replica to be used.
+    pub api_replica_max_lag_ms: Option<u64>,
+    /// Handling of Postgres migrations on startup. If not set, migrations are neither checked nor applied
+    /// and are expected to be run separately.
+    pub migrations_mode: Option<MigrationsMode>,
     pub test_server_url: Option<String>,
     pub test_prover_url: Option<String>,
 }
@@ -176,4 +198,9 @@ impl PostgresConfig {
     pub fn slow_query_threshold(&self) -> Option<Duration> {
         self.slow_query_threshold_ms.map(Duration::from_millis)
     }
+
+    /// Returns the maximum replication lag for an API read replica to be used.
+    pub fn api_replica_max_lag(&self) -> Option<Duration> {
+        self.api_replica_max_lag_ms.map(Duration::from_millis)
+    }
 }
@@ -40,6 +40,7 @@ impl EthConfig {
                 l1_batch_min_age_before_execute_seconds: None,
                 max_acceptable_priority_fee_in_gwei: 100000000000,
                 pubdata_sending_mode: PubdataSendingMode::Calldata,
+                blob_base_fee_calldata_fallback_threshold: None,
             }),
             gas_adjuster: Some(GasAdjusterConfig {
                 default_priority_fee_per_gas: 1000000000,
@@ -81,6 +82,8 @@ pub enum PubdataSendingMode {
     #[default]
     Calldata,
     Blobs,
+    /// Pubdata is sent to a DA layer by the DA dispatcher.
+    Custom,
 }
 
 #[derive(Debug, Deserialize, Clone, PartialEq)]
@@ -114,8 +117,11 @@ pub struct SenderConfig {
     // Max acceptable fee for sending tx it acts as a safeguard to prevent sending tx with very high fees.
     pub max_acceptable_priority_fee_in_gwei: u64,
 
-    /// The mode in which we send pubdata, either Calldata or Blobs
+    /// The mode in which we send pubdata: Calldata, Blobs or Custom (external DA layer)
     pub pubdata_sending_mode: PubdataSendingMode,
+    /// Blob base fee (in wei) above which commit transactions are sent with calldata instead of blobs.
+    /// Only has effect if `pubdata_sending_mode` is `Blobs`. If not specified, blobs are always used.
+    pub blob_base_fee_calldata_fallback_threshold: Option<u64>,
 }
 
 impl SenderConfig {
@@ -1,6 +1,6 @@
 //! Experimental part of configuration.
 
-use std::num::NonZeroU32;
+use std::{num::NonZeroU32, time::Duration};
 
 use serde::Deserialize;
 
@@ -12,6 +12,15 @@ pub struct ExperimentalDBConfig {
     /// Maximum number of files concurrently opened by state keeper cache RocksDB. Useful to fit into OS limits; can be used
     /// as a rudimentary way to control RAM usage of the cache.
     pub state_keeper_db_max_open_files: Option<NonZeroU32>,
+    /// Soft limit on the total size of SST files in the state keeper cache RocksDB. If exceeded, the cache is compacted
+    /// immediately. If not set, the cache size is not limited.
+    pub state_keeper_db_max_size_mb: Option<usize>,
+    /// Interval between manual compactions of the state keeper cache RocksDB. If not set, the cache is not
+    /// compacted manually (unless it exceeds `state_keeper_db_max_size_mb`).
+    pub state_keeper_db_compaction_interval_sec: Option<u64>,
+    /// SST files of the state keeper cache RocksDB not compacted for longer than this period will be compacted
+    /// by RocksDB in background, so that stale data is eventually removed. If not set, default RocksDB behavior is used.
+    pub state_keeper_db_compaction_ttl_sec: Option<u64>,
 }
 
 impl Default for ExperimentalDBConfig {
@@ -20,6 +29,9 @@ impl Default for ExperimentalDBConfig {
             state_keeper_db_block_cache_capacity_mb:
                 Self::default_state_keeper_db_block_cache_capacity_mb(),
             state_keeper_db_max_open_files: None,
+            state_keeper_db_max_size_mb: None,
+            state_keeper_db_compaction_interval_sec: None,
+            state_keeper_db_compaction_ttl_sec: None,
         }
     }
 }
@@ -32,4 +44,19 @@ impl ExperimentalDBConfig {
     pub fn state_keeper_db_block_cache_capacity(&self) -> usize {
         self.state_keeper_db_block_cache_capacity_mb * super::BYTES_IN_MEGABYTE
     }
+
+    pub fn state_keeper_db_max_size(&self) -> Option<u64> {
+        self.state_keeper_db_max_size_mb
+            .map(|size| (size * super::BYTES_IN_MEGABYTE) as u64)
+    }
+
+    pub fn state_keeper_db_compaction_interval(&self) -> Option<Duration> {
+        self.state_keeper_db_compaction_interval_sec
+            .map(Duration::from_secs)
+    }
+
+    pub fn state_keeper_db_compaction_ttl(&self) -> Option<Duration> {
+        self.state_keeper_db_compaction_ttl_sec
+            .map(Duration::from_secs)
+    }
 }
@@ -1,9 +1,15 @@
 use crate::{
     configs::{
+        base_token_price::BaseTokenPriceConfig,
         chain::{CircuitBreakerConfig, MempoolConfig, OperationsManagerConfig, StateKeeperConfig},
+        commitment_generator::CommitmentGeneratorConfig,
+        da_client::DAClientConfig,
+        da_dispatcher::DADispatcherConfig,
         fri_prover_group::FriProverGroupConfig,
         house_keeper::HouseKeeperConfig,
-        vm_runner::ProtectiveReadsWriterConfig,
+        vm_runner::{
+            BasicWitnessInputProducerConfig, ProtectiveReadsWriterConfig, VmPlaygroundConfig,
+        },
         FriProofCompressorConfig, FriProverConfig, FriProverGatewayConfig,
         FriWitnessGeneratorConfig, FriWitnessVectorGeneratorConfig, ObservabilityConfig,
         PrometheusConfig, ProofDataHandlerConfig,
@@ -12,7 +18,7 @@ use crate::{
     SnapshotsCreatorConfig,
 };
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct GeneralConfig {
     pub postgres_config: Option<PostgresConfig>,
     pub api_config: Option<ApiConfig>,
@@ -35,5 +41,11 @@ pub struct GeneralConfig {
     pub snapshot_creator: Option<SnapshotsCreatorConfig>,
     pub observability: Option<ObservabilityConfig>,
     pub protective_reads_writer_config: Option<ProtectiveReadsWriterConfig>,
+    pub basic_witness_input_producer_config: Option<BasicWitnessInputProducerConfig>,
+    pub vm_playground_config: Option<VmPlaygroundConfig>,
     pub core_object_store: Option<ObjectStoreConfig>,
+    pub da_dispatcher_config: Option<DADispatcherConfig>,
+    pub da_client_config: Option<DAClientConfig>,
+    pub base_token_price_config: Option<BaseTokenPriceConfig>,
+    pub commitment_generator_config: Option<CommitmentGeneratorConfig>,
 }
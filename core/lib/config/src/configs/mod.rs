@@ -1,8 +1,12 @@
 // Public re-exports
 pub use self::{
     api::ApiConfig,
+    base_token_price::BaseTokenPriceConfig,
+    commitment_generator::CommitmentGeneratorConfig,
     contract_verifier::ContractVerifierConfig,
     contracts::{ContractsConfig, EcosystemContracts},
+    da_client::DAClientConfig,
+    da_dispatcher::DADispatcherConfig,
     database::{DBConfig, PostgresConfig},
     eth_sender::{EthConfig, GasAdjusterConfig},
     eth_watch::EthWatchConfig,
@@ -15,19 +19,24 @@ pub use self::{
     general::GeneralConfig,
     genesis::GenesisConfig,
     object_store::ObjectStoreConfig,
-    observability::{ObservabilityConfig, OpentelemetryConfig},
+    observability::{ObservabilityConfig, OpentelemetryConfig, SloConfig},
     proof_data_handler::ProofDataHandlerConfig,
+    reloadable::{ReloadableConfig, ReloadableFeeParams},
     secrets::{DatabaseSecrets, L1Secrets, Secrets},
     snapshots_creator::SnapshotsCreatorConfig,
     utils::PrometheusConfig,
-    vm_runner::ProtectiveReadsWriterConfig,
+    vm_runner::{BasicWitnessInputProducerConfig, ProtectiveReadsWriterConfig, VmPlaygroundConfig},
 };
 
 pub mod api;
+pub mod base_token_price;
 pub mod chain;
+pub mod commitment_generator;
 pub mod consensus;
 pub mod contract_verifier;
 pub mod contracts;
+pub mod da_client;
+pub mod da_dispatcher;
 pub mod database;
 pub mod eth_sender;
 pub mod eth_watch;
@@ -44,6 +53,7 @@ pub mod house_keeper;
 pub mod object_store;
 pub mod observability;
 pub mod proof_data_handler;
+pub mod reloadable;
 pub mod secrets;
 pub mod snapshots_creator;
 pub mod utils;
@@ -40,4 +40,17 @@ pub enum ObjectStoreMode {
     FileBacked {
         file_backed_base_path: String,
     },
+    /// AWS S3 or an S3-compatible storage (e.g., MinIO).
+    S3 {
+        /// Name of the S3 bucket.
+        bucket_base_url: String,
+        s3_region: String,
+        /// Custom endpoint URL, e.g. `http://localhost:9000` for a local MinIO instance. If specified,
+        /// path-style addressing is used; otherwise, the store connects to AWS S3 in `s3_region`
+        /// using virtual-hosted–style addressing.
+        s3_endpoint: Option<String>,
+        /// Path to an AWS-style credentials file. If not specified, credentials are read from
+        /// the `AWS_ACCESS_KEY_ID`, `AWS_SECRET_ACCESS_KEY` and (optionally) `AWS_SESSION_TOKEN` env vars.
+        s3_credential_file_path: Option<String>,
+    },
 }
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 /// Configuration for the essential observability stack, like
 /// logging and sentry integration.
 #[derive(Debug, Clone, PartialEq)]
@@ -6,6 +8,9 @@ pub struct ObservabilityConfig {
     pub sentry_url: Option<String>,
     /// Name of the environment to use in Sentry.
     pub sentry_environment: Option<String>,
+    /// Release name to use in Sentry, e.g. `zksync_server@24.7.0`. If not set, the release is derived
+    /// from the binary version.
+    pub sentry_release: Option<String>,
     /// Opentelemetry configuration.
     pub opentelemetry: Option<OpentelemetryConfig>,
     /// Format of the logs as expected by the `vlog` crate.
@@ -13,6 +18,51 @@ pub struct ObservabilityConfig {
     pub log_format: String,
     // Log directives in format that is used in `RUST_LOG`
     pub log_directives: Option<String>,
+    /// Per-target log sampling directives, e.g. `zksync_eth_sender=100,zksync_node_sync=10s`: `{n}` logs
+    /// one of `n` events from the same callsite, and `{n}s` logs at most one event from the same callsite
+    /// per `n` seconds. If not set, logs are not sampled.
+    pub log_sampling_directives: Option<String>,
+    /// Port of the admin server allowing to change log directives at runtime. The server binds
+    /// to the loopback interface only and should not be exposed publicly. If not set, the server is not started.
+    pub admin_port: Option<u16>,
+    /// Enables CPU profiling and async task dump endpoints on the admin server.
+    pub admin_profiling_enabled: bool,
+    /// Thresholds for service level objectives evaluated by the node itself.
+    pub slo: SloConfig,
+}
+
+/// Thresholds for service level objectives (SLOs) evaluated by the node itself. Each set threshold
+/// is exposed as a health component (`slo_*`) that is degraded while the threshold is exceeded.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SloConfig {
+    /// Interval between SLO evaluations in milliseconds. If not set, SLOs are evaluated every 10 seconds.
+    pub check_interval_ms: Option<u64>,
+    /// Maximum lag behind the main node in L2 blocks. Only applies to external nodes.
+    pub max_sync_lag: Option<u32>,
+    /// Maximum share of used connections in the main DB connection pool, from 0 to 1.
+    pub max_pool_saturation: Option<f64>,
+    /// Maximum 99th percentile of API call latency over the last 5 minutes in milliseconds.
+    pub max_api_p99_latency_ms: Option<u64>,
+}
+
+impl SloConfig {
+    const DEFAULT_CHECK_INTERVAL: Duration = Duration::from_secs(10);
+
+    pub fn check_interval(&self) -> Duration {
+        self.check_interval_ms
+            .map_or(Self::DEFAULT_CHECK_INTERVAL, Duration::from_millis)
+    }
+
+    pub fn max_api_p99_latency(&self) -> Option<Duration> {
+        self.max_api_p99_latency_ms.map(Duration::from_millis)
+    }
+
+    /// Checks whether no SLO thresholds are set.
+    pub fn is_empty(&self) -> bool {
+        self.max_sync_lag.is_none()
+            && self.max_pool_saturation.is_none()
+            && self.max_api_p99_latency_ms.is_none()
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -21,4 +71,10 @@ pub struct OpentelemetryConfig {
     pub level: String,
     /// Opentelemetry HTTP collector endpoint.
     pub endpoint: String,
+    /// Ratio of traces to export, from 0 to 1. Traces started by remote callers that have sampled them
+    /// (e.g., RPC requests with a `traceparent` header) are always exported. If not set, all traces are exported.
+    pub sampling_ratio: Option<f64>,
+    /// Additional resource attributes attached to exported spans as comma-separated `key=value` pairs
+    /// (the same format as used by the `OTEL_RESOURCE_ATTRIBUTES` env variable).
+    pub resource_attributes: Option<String>,
 }
@@ -0,0 +1,103 @@
+use std::num::NonZeroU32;
+
+use serde::Serialize;
+
+use crate::configs::{chain::StateKeeperConfig, GeneralConfig};
+
+/// Subset of the node configuration that can be changed at runtime, without restarting the node.
+/// Changes to other config values only take effect after a restart.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ReloadableConfig {
+    /// Log directives in the `RUST_LOG` format (`observability.log_directives`).
+    pub log_directives: Option<String>,
+    /// Maximum number of requests per minute for a WebSocket session
+    /// (`api.web3_json_rpc.websocket_requests_per_minute_limit`). Applies to the sessions opened after the change.
+    pub websocket_requests_per_minute_limit: Option<NonZeroU32>,
+    /// Fee model parameters from the state keeper config.
+    pub fee_params: Option<ReloadableFeeParams>,
+}
+
+impl ReloadableConfig {
+    /// Extracts the reloadable subset from the general config.
+    pub fn from_general(config: &GeneralConfig) -> Self {
+        Self {
+            log_directives: config
+                .observability
+                .as_ref()
+                .and_then(|config| config.log_directives.clone()),
+            websocket_requests_per_minute_limit: config
+                .api_config
+                .as_ref()
+                .map(|config| config.web3_json_rpc.websocket_requests_per_minute_limit()),
+            fee_params: config
+                .state_keeper_config
+                .as_ref()
+                .map(ReloadableFeeParams::from_state_keeper_config),
+        }
+    }
+
+    /// Overwrites the reloadable values in the general config with the values from this config, so that
+    /// the general config reflects the configuration effective at runtime.
+    pub fn apply_to(&self, config: &mut GeneralConfig) {
+        if let Some(observability) = &mut config.observability {
+            observability.log_directives = self.log_directives.clone();
+        }
+        if let (Some(api), Some(limit)) = (
+            &mut config.api_config,
+            self.websocket_requests_per_minute_limit,
+        ) {
+            // Avoid replacing an unset limit with the equivalent default value.
+            if api.web3_json_rpc.websocket_requests_per_minute_limit() != limit {
+                api.web3_json_rpc.websocket_requests_per_minute_limit = Some(limit);
+            }
+        }
+        if let (Some(state_keeper), Some(fee_params)) =
+            (&mut config.state_keeper_config, &self.fee_params)
+        {
+            state_keeper.minimal_l2_gas_price = fee_params.minimal_l2_gas_price;
+            state_keeper.compute_overhead_part = fee_params.compute_overhead_part;
+            state_keeper.pubdata_overhead_part = fee_params.pubdata_overhead_part;
+            state_keeper.batch_overhead_l1_gas = fee_params.batch_overhead_l1_gas;
+        }
+    }
+
+    /// Returns names of the fields that differ between this and the other config.
+    pub fn changed_fields(&self, other: &Self) -> Vec<&'static str> {
+        let mut fields = vec![];
+        if self.log_directives != other.log_directives {
+            fields.push("log_directives");
+        }
+        if self.websocket_requests_per_minute_limit != other.websocket_requests_per_minute_limit {
+            fields.push("websocket_requests_per_minute_limit");
+        }
+        if self.fee_params != other.fee_params {
+            fields.push("fee_params");
+        }
+        fields
+    }
+}
+
+/// Fee model parameters that can be changed at runtime. The fee model version cannot be changed
+/// without restarting the node.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct ReloadableFeeParams {
+    /// See [`StateKeeperConfig::minimal_l2_gas_price`].
+    pub minimal_l2_gas_price: u64,
+    /// See [`StateKeeperConfig::compute_overhead_part`].
+    pub compute_overhead_part: f64,
+    /// See [`StateKeeperConfig::pubdata_overhead_part`].
+    pub pubdata_overhead_part: f64,
+    /// See [`StateKeeperConfig::batch_overhead_l1_gas`].
+    pub batch_overhead_l1_gas: u64,
+}
+
+impl ReloadableFeeParams {
+    pub fn from_state_keeper_config(config: &StateKeeperConfig) -> Self {
+        Self {
+            minimal_l2_gas_price: config.minimal_l2_gas_price,
+            compute_overhead_part: config.compute_overhead_part,
+            pubdata_overhead_part: config.pubdata_overhead_part,
+            batch_overhead_l1_gas: config.batch_overhead_l1_gas,
+        }
+    }
+}
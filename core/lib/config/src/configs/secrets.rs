@@ -8,11 +8,17 @@ pub struct DatabaseSecrets {
     pub server_url: Option<SensitiveUrl>,
     pub prover_url: Option<SensitiveUrl>,
     pub server_replica_url: Option<SensitiveUrl>,
+    /// Read replicas used by the API servers. If specified, API connections are distributed among these replicas,
+    /// and the replica URL is only used if all of them are lagging or unavailable.
+    pub server_api_replica_urls: Vec<SensitiveUrl>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct L1Secrets {
     pub l1_rpc_url: SensitiveUrl,
+    /// Fallback L1 RPC URLs in the priority order. If specified, the L1 client fails over to these URLs
+    /// if the main URL is unavailable or rate-limits requests.
+    pub fallback_l1_rpc_urls: Vec<SensitiveUrl>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
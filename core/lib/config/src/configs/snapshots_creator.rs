@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use serde::Deserialize;
 
 use crate::ObjectStoreConfig;
@@ -10,6 +12,15 @@ pub struct SnapshotsCreatorConfig {
     #[serde(default = "snapshots_creator_concurrent_queries_count")]
     pub concurrent_queries_count: u32,
     pub object_store: Option<ObjectStoreConfig>,
+    /// Interval between snapshot creation attempts (in seconds) if the creator is run as a node component.
+    /// The default value is 1 hour. Not used by the standalone snapshot creator, which creates a single snapshot per run.
+    pub creation_interval_sec: Option<u64>,
+}
+
+impl SnapshotsCreatorConfig {
+    pub fn creation_interval(&self) -> Duration {
+        Duration::from_secs(self.creation_interval_sec.unwrap_or(3_600))
+    }
 }
 
 fn snapshots_creator_storage_logs_chunk_size_default() -> u64 {
@@ -10,6 +10,13 @@ pub struct PrometheusConfig {
     pub pushgateway_url: String,
     /// Push interval in ms.
     pub push_interval_ms: Option<u64>,
+    /// Whether the server should push metrics to the push gateway instead of exposing them on `listener_port`.
+    #[serde(default)]
+    pub push_gateway_enabled: bool,
+    /// Name of the job used in the grouping key of pushed metrics. If not set, `zksync_server` is used.
+    pub push_job_name: Option<String>,
+    /// Value of the `instance` label used in the grouping key of pushed metrics. If not set, the `POD_NAME` env variable is used.
+    pub push_instance: Option<String>,
 }
 
 impl PrometheusConfig {
@@ -17,6 +24,16 @@ impl PrometheusConfig {
         Duration::from_millis(self.push_interval_ms.unwrap_or(100))
     }
 
+    pub fn push_job_name(&self) -> &str {
+        self.push_job_name.as_deref().unwrap_or("zksync_server")
+    }
+
+    pub fn push_instance(&self) -> String {
+        self.push_instance
+            .clone()
+            .unwrap_or_else(|| env::var("POD_NAME").unwrap_or_else(|_| "UNKNOWN_POD".to_owned()))
+    }
+
     /// Returns the full endpoint URL for the push gateway.
     pub fn gateway_endpoint(&self) -> String {
         let gateway_url = &self.pushgateway_url;
@@ -1,5 +1,8 @@
+use anyhow::Context as _;
 use serde::Deserialize;
-use zksync_basic_types::L1BatchNumber;
+use zksync_basic_types::{
+    protocol_version::ProtocolVersionId, vm_version::VmVersion, L1BatchNumber,
+};
 
 #[derive(Debug, Deserialize, Clone, PartialEq, Default)]
 pub struct ProtectiveReadsWriterConfig {
@@ -17,3 +20,51 @@ impl ProtectiveReadsWriterConfig {
         "./db/protective_reads_writer".to_owned()
     }
 }
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Default)]
+pub struct BasicWitnessInputProducerConfig {
+    /// Path to the RocksDB data directory that serves state cache.
+    #[serde(default = "BasicWitnessInputProducerConfig::default_db_path")]
+    pub db_path: String,
+    /// How many max batches should be processed at the same time.
+    pub window_size: u32,
+    /// All batches before this one (inclusive) are always considered to be processed.
+    pub first_processed_batch: L1BatchNumber,
+}
+
+impl BasicWitnessInputProducerConfig {
+    fn default_db_path() -> String {
+        "./db/basic_witness_input_producer".to_owned()
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Default)]
+pub struct VmPlaygroundConfig {
+    /// Path to the RocksDB data directory that serves state cache.
+    #[serde(default = "VmPlaygroundConfig::default_db_path")]
+    pub db_path: String,
+    /// How many max batches should be processed at the same time.
+    pub window_size: u32,
+    /// All batches before this one (inclusive) are always considered to be processed.
+    pub first_processed_batch: L1BatchNumber,
+    /// Protocol version whose VM is used to re-execute batches. If not specified, each batch is re-executed
+    /// with the VM corresponding to its own protocol version.
+    pub vm_protocol_version: Option<u16>,
+}
+
+impl VmPlaygroundConfig {
+    fn default_db_path() -> String {
+        "./db/vm_playground".to_owned()
+    }
+
+    /// Returns the VM version used to re-execute batches, or `None` if each batch should be re-executed
+    /// with the VM corresponding to its protocol version.
+    pub fn vm_version(&self) -> anyhow::Result<Option<VmVersion>> {
+        let Some(version) = self.vm_protocol_version else {
+            return Ok(None);
+        };
+        let version = ProtocolVersionId::try_from(version)
+            .with_context(|| format!("unsupported VM protocol version: {version}"))?;
+        Ok(Some(version.into()))
+    }
+}
@@ -64,6 +64,8 @@ impl Distribution<configs::api::Web3JsonRpcConfig> for EncodeDist {
             filters_disabled: self.sample(rng),
             filters_limit: self.sample(rng),
             subscriptions_limit: self.sample(rng),
+            subscriptions_per_connection_limit: self.sample(rng),
+            subscription_backlog_size: self.sample(rng),
             pubsub_polling_interval: self.sample(rng),
             max_nonce_ahead: self.sample(rng),
             gas_price_scale_factor: self.sample(rng),
@@ -78,6 +80,7 @@ impl Distribution<configs::api::Web3JsonRpcConfig> for EncodeDist {
             initial_writes_cache_size_mb: self.sample(rng),
             latest_values_cache_size_mb: self.sample(rng),
             fee_history_limit: self.sample(rng),
+            max_logs_block_range: self.sample(rng),
             max_batch_request_size: self.sample(rng),
             max_response_body_size_mb: self.sample(rng),
             max_response_body_size_overrides_mb: [
@@ -97,6 +100,13 @@ impl Distribution<configs::api::Web3JsonRpcConfig> for EncodeDist {
             mempool_cache_update_interval: self.sample(rng),
             mempool_cache_size: self.sample(rng),
             whitelisted_tokens_for_aa: self.sample_range(rng).map(|_| rng.gen()).collect(),
+            evm_emulator_enabled: self.sample(rng),
+            max_pending_txs_per_initiator: self.sample(rng),
+            min_max_fee_per_gas: self.sample(rng),
+            denied_initiators: self.sample_range(rng).map(|_| rng.gen()).collect(),
+            eth_call_cache_size: self.sample(rng),
+            eth_call_cache_ttl_sec: self.sample(rng),
+            shutdown_drain_timeout_sec: self.sample(rng),
         }
     }
 }
@@ -134,6 +144,9 @@ impl Distribution<configs::PrometheusConfig> for EncodeDist {
             listener_port: self.sample(rng),
             pushgateway_url: self.sample(rng),
             push_interval_ms: self.sample(rng),
+            push_gateway_enabled: self.sample(rng),
+            push_job_name: self.sample(rng),
+            push_instance: self.sample(rng),
         }
     }
 }
@@ -175,6 +188,7 @@ impl Distribution<configs::chain::StateKeeperConfig> for EncodeDist {
             validation_computational_gas_limit: self.sample(rng),
             save_call_traces: self.sample(rng),
             max_circuits_per_batch: self.sample(rng),
+            protective_reads_persistence_enabled: self.sample(rng),
             // These values are not involved into files serialization skip them
             fee_account_addr: None,
             bootloader_hash: None,
@@ -271,6 +285,7 @@ impl Distribution<configs::database::MerkleTreeConfig> for EncodeDist {
             memtable_capacity_mb: self.sample(rng),
             stalled_writes_timeout_sec: self.sample(rng),
             max_l1_batches_per_iter: self.sample(rng),
+            thread_pool_size: self.sample(rng),
         }
     }
 }
@@ -280,6 +295,9 @@ impl Distribution<configs::ExperimentalDBConfig> for EncodeDist {
         configs::ExperimentalDBConfig {
             state_keeper_db_block_cache_capacity_mb: self.sample(rng),
             state_keeper_db_max_open_files: self.sample(rng),
+            state_keeper_db_max_size_mb: self.sample(rng),
+            state_keeper_db_compaction_interval_sec: self.sample(rng),
+            state_keeper_db_compaction_ttl_sec: self.sample(rng),
         }
     }
 }
@@ -303,6 +321,13 @@ impl Distribution<configs::database::PostgresConfig> for EncodeDist {
             statement_timeout_sec: self.sample(rng),
             long_connection_threshold_ms: self.sample(rng),
             slow_query_threshold_ms: self.sample(rng),
+            api_replica_max_lag_ms: self.sample(rng),
+            migrations_mode: self.sample_opt(|| {
+                [
+                    configs::database::MigrationsMode::Check,
+                    configs::database::MigrationsMode::Apply,
+                ][rng.gen_range(0..2)]
+            }),
             test_server_url: self.sample(rng),
             test_prover_url: self.sample(rng),
         }
@@ -343,9 +368,10 @@ impl Distribution<configs::eth_sender::ProofLoadingMode> for EncodeDist {
 impl Distribution<configs::eth_sender::PubdataSendingMode> for EncodeDist {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> configs::eth_sender::PubdataSendingMode {
         type T = configs::eth_sender::PubdataSendingMode;
-        match rng.gen_range(0..2) {
+        match rng.gen_range(0..3) {
             0 => T::Calldata,
-            _ => T::Blobs,
+            1 => T::Blobs,
+            _ => T::Custom,
         }
     }
 }
@@ -370,6 +396,7 @@ impl Distribution<configs::eth_sender::SenderConfig> for EncodeDist {
             l1_batch_min_age_before_execute_seconds: self.sample(rng),
             max_acceptable_priority_fee_in_gwei: self.sample(rng),
             pubdata_sending_mode: PubdataSendingMode::Calldata,
+            blob_base_fee_calldata_fallback_threshold: self.sample(rng),
         }
     }
 }
@@ -593,7 +620,7 @@ impl Distribution<configs::house_keeper::HouseKeeperConfig> for EncodeDist {
 impl Distribution<configs::object_store::ObjectStoreMode> for EncodeDist {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> configs::object_store::ObjectStoreMode {
         type T = configs::object_store::ObjectStoreMode;
-        match rng.gen_range(0..4) {
+        match rng.gen_range(0..5) {
             0 => T::GCS {
                 bucket_base_url: self.sample(rng),
             },
@@ -604,6 +631,12 @@ impl Distribution<configs::object_store::ObjectStoreMode> for EncodeDist {
             2 => T::FileBacked {
                 file_backed_base_path: self.sample(rng),
             },
+            3 => T::S3 {
+                bucket_base_url: self.sample(rng),
+                s3_region: self.sample(rng),
+                s3_endpoint: self.sample(rng),
+                s3_credential_file_path: self.sample(rng),
+            },
             _ => T::GCSAnonymousReadOnly {
                 bucket_base_url: self.sample(rng),
             },
@@ -637,6 +670,7 @@ impl Distribution<configs::SnapshotsCreatorConfig> for EncodeDist {
             storage_logs_chunk_size: self.sample(rng),
             concurrent_queries_count: self.sample(rng),
             object_store: self.sample(rng),
+            creation_interval_sec: self.sample(rng),
         }
     }
 }
@@ -646,9 +680,25 @@ impl Distribution<configs::ObservabilityConfig> for EncodeDist {
         configs::ObservabilityConfig {
             sentry_url: self.sample(rng),
             sentry_environment: self.sample(rng),
+            sentry_release: self.sample(rng),
             log_format: self.sample(rng),
             opentelemetry: self.sample(rng),
             log_directives: self.sample(rng),
+            log_sampling_directives: self.sample(rng),
+            admin_port: self.sample(rng),
+            admin_profiling_enabled: self.sample(rng),
+            slo: self.sample(rng),
+        }
+    }
+}
+
+impl Distribution<configs::SloConfig> for EncodeDist {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> configs::SloConfig {
+        configs::SloConfig {
+            check_interval_ms: self.sample(rng),
+            max_sync_lag: self.sample(rng),
+            max_pool_saturation: self.sample_opt(|| rng.gen()),
+            max_api_p99_latency_ms: self.sample(rng),
         }
     }
 }
@@ -658,6 +708,8 @@ impl Distribution<configs::OpentelemetryConfig> for EncodeDist {
         configs::OpentelemetryConfig {
             level: self.sample(rng),
             endpoint: self.sample(rng),
+            sampling_ratio: self.sample_opt(|| rng.gen()),
+            resource_attributes: self.sample(rng),
         }
     }
 }
@@ -741,17 +793,43 @@ impl Distribution<configs::consensus::ConsensusConfig> for EncodeDist {
                 .sample_range(rng)
                 .map(|_| (NodePublicKey(self.sample(rng)), Host(self.sample(rng))))
                 .collect(),
+            registry_address: self.sample_opt(|| rng.gen()),
+            admin_addr: self.sample(rng),
+            gossip_rate_limit: self.sample(rng),
             genesis_spec: self.sample(rng),
         }
     }
 }
 
+impl Distribution<configs::consensus::GossipRateLimitConfig> for EncodeDist {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> configs::consensus::GossipRateLimitConfig {
+        configs::consensus::GossipRateLimitConfig {
+            max_inbound_bytes_per_sec: self.sample(rng),
+            max_inbound_burst_bytes: self.sample(rng),
+            max_connections_per_peer: self.sample(rng),
+            ban_duration_sec: self.sample(rng),
+        }
+    }
+}
+
 impl Distribution<configs::consensus::ConsensusSecrets> for EncodeDist {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> configs::consensus::ConsensusSecrets {
-        use configs::consensus::{ConsensusSecrets, NodeSecretKey, ValidatorSecretKey};
+        use configs::consensus::{
+            AttesterSecretKey, ConsensusKeystore, ConsensusSecrets, NodeSecretKey,
+            ValidatorSecretKey,
+        };
         ConsensusSecrets {
             validator_key: self.sample_opt(|| ValidatorSecretKey(String::into(self.sample(rng)))),
             node_key: self.sample_opt(|| NodeSecretKey(String::into(self.sample(rng)))),
+            attester_key: self.sample_opt(|| AttesterSecretKey(String::into(self.sample(rng)))),
+            keystore: self.sample_opt(|| ConsensusKeystore {
+                path: self.sample::<String>(rng).into(),
+                fallback_paths: self
+                    .sample_range(rng)
+                    .map(|_| self.sample::<String>(rng).into())
+                    .collect(),
+                password_file: self.sample_opt(|| self.sample::<String>(rng).into()),
+            }),
         }
     }
 }
@@ -761,6 +839,10 @@ impl Distribution<configs::secrets::L1Secrets> for EncodeDist {
         use configs::secrets::L1Secrets;
         L1Secrets {
             l1_rpc_url: format!("localhost:{}", rng.gen::<u16>()).parse().unwrap(),
+            fallback_l1_rpc_urls: self
+                .sample_range(rng)
+                .map(|_| format!("localhost:{}", rng.gen::<u16>()).parse().unwrap())
+                .collect(),
         }
     }
 }
@@ -771,6 +853,10 @@ impl Distribution<configs::secrets::DatabaseSecrets> for EncodeDist {
         DatabaseSecrets {
             server_url: Some(format!("localhost:{}", rng.gen::<u16>()).parse().unwrap()),
             server_replica_url: Some(format!("localhost:{}", rng.gen::<u16>()).parse().unwrap()),
+            server_api_replica_urls: self
+                .sample_range(rng)
+                .map(|_| format!("localhost:{}", rng.gen::<u16>()).parse().unwrap())
+                .collect(),
             prover_url: Some(format!("localhost:{}", rng.gen::<u16>()).parse().unwrap()),
         }
     }
@@ -786,3 +872,131 @@ impl Distribution<configs::secrets::Secrets> for EncodeDist {
         }
     }
 }
+
+impl Distribution<configs::da_dispatcher::DADispatcherConfig> for EncodeDist {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> configs::da_dispatcher::DADispatcherConfig {
+        configs::da_dispatcher::DADispatcherConfig {
+            polling_interval_ms: self.sample(rng),
+            max_rows_to_dispatch: self.sample(rng),
+            max_retries: self.sample(rng),
+            initial_retry_backoff_ms: self.sample(rng),
+            max_retry_backoff_ms: self.sample(rng),
+            max_l1_batch_age_secs: self.sample(rng),
+            cost_budget_period_secs: self.sample(rng),
+            max_dispatched_bytes_per_period: self.sample(rng),
+            max_fee_per_period: self.sample(rng),
+        }
+    }
+}
+
+impl Distribution<configs::da_client::mantle::MantleDAConfig> for EncodeDist {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> configs::da_client::mantle::MantleDAConfig {
+        configs::da_client::mantle::MantleDAConfig {
+            api_node_url: self.sample(rng),
+            request_timeout_ms: self.sample(rng),
+            wait_for_finalization: self.sample(rng),
+            max_blob_size_bytes: self.sample_opt(|| rng.gen::<u32>() as usize),
+        }
+    }
+}
+
+impl Distribution<configs::da_client::eigen::EigenDAConfig> for EncodeDist {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> configs::da_client::eigen::EigenDAConfig {
+        configs::da_client::eigen::EigenDAConfig {
+            disperser_rpc: self.sample(rng),
+            request_timeout_ms: self.sample(rng),
+            custom_quorum_numbers: self.sample_collect(rng),
+            account_id: self.sample_opt(|| self.sample(rng)),
+            wait_for_finalization: self.sample(rng),
+            max_blob_size_bytes: self.sample_opt(|| rng.gen::<u32>() as usize),
+        }
+    }
+}
+
+impl Distribution<configs::da_client::celestia::CelestiaConfig> for EncodeDist {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> configs::da_client::celestia::CelestiaConfig {
+        configs::da_client::celestia::CelestiaConfig {
+            api_node_url: self.sample(rng),
+            auth_token: self.sample_opt(|| self.sample(rng)),
+            namespace: self.sample(rng),
+            gas_price: self.sample_opt(|| rng.gen()),
+            inclusion_confirmations: self.sample(rng),
+            request_timeout_ms: self.sample(rng),
+            max_blob_size_bytes: self.sample_opt(|| rng.gen::<u32>() as usize),
+        }
+    }
+}
+
+impl Distribution<configs::da_client::avail::AvailConfig> for EncodeDist {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> configs::da_client::avail::AvailConfig {
+        configs::da_client::avail::AvailConfig {
+            api_node_url: self.sample(rng),
+            bridge_api_url: self.sample(rng),
+            app_id: self.sample(rng),
+            request_timeout_ms: self.sample(rng),
+            max_blob_size_bytes: self.sample_opt(|| rng.gen::<u32>() as usize),
+        }
+    }
+}
+
+impl Distribution<configs::da_client::DAClientConfig> for EncodeDist {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> configs::da_client::DAClientConfig {
+        type T = configs::da_client::DAClient;
+        let sample_client = |rng: &mut R| match rng.gen_range(0..5) {
+            0 => T::Mantle(self.sample(rng)),
+            1 => T::EigenDA(self.sample(rng)),
+            2 => T::Celestia(self.sample(rng)),
+            3 => T::Avail(self.sample(rng)),
+            _ => T::ObjectStore(self.sample(rng)),
+        };
+        let client = sample_client(rng);
+        let shadow_client = self.sample_opt(|| sample_client(rng));
+        configs::da_client::DAClientConfig {
+            client,
+            shadow_client,
+        }
+    }
+}
+
+impl Distribution<configs::commitment_generator::CommitmentGeneratorConfig> for EncodeDist {
+    fn sample<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+    ) -> configs::commitment_generator::CommitmentGeneratorConfig {
+        configs::commitment_generator::CommitmentGeneratorConfig {
+            max_parallelism: self.sample(rng),
+        }
+    }
+}
+
+impl Distribution<configs::base_token_price::BaseTokenPriceConfig> for EncodeDist {
+    fn sample<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+    ) -> configs::base_token_price::BaseTokenPriceConfig {
+        use configs::base_token_price as c;
+        c::BaseTokenPriceConfig {
+            polling_interval_ms: self.sample(rng),
+            ratio_cache_update_interval_ms: self.sample(rng),
+            max_deviation_bps: self.sample(rng),
+            max_staleness_secs: self.sample(rng),
+            min_agreeing_sources: self.sample(rng),
+            base_token_decimals: self.sample_opt(|| rng.gen()),
+            fixed: self.sample_opt(|| c::FixedRatioSourceConfig {
+                numerator: rng.gen(),
+                denominator: rng.gen(),
+            }),
+            coingecko: self.sample_opt(|| c::CoingeckoSourceConfig {
+                api_url: self.sample(rng),
+                api_key: self.sample(rng),
+                platform_id: self.sample(rng),
+                token_address: rng.gen(),
+            }),
+            uniswap_v3_twap: self.sample_opt(|| c::UniswapV3TwapSourceConfig {
+                pool_address: rng.gen(),
+                base_token_is_token0: rng.gen(),
+                window_secs: self.sample(rng),
+            }),
+        }
+    }
+}
@@ -22,4 +22,11 @@ pub enum ContractVerifierError {
     AbstractContract(String),
     #[error("Failed to deserialize standard JSON input")]
     FailedToDeserializeInput,
+    #[error("Invalid source file path: {0}")]
+    InvalidSourcePath(String),
+    #[error(
+        "Contract links libraries with unspecified addresses: {0}; specify library addresses \
+         in `settings.libraries` of the standard JSON input"
+    )]
+    MissingLibraries(String),
 }
@@ -1,6 +1,9 @@
+//! Contract verifier able to verify contracts created with `zksolc` or `zkvyper` toolchains.
+
 use std::{
     collections::HashMap,
     path::Path,
+    sync::Arc,
     time::{Duration, Instant},
 };
 
@@ -9,16 +12,17 @@ use chrono::Utc;
 use ethabi::{Contract, Token};
 use lazy_static::lazy_static;
 use regex::Regex;
-use tokio::time;
-use zksync_config::ContractVerifierConfig;
+use tokio::{sync::watch, time};
+use zksync_config::{ContractVerifierConfig, ObjectStoreConfig};
 use zksync_dal::{Connection, ConnectionPool, Core, CoreDal};
-use zksync_env_config::FromEnv;
+use zksync_object_store::{Bucket, ObjectStore, ObjectStoreError, ObjectStoreFactory};
 use zksync_queued_job_processor::{async_trait, JobProcessor};
 use zksync_types::{
     contract_verification_api::{
         CompilationArtifacts, CompilerType, DeployContractCalldata, SourceCodeData,
-        VerificationInfo, VerificationRequest,
+        VerificationIncomingRequest, VerificationInfo, VerificationRequest,
     },
+    web3::keccak256,
     Address,
 };
 use zksync_utils::workspace_dir_or_current_dir;
@@ -27,9 +31,14 @@ use crate::{
     error::ContractVerifierError,
     metrics::API_CONTRACT_VERIFIER_METRICS,
     zksolc_utils::{Optimizer, Settings, Source, StandardJson, ZkSolc, ZkSolcInput, ZkSolcOutput},
-    zkvyper_utils::{ZkVyper, ZkVyperInput},
+    zkvyper_utils::{contract_file_path, VyperStandardJson, ZkVyper, ZkVyperInput},
 };
 
+pub mod error;
+mod metrics;
+pub mod zksolc_utils;
+pub mod zkvyper_utils;
+
 lazy_static! {
     static ref DEPLOYER_CONTRACT: Contract = zksync_contracts::deployer_contract();
 }
@@ -44,10 +53,58 @@ enum ConstructorArgs {
     Ignore,
 }
 
-#[derive(Debug)]
+/// Lists compiler versions available in the `etc/*-bin` directories of the workspace and persists them
+/// in Postgres, so that they are returned by the contract verification API.
+pub async fn update_compiler_versions(
+    connection_pool: &ConnectionPool<Core>,
+) -> anyhow::Result<()> {
+    let mut storage = connection_pool.connection().await?;
+    let mut transaction = storage.start_transaction().await?;
+
+    let zksolc_versions = list_compiler_versions("zksolc-bin")?;
+    transaction
+        .contract_verification_dal()
+        .set_zksolc_versions(zksolc_versions)
+        .await?;
+    let solc_versions = list_compiler_versions("solc-bin")?;
+    transaction
+        .contract_verification_dal()
+        .set_solc_versions(solc_versions)
+        .await?;
+    let zkvyper_versions = list_compiler_versions("zkvyper-bin")?;
+    transaction
+        .contract_verification_dal()
+        .set_zkvyper_versions(zkvyper_versions)
+        .await?;
+    let vyper_versions = list_compiler_versions("vyper-bin")?;
+    transaction
+        .contract_verification_dal()
+        .set_vyper_versions(vyper_versions)
+        .await?;
+
+    transaction.commit().await?;
+    Ok(())
+}
+
+fn list_compiler_versions(dir_name: &str) -> anyhow::Result<Vec<String>> {
+    let path = home_path().join("etc").join(dir_name);
+    let entries = std::fs::read_dir(&path)
+        .with_context(|| format!("failed reading compilers directory `{}`", path.display()))?;
+    let mut versions = vec![];
+    for entry in entries {
+        let entry = entry.with_context(|| format!("failed reading `{}`", path.display()))?;
+        if entry.file_type().is_ok_and(|file_type| file_type.is_dir()) {
+            versions.extend(entry.file_name().into_string().ok());
+        }
+    }
+    Ok(versions)
+}
+
+#[derive(Debug, Clone)]
 pub struct ContractVerifier {
     config: ContractVerifierConfig,
     connection_pool: ConnectionPool<Core>,
+    artifacts_cache: Option<Arc<dyn ObjectStore>>,
 }
 
 impl ContractVerifier {
@@ -55,15 +112,48 @@ impl ContractVerifier {
         Self {
             config,
             connection_pool,
+            artifacts_cache: None,
         }
     }
 
+    /// Enables caching of compilation artifacts in the provided object store. Artifacts are keyed by the compilation
+    /// input (sources, compiler versions and settings), so that repeated verification requests for the same sources
+    /// (e.g., for multiple deployments of the same contract) don't need to invoke compilers.
+    pub fn with_artifacts_cache(mut self, object_store: Arc<dyn ObjectStore>) -> Self {
+        self.artifacts_cache = Some(object_store);
+        self
+    }
+
+    /// Same as [`Self::with_artifacts_cache()`], but creates the object store from the provided config.
+    pub async fn with_artifacts_cache_config(
+        self,
+        object_store_config: ObjectStoreConfig,
+    ) -> anyhow::Result<Self> {
+        let object_store = ObjectStoreFactory::new(object_store_config)
+            .create_store()
+            .await
+            .context("failed creating object store for compilation artifacts")?;
+        Ok(self.with_artifacts_cache(object_store))
+    }
+
+    /// Persists compiler versions available to the verifier (see [`update_compiler_versions()`]) and then
+    /// processes verification requests until a stop signal is received.
+    pub async fn update_compiler_versions_and_run(
+        self,
+        stop_receiver: watch::Receiver<bool>,
+    ) -> anyhow::Result<()> {
+        update_compiler_versions(&self.connection_pool)
+            .await
+            .context("failed updating compiler versions")?;
+        self.run(stop_receiver, None).await
+    }
+
     async fn verify(
+        &self,
         storage: &mut Connection<'_, Core>,
         mut request: VerificationRequest,
-        config: ContractVerifierConfig,
     ) -> Result<VerificationInfo, ContractVerifierError> {
-        let artifacts = Self::compile(request.clone(), config).await?;
+        let artifacts = self.compile_with_cache(request.clone()).await?;
 
         // Bytecode should be present because it is checked when accepting request.
         let (deployed_bytecode, creation_tx_calldata) = storage
@@ -180,6 +270,17 @@ impl ContractVerifier {
                     .get(&contract_name)
                     .cloned()
                     .ok_or(ContractVerifierError::MissingContract(contract_name))?;
+                // Libraries must be linked at compile time, so the bytecode cannot match if some of them are missing.
+                let missing_libraries = contract["missingLibraries"]
+                    .as_array()
+                    .filter(|libraries| !libraries.is_empty());
+                if let Some(libraries) = missing_libraries {
+                    let libraries: Vec<_> =
+                        libraries.iter().filter_map(|name| name.as_str()).collect();
+                    return Err(ContractVerifierError::MissingLibraries(
+                        libraries.join(", "),
+                    ));
+                }
                 let bytecode_str = contract["evm"]["bytecode"]["object"].as_str().ok_or(
                     ContractVerifierError::AbstractContract(request.req.contract_name),
                 )?;
@@ -213,12 +314,15 @@ impl ContractVerifier {
         config: ContractVerifierConfig,
     ) -> Result<CompilationArtifacts, ContractVerifierError> {
         // Users may provide either just contract name or
-        // source file name and contract name joined with ":".
-        let contract_name =
-            if let Some((_file_name, contract_name)) = request.req.contract_name.rsplit_once(':') {
-                contract_name.to_string()
+        // source file path and contract name joined with ":".
+        let (file_path, contract_name) =
+            if let Some((file_path, contract_name)) = request.req.contract_name.rsplit_once(':') {
+                (
+                    Some(contract_file_path(file_path)),
+                    contract_name.to_string(),
+                )
             } else {
-                request.req.contract_name.clone()
+                (None, request.req.contract_name.clone())
             };
         let input = Self::build_zkvyper_input(request.clone())?;
 
@@ -259,7 +363,12 @@ impl ContractVerifier {
             .ok_or(ContractVerifierError::InternalError)?;
         for (path, artifact) in object {
             let path = Path::new(&path);
-            if path.file_name().unwrap().to_str().unwrap() == file_name {
+            let is_matching = match &file_path {
+                // Output paths are absolute, so the specified path is matched as a suffix.
+                Some(file_path) => path.ends_with(file_path),
+                None => path.file_name().and_then(|name| name.to_str()) == Some(file_name.as_str()),
+            };
+            if is_matching {
                 let bytecode_str = artifact["bytecode"]
                     .as_str()
                     .ok_or(ContractVerifierError::InternalError)?;
@@ -274,6 +383,65 @@ impl ContractVerifier {
         Err(ContractVerifierError::MissingContract(contract_name))
     }
 
+    /// Returns the cache key for compilation artifacts. Request fields not affecting compilation are reset.
+    fn artifacts_cache_key(request: &VerificationIncomingRequest) -> String {
+        let mut request = request.clone();
+        request.contract_address = Address::zero();
+        request.constructor_arguments = Vec::new().into();
+        let serialized_request =
+            serde_json::to_vec(&request).expect("failed serializing verification request");
+        hex::encode(keccak256(&serialized_request))
+    }
+
+    async fn compile_with_cache(
+        &self,
+        request: VerificationRequest,
+    ) -> Result<CompilationArtifacts, ContractVerifierError> {
+        let Some(object_store) = &self.artifacts_cache else {
+            return Self::compile(request, self.config.clone()).await;
+        };
+
+        let key = Self::artifacts_cache_key(&request.req);
+        match object_store
+            .get_raw(Bucket::ContractVerifierArtifacts, &key)
+            .await
+        {
+            Ok(bytes) => match serde_json::from_slice(&bytes) {
+                Ok(artifacts) => {
+                    tracing::info!(
+                        "Using cached compilation artifacts for request {}",
+                        request.id
+                    );
+                    return Ok(artifacts);
+                }
+                Err(err) => {
+                    tracing::warn!(
+                        "Failed deserializing cached compilation artifacts `{key}`: {err}"
+                    );
+                }
+            },
+            Err(ObjectStoreError::KeyNotFound(_)) => { /* continue to compilation */ }
+            Err(err) => {
+                tracing::warn!("Failed getting cached compilation artifacts `{key}`: {err}");
+            }
+        }
+
+        let artifacts = Self::compile(request, self.config.clone()).await?;
+        let serialized_artifacts =
+            serde_json::to_vec(&artifacts).expect("failed serializing compilation artifacts");
+        if let Err(err) = object_store
+            .put_raw(
+                Bucket::ContractVerifierArtifacts,
+                &key,
+                serialized_artifacts,
+            )
+            .await
+        {
+            tracing::warn!("Failed caching compilation artifacts `{key}`: {err}");
+        }
+        Ok(artifacts)
+    }
+
     async fn compile(
         request: VerificationRequest,
         config: ContractVerifierConfig,
@@ -346,12 +514,32 @@ impl ContractVerifier {
     fn build_zkvyper_input(
         request: VerificationRequest,
     ) -> Result<ZkVyperInput, ContractVerifierError> {
-        let sources = match request.req.source_code_data {
-            SourceCodeData::VyperMultiFile(s) => s,
+        let (sources, interfaces) = match request.req.source_code_data {
+            SourceCodeData::VyperMultiFile(s) => (s, HashMap::new()),
+            SourceCodeData::VyperStandardJsonInput(map) => {
+                let input: VyperStandardJson =
+                    serde_json::from_value(serde_json::Value::Object(map))
+                        .map_err(|_| ContractVerifierError::FailedToDeserializeInput)?;
+                if input.language != "Vyper" {
+                    return Err(ContractVerifierError::FailedToDeserializeInput);
+                }
+                let sources = input
+                    .sources
+                    .into_iter()
+                    .map(|(name, source)| (name, source.content))
+                    .collect();
+                let interfaces = input
+                    .interfaces
+                    .into_iter()
+                    .map(|(name, interface)| (name, interface.into_content()))
+                    .collect();
+                (sources, interfaces)
+            }
             _ => panic!("Unexpected SourceCode variant"),
         };
         Ok(ZkVyperInput {
             sources,
+            interfaces,
             optimizer_mode: request.req.optimizer_mode,
         })
     }
@@ -514,16 +702,14 @@ impl JobProcessor for ContractVerifier {
         job: VerificationRequest,
         started_at: Instant,
     ) -> tokio::task::JoinHandle<anyhow::Result<()>> {
-        let connection_pool = self.connection_pool.clone();
+        let this = self.clone();
         tokio::task::spawn(async move {
             tracing::info!("Started to process request with id = {}", job.id);
 
-            let config: ContractVerifierConfig =
-                ContractVerifierConfig::from_env().context("ContractVerifierConfig")?;
-            let mut connection = connection_pool.connection().await.unwrap();
+            let mut connection = this.connection_pool.connection().await.unwrap();
 
             let job_id = job.id;
-            let verification_result = Self::verify(&mut connection, job, config).await;
+            let verification_result = this.verify(&mut connection, job).await;
             Self::process_result(&mut connection, job_id, verification_result).await;
 
             API_CONTRACT_VERIFIER_METRICS
@@ -0,0 +1,134 @@
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::Write,
+    path::{Component, Path, PathBuf},
+    process::Stdio,
+};
+
+use serde::Deserialize;
+
+use crate::{error::ContractVerifierError, zksolc_utils::Source};
+
+#[derive(Debug)]
+pub struct ZkVyperInput {
+    /// Compiled contract sources keyed by their file path.
+    pub sources: HashMap<String, String>,
+    /// Interfaces keyed by their file path. Interfaces can be imported by contracts, but are not compiled themselves.
+    pub interfaces: HashMap<String, String>,
+    pub optimizer_mode: Option<String>,
+}
+
+/// Vyper standard JSON input. Only the fields relevant for verification are parsed.
+#[derive(Debug, Deserialize)]
+pub struct VyperStandardJson {
+    /// The input language; must be `Vyper`.
+    pub language: String,
+    /// The input source code files hashmap.
+    pub sources: HashMap<String, Source>,
+    /// Interfaces specified either as Vyper sources (`content`) or as JSON ABIs (`abi`).
+    #[serde(default)]
+    pub interfaces: HashMap<String, VyperInterface>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum VyperInterface {
+    Source { content: String },
+    Abi { abi: serde_json::Value },
+}
+
+impl VyperInterface {
+    /// Returns the interface file contents.
+    pub fn into_content(self) -> String {
+        match self {
+            Self::Source { content } => content,
+            Self::Abi { abi } => abi.to_string(),
+        }
+    }
+}
+
+/// Returns the path to the contract file as written for compilation.
+pub fn contract_file_path(name: &str) -> String {
+    if name.ends_with(".vy") {
+        name.to_owned()
+    } else {
+        format!("{name}.vy")
+    }
+}
+
+fn write_file(dir: &Path, name: &str, content: &str) -> Result<PathBuf, ContractVerifierError> {
+    // Disallow writing outside the temporary directory (e.g., via absolute paths or `..` components).
+    let is_valid = Path::new(name)
+        .components()
+        .all(|component| matches!(component, Component::Normal(_)));
+    if !is_valid {
+        return Err(ContractVerifierError::InvalidSourcePath(name.to_owned()));
+    }
+    let path = dir.join(name);
+    if let Some(prefix) = path.parent() {
+        std::fs::create_dir_all(prefix).map_err(|_err| ContractVerifierError::InternalError)?;
+    }
+    let mut file = File::create(&path).map_err(|_err| ContractVerifierError::InternalError)?;
+    file.write_all(content.as_bytes())
+        .map_err(|_err| ContractVerifierError::InternalError)?;
+    Ok(path)
+}
+
+pub struct ZkVyper {
+    zkvyper_path: PathBuf,
+    vyper_path: PathBuf,
+}
+
+impl ZkVyper {
+    pub fn new(zkvyper_path: impl Into<PathBuf>, vyper_path: impl Into<PathBuf>) -> Self {
+        ZkVyper {
+            zkvyper_path: zkvyper_path.into(),
+            vyper_path: vyper_path.into(),
+        }
+    }
+
+    pub async fn async_compile(
+        &self,
+        input: ZkVyperInput,
+    ) -> Result<serde_json::Value, ContractVerifierError> {
+        let mut command = tokio::process::Command::new(&self.zkvyper_path);
+        if let Some(o) = input.optimizer_mode.as_ref() {
+            command.arg("-O").arg(o);
+        }
+        command
+            .arg("--vyper")
+            .arg(self.vyper_path.to_str().unwrap())
+            .arg("-f")
+            .arg("combined_json")
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+
+        let temp_dir = tempfile::tempdir().map_err(|_err| ContractVerifierError::InternalError)?;
+        for (name, content) in &input.interfaces {
+            write_file(temp_dir.path(), name, content)?;
+        }
+        for (name, content) in &input.sources {
+            let path = write_file(temp_dir.path(), &contract_file_path(name), content)?;
+            command.arg(path.into_os_string());
+        }
+        // Imports are resolved relative to the working directory.
+        command.current_dir(temp_dir.path());
+
+        let child = command
+            .spawn()
+            .map_err(|_err| ContractVerifierError::InternalError)?;
+        let output = child
+            .wait_with_output()
+            .await
+            .map_err(|_err| ContractVerifierError::InternalError)?;
+        if output.status.success() {
+            Ok(serde_json::from_slice(&output.stdout).expect("Compiler output must be valid JSON"))
+        } else {
+            Err(ContractVerifierError::CompilerError(
+                "zkvyper".to_string(),
+                String::from_utf8_lossy(&output.stderr).to_string(),
+            ))
+        }
+    }
+}
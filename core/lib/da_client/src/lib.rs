@@ -0,0 +1,50 @@
+pub mod split;
+pub mod types;
+
+use std::fmt;
+
+use async_trait::async_trait;
+use types::{DAError, DispatchResponse, InclusionData};
+
+/// Trait that defines the interface for the data availability layer clients.
+#[async_trait]
+pub trait DataAvailabilityClient: Sync + Send + fmt::Debug {
+    /// Dispatches a blob to the data availability layer.
+    async fn dispatch_blob(
+        &self,
+        batch_number: u32,
+        data: Vec<u8>,
+    ) -> Result<DispatchResponse, DAError>;
+
+    /// Fetches the inclusion data for a given blob ID. Returns `None` if the blob isn't included yet.
+    async fn get_inclusion_data(&self, blob_id: &str) -> Result<Option<InclusionData>, DAError>;
+
+    /// Fetches the blob referenced by the inclusion data (in the form committed to L1) from the DA layer,
+    /// checking that the inclusion data is valid in the process. Returns `None` if the blob is not found.
+    ///
+    /// Used by external nodes to verify the pubdata of committed L1 batches. The default implementation
+    /// returns a non-transient error, i.e., blob retrieval is not supported by the client.
+    async fn get_blob(&self, inclusion_data: &[u8]) -> Result<Option<Vec<u8>>, DAError> {
+        let _ = inclusion_data;
+        Err(DAError {
+            error: anyhow::anyhow!("blob retrieval is not supported by {self:?}"),
+            is_transient: false,
+        })
+    }
+
+    /// Returns the name of the DA layer the client dispatches blobs to, e.g. to label metrics
+    /// and DA cost records.
+    fn client_type(&self) -> &'static str;
+
+    /// Clones the client and wraps it in a `Box`.
+    fn clone_boxed(&self) -> Box<dyn DataAvailabilityClient>;
+
+    /// Returns the maximum size of the blob (in bytes) that can be dispatched. `None` means no limit.
+    fn blob_size_limit(&self) -> Option<usize>;
+}
+
+impl Clone for Box<dyn DataAvailabilityClient> {
+    fn clone(&self) -> Box<dyn DataAvailabilityClient> {
+        self.clone_boxed()
+    }
+}
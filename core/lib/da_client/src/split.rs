@@ -0,0 +1,415 @@
+//! Splitting of pubdata exceeding the blob size limit of the DA layer into multiple blobs.
+//!
+//! Each part of a split blob is prefixed with a header consisting of the part index, the total number
+//! of parts and the payload length (big-endian `u16`, `u16` and `u32` respectively). The payload length allows
+//! reassembling blobs padded by the DA layer. Blob IDs of split blobs have the `split:` prefix followed by
+//! the comma-separated blob IDs of the parts; the inclusion data is [`SPLIT_INCLUSION_DATA_MAGIC`]
+//! followed by the number of parts and the length-prefixed inclusion data of each part.
+//!
+//! Pubdata fitting into a single blob is dispatched as is, so the blob IDs and inclusion data for such pubdata
+//! are the same as for the wrapped client.
+
+use std::fmt;
+
+use async_trait::async_trait;
+
+use crate::{
+    types::{DAError, DispatchResponse, InclusionData},
+    DataAvailabilityClient,
+};
+
+/// Prefix of the inclusion data for split blobs.
+pub const SPLIT_INCLUSION_DATA_MAGIC: &[u8; 8] = b"zkDAsplt";
+/// Prefix of blob IDs for split blobs.
+const SPLIT_BLOB_ID_PREFIX: &str = "split:";
+/// Size of the header prefixed to each part of a split blob.
+const PART_HEADER_SIZE: usize = 8;
+
+/// [`DataAvailabilityClient`] decorator splitting pubdata exceeding the blob size limit of the wrapped client
+/// into multiple blobs, and reassembling them when retrieving blobs.
+#[derive(Clone)]
+pub struct SplittingClient {
+    inner: Box<dyn DataAvailabilityClient>,
+}
+
+impl fmt::Debug for SplittingClient {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_tuple("SplittingClient")
+            .field(&self.inner)
+            .finish()
+    }
+}
+
+impl SplittingClient {
+    pub fn new(inner: Box<dyn DataAvailabilityClient>) -> Self {
+        Self { inner }
+    }
+
+    /// Returns the maximum payload size of a single part, or `None` if blobs are never split.
+    fn max_part_payload_size(&self) -> Result<Option<usize>, DAError> {
+        let Some(limit) = self.inner.blob_size_limit() else {
+            return Ok(None);
+        };
+        if limit <= PART_HEADER_SIZE {
+            return Err(to_non_retriable_da_error(anyhow::anyhow!(
+                "blob size limit ({limit} bytes) is too small to split blobs"
+            )));
+        }
+        Ok(Some(limit - PART_HEADER_SIZE))
+    }
+}
+
+#[async_trait]
+impl DataAvailabilityClient for SplittingClient {
+    async fn dispatch_blob(
+        &self,
+        batch_number: u32,
+        data: Vec<u8>,
+    ) -> Result<DispatchResponse, DAError> {
+        let part_size = match self.max_part_payload_size()? {
+            Some(part_size) if data.len() > part_size + PART_HEADER_SIZE => part_size,
+            _ => return self.inner.dispatch_blob(batch_number, data).await,
+        };
+
+        let parts = split_blob(&data, part_size)?;
+        let part_count = parts.len();
+        let mut blob_ids = Vec::with_capacity(part_count);
+        let mut fee = Some(0_u64);
+        // If dispatching a part fails, all parts will be re-dispatched on retry. This is wasteful,
+        // but allows to not persist partially dispatched blobs.
+        for part in parts {
+            let response = self.inner.dispatch_blob(batch_number, part).await?;
+            if response.blob_id.contains(',') {
+                return Err(to_non_retriable_da_error(anyhow::anyhow!(
+                    "blob ID `{}` cannot be used for split blobs",
+                    response.blob_id
+                )));
+            }
+            blob_ids.push(response.blob_id);
+            fee = fee
+                .zip(response.fee)
+                .map(|(total, fee)| total.saturating_add(fee));
+        }
+        tracing::debug!(
+            "Split pubdata for L1 batch #{batch_number} ({} bytes) into {part_count} blobs",
+            data.len()
+        );
+        Ok(DispatchResponse {
+            blob_id: format!("{SPLIT_BLOB_ID_PREFIX}{}", blob_ids.join(",")),
+            fee,
+        })
+    }
+
+    async fn get_inclusion_data(&self, blob_id: &str) -> Result<Option<InclusionData>, DAError> {
+        let Some(part_ids) = blob_id.strip_prefix(SPLIT_BLOB_ID_PREFIX) else {
+            return self.inner.get_inclusion_data(blob_id).await;
+        };
+
+        let mut parts = vec![];
+        for part_id in part_ids.split(',') {
+            let Some(part) = self.inner.get_inclusion_data(part_id).await? else {
+                return Ok(None);
+            };
+            parts.push(part.data);
+        }
+        let data = encode_inclusion_data(&parts)?;
+        Ok(Some(InclusionData { data }))
+    }
+
+    async fn get_blob(&self, inclusion_data: &[u8]) -> Result<Option<Vec<u8>>, DAError> {
+        let Some(inclusion_data) = inclusion_data.strip_prefix(SPLIT_INCLUSION_DATA_MAGIC) else {
+            return self.inner.get_blob(inclusion_data).await;
+        };
+
+        let parts_inclusion_data =
+            decode_inclusion_data(inclusion_data).map_err(to_non_retriable_da_error)?;
+        let mut parts = Vec::with_capacity(parts_inclusion_data.len());
+        for part_inclusion_data in parts_inclusion_data {
+            let Some(part) = self.inner.get_blob(part_inclusion_data).await? else {
+                return Ok(None);
+            };
+            parts.push(part);
+        }
+        reassemble_blob(&parts)
+            .map(Some)
+            .map_err(to_non_retriable_da_error)
+    }
+
+    fn client_type(&self) -> &'static str {
+        self.inner.client_type()
+    }
+
+    fn clone_boxed(&self) -> Box<dyn DataAvailabilityClient> {
+        Box::new(self.clone())
+    }
+
+    fn blob_size_limit(&self) -> Option<usize> {
+        None
+    }
+}
+
+fn split_blob(data: &[u8], part_size: usize) -> Result<Vec<Vec<u8>>, DAError> {
+    let part_count = data.len().div_ceil(part_size);
+    let part_count = u16::try_from(part_count).map_err(|_| {
+        to_non_retriable_da_error(anyhow::anyhow!(
+            "pubdata ({} bytes) requires too many blobs ({part_count})",
+            data.len()
+        ))
+    })?;
+
+    let parts = data.chunks(part_size).enumerate().map(|(index, payload)| {
+        let mut part = Vec::with_capacity(PART_HEADER_SIZE + payload.len());
+        part.extend_from_slice(&(index as u16).to_be_bytes());
+        part.extend_from_slice(&part_count.to_be_bytes());
+        part.extend_from_slice(&(payload.len() as u32).to_be_bytes());
+        part.extend_from_slice(payload);
+        part
+    });
+    Ok(parts.collect())
+}
+
+fn reassemble_blob(parts: &[Vec<u8>]) -> anyhow::Result<Vec<u8>> {
+    let mut data = vec![];
+    for (expected_index, part) in parts.iter().enumerate() {
+        anyhow::ensure!(
+            part.len() >= PART_HEADER_SIZE,
+            "blob part #{expected_index} is too short ({} bytes)",
+            part.len()
+        );
+        let (header, payload) = part.split_at(PART_HEADER_SIZE);
+        let index = u16::from_be_bytes([header[0], header[1]]);
+        let count = u16::from_be_bytes([header[2], header[3]]);
+        let payload_len = u32::from_be_bytes([header[4], header[5], header[6], header[7]]);
+
+        anyhow::ensure!(
+            usize::from(index) == expected_index && usize::from(count) == parts.len(),
+            "unexpected header of blob part #{expected_index}: index {index}, part count {count}"
+        );
+        let payload_len = payload_len as usize;
+        anyhow::ensure!(
+            payload.len() >= payload_len,
+            "blob part #{expected_index} is shorter than its payload length ({} < {payload_len})",
+            payload.len()
+        );
+        // The remaining bytes are padding added by the DA layer.
+        data.extend_from_slice(&payload[..payload_len]);
+    }
+    Ok(data)
+}
+
+fn encode_inclusion_data(parts: &[Vec<u8>]) -> Result<Vec<u8>, DAError> {
+    let to_u32 = |len: usize| {
+        u32::try_from(len).map_err(|_| {
+            to_non_retriable_da_error(anyhow::anyhow!("inclusion data length overflow"))
+        })
+    };
+
+    let mut data = SPLIT_INCLUSION_DATA_MAGIC.to_vec();
+    data.extend_from_slice(&to_u32(parts.len())?.to_be_bytes());
+    for part in parts {
+        data.extend_from_slice(&to_u32(part.len())?.to_be_bytes());
+        data.extend_from_slice(part);
+    }
+    Ok(data)
+}
+
+/// Decodes the inclusion data of a split blob (without [`SPLIT_INCLUSION_DATA_MAGIC`]) into the inclusion data
+/// of its parts.
+fn decode_inclusion_data(mut data: &[u8]) -> anyhow::Result<Vec<&[u8]>> {
+    fn read_u32(data: &mut &[u8]) -> anyhow::Result<usize> {
+        anyhow::ensure!(data.len() >= 4, "unexpected end of inclusion data");
+        let (bytes, rest) = data.split_at(4);
+        *data = rest;
+        Ok(u32::from_be_bytes(bytes.try_into().unwrap()) as usize)
+    }
+
+    let part_count = read_u32(&mut data)?;
+    anyhow::ensure!(part_count > 0, "inclusion data has no parts");
+    let mut parts = vec![];
+    for _ in 0..part_count {
+        let len = read_u32(&mut data)?;
+        anyhow::ensure!(data.len() >= len, "unexpected end of inclusion data");
+        let (part, rest) = data.split_at(len);
+        parts.push(part);
+        data = rest;
+    }
+    anyhow::ensure!(
+        data.is_empty(),
+        "inclusion data has {} trailing bytes",
+        data.len()
+    );
+    Ok(parts)
+}
+
+fn to_non_retriable_da_error(error: anyhow::Error) -> DAError {
+    DAError {
+        error,
+        is_transient: false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::HashMap,
+        sync::{Arc, Mutex},
+    };
+
+    use super::*;
+
+    /// Client storing blobs in memory; blobs are padded to 4 bytes to emulate DA layer padding.
+    #[derive(Debug, Clone, Default)]
+    struct MockClient {
+        blobs: Arc<Mutex<HashMap<String, Vec<u8>>>>,
+        limit: Option<usize>,
+    }
+
+    #[async_trait]
+    impl DataAvailabilityClient for MockClient {
+        async fn dispatch_blob(
+            &self,
+            _batch_number: u32,
+            mut data: Vec<u8>,
+        ) -> Result<DispatchResponse, DAError> {
+            if let Some(limit) = self.limit {
+                assert!(data.len() <= limit, "{} > {limit}", data.len());
+            }
+            data.resize(data.len().next_multiple_of(4), 0);
+            let mut blobs = self.blobs.lock().unwrap();
+            let blob_id = blobs.len().to_string();
+            let fee = data.len() as u64;
+            blobs.insert(blob_id.clone(), data);
+            Ok(DispatchResponse::from(blob_id).with_fee(fee))
+        }
+
+        async fn get_inclusion_data(
+            &self,
+            blob_id: &str,
+        ) -> Result<Option<InclusionData>, DAError> {
+            let is_included = self.blobs.lock().unwrap().contains_key(blob_id);
+            Ok(is_included.then(|| InclusionData {
+                data: blob_id.as_bytes().to_vec(),
+            }))
+        }
+
+        async fn get_blob(&self, inclusion_data: &[u8]) -> Result<Option<Vec<u8>>, DAError> {
+            let blob_id = String::from_utf8(inclusion_data.to_vec()).unwrap();
+            Ok(self.blobs.lock().unwrap().get(&blob_id).cloned())
+        }
+
+        fn client_type(&self) -> &'static str {
+            "mock"
+        }
+
+        fn clone_boxed(&self) -> Box<dyn DataAvailabilityClient> {
+            Box::new(self.clone())
+        }
+
+        fn blob_size_limit(&self) -> Option<usize> {
+            self.limit
+        }
+    }
+
+    async fn dispatch_and_retrieve(client: &SplittingClient, data: Vec<u8>) -> String {
+        let response = client.dispatch_blob(1, data.clone()).await.unwrap();
+        let inclusion_data = client
+            .get_inclusion_data(&response.blob_id)
+            .await
+            .unwrap()
+            .unwrap();
+        let blob = client
+            .get_blob(&inclusion_data.data)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(blob[..data.len()], data);
+        response.blob_id
+    }
+
+    #[tokio::test]
+    async fn small_blobs_are_not_split() {
+        let inner = MockClient {
+            limit: Some(32),
+            ..MockClient::default()
+        };
+        let client = SplittingClient::new(Box::new(inner.clone()));
+        assert_eq!(client.blob_size_limit(), None);
+
+        let blob_id = dispatch_and_retrieve(&client, vec![1; 32]).await;
+        assert_eq!(blob_id, "0");
+        assert_eq!(inner.blobs.lock().unwrap()["0"], [1; 32]);
+    }
+
+    #[tokio::test]
+    async fn large_blobs_are_split() {
+        let inner = MockClient {
+            limit: Some(32),
+            ..MockClient::default()
+        };
+        let client = SplittingClient::new(Box::new(inner.clone()));
+
+        let data: Vec<u8> = (0..90).collect();
+        let response = client.dispatch_blob(1, data.clone()).await.unwrap();
+        // Fees of the mock client are equal to the padded blob sizes.
+        assert_eq!(response.fee, Some(32 * 3 + 28));
+        let blob_id = dispatch_and_retrieve(&client, data.clone()).await;
+        assert_eq!(blob_id, "split:4,5,6,7");
+
+        let blobs = inner.blobs.lock().unwrap();
+        assert_eq!(blobs["4"][..PART_HEADER_SIZE], [0, 0, 0, 4, 0, 0, 0, 24]);
+        assert_eq!(blobs["7"][..PART_HEADER_SIZE], [0, 3, 0, 4, 0, 0, 0, 18]);
+        // The last part is padded by the mock client, but the padding is removed on reassembly.
+        assert_eq!(blobs["7"].len(), 28);
+        drop(blobs);
+
+        let inclusion_data = client.get_inclusion_data(&blob_id).await.unwrap().unwrap();
+        let blob = client.get_blob(&inclusion_data.data).await.unwrap();
+        assert_eq!(blob, Some(data));
+    }
+
+    #[tokio::test]
+    async fn split_blob_is_not_included_until_all_parts_are() {
+        let inner = MockClient {
+            limit: Some(32),
+            ..MockClient::default()
+        };
+        let client = SplittingClient::new(Box::new(inner.clone()));
+        let response = client.dispatch_blob(1, vec![1; 64]).await.unwrap();
+        inner.blobs.lock().unwrap().remove("1");
+
+        let inclusion_data = client.get_inclusion_data(&response.blob_id).await.unwrap();
+        assert!(inclusion_data.is_none());
+    }
+
+    #[test]
+    fn reassembly_errors() {
+        let parts = split_blob(&[1; 20], 8).unwrap();
+        assert_eq!(parts.len(), 3);
+        assert_eq!(reassemble_blob(&parts).unwrap(), [1; 20]);
+
+        let err = reassemble_blob(&[parts[1].clone(), parts[0].clone(), parts[2].clone()])
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("unexpected header"), "{err}");
+        let err = reassemble_blob(&parts[..2]).unwrap_err().to_string();
+        assert!(err.contains("unexpected header"), "{err}");
+        let truncated_part = parts[0][..PART_HEADER_SIZE + 4].to_vec();
+        let err = reassemble_blob(&[truncated_part, parts[1].clone(), parts[2].clone()])
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("shorter than its payload length"), "{err}");
+    }
+
+    #[test]
+    fn inclusion_data_roundtrip() {
+        let parts = vec![vec![1, 2, 3], vec![], vec![4; 40]];
+        let encoded = encode_inclusion_data(&parts).unwrap();
+        let encoded = encoded.strip_prefix(SPLIT_INCLUSION_DATA_MAGIC).unwrap();
+        let decoded = decode_inclusion_data(encoded).unwrap();
+        assert_eq!(decoded, parts.iter().map(Vec::as_slice).collect::<Vec<_>>());
+
+        decode_inclusion_data(&encoded[..encoded.len() - 1]).unwrap_err();
+        decode_inclusion_data(&[0; 4]).unwrap_err();
+    }
+}
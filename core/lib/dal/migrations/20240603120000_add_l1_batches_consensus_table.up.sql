@@ -0,0 +1,8 @@
+CREATE TABLE IF NOT EXISTS l1_batches_consensus (
+    l1_batch_number BIGINT PRIMARY KEY,
+    batch_hash BYTEA NOT NULL,
+    attester BYTEA NOT NULL,
+    signature BYTEA NOT NULL,
+    created_at TIMESTAMP NOT NULL,
+    updated_at TIMESTAMP NOT NULL
+);
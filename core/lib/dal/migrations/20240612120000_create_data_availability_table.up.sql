@@ -0,0 +1,12 @@
+CREATE TABLE IF NOT EXISTS data_availability
+(
+    l1_batch_number BIGINT PRIMARY KEY REFERENCES l1_batches (number) ON DELETE CASCADE,
+
+    -- The blob is an abstract term here, unrelated to any particular DA implementation.
+    blob_id         TEXT      NOT NULL,
+    inclusion_data  BYTEA,
+    sent_at         TIMESTAMP NOT NULL,
+
+    created_at      TIMESTAMP NOT NULL,
+    updated_at      TIMESTAMP NOT NULL
+);
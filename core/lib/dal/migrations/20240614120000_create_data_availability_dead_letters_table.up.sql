@@ -0,0 +1,14 @@
+CREATE TABLE IF NOT EXISTS data_availability_dead_letters
+(
+    l1_batch_number BIGINT PRIMARY KEY REFERENCES l1_batches (number) ON DELETE CASCADE,
+
+    -- Stage at which the L1 batch has failed: either 'dispatch' or 'inclusion'.
+    stage           TEXT      NOT NULL,
+    -- Blob ID; only set for L1 batches that have failed at the inclusion stage.
+    blob_id         TEXT,
+    error           TEXT      NOT NULL,
+    attempts        INT       NOT NULL,
+
+    created_at      TIMESTAMP NOT NULL,
+    updated_at      TIMESTAMP NOT NULL
+);
@@ -0,0 +1,16 @@
+CREATE TABLE IF NOT EXISTS data_availability_costs
+(
+    l1_batch_number BIGINT    NOT NULL REFERENCES l1_batches (number) ON DELETE CASCADE,
+    -- Name of the DA layer the pubdata was dispatched to.
+    client_type     TEXT      NOT NULL,
+    -- Total size of the dispatched blobs; accumulated if the L1 batch pubdata is dispatched multiple times.
+    blob_size_bytes BIGINT    NOT NULL,
+    -- Total fee paid in the smallest units of the DA layer's native token, or NULL if unknown.
+    fee             BIGINT,
+
+    created_at      TIMESTAMP NOT NULL,
+    updated_at      TIMESTAMP NOT NULL,
+    PRIMARY KEY (l1_batch_number, client_type)
+);
+
+CREATE INDEX IF NOT EXISTS data_availability_costs_updated_at_idx ON data_availability_costs (updated_at);
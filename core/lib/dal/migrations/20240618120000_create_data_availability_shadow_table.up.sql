@@ -0,0 +1,16 @@
+CREATE TABLE IF NOT EXISTS data_availability_shadow
+(
+    l1_batch_number BIGINT    NOT NULL REFERENCES l1_batches (number) ON DELETE CASCADE,
+    -- Name of the shadow DA layer.
+    client_type     TEXT      NOT NULL,
+    -- NULL if dispatching the pubdata to the shadow DA layer has failed.
+    blob_id         TEXT,
+    inclusion_data  BYTEA,
+    -- Error returned by the shadow DA layer when dispatching the pubdata or getting the inclusion data.
+    error           TEXT,
+    sent_at         TIMESTAMP NOT NULL,
+
+    created_at      TIMESTAMP NOT NULL,
+    updated_at      TIMESTAMP NOT NULL,
+    PRIMARY KEY (l1_batch_number, client_type)
+);
@@ -0,0 +1,3 @@
+DROP TRIGGER IF EXISTS admin_audit_log_append_only ON admin_audit_log;
+DROP FUNCTION IF EXISTS admin_audit_log_reject_changes;
+DROP TABLE IF EXISTS admin_audit_log;
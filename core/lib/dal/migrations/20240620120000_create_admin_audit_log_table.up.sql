@@ -0,0 +1,24 @@
+CREATE TABLE IF NOT EXISTS admin_audit_log
+(
+    id         BIGSERIAL PRIMARY KEY,
+    -- Who performed the action, e.g. `cli:alice` or `admin_api`.
+    actor      TEXT      NOT NULL,
+    -- Machine-readable action name, e.g. `revert_l1_batches`.
+    action     TEXT      NOT NULL,
+    -- Action-specific parameters.
+    params     JSONB     NOT NULL,
+    created_at TIMESTAMP NOT NULL
+);
+
+CREATE OR REPLACE FUNCTION admin_audit_log_reject_changes() RETURNS TRIGGER AS
+$$
+BEGIN
+    RAISE EXCEPTION 'admin_audit_log is append-only';
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER admin_audit_log_append_only
+    BEFORE UPDATE OR DELETE
+    ON admin_audit_log
+    FOR EACH ROW
+EXECUTE FUNCTION admin_audit_log_reject_changes();
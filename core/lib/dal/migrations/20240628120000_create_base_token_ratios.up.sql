@@ -0,0 +1,11 @@
+CREATE TABLE IF NOT EXISTS base_token_ratios
+(
+    id              SERIAL PRIMARY KEY,
+    -- Ratio between the smallest units of the base token and wei: `numerator` base token units are worth `denominator` wei.
+    numerator       NUMERIC(20, 0) NOT NULL,
+    denominator     NUMERIC(20, 0) NOT NULL,
+    -- When the ratio was fetched from the price sources.
+    ratio_timestamp TIMESTAMP      NOT NULL,
+    created_at      TIMESTAMP      NOT NULL,
+    updated_at      TIMESTAMP      NOT NULL
+);
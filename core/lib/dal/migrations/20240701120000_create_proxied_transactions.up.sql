@@ -0,0 +1,18 @@
+-- Persistent resubmission queue of transactions proxied by the external node to the main node.
+CREATE TABLE IF NOT EXISTS proxied_transactions
+(
+    tx_hash           BYTEA PRIMARY KEY,
+    initiator_address BYTEA     NOT NULL,
+    nonce             BIGINT    NOT NULL,
+    -- Raw transaction bytes as received by `eth_sendRawTransaction`.
+    raw_tx            BYTEA     NOT NULL,
+    -- Number of (re)submission attempts performed by the resubmission task.
+    attempts          INT       NOT NULL DEFAULT 0,
+    next_attempt_at   TIMESTAMP NOT NULL,
+    expires_at        TIMESTAMP NOT NULL,
+    created_at        TIMESTAMP NOT NULL,
+    updated_at        TIMESTAMP NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS proxied_transactions_next_attempt_at_idx
+    ON proxied_transactions (next_attempt_at);
@@ -0,0 +1,2 @@
+-- Source of the tree data (root hash) for L1 batches processed by the tree data fetcher on external nodes.
+ALTER TABLE l1_batches ADD COLUMN IF NOT EXISTS tree_data_source TEXT;
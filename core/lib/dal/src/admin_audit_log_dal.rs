@@ -0,0 +1,143 @@
+use chrono::NaiveDateTime;
+use serde::Serialize;
+use zksync_db_connection::{connection::Connection, error::DalResult, instrument::InstrumentExt};
+
+use crate::Core;
+
+#[derive(Debug)]
+pub struct AdminAuditLogDal<'a, 'c> {
+    pub(crate) storage: &'a mut Connection<'c, Core>,
+}
+
+/// Entry of the append-only log of administrative actions (reverts, configuration changes etc.).
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AdminAuditLogEntry {
+    pub id: u64,
+    /// Who performed the action, e.g. `cli:alice` or `admin_api`.
+    pub actor: String,
+    /// Machine-readable action name, e.g. `revert_l1_batches`.
+    pub action: String,
+    /// Action-specific parameters.
+    pub params: serde_json::Value,
+    pub created_at: NaiveDateTime,
+}
+
+impl AdminAuditLogDal<'_, '_> {
+    /// Records an administrative action. Returns the ID of the created entry.
+    pub async fn insert_entry(
+        &mut self,
+        actor: &str,
+        action: &str,
+        params: &serde_json::Value,
+    ) -> DalResult<u64> {
+        let id = sqlx::query_scalar!(
+            r#"
+            INSERT INTO
+                admin_audit_log (actor, action, params, created_at)
+            VALUES
+                ($1, $2, $3, NOW())
+            RETURNING
+                id
+            "#,
+            actor,
+            action,
+            params
+        )
+        .instrument("insert_admin_audit_log_entry")
+        .with_arg("actor", &actor)
+        .with_arg("action", &action)
+        .fetch_one(self.storage)
+        .await?;
+        Ok(id as u64)
+    }
+
+    /// Returns up to `limit` latest entries with IDs less than `before_id` (if specified), newest first.
+    pub async fn get_entries(
+        &mut self,
+        before_id: Option<u64>,
+        limit: usize,
+    ) -> DalResult<Vec<AdminAuditLogEntry>> {
+        let before_id = before_id.map_or(i64::MAX, |id| id as i64);
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                id,
+                actor,
+                action,
+                params,
+                created_at
+            FROM
+                admin_audit_log
+            WHERE
+                id < $1
+            ORDER BY
+                id DESC
+            LIMIT
+                $2
+            "#,
+            before_id,
+            limit as i64
+        )
+        .instrument("get_admin_audit_log_entries")
+        .with_arg("before_id", &before_id)
+        .with_arg("limit", &limit)
+        .fetch_all(self.storage)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| AdminAuditLogEntry {
+                id: row.id as u64,
+                actor: row.actor,
+                action: row.action,
+                params: row.params,
+                created_at: row.created_at,
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ConnectionPool, CoreDal};
+
+    #[tokio::test]
+    async fn inserting_and_paginating_audit_log_entries() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = pool.connection().await.unwrap();
+
+        let mut ids = vec![];
+        for i in 0..3 {
+            let params = serde_json::json!({ "l1_batch_number": i });
+            let id = conn
+                .admin_audit_log_dal()
+                .insert_entry("test", "revert_l1_batches", &params)
+                .await
+                .unwrap();
+            ids.push(id);
+        }
+
+        let entries = conn
+            .admin_audit_log_dal()
+            .get_entries(None, 2)
+            .await
+            .unwrap();
+        let entry_ids: Vec<_> = entries.iter().map(|entry| entry.id).collect();
+        assert_eq!(entry_ids, [ids[2], ids[1]]);
+        assert_eq!(entries[0].actor, "test");
+        assert_eq!(entries[0].action, "revert_l1_batches");
+        assert_eq!(
+            entries[0].params,
+            serde_json::json!({ "l1_batch_number": 2 })
+        );
+
+        let entries = conn
+            .admin_audit_log_dal()
+            .get_entries(Some(ids[1]), 10)
+            .await
+            .unwrap();
+        let entry_ids: Vec<_> = entries.iter().map(|entry| entry.id).collect();
+        assert_eq!(entry_ids, [ids[0]]);
+    }
+}
@@ -0,0 +1,140 @@
+use std::{num::NonZeroU64, time::SystemTime};
+
+use bigdecimal::{BigDecimal, ToPrimitive};
+use chrono::{DateTime, NaiveDateTime, Utc};
+use zksync_db_connection::{
+    connection::Connection,
+    error::{DalResult, SqlxContext},
+    instrument::InstrumentExt,
+};
+
+use crate::Core;
+
+#[derive(Debug)]
+pub struct BaseTokenDal<'a, 'c> {
+    pub(crate) storage: &'a mut Connection<'c, Core>,
+}
+
+/// Base token / ETH conversion ratio persisted by the base token ratio persister.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StoredBaseTokenRatio {
+    pub id: u32,
+    /// Number of the smallest base token units worth `denominator` wei.
+    pub numerator: NonZeroU64,
+    pub denominator: NonZeroU64,
+    /// When the ratio was fetched from the price sources.
+    pub ratio_timestamp: NaiveDateTime,
+}
+
+impl BaseTokenDal<'_, '_> {
+    /// Persists a new base token ratio. Returns the ID of the inserted row.
+    pub async fn insert_token_ratio(
+        &mut self,
+        numerator: NonZeroU64,
+        denominator: NonZeroU64,
+        ratio_timestamp: SystemTime,
+    ) -> DalResult<u32> {
+        let ratio_timestamp = DateTime::<Utc>::from(ratio_timestamp).naive_utc();
+        let id = sqlx::query_scalar!(
+            r#"
+            INSERT INTO
+                base_token_ratios (numerator, denominator, ratio_timestamp, created_at, updated_at)
+            VALUES
+                ($1, $2, $3, NOW(), NOW())
+            RETURNING
+                id
+            "#,
+            BigDecimal::from(numerator.get()),
+            BigDecimal::from(denominator.get()),
+            ratio_timestamp,
+        )
+        .instrument("insert_token_ratio")
+        .with_arg("numerator", &numerator)
+        .with_arg("denominator", &denominator)
+        .with_arg("ratio_timestamp", &ratio_timestamp)
+        .fetch_one(self.storage)
+        .await?;
+        Ok(id as u32)
+    }
+
+    /// Returns the most recently persisted base token ratio, if any.
+    pub async fn get_latest_ratio(&mut self) -> DalResult<Option<StoredBaseTokenRatio>> {
+        sqlx::query!(
+            r#"
+            SELECT
+                id,
+                numerator,
+                denominator,
+                ratio_timestamp
+            FROM
+                base_token_ratios
+            ORDER BY
+                ratio_timestamp DESC
+            LIMIT
+                1
+            "#
+        )
+        .try_map(|row| {
+            Ok(StoredBaseTokenRatio {
+                id: row.id as u32,
+                numerator: parse_ratio_part(&row.numerator).decode_column("numerator")?,
+                denominator: parse_ratio_part(&row.denominator).decode_column("denominator")?,
+                ratio_timestamp: row.ratio_timestamp,
+            })
+        })
+        .instrument("get_latest_ratio")
+        .fetch_optional(self.storage)
+        .await
+    }
+}
+
+fn parse_ratio_part(value: &BigDecimal) -> Result<NonZeroU64, String> {
+    value
+        .to_u64()
+        .and_then(NonZeroU64::new)
+        .ok_or_else(|| format!("{value} is not a positive u64 value"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ConnectionPool, CoreDal};
+
+    #[tokio::test]
+    async fn inserting_and_getting_base_token_ratios() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = pool.connection().await.unwrap();
+
+        let latest = conn.base_token_dal().get_latest_ratio().await.unwrap();
+        assert_eq!(latest, None);
+
+        let timestamp = SystemTime::now();
+        let numerator = NonZeroU64::new(3).unwrap();
+        let denominator = NonZeroU64::new(u64::MAX).unwrap();
+        let older_timestamp = timestamp - std::time::Duration::from_secs(60);
+        conn.base_token_dal()
+            .insert_token_ratio(denominator, numerator, older_timestamp)
+            .await
+            .unwrap();
+        let id = conn
+            .base_token_dal()
+            .insert_token_ratio(numerator, denominator, timestamp)
+            .await
+            .unwrap();
+
+        let latest = conn
+            .base_token_dal()
+            .get_latest_ratio()
+            .await
+            .unwrap()
+            .expect("no ratio");
+        assert_eq!(latest.id, id);
+        assert_eq!(latest.numerator, numerator);
+        assert_eq!(latest.denominator, denominator);
+        // Postgres stores timestamps with microsecond precision.
+        assert_eq!(
+            latest.ratio_timestamp.and_utc().timestamp_micros(),
+            DateTime::<Utc>::from(timestamp).timestamp_micros()
+        );
+    }
+}
@@ -7,6 +7,7 @@ use std::{
 
 use anyhow::Context as _;
 use bigdecimal::{BigDecimal, FromPrimitive, ToPrimitive};
+use serde::Serialize;
 use zksync_db_connection::{
     connection::Connection,
     error::{DalResult, SqlxContext},
@@ -37,6 +38,23 @@ pub struct BlocksDal<'a, 'c> {
     pub(crate) storage: &'a mut Connection<'c, Core>,
 }
 
+/// Numbers of rows removed or reset when rolling back Postgres data to a certain L1 batch.
+/// See [`BlocksDal::get_rollback_stats()`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct RollbackStats {
+    pub l1_batches: u64,
+    pub l2_blocks: u64,
+    /// Transactions that are returned to the mempool.
+    pub transactions: u64,
+    pub events: u64,
+    pub l2_to_l1_logs: u64,
+    pub storage_logs: u64,
+    pub initial_writes: u64,
+    pub factory_deps: u64,
+    pub eth_txs: u64,
+    pub snapshots: u64,
+}
+
 impl BlocksDal<'_, '_> {
     pub async fn get_consistency_checker_last_processed_l1_batch(
         &mut self,
@@ -284,6 +302,37 @@ impl BlocksDal<'_, '_> {
         Ok(l1_batches.into_iter().map(Into::into).collect())
     }
 
+    /// Returns numbers and seal times of L1 batches that are committed, proven or executed
+    /// by the specified Ethereum transaction.
+    pub async fn get_l1_batch_seal_times_for_eth_tx_id(
+        &mut self,
+        eth_tx_id: u32,
+    ) -> DalResult<Vec<(L1BatchNumber, chrono::NaiveDateTime)>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                number,
+                created_at
+            FROM
+                l1_batches
+            WHERE
+                eth_commit_tx_id = $1
+                OR eth_prove_tx_id = $1
+                OR eth_execute_tx_id = $1
+            "#,
+            eth_tx_id as i32
+        )
+        .instrument("get_l1_batch_seal_times_for_eth_tx_id")
+        .with_arg("eth_tx_id", &eth_tx_id)
+        .fetch_all(self.storage)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| (L1BatchNumber(row.number as u32), row.created_at))
+            .collect())
+    }
+
     async fn get_storage_l1_batch(
         &mut self,
         number: L1BatchNumber,
@@ -691,6 +740,7 @@ impl BlocksDal<'_, '_> {
                     virtual_blocks,
                     fair_pubdata_price,
                     gas_limit,
+                    logs_bloom,
                     created_at,
                     updated_at
                 )
@@ -712,6 +762,7 @@ impl BlocksDal<'_, '_> {
                     $14,
                     $15,
                     $16,
+                    $17,
                     NOW(),
                     NOW()
                 )
@@ -738,6 +789,7 @@ impl BlocksDal<'_, '_> {
             i64::from(l2_block_header.virtual_blocks),
             l2_block_header.batch_fee_input.fair_pubdata_price() as i64,
             l2_block_header.gas_limit as i64,
+            l2_block_header.logs_bloom.as_bytes(),
         );
 
         instrumentation.with(query).execute(self.storage).await?;
@@ -764,7 +816,8 @@ impl BlocksDal<'_, '_> {
                 protocol_version,
                 virtual_blocks,
                 fair_pubdata_price,
-                gas_limit
+                gas_limit,
+                logs_bloom
             FROM
                 miniblocks
             ORDER BY
@@ -803,7 +856,8 @@ impl BlocksDal<'_, '_> {
                 protocol_version,
                 virtual_blocks,
                 fair_pubdata_price,
-                gas_limit
+                gas_limit,
+                logs_bloom
             FROM
                 miniblocks
             WHERE
@@ -1661,6 +1715,31 @@ impl BlocksDal<'_, '_> {
             .context("map_l1_batches()")
     }
 
+    /// Returns pubdata (state diffs, L2-to-L1 logs and messages, published bytecodes) computed by the VM
+    /// for the specified L1 batch. Returns `None` if the batch is not sealed, or if it was sealed by a VM
+    /// version not exposing pubdata.
+    pub async fn get_l1_batch_pubdata(
+        &mut self,
+        number: L1BatchNumber,
+    ) -> DalResult<Option<Vec<u8>>> {
+        Ok(sqlx::query!(
+            r#"
+            SELECT
+                pubdata_input
+            FROM
+                l1_batches
+            WHERE
+                number = $1
+            "#,
+            i64::from(number.0)
+        )
+        .instrument("get_l1_batch_pubdata")
+        .with_arg("number", &number)
+        .fetch_optional(self.storage)
+        .await?
+        .and_then(|row| row.pubdata_input))
+    }
+
     pub async fn get_l1_batch_state_root(
         &mut self,
         number: L1BatchNumber,
@@ -1767,6 +1846,55 @@ impl BlocksDal<'_, '_> {
         }))
     }
 
+    /// Records the source of tree data for the specified L1 batch (e.g., if tree data was fetched from L1
+    /// or from the main node).
+    pub async fn set_l1_batch_tree_data_source(
+        &mut self,
+        number: L1BatchNumber,
+        source: &str,
+    ) -> DalResult<()> {
+        sqlx::query!(
+            r#"
+            UPDATE l1_batches
+            SET
+                tree_data_source = $1
+            WHERE
+                number = $2
+            "#,
+            source,
+            i64::from(number.0)
+        )
+        .instrument("set_l1_batch_tree_data_source")
+        .with_arg("number", &number)
+        .with_arg("source", &source)
+        .execute(self.storage)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn get_l1_batch_tree_data_source(
+        &mut self,
+        number: L1BatchNumber,
+    ) -> DalResult<Option<String>> {
+        let row = sqlx::query!(
+            r#"
+            SELECT
+                tree_data_source
+            FROM
+                l1_batches
+            WHERE
+                number = $1
+            "#,
+            i64::from(number.0)
+        )
+        .instrument("get_l1_batch_tree_data_source")
+        .with_arg("number", &number)
+        .fetch_optional(self.storage)
+        .await?;
+
+        Ok(row.and_then(|row| row.tree_data_source))
+    }
+
     async fn map_storage_l1_batch(
         &mut self,
         storage_batch: StorageL1Batch,
@@ -1818,6 +1946,141 @@ impl BlocksDal<'_, '_> {
         .collect())
     }
 
+    /// Returns the numbers of rows that would be removed or reset by rolling back Postgres data so that
+    /// the specified L1 batch and L2 block are the last ones left. Doesn't modify the storage.
+    pub async fn get_rollback_stats(
+        &mut self,
+        last_l1_batch_to_keep: L1BatchNumber,
+        last_l2_block_to_keep: L2BlockNumber,
+    ) -> DalResult<RollbackStats> {
+        let row = sqlx::query!(
+            r#"
+            SELECT
+                (
+                    SELECT
+                        COUNT(*)
+                    FROM
+                        l1_batches
+                    WHERE
+                        number > $1
+                ) AS "l1_batches!",
+                (
+                    SELECT
+                        COUNT(*)
+                    FROM
+                        miniblocks
+                    WHERE
+                        number > $2
+                ) AS "l2_blocks!",
+                (
+                    SELECT
+                        COUNT(*)
+                    FROM
+                        transactions
+                    WHERE
+                        miniblock_number > $2
+                ) AS "transactions!",
+                (
+                    SELECT
+                        COUNT(*)
+                    FROM
+                        events
+                    WHERE
+                        miniblock_number > $2
+                ) AS "events!",
+                (
+                    SELECT
+                        COUNT(*)
+                    FROM
+                        l2_to_l1_logs
+                    WHERE
+                        miniblock_number > $2
+                ) AS "l2_to_l1_logs!",
+                (
+                    SELECT
+                        COUNT(*)
+                    FROM
+                        storage_logs
+                    WHERE
+                        miniblock_number > $2
+                ) AS "storage_logs!",
+                (
+                    SELECT
+                        COUNT(*)
+                    FROM
+                        initial_writes
+                    WHERE
+                        l1_batch_number > $1
+                ) AS "initial_writes!",
+                (
+                    SELECT
+                        COUNT(*)
+                    FROM
+                        factory_deps
+                    WHERE
+                        miniblock_number > $2
+                ) AS "factory_deps!",
+                (
+                    SELECT
+                        COUNT(*)
+                    FROM
+                        eth_txs
+                    WHERE
+                        id IN (
+                            SELECT
+                                eth_commit_tx_id
+                            FROM
+                                l1_batches
+                            WHERE
+                                number > $1
+                            UNION
+                            SELECT
+                                eth_prove_tx_id
+                            FROM
+                                l1_batches
+                            WHERE
+                                number > $1
+                            UNION
+                            SELECT
+                                eth_execute_tx_id
+                            FROM
+                                l1_batches
+                            WHERE
+                                number > $1
+                        )
+                ) AS "eth_txs!",
+                (
+                    SELECT
+                        COUNT(*)
+                    FROM
+                        snapshots
+                    WHERE
+                        l1_batch_number > $1
+                ) AS "snapshots!"
+            "#,
+            i64::from(last_l1_batch_to_keep.0),
+            i64::from(last_l2_block_to_keep.0)
+        )
+        .instrument("get_rollback_stats")
+        .with_arg("last_l1_batch_to_keep", &last_l1_batch_to_keep)
+        .with_arg("last_l2_block_to_keep", &last_l2_block_to_keep)
+        .fetch_one(self.storage)
+        .await?;
+
+        Ok(RollbackStats {
+            l1_batches: row.l1_batches as u64,
+            l2_blocks: row.l2_blocks as u64,
+            transactions: row.transactions as u64,
+            events: row.events as u64,
+            l2_to_l1_logs: row.l2_to_l1_logs as u64,
+            storage_logs: row.storage_logs as u64,
+            initial_writes: row.initial_writes as u64,
+            factory_deps: row.factory_deps as u64,
+            eth_txs: row.eth_txs as u64,
+            snapshots: row.snapshots as u64,
+        })
+    }
+
     pub async fn delete_initial_writes(
         &mut self,
         last_batch_to_keep: L1BatchNumber,
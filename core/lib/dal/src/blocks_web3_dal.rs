@@ -1,3 +1,5 @@
+use std::ops;
+
 use zksync_db_connection::{
     connection::Connection, error::DalResult, instrument::InstrumentExt, interpolate_query,
     match_query_as,
@@ -29,6 +31,22 @@ pub struct BlocksWeb3Dal<'a, 'c> {
     pub(crate) storage: &'a mut Connection<'c, Core>,
 }
 
+/// L2 block data used in `eth_feeHistory`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeeHistoryBlock {
+    pub number: L2BlockNumber,
+    pub base_fee_per_gas: U256,
+    pub gas_limit: u64,
+}
+
+/// Transaction data used to compute reward percentiles in `eth_feeHistory`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeeHistoryTransaction {
+    pub block_number: L2BlockNumber,
+    pub effective_gas_price: U256,
+    pub gas_used: u64,
+}
+
 impl BlocksWeb3Dal<'_, '_> {
     pub async fn get_api_block(
         &mut self,
@@ -566,15 +584,18 @@ impl BlocksWeb3Dal<'_, '_> {
 
     /// Returns `base_fee_per_gas` for L2 block range [min(newest_block - block_count + 1, 0), newest_block]
     /// in descending order of L2 block numbers.
+    /// Returns data for up to `block_count` L2 blocks ending with `newest_block`, in the descending block order.
     pub async fn get_fee_history(
         &mut self,
         newest_block: L2BlockNumber,
         block_count: u64,
-    ) -> DalResult<Vec<U256>> {
+    ) -> DalResult<Vec<FeeHistoryBlock>> {
         let result: Vec<_> = sqlx::query!(
             r#"
             SELECT
-                base_fee_per_gas
+                number,
+                base_fee_per_gas,
+                gas_limit
             FROM
                 miniblocks
             WHERE
@@ -593,12 +614,57 @@ impl BlocksWeb3Dal<'_, '_> {
         .fetch_all(self.storage)
         .await?
         .into_iter()
-        .map(|row| bigdecimal_to_u256(row.base_fee_per_gas))
+        .map(|row| FeeHistoryBlock {
+            number: L2BlockNumber(row.number as u32),
+            base_fee_per_gas: bigdecimal_to_u256(row.base_fee_per_gas),
+            gas_limit: row.gas_limit.unwrap_or(i64::from(LEGACY_BLOCK_GAS_LIMIT)) as u64,
+        })
         .collect();
 
         Ok(result)
     }
 
+    /// Returns data for all executed transactions in the specified L2 block range.
+    pub async fn get_fee_history_transactions(
+        &mut self,
+        l2_blocks: ops::RangeInclusive<L2BlockNumber>,
+    ) -> DalResult<Vec<FeeHistoryTransaction>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                miniblock_number AS "miniblock_number!",
+                effective_gas_price AS "effective_gas_price!",
+                gas_limit AS "gas_limit!",
+                refunded_gas
+            FROM
+                transactions
+            WHERE
+                miniblock_number BETWEEN $1 AND $2
+                AND effective_gas_price IS NOT NULL
+                AND gas_limit IS NOT NULL
+            "#,
+            i64::from(l2_blocks.start().0),
+            i64::from(l2_blocks.end().0)
+        )
+        .instrument("get_fee_history_transactions")
+        .with_arg("l2_blocks", &l2_blocks)
+        .fetch_all(self.storage)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                let gas_limit = bigdecimal_to_u256(row.gas_limit);
+                let gas_used = gas_limit.saturating_sub(U256::from(row.refunded_gas as u64));
+                FeeHistoryTransaction {
+                    block_number: L2BlockNumber(row.miniblock_number as u32),
+                    effective_gas_price: bigdecimal_to_u256(row.effective_gas_price),
+                    gas_used: gas_used.try_into().unwrap_or(u64::MAX),
+                }
+            })
+            .collect())
+    }
+
     pub async fn get_block_details(
         &mut self,
         block_number: L2BlockNumber,
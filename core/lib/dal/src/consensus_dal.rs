@@ -8,11 +8,22 @@ use zksync_db_connection::{
     error::{DalResult, SqlxContext},
     instrument::{InstrumentExt, Instrumented},
 };
-use zksync_types::L2BlockNumber;
+use zksync_types::{Address, L1BatchNumber, L2BlockNumber, PackedEthSignature, H256};
 
 pub use crate::consensus::Payload;
 use crate::{Core, CoreDal};
 
+/// Attestation of an L1 batch signed by the attester of this node.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BatchAttestation {
+    pub number: L1BatchNumber,
+    /// Root hash of the attested L1 batch.
+    pub hash: H256,
+    /// Address of the attester key.
+    pub attester: Address,
+    pub signature: PackedEthSignature,
+}
+
 /// Storage access methods for `zksync_core::consensus` module.
 #[derive(Debug)]
 pub struct ConsensusDal<'a, 'c> {
@@ -111,17 +122,26 @@ impl ConsensusDal<'_, '_> {
     }
 
     /// Fetches the range of L2 blocks present in storage.
-    /// If storage was recovered from snapshot, the range doesn't need to start at 0.
+    /// If storage was recovered from snapshot or pruned, the range doesn't need to start at 0.
     pub async fn block_range(&mut self) -> DalResult<ops::Range<validator::BlockNumber>> {
         let mut txn = self.storage.start_transaction().await?;
         let snapshot = txn
             .snapshot_recovery_dal()
             .get_applied_snapshot_status()
             .await?;
+        let pruning = txn.pruning_dal().get_pruning_info().await?;
         // `snapshot.l2_block_number` indicates the last block processed.
         // This block is NOT present in storage. Therefore, the first block
         // that will appear in storage is `snapshot.l2_block_number + 1`.
-        let start = validator::BlockNumber(snapshot.map_or(0, |s| s.l2_block_number.0 + 1).into());
+        // Similarly, hard pruning removes all the blocks up to `last_hard_pruned_l2_block`.
+        let last_removed = [
+            snapshot.map(|s| s.l2_block_number),
+            pruning.last_hard_pruned_l2_block,
+        ]
+        .into_iter()
+        .flatten()
+        .max();
+        let start = validator::BlockNumber(last_removed.map_or(0, |n| n.0 + 1).into());
         let end = txn
             .blocks_dal()
             .get_sealed_l2_block_number()
@@ -351,6 +371,90 @@ impl ConsensusDal<'_, '_> {
         txn.commit().await?;
         Ok(())
     }
+
+    /// Fetches the attestation of the L1 batch with the given `number`, if any.
+    pub async fn batch_attestation(
+        &mut self,
+        number: L1BatchNumber,
+    ) -> DalResult<Option<BatchAttestation>> {
+        sqlx::query!(
+            r#"
+            SELECT
+                l1_batch_number,
+                batch_hash,
+                attester,
+                signature
+            FROM
+                l1_batches_consensus
+            WHERE
+                l1_batch_number = $1
+            "#,
+            i64::from(number.0)
+        )
+        .try_map(|row| {
+            Ok(BatchAttestation {
+                number: L1BatchNumber(row.l1_batch_number as u32),
+                hash: H256::from_slice(&row.batch_hash),
+                attester: Address::from_slice(&row.attester),
+                signature: PackedEthSignature::deserialize_packed(&row.signature)
+                    .decode_column("signature")?,
+            })
+        })
+        .instrument("batch_attestation")
+        .with_arg("number", &number)
+        .fetch_optional(self.storage)
+        .await
+    }
+
+    /// Fetches the number of the last attested L1 batch.
+    pub async fn last_batch_attestation_number(&mut self) -> DalResult<Option<L1BatchNumber>> {
+        Ok(sqlx::query!(
+            r#"
+            SELECT
+                MAX(l1_batch_number) AS "number"
+            FROM
+                l1_batches_consensus
+            "#
+        )
+        .instrument("last_batch_attestation_number")
+        .fetch_one(self.storage)
+        .await?
+        .number
+        .map(|number| L1BatchNumber(number as u32)))
+    }
+
+    /// Persists an attestation of an L1 batch.
+    /// Fails if an attestation for this batch is already present, which makes signing
+    /// two different attestations for the same batch impossible.
+    pub async fn insert_batch_attestation(
+        &mut self,
+        attestation: &BatchAttestation,
+    ) -> DalResult<()> {
+        sqlx::query!(
+            r#"
+            INSERT INTO
+                l1_batches_consensus (
+                    l1_batch_number,
+                    batch_hash,
+                    attester,
+                    signature,
+                    created_at,
+                    updated_at
+                )
+            VALUES
+                ($1, $2, $3, $4, NOW(), NOW())
+            "#,
+            i64::from(attestation.number.0),
+            attestation.hash.as_bytes(),
+            attestation.attester.as_bytes(),
+            &attestation.signature.serialize_packed()[..],
+        )
+        .instrument("insert_batch_attestation")
+        .with_arg("number", &attestation.number)
+        .execute(self.storage)
+        .await?;
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -358,7 +462,9 @@ mod tests {
     use rand::Rng as _;
     use zksync_consensus_roles::validator;
     use zksync_consensus_storage::ReplicaState;
+    use zksync_types::{K256PrivateKey, L1BatchNumber, PackedEthSignature, H256};
 
+    use super::BatchAttestation;
     use crate::{ConnectionPool, Core, CoreDal};
 
     #[tokio::test]
@@ -395,4 +501,57 @@ mod tests {
             }
         }
     }
+
+    #[tokio::test]
+    async fn batch_attestation_read_write() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = pool.connection().await.unwrap();
+        let number = L1BatchNumber(1);
+        assert_eq!(
+            None,
+            conn.consensus_dal()
+                .last_batch_attestation_number()
+                .await
+                .unwrap()
+        );
+
+        let key = K256PrivateKey::random();
+        let hash = H256::repeat_byte(1);
+        let attestation = BatchAttestation {
+            number,
+            hash,
+            attester: key.address(),
+            signature: PackedEthSignature::sign_raw(&key, &hash).unwrap(),
+        };
+        conn.consensus_dal()
+            .insert_batch_attestation(&attestation)
+            .await
+            .unwrap();
+        assert_eq!(
+            Some(attestation.clone()),
+            conn.consensus_dal()
+                .batch_attestation(number)
+                .await
+                .unwrap()
+        );
+        assert_eq!(
+            Some(number),
+            conn.consensus_dal()
+                .last_batch_attestation_number()
+                .await
+                .unwrap()
+        );
+
+        // Attesting the same batch for the second time is not allowed.
+        let other_hash = H256::repeat_byte(2);
+        let other_attestation = BatchAttestation {
+            hash: other_hash,
+            signature: PackedEthSignature::sign_raw(&key, &other_hash).unwrap(),
+            ..attestation
+        };
+        conn.consensus_dal()
+            .insert_batch_attestation(&other_attestation)
+            .await
+            .unwrap_err();
+    }
 }
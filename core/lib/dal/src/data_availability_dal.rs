@@ -0,0 +1,630 @@
+use std::{fmt, str::FromStr};
+
+use chrono::NaiveDateTime;
+use zksync_db_connection::{
+    connection::Connection,
+    error::DalResult,
+    instrument::{InstrumentExt, Instrumented},
+};
+use zksync_types::{pubdata_da::DataAvailabilityBlob, L1BatchNumber};
+
+use crate::{models::storage_data_availability::StorageDABlob, Core};
+
+#[derive(Debug)]
+pub struct DataAvailabilityDal<'a, 'c> {
+    pub(crate) storage: &'a mut Connection<'c, Core>,
+}
+
+/// L1 batch pubdata that is ready to be dispatched to the DA layer.
+#[derive(Debug)]
+pub struct L1BatchDA {
+    pub pubdata: Vec<u8>,
+    pub l1_batch_number: L1BatchNumber,
+    /// Time when the L1 batch was sealed.
+    pub sealed_at: NaiveDateTime,
+}
+
+/// Stage of the DA processing at which an L1 batch has failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DADeadLetterStage {
+    /// Dispatching the L1 batch pubdata to the DA layer.
+    Dispatch,
+    /// Getting the inclusion data for the dispatched blob.
+    Inclusion,
+}
+
+impl DADeadLetterStage {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Dispatch => "dispatch",
+            Self::Inclusion => "inclusion",
+        }
+    }
+}
+
+impl fmt::Display for DADeadLetterStage {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(self.as_str())
+    }
+}
+
+impl FromStr for DADeadLetterStage {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "dispatch" => Ok(Self::Dispatch),
+            "inclusion" => Ok(Self::Inclusion),
+            _ => Err(anyhow::anyhow!("unknown DA dead letter stage: {s}")),
+        }
+    }
+}
+
+/// L1 batch that has failed DA processing with a non-retriable error. Such batches are skipped
+/// by the DA dispatcher until the dead letter is removed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DADeadLetter {
+    pub l1_batch_number: L1BatchNumber,
+    pub stage: DADeadLetterStage,
+    pub blob_id: Option<String>,
+    pub error: String,
+    pub attempts: u32,
+    pub created_at: NaiveDateTime,
+}
+
+/// Costs of dispatching pubdata to a DA layer, aggregated over a period of time.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DACostSummary {
+    /// Name of the DA layer.
+    pub client_type: String,
+    pub l1_batch_count: u64,
+    pub blob_size_bytes: u64,
+    /// Total fee for the L1 batches with known fees, in the smallest units of the DA layer's native token.
+    pub fee: u64,
+    /// Number of L1 batches for which the fee is unknown.
+    pub l1_batches_with_unknown_fee: u64,
+}
+
+impl DataAvailabilityDal<'_, '_> {
+    /// Inserts the blob ID for the given L1 batch. If the blob ID is already present,
+    /// verifies that it matches the one provided in the function arguments
+    /// (preventing the same L1 batch from being stored twice).
+    pub async fn insert_l1_batch_da(
+        &mut self,
+        number: L1BatchNumber,
+        blob_id: &str,
+        sent_at: NaiveDateTime,
+    ) -> DalResult<()> {
+        let update_result = sqlx::query!(
+            r#"
+            INSERT INTO
+                data_availability (l1_batch_number, blob_id, sent_at, created_at, updated_at)
+            VALUES
+                ($1, $2, $3, NOW(), NOW())
+            ON CONFLICT DO NOTHING
+            "#,
+            i64::from(number.0),
+            blob_id,
+            sent_at,
+        )
+        .instrument("insert_l1_batch_da")
+        .with_arg("number", &number)
+        .with_arg("blob_id", &blob_id)
+        .report_latency()
+        .execute(self.storage)
+        .await?;
+
+        if update_result.rows_affected() == 0 {
+            tracing::debug!(
+                "L1 batch #{number}: DA blob ID wasn't updated as it's already present"
+            );
+
+            let instrumentation =
+                Instrumented::new("get_matching_batch_da_blob_id").with_arg("number", &number);
+
+            // Batch was already processed. Verify that the existing DA blob ID matches.
+            let query = sqlx::query!(
+                r#"
+                SELECT
+                    blob_id
+                FROM
+                    data_availability
+                WHERE
+                    l1_batch_number = $1
+                "#,
+                i64::from(number.0),
+            );
+
+            let matched: String = instrumentation
+                .clone()
+                .with(query)
+                .report_latency()
+                .fetch_one(self.storage)
+                .await?
+                .blob_id;
+
+            if matched != blob_id {
+                let err = instrumentation.constraint_error(anyhow::anyhow!(
+                    "Error storing DA blob ID. DA blob ID {blob_id} for L1 batch #{number} does not match the expected value"
+                ));
+                return Err(err);
+            }
+        }
+        Ok(())
+    }
+
+    /// Saves the inclusion data for the given L1 batch. If the inclusion data is already present,
+    /// verifies that it matches the one provided in the function arguments
+    /// (meaning that the inclusion data corresponds to the same DA blob).
+    pub async fn save_l1_batch_inclusion_data(
+        &mut self,
+        number: L1BatchNumber,
+        da_inclusion_data: &[u8],
+    ) -> DalResult<()> {
+        let update_result = sqlx::query!(
+            r#"
+            UPDATE data_availability
+            SET
+                inclusion_data = $1,
+                updated_at = NOW()
+            WHERE
+                l1_batch_number = $2
+                AND inclusion_data IS NULL
+            "#,
+            da_inclusion_data,
+            i64::from(number.0),
+        )
+        .instrument("save_l1_batch_da_data")
+        .with_arg("number", &number)
+        .report_latency()
+        .execute(self.storage)
+        .await?;
+
+        if update_result.rows_affected() == 0 {
+            tracing::debug!("L1 batch #{number}: DA data wasn't updated as it's already present");
+
+            let instrumentation =
+                Instrumented::new("get_matching_batch_da_data").with_arg("number", &number);
+
+            // Batch was already processed. Verify that the existing DA data matches.
+            let query = sqlx::query!(
+                r#"
+                SELECT
+                    inclusion_data
+                FROM
+                    data_availability
+                WHERE
+                    l1_batch_number = $1
+                "#,
+                i64::from(number.0),
+            );
+
+            let matched: Option<Vec<u8>> = instrumentation
+                .clone()
+                .with(query)
+                .report_latency()
+                .fetch_one(self.storage)
+                .await?
+                .inclusion_data;
+
+            if matched.as_deref() != Some(da_inclusion_data) {
+                let err = instrumentation.constraint_error(anyhow::anyhow!(
+                    "Error storing DA inclusion data. DA data for L1 batch #{number} does not match the expected value"
+                ));
+                return Err(err);
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the first blob (in the L1 batch number order) that is dispatched to the DA layer
+    /// but doesn't have the inclusion data yet.
+    pub async fn get_first_da_blob_awaiting_inclusion(
+        &mut self,
+    ) -> DalResult<Option<DataAvailabilityBlob>> {
+        Ok(sqlx::query_as!(
+            StorageDABlob,
+            r#"
+            SELECT
+                l1_batch_number,
+                blob_id,
+                inclusion_data,
+                sent_at
+            FROM
+                data_availability
+            WHERE
+                inclusion_data IS NULL
+                AND NOT EXISTS (
+                    SELECT
+                        1
+                    FROM
+                        data_availability_dead_letters
+                    WHERE
+                        data_availability_dead_letters.l1_batch_number = data_availability.l1_batch_number
+                )
+            ORDER BY
+                l1_batch_number
+            LIMIT
+                1
+            "#,
+        )
+        .instrument("get_first_da_blob_awaiting_inclusion")
+        .fetch_optional(self.storage)
+        .await?
+        .map(DataAvailabilityBlob::from))
+    }
+
+    /// Returns the inclusion data for the given L1 batch, or `None` if the batch pubdata
+    /// is not dispatched or not included in the DA layer yet.
+    pub async fn get_l1_batch_inclusion_data(
+        &mut self,
+        number: L1BatchNumber,
+    ) -> DalResult<Option<Vec<u8>>> {
+        Ok(sqlx::query!(
+            r#"
+            SELECT
+                inclusion_data
+            FROM
+                data_availability
+            WHERE
+                l1_batch_number = $1
+            "#,
+            i64::from(number.0),
+        )
+        .instrument("get_l1_batch_inclusion_data")
+        .with_arg("number", &number)
+        .fetch_optional(self.storage)
+        .await?
+        .and_then(|row| row.inclusion_data))
+    }
+
+    /// Fetches the pubdata and `l1_batch_number` for the L1 batches that are ready for DA dispatch.
+    pub async fn get_ready_for_da_dispatch_l1_batches(
+        &mut self,
+        limit: usize,
+    ) -> DalResult<Vec<L1BatchDA>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                number,
+                pubdata_input,
+                l1_batches.created_at
+            FROM
+                l1_batches
+                LEFT JOIN data_availability ON data_availability.l1_batch_number = l1_batches.number
+                LEFT JOIN data_availability_dead_letters ON data_availability_dead_letters.l1_batch_number = l1_batches.number
+            WHERE
+                eth_commit_tx_id IS NULL
+                AND number != 0
+                AND data_availability.blob_id IS NULL
+                AND data_availability_dead_letters.l1_batch_number IS NULL
+                AND pubdata_input IS NOT NULL
+            ORDER BY
+                number
+            LIMIT
+                $1
+            "#,
+            limit as i64,
+        )
+        .instrument("get_ready_for_da_dispatch_l1_batches")
+        .with_arg("limit", &limit)
+        .fetch_all(self.storage)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| L1BatchDA {
+                // `unwrap` is safe here because we have a `WHERE` clause that filters out `NULL` values
+                pubdata: row.pubdata_input.unwrap(),
+                l1_batch_number: L1BatchNumber(row.number as u32),
+                sealed_at: row.created_at,
+            })
+            .collect())
+    }
+
+    /// Moves the L1 batch to the dead letter state, so that it's skipped by the DA dispatcher.
+    /// If the L1 batch is already in this state, the dead letter is overwritten.
+    pub async fn insert_dead_letter(
+        &mut self,
+        number: L1BatchNumber,
+        stage: DADeadLetterStage,
+        blob_id: Option<&str>,
+        error: &str,
+        attempts: u32,
+    ) -> DalResult<()> {
+        sqlx::query!(
+            r#"
+            INSERT INTO
+                data_availability_dead_letters (
+                    l1_batch_number,
+                    stage,
+                    blob_id,
+                    error,
+                    attempts,
+                    created_at,
+                    updated_at
+                )
+            VALUES
+                ($1, $2, $3, $4, $5, NOW(), NOW())
+            ON CONFLICT (l1_batch_number) DO
+            UPDATE
+            SET
+                stage = excluded.stage,
+                blob_id = excluded.blob_id,
+                error = excluded.error,
+                attempts = excluded.attempts,
+                updated_at = NOW()
+            "#,
+            i64::from(number.0),
+            stage.as_str(),
+            blob_id,
+            error,
+            attempts as i32,
+        )
+        .instrument("insert_da_dead_letter")
+        .with_arg("number", &number)
+        .with_arg("stage", &stage.as_str())
+        .report_latency()
+        .execute(self.storage)
+        .await?;
+        Ok(())
+    }
+
+    /// Removes the dead letter for the L1 batch, so that the DA dispatcher processes the batch again.
+    /// Returns `false` if there was no dead letter for the batch.
+    pub async fn remove_dead_letter(&mut self, number: L1BatchNumber) -> DalResult<bool> {
+        let result = sqlx::query!(
+            r#"
+            DELETE FROM data_availability_dead_letters
+            WHERE
+                l1_batch_number = $1
+            "#,
+            i64::from(number.0),
+        )
+        .instrument("remove_da_dead_letter")
+        .with_arg("number", &number)
+        .execute(self.storage)
+        .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Returns the dead letters in the L1 batch number order.
+    pub async fn get_dead_letters(&mut self, limit: usize) -> DalResult<Vec<DADeadLetter>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                l1_batch_number,
+                stage,
+                blob_id,
+                error,
+                attempts,
+                created_at
+            FROM
+                data_availability_dead_letters
+            ORDER BY
+                l1_batch_number
+            LIMIT
+                $1
+            "#,
+            limit as i64,
+        )
+        .instrument("get_da_dead_letters")
+        .with_arg("limit", &limit)
+        .fetch_all(self.storage)
+        .await?;
+
+        rows.into_iter()
+            .map(|row| {
+                let stage = row.stage.parse().map_err(|err| {
+                    Instrumented::new("get_da_dead_letters").constraint_error(err)
+                })?;
+                Ok(DADeadLetter {
+                    l1_batch_number: L1BatchNumber(row.l1_batch_number as u32),
+                    stage,
+                    blob_id: row.blob_id,
+                    error: row.error,
+                    attempts: row.attempts as u32,
+                    created_at: row.created_at,
+                })
+            })
+            .collect()
+    }
+
+    /// Records the cost of dispatching the L1 batch pubdata to the DA layer. If the cost is already recorded
+    /// (i.e., the pubdata was dispatched again), the costs are summed up.
+    pub async fn insert_l1_batch_da_cost(
+        &mut self,
+        number: L1BatchNumber,
+        client_type: &str,
+        blob_size_bytes: u64,
+        fee: Option<u64>,
+    ) -> DalResult<()> {
+        sqlx::query!(
+            r#"
+            INSERT INTO
+                data_availability_costs (
+                    l1_batch_number,
+                    client_type,
+                    blob_size_bytes,
+                    fee,
+                    created_at,
+                    updated_at
+                )
+            VALUES
+                ($1, $2, $3, $4, NOW(), NOW())
+            ON CONFLICT (l1_batch_number, client_type) DO
+            UPDATE
+            SET
+                blob_size_bytes = data_availability_costs.blob_size_bytes + excluded.blob_size_bytes,
+                fee = data_availability_costs.fee + excluded.fee,
+                updated_at = NOW()
+            "#,
+            i64::from(number.0),
+            client_type,
+            blob_size_bytes as i64,
+            fee.map(|fee| fee as i64),
+        )
+        .instrument("insert_l1_batch_da_cost")
+        .with_arg("number", &number)
+        .with_arg("client_type", &client_type)
+        .report_latency()
+        .execute(self.storage)
+        .await?;
+        Ok(())
+    }
+
+    /// Returns the DA costs recorded since the specified time, grouped by the DA layer.
+    pub async fn get_da_costs_since(
+        &mut self,
+        since: NaiveDateTime,
+    ) -> DalResult<Vec<DACostSummary>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                client_type,
+                COUNT(*) AS "l1_batch_count!",
+                SUM(blob_size_bytes)::BIGINT AS "blob_size_bytes!",
+                COALESCE(SUM(fee), 0)::BIGINT AS "fee!",
+                COUNT(*) FILTER (
+                    WHERE
+                        fee IS NULL
+                ) AS "l1_batches_with_unknown_fee!"
+            FROM
+                data_availability_costs
+            WHERE
+                updated_at >= $1
+            GROUP BY
+                client_type
+            ORDER BY
+                client_type
+            "#,
+            since,
+        )
+        .instrument("get_da_costs_since")
+        .with_arg("since", &since)
+        .report_latency()
+        .fetch_all(self.storage)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| DACostSummary {
+                client_type: row.client_type,
+                l1_batch_count: row.l1_batch_count as u64,
+                blob_size_bytes: row.blob_size_bytes as u64,
+                fee: row.fee as u64,
+                l1_batches_with_unknown_fee: row.l1_batches_with_unknown_fee as u64,
+            })
+            .collect())
+    }
+
+    /// Records the result of dispatching the L1 batch pubdata to the shadow DA layer. Either `blob_id` or `error`
+    /// must be set. Does nothing if the result is already recorded.
+    pub async fn insert_shadow_blob(
+        &mut self,
+        number: L1BatchNumber,
+        client_type: &str,
+        blob_id: Option<&str>,
+        error: Option<&str>,
+        sent_at: NaiveDateTime,
+    ) -> DalResult<()> {
+        sqlx::query!(
+            r#"
+            INSERT INTO
+                data_availability_shadow (
+                    l1_batch_number,
+                    client_type,
+                    blob_id,
+                    error,
+                    sent_at,
+                    created_at,
+                    updated_at
+                )
+            VALUES
+                ($1, $2, $3, $4, $5, NOW(), NOW())
+            ON CONFLICT DO NOTHING
+            "#,
+            i64::from(number.0),
+            client_type,
+            blob_id,
+            error,
+            sent_at,
+        )
+        .instrument("insert_da_shadow_blob")
+        .with_arg("number", &number)
+        .with_arg("client_type", &client_type)
+        .report_latency()
+        .execute(self.storage)
+        .await?;
+        Ok(())
+    }
+
+    /// Returns the first blob (in the L1 batch number order) that is dispatched to the shadow DA layer
+    /// but doesn't have the inclusion data or an error yet.
+    pub async fn get_first_shadow_blob_awaiting_inclusion(
+        &mut self,
+        client_type: &str,
+    ) -> DalResult<Option<DataAvailabilityBlob>> {
+        Ok(sqlx::query_as!(
+            StorageDABlob,
+            r#"
+            SELECT
+                l1_batch_number,
+                blob_id AS "blob_id!",
+                inclusion_data,
+                sent_at
+            FROM
+                data_availability_shadow
+            WHERE
+                client_type = $1
+                AND blob_id IS NOT NULL
+                AND inclusion_data IS NULL
+                AND error IS NULL
+            ORDER BY
+                l1_batch_number
+            LIMIT
+                1
+            "#,
+            client_type,
+        )
+        .instrument("get_first_da_shadow_blob_awaiting_inclusion")
+        .with_arg("client_type", &client_type)
+        .fetch_optional(self.storage)
+        .await?
+        .map(DataAvailabilityBlob::from))
+    }
+
+    /// Records the result of getting the inclusion data from the shadow DA layer. Either `inclusion_data`
+    /// or `error` must be set.
+    pub async fn save_shadow_inclusion_result(
+        &mut self,
+        number: L1BatchNumber,
+        client_type: &str,
+        inclusion_data: Option<&[u8]>,
+        error: Option<&str>,
+    ) -> DalResult<()> {
+        sqlx::query!(
+            r#"
+            UPDATE data_availability_shadow
+            SET
+                inclusion_data = $3,
+                error = $4,
+                updated_at = NOW()
+            WHERE
+                l1_batch_number = $1
+                AND client_type = $2
+            "#,
+            i64::from(number.0),
+            client_type,
+            inclusion_data,
+            error,
+        )
+        .instrument("save_da_shadow_inclusion_result")
+        .with_arg("number", &number)
+        .with_arg("client_type", &client_type)
+        .report_latency()
+        .execute(self.storage)
+        .await?;
+        Ok(())
+    }
+}
@@ -6,7 +6,7 @@ use sqlx::{
 use zksync_db_connection::{connection::Connection, error::DalResult, instrument::InstrumentExt};
 use zksync_types::{
     api::{GetLogsFilter, Log},
-    Address, L2BlockNumber, H256,
+    Address, L2BlockNumber, H2048, H256,
 };
 
 use crate::{models::storage_event::StorageWeb3Log, Core};
@@ -64,9 +64,26 @@ impl EventsWeb3Dal<'_, '_> {
     }
 
     /// Returns logs for given filter.
-    #[allow(clippy::type_complexity)]
     pub async fn get_logs(&mut self, filter: GetLogsFilter, limit: usize) -> DalResult<Vec<Log>> {
-        let (where_sql, arg_index) = self.build_get_logs_where_clause(&filter);
+        self.get_logs_after(filter, None, limit).await
+    }
+
+    /// Returns logs for given filter positioned after the log with the specified L2 block number and index in the block
+    /// (if specified). Used to paginate over logs.
+    #[allow(clippy::type_complexity)]
+    pub async fn get_logs_after(
+        &mut self,
+        filter: GetLogsFilter,
+        after: Option<(L2BlockNumber, u32)>,
+        limit: usize,
+    ) -> DalResult<Vec<Log>> {
+        let (mut where_sql, arg_index) = self.build_get_logs_where_clause(&filter);
+        if let Some((block_number, log_index)) = after {
+            where_sql += &format!(
+                " AND ((miniblock_number, event_index_in_block) > ({}, {}))",
+                block_number.0, log_index
+            );
+        }
         let query = format!(
             r#"
             WITH events_select AS (
@@ -107,6 +124,7 @@ impl EventsWeb3Dal<'_, '_> {
             .instrument("get_logs")
             .report_latency()
             .with_arg("filter", &filter)
+            .with_arg("after", &after)
             .with_arg("limit", &limit)
             .fetch_all(self.storage)
             .await?;
@@ -239,6 +257,105 @@ impl EventsWeb3Dal<'_, '_> {
         let logs = db_logs.into_iter().map(Into::into).collect();
         Ok(logs)
     }
+
+    /// Returns log blooms for all L2 blocks after `from_block`, ordered by block number.
+    /// The bloom is `None` for L2 blocks sealed before blooms were persisted.
+    pub async fn get_logs_blooms(
+        &mut self,
+        from_block: L2BlockNumber,
+    ) -> DalResult<Vec<(L2BlockNumber, Option<H2048>)>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                number,
+                logs_bloom
+            FROM
+                miniblocks
+            WHERE
+                number > $1
+            ORDER BY
+                number ASC
+            "#,
+            i64::from(from_block.0)
+        )
+        .instrument("get_logs_blooms")
+        .with_arg("from_block", &from_block)
+        .fetch_all(self.storage)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                let bloom = row.logs_bloom.map(|bloom| H2048::from_slice(&bloom));
+                (L2BlockNumber(row.number as u32), bloom)
+            })
+            .collect())
+    }
+
+    /// Returns all logs for the specified L2 blocks, ordered by block number and index in block.
+    pub async fn get_logs_for_blocks(
+        &mut self,
+        block_numbers: &[L2BlockNumber],
+    ) -> DalResult<Vec<Log>> {
+        let block_numbers: Vec<_> = block_numbers
+            .iter()
+            .map(|number| i64::from(number.0))
+            .collect();
+        let db_logs: Vec<StorageWeb3Log> = sqlx::query_as!(
+            StorageWeb3Log,
+            r#"
+            WITH
+                events_select AS (
+                    SELECT
+                        address,
+                        topic1,
+                        topic2,
+                        topic3,
+                        topic4,
+                        value,
+                        miniblock_number,
+                        tx_hash,
+                        tx_index_in_block,
+                        event_index_in_block,
+                        event_index_in_tx
+                    FROM
+                        events
+                    WHERE
+                        miniblock_number = ANY($1)
+                    ORDER BY
+                        miniblock_number ASC,
+                        event_index_in_block ASC
+                )
+            SELECT
+                miniblocks.hash AS "block_hash?",
+                address AS "address!",
+                topic1 AS "topic1!",
+                topic2 AS "topic2!",
+                topic3 AS "topic3!",
+                topic4 AS "topic4!",
+                value AS "value!",
+                miniblock_number AS "miniblock_number!",
+                miniblocks.l1_batch_number AS "l1_batch_number?",
+                tx_hash AS "tx_hash!",
+                tx_index_in_block AS "tx_index_in_block!",
+                event_index_in_block AS "event_index_in_block!",
+                event_index_in_tx AS "event_index_in_tx!"
+            FROM
+                events_select
+                INNER JOIN miniblocks ON events_select.miniblock_number = miniblocks.number
+            ORDER BY
+                miniblock_number ASC,
+                event_index_in_block ASC
+            "#,
+            &block_numbers
+        )
+        .instrument("get_logs_for_blocks")
+        .with_arg("block_numbers.len", &block_numbers.len())
+        .fetch_all(self.storage)
+        .await?;
+        let logs = db_logs.into_iter().map(Into::into).collect();
+        Ok(logs)
+    }
 }
 
 #[cfg(test)]
@@ -12,11 +12,14 @@ pub use zksync_db_connection::{
 };
 
 use crate::{
-    blocks_dal::BlocksDal, blocks_web3_dal::BlocksWeb3Dal, consensus_dal::ConsensusDal,
-    contract_verification_dal::ContractVerificationDal, eth_sender_dal::EthSenderDal,
-    events_dal::EventsDal, events_web3_dal::EventsWeb3Dal, factory_deps_dal::FactoryDepsDal,
-    proof_generation_dal::ProofGenerationDal, protocol_versions_dal::ProtocolVersionsDal,
-    protocol_versions_web3_dal::ProtocolVersionsWeb3Dal, pruning_dal::PruningDal,
+    admin_audit_log_dal::AdminAuditLogDal, base_token_dal::BaseTokenDal, blocks_dal::BlocksDal,
+    blocks_web3_dal::BlocksWeb3Dal, consensus_dal::ConsensusDal,
+    contract_verification_dal::ContractVerificationDal, data_availability_dal::DataAvailabilityDal,
+    eth_sender_dal::EthSenderDal, events_dal::EventsDal, events_web3_dal::EventsWeb3Dal,
+    factory_deps_dal::FactoryDepsDal, proof_generation_dal::ProofGenerationDal,
+    protocol_versions_dal::ProtocolVersionsDal,
+    protocol_versions_web3_dal::ProtocolVersionsWeb3Dal,
+    proxied_transactions_dal::ProxiedTransactionsDal, pruning_dal::PruningDal,
     snapshot_recovery_dal::SnapshotRecoveryDal, snapshots_creator_dal::SnapshotsCreatorDal,
     snapshots_dal::SnapshotsDal, storage_logs_dal::StorageLogsDal,
     storage_logs_dedup_dal::StorageLogsDedupDal, storage_web3_dal::StorageWeb3Dal,
@@ -26,21 +29,26 @@ use crate::{
     transactions_web3_dal::TransactionsWeb3Dal, vm_runner_dal::VmRunnerDal,
 };
 
+pub mod admin_audit_log_dal;
+pub mod base_token_dal;
 pub mod blocks_dal;
 pub mod blocks_web3_dal;
 pub mod consensus;
 pub mod consensus_dal;
 pub mod contract_verification_dal;
+pub mod data_availability_dal;
 pub mod eth_sender_dal;
 pub mod events_dal;
 pub mod events_web3_dal;
 pub mod factory_deps_dal;
 pub mod helpers;
 pub mod metrics;
+pub mod migrations;
 mod models;
 pub mod proof_generation_dal;
 pub mod protocol_versions_dal;
 pub mod protocol_versions_web3_dal;
+pub mod proxied_transactions_dal;
 pub mod pruning_dal;
 pub mod snapshot_recovery_dal;
 pub mod snapshots_creator_dal;
@@ -125,6 +133,14 @@ where
     fn pruning_dal(&mut self) -> PruningDal<'_, 'a>;
 
     fn vm_runner_dal(&mut self) -> VmRunnerDal<'_, 'a>;
+
+    fn data_availability_dal(&mut self) -> DataAvailabilityDal<'_, 'a>;
+
+    fn admin_audit_log_dal(&mut self) -> AdminAuditLogDal<'_, 'a>;
+
+    fn base_token_dal(&mut self) -> BaseTokenDal<'_, 'a>;
+
+    fn proxied_transactions_dal(&mut self) -> ProxiedTransactionsDal<'_, 'a>;
 }
 
 #[derive(Clone, Debug)]
@@ -243,4 +259,20 @@ impl<'a> CoreDal<'a> for Connection<'a, Core> {
     fn vm_runner_dal(&mut self) -> VmRunnerDal<'_, 'a> {
         VmRunnerDal { storage: self }
     }
+
+    fn data_availability_dal(&mut self) -> DataAvailabilityDal<'_, 'a> {
+        DataAvailabilityDal { storage: self }
+    }
+
+    fn admin_audit_log_dal(&mut self) -> AdminAuditLogDal<'_, 'a> {
+        AdminAuditLogDal { storage: self }
+    }
+
+    fn base_token_dal(&mut self) -> BaseTokenDal<'_, 'a> {
+        BaseTokenDal { storage: self }
+    }
+
+    fn proxied_transactions_dal(&mut self) -> ProxiedTransactionsDal<'_, 'a> {
+        ProxiedTransactionsDal { storage: self }
+    }
 }
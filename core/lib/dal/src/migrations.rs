@@ -0,0 +1,181 @@
+//! Checking and applying Postgres migrations for the core database.
+
+use std::collections::HashSet;
+
+use anyhow::Context as _;
+use sqlx::{
+    migrate::{Migrate, Migrator},
+    PgConnection,
+};
+use zksync_db_connection::connection::Connection;
+
+use crate::Core;
+
+fn migrator() -> Migrator {
+    sqlx::migrate!("./migrations")
+}
+
+/// Status of Postgres migrations as compared to migrations known to this binary.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MigrationsStatus {
+    /// Versions of migrations known to this binary, but not applied to the database.
+    pub pending: Vec<i64>,
+    /// Versions of migrations applied to the database, but unknown to this binary. Non-empty value means
+    /// that the database schema is newer than this binary supports.
+    pub unknown: Vec<i64>,
+    /// Version of a migration that has failed and left the database in an inconsistent state, if any.
+    pub dirty: Option<i64>,
+}
+
+impl MigrationsStatus {
+    /// Checks whether the database schema exactly corresponds to the one expected by this binary.
+    pub fn is_up_to_date(&self) -> bool {
+        self.pending.is_empty() && self.unknown.is_empty() && self.dirty.is_none()
+    }
+
+    /// Returns an error if the database schema cannot be used (or migrated to be used) by this binary.
+    pub fn ensure_compatible(&self) -> anyhow::Result<()> {
+        if let Some(version) = self.dirty {
+            anyhow::bail!(
+                "migration {version} has previously failed; the database must be fixed manually"
+            );
+        }
+        if !self.unknown.is_empty() {
+            anyhow::bail!(
+                "database contains migrations unknown to this binary: {:?}; the database schema is newer \
+                 than supported, please update the binary",
+                self.unknown
+            );
+        }
+        Ok(())
+    }
+}
+
+async fn get_status(conn: &mut PgConnection) -> anyhow::Result<MigrationsStatus> {
+    conn.ensure_migrations_table()
+        .await
+        .context("failed creating migrations table")?;
+    let dirty = conn
+        .dirty_version()
+        .await
+        .context("failed getting dirty migration version")?;
+    let applied: HashSet<_> = conn
+        .list_applied_migrations()
+        .await
+        .context("failed listing applied migrations")?
+        .into_iter()
+        .map(|migration| migration.version)
+        .collect();
+
+    let migrator = migrator();
+    let known: HashSet<_> = migrator
+        .iter()
+        .filter(|migration| !migration.migration_type.is_down_migration())
+        .map(|migration| migration.version)
+        .collect();
+    let mut pending: Vec<_> = known.difference(&applied).copied().collect();
+    pending.sort_unstable();
+    let mut unknown: Vec<_> = applied.difference(&known).copied().collect();
+    unknown.sort_unstable();
+    Ok(MigrationsStatus {
+        pending,
+        unknown,
+        dirty,
+    })
+}
+
+/// Returns the status of migrations for the database.
+pub async fn migrations_status(
+    storage: &mut Connection<'_, Core>,
+) -> anyhow::Result<MigrationsStatus> {
+    get_status(storage.conn()).await
+}
+
+/// Applies pending migrations to the database. Migrations are applied under a Postgres advisory lock,
+/// so it's safe to call this method concurrently from multiple nodes sharing the database.
+///
+/// Returns the status of migrations before they were applied.
+///
+/// # Errors
+///
+/// Returns an error if the database contains migrations unknown to this binary, or if a migration
+/// has failed previously.
+pub async fn apply_migrations(
+    storage: &mut Connection<'_, Core>,
+) -> anyhow::Result<MigrationsStatus> {
+    let conn = storage.conn();
+    conn.lock()
+        .await
+        .context("failed acquiring advisory lock for migrations")?;
+    let result = apply_migrations_locked(conn).await;
+    conn.unlock()
+        .await
+        .context("failed releasing advisory lock for migrations")?;
+    result
+}
+
+async fn apply_migrations_locked(conn: &mut PgConnection) -> anyhow::Result<MigrationsStatus> {
+    // The status must be checked under the lock, since migrations may have been applied by another node
+    // while we were waiting for the lock.
+    let status = get_status(conn).await?;
+    status.ensure_compatible()?;
+    if status.pending.is_empty() {
+        return Ok(status);
+    }
+
+    tracing::info!(
+        "Applying {} pending migrations: {:?}",
+        status.pending.len(),
+        status.pending
+    );
+    let mut migrator = migrator();
+    // The lock is already held.
+    migrator.set_locking(false);
+    migrator
+        .run(&mut *conn)
+        .await
+        .context("failed applying migrations")?;
+    Ok(status)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConnectionPool;
+
+    #[tokio::test]
+    async fn migrations_are_up_to_date_for_test_database() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut storage = pool.connection().await.unwrap();
+
+        let status = migrations_status(&mut storage).await.unwrap();
+        assert!(status.is_up_to_date(), "{status:?}");
+        status.ensure_compatible().unwrap();
+
+        let status = apply_migrations(&mut storage).await.unwrap();
+        assert!(status.is_up_to_date(), "{status:?}");
+    }
+
+    #[test]
+    fn checking_migrations_status_compatibility() {
+        let status = MigrationsStatus {
+            pending: vec![1, 2],
+            ..MigrationsStatus::default()
+        };
+        assert!(!status.is_up_to_date());
+        status.ensure_compatible().unwrap();
+
+        let status = MigrationsStatus {
+            unknown: vec![3],
+            ..MigrationsStatus::default()
+        };
+        let err = status.ensure_compatible().unwrap_err().to_string();
+        assert!(err.contains("newer than supported"), "{err}");
+
+        let status = MigrationsStatus {
+            dirty: Some(3),
+            ..MigrationsStatus::default()
+        };
+        status.ensure_compatible().unwrap_err();
+    }
+}
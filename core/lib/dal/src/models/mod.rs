@@ -1,4 +1,5 @@
 pub mod storage_block;
+pub mod storage_data_availability;
 use anyhow::Context as _;
 use zksync_db_connection::error::SqlxContext;
 use zksync_types::{ProtocolVersionId, H160, H256};
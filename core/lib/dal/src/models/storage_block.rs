@@ -243,6 +243,7 @@ impl TryFrom<StorageL1Batch> for L1BatchMetadata {
                     .map(|v| (v as u16).try_into().unwrap()),
             },
             state_diffs_compressed: batch.compressed_state_diffs.unwrap_or_default(),
+            da_inclusion_data: None,
             events_queue_commitment: batch.events_queue_commitment.map(|v| H256::from_slice(&v)),
             bootloader_initial_content_commitment: batch
                 .bootloader_initial_content_commitment
@@ -426,6 +427,7 @@ pub(crate) struct StorageL2BlockHeader {
     /// The formal value of the gas limit for the miniblock.
     /// This value should bound the maximal amount of gas that can be spent by transactions in the miniblock.
     pub gas_limit: Option<i64>,
+    pub logs_bloom: Option<Vec<u8>>,
 }
 
 impl From<StorageL2BlockHeader> for L2BlockHeader {
@@ -468,6 +470,10 @@ impl From<StorageL2BlockHeader> for L2BlockHeader {
             protocol_version,
             virtual_blocks: row.virtual_blocks as u32,
             gas_limit: row.gas_limit.unwrap_or(i64::from(LEGACY_BLOCK_GAS_LIMIT)) as u64,
+            logs_bloom: row
+                .logs_bloom
+                .map(|bloom| H2048::from_slice(&bloom))
+                .unwrap_or_default(),
         }
     }
 }
@@ -0,0 +1,22 @@
+use chrono::NaiveDateTime;
+use zksync_types::{pubdata_da::DataAvailabilityBlob, L1BatchNumber};
+
+/// Represents a blob in the data availability layer.
+#[derive(Debug, Clone)]
+pub(crate) struct StorageDABlob {
+    pub l1_batch_number: i64,
+    pub blob_id: String,
+    pub inclusion_data: Option<Vec<u8>>,
+    pub sent_at: NaiveDateTime,
+}
+
+impl From<StorageDABlob> for DataAvailabilityBlob {
+    fn from(blob: StorageDABlob) -> DataAvailabilityBlob {
+        DataAvailabilityBlob {
+            l1_batch_number: L1BatchNumber(blob.l1_batch_number as u32),
+            blob_id: blob.blob_id,
+            inclusion_data: blob.inclusion_data,
+            sent_at: blob.sent_at,
+        }
+    }
+}
@@ -0,0 +1,271 @@
+use std::time::Duration;
+
+use zksync_db_connection::{connection::Connection, error::DalResult, instrument::InstrumentExt};
+use zksync_types::{Address, Nonce, H256};
+
+use crate::Core;
+
+#[derive(Debug)]
+pub struct ProxiedTransactionsDal<'a, 'c> {
+    pub(crate) storage: &'a mut Connection<'c, Core>,
+}
+
+/// Transaction persisted in the resubmission queue of the external node transaction proxy.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProxiedTransaction {
+    pub tx_hash: H256,
+    pub initiator_address: Address,
+    pub nonce: Nonce,
+    pub raw_tx: Vec<u8>,
+    /// Number of resubmission attempts performed so far.
+    pub attempts: u32,
+}
+
+impl ProxiedTransactionsDal<'_, '_> {
+    /// Adds a transaction to the queue. The first resubmission attempt will be due after `first_attempt_delay`;
+    /// the transaction will be dropped from the queue after `expiration`. Does nothing if the transaction
+    /// is already queued.
+    pub async fn insert_transaction(
+        &mut self,
+        tx_hash: H256,
+        initiator_address: Address,
+        nonce: Nonce,
+        raw_tx: &[u8],
+        first_attempt_delay: Duration,
+        expiration: Duration,
+    ) -> DalResult<()> {
+        sqlx::query!(
+            r#"
+            INSERT INTO
+                proxied_transactions (
+                    tx_hash,
+                    initiator_address,
+                    nonce,
+                    raw_tx,
+                    attempts,
+                    next_attempt_at,
+                    expires_at,
+                    created_at,
+                    updated_at
+                )
+            VALUES
+                (
+                    $1,
+                    $2,
+                    $3,
+                    $4,
+                    0,
+                    NOW() + MAKE_INTERVAL(secs => $5),
+                    NOW() + MAKE_INTERVAL(secs => $6),
+                    NOW(),
+                    NOW()
+                )
+            ON CONFLICT (tx_hash) DO NOTHING
+            "#,
+            tx_hash.as_bytes(),
+            initiator_address.as_bytes(),
+            i64::from(nonce.0),
+            raw_tx,
+            first_attempt_delay.as_secs_f64(),
+            expiration.as_secs_f64()
+        )
+        .instrument("insert_proxied_transaction")
+        .with_arg("tx_hash", &tx_hash)
+        .execute(self.storage)
+        .await?;
+        Ok(())
+    }
+
+    /// Returns up to `limit` non-expired transactions due for resubmission, oldest first.
+    pub async fn get_due_transactions(
+        &mut self,
+        limit: usize,
+    ) -> DalResult<Vec<ProxiedTransaction>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                tx_hash,
+                initiator_address,
+                nonce,
+                raw_tx,
+                attempts
+            FROM
+                proxied_transactions
+            WHERE
+                next_attempt_at <= NOW()
+                AND expires_at > NOW()
+            ORDER BY
+                next_attempt_at
+            LIMIT
+                $1
+            "#,
+            limit as i64
+        )
+        .instrument("get_due_proxied_transactions")
+        .with_arg("limit", &limit)
+        .fetch_all(self.storage)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| ProxiedTransaction {
+                tx_hash: H256::from_slice(&row.tx_hash),
+                initiator_address: Address::from_slice(&row.initiator_address),
+                nonce: Nonce(row.nonce as u32),
+                raw_tx: row.raw_tx,
+                attempts: row.attempts as u32,
+            })
+            .collect())
+    }
+
+    /// Records a resubmission attempt for the transaction and schedules the next one after `delay`.
+    pub async fn reschedule_transaction(
+        &mut self,
+        tx_hash: H256,
+        delay: Duration,
+    ) -> DalResult<()> {
+        sqlx::query!(
+            r#"
+            UPDATE proxied_transactions
+            SET
+                attempts = attempts + 1,
+                next_attempt_at = NOW() + MAKE_INTERVAL(secs => $2),
+                updated_at = NOW()
+            WHERE
+                tx_hash = $1
+            "#,
+            tx_hash.as_bytes(),
+            delay.as_secs_f64()
+        )
+        .instrument("reschedule_proxied_transaction")
+        .with_arg("tx_hash", &tx_hash)
+        .with_arg("delay", &delay)
+        .execute(self.storage)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn remove_transaction(&mut self, tx_hash: H256) -> DalResult<()> {
+        sqlx::query!(
+            r#"
+            DELETE FROM proxied_transactions
+            WHERE
+                tx_hash = $1
+            "#,
+            tx_hash.as_bytes()
+        )
+        .instrument("remove_proxied_transaction")
+        .with_arg("tx_hash", &tx_hash)
+        .execute(self.storage)
+        .await?;
+        Ok(())
+    }
+
+    /// Removes expired transactions from the queue. Returns the number of removed transactions.
+    pub async fn remove_expired_transactions(&mut self) -> DalResult<usize> {
+        let result = sqlx::query!(
+            r#"
+            DELETE FROM proxied_transactions
+            WHERE
+                expires_at <= NOW()
+            "#
+        )
+        .instrument("remove_expired_proxied_transactions")
+        .execute(self.storage)
+        .await?;
+        Ok(result.rows_affected() as usize)
+    }
+
+    /// Returns the number of transactions in the queue.
+    pub async fn get_queue_size(&mut self) -> DalResult<usize> {
+        let count = sqlx::query_scalar!(
+            r#"
+            SELECT
+                COUNT(*) AS "count!"
+            FROM
+                proxied_transactions
+            "#
+        )
+        .instrument("get_proxied_transactions_queue_size")
+        .fetch_one(self.storage)
+        .await?;
+        Ok(count as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ConnectionPool, CoreDal};
+
+    #[tokio::test]
+    async fn proxied_transactions_queue_basics() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = pool.connection().await.unwrap();
+        let mut dal = conn.proxied_transactions_dal();
+
+        let (tx_hash, initiator_address) = (H256::repeat_byte(1), Address::repeat_byte(2));
+        dal.insert_transaction(
+            tx_hash,
+            initiator_address,
+            Nonce(3),
+            b"tx",
+            Duration::ZERO,
+            Duration::from_secs(3_600),
+        )
+        .await
+        .unwrap();
+        // Inserting a duplicate transaction should be a no-op.
+        dal.insert_transaction(
+            tx_hash,
+            initiator_address,
+            Nonce(3),
+            b"tx",
+            Duration::ZERO,
+            Duration::from_secs(3_600),
+        )
+        .await
+        .unwrap();
+        // Expired transaction
+        dal.insert_transaction(
+            H256::repeat_byte(0xff),
+            initiator_address,
+            Nonce(4),
+            b"expired",
+            Duration::ZERO,
+            Duration::ZERO,
+        )
+        .await
+        .unwrap();
+        assert_eq!(dal.get_queue_size().await.unwrap(), 2);
+
+        let due_txs = dal.get_due_transactions(10).await.unwrap();
+        assert_eq!(
+            due_txs,
+            [ProxiedTransaction {
+                tx_hash,
+                initiator_address,
+                nonce: Nonce(3),
+                raw_tx: b"tx".to_vec(),
+                attempts: 0,
+            }]
+        );
+
+        assert_eq!(dal.remove_expired_transactions().await.unwrap(), 1);
+        assert_eq!(dal.get_queue_size().await.unwrap(), 1);
+
+        dal.reschedule_transaction(tx_hash, Duration::from_secs(3_600))
+            .await
+            .unwrap();
+        assert!(dal.get_due_transactions(10).await.unwrap().is_empty());
+        dal.reschedule_transaction(tx_hash, Duration::ZERO)
+            .await
+            .unwrap();
+        let due_txs = dal.get_due_transactions(10).await.unwrap();
+        assert_eq!(due_txs.len(), 1);
+        assert_eq!(due_txs[0].attempts, 2);
+
+        dal.remove_transaction(tx_hash).await.unwrap();
+        assert_eq!(dal.get_queue_size().await.unwrap(), 0);
+    }
+}
@@ -32,6 +32,8 @@ pub struct HardPruningStats {
     pub deleted_events: u64,
     pub deleted_call_traces: u64,
     pub deleted_l2_to_l1_logs: u64,
+    pub deleted_l2_block_certificates: u64,
+    pub deleted_batch_attestations: u64,
 }
 
 #[derive(Debug, sqlx::Type)]
@@ -185,6 +187,12 @@ impl PruningDal<'_, '_> {
             let deleted_storage_logs_from_pruned_batches = self
                 .prune_storage_logs_in_range(first_l2_block_to_prune..=last_l2_block_to_prune)
                 .await?;
+            let deleted_l2_block_certificates = self
+                .delete_l2_block_certificates(first_l2_block_to_prune..=last_l2_block_to_prune)
+                .await?;
+            let deleted_batch_attestations = self
+                .delete_batch_attestations(last_l1_batch_to_prune)
+                .await?;
             let deleted_l1_batches = self.delete_l1_batches(last_l1_batch_to_prune).await?;
             let deleted_l2_blocks = self.delete_l2_blocks(last_l2_block_to_prune).await?;
 
@@ -196,6 +204,8 @@ impl PruningDal<'_, '_> {
                 deleted_call_traces,
                 deleted_storage_logs_from_past_batches,
                 deleted_storage_logs_from_pruned_batches,
+                deleted_l2_block_certificates,
+                deleted_batch_attestations,
             }
         } else {
             HardPruningStats::default()
@@ -379,6 +389,50 @@ impl PruningDal<'_, '_> {
         Ok(execution_result.rows_affected())
     }
 
+    async fn delete_l2_block_certificates(
+        &mut self,
+        l2_blocks_to_prune: ops::RangeInclusive<L2BlockNumber>,
+    ) -> DalResult<u64> {
+        let execution_result = sqlx::query!(
+            r#"
+            DELETE FROM miniblocks_consensus
+            WHERE
+                number BETWEEN $1 AND $2
+            "#,
+            i64::from(l2_blocks_to_prune.start().0),
+            i64::from(l2_blocks_to_prune.end().0)
+        )
+        .instrument("hard_prune_batches_range#delete_l2_block_certificates")
+        .with_arg("l2_blocks_to_prune", &l2_blocks_to_prune)
+        .report_latency()
+        .execute(self.storage)
+        .await?;
+        Ok(execution_result.rows_affected())
+    }
+
+    // Attestations are not linked to `l1_batches`, so that they outlive reverted batches;
+    // thus, they need to be deleted explicitly. Pruned batches are never attested again,
+    // so deleting their attestations doesn't weaken the double-signing protection.
+    async fn delete_batch_attestations(
+        &mut self,
+        last_l1_batch_to_prune: L1BatchNumber,
+    ) -> DalResult<u64> {
+        let execution_result = sqlx::query!(
+            r#"
+            DELETE FROM l1_batches_consensus
+            WHERE
+                l1_batch_number <= $1
+            "#,
+            i64::from(last_l1_batch_to_prune.0),
+        )
+        .instrument("hard_prune_batches_range#delete_batch_attestations")
+        .with_arg("last_l1_batch_to_prune", &last_l1_batch_to_prune)
+        .report_latency()
+        .execute(self.storage)
+        .await?;
+        Ok(execution_result.rows_affected())
+    }
+
     async fn delete_l1_batches(&mut self, last_l1_batch_to_prune: L1BatchNumber) -> DalResult<u64> {
         let execution_result = sqlx::query!(
             r#"
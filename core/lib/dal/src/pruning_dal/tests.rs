@@ -7,12 +7,13 @@ use zksync_types::{
     fee::TransactionExecutionMetrics,
     l2_to_l1_log::{L2ToL1Log, UserL2ToL1Log},
     tx::IncludedTxLocation,
-    AccountTreeId, Address, L1BatchNumber, L2BlockNumber, L2ChainId, ProtocolVersion,
-    ProtocolVersionId, StorageKey, StorageLog, H256,
+    AccountTreeId, Address, K256PrivateKey, L1BatchNumber, L2BlockNumber, L2ChainId,
+    PackedEthSignature, ProtocolVersion, ProtocolVersionId, StorageKey, StorageLog, H256,
 };
 
 use super::*;
 use crate::{
+    consensus_dal::BatchAttestation,
     storage_logs_dal::DbStorageLog,
     tests::{
         create_l2_block_header, mock_execution_result, mock_l2_to_l1_log, mock_l2_transaction,
@@ -464,6 +465,43 @@ async fn l1_batches_can_be_hard_pruned() {
     );
 }
 
+#[tokio::test]
+async fn batch_attestations_are_hard_pruned() {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let mut conn = pool.connection().await.unwrap();
+    insert_realistic_l1_batches(&mut conn, 10).await;
+
+    let key = K256PrivateKey::random();
+    for number in 0..10 {
+        let hash = H256::repeat_byte(number as u8);
+        let attestation = BatchAttestation {
+            number: L1BatchNumber(number),
+            hash,
+            attester: key.address(),
+            signature: PackedEthSignature::sign_raw(&key, &hash).unwrap(),
+        };
+        conn.consensus_dal()
+            .insert_batch_attestation(&attestation)
+            .await
+            .unwrap();
+    }
+
+    let stats = conn
+        .pruning_dal()
+        .hard_prune_batches_range(L1BatchNumber(5), L2BlockNumber(11))
+        .await
+        .unwrap();
+    assert_eq!(stats.deleted_batch_attestations, 6);
+    for number in 0..10 {
+        let attestation = conn
+            .consensus_dal()
+            .batch_attestation(L1BatchNumber(number))
+            .await
+            .unwrap();
+        assert_eq!(attestation.is_some(), number > 5, "{number}");
+    }
+}
+
 #[tokio::test]
 async fn transactions_are_handled_correctly_after_pruning() {
     let pool = ConnectionPool::<Core>::test_pool().await;
@@ -1691,6 +1691,34 @@ impl TransactionsDal<'_, '_> {
         Ok(rows.len())
     }
 
+    /// Returns the number of L2 transactions from the specified initiator that are not yet included
+    /// into an L2 block (i.e., are pending in the mempool).
+    pub async fn get_pending_txs_count_for_initiator(
+        &mut self,
+        initiator_address: Address,
+    ) -> DalResult<usize> {
+        let count = sqlx::query!(
+            r#"
+            SELECT
+                COUNT(*) AS "count!"
+            FROM
+                transactions
+            WHERE
+                initiator_address = $1
+                AND miniblock_number IS NULL
+                AND error IS NULL
+                AND is_priority = FALSE
+            "#,
+            initiator_address.as_bytes()
+        )
+        .instrument("get_pending_txs_count_for_initiator")
+        .with_arg("initiator_address", &initiator_address)
+        .fetch_one(self.storage)
+        .await?
+        .count;
+        Ok(count as usize)
+    }
+
     /// Fetches new updates for mempool. Returns new transactions and current nonces for related accounts;
     /// the latter are only used to bootstrap mempool for given account.
     pub async fn sync_mempool(
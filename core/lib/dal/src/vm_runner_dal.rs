@@ -82,4 +82,152 @@ impl VmRunnerDal<'_, '_> {
         .await?;
         Ok(())
     }
+
+    pub async fn get_bwip_latest_processed_batch(
+        &mut self,
+        default_batch: L1BatchNumber,
+    ) -> DalResult<L1BatchNumber> {
+        let row = sqlx::query!(
+            r#"
+            SELECT
+                COALESCE(MAX(l1_batch_number), $1) AS "last_processed_l1_batch!"
+            FROM
+                vm_runner_bwip
+            "#,
+            default_batch.0 as i32
+        )
+        .instrument("get_bwip_latest_processed_batch")
+        .report_latency()
+        .fetch_one(self.storage)
+        .await?;
+        Ok(L1BatchNumber(row.last_processed_l1_batch as u32))
+    }
+
+    pub async fn get_bwip_last_ready_batch(
+        &mut self,
+        window_size: u32,
+    ) -> DalResult<L1BatchNumber> {
+        let row = sqlx::query!(
+            r#"
+            WITH
+                available_batches AS (
+                    SELECT
+                        MAX(number) AS "last_batch"
+                    FROM
+                        l1_batches
+                ),
+                processed_batches AS (
+                    SELECT
+                        COALESCE(MAX(l1_batch_number), 0) + $1 AS "last_ready_batch"
+                    FROM
+                        vm_runner_bwip
+                )
+            SELECT
+                LEAST(last_batch, last_ready_batch) AS "last_ready_batch!"
+            FROM
+                available_batches
+                FULL JOIN processed_batches ON TRUE
+            "#,
+            window_size as i32
+        )
+        .instrument("get_bwip_last_ready_batch")
+        .report_latency()
+        .fetch_one(self.storage)
+        .await?;
+        Ok(L1BatchNumber(row.last_ready_batch as u32))
+    }
+
+    pub async fn mark_bwip_batch_as_completed(
+        &mut self,
+        l1_batch_number: L1BatchNumber,
+    ) -> DalResult<()> {
+        sqlx::query!(
+            r#"
+            INSERT INTO
+                vm_runner_bwip (l1_batch_number, created_at, updated_at)
+            VALUES
+                ($1, NOW(), NOW())
+            "#,
+            i64::from(l1_batch_number.0),
+        )
+        .instrument("mark_bwip_batch_as_completed")
+        .report_latency()
+        .execute(self.storage)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn get_vm_playground_latest_processed_batch(
+        &mut self,
+        default_batch: L1BatchNumber,
+    ) -> DalResult<L1BatchNumber> {
+        let row = sqlx::query!(
+            r#"
+            SELECT
+                COALESCE(MAX(l1_batch_number), $1) AS "last_processed_l1_batch!"
+            FROM
+                vm_runner_vm_playground
+            "#,
+            default_batch.0 as i32
+        )
+        .instrument("get_vm_playground_latest_processed_batch")
+        .report_latency()
+        .fetch_one(self.storage)
+        .await?;
+        Ok(L1BatchNumber(row.last_processed_l1_batch as u32))
+    }
+
+    pub async fn get_vm_playground_last_ready_batch(
+        &mut self,
+        window_size: u32,
+    ) -> DalResult<L1BatchNumber> {
+        let row = sqlx::query!(
+            r#"
+            WITH
+                available_batches AS (
+                    SELECT
+                        MAX(number) AS "last_batch"
+                    FROM
+                        l1_batches
+                ),
+                processed_batches AS (
+                    SELECT
+                        COALESCE(MAX(l1_batch_number), 0) + $1 AS "last_ready_batch"
+                    FROM
+                        vm_runner_vm_playground
+                )
+            SELECT
+                LEAST(last_batch, last_ready_batch) AS "last_ready_batch!"
+            FROM
+                available_batches
+                FULL JOIN processed_batches ON TRUE
+            "#,
+            window_size as i32
+        )
+        .instrument("get_vm_playground_last_ready_batch")
+        .report_latency()
+        .fetch_one(self.storage)
+        .await?;
+        Ok(L1BatchNumber(row.last_ready_batch as u32))
+    }
+
+    pub async fn mark_vm_playground_batch_as_completed(
+        &mut self,
+        l1_batch_number: L1BatchNumber,
+    ) -> DalResult<()> {
+        sqlx::query!(
+            r#"
+            INSERT INTO
+                vm_runner_vm_playground (l1_batch_number, created_at, updated_at)
+            VALUES
+                ($1, NOW(), NOW())
+            "#,
+            i64::from(l1_batch_number.0),
+        )
+        .instrument("mark_vm_playground_batch_as_completed")
+        .report_latency()
+        .execute(self.storage)
+        .await?;
+        Ok(())
+    }
 }
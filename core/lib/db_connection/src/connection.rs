@@ -6,7 +6,7 @@ use std::{
         atomic::{AtomicUsize, Ordering},
         Mutex,
     },
-    time::{Instant, SystemTime},
+    time::{Duration, Instant, SystemTime},
 };
 
 use sqlx::{
@@ -14,11 +14,10 @@ use sqlx::{
 };
 
 use crate::{
-    connection_pool::ConnectionPool,
+    connection_pool::ConnectionThresholds,
     error::{DalConnectionError, DalResult},
     instrument::InstrumentExt,
     metrics::CONNECTION_METRICS,
-    utils::InternalMarker,
 };
 
 /// Tags that can be associated with a connection.
@@ -26,6 +25,8 @@ use crate::{
 pub struct ConnectionTags {
     pub requester: &'static str,
     pub location: &'static Location<'static>,
+    /// ID of the request (e.g., a JSON-RPC call) being served when the connection was acquired.
+    pub request_id: Option<vlog::RequestId>,
 }
 
 impl ConnectionTags {
@@ -42,7 +43,11 @@ impl fmt::Display for ConnectionTags {
             self.requester,
             self.location.file(),
             self.location.line()
-        )
+        )?;
+        if let Some(request_id) = self.request_id {
+            write!(formatter, " for request {request_id}")?;
+        }
+        Ok(())
     }
 }
 
@@ -101,6 +106,7 @@ impl TracedConnections {
 struct PooledConnection<'a> {
     connection: PoolConnection<Postgres>,
     tags: Option<ConnectionTags>,
+    long_connection_threshold: Duration,
     created_at: Instant,
     traced: Option<(&'a TracedConnections, usize)>,
 }
@@ -121,9 +127,7 @@ impl Drop for PooledConnection<'_> {
             let lifetime = self.created_at.elapsed();
             CONNECTION_METRICS.lifetime[&tags.requester].observe(lifetime);
 
-            if lifetime
-                > ConnectionPool::<InternalMarker>::global_config().long_connection_threshold()
-            {
+            if lifetime > self.long_connection_threshold {
                 let file = tags.location.file();
                 let line = tags.location.line();
                 tracing::info!(
@@ -156,6 +160,7 @@ pub trait DbMarker: 'static + Send + Sync + Clone {}
 #[derive(Debug)]
 pub struct Connection<'a, DB: DbMarker> {
     inner: ConnectionInner<'a>,
+    thresholds: ConnectionThresholds,
     _marker: std::marker::PhantomData<DB>,
 }
 
@@ -166,12 +171,14 @@ impl<'a, DB: DbMarker> Connection<'a, DB> {
     pub(crate) fn from_pool(
         connection: PoolConnection<Postgres>,
         tags: Option<ConnectionTags>,
+        thresholds: ConnectionThresholds,
         traced_connections: Option<&'a TracedConnections>,
     ) -> Self {
         let created_at = Instant::now();
         let inner = ConnectionInner::Pooled(PooledConnection {
             connection,
             tags,
+            long_connection_threshold: thresholds.long_connection(),
             created_at,
             traced: traced_connections.map(|connections| {
                 let id = connections.acquire(tags, created_at);
@@ -180,12 +187,14 @@ impl<'a, DB: DbMarker> Connection<'a, DB> {
         });
         Self {
             inner,
+            thresholds,
             _marker: Default::default(),
         }
     }
 
     /// Starts a transaction or a new checkpoint within the current transaction.
     pub async fn start_transaction(&mut self) -> DalResult<Connection<'_, DB>> {
+        let thresholds = self.thresholds;
         let (conn, tags) = self.conn_and_tags();
         let inner = ConnectionInner::Transaction {
             transaction: conn
@@ -196,6 +205,7 @@ impl<'a, DB: DbMarker> Connection<'a, DB> {
         };
         Ok(Connection {
             inner,
+            thresholds,
             _marker: Default::default(),
         })
     }
@@ -272,6 +282,11 @@ impl<'a, DB: DbMarker> Connection<'a, DB> {
         self.conn_and_tags().0
     }
 
+    /// Returns the threshold to denote a DB query executed on this connection as "slow".
+    pub(crate) fn slow_query_threshold(&self) -> Duration {
+        self.thresholds.slow_query()
+    }
+
     pub fn conn_and_tags(&mut self) -> (&mut PgConnection, Option<&ConnectionTags>) {
         match &mut self.inner {
             ConnectionInner::Pooled(pooled) => (&mut pooled.connection, pooled.tags.as_ref()),
@@ -310,6 +325,7 @@ impl<'a, DB: DbMarker> TransactionBuilder<'a, '_, DB> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{connection_pool::ConnectionPool, utils::InternalMarker};
 
     #[tokio::test]
     async fn processor_tags_propagate_to_transactions() {
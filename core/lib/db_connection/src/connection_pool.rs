@@ -22,6 +22,8 @@ use crate::{
     connection::{Connection, ConnectionTags, DbMarker, TracedConnections},
     error::{DalConnectionError, DalResult},
     metrics::CONNECTION_METRICS,
+    replicas::ReplicaSet,
+    utils::InternalMarker,
 };
 
 /// Builder for [`ConnectionPool`]s.
@@ -31,6 +33,9 @@ pub struct ConnectionPoolBuilder<DB: DbMarker> {
     max_size: u32,
     acquire_timeout: Duration,
     statement_timeout: Option<Duration>,
+    thresholds: ConnectionThresholds,
+    replica_urls: Vec<SensitiveUrl>,
+    max_replica_lag: Duration,
     _db: PhantomData<DB>,
 }
 
@@ -42,12 +47,18 @@ impl<DB: DbMarker> fmt::Debug for ConnectionPoolBuilder<DB> {
             .field("max_size", &self.max_size)
             .field("acquire_timeout", &self.acquire_timeout)
             .field("statement_timeout", &self.statement_timeout)
+            .field("thresholds", &self.thresholds)
+            .field("replica_urls", &self.replica_urls)
+            .field("max_replica_lag", &self.max_replica_lag)
             .field("db", &any::type_name::<DB>())
             .finish()
     }
 }
 
 impl<DB: DbMarker> ConnectionPoolBuilder<DB> {
+    /// Default maximum replication lag for a read replica to be used.
+    const DEFAULT_MAX_REPLICA_LAG: Duration = Duration::from_secs(2);
+
     /// Overrides the maximum number of connections that can be allocated by the pool.
     pub fn set_max_size(&mut self, max_size: u32) -> &mut Self {
         self.max_size = max_size;
@@ -72,18 +83,60 @@ impl<DB: DbMarker> ConnectionPoolBuilder<DB> {
         self
     }
 
+    /// Overrides the threshold for the DB connection lifetime to denote a connection as long-living and log its details.
+    /// If not specified, the threshold from [`GlobalConnectionPoolConfig`] will be used.
+    pub fn set_long_connection_threshold(&mut self, threshold: Option<Duration>) -> &mut Self {
+        self.thresholds.long_connection = threshold;
+        self
+    }
+
+    /// Overrides the threshold to denote a DB query as "slow" and log its details. If not specified,
+    /// the threshold from [`GlobalConnectionPoolConfig`] will be used.
+    pub fn set_slow_query_threshold(&mut self, threshold: Option<Duration>) -> &mut Self {
+        self.thresholds.slow_query = threshold;
+        self
+    }
+
+    /// Sets URLs of read replicas of the database. If set, connections are distributed among the replicas
+    /// in the round-robin fashion, skipping replicas lagging behind by more than [the maximum lag](Self::set_max_replica_lag()).
+    /// If all replicas are lagging or unavailable, connections are acquired from the main database.
+    ///
+    /// Each replica gets its own pool with the same maximum size as the main one. Since replicas are read-only,
+    /// a pool with replicas must only be used for reads.
+    pub fn set_replica_urls(&mut self, urls: Vec<SensitiveUrl>) -> &mut Self {
+        self.replica_urls = urls
+            .into_iter()
+            .map(|url| url.with_sensitive_query_params(&["user", "password"]))
+            .collect();
+        self
+    }
+
+    /// Sets the maximum replication lag for a read replica to be used. If not specified, the lag defaults to 2 seconds.
+    pub fn set_max_replica_lag(&mut self, max_lag: Option<Duration>) -> &mut Self {
+        self.max_replica_lag = max_lag.unwrap_or(Self::DEFAULT_MAX_REPLICA_LAG);
+        self
+    }
+
     /// Returns the maximum number of connections that can be allocated by the pool.
     pub fn max_size(&self) -> u32 {
         self.max_size
     }
 
-    /// Builds a connection pool from this builder.
-    pub async fn build(&self) -> anyhow::Result<ConnectionPool<DB>> {
+    /// Returns URLs of read replicas of the database.
+    pub fn replica_urls(&self) -> &[SensitiveUrl] {
+        &self.replica_urls
+    }
+
+    /// Returns the maximum replication lag for a read replica to be used.
+    pub fn max_replica_lag(&self) -> Duration {
+        self.max_replica_lag
+    }
+
+    async fn connect(&self, database_url: &SensitiveUrl) -> anyhow::Result<PgPool> {
         let options = PgPoolOptions::new()
             .max_connections(self.max_size)
             .acquire_timeout(self.acquire_timeout);
-        let mut connect_options: PgConnectOptions = self
-            .database_url
+        let mut connect_options: PgConnectOptions = database_url
             .expose_str()
             .parse()
             .context("Failed parsing database URL")?;
@@ -91,27 +144,50 @@ impl<DB: DbMarker> ConnectionPoolBuilder<DB> {
             let timeout_string = format!("{}s", timeout.as_secs());
             connect_options = connect_options.options([("statement_timeout", timeout_string)]);
         }
-        let pool = options
+        options
             .connect_with(connect_options)
             .await
-            .context("Failed connecting to database")?;
+            .context("Failed connecting to database")
+    }
+
+    /// Builds a connection pool from this builder.
+    pub async fn build(&self) -> anyhow::Result<ConnectionPool<DB>> {
+        let pool = self.connect(&self.database_url).await?;
+        let replicas = if self.replica_urls.is_empty() {
+            None
+        } else {
+            let mut replicas = Vec::with_capacity(self.replica_urls.len());
+            for (i, url) in self.replica_urls.iter().enumerate() {
+                let replica_pool = self
+                    .connect(url)
+                    .await
+                    .with_context(|| format!("Failed connecting to read replica #{i}"))?;
+                replicas.push((url.clone(), replica_pool));
+            }
+            Some(ReplicaSet::spawn(replicas, self.max_replica_lag))
+        };
         tracing::info!("Created DB pool with parameters {self:?}");
         Ok(ConnectionPool {
             database_url: self.database_url.clone(),
             inner: pool,
             max_size: self.max_size,
+            thresholds: self.thresholds,
+            replicas,
             traced_connections: None,
             _db: PhantomData,
         })
     }
 
-    /// Builds a connection pool that has a single connection.
+    /// Builds a connection pool that has a single connection. Read replicas are not used by the built pool.
     pub async fn build_singleton(&self) -> anyhow::Result<ConnectionPool<DB>> {
         let singleton_builder = Self {
             database_url: self.database_url.clone(),
             max_size: 1,
             acquire_timeout: self.acquire_timeout,
             statement_timeout: self.statement_timeout,
+            thresholds: self.thresholds,
+            replica_urls: vec![],
+            max_replica_lag: self.max_replica_lag,
             _db: PhantomData,
         };
         singleton_builder.build().await
@@ -248,12 +324,35 @@ impl GlobalConnectionPoolConfig {
     }
 }
 
+/// Per-pool overrides of the thresholds from [`GlobalConnectionPoolConfig`].
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct ConnectionThresholds {
+    long_connection: Option<Duration>,
+    slow_query: Option<Duration>,
+}
+
+impl ConnectionThresholds {
+    pub(crate) fn long_connection(&self) -> Duration {
+        self.long_connection.unwrap_or_else(|| {
+            ConnectionPool::<InternalMarker>::global_config().long_connection_threshold()
+        })
+    }
+
+    pub(crate) fn slow_query(&self) -> Duration {
+        self.slow_query.unwrap_or_else(|| {
+            ConnectionPool::<InternalMarker>::global_config().slow_query_threshold()
+        })
+    }
+}
+
 /// Pool of reusable database connections.
 #[derive(Clone)]
 pub struct ConnectionPool<DB: DbMarker> {
     pub(crate) inner: PgPool,
     database_url: SensitiveUrl,
     max_size: u32,
+    thresholds: ConnectionThresholds,
+    replicas: Option<Arc<ReplicaSet>>,
     pub(crate) traced_connections: Option<Arc<TracedConnections>>,
     _db: PhantomData<DB>,
 }
@@ -265,7 +364,9 @@ impl<DB: DbMarker> fmt::Debug for ConnectionPool<DB> {
             .field("database_url", &self.database_url)
             .field("options", &self.inner.options())
             .field("size", &self.inner.size())
+            .field("thresholds", &self.thresholds)
             .field("num_idle", &self.inner.num_idle())
+            .field("replicas", &self.replicas)
             .field("db", &any::type_name::<DB>())
             .field("traced_connections", &self.traced_connections)
             .finish()
@@ -316,6 +417,9 @@ impl<DB: DbMarker> ConnectionPool<DB> {
             max_size: max_pool_size,
             acquire_timeout: Duration::from_secs(30), // Default value used by `sqlx`
             statement_timeout: None,
+            thresholds: ConnectionThresholds::default(),
+            replica_urls: vec![],
+            max_replica_lag: ConnectionPoolBuilder::<DB>::DEFAULT_MAX_REPLICA_LAG,
             _db: PhantomData,
         }
     }
@@ -339,6 +443,12 @@ impl<DB: DbMarker> ConnectionPool<DB> {
         self.max_size
     }
 
+    /// Returns the share of connections currently in use relative to the maximum pool size, from 0 to 1.
+    pub fn saturation(&self) -> f64 {
+        let used_connections = (self.inner.size() as usize).saturating_sub(self.inner.num_idle());
+        used_connections as f64 / f64::from(self.max_size.max(1))
+    }
+
     /// Creates a `Connection` entity over a recoverable connection.
     /// Upon a database outage connection will block the thread until
     /// it will be able to recover the connection (or, if connection cannot
@@ -367,6 +477,7 @@ impl<DB: DbMarker> ConnectionPool<DB> {
             let tags = ConnectionTags {
                 requester,
                 location,
+                request_id: vlog::RequestId::current(),
             };
             self.connection_inner(Some(tags)).await
         }
@@ -386,6 +497,7 @@ impl<DB: DbMarker> ConnectionPool<DB> {
         Ok(Connection::<DB>::from_pool(
             conn,
             tags,
+            self.thresholds,
             self.traced_connections.as_deref(),
         ))
     }
@@ -398,12 +510,11 @@ impl<DB: DbMarker> ConnectionPool<DB> {
         const AVG_BACKOFF_INTERVAL: Duration = Duration::from_secs(1);
 
         for _ in 0..DB_CONNECTION_RETRIES {
-            CONNECTION_METRICS
-                .pool_size
-                .observe(self.inner.size() as usize);
-            CONNECTION_METRICS.pool_idle.observe(self.inner.num_idle());
+            let pool = self.select_pool();
+            CONNECTION_METRICS.pool_size.observe(pool.size() as usize);
+            CONNECTION_METRICS.pool_idle.observe(pool.num_idle());
 
-            let connection = self.inner.acquire().await;
+            let connection = pool.acquire().await;
             let connection_err = match connection {
                 Ok(connection) => return Ok(connection),
                 Err(err) => err,
@@ -421,12 +532,20 @@ impl<DB: DbMarker> ConnectionPool<DB> {
         }
 
         // Attempting to get the pooled connection for the last time
-        self.inner.acquire().await.map_err(|err| {
+        self.select_pool().acquire().await.map_err(|err| {
             Self::report_connection_error(&err);
             DalConnectionError::acquire_connection(err, tags.cloned()).into()
         })
     }
 
+    /// Selects a read replica to acquire a connection from, falling back to the main database.
+    fn select_pool(&self) -> &PgPool {
+        self.replicas
+            .as_deref()
+            .and_then(ReplicaSet::select)
+            .unwrap_or(&self.inner)
+    }
+
     fn report_connection_error(err: &sqlx::Error) {
         CONNECTION_METRICS.pool_acquire_error[&err.into()].inc();
     }
@@ -437,7 +556,6 @@ mod tests {
     use assert_matches::assert_matches;
 
     use super::*;
-    use crate::utils::InternalMarker;
 
     #[tokio::test]
     async fn setting_statement_timeout() {
@@ -465,4 +583,38 @@ mod tests {
             sqlx::Error::Database(db_err) if db_err.message().contains("statement timeout")
         );
     }
+
+    #[tokio::test]
+    async fn overriding_thresholds_for_pool() {
+        let db_url = TestTemplate::empty()
+            .unwrap()
+            .create_db::<InternalMarker>(1)
+            .await
+            .unwrap()
+            .database_url;
+
+        let pool = ConnectionPool::<InternalMarker>::singleton(db_url)
+            .set_slow_query_threshold(Some(Duration::from_millis(10)))
+            .build()
+            .await
+            .unwrap();
+        let mut storage = pool.connection().await.unwrap();
+        assert_eq!(storage.slow_query_threshold(), Duration::from_millis(10));
+        let transaction = storage.start_transaction().await.unwrap();
+        assert_eq!(
+            transaction.slow_query_threshold(),
+            Duration::from_millis(10)
+        );
+
+        let thresholds = ConnectionThresholds::default();
+        let global_config = ConnectionPool::<InternalMarker>::global_config();
+        assert_eq!(
+            thresholds.slow_query(),
+            global_config.slow_query_threshold()
+        );
+        assert_eq!(
+            thresholds.long_connection(),
+            global_config.long_connection_threshold()
+        );
+    }
 }
@@ -11,7 +11,7 @@
 //! [`Instrumented`] methods on the returned struct, e.g. to [report query latency](Instrumented::report_latency())
 //! and/or [to add logged args](Instrumented::with_arg()) for a query.
 
-use std::{fmt, future::Future, panic::Location};
+use std::{fmt, future::Future, panic::Location, time::Duration};
 
 use sqlx::{
     postgres::{PgCopyIn, PgQueryResult, PgRow},
@@ -19,13 +19,12 @@ use sqlx::{
     FromRow, IntoArguments, PgConnection, Postgres,
 };
 use tokio::time::Instant;
+use tracing::Instrument as _;
 
 use crate::{
     connection::{Connection, ConnectionTags, DbMarker},
-    connection_pool::ConnectionPool,
     error::{DalError, DalRequestError, DalResult},
     metrics::REQUEST_METRICS,
-    utils::InternalMarker,
 };
 
 type ThreadSafeDebug<'a> = dyn fmt::Debug + Send + Sync + 'a;
@@ -203,6 +202,7 @@ impl<'a> InstrumentedData<'a> {
     async fn fetch<R>(
         self,
         connection_tags: Option<&ConnectionTags>,
+        slow_query_threshold: Duration,
         query_future: impl Future<Output = Result<R, sqlx::Error>>,
     ) -> DalResult<R> {
         let Self {
@@ -213,10 +213,14 @@ impl<'a> InstrumentedData<'a> {
             slow_query_reporting_enabled,
         } = self;
         let started_at = Instant::now();
+        // The span allows tracing queries as a part of the calling component's trace (e.g., via OpenTelemetry).
+        // The request ID is recorded explicitly rather than added to the SQL text as a comment; the latter would
+        // defeat caching of prepared statements.
+        let request_id = vlog::RequestId::current().map(tracing::field::display);
+        let query_future =
+            query_future.instrument(tracing::debug_span!("db_query", name, request_id));
         tokio::pin!(query_future);
 
-        let slow_query_threshold =
-            ConnectionPool::<InternalMarker>::global_config().slow_query_threshold();
         let mut is_slow = false;
         let output =
             tokio::time::timeout_at(started_at + slow_query_threshold, &mut query_future).await;
@@ -360,8 +364,11 @@ where
         self,
         storage: &mut Connection<'_, DB>,
     ) -> DalResult<PgQueryResult> {
+        let slow_query_threshold = storage.slow_query_threshold();
         let (conn, tags) = storage.conn_and_tags();
-        self.data.fetch(tags, self.query.execute(conn)).await
+        self.data
+            .fetch(tags, slow_query_threshold, self.query.execute(conn))
+            .await
     }
 
     /// Fetches an optional row using this query.
@@ -369,8 +376,11 @@ where
         self,
         storage: &mut Connection<'_, DB>,
     ) -> DalResult<Option<PgRow>> {
+        let slow_query_threshold = storage.slow_query_threshold();
         let (conn, tags) = storage.conn_and_tags();
-        self.data.fetch(tags, self.query.fetch_optional(conn)).await
+        self.data
+            .fetch(tags, slow_query_threshold, self.query.fetch_optional(conn))
+            .await
     }
 }
 
@@ -384,8 +394,11 @@ where
         self,
         storage: &mut Connection<'_, DB>,
     ) -> DalResult<Vec<O>> {
+        let slow_query_threshold = storage.slow_query_threshold();
         let (conn, tags) = storage.conn_and_tags();
-        self.data.fetch(tags, self.query.fetch_all(conn)).await
+        self.data
+            .fetch(tags, slow_query_threshold, self.query.fetch_all(conn))
+            .await
     }
 }
 
@@ -400,14 +413,20 @@ where
         self,
         storage: &mut Connection<'_, DB>,
     ) -> DalResult<Option<O>> {
+        let slow_query_threshold = storage.slow_query_threshold();
         let (conn, tags) = storage.conn_and_tags();
-        self.data.fetch(tags, self.query.fetch_optional(conn)).await
+        self.data
+            .fetch(tags, slow_query_threshold, self.query.fetch_optional(conn))
+            .await
     }
 
     /// Fetches a single row using this query.
     pub async fn fetch_one<DB: DbMarker>(self, storage: &mut Connection<'_, DB>) -> DalResult<O> {
+        let slow_query_threshold = storage.slow_query_threshold();
         let (conn, tags) = storage.conn_and_tags();
-        self.data.fetch(tags, self.query.fetch_one(conn)).await
+        self.data
+            .fetch(tags, slow_query_threshold, self.query.fetch_one(conn))
+            .await
     }
 }
 
@@ -422,14 +441,20 @@ where
         self,
         storage: &mut Connection<'_, DB>,
     ) -> DalResult<Option<O>> {
+        let slow_query_threshold = storage.slow_query_threshold();
         let (conn, tags) = storage.conn_and_tags();
-        self.data.fetch(tags, self.query.fetch_optional(conn)).await
+        self.data
+            .fetch(tags, slow_query_threshold, self.query.fetch_optional(conn))
+            .await
     }
 
     /// Fetches a single row using this query.
     pub async fn fetch_one<DB: DbMarker>(self, storage: &mut Connection<'_, DB>) -> DalResult<O> {
+        let slow_query_threshold = storage.slow_query_threshold();
         let (conn, tags) = storage.conn_and_tags();
-        self.data.fetch(tags, self.query.fetch_one(conn)).await
+        self.data
+            .fetch(tags, slow_query_threshold, self.query.fetch_one(conn))
+            .await
     }
 
     /// Fetches all rows using this query and collects them into a `Vec`.
@@ -437,8 +462,11 @@ where
         self,
         storage: &mut Connection<'_, DB>,
     ) -> DalResult<Vec<O>> {
+        let slow_query_threshold = storage.slow_query_threshold();
         let (conn, tags) = storage.conn_and_tags();
-        self.data.fetch(tags, self.query.fetch_all(conn)).await
+        self.data
+            .fetch(tags, slow_query_threshold, self.query.fetch_all(conn))
+            .await
     }
 }
 
@@ -8,4 +8,5 @@ pub mod instrument;
 pub mod metrics;
 #[macro_use]
 pub mod macro_utils;
+mod replicas;
 pub mod utils;
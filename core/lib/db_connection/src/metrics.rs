@@ -1,7 +1,7 @@
 use std::{thread, time::Duration};
 
 use vise::{
-    Buckets, Counter, EncodeLabelSet, EncodeLabelValue, Family, Histogram, LabeledFamily,
+    Buckets, Counter, EncodeLabelSet, EncodeLabelValue, Family, Gauge, Histogram, LabeledFamily,
     LatencyObserver, Metrics, Unit,
 };
 
@@ -90,6 +90,12 @@ pub(crate) struct ConnectionMetrics {
     /// Lifetime of a DB connection, tagged with the requester label.
     #[metrics(buckets = Buckets::LATENCIES, unit = Unit::Seconds, labels = ["requester"])]
     pub lifetime: LabeledFamily<&'static str, Histogram<Duration>>,
+    /// Replication lag of a read replica, labeled by the replica index in the pool config.
+    #[metrics(unit = Unit::Seconds, labels = ["replica"])]
+    pub replica_lag: LabeledFamily<usize, Gauge<Duration>>,
+    /// Number of times a connection was acquired from the primary database because all read replicas
+    /// were lagging or unavailable.
+    pub replica_fallback: Counter,
 }
 
 #[vise::register]
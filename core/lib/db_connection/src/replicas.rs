@@ -0,0 +1,182 @@
+//! Routing of connections to read replicas of the database.
+
+use std::{
+    fmt,
+    sync::{
+        atomic::{AtomicU64, AtomicUsize, Ordering},
+        Arc, Weak,
+    },
+    time::Duration,
+};
+
+use sqlx::postgres::PgPool;
+use zksync_basic_types::url::SensitiveUrl;
+
+use crate::metrics::CONNECTION_METRICS;
+
+/// Query returning the replication lag of the queried database in seconds. Returns 0 for a primary database,
+/// and for a replica that has replayed all WAL it has received (otherwise, an idle replica would report
+/// an ever-growing lag).
+const LAG_QUERY: &str = "SELECT CASE \
+    WHEN NOT pg_is_in_recovery() OR pg_last_wal_receive_lsn() = pg_last_wal_replay_lsn() THEN 0 \
+    ELSE COALESCE(EXTRACT(EPOCH FROM NOW() - pg_last_xact_replay_timestamp()), 0) \
+    END::FLOAT8";
+
+/// Lag value used for replicas that cannot be queried.
+const UNAVAILABLE_LAG_MS: u64 = u64::MAX;
+
+struct Replica {
+    url: SensitiveUrl,
+    pool: PgPool,
+    lag_ms: AtomicU64,
+}
+
+impl fmt::Debug for Replica {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("Replica")
+            .field("url", &self.url)
+            .field("size", &self.pool.size())
+            .field("num_idle", &self.pool.num_idle())
+            .field("lag_ms", &self.lag_ms)
+            .finish()
+    }
+}
+
+/// Set of read replicas. Connections are distributed among replicas in the round-robin fashion, skipping replicas
+/// lagging behind the primary database by more than the configured threshold.
+#[derive(Debug)]
+pub(crate) struct ReplicaSet {
+    replicas: Vec<Replica>,
+    next: AtomicUsize,
+    max_lag: Duration,
+}
+
+impl ReplicaSet {
+    /// Interval between replication lag checks.
+    const LAG_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+    fn new(replicas: Vec<(SensitiveUrl, PgPool)>, max_lag: Duration) -> Self {
+        let replicas = replicas
+            .into_iter()
+            .map(|(url, pool)| Replica {
+                url,
+                pool,
+                // Replicas are not used until their lag is checked for the first time.
+                lag_ms: AtomicU64::new(UNAVAILABLE_LAG_MS),
+            })
+            .collect();
+        Self {
+            replicas,
+            next: AtomicUsize::new(0),
+            max_lag,
+        }
+    }
+
+    /// Creates a replica set and spawns a background task monitoring replication lag. The task terminates
+    /// once the set is dropped.
+    pub(crate) fn spawn(replicas: Vec<(SensitiveUrl, PgPool)>, max_lag: Duration) -> Arc<Self> {
+        let this = Arc::new(Self::new(replicas, max_lag));
+        tokio::spawn(Self::run_lag_monitor(Arc::downgrade(&this)));
+        this
+    }
+
+    /// Selects a pool for the next connection. Returns `None` if all replicas are lagging or unavailable.
+    pub(crate) fn select(&self) -> Option<&PgPool> {
+        let max_lag_ms = u64::try_from(self.max_lag.as_millis()).unwrap_or(u64::MAX);
+        let start = self.next.fetch_add(1, Ordering::Relaxed);
+        let selected = (0..self.replicas.len())
+            .map(|offset| &self.replicas[start.wrapping_add(offset) % self.replicas.len()])
+            .find(|replica| replica.lag_ms.load(Ordering::Relaxed) <= max_lag_ms);
+        if selected.is_none() {
+            CONNECTION_METRICS.replica_fallback.inc();
+        }
+        selected.map(|replica| &replica.pool)
+    }
+
+    async fn update_lags(&self) {
+        for (i, replica) in self.replicas.iter().enumerate() {
+            let lag = sqlx::query_scalar::<_, f64>(LAG_QUERY)
+                .fetch_one(&replica.pool)
+                .await;
+            let lag_ms = match lag {
+                Ok(lag) => {
+                    let lag = Duration::from_secs_f64(lag.max(0.0));
+                    CONNECTION_METRICS.replica_lag[&i].set(lag);
+                    u64::try_from(lag.as_millis()).unwrap_or(u64::MAX)
+                }
+                Err(err) => {
+                    tracing::warn!(
+                        "Failed checking replication lag for replica #{i} ({:?}): {err}",
+                        replica.url
+                    );
+                    UNAVAILABLE_LAG_MS
+                }
+            };
+            replica.lag_ms.store(lag_ms, Ordering::Relaxed);
+        }
+    }
+
+    async fn run_lag_monitor(this: Weak<Self>) {
+        loop {
+            let Some(this) = this.upgrade() else {
+                return;
+            };
+            this.update_lags().await;
+            drop(this);
+            tokio::time::sleep(Self::LAG_CHECK_INTERVAL).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{connection_pool::ConnectionPool, utils::InternalMarker};
+
+    fn lazy_pool() -> PgPool {
+        PgPool::connect_lazy("postgres://postgres@localhost/unused").unwrap()
+    }
+
+    #[tokio::test]
+    async fn selecting_replicas() {
+        let url: SensitiveUrl = "postgres://postgres@localhost/unused".parse().unwrap();
+        let replicas = (0..3).map(|_| (url.clone(), lazy_pool())).collect();
+        let set = ReplicaSet::new(replicas, Duration::from_millis(100));
+        // No replica was checked yet.
+        assert!(set.select().is_none());
+
+        for replica in &set.replicas {
+            replica.lag_ms.store(10, Ordering::Relaxed);
+        }
+        let selected: Vec<_> = (0..6)
+            .map(|_| set.select().unwrap() as *const PgPool)
+            .collect();
+        let expected: Vec<_> = (0..6)
+            .map(|i| &set.replicas[(i + 1) % 3].pool as *const PgPool)
+            .collect();
+        assert_eq!(selected, expected);
+
+        // Lagging replica should be skipped.
+        set.replicas[1].lag_ms.store(1_000, Ordering::Relaxed);
+        for _ in 0..6 {
+            let selected = set.select().unwrap();
+            assert!(!std::ptr::eq(selected, &set.replicas[1].pool));
+        }
+
+        for replica in &set.replicas {
+            replica.lag_ms.store(1_000, Ordering::Relaxed);
+        }
+        assert!(set.select().is_none());
+    }
+
+    #[tokio::test]
+    async fn checking_lag_for_primary_database() {
+        let pool = ConnectionPool::<InternalMarker>::test_pool().await;
+        let url = pool.database_url().clone();
+        let set = ReplicaSet::new(vec![(url, pool.inner.clone())], Duration::ZERO);
+        set.update_lags().await;
+        assert_eq!(set.replicas[0].lag_ms.load(Ordering::Relaxed), 0);
+        assert!(std::ptr::eq(set.select().unwrap(), &set.replicas[0].pool));
+    }
+}
@@ -64,6 +64,8 @@ mod tests {
                 filters_disabled: false,
                 filters_limit: Some(10000),
                 subscriptions_limit: Some(10000),
+                subscriptions_per_connection_limit: Some(128),
+                subscription_backlog_size: Some(256),
                 pubsub_polling_interval: Some(200),
                 max_nonce_ahead: 5,
                 request_timeout: Some(10),
@@ -81,6 +83,7 @@ mod tests {
                 initial_writes_cache_size_mb: Some(32),
                 latest_values_cache_size_mb: Some(256),
                 fee_history_limit: Some(100),
+                max_logs_block_range: Some(50000),
                 max_batch_request_size: Some(200),
                 max_response_body_size_mb: Some(10),
                 max_response_body_size_overrides_mb: [
@@ -98,11 +101,21 @@ mod tests {
                     addr("0x0000000000000000000000000000000000000001"),
                     addr("0x0000000000000000000000000000000000000002"),
                 ],
+                evm_emulator_enabled: true,
+                max_pending_txs_per_initiator: Some(16),
+                min_max_fee_per_gas: Some(100_000_000),
+                denied_initiators: vec![addr("0x0000000000000000000000000000000000000003")],
+                eth_call_cache_size: Some(10_000),
+                eth_call_cache_ttl_sec: Some(30),
+                shutdown_drain_timeout_sec: Some(45),
             },
             prometheus: PrometheusConfig {
                 listener_port: 3312,
                 pushgateway_url: "http://127.0.0.1:9091".into(),
                 push_interval_ms: Some(100),
+                push_gateway_enabled: true,
+                push_job_name: Some("zksync_server".into()),
+                push_instance: None,
             },
             healthcheck: HealthCheckConfig {
                 port: 8081,
@@ -125,6 +138,8 @@ mod tests {
             API_WEB3_JSON_RPC_FILTERS_DISABLED=false
             API_WEB3_JSON_RPC_FILTERS_LIMIT=10000
             API_WEB3_JSON_RPC_SUBSCRIPTIONS_LIMIT=10000
+            API_WEB3_JSON_RPC_SUBSCRIPTIONS_PER_CONNECTION_LIMIT=128
+            API_WEB3_JSON_RPC_SUBSCRIPTION_BACKLOG_SIZE=256
             API_WEB3_JSON_RPC_PUBSUB_POLLING_INTERVAL=200
             API_WEB3_JSON_RPC_MAX_NONCE_AHEAD=5
             API_WEB3_JSON_RPC_GAS_PRICE_SCALE_FACTOR=1.2
@@ -139,9 +154,17 @@ mod tests {
             API_WEB3_JSON_RPC_INITIAL_WRITES_CACHE_SIZE_MB=32
             API_WEB3_JSON_RPC_LATEST_VALUES_CACHE_SIZE_MB=256
             API_WEB3_JSON_RPC_FEE_HISTORY_LIMIT=100
+            API_WEB3_JSON_RPC_MAX_LOGS_BLOCK_RANGE=50000
             API_WEB3_JSON_RPC_MAX_BATCH_REQUEST_SIZE=200
             API_WEB3_JSON_RPC_WEBSOCKET_REQUESTS_PER_MINUTE_LIMIT=10
             API_WEB3_JSON_RPC_MEMPOOL_CACHE_SIZE=10000
+            API_WEB3_JSON_RPC_EVM_EMULATOR_ENABLED=true
+            API_WEB3_JSON_RPC_MAX_PENDING_TXS_PER_INITIATOR=16
+            API_WEB3_JSON_RPC_MIN_MAX_FEE_PER_GAS=100000000
+            API_WEB3_JSON_RPC_DENIED_INITIATORS="0x0000000000000000000000000000000000000003"
+            API_WEB3_JSON_RPC_ETH_CALL_CACHE_SIZE=10000
+            API_WEB3_JSON_RPC_ETH_CALL_CACHE_TTL_SEC=30
+            API_WEB3_JSON_RPC_SHUTDOWN_DRAIN_TIMEOUT_SEC=45
             API_WEB3_JSON_RPC_MEMPOOL_CACHE_UPDATE_INTERVAL=50
             API_CONTRACT_VERIFICATION_PORT="3070"
             API_CONTRACT_VERIFICATION_URL="http://127.0.0.1:3070"
@@ -150,6 +173,8 @@ mod tests {
             API_PROMETHEUS_LISTENER_PORT="3312"
             API_PROMETHEUS_PUSHGATEWAY_URL="http://127.0.0.1:9091"
             API_PROMETHEUS_PUSH_INTERVAL_MS=100
+            API_PROMETHEUS_PUSH_GATEWAY_ENABLED=true
+            API_PROMETHEUS_PUSH_JOB_NAME="zksync_server"
             API_HEALTHCHECK_PORT=8081
             API_HEALTHCHECK_SLOW_TIME_LIMIT_MS=250
             API_HEALTHCHECK_HARD_TIME_LIMIT_MS=2000
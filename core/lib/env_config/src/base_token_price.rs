@@ -0,0 +1,118 @@
+use zksync_config::configs::base_token_price::BaseTokenPriceConfig;
+
+use crate::{envy_load, FromEnv};
+
+impl FromEnv for BaseTokenPriceConfig {
+    fn from_env() -> anyhow::Result<Self> {
+        let mut config: Self = envy_load("base_token_price", "BASE_TOKEN_PRICE_")?;
+        // Each source is enabled by setting its required variable.
+        if std::env::var_os("BASE_TOKEN_PRICE_FIXED_NUMERATOR").is_some() {
+            config.fixed = Some(envy_load(
+                "base_token_price_fixed",
+                "BASE_TOKEN_PRICE_FIXED_",
+            )?);
+        }
+        if std::env::var_os("BASE_TOKEN_PRICE_COINGECKO_TOKEN_ADDRESS").is_some() {
+            config.coingecko = Some(envy_load(
+                "base_token_price_coingecko",
+                "BASE_TOKEN_PRICE_COINGECKO_",
+            )?);
+        }
+        if std::env::var_os("BASE_TOKEN_PRICE_UNISWAP_V3_TWAP_POOL_ADDRESS").is_some() {
+            config.uniswap_v3_twap = Some(envy_load(
+                "base_token_price_uniswap_v3_twap",
+                "BASE_TOKEN_PRICE_UNISWAP_V3_TWAP_",
+            )?);
+        }
+        anyhow::ensure!(
+            config.fixed.is_some()
+                || config.coingecko.is_some()
+                || config.uniswap_v3_twap.is_some(),
+            "no base token price sources are configured"
+        );
+        Ok(config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zksync_config::configs::base_token_price::{
+        CoingeckoSourceConfig, FixedRatioSourceConfig, UniswapV3TwapSourceConfig,
+    };
+
+    use super::*;
+    use crate::test_utils::{addr, EnvMutex};
+
+    static MUTEX: EnvMutex = EnvMutex::new();
+
+    fn expected_config() -> BaseTokenPriceConfig {
+        BaseTokenPriceConfig {
+            polling_interval_ms: Some(10_000),
+            ratio_cache_update_interval_ms: Some(2_000),
+            max_deviation_bps: Some(300),
+            max_staleness_secs: Some(900),
+            min_agreeing_sources: Some(2),
+            base_token_decimals: Some(18),
+            fixed: Some(FixedRatioSourceConfig {
+                numerator: 3,
+                denominator: 2,
+            }),
+            coingecko: Some(CoingeckoSourceConfig {
+                api_url: None,
+                api_key: Some("key".to_owned()),
+                platform_id: None,
+                token_address: addr("3c3a81e81dc49a522a592e7622a7e711c06bf354"),
+            }),
+            uniswap_v3_twap: Some(UniswapV3TwapSourceConfig {
+                pool_address: addr("f4c5e0f4590b6679b3030d29a84857f226087fef"),
+                base_token_is_token0: true,
+                window_secs: Some(600),
+            }),
+        }
+    }
+
+    #[test]
+    fn from_env_base_token_price() {
+        let mut lock = MUTEX.lock();
+        let config = r#"
+            BASE_TOKEN_PRICE_POLLING_INTERVAL_MS=10000
+            BASE_TOKEN_PRICE_RATIO_CACHE_UPDATE_INTERVAL_MS=2000
+            BASE_TOKEN_PRICE_MAX_DEVIATION_BPS=300
+            BASE_TOKEN_PRICE_MAX_STALENESS_SECS=900
+            BASE_TOKEN_PRICE_MIN_AGREEING_SOURCES=2
+            BASE_TOKEN_PRICE_BASE_TOKEN_DECIMALS=18
+            BASE_TOKEN_PRICE_FIXED_NUMERATOR=3
+            BASE_TOKEN_PRICE_FIXED_DENOMINATOR=2
+            BASE_TOKEN_PRICE_COINGECKO_API_KEY=key
+            BASE_TOKEN_PRICE_COINGECKO_TOKEN_ADDRESS=0x3c3a81e81dc49a522a592e7622a7e711c06bf354
+            BASE_TOKEN_PRICE_UNISWAP_V3_TWAP_POOL_ADDRESS=0xf4c5e0f4590b6679b3030d29a84857f226087fef
+            BASE_TOKEN_PRICE_UNISWAP_V3_TWAP_BASE_TOKEN_IS_TOKEN0=true
+            BASE_TOKEN_PRICE_UNISWAP_V3_TWAP_WINDOW_SECS=600
+        "#;
+        lock.set_env(config);
+        lock.remove_env(&[
+            "BASE_TOKEN_PRICE_COINGECKO_API_URL",
+            "BASE_TOKEN_PRICE_COINGECKO_PLATFORM_ID",
+        ]);
+
+        let actual = BaseTokenPriceConfig::from_env().unwrap();
+        assert_eq!(actual, expected_config());
+    }
+
+    #[test]
+    fn from_env_base_token_price_without_sources() {
+        let mut lock = MUTEX.lock();
+        lock.set_env("BASE_TOKEN_PRICE_POLLING_INTERVAL_MS=10000");
+        lock.remove_env(&[
+            "BASE_TOKEN_PRICE_FIXED_NUMERATOR",
+            "BASE_TOKEN_PRICE_COINGECKO_TOKEN_ADDRESS",
+            "BASE_TOKEN_PRICE_UNISWAP_V3_TWAP_POOL_ADDRESS",
+        ]);
+
+        let err = BaseTokenPriceConfig::from_env().unwrap_err();
+        assert!(
+            err.to_string().contains("no base token price sources"),
+            "{err}"
+        );
+    }
+}
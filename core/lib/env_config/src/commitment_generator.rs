@@ -0,0 +1,39 @@
+use zksync_config::configs::CommitmentGeneratorConfig;
+
+use crate::{envy_load, FromEnv};
+
+impl FromEnv for CommitmentGeneratorConfig {
+    fn from_env() -> anyhow::Result<Self> {
+        envy_load("commitment_generator", "COMMITMENT_GENERATOR_")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroU32;
+
+    use super::*;
+    use crate::test_utils::EnvMutex;
+
+    static MUTEX: EnvMutex = EnvMutex::new();
+
+    #[test]
+    fn from_env_commitment_generator() {
+        let mut lock = MUTEX.lock();
+        let config = r#"
+            COMMITMENT_GENERATOR_MAX_PARALLELISM=8
+        "#;
+        lock.set_env(config);
+        let actual = CommitmentGeneratorConfig::from_env().unwrap();
+        assert_eq!(
+            actual,
+            CommitmentGeneratorConfig {
+                max_parallelism: NonZeroU32::new(8),
+            }
+        );
+
+        lock.remove_env(&["COMMITMENT_GENERATOR_MAX_PARALLELISM"]);
+        let actual = CommitmentGeneratorConfig::from_env().unwrap();
+        assert_eq!(actual, CommitmentGeneratorConfig::default());
+    }
+}
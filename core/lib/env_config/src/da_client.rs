@@ -0,0 +1,238 @@
+use zksync_config::configs::da_client::{
+    DAClient, DAClientConfig, AVAIL_CLIENT_CONFIG_NAME, CELESTIA_CLIENT_CONFIG_NAME,
+    EIGEN_CLIENT_CONFIG_NAME, MANTLE_CLIENT_CONFIG_NAME, OBJECT_STORE_CLIENT_CONFIG_NAME,
+};
+
+use crate::{envy_load, FromEnv};
+
+impl FromEnv for DAClientConfig {
+    fn from_env() -> anyhow::Result<Self> {
+        let client_tag = std::env::var("DA_CLIENT")?;
+        let client = load_client(&client_tag, "DA_")?;
+        let shadow_client = match std::env::var("DA_SHADOW_CLIENT") {
+            Ok(shadow_tag) => Some(load_client(&shadow_tag, "DA_SHADOW_")?),
+            Err(_) => None,
+        };
+
+        Ok(Self {
+            client,
+            shadow_client,
+        })
+    }
+}
+
+fn load_client(client_tag: &str, prefix: &str) -> anyhow::Result<DAClient> {
+    Ok(match client_tag {
+        MANTLE_CLIENT_CONFIG_NAME => DAClient::Mantle(envy_load("da_mantle_config", prefix)?),
+        EIGEN_CLIENT_CONFIG_NAME => DAClient::EigenDA(envy_load("da_eigen_config", prefix)?),
+        CELESTIA_CLIENT_CONFIG_NAME => DAClient::Celestia(envy_load("da_celestia_config", prefix)?),
+        AVAIL_CLIENT_CONFIG_NAME => DAClient::Avail(envy_load("da_avail_config", prefix)?),
+        OBJECT_STORE_CLIENT_CONFIG_NAME => {
+            DAClient::ObjectStore(envy_load("da_object_store", prefix)?)
+        }
+        _ => anyhow::bail!("Unknown DA client name: {}", client_tag),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use zksync_config::{
+        configs::{
+            da_client::{
+                avail::AvailConfig, celestia::CelestiaConfig, eigen::EigenDAConfig,
+                mantle::MantleDAConfig,
+            },
+            object_store::ObjectStoreMode,
+        },
+        ObjectStoreConfig,
+    };
+
+    use super::*;
+    use crate::test_utils::EnvMutex;
+
+    static MUTEX: EnvMutex = EnvMutex::new();
+
+    #[test]
+    fn from_env_mantle_client() {
+        let mut lock = MUTEX.lock();
+        let config = r#"
+            DA_CLIENT="Mantle"
+            DA_API_NODE_URL="localhost:12345"
+            DA_REQUEST_TIMEOUT_MS=10000
+            DA_WAIT_FOR_FINALIZATION=true
+            DA_MAX_BLOB_SIZE_BYTES=2097152
+        "#;
+        lock.set_env(config);
+
+        let actual = DAClientConfig::from_env().unwrap();
+        assert_eq!(
+            actual,
+            DAClientConfig {
+                client: DAClient::Mantle(MantleDAConfig {
+                    api_node_url: "localhost:12345".to_owned(),
+                    request_timeout_ms: 10_000,
+                    wait_for_finalization: true,
+                    max_blob_size_bytes: Some(2_097_152),
+                }),
+                shadow_client: None,
+            }
+        );
+    }
+
+    #[test]
+    fn from_env_eigen_client() {
+        let mut lock = MUTEX.lock();
+        let config = r#"
+            DA_CLIENT="EigenDA"
+            DA_DISPERSER_RPC="https://disperser-holesky.eigenda.xyz:443"
+            DA_CUSTOM_QUORUM_NUMBERS="2,3"
+            DA_ACCOUNT_ID="0x1234"
+            DA_WAIT_FOR_FINALIZATION=false
+        "#;
+        lock.set_env(config);
+        lock.remove_env(&["DA_REQUEST_TIMEOUT_MS", "DA_MAX_BLOB_SIZE_BYTES"]);
+
+        let actual = DAClientConfig::from_env().unwrap();
+        assert_eq!(
+            actual,
+            DAClientConfig {
+                client: DAClient::EigenDA(EigenDAConfig {
+                    disperser_rpc: "https://disperser-holesky.eigenda.xyz:443".to_owned(),
+                    request_timeout_ms: EigenDAConfig::default_request_timeout_ms(),
+                    custom_quorum_numbers: vec![2, 3],
+                    account_id: Some("0x1234".to_owned()),
+                    wait_for_finalization: false,
+                    max_blob_size_bytes: None,
+                }),
+                shadow_client: None,
+            }
+        );
+    }
+
+    #[test]
+    fn from_env_celestia_client() {
+        let mut lock = MUTEX.lock();
+        let config = r#"
+            DA_CLIENT="Celestia"
+            DA_API_NODE_URL="http://localhost:26658"
+            DA_AUTH_TOKEN="secret"
+            DA_NAMESPACE="0x000008e5f679bf7116cb"
+            DA_GAS_PRICE=0.002
+            DA_INCLUSION_CONFIRMATIONS=2
+            DA_REQUEST_TIMEOUT_MS=30000
+            DA_MAX_BLOB_SIZE_BYTES=1973786
+        "#;
+        lock.set_env(config);
+
+        let actual = DAClientConfig::from_env().unwrap();
+        assert_eq!(
+            actual,
+            DAClientConfig {
+                client: DAClient::Celestia(CelestiaConfig {
+                    api_node_url: "http://localhost:26658".to_owned(),
+                    auth_token: Some("secret".to_owned()),
+                    namespace: "0x000008e5f679bf7116cb".to_owned(),
+                    gas_price: Some(0.002),
+                    inclusion_confirmations: 2,
+                    request_timeout_ms: 30_000,
+                    max_blob_size_bytes: Some(1_973_786),
+                }),
+                shadow_client: None,
+            }
+        );
+    }
+
+    #[test]
+    fn from_env_avail_client() {
+        let mut lock = MUTEX.lock();
+        let config = r#"
+            DA_CLIENT="Avail"
+            DA_API_NODE_URL="http://localhost:7007"
+            DA_BRIDGE_API_URL="https://turing-bridge-api.avail.so"
+            DA_APP_ID=42
+            DA_REQUEST_TIMEOUT_MS=20000
+            DA_MAX_BLOB_SIZE_BYTES=524288
+        "#;
+        lock.set_env(config);
+
+        let actual = DAClientConfig::from_env().unwrap();
+        assert_eq!(
+            actual,
+            DAClientConfig {
+                client: DAClient::Avail(AvailConfig {
+                    api_node_url: "http://localhost:7007".to_owned(),
+                    bridge_api_url: "https://turing-bridge-api.avail.so".to_owned(),
+                    app_id: 42,
+                    request_timeout_ms: 20_000,
+                    max_blob_size_bytes: Some(524_288),
+                }),
+                shadow_client: None,
+            }
+        );
+    }
+
+    #[test]
+    fn from_env_object_store_client() {
+        let mut lock = MUTEX.lock();
+        let config = r#"
+            DA_CLIENT="ObjectStore"
+            DA_MODE="FileBacked"
+            DA_FILE_BACKED_BASE_PATH="./chains/era/artifacts/"
+            DA_MAX_RETRIES=3
+        "#;
+        lock.set_env(config);
+
+        let actual = DAClientConfig::from_env().unwrap();
+        assert_eq!(
+            actual,
+            DAClientConfig {
+                client: DAClient::ObjectStore(ObjectStoreConfig {
+                    mode: ObjectStoreMode::FileBacked {
+                        file_backed_base_path: "./chains/era/artifacts/".to_owned(),
+                    },
+                    max_retries: 3,
+                    local_mirror_path: None,
+                }),
+                shadow_client: None,
+            }
+        );
+    }
+
+    #[test]
+    fn from_env_shadow_client() {
+        let mut lock = MUTEX.lock();
+        let config = r#"
+            DA_CLIENT="Mantle"
+            DA_API_NODE_URL="localhost:12345"
+            DA_REQUEST_TIMEOUT_MS=10000
+            DA_WAIT_FOR_FINALIZATION=true
+            DA_SHADOW_CLIENT="Avail"
+            DA_SHADOW_API_NODE_URL="http://localhost:7007"
+            DA_SHADOW_BRIDGE_API_URL="https://turing-bridge-api.avail.so"
+            DA_SHADOW_APP_ID=42
+            DA_SHADOW_REQUEST_TIMEOUT_MS=20000
+        "#;
+        lock.set_env(config);
+        lock.remove_env(&["DA_MAX_BLOB_SIZE_BYTES", "DA_SHADOW_MAX_BLOB_SIZE_BYTES"]);
+
+        let actual = DAClientConfig::from_env().unwrap();
+        assert_eq!(
+            actual,
+            DAClientConfig {
+                client: DAClient::Mantle(MantleDAConfig {
+                    api_node_url: "localhost:12345".to_owned(),
+                    request_timeout_ms: 10_000,
+                    wait_for_finalization: true,
+                    max_blob_size_bytes: None,
+                }),
+                shadow_client: Some(DAClient::Avail(AvailConfig {
+                    api_node_url: "http://localhost:7007".to_owned(),
+                    bridge_api_url: "https://turing-bridge-api.avail.so".to_owned(),
+                    app_id: 42,
+                    request_timeout_ms: 20_000,
+                    max_blob_size_bytes: None,
+                })),
+            }
+        );
+    }
+}
@@ -0,0 +1,56 @@
+use zksync_config::DADispatcherConfig;
+
+use crate::{envy_load, FromEnv};
+
+impl FromEnv for DADispatcherConfig {
+    fn from_env() -> anyhow::Result<Self> {
+        envy_load("da_dispatcher", "DA_DISPATCHER_")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zksync_config::configs::da_dispatcher::DADispatcherConfig;
+
+    use super::*;
+    use crate::test_utils::EnvMutex;
+
+    static MUTEX: EnvMutex = EnvMutex::new();
+
+    fn expected_da_layer_config(
+        interval: u32,
+        rows_limit: u32,
+        max_retries: u16,
+    ) -> DADispatcherConfig {
+        DADispatcherConfig {
+            polling_interval_ms: Some(interval),
+            max_rows_to_dispatch: Some(rows_limit),
+            max_retries: Some(max_retries),
+            initial_retry_backoff_ms: Some(500),
+            max_retry_backoff_ms: Some(60_000),
+            max_l1_batch_age_secs: Some(7_200),
+            cost_budget_period_secs: Some(3_600),
+            max_dispatched_bytes_per_period: Some(1_000_000),
+            max_fee_per_period: None,
+        }
+    }
+
+    #[test]
+    fn from_env_da_dispatcher() {
+        let mut lock = MUTEX.lock();
+        let config = r#"
+            DA_DISPATCHER_POLLING_INTERVAL_MS=5000
+            DA_DISPATCHER_MAX_ROWS_TO_DISPATCH=60
+            DA_DISPATCHER_MAX_RETRIES=7
+            DA_DISPATCHER_INITIAL_RETRY_BACKOFF_MS=500
+            DA_DISPATCHER_MAX_RETRY_BACKOFF_MS=60000
+            DA_DISPATCHER_MAX_L1_BATCH_AGE_SECS=7200
+            DA_DISPATCHER_COST_BUDGET_PERIOD_SECS=3600
+            DA_DISPATCHER_MAX_DISPATCHED_BYTES_PER_PERIOD=1000000
+        "#;
+        lock.set_env(config);
+        lock.remove_env(&["DA_DISPATCHER_MAX_FEE_PER_PERIOD"]);
+        let actual = DADispatcherConfig::from_env().unwrap();
+        assert_eq!(actual, expected_da_layer_config(5000, 60, 7));
+    }
+}
@@ -23,6 +23,14 @@ impl FromEnv for L1Secrets {
                 .context("ETH_CLIENT_WEB3_URL")?
                 .parse()
                 .context("ETH_CLIENT_WEB3_URL")?,
+            fallback_l1_rpc_urls: match std::env::var("ETH_CLIENT_FALLBACK_WEB3_URLS") {
+                Ok(urls) => urls
+                    .split(',')
+                    .map(|url| url.trim().parse())
+                    .collect::<Result<_, _>>()
+                    .context("ETH_CLIENT_FALLBACK_WEB3_URLS")?,
+                Err(_) => vec![],
+            },
         })
     }
 }
@@ -70,6 +78,7 @@ mod tests {
                     l1_batch_min_age_before_execute_seconds: Some(1000),
                     max_acceptable_priority_fee_in_gwei: 100_000_000_000,
                     pubdata_sending_mode: PubdataSendingMode::Calldata,
+                    blob_base_fee_calldata_fallback_threshold: Some(50_000_000_000),
                 }),
                 gas_adjuster: Some(GasAdjusterConfig {
                     default_priority_fee_per_gas: 20000000000,
@@ -92,6 +101,10 @@ mod tests {
             },
             L1Secrets {
                 l1_rpc_url: "http://127.0.0.1:8545".to_string().parse().unwrap(),
+                fallback_l1_rpc_urls: vec![
+                    "http://127.0.0.1:8546".parse().unwrap(),
+                    "http://127.0.0.1:8547".parse().unwrap(),
+                ],
             },
         )
     }
@@ -131,7 +144,9 @@ mod tests {
             ETH_SENDER_SENDER_L1_BATCH_MIN_AGE_BEFORE_EXECUTE_SECONDS="1000"
             ETH_SENDER_SENDER_MAX_ACCEPTABLE_PRIORITY_FEE_IN_GWEI="100000000000"
             ETH_SENDER_SENDER_PUBDATA_SENDING_MODE="Calldata"
+            ETH_SENDER_SENDER_BLOB_BASE_FEE_CALLDATA_FALLBACK_THRESHOLD="50000000000"
             ETH_CLIENT_WEB3_URL="http://127.0.0.1:8545"
+            ETH_CLIENT_FALLBACK_WEB3_URLS="http://127.0.0.1:8546,http://127.0.0.1:8547"
 
         "#;
         lock.set_env(config);
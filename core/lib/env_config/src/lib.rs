@@ -2,9 +2,13 @@ use anyhow::Context as _;
 use serde::de::DeserializeOwned;
 
 mod api;
+mod base_token_price;
 mod chain;
+mod commitment_generator;
 mod contract_verifier;
 mod contracts;
+mod da_client;
+mod da_dispatcher;
 mod database;
 mod eth_sender;
 mod eth_watch;
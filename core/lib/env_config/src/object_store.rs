@@ -92,6 +92,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn s3_config_from_env() {
+        let mut lock = MUTEX.lock();
+        let config = r#"
+            OBJECT_STORE_MODE="S3"
+            OBJECT_STORE_BUCKET_BASE_URL="zksync-artifacts"
+            OBJECT_STORE_S3_REGION="eu-central-1"
+            OBJECT_STORE_S3_ENDPOINT="http://localhost:9000"
+            OBJECT_STORE_MAX_RETRIES="3"
+        "#;
+        lock.set_env(config);
+        let actual = ObjectStoreConfig::from_env().unwrap();
+        assert_eq!(actual.max_retries, 3);
+        assert_eq!(
+            actual.mode,
+            ObjectStoreMode::S3 {
+                bucket_base_url: "zksync-artifacts".to_owned(),
+                s3_region: "eu-central-1".to_owned(),
+                s3_endpoint: Some("http://localhost:9000".to_owned()),
+                s3_credential_file_path: None,
+            }
+        );
+    }
+
     #[test]
     fn public_bucket_config_from_env() {
         let mut lock = MUTEX.lock();
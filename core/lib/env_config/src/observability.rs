@@ -1,4 +1,5 @@
-use zksync_config::configs::{ObservabilityConfig, OpentelemetryConfig};
+use anyhow::Context as _;
+use zksync_config::configs::{ObservabilityConfig, OpentelemetryConfig, SloConfig};
 
 use crate::FromEnv;
 
@@ -25,6 +26,7 @@ impl FromEnv for ObservabilityConfig {
                 _ => None,
             }
         };
+        let sentry_release = std::env::var("MISC_SENTRY_RELEASE").ok();
         let log_format = if let Ok(log_format) = std::env::var("MISC_LOG_FORMAT") {
             if log_format != "plain" && log_format != "json" {
                 anyhow::bail!("MISC_LOG_FORMAT has an unexpected value {}", log_format);
@@ -35,19 +37,65 @@ impl FromEnv for ObservabilityConfig {
         };
         let opentelemetry_level = std::env::var("OPENTELEMETRY_LEVEL").ok();
         let otlp_endpoint = std::env::var("OTLP_ENDPOINT").ok();
+        let sampling_ratio = std::env::var("OPENTELEMETRY_SAMPLING_RATIO")
+            .ok()
+            .map(|ratio| ratio.parse())
+            .transpose()
+            .context("OPENTELEMETRY_SAMPLING_RATIO")?;
+        let resource_attributes = std::env::var("OPENTELEMETRY_RESOURCE_ATTRIBUTES").ok();
         let opentelemetry = match (opentelemetry_level, otlp_endpoint) {
-            (Some(level), Some(endpoint)) => Some(OpentelemetryConfig { level, endpoint }),
+            (Some(level), Some(endpoint)) => Some(OpentelemetryConfig {
+                level,
+                endpoint,
+                sampling_ratio,
+                resource_attributes,
+            }),
             _ => None,
         };
 
         let log_directives = std::env::var("RUST_LOG").ok();
+        let log_sampling_directives = std::env::var("MISC_LOG_SAMPLING_DIRECTIVES").ok();
+        let admin_port = std::env::var("MISC_ADMIN_PORT")
+            .ok()
+            .map(|port| port.parse())
+            .transpose()
+            .context("MISC_ADMIN_PORT")?;
+        let admin_profiling_enabled = std::env::var("MISC_ADMIN_PROFILING_ENABLED")
+            .ok()
+            .map(|enabled| enabled.parse())
+            .transpose()
+            .context("MISC_ADMIN_PROFILING_ENABLED")?
+            .unwrap_or(false);
+        let slo = SloConfig {
+            check_interval_ms: parse_optional_var("MISC_SLO_CHECK_INTERVAL_MS")?,
+            max_sync_lag: parse_optional_var("MISC_SLO_MAX_SYNC_LAG")?,
+            max_pool_saturation: parse_optional_var("MISC_SLO_MAX_POOL_SATURATION")?,
+            max_api_p99_latency_ms: parse_optional_var("MISC_SLO_MAX_API_P99_LATENCY_MS")?,
+        };
 
         Ok(ObservabilityConfig {
             sentry_url,
             sentry_environment,
+            sentry_release,
             log_format,
             opentelemetry,
             log_directives,
+            log_sampling_directives,
+            admin_port,
+            admin_profiling_enabled,
+            slo,
         })
     }
 }
+
+fn parse_optional_var<T>(name: &str) -> anyhow::Result<Option<T>>
+where
+    T: std::str::FromStr,
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    std::env::var(name)
+        .ok()
+        .map(|value| value.parse())
+        .transpose()
+        .with_context(|| name.to_owned())
+}
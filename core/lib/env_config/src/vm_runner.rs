@@ -1,4 +1,6 @@
-use zksync_config::configs::ProtectiveReadsWriterConfig;
+use zksync_config::configs::{
+    BasicWitnessInputProducerConfig, ProtectiveReadsWriterConfig, VmPlaygroundConfig,
+};
 
 use crate::{envy_load, FromEnv};
 
@@ -7,3 +9,15 @@ impl FromEnv for ProtectiveReadsWriterConfig {
         envy_load("vm_runner.protective_reads", "VM_RUNNER_PROTECTIVE_READS_")
     }
 }
+
+impl FromEnv for BasicWitnessInputProducerConfig {
+    fn from_env() -> anyhow::Result<Self> {
+        envy_load("vm_runner.bwip", "VM_RUNNER_BWIP_")
+    }
+}
+
+impl FromEnv for VmPlaygroundConfig {
+    fn from_env() -> anyhow::Result<Self> {
+        envy_load("vm_runner.playground", "VM_RUNNER_PLAYGROUND_")
+    }
+}
@@ -1,21 +1,24 @@
 use std::{
-    collections::HashMap,
+    borrow::Cow,
+    collections::{BTreeMap, HashMap},
     fmt,
     sync::{Arc, Mutex},
     thread,
-    time::Duration,
+    time::{Duration, SystemTime},
 };
 
 // Public re-export for other crates to be able to implement the interface.
 pub use async_trait::async_trait;
 use futures::future;
-use serde::Serialize;
+use serde::{Serialize, Serializer};
 use tokio::sync::watch;
 
 use self::metrics::{CheckResult, METRICS};
+pub use self::slo::{LatencyWindow, SloMonitor};
 use crate::metrics::AppHealthCheckConfig;
 
 mod metrics;
+mod slo;
 #[cfg(test)]
 mod tests;
 
@@ -40,11 +43,30 @@ pub enum HealthStatus {
 }
 
 impl HealthStatus {
-    /// Checks whether a component is healthy according to this status.
+    /// Checks whether a component is healthy according to this status. This is used as a readiness indicator,
+    /// i.e., whether the component is ready to receive traffic.
     pub fn is_healthy(self) -> bool {
         matches!(self, Self::Ready | Self::Affected)
     }
 
+    /// Checks whether a component is live according to this status, i.e., it doesn't require the process to be restarted.
+    /// Unlike [readiness](Self::is_healthy()), liveness is retained while the component is initializing
+    /// (e.g., the Merkle tree is catching up) or shutting down.
+    pub fn is_live(self) -> bool {
+        !matches!(self, Self::Panicked)
+    }
+
+    /// Returns the coarse-grained condition corresponding to this status.
+    pub fn condition(self) -> HealthCondition {
+        match self {
+            Self::Ready => HealthCondition::Ready,
+            Self::Affected => HealthCondition::Degraded,
+            Self::NotReady | Self::ShuttingDown | Self::ShutDown | Self::Panicked => {
+                HealthCondition::NotReady
+            }
+        }
+    }
+
     fn priority_for_aggregation(self) -> usize {
         match self {
             Self::Ready => 0,
@@ -57,15 +79,57 @@ impl HealthStatus {
     }
 }
 
+/// Coarse-grained health condition derived from [`HealthStatus`]. Unlike the status, the set of conditions is stable,
+/// so it's convenient to use in alerting rules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HealthCondition {
+    /// Component is fully operational.
+    Ready,
+    /// Component is operational, but is affected by some issue.
+    Degraded,
+    /// Component is not operational.
+    NotReady,
+}
+
 /// Health of a single component.
 #[derive(Debug, Clone, Serialize, PartialEq)]
 pub struct Health {
     status: HealthStatus,
+    condition: HealthCondition,
+    /// Machine-readable cause of the status, e.g. `main_node_unreachable`. Mostly useful for non-ready statuses.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cause: Option<Cow<'static, str>>,
+    /// Last time the component was reported as [ready](HealthStatus::Ready). Serialized as a Unix timestamp in seconds.
+    #[serde(
+        rename = "last_success_timestamp",
+        serialize_with = "serialize_timestamp",
+        skip_serializing_if = "Option::is_none"
+    )]
+    last_success_at: Option<SystemTime>,
+    /// Last time the component health was updated. Serialized as a Unix timestamp in seconds.
+    #[serde(
+        rename = "last_updated_timestamp",
+        serialize_with = "serialize_timestamp",
+        skip_serializing_if = "Option::is_none"
+    )]
+    last_updated_at: Option<SystemTime>,
+    /// Component-specific counters, e.g. the number of failed operations.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    counters: BTreeMap<&'static str, u64>,
     /// Component-specific details allowing to assess whether the component is healthy or not.
     #[serde(skip_serializing_if = "Option::is_none")]
     details: Option<serde_json::Value>,
 }
 
+fn serialize_timestamp<S: Serializer>(
+    timestamp: &Option<SystemTime>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let timestamp = timestamp.and_then(|ts| ts.duration_since(SystemTime::UNIX_EPOCH).ok());
+    timestamp.map(|ts| ts.as_secs()).serialize(serializer)
+}
+
 impl Health {
     /// Sets health details.
     #[must_use]
@@ -75,6 +139,62 @@ impl Health {
         self
     }
 
+    /// Sets a machine-readable cause of the status. By convention, causes are `snake_case` strings.
+    #[must_use]
+    pub fn with_cause(mut self, cause: impl Into<Cow<'static, str>>) -> Self {
+        self.cause = Some(cause.into());
+        self
+    }
+
+    /// Sets the last time the component was successful. If not set explicitly, the timestamp is tracked
+    /// automatically by [`HealthUpdater`] based on the reported statuses.
+    #[must_use]
+    pub fn with_last_success(mut self, timestamp: SystemTime) -> Self {
+        self.last_success_at = Some(timestamp);
+        self
+    }
+
+    /// Sets a component-specific counter.
+    #[must_use]
+    pub fn with_counter(mut self, name: &'static str, value: u64) -> Self {
+        self.counters.insert(name, value);
+        self
+    }
+
+    /// Returns the coarse-grained health condition.
+    pub fn condition(&self) -> HealthCondition {
+        self.condition
+    }
+
+    /// Returns the cause of the status, if any.
+    pub fn cause(&self) -> Option<&str> {
+        self.cause.as_deref()
+    }
+
+    /// Returns the last time the component was successful, if known.
+    pub fn last_success_at(&self) -> Option<SystemTime> {
+        self.last_success_at
+    }
+
+    /// Returns the last time the component health was updated, if known. This timestamp is tracked
+    /// automatically by [`HealthUpdater`].
+    pub fn last_updated_at(&self) -> Option<SystemTime> {
+        self.last_updated_at
+    }
+
+    /// Returns component-specific counters.
+    pub fn counters(&self) -> &BTreeMap<&'static str, u64> {
+        &self.counters
+    }
+
+    /// Compares health information ignoring the last success and last update timestamps (which change on each update).
+    fn is_same_as(&self, other: &Self) -> bool {
+        self.status == other.status
+            && self.cause == other.cause
+            && self.counters == other.counters
+            && self.details == other.details
+    }
+
     /// Returns the overall health status.
     pub fn status(&self) -> HealthStatus {
         self.status
@@ -90,6 +210,11 @@ impl From<HealthStatus> for Health {
     fn from(status: HealthStatus) -> Self {
         Self {
             status,
+            condition: status.condition(),
+            cause: None,
+            last_success_at: None,
+            last_updated_at: None,
+            counters: BTreeMap::new(),
             details: None,
         }
     }
@@ -200,7 +325,23 @@ impl AppHealthCheck {
             .map(|health| health.status)
             .max_by_key(|status| status.priority_for_aggregation())
             .unwrap_or(HealthStatus::Ready);
-        let inner = aggregated_status.into();
+        let count_components = |condition| {
+            let count = components
+                .values()
+                .filter(|health| health.condition == condition)
+                .count();
+            count as u64
+        };
+        let inner = Health::from(aggregated_status)
+            .with_counter("ready_components", count_components(HealthCondition::Ready))
+            .with_counter(
+                "degraded_components",
+                count_components(HealthCondition::Degraded),
+            )
+            .with_counter(
+                "not_ready_components",
+                count_components(HealthCondition::NotReady),
+            );
 
         let health = AppHealth { inner, components };
         if !health.inner.status.is_healthy() {
@@ -270,7 +411,9 @@ impl AppHealthCheck {
                     "Health check `{check_name}` timed out, taking >{hard_time_limit:?} to complete; marking as not ready"
                 );
                 METRICS.observe_abnormal_check(check_name, CheckResult::TimedOut, elapsed);
-                (check_name, HealthStatus::NotReady.into())
+                let health =
+                    Health::from(HealthStatus::NotReady).with_cause("health_check_timed_out");
+                (check_name, health)
             }
         }
     }
@@ -285,10 +428,18 @@ pub struct AppHealth {
 }
 
 impl AppHealth {
+    /// Checks whether the application is ready to receive traffic, i.e., all its components are [healthy](HealthStatus::is_healthy()).
     pub fn is_healthy(&self) -> bool {
         self.inner.status.is_healthy()
     }
 
+    /// Checks whether the application is live, i.e., none of its components require the process to be restarted.
+    pub fn is_live(&self) -> bool {
+        self.components
+            .values()
+            .all(|health| health.status.is_live())
+    }
+
     /// Returns a reference to the overall health of the application.
     pub fn inner(&self) -> &Health {
         &self.inner
@@ -391,9 +542,23 @@ impl HealthUpdater {
     /// Updates the health check information, returning if a change occurred from previous state.
     /// Note, description change on Health is counted as a change, even if status is the same.
     /// I.e., `Health { Ready, None }` to `Health { Ready, Some(_) }` is considered a change.
-    pub fn update(&self, health: Health) -> bool {
+    /// A change in the last success or last update timestamp is not considered a change.
+    ///
+    /// If the last success timestamp is not set in the provided `health`, it is set to the current time
+    /// if the status is [`HealthStatus::Ready`], and is retained from the previous health otherwise.
+    /// The last update timestamp is always set to the current time.
+    pub fn update(&self, mut health: Health) -> bool {
+        health.last_updated_at = Some(SystemTime::now());
+        if health.last_success_at.is_none() {
+            health.last_success_at = if health.status == HealthStatus::Ready {
+                Some(SystemTime::now())
+            } else {
+                self.health_sender.borrow().last_success_at
+            };
+        }
+
         let old_health = self.health_sender.send_replace(health.clone());
-        if old_health != health {
+        if !old_health.is_same_as(&health) {
             tracing::debug!(
                 "Changed health of `{}` from {} to {}",
                 self.name,
@@ -0,0 +1,185 @@
+//! Built-in checks of service level objectives (SLOs) evaluated by the node itself.
+
+use std::{
+    collections::VecDeque,
+    fmt,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use serde::Serialize;
+use tokio::sync::watch;
+
+use crate::{Health, HealthStatus, HealthUpdater, ReactiveHealthCheck};
+
+/// Health cause reported by SLO checks with an exceeded threshold.
+const THRESHOLD_EXCEEDED_CAUSE: &str = "slo_threshold_exceeded";
+
+#[derive(Debug, Serialize)]
+struct SloHealthDetails {
+    /// Last measured value. `None` if there was no data to measure (e.g., no API calls were served recently).
+    value: Option<f64>,
+    threshold: f64,
+}
+
+type SloProbe = Box<dyn Fn() -> Option<f64> + Send + Sync>;
+
+struct SloCheck {
+    threshold: f64,
+    probe: SloProbe,
+    is_exceeded: bool,
+    health_updater: HealthUpdater,
+}
+
+impl fmt::Debug for SloCheck {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("SloCheck")
+            .field("threshold", &self.threshold)
+            .field("is_exceeded", &self.is_exceeded)
+            .field("health_updater", &self.health_updater)
+            .finish_non_exhaustive()
+    }
+}
+
+impl SloCheck {
+    fn evaluate(&mut self) {
+        let value = (self.probe)();
+        let is_exceeded = value.map_or(false, |value| value > self.threshold);
+        let name = self.health_updater.name;
+        if is_exceeded && !self.is_exceeded {
+            tracing::warn!(
+                "SLO `{name}` is violated: {value:?} exceeds threshold {}",
+                self.threshold
+            );
+        } else if !is_exceeded && self.is_exceeded {
+            tracing::info!("SLO `{name}` is no longer violated: {value:?}");
+        }
+        self.is_exceeded = is_exceeded;
+
+        let health = if is_exceeded {
+            Health::from(HealthStatus::Affected).with_cause(THRESHOLD_EXCEEDED_CAUSE)
+        } else {
+            Health::from(HealthStatus::Ready)
+        };
+        let details = SloHealthDetails {
+            value,
+            threshold: self.threshold,
+        };
+        self.health_updater.update(health.with_details(details));
+    }
+}
+
+/// Periodically evaluates SLO thresholds (e.g., sync lag or API latency). Each threshold is exposed as a separate
+/// health component, which is [affected](HealthStatus::Affected) (i.e., degraded) while the threshold is exceeded.
+/// This allows to get basic alerting from the health endpoint without a full-fledged Prometheus alerting pipeline.
+#[derive(Debug)]
+pub struct SloMonitor {
+    check_interval: Duration,
+    checks: Vec<SloCheck>,
+}
+
+impl SloMonitor {
+    pub fn new(check_interval: Duration) -> Self {
+        Self {
+            check_interval,
+            checks: vec![],
+        }
+    }
+
+    /// Adds a check for the value returned by `probe`, which is exceeded if the value is greater than `threshold`.
+    /// The probe should return `None` if there's no data to measure; the check is considered passing in this case.
+    ///
+    /// Returns the health check for the SLO, which should be inserted into the application health.
+    pub fn add_check(
+        &mut self,
+        name: &'static str,
+        threshold: f64,
+        probe: impl Fn() -> Option<f64> + Send + Sync + 'static,
+    ) -> ReactiveHealthCheck {
+        let (health_check, health_updater) = ReactiveHealthCheck::new(name);
+        self.checks.push(SloCheck {
+            threshold,
+            probe: Box::new(probe),
+            is_exceeded: false,
+            health_updater,
+        });
+        health_check
+    }
+
+    /// Checks whether this monitor has no checks.
+    pub fn is_empty(&self) -> bool {
+        self.checks.is_empty()
+    }
+
+    /// Runs this monitor until a stop signal is received.
+    pub async fn run(mut self, mut stop_receiver: watch::Receiver<bool>) {
+        while !*stop_receiver.borrow() {
+            for check in &mut self.checks {
+                check.evaluate();
+            }
+            // Error here corresponds to a timeout w/o `stop_receiver` changed; we're OK with this.
+            tokio::time::timeout(self.check_interval, stop_receiver.changed())
+                .await
+                .ok();
+        }
+        tracing::info!("Stop signal received, SLO monitor is shutting down");
+    }
+}
+
+/// Sliding window of recent latencies allowing to check latency percentiles in SLO probes.
+#[derive(Debug)]
+pub struct LatencyWindow {
+    window: Duration,
+    capacity: usize,
+    observations: Mutex<VecDeque<(Instant, Duration)>>,
+}
+
+impl LatencyWindow {
+    /// Creates a window retaining at most `capacity` latencies observed during the last `window`.
+    pub const fn new(window: Duration, capacity: usize) -> Self {
+        Self {
+            window,
+            capacity,
+            observations: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    /// Observes a latency.
+    pub fn observe(&self, latency: Duration) {
+        let mut observations = self
+            .observations
+            .lock()
+            .expect("latency window is poisoned");
+        if observations.len() >= self.capacity {
+            observations.pop_front();
+        }
+        observations.push_back((Instant::now(), latency));
+    }
+
+    /// Returns the specified percentile (`quantile` is from 0 to 1) of latencies observed during the window,
+    /// or `None` if there were no observations.
+    pub fn percentile(&self, quantile: f64) -> Option<Duration> {
+        let now = Instant::now();
+        let mut latencies: Vec<_> = {
+            let mut observations = self
+                .observations
+                .lock()
+                .expect("latency window is poisoned");
+            while let Some(&(observed_at, _)) = observations.front() {
+                if now.duration_since(observed_at) <= self.window {
+                    break;
+                }
+                observations.pop_front();
+            }
+            observations.iter().map(|&(_, latency)| latency).collect()
+        };
+        if latencies.is_empty() {
+            return None;
+        }
+
+        latencies.sort_unstable();
+        let idx = ((latencies.len() - 1) as f64 * quantile.clamp(0.0, 1.0)).round() as usize;
+        Some(latencies[idx])
+    }
+}
@@ -77,6 +77,46 @@ async fn updating_health_status_return_value() {
     assert!(updated);
 }
 
+#[tokio::test]
+async fn tracking_last_success_timestamp() {
+    let (health_check, health_updater) = ReactiveHealthCheck::new("test");
+    assert_eq!(health_check.check_health().await.last_success_at(), None);
+
+    health_updater.update(HealthStatus::Ready.into());
+    let health = health_check.check_health().await;
+    assert_eq!(health.condition(), HealthCondition::Ready);
+    let last_success_at = health.last_success_at().unwrap();
+
+    // Timestamp changes should not be considered health changes.
+    let updated = health_updater.update(HealthStatus::Ready.into());
+    assert!(!updated);
+
+    let health = Health::from(HealthStatus::Affected)
+        .with_cause("test_failure")
+        .with_counter("failures", 1);
+    let updated = health_updater.update(health);
+    assert!(updated);
+    let health = health_check.check_health().await;
+    assert_eq!(health.condition(), HealthCondition::Degraded);
+    assert_eq!(health.cause(), Some("test_failure"));
+    assert_eq!(health.counters()["failures"], 1);
+    assert!(health.last_success_at().unwrap() >= last_success_at);
+
+    let json = serde_json::to_value(&health).unwrap();
+    assert_eq!(json["status"], "affected");
+    assert_eq!(json["condition"], "degraded");
+    assert_eq!(json["cause"], "test_failure");
+    assert_eq!(json["counters"], serde_json::json!({ "failures": 1 }));
+    assert!(json["last_success_timestamp"].is_u64());
+    assert!(json["last_updated_timestamp"].is_u64());
+    assert!(health.last_updated_at().unwrap() >= health.last_success_at().unwrap());
+
+    drop(health_updater);
+    let health = health_check.check_health().await;
+    assert_eq!(health.condition(), HealthCondition::NotReady);
+    assert!(health.last_success_at().is_some());
+}
+
 #[tokio::test]
 async fn aggregating_health_checks() {
     let (first_check, first_updater) = ReactiveHealthCheck::new("first");
@@ -114,6 +154,10 @@ async fn aggregating_health_checks() {
     let app_health = checks.check_health().await;
     assert!(app_health.is_healthy());
     assert_matches!(app_health.inner.status(), HealthStatus::Affected);
+    assert_eq!(app_health.inner.condition(), HealthCondition::Degraded);
+    assert_eq!(app_health.inner.counters()["ready_components"], 1);
+    assert_eq!(app_health.inner.counters()["degraded_components"], 1);
+    assert_eq!(app_health.inner.counters()["not_ready_components"], 0);
     assert_matches!(app_health.components["first"].status, HealthStatus::Ready);
     assert_matches!(
         app_health.components["second"].status,
@@ -135,6 +179,37 @@ async fn aggregating_health_checks() {
     );
 }
 
+#[tokio::test]
+async fn readiness_and_liveness() {
+    let (first_check, first_updater) = ReactiveHealthCheck::new("first");
+    let (second_check, second_updater) = ReactiveHealthCheck::new("second");
+    let checks = AppHealthCheck {
+        components: Mutex::new(vec![Arc::new(first_check), Arc::new(second_check)]),
+        ..AppHealthCheck::default()
+    };
+
+    // Initializing components should not affect liveness.
+    let app_health = checks.check_health().await;
+    assert!(!app_health.is_healthy());
+    assert!(app_health.is_live());
+
+    first_updater.update(HealthStatus::Ready.into());
+    second_updater.update(HealthStatus::Ready.into());
+    let app_health = checks.check_health().await;
+    assert!(app_health.is_healthy());
+    assert!(app_health.is_live());
+    assert!(app_health.components["first"].last_updated_at().is_some());
+
+    let task = tokio::spawn(async move {
+        let _first_updater = first_updater;
+        panic!("oops");
+    });
+    assert!(task.await.unwrap_err().is_panic());
+    let app_health = checks.check_health().await;
+    assert!(!app_health.is_healthy());
+    assert!(!app_health.is_live());
+}
+
 #[test]
 fn adding_duplicate_component() {
     let checks = AppHealthCheck::default();
@@ -148,3 +223,61 @@ fn adding_duplicate_component() {
         .unwrap_err();
     assert_matches!(err, AppHealthCheckError::RedefinedComponent("test"));
 }
+
+#[tokio::test]
+async fn slo_monitor_basics() {
+    let value = Arc::new(std::sync::atomic::AtomicU64::new(5));
+    let mut monitor = SloMonitor::new(Duration::from_millis(10));
+    let probe_value = value.clone();
+    let mut health_check = monitor.add_check("slo_test", 10.0, move || {
+        Some(probe_value.load(std::sync::atomic::Ordering::Relaxed) as f64)
+    });
+    assert!(!monitor.is_empty());
+
+    let (stop_sender, stop_receiver) = watch::channel(false);
+    let monitor_task = tokio::spawn(monitor.run(stop_receiver));
+    let health = health_check
+        .wait_for(|health| health.status() == HealthStatus::Ready)
+        .await;
+    assert_eq!(health.details().unwrap()["value"], 5.0);
+
+    value.store(20, std::sync::atomic::Ordering::Relaxed);
+    let health = health_check
+        .wait_for(|health| health.status() == HealthStatus::Affected)
+        .await;
+    assert_eq!(health.condition(), HealthCondition::Degraded);
+    assert_eq!(health.cause(), Some("slo_threshold_exceeded"));
+    assert_eq!(health.details().unwrap()["threshold"], 10.0);
+
+    value.store(10, std::sync::atomic::Ordering::Relaxed);
+    health_check
+        .wait_for(|health| health.status() == HealthStatus::Ready)
+        .await;
+
+    stop_sender.send_replace(true);
+    monitor_task.await.unwrap();
+    assert_matches!(
+        health_check.check_health().await.status(),
+        HealthStatus::ShutDown
+    );
+}
+
+#[test]
+fn latency_window_percentiles() {
+    let window = LatencyWindow::new(Duration::from_secs(60), 100);
+    assert_eq!(window.percentile(0.99), None);
+
+    for ms in 1..=200 {
+        window.observe(Duration::from_millis(ms));
+    }
+    // Only the last 100 observations are retained.
+    assert_eq!(window.percentile(0.0), Some(Duration::from_millis(101)));
+    assert_eq!(window.percentile(0.5), Some(Duration::from_millis(151)));
+    assert_eq!(window.percentile(0.99), Some(Duration::from_millis(199)));
+    assert_eq!(window.percentile(1.0), Some(Duration::from_millis(200)));
+
+    let window = LatencyWindow::new(Duration::ZERO, 100);
+    window.observe(Duration::from_millis(1));
+    std::thread::sleep(Duration::from_millis(1));
+    assert_eq!(window.percentile(0.99), None);
+}
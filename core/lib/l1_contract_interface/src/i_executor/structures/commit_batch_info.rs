@@ -17,6 +17,7 @@ use crate::{
 /// These are used by the L1 Contracts to indicate what DA layer is used for pubdata
 const PUBDATA_SOURCE_CALLDATA: u8 = 0;
 const PUBDATA_SOURCE_BLOBS: u8 = 1;
+const PUBDATA_SOURCE_CUSTOM: u8 = 2;
 
 /// Encoding for `CommitBatchInfo` from `IExecutor.sol` for a contract running in rollup mode.
 #[derive(Debug)]
@@ -207,6 +208,22 @@ impl Tokenizable for CommitBatchInfo<'_> {
                 (L1BatchCommitmentMode::Validium, PubdataDA::Blobs) => {
                     vec![PUBDATA_SOURCE_BLOBS]
                 }
+                // The pubdata is stored on the external DA layer; the contracts only receive the data
+                // proving its inclusion.
+                (L1BatchCommitmentMode::Validium, PubdataDA::Custom) => {
+                    let inclusion_data = self
+                        .l1_batch_with_metadata
+                        .metadata
+                        .da_inclusion_data
+                        .as_deref()
+                        .unwrap_or_default();
+                    std::iter::once(PUBDATA_SOURCE_CUSTOM)
+                        .chain(inclusion_data.iter().copied())
+                        .collect()
+                }
+                (L1BatchCommitmentMode::Rollup, PubdataDA::Custom) => {
+                    panic!("Custom pubdata DA is incompatible with Rollup mode")
+                }
 
                 (L1BatchCommitmentMode::Rollup, PubdataDA::Calldata) => {
                     // We compute and add the blob commitment to the pubdata payload so that we can verify the proof
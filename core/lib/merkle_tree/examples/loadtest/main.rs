@@ -11,6 +11,7 @@ use std::{
 use anyhow::Context as _;
 use clap::Parser;
 use rand::{rngs::StdRng, seq::IteratorRandom, SeedableRng};
+use rayon::ThreadPoolBuilder;
 use tempfile::TempDir;
 use tracing_subscriber::EnvFilter;
 use zksync_crypto::hasher::blake2::Blake2Hasher;
@@ -80,6 +81,10 @@ struct Cli {
     /// Enables tree pruning.
     #[arg(long = "prune", conflicts_with = "in_memory")]
     prune: bool,
+    /// Number of threads in the `rayon` thread pool used for hashing. If not specified, the global `rayon` pool
+    /// is used. 0 means the default number of threads (usually, the number of logical CPUs).
+    #[arg(long = "threads")]
+    thread_count: Option<usize>,
 }
 
 impl Cli {
@@ -94,6 +99,23 @@ impl Cli {
         Self::init_logging();
         tracing::info!("Launched with options: {self:?}");
 
+        if let Some(thread_count) = self.thread_count {
+            let thread_pool = ThreadPoolBuilder::new()
+                .thread_name(|idx| format!("merkle-tree-{idx}"))
+                .num_threads(thread_count)
+                .build()
+                .context("failed initializing `rayon` thread pool")?;
+            tracing::info!(
+                "Using dedicated thread pool with {} threads",
+                thread_pool.current_num_threads()
+            );
+            thread_pool.install(|| self.run_inner())
+        } else {
+            self.run_inner()
+        }
+    }
+
+    fn run_inner(self) -> anyhow::Result<()> {
         let (mut mock_db, mut rocksdb);
         let mut _temp_dir = None;
         let mut pruner_handles = None;
@@ -141,6 +163,7 @@ impl Cli {
         let mut tree = MerkleTree::with_hasher(db, hasher).context("cannot create tree")?;
         let mut next_key_idx = 0_u64;
         let mut next_value_idx = 0_u64;
+        let mut total_elapsed = Duration::ZERO;
         for version in 0..self.commit_count {
             let new_keys: Vec<_> = Self::generate_keys(next_key_idx..)
                 .take(self.writes_per_commit)
@@ -191,9 +214,18 @@ impl Cli {
             }
 
             let elapsed = start.elapsed();
+            total_elapsed += elapsed;
             tracing::info!("Processed block #{version} in {elapsed:?}, root hash = {root_hash:?}");
         }
 
+        let total_ops = self.commit_count as f64
+            * (self.writes_per_commit + self.updates_per_commit + self.reads_per_commit) as f64;
+        tracing::info!(
+            "Processed {} blocks in {total_elapsed:?} ({:.0} ops/s)",
+            self.commit_count,
+            total_ops / total_elapsed.as_secs_f64()
+        );
+
         tracing::info!("Verifying tree consistency...");
         let start = Instant::now();
         tree.verify_consistency(self.commit_count - 1, false)
@@ -9,8 +9,8 @@ use crate::{
     consistency::ConsistencyError,
     storage::{PatchSet, Patched, RocksDBWrapper},
     types::{
-        Key, Root, TreeEntry, TreeEntryWithProof, TreeInstruction, TreeLogEntry, ValueHash,
-        TREE_DEPTH,
+        Key, NodeKey, RawNode, Root, TreeEntry, TreeEntryWithProof, TreeInstruction, TreeLogEntry,
+        ValueHash, TREE_DEPTH,
     },
     BlockOutput, HashTree, MerkleTree, MerkleTreePruner, MerkleTreePrunerHandle, NoVersionError,
 };
@@ -459,4 +459,8 @@ impl ZkSyncTreeReader {
         let version = l1_batch_number.0.into();
         self.0.verify_consistency(version, true)
     }
+    /// Returns raw nodes for the specified `keys`. Intended for debugging purposes only.
+    pub fn raw_nodes(&self, keys: &[NodeKey]) -> Vec<Option<RawNode>> {
+        self.0.db.raw_nodes(keys)
+    }
 }
@@ -82,7 +82,10 @@ mod utils;
 pub mod unstable {
     pub use crate::{
         errors::DeserializeError,
-        types::{Manifest, Node, NodeKey, ProfiledTreeOperation, Root},
+        types::{
+            ChildRef, InternalNode, LeafNode, Manifest, Node, NodeKey, ProfiledTreeOperation,
+            RawNode, Root,
+        },
     };
 }
 
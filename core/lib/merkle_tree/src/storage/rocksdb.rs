@@ -20,8 +20,8 @@ use crate::{
         Database, NodeKeys, PatchSet,
     },
     types::{
-        InternalNode, LeafNode, Manifest, Nibbles, Node, NodeKey, ProfiledTreeOperation, Root,
-        StaleNodeKey,
+        InternalNode, LeafNode, Manifest, Nibbles, Node, NodeKey, ProfiledTreeOperation, RawNode,
+        Root, StaleNodeKey,
     },
 };
 
@@ -112,7 +112,7 @@ impl RocksDBWrapper {
             .expect("Failed reading from RocksDB")
     }
 
-    fn raw_nodes(&self, keys: &NodeKeys) -> Vec<Option<DBPinnableSlice<'_>>> {
+    fn multi_get_nodes(&self, keys: &NodeKeys) -> Vec<Option<DBPinnableSlice<'_>>> {
         // Propagate the currently profiled operation to rayon threads used in the parallel iterator below.
         let profiled_operation = self
             .profiled_operation
@@ -136,6 +136,42 @@ impl RocksDBWrapper {
             .collect()
     }
 
+    /// Returns raw nodes for the specified `keys`. Nodes are deserialized as both leaves and internal nodes
+    /// since the storage doesn't record the node kind. Keys with empty nibbles correspond to tree roots;
+    /// the root node is extracted from the root data in this case.
+    pub fn raw_nodes(&self, keys: &[NodeKey]) -> Vec<Option<RawNode>> {
+        let keys: Vec<_> = keys.iter().map(|key| (*key, false)).collect();
+        let raw_nodes = self.multi_get_nodes(&keys).into_iter().zip(&keys);
+        raw_nodes
+            .map(|(maybe_node, (key, _))| {
+                let raw = maybe_node?.to_vec();
+                let (leaf, internal) = if key.is_empty() {
+                    match Root::deserialize(&raw) {
+                        Ok(Root::Filled {
+                            node: Node::Leaf(leaf),
+                            ..
+                        }) => (Some(leaf), None),
+                        Ok(Root::Filled {
+                            node: Node::Internal(node),
+                            ..
+                        }) => (None, Some(node)),
+                        Ok(Root::Empty) | Err(_) => (None, None),
+                    }
+                } else {
+                    (
+                        LeafNode::deserialize(&raw).ok(),
+                        InternalNode::deserialize(&raw).ok(),
+                    )
+                };
+                Some(RawNode {
+                    raw,
+                    leaf,
+                    internal,
+                })
+            })
+            .collect()
+    }
+
     fn deserialize_node(
         raw_node: &[u8],
         key: &NodeKey,
@@ -204,7 +240,7 @@ impl Database for RocksDBWrapper {
     }
 
     fn tree_nodes(&self, keys: &NodeKeys) -> Vec<Option<Node>> {
-        let raw_nodes = self.raw_nodes(keys).into_iter().zip(keys);
+        let raw_nodes = self.multi_get_nodes(keys).into_iter().zip(keys);
 
         let nodes = raw_nodes.map(|(maybe_node, (key, is_leaf))| {
             maybe_node
@@ -389,6 +425,31 @@ mod tests {
         assert_contains_exactly_keys(&db, &expected_keys);
     }
 
+    #[test]
+    fn reading_raw_nodes() {
+        let dir = TempDir::new().expect("failed creating temporary dir for RocksDB");
+        let mut db = RocksDBWrapper::new(dir.path()).unwrap();
+        let root = Root::new(2, Node::Internal(InternalNode::default()));
+        let nodes = generate_nodes(0, &[1, 2]);
+        let node_keys: Vec<_> = nodes.keys().copied().collect();
+        db.apply_patch(create_patch(0, root, nodes)).unwrap();
+
+        let mut keys = vec![NodeKey::empty(0), NodeKey::empty(1)];
+        keys.extend_from_slice(&node_keys);
+        let raw_nodes = db.raw_nodes(&keys);
+        assert_eq!(raw_nodes.len(), keys.len());
+
+        let raw_root = raw_nodes[0].as_ref().unwrap();
+        assert!(raw_root.leaf.is_none());
+        assert_eq!(raw_root.internal.as_ref().unwrap().child_count(), 0);
+        assert!(raw_nodes[1].is_none());
+        for raw_node in &raw_nodes[2..] {
+            let raw_node = raw_node.as_ref().unwrap();
+            assert!(!raw_node.raw.is_empty());
+            assert!(raw_node.leaf.is_some());
+        }
+    }
+
     fn assert_contains_exactly_keys(db: &RocksDBWrapper, expected_keys: &HashSet<NodeKey>) {
         let cf = MerkleTreeColumnFamily::Tree;
         let actual_keys: HashSet<_> = db
@@ -2,7 +2,9 @@
 //! some of these types are declared as public and can be even exported using the `unstable` module.
 //! Still, logically these types are private, so adding them to new public APIs etc. is a logical error.
 
-use std::{collections::HashMap, fmt, num::NonZeroU64};
+use std::{collections::HashMap, fmt, num::NonZeroU64, str::FromStr};
+
+use anyhow::Context as _;
 
 use crate::{
     hasher::{HashTree, InternalNodeCache},
@@ -337,13 +339,47 @@ impl fmt::Display for NodeKey {
     }
 }
 
+/// Parses a key in the format produced by the [`Display`](fmt::Display) implementation,
+/// i.e. `{version}:{nibbles}` where `nibbles` is a (possibly empty) hex string.
+impl FromStr for NodeKey {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (version, nibbles) = s
+            .split_once(':')
+            .context("node key must have `{version}:{nibbles}` format")?;
+        let version = version.parse().context("invalid key version")?;
+        anyhow::ensure!(
+            nibbles.len() <= 2 * KEY_SIZE,
+            "too many nibbles in key: {}, max {}",
+            nibbles.len(),
+            2 * KEY_SIZE
+        );
+
+        let mut parsed_nibbles = Nibbles::EMPTY;
+        for ch in nibbles.chars() {
+            let nibble = ch
+                .to_digit(16)
+                .with_context(|| format!("invalid nibble: {ch:?}"))?;
+            #[allow(clippy::cast_possible_truncation)] // `nibble < 16`
+            let nibble = nibble as u8;
+            parsed_nibbles = parsed_nibbles.push(nibble).unwrap();
+            // ^ `unwrap()` is safe: the number of nibbles was checked above
+        }
+        Ok(parsed_nibbles.with_version(version))
+    }
+}
+
 /// Leaf node of the tree.
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(test, derive(PartialEq, Eq))]
 pub struct LeafNode {
-    pub(crate) full_key: Key,
-    pub(crate) value_hash: ValueHash,
-    pub(crate) leaf_index: u64,
+    /// Full key of the leaf.
+    pub full_key: Key,
+    /// Hash of the value stored in the leaf.
+    pub value_hash: ValueHash,
+    /// 1-based index of the leaf (i.e., its order of insertion into the tree).
+    pub leaf_index: u64,
 }
 
 impl LeafNode {
@@ -364,15 +400,18 @@ impl LeafNode {
 /// Reference to a child in an [`InternalNode`].
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(test, derive(PartialEq, Eq))]
-pub(crate) struct ChildRef {
+pub struct ChildRef {
+    /// Hash of the child node.
     pub hash: ValueHash,
+    /// Version of the tree at which the child was last updated.
     pub version: u64,
+    /// Whether the child is a leaf.
     pub is_leaf: bool,
 }
 
 impl ChildRef {
     /// Creates a reference to a child with `value_hash` left blank (it will be computed later).
-    pub fn leaf(version: u64) -> Self {
+    pub(crate) fn leaf(version: u64) -> Self {
         Self {
             hash: ValueHash::default(),
             version,
@@ -380,7 +419,7 @@ impl ChildRef {
         }
     }
 
-    pub fn internal(version: u64) -> Self {
+    pub(crate) fn internal(version: u64) -> Self {
         Self {
             hash: ValueHash::default(),
             version,
@@ -449,7 +488,8 @@ impl InternalNode {
         self.cache.get_or_insert(cache)
     }
 
-    pub(crate) fn children(&self) -> impl Iterator<Item = (u8, &ChildRef)> + '_ {
+    /// Iterates over the children of this node together with their nibbles.
+    pub fn children(&self) -> impl Iterator<Item = (u8, &ChildRef)> + '_ {
         self.children.iter()
     }
 
@@ -510,6 +550,19 @@ impl From<InternalNode> for Node {
     }
 }
 
+/// Raw node fetched from a database. Since the storage format doesn't record whether a node is a leaf,
+/// the node is deserialized as both a leaf and an internal node; either (or both) of these may fail.
+/// Intended for debugging purposes only.
+#[derive(Debug)]
+pub struct RawNode {
+    /// Bytes of the serialized node.
+    pub raw: Vec<u8>,
+    /// Leaf if the node can be deserialized into it.
+    pub leaf: Option<LeafNode>,
+    /// Internal node if the node can be deserialized into it.
+    pub internal: Option<InternalNode>,
+}
+
 /// Root node of the tree. Besides a [`Node`], contains the general information about the tree
 /// (e.g., the number of leaves).
 #[derive(Debug, Clone)]
@@ -704,6 +757,23 @@ mod tests {
         assert_eq!(key_copy, empty_key);
     }
 
+    #[test]
+    fn parsing_node_key() {
+        for nibble_count in [0, 1, 6, 7, 64] {
+            let node_key = Nibbles::new(&TEST_KEY, nibble_count).with_version(3);
+            let parsed: NodeKey = node_key.to_string().parse().unwrap();
+            assert_eq!(parsed, node_key);
+        }
+
+        let err = "3".parse::<NodeKey>().unwrap_err().to_string();
+        assert!(err.contains("format"), "{err}");
+        let err = "3:deadbeeg".parse::<NodeKey>().unwrap_err().to_string();
+        assert!(err.contains("invalid nibble"), "{err}");
+        let long_key = format!("3:{}", "0".repeat(65));
+        let err = long_key.parse::<NodeKey>().unwrap_err().to_string();
+        assert!(err.contains("too many nibbles"), "{err}");
+    }
+
     #[test]
     fn nibbles_created_from_different_sources_can_be_equal() {
         let nibbles = Nibbles::new(&TEST_KEY, 1);
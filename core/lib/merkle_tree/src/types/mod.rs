@@ -2,11 +2,11 @@
 
 use zksync_types::{H256, U256};
 
-pub(crate) use self::internal::{
-    ChildRef, Nibbles, NibblesBytes, StaleNodeKey, TreeTags, HASH_SIZE, KEY_SIZE, TREE_DEPTH,
-};
 pub use self::internal::{
-    InternalNode, LeafNode, Manifest, Node, NodeKey, ProfiledTreeOperation, Root,
+    ChildRef, InternalNode, LeafNode, Manifest, Node, NodeKey, ProfiledTreeOperation, RawNode, Root,
+};
+pub(crate) use self::internal::{
+    Nibbles, NibblesBytes, StaleNodeKey, TreeTags, HASH_SIZE, KEY_SIZE, TREE_DEPTH,
 };
 
 mod internal;
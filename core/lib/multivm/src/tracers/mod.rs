@@ -1,11 +1,13 @@
 pub mod call_tracer;
 mod multivm_dispatcher;
 pub mod old_tracers;
+pub mod opcode_tracer;
 pub mod prestate_tracer;
 pub mod storage_invocation;
 pub mod validator;
 
 pub use call_tracer::CallTracer;
 pub use multivm_dispatcher::TracerDispatcher;
+pub use opcode_tracer::OpcodeTracer;
 pub use prestate_tracer::PrestateTracer;
 pub use storage_invocation::StorageInvocations;
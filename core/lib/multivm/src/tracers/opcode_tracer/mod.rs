@@ -0,0 +1,68 @@
+use std::{fmt, sync::Arc};
+
+use once_cell::sync::OnceCell;
+use zksync_system_constants::BOOTLOADER_ADDRESS;
+use zksync_types::{
+    vm_trace::{OpcodeStep, OpcodeTrace},
+    Address,
+};
+
+use crate::glue::tracers::IntoOldVmTracer;
+
+pub mod vm_1_4_1;
+pub mod vm_1_4_2;
+pub mod vm_boojum_integration;
+pub mod vm_latest;
+pub mod vm_refunds_enhancement;
+pub mod vm_virtual_blocks;
+
+/// Tracer recording every opcode executed by the VM outside the bootloader, up to the specified number of steps.
+#[derive(Debug, Clone)]
+pub struct OpcodeTracer {
+    trace: OpcodeTrace,
+    max_steps: usize,
+    result: Arc<OnceCell<OpcodeTrace>>,
+}
+
+impl OpcodeTracer {
+    pub fn new(max_steps: usize, result: Arc<OnceCell<OpcodeTrace>>) -> Self {
+        Self {
+            trace: OpcodeTrace::default(),
+            max_steps,
+            result,
+        }
+    }
+
+    fn record_step(
+        &mut self,
+        pc: u16,
+        opcode: &dyn fmt::Debug,
+        gas: u32,
+        depth: usize,
+        contract_address: Address,
+    ) {
+        if contract_address == BOOTLOADER_ADDRESS || self.trace.truncated {
+            return;
+        }
+        if self.trace.steps.len() >= self.max_steps {
+            self.trace.truncated = true;
+            return;
+        }
+        self.trace.steps.push(OpcodeStep {
+            pc,
+            op: format!("{opcode:?}"),
+            gas: gas.into(),
+            depth,
+            contract_address,
+        });
+    }
+
+    fn store_result(&mut self) {
+        let trace = std::mem::take(&mut self.trace);
+        // The result may be already set if the VM is executed multiple times with the same tracer.
+        self.result.set(trace).ok();
+    }
+}
+
+// Old VMs don't support custom tracers, so opcodes are not recorded for them.
+impl IntoOldVmTracer for OpcodeTracer {}
@@ -10,6 +10,7 @@ use crate::{
     mirror::MirroringObjectStore,
     raw::{ObjectStore, ObjectStoreError},
     retries::StoreWithRetries,
+    s3::{S3Store, S3StoreAuthMode},
 };
 
 /// Factory of [`ObjectStore`]s that caches the store instance once it's created. Used mainly for legacy reasons.
@@ -92,6 +93,27 @@ impl ObjectStoreFactory {
                 .await?;
                 Self::wrap_mirroring(store, config.local_mirror_path.as_ref()).await
             }
+            ObjectStoreMode::S3 {
+                bucket_base_url,
+                s3_region,
+                s3_endpoint,
+                s3_credential_file_path,
+            } => {
+                let auth_mode = match s3_credential_file_path {
+                    Some(path) => S3StoreAuthMode::CredentialFile(path.clone()),
+                    None => S3StoreAuthMode::Environment,
+                };
+                let store = StoreWithRetries::try_new(config.max_retries, || {
+                    S3Store::new(
+                        auth_mode.clone(),
+                        bucket_base_url.clone(),
+                        s3_region.clone(),
+                        s3_endpoint.clone(),
+                    )
+                })
+                .await?;
+                Self::wrap_mirroring(store, config.local_mirror_path.as_ref()).await
+            }
 
             ObjectStoreMode::FileBacked {
                 file_backed_base_path,
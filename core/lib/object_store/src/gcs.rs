@@ -96,7 +96,7 @@ impl From<AuthError> for ObjectStoreError {
     }
 }
 
-fn has_transient_io_source(mut err: &(dyn StdError + 'static)) -> bool {
+pub(crate) fn has_transient_io_source(mut err: &(dyn StdError + 'static)) -> bool {
     loop {
         if err.is::<io::Error>() {
             // We treat any I/O errors as transient. This isn't always true, but frequently occurring I/O errors
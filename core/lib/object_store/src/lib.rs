@@ -3,6 +3,7 @@
 //!
 //! - [File-backed store](FileBackedObjectStore) saving blobs as separate files in the local filesystem
 //! - [GCS-based store](GoogleCloudStore)
+//! - [S3-based store](S3Store) that works with AWS S3 and S3-compatible storages (e.g., MinIO)
 //! - [Mock in-memory store](MockObjectStore)
 //!
 //! Normally, these implementations are not used directly. Instead, a store trait object (`Arc<dyn ObjectStore>`)
@@ -32,6 +33,7 @@ mod mock;
 mod objects;
 mod raw;
 mod retries;
+mod s3;
 
 // Re-export `bincode` crate so that client binaries can conveniently use it.
 pub use bincode;
@@ -48,4 +50,5 @@ pub use self::{
     mock::MockObjectStore,
     objects::StoredObject,
     raw::{Bucket, ObjectStore, ObjectStoreError},
+    s3::{S3Store, S3StoreAuthMode},
 };
@@ -0,0 +1,105 @@
+//! Safeguard against unbounded label cardinality.
+
+use std::{
+    collections::BTreeSet,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        RwLock,
+    },
+};
+
+use vise::{Counter, Global, LabeledFamily, Metrics};
+
+/// Label value used for all values exceeding the cardinality limit.
+pub const OVERFLOW_LABEL_VALUE: &str = "other";
+
+#[derive(Debug, Metrics)]
+#[metrics(prefix = "metrics_cardinality")]
+struct CardinalityMetrics {
+    /// Number of observations with a label value aggregated into the overflow bucket.
+    #[metrics(labels = ["family"])]
+    overflows: LabeledFamily<&'static str, Counter>,
+}
+
+#[vise::register]
+static METRICS: Global<CardinalityMetrics> = Global::new();
+
+/// Caps the number of distinct values of a metric label (e.g., method names or peer IDs) that originate
+/// from an unbounded source. The first `max_values` distinct values are passed through as is; all other
+/// values are replaced with [`OVERFLOW_LABEL_VALUE`].
+///
+/// The guard is intended to be used as a static next to the guarded metric family:
+///
+/// ```
+/// # use prometheus_exporter::LabelCardinalityGuard;
+/// static METHOD_GUARD: LabelCardinalityGuard = LabelCardinalityGuard::new("rpc_method", 100);
+///
+/// let method = METHOD_GUARD.guard("eth_call");
+/// assert_eq!(method, "eth_call");
+/// ```
+#[derive(Debug)]
+pub struct LabelCardinalityGuard {
+    family: &'static str,
+    max_values: usize,
+    values: RwLock<BTreeSet<String>>,
+    overflowed: AtomicBool,
+}
+
+impl LabelCardinalityGuard {
+    /// Creates a guard for the specified metric family (used in logs and the overflow metric).
+    pub const fn new(family: &'static str, max_values: usize) -> Self {
+        Self {
+            family,
+            max_values,
+            values: RwLock::new(BTreeSet::new()),
+            overflowed: AtomicBool::new(false),
+        }
+    }
+
+    /// Returns the label value to use for the provided `value`.
+    pub fn guard<'a>(&self, value: &'a str) -> &'a str {
+        let values = self.values.read().expect("cardinality guard is poisoned");
+        if values.contains(value) {
+            return value;
+        }
+        drop(values);
+
+        let mut values = self.values.write().expect("cardinality guard is poisoned");
+        if values.contains(value) {
+            return value;
+        }
+        if values.len() < self.max_values {
+            values.insert(value.to_owned());
+            return value;
+        }
+        drop(values);
+
+        METRICS.overflows[&self.family].inc();
+        if !self.overflowed.swap(true, Ordering::Relaxed) {
+            tracing::warn!(
+                "Metric family `{}` exceeded the limit of {} distinct label values; further values \
+                 (e.g., `{value}`) are aggregated into `{OVERFLOW_LABEL_VALUE}`",
+                self.family,
+                self.max_values
+            );
+        }
+        OVERFLOW_LABEL_VALUE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn guard_aggregates_overflowing_values() {
+        let guard = LabelCardinalityGuard::new("test", 2);
+        assert_eq!(guard.guard("a"), "a");
+        assert_eq!(guard.guard("b"), "b");
+        assert_eq!(guard.guard("a"), "a");
+        assert_eq!(guard.guard("c"), OVERFLOW_LABEL_VALUE);
+        assert_eq!(guard.guard("d"), OVERFLOW_LABEL_VALUE);
+        assert_eq!(guard.guard("b"), "b");
+        assert_eq!(METRICS.overflows[&"test"].get(), 2);
+    }
+}
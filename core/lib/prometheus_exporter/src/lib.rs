@@ -1,11 +1,16 @@
 use std::{net::Ipv4Addr, time::Duration};
 
 use anyhow::Context as _;
+use base64::Engine as _;
 use metrics_exporter_prometheus::{Matcher, PrometheusBuilder};
 use tokio::sync::watch;
 use vise::MetricsCollection;
 use vise_exporter::MetricsExporter;
 
+pub use crate::cardinality::{LabelCardinalityGuard, OVERFLOW_LABEL_VALUE};
+
+mod cardinality;
+
 fn configure_legacy_exporter(builder: PrometheusBuilder) -> PrometheusBuilder {
     // in seconds
     let default_latency_buckets = [0.001, 0.005, 0.025, 0.1, 0.25, 1.0, 5.0, 30.0, 120.0];
@@ -28,6 +33,37 @@ fn configure_legacy_exporter(builder: PrometheusBuilder) -> PrometheusBuilder {
         .unwrap()
 }
 
+/// Builds a Prometheus push gateway endpoint for the specified grouping key, i.e. the `job` name and additional labels
+/// (e.g., `instance`), which are attached to all pushed metrics.
+///
+/// Label values that cannot be represented in a URL path segment as is are base64-encoded, as supported
+/// by the push gateway.
+pub fn push_gateway_endpoint<'a>(
+    gateway_url: &str,
+    job: &str,
+    labels: impl IntoIterator<Item = (&'a str, &'a str)>,
+) -> String {
+    fn path_segments(name: &str, value: &str) -> String {
+        if value.is_empty() {
+            // Empty values are represented by a single padding char.
+            format!("{name}@base64/=")
+        } else if value.contains('/') {
+            let value = base64::engine::general_purpose::URL_SAFE.encode(value);
+            format!("{name}@base64/{value}")
+        } else {
+            format!("{name}/{value}")
+        }
+    }
+
+    let gateway_url = gateway_url.trim_end_matches('/');
+    let mut endpoint = format!("{gateway_url}/metrics/{}", path_segments("job", job));
+    for (name, value) in labels {
+        endpoint.push('/');
+        endpoint.push_str(&path_segments(name, value));
+    }
+    endpoint
+}
+
 #[derive(Debug)]
 enum PrometheusTransport {
     Pull {
@@ -140,3 +176,34 @@ impl PrometheusExporterConfig {
         exporter.await.context("Prometheus exporter failed")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn building_push_gateway_endpoint() {
+        let endpoint = push_gateway_endpoint("http://127.0.0.1:9091/", "zksync_server", []);
+        assert_eq!(endpoint, "http://127.0.0.1:9091/metrics/job/zksync_server");
+
+        let endpoint = push_gateway_endpoint(
+            "http://127.0.0.1:9091",
+            "external_node",
+            [("instance", "en-0"), ("namespace", "mainnet")],
+        );
+        assert_eq!(
+            endpoint,
+            "http://127.0.0.1:9091/metrics/job/external_node/instance/en-0/namespace/mainnet"
+        );
+
+        let endpoint = push_gateway_endpoint(
+            "http://127.0.0.1:9091",
+            "job",
+            [("path", "/var/tmp"), ("empty", "")],
+        );
+        assert_eq!(
+            endpoint,
+            "http://127.0.0.1:9091/metrics/job/job/path@base64/L3Zhci90bXA=/empty@base64/="
+        );
+    }
+}
@@ -83,6 +83,8 @@ impl ProtoRepr for proto::Web3JsonRpc {
             filters_disabled: self.filters_disabled.unwrap_or(false),
             filters_limit: self.filters_limit,
             subscriptions_limit: self.subscriptions_limit,
+            subscriptions_per_connection_limit: self.subscriptions_per_connection_limit,
+            subscription_backlog_size: self.subscription_backlog_size,
             pubsub_polling_interval: self.pubsub_polling_interval,
             max_nonce_ahead: *required(&self.max_nonce_ahead).context("max_nonce_ahead")?,
             gas_price_scale_factor: *required(&self.gas_price_scale_factor)
@@ -124,6 +126,7 @@ impl ProtoRepr for proto::Web3JsonRpc {
                 .transpose()
                 .context("latest_values_cache_size_mb")?,
             fee_history_limit: self.fee_history_limit,
+            max_logs_block_range: self.max_logs_block_range,
             max_batch_request_size: self
                 .max_batch_request_size
                 .map(|x| x.try_into())
@@ -154,6 +157,23 @@ impl ProtoRepr for proto::Web3JsonRpc {
                 .map(|(i, k)| parse_h160(k).context(i))
                 .collect::<Result<Vec<_>, _>>()
                 .context("account_pks")?,
+            evm_emulator_enabled: self.evm_emulator_enabled.unwrap_or(false),
+            max_pending_txs_per_initiator: self.max_pending_txs_per_initiator,
+            min_max_fee_per_gas: self.min_max_fee_per_gas,
+            denied_initiators: self
+                .denied_initiators
+                .iter()
+                .enumerate()
+                .map(|(i, k)| parse_h160(k).context(i))
+                .collect::<Result<Vec<_>, _>>()
+                .context("denied_initiators")?,
+            eth_call_cache_size: self
+                .eth_call_cache_size
+                .map(|x| x.try_into())
+                .transpose()
+                .context("eth_call_cache_size")?,
+            eth_call_cache_ttl_sec: self.eth_call_cache_ttl_sec,
+            shutdown_drain_timeout_sec: self.shutdown_drain_timeout_sec,
         })
     }
 
@@ -169,6 +189,8 @@ impl ProtoRepr for proto::Web3JsonRpc {
             mempool_cache_size: this.mempool_cache_size.map(|x| x.try_into().unwrap()),
             filters_limit: this.filters_limit,
             subscriptions_limit: this.subscriptions_limit,
+            subscriptions_per_connection_limit: this.subscriptions_per_connection_limit,
+            subscription_backlog_size: this.subscription_backlog_size,
             pubsub_polling_interval: this.pubsub_polling_interval,
             max_nonce_ahead: Some(this.max_nonce_ahead),
             gas_price_scale_factor: Some(this.gas_price_scale_factor),
@@ -197,6 +219,7 @@ impl ProtoRepr for proto::Web3JsonRpc {
                 .latest_values_cache_size_mb
                 .map(|x| x.try_into().unwrap()),
             fee_history_limit: this.fee_history_limit,
+            max_logs_block_range: this.max_logs_block_range,
             max_batch_request_size: this.max_batch_request_size.map(|x| x.try_into().unwrap()),
             max_response_body_size_mb: this
                 .max_response_body_size_mb
@@ -222,6 +245,17 @@ impl ProtoRepr for proto::Web3JsonRpc {
                 .iter()
                 .map(|k| format!("{:?}", k))
                 .collect(),
+            evm_emulator_enabled: Some(this.evm_emulator_enabled),
+            max_pending_txs_per_initiator: this.max_pending_txs_per_initiator,
+            min_max_fee_per_gas: this.min_max_fee_per_gas,
+            denied_initiators: this
+                .denied_initiators
+                .iter()
+                .map(|k| format!("{:?}", k))
+                .collect(),
+            eth_call_cache_size: this.eth_call_cache_size.map(|x| x.try_into().unwrap()),
+            eth_call_cache_ttl_sec: this.eth_call_cache_ttl_sec,
+            shutdown_drain_timeout_sec: this.shutdown_drain_timeout_sec,
         }
     }
 }
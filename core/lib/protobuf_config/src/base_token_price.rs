@@ -0,0 +1,107 @@
+use anyhow::Context as _;
+use zksync_config::configs::base_token_price::{
+    BaseTokenPriceConfig, CoingeckoSourceConfig, FixedRatioSourceConfig, UniswapV3TwapSourceConfig,
+};
+use zksync_protobuf::{required, ProtoRepr};
+
+use crate::{parse_h160, proto::base_token_price as proto};
+
+impl ProtoRepr for proto::BaseTokenPrice {
+    type Type = BaseTokenPriceConfig;
+
+    fn read(&self) -> anyhow::Result<Self::Type> {
+        Ok(Self::Type {
+            polling_interval_ms: self.polling_interval_ms,
+            ratio_cache_update_interval_ms: self.ratio_cache_update_interval_ms,
+            max_deviation_bps: self.max_deviation_bps,
+            max_staleness_secs: self.max_staleness_secs,
+            min_agreeing_sources: self.min_agreeing_sources,
+            base_token_decimals: self
+                .base_token_decimals
+                .map(u8::try_from)
+                .transpose()
+                .context("base_token_decimals")?,
+            fixed: self
+                .fixed
+                .as_ref()
+                .map(read_fixed)
+                .transpose()
+                .context("fixed")?,
+            coingecko: self
+                .coingecko
+                .as_ref()
+                .map(read_coingecko)
+                .transpose()
+                .context("coingecko")?,
+            uniswap_v3_twap: self
+                .uniswap_v3_twap
+                .as_ref()
+                .map(read_uniswap_v3_twap)
+                .transpose()
+                .context("uniswap_v3_twap")?,
+        })
+    }
+
+    fn build(this: &Self::Type) -> Self {
+        Self {
+            polling_interval_ms: this.polling_interval_ms,
+            ratio_cache_update_interval_ms: this.ratio_cache_update_interval_ms,
+            max_deviation_bps: this.max_deviation_bps,
+            max_staleness_secs: this.max_staleness_secs,
+            min_agreeing_sources: this.min_agreeing_sources,
+            base_token_decimals: this.base_token_decimals.map(Into::into),
+            fixed: this.fixed.as_ref().map(|fixed| proto::FixedRatioSource {
+                numerator: Some(fixed.numerator),
+                denominator: Some(fixed.denominator),
+            }),
+            coingecko: this
+                .coingecko
+                .as_ref()
+                .map(|coingecko| proto::CoingeckoSource {
+                    api_url: coingecko.api_url.clone(),
+                    api_key: coingecko.api_key.clone(),
+                    platform_id: coingecko.platform_id.clone(),
+                    token_address: Some(format!("{:?}", coingecko.token_address)),
+                }),
+            uniswap_v3_twap: this
+                .uniswap_v3_twap
+                .as_ref()
+                .map(|twap| proto::UniswapV3TwapSource {
+                    pool_address: Some(format!("{:?}", twap.pool_address)),
+                    base_token_is_token0: Some(twap.base_token_is_token0),
+                    window_secs: twap.window_secs,
+                }),
+        }
+    }
+}
+
+fn read_fixed(fixed: &proto::FixedRatioSource) -> anyhow::Result<FixedRatioSourceConfig> {
+    Ok(FixedRatioSourceConfig {
+        numerator: *required(&fixed.numerator).context("numerator")?,
+        denominator: *required(&fixed.denominator).context("denominator")?,
+    })
+}
+
+fn read_coingecko(coingecko: &proto::CoingeckoSource) -> anyhow::Result<CoingeckoSourceConfig> {
+    Ok(CoingeckoSourceConfig {
+        api_url: coingecko.api_url.clone(),
+        api_key: coingecko.api_key.clone(),
+        platform_id: coingecko.platform_id.clone(),
+        token_address: required(&coingecko.token_address)
+            .and_then(|x| parse_h160(x))
+            .context("token_address")?,
+    })
+}
+
+fn read_uniswap_v3_twap(
+    twap: &proto::UniswapV3TwapSource,
+) -> anyhow::Result<UniswapV3TwapSourceConfig> {
+    Ok(UniswapV3TwapSourceConfig {
+        pool_address: required(&twap.pool_address)
+            .and_then(|x| parse_h160(x))
+            .context("pool_address")?,
+        base_token_is_token0: *required(&twap.base_token_is_token0)
+            .context("base_token_is_token0")?,
+        window_secs: twap.window_secs,
+    })
+}
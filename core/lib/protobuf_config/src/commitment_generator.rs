@@ -0,0 +1,27 @@
+use std::num::NonZeroU32;
+
+use anyhow::Context as _;
+use zksync_config::configs::CommitmentGeneratorConfig;
+use zksync_protobuf::ProtoRepr;
+
+use crate::proto::commitment_generator as proto;
+
+impl ProtoRepr for proto::CommitmentGenerator {
+    type Type = CommitmentGeneratorConfig;
+
+    fn read(&self) -> anyhow::Result<Self::Type> {
+        Ok(Self::Type {
+            max_parallelism: self
+                .max_parallelism
+                .map(|count| NonZeroU32::new(count).context("cannot be 0"))
+                .transpose()
+                .context("max_parallelism")?,
+        })
+    }
+
+    fn build(this: &Self::Type) -> Self {
+        Self {
+            max_parallelism: this.max_parallelism.map(NonZeroU32::get),
+        }
+    }
+}
@@ -1,12 +1,12 @@
 use anyhow::Context as _;
 use zksync_basic_types::L2ChainId;
 use zksync_config::configs::consensus::{
-    ConsensusConfig, GenesisSpec, Host, NodePublicKey, ProtocolVersion, ValidatorPublicKey,
-    WeightedValidator,
+    ConsensusConfig, GenesisSpec, GossipRateLimitConfig, Host, NodePublicKey, ProtocolVersion,
+    ValidatorPublicKey, WeightedValidator,
 };
 use zksync_protobuf::{repr::ProtoRepr, required};
 
-use crate::{proto::consensus as proto, read_optional_repr};
+use crate::{parse_h160, proto::consensus as proto, read_optional_repr};
 
 impl ProtoRepr for proto::WeightedValidator {
     type Type = WeightedValidator;
@@ -54,6 +54,30 @@ impl ProtoRepr for proto::GenesisSpec {
     }
 }
 
+impl ProtoRepr for proto::GossipRateLimit {
+    type Type = GossipRateLimitConfig;
+    fn read(&self) -> anyhow::Result<Self::Type> {
+        Ok(Self::Type {
+            max_inbound_bytes_per_sec: *required(&self.max_inbound_bytes_per_sec)
+                .context("max_inbound_bytes_per_sec")?,
+            max_inbound_burst_bytes: *required(&self.max_inbound_burst_bytes)
+                .context("max_inbound_burst_bytes")?,
+            max_connections_per_peer: required(&self.max_connections_per_peer)
+                .and_then(|x| Ok((*x).try_into()?))
+                .context("max_connections_per_peer")?,
+            ban_duration_sec: *required(&self.ban_duration_sec).context("ban_duration_sec")?,
+        })
+    }
+    fn build(this: &Self::Type) -> Self {
+        Self {
+            max_inbound_bytes_per_sec: Some(this.max_inbound_bytes_per_sec),
+            max_inbound_burst_bytes: Some(this.max_inbound_burst_bytes),
+            max_connections_per_peer: Some(this.max_connections_per_peer.try_into().unwrap()),
+            ban_duration_sec: Some(this.ban_duration_sec),
+        }
+    }
+}
+
 impl ProtoRepr for proto::Config {
     type Type = ConsensusConfig;
     fn read(&self) -> anyhow::Result<Self::Type> {
@@ -84,6 +108,20 @@ impl ProtoRepr for proto::Config {
                 .enumerate()
                 .map(|(i, e)| read_addr(e).context(i))
                 .collect::<Result<_, _>>()?,
+            registry_address: self
+                .registry_address
+                .as_ref()
+                .map(|x| parse_h160(x))
+                .transpose()
+                .context("registry_address")?,
+            admin_addr: self
+                .admin_addr
+                .as_ref()
+                .map(|x| x.parse())
+                .transpose()
+                .context("admin_addr")?,
+            gossip_rate_limit: read_optional_repr(&self.gossip_rate_limit)
+                .context("gossip_rate_limit")?,
             genesis_spec: read_optional_repr(&self.genesis_spec).context("genesis_spec")?,
         })
     }
@@ -109,6 +147,9 @@ impl ProtoRepr for proto::Config {
                     addr: Some(x.1 .0.clone()),
                 })
                 .collect(),
+            registry_address: this.registry_address.map(|x| format!("{x:?}")),
+            admin_addr: this.admin_addr.map(|x| x.to_string()),
+            gossip_rate_limit: this.gossip_rate_limit.as_ref().map(ProtoRepr::build),
             genesis_spec: this.genesis_spec.as_ref().map(ProtoRepr::build),
         }
     }
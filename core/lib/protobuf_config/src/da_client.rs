@@ -0,0 +1,166 @@
+use anyhow::Context;
+use zksync_config::configs::da_client::{
+    avail::AvailConfig, celestia::CelestiaConfig, eigen::EigenDAConfig, mantle::MantleDAConfig,
+    DAClient, DAClientConfig,
+};
+use zksync_protobuf::{required, ProtoRepr};
+
+use crate::proto::da_client as proto;
+
+impl ProtoRepr for proto::DataAvailabilityClient {
+    type Type = DAClientConfig;
+
+    fn read(&self) -> anyhow::Result<Self::Type> {
+        let config = required(&self.config).context("config")?;
+        let client = read_client(config)?;
+        let shadow_client = self
+            .shadow_client
+            .as_ref()
+            .map(|shadow| {
+                anyhow::ensure!(
+                    shadow.shadow_client.is_none(),
+                    "shadow client cannot have its own shadow client"
+                );
+                read_client(required(&shadow.config).context("config")?)
+            })
+            .transpose()
+            .context("shadow_client")?;
+
+        Ok(DAClientConfig {
+            client,
+            shadow_client,
+        })
+    }
+
+    fn build(this: &Self::Type) -> Self {
+        Self {
+            config: Some(build_client(&this.client)),
+            shadow_client: this.shadow_client.as_ref().map(|client| {
+                Box::new(Self {
+                    config: Some(build_client(client)),
+                    shadow_client: None,
+                })
+            }),
+        }
+    }
+}
+
+fn read_client(config: &proto::data_availability_client::Config) -> anyhow::Result<DAClient> {
+    Ok(match config {
+        proto::data_availability_client::Config::Mantle(conf) => DAClient::Mantle(MantleDAConfig {
+            api_node_url: required(&conf.api_node_url)
+                .context("api_node_url")?
+                .clone(),
+            request_timeout_ms: conf
+                .request_timeout_ms
+                .unwrap_or_else(MantleDAConfig::default_request_timeout_ms),
+            wait_for_finalization: conf.wait_for_finalization.unwrap_or_default(),
+            max_blob_size_bytes: conf
+                .max_blob_size_bytes
+                .map(|x| x.try_into())
+                .transpose()
+                .context("max_blob_size_bytes")?,
+        }),
+        proto::data_availability_client::Config::Eigen(conf) => DAClient::EigenDA(EigenDAConfig {
+            disperser_rpc: required(&conf.disperser_rpc)
+                .context("disperser_rpc")?
+                .clone(),
+            request_timeout_ms: conf
+                .request_timeout_ms
+                .unwrap_or_else(EigenDAConfig::default_request_timeout_ms),
+            custom_quorum_numbers: conf.custom_quorum_numbers.clone(),
+            account_id: conf.account_id.clone(),
+            wait_for_finalization: conf.wait_for_finalization.unwrap_or_default(),
+            max_blob_size_bytes: conf
+                .max_blob_size_bytes
+                .map(|x| x.try_into())
+                .transpose()
+                .context("max_blob_size_bytes")?,
+        }),
+        proto::data_availability_client::Config::Celestia(conf) => {
+            DAClient::Celestia(CelestiaConfig {
+                api_node_url: required(&conf.api_node_url)
+                    .context("api_node_url")?
+                    .clone(),
+                auth_token: conf.auth_token.clone(),
+                namespace: required(&conf.namespace).context("namespace")?.clone(),
+                gas_price: conf.gas_price,
+                inclusion_confirmations: conf.inclusion_confirmations.unwrap_or_default(),
+                request_timeout_ms: conf
+                    .request_timeout_ms
+                    .unwrap_or_else(CelestiaConfig::default_request_timeout_ms),
+                max_blob_size_bytes: conf
+                    .max_blob_size_bytes
+                    .map(|x| x.try_into())
+                    .transpose()
+                    .context("max_blob_size_bytes")?,
+            })
+        }
+        proto::data_availability_client::Config::Avail(conf) => DAClient::Avail(AvailConfig {
+            api_node_url: required(&conf.api_node_url)
+                .context("api_node_url")?
+                .clone(),
+            bridge_api_url: required(&conf.bridge_api_url)
+                .context("bridge_api_url")?
+                .clone(),
+            app_id: *required(&conf.app_id).context("app_id")?,
+            request_timeout_ms: conf
+                .request_timeout_ms
+                .unwrap_or_else(AvailConfig::default_request_timeout_ms),
+            max_blob_size_bytes: conf
+                .max_blob_size_bytes
+                .map(|x| x.try_into())
+                .transpose()
+                .context("max_blob_size_bytes")?,
+        }),
+        proto::data_availability_client::Config::ObjectStore(conf) => {
+            DAClient::ObjectStore(conf.read().context("object_store")?)
+        }
+    })
+}
+
+fn build_client(client: &DAClient) -> proto::data_availability_client::Config {
+    match client {
+        DAClient::Mantle(config) => {
+            proto::data_availability_client::Config::Mantle(proto::MantleConfig {
+                api_node_url: Some(config.api_node_url.clone()),
+                request_timeout_ms: Some(config.request_timeout_ms),
+                wait_for_finalization: Some(config.wait_for_finalization),
+                max_blob_size_bytes: config.max_blob_size_bytes.map(|x| x as u64),
+            })
+        }
+        DAClient::EigenDA(config) => {
+            proto::data_availability_client::Config::Eigen(proto::EigenConfig {
+                disperser_rpc: Some(config.disperser_rpc.clone()),
+                request_timeout_ms: Some(config.request_timeout_ms),
+                custom_quorum_numbers: config.custom_quorum_numbers.clone(),
+                account_id: config.account_id.clone(),
+                wait_for_finalization: Some(config.wait_for_finalization),
+                max_blob_size_bytes: config.max_blob_size_bytes.map(|x| x as u64),
+            })
+        }
+        DAClient::Celestia(config) => {
+            proto::data_availability_client::Config::Celestia(proto::CelestiaConfig {
+                api_node_url: Some(config.api_node_url.clone()),
+                auth_token: config.auth_token.clone(),
+                namespace: Some(config.namespace.clone()),
+                gas_price: config.gas_price,
+                inclusion_confirmations: Some(config.inclusion_confirmations),
+                request_timeout_ms: Some(config.request_timeout_ms),
+                max_blob_size_bytes: config.max_blob_size_bytes.map(|x| x as u64),
+            })
+        }
+        DAClient::Avail(config) => {
+            proto::data_availability_client::Config::Avail(proto::AvailConfig {
+                api_node_url: Some(config.api_node_url.clone()),
+                bridge_api_url: Some(config.bridge_api_url.clone()),
+                app_id: Some(config.app_id),
+                request_timeout_ms: Some(config.request_timeout_ms),
+                max_blob_size_bytes: config.max_blob_size_bytes.map(|x| x as u64),
+            })
+        }
+        DAClient::ObjectStore(config) => {
+            proto::data_availability_client::Config::ObjectStore(ProtoRepr::build(config))
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use zksync_config::configs::{self};
+use zksync_protobuf::ProtoRepr;
+
+use crate::proto::da_dispatcher as proto;
+
+impl ProtoRepr for proto::DataAvailabilityDispatcher {
+    type Type = configs::da_dispatcher::DADispatcherConfig;
+
+    fn read(&self) -> anyhow::Result<Self::Type> {
+        Ok(configs::da_dispatcher::DADispatcherConfig {
+            polling_interval_ms: self.polling_interval_ms,
+            max_rows_to_dispatch: self.max_rows_to_dispatch,
+            max_retries: self.max_retries.map(|x| x as u16),
+            initial_retry_backoff_ms: self.initial_retry_backoff_ms,
+            max_retry_backoff_ms: self.max_retry_backoff_ms,
+            max_l1_batch_age_secs: self.max_l1_batch_age_secs,
+            cost_budget_period_secs: self.cost_budget_period_secs,
+            max_dispatched_bytes_per_period: self.max_dispatched_bytes_per_period,
+            max_fee_per_period: self.max_fee_per_period,
+        })
+    }
+
+    fn build(this: &Self::Type) -> Self {
+        Self {
+            polling_interval_ms: this.polling_interval_ms,
+            max_rows_to_dispatch: this.max_rows_to_dispatch,
+            max_retries: this.max_retries.map(Into::into),
+            initial_retry_backoff_ms: this.initial_retry_backoff_ms,
+            max_retry_backoff_ms: this.max_retry_backoff_ms,
+            max_l1_batch_age_secs: this.max_l1_batch_age_secs,
+            cost_budget_period_secs: this.cost_budget_period_secs,
+            max_dispatched_bytes_per_period: this.max_dispatched_bytes_per_period,
+            max_fee_per_period: this.max_fee_per_period,
+        }
+    }
+}
@@ -25,6 +25,24 @@ impl proto::MerkleTreeMode {
     }
 }
 
+impl proto::MigrationsMode {
+    fn new(x: &configs::database::MigrationsMode) -> Self {
+        use configs::database::MigrationsMode as From;
+        match x {
+            From::Check => Self::Check,
+            From::Apply => Self::Apply,
+        }
+    }
+
+    fn parse(&self) -> configs::database::MigrationsMode {
+        use configs::database::MigrationsMode as To;
+        match self {
+            Self::Check => To::Check,
+            Self::Apply => To::Apply,
+        }
+    }
+}
+
 impl ProtoRepr for proto::MerkleTree {
     type Type = configs::database::MerkleTreeConfig;
     fn read(&self) -> anyhow::Result<Self::Type> {
@@ -48,6 +66,11 @@ impl ProtoRepr for proto::MerkleTree {
             max_l1_batches_per_iter: required(&self.max_l1_batches_per_iter)
                 .and_then(|x| Ok((*x).try_into()?))
                 .context("max_l1_batches_per_iter")?,
+            thread_pool_size: self
+                .thread_pool_size
+                .map(|x| x.try_into())
+                .transpose()
+                .context("thread_pool_size")?,
         })
     }
 
@@ -60,6 +83,7 @@ impl ProtoRepr for proto::MerkleTree {
             memtable_capacity_mb: Some(this.memtable_capacity_mb.try_into().unwrap()),
             stalled_writes_timeout_sec: Some(this.stalled_writes_timeout_sec),
             max_l1_batches_per_iter: Some(this.max_l1_batches_per_iter.try_into().unwrap()),
+            thread_pool_size: this.thread_pool_size.map(|x| x.try_into().unwrap()),
         }
     }
 }
@@ -109,6 +133,12 @@ impl ProtoRepr for proto::Postgres {
             statement_timeout_sec: self.statement_timeout_sec,
             long_connection_threshold_ms: self.long_connection_threshold_ms,
             slow_query_threshold_ms: self.slow_query_threshold_ms,
+            api_replica_max_lag_ms: self.api_replica_max_lag_ms,
+            migrations_mode: self
+                .migrations_mode
+                .map(|x| Ok::<_, anyhow::Error>(proto::MigrationsMode::try_from(x)?.parse()))
+                .transpose()
+                .context("migrations_mode")?,
             test_server_url,
             test_prover_url,
         })
@@ -122,6 +152,11 @@ impl ProtoRepr for proto::Postgres {
             statement_timeout_sec: this.statement_timeout_sec,
             long_connection_threshold_ms: this.long_connection_threshold_ms,
             slow_query_threshold_ms: this.slow_query_threshold_ms,
+            api_replica_max_lag_ms: this.api_replica_max_lag_ms,
+            migrations_mode: this
+                .migrations_mode
+                .as_ref()
+                .map(|x| proto::MigrationsMode::new(x).into()),
             test: Some(proto::TestDatabase {
                 server_url: this.test_server_url.clone(),
                 prover_url: this.test_prover_url.clone(),
@@ -0,0 +1,176 @@
+//! Diffs between the general config of a running node and a candidate YAML config file.
+
+use std::collections::BTreeMap;
+
+use anyhow::Context as _;
+use serde::Serialize;
+use serde_json::Value;
+use zksync_config::configs::GeneralConfig;
+use zksync_protobuf::ProtoRepr;
+
+use crate::{interpolation::interpolate_env_vars, proto::general as proto};
+
+/// YAML paths of the values that are applied without a restart if the node watches its config file
+/// (see `ReloadableConfig`).
+const HOT_RELOADABLE_PATHS: &[&str] = &[
+    "observability.log_directives",
+    "api.web3_json_rpc.websocket_requests_per_minute_limit",
+    "state_keeper.minimal_l2_gas_price",
+    "state_keeper.compute_overhead_part",
+    "state_keeper.pubdata_overhead_part",
+    "state_keeper.batch_overhead_l1_gas",
+];
+
+/// Substrings of the config keys which values are redacted in diffs.
+const SENSITIVE_KEY_PARTS: &[&str] = &["token", "secret", "password", "private_key"];
+const REDACTED_VALUE: &str = "<redacted>";
+
+/// How a config change can be applied to a running node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ApplyMode {
+    /// The change is applied at runtime once the node's config file is updated.
+    HotReload,
+    /// The change requires restarting the node.
+    Restart,
+}
+
+/// Change of a single config value.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ConfigChange {
+    /// Dot-separated path to the value in the YAML config, e.g. `api.web3_json_rpc.http_port`.
+    pub path: String,
+    /// Currently effective value; `None` if the value is not set.
+    pub current: Option<Value>,
+    /// Value in the candidate config; `None` if the value is not set.
+    pub candidate: Option<Value>,
+    pub apply: ApplyMode,
+}
+
+/// Structured diff between the effective and candidate general configs.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ConfigDiff {
+    /// Changed values ordered by their path.
+    pub changes: Vec<ConfigChange>,
+    /// Whether applying the candidate config requires restarting the node.
+    pub requires_restart: bool,
+}
+
+impl ConfigDiff {
+    /// Compares the currently effective config with the candidate general YAML config.
+    /// If `hot_reload_enabled` is not set, all changes are reported as requiring a restart.
+    ///
+    /// Values are compared after parsing, so changes in formatting or comments, and specifying values equal to
+    /// the defaults filled in during parsing, are not reported. Values of keys that look sensitive (e.g., ones containing
+    /// `token` or `password`) are redacted. Env variables in the candidate config are
+    /// [interpolated](crate::interpolation) using the node environment.
+    pub fn new(
+        current: &GeneralConfig,
+        candidate_yaml: &str,
+        hot_reload_enabled: bool,
+    ) -> anyhow::Result<Self> {
+        let candidate_yaml = interpolate_env_vars(candidate_yaml)
+            .context("failed interpolating env variables in candidate config")?;
+        let deserializer = serde_yaml::Deserializer::from_str(&candidate_yaml);
+        let candidate: proto::GeneralConfig =
+            zksync_protobuf::serde::deserialize_proto_with_options(deserializer, false)
+                .context("failed deserializing candidate config")?;
+        let candidate = candidate
+            .read()
+            .context("failed parsing candidate config")?;
+
+        let mut current_values = BTreeMap::new();
+        flatten(&to_json(current)?, String::new(), &mut current_values);
+        let mut candidate_values = BTreeMap::new();
+        flatten(&to_json(&candidate)?, String::new(), &mut candidate_values);
+
+        let mut paths: Vec<_> = current_values
+            .keys()
+            .chain(candidate_values.keys())
+            .collect();
+        paths.sort_unstable();
+        paths.dedup();
+        let changes: Vec<_> = paths
+            .into_iter()
+            .filter_map(|path| {
+                let current = current_values.get(path);
+                let candidate = candidate_values.get(path);
+                (current != candidate).then(|| {
+                    Self::change(
+                        path,
+                        current.cloned(),
+                        candidate.cloned(),
+                        hot_reload_enabled,
+                    )
+                })
+            })
+            .collect();
+
+        let requires_restart = changes
+            .iter()
+            .any(|change| change.apply == ApplyMode::Restart);
+        Ok(Self {
+            changes,
+            requires_restart,
+        })
+    }
+
+    fn change(
+        path: &str,
+        current: Option<Value>,
+        candidate: Option<Value>,
+        hot_reload_enabled: bool,
+    ) -> ConfigChange {
+        // Removing reloadable values is conservatively treated as requiring a restart; e.g., log directives
+        // are not reset if they are removed from the config.
+        let is_hot_reloadable =
+            hot_reload_enabled && candidate.is_some() && HOT_RELOADABLE_PATHS.contains(&path);
+        let is_sensitive = SENSITIVE_KEY_PARTS
+            .iter()
+            .any(|part| path.rsplit('.').next().unwrap().contains(part));
+        let redact = |value: Option<Value>| {
+            if is_sensitive {
+                value.map(|_| REDACTED_VALUE.into())
+            } else {
+                value
+            }
+        };
+
+        ConfigChange {
+            path: path.to_owned(),
+            current: redact(current),
+            candidate: redact(candidate),
+            apply: if is_hot_reloadable {
+                ApplyMode::HotReload
+            } else {
+                ApplyMode::Restart
+            },
+        }
+    }
+}
+
+fn to_json(config: &GeneralConfig) -> anyhow::Result<Value> {
+    let config = proto::GeneralConfig::build(config);
+    zksync_protobuf::serde::serialize_proto(&config, serde_json::value::Serializer)
+        .context("failed serializing config")
+}
+
+/// Flattens a JSON object into leaf values keyed by dot-separated paths. Arrays and empty objects are treated as leaves.
+fn flatten(value: &Value, path: String, output: &mut BTreeMap<String, Value>) {
+    match value {
+        Value::Object(map) if !map.is_empty() => {
+            for (key, value) in map {
+                let child_path = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{path}.{key}")
+                };
+                flatten(value, child_path, output);
+            }
+        }
+        Value::Null => { /* unset value */ }
+        _ => {
+            output.insert(path, value.clone());
+        }
+    }
+}
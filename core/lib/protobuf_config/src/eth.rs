@@ -30,6 +30,7 @@ impl proto::PubdataSendingMode {
         match x {
             From::Calldata => Self::Calldata,
             From::Blobs => Self::Blobs,
+            From::Custom => Self::Custom,
         }
     }
 
@@ -38,6 +39,7 @@ impl proto::PubdataSendingMode {
         match self {
             Self::Calldata => To::Calldata,
             Self::Blobs => To::Blobs,
+            Self::Custom => To::Custom,
         }
     }
 }
@@ -109,6 +111,8 @@ impl ProtoRepr for proto::Sender {
                 .and_then(|x| Ok(proto::PubdataSendingMode::try_from(*x)?))
                 .context("pubdata_sending_mode")?
                 .parse(),
+            blob_base_fee_calldata_fallback_threshold: self
+                .blob_base_fee_calldata_fallback_threshold,
         })
     }
 
@@ -139,6 +143,8 @@ impl ProtoRepr for proto::Sender {
             pubdata_sending_mode: Some(
                 proto::PubdataSendingMode::new(&this.pubdata_sending_mode).into(),
             ),
+            blob_base_fee_calldata_fallback_threshold: this
+                .blob_base_fee_calldata_fallback_threshold,
         }
     }
 }
@@ -21,6 +21,13 @@ impl ProtoRepr for proto::Db {
                 .map(|count| NonZeroU32::new(count).context("cannot be 0"))
                 .transpose()
                 .context("state_keeper_db_max_open_files")?,
+            state_keeper_db_max_size_mb: self
+                .state_keeper_db_max_size_mb
+                .map(|size| size.try_into())
+                .transpose()
+                .context("state_keeper_db_max_size_mb")?,
+            state_keeper_db_compaction_interval_sec: self.state_keeper_db_compaction_interval_sec,
+            state_keeper_db_compaction_ttl_sec: self.state_keeper_db_compaction_ttl_sec,
         })
     }
 
@@ -34,6 +41,11 @@ impl ProtoRepr for proto::Db {
             state_keeper_db_max_open_files: this
                 .state_keeper_db_max_open_files
                 .map(NonZeroU32::get),
+            state_keeper_db_max_size_mb: this
+                .state_keeper_db_max_size_mb
+                .map(|size| size.try_into().expect("state_keeper_db_max_size_mb")),
+            state_keeper_db_compaction_interval_sec: this.state_keeper_db_compaction_interval_sec,
+            state_keeper_db_compaction_ttl_sec: this.state_keeper_db_compaction_ttl_sec,
         }
     }
 }
@@ -39,8 +39,21 @@ impl ProtoRepr for proto::GeneralConfig {
             observability: read_optional_repr(&self.observability).context("observability")?,
             protective_reads_writer_config: read_optional_repr(&self.protective_reads_writer)
                 .context("protective_reads_writer")?,
+            basic_witness_input_producer_config: read_optional_repr(
+                &self.basic_witness_input_producer,
+            )
+            .context("basic_witness_input_producer")?,
+            vm_playground_config: read_optional_repr(&self.vm_playground)
+                .context("vm_playground")?,
             core_object_store: read_optional_repr(&self.core_object_store)
                 .context("core_object_store")?,
+            da_dispatcher_config: read_optional_repr(&self.da_dispatcher)
+                .context("da_dispatcher")?,
+            da_client_config: read_optional_repr(&self.da_client).context("da_client")?,
+            base_token_price_config: read_optional_repr(&self.base_token_price)
+                .context("base_token_price")?,
+            commitment_generator_config: read_optional_repr(&self.commitment_generator)
+                .context("commitment_generator")?,
         })
     }
 
@@ -76,7 +89,19 @@ impl ProtoRepr for proto::GeneralConfig {
                 .protective_reads_writer_config
                 .as_ref()
                 .map(ProtoRepr::build),
+            basic_witness_input_producer: this
+                .basic_witness_input_producer_config
+                .as_ref()
+                .map(ProtoRepr::build),
+            vm_playground: this.vm_playground_config.as_ref().map(ProtoRepr::build),
             core_object_store: this.core_object_store.as_ref().map(ProtoRepr::build),
+            da_dispatcher: this.da_dispatcher_config.as_ref().map(ProtoRepr::build),
+            da_client: this.da_client_config.as_ref().map(ProtoRepr::build),
+            base_token_price: this.base_token_price_config.as_ref().map(ProtoRepr::build),
+            commitment_generator: this
+                .commitment_generator_config
+                .as_ref()
+                .map(ProtoRepr::build),
         }
     }
 }
@@ -0,0 +1,215 @@
+//! Interpolation of env variables in YAML config values.
+//!
+//! String values in YAML (and thus JSON) configs can reference env variables, so that a single config template
+//! can be reused across environments:
+//!
+//! - `${VAR}` is replaced with the value of `VAR`; it's an error if `VAR` is not set.
+//! - `${VAR:-default}` is replaced with `default` if `VAR` is not set or empty.
+//! - `${VAR:?message}` is an error with the specified message if `VAR` is not set or empty.
+//! - `$${` is replaced with a literal `${`.
+//!
+//! Keys and comments are not interpolated. Interpolated numbers and Booleans are substituted without quotes,
+//! so they can be used for numeric values, e.g. `http_port: ${PORT:-3050}`.
+
+use std::{collections::HashMap, path::Path};
+
+use anyhow::Context as _;
+use serde_yaml::Value;
+
+/// Reads a YAML config file and interpolates env variables in its values.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read or interpolation fails.
+pub fn read_yaml_config(path: &Path) -> anyhow::Result<String> {
+    let yaml = std::fs::read_to_string(path)
+        .with_context(|| format!("failed reading config file `{}`", path.display()))?;
+    interpolate_env_vars(&yaml).with_context(|| {
+        format!(
+            "failed interpolating env variables in config file `{}`",
+            path.display()
+        )
+    })
+}
+
+/// Interpolates env variables in values of the provided YAML document.
+///
+/// # Errors
+///
+/// Returns an error if the document is malformed, or references a required env variable which is not set.
+pub fn interpolate_env_vars(yaml: &str) -> anyhow::Result<String> {
+    interpolate_vars(yaml, |name| std::env::var(name).ok())
+}
+
+pub(crate) fn interpolate_vars(
+    yaml: &str,
+    vars: impl Fn(&str) -> Option<String>,
+) -> anyhow::Result<String> {
+    if !yaml.contains("${") {
+        return Ok(yaml.to_owned());
+    }
+
+    let document: Value = serde_yaml::from_str(yaml).context("failed parsing YAML")?;
+    let mut values = HashMap::new();
+    collect_values(&document, String::new(), &vars, &mut values)?;
+    if values.is_empty() {
+        return Ok(yaml.to_owned());
+    }
+
+    // Values are replaced in the original text rather than in the parsed document, since serializing the document
+    // could change the representation of other values (e.g., `0x01` would become `1`). Replacement is performed
+    // in a single pass, so that interpolated values are never interpolated again.
+    let mut patterns = vec![];
+    for (raw, (path, value)) in &values {
+        // Numbers and Booleans are substituted without quotes, so that they can be used both for numeric
+        // and string fields. Other values are substituted as JSON strings, which are valid double-quoted YAML scalars.
+        let replacement = if is_plain_scalar(value) {
+            value.clone()
+        } else {
+            serde_json::to_string(value).expect("failed serializing string")
+        };
+        patterns.push((format!("\"{raw}\""), replacement.clone(), path));
+        patterns.push((format!("'{raw}'"), replacement.clone(), path));
+        patterns.push((raw.clone(), replacement, path));
+    }
+    // Longer patterns are matched first, so that a value being a prefix of another one doesn't break it.
+    patterns.sort_unstable_by(|(pattern, ..), (other, ..)| other.len().cmp(&pattern.len()));
+
+    let mut output = String::with_capacity(yaml.len());
+    let mut replaced_paths = vec![];
+    let mut rest = yaml;
+    'outer: while let Some(ch) = rest.chars().next() {
+        for (pattern, replacement, path) in &patterns {
+            if let Some(tail) = rest.strip_prefix(pattern.as_str()) {
+                output.push_str(replacement);
+                replaced_paths.push(path.as_str());
+                rest = tail;
+                continue 'outer;
+            }
+        }
+        output.push(ch);
+        rest = &rest[ch.len_utf8()..];
+    }
+
+    for (path, _) in values.values() {
+        anyhow::ensure!(
+            replaced_paths.contains(&path.as_str()),
+            "cannot interpolate value at `{path}`; values with env variables must be plain or quoted scalars \
+             without escape sequences"
+        );
+    }
+    Ok(output)
+}
+
+/// Checks whether the interpolated value is a number or a Boolean, which can be substituted without quotes.
+fn is_plain_scalar(value: &str) -> bool {
+    !value.contains(['\n', '#', ':', '[', '{', ',', '\'', '"'])
+        && matches!(
+            serde_yaml::from_str::<Value>(value),
+            Ok(Value::Number(_) | Value::Bool(_))
+        )
+}
+
+/// Collects string values with interpolation directives in `value`, keyed by their raw representation.
+/// Values are mapped to the path of the first occurrence and the interpolated value.
+fn collect_values(
+    value: &Value,
+    path: String,
+    vars: &impl Fn(&str) -> Option<String>,
+    values: &mut HashMap<String, (String, String)>,
+) -> anyhow::Result<()> {
+    match value {
+        Value::String(s) => {
+            if s.contains("${") && !values.contains_key(s) {
+                let interpolated = interpolate_value(s, vars)
+                    .with_context(|| format!("failed interpolating value at `{path}`"))?;
+                values.insert(s.clone(), (path, interpolated));
+            }
+        }
+        Value::Sequence(items) => {
+            for (i, item) in items.iter().enumerate() {
+                collect_values(item, format!("{path}[{i}]"), vars, values)?;
+            }
+        }
+        Value::Mapping(map) => {
+            for (key, value) in map {
+                let key = match key {
+                    Value::String(key) => key.clone(),
+                    key => serde_yaml::to_string(key)
+                        .unwrap_or_default()
+                        .trim()
+                        .to_owned(),
+                };
+                let child_path = if path.is_empty() {
+                    key
+                } else {
+                    format!("{path}.{key}")
+                };
+                collect_values(value, child_path, vars, values)?;
+            }
+        }
+        Value::Tagged(tagged) => collect_values(&tagged.value, path, vars, values)?,
+        Value::Null | Value::Bool(_) | Value::Number(_) => {}
+    }
+    Ok(())
+}
+
+fn interpolate_value(
+    value: &str,
+    vars: &impl Fn(&str) -> Option<String>,
+) -> anyhow::Result<String> {
+    let mut output = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some(pos) = rest.find("${") {
+        if rest[..pos].ends_with('$') {
+            // Escaped `$${`.
+            output.push_str(&rest[..pos - 1]);
+            output.push_str("${");
+            rest = &rest[pos + 2..];
+            continue;
+        }
+        output.push_str(&rest[..pos]);
+        let (directive, tail) = rest[pos + 2..]
+            .split_once('}')
+            .with_context(|| format!("unterminated `${{` in `{value}`"))?;
+        output.push_str(&resolve_directive(directive, vars)?);
+        rest = tail;
+    }
+    output.push_str(rest);
+    Ok(output)
+}
+
+fn resolve_directive(
+    directive: &str,
+    vars: &impl Fn(&str) -> Option<String>,
+) -> anyhow::Result<String> {
+    let (name, modifier) = match directive.split_once(':') {
+        Some((name, modifier)) => (name, Some(modifier)),
+        None => (directive, None),
+    };
+    let is_valid_name = name
+        .chars()
+        .next()
+        .map_or(false, |ch| ch.is_ascii_alphabetic() || ch == '_')
+        && name
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || ch == '_');
+    anyhow::ensure!(is_valid_name, "invalid env variable name `{name}`");
+
+    let value = vars(name);
+    match modifier {
+        None => value.with_context(|| format!("env variable `{name}` is not set")),
+        Some(modifier) => {
+            let value = value.filter(|value| !value.is_empty());
+            if let Some(default) = modifier.strip_prefix('-') {
+                Ok(value.unwrap_or_else(|| default.to_owned()))
+            } else if let Some(message) = modifier.strip_prefix('?') {
+                value.with_context(|| format!("env variable `{name}` is not set: {message}"))
+            } else {
+                anyhow::bail!(
+                    "unsupported modifier in `${{{directive}}}`; use `${{{name}:-default}}` or `${{{name}:?message}}`"
+                )
+            }
+        }
+    }
+}
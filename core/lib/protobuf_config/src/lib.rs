@@ -5,22 +5,29 @@
 //! * protobuf json format
 
 mod api;
+mod base_token_price;
 mod chain;
 mod circuit_breaker;
+mod commitment_generator;
 mod consensus;
 mod contract_verifier;
 mod contracts;
+mod da_client;
+mod da_dispatcher;
 mod database;
+pub mod diff;
 mod eth;
 mod experimental;
 mod general;
 mod genesis;
 mod house_keeper;
+pub mod interpolation;
 mod object_store;
 mod observability;
 mod proof_data_handler;
 pub mod proto;
 mod prover;
+pub mod schema;
 mod secrets;
 mod snapshots_creator;
 pub mod testonly;
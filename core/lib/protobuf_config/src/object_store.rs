@@ -37,6 +37,14 @@ impl ProtoRepr for proto::ObjectStore {
                     .context("file_backed_base_path")?
                     .clone(),
             },
+            proto::object_store::Mode::S3(mode) => ObjectStoreMode::S3 {
+                bucket_base_url: required(&mode.bucket_base_url)
+                    .context("bucket_base_url")?
+                    .clone(),
+                s3_region: required(&mode.region).context("region")?.clone(),
+                s3_endpoint: mode.endpoint.clone(),
+                s3_credential_file_path: mode.credential_file_path.clone(),
+            },
         };
 
         Ok(Self::Type {
@@ -76,6 +84,17 @@ impl ProtoRepr for proto::ObjectStore {
             } => proto::object_store::Mode::FileBacked(proto::object_store::FileBacked {
                 file_backed_base_path: Some(file_backed_base_path.clone()),
             }),
+            ObjectStoreMode::S3 {
+                bucket_base_url,
+                s3_region,
+                s3_endpoint,
+                s3_credential_file_path,
+            } => proto::object_store::Mode::S3(proto::object_store::S3 {
+                bucket_base_url: Some(bucket_base_url.clone()),
+                region: Some(s3_region.clone()),
+                endpoint: s3_endpoint.clone(),
+                credential_file_path: s3_credential_file_path.clone(),
+            }),
         };
 
         Self {
@@ -7,7 +7,7 @@ use crate::proto::observability as proto;
 impl ProtoRepr for proto::Observability {
     type Type = configs::ObservabilityConfig;
     fn read(&self) -> anyhow::Result<Self::Type> {
-        let (sentry_url, sentry_environment) = if let Some(sentry) = &self.sentry {
+        let (sentry_url, sentry_environment, sentry_release) = if let Some(sentry) = &self.sentry {
             let sentry_url = required(&sentry.url).context("sentry_url")?.clone();
             let sentry_url = if sentry_url.to_lowercase() == *"unset" {
                 None
@@ -22,13 +22,15 @@ impl ProtoRepr for proto::Observability {
                         .context("sentry.environment")?
                         .clone(),
                 ),
+                sentry.release.clone(),
             )
         } else {
-            (None, None)
+            (None, None, None)
         };
         Ok(Self::Type {
             sentry_url,
             sentry_environment,
+            sentry_release,
             log_format: required(&self.log_format).context("log_format")?.clone(),
             opentelemetry: self
                 .opentelemetry
@@ -36,6 +38,19 @@ impl ProtoRepr for proto::Observability {
                 .map(|cfg| cfg.read().context("opentelemetry"))
                 .transpose()?,
             log_directives: self.log_directives.clone(),
+            log_sampling_directives: self.log_sampling_directives.clone(),
+            admin_port: self
+                .admin_port
+                .map(u16::try_from)
+                .transpose()
+                .context("admin_port")?,
+            admin_profiling_enabled: self.admin_profiling_enabled.unwrap_or(false),
+            slo: self
+                .slo
+                .as_ref()
+                .map(|slo| slo.read().context("slo"))
+                .transpose()?
+                .unwrap_or_default(),
         })
     }
 
@@ -48,6 +63,7 @@ impl ProtoRepr for proto::Observability {
                 environment: this.sentry_environment.clone(),
                 panic_interval: None,
                 error_interval: None,
+                release: this.sentry_release.clone(),
             })
         };
         Self {
@@ -55,6 +71,32 @@ impl ProtoRepr for proto::Observability {
             log_format: Some(this.log_format.clone()),
             opentelemetry: this.opentelemetry.as_ref().map(ProtoRepr::build),
             log_directives: this.log_directives.clone(),
+            log_sampling_directives: this.log_sampling_directives.clone(),
+            admin_port: this.admin_port.map(Into::into),
+            admin_profiling_enabled: Some(this.admin_profiling_enabled),
+            slo: Some(ProtoRepr::build(&this.slo)),
+        }
+    }
+}
+
+impl ProtoRepr for proto::Slo {
+    type Type = configs::SloConfig;
+
+    fn read(&self) -> anyhow::Result<Self::Type> {
+        Ok(Self::Type {
+            check_interval_ms: self.check_interval_ms,
+            max_sync_lag: self.max_sync_lag,
+            max_pool_saturation: self.max_pool_saturation,
+            max_api_p99_latency_ms: self.max_api_p99_latency_ms,
+        })
+    }
+
+    fn build(this: &Self::Type) -> Self {
+        Self {
+            check_interval_ms: this.check_interval_ms,
+            max_sync_lag: this.max_sync_lag,
+            max_pool_saturation: this.max_pool_saturation,
+            max_api_p99_latency_ms: this.max_api_p99_latency_ms,
         }
     }
 }
@@ -66,6 +108,8 @@ impl ProtoRepr for proto::Opentelemetry {
         Ok(Self::Type {
             level: required(&self.level).context("level")?.clone(),
             endpoint: required(&self.endpoint).context("endpoint")?.clone(),
+            sampling_ratio: self.sampling_ratio,
+            resource_attributes: self.resource_attributes.clone(),
         })
     }
 
@@ -73,6 +117,8 @@ impl ProtoRepr for proto::Opentelemetry {
         Self {
             level: Some(this.level.clone()),
             endpoint: Some(this.endpoint.clone()),
+            sampling_ratio: this.sampling_ratio,
+            resource_attributes: this.resource_attributes.clone(),
         }
     }
 }
@@ -0,0 +1,675 @@
+//! JSON Schema generation for YAML config files.
+//!
+//! Schemas are derived from the Protobuf definitions of the configs. Besides field types, the generator
+//! interprets the conventional trailing field comments (e.g., `// required; ms` or `// optional; H160`):
+//! required fields, units, integer ranges, hex formats and default values. Other comments are included
+//! into field descriptions.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt,
+    str::FromStr,
+};
+
+use anyhow::Context as _;
+use serde_json::{json, Map, Value};
+
+/// Protobuf definitions of all config files, together with their paths for error reporting.
+const PROTO_FILES: &[(&str, &str)] = &[
+    ("config/api.proto", include_str!("proto/config/api.proto")),
+    (
+        "config/base_token_price.proto",
+        include_str!("proto/config/base_token_price.proto"),
+    ),
+    (
+        "config/chain.proto",
+        include_str!("proto/config/chain.proto"),
+    ),
+    (
+        "config/commitment_generator.proto",
+        include_str!("proto/config/commitment_generator.proto"),
+    ),
+    (
+        "config/circuit_breaker.proto",
+        include_str!("proto/config/circuit_breaker.proto"),
+    ),
+    (
+        "config/contract_verifier.proto",
+        include_str!("proto/config/contract_verifier.proto"),
+    ),
+    (
+        "config/contracts.proto",
+        include_str!("proto/config/contracts.proto"),
+    ),
+    (
+        "config/da_client.proto",
+        include_str!("proto/config/da_client.proto"),
+    ),
+    (
+        "config/da_dispatcher.proto",
+        include_str!("proto/config/da_dispatcher.proto"),
+    ),
+    (
+        "config/database.proto",
+        include_str!("proto/config/database.proto"),
+    ),
+    (
+        "config/eth_sender.proto",
+        include_str!("proto/config/eth_sender.proto"),
+    ),
+    (
+        "config/experimental.proto",
+        include_str!("proto/config/experimental.proto"),
+    ),
+    (
+        "config/general.proto",
+        include_str!("proto/config/general.proto"),
+    ),
+    (
+        "config/genesis.proto",
+        include_str!("proto/config/genesis.proto"),
+    ),
+    (
+        "config/house_keeper.proto",
+        include_str!("proto/config/house_keeper.proto"),
+    ),
+    (
+        "config/object_store.proto",
+        include_str!("proto/config/object_store.proto"),
+    ),
+    (
+        "config/observability.proto",
+        include_str!("proto/config/observability.proto"),
+    ),
+    (
+        "config/prover.proto",
+        include_str!("proto/config/prover.proto"),
+    ),
+    (
+        "config/secrets.proto",
+        include_str!("proto/config/secrets.proto"),
+    ),
+    (
+        "config/snapshots_creator.proto",
+        include_str!("proto/config/snapshots_creator.proto"),
+    ),
+    (
+        "config/utils.proto",
+        include_str!("proto/config/utils.proto"),
+    ),
+    (
+        "config/vm_runner.proto",
+        include_str!("proto/config/vm_runner.proto"),
+    ),
+    (
+        "config/wallets.proto",
+        include_str!("proto/config/wallets.proto"),
+    ),
+    (
+        "core/consensus.proto",
+        include_str!("proto/core/consensus.proto"),
+    ),
+];
+
+/// YAML config file used by the main node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigFile {
+    General,
+    Secrets,
+    Wallets,
+    Contracts,
+    Genesis,
+    Consensus,
+}
+
+impl ConfigFile {
+    pub const ALL: [Self; 6] = [
+        Self::General,
+        Self::Secrets,
+        Self::Wallets,
+        Self::Contracts,
+        Self::Genesis,
+        Self::Consensus,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::General => "general",
+            Self::Secrets => "secrets",
+            Self::Wallets => "wallets",
+            Self::Contracts => "contracts",
+            Self::Genesis => "genesis",
+            Self::Consensus => "consensus",
+        }
+    }
+
+    /// Returns the full name of the root Protobuf message for this file.
+    fn message_name(self) -> &'static str {
+        match self {
+            Self::General => "zksync.config.general.GeneralConfig",
+            Self::Secrets => "zksync.config.secrets.Secrets",
+            Self::Wallets => "zksync.config.wallets.Wallets",
+            Self::Contracts => "zksync.config.contracts.Contracts",
+            Self::Genesis => "zksync.config.genesis.Genesis",
+            Self::Consensus => "zksync.core.consensus.Config",
+        }
+    }
+}
+
+impl fmt::Display for ConfigFile {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(self.name())
+    }
+}
+
+impl FromStr for ConfigFile {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|file| file.name() == s)
+            .with_context(|| {
+                let names: Vec<_> = Self::ALL.iter().map(|file| file.name()).collect();
+                format!("unknown config file `{s}`; expected one of {names:?}")
+            })
+    }
+}
+
+/// Generates a JSON Schema (draft 7) for the specified config file.
+pub fn json_schema(file: ConfigFile) -> anyhow::Result<Value> {
+    let definitions = ProtoDefinitions::parse()?;
+    let mut schema = definitions.json_schema(file.message_name())?;
+    schema["title"] = format!("{file} config").into();
+    Ok(schema)
+}
+
+#[derive(Debug, Default)]
+struct MessageDef {
+    description: Option<String>,
+    fields: Vec<FieldDef>,
+    /// Names of fields in each `oneof` of the message.
+    oneofs: Vec<Vec<String>>,
+}
+
+#[derive(Debug)]
+struct FieldDef {
+    name: String,
+    type_name: String,
+    repeated: bool,
+    description: Option<String>,
+    annotations: FieldAnnotations,
+}
+
+#[derive(Debug, Default)]
+struct EnumDef {
+    description: Option<String>,
+    values: Vec<String>,
+}
+
+/// Information about a field extracted from its trailing comment.
+#[derive(Debug, Default)]
+struct FieldAnnotations {
+    required: bool,
+    unit: Option<&'static str>,
+    /// Additional schema keywords constraining field values (for repeated fields, individual items).
+    keywords: Map<String, Value>,
+    /// Default value literal, e.g. `false` or `rollup`.
+    default: Option<String>,
+    notes: Vec<String>,
+}
+
+impl FieldAnnotations {
+    fn parse(comment: &str) -> Self {
+        let mut this = Self::default();
+        for token in comment.split(';').map(str::trim) {
+            let token = match token.strip_prefix("required") {
+                Some("") => {
+                    this.required = true;
+                    continue;
+                }
+                // Handles a missing separator, as in `required ms`.
+                Some(rest) if rest.starts_with(' ') && Self::unit(rest.trim()).is_some() => {
+                    this.required = true;
+                    rest.trim()
+                }
+                _ => token,
+            };
+
+            if let Some(unit) = Self::unit(token) {
+                this.unit = Some(unit);
+                continue;
+            }
+            match token {
+                "" | "optional" | "?" => { /* no information */ }
+                "u8" => {
+                    this.keywords.insert("maximum".into(), u8::MAX.into());
+                }
+                "u16" => {
+                    this.keywords.insert("maximum".into(), u16::MAX.into());
+                }
+                "H160" | "h160" => {
+                    this.keywords
+                        .insert("pattern".into(), "^(0x)?[0-9a-fA-F]{40}$".into());
+                }
+                "H256" | "h256" => {
+                    this.keywords
+                        .insert("pattern".into(), "^(0x)?[0-9a-fA-F]{64}$".into());
+                }
+                "url" => {
+                    this.keywords.insert("format".into(), "uri".into());
+                }
+                "[0,1]" | "from 0 to 1" => {
+                    this.keywords.insert("minimum".into(), 0.into());
+                    this.keywords.insert("maximum".into(), 1.into());
+                }
+                _ => {
+                    if let Some(default) = Self::default_literal(token) {
+                        this.default = Some(default.to_owned());
+                    } else {
+                        this.notes.push(token.to_owned());
+                    }
+                }
+            }
+        }
+        this
+    }
+
+    fn unit(token: &str) -> Option<&'static str> {
+        Some(match token.trim_end_matches('?') {
+            "ms" => "milliseconds",
+            "s" | "sec" | "seconds" => "seconds",
+            "B" | "bytes" => "bytes",
+            "MB" => "MiB",
+            "wei" => "wei",
+            "gwei" => "gwei",
+            "%" => "percent",
+            "utia" => "utia",
+            "L2 blocks" => "L2 blocks",
+            _ => return None,
+        })
+    }
+
+    /// Extracts the default value from comments like `default false`, `defaults to false`
+    /// or `optional, default to rollup`.
+    fn default_literal(token: &str) -> Option<&str> {
+        let (_, rest) = token.split_once("default")?;
+        let rest = rest.strip_prefix('s').unwrap_or(rest).trim_start();
+        let literal = rest.strip_prefix("to ").unwrap_or(rest).trim();
+        let is_single_word = !literal.is_empty() && !literal.contains(char::is_whitespace);
+        is_single_word.then_some(literal)
+    }
+}
+
+#[derive(Debug)]
+enum Scope {
+    Message(String),
+    Enum(String),
+    Oneof { message: String, index: usize },
+}
+
+/// Messages and enums parsed from the Protobuf definitions, keyed by the full name.
+#[derive(Debug, Default)]
+struct ProtoDefinitions {
+    messages: HashMap<String, MessageDef>,
+    enums: HashMap<String, EnumDef>,
+}
+
+impl ProtoDefinitions {
+    fn parse() -> anyhow::Result<Self> {
+        let mut this = Self::default();
+        for &(path, contents) in PROTO_FILES {
+            this.parse_file(contents)
+                .with_context(|| format!("failed parsing `{path}`"))?;
+        }
+        Ok(this)
+    }
+
+    /// Parses a Protobuf file. Only the subset of the syntax used in config definitions is supported;
+    /// in particular, each statement must be on a separate line.
+    fn parse_file(&mut self, contents: &str) -> anyhow::Result<()> {
+        let mut package = String::new();
+        let mut scopes = Vec::<Scope>::new();
+        let mut comments = Vec::<&str>::new();
+
+        for (line_idx, line) in contents.lines().enumerate() {
+            let (statement, trailing_comment) = match line.split_once("//") {
+                Some((statement, comment)) => (statement.trim(), Some(comment.trim())),
+                None => (line.trim(), None),
+            };
+            if statement.is_empty() {
+                match trailing_comment {
+                    Some(comment) if !comment.is_empty() => comments.push(comment),
+                    _ => comments.clear(),
+                }
+                continue;
+            }
+            let description = (!comments.is_empty()).then(|| comments.join(" "));
+            comments.clear();
+
+            self.parse_statement(
+                statement,
+                trailing_comment,
+                description,
+                &mut package,
+                &mut scopes,
+            )
+            .with_context(|| format!("line {}: `{line}`", line_idx + 1))?;
+        }
+        anyhow::ensure!(scopes.is_empty(), "unclosed scopes: {scopes:?}");
+        Ok(())
+    }
+
+    fn parse_statement(
+        &mut self,
+        statement: &str,
+        trailing_comment: Option<&str>,
+        description: Option<String>,
+        package: &mut String,
+        scopes: &mut Vec<Scope>,
+    ) -> anyhow::Result<()> {
+        // Braces may be not separated from names, as in `message Name{`.
+        let spaced_statement = statement.replace('{', " { ");
+        let words: Vec<_> = spaced_statement.split_whitespace().collect();
+        match words.as_slice() {
+            ["syntax" | "import" | "option" | "reserved", ..] => {}
+            ["package", name] => {
+                *package = name.trim_end_matches(';').to_owned();
+            }
+            ["message", name, "{"] => {
+                let full_name = Self::scoped_name(package, scopes, name);
+                let message = MessageDef {
+                    description,
+                    ..MessageDef::default()
+                };
+                self.messages.insert(full_name.clone(), message);
+                scopes.push(Scope::Message(full_name));
+            }
+            ["enum", name, "{"] => {
+                let full_name = Self::scoped_name(package, scopes, name);
+                let enum_def = EnumDef {
+                    description,
+                    values: vec![],
+                };
+                self.enums.insert(full_name.clone(), enum_def);
+                scopes.push(Scope::Enum(full_name));
+            }
+            ["oneof", _, "{"] => {
+                let Some(Scope::Message(message)) = scopes.last() else {
+                    anyhow::bail!("`oneof` outside a message");
+                };
+                let message_def = self.messages.get_mut(message).unwrap();
+                message_def.oneofs.push(vec![]);
+                let scope = Scope::Oneof {
+                    message: message.clone(),
+                    index: message_def.oneofs.len() - 1,
+                };
+                scopes.push(scope);
+            }
+            ["}"] => {
+                scopes.pop().context("unbalanced braces")?;
+            }
+            _ => match scopes.last() {
+                Some(Scope::Enum(name)) => {
+                    let (value, _) = statement.split_once('=').context("invalid enum value")?;
+                    let enum_def = self.enums.get_mut(name).unwrap();
+                    enum_def.values.push(value.trim().to_owned());
+                }
+                Some(Scope::Message(message)) => {
+                    let field = Self::parse_field(statement, trailing_comment, description)?;
+                    self.messages.get_mut(message).unwrap().fields.push(field);
+                }
+                Some(Scope::Oneof { message, index }) => {
+                    let field = Self::parse_field(statement, trailing_comment, description)?;
+                    let message_def = self.messages.get_mut(message).unwrap();
+                    message_def.oneofs[*index].push(field.name.clone());
+                    message_def.fields.push(field);
+                }
+                None => anyhow::bail!("unsupported top-level statement"),
+            },
+        }
+        Ok(())
+    }
+
+    fn scoped_name(package: &str, scopes: &[Scope], name: &str) -> String {
+        let parent = scopes.iter().rev().find_map(|scope| match scope {
+            Scope::Message(name) | Scope::Enum(name) => Some(name.as_str()),
+            Scope::Oneof { .. } => None,
+        });
+        format!("{}.{name}", parent.unwrap_or(package))
+    }
+
+    /// Parses a field declaration: `[optional | repeated] {type} {name} = {tag};`.
+    fn parse_field(
+        statement: &str,
+        trailing_comment: Option<&str>,
+        description: Option<String>,
+    ) -> anyhow::Result<FieldDef> {
+        let statement = statement
+            .strip_suffix(';')
+            .context("field declaration must end with `;`")?;
+        let (declaration, _) = statement
+            .split_once('=')
+            .context("field declaration must have a tag")?;
+        let mut words: Vec<_> = declaration.split_whitespace().collect();
+        let repeated = match words.first() {
+            Some(&"repeated") => {
+                words.remove(0);
+                true
+            }
+            Some(&"optional") => {
+                words.remove(0);
+                false
+            }
+            _ => false,
+        };
+        let [type_name, name] = words.as_slice() else {
+            anyhow::bail!("unsupported field declaration");
+        };
+        Ok(FieldDef {
+            name: (*name).to_owned(),
+            type_name: (*type_name).to_owned(),
+            repeated,
+            description,
+            annotations: trailing_comment
+                .map(FieldAnnotations::parse)
+                .unwrap_or_default(),
+        })
+    }
+
+    /// Resolves a type reference according to the Protobuf scoping rules, i.e., starting from the innermost scope.
+    fn resolve(&self, scope: &str, type_name: &str) -> Option<String> {
+        let is_defined =
+            |name: &str| self.messages.contains_key(name) || self.enums.contains_key(name);
+        if let Some(full_name) = type_name.strip_prefix('.') {
+            return is_defined(full_name).then(|| full_name.to_owned());
+        }
+
+        let mut scope = Some(scope);
+        while let Some(current) = scope {
+            let candidate = if current.is_empty() {
+                type_name.to_owned()
+            } else {
+                format!("{current}.{type_name}")
+            };
+            if is_defined(&candidate) {
+                return Some(candidate);
+            }
+            scope = (!current.is_empty())
+                .then(|| current.rsplit_once('.').map_or("", |(parent, _)| parent));
+        }
+        None
+    }
+
+    fn json_schema(&self, root: &str) -> anyhow::Result<Value> {
+        let mut definitions = Map::new();
+        let mut queue = VecDeque::from([root.to_owned()]);
+        while let Some(name) = queue.pop_front() {
+            if definitions.contains_key(&name) {
+                continue;
+            }
+            let schema = if let Some(message) = self.messages.get(&name) {
+                self.message_schema(&name, message, &mut queue)?
+            } else if let Some(enum_def) = self.enums.get(&name) {
+                Self::enum_schema(enum_def)
+            } else {
+                anyhow::bail!("unknown type `{name}`");
+            };
+            definitions.insert(name, schema);
+        }
+
+        Ok(json!({
+            "$schema": "http://json-schema.org/draft-07/schema#",
+            "$ref": format!("#/definitions/{root}"),
+            "definitions": definitions,
+        }))
+    }
+
+    fn enum_schema(enum_def: &EnumDef) -> Value {
+        let mut schema = json!({
+            "type": "string",
+            "enum": enum_def.values,
+        });
+        if let Some(description) = &enum_def.description {
+            schema["description"] = description.as_str().into();
+        }
+        schema
+    }
+
+    fn message_schema(
+        &self,
+        name: &str,
+        message: &MessageDef,
+        queue: &mut VecDeque<String>,
+    ) -> anyhow::Result<Value> {
+        let mut properties = Map::new();
+        let mut required = vec![];
+        for field in &message.fields {
+            let schema = self
+                .field_schema(name, field, queue)
+                .with_context(|| format!("field `{name}.{}`", field.name))?;
+            properties.insert(field.name.clone(), schema);
+            if field.annotations.required {
+                required.push(field.name.as_str());
+            }
+        }
+
+        let mut schema = json!({
+            "type": "object",
+            "properties": properties,
+            "additionalProperties": false,
+        });
+        if let Some(description) = &message.description {
+            schema["description"] = description.as_str().into();
+        }
+        if !required.is_empty() {
+            schema["required"] = required.into();
+        }
+        // Exactly one field from each `oneof` must be specified.
+        let mut oneofs: Vec<_> = message
+            .oneofs
+            .iter()
+            .map(|fields| {
+                let variants = fields.iter().map(|name| json!({ "required": [name] }));
+                Value::from(variants.collect::<Vec<_>>())
+            })
+            .collect();
+        match oneofs.len() {
+            0 => { /* no constraints */ }
+            1 => schema["oneOf"] = oneofs.pop().unwrap(),
+            _ => {
+                let constraints = oneofs
+                    .into_iter()
+                    .map(|variants| json!({ "oneOf": variants }));
+                schema["allOf"] = constraints.collect::<Vec<_>>().into();
+            }
+        }
+        Ok(schema)
+    }
+
+    fn field_schema(
+        &self,
+        scope: &str,
+        field: &FieldDef,
+        queue: &mut VecDeque<String>,
+    ) -> anyhow::Result<Value> {
+        let annotations = &field.annotations;
+        let mut default = annotations
+            .default
+            .as_deref()
+            .and_then(|literal| serde_json::from_str::<Value>(literal).ok());
+
+        let mut item_schema = if let Some(schema) = Self::scalar_schema(&field.type_name) {
+            schema
+        } else {
+            let type_name = self
+                .resolve(scope, &field.type_name)
+                .with_context(|| format!("cannot resolve type `{}`", field.type_name))?;
+            if let (Some(enum_def), Some(literal)) =
+                (self.enums.get(&type_name), &annotations.default)
+            {
+                default = enum_def
+                    .values
+                    .iter()
+                    .find(|value| value.eq_ignore_ascii_case(literal))
+                    .map(|value| value.as_str().into());
+            }
+            let reference = json!({ "$ref": format!("#/definitions/{type_name}") });
+            queue.push_back(type_name);
+            reference
+        };
+
+        for (keyword, value) in &annotations.keywords {
+            item_schema[keyword] = value.clone();
+        }
+        let mut schema = if field.repeated {
+            json!({ "type": "array", "items": item_schema })
+        } else {
+            item_schema
+        };
+        // Keywords adjacent to `$ref` are ignored in draft 7, so the reference needs to be wrapped.
+        if let Some(reference) = schema.get("$ref").cloned() {
+            schema = json!({ "allOf": [{ "$ref": reference }] });
+        }
+
+        // If the default value cannot be represented in the schema, it's mentioned in the description.
+        let unresolved_default = annotations
+            .default
+            .as_ref()
+            .filter(|_| default.is_none())
+            .map(|literal| format!("default {literal}"));
+        let description: Vec<_> = field
+            .description
+            .iter()
+            .chain(&annotations.notes)
+            .chain(&unresolved_default)
+            .map(String::as_str)
+            .collect();
+        if !description.is_empty() {
+            schema["description"] = description.join("; ").into();
+        }
+        if let Some(unit) = annotations.unit {
+            schema["x-unit"] = unit.into();
+        }
+        if let Some(default) = default {
+            schema["default"] = default;
+        }
+        Ok(schema)
+    }
+
+    fn scalar_schema(type_name: &str) -> Option<Value> {
+        Some(match type_name {
+            "double" | "float" => json!({ "type": "number" }),
+            "int32" | "int64" | "sint32" | "sint64" | "sfixed32" | "sfixed64" => {
+                json!({ "type": "integer" })
+            }
+            "uint32" | "uint64" | "fixed32" | "fixed64" => {
+                json!({ "type": "integer", "minimum": 0 })
+            }
+            "bool" => json!({ "type": "boolean" }),
+            "string" => json!({ "type": "string" }),
+            "bytes" => json!({ "type": "string", "contentEncoding": "base64" }),
+            _ => return None,
+        })
+    }
+}
@@ -4,7 +4,9 @@ use anyhow::Context;
 use secrecy::ExposeSecret;
 use zksync_basic_types::url::SensitiveUrl;
 use zksync_config::configs::{
-    consensus::{ConsensusSecrets, NodeSecretKey, ValidatorSecretKey},
+    consensus::{
+        AttesterSecretKey, ConsensusKeystore, ConsensusSecrets, NodeSecretKey, ValidatorSecretKey,
+    },
     secrets::Secrets,
     DatabaseSecrets, L1Secrets,
 };
@@ -56,10 +58,19 @@ impl ProtoRepr for proto::DatabaseSecrets {
             .map(str::parse::<SensitiveUrl>)
             .transpose()
             .context("prover_url")?;
+        let server_api_replica_urls = self
+            .server_api_replica_urls
+            .iter()
+            .enumerate()
+            .map(|(i, url)| {
+                SensitiveUrl::from_str(url).with_context(|| format!("server_api_replica_urls[{i}]"))
+            })
+            .collect::<anyhow::Result<_>>()?;
         Ok(Self::Type {
             server_url,
             prover_url,
             server_replica_url,
+            server_api_replica_urls,
         })
     }
 
@@ -70,6 +81,11 @@ impl ProtoRepr for proto::DatabaseSecrets {
                 .server_replica_url
                 .as_ref()
                 .map(|a| a.expose_str().to_string()),
+            server_api_replica_urls: this
+                .server_api_replica_urls
+                .iter()
+                .map(|url| url.expose_str().to_string())
+                .collect(),
             prover_url: this.prover_url.as_ref().map(|a| a.expose_str().to_string()),
         }
     }
@@ -80,12 +96,26 @@ impl ProtoRepr for proto::L1Secrets {
     fn read(&self) -> anyhow::Result<Self::Type> {
         Ok(Self::Type {
             l1_rpc_url: SensitiveUrl::from_str(required(&self.l1_rpc_url).context("l1_rpc_url")?)?,
+            fallback_l1_rpc_urls: self
+                .fallback_l1_rpc_urls
+                .iter()
+                .enumerate()
+                .map(|(i, url)| {
+                    SensitiveUrl::from_str(url)
+                        .with_context(|| format!("fallback_l1_rpc_urls[{i}]"))
+                })
+                .collect::<anyhow::Result<_>>()?,
         })
     }
 
     fn build(this: &Self::Type) -> Self {
         Self {
             l1_rpc_url: Some(this.l1_rpc_url.expose_str().to_string()),
+            fallback_l1_rpc_urls: this
+                .fallback_l1_rpc_urls
+                .iter()
+                .map(|url| url.expose_str().to_string())
+                .collect(),
         }
     }
 }
@@ -102,6 +132,11 @@ impl ProtoRepr for proto::ConsensusSecrets {
                 .node_key
                 .as_ref()
                 .map(|x| NodeSecretKey(x.clone().into())),
+            attester_key: self
+                .attester_key
+                .as_ref()
+                .map(|x| AttesterSecretKey(x.clone().into())),
+            keystore: read_optional_repr(&self.keystore).context("keystore")?,
         })
     }
 
@@ -112,6 +147,37 @@ impl ProtoRepr for proto::ConsensusSecrets {
                 .as_ref()
                 .map(|x| x.0.expose_secret().clone()),
             node_key: this.node_key.as_ref().map(|x| x.0.expose_secret().clone()),
+            attester_key: this
+                .attester_key
+                .as_ref()
+                .map(|x| x.0.expose_secret().clone()),
+            keystore: this.keystore.as_ref().map(ProtoRepr::build),
+        }
+    }
+}
+
+impl ProtoRepr for proto::ConsensusKeystore {
+    type Type = ConsensusKeystore;
+    fn read(&self) -> anyhow::Result<Self::Type> {
+        Ok(Self::Type {
+            path: required(&self.path).context("path")?.into(),
+            fallback_paths: self.fallback_paths.iter().map(Into::into).collect(),
+            password_file: self.password_file.as_ref().map(Into::into),
+        })
+    }
+
+    fn build(this: &Self::Type) -> Self {
+        Self {
+            path: Some(this.path.to_string_lossy().into_owned()),
+            fallback_paths: this
+                .fallback_paths
+                .iter()
+                .map(|path| path.to_string_lossy().into_owned())
+                .collect(),
+            password_file: this
+                .password_file
+                .as_ref()
+                .map(|path| path.to_string_lossy().into_owned()),
         }
     }
 }
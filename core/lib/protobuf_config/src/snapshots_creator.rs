@@ -18,6 +18,7 @@ impl ProtoRepr for proto::SnapshotsCreator {
             concurrent_queries_count: *required(&self.concurrent_queries_count)
                 .context("concurrent_queries_count")?,
             object_store,
+            creation_interval_sec: self.creation_interval_sec,
         })
     }
 
@@ -26,6 +27,7 @@ impl ProtoRepr for proto::SnapshotsCreator {
             storage_logs_chunk_size: Some(this.storage_logs_chunk_size),
             concurrent_queries_count: Some(this.concurrent_queries_count),
             object_store: this.object_store.as_ref().map(ProtoRepr::build),
+            creation_interval_sec: this.creation_interval_sec,
         }
     }
 }
@@ -6,7 +6,12 @@ use zksync_protobuf::{
     ProtoRepr,
 };
 
-use crate::proto;
+use crate::{
+    diff::{ApplyMode, ConfigDiff},
+    interpolation::interpolate_vars,
+    proto,
+    schema::{json_schema, ConfigFile},
+};
 
 /// Tests config <-> proto (boilerplate) conversions.
 #[test]
@@ -42,6 +47,10 @@ fn test_encoding() {
     test_encode_all_formats::<ReprConv<proto::prover::ProofDataHandler>>(rng);
     test_encode_all_formats::<ReprConv<proto::snapshot_creator::SnapshotsCreator>>(rng);
     test_encode_all_formats::<ReprConv<proto::observability::Observability>>(rng);
+    test_encode_all_formats::<ReprConv<proto::da_dispatcher::DataAvailabilityDispatcher>>(rng);
+    test_encode_all_formats::<ReprConv<proto::da_client::DataAvailabilityClient>>(rng);
+    test_encode_all_formats::<ReprConv<proto::base_token_price::BaseTokenPrice>>(rng);
+    test_encode_all_formats::<ReprConv<proto::commitment_generator::CommitmentGenerator>>(rng);
 }
 
 pub fn decode_yaml_repr<T: ProtoRepr>(
@@ -66,3 +75,203 @@ fn verify_file_parsing() {
         .unwrap();
     decode_yaml_repr::<proto::secrets::Secrets>(&base_path.join("secrets.yaml"), true).unwrap();
 }
+
+#[test]
+fn generating_json_schemas() {
+    for file in ConfigFile::ALL {
+        let schema = json_schema(file).unwrap();
+        assert_eq!(file.to_string().parse::<ConfigFile>().unwrap(), file);
+        assert!(
+            schema["definitions"].as_object().unwrap().len() > 1,
+            "{schema}"
+        );
+    }
+
+    let schema = json_schema(ConfigFile::General).unwrap();
+    let web3_rpc = &schema["definitions"]["zksync.config.api.Web3JsonRpc"];
+    assert_eq!(web3_rpc["additionalProperties"], false);
+    assert!(web3_rpc["required"]
+        .as_array()
+        .unwrap()
+        .contains(&"http_port".into()));
+    assert_eq!(web3_rpc["properties"]["http_port"]["maximum"], 65_535);
+    assert_eq!(
+        web3_rpc["properties"]["request_timeout"]["x-unit"],
+        "seconds"
+    );
+    assert_eq!(
+        web3_rpc["properties"]["evm_emulator_enabled"]["default"],
+        false
+    );
+
+    let object_store = &schema["definitions"]["zksync.config.object_store.ObjectStore"];
+    let variants = object_store["oneOf"].as_array().unwrap();
+    assert_eq!(variants.len(), 4);
+
+    let schema = json_schema(ConfigFile::Genesis).unwrap();
+    let genesis = &schema["definitions"]["zksync.config.genesis.Genesis"];
+    let mode = &genesis["properties"]["l1_batch_commit_data_generator_mode"];
+    assert_eq!(mode["default"], "Rollup");
+    assert_eq!(
+        mode["allOf"][0]["$ref"],
+        "#/definitions/zksync.config.genesis.L1BatchCommitDataGeneratorMode"
+    );
+}
+
+/// Checks that all keys in the YAML value are described by the schema.
+fn assert_keys_in_schema(
+    definitions: &serde_json::Value,
+    schema: &serde_json::Value,
+    value: &serde_yaml::Value,
+    path: &str,
+) {
+    let schema = if let Some(reference) = schema["$ref"]
+        .as_str()
+        .or(schema["allOf"][0]["$ref"].as_str())
+    {
+        let name = reference.strip_prefix("#/definitions/").unwrap();
+        &definitions[name]
+    } else {
+        schema
+    };
+    match value {
+        serde_yaml::Value::Mapping(map) => {
+            for (key, value) in map {
+                let key = key.as_str().unwrap();
+                let field_schema = &schema["properties"][key];
+                assert!(!field_schema.is_null(), "unknown key `{path}.{key}`");
+                assert_keys_in_schema(definitions, field_schema, value, &format!("{path}.{key}"));
+            }
+        }
+        serde_yaml::Value::Sequence(items) => {
+            for item in items {
+                assert_keys_in_schema(definitions, &schema["items"], item, path);
+            }
+        }
+        _ => { /* scalar value */ }
+    }
+}
+
+#[test]
+fn json_schemas_describe_file_based_configs() {
+    let base_path = PathBuf::from_str("../../../etc/env/file_based/").unwrap();
+    let files = [
+        (ConfigFile::General, "general.yaml"),
+        (ConfigFile::Genesis, "genesis.yaml"),
+        (ConfigFile::Contracts, "contracts.yaml"),
+        (ConfigFile::Secrets, "secrets.yaml"),
+    ];
+    for (file, file_name) in files {
+        let schema = json_schema(file).unwrap();
+        let yaml = std::fs::read_to_string(base_path.join(file_name)).unwrap();
+        let yaml: serde_yaml::Value = serde_yaml::from_str(&yaml).unwrap();
+        assert_keys_in_schema(&schema["definitions"], &schema, &yaml, file_name);
+    }
+}
+
+#[test]
+fn diffing_general_configs() {
+    let path = PathBuf::from_str("../../../etc/env/file_based/general.yaml").unwrap();
+    let yaml = std::fs::read_to_string(&path).unwrap();
+    let current = decode_yaml_repr::<proto::general::GeneralConfig>(&path, true).unwrap();
+
+    let diff = ConfigDiff::new(&current, &yaml, true).unwrap();
+    assert!(diff.changes.is_empty(), "{diff:?}");
+    assert!(!diff.requires_restart);
+
+    let fee_change = yaml.replace(
+        "minimal_l2_gas_price: 100000000",
+        "minimal_l2_gas_price: 200000000",
+    );
+    let diff = ConfigDiff::new(&current, &fee_change, true).unwrap();
+    assert_eq!(diff.changes.len(), 1, "{diff:?}");
+    assert_eq!(diff.changes[0].path, "state_keeper.minimal_l2_gas_price");
+    assert_eq!(diff.changes[0].apply, ApplyMode::HotReload);
+    assert!(!diff.requires_restart);
+    // Without hot reload, the change requires a restart.
+    let diff = ConfigDiff::new(&current, &fee_change, false).unwrap();
+    assert_eq!(diff.changes[0].apply, ApplyMode::Restart);
+    assert!(diff.requires_restart);
+
+    let port_change = fee_change.replace("http_port: 3050", "http_port: 3060");
+    let diff = ConfigDiff::new(&current, &port_change, true).unwrap();
+    let changed_paths: Vec<_> = diff
+        .changes
+        .iter()
+        .map(|change| change.path.as_str())
+        .collect();
+    assert_eq!(
+        changed_paths,
+        [
+            "api.web3_json_rpc.http_port",
+            "state_keeper.minimal_l2_gas_price"
+        ]
+    );
+    let port_change = &diff.changes[0];
+    assert_eq!(port_change.current, Some(3050.into()));
+    assert_eq!(port_change.candidate, Some(3060.into()));
+    assert_eq!(port_change.apply, ApplyMode::Restart);
+    assert!(diff.requires_restart);
+}
+
+#[test]
+fn interpolating_env_vars() {
+    let vars = |name: &str| match name {
+        "HTTP_PORT" => Some("3060".to_owned()),
+        "DB_HOST" => Some("db.internal".to_owned()),
+        "EMPTY" => Some(String::new()),
+        _ => None,
+    };
+    let yaml = r#"
+        # Comments are not interpolated: ${UNSET}
+        api:
+          web3_json_rpc:
+            http_port: ${HTTP_PORT}
+            ws_port: "${WS_PORT:-3061}"
+            http_url: http://${API_HOST:-127.0.0.1}:${HTTP_PORT}
+          filters_disabled: ${FILTERS_DISABLED:-false}
+        postgres:
+          server_url: 'postgres://postgres@${DB_HOST}/zksync'
+          pool_size: ${EMPTY:-50}
+        da_client:
+          header: $${NOT_A_VAR}
+        contracts:
+          diamond_proxy_addr: 0x0000000000000000000000000000000000000001
+        items: ["${HTTP_PORT}", "${DB_HOST}"]
+    "#;
+    let interpolated = interpolate_vars(yaml, vars).unwrap();
+    let value: serde_yaml::Value = serde_yaml::from_str(&interpolated).unwrap();
+    let api = &value["api"]["web3_json_rpc"];
+    assert_eq!(api["http_port"].as_u64(), Some(3060));
+    assert_eq!(api["ws_port"].as_u64(), Some(3061));
+    assert_eq!(api["http_url"].as_str(), Some("http://127.0.0.1:3060"));
+    assert_eq!(value["api"]["filters_disabled"].as_bool(), Some(false));
+    assert_eq!(
+        value["postgres"]["server_url"].as_str(),
+        Some("postgres://postgres@db.internal/zksync")
+    );
+    assert_eq!(value["postgres"]["pool_size"].as_u64(), Some(50));
+    assert_eq!(value["da_client"]["header"].as_str(), Some("${NOT_A_VAR}"));
+    assert_eq!(value["items"][0].as_u64(), Some(3060));
+    assert_eq!(value["items"][1].as_str(), Some("db.internal"));
+    // Values without interpolation must retain their representation.
+    assert!(
+        interpolated.contains("diamond_proxy_addr: 0x0000000000000000000000000000000000000001"),
+        "{interpolated}"
+    );
+    assert!(interpolated.contains("${UNSET}"), "{interpolated}");
+
+    let err = interpolate_vars("postgres:\n  server_url: ${DB_URL}", vars).unwrap_err();
+    let err = format!("{err:#}");
+    assert!(err.contains("postgres.server_url"), "{err}");
+    assert!(err.contains("`DB_URL` is not set"), "{err}");
+
+    let err = interpolate_vars("url: ${EMPTY:?must be set for mainnet}", vars).unwrap_err();
+    assert!(
+        format!("{err:#}").contains("must be set for mainnet"),
+        "{err:#}"
+    );
+    for invalid_yaml in ["url: ${1VAR}", "url: ${VAR", "url: ${VAR:=default}"] {
+        interpolate_vars(invalid_yaml, vars).unwrap_err();
+    }
+}
@@ -15,6 +15,9 @@ impl ProtoRepr for proto::Prometheus {
                 .context("pushgateway_url")?
                 .clone(),
             push_interval_ms: self.push_interval_ms,
+            push_gateway_enabled: self.push_gateway_enabled.unwrap_or(false),
+            push_job_name: self.push_job_name.clone(),
+            push_instance: self.push_instance.clone(),
         })
     }
 
@@ -23,6 +26,9 @@ impl ProtoRepr for proto::Prometheus {
             listener_port: Some(this.listener_port.into()),
             pushgateway_url: Some(this.pushgateway_url.clone()),
             push_interval_ms: this.push_interval_ms,
+            push_gateway_enabled: Some(this.push_gateway_enabled),
+            push_job_name: this.push_job_name.clone(),
+            push_instance: this.push_instance.clone(),
         }
     }
 }
@@ -26,3 +26,53 @@ impl ProtoRepr for proto::ProtectiveReadsWriter {
         }
     }
 }
+
+impl ProtoRepr for proto::BasicWitnessInputProducer {
+    type Type = configs::BasicWitnessInputProducerConfig;
+
+    fn read(&self) -> anyhow::Result<Self::Type> {
+        Ok(Self::Type {
+            db_path: required(&self.db_path).context("db_path")?.clone(),
+            window_size: *required(&self.window_size).context("window_size")? as u32,
+            first_processed_batch: L1BatchNumber(
+                *required(&self.first_processed_batch).context("first_batch")? as u32,
+            ),
+        })
+    }
+
+    fn build(this: &Self::Type) -> Self {
+        Self {
+            db_path: Some(this.db_path.clone()),
+            window_size: Some(this.window_size as u64),
+            first_processed_batch: Some(this.first_processed_batch.0 as u64),
+        }
+    }
+}
+
+impl ProtoRepr for proto::VmPlayground {
+    type Type = configs::VmPlaygroundConfig;
+
+    fn read(&self) -> anyhow::Result<Self::Type> {
+        Ok(Self::Type {
+            db_path: required(&self.db_path).context("db_path")?.clone(),
+            window_size: *required(&self.window_size).context("window_size")? as u32,
+            first_processed_batch: L1BatchNumber(
+                *required(&self.first_processed_batch).context("first_batch")? as u32,
+            ),
+            vm_protocol_version: self
+                .vm_protocol_version
+                .map(u16::try_from)
+                .transpose()
+                .context("vm_protocol_version")?,
+        })
+    }
+
+    fn build(this: &Self::Type) -> Self {
+        Self {
+            db_path: Some(this.db_path.clone()),
+            window_size: Some(this.window_size as u64),
+            first_processed_batch: Some(this.first_processed_batch.0 as u64),
+            vm_protocol_version: this.vm_protocol_version.map(u32::from),
+        }
+    }
+}
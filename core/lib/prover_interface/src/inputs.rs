@@ -1,9 +1,9 @@
-use std::{convert::TryInto, fmt::Debug};
+use std::{collections::HashMap, convert::TryInto, fmt::Debug};
 
 use serde::{Deserialize, Serialize};
 use serde_with::{serde_as, Bytes};
 use zksync_object_store::{serialize_using_bincode, Bucket, StoredObject};
-use zksync_types::{L1BatchNumber, H256, U256};
+use zksync_types::{L1BatchNumber, ProtocolVersionId, H256, U256};
 
 const HASH_LEN: usize = H256::len_bytes();
 
@@ -144,6 +144,40 @@ pub struct BasicCircuitWitnessGeneratorInput {
     pub merkle_paths_input: PrepareBasicCircuitsJob,
 }
 
+/// Data produced by re-executing an L1 batch in the VM that is required to generate basic circuits for the batch.
+/// Produced by the basic witness input producer VM runner, which can run both on the main node and on external nodes.
+///
+/// # Stability
+///
+/// This type is serialized using `bincode`; changes in its `serde` serialization must be backwards-compatible.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VMRunWitnessInputData {
+    pub l1_batch_number: L1BatchNumber,
+    /// Bytecodes of contracts used during batch execution, keyed by the bytecode hash.
+    pub used_bytecodes: HashMap<U256, Vec<[u8; 32]>>,
+    /// Initial content of the bootloader heap.
+    pub initial_heap_content: Vec<(usize, U256)>,
+    pub protocol_version: ProtocolVersionId,
+    /// Bootloader bytecode split into 32-byte words.
+    pub bootloader_code: Vec<[u8; 32]>,
+    pub default_account_code_hash: U256,
+    /// Refunds returned by the storage oracle.
+    pub storage_refunds: Vec<u32>,
+    /// Pubdata costs returned by the storage oracle.
+    pub pubdata_costs: Vec<i32>,
+}
+
+impl StoredObject for VMRunWitnessInputData {
+    const BUCKET: Bucket = Bucket::WitnessInput;
+    type Key<'a> = L1BatchNumber;
+
+    fn encode_key(key: Self::Key<'_>) -> String {
+        format!("vm_run_data_{key}.bin")
+    }
+
+    serialize_using_bincode!();
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
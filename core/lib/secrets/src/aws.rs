@@ -0,0 +1,258 @@
+//! AWS Secrets Manager backend.
+
+use anyhow::Context as _;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use secrecy::{ExposeSecret, Secret};
+use sha2::{Digest, Sha256};
+
+use crate::backend::SecretsBackend;
+
+const SERVICE: &str = "secretsmanager";
+
+/// AWS credentials used to sign requests.
+#[derive(Debug)]
+pub(crate) struct AwsCredentials {
+    pub access_key_id: String,
+    pub secret_access_key: Secret<String>,
+    pub session_token: Option<Secret<String>>,
+}
+
+impl AwsCredentials {
+    /// Reads credentials from the standard `AWS_ACCESS_KEY_ID`, `AWS_SECRET_ACCESS_KEY` and `AWS_SESSION_TOKEN`
+    /// env variables.
+    pub fn from_env() -> anyhow::Result<Self> {
+        Ok(Self {
+            access_key_id: std::env::var("AWS_ACCESS_KEY_ID")
+                .context("AWS_ACCESS_KEY_ID env variable is not set")?,
+            secret_access_key: std::env::var("AWS_SECRET_ACCESS_KEY")
+                .map(Secret::new)
+                .context("AWS_SECRET_ACCESS_KEY env variable is not set")?,
+            session_token: std::env::var("AWS_SESSION_TOKEN").ok().map(Secret::new),
+        })
+    }
+}
+
+/// Backend reading secrets using the `GetSecretValue` action of the AWS Secrets Manager API.
+#[derive(Debug)]
+pub(crate) struct AwsSecretsManagerBackend {
+    client: reqwest::Client,
+    default_region: Option<String>,
+    credentials: AwsCredentials,
+}
+
+impl AwsSecretsManagerBackend {
+    pub fn new(default_region: Option<String>, credentials: AwsCredentials) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            default_region,
+            credentials,
+        }
+    }
+
+    /// Returns the region of the secret, which is taken from the secret ARN if it's specified.
+    fn region<'a>(&'a self, secret_id: &'a str) -> anyhow::Result<&'a str> {
+        if let Some(arn) = secret_id.strip_prefix("arn:") {
+            // ARN format is `arn:{partition}:secretsmanager:{region}:{account}:secret:{name}`
+            let region = arn.split(':').nth(2).filter(|region| !region.is_empty());
+            return region.with_context(|| format!("secret ARN `{secret_id}` has no region"));
+        }
+        self.default_region
+            .as_deref()
+            .context("AWS region is not specified; set AWS_REGION env variable or use secret ARN")
+    }
+}
+
+#[async_trait]
+impl SecretsBackend for AwsSecretsManagerBackend {
+    async fn fetch(&self, secret_id: &str) -> anyhow::Result<Secret<String>> {
+        let region = self.region(secret_id)?;
+        let host = format!("{SERVICE}.{region}.amazonaws.com");
+        let body = serde_json::json!({ "SecretId": secret_id }).to_string();
+        let request = SignedRequest::new(&host, region, &body, &self.credentials, Utc::now());
+
+        let mut builder = self
+            .client
+            .post(format!("https://{host}/"))
+            .header("content-type", SignedRequest::CONTENT_TYPE)
+            .header("x-amz-date", &request.amz_date)
+            .header("x-amz-target", SignedRequest::TARGET)
+            .header("authorization", request.authorization);
+        if let Some(token) = &self.credentials.session_token {
+            builder = builder.header("x-amz-security-token", token.expose_secret());
+        }
+        let response = builder
+            .body(body)
+            .send()
+            .await
+            .with_context(|| format!("failed requesting AWS secret `{secret_id}`"))?
+            .error_for_status()
+            .with_context(|| {
+                format!("AWS Secrets Manager returned error for secret `{secret_id}`")
+            })?;
+        let response: serde_json::Value = response.json().await.ok().with_context(|| {
+            format!("AWS Secrets Manager returned malformed response for secret `{secret_id}`")
+        })?;
+        let secret = response
+            .get("SecretString")
+            .and_then(serde_json::Value::as_str)
+            .with_context(|| format!("AWS secret `{secret_id}` is not a string secret"))?;
+        Ok(Secret::new(secret.to_owned()))
+    }
+}
+
+/// `GetSecretValue` request signed with AWS Signature Version 4.
+#[derive(Debug)]
+struct SignedRequest {
+    amz_date: String,
+    authorization: String,
+}
+
+impl SignedRequest {
+    const CONTENT_TYPE: &'static str = "application/x-amz-json-1.1";
+    const TARGET: &'static str = "secretsmanager.GetSecretValue";
+
+    fn new(
+        host: &str,
+        region: &str,
+        body: &str,
+        credentials: &AwsCredentials,
+        now: DateTime<Utc>,
+    ) -> Self {
+        let amz_date = now.format("%Y%m%dT%H%M%SZ").to_string();
+        let date = now.format("%Y%m%d").to_string();
+
+        // Headers must be sorted by name.
+        let mut headers = vec![
+            ("content-type", Self::CONTENT_TYPE),
+            ("host", host),
+            ("x-amz-date", &amz_date),
+        ];
+        if let Some(token) = &credentials.session_token {
+            headers.push(("x-amz-security-token", token.expose_secret()));
+        }
+        headers.push(("x-amz-target", Self::TARGET));
+
+        let canonical_headers: String = headers
+            .iter()
+            .map(|(name, value)| format!("{name}:{value}\n"))
+            .collect();
+        let signed_headers = headers
+            .iter()
+            .map(|(name, _)| *name)
+            .collect::<Vec<_>>()
+            .join(";");
+        let canonical_request = format!(
+            "POST\n/\n\n{canonical_headers}\n{signed_headers}\n{}",
+            hex::encode(Sha256::digest(body))
+        );
+
+        let scope = format!("{date}/{region}/{SERVICE}/aws4_request");
+        let string_to_sign = format!(
+            "AWS4-HMAC-SHA256\n{amz_date}\n{scope}\n{}",
+            hex::encode(Sha256::digest(&canonical_request))
+        );
+        let signing_key = derive_signing_key(
+            credentials.secret_access_key.expose_secret(),
+            &date,
+            region,
+            SERVICE,
+        );
+        let signature = hex::encode(hmac_sha256(&signing_key, string_to_sign.as_bytes()));
+        let authorization = format!(
+            "AWS4-HMAC-SHA256 Credential={}/{scope}, SignedHeaders={signed_headers}, Signature={signature}",
+            credentials.access_key_id
+        );
+        Self {
+            amz_date,
+            authorization,
+        }
+    }
+}
+
+fn hmac_sha256(key: &[u8], data: &[u8]) -> [u8; 32] {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts keys of any size");
+    mac.update(data);
+    mac.finalize().into_bytes().into()
+}
+
+fn derive_signing_key(
+    secret_access_key: &str,
+    date: &str,
+    region: &str,
+    service: &str,
+) -> [u8; 32] {
+    let key = hmac_sha256(
+        format!("AWS4{secret_access_key}").as_bytes(),
+        date.as_bytes(),
+    );
+    let key = hmac_sha256(&key, region.as_bytes());
+    let key = hmac_sha256(&key, service.as_bytes());
+    hmac_sha256(&key, b"aws4_request")
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    #[test]
+    fn deriving_signing_key() {
+        // Example from the AWS documentation.
+        let key = derive_signing_key(
+            "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY",
+            "20120215",
+            "us-east-1",
+            "iam",
+        );
+        assert_eq!(
+            hex::encode(key),
+            "f4780e2d9f65fa895f9c67b32ce1baf0b0d8a43505a000a1a9e090d414db404d"
+        );
+    }
+
+    #[test]
+    fn signing_request() {
+        let credentials = AwsCredentials {
+            access_key_id: "AKIDEXAMPLE".to_owned(),
+            secret_access_key: Secret::new("wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY".to_owned()),
+            session_token: None,
+        };
+        let now = Utc.with_ymd_and_hms(2024, 5, 1, 12, 30, 0).unwrap();
+        let request = SignedRequest::new(
+            "secretsmanager.us-east-1.amazonaws.com",
+            "us-east-1",
+            r#"{"SecretId":"zksync"}"#,
+            &credentials,
+            now,
+        );
+        assert_eq!(request.amz_date, "20240501T123000Z");
+        assert_eq!(
+            request.authorization,
+            "AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/20240501/us-east-1/secretsmanager/aws4_request, \
+             SignedHeaders=content-type;host;x-amz-date;x-amz-target, \
+             Signature=b83fc8c3d035f100b49d17e55c52741d8bbd44bb15d60c8eb51ff2b847679fd7"
+        );
+    }
+
+    #[test]
+    fn getting_region_from_arn() {
+        let backend = AwsSecretsManagerBackend::new(
+            Some("us-east-1".to_owned()),
+            AwsCredentials {
+                access_key_id: String::new(),
+                secret_access_key: Secret::new(String::new()),
+                session_token: None,
+            },
+        );
+        assert_eq!(backend.region("zksync/database").unwrap(), "us-east-1");
+        assert_eq!(
+            backend
+                .region("arn:aws:secretsmanager:eu-west-2:123456789012:secret:zksync-AbCdEf")
+                .unwrap(),
+            "eu-west-2"
+        );
+    }
+}
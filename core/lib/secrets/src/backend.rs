@@ -0,0 +1,37 @@
+//! Abstraction over secret managers.
+
+use std::fmt;
+
+use anyhow::Context as _;
+use async_trait::async_trait;
+use secrecy::{ExposeSecret, Secret};
+
+/// Secret manager able to fetch secrets by their path.
+#[async_trait]
+pub(crate) trait SecretsBackend: fmt::Debug + Send + Sync {
+    /// Fetches the secret at the specified path. For secret managers storing key–value maps (e.g., Vault),
+    /// the map should be returned serialized as a JSON object.
+    async fn fetch(&self, path: &str) -> anyhow::Result<Secret<String>>;
+}
+
+/// Extracts a field from a secret serialized as a JSON object.
+pub(crate) fn extract_field(
+    secret: &Secret<String>,
+    field: &str,
+) -> anyhow::Result<Secret<String>> {
+    // Errors are not propagated as is since they may contain parts of the secret.
+    let object: serde_json::Map<String, serde_json::Value> =
+        serde_json::from_str(secret.expose_secret())
+            .ok()
+            .context("secret is not a JSON object")?;
+    let value = object
+        .get(field)
+        .with_context(|| format!("secret doesn't have field `{field}`"))?;
+    let value = match value {
+        serde_json::Value::String(value) => value.clone(),
+        serde_json::Value::Number(value) => value.to_string(),
+        serde_json::Value::Bool(value) => value.to_string(),
+        _ => anyhow::bail!("secret field `{field}` is not a scalar value"),
+    };
+    Ok(Secret::new(value))
+}
@@ -0,0 +1,63 @@
+//! Utility to create and inspect encrypted secrets files.
+
+use std::path::PathBuf;
+
+use anyhow::Context as _;
+use clap::{Parser, Subcommand};
+use secrecy::ExposeSecret as _;
+use zksync_secrets::{decrypt_secrets_file, encrypt_secrets_file, SecretsFileKey};
+
+#[derive(Debug, Parser)]
+#[command(author = "Matter Labs", version, about = "Encrypted secrets file utility", long_about = None)]
+struct Cli {
+    /// Keyfile used to encrypt / decrypt the secrets file. If not specified, the passphrase is prompted.
+    #[arg(long, global = true)]
+    keyfile: Option<PathBuf>,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Encrypts a YAML or JSON map of secrets.
+    Encrypt {
+        /// Path to the plaintext secrets.
+        #[arg(long)]
+        input: PathBuf,
+        /// Path to write the encrypted secrets file to.
+        #[arg(long)]
+        output: PathBuf,
+    },
+    /// Decrypts an encrypted secrets file and prints the plaintext secrets to stdout.
+    Decrypt {
+        /// Path to the encrypted secrets file.
+        #[arg(long)]
+        input: PathBuf,
+    },
+}
+
+fn main() -> anyhow::Result<()> {
+    let cli = Cli::parse();
+    let is_encrypting = matches!(cli.command, Command::Encrypt { .. });
+    let key = match cli.keyfile {
+        Some(keyfile) => SecretsFileKey::Keyfile(keyfile),
+        None => SecretsFileKey::prompt("Passphrase", is_encrypting)?,
+    };
+
+    match cli.command {
+        Command::Encrypt { input, output } => {
+            let plaintext = std::fs::read_to_string(&input)
+                .with_context(|| format!("failed reading {input:?}"))?;
+            let contents = encrypt_secrets_file(&plaintext, &key)?;
+            std::fs::write(&output, contents)
+                .with_context(|| format!("failed writing {output:?}"))?;
+        }
+        Command::Decrypt { input } => {
+            let contents = std::fs::read_to_string(&input)
+                .with_context(|| format!("failed reading {input:?}"))?;
+            let plaintext = decrypt_secrets_file(&contents, &key)?;
+            print!("{}", plaintext.expose_secret());
+        }
+    }
+    Ok(())
+}
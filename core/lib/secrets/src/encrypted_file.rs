@@ -0,0 +1,225 @@
+//! Local secrets file encrypted with a keyfile or a passphrase.
+//!
+//! The file is a JSON envelope containing a YAML (or JSON) map of named secrets encrypted with XChaCha20-Poly1305.
+//! The encryption key is derived using PBKDF2-HMAC-SHA256 from either a passphrase, or the contents of a keyfile
+//! (with trailing whitespace trimmed, so that a keyfile can be generated with e.g. `openssl rand -hex 32`).
+
+use std::{
+    collections::HashMap,
+    io::IsTerminal as _,
+    path::{Path, PathBuf},
+};
+
+use anyhow::Context as _;
+use async_trait::async_trait;
+use chacha20poly1305::{
+    aead::{Aead as _, KeyInit as _},
+    Key, XChaCha20Poly1305, XNonce,
+};
+use rand::Rng as _;
+use secrecy::{ExposeSecret as _, Secret};
+use serde::{Deserialize, Serialize};
+
+use crate::backend::SecretsBackend;
+
+const VERSION: u32 = 1;
+const KDF_ROUNDS: u32 = 600_000;
+
+/// Key unlocking an encrypted secrets file.
+#[derive(Debug, Clone)]
+pub enum SecretsFileKey {
+    /// Path to the keyfile.
+    Keyfile(PathBuf),
+    /// Passphrase.
+    Passphrase(Secret<String>),
+}
+
+impl SecretsFileKey {
+    /// Prompts the passphrase on the terminal. If `confirm` is set, the passphrase is asked twice.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if stdin is not a terminal or the passphrase cannot be read.
+    pub fn prompt(prompt: &str, confirm: bool) -> anyhow::Result<Self> {
+        anyhow::ensure!(
+            std::io::stdin().is_terminal(),
+            "cannot prompt passphrase since stdin is not a terminal"
+        );
+        let passphrase = Secret::new(
+            rpassword::prompt_password(format!("{prompt}: "))
+                .context("failed reading passphrase")?,
+        );
+        if confirm {
+            let confirmation = Secret::new(
+                rpassword::prompt_password("Confirm passphrase: ")
+                    .context("failed reading passphrase")?,
+            );
+            anyhow::ensure!(
+                passphrase.expose_secret() == confirmation.expose_secret(),
+                "passphrases do not match"
+            );
+        }
+        anyhow::ensure!(
+            !passphrase.expose_secret().is_empty(),
+            "passphrase is empty"
+        );
+        Ok(Self::Passphrase(passphrase))
+    }
+
+    fn material(&self) -> anyhow::Result<Secret<Vec<u8>>> {
+        match self {
+            Self::Keyfile(path) => {
+                let mut contents = std::fs::read(path)
+                    .with_context(|| format!("failed reading keyfile {path:?}"))?;
+                let trimmed_len = contents
+                    .iter()
+                    .rposition(|byte| !byte.is_ascii_whitespace())
+                    .map_or(0, |pos| pos + 1);
+                contents.truncate(trimmed_len);
+                let contents = Secret::new(contents);
+                anyhow::ensure!(
+                    !contents.expose_secret().is_empty(),
+                    "keyfile {path:?} is empty"
+                );
+                Ok(contents)
+            }
+            Self::Passphrase(passphrase) => {
+                Ok(Secret::new(passphrase.expose_secret().as_bytes().to_vec()))
+            }
+        }
+    }
+
+    fn cipher(&self, salt: &[u8], rounds: u32) -> anyhow::Result<XChaCha20Poly1305> {
+        let mut key = Key::default();
+        pbkdf2::pbkdf2_hmac::<sha2::Sha256>(
+            self.material()?.expose_secret(),
+            salt,
+            rounds,
+            &mut key,
+        );
+        Ok(XChaCha20Poly1305::new(&key))
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct EncryptedFile {
+    version: u32,
+    kdf_rounds: u32,
+    /// Hex-encoded.
+    salt: String,
+    /// Hex-encoded.
+    nonce: String,
+    /// Hex-encoded.
+    ciphertext: String,
+}
+
+type Entries = serde_json::Map<String, serde_json::Value>;
+
+fn parse_entries(plaintext: &str) -> anyhow::Result<Entries> {
+    // Errors are not propagated as is since they may contain parts of the secrets.
+    serde_yaml::from_str(plaintext)
+        .ok()
+        .context("secrets must be a YAML or JSON map")
+}
+
+/// Encrypts secrets with the specified key, returning the encrypted file contents. `plaintext` must be a YAML
+/// or JSON map from secret names to secrets; a secret can be a scalar or a map of fields.
+///
+/// # Errors
+///
+/// Returns an error if `plaintext` is malformed or the keyfile cannot be read.
+pub fn encrypt_secrets_file(plaintext: &str, key: &SecretsFileKey) -> anyhow::Result<String> {
+    encrypt_with_rounds(plaintext, key, KDF_ROUNDS)
+}
+
+pub(crate) fn encrypt_with_rounds(
+    plaintext: &str,
+    key: &SecretsFileKey,
+    kdf_rounds: u32,
+) -> anyhow::Result<String> {
+    parse_entries(plaintext)?;
+    let rng = &mut rand::thread_rng();
+    let salt: [u8; 32] = rng.gen();
+    let nonce: [u8; 24] = rng.gen();
+    let ciphertext = key
+        .cipher(&salt, kdf_rounds)?
+        .encrypt(XNonce::from_slice(&nonce), plaintext.as_bytes())
+        .map_err(|_| anyhow::format_err!("encryption failed"))?;
+    let file = EncryptedFile {
+        version: VERSION,
+        kdf_rounds,
+        salt: hex::encode(salt),
+        nonce: hex::encode(nonce),
+        ciphertext: hex::encode(ciphertext),
+    };
+    serde_json::to_string_pretty(&file).context("failed serializing encrypted file")
+}
+
+/// Decrypts the encrypted secrets file contents, returning the plaintext secrets.
+///
+/// # Errors
+///
+/// Returns an error if the file is malformed or cannot be decrypted with the specified key.
+pub fn decrypt_secrets_file(
+    contents: &str,
+    key: &SecretsFileKey,
+) -> anyhow::Result<Secret<String>> {
+    let file: EncryptedFile =
+        serde_json::from_str(contents).context("failed parsing encrypted file")?;
+    anyhow::ensure!(
+        file.version == VERSION,
+        "unsupported encrypted file version {}",
+        file.version
+    );
+    let salt = hex::decode(&file.salt).context("salt")?;
+    let nonce = hex::decode(&file.nonce).context("nonce")?;
+    anyhow::ensure!(nonce.len() == 24, "invalid nonce length");
+    anyhow::ensure!(file.kdf_rounds > 0, "invalid kdf_rounds");
+    let ciphertext = hex::decode(&file.ciphertext).context("ciphertext")?;
+    let plaintext = key
+        .cipher(&salt, file.kdf_rounds)?
+        .decrypt(XNonce::from_slice(&nonce), ciphertext.as_slice())
+        .map_err(|_| anyhow::format_err!("decryption failed, wrong keyfile or passphrase?"))?;
+    let plaintext = String::from_utf8(plaintext)
+        .ok()
+        .context("decrypted secrets are not UTF-8")?;
+    Ok(Secret::new(plaintext))
+}
+
+/// Backend serving secrets from an encrypted file. The file is decrypted once when the backend is created.
+#[derive(Debug)]
+pub(crate) struct EncryptedFileBackend {
+    secrets: HashMap<String, Secret<String>>,
+}
+
+impl EncryptedFileBackend {
+    pub fn new(path: &Path, key: &SecretsFileKey) -> anyhow::Result<Self> {
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("failed reading encrypted secrets file {path:?}"))?;
+        let plaintext = decrypt_secrets_file(&contents, key)
+            .with_context(|| format!("failed decrypting secrets file {path:?}"))?;
+        let entries = parse_entries(plaintext.expose_secret())?;
+        let secrets = entries
+            .into_iter()
+            .map(|(name, value)| {
+                // Maps are serialized as JSON objects, so that their fields can be referenced.
+                let value = match value {
+                    serde_json::Value::String(value) => value,
+                    value => value.to_string(),
+                };
+                (name, Secret::new(value))
+            })
+            .collect();
+        Ok(Self { secrets })
+    }
+}
+
+#[async_trait]
+impl SecretsBackend for EncryptedFileBackend {
+    async fn fetch(&self, path: &str) -> anyhow::Result<Secret<String>> {
+        self.secrets
+            .get(path)
+            .cloned()
+            .with_context(|| format!("encrypted secrets file doesn't contain secret `{path}`"))
+    }
+}
@@ -0,0 +1,85 @@
+//! GCP Secret Manager backend.
+
+use std::fmt;
+
+use anyhow::Context as _;
+use async_trait::async_trait;
+use base64::Engine as _;
+use google_cloud_auth::{
+    project::{create_token_source, Config},
+    token_source::TokenSource,
+};
+use secrecy::Secret;
+
+use crate::backend::SecretsBackend;
+
+const SCOPES: [&str; 1] = ["https://www.googleapis.com/auth/cloud-platform"];
+
+/// Backend reading secrets using the `AccessSecretVersion` method of the GCP Secret Manager API.
+/// Uses ambient authentication (e.g., via the `GOOGLE_APPLICATION_CREDENTIALS` env variable
+/// or the metadata server if the binary runs on Google Cloud).
+pub(crate) struct GcpSecretManagerBackend {
+    client: reqwest::Client,
+    token_source: Box<dyn TokenSource>,
+}
+
+impl fmt::Debug for GcpSecretManagerBackend {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("GcpSecretManagerBackend")
+            // Skip `token_source` as its representation may contain sensitive info
+            .finish_non_exhaustive()
+    }
+}
+
+impl GcpSecretManagerBackend {
+    pub async fn new() -> anyhow::Result<Self> {
+        let config = Config {
+            scopes: Some(&SCOPES),
+            ..Config::default()
+        };
+        let token_source = create_token_source(config)
+            .await
+            .context("failed initializing GCP authentication")?;
+        Ok(Self {
+            client: reqwest::Client::new(),
+            token_source,
+        })
+    }
+}
+
+#[async_trait]
+impl SecretsBackend for GcpSecretManagerBackend {
+    async fn fetch(&self, path: &str) -> anyhow::Result<Secret<String>> {
+        let token = self
+            .token_source
+            .token()
+            .await
+            .context("failed getting GCP access token")?;
+        let url = format!("https://secretmanager.googleapis.com/v1/{path}:access");
+        let response = self
+            .client
+            .get(&url)
+            .bearer_auth(&token.access_token)
+            .send()
+            .await
+            .with_context(|| format!("failed requesting GCP secret `{path}`"))?
+            .error_for_status()
+            .with_context(|| format!("GCP Secret Manager returned error for secret `{path}`"))?;
+        let response: serde_json::Value = response.json().await.ok().with_context(|| {
+            format!("GCP Secret Manager returned malformed response for secret `{path}`")
+        })?;
+        let data = response
+            .pointer("/payload/data")
+            .and_then(serde_json::Value::as_str)
+            .with_context(|| format!("GCP response for secret `{path}` doesn't contain payload"))?;
+        let data = base64::engine::general_purpose::STANDARD
+            .decode(data)
+            .ok()
+            .with_context(|| format!("GCP secret `{path}` has invalid base64 payload"))?;
+        let data = String::from_utf8(data)
+            .ok()
+            .with_context(|| format!("GCP secret `{path}` is not a UTF-8 string"))?;
+        Ok(Secret::new(data))
+    }
+}
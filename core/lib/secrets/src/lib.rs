@@ -0,0 +1,31 @@
+//! Resolution of secrets stored in external secret managers.
+//!
+//! Instead of specifying secrets (database URLs, L1 RPC URLs, operator and consensus keys) in plaintext,
+//! config values can contain [references](SecretRef) to secrets stored in one of the following backends:
+//!
+//! - `vault://{mount}/{path}#{field}`: field of a secret in the HashiCorp Vault KV v2 secrets engine
+//! - `aws-sm://{secret_id}[#{field}]`: AWS Secrets Manager secret (`secret_id` may be a name or an ARN)
+//! - `gcp-sm://projects/{project}/secrets/{secret}[/versions/{version}][#{field}]`: GCP Secret Manager secret
+//!   version (the latest version by default)
+//! - `encrypted-file://{name}[#{field}]`: secret from a local [encrypted file](encrypt_secrets_file()), which is
+//!   unlocked with a keyfile or a passphrase
+//!
+//! If `field` is specified for AWS, GCP or an encrypted file, the secret is parsed as a JSON object, and the value
+//! of the specified field is used. References are resolved by [`SecretsResolver`], which caches fetched secrets
+//! and can track rotation of secrets. The encrypted file is decrypted once, when the first secret from it is resolved.
+
+pub use self::{
+    encrypted_file::{decrypt_secrets_file, encrypt_secrets_file, SecretsFileKey},
+    reference::{SecretBackendKind, SecretRef},
+    resolver::{SecretsResolver, SecretsResolverConfig},
+};
+
+mod aws;
+mod backend;
+mod encrypted_file;
+mod gcp;
+mod reference;
+mod resolver;
+#[cfg(test)]
+mod tests;
+mod vault;
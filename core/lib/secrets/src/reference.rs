@@ -0,0 +1,143 @@
+//! References to secrets stored in secret managers.
+
+use std::fmt;
+
+use anyhow::Context as _;
+
+/// Kind of secret manager storing a secret.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SecretBackendKind {
+    /// HashiCorp Vault (KV v2 secrets engine).
+    Vault,
+    /// AWS Secrets Manager.
+    AwsSecretsManager,
+    /// GCP Secret Manager.
+    GcpSecretManager,
+    /// Local file encrypted with a keyfile or a passphrase.
+    EncryptedFile,
+}
+
+impl SecretBackendKind {
+    const ALL: [Self; 4] = [
+        Self::Vault,
+        Self::AwsSecretsManager,
+        Self::GcpSecretManager,
+        Self::EncryptedFile,
+    ];
+
+    fn scheme(self) -> &'static str {
+        match self {
+            Self::Vault => "vault",
+            Self::AwsSecretsManager => "aws-sm",
+            Self::GcpSecretManager => "gcp-sm",
+            Self::EncryptedFile => "encrypted-file",
+        }
+    }
+}
+
+/// Reference to a secret, e.g. `vault://secret/zksync/database#server_url`. See the crate docs for the supported formats.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SecretRef {
+    backend: SecretBackendKind,
+    path: String,
+    field: Option<String>,
+}
+
+impl fmt::Display for SecretRef {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "{}://{}", self.backend.scheme(), self.path)?;
+        if let Some(field) = &self.field {
+            write!(formatter, "#{field}")?;
+        }
+        Ok(())
+    }
+}
+
+impl SecretRef {
+    /// Parses a reference from the provided config value. Returns `Ok(None)` if the value doesn't have a scheme
+    /// of any of the supported secret managers (i.e., is a plaintext value).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value has a supported scheme, but is malformed.
+    pub fn parse(value: &str) -> anyhow::Result<Option<Self>> {
+        let Some((backend, rest)) = SecretBackendKind::ALL.into_iter().find_map(|backend| {
+            let rest = value.strip_prefix(backend.scheme())?.strip_prefix("://")?;
+            Some((backend, rest))
+        }) else {
+            return Ok(None);
+        };
+
+        let (path, field) = match rest.rsplit_once('#') {
+            Some((path, field)) => (path, Some(field)),
+            None => (rest, None),
+        };
+        anyhow::ensure!(
+            !path.is_empty(),
+            "secret reference `{value}` has empty path"
+        );
+        anyhow::ensure!(
+            field.map_or(true, |field| !field.is_empty()),
+            "secret reference `{value}` has empty field"
+        );
+
+        let path = match backend {
+            SecretBackendKind::Vault => {
+                anyhow::ensure!(
+                    field.is_some(),
+                    "Vault secret reference `{value}` must specify a field, e.g. `vault://secret/path#field`"
+                );
+                let (mount, secret_path) = path.split_once('/').with_context(|| {
+                    format!(
+                        "Vault secret reference `{value}` must have `{{mount}}/{{path}}` format"
+                    )
+                })?;
+                anyhow::ensure!(
+                    !mount.is_empty() && !secret_path.is_empty(),
+                    "Vault secret reference `{value}` must have `{{mount}}/{{path}}` format"
+                );
+                path.to_owned()
+            }
+            SecretBackendKind::AwsSecretsManager | SecretBackendKind::EncryptedFile => {
+                path.to_owned()
+            }
+            SecretBackendKind::GcpSecretManager => {
+                let segments: Vec<_> = path.split('/').collect();
+                match segments.as_slice() {
+                    ["projects", project, "secrets", secret] if !project.is_empty() && !secret.is_empty() => {
+                        format!("{path}/versions/latest")
+                    }
+                    ["projects", project, "secrets", secret, "versions", version]
+                        if !project.is_empty() && !secret.is_empty() && !version.is_empty() =>
+                    {
+                        path.to_owned()
+                    }
+                    _ => anyhow::bail!(
+                        "GCP secret reference `{value}` must have `projects/{{project}}/secrets/{{secret}}[/versions/{{version}}]` format"
+                    ),
+                }
+            }
+        };
+
+        Ok(Some(Self {
+            backend,
+            path,
+            field: field.map(str::to_owned),
+        }))
+    }
+
+    /// Returns the secret manager storing the secret.
+    pub fn backend(&self) -> SecretBackendKind {
+        self.backend
+    }
+
+    /// Returns the path to the secret in the secret manager.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Returns the field of the secret containing the value, if any.
+    pub fn field(&self) -> Option<&str> {
+        self.field.as_deref()
+    }
+}
@@ -0,0 +1,523 @@
+//! Resolver of secret references with caching and rotation tracking.
+
+use std::{
+    collections::{HashMap, HashSet},
+    path::PathBuf,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use anyhow::Context as _;
+use secrecy::{ExposeSecret, Secret};
+use tokio::sync::{watch, OnceCell};
+use zksync_basic_types::url::SensitiveUrl;
+
+use crate::{
+    aws::{AwsCredentials, AwsSecretsManagerBackend},
+    backend::{extract_field, SecretsBackend},
+    encrypted_file::{EncryptedFileBackend, SecretsFileKey},
+    gcp::GcpSecretManagerBackend,
+    reference::{SecretBackendKind, SecretRef},
+    vault::VaultBackend,
+};
+
+/// Configuration of [`SecretsResolver`].
+#[derive(Debug, Clone)]
+pub struct SecretsResolverConfig {
+    /// Vault server address, e.g. `https://vault.example.com:8200`.
+    pub vault_addr: Option<String>,
+    /// Token used to authenticate to Vault.
+    pub vault_token: Option<Secret<String>>,
+    /// Vault Enterprise namespace.
+    pub vault_namespace: Option<String>,
+    /// AWS region used for secrets not specified by ARN.
+    pub aws_region: Option<String>,
+    /// Path to the encrypted secrets file.
+    pub encrypted_file_path: Option<PathBuf>,
+    /// Key unlocking the encrypted secrets file. If not specified, the passphrase is prompted on the terminal.
+    pub encrypted_file_key: Option<SecretsFileKey>,
+    /// Time during which a fetched secret is reused. Also used as the interval between checks
+    /// of subscribed secrets for rotation unless [`Self::rotation_interval`] is set.
+    pub cache_ttl: Duration,
+    /// Interval between checks of subscribed secrets for rotation. If set, the node periodically refetches
+    /// the secrets it was started with and restarts once any of them is rotated.
+    pub rotation_interval: Option<Duration>,
+}
+
+impl SecretsResolverConfig {
+    const DEFAULT_CACHE_TTL: Duration = Duration::from_secs(300);
+
+    /// Reads the config from the standard env variables of secret managers (`VAULT_ADDR`, `VAULT_TOKEN`,
+    /// `VAULT_NAMESPACE`, `AWS_REGION` / `AWS_DEFAULT_REGION`), `SECRETS_CACHE_TTL_SEC`, `SECRETS_ROTATION_INTERVAL_SEC`,
+    /// and the encrypted secrets file
+    /// variables (`SECRETS_FILE_PATH` and either `SECRETS_FILE_KEYFILE` or `SECRETS_FILE_PASSPHRASE`).
+    /// AWS credentials are read from env variables and GCP credentials are discovered when a secret
+    /// from the corresponding manager is first requested.
+    pub fn from_env() -> anyhow::Result<Self> {
+        let cache_ttl = match std::env::var("SECRETS_CACHE_TTL_SEC") {
+            Ok(ttl) => Duration::from_secs(
+                ttl.parse()
+                    .context("SECRETS_CACHE_TTL_SEC must be a non-negative integer")?,
+            ),
+            Err(_) => Self::DEFAULT_CACHE_TTL,
+        };
+        let rotation_interval = match std::env::var("SECRETS_ROTATION_INTERVAL_SEC") {
+            Ok(interval) => {
+                let interval: u64 = interval
+                    .parse()
+                    .context("SECRETS_ROTATION_INTERVAL_SEC must be a positive integer")?;
+                anyhow::ensure!(
+                    interval > 0,
+                    "SECRETS_ROTATION_INTERVAL_SEC must be positive"
+                );
+                Some(Duration::from_secs(interval))
+            }
+            Err(_) => None,
+        };
+        let encrypted_file_key = match (
+            std::env::var_os("SECRETS_FILE_KEYFILE"),
+            std::env::var("SECRETS_FILE_PASSPHRASE"),
+        ) {
+            (Some(_), Ok(_)) => anyhow::bail!(
+                "only one of SECRETS_FILE_KEYFILE and SECRETS_FILE_PASSPHRASE may be specified"
+            ),
+            (Some(keyfile), Err(_)) => Some(SecretsFileKey::Keyfile(keyfile.into())),
+            (None, Ok(passphrase)) => Some(SecretsFileKey::Passphrase(Secret::new(passphrase))),
+            (None, Err(_)) => None,
+        };
+        Ok(Self {
+            vault_addr: std::env::var("VAULT_ADDR").ok(),
+            vault_token: std::env::var("VAULT_TOKEN").ok().map(Secret::new),
+            vault_namespace: std::env::var("VAULT_NAMESPACE").ok(),
+            aws_region: std::env::var("AWS_REGION")
+                .or_else(|_| std::env::var("AWS_DEFAULT_REGION"))
+                .ok(),
+            encrypted_file_path: std::env::var_os("SECRETS_FILE_PATH").map(PathBuf::from),
+            encrypted_file_key,
+            cache_ttl,
+            rotation_interval,
+        })
+    }
+}
+
+impl Default for SecretsResolverConfig {
+    fn default() -> Self {
+        Self {
+            vault_addr: None,
+            vault_token: None,
+            vault_namespace: None,
+            aws_region: None,
+            encrypted_file_path: None,
+            encrypted_file_key: None,
+            cache_ttl: Self::DEFAULT_CACHE_TTL,
+            rotation_interval: None,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct CachedSecret {
+    secret: Secret<String>,
+    fetched_at: Instant,
+}
+
+type SecretKey = (SecretBackendKind, String);
+
+#[derive(Debug)]
+struct Subscription {
+    reference: SecretRef,
+    sender: watch::Sender<Secret<String>>,
+}
+
+#[derive(Debug, Default)]
+struct Backends {
+    vault: OnceCell<Arc<dyn SecretsBackend>>,
+    aws: OnceCell<Arc<dyn SecretsBackend>>,
+    gcp: OnceCell<Arc<dyn SecretsBackend>>,
+    encrypted_file: OnceCell<Arc<dyn SecretsBackend>>,
+}
+
+impl Backends {
+    fn get(&self, kind: SecretBackendKind) -> &OnceCell<Arc<dyn SecretsBackend>> {
+        match kind {
+            SecretBackendKind::Vault => &self.vault,
+            SecretBackendKind::AwsSecretsManager => &self.aws,
+            SecretBackendKind::GcpSecretManager => &self.gcp,
+            SecretBackendKind::EncryptedFile => &self.encrypted_file,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Inner {
+    config: SecretsResolverConfig,
+    backends: Backends,
+    cache: tokio::sync::Mutex<HashMap<SecretKey, CachedSecret>>,
+    subscriptions: Mutex<Vec<Subscription>>,
+    resolved_references: Mutex<HashSet<SecretRef>>,
+}
+
+/// Resolver of [secret references](SecretRef) in config values.
+///
+/// Fetched secrets are cached for [`SecretsResolverConfig::cache_ttl`], so that several fields of the same secret
+/// are fetched with a single request, and a rotated secret is picked up once the cached value expires.
+/// Components that can apply rotated secrets without a restart can [subscribe](Self::subscribe()) to secret changes;
+/// subscriptions are refreshed by [`Self::run_rotation()`].
+///
+/// The resolver is cheap to clone.
+#[derive(Debug, Clone)]
+pub struct SecretsResolver {
+    inner: Arc<Inner>,
+}
+
+impl SecretsResolver {
+    /// Creates a new resolver. Secret manager clients are initialized lazily, when the first secret
+    /// from the corresponding manager is requested.
+    pub fn new(config: SecretsResolverConfig) -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                config,
+                backends: Backends::default(),
+                cache: tokio::sync::Mutex::default(),
+                subscriptions: Mutex::default(),
+                resolved_references: Mutex::default(),
+            }),
+        }
+    }
+
+    /// Creates a resolver with the config [read from env variables](SecretsResolverConfig::from_env()).
+    pub fn from_env() -> anyhow::Result<Self> {
+        Ok(Self::new(SecretsResolverConfig::from_env()?))
+    }
+
+    #[cfg(test)]
+    pub(crate) fn with_backend(
+        self,
+        kind: SecretBackendKind,
+        backend: Arc<dyn SecretsBackend>,
+    ) -> Self {
+        self.inner
+            .backends
+            .get(kind)
+            .set(backend)
+            .expect("backend is already initialized");
+        self
+    }
+
+    async fn backend(&self, kind: SecretBackendKind) -> anyhow::Result<&Arc<dyn SecretsBackend>> {
+        let config = &self.inner.config;
+        self.inner
+            .backends
+            .get(kind)
+            .get_or_try_init(|| async {
+                let backend: Arc<dyn SecretsBackend> = match kind {
+                    SecretBackendKind::Vault => {
+                        let addr = config.vault_addr.clone().context(
+                            "Vault address is not specified; set VAULT_ADDR env variable",
+                        )?;
+                        let token = config.vault_token.clone().context(
+                            "Vault token is not specified; set VAULT_TOKEN env variable",
+                        )?;
+                        Arc::new(VaultBackend::new(
+                            addr,
+                            token,
+                            config.vault_namespace.clone(),
+                        ))
+                    }
+                    SecretBackendKind::AwsSecretsManager => {
+                        Arc::new(AwsSecretsManagerBackend::new(
+                            config.aws_region.clone(),
+                            AwsCredentials::from_env()?,
+                        ))
+                    }
+                    SecretBackendKind::GcpSecretManager => {
+                        Arc::new(GcpSecretManagerBackend::new().await?)
+                    }
+                    SecretBackendKind::EncryptedFile => {
+                        let path = config.encrypted_file_path.clone().context(
+                            "encrypted secrets file is not specified; set SECRETS_FILE_PATH env variable",
+                        )?;
+                        let key = config.encrypted_file_key.clone();
+                        // Key derivation is CPU-heavy, and prompting the passphrase blocks.
+                        let backend = tokio::task::spawn_blocking(move || {
+                            let key = match key {
+                                Some(key) => key,
+                                None => SecretsFileKey::prompt(
+                                    &format!("Passphrase for encrypted secrets file {path:?}"),
+                                    false,
+                                )
+                                .context(
+                                    "set SECRETS_FILE_KEYFILE or SECRETS_FILE_PASSPHRASE env variable",
+                                )?,
+                            };
+                            EncryptedFileBackend::new(&path, &key)
+                        })
+                        .await
+                        .context("panicked decrypting secrets file")??;
+                        Arc::new(backend)
+                    }
+                };
+                anyhow::Ok(backend)
+            })
+            .await
+    }
+
+    /// Fetches the whole secret, using the cached value if it's fresh enough and `force_fetch` is not set.
+    async fn fetch(
+        &self,
+        kind: SecretBackendKind,
+        path: &str,
+        force_fetch: bool,
+    ) -> anyhow::Result<Secret<String>> {
+        let key = (kind, path.to_owned());
+        let mut cache = self.inner.cache.lock().await;
+        if !force_fetch {
+            if let Some(cached) = cache.get(&key) {
+                if cached.fetched_at.elapsed() < self.inner.config.cache_ttl {
+                    return Ok(cached.secret.clone());
+                }
+            }
+        }
+
+        // The cache lock is held while fetching, so that concurrent requests for the same secret don't lead
+        // to redundant fetches. Secrets are normally resolved on node start, so contention is not an issue.
+        let backend = self.backend(kind).await?;
+        let secret = backend.fetch(path).await?;
+        cache.insert(
+            key,
+            CachedSecret {
+                secret: secret.clone(),
+                fetched_at: Instant::now(),
+            },
+        );
+        Ok(secret)
+    }
+
+    async fn resolve_inner(
+        &self,
+        reference: &SecretRef,
+        force_fetch: bool,
+    ) -> anyhow::Result<Secret<String>> {
+        let secret = self
+            .fetch(reference.backend(), reference.path(), force_fetch)
+            .await?;
+        match reference.field() {
+            Some(field) => extract_field(&secret, field),
+            None => Ok(secret),
+        }
+        .with_context(|| format!("failed resolving secret `{reference}`"))
+    }
+
+    /// Resolves the specified secret reference.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the secret cannot be fetched or doesn't contain the referenced field.
+    pub async fn resolve(&self, reference: &SecretRef) -> anyhow::Result<Secret<String>> {
+        let secret = self.resolve_inner(reference, false).await?;
+        self.inner
+            .resolved_references
+            .lock()
+            .expect("resolved secret references are poisoned")
+            .insert(reference.clone());
+        Ok(secret)
+    }
+
+    /// Returns all references successfully resolved by this resolver so far, e.g. the secrets the node was started with.
+    pub fn resolved_references(&self) -> Vec<SecretRef> {
+        let references = self
+            .inner
+            .resolved_references
+            .lock()
+            .expect("resolved secret references are poisoned");
+        references.iter().cloned().collect()
+    }
+
+    /// Returns the configured interval between checks for secret rotation, if rotation tracking is enabled.
+    pub fn rotation_interval(&self) -> Option<Duration> {
+        self.inner.config.rotation_interval
+    }
+
+    /// Resolves a URL that may be a secret reference (e.g., a database URL). URLs that are not secret references
+    /// are returned as is.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the reference cannot be resolved or the secret is not a valid URL.
+    pub async fn resolve_url(&self, url: SensitiveUrl) -> anyhow::Result<SensitiveUrl> {
+        let Some(reference) = SecretRef::parse(url.expose_str())? else {
+            return Ok(url);
+        };
+        let secret = self.resolve(&reference).await?;
+        secret
+            .expose_secret()
+            .parse()
+            // Errors are not propagated as is since they may contain parts of the secret.
+            .ok()
+            .with_context(|| format!("secret `{reference}` is not a valid URL"))
+    }
+
+    /// Resolves all secret references among string values in the provided YAML document (e.g., the secrets
+    /// or wallets config) and returns the document with references replaced by secret values.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the YAML is malformed or any of the references cannot be resolved.
+    pub async fn resolve_yaml(&self, yaml: &str) -> anyhow::Result<String> {
+        let document: serde_yaml::Value =
+            serde_yaml::from_str(yaml).context("failed parsing YAML")?;
+        let mut references = HashMap::new();
+        collect_references(&document, &mut references)?;
+
+        // References are replaced in the original text rather than in the parsed document, since serializing
+        // the document could change the representation of other values (e.g., `0x01` would become `1`).
+        // Longer references are replaced first, so that a reference being a prefix of another one doesn't break it.
+        let mut references: Vec<_> = references.into_iter().collect();
+        references.sort_unstable_by(|(raw, _), (other_raw, _)| other_raw.len().cmp(&raw.len()));
+        let mut resolved_yaml = yaml.to_owned();
+        for (raw, reference) in references {
+            let secret = self.resolve(&reference).await?;
+            // JSON strings are valid double-quoted YAML scalars.
+            let value =
+                serde_json::to_string(secret.expose_secret()).expect("failed serializing string");
+            for pattern in [format!("\"{raw}\""), format!("'{raw}'"), raw] {
+                resolved_yaml = resolved_yaml.replace(&pattern, &value);
+            }
+        }
+        Ok(resolved_yaml)
+    }
+
+    /// Subscribes to changes of the specified secret. The returned receiver is updated by [`Self::run_rotation()`]
+    /// each time the secret is rotated.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the secret cannot be resolved.
+    pub async fn subscribe(
+        &self,
+        reference: SecretRef,
+    ) -> anyhow::Result<watch::Receiver<Secret<String>>> {
+        let secret = self.resolve(&reference).await?;
+        let (sender, receiver) = watch::channel(secret);
+        let mut subscriptions = self
+            .inner
+            .subscriptions
+            .lock()
+            .expect("secret subscriptions are poisoned");
+        subscriptions.push(Subscription { reference, sender });
+        Ok(receiver)
+    }
+
+    #[cfg(test)]
+    pub(crate) fn subscription_count(&self) -> usize {
+        let subscriptions = self
+            .inner
+            .subscriptions
+            .lock()
+            .expect("secret subscriptions are poisoned");
+        subscriptions.len()
+    }
+
+    /// Refetches all subscribed secrets and notifies subscribers about changed ones. Returns the number of rotated secrets.
+    pub(crate) async fn refresh_subscriptions(&self) -> usize {
+        let references: Vec<_> = {
+            let mut subscriptions = self
+                .inner
+                .subscriptions
+                .lock()
+                .expect("secret subscriptions are poisoned");
+            subscriptions.retain(|subscription| !subscription.sender.is_closed());
+            subscriptions
+                .iter()
+                .map(|subscription| subscription.reference.clone())
+                .collect()
+        };
+
+        let mut fetched_secrets = HashSet::new();
+        let mut resolved = Vec::with_capacity(references.len());
+        for reference in references {
+            // Each secret is fetched at most once per refresh, even if several of its fields are subscribed to.
+            let key = (reference.backend(), reference.path().to_owned());
+            let force_fetch = fetched_secrets.insert(key);
+            match self.resolve_inner(&reference, force_fetch).await {
+                Ok(secret) => resolved.push((reference, secret)),
+                Err(err) => {
+                    tracing::warn!("Failed refreshing secret `{reference}`: {err:#}");
+                }
+            }
+        }
+
+        let subscriptions = self
+            .inner
+            .subscriptions
+            .lock()
+            .expect("secret subscriptions are poisoned");
+        let mut rotated_count = 0;
+        for (reference, secret) in resolved {
+            for subscription in subscriptions
+                .iter()
+                .filter(|sub| sub.reference == reference)
+            {
+                let is_rotated = subscription.sender.send_if_modified(|current| {
+                    let is_changed = current.expose_secret() != secret.expose_secret();
+                    if is_changed {
+                        *current = secret.clone();
+                    }
+                    is_changed
+                });
+                if is_rotated {
+                    tracing::info!("Secret `{reference}` was rotated");
+                    rotated_count += 1;
+                }
+            }
+        }
+        rotated_count
+    }
+
+    /// Periodically checks subscribed secrets for rotation until a stop signal is received.
+    pub async fn run_rotation(
+        self,
+        mut stop_receiver: watch::Receiver<bool>,
+    ) -> anyhow::Result<()> {
+        let config = &self.inner.config;
+        let interval = config.rotation_interval.unwrap_or(config.cache_ttl);
+        loop {
+            if tokio::time::timeout(interval, stop_receiver.changed())
+                .await
+                .is_ok()
+            {
+                break;
+            }
+            self.refresh_subscriptions().await;
+        }
+        tracing::info!("Stop signal received, secrets rotation is shutting down");
+        Ok(())
+    }
+}
+
+/// Collects secret references among string values in `value`, keyed by their raw representation.
+fn collect_references(
+    value: &serde_yaml::Value,
+    references: &mut HashMap<String, SecretRef>,
+) -> anyhow::Result<()> {
+    match value {
+        serde_yaml::Value::String(s) => {
+            if let Some(reference) = SecretRef::parse(s)? {
+                references.insert(s.clone(), reference);
+            }
+        }
+        serde_yaml::Value::Sequence(items) => {
+            for item in items {
+                collect_references(item, references)?;
+            }
+        }
+        serde_yaml::Value::Mapping(map) => {
+            for value in map.values() {
+                collect_references(value, references)?;
+            }
+        }
+        serde_yaml::Value::Tagged(tagged) => collect_references(&tagged.value, references)?,
+        serde_yaml::Value::Null | serde_yaml::Value::Bool(_) | serde_yaml::Value::Number(_) => {}
+    }
+    Ok(())
+}
@@ -0,0 +1,60 @@
+//! HashiCorp Vault backend.
+
+use anyhow::Context as _;
+use async_trait::async_trait;
+use secrecy::{ExposeSecret, Secret};
+
+use crate::backend::SecretsBackend;
+
+/// Backend reading secrets from the Vault KV v2 secrets engine via the HTTP API.
+#[derive(Debug)]
+pub(crate) struct VaultBackend {
+    client: reqwest::Client,
+    addr: String,
+    token: Secret<String>,
+    namespace: Option<String>,
+}
+
+impl VaultBackend {
+    pub fn new(addr: String, token: Secret<String>, namespace: Option<String>) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            addr: addr.trim_end_matches('/').to_owned(),
+            token,
+            namespace,
+        }
+    }
+}
+
+#[async_trait]
+impl SecretsBackend for VaultBackend {
+    async fn fetch(&self, path: &str) -> anyhow::Result<Secret<String>> {
+        // `path` is validated to have the `{mount}/{path}` format when parsing the reference.
+        let (mount, secret_path) = path.split_once('/').context("invalid Vault secret path")?;
+        let url = format!("{}/v1/{mount}/data/{secret_path}", self.addr);
+        let mut request = self
+            .client
+            .get(&url)
+            .header("X-Vault-Token", self.token.expose_secret());
+        if let Some(namespace) = &self.namespace {
+            request = request.header("X-Vault-Namespace", namespace);
+        }
+        let response = request
+            .send()
+            .await
+            .with_context(|| format!("failed requesting Vault secret `{path}`"))?
+            .error_for_status()
+            .with_context(|| format!("Vault returned error for secret `{path}`"))?;
+        let mut response: serde_json::Value =
+            response.json().await.ok().with_context(|| {
+                format!("Vault returned malformed response for secret `{path}`")
+            })?;
+        // KV v2 engine nests secret data as `data.data`.
+        let data = response
+            .pointer_mut("/data/data")
+            .filter(|data| data.is_object())
+            .with_context(|| format!("Vault response for secret `{path}` doesn't contain data"))?
+            .take();
+        Ok(Secret::new(data.to_string()))
+    }
+}
@@ -29,6 +29,8 @@ pub(super) struct ValuesCacheMetrics {
     /// Number of times the negative initial writes cache was successfully used. This is distinct
     /// from cache hits (we can hit the cache, but the cached value may be outdated).
     pub effective_values: Counter,
+    /// Number of times the factory deps cache was cleared after a protocol upgrade.
+    pub factory_deps_invalidations: Counter,
 }
 
 #[vise::register]
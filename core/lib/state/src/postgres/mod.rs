@@ -12,7 +12,9 @@ use tokio::{
     },
 };
 use zksync_dal::{Connection, ConnectionPool, Core, CoreDal};
-use zksync_types::{L1BatchNumber, L2BlockNumber, StorageKey, StorageValue, H256};
+use zksync_types::{
+    L1BatchNumber, L2BlockNumber, ProtocolVersionId, StorageKey, StorageValue, H256,
+};
 
 use self::metrics::{Method, ValuesUpdateStage, CACHE_METRICS, STORAGE_METRICS};
 use crate::{
@@ -246,7 +248,8 @@ struct ValuesCacheAndUpdater {
 ///
 /// Currently, this struct includes the following caches:
 ///
-/// - Cache for smart contract bytecodes (never invalidated, since it is content-addressable)
+/// - Cache for smart contract bytecodes (content-addressable, but cleared on protocol upgrades since an upgrade
+///   typically replaces the most frequently decommitted system contracts)
 /// - Cache for L1 batch numbers of initial writes for storage keys (never invalidated, except after
 ///   reverting L1 batch execution)
 /// - Cache of the VM storage snapshot corresponding to the latest sealed L2 block
@@ -263,6 +266,8 @@ pub struct PostgresStorageCaches {
     // it wasn't written to at the point that interests us.
     negative_initial_writes: InitialWritesCache,
     values: Option<ValuesCacheAndUpdater>,
+    /// Latest protocol version observed via [`Self::observe_protocol_version()`].
+    protocol_version: Arc<RwLock<Option<ProtocolVersionId>>>,
 }
 
 impl PostgresStorageCaches {
@@ -284,7 +289,30 @@ impl PostgresStorageCaches {
                 initial_writes_capacity / 2,
             ),
             values: None,
+            protocol_version: Arc::default(),
+        }
+    }
+
+    /// Records the protocol version of the latest sealed L2 block. If the version has increased since the previous call,
+    /// the factory deps cache is cleared.
+    pub fn observe_protocol_version(&self, protocol_version: ProtocolVersionId) {
+        let mut observed_version = self
+            .protocol_version
+            .write()
+            .expect("protocol version lock is poisoned");
+        match *observed_version {
+            Some(prev_version) if prev_version >= protocol_version => return,
+            Some(prev_version) => {
+                tracing::info!(
+                    "Protocol version was upgraded from {prev_version:?} to {protocol_version:?}; \
+                     clearing factory deps cache"
+                );
+                self.factory_deps.clear();
+                CACHE_METRICS.factory_deps_invalidations.inc();
+            }
+            None => { /* first observed version */ }
         }
+        *observed_version = Some(protocol_version);
     }
 
     /// Configures the VM storage values cache. The returned closure is the background task that will update
@@ -300,6 +300,27 @@ async fn using_factory_deps_cache() {
         .unwrap();
 }
 
+#[test]
+fn factory_deps_cache_is_cleared_on_protocol_upgrade() {
+    let caches = PostgresStorageCaches::new(1_024, 1_024);
+    let hash = H256::repeat_byte(1);
+    let dep = TimestampedFactoryDep {
+        bytecode: vec![1; 32],
+        inserted_at: L2BlockNumber(0),
+    };
+    caches.observe_protocol_version(ProtocolVersionId::Version23);
+    caches.factory_deps.insert(hash, dep.clone());
+
+    caches.observe_protocol_version(ProtocolVersionId::Version23);
+    assert_eq!(caches.factory_deps.get(&hash), Some(dep.clone()));
+    // Observing an older version must not clear the cache.
+    caches.observe_protocol_version(ProtocolVersionId::Version22);
+    assert_eq!(caches.factory_deps.get(&hash), Some(dep));
+
+    caches.observe_protocol_version(ProtocolVersionId::Version24);
+    assert_eq!(caches.factory_deps.get(&hash), None);
+}
+
 fn test_initial_writes_cache(pool: &ConnectionPool<Core>, rt_handle: Handle) {
     let connection = rt_handle.block_on(pool.connection()).unwrap();
     let caches = PostgresStorageCaches::new(1_024, 4 * 1_024 * 1_024);
@@ -0,0 +1,157 @@
+//! Background maintenance of [`RocksdbStorage`](super::RocksdbStorage): periodic compaction and size control.
+
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use anyhow::Context as _;
+use once_cell::sync::OnceCell;
+use tokio::sync::watch;
+use zksync_storage::{BlockCacheStats, RocksDB};
+
+use super::{metrics::MAINTENANCE_METRICS, StateKeeperColumnFamily};
+
+/// Configuration for [`RocksdbMaintenanceTask`].
+#[derive(Debug, Clone)]
+pub struct RocksdbMaintenanceConfig {
+    /// Interval between storage size checks. Metrics are reported with the same interval.
+    pub check_interval: Duration,
+    /// Interval between manual compactions of the storage. If not set, the storage is only compacted
+    /// when it exceeds [`Self::max_size_bytes`].
+    pub compaction_interval: Option<Duration>,
+    /// Soft limit on the total size of SST files in the storage. If exceeded, the storage is compacted
+    /// immediately; if it's still exceeded after compaction, a warning is logged and the corresponding metric is set.
+    pub max_size_bytes: Option<u64>,
+}
+
+impl Default for RocksdbMaintenanceConfig {
+    fn default() -> Self {
+        Self {
+            check_interval: Duration::from_secs(60),
+            compaction_interval: None,
+            max_size_bytes: None,
+        }
+    }
+}
+
+/// Outcome of a single maintenance iteration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct MaintenanceOutcome {
+    pub compacted: bool,
+    pub size_limit_exceeded: bool,
+}
+
+/// Task periodically compacting the state keeper RocksDB cache and reporting its size and block cache hit rate.
+/// Waits until the cache is initialized by [`AsyncCatchupTask`](crate::AsyncCatchupTask).
+#[derive(Debug)]
+pub struct RocksdbMaintenanceTask {
+    rocksdb_cell: Arc<OnceCell<RocksDB<StateKeeperColumnFamily>>>,
+    config: RocksdbMaintenanceConfig,
+}
+
+impl RocksdbMaintenanceTask {
+    pub fn new(
+        rocksdb_cell: Arc<OnceCell<RocksDB<StateKeeperColumnFamily>>>,
+        config: RocksdbMaintenanceConfig,
+    ) -> Self {
+        Self {
+            rocksdb_cell,
+            config,
+        }
+    }
+
+    /// Runs the task until a stop signal is received.
+    ///
+    /// # Errors
+    ///
+    /// Propagates panics in blocking maintenance operations.
+    pub async fn run(self, mut stop_receiver: watch::Receiver<bool>) -> anyhow::Result<()> {
+        tracing::info!("Starting RocksDB cache maintenance with {:?}", self.config);
+        let mut last_compaction_at = Instant::now();
+        let mut prev_block_cache_stats = None;
+
+        loop {
+            let stop = tokio::time::timeout(self.config.check_interval, stop_receiver.changed());
+            if stop.await.is_ok() {
+                break;
+            }
+            let Some(rocksdb) = self.rocksdb_cell.get() else {
+                tracing::debug!("RocksDB cache is not initialized yet; skipping maintenance");
+                continue;
+            };
+
+            if let Some(stats) = rocksdb.block_cache_stats() {
+                Self::report_hit_rate(prev_block_cache_stats.unwrap_or_default(), stats);
+                prev_block_cache_stats = Some(stats);
+            }
+
+            let compaction_due = self
+                .config
+                .compaction_interval
+                .map_or(false, |interval| last_compaction_at.elapsed() >= interval);
+            let rocksdb = rocksdb.clone();
+            let max_size_bytes = self.config.max_size_bytes;
+            let outcome = tokio::task::spawn_blocking(move || {
+                Self::maintain(&rocksdb, max_size_bytes, compaction_due)
+            })
+            .await
+            .context("panicked during RocksDB cache maintenance")?;
+            if outcome.compacted {
+                last_compaction_at = Instant::now();
+            }
+        }
+        tracing::info!("Stop signal received, RocksDB cache maintenance is shutting down");
+        Ok(())
+    }
+
+    fn report_hit_rate(prev: BlockCacheStats, current: BlockCacheStats) {
+        let hits = current.hits.saturating_sub(prev.hits);
+        let misses = current.misses.saturating_sub(prev.misses);
+        if hits + misses > 0 {
+            MAINTENANCE_METRICS
+                .block_cache_hit_rate
+                .set(hits as f64 / (hits + misses) as f64);
+        }
+    }
+
+    pub(super) fn maintain(
+        rocksdb: &RocksDB<StateKeeperColumnFamily>,
+        max_size_bytes: Option<u64>,
+        compaction_due: bool,
+    ) -> MaintenanceOutcome {
+        let mut size = rocksdb.total_sst_files_size();
+        MAINTENANCE_METRICS.size.set(size);
+        let exceeds_limit = |size| max_size_bytes.map_or(false, |max_size| size > max_size);
+
+        let compacted = compaction_due || exceeds_limit(size);
+        if compacted {
+            tracing::info!(
+                "Compacting RocksDB cache with size {size}B (limit: {max_size_bytes:?}, scheduled: {compaction_due})"
+            );
+            let started_at = Instant::now();
+            rocksdb.compact();
+            let elapsed = started_at.elapsed();
+            MAINTENANCE_METRICS.compaction_latency.observe(elapsed);
+            MAINTENANCE_METRICS.compactions.inc();
+
+            size = rocksdb.total_sst_files_size();
+            MAINTENANCE_METRICS.size.set(size);
+            tracing::info!("Compacted RocksDB cache in {elapsed:?}; new size: {size}B");
+        }
+
+        let size_limit_exceeded = exceeds_limit(size);
+        if size_limit_exceeded {
+            tracing::warn!(
+                "RocksDB cache size {size}B exceeds the configured limit {max_size_bytes:?} even after compaction"
+            );
+        }
+        MAINTENANCE_METRICS
+            .size_limit_exceeded
+            .set(size_limit_exceeded.into());
+        MaintenanceOutcome {
+            compacted,
+            size_limit_exceeded,
+        }
+    }
+}
@@ -2,7 +2,9 @@
 
 use std::time::Duration;
 
-use vise::{Buckets, EncodeLabelSet, EncodeLabelValue, Family, Gauge, Histogram, Metrics, Unit};
+use vise::{
+    Buckets, Counter, EncodeLabelSet, EncodeLabelValue, Family, Gauge, Histogram, Metrics, Unit,
+};
 
 #[derive(Debug, Metrics)]
 #[metrics(prefix = "server_state_keeper_secondary_storage")]
@@ -51,3 +53,25 @@ pub(super) struct RocksdbRecoveryMetrics {
 
 #[vise::register]
 pub(super) static RECOVERY_METRICS: vise::Global<RocksdbRecoveryMetrics> = vise::Global::new();
+
+/// Metrics related to maintenance (compaction and size control) of the storage.
+#[derive(Debug, Metrics)]
+#[metrics(prefix = "server_state_keeper_secondary_storage_maintenance")]
+pub(super) struct RocksdbMaintenanceMetrics {
+    /// Total size of SST files in the storage.
+    #[metrics(unit = Unit::Bytes)]
+    pub size: Gauge<u64>,
+    /// Block cache hit rate since the previous check, in [0, 1].
+    pub block_cache_hit_rate: Gauge<f64>,
+    /// Number of manual compactions performed.
+    pub compactions: Counter,
+    /// Latency of a manual compaction.
+    #[metrics(buckets = Buckets::LATENCIES, unit = Unit::Seconds)]
+    pub compaction_latency: Histogram<Duration>,
+    /// Set to 1 if the storage size exceeds the configured limit even after compaction, 0 otherwise.
+    pub size_limit_exceeded: Gauge<u64>,
+}
+
+#[vise::register]
+pub(super) static MAINTENANCE_METRICS: vise::Global<RocksdbMaintenanceMetrics> =
+    vise::Global::new();
@@ -24,7 +24,7 @@ use std::{
     mem,
     num::NonZeroU32,
     path::{Path, PathBuf},
-    time::Instant,
+    time::{Duration, Instant},
 };
 
 use anyhow::Context as _;
@@ -34,11 +34,13 @@ use zksync_dal::{Connection, Core, CoreDal, DalError};
 use zksync_storage::{db::NamedColumnFamily, RocksDB, RocksDBOptions};
 use zksync_types::{L1BatchNumber, StorageKey, StorageValue, H256};
 
+pub use self::maintenance::{RocksdbMaintenanceConfig, RocksdbMaintenanceTask};
 #[cfg(test)]
 use self::tests::RocksdbStorageEventListener;
 use self::{metrics::METRICS, recovery::Strategy};
 use crate::{InMemoryStorage, ReadStorage};
 
+mod maintenance;
 mod metrics;
 mod recovery;
 #[cfg(test)]
@@ -133,6 +135,9 @@ pub struct RocksdbStorageOptions {
     /// Number of open files that can be simultaneously opened by RocksDB. Default is `None`, for no limit.
     /// Can be used to restrict memory usage of RocksDB.
     pub max_open_files: Option<NonZeroU32>,
+    /// If set, SST files not compacted for longer than this period will be compacted by RocksDB in background.
+    /// Default is `None`, for default RocksDB behavior.
+    pub periodic_compaction_ttl: Option<Duration>,
 }
 
 impl Default for RocksdbStorageOptions {
@@ -140,6 +145,7 @@ impl Default for RocksdbStorageOptions {
         Self {
             block_cache_capacity: 128 << 20,
             max_open_files: None,
+            periodic_compaction_ttl: None,
         }
     }
 }
@@ -149,6 +155,9 @@ impl RocksdbStorageOptions {
         RocksDBOptions {
             block_cache_capacity: Some(self.block_cache_capacity),
             max_open_files: self.max_open_files,
+            periodic_compaction_ttl: self.periodic_compaction_ttl,
+            // Used to report the block cache hit rate in `RocksdbMaintenanceTask`.
+            collect_statistics: true,
             ..RocksDBOptions::default()
         }
     }
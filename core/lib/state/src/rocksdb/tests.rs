@@ -440,3 +440,46 @@ async fn recovery_fault_tolerance() {
         assert!(!storage.is_write_initial(&log.key));
     }
 }
+
+#[tokio::test]
+async fn rocksdb_storage_maintenance() {
+    let dir = TempDir::new().expect("cannot create temporary dir for state keeper");
+    let mut storage = RocksdbStorage::new(dir.path().into(), RocksdbStorageOptions::default())
+        .await
+        .unwrap();
+    let storage_logs: HashMap<_, _> = gen_storage_logs(0..100)
+        .into_iter()
+        .map(|log| (log.key, log.value))
+        .collect();
+    let changed_keys = RocksdbStorage::process_transaction_logs(&storage.db, storage_logs.clone());
+    storage.pending_patch.state = changed_keys
+        .into_iter()
+        .map(|(key, state_value)| (key.hashed_key(), (state_value.value, 1)))
+        .collect();
+    storage.save(Some(L1BatchNumber(0))).await.unwrap();
+    let db = storage.db.clone();
+
+    let outcome = RocksdbMaintenanceTask::maintain(&db, None, false);
+    assert!(!outcome.compacted);
+    assert!(!outcome.size_limit_exceeded);
+
+    let outcome = RocksdbMaintenanceTask::maintain(&db, None, true);
+    assert!(outcome.compacted);
+    assert!(!outcome.size_limit_exceeded);
+    // After compaction, all data should be flushed to SST files.
+    let size = db.total_sst_files_size();
+    assert!(size > 0);
+
+    let outcome = RocksdbMaintenanceTask::maintain(&db, Some(size + 1), false);
+    assert!(!outcome.compacted);
+    let outcome = RocksdbMaintenanceTask::maintain(&db, Some(1), false);
+    assert!(outcome.compacted);
+    assert!(outcome.size_limit_exceeded);
+
+    // Reading from the storage should populate block cache stats.
+    for (key, value) in &storage_logs {
+        assert_eq!(storage.read_value(key), *value);
+    }
+    let stats = db.block_cache_stats().unwrap();
+    assert!(stats.hits + stats.misses > 0);
+}
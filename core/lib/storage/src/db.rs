@@ -104,6 +104,8 @@ pub(crate) struct RocksDBInner {
     db: DB,
     db_name: &'static str,
     cf_names: HashSet<&'static str>,
+    /// DB options with statistics enabled; `None` if statistics are not collected for the DB.
+    statistics: Option<Options>,
     _registry_entry: RegistryEntry,
     // Importantly, `Cache`s must be dropped after `DB`, so we place them as the last field
     // (fields in a struct are dropped in the declaration order).
@@ -298,6 +300,12 @@ pub struct RocksDBOptions {
     pub stalled_writes_retries: StalledWritesRetries,
     /// Number of open files that can be used by the DB. Default is None, for no limit.
     pub max_open_files: Option<NonZeroU32>,
+    /// If set, SST files not compacted for longer than this period will be picked up for compaction,
+    /// so that stale data is eventually removed. If not set, default RocksDB behavior is used.
+    pub periodic_compaction_ttl: Option<Duration>,
+    /// Whether to collect RocksDB statistics (e.g., block cache hits / misses) for the DB. Collecting
+    /// statistics has a small performance overhead.
+    pub collect_statistics: bool,
 }
 
 impl Default for RocksDBOptions {
@@ -308,10 +316,21 @@ impl Default for RocksDBOptions {
             large_memtable_capacity: None,
             stalled_writes_retries: StalledWritesRetries::new(Duration::from_secs(10)),
             max_open_files: None,
+            periodic_compaction_ttl: None,
+            collect_statistics: false,
         }
     }
 }
 
+/// Block cache statistics for a [`RocksDB`] instance accumulated since its initialization.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BlockCacheStats {
+    /// Number of block cache hits.
+    pub hits: u64,
+    /// Number of block cache misses.
+    pub misses: u64,
+}
+
 /// Thin wrapper around a RocksDB instance.
 ///
 /// The wrapper is cheaply cloneable; internally, it wraps a DB instance in an [`Arc`].
@@ -348,6 +367,9 @@ impl<CF: NamedColumnFamily> RocksDB<CF> {
             -1
         };
         db_options.set_max_open_files(max_open_files);
+        if options.collect_statistics {
+            db_options.enable_statistics();
+        }
         let existing_cfs = DB::list_cf(&db_options, path).unwrap_or_else(|err| {
             tracing::warn!(
                 "Failed getting column families for RocksDB `{}` at `{}`, assuming CFs are empty; {err}",
@@ -398,7 +420,11 @@ impl<CF: NamedColumnFamily> RocksDB<CF> {
             }
 
             let memtable_capacity = options.large_memtable_capacity.filter(|_| requires_tuning);
-            let cf_options = Self::rocksdb_options(memtable_capacity, Some(block_based_options));
+            let mut cf_options =
+                Self::rocksdb_options(memtable_capacity, Some(block_based_options));
+            if let Some(ttl) = options.periodic_compaction_ttl {
+                cf_options.set_periodic_compaction_seconds(ttl.as_secs());
+            }
             ColumnFamilyDescriptor::new(cf_name, cf_options)
         });
 
@@ -407,6 +433,7 @@ impl<CF: NamedColumnFamily> RocksDB<CF> {
             db,
             db_name: CF::DB_NAME,
             cf_names,
+            statistics: options.collect_statistics.then_some(db_options),
             _registry_entry: RegistryEntry::new(),
             _caches: caches,
         });
@@ -479,6 +506,57 @@ impl<CF: NamedColumnFamily> RocksDB<CF> {
             .unwrap_or(0)
     }
 
+    /// Returns the total size of SST files in all column families of the DB in bytes.
+    pub fn total_sst_files_size(&self) -> u64 {
+        CF::ALL
+            .iter()
+            .filter_map(|&cf| {
+                let cf = self.column_family(cf);
+                self.inner
+                    .int_property(cf, properties::TOTAL_SST_FILES_SIZE)
+            })
+            .sum()
+    }
+
+    /// Manually compacts all column families in the DB.
+    ///
+    /// This method is blocking and should be wrapped in `spawn_blocking(_)` if run in the async context.
+    pub fn compact(&self) {
+        for &cf in CF::ALL {
+            let cf_handle = self.column_family(cf);
+            tracing::debug!("Compacting CF `{}` in DB `{}`", cf.name(), CF::DB_NAME);
+            self.inner
+                .db
+                .compact_range_cf(cf_handle, None::<&[u8]>, None::<&[u8]>);
+        }
+    }
+
+    /// Returns block cache statistics for the DB. Returns `None` if statistics are not collected
+    /// (see [`RocksDBOptions::collect_statistics`]).
+    pub fn block_cache_stats(&self) -> Option<BlockCacheStats> {
+        const HIT_TICKER: &str = "rocksdb.block.cache.hit";
+        const MISS_TICKER: &str = "rocksdb.block.cache.miss";
+
+        let statistics = self.inner.statistics.as_ref()?.get_statistics()?;
+        let mut stats = BlockCacheStats::default();
+        // Like with perf context, RocksDB C API doesn't expose individual tickers, so we parse them
+        // from the string representation, which has lines like `rocksdb.block.cache.hit COUNT : 42`.
+        for line in statistics.lines() {
+            let Some((name, count)) = line.split_once(" COUNT : ") else {
+                continue;
+            };
+            let Ok(count) = count.trim().parse() else {
+                continue;
+            };
+            match name.trim() {
+                HIT_TICKER => stats.hits = count,
+                MISS_TICKER => stats.misses = count,
+                _ => { /* other tickers are not used */ }
+            }
+        }
+        Some(stats)
+    }
+
     pub fn multi_get<K, I>(&self, keys: I) -> Vec<Result<Option<Vec<u8>>, rocksdb::Error>>
     where
         K: AsRef<[u8]>,
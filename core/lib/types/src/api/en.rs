@@ -46,3 +46,7 @@ pub struct SyncBlock {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ConsensusGenesis(pub serde_json::Value);
+
+/// Consensus certificate (`validator::CommitQC`) of an L2 block, serialized as JSON.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlockCertificate(pub serde_json::Value);
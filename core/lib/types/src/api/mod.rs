@@ -6,17 +6,19 @@ use zksync_basic_types::{
     L1BatchNumber, H160, H2048, H256, H64, U256, U64,
 };
 use zksync_contracts::BaseSystemContractsHashes;
+use zksync_utils::bytecode::BytecodeMarker;
 
 pub use crate::transaction_request::{
     Eip712Meta, SerializationTransactionError, TransactionRequest,
 };
 use crate::{
     protocol_version::L1VerifierConfig,
-    vm_trace::{Call, CallType},
+    vm_trace::{Call, CallType, OpcodeStep},
     Address, L2BlockNumber, ProtocolVersionId,
 };
 
 pub mod en;
+pub mod state_override;
 
 /// Block Number
 #[derive(Copy, Clone, Debug, PartialEq, Display)]
@@ -259,6 +261,14 @@ pub struct TransactionReceipt {
     /// Effective gas price
     #[serde(rename = "effectiveGasPrice")]
     pub effective_gas_price: Option<U256>,
+    /// Kind of the bytecode deployed at `contract_address`. Only populated by nodes with
+    /// the EVM emulator enabled.
+    #[serde(
+        rename = "contractBytecodeKind",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub contract_bytecode_kind: Option<BytecodeMarker>,
 }
 
 /// The block type returned from RPC calls.
@@ -578,6 +588,26 @@ pub struct TransactionDetails {
     pub eth_execute_tx_hash: Option<H256>,
 }
 
+/// Position of a log in the chain used to continue a paginated log query. The query is continued
+/// from the log immediately after the referenced one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogsCursor {
+    /// Number of the L2 block containing the log.
+    pub block_number: U64,
+    /// Index of the log in the L2 block.
+    pub log_index: U64,
+}
+
+/// Page of logs returned by `zks_getLogsPaginated`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogsPage {
+    pub logs: Vec<Log>,
+    /// Cursor to get the next page of logs; `None` if there are no more logs matching the filter.
+    pub next_cursor: Option<LogsCursor>,
+}
+
 #[derive(Debug, Clone)]
 pub struct GetLogsFilter {
     pub from_block: L2BlockNumber,
@@ -640,6 +670,18 @@ impl From<Call> for DebugCall {
     }
 }
 
+/// Result of `debug_traceCall.opcodeTracer`, modeled after the geth struct logger output.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DebugOpcodeTrace {
+    pub gas: U256,
+    pub failed: bool,
+    pub return_value: Bytes,
+    /// Whether `struct_logs` were truncated because the number of executed opcodes exceeded the server limit.
+    pub truncated: bool,
+    pub struct_logs: Vec<OpcodeStep>,
+}
+
 // TODO (PLA-965): remove deprecated fields from the struct. It is currently in a "migration" phase
 // to keep compatibility between old and new versions.
 #[derive(Default, Serialize, Deserialize, Clone, Debug)]
@@ -735,6 +777,72 @@ pub struct TracerConfig {
     pub tracer: SupportedTracers,
     #[serde(default)]
     pub tracer_config: CallTracerConfig,
+    /// Protocol version whose VM rules are used for the simulation; only applicable to `debug_traceCall`.
+    /// If not specified, the version active at the target block is used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub protocol_version: Option<u16>,
+}
+
+/// Additional options for `eth_call`.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CallOptions {
+    /// Protocol version whose VM rules are used for the call. If not specified, the version active
+    /// at the target block is used.
+    #[serde(default)]
+    pub protocol_version: Option<u16>,
+    /// State override applied on top of the state at the target block before executing the call.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state_override: Option<state_override::StateOverride>,
+}
+
+/// Third parameter of `eth_call`. Can be specified either as zkSync-specific [`CallOptions`], or as
+/// a [`StateOverride`](state_override::StateOverride) for compatibility with Geth.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum CallOptionsVariant {
+    // Must go first: an object with `CallOptions` fields is never a valid state override since its keys
+    // are not addresses, while any object would be parsed as `CallOptions` with default values.
+    StateOverride(state_override::StateOverride),
+    Options(CallOptions),
+}
+
+impl From<CallOptions> for CallOptionsVariant {
+    fn from(options: CallOptions) -> Self {
+        Self::Options(options)
+    }
+}
+
+impl From<state_override::StateOverride> for CallOptionsVariant {
+    fn from(state_override: state_override::StateOverride) -> Self {
+        Self::StateOverride(state_override)
+    }
+}
+
+impl From<CallOptionsVariant> for CallOptions {
+    fn from(value: CallOptionsVariant) -> Self {
+        match value {
+            CallOptionsVariant::StateOverride(state_override) => Self {
+                state_override: Some(state_override),
+                ..Self::default()
+            },
+            CallOptionsVariant::Options(options) => options,
+        }
+    }
+}
+
+/// Options for `debug_traceCallMany`.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct TraceCallManyOptions {
+    #[serde(default)]
+    pub tracer_config: CallTracerConfig,
+    /// State overrides applied before executing the first call.
+    #[serde(default)]
+    pub state_override: Option<state_override::StateOverride>,
+    /// Overrides for the block context shared by all calls.
+    #[serde(default)]
+    pub block_override: Option<state_override::BlockOverrides>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -815,6 +923,39 @@ pub struct ApiStorageLog {
     pub written_value: U256,
 }
 
+/// Result of simulating a transaction with `zks_simulateTransaction`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionSimulationResult {
+    /// Whether the transaction was executed successfully.
+    pub success: bool,
+    /// Data returned by the transaction, or the revert data if the transaction was reverted.
+    pub output: Bytes,
+    /// Human-readable revert or halt reason if the transaction has failed.
+    pub revert_reason: Option<String>,
+    /// Gas used by the transaction.
+    pub gas: SimulatedGasBreakdown,
+    /// Events emitted by the transaction. Events are emitted even if the transaction has failed
+    /// (e.g., by the bootloader on paying the fee).
+    pub events: Vec<Log>,
+}
+
+/// Breakdown of gas used by a simulated transaction.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimulatedGasBreakdown {
+    /// Gas limit the transaction was simulated with.
+    pub gas_limit: U256,
+    /// Total gas used by the transaction, including the gas spent on publishing pubdata.
+    pub gas_used: U256,
+    /// Gas spent on computations, i.e. excluding the gas spent on publishing pubdata.
+    pub computational_gas_used: U256,
+    /// Number of bytes of pubdata published by the transaction.
+    pub pubdata_published: U256,
+    /// Gas that would be refunded to the transaction initiator.
+    pub gas_refunded: U256,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -848,4 +989,40 @@ mod tests {
         serde_json::from_str::<OldProtocolVersion>(&serde_json::to_string(&new_version).unwrap())
             .unwrap();
     }
+
+    #[test]
+    fn deserializing_call_options_variant() {
+        let json = serde_json::json!({ "protocolVersion": 24 });
+        let options: CallOptionsVariant = serde_json::from_value(json).unwrap();
+        let options = CallOptions::from(options);
+        assert_eq!(options.protocol_version, Some(24));
+        assert_eq!(options.state_override, None);
+
+        let json = serde_json::json!({
+            "0x0123456789abcdef0123456789abcdef01234567": { "balance": "0x123" },
+        });
+        let options: CallOptionsVariant = serde_json::from_value(json).unwrap();
+        assert!(matches!(&options, CallOptionsVariant::StateOverride(_)));
+        let options = CallOptions::from(options);
+        assert_eq!(options.protocol_version, None);
+        let state_override = options.state_override.unwrap();
+        let address = "0x0123456789abcdef0123456789abcdef01234567"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            state_override.get(&address).unwrap().balance,
+            Some(0x123.into())
+        );
+
+        let json = serde_json::json!({
+            "protocolVersion": 24,
+            "stateOverride": {
+                "0x0123456789abcdef0123456789abcdef01234567": { "nonce": "0x1" },
+            },
+        });
+        let options: CallOptionsVariant = serde_json::from_value(json).unwrap();
+        let options = CallOptions::from(options);
+        assert_eq!(options.protocol_version, Some(24));
+        assert!(options.state_override.unwrap().get(&address).is_some());
+    }
 }
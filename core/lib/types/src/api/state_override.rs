@@ -0,0 +1,119 @@
+//! API types related to state and block overrides used in simulation methods (e.g., `debug_traceCallMany`).
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use zksync_basic_types::{web3::Bytes, H256, U256, U64};
+
+use crate::Address;
+
+/// Collection of overridden accounts, keyed by the account address.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct StateOverride(HashMap<Address, OverrideAccount>);
+
+impl StateOverride {
+    /// Wraps the provided account overrides.
+    pub fn new(state: HashMap<Address, OverrideAccount>) -> Self {
+        Self(state)
+    }
+
+    /// Gets overrides for the specified account.
+    pub fn get(&self, address: &Address) -> Option<&OverrideAccount> {
+        self.0.get(address)
+    }
+
+    /// Iterates over all account overrides.
+    pub fn iter(&self) -> impl Iterator<Item = (&Address, &OverrideAccount)> + '_ {
+        self.0.iter()
+    }
+}
+
+/// Account override for a single address.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OverrideAccount {
+    /// Overridden base token balance of the account.
+    pub balance: Option<U256>,
+    /// Overridden account (transaction) nonce. The deployment nonce is preserved.
+    pub nonce: Option<U256>,
+    /// Overridden contract bytecode. Must be a valid EraVM bytecode.
+    pub code: Option<Bytes>,
+    /// Overridden contract storage; either a full replacement or a diff.
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    pub state: Option<OverrideState>,
+}
+
+/// Contract storage override.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum OverrideState {
+    /// Replaces the entire contract storage; slots not mentioned here are treated as zeroed.
+    State(HashMap<H256, H256>),
+    /// Overrides only the specified storage slots, leaving the other slots intact.
+    StateDiff(HashMap<H256, H256>),
+}
+
+/// Overrides for the block context that simulated calls are executed in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockOverrides {
+    /// Overridden block timestamp. Must not be lower than the timestamp of the parent block,
+    /// otherwise the simulation will fail.
+    pub timestamp: Option<U64>,
+    /// Overridden base fee per gas.
+    pub base_fee: Option<U64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserializing_state_override() {
+        let json = serde_json::json!({
+            "0x0123456789abcdef0123456789abcdef01234567": {
+                "balance": "0x123",
+                "nonce": "0x1",
+            },
+            "0x123456789abcdef0123456789abcdef012345678": {
+                "stateDiff": {
+                    "0x0000000000000000000000000000000000000000000000000000000000000000":
+                        "0x0000000000000000000000000000000000000000000000000000000000000001",
+                },
+            },
+        });
+        let state_override: StateOverride = serde_json::from_value(json).unwrap();
+
+        let account = state_override
+            .get(
+                &"0x0123456789abcdef0123456789abcdef01234567"
+                    .parse()
+                    .unwrap(),
+            )
+            .unwrap();
+        assert_eq!(account.balance, Some(0x123.into()));
+        assert_eq!(account.nonce, Some(1.into()));
+        assert_eq!(account.code, None);
+        assert_eq!(account.state, None);
+
+        let account = state_override
+            .get(
+                &"0x123456789abcdef0123456789abcdef012345678"
+                    .parse()
+                    .unwrap(),
+            )
+            .unwrap();
+        let Some(OverrideState::StateDiff(diff)) = &account.state else {
+            panic!("Unexpected state override: {account:?}");
+        };
+        assert_eq!(diff[&H256::zero()], H256::from_low_u64_be(1));
+    }
+
+    #[test]
+    fn deserializing_block_overrides() {
+        let json = serde_json::json!({ "timestamp": "0x10", "baseFee": "0x5f5e100" });
+        let overrides: BlockOverrides = serde_json::from_value(json).unwrap();
+        assert_eq!(overrides.timestamp, Some(16.into()));
+        assert_eq!(overrides.base_fee, Some(100_000_000.into()));
+    }
+}
@@ -1,7 +1,7 @@
 use std::{fmt, ops};
 
 use serde::{Deserialize, Serialize};
-use zksync_basic_types::{Address, H2048, H256, U256};
+use zksync_basic_types::{ethabi::ethereum_types::BloomInput, Address, H2048, H256, U256};
 use zksync_contracts::BaseSystemContractsHashes;
 use zksync_system_constants::SYSTEM_BLOCK_INFO_BLOCK_NUMBER_MULTIPLIER;
 use zksync_utils::concat_and_hash;
@@ -11,7 +11,7 @@ use crate::{
     l2_to_l1_log::{SystemL2ToL1Log, UserL2ToL1Log},
     priority_op_onchain_data::PriorityOpOnchainData,
     web3::keccak256,
-    AccountTreeId, L1BatchNumber, L2BlockNumber, ProtocolVersionId, Transaction,
+    AccountTreeId, L1BatchNumber, L2BlockNumber, ProtocolVersionId, Transaction, VmEvent,
 };
 
 /// Represents a successfully deployed smart contract.
@@ -82,6 +82,8 @@ pub struct L2BlockHeader {
     /// Note, that it is an `u64`, i.e. while the computational limit for the bootloader is an `u32` a much larger
     /// amount of gas can be spent on pubdata.
     pub gas_limit: u64,
+    /// Bloom filter for the event logs in the L2 block. Zero for L2 blocks sealed before blooms were persisted.
+    pub logs_bloom: H2048,
 }
 
 /// Structure that represents the data is returned by the storage oracle during batch execution.
@@ -267,6 +269,19 @@ impl L2BlockHasher {
     }
 }
 
+/// Builds a bloom filter for the specified events in the same way as Ethereum does for blocks, i.e.
+/// from event addresses and topics.
+pub fn build_logs_bloom<'a>(events: impl IntoIterator<Item = &'a VmEvent>) -> H2048 {
+    let mut bloom = H2048::zero();
+    for event in events {
+        bloom.accrue(BloomInput::Raw(event.address.as_bytes()));
+        for topic in &event.indexed_topics {
+            bloom.accrue(BloomInput::Raw(topic.as_bytes()));
+        }
+    }
+    bloom
+}
+
 /// Returns block.number/timestamp based on the block's information
 pub fn unpack_block_info(info: U256) -> (u64, u64) {
     let block_number = (info / SYSTEM_BLOCK_INFO_BLOCK_NUMBER_MULTIPLIER).as_u64();
@@ -337,4 +352,19 @@ mod tests {
         assert_eq!(block_number, unpacked_block_number);
         assert_eq!(block_timestamp, unpacked_block_timestamp);
     }
+
+    #[test]
+    fn building_logs_bloom() {
+        let event = VmEvent {
+            location: (L1BatchNumber(1), 0),
+            address: Address::repeat_byte(1),
+            indexed_topics: vec![H256::repeat_byte(2)],
+            value: vec![],
+        };
+        let bloom = build_logs_bloom([&event]);
+        assert!(bloom.contains_input(BloomInput::Raw(Address::repeat_byte(1).as_bytes())));
+        assert!(bloom.contains_input(BloomInput::Raw(H256::repeat_byte(2).as_bytes())));
+        assert!(!bloom.contains_input(BloomInput::Raw(Address::repeat_byte(3).as_bytes())));
+        assert_eq!(build_logs_bloom([]), H2048::zero());
+    }
 }
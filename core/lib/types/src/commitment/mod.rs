@@ -92,6 +92,10 @@ pub struct L1BatchMetadata {
     /// commitment to the transactions in the batch.
     pub bootloader_initial_content_commitment: Option<H256>,
     pub state_diffs_compressed: Vec<u8>,
+    /// Data proving the inclusion of the batch pubdata in the external DA layer (only for `PubdataDA::Custom`).
+    /// Not persisted together with the rest of the metadata; it's loaded from the DA dispatcher tables when needed.
+    #[serde(default)]
+    pub da_inclusion_data: Option<Vec<u8>>,
 }
 
 impl L1BatchMetadata {
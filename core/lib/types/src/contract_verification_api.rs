@@ -18,6 +18,9 @@ pub enum SourceCodeData {
     StandardJsonInput(serde_json::Map<String, serde_json::Value>),
     #[serde(rename = "vyper-multi-file")]
     VyperMultiFile(HashMap<String, String>),
+    /// Vyper standard JSON input, with `sources` and (optionally) `interfaces` specifying files by their `content`.
+    #[serde(rename = "vyper-standard-json-input")]
+    VyperStandardJsonInput(serde_json::Map<String, serde_json::Value>),
     #[serde(rename = "yul-single-file")]
     YulSingleFile(String),
 }
@@ -28,7 +31,9 @@ impl SourceCodeData {
             SourceCodeData::SolSingleFile(_)
             | SourceCodeData::StandardJsonInput(_)
             | SourceCodeData::YulSingleFile(_) => CompilerType::Solc,
-            SourceCodeData::VyperMultiFile(_) => CompilerType::Vyper,
+            SourceCodeData::VyperMultiFile(_) | SourceCodeData::VyperStandardJsonInput(_) => {
+                CompilerType::Vyper
+            }
         }
     }
 }
@@ -99,6 +104,17 @@ impl<'de> Visitor<'de> for SourceCodeVisitor {
                         .clone(),
                 )
             }
+            Some("vyper-standard-json-input") => {
+                let value = source_code.ok_or_else(|| A::Error::missing_field("source_code"))?;
+                SourceCodeData::VyperStandardJsonInput(
+                    value
+                        .as_object()
+                        .ok_or_else(|| {
+                            A::Error::invalid_type(Unexpected::Other(&value.to_string()), &self)
+                        })?
+                        .clone(),
+                )
+            }
             Some("vyper-multi-file") => {
                 let value = source_code.ok_or_else(|| A::Error::missing_field("source_code"))?;
                 let obj = value
@@ -119,6 +135,7 @@ impl<'de> Visitor<'de> for SourceCodeVisitor {
                         "solidity-standard-json-input",
                         "yul-single-file",
                         "vyper-multi-file",
+                        "vyper-standard-json-input",
                     ],
                 ))
             }
@@ -262,6 +279,14 @@ mod tests {
             Ok(SourceCodeData::StandardJsonInput(_))
         ));
 
+        let vyper_json_input_str =
+            r#"{"codeFormat": "vyper-standard-json-input", "sourceCode": {"language": "Vyper"}}"#;
+        let vyper_json_input_result = serde_json::from_str::<SourceCodeData>(vyper_json_input_str);
+        assert!(matches!(
+            vyper_json_input_result,
+            Ok(SourceCodeData::VyperStandardJsonInput(_))
+        ));
+
         let type_not_specified_str = r#"{"sourceCode": "text"}"#;
         let type_not_specified_result =
             serde_json::from_str::<SourceCodeData>(type_not_specified_str);
@@ -1,7 +1,10 @@
+use chrono::NaiveDateTime;
 use num_enum::TryFromPrimitive;
 use serde::{Deserialize, Serialize};
 use zksync_config::configs::eth_sender::PubdataSendingMode;
 
+use crate::L1BatchNumber;
+
 /// Enum holding the current values used for DA Layers.
 #[repr(u8)]
 #[derive(Debug, Clone, Copy, Deserialize, PartialEq, Serialize)]
@@ -9,6 +12,8 @@ use zksync_config::configs::eth_sender::PubdataSendingMode;
 pub enum PubdataDA {
     Calldata = 0,
     Blobs,
+    /// Pubdata is dispatched to an external DA layer; only the inclusion data is sent to L1.
+    Custom,
 }
 
 impl From<PubdataSendingMode> for PubdataDA {
@@ -16,6 +21,16 @@ impl From<PubdataSendingMode> for PubdataDA {
         match value {
             PubdataSendingMode::Calldata => PubdataDA::Calldata,
             PubdataSendingMode::Blobs => PubdataDA::Blobs,
+            PubdataSendingMode::Custom => PubdataDA::Custom,
         }
     }
 }
+
+/// Represents a blob in the data availability layer.
+#[derive(Debug, Clone)]
+pub struct DataAvailabilityBlob {
+    pub l1_batch_number: L1BatchNumber,
+    pub blob_id: String,
+    pub inclusion_data: Option<Vec<u8>>,
+    pub sent_at: NaiveDateTime,
+}
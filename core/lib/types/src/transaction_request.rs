@@ -17,7 +17,7 @@ use crate::{
     l2::{L2Tx, TransactionType},
     web3::{keccak256, AccessList, Bytes},
     Address, EIP712TypedStructure, Eip712Domain, L1TxCommonData, L2ChainId, Nonce,
-    PackedEthSignature, StructBuilder, LEGACY_TX_TYPE, U256, U64,
+    PackedEthSignature, ProtocolVersionId, StructBuilder, LEGACY_TX_TYPE, U256, U64,
 };
 
 /// Call contract request (eth_call / eth_estimateGas)
@@ -70,6 +70,8 @@ pub struct CallRequest {
 /// sometimes users may want to override those.
 pub struct CallOverrides {
     pub enforced_base_fee: Option<u64>,
+    /// Protocol version whose VM rules should be used instead of the version active at the target block.
+    pub protocol_version: Option<ProtocolVersionId>,
 }
 
 impl CallRequest {
@@ -90,7 +92,10 @@ impl CallRequest {
             None
         };
 
-        Ok(CallOverrides { enforced_base_fee })
+        Ok(CallOverrides {
+            enforced_base_fee,
+            protocol_version: None,
+        })
     }
 }
 
@@ -51,6 +51,27 @@ pub enum MemoryDirection {
     Write,
 }
 
+/// Opcode executed by the VM, as recorded by the opcode tracer.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct OpcodeStep {
+    pub pc: u16,
+    pub op: String,
+    /// Gas (ergs) remaining in the current frame before the opcode is executed.
+    pub gas: u64,
+    /// Depth of the call stack, including near calls.
+    pub depth: usize,
+    pub contract_address: Address,
+}
+
+/// Opcodes recorded by the opcode tracer.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct OpcodeTrace {
+    pub steps: Vec<OpcodeStep>,
+    /// Whether recording stopped because the step limit was reached.
+    pub truncated: bool,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ContractSourceDebugInfo {
     pub assembly_code: String,
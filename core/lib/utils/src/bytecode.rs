@@ -1,6 +1,7 @@
 use std::{collections::HashMap, convert::TryInto};
 
 use itertools::Itertools;
+use serde::{Deserialize, Serialize};
 use zksync_basic_types::{
     ethabi::{encode, Token},
     H256,
@@ -163,6 +164,28 @@ pub fn hash_bytecode(code: &[u8]) -> H256 {
     H256(hash)
 }
 
+/// Kind of a bytecode as encoded in the version byte of its hash.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[repr(u8)]
+pub enum BytecodeMarker {
+    /// Native EraVM bytecode.
+    EraVm = 1,
+    /// EVM bytecode executed by the EVM emulator.
+    Evm = 2,
+}
+
+impl BytecodeMarker {
+    /// Parses the bytecode kind from the bytecode hash. Returns `None` if the version byte is unknown.
+    pub fn new(bytecode_hash: H256) -> Option<Self> {
+        Some(match bytecode_hash[0] {
+            val if val == Self::EraVm as u8 => Self::EraVm,
+            val if val == Self::Evm as u8 => Self::Evm,
+            _ => return None,
+        })
+    }
+}
+
 pub fn bytecode_len_in_words(bytecodehash: &H256) -> u16 {
     u16::from_be_bytes([bytecodehash[2], bytecodehash[3]])
 }
@@ -209,6 +232,20 @@ mod test {
         assert_eq!(example_code, decompressed);
     }
 
+    #[test]
+    fn parsing_bytecode_marker() {
+        let era_vm_hash = hash_bytecode(&[0; 32]);
+        assert_eq!(
+            BytecodeMarker::new(era_vm_hash),
+            Some(BytecodeMarker::EraVm)
+        );
+
+        let mut evm_hash = era_vm_hash;
+        evm_hash.0[0] = 2;
+        assert_eq!(BytecodeMarker::new(evm_hash), Some(BytecodeMarker::Evm));
+        assert_eq!(BytecodeMarker::new(H256::zero()), None);
+    }
+
     #[test]
     fn bytecode_compression_statistics_test() {
         let example_code =
@@ -1,7 +1,15 @@
 //! This module contains the observability subsystem.
 //! It is responsible for providing a centralized interface for consistent observability configuration.
 
-use std::{backtrace::Backtrace, borrow::Cow, panic::PanicInfo, str::FromStr};
+use std::{
+    backtrace::Backtrace,
+    borrow::Cow,
+    collections::HashMap,
+    future::Future,
+    panic::PanicInfo,
+    str::FromStr,
+    sync::{Arc, OnceLock},
+};
 
 // Temporary re-export of `sentry::capture_message` aiming to simplify the transition from `vlog` to using
 // crates directly.
@@ -15,19 +23,32 @@ use opentelemetry::{
 };
 use opentelemetry_otlp::WithExportConfig;
 use opentelemetry_semantic_conventions::resource::SERVICE_NAME;
-pub use sentry::{capture_message, Level as AlertLevel};
-use sentry::{types::Dsn, ClientInitGuard};
+pub use sentry::{capture_error, capture_message, Level as AlertLevel};
+use sentry::{types::Dsn, ClientInitGuard, Hub, SentryFutureExt};
 use serde::{de::Error, Deserialize, Deserializer};
-use tracing_opentelemetry::OpenTelemetryLayer;
+use tracing_opentelemetry::{OpenTelemetryLayer, OpenTelemetrySpanExt};
 use tracing_subscriber::{
     filter::Filtered,
     fmt,
     layer::{Layered, SubscriberExt},
     registry::LookupSpan,
+    reload,
     util::SubscriberInitExt,
-    EnvFilter, Layer,
+    EnvFilter, Layer, Registry,
+};
+
+use crate::sampling::SamplingFilter;
+pub use crate::{
+    request_id::{RequestId, RequestIdGuard, RequestIdParseError, WithRequestId},
+    sampling::{LogSamplingDirectives, LogSamplingError},
 };
 
+mod request_id;
+mod sampling;
+
+/// Names of the W3C Trace Context headers.
+const TRACE_CONTEXT_HEADERS: [&str; 2] = ["traceparent", "tracestate"];
+
 type TracingLayer<Inner> =
     Layered<Filtered<OpenTelemetryLayer<Inner, Tracer>, EnvFilter, Inner>, Inner>;
 
@@ -133,6 +154,106 @@ pub struct OpenTelemetryOptions {
     pub otlp_endpoint: String,
     /// Logical service name to be used for exported events. See [`SERVICE_NAME`].
     pub service_name: String,
+    /// Ratio of traces to sample, from 0 to 1. Traces with a sampled remote parent are always sampled.
+    pub sampling_ratio: Option<f64>,
+    /// Additional resource attributes attached to all exported spans, e.g. `deployment.environment`.
+    pub resource_attributes: OpenTelemetryResourceAttributes,
+}
+
+/// Error parsing [`OpenTelemetryResourceAttributes`].
+#[derive(Debug)]
+pub struct OpenTelemetryResourceAttributesError(String);
+
+impl std::fmt::Display for OpenTelemetryResourceAttributesError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for OpenTelemetryResourceAttributesError {}
+
+/// OpenTelemetry resource attributes in the format used by the `OTEL_RESOURCE_ATTRIBUTES` env variable,
+/// i.e. comma-separated `key=value` pairs (e.g., `deployment.environment=testnet,service.instance.id=en-0`).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct OpenTelemetryResourceAttributes(Vec<(String, String)>);
+
+impl OpenTelemetryResourceAttributes {
+    fn to_key_values(&self) -> impl Iterator<Item = KeyValue> + '_ {
+        self.0
+            .iter()
+            .map(|(key, value)| KeyValue::new(key.clone(), value.clone()))
+    }
+}
+
+impl FromStr for OpenTelemetryResourceAttributes {
+    type Err = OpenTelemetryResourceAttributesError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let attributes = s
+            .split(',')
+            .map(str::trim)
+            .filter(|pair| !pair.is_empty())
+            .map(|pair| {
+                let (key, value) = pair.split_once('=').ok_or_else(|| {
+                    OpenTelemetryResourceAttributesError(format!(
+                        "attribute `{pair}` has no `=` separator"
+                    ))
+                })?;
+                let key = key.trim();
+                if key.is_empty() {
+                    return Err(OpenTelemetryResourceAttributesError(format!(
+                        "attribute `{pair}` has an empty key"
+                    )));
+                }
+                Ok((key.to_owned(), value.trim().to_owned()))
+            });
+        attributes.collect::<Result<_, _>>().map(Self)
+    }
+}
+
+/// Handle for the log directives set by [`ObservabilityBuilder::build()`].
+static LOG_DIRECTIVES: OnceLock<LogDirectivesHandle> = OnceLock::new();
+
+#[derive(Debug)]
+pub struct LogDirectivesError(String);
+
+impl std::fmt::Display for LogDirectivesError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for LogDirectivesError {}
+
+/// Handle allowing to change log directives (in the `RUST_LOG` format) at runtime, e.g. to enable debug logs
+/// for a single component without restarting the process. Only affects logs; spans exported via OpenTelemetry
+/// are filtered independently.
+#[derive(Debug, Clone)]
+pub struct LogDirectivesHandle(reload::Handle<EnvFilter, Registry>);
+
+impl LogDirectivesHandle {
+    /// Returns the handle for the observability subsystem initialized in this process, or `None`
+    /// if the subsystem is not initialized.
+    pub fn global() -> Option<&'static Self> {
+        LOG_DIRECTIVES.get()
+    }
+
+    /// Returns the currently used log directives.
+    pub fn current(&self) -> Result<String, LogDirectivesError> {
+        self.0
+            .with_current(ToString::to_string)
+            .map_err(|err| LogDirectivesError(err.to_string()))
+    }
+
+    /// Replaces log directives. Returns an error if the directives cannot be parsed; in this case,
+    /// the current directives are retained.
+    pub fn update(&self, directives: &str) -> Result<(), LogDirectivesError> {
+        let filter = EnvFilter::try_new(directives)
+            .map_err(|err| LogDirectivesError(format!("invalid log directives: {err}")))?;
+        self.0
+            .reload(filter)
+            .map_err(|err| LogDirectivesError(err.to_string()))
+    }
 }
 
 /// Builder for the observability subsystem.
@@ -141,8 +262,10 @@ pub struct OpenTelemetryOptions {
 pub struct ObservabilityBuilder {
     log_format: LogFormat,
     log_directives: Option<String>,
+    log_sampling: LogSamplingDirectives,
     sentry_url: Option<Dsn>,
     sentry_environment: Option<String>,
+    sentry_release: Option<String>,
     opentelemetry_options: Option<OpenTelemetryOptions>,
 }
 
@@ -150,6 +273,16 @@ pub struct ObservabilityBuilder {
 /// Releases configured integrations upon being dropped.
 pub struct ObservabilityGuard {
     _sentry_guard: Option<ClientInitGuard>,
+    opentelemetry_enabled: bool,
+}
+
+impl Drop for ObservabilityGuard {
+    fn drop(&mut self) {
+        if self.opentelemetry_enabled {
+            // Flushes the spans that are not exported yet.
+            opentelemetry::global::shutdown_tracer_provider();
+        }
+    }
 }
 
 impl std::fmt::Debug for ObservabilityGuard {
@@ -176,6 +309,12 @@ impl ObservabilityBuilder {
         self
     }
 
+    /// Sets per-target sampling / rate limiting for logs. By default, logs are not sampled.
+    pub fn with_log_sampling(mut self, directives: LogSamplingDirectives) -> Self {
+        self.log_sampling = directives;
+        self
+    }
+
     /// Enables Sentry integration.
     /// Returns an error if the provided Sentry URL is invalid.
     pub fn with_sentry_url(
@@ -194,6 +333,13 @@ impl ObservabilityBuilder {
         self
     }
 
+    /// Sets the release name reported in Sentry events, e.g. `zksync_server@24.7.0`.
+    /// If not set, the name and version of the `vlog` crate will be used.
+    pub fn with_sentry_release(mut self, release: Option<String>) -> Self {
+        self.sentry_release = release;
+        self
+    }
+
     pub fn with_opentelemetry(
         mut self,
         opentelemetry_level: &str,
@@ -204,20 +350,38 @@ impl ObservabilityBuilder {
             opentelemetry_level: opentelemetry_level.parse()?,
             otlp_endpoint,
             service_name,
+            sampling_ratio: None,
+            resource_attributes: OpenTelemetryResourceAttributes::default(),
         });
         Ok(self)
     }
 
-    fn add_opentelemetry_layer<S>(
-        opentelemetry_level: OpenTelemetryLevel,
-        otlp_endpoint: String,
-        service_name: String,
-        subscriber: S,
-    ) -> TracingLayer<S>
+    /// Sets the ratio of traces exported via OpenTelemetry. Has no effect if OpenTelemetry is not enabled
+    /// via [`Self::with_opentelemetry()`] beforehand. By default, all traces are exported.
+    pub fn with_opentelemetry_sampling_ratio(mut self, sampling_ratio: f64) -> Self {
+        if let Some(options) = &mut self.opentelemetry_options {
+            options.sampling_ratio = Some(sampling_ratio);
+        }
+        self
+    }
+
+    /// Sets additional resource attributes attached to all spans exported via OpenTelemetry. Has no effect
+    /// if OpenTelemetry is not enabled via [`Self::with_opentelemetry()`] beforehand.
+    pub fn with_opentelemetry_resource_attributes(
+        mut self,
+        attributes: OpenTelemetryResourceAttributes,
+    ) -> Self {
+        if let Some(options) = &mut self.opentelemetry_options {
+            options.resource_attributes = attributes;
+        }
+        self
+    }
+
+    fn add_opentelemetry_layer<S>(options: OpenTelemetryOptions, subscriber: S) -> TracingLayer<S>
     where
         S: tracing::Subscriber + for<'span> LookupSpan<'span> + Send + Sync,
     {
-        let filter = match opentelemetry_level {
+        let filter = match options.opentelemetry_level {
             OpenTelemetryLevel::OFF => EnvFilter::new("off"),
             OpenTelemetryLevel::INFO => EnvFilter::new("info"),
             OpenTelemetryLevel::DEBUG => EnvFilter::new("debug"),
@@ -229,18 +393,25 @@ impl ObservabilityBuilder {
             .add_directive("otel::tracing=trace".parse().unwrap())
             .add_directive("otel=debug".parse().unwrap());
 
-        let resource = vec![KeyValue::new(SERVICE_NAME, service_name)];
+        let resource: Vec<_> = options
+            .resource_attributes
+            .to_key_values()
+            .chain([KeyValue::new(SERVICE_NAME, options.service_name)])
+            .collect();
+        let sampler = options
+            .sampling_ratio
+            .map_or(Sampler::AlwaysOn, Sampler::TraceIdRatioBased);
 
         let tracer = opentelemetry_otlp::new_pipeline()
             .tracing()
             .with_exporter(
                 opentelemetry_otlp::new_exporter()
                     .http()
-                    .with_endpoint(otlp_endpoint),
+                    .with_endpoint(options.otlp_endpoint),
             )
             .with_trace_config(
                 trace::config()
-                    .with_sampler(Sampler::AlwaysOn)
+                    .with_sampler(Sampler::ParentBased(Box::new(sampler)))
                     .with_id_generator(RandomIdGenerator::default())
                     .with_resource(Resource::new(resource)),
             )
@@ -257,25 +428,27 @@ impl ObservabilityBuilder {
     /// Initializes the observability subsystem.
     pub fn build(self) -> ObservabilityGuard {
         // Initialize logs.
+        let opentelemetry_enabled = self.opentelemetry_options.is_some();
 
         let env_filter = if let Some(log_directives) = self.log_directives {
             tracing_subscriber::EnvFilter::new(log_directives)
         } else {
             tracing_subscriber::EnvFilter::from_default_env()
         };
+        let (env_filter, log_directives_handle) = reload::Layer::new(env_filter);
+        LOG_DIRECTIVES
+            .set(LogDirectivesHandle(log_directives_handle))
+            .ok();
+        let log_filter = SamplingFilter::new(env_filter, self.log_sampling);
 
         match self.log_format {
             LogFormat::Plain => {
+                // The log filter is applied to the logging layer only, so that spans exported via OpenTelemetry
+                // are filtered independently.
                 let subscriber = tracing_subscriber::registry()
-                    .with(env_filter)
-                    .with(fmt::Layer::default());
+                    .with(fmt::Layer::default().with_filter(log_filter));
                 if let Some(opts) = self.opentelemetry_options {
-                    let subscriber = Self::add_opentelemetry_layer(
-                        opts.opentelemetry_level,
-                        opts.otlp_endpoint,
-                        opts.service_name,
-                        subscriber,
-                    );
+                    let subscriber = Self::add_opentelemetry_layer(opts, subscriber);
                     subscriber.init()
                 } else {
                     subscriber.init()
@@ -283,20 +456,16 @@ impl ObservabilityBuilder {
             }
             LogFormat::Json => {
                 let timer = tracing_subscriber::fmt::time::UtcTime::rfc_3339();
-                let subscriber = tracing_subscriber::registry().with(env_filter).with(
+                let subscriber = tracing_subscriber::registry().with(
                     fmt::Layer::default()
                         .with_file(true)
                         .with_line_number(true)
                         .with_timer(timer)
-                        .json(),
+                        .json()
+                        .with_filter(log_filter),
                 );
                 if let Some(opts) = self.opentelemetry_options {
-                    let subscriber = Self::add_opentelemetry_layer(
-                        opts.opentelemetry_level,
-                        opts.otlp_endpoint,
-                        opts.service_name,
-                        subscriber,
-                    );
+                    let subscriber = Self::add_opentelemetry_layer(opts, subscriber);
                     subscriber.init()
                 } else {
                     subscriber.init()
@@ -317,8 +486,9 @@ impl ObservabilityBuilder {
 
         // Initialize the Sentry.
         let sentry_guard = if let Some(sentry_url) = self.sentry_url {
+            let release = self.sentry_release.map(Cow::from);
             let options = sentry::ClientOptions {
-                release: sentry::release_name!(),
+                release: release.or_else(|| sentry::release_name!()),
                 environment: self.sentry_environment.map(Cow::from),
                 attach_stacktrace: true,
                 ..Default::default()
@@ -331,10 +501,45 @@ impl ObservabilityBuilder {
 
         ObservabilityGuard {
             _sentry_guard: sentry_guard,
+            opentelemetry_enabled,
         }
     }
 }
 
+/// Sets a tag attached to all Sentry events reported by the process, e.g. the chain ID. Has no effect
+/// if Sentry is not configured. Should be called before spawning tasks, since threads that have already
+/// reported to Sentry don't observe new tags.
+pub fn set_sentry_tag(key: &str, value: impl ToString) {
+    sentry::configure_scope(|scope| scope.set_tag(key, value));
+}
+
+/// Binds the provided future to a separate Sentry scope with the `component` tag, so that panics
+/// and errors reported from the future can be attributed to the component. Tags set via [`set_sentry_tag()`]
+/// before calling this function are inherited.
+pub fn with_sentry_component<F: Future>(
+    component: &str,
+    future: F,
+) -> impl Future<Output = F::Output> {
+    let hub = Arc::new(Hub::new_from_top(Hub::current()));
+    hub.configure_scope(|scope| scope.set_tag("component", component));
+    future.bind_hub(hub)
+}
+
+/// Sets the remote span propagated via W3C Trace Context headers (`traceparent` and `tracestate`)
+/// as the parent of the provided span, so that the span is exported as a part of the remote trace.
+/// `get_header` must return the value of the header with the specified name.
+///
+/// Has no effect if OpenTelemetry is not enabled, or if the headers are missing or malformed.
+pub fn set_remote_parent<'a>(span: &tracing::Span, get_header: impl Fn(&str) -> Option<&'a str>) {
+    let headers: HashMap<String, String> = TRACE_CONTEXT_HEADERS
+        .into_iter()
+        .filter_map(|name| Some((name.to_owned(), get_header(name)?.to_owned())))
+        .collect();
+    let context =
+        opentelemetry::global::get_text_map_propagator(|propagator| propagator.extract(&headers));
+    span.set_parent(context);
+}
+
 fn json_panic_handler(panic_info: &PanicInfo) {
     let backtrace = Backtrace::force_capture();
     let timestamp = chrono::Utc::now();
@@ -367,3 +572,35 @@ fn json_panic_handler(panic_info: &PanicInfo) {
         })
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parsing_resource_attributes() {
+        let attributes: OpenTelemetryResourceAttributes =
+            "deployment.environment=testnet, service.instance.id = en-0,"
+                .parse()
+                .unwrap();
+        assert_eq!(
+            attributes.0,
+            [
+                ("deployment.environment".to_owned(), "testnet".to_owned()),
+                ("service.instance.id".to_owned(), "en-0".to_owned()),
+            ]
+        );
+
+        let attributes: OpenTelemetryResourceAttributes = "".parse().unwrap();
+        assert_eq!(attributes, OpenTelemetryResourceAttributes::default());
+
+        let err = "deployment.environment"
+            .parse::<OpenTelemetryResourceAttributes>()
+            .unwrap_err();
+        assert!(err.to_string().contains("no `=`"), "{err}");
+        let err = "=testnet"
+            .parse::<OpenTelemetryResourceAttributes>()
+            .unwrap_err();
+        assert!(err.to_string().contains("empty key"), "{err}");
+    }
+}
@@ -0,0 +1,157 @@
+//! Request IDs allowing to correlate logs, DB queries and outbound calls made while serving a single request.
+
+use std::{
+    cell::Cell,
+    fmt,
+    future::Future,
+    pin::Pin,
+    str::FromStr,
+    task::{Context, Poll},
+};
+
+use pin_project_lite::pin_project;
+
+thread_local! {
+    static CURRENT_REQUEST_ID: Cell<Option<RequestId>> = const { Cell::new(None) };
+}
+
+/// Randomly generated request ID. Displayed as 16 hex digits.
+///
+/// The ID is set for the current thread while a future wrapped with [`Self::scope()`] is polled,
+/// so it can be accessed via [`Self::current()`] by any code called from the future (e.g., DB access
+/// or HTTP clients). The ID is not propagated to spawned tasks.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RequestId(u64);
+
+impl fmt::Debug for RequestId {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, formatter)
+    }
+}
+
+impl fmt::Display for RequestId {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "{:016x}", self.0)
+    }
+}
+
+/// Error parsing [`RequestId`].
+#[derive(Debug)]
+pub struct RequestIdParseError(());
+
+impl fmt::Display for RequestIdParseError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("request ID must consist of 1 to 16 hex digits")
+    }
+}
+
+impl std::error::Error for RequestIdParseError {}
+
+impl FromStr for RequestId {
+    type Err = RequestIdParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() || s.len() > 16 {
+            return Err(RequestIdParseError(()));
+        }
+        u64::from_str_radix(s, 16)
+            .map(Self)
+            .map_err(|_| RequestIdParseError(()))
+    }
+}
+
+impl RequestId {
+    /// HTTP header used to propagate request IDs between nodes.
+    pub const HEADER: &'static str = "x-request-id";
+
+    /// Generates a new random ID.
+    pub fn generate() -> Self {
+        Self(rand::random())
+    }
+
+    /// Returns the ID of the request currently being served by this thread, if any.
+    pub fn current() -> Option<Self> {
+        CURRENT_REQUEST_ID.with(Cell::get)
+    }
+
+    /// Sets this ID as current until the returned guard is dropped.
+    pub fn enter(self) -> RequestIdGuard {
+        let prev = CURRENT_REQUEST_ID.with(|cell| cell.replace(Some(self)));
+        RequestIdGuard { prev }
+    }
+
+    /// Wraps the provided future so that this ID is set as current each time the future is polled.
+    pub fn scope<F: Future>(self, future: F) -> WithRequestId<F> {
+        WithRequestId {
+            inner: future,
+            request_id: self,
+        }
+    }
+}
+
+/// Guard returned by [`RequestId::enter()`]. Restores the previous request ID on drop.
+#[must_use = "request ID is reset when the guard is dropped"]
+#[derive(Debug)]
+pub struct RequestIdGuard {
+    prev: Option<RequestId>,
+}
+
+impl Drop for RequestIdGuard {
+    fn drop(&mut self) {
+        CURRENT_REQUEST_ID.with(|cell| cell.set(self.prev));
+    }
+}
+
+pin_project! {
+    /// Future returned by [`RequestId::scope()`].
+    #[derive(Debug)]
+    pub struct WithRequestId<F> {
+        #[pin]
+        inner: F,
+        request_id: RequestId,
+    }
+}
+
+impl<F: Future> Future for WithRequestId<F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let projection = self.project();
+        let _guard = projection.request_id.enter();
+        projection.inner.poll(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn request_id_roundtrip() {
+        let id = RequestId::generate();
+        let id_str = id.to_string();
+        assert_eq!(id_str.len(), 16);
+        assert_eq!(id_str.parse::<RequestId>().unwrap(), id);
+
+        for invalid in ["", "not hex", "0123456789abcdef0", "-1"] {
+            invalid.parse::<RequestId>().unwrap_err();
+        }
+    }
+
+    #[test]
+    fn entering_request_id() {
+        assert_eq!(RequestId::current(), None);
+        let id = RequestId::generate();
+        {
+            let _guard = id.enter();
+            assert_eq!(RequestId::current(), Some(id));
+            let nested_id = RequestId::generate();
+            {
+                let _guard = nested_id.enter();
+                assert_eq!(RequestId::current(), Some(nested_id));
+            }
+            assert_eq!(RequestId::current(), Some(id));
+        }
+        assert_eq!(RequestId::current(), None);
+    }
+}
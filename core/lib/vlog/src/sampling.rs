@@ -0,0 +1,317 @@
+//! Sampling and rate limiting for high-volume log targets.
+
+use std::{
+    collections::HashMap,
+    fmt,
+    str::FromStr,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use tracing::{callsite, span, subscriber::Interest, Event, Metadata};
+use tracing_subscriber::layer::{Context, Filter};
+
+/// Interval between summaries of suppressed log events.
+const SUMMARY_INTERVAL: Duration = Duration::from_secs(60);
+/// Target of log events summarizing suppressed events.
+const SUMMARY_TARGET: &str = "vlog::sampling";
+
+/// Sampling strategy for a single log target.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum SamplingRule {
+    /// Log one of `n` events from the same callsite.
+    OneIn(u64),
+    /// Log at most one event from the same callsite per the specified interval.
+    RateLimit(Duration),
+}
+
+/// Error parsing [`LogSamplingDirectives`].
+#[derive(Debug)]
+pub struct LogSamplingError(String);
+
+impl fmt::Display for LogSamplingError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for LogSamplingError {}
+
+/// Per-target log sampling directives, e.g. `zksync_eth_sender=100,zksync_node_sync::fetcher=10s`.
+///
+/// Each directive has the `{target}={rule}` format. The rule either has the `{n}` format, meaning that one of `n` events
+/// from the same callsite (i.e., the same `tracing` macro invocation) is logged, or the `{n}s` format, meaning that at most
+/// one event from the same callsite is logged per `n` seconds. Targets are matched by prefix, similar to `RUST_LOG`;
+/// if several targets match an event, the most specific one is used. Events from targets without directives are not sampled.
+///
+/// The number of suppressed events is periodically logged with the `vlog::sampling` target, so that suppression
+/// is visible in the logs.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LogSamplingDirectives {
+    rules: Vec<(String, SamplingRule)>,
+}
+
+impl FromStr for LogSamplingDirectives {
+    type Err = LogSamplingError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut rules = vec![];
+        for directive in s.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+            let (target, rule) = directive.split_once('=').ok_or_else(|| {
+                LogSamplingError(format!("directive `{directive}` has no `=` separator"))
+            })?;
+            let target = target.trim();
+            if target.is_empty() {
+                return Err(LogSamplingError(format!(
+                    "directive `{directive}` has an empty target"
+                )));
+            }
+
+            let rule = rule.trim();
+            let rule = if let Some(secs) = rule.strip_suffix('s') {
+                let secs: u64 = secs.parse().map_err(|err| {
+                    LogSamplingError(format!("invalid interval in `{directive}`: {err}"))
+                })?;
+                SamplingRule::RateLimit(Duration::from_secs(secs))
+            } else {
+                let n: u64 = rule.parse().map_err(|err| {
+                    LogSamplingError(format!("invalid sampling rate in `{directive}`: {err}"))
+                })?;
+                SamplingRule::OneIn(n)
+            };
+            if matches!(
+                rule,
+                SamplingRule::OneIn(0) | SamplingRule::RateLimit(Duration::ZERO)
+            ) {
+                return Err(LogSamplingError(format!(
+                    "sampling rate in `{directive}` must be positive"
+                )));
+            }
+            rules.push((target.to_owned(), rule));
+        }
+        // Sort rules so that the most specific targets are checked first.
+        rules.sort_by(|(target, _), (other_target, _)| other_target.len().cmp(&target.len()));
+        Ok(Self { rules })
+    }
+}
+
+impl LogSamplingDirectives {
+    pub(crate) fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    fn rule_for(&self, target: &str) -> Option<SamplingRule> {
+        self.rules.iter().find_map(|(rule_target, rule)| {
+            let is_match = target
+                .strip_prefix(rule_target.as_str())
+                .map_or(false, |rest| rest.is_empty() || rest.starts_with("::"));
+            is_match.then_some(*rule)
+        })
+    }
+}
+
+#[derive(Debug, Default)]
+struct CallsiteState {
+    event_count: u64,
+    last_logged_at: Option<Instant>,
+    suppressed_count: u64,
+}
+
+impl CallsiteState {
+    fn should_log(&mut self, rule: SamplingRule, now: Instant) -> bool {
+        let should_log = match rule {
+            SamplingRule::OneIn(n) => self.event_count % n == 0,
+            SamplingRule::RateLimit(interval) => self
+                .last_logged_at
+                .map_or(true, |logged_at| now.duration_since(logged_at) >= interval),
+        };
+        self.event_count += 1;
+        if should_log {
+            self.last_logged_at = Some(now);
+        } else {
+            self.suppressed_count += 1;
+        }
+        should_log
+    }
+}
+
+type CallsiteStates = HashMap<callsite::Identifier, (&'static Metadata<'static>, CallsiteState)>;
+type SharedCallsiteStates = Arc<Mutex<CallsiteStates>>;
+
+/// Per-layer filter wrapping the log directives filter and sampling events for the targets
+/// specified in [`LogSamplingDirectives`].
+#[derive(Debug)]
+pub(crate) struct SamplingFilter<F> {
+    inner: F,
+    directives: LogSamplingDirectives,
+    states: SharedCallsiteStates,
+}
+
+impl<F> SamplingFilter<F> {
+    pub(crate) fn new(inner: F, directives: LogSamplingDirectives) -> Self {
+        let states = SharedCallsiteStates::default();
+        if !directives.is_empty() {
+            let states = Arc::downgrade(&states);
+            std::thread::Builder::new()
+                .name("vlog-sampling".to_owned())
+                .spawn(move || loop {
+                    std::thread::sleep(SUMMARY_INTERVAL);
+                    let Some(states) = states.upgrade() else {
+                        return;
+                    };
+                    report_suppressed_events(&states);
+                })
+                .expect("failed spawning log sampling thread");
+        }
+        Self {
+            inner,
+            directives,
+            states,
+        }
+    }
+
+    fn sample(&self, metadata: &'static Metadata<'static>) -> bool {
+        let Some(rule) = self.directives.rule_for(metadata.target()) else {
+            return true;
+        };
+        let mut states = self.states.lock().expect("log sampling state is poisoned");
+        let (_, state) = states
+            .entry(metadata.callsite())
+            .or_insert_with(|| (metadata, CallsiteState::default()));
+        state.should_log(rule, Instant::now())
+    }
+}
+
+/// Logs and resets suppressed event counters. Must be called outside the `tracing` dispatch.
+fn report_suppressed_events(states: &Mutex<CallsiteStates>) {
+    let mut suppressed = vec![];
+    {
+        let mut states = states.lock().expect("log sampling state is poisoned");
+        for (metadata, state) in states.values_mut() {
+            if state.suppressed_count > 0 {
+                suppressed.push((*metadata, state.suppressed_count));
+                state.suppressed_count = 0;
+            }
+        }
+    }
+
+    for (metadata, count) in suppressed {
+        tracing::warn!(
+            target: SUMMARY_TARGET,
+            "Suppressed {count} `{}` events from `{}` ({}:{}) in the last {SUMMARY_INTERVAL:?}",
+            metadata.level(),
+            metadata.target(),
+            metadata.file().unwrap_or("?"),
+            metadata.line().unwrap_or(0)
+        );
+    }
+}
+
+impl<S, F: Filter<S>> Filter<S> for SamplingFilter<F> {
+    fn enabled(&self, metadata: &Metadata<'_>, cx: &Context<'_, S>) -> bool {
+        self.inner.enabled(metadata, cx)
+    }
+
+    fn callsite_enabled(&self, metadata: &'static Metadata<'static>) -> Interest {
+        let interest = self.inner.callsite_enabled(metadata);
+        if metadata.is_event()
+            && interest.is_always()
+            && self.directives.rule_for(metadata.target()).is_some()
+        {
+            // Sampled events must be checked each time.
+            Interest::sometimes()
+        } else {
+            interest
+        }
+    }
+
+    fn event_enabled(&self, event: &Event<'_>, cx: &Context<'_, S>) -> bool {
+        self.inner.event_enabled(event, cx) && self.sample(event.metadata())
+    }
+
+    fn max_level_hint(&self) -> Option<tracing::level_filters::LevelFilter> {
+        self.inner.max_level_hint()
+    }
+
+    fn on_new_span(&self, attrs: &span::Attributes<'_>, id: &span::Id, cx: Context<'_, S>) {
+        self.inner.on_new_span(attrs, id, cx);
+    }
+
+    fn on_record(&self, id: &span::Id, values: &span::Record<'_>, cx: Context<'_, S>) {
+        self.inner.on_record(id, values, cx);
+    }
+
+    fn on_enter(&self, id: &span::Id, cx: Context<'_, S>) {
+        self.inner.on_enter(id, cx);
+    }
+
+    fn on_exit(&self, id: &span::Id, cx: Context<'_, S>) {
+        self.inner.on_exit(id, cx);
+    }
+
+    fn on_close(&self, id: span::Id, cx: Context<'_, S>) {
+        self.inner.on_close(id, cx);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parsing_sampling_directives() {
+        let directives: LogSamplingDirectives =
+            "zksync_eth_sender=100, zksync_node_sync::fetcher=10s"
+                .parse()
+                .unwrap();
+        assert_eq!(
+            directives.rule_for("zksync_node_sync::fetcher"),
+            Some(SamplingRule::RateLimit(Duration::from_secs(10)))
+        );
+        assert_eq!(
+            directives.rule_for("zksync_eth_sender::eth_tx_manager"),
+            Some(SamplingRule::OneIn(100))
+        );
+        assert_eq!(directives.rule_for("zksync_eth_sender_v2"), None);
+        assert_eq!(directives.rule_for("zksync_node_sync::sync_action"), None);
+
+        let directives: LogSamplingDirectives = "".parse().unwrap();
+        assert!(directives.is_empty());
+
+        for invalid in ["zksync", "=10", "zksync=0", "zksync=-1", "zksync=ms"] {
+            invalid.parse::<LogSamplingDirectives>().unwrap_err();
+        }
+    }
+
+    #[test]
+    fn most_specific_sampling_directive_is_used() {
+        let directives: LogSamplingDirectives = "zksync=10,zksync::api=100".parse().unwrap();
+        assert_eq!(
+            directives.rule_for("zksync::api::web3"),
+            Some(SamplingRule::OneIn(100))
+        );
+        assert_eq!(
+            directives.rule_for("zksync::state"),
+            Some(SamplingRule::OneIn(10))
+        );
+    }
+
+    #[test]
+    fn sampling_callsite_events() {
+        let start = Instant::now();
+        let mut state = CallsiteState::default();
+        let logged: Vec<_> = (0..7)
+            .map(|_| state.should_log(SamplingRule::OneIn(3), start))
+            .collect();
+        assert_eq!(logged, [true, false, false, true, false, false, true]);
+        assert_eq!(state.suppressed_count, 4);
+
+        let mut state = CallsiteState::default();
+        let rule = SamplingRule::RateLimit(Duration::from_secs(10));
+        assert!(state.should_log(rule, start));
+        assert!(!state.should_log(rule, start + Duration::from_secs(5)));
+        assert!(state.should_log(rule, start + Duration::from_secs(10)));
+        assert!(!state.should_log(rule, start + Duration::from_secs(11)));
+        assert_eq!(state.suppressed_count, 2);
+    }
+}
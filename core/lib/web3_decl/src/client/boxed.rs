@@ -15,7 +15,7 @@ use super::{ForNetwork, Network, TaggedClient};
 pub struct RawParams(pub(super) Option<Box<JsonRawValue>>);
 
 impl RawParams {
-    fn new(params: impl ToRpcParams) -> Result<Self, serde_json::Error> {
+    pub(super) fn new(params: impl ToRpcParams) -> Result<Self, serde_json::Error> {
         params.to_rpc_params().map(Self)
     }
 }
@@ -0,0 +1,191 @@
+//! Client caching responses for immutable data.
+
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use async_trait::async_trait;
+use jsonrpsee::core::{
+    client::{BatchResponse, ClientT, Error},
+    params::BatchRequestBuilder,
+    traits::ToRpcParams,
+};
+use serde::de::DeserializeOwned;
+use tokio::time::Instant;
+
+use super::{boxed::RawParams, ForNetwork, TaggedClient};
+
+type CacheKey = (String, String);
+
+#[derive(Debug)]
+struct ResponseCache {
+    entries: HashMap<CacheKey, (Instant, serde_json::Value)>,
+    ttl: Duration,
+    capacity: usize,
+}
+
+impl ResponseCache {
+    fn get(&mut self, key: &CacheKey) -> Option<serde_json::Value> {
+        let (inserted_at, value) = self.entries.get(key)?;
+        if inserted_at.elapsed() < self.ttl {
+            Some(value.clone())
+        } else {
+            self.entries.remove(key);
+            None
+        }
+    }
+
+    fn insert(&mut self, key: CacheKey, value: serde_json::Value) {
+        if self.entries.len() >= self.capacity {
+            let ttl = self.ttl;
+            self.entries
+                .retain(|_, (inserted_at, _)| inserted_at.elapsed() < ttl);
+        }
+        if self.entries.len() < self.capacity {
+            self.entries.insert(key, (Instant::now(), value));
+        }
+    }
+}
+
+/// JSON-RPC client wrapper caching responses for methods returning immutable data, such as blocks queried
+/// by hash or number and transaction receipts. Responses are cached for a short TTL, which bounds the staleness
+/// of cached data in case of a reorg.
+///
+/// Only requests are cached; notifications and batch requests are passed through to the wrapped client as-is.
+/// Empty (`null`) responses, e.g. for pending transactions, are never cached.
+#[derive(Clone)]
+pub struct CachingClient<C> {
+    inner: C,
+    cache: Arc<Mutex<ResponseCache>>,
+}
+
+impl<C: fmt::Debug> fmt::Debug for CachingClient<C> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cache = self.cache.lock().expect("response cache is poisoned");
+        formatter
+            .debug_struct("CachingClient")
+            .field("inner", &self.inner)
+            .field("ttl", &cache.ttl)
+            .field("capacity", &cache.capacity)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<C> CachingClient<C> {
+    /// Default maximum number of cached responses.
+    pub const DEFAULT_CAPACITY: usize = 1_024;
+
+    /// Wraps the provided client caching responses for the specified TTL.
+    pub fn new(inner: C, ttl: Duration) -> Self {
+        Self::with_capacity(inner, ttl, Self::DEFAULT_CAPACITY)
+    }
+
+    /// Wraps the provided client caching at most `capacity` responses for the specified TTL.
+    pub fn with_capacity(inner: C, ttl: Duration, capacity: usize) -> Self {
+        Self {
+            inner,
+            cache: Arc::new(Mutex::new(ResponseCache {
+                entries: HashMap::new(),
+                ttl,
+                capacity,
+            })),
+        }
+    }
+
+    /// Checks whether the response for the specified method and params is immutable and thus can be cached.
+    fn is_cacheable(method: &str, params: &RawParams) -> bool {
+        match method {
+            "eth_chainId" | "eth_getBlockByHash" | "eth_getTransactionReceipt" => true,
+            // Blocks can only be cached if they are requested by number rather than by a tag (e.g., `latest`).
+            "eth_getBlockByNumber" => {
+                let Some(params) = &params.0 else {
+                    return false;
+                };
+                let Ok(params) = serde_json::from_str::<Vec<serde_json::Value>>(params.get())
+                else {
+                    return false;
+                };
+                let block = params.first().and_then(serde_json::Value::as_str);
+                block.map_or(false, |block| block.starts_with("0x"))
+            }
+            _ => false,
+        }
+    }
+
+    fn cache_key(method: &str, params: &RawParams) -> CacheKey {
+        let params = params.0.as_ref().map_or("", |params| params.get());
+        (method.to_owned(), params.to_owned())
+    }
+}
+
+impl<C: ForNetwork> ForNetwork for CachingClient<C> {
+    type Net = C::Net;
+
+    fn network(&self) -> Self::Net {
+        self.inner.network()
+    }
+
+    fn component(&self) -> &'static str {
+        self.inner.component()
+    }
+}
+
+impl<C: TaggedClient> TaggedClient for CachingClient<C> {
+    fn set_component(&mut self, component_name: &'static str) {
+        self.inner.set_component(component_name);
+    }
+}
+
+#[async_trait]
+impl<C: ClientT + Send + Sync> ClientT for CachingClient<C> {
+    async fn notification<Params>(&self, method: &str, params: Params) -> Result<(), Error>
+    where
+        Params: ToRpcParams + Send,
+    {
+        self.inner.notification(method, params).await
+    }
+
+    async fn request<R, Params>(&self, method: &str, params: Params) -> Result<R, Error>
+    where
+        R: DeserializeOwned,
+        Params: ToRpcParams + Send,
+    {
+        let params = RawParams::new(params).map_err(Error::ParseError)?;
+        if !Self::is_cacheable(method, &params) {
+            return self.inner.request(method, params).await;
+        }
+
+        let key = Self::cache_key(method, &params);
+        let cached_value = self
+            .cache
+            .lock()
+            .expect("response cache is poisoned")
+            .get(&key);
+        let value = if let Some(value) = cached_value {
+            value
+        } else {
+            let value: serde_json::Value = self.inner.request(method, params).await?;
+            if !value.is_null() {
+                self.cache
+                    .lock()
+                    .expect("response cache is poisoned")
+                    .insert(key, value.clone());
+            }
+            value
+        };
+        serde_json::from_value(value).map_err(Error::ParseError)
+    }
+
+    async fn batch_request<'a, R>(
+        &self,
+        batch: BatchRequestBuilder<'a>,
+    ) -> Result<BatchResponse<'a, R>, Error>
+    where
+        R: DeserializeOwned + fmt::Debug + 'a,
+    {
+        self.inner.batch_request(batch).await
+    }
+}
@@ -0,0 +1,331 @@
+//! Client failing over among multiple endpoints.
+
+use std::{
+    fmt,
+    num::NonZeroUsize,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use anyhow::Context as _;
+use async_trait::async_trait;
+use jsonrpsee::{
+    core::{
+        client::{BatchResponse, ClientT, Error},
+        params::BatchRequestBuilder,
+        traits::ToRpcParams,
+    },
+    rpc_params,
+};
+use serde::de::DeserializeOwned;
+use tokio::sync::watch;
+use zksync_health_check::{Health, HealthStatus, HealthUpdater, ReactiveHealthCheck};
+use zksync_types::url::SensitiveUrl;
+
+use super::{
+    boxed::RawParams, CachingClient, Client, ClientBase, DynClient, ForNetwork, HttpClient,
+    Network, TaggedClient,
+};
+
+/// JSON-RPC client failing over among multiple endpoints serving the same network (e.g., main node replicas
+/// or multiple L1 RPC providers).
+///
+/// Requests are sent to the currently active endpoint. If a request fails with a transport-level error
+/// (including timeouts) or is rate-limited by the endpoint, the client switches to the next endpoint and retries the request there. Each endpoint
+/// is a separate [`Client`] with its own rate limit. Endpoints should be periodically probed using
+/// [`Self::probe_endpoints()`], which switches back to higher-priority endpoints once they recover.
+#[derive(Clone)]
+pub struct FailoverClient<Net, C = HttpClient> {
+    endpoints: Vec<Client<Net, C>>,
+    active_endpoint: Arc<AtomicUsize>,
+}
+
+impl<Net: fmt::Debug, C: 'static> fmt::Debug for FailoverClient<Net, C> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("FailoverClient")
+            .field("endpoints", &self.endpoints)
+            .field("active_endpoint", &self.active_endpoint)
+            .finish()
+    }
+}
+
+impl<Net: Network, C: ClientBase> FailoverClient<Net, C> {
+    /// JSON-RPC error codes used by L1 providers to signal that the request was rate-limited
+    /// (`-32005` is the "limit exceeded" code from EIP-1474; some providers mirror the HTTP 429 status).
+    const RATE_LIMIT_ERROR_CODES: &'static [i32] = &[-32005, 429];
+
+    /// Creates a client from the provided endpoints, listed in the priority order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `endpoints` are empty.
+    pub fn new(endpoints: Vec<Client<Net, C>>) -> Self {
+        assert!(!endpoints.is_empty(), "No endpoints provided");
+        Self {
+            endpoints,
+            active_endpoint: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    /// Returns a task probing endpoints of this client and reporting the active endpoint via a health check
+    /// with the specified name, or `None` if the client has a single endpoint.
+    pub fn prober(&self, health_check_name: &'static str) -> Option<FailoverClientProber<Net, C>> {
+        (self.endpoints.len() > 1)
+            .then(|| FailoverClientProber::new(self.clone(), health_check_name))
+    }
+
+    /// Returns the index of the currently active endpoint.
+    pub fn active_endpoint(&self) -> usize {
+        self.active_endpoint.load(Ordering::Relaxed)
+    }
+
+    /// Returns URLs of all endpoints in the priority order.
+    pub fn endpoint_urls(&self) -> impl Iterator<Item = &SensitiveUrl> + '_ {
+        self.endpoints.iter().map(|client| &client.url)
+    }
+
+    /// Probes all endpoints and switches to the highest-priority healthy endpoint, if any.
+    /// Returns the health status of each endpoint.
+    pub async fn probe_endpoints(&self) -> Vec<bool> {
+        let probes = self.endpoints.iter().map(|client| async move {
+            let response: Result<serde_json::Value, _> =
+                client.request("eth_chainId", rpc_params![]).await;
+            match response {
+                Ok(_) => true,
+                Err(err) => {
+                    tracing::info!("Probing endpoint {:?} failed: {err}", client.url);
+                    false
+                }
+            }
+        });
+        let health = futures::future::join_all(probes).await;
+
+        if let Some(idx) = health.iter().position(|&is_healthy| is_healthy) {
+            let prev_idx = self.active_endpoint.swap(idx, Ordering::Relaxed);
+            if prev_idx != idx {
+                tracing::info!(
+                    "Switched to endpoint #{idx} ({:?}) after probing",
+                    self.endpoints[idx].url
+                );
+            }
+        } else {
+            tracing::warn!("All {} endpoints are unhealthy", self.endpoints.len());
+        }
+        health
+    }
+
+    /// Checks whether the error should lead to a failover: either it's a transport-level error,
+    /// or the endpoint has rate-limited the request.
+    fn is_failover_error(err: &Error) -> bool {
+        match err {
+            Error::Transport(_) | Error::RestartNeeded(_) | Error::RequestTimeout => true,
+            Error::Call(err) => Self::RATE_LIMIT_ERROR_CODES.contains(&err.code()),
+            _ => false,
+        }
+    }
+
+    /// Switches from the endpoint `idx` to the next one unless the active endpoint was already changed.
+    fn fail_over(&self, idx: usize, err: &Error) -> usize {
+        let next_idx = (idx + 1) % self.endpoints.len();
+        match self.active_endpoint.compare_exchange(
+            idx,
+            next_idx,
+            Ordering::Relaxed,
+            Ordering::Relaxed,
+        ) {
+            Ok(_) => {
+                tracing::warn!(
+                    "Endpoint #{idx} ({:?}) failed: {err}; failing over to endpoint #{next_idx} ({:?})",
+                    self.endpoints[idx].url,
+                    self.endpoints[next_idx].url
+                );
+                next_idx
+            }
+            Err(current_idx) => current_idx,
+        }
+    }
+}
+
+impl<Net: Network> FailoverClient<Net> {
+    /// Creates an HTTP client for the specified network failing over among `endpoints`, listed in the priority order.
+    /// Each endpoint is specified by its URL and an optional rate limit (in requests per second) applied
+    /// to this endpoint only.
+    pub fn http(
+        network: Net,
+        endpoints: impl IntoIterator<Item = (SensitiveUrl, Option<NonZeroUsize>)>,
+    ) -> anyhow::Result<Self> {
+        let endpoints = endpoints
+            .into_iter()
+            .enumerate()
+            .map(|(i, (url, rate_limit_rps))| {
+                let mut builder = Client::http(url)
+                    .with_context(|| format!("failed creating JSON-RPC client for endpoint #{i}"))?
+                    .for_network(network);
+                if let Some(rps) = rate_limit_rps {
+                    builder = builder.with_allowed_requests_per_second(rps);
+                }
+                Ok(builder.build())
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        anyhow::ensure!(!endpoints.is_empty(), "no endpoints provided");
+        Ok(Self::new(endpoints))
+    }
+
+    /// Boxes this client, optionally wrapping it into a [`CachingClient`] caching responses for immutable data
+    /// with the specified TTL.
+    pub fn into_boxed(self, response_cache_ttl: Option<Duration>) -> Box<DynClient<Net>> {
+        match response_cache_ttl {
+            Some(ttl) => Box::new(CachingClient::new(self, ttl)),
+            None => Box::new(self),
+        }
+    }
+}
+
+impl<Net: Network, C: ClientBase> ForNetwork for FailoverClient<Net, C> {
+    type Net = Net;
+
+    fn network(&self) -> Self::Net {
+        self.endpoints[0].network()
+    }
+
+    fn component(&self) -> &'static str {
+        self.endpoints[0].component()
+    }
+}
+
+impl<Net: Network, C: ClientBase> TaggedClient for FailoverClient<Net, C> {
+    fn set_component(&mut self, component_name: &'static str) {
+        for client in &mut self.endpoints {
+            client.set_component(component_name);
+        }
+    }
+}
+
+#[async_trait]
+impl<Net: Network, C: ClientBase> ClientT for FailoverClient<Net, C> {
+    async fn notification<Params>(&self, method: &str, params: Params) -> Result<(), Error>
+    where
+        Params: ToRpcParams + Send,
+    {
+        let params = RawParams::new(params).map_err(Error::ParseError)?;
+        let mut idx = self.active_endpoint();
+        for _ in 1..self.endpoints.len() {
+            let params = RawParams(params.0.clone());
+            match self.endpoints[idx].notification(method, params).await {
+                Err(err) if Self::is_failover_error(&err) => idx = self.fail_over(idx, &err),
+                result => return result,
+            }
+        }
+        self.endpoints[idx].notification(method, params).await
+    }
+
+    async fn request<R, Params>(&self, method: &str, params: Params) -> Result<R, Error>
+    where
+        R: DeserializeOwned,
+        Params: ToRpcParams + Send,
+    {
+        let params = RawParams::new(params).map_err(Error::ParseError)?;
+        let mut idx = self.active_endpoint();
+        for _ in 1..self.endpoints.len() {
+            let params = RawParams(params.0.clone());
+            match self.endpoints[idx].request(method, params).await {
+                Err(err) if Self::is_failover_error(&err) => idx = self.fail_over(idx, &err),
+                result => return result,
+            }
+        }
+        self.endpoints[idx].request(method, params).await
+    }
+
+    async fn batch_request<'a, R>(
+        &self,
+        batch: BatchRequestBuilder<'a>,
+    ) -> Result<BatchResponse<'a, R>, Error>
+    where
+        R: DeserializeOwned + fmt::Debug + 'a,
+    {
+        let mut idx = self.active_endpoint();
+        for _ in 1..self.endpoints.len() {
+            match self.endpoints[idx].batch_request(batch.clone()).await {
+                Err(err) if Self::is_failover_error(&err) => idx = self.fail_over(idx, &err),
+                result => return result,
+            }
+        }
+        self.endpoints[idx].batch_request(batch).await
+    }
+}
+
+/// Task periodically probing endpoints of a [`FailoverClient`] (see [`FailoverClient::probe_endpoints()`])
+/// and reporting the currently active endpoint via a health check.
+#[derive(Debug)]
+pub struct FailoverClientProber<Net, C = HttpClient> {
+    client: FailoverClient<Net, C>,
+    health_updater: HealthUpdater,
+}
+
+impl<Net: Network, C: ClientBase> FailoverClientProber<Net, C> {
+    const PROBE_INTERVAL: Duration = Duration::from_secs(10);
+
+    fn new(client: FailoverClient<Net, C>, health_check_name: &'static str) -> Self {
+        let (_, health_updater) = ReactiveHealthCheck::new(health_check_name);
+        Self {
+            client,
+            health_updater,
+        }
+    }
+
+    /// Returns the health check for this prober.
+    pub fn health_check(&self) -> ReactiveHealthCheck {
+        self.health_updater.subscribe()
+    }
+
+    fn health(&self, endpoints_health: &[bool]) -> Health {
+        let active_endpoint = self.client.active_endpoint();
+        let status = if endpoints_health[active_endpoint] {
+            if active_endpoint == 0 {
+                HealthStatus::Ready
+            } else {
+                // Working on a fallback endpoint
+                HealthStatus::Affected
+            }
+        } else {
+            HealthStatus::NotReady
+        };
+        let endpoints: Vec<_> = self
+            .client
+            .endpoint_urls()
+            .zip(endpoints_health)
+            .map(|(url, is_healthy)| {
+                serde_json::json!({
+                    "url": format!("{url:?}"),
+                    "healthy": is_healthy,
+                })
+            })
+            .collect();
+        Health::from(status).with_details(serde_json::json!({
+            "active_endpoint": active_endpoint,
+            "endpoints": endpoints,
+        }))
+    }
+
+    /// Runs this prober until a stop signal is received.
+    pub async fn run(self, mut stop_receiver: watch::Receiver<bool>) -> anyhow::Result<()> {
+        while !*stop_receiver.borrow_and_update() {
+            let endpoints_health = self.client.probe_endpoints().await;
+            self.health_updater.update(self.health(&endpoints_health));
+
+            if tokio::time::timeout(Self::PROBE_INTERVAL, stop_receiver.changed())
+                .await
+                .is_ok()
+            {
+                break;
+            }
+        }
+        self.health_updater
+            .update(HealthStatus::ShuttingDown.into());
+        Ok(())
+    }
+}
@@ -3,6 +3,7 @@
 use std::time::Duration;
 
 use jsonrpsee::{core::client, http_client::transport};
+use prometheus_exporter::LabelCardinalityGuard;
 use vise::{
     Buckets, Counter, DurationAsSecs, EncodeLabelSet, EncodeLabelValue, Family, Histogram, Info,
     LabeledFamily, Metrics, Unit,
@@ -10,6 +11,10 @@ use vise::{
 
 use super::{AcquireStats, CallOrigin, SharedRateLimit};
 
+/// Method names are provided by the client callers and are generally bounded, but a misbehaving caller
+/// (e.g., one forwarding arbitrary requests) could blow up the number of time series.
+static METHOD_GUARD: LabelCardinalityGuard = LabelCardinalityGuard::new("l2_client_method", 256);
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, EncodeLabelSet)]
 pub(super) struct RequestLabels {
     pub network: String,
@@ -102,7 +107,7 @@ impl L2ClientMetrics {
             let request_labels = RequestLabels {
                 network: network.to_owned(),
                 component,
-                method: method.to_owned(),
+                method: METHOD_GUARD.guard(method).to_owned(),
             };
             self.rate_limit_latency[&request_labels].observe(stats.total_sleep_time);
         }
@@ -118,7 +123,7 @@ impl L2ClientMetrics {
             let request_labels = RequestLabels {
                 network: network.to_owned(),
                 component,
-                method: method.to_owned(),
+                method: METHOD_GUARD.guard(method).to_owned(),
             };
             self.rate_limit_timeout[&request_labels].inc();
         }
@@ -148,7 +153,7 @@ impl L2ClientMetrics {
                 let labels = RpcErrorLabels {
                     network,
                     component,
-                    method: method.to_owned(),
+                    method: METHOD_GUARD.guard(method).to_owned(),
                     code: err.code(),
                 };
                 if self.rpc_errors[&labels].inc() == 0 {
@@ -173,7 +178,7 @@ impl L2ClientMetrics {
                 let labels = HttpErrorLabels {
                     network,
                     component,
-                    method: method.to_owned(),
+                    method: METHOD_GUARD.guard(method).to_owned(),
                     status,
                 };
                 if self.http_errors[&labels].inc() == 0 {
@@ -195,7 +200,7 @@ impl L2ClientMetrics {
         let labels = GenericErrorLabels {
             network,
             component,
-            method: method.to_owned(),
+            method: METHOD_GUARD.guard(method).to_owned(),
             kind,
         };
         if self.generic_errors[&labels].inc() == 0 {
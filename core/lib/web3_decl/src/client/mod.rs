@@ -8,6 +8,10 @@
 //! - [`MockClient`] is a mock client useful for testing. Bear in mind that because of the client being generic,
 //!   mock tooling is fairly low-level. Prefer defining a domain-specific wrapper trait for the client functionality and mock it
 //!   where it's possible.
+//! - [`FailoverClient`] wraps multiple [`Client`]s for the same network and fails over among them
+//!   on transport errors and rate limiting. Its endpoints should be periodically probed using [`FailoverClientProber`].
+//! - [`CachingClient`] wraps another client and caches responses for immutable data (e.g., blocks and receipts)
+//!   for a short TTL.
 //! - [`BoxedL2Client`] is a generic client (essentially, a wrapper around a trait object). Use it for dependency injection
 //!   instead of `L2Client`. Both `L2Client` and `MockL2Client` are convertible to `BoxedL2Client`.
 
@@ -27,25 +31,34 @@ use jsonrpsee::{
         params::BatchRequestBuilder,
         traits::ToRpcParams,
     },
-    http_client::{HttpClient, HttpClientBuilder},
+    http_client::{self, transport::HttpBackend, HttpClientBuilder},
     ws_client,
 };
 use serde::de::DeserializeOwned;
 use tokio::time::Instant;
 use zksync_types::url::SensitiveUrl;
 
-use self::metrics::{L2ClientMetrics, METRICS};
 pub use self::{
     boxed::{DynClient, ObjectSafeClient},
+    caching::CachingClient,
+    failover::{FailoverClient, FailoverClientProber},
     mock::MockClient,
     network::{ForNetwork, Network, TaggedClient, L1, L2},
+    request_id::RequestIdService,
     shared::Shared,
 };
+use self::{
+    metrics::{L2ClientMetrics, METRICS},
+    request_id::RequestIdLayer,
+};
 
 mod boxed;
+mod caching;
+mod failover;
 mod metrics;
 mod mock;
 mod network;
+mod request_id;
 mod shared;
 #[cfg(test)]
 mod tests;
@@ -119,6 +132,9 @@ pub struct Client<Net, C = HttpClient> {
     network: Net,
 }
 
+/// HTTP client propagating [`vlog::RequestId`]s to the server.
+pub type HttpClient = http_client::HttpClient<RequestIdService<HttpBackend>>;
+
 /// Client using the WebSocket transport.
 pub type WsClient<Net> = Client<Net, Shared<ws_client::WsClient>>;
 
@@ -140,7 +156,9 @@ impl<Net: fmt::Debug, C: 'static> fmt::Debug for Client<Net, C> {
 impl<Net: Network> Client<Net> {
     /// Creates an HTTP-backed client.
     pub fn http(url: SensitiveUrl) -> anyhow::Result<ClientBuilder<Net>> {
-        let client = HttpClientBuilder::default().build(url.expose_str())?;
+        let client = HttpClientBuilder::default()
+            .set_http_middleware(tower::ServiceBuilder::new().layer(RequestIdLayer))
+            .build(url.expose_str())?;
         Ok(ClientBuilder::new(client, url))
     }
 }
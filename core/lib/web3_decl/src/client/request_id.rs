@@ -0,0 +1,48 @@
+//! HTTP middleware propagating request IDs.
+
+use std::task::{Context, Poll};
+
+use http::HeaderValue;
+use vlog::RequestId;
+
+/// [`tower`] layer wrapping HTTP transport into [`RequestIdService`].
+#[derive(Debug, Clone, Copy)]
+pub(super) struct RequestIdLayer;
+
+impl<S> tower::Layer<S> for RequestIdLayer {
+    type Service = RequestIdService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RequestIdService { inner }
+    }
+}
+
+/// HTTP middleware setting the `x-request-id` header to the [`RequestId`] of the request currently served
+/// by the caller (e.g., a JSON-RPC call proxied by an external node to the main node), so that the request
+/// can be followed across nodes.
+#[derive(Debug, Clone)]
+pub struct RequestIdService<S> {
+    inner: S,
+}
+
+impl<S, B> tower::Service<http::Request<B>> for RequestIdService<S>
+where
+    S: tower::Service<http::Request<B>>,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = S::Future;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut request: http::Request<B>) -> Self::Future {
+        if let Some(request_id) = RequestId::current() {
+            let value = HeaderValue::from_str(&request_id.to_string())
+                .expect("request ID is a valid header value");
+            request.headers_mut().insert(RequestId::HEADER, value);
+        }
+        self.inner.call(request)
+    }
+}
@@ -1,15 +1,27 @@
 //! Tests for `L2Client` focused on rate limiting.
 
 use std::{
-    sync::{Arc, Mutex},
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
     time::Duration,
 };
 
 use assert_matches::assert_matches;
 use futures::future;
-use jsonrpsee::{http_client::transport, rpc_params, types::error::ErrorCode};
+use jsonrpsee::{
+    core::RpcResult,
+    http_client::transport,
+    rpc_params,
+    server::ServerBuilder,
+    types::{error::ErrorCode, ErrorObject},
+    RpcModule,
+};
 use rand::{rngs::StdRng, Rng, SeedableRng};
 use test_casing::test_casing;
+use tokio::sync::watch;
+use zksync_health_check::{CheckHealth, HealthStatus};
 use zksync_types::{L2ChainId, U64};
 
 use super::{
@@ -272,3 +284,187 @@ async fn wrapping_mock_client() {
     };
     assert!(metrics.http_errors.contains(&labels), "{metrics:?}");
 }
+
+#[tokio::test]
+async fn failover_client_basics() {
+    let is_primary_down = Arc::new(AtomicBool::new(true));
+    let primary_client = MockClient::builder(L2::default())
+        .method("eth_chainId", {
+            let is_primary_down = is_primary_down.clone();
+            move || {
+                if is_primary_down.load(Ordering::Relaxed) {
+                    let http_err = transport::Error::RequestFailure { status_code: 503 };
+                    Err(Error::Transport(http_err.into()))
+                } else {
+                    Ok(U64::from(270))
+                }
+            }
+        })
+        .build();
+    let backup_client = MockClient::builder(L2::default())
+        .method("eth_chainId", || Ok(U64::from(270)))
+        .build();
+    let endpoints = [primary_client, backup_client].map(|client| {
+        ClientBuilder::<L2, _>::new(client, "http://localhost".parse().unwrap())
+            .with_allowed_requests_per_second(NonZeroUsize::new(100).unwrap())
+            .build()
+    });
+    let client = FailoverClient::new(endpoints.into());
+    assert_eq!(client.active_endpoint(), 0);
+
+    // The request should transparently fail over to the backup endpoint.
+    let chain_id: U64 = client.request("eth_chainId", rpc_params![]).await.unwrap();
+    assert_eq!(chain_id, U64::from(270));
+    assert_eq!(client.active_endpoint(), 1);
+
+    // Non-transport errors should not lead to failover.
+    let err = client
+        .request::<String, _>("unknown", rpc_params![])
+        .await
+        .unwrap_err();
+    assert_matches!(err, Error::Call(_));
+    assert_eq!(client.active_endpoint(), 1);
+
+    let health = client.probe_endpoints().await;
+    assert_eq!(health, [false, true]);
+    assert_eq!(client.active_endpoint(), 1);
+
+    // Once the primary endpoint recovers, probing should switch back to it.
+    is_primary_down.store(false, Ordering::Relaxed);
+    let health = client.probe_endpoints().await;
+    assert_eq!(health, [true, true]);
+    assert_eq!(client.active_endpoint(), 0);
+}
+
+#[tokio::test]
+async fn failover_client_switches_endpoint_on_rate_limiting() {
+    let primary_client = MockClient::builder(L1::default())
+        .method("eth_chainId", || {
+            Err::<U64, _>(Error::Call(ErrorObject::owned(
+                -32005,
+                "limit exceeded",
+                None::<()>,
+            )))
+        })
+        .build();
+    let backup_client = MockClient::builder(L1::default())
+        .method("eth_chainId", || Ok(U64::from(9)))
+        .build();
+    let endpoints = [primary_client, backup_client].map(|client| {
+        ClientBuilder::<L1, _>::new(client, "http://localhost".parse().unwrap())
+            .with_allowed_requests_per_second(NonZeroUsize::new(100).unwrap())
+            .build()
+    });
+    let client = FailoverClient::new(endpoints.into());
+
+    let chain_id: U64 = client.request("eth_chainId", rpc_params![]).await.unwrap();
+    assert_eq!(chain_id, U64::from(9));
+    assert_eq!(client.active_endpoint(), 1);
+}
+
+#[tokio::test]
+async fn failover_client_over_http() {
+    // The primary endpoint doesn't accept connections.
+    let primary_addr = std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap();
+    let mut rpc_module = RpcModule::new(());
+    rpc_module
+        .register_method("eth_chainId", |_, _| RpcResult::Ok(U64::from(270)))
+        .unwrap();
+    let backup_server = ServerBuilder::default()
+        .http_only()
+        .build("127.0.0.1:0")
+        .await
+        .unwrap();
+    let backup_addr = backup_server.local_addr().unwrap();
+    let backup_server_handle = backup_server.start(rpc_module);
+
+    let endpoints =
+        [primary_addr, backup_addr].map(|addr| (format!("http://{addr}/").parse().unwrap(), None));
+    let client = FailoverClient::http(L2::default(), endpoints).unwrap();
+    let prober = client.prober("main_node_client").unwrap();
+    let mut health_check = prober.health_check();
+
+    let chain_id: U64 = client.request("eth_chainId", rpc_params![]).await.unwrap();
+    assert_eq!(chain_id, U64::from(270));
+    assert_eq!(client.active_endpoint(), 1);
+
+    let (stop_sender, stop_receiver) = watch::channel(false);
+    let prober_task = tokio::spawn(prober.run(stop_receiver));
+    let health = health_check
+        .wait_for(|health| health.status() != HealthStatus::NotReady)
+        .await;
+    assert_eq!(health.status(), HealthStatus::Affected);
+    let details = health.details().unwrap();
+    assert_eq!(details["active_endpoint"], 1);
+    assert_eq!(details["endpoints"][0]["healthy"], false);
+    assert_eq!(details["endpoints"][1]["healthy"], true);
+
+    stop_sender.send_replace(true);
+    prober_task.await.unwrap().unwrap();
+    let health = health_check.check_health().await;
+    assert_eq!(health.status(), HealthStatus::ShuttingDown);
+    backup_server_handle.stop().unwrap();
+}
+
+#[tokio::test]
+async fn caching_client_basics() {
+    tokio::time::pause();
+
+    let request_count = Arc::new(AtomicUsize::new(0));
+    let mock_client = MockClient::builder(L1::default())
+        .method("eth_getBlockByNumber", {
+            let request_count = request_count.clone();
+            move |number: String, _full: bool| {
+                request_count.fetch_add(1, Ordering::Relaxed);
+                Ok(serde_json::json!({ "number": number }))
+            }
+        })
+        .method("eth_getTransactionReceipt", {
+            let request_count = request_count.clone();
+            move |_hash: String| {
+                request_count.fetch_add(1, Ordering::Relaxed);
+                Ok(serde_json::Value::Null)
+            }
+        })
+        .build();
+    let client = CachingClient::new(mock_client, Duration::from_secs(10));
+
+    for _ in 0..3 {
+        let block: serde_json::Value = client
+            .request("eth_getBlockByNumber", rpc_params!["0x1", false])
+            .await
+            .unwrap();
+        assert_eq!(block, serde_json::json!({ "number": "0x1" }));
+    }
+    assert_eq!(request_count.load(Ordering::Relaxed), 1);
+
+    // Blocks requested by a tag must not be cached.
+    for _ in 0..3 {
+        let _: serde_json::Value = client
+            .request("eth_getBlockByNumber", rpc_params!["latest", false])
+            .await
+            .unwrap();
+    }
+    assert_eq!(request_count.load(Ordering::Relaxed), 4);
+
+    // Empty responses must not be cached.
+    for _ in 0..3 {
+        let receipt: Option<serde_json::Value> = client
+            .request("eth_getTransactionReceipt", rpc_params!["0x01"])
+            .await
+            .unwrap();
+        assert_eq!(receipt, None);
+    }
+    assert_eq!(request_count.load(Ordering::Relaxed), 7);
+
+    // Cached entries should expire after the TTL.
+    tokio::time::advance(Duration::from_secs(11)).await;
+    let _: serde_json::Value = client
+        .request("eth_getBlockByNumber", rpc_params!["0x1", false])
+        .await
+        .unwrap();
+    assert_eq!(request_count.load(Ordering::Relaxed), 8);
+}
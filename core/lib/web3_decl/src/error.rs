@@ -37,8 +37,18 @@ pub enum Web3Error {
     FilterNotFound,
     #[error("Query returned more than {0} results. Try with this block range [{1:#x}, {2:#x}].")]
     LogsLimitExceeded(usize, u32, u32),
+    #[error("Block range is too wide; at most {0} blocks can be queried at once")]
+    LogsBlockRangeExceeded(u32),
     #[error("invalid filter: if blockHash is supplied fromBlock and toBlock must not be")]
     InvalidFilterBlockHash,
+    #[error("Invalid state override: {0}")]
+    InvalidStateOverride(String),
+    #[error("Invalid reward percentiles: {0}")]
+    InvalidRewardPercentiles(String),
+    #[error("Too many calls; at most {0} calls can be executed at once")]
+    TooManyCalls(usize),
+    #[error("Protocol version {0} is not supported")]
+    UnsupportedProtocolVersion(u16),
     /// Weaker form of a "method not found" error; the method implementation is technically present,
     /// but the node configuration prevents the method from functioning.
     #[error("Method not implemented")]
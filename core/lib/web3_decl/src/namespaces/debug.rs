@@ -2,7 +2,10 @@
 use jsonrpsee::core::RpcResult;
 use jsonrpsee::proc_macros::rpc;
 use zksync_types::{
-    api::{BlockId, BlockNumber, DebugCall, ResultDebugCall, TracerConfig},
+    api::{
+        BlockId, BlockNumber, CallOptions, DebugCall, DebugOpcodeTrace, ResultDebugCall,
+        TraceCallManyOptions, TracerConfig,
+    },
     debug_flat_call::DebugCallFlat,
     transaction_request::CallRequest,
 };
@@ -50,6 +53,22 @@ pub trait DebugNamespace {
         options: Option<TracerConfig>,
     ) -> RpcResult<DebugCall>;
 
+    #[method(name = "traceCall.opcodeTracer")]
+    async fn trace_call_opcodes(
+        &self,
+        request: CallRequest,
+        block: Option<BlockId>,
+        options: Option<CallOptions>,
+    ) -> RpcResult<DebugOpcodeTrace>;
+
+    #[method(name = "traceCallMany")]
+    async fn trace_call_many(
+        &self,
+        requests: Vec<CallRequest>,
+        block: Option<BlockId>,
+        options: Option<TraceCallManyOptions>,
+    ) -> RpcResult<Vec<DebugCall>>;
+
     #[method(name = "traceTransaction")]
     async fn trace_transaction(
         &self,
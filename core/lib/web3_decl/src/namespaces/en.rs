@@ -25,6 +25,17 @@ pub trait EnNamespace {
     #[method(name = "consensusGenesis")]
     async fn consensus_genesis(&self) -> RpcResult<Option<en::ConsensusGenesis>>;
 
+    /// Returns the consensus quorum certificate for the specified L2 block, or `None` if the block
+    /// is not finalized by consensus (yet).
+    ///
+    /// Together with `consensusGenesis`, it allows light clients to verify the blocks returned
+    /// by the node without trusting it.
+    #[method(name = "blockCertificate")]
+    async fn block_certificate(
+        &self,
+        block_number: L2BlockNumber,
+    ) -> RpcResult<Option<en::BlockCertificate>>;
+
     /// Lists all tokens created at or before the specified `block_number`.
     ///
     /// This method is used by EN after snapshot recovery in order to recover token records.
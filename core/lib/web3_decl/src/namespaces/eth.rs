@@ -2,7 +2,9 @@
 use jsonrpsee::core::RpcResult;
 use jsonrpsee::proc_macros::rpc;
 use zksync_types::{
-    api::{BlockId, BlockIdVariant, BlockNumber, Transaction, TransactionVariant},
+    api::{
+        BlockId, BlockIdVariant, BlockNumber, CallOptionsVariant, Transaction, TransactionVariant,
+    },
     transaction_request::CallRequest,
     Address, H256,
 };
@@ -31,7 +33,12 @@ pub trait EthNamespace {
     async fn chain_id(&self) -> RpcResult<U64>;
 
     #[method(name = "call")]
-    async fn call(&self, req: CallRequest, block: Option<BlockIdVariant>) -> RpcResult<Bytes>;
+    async fn call(
+        &self,
+        req: CallRequest,
+        block: Option<BlockIdVariant>,
+        options: Option<CallOptionsVariant>,
+    ) -> RpcResult<Bytes>;
 
     #[method(name = "estimateGas")]
     async fn estimate_gas(&self, req: CallRequest, _block: Option<BlockNumber>) -> RpcResult<U256>;
@@ -189,7 +196,15 @@ mod pub_sub {
             filter: Option<PubSubFilter>,
         ) -> SubscriptionResult;
     }
+
+    /// Resumption of subscriptions created with `eth_subscribe`. Notifications for resumed subscriptions
+    /// are sent in the same way as for `eth_subscribe`.
+    #[rpc(server)]
+    pub trait ZksPubSub {
+        #[subscription(name = "zks_resubscribe" => "eth_subscription", unsubscribe = "zks_unsubscribe", item = PubSubResult)]
+        async fn resubscribe(&self, cursor: String) -> SubscriptionResult;
+    }
 }
 
 #[cfg(feature = "server")]
-pub use self::pub_sub::EthPubSubServer;
+pub use self::pub_sub::{EthPubSubServer, ZksPubSubServer};
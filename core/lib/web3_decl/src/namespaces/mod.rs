@@ -6,8 +6,8 @@ pub use self::{
 #[cfg(feature = "server")]
 pub use self::{
     debug::DebugNamespaceServer, en::EnNamespaceServer, eth::EthNamespaceServer,
-    eth::EthPubSubServer, net::NetNamespaceServer, snapshots::SnapshotsNamespaceServer,
-    web3::Web3NamespaceServer, zks::ZksNamespaceServer,
+    eth::EthPubSubServer, eth::ZksPubSubServer, net::NetNamespaceServer,
+    snapshots::SnapshotsNamespaceServer, web3::Web3NamespaceServer, zks::ZksNamespaceServer,
 };
 
 mod debug;
@@ -5,8 +5,9 @@ use jsonrpsee::core::RpcResult;
 use jsonrpsee::proc_macros::rpc;
 use zksync_types::{
     api::{
-        BlockDetails, BridgeAddresses, L1BatchDetails, L2ToL1LogProof, Proof, ProtocolVersion,
-        TransactionDetailedResult, TransactionDetails,
+        state_override::StateOverride, BlockDetails, BlockIdVariant, BridgeAddresses,
+        L1BatchDetails, L2ToL1LogProof, LogsCursor, LogsPage, Proof, ProtocolVersion,
+        TransactionDetailedResult, TransactionDetails, TransactionSimulationResult,
     },
     fee::Fee,
     fee_model::{FeeParams, PubdataIndependentBatchFeeModelInput},
@@ -16,7 +17,7 @@ use zksync_types::{
 
 use crate::{
     client::{ForNetwork, L2},
-    types::{Bytes, Token},
+    types::{Bytes, Filter, Token},
 };
 
 #[cfg_attr(
@@ -100,6 +101,21 @@ pub trait ZksNamespace {
     async fn get_l1_batch_details(&self, batch: L1BatchNumber)
         -> RpcResult<Option<L1BatchDetails>>;
 
+    #[method(name = "getL1BatchPubdata")]
+    async fn get_l1_batch_pubdata(&self, batch: L1BatchNumber) -> RpcResult<Option<Bytes>>;
+
+    /// Returns a page of logs matching the filter, starting after the log referenced by `cursor`
+    /// (or from the start of the filter block range if `cursor` is not specified). Unlike `eth_getLogs`,
+    /// the block range and the total number of matching logs are not limited; the returned cursor
+    /// should be passed to the next call to get the following page.
+    #[method(name = "getLogsPaginated")]
+    async fn get_logs_paginated(
+        &self,
+        filter: Filter,
+        cursor: Option<LogsCursor>,
+        limit: Option<u32>,
+    ) -> RpcResult<LogsPage>;
+
     #[method(name = "getBytecodeByHash")]
     async fn get_bytecode_by_hash(&self, hash: H256) -> RpcResult<Option<Vec<u8>>>;
 
@@ -131,4 +147,12 @@ pub trait ZksNamespace {
         &self,
         tx_bytes: Bytes,
     ) -> RpcResult<TransactionDetailedResult>;
+
+    #[method(name = "simulateTransaction")]
+    async fn simulate_transaction(
+        &self,
+        req: CallRequest,
+        block: Option<BlockIdVariant>,
+        state_override: Option<StateOverride>,
+    ) -> RpcResult<TransactionSimulationResult>;
 }
@@ -20,6 +20,7 @@ pub use zksync_types::{
     web3::{BlockHeader, Bytes, CallRequest, FeeHistory, Index, SyncState, TraceFilter, Work},
     Address, Transaction, H160, H256, H64, U256, U64,
 };
+use zksync_types::{ethabi::ethereum_types::BloomInput, H2048};
 
 /// Token in the zkSync network
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -275,6 +276,33 @@ impl PubSubFilter {
         }
         true
     }
+
+    /// Checks whether a block with the specified logs bloom may contain logs matching this filter.
+    /// False positives are possible, but false negatives are not.
+    pub fn may_match_bloom(&self, bloom: &H2048) -> bool {
+        let contains = |value: &[u8]| bloom.contains_input(BloomInput::Raw(value));
+        if let Some(addresses) = &self.address {
+            if !addresses
+                .0
+                .iter()
+                .any(|address| contains(address.as_bytes()))
+            {
+                return false;
+            }
+        }
+        if let Some(all_topics) = &self.topics {
+            for expected_topics in all_topics.iter().flatten() {
+                if !expected_topics
+                    .0
+                    .iter()
+                    .any(|topic| contains(topic.as_bytes()))
+                {
+                    return false;
+                }
+            }
+        }
+        true
+    }
 }
 
 #[derive(Default, Clone)]
@@ -350,6 +378,31 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn matching_pub_sub_filter_against_bloom() {
+        let address = Address::repeat_byte(1);
+        let topic = H256::repeat_byte(2);
+        let mut bloom = H2048::zero();
+        bloom.accrue(BloomInput::Raw(address.as_bytes()));
+        bloom.accrue(BloomInput::Raw(topic.as_bytes()));
+
+        assert!(PubSubFilter::default().may_match_bloom(&bloom));
+        assert!(PubSubFilter::default().may_match_bloom(&H2048::zero()));
+
+        let filter = PubSubFilterBuilder::default()
+            .set_address(vec![Address::repeat_byte(3), address])
+            .set_topics(None, Some(vec![topic]), None, None)
+            .build();
+        assert!(filter.may_match_bloom(&bloom));
+        assert!(!filter.may_match_bloom(&H2048::zero()));
+
+        let filter = PubSubFilterBuilder::default()
+            .set_address(vec![address])
+            .set_topics(Some(vec![H256::repeat_byte(3)]), None, None, None)
+            .build();
+        assert!(!filter.may_match_bloom(&bloom));
+    }
+
     #[test]
     fn get_block_number_serde() {
         let test_vector = &[
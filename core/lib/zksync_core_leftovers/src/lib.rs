@@ -55,6 +55,7 @@ use zksync_metadata_calculator::{
     api_server::TreeApiHttpClient, MetadataCalculator, MetadataCalculatorConfig,
 };
 use zksync_node_api_server::{
+    admin::AdminServer,
     healthcheck::HealthCheckHandle,
     tx_sender::{build_tx_sender, TxSenderConfig},
     web3::{self, mempool_cache::MempoolCache, state::InternalApiConfig, Namespace},
@@ -66,7 +67,7 @@ use zksync_node_genesis::{ensure_genesis_state, GenesisParams};
 use zksync_object_store::{ObjectStore, ObjectStoreFactory};
 use zksync_queued_job_processor::JobProcessor;
 use zksync_shared_metrics::{InitStage, APP_METRICS};
-use zksync_state::{PostgresStorageCaches, RocksdbStorageOptions};
+use zksync_state::{PostgresStorageCaches, RocksdbMaintenanceConfig, RocksdbStorageOptions};
 use zksync_state_keeper::{
     create_state_keeper, io::seal_logic::l2_block_seal_subtasks::L2BlockSealProcess,
     AsyncRocksdbCache, MempoolFetcher, MempoolGuard, OutputHandler, StateKeeperPersistence,
@@ -156,6 +157,17 @@ pub enum Component {
     CommitmentGenerator,
     /// VM runner-based component that saves protective reads to Postgres.
     VmRunnerProtectiveReads,
+    /// VM runner-based component that saves basic witness inputs to the object store.
+    VmRunnerBwip,
+    /// VM runner-based component that re-executes L1 batches (possibly with another VM version) and compares
+    /// the outcomes with the stored ones.
+    VmPlayground,
+    /// A component to handle anything related to a chain's data availability.
+    DADispatcher,
+    /// Component fetching the base token / ETH conversion ratio and persisting it in Postgres.
+    BaseTokenRatioPersister,
+    /// Component periodically creating app-level storage snapshots used for node recovery.
+    SnapshotsCreator,
 }
 
 #[derive(Debug)]
@@ -195,6 +207,13 @@ impl FromStr for Components {
             "vm_runner_protective_reads" => {
                 Ok(Components(vec![Component::VmRunnerProtectiveReads]))
             }
+            "vm_runner_bwip" => Ok(Components(vec![Component::VmRunnerBwip])),
+            "vm_playground" => Ok(Components(vec![Component::VmPlayground])),
+            "da_dispatcher" => Ok(Components(vec![Component::DADispatcher])),
+            "base_token_ratio_persister" => {
+                Ok(Components(vec![Component::BaseTokenRatioPersister]))
+            }
+            "snapshots_creator" => Ok(Components(vec![Component::SnapshotsCreator])),
             other => Err(format!("{} is not a valid component name", other)),
         }
     }
@@ -237,11 +256,13 @@ pub async fn initialize_components(
             .await
             .context("failed to build connection_pool")?;
     // We're most interested in setting acquire / statement timeouts for the API server, which puts the most load
-    // on Postgres.
+    // on Postgres. This pool is only used for reads, so it can be routed to API read replicas.
     let replica_connection_pool =
         ConnectionPool::<Core>::builder(database_secrets.replica_url()?, pool_size)
             .set_acquire_timeout(postgres_config.acquire_timeout())
             .set_statement_timeout(postgres_config.statement_timeout())
+            .set_replica_urls(database_secrets.server_api_replica_urls.clone())
+            .set_max_replica_lag(postgres_config.api_replica_max_lag())
             .build()
             .await
             .context("failed to build replica_connection_pool")?;
@@ -317,6 +338,15 @@ pub async fn initialize_components(
         tokio::spawn(circuit_breaker_checker.run(stop_receiver.clone())),
     ];
 
+    if let Some(observability_config) = &configs.observability {
+        if let Some(port) = observability_config.admin_port {
+            let admin_server = AdminServer::new(([127, 0, 0, 1], port).into())
+                .with_profiling(observability_config.admin_profiling_enabled)
+                .with_audit_log(connection_pool.clone());
+            task_futures.push(tokio::spawn(admin_server.run(stop_receiver.clone())));
+        }
+    }
+
     if components.contains(&Component::WsApi)
         || components.contains(&Component::HttpApi)
         || components.contains(&Component::ContractVerificationApi)
@@ -531,6 +561,22 @@ pub async fn initialize_components(
             .context("consensus component's secrets are missing")?;
         let started_at = Instant::now();
         tracing::info!("initializing Consensus");
+        let registry_client = match &configs.api_config {
+            Some(api) => {
+                zksync_node_consensus::era::registry_client(&cfg, &api.web3_json_rpc.http_url)
+                    .context("registry_client()")?
+            }
+            None => None,
+        };
+        let attester = zksync_node_consensus::Attester::new(connection_pool.clone(), &secrets)
+            .context("Attester::new()")?
+            .map(|attester| match registry_client.clone() {
+                Some(client) => attester.with_registry(&cfg, client),
+                None => attester,
+            });
+        if let Some(attester) = &attester {
+            app_health.insert_component(attester.health_check())?;
+        }
         let pool = connection_pool.clone();
         let mut stop_receiver = stop_receiver.clone();
         task_futures.push(tokio::spawn(async move {
@@ -541,8 +587,15 @@ pub async fn initialize_components(
             // but we only need to wait for stop signal once, and it will be propagated to all child contexts.
             let root_ctx = ctx::root();
             scope::run!(&root_ctx, |ctx, s| async move {
+                if let Some(attester) = attester {
+                    s.spawn_bg(attester.run(ctx));
+                }
                 s.spawn_bg(zksync_node_consensus::era::run_main_node(
-                    ctx, cfg, secrets, pool,
+                    ctx,
+                    cfg,
+                    secrets,
+                    pool,
+                    registry_client,
                 ));
                 let _ = stop_receiver.wait_for(|stop| *stop).await?;
                 Ok(())
@@ -629,15 +682,27 @@ pub async fn initialize_components(
         let operator_blobs_address = eth_sender_wallets.blob_operator.map(|x| x.address());
 
         let sender_config = eth.sender.clone().context("eth_sender")?;
+        let mut aggregator = Aggregator::new(
+            sender_config.clone(),
+            store_factory.create_store().await?,
+            operator_blobs_address.is_some(),
+            l1_batch_commit_data_generator_mode,
+        );
+        if sender_config
+            .blob_base_fee_calldata_fallback_threshold
+            .is_some()
+        {
+            aggregator = aggregator.with_l1_tx_params(
+                gas_adjuster
+                    .get_or_init()
+                    .await
+                    .context("gas_adjuster.get_or_init()")?,
+            );
+        }
         let eth_tx_aggregator_actor = EthTxAggregator::new(
             eth_sender_pool,
             sender_config.clone(),
-            Aggregator::new(
-                sender_config.clone(),
-                store_factory.create_store().await?,
-                operator_blobs_address.is_some(),
-                l1_batch_commit_data_generator_mode,
-            ),
+            aggregator,
             Box::new(eth_client),
             contracts_config.validator_timelock_addr,
             contracts_config.l1_multicall3_addr,
@@ -766,16 +831,21 @@ pub async fn initialize_components(
     }
 
     if components.contains(&Component::CommitmentGenerator) {
-        let pool_size = CommitmentGenerator::default_parallelism().get();
+        let parallelism = configs
+            .commitment_generator_config
+            .as_ref()
+            .and_then(|config| config.max_parallelism)
+            .unwrap_or_else(CommitmentGenerator::default_parallelism);
         let commitment_generator_pool =
-            ConnectionPool::<Core>::builder(database_secrets.master_url()?, pool_size)
+            ConnectionPool::<Core>::builder(database_secrets.master_url()?, parallelism.get())
                 .build()
                 .await
                 .context("failed to build commitment_generator_pool")?;
-        let commitment_generator = CommitmentGenerator::new(
+        let mut commitment_generator = CommitmentGenerator::new(
             commitment_generator_pool,
             genesis_config.l1_batch_commit_data_generator_mode,
         );
+        commitment_generator.set_max_parallelism(parallelism);
         app_health.insert_component(commitment_generator.health_check())?;
         task_futures.push(tokio::spawn(
             commitment_generator.run(stop_receiver.clone()),
@@ -850,12 +920,19 @@ async fn add_state_keeper_to_task_futures(
             .experimental
             .state_keeper_db_block_cache_capacity(),
         max_open_files: db_config.experimental.state_keeper_db_max_open_files,
+        periodic_compaction_ttl: db_config.experimental.state_keeper_db_compaction_ttl(),
     };
     let (async_cache, async_catchup_task) = AsyncRocksdbCache::new(
         async_cache_pool,
         db_config.state_keeper_db_path.clone(),
         cache_options,
     );
+    let maintenance_task = async_cache.maintenance_task(RocksdbMaintenanceConfig {
+        compaction_interval: db_config.experimental.state_keeper_db_compaction_interval(),
+        max_size_bytes: db_config.experimental.state_keeper_db_max_size(),
+        ..RocksdbMaintenanceConfig::default()
+    });
+    task_futures.push(tokio::spawn(maintenance_task.run(stop_receiver.clone())));
 
     let tree_writes_persistence = TreeWritesPersistence::new(persistence_pool);
     let output_handler =
@@ -1333,6 +1410,12 @@ async fn run_ws_api(
             .with_updaters_pool(updaters_pool)
             .with_filter_limit(api_config.web3_json_rpc.filters_limit())
             .with_subscriptions_limit(api_config.web3_json_rpc.subscriptions_limit())
+            .with_subscriptions_per_connection_limit(
+                api_config
+                    .web3_json_rpc
+                    .subscriptions_per_connection_limit(),
+            )
+            .with_subscription_backlog_size(api_config.web3_json_rpc.subscription_backlog_size())
             .with_batch_request_size_limit(api_config.web3_json_rpc.max_batch_request_size())
             .with_response_body_size_limit(api_config.web3_json_rpc.max_response_body_size())
             .with_websocket_requests_per_minute_limit(
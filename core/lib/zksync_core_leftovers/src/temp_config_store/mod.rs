@@ -8,12 +8,14 @@ use zksync_config::{
         fri_prover_group::FriProverGroupConfig,
         house_keeper::HouseKeeperConfig,
         wallets::{AddressWallet, EthSender, StateKeeper, Wallet, Wallets},
-        FriProofCompressorConfig, FriProverConfig, FriProverGatewayConfig,
-        FriWitnessGeneratorConfig, FriWitnessVectorGeneratorConfig, GeneralConfig,
-        ObservabilityConfig, PrometheusConfig, ProofDataHandlerConfig, ProtectiveReadsWriterConfig,
+        BasicWitnessInputProducerConfig, CommitmentGeneratorConfig, FriProofCompressorConfig,
+        FriProverConfig, FriProverGatewayConfig, FriWitnessGeneratorConfig,
+        FriWitnessVectorGeneratorConfig, GeneralConfig, ObservabilityConfig, PrometheusConfig,
+        ProofDataHandlerConfig, ProtectiveReadsWriterConfig, VmPlaygroundConfig,
     },
-    ApiConfig, ContractVerifierConfig, DBConfig, EthConfig, EthWatchConfig, GasAdjusterConfig,
-    ObjectStoreConfig, PostgresConfig, SnapshotsCreatorConfig,
+    ApiConfig, BaseTokenPriceConfig, ContractVerifierConfig, DAClientConfig, DADispatcherConfig,
+    DBConfig, EthConfig, EthWatchConfig, GasAdjusterConfig, ObjectStoreConfig, PostgresConfig,
+    SnapshotsCreatorConfig,
 };
 use zksync_protobuf::{repr::ProtoRepr, ProtoFmt};
 
@@ -61,7 +63,13 @@ pub struct TempConfigStore {
     pub observability: Option<ObservabilityConfig>,
     pub snapshot_creator: Option<SnapshotsCreatorConfig>,
     pub protective_reads_writer_config: Option<ProtectiveReadsWriterConfig>,
+    pub basic_witness_input_producer_config: Option<BasicWitnessInputProducerConfig>,
+    pub vm_playground_config: Option<VmPlaygroundConfig>,
     pub core_object_store: Option<ObjectStoreConfig>,
+    pub da_dispatcher_config: Option<DADispatcherConfig>,
+    pub da_client_config: Option<DAClientConfig>,
+    pub base_token_price_config: Option<BaseTokenPriceConfig>,
+    pub commitment_generator_config: Option<CommitmentGeneratorConfig>,
 }
 
 impl TempConfigStore {
@@ -88,7 +96,13 @@ impl TempConfigStore {
             snapshot_creator: self.snapshot_creator.clone(),
             observability: self.observability.clone(),
             protective_reads_writer_config: self.protective_reads_writer_config.clone(),
+            basic_witness_input_producer_config: self.basic_witness_input_producer_config.clone(),
+            vm_playground_config: self.vm_playground_config.clone(),
             core_object_store: self.core_object_store.clone(),
+            da_dispatcher_config: self.da_dispatcher_config.clone(),
+            da_client_config: self.da_client_config.clone(),
+            base_token_price_config: self.base_token_price_config.clone(),
+            commitment_generator_config: self.commitment_generator_config.clone(),
         }
     }
 
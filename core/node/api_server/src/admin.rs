@@ -0,0 +1,682 @@
+//! Admin server allowing to inspect and change the node configuration at runtime.
+//!
+//! The server exposes the following endpoints:
+//!
+//! - `GET /log/directives` returns the currently used log directives (in the `RUST_LOG` format).
+//! - `PUT /log/directives` replaces log directives with the ones provided in the request body.
+//! - `GET /debug/jemalloc/stats` returns jemalloc allocation statistics (allocated, active, resident etc. bytes) as JSON.
+//! - `POST /config/diff` compares the effective node configuration with the candidate config file provided
+//!   in the request body, and returns a structured diff as JSON. Only available if the node provides a [`ConfigDiffer`].
+//! - `POST /revert?l1_batch_number={n}&dry_run={bool}` rolls back the node state so that the specified L1 batch
+//!   is the last one left. With `dry_run=true` (the default), only returns the rollback plan, i.e. the data
+//!   that would be removed. Only available if the node provides a [`RevertHandler`]; requires the bearer token
+//!   specified when enabling the endpoint in the `Authorization` header.
+//!
+//! If the audit log is enabled, changes made via the server are recorded in the append-only admin audit log
+//! in Postgres. The actor is taken from the `x-admin-actor` request header (`admin_api` if not specified).
+//! The log can be inspected via `GET /audit_log?before_id={id}&limit={limit}`, which returns entries
+//! from newest to oldest.
+//!
+//! If profiling is enabled, the following endpoints are exposed as well:
+//!
+//! - `GET /debug/pprof/profile?seconds={seconds}` captures a CPU profile for the specified duration
+//!   (30 seconds by default) and returns it in the pprof protobuf format. The profile can be analyzed
+//!   with `go tool pprof` or other compatible tools.
+//! - `GET /debug/pprof/heap` dumps a jemalloc heap profile, which can be analyzed with `jeprof`. Requires the binary
+//!   to enable jemalloc profiling in `malloc_conf` (the server and the external node do this). Allocations are sampled
+//!   only after the admin server is started with profiling enabled.
+//! - `GET /debug/tasks` returns a dump of async tasks running on the Tokio runtime. Requires the node
+//!   to be built with `RUSTFLAGS="--cfg tokio_unstable --cfg tokio_taskdump"`.
+//!
+//! Apart from the revert endpoint, the server has no authentication and must not be exposed publicly.
+
+use std::{
+    fmt,
+    net::SocketAddr,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use anyhow::Context as _;
+use axum::{
+    extract::{Query, State},
+    http::{header, HeaderMap, StatusCode},
+    response::IntoResponse,
+    routing::{get, post},
+    Json, Router,
+};
+use pprof::protos::Message as _;
+use secrecy::{ExposeSecret as _, Secret};
+use serde::Deserialize;
+use tokio::sync::watch;
+use vlog::LogDirectivesHandle;
+use zksync_dal::{admin_audit_log_dal::AdminAuditLogEntry, ConnectionPool, Core, CoreDal};
+use zksync_types::L1BatchNumber;
+
+type ErrorResponse = (StatusCode, String);
+
+/// Header specifying the actor recorded in the audit log.
+const ACTOR_HEADER: &str = "x-admin-actor";
+const DEFAULT_ACTOR: &str = "admin_api";
+
+/// Compares the configuration effective for the node with candidate config files.
+pub trait ConfigDiffer: fmt::Debug + Send + Sync + 'static {
+    /// Parses the candidate config file and returns its diff with the effective configuration.
+    fn diff(&self, candidate: &str) -> anyhow::Result<serde_json::Value>;
+}
+
+/// Rolls back the node state on request (see the `/revert` endpoint).
+#[async_trait::async_trait]
+pub trait RevertHandler: fmt::Debug + Send + Sync + 'static {
+    /// Returns the plan of rolling back the node state to the specified L1 batch without changing anything.
+    async fn plan(&self, last_l1_batch_to_keep: L1BatchNumber)
+        -> anyhow::Result<serde_json::Value>;
+
+    /// Rolls back the node state to the specified L1 batch, returning the executed plan. The rollback
+    /// may be performed asynchronously (e.g., after the node stops its components).
+    async fn revert(
+        &self,
+        last_l1_batch_to_keep: L1BatchNumber,
+    ) -> anyhow::Result<serde_json::Value>;
+}
+
+#[derive(Debug, Clone)]
+struct RevertEndpoint {
+    handler: Arc<dyn RevertHandler>,
+    auth_token: Arc<Secret<String>>,
+}
+
+#[derive(Debug, Clone)]
+struct AdminState {
+    audit_pool: Option<ConnectionPool<Core>>,
+    config_differ: Option<Arc<dyn ConfigDiffer>>,
+    revert: Option<RevertEndpoint>,
+}
+
+impl AdminState {
+    async fn record_audit_entry(
+        &self,
+        headers: &HeaderMap,
+        action: &str,
+        params: serde_json::Value,
+    ) -> Result<(), ErrorResponse> {
+        let Some(pool) = &self.audit_pool else {
+            return Ok(());
+        };
+        let actor = headers
+            .get(ACTOR_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or(DEFAULT_ACTOR);
+        let mut storage = pool
+            .connection_tagged("admin_server")
+            .await
+            .map_err(internal_error)?;
+        storage
+            .admin_audit_log_dal()
+            .insert_entry(actor, action, &params)
+            .await
+            .map_err(internal_error)?;
+        Ok(())
+    }
+}
+
+fn internal_error(err: impl std::fmt::Display) -> ErrorResponse {
+    (StatusCode::INTERNAL_SERVER_ERROR, err.to_string())
+}
+
+fn log_directives_handle() -> Result<&'static LogDirectivesHandle, ErrorResponse> {
+    LogDirectivesHandle::global().ok_or_else(|| {
+        let message = "observability subsystem is not initialized".to_owned();
+        (StatusCode::SERVICE_UNAVAILABLE, message)
+    })
+}
+
+async fn get_log_directives() -> Result<String, ErrorResponse> {
+    log_directives_handle()?
+        .current()
+        .map_err(|err| (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()))
+}
+
+async fn set_log_directives(
+    State(state): State<AdminState>,
+    headers: HeaderMap,
+    directives: String,
+) -> Result<String, ErrorResponse> {
+    let handle = log_directives_handle()?;
+    let directives = directives.trim();
+    handle
+        .update(directives)
+        .map_err(|err| (StatusCode::BAD_REQUEST, err.to_string()))?;
+    tracing::info!("Updated log directives to `{directives}`");
+    let params = serde_json::json!({ "directives": directives });
+    state
+        .record_audit_entry(&headers, "update_log_directives", params)
+        .await?;
+    handle
+        .current()
+        .map_err(|err| (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()))
+}
+
+#[derive(Debug, Deserialize)]
+struct AuditLogQuery {
+    before_id: Option<u64>,
+    #[serde(default = "AuditLogQuery::default_limit")]
+    limit: usize,
+}
+
+impl AuditLogQuery {
+    const MAX_LIMIT: usize = 1_000;
+
+    const fn default_limit() -> usize {
+        100
+    }
+}
+
+async fn get_audit_log(
+    State(state): State<AdminState>,
+    Query(query): Query<AuditLogQuery>,
+) -> Result<Json<Vec<AdminAuditLogEntry>>, ErrorResponse> {
+    let Some(pool) = &state.audit_pool else {
+        let message = "audit log is not enabled".to_owned();
+        return Err((StatusCode::NOT_FOUND, message));
+    };
+    if !(1..=AuditLogQuery::MAX_LIMIT).contains(&query.limit) {
+        let message = format!("`limit` must be in 1..={}", AuditLogQuery::MAX_LIMIT);
+        return Err((StatusCode::BAD_REQUEST, message));
+    }
+
+    let mut storage = pool
+        .connection_tagged("admin_server")
+        .await
+        .map_err(internal_error)?;
+    let entries = storage
+        .admin_audit_log_dal()
+        .get_entries(query.before_id, query.limit)
+        .await
+        .map_err(internal_error)?;
+    Ok(Json(entries))
+}
+
+async fn diff_config(
+    State(state): State<AdminState>,
+    candidate: String,
+) -> Result<Json<serde_json::Value>, ErrorResponse> {
+    let Some(differ) = &state.config_differ else {
+        let message = "config diffs are not supported by the node".to_owned();
+        return Err((StatusCode::NOT_FOUND, message));
+    };
+    differ
+        .diff(&candidate)
+        .map(Json)
+        .map_err(|err| (StatusCode::BAD_REQUEST, format!("{err:#}")))
+}
+
+#[derive(Debug, Deserialize)]
+struct RevertQuery {
+    l1_batch_number: u32,
+    #[serde(default = "RevertQuery::default_dry_run")]
+    dry_run: bool,
+}
+
+impl RevertQuery {
+    const fn default_dry_run() -> bool {
+        true
+    }
+}
+
+/// Checks the bearer token in the `Authorization` header.
+fn authorize(headers: &HeaderMap, expected_token: &Secret<String>) -> Result<(), ErrorResponse> {
+    let token = headers
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+    let expected = expected_token.expose_secret().as_bytes();
+    // An empty expected token would authorize requests with an empty bearer token.
+    let is_authorized = !expected.is_empty()
+        && token.map_or(false, |token| {
+            // Compare in constant time to not leak the token via response timings.
+            token.len() == expected.len()
+                && token
+                    .bytes()
+                    .zip(expected)
+                    .fold(0_u8, |acc, (x, y)| acc | (x ^ y))
+                    == 0
+        });
+    if is_authorized {
+        Ok(())
+    } else {
+        let message = "missing or invalid bearer token".to_owned();
+        Err((StatusCode::UNAUTHORIZED, message))
+    }
+}
+
+async fn revert(
+    State(state): State<AdminState>,
+    headers: HeaderMap,
+    Query(query): Query<RevertQuery>,
+) -> Result<Json<serde_json::Value>, ErrorResponse> {
+    let Some(endpoint) = &state.revert else {
+        let message = "reverts are not supported by the node".to_owned();
+        return Err((StatusCode::NOT_FOUND, message));
+    };
+    authorize(&headers, &endpoint.auth_token)?;
+
+    let last_l1_batch_to_keep = L1BatchNumber(query.l1_batch_number);
+    if query.dry_run {
+        return endpoint
+            .handler
+            .plan(last_l1_batch_to_keep)
+            .await
+            .map(Json)
+            .map_err(|err| (StatusCode::BAD_REQUEST, format!("{err:#}")));
+    }
+
+    tracing::warn!("Requested rolling back node state to L1 batch #{last_l1_batch_to_keep}");
+    let params = serde_json::json!({ "last_l1_batch_to_keep": last_l1_batch_to_keep.0 });
+    state
+        .record_audit_entry(&headers, "request_revert", params)
+        .await?;
+    endpoint
+        .handler
+        .revert(last_l1_batch_to_keep)
+        .await
+        .map(Json)
+        .map_err(|err| (StatusCode::BAD_REQUEST, format!("{err:#}")))
+}
+
+/// Only one CPU profile can be captured at a time.
+static IS_PROFILING: AtomicBool = AtomicBool::new(false);
+
+/// Resets [`IS_PROFILING`] on drop.
+#[derive(Debug)]
+struct ProfilingGuard(());
+
+impl ProfilingGuard {
+    fn acquire() -> Option<Self> {
+        IS_PROFILING
+            .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+            .ok()
+            .map(|_| Self(()))
+    }
+}
+
+impl Drop for ProfilingGuard {
+    fn drop(&mut self) {
+        IS_PROFILING.store(false, Ordering::Release);
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct ProfileQuery {
+    #[serde(default = "ProfileQuery::default_seconds")]
+    seconds: u64,
+    #[serde(default = "ProfileQuery::default_frequency")]
+    frequency: i32,
+}
+
+impl ProfileQuery {
+    const MAX_SECONDS: u64 = 300;
+    const MAX_FREQUENCY: i32 = 1_000;
+
+    const fn default_seconds() -> u64 {
+        30
+    }
+
+    const fn default_frequency() -> i32 {
+        99
+    }
+
+    fn validate(&self) -> Result<(), ErrorResponse> {
+        if !(1..=Self::MAX_SECONDS).contains(&self.seconds) {
+            let message = format!("`seconds` must be in 1..={}", Self::MAX_SECONDS);
+            return Err((StatusCode::BAD_REQUEST, message));
+        }
+        if !(1..=Self::MAX_FREQUENCY).contains(&self.frequency) {
+            let message = format!("`frequency` must be in 1..={}", Self::MAX_FREQUENCY);
+            return Err((StatusCode::BAD_REQUEST, message));
+        }
+        Ok(())
+    }
+}
+
+fn capture_cpu_profile(duration: Duration, frequency: i32) -> anyhow::Result<Vec<u8>> {
+    let guard = pprof::ProfilerGuardBuilder::default()
+        .frequency(frequency)
+        .blocklist(&["libc", "libgcc", "pthread", "vdso"])
+        .build()
+        .context("failed starting profiler")?;
+    std::thread::sleep(duration);
+    let profile = guard
+        .report()
+        .build()
+        .context("failed building profiling report")?
+        .pprof()
+        .context("failed converting report to pprof format")?;
+    Ok(profile.encode_to_vec())
+}
+
+async fn cpu_profile(
+    Query(query): Query<ProfileQuery>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    query.validate()?;
+    let _guard = ProfilingGuard::acquire().ok_or_else(|| {
+        let message = "another CPU profile is being captured".to_owned();
+        (StatusCode::CONFLICT, message)
+    })?;
+
+    tracing::info!(
+        "Capturing CPU profile for {}s with frequency {}Hz",
+        query.seconds,
+        query.frequency
+    );
+    let duration = Duration::from_secs(query.seconds);
+    let profile =
+        tokio::task::spawn_blocking(move || capture_cpu_profile(duration, query.frequency))
+            .await
+            .context("profiling task panicked")
+            .and_then(|res| res)
+            .map_err(|err| (StatusCode::INTERNAL_SERVER_ERROR, format!("{err:#}")))?;
+
+    let headers = [
+        (header::CONTENT_TYPE, "application/octet-stream"),
+        (
+            header::CONTENT_DISPOSITION,
+            "attachment; filename=\"profile.pb\"",
+        ),
+    ];
+    Ok((headers, profile))
+}
+
+#[cfg(all(tokio_unstable, tokio_taskdump))]
+async fn task_dump() -> Result<String, ErrorResponse> {
+    use std::fmt::Write as _;
+
+    const DUMP_TIMEOUT: Duration = Duration::from_secs(10);
+
+    let handle = tokio::runtime::Handle::current();
+    let dump = tokio::time::timeout(DUMP_TIMEOUT, handle.dump())
+        .await
+        .map_err(|_| {
+            let message = "timed out collecting task dump".to_owned();
+            (StatusCode::INTERNAL_SERVER_ERROR, message)
+        })?;
+
+    let mut output = String::new();
+    for (i, task) in dump.tasks().iter().enumerate() {
+        writeln!(output, "Task #{i}:\n{}\n", task.trace()).ok();
+    }
+    Ok(output)
+}
+
+#[cfg(not(all(tokio_unstable, tokio_taskdump)))]
+async fn task_dump() -> Result<String, ErrorResponse> {
+    let message = "task dumps require building with \
+        `RUSTFLAGS=\"--cfg tokio_unstable --cfg tokio_taskdump\"`";
+    Err((StatusCode::NOT_IMPLEMENTED, message.to_owned()))
+}
+
+#[cfg(not(target_env = "msvc"))]
+mod jemalloc {
+    use std::{
+        ffi::{c_char, CString},
+        sync::atomic::{AtomicU64, Ordering},
+    };
+
+    use anyhow::Context as _;
+    use serde::Serialize;
+    use tikv_jemalloc_ctl::{epoch, raw, stats};
+
+    /// Allocation statistics in bytes. See jemalloc docs for the meaning of each value.
+    #[derive(Debug, Serialize)]
+    pub(super) struct JemallocStats {
+        allocated: usize,
+        active: usize,
+        metadata: usize,
+        resident: usize,
+        mapped: usize,
+        retained: usize,
+    }
+
+    pub(super) fn stats() -> anyhow::Result<JemallocStats> {
+        // Statistics are cached by jemalloc; advancing the epoch refreshes them.
+        epoch::advance().context("failed advancing jemalloc epoch")?;
+        Ok(JemallocStats {
+            allocated: stats::allocated::read().context("allocated")?,
+            active: stats::active::read().context("active")?,
+            metadata: stats::metadata::read().context("metadata")?,
+            resident: stats::resident::read().context("resident")?,
+            mapped: stats::mapped::read().context("mapped")?,
+            retained: stats::retained::read().context("retained")?,
+        })
+    }
+
+    pub(super) fn is_profiling_available() -> bool {
+        // SAFETY: `opt.prof` has the `bool` type.
+        unsafe { raw::read::<bool>(b"opt.prof\0") }.unwrap_or(false)
+    }
+
+    pub(super) fn activate_profiling() -> anyhow::Result<()> {
+        // SAFETY: `prof.active` has the `bool` type.
+        unsafe { raw::write(b"prof.active\0", true) }.context("failed activating heap profiling")
+    }
+
+    pub(super) fn dump_heap_profile() -> anyhow::Result<Vec<u8>> {
+        static DUMP_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+        let dump_idx = DUMP_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let path = std::env::temp_dir().join(format!(
+            "zksync-heap-{}-{dump_idx}.prof",
+            std::process::id()
+        ));
+        let c_path = path
+            .to_str()
+            .and_then(|path| CString::new(path).ok())
+            .with_context(|| format!("invalid heap profile path: {path:?}"))?;
+        // SAFETY: `prof.dump` accepts a nul-terminated path, which lives until the end of the call.
+        unsafe { raw::write::<*const c_char>(b"prof.dump\0", c_path.as_ptr()) }
+            .context("failed dumping heap profile")?;
+
+        let profile = std::fs::read(&path);
+        if let Err(err) = std::fs::remove_file(&path) {
+            tracing::warn!("Failed removing heap profile at {path:?}: {err}");
+        }
+        profile.with_context(|| format!("failed reading heap profile at {path:?}"))
+    }
+}
+
+#[cfg(not(target_env = "msvc"))]
+async fn jemalloc_stats() -> Result<Json<jemalloc::JemallocStats>, ErrorResponse> {
+    jemalloc::stats()
+        .map(Json)
+        .map_err(|err| (StatusCode::INTERNAL_SERVER_ERROR, format!("{err:#}")))
+}
+
+#[cfg(target_env = "msvc")]
+async fn jemalloc_stats() -> Result<String, ErrorResponse> {
+    let message = "jemalloc is not used on this platform".to_owned();
+    Err((StatusCode::NOT_IMPLEMENTED, message))
+}
+
+#[cfg(not(target_env = "msvc"))]
+async fn heap_profile() -> Result<impl IntoResponse, ErrorResponse> {
+    if !jemalloc::is_profiling_available() {
+        let message =
+            "heap profiling requires enabling jemalloc profiling via `prof:true` in `malloc_conf`";
+        return Err((StatusCode::NOT_IMPLEMENTED, message.to_owned()));
+    }
+
+    tracing::info!("Dumping heap profile");
+    let profile = tokio::task::spawn_blocking(jemalloc::dump_heap_profile)
+        .await
+        .context("heap profiling task panicked")
+        .and_then(|res| res)
+        .map_err(|err| (StatusCode::INTERNAL_SERVER_ERROR, format!("{err:#}")))?;
+
+    let headers = [
+        (header::CONTENT_TYPE, "application/octet-stream"),
+        (
+            header::CONTENT_DISPOSITION,
+            "attachment; filename=\"heap.prof\"",
+        ),
+    ];
+    Ok((headers, profile))
+}
+
+#[cfg(target_env = "msvc")]
+async fn heap_profile() -> Result<String, ErrorResponse> {
+    let message = "jemalloc is not used on this platform".to_owned();
+    Err((StatusCode::NOT_IMPLEMENTED, message))
+}
+
+/// Admin server allowing to inspect and change the node configuration at runtime.
+#[derive(Debug)]
+pub struct AdminServer {
+    bind_address: SocketAddr,
+    profiling_enabled: bool,
+    audit_pool: Option<ConnectionPool<Core>>,
+    config_differ: Option<Arc<dyn ConfigDiffer>>,
+    revert: Option<RevertEndpoint>,
+}
+
+impl AdminServer {
+    pub fn new(bind_address: SocketAddr) -> Self {
+        Self {
+            bind_address,
+            profiling_enabled: false,
+            audit_pool: None,
+            config_differ: None,
+            revert: None,
+        }
+    }
+
+    /// Enables recording changes made via the server in the admin audit log stored in Postgres,
+    /// and the endpoint to inspect this log.
+    #[must_use]
+    pub fn with_audit_log(mut self, pool: ConnectionPool<Core>) -> Self {
+        self.audit_pool = Some(pool);
+        self
+    }
+
+    /// Enables the endpoint comparing the effective configuration with candidate config files.
+    #[must_use]
+    pub fn with_config_differ(mut self, differ: Arc<dyn ConfigDiffer>) -> Self {
+        self.config_differ = Some(differ);
+        self
+    }
+
+    /// Enables the endpoint rolling back the node state. Requests to the endpoint must be authorized
+    /// with the specified bearer token.
+    #[must_use]
+    pub fn with_revert_handler(
+        mut self,
+        handler: Arc<dyn RevertHandler>,
+        auth_token: Secret<String>,
+    ) -> Self {
+        self.revert = Some(RevertEndpoint {
+            handler,
+            auth_token: Arc::new(auth_token),
+        });
+        self
+    }
+
+    /// Enables or disables profiling endpoints. Profiling is disabled by default.
+    #[must_use]
+    pub fn with_profiling(mut self, enabled: bool) -> Self {
+        self.profiling_enabled = enabled;
+        self
+    }
+
+    /// Runs the server until a stop signal is received.
+    pub async fn run(self, mut stop_receiver: watch::Receiver<bool>) -> anyhow::Result<()> {
+        let bind_address = self.bind_address;
+        tracing::info!(
+            "Starting admin server on {bind_address}, profiling enabled: {}, audit log enabled: {}, reverts enabled: {}",
+            self.profiling_enabled,
+            self.audit_pool.is_some(),
+            self.revert.is_some()
+        );
+
+        let mut app = Router::new()
+            .route(
+                "/log/directives",
+                get(get_log_directives).put(set_log_directives),
+            )
+            .route("/audit_log", get(get_audit_log))
+            .route("/config/diff", post(diff_config))
+            .route("/revert", post(revert))
+            .route("/debug/jemalloc/stats", get(jemalloc_stats));
+        if self.profiling_enabled {
+            #[cfg(not(target_env = "msvc"))]
+            if jemalloc::is_profiling_available() {
+                match jemalloc::activate_profiling() {
+                    Ok(()) => tracing::info!("Activated jemalloc heap profiling"),
+                    Err(err) => tracing::warn!("{err:#}"),
+                }
+            }
+
+            app = app
+                .route("/debug/pprof/profile", get(cpu_profile))
+                .route("/debug/pprof/heap", get(heap_profile))
+                .route("/debug/tasks", get(task_dump));
+        }
+        let app = app.with_state(AdminState {
+            audit_pool: self.audit_pool,
+            config_differ: self.config_differ,
+            revert: self.revert,
+        });
+
+        axum::Server::try_bind(&bind_address)
+            .with_context(|| format!("failed binding admin server to {bind_address}"))?
+            .serve(app.into_make_service())
+            .with_graceful_shutdown(async move {
+                if stop_receiver.changed().await.is_err() {
+                    tracing::warn!(
+                        "Stop signal sender for admin server was dropped without sending a signal"
+                    );
+                }
+                tracing::info!("Stop signal received, admin server is shutting down");
+            })
+            .await
+            .context("admin server failed")?;
+        tracing::info!("Admin server shut down");
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::HeaderValue;
+
+    use super::*;
+
+    fn headers_with_token(token: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        let value = HeaderValue::from_str(&format!("Bearer {token}")).unwrap();
+        headers.insert(header::AUTHORIZATION, value);
+        headers
+    }
+
+    #[test]
+    fn authorizing_revert_requests() {
+        let expected_token = Secret::new("test-token".to_owned());
+        authorize(&headers_with_token("test-token"), &expected_token).unwrap();
+
+        let (status, _) =
+            authorize(&headers_with_token("wrong-token"), &expected_token).unwrap_err();
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        let (status, _) = authorize(&HeaderMap::new(), &expected_token).unwrap_err();
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+    }
+
+    #[test]
+    fn empty_expected_token_rejects_all_requests() {
+        let expected_token = Secret::new(String::new());
+        let (status, _) = authorize(&headers_with_token(""), &expected_token).unwrap_err();
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        let (status, _) = authorize(&HeaderMap::new(), &expected_token).unwrap_err();
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+    }
+}
@@ -23,7 +23,7 @@ use zksync_system_constants::{
     SYSTEM_CONTEXT_CURRENT_TX_ROLLING_HASH_POSITION, ZKPORTER_IS_AVAILABLE,
 };
 use zksync_types::{
-    api,
+    api::{self, state_override::StateOverride},
     block::{pack_block_info, unpack_block_info, L2BlockHasher},
     fee_model::BatchFeeInput,
     get_nonce_key,
@@ -34,11 +34,13 @@ use zksync_types::{
 use zksync_utils::{h256_to_u256, time::seconds_since_epoch, u256_to_h256};
 
 use super::{
+    storage::StorageWithOverrides,
     vm_metrics::{self, SandboxStage, SANDBOX_METRICS},
     BlockArgs, TxExecutionArgs, TxSharedArgs, VmPermit,
 };
 
-type BoxedVm<'a> = Box<VmInstance<StorageView<PostgresStorage<'a>>, HistoryDisabled>>;
+type SandboxStorage<'a> = StorageView<StorageWithOverrides<PostgresStorage<'a>>>;
+type BoxedVm<'a> = Box<VmInstance<SandboxStorage<'a>, HistoryDisabled>>;
 
 #[derive(Debug)]
 struct Sandbox<'a> {
@@ -46,7 +48,7 @@ struct Sandbox<'a> {
     l1_batch_env: L1BatchEnv,
     execution_args: &'a TxExecutionArgs,
     l2_block_info_to_reset: Option<StoredL2BlockInfo>,
-    storage_view: StorageView<PostgresStorage<'a>>,
+    storage_view: SandboxStorage<'a>,
 }
 
 impl<'a> Sandbox<'a> {
@@ -55,6 +57,7 @@ impl<'a> Sandbox<'a> {
         shared_args: TxSharedArgs,
         execution_args: &'a TxExecutionArgs,
         block_args: BlockArgs,
+        state_override: Option<StateOverride>,
     ) -> anyhow::Result<Sandbox<'a>> {
         let resolve_started_at = Instant::now();
         let resolved_block_info = block_args
@@ -71,6 +74,9 @@ impl<'a> Sandbox<'a> {
             shared_args
                 .caches
                 .schedule_values_update(resolved_block_info.state_l2_block_number);
+            shared_args
+                .caches
+                .observe_protocol_version(resolved_block_info.protocol_version);
         }
 
         let (next_l2_block_info, l2_block_info_to_reset) = Self::load_l2_block_info(
@@ -90,6 +96,10 @@ impl<'a> Sandbox<'a> {
         .context("cannot create `PostgresStorage`")?
         .with_caches(shared_args.caches.clone());
 
+        let mut storage = StorageWithOverrides::new(storage);
+        if let Some(state_override) = &state_override {
+            storage = storage.with_state_override(state_override);
+        }
         let storage_view = StorageView::new(storage);
         let (system_env, l1_batch_env) = Self::prepare_env(
             shared_args,
@@ -217,7 +227,7 @@ impl<'a> Sandbox<'a> {
         shared_args: TxSharedArgs,
         execution_args: &TxExecutionArgs,
         resolved_block_info: &ResolvedBlockInfo,
-        next_l2_block_info: L2BlockEnv,
+        mut next_l2_block_info: L2BlockEnv,
     ) -> (SystemEnv, L1BatchEnv) {
         let TxSharedArgs {
             operator_account,
@@ -232,11 +242,19 @@ impl<'a> Sandbox<'a> {
         let fee_input = resolved_block_info
             .historical_fee_input
             .unwrap_or(fee_input);
+        let mut l1_batch_timestamp = resolved_block_info.l1_batch_timestamp;
+        if let Some(timestamp) = execution_args.enforced_timestamp {
+            next_l2_block_info.timestamp = timestamp;
+            l1_batch_timestamp = timestamp;
+        }
+        let protocol_version = execution_args
+            .enforced_protocol_version
+            .unwrap_or(resolved_block_info.protocol_version);
         let system_env = SystemEnv {
             zk_porter_available: ZKPORTER_IS_AVAILABLE,
-            version: resolved_block_info.protocol_version,
+            version: protocol_version,
             base_system_smart_contracts: base_system_contracts
-                .get_by_protocol_version(resolved_block_info.protocol_version),
+                .get_by_protocol_version(protocol_version),
             bootloader_gas_limit: BATCH_COMPUTATIONAL_GAS_LIMIT,
             execution_mode: execution_args.execution_mode,
             default_validation_computational_gas_limit: validation_computational_gas_limit,
@@ -245,7 +263,7 @@ impl<'a> Sandbox<'a> {
         let l1_batch_env = L1BatchEnv {
             previous_batch_hash: None,
             number: resolved_block_info.vm_l1_batch_number,
-            timestamp: resolved_block_info.l1_batch_timestamp,
+            timestamp: l1_batch_timestamp,
             fee_input,
             fee_account: *operator_account.address(),
             enforced_base_fee: execution_args.enforced_base_fee,
@@ -259,7 +277,7 @@ impl<'a> Sandbox<'a> {
         mut self,
         tx: &Transaction,
         adjust_pubdata_price: bool,
-    ) -> (BoxedVm<'a>, StoragePtr<StorageView<PostgresStorage<'a>>>) {
+    ) -> (BoxedVm<'a>, StoragePtr<SandboxStorage<'a>>) {
         self.setup_storage_view(tx);
         let protocol_version = self.system_env.version;
         if adjust_pubdata_price {
@@ -295,8 +313,9 @@ pub(super) fn apply_vm_in_sandbox<T>(
     connection_pool: &ConnectionPool<Core>,
     tx: Transaction,
     block_args: BlockArgs,
+    state_override: Option<StateOverride>,
     apply: impl FnOnce(
-        &mut VmInstance<StorageView<PostgresStorage<'_>>, HistoryDisabled>,
+        &mut VmInstance<SandboxStorage<'_>, HistoryDisabled>,
         Transaction,
         ProtocolVersionId,
     ) -> T,
@@ -319,6 +338,7 @@ pub(super) fn apply_vm_in_sandbox<T>(
         shared_args,
         execution_args,
         block_args,
+        state_override,
     ))?;
     let protocol_version = sandbox.system_env.version;
     let (mut vm, storage_view) = sandbox.into_vm(&tx, adjust_pubdata_price);
@@ -9,8 +9,11 @@ use multivm::{
 use tracing::{span, Level};
 use zksync_dal::{ConnectionPool, Core};
 use zksync_types::{
-    fee::TransactionExecutionMetrics, l2::L2Tx, transaction_request::CallOverrides,
-    ExecuteTransactionCommon, Nonce, PackedEthSignature, Transaction, U256,
+    api::state_override::{BlockOverrides, StateOverride},
+    fee::TransactionExecutionMetrics,
+    l2::L2Tx,
+    transaction_request::CallOverrides,
+    ExecuteTransactionCommon, Nonce, PackedEthSignature, ProtocolVersionId, Transaction, U256,
 };
 
 use super::{
@@ -24,6 +27,9 @@ pub(crate) struct TxExecutionArgs {
     pub enforced_nonce: Option<Nonce>,
     pub added_balance: U256,
     pub enforced_base_fee: Option<u64>,
+    pub enforced_timestamp: Option<u64>,
+    /// Protocol version to use instead of the version active at the target block.
+    pub enforced_protocol_version: Option<ProtocolVersionId>,
     pub missed_storage_invocation_limit: usize,
 }
 
@@ -34,12 +40,14 @@ impl TxExecutionArgs {
             enforced_nonce: Some(tx.nonce()),
             added_balance: U256::zero(),
             enforced_base_fee: Some(tx.common_data.fee.max_fee_per_gas.as_u64()),
+            enforced_timestamp: None,
+            enforced_protocol_version: None,
             missed_storage_invocation_limit: usize::MAX,
         }
     }
 
     fn for_eth_call(
-        enforced_base_fee: Option<u64>,
+        call_overrides: &CallOverrides,
         vm_execution_cache_misses_limit: Option<usize>,
     ) -> Self {
         let missed_storage_invocation_limit = vm_execution_cache_misses_limit.unwrap_or(usize::MAX);
@@ -47,11 +55,23 @@ impl TxExecutionArgs {
             execution_mode: TxExecutionMode::EthCall,
             enforced_nonce: None,
             added_balance: U256::zero(),
-            enforced_base_fee,
+            enforced_base_fee: call_overrides.enforced_base_fee,
+            enforced_timestamp: None,
+            enforced_protocol_version: call_overrides.protocol_version,
             missed_storage_invocation_limit,
         }
     }
 
+    fn with_block_overrides(mut self, block_overrides: &BlockOverrides) -> Self {
+        if let Some(base_fee) = block_overrides.base_fee {
+            self.enforced_base_fee = Some(base_fee.as_u64());
+        }
+        if let Some(timestamp) = block_overrides.timestamp {
+            self.enforced_timestamp = Some(timestamp.as_u64());
+        }
+        self
+    }
+
     pub fn for_gas_estimate(
         vm_execution_cache_misses_limit: Option<usize>,
         tx: &Transaction,
@@ -72,6 +92,8 @@ impl TxExecutionArgs {
             enforced_nonce: tx.nonce(),
             added_balance,
             enforced_base_fee: Some(base_fee),
+            enforced_timestamp: None,
+            enforced_protocol_version: None,
         }
     }
 }
@@ -133,6 +155,7 @@ impl TransactionExecutor {
                 &connection_pool,
                 tx,
                 block_args,
+                None,
                 |vm, tx, _| {
                     let storage_invocation_tracer =
                         StorageInvocations::new(execution_args.missed_storage_invocation_limit);
@@ -163,6 +186,8 @@ impl TransactionExecutor {
         })
     }
 
+    /// Executes an `eth_call`-like transaction. If a state override is provided, it is applied on top of the state
+    /// at the target block; the override must be validated beforehand.
     #[allow(clippy::too_many_arguments)]
     pub async fn execute_tx_eth_call(
         &self,
@@ -170,32 +195,139 @@ impl TransactionExecutor {
         shared_args: TxSharedArgs,
         connection_pool: ConnectionPool<Core>,
         call_overrides: CallOverrides,
-        mut tx: L2Tx,
+        tx: L2Tx,
         block_args: BlockArgs,
         vm_execution_cache_misses_limit: Option<usize>,
         custom_tracers: Vec<ApiTracer>,
+        state_override: Option<StateOverride>,
     ) -> anyhow::Result<VmExecutionResultAndLogs> {
-        let execution_args = TxExecutionArgs::for_eth_call(
-            call_overrides.enforced_base_fee,
-            vm_execution_cache_misses_limit,
-        );
+        if state_override.is_none() {
+            let execution_args =
+                TxExecutionArgs::for_eth_call(&call_overrides, vm_execution_cache_misses_limit);
+            let output = self
+                .execute_tx_in_sandbox(
+                    vm_permit,
+                    shared_args,
+                    false,
+                    execution_args,
+                    connection_pool,
+                    Self::with_eth_call_signature(tx).into(),
+                    block_args,
+                    custom_tracers,
+                )
+                .await?;
+            return Ok(output.vm);
+        }
+
+        let mut results = self
+            .execute_tx_bundle_eth_call(
+                vm_permit,
+                shared_args,
+                connection_pool,
+                call_overrides,
+                vec![tx],
+                block_args,
+                vm_execution_cache_misses_limit,
+                vec![custom_tracers],
+                state_override,
+                None,
+            )
+            .await?;
+        Ok(results.pop().expect("no result for executed call"))
+    }
 
+    fn with_eth_call_signature(mut tx: L2Tx) -> L2Tx {
         if tx.common_data.signature.is_empty() {
             tx.common_data.signature = PackedEthSignature::default().serialize_packed().into();
         }
+        tx
+    }
 
-        let output = self
-            .execute_tx_in_sandbox(
+    /// Executes a sequence of `eth_call`-like transactions in a single sandbox session, so that
+    /// state changes made by a call are visible to the subsequent calls.
+    ///
+    /// The provided state and block overrides are applied once, before the first call is executed.
+    /// `custom_tracers` are specified separately for each transaction.
+    #[allow(clippy::too_many_arguments)]
+    #[tracing::instrument(skip_all, fields(txs.len = txs.len()))]
+    pub async fn execute_tx_bundle_eth_call(
+        &self,
+        vm_permit: VmPermit,
+        shared_args: TxSharedArgs,
+        connection_pool: ConnectionPool<Core>,
+        call_overrides: CallOverrides,
+        txs: Vec<L2Tx>,
+        block_args: BlockArgs,
+        vm_execution_cache_misses_limit: Option<usize>,
+        custom_tracers: Vec<Vec<ApiTracer>>,
+        state_override: Option<StateOverride>,
+        block_overrides: Option<BlockOverrides>,
+    ) -> anyhow::Result<Vec<VmExecutionResultAndLogs>> {
+        assert_eq!(
+            txs.len(),
+            custom_tracers.len(),
+            "Custom tracers must be specified for each transaction"
+        );
+
+        let mut txs: Vec<Transaction> = txs
+            .into_iter()
+            .map(|tx| Self::with_eth_call_signature(tx).into())
+            .collect();
+        if let Self::Mock(mock_executor) = self {
+            return txs
+                .iter()
+                .map(|tx| Ok(mock_executor.execute_tx(tx, &block_args)?.vm))
+                .collect();
+        }
+        if txs.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let mut execution_args =
+            TxExecutionArgs::for_eth_call(&call_overrides, vm_execution_cache_misses_limit);
+        if let Some(block_overrides) = &block_overrides {
+            execution_args = execution_args.with_block_overrides(block_overrides);
+        }
+
+        let first_tx = txs.remove(0);
+        let results = tokio::task::spawn_blocking(move || {
+            let span = span!(Level::DEBUG, "execute_bundle_in_sandbox").entered();
+            let result = apply::apply_vm_in_sandbox(
                 vm_permit,
                 shared_args,
                 false,
-                execution_args,
-                connection_pool,
-                tx.into(),
+                &execution_args,
+                &connection_pool,
+                first_tx,
                 block_args,
-                custom_tracers,
-            )
-            .await?;
-        Ok(output.vm)
+                state_override,
+                |vm, first_tx, _| {
+                    let txs = std::iter::once(first_tx).chain(txs);
+                    txs.zip(custom_tracers)
+                        .map(|(tx, custom_tracers)| {
+                            let storage_invocation_tracer = StorageInvocations::new(
+                                execution_args.missed_storage_invocation_limit,
+                            );
+                            let custom_tracers: Vec<_> = custom_tracers
+                                .into_iter()
+                                .map(|tracer| tracer.into_boxed())
+                                .chain(vec![storage_invocation_tracer.into_tracer_pointer()])
+                                .collect();
+                            let (_, result) = vm.inspect_transaction_with_bytecode_compression(
+                                custom_tracers.into(),
+                                tx,
+                                true,
+                            );
+                            result
+                        })
+                        .collect::<Vec<_>>()
+                },
+            );
+            span.exit();
+            result
+        })
+        .await
+        .context("transaction bundle execution panicked")??;
+        Ok(results)
     }
 }
@@ -0,0 +1,215 @@
+//! VM storage functionality specifically used in the VM sandbox.
+
+use std::collections::{HashMap, HashSet};
+
+use zksync_state::ReadStorage;
+use zksync_types::{
+    api::state_override::{OverrideState, StateOverride},
+    get_code_key, get_known_code_key, get_nonce_key,
+    utils::{decompose_full_nonce, nonces_to_full_nonce, storage_key_for_eth_balance},
+    AccountTreeId, StorageKey, StorageValue, H256,
+};
+use zksync_utils::{
+    bytecode::{hash_bytecode, validate_bytecode, InvalidBytecodeError},
+    h256_to_u256, u256_to_h256,
+};
+
+/// Checks that a state override can be applied, e.g. that all overridden bytecodes are valid.
+pub(crate) fn validate_state_override(state_override: &StateOverride) -> Result<(), String> {
+    for (address, account) in state_override.iter() {
+        if let Some(code) = &account.code {
+            validate_bytecode(&code.0).map_err(|err: InvalidBytecodeError| {
+                format!("invalid bytecode for account {address:?}: {err}")
+            })?;
+        }
+    }
+    Ok(())
+}
+
+/// [`ReadStorage`] wrapper applying a [`StateOverride`] on top of the underlying storage.
+#[derive(Debug)]
+pub(super) struct StorageWithOverrides<S> {
+    storage_handle: S,
+    overridden_slots: HashMap<StorageKey, H256>,
+    overridden_factory_deps: HashMap<H256, Vec<u8>>,
+    /// Accounts with fully replaced storage; all non-overridden slots of these accounts read as zero.
+    overridden_accounts: HashSet<AccountTreeId>,
+}
+
+impl<S: ReadStorage> StorageWithOverrides<S> {
+    /// Creates a storage without overrides.
+    pub fn new(storage_handle: S) -> Self {
+        Self {
+            storage_handle,
+            overridden_slots: HashMap::new(),
+            overridden_factory_deps: HashMap::new(),
+            overridden_accounts: HashSet::new(),
+        }
+    }
+
+    /// Applies the provided state override. The override is expected to be validated
+    /// using [`validate_state_override()`].
+    pub fn with_state_override(mut self, state_override: &StateOverride) -> Self {
+        for (address, account) in state_override.iter() {
+            if let Some(balance) = account.balance {
+                let balance_key = storage_key_for_eth_balance(address);
+                self.overridden_slots
+                    .insert(balance_key, u256_to_h256(balance));
+            }
+
+            if let Some(nonce) = account.nonce {
+                let nonce_key = get_nonce_key(address);
+                let full_nonce = self.read_value(&nonce_key);
+                let (_, deployment_nonce) = decompose_full_nonce(h256_to_u256(full_nonce));
+                let new_full_nonce = nonces_to_full_nonce(nonce, deployment_nonce);
+                self.overridden_slots
+                    .insert(nonce_key, u256_to_h256(new_full_nonce));
+            }
+
+            if let Some(code) = &account.code {
+                let code_hash = hash_bytecode(&code.0);
+                self.overridden_slots
+                    .insert(get_code_key(address), code_hash);
+                self.overridden_slots
+                    .insert(get_known_code_key(&code_hash), H256::from_low_u64_be(1));
+                self.overridden_factory_deps
+                    .insert(code_hash, code.0.clone());
+            }
+
+            let account_id = AccountTreeId::new(*address);
+            match &account.state {
+                Some(OverrideState::State(state)) => {
+                    self.overridden_accounts.insert(account_id);
+                    for (&slot, &value) in state {
+                        let key = StorageKey::new(account_id, slot);
+                        self.overridden_slots.insert(key, value);
+                    }
+                }
+                Some(OverrideState::StateDiff(state_diff)) => {
+                    for (&slot, &value) in state_diff {
+                        let key = StorageKey::new(account_id, slot);
+                        self.overridden_slots.insert(key, value);
+                    }
+                }
+                None => { /* do nothing */ }
+            }
+        }
+        self
+    }
+}
+
+impl<S: ReadStorage> ReadStorage for StorageWithOverrides<S> {
+    fn read_value(&mut self, key: &StorageKey) -> StorageValue {
+        if let Some(value) = self.overridden_slots.get(key) {
+            return *value;
+        }
+        if self.overridden_accounts.contains(key.account()) {
+            return H256::zero();
+        }
+        self.storage_handle.read_value(key)
+    }
+
+    fn is_write_initial(&mut self, key: &StorageKey) -> bool {
+        self.storage_handle.is_write_initial(key)
+    }
+
+    fn load_factory_dep(&mut self, hash: H256) -> Option<Vec<u8>> {
+        self.overridden_factory_deps
+            .get(&hash)
+            .cloned()
+            .or_else(|| self.storage_handle.load_factory_dep(hash))
+    }
+
+    fn get_enumeration_index(&mut self, key: &StorageKey) -> Option<u64> {
+        self.storage_handle.get_enumeration_index(key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zksync_state::InMemoryStorage;
+    use zksync_types::{api::state_override::OverrideAccount, web3::Bytes, Address, U256};
+
+    use super::*;
+
+    #[test]
+    fn overriding_state() {
+        let address = Address::repeat_byte(1);
+        let slot_key = StorageKey::new(AccountTreeId::new(address), H256::repeat_byte(2));
+        let other_slot_key = StorageKey::new(AccountTreeId::new(address), H256::repeat_byte(3));
+        let nonce_key = get_nonce_key(&address);
+
+        let mut storage = InMemoryStorage::default();
+        storage.set_value(slot_key, H256::repeat_byte(0xaa));
+        storage.set_value(other_slot_key, H256::repeat_byte(0xbb));
+        let full_nonce = nonces_to_full_nonce(5.into(), 3.into());
+        storage.set_value(nonce_key, u256_to_h256(full_nonce));
+
+        let code = vec![1_u8; 32];
+        let state_override = StateOverride::new(HashMap::from([(
+            address,
+            OverrideAccount {
+                balance: Some(U256::from(123)),
+                nonce: Some(U256::from(10)),
+                code: Some(Bytes(code.clone())),
+                state: Some(OverrideState::StateDiff(HashMap::from([(
+                    H256::repeat_byte(2),
+                    H256::repeat_byte(0xcc),
+                )]))),
+            },
+        )]));
+        validate_state_override(&state_override).unwrap();
+
+        let mut storage = StorageWithOverrides::new(storage).with_state_override(&state_override);
+        assert_eq!(storage.read_value(&slot_key), H256::repeat_byte(0xcc));
+        assert_eq!(storage.read_value(&other_slot_key), H256::repeat_byte(0xbb));
+        let balance = storage.read_value(&storage_key_for_eth_balance(&address));
+        assert_eq!(h256_to_u256(balance), U256::from(123));
+        let (nonce, deployment_nonce) =
+            decompose_full_nonce(h256_to_u256(storage.read_value(&nonce_key)));
+        assert_eq!((nonce, deployment_nonce), (10.into(), 3.into()));
+
+        let code_hash = storage.read_value(&get_code_key(&address));
+        assert_eq!(code_hash, hash_bytecode(&code));
+        assert!(storage.is_bytecode_known(&code_hash));
+        assert_eq!(storage.load_factory_dep(code_hash).unwrap(), code);
+    }
+
+    #[test]
+    fn replacing_account_state() {
+        let address = Address::repeat_byte(1);
+        let slot_key = StorageKey::new(AccountTreeId::new(address), H256::repeat_byte(2));
+        let other_slot_key = StorageKey::new(AccountTreeId::new(address), H256::repeat_byte(3));
+        let mut storage = InMemoryStorage::default();
+        storage.set_value(slot_key, H256::repeat_byte(0xaa));
+        storage.set_value(other_slot_key, H256::repeat_byte(0xbb));
+
+        let state_override = StateOverride::new(HashMap::from([(
+            address,
+            OverrideAccount {
+                state: Some(OverrideState::State(HashMap::from([(
+                    H256::repeat_byte(2),
+                    H256::repeat_byte(0xcc),
+                )]))),
+                ..OverrideAccount::default()
+            },
+        )]));
+
+        let mut storage = StorageWithOverrides::new(storage).with_state_override(&state_override);
+        assert_eq!(storage.read_value(&slot_key), H256::repeat_byte(0xcc));
+        assert_eq!(storage.read_value(&other_slot_key), H256::zero());
+    }
+
+    #[test]
+    fn invalid_bytecode_is_rejected() {
+        let state_override = StateOverride::new(HashMap::from([(
+            Address::repeat_byte(1),
+            OverrideAccount {
+                code: Some(Bytes(vec![1_u8; 64])),
+                ..OverrideAccount::default()
+            },
+        )]));
+        let err = validate_state_override(&state_override).unwrap_err();
+        assert!(err.contains("even number"), "{err}");
+    }
+}
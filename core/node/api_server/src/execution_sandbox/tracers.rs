@@ -1,14 +1,20 @@
 use std::sync::Arc;
 
-use multivm::{tracers::CallTracer, vm_latest::HistoryMode, MultiVMTracer, MultiVmTracerPointer};
+use multivm::{
+    tracers::{CallTracer, OpcodeTracer},
+    vm_latest::HistoryMode,
+    MultiVMTracer, MultiVmTracerPointer,
+};
 use once_cell::sync::OnceCell;
 use zksync_state::WriteStorage;
-use zksync_types::vm_trace::Call;
+use zksync_types::vm_trace::{Call, OpcodeTrace};
 
 /// Custom tracers supported by our API
 #[derive(Debug)]
 pub(crate) enum ApiTracer {
     CallTracer(Arc<OnceCell<Vec<Call>>>),
+    /// Records executed opcodes, up to the specified number of steps.
+    OpcodeTracer(usize, Arc<OnceCell<OpcodeTrace>>),
 }
 
 impl ApiTracer {
@@ -20,6 +26,9 @@ impl ApiTracer {
     ) -> MultiVmTracerPointer<S, H> {
         match self {
             ApiTracer::CallTracer(tracer) => CallTracer::new(tracer.clone()).into_tracer_pointer(),
+            ApiTracer::OpcodeTracer(max_steps, tracer) => {
+                OpcodeTracer::new(max_steps, tracer).into_tracer_pointer()
+            }
         }
     }
 }
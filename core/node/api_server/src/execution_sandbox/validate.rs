@@ -72,6 +72,7 @@ impl TransactionExecutor {
                 &connection_pool,
                 tx,
                 block_args,
+                None,
                 |vm, tx, protocol_version| {
                     let stage_latency = SANDBOX_METRICS.sandbox[&SandboxStage::Validation].start();
                     let span = tracing::debug_span!("validation").entered();
@@ -4,7 +4,8 @@ use axum::{extract::State, http::StatusCode, routing::get, Json, Router};
 use tokio::sync::watch;
 use zksync_health_check::{AppHealth, AppHealthCheck};
 
-async fn check_health(
+/// Readiness check: responds with 200 OK iff all components are healthy, i.e., the app can receive traffic.
+async fn check_readiness(
     app_health_check: State<Arc<AppHealthCheck>>,
 ) -> (StatusCode, Json<AppHealth>) {
     let response = app_health_check.check_health().await;
@@ -16,6 +17,20 @@ async fn check_health(
     (response_code, Json(response))
 }
 
+/// Liveness check: responds with 200 OK unless some components require the app to be restarted (e.g., have panicked).
+/// Unlike readiness, liveness is not affected by components that are still initializing.
+async fn check_liveness(
+    app_health_check: State<Arc<AppHealthCheck>>,
+) -> (StatusCode, Json<AppHealth>) {
+    let response = app_health_check.check_health().await;
+    let response_code = if response.is_live() {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    (response_code, Json(response))
+}
+
 async fn run_server(
     bind_address: &SocketAddr,
     app_health_check: Arc<AppHealthCheck>,
@@ -26,7 +41,10 @@ async fn run_server(
     );
 
     let app = Router::new()
-        .route("/health", get(check_health))
+        // `/health` is retained for backward compatibility; it's equivalent to the readiness check.
+        .route("/health", get(check_readiness))
+        .route("/health/ready", get(check_readiness))
+        .route("/health/live", get(check_liveness))
         .with_state(app_health_check);
 
     axum::Server::bind(bind_address)
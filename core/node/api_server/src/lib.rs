@@ -2,6 +2,7 @@
 
 #[macro_use]
 mod utils;
+pub mod admin;
 pub mod execution_sandbox;
 pub mod healthcheck;
 pub mod tx_sender;
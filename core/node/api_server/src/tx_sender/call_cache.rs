@@ -0,0 +1,188 @@
+//! Cache for `eth_call` results on historical blocks.
+
+use std::{
+    num::NonZeroUsize,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use lru::LruCache;
+use vise::{Counter, EncodeLabelSet, EncodeLabelValue, Family, Gauge, Metrics, Unit};
+use zksync_types::{
+    l2::L2Tx, transaction_request::CallOverrides, web3::keccak256, L2BlockNumber, H256,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EncodeLabelValue, EncodeLabelSet)]
+#[metrics(label = "result", rename_all = "snake_case")]
+enum CacheLookupResult {
+    Hit,
+    Miss,
+    Expired,
+}
+
+#[derive(Debug, Metrics)]
+#[metrics(prefix = "api_eth_call_cache")]
+struct EthCallCacheMetrics {
+    /// Number of cache lookups grouped by the result. The hit rate can be computed as the ratio
+    /// of `hit` lookups to all lookups.
+    lookups: Family<CacheLookupResult, Counter>,
+    /// Current number of entries in the cache.
+    entries: Gauge<usize>,
+    /// Configured TTL of cache entries.
+    #[metrics(unit = Unit::Seconds)]
+    ttl: Gauge<Duration>,
+}
+
+#[vise::register]
+static METRICS: vise::Global<EthCallCacheMetrics> = vise::Global::new();
+
+/// Key of an [`EthCallCache`] entry: the resolved block number and the hash of the call payload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct EthCallCacheKey {
+    block_number: L2BlockNumber,
+    payload_hash: H256,
+}
+
+impl EthCallCacheKey {
+    pub fn new(block_number: L2BlockNumber, call_overrides: &CallOverrides, tx: &L2Tx) -> Self {
+        // The received timestamp is set to the current time when a call request is converted to a transaction,
+        // and it doesn't influence execution.
+        let mut tx = tx.clone();
+        tx.received_timestamp_ms = 0;
+        let payload = (
+            tx,
+            call_overrides.enforced_base_fee,
+            call_overrides.protocol_version,
+        );
+        let payload = serde_json::to_vec(&payload).expect("failed serializing call payload");
+        Self {
+            block_number,
+            payload_hash: H256(keccak256(&payload)),
+        }
+    }
+}
+
+/// LRU cache for the results of `eth_call` executed on historical blocks. Such calls are idempotent
+/// (the VM state and the fee input are fully determined by the block), so their results can be reused.
+/// Entries are additionally invalidated after a configurable TTL, so that results don't outlive
+/// a potential block rollback for too long.
+#[derive(Debug)]
+pub(crate) struct EthCallCache {
+    entries: Mutex<LruCache<EthCallCacheKey, (Instant, Vec<u8>)>>,
+    ttl: Duration,
+}
+
+impl EthCallCache {
+    pub fn new(capacity: NonZeroUsize, ttl: Duration) -> Self {
+        METRICS.ttl.set(ttl);
+        Self {
+            entries: Mutex::new(LruCache::new(capacity)),
+            ttl,
+        }
+    }
+
+    pub fn get(&self, key: &EthCallCacheKey) -> Option<Vec<u8>> {
+        let mut entries = self.entries.lock().expect("eth_call cache is poisoned");
+        let cached_output = entries.get(key).map(|(inserted_at, output)| {
+            (inserted_at.elapsed() < self.ttl).then(|| output.clone())
+        });
+        let (lookup_result, output) = match cached_output {
+            Some(Some(output)) => (CacheLookupResult::Hit, Some(output)),
+            Some(None) => {
+                entries.pop(key);
+                (CacheLookupResult::Expired, None)
+            }
+            None => (CacheLookupResult::Miss, None),
+        };
+        METRICS.lookups[&lookup_result].inc();
+        METRICS.entries.set(entries.len());
+        output
+    }
+
+    pub fn insert(&self, key: EthCallCacheKey, output: Vec<u8>) {
+        let mut entries = self.entries.lock().expect("eth_call cache is poisoned");
+        entries.put(key, (Instant::now(), output));
+        METRICS.entries.set(entries.len());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zksync_types::{fee::Fee, Address, Nonce, U256};
+
+    use super::*;
+
+    fn mock_call(calldata: Vec<u8>) -> L2Tx {
+        L2Tx::new(
+            Address::repeat_byte(1),
+            calldata,
+            Nonce(0),
+            Fee {
+                gas_limit: 1_000_000.into(),
+                max_fee_per_gas: 100_000_000.into(),
+                max_priority_fee_per_gas: U256::zero(),
+                gas_per_pubdata_limit: 50_000.into(),
+            },
+            Address::repeat_byte(2),
+            U256::zero(),
+            None,
+            Default::default(),
+        )
+    }
+
+    #[test]
+    fn cache_key_ignores_received_timestamp() {
+        let overrides = CallOverrides {
+            enforced_base_fee: None,
+            protocol_version: None,
+        };
+        let tx = mock_call(vec![1, 2, 3]);
+        let mut other_tx = tx.clone();
+        other_tx.received_timestamp_ms += 1_000;
+        let key = EthCallCacheKey::new(L2BlockNumber(1), &overrides, &tx);
+        assert_eq!(
+            key,
+            EthCallCacheKey::new(L2BlockNumber(1), &overrides, &other_tx)
+        );
+
+        assert_ne!(key, EthCallCacheKey::new(L2BlockNumber(2), &overrides, &tx));
+        let other_tx = mock_call(vec![1, 2, 4]);
+        assert_ne!(
+            key,
+            EthCallCacheKey::new(L2BlockNumber(1), &overrides, &other_tx)
+        );
+        let other_overrides = CallOverrides {
+            enforced_base_fee: Some(1),
+            protocol_version: None,
+        };
+        assert_ne!(
+            key,
+            EthCallCacheKey::new(L2BlockNumber(1), &other_overrides, &tx)
+        );
+    }
+
+    #[test]
+    fn cache_evicts_expired_and_least_recently_used_entries() {
+        let overrides = CallOverrides {
+            enforced_base_fee: None,
+            protocol_version: None,
+        };
+        let keys: Vec<_> = (0..3)
+            .map(|i| EthCallCacheKey::new(L2BlockNumber(i), &overrides, &mock_call(vec![])))
+            .collect();
+
+        let cache = EthCallCache::new(NonZeroUsize::new(2).unwrap(), Duration::from_secs(60));
+        cache.insert(keys[0], vec![0]);
+        cache.insert(keys[1], vec![1]);
+        assert_eq!(cache.get(&keys[0]), Some(vec![0]));
+        cache.insert(keys[2], vec![2]);
+        // `keys[1]` is the least recently used entry.
+        assert_eq!(cache.get(&keys[1]), None);
+        assert_eq!(cache.get(&keys[0]), Some(vec![0]));
+        assert_eq!(cache.get(&keys[2]), Some(vec![2]));
+
+        let cache = EthCallCache::new(NonZeroUsize::new(2).unwrap(), Duration::ZERO);
+        cache.insert(keys[0], vec![0]);
+        assert_eq!(cache.get(&keys[0]), None);
+    }
+}
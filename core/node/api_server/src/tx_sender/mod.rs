@@ -1,6 +1,11 @@
 //! Helper module to submit transactions into the zkSync Network.
 
-use std::{sync::Arc, time::Instant};
+use std::{
+    collections::HashSet,
+    num::NonZeroUsize,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 use anyhow::Context as _;
 use multivm::{
@@ -24,20 +29,26 @@ use zksync_state_keeper::{
     SequencerSealer,
 };
 use zksync_types::{
+    api::state_override::StateOverride,
     fee::{Fee, TransactionExecutionMetrics},
     fee_model::BatchFeeInput,
     get_code_key, get_intrinsic_constants,
     l2::{error::TxCheckError::TxDuplication, L2Tx},
     transaction_request::CallOverrides,
     utils::storage_key_for_eth_balance,
-    AccountTreeId, Address, ExecuteTransactionCommon, L2ChainId, Nonce, PackedEthSignature,
-    ProtocolVersionId, Transaction, VmVersion, H160, H256, MAX_L2_TX_GAS_LIMIT,
-    MAX_NEW_FACTORY_DEPS, U256,
+    web3::keccak256,
+    AccountTreeId, Address, Execute, ExecuteTransactionCommon, L2ChainId, Nonce,
+    PackedEthSignature, ProtocolVersionId, Transaction, VmVersion, CONTRACT_DEPLOYER_ADDRESS, H160,
+    H256, MAX_L2_TX_GAS_LIMIT, MAX_NEW_FACTORY_DEPS, U256,
 };
 use zksync_utils::h256_to_u256;
 
 pub(super) use self::result::SubmitTxError;
-use self::{master_pool_sink::MasterPoolSink, tx_sink::TxSink};
+use self::{
+    call_cache::{EthCallCache, EthCallCacheKey},
+    master_pool_sink::MasterPoolSink,
+    tx_sink::TxSink,
+};
 use crate::{
     execution_sandbox::{
         BlockArgs, SubmitTxStage, TransactionExecutor, TxExecutionArgs, TxSharedArgs,
@@ -46,12 +57,14 @@ use crate::{
     tx_sender::result::ApiCallResult,
 };
 
+mod call_cache;
 pub mod master_pool_sink;
 pub mod proxy;
 mod result;
 #[cfg(test)]
 pub(crate) mod tests;
 pub mod tx_sink;
+pub mod whitelisted_tokens_for_aa;
 
 pub async fn build_tx_sender(
     tx_sender_config: &TxSenderConfig,
@@ -86,6 +99,20 @@ pub async fn build_tx_sender(
     Ok((tx_sender, vm_barrier))
 }
 
+/// Signatures of `ContractDeployer` methods deploying EVM bytecode via the EVM emulator.
+const EVM_DEPLOYMENT_SIGNATURES: [&str; 2] = ["createEVM(bytes)", "create2EVM(bytes32,bytes)"];
+
+/// Checks whether the transaction deploys EVM bytecode, which requires the EVM emulator.
+pub(crate) fn is_evm_deployment(execute: &Execute) -> bool {
+    if execute.contract_address != CONTRACT_DEPLOYER_ADDRESS || execute.calldata.len() < 4 {
+        return false;
+    }
+    let selector = &execute.calldata[..4];
+    EVM_DEPLOYMENT_SIGNATURES
+        .iter()
+        .any(|signature| keccak256(signature.as_bytes())[..4] == *selector)
+}
+
 #[derive(Debug, Clone)]
 pub struct MultiVMBaseSystemContracts {
     /// Contracts to be used for pre-virtual-blocks protocol versions.
@@ -252,6 +279,9 @@ impl TxSenderBuilder {
                 Arc::new(RwLock::new(self.config.whitelisted_tokens_for_aa.clone()))
             });
 
+        let eth_call_cache = NonZeroUsize::new(self.config.eth_call_cache_size)
+            .map(|capacity| EthCallCache::new(capacity, self.config.eth_call_cache_ttl));
+
         TxSender(Arc::new(TxSenderInner {
             sender_config: self.config,
             tx_sink: self.tx_sink,
@@ -263,6 +293,7 @@ impl TxSenderBuilder {
             whitelisted_tokens_for_aa_cache,
             sealer,
             executor: TransactionExecutor::Real,
+            eth_call_cache,
         }))
     }
 }
@@ -281,6 +312,14 @@ pub struct TxSenderConfig {
     pub validation_computational_gas_limit: u32,
     pub chain_id: L2ChainId,
     pub whitelisted_tokens_for_aa: Vec<Address>,
+    pub evm_emulator_enabled: bool,
+    pub max_pending_txs_per_initiator: Option<u32>,
+    pub min_max_fee_per_gas: Option<u64>,
+    pub denied_initiators: HashSet<Address>,
+    /// Max number of cached `eth_call` results on historical blocks. If set to 0, results are not cached.
+    pub eth_call_cache_size: usize,
+    /// Time-to-live of cached `eth_call` results.
+    pub eth_call_cache_ttl: Duration,
 }
 
 impl TxSenderConfig {
@@ -300,6 +339,12 @@ impl TxSenderConfig {
                 .validation_computational_gas_limit,
             chain_id,
             whitelisted_tokens_for_aa: web3_json_config.whitelisted_tokens_for_aa.clone(),
+            evm_emulator_enabled: web3_json_config.evm_emulator_enabled,
+            max_pending_txs_per_initiator: web3_json_config.max_pending_txs_per_initiator,
+            min_max_fee_per_gas: web3_json_config.min_max_fee_per_gas,
+            denied_initiators: web3_json_config.denied_initiators.iter().copied().collect(),
+            eth_call_cache_size: web3_json_config.eth_call_cache_size.unwrap_or(0),
+            eth_call_cache_ttl: web3_json_config.eth_call_cache_ttl(),
         }
     }
 }
@@ -321,6 +366,8 @@ pub struct TxSenderInner {
     /// Batch sealer used to check whether transaction can be executed by the sequencer.
     sealer: Arc<dyn ConditionalSealer>,
     pub(super) executor: TransactionExecutor,
+    /// Cache for `eth_call` results on historical blocks; `None` if caching is disabled.
+    eth_call_cache: Option<EthCallCache>,
 }
 
 #[derive(Clone)]
@@ -486,6 +533,20 @@ impl TxSender {
         tx: &L2Tx,
         protocol_version: ProtocolVersionId,
     ) -> Result<(), SubmitTxError> {
+        if self
+            .0
+            .sender_config
+            .denied_initiators
+            .contains(&tx.initiator_account())
+        {
+            tracing::info!(
+                "Submitted Tx {:?} is rejected because its initiator {:?} is denied",
+                tx.hash(),
+                tx.initiator_account()
+            );
+            return Err(SubmitTxError::InitiatorDenied);
+        }
+
         // This check is intended to ensure that the gas-related values will be safe to convert to u64 in the future computations.
         let max_gas = U256::from(u64::MAX);
         if tx.common_data.fee.gas_limit > max_gas
@@ -523,6 +584,16 @@ impl TxSender {
             );
             return Err(SubmitTxError::MaxFeePerGasTooLow);
         }
+        if let Some(min_max_fee_per_gas) = self.0.sender_config.min_max_fee_per_gas {
+            if tx.common_data.fee.max_fee_per_gas < min_max_fee_per_gas.into() {
+                tracing::info!(
+                    "Submitted Tx is Unexecutable {:?} because of MaxFeePerGasBelowMinimum {}",
+                    tx.hash(),
+                    tx.common_data.fee.max_fee_per_gas
+                );
+                return Err(SubmitTxError::MaxFeePerGasBelowMinimum(min_max_fee_per_gas));
+            }
+        }
         if tx.common_data.fee.max_fee_per_gas < tx.common_data.fee.max_priority_fee_per_gas {
             tracing::info!(
                 "Submitted Tx is Unexecutable {:?} because of MaxPriorityFeeGreaterThanMaxFee {}",
@@ -537,6 +608,13 @@ impl TxSender {
                 MAX_NEW_FACTORY_DEPS,
             ));
         }
+        if !self.0.sender_config.evm_emulator_enabled && is_evm_deployment(&tx.execute) {
+            tracing::info!(
+                "Submitted Tx is Unexecutable {:?} because of EvmDeploymentsDisabled",
+                tx.hash()
+            );
+            return Err(SubmitTxError::EvmDeploymentsDisabled);
+        }
 
         let intrinsic_consts = get_intrinsic_constants();
         assert!(
@@ -551,6 +629,7 @@ impl TxSender {
         // We still double-check the nonce manually
         // to make sure that only the correct nonce is submitted and the transaction's hashes never repeat
         self.validate_account_nonce(tx).await?;
+        self.validate_pending_txs_count(tx).await?;
         // Even though without enough balance the tx will not pass anyway
         // we check the user for enough balance explicitly here for better DevEx.
         self.validate_enough_balance(tx).await?;
@@ -588,6 +667,30 @@ impl TxSender {
         }
     }
 
+    async fn validate_pending_txs_count(&self, tx: &L2Tx) -> Result<(), SubmitTxError> {
+        let Some(max_pending_txs) = self.0.sender_config.max_pending_txs_per_initiator else {
+            return Ok(());
+        };
+        let initiator_account = tx.initiator_account();
+        let pending_txs_count = self
+            .acquire_replica_connection()
+            .await?
+            .transactions_dal()
+            .get_pending_txs_count_for_initiator(initiator_account)
+            .await
+            .with_context(|| {
+                format!("failed getting pending transactions count for {initiator_account:?}")
+            })?;
+        if pending_txs_count >= max_pending_txs as usize {
+            tracing::info!(
+                "Submitted Tx {:?} is rejected because its initiator {initiator_account:?} has {pending_txs_count} pending txs",
+                tx.hash()
+            );
+            return Err(SubmitTxError::TooManyPendingTransactions(max_pending_txs));
+        }
+        Ok(())
+    }
+
     async fn get_expected_nonce(&self, initiator_account: Address) -> anyhow::Result<Nonce> {
         let mut storage = self.acquire_replica_connection().await?;
         let latest_block_number = storage
@@ -973,12 +1076,54 @@ impl TxSender {
         block_args: BlockArgs,
         call_overrides: CallOverrides,
         tx: L2Tx,
+        state_override: Option<StateOverride>,
     ) -> Result<Vec<u8>, SubmitTxError> {
+        // Calls on the pending or latest blocks depend on the current fee input and state, so they are never cached.
+        // Calls with state overrides are not cached either since the override is not a part of the cache key.
+        let cache_key = self
+            .0
+            .eth_call_cache
+            .as_ref()
+            .filter(|_| {
+                !block_args.resolves_to_latest_sealed_l2_block() && state_override.is_none()
+            })
+            .map(|cache| {
+                let key =
+                    EthCallCacheKey::new(block_args.resolved_block_number(), &call_overrides, &tx);
+                (cache, key)
+            });
+        if let Some((cache, key)) = &cache_key {
+            if let Some(output) = cache.get(key) {
+                return Ok(output);
+            }
+        }
+
+        let output = self
+            .simulate_call(block_args, call_overrides, tx, state_override)
+            .await?
+            .into_api_call_result()?;
+
+        if let Some((cache, key)) = cache_key {
+            cache.insert(key, output.clone());
+        }
+        Ok(output)
+    }
+
+    /// Executes a call in the sandbox without persisting anything and returns the full VM output, including
+    /// the execution result, gas stats and emitted events. The state override (if any) must be validated beforehand.
+    pub(super) async fn simulate_call(
+        &self,
+        block_args: BlockArgs,
+        call_overrides: CallOverrides,
+        tx: L2Tx,
+        state_override: Option<StateOverride>,
+    ) -> Result<VmExecutionResultAndLogs, SubmitTxError> {
         let vm_permit = self.0.vm_concurrency_limiter.acquire().await;
         let vm_permit = vm_permit.ok_or(SubmitTxError::ServerShuttingDown)?;
 
         let vm_execution_cache_misses_limit = self.0.sender_config.vm_execution_cache_misses_limit;
-        self.0
+        Ok(self
+            .0
             .executor
             .execute_tx_eth_call(
                 vm_permit,
@@ -989,9 +1134,9 @@ impl TxSender {
                 block_args,
                 vm_execution_cache_misses_limit,
                 vec![],
+                state_override,
             )
-            .await?
-            .into_api_call_result()
+            .await?)
     }
 
     pub async fn gas_price(&self) -> anyhow::Result<u64> {
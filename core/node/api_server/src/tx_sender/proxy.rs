@@ -7,13 +7,18 @@ use std::{
 
 use anyhow::Context;
 use chrono::{TimeZone, Utc};
+use serde::Serialize;
 use tokio::sync::{watch, RwLock};
+use vise::{Counter, EncodeLabelSet, EncodeLabelValue, Family, Gauge, Metrics};
 use zksync_dal::{
     helpers::wait_for_l1_batch, transactions_dal::L2TxSubmissionResult, Connection, ConnectionPool,
     Core, CoreDal, DalError,
 };
+use zksync_health_check::{Health, HealthStatus, HealthUpdater, ReactiveHealthCheck};
 use zksync_shared_metrics::{TxStage, APP_METRICS};
-use zksync_types::{api, fee::TransactionExecutionMetrics, l2::L2Tx, Address, Nonce, H256, U256};
+use zksync_types::{
+    api, fee::TransactionExecutionMetrics, l2::L2Tx, web3::Bytes, Address, Nonce, H256, U256,
+};
 use zksync_web3_decl::{
     client::{DynClient, L2},
     error::{ClientRpcContext, EnrichedClientResult, Web3Error},
@@ -205,12 +210,231 @@ impl TxCache {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EncodeLabelValue, EncodeLabelSet)]
+#[metrics(label = "reason", rename_all = "snake_case")]
+enum DroppedTxReason {
+    /// Transaction is included into a block or replaced.
+    Included,
+    /// Transaction was rejected by the main node.
+    Rejected,
+    Expired,
+}
+
+#[derive(Debug, Metrics)]
+#[metrics(prefix = "api_tx_proxy")]
+struct TxProxyMetrics {
+    /// Number of transactions in the persistent resubmission queue.
+    queue_depth: Gauge<usize>,
+    /// Number of transactions kept in the queue after the main node was unreachable on submission.
+    deferred_submissions: Counter,
+    /// Number of transaction resubmissions to the main node.
+    resubmissions: Counter,
+    /// Number of transactions dropped from the queue.
+    dropped_txs: Family<DroppedTxReason, Counter>,
+}
+
+#[vise::register]
+static TX_PROXY_METRICS: vise::Global<TxProxyMetrics> = vise::Global::new();
+
+/// Configuration of the persistent resubmission queue of [`TxProxy`].
+#[derive(Debug, Clone)]
+pub struct TxProxyQueueConfig {
+    /// Delay before the first resubmission of a transaction. The delay is doubled after each attempt.
+    pub resubmission_interval: Duration,
+    /// Upper bound for the delay between resubmission attempts.
+    pub max_resubmission_interval: Duration,
+    /// Time after which a transaction not included into a block is dropped from the queue.
+    pub tx_expiration: Duration,
+    /// Interval between polling the queue for transactions due for resubmission.
+    pub poll_interval: Duration,
+}
+
+impl Default for TxProxyQueueConfig {
+    fn default() -> Self {
+        Self {
+            resubmission_interval: Duration::from_secs(10),
+            max_resubmission_interval: Duration::from_secs(300),
+            tx_expiration: Duration::from_secs(3_600),
+            poll_interval: Duration::from_secs(1),
+        }
+    }
+}
+
+impl TxProxyQueueConfig {
+    fn resubmission_delay(&self, attempts: u32) -> Duration {
+        let multiplier = 1_u32 << attempts.min(16);
+        self.resubmission_interval
+            .saturating_mul(multiplier)
+            .min(self.max_resubmission_interval)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ResubmissionQueue {
+    pool: ConnectionPool<Core>,
+    config: TxProxyQueueConfig,
+}
+
+impl ResubmissionQueue {
+    async fn push(&self, tx: &L2Tx) -> anyhow::Result<()> {
+        let raw_tx = tx.common_data.input_data().context("raw tx is absent")?;
+        let mut storage = self.pool.connection_tagged("api").await?;
+        storage
+            .proxied_transactions_dal()
+            .insert_transaction(
+                tx.hash(),
+                tx.initiator_account(),
+                tx.nonce(),
+                raw_tx,
+                self.config.resubmission_interval,
+                self.config.tx_expiration,
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn remove(&self, tx_hash: H256) -> anyhow::Result<()> {
+        let mut storage = self.pool.connection_tagged("api").await?;
+        storage
+            .proxied_transactions_dal()
+            .remove_transaction(tx_hash)
+            .await?;
+        Ok(())
+    }
+}
+
+/// Health details reported by [`TxResubmissionTask`].
+#[derive(Debug, Serialize)]
+struct TxProxyQueueHealth {
+    queue_depth: usize,
+}
+
+/// Task resubmitting transactions persisted in the [`TxProxy`] queue to the main node with exponential backoff
+/// until they are included into a block (as observed by the local node), are rejected by the main node, or expire.
+#[derive(Debug)]
+pub struct TxResubmissionTask {
+    tx_cache: TxCache,
+    client: Box<DynClient<L2>>,
+    queue: ResubmissionQueue,
+    health_updater: HealthUpdater,
+}
+
+impl TxResubmissionTask {
+    /// Max number of transactions resubmitted in a single iteration.
+    const BATCH_SIZE: usize = 100;
+
+    /// Returns the health check for this task.
+    pub fn health_check(&self) -> ReactiveHealthCheck {
+        self.health_updater.subscribe()
+    }
+
+    async fn step(&self) -> anyhow::Result<()> {
+        let mut storage = self.queue.pool.connection_tagged("api").await?;
+        let expired_count = storage
+            .proxied_transactions_dal()
+            .remove_expired_transactions()
+            .await?;
+        if expired_count > 0 {
+            tracing::info!("Dropped {expired_count} expired transactions from the proxy queue");
+            TX_PROXY_METRICS.dropped_txs[&DroppedTxReason::Expired].inc_by(expired_count as u64);
+        }
+
+        let due_txs = storage
+            .proxied_transactions_dal()
+            .get_due_transactions(Self::BATCH_SIZE)
+            .await?;
+        let addresses: Vec<_> = due_txs.iter().map(|tx| tx.initiator_address).collect();
+        let nonces_for_accounts = storage
+            .storage_web3_dal()
+            .get_nonces_for_addresses(&addresses)
+            .await?;
+        drop(storage); // Don't hold a DB connection while communicating with the main node.
+
+        for tx in due_txs {
+            let tx_hash = tx.tx_hash;
+            let stored_nonce = nonces_for_accounts
+                .get(&tx.initiator_address)
+                .copied()
+                .unwrap_or(Nonce(0));
+            if tx.nonce < stored_nonce {
+                tracing::debug!("Transaction {tx_hash:?} is included or replaced; removing it from the proxy queue");
+                self.queue.remove(tx_hash).await?;
+                TX_PROXY_METRICS.dropped_txs[&DroppedTxReason::Included].inc();
+                continue;
+            }
+
+            tracing::debug!(
+                "Resubmitting tx {tx_hash:?} to the main node (attempt #{})",
+                tx.attempts + 1
+            );
+            TX_PROXY_METRICS.resubmissions.inc();
+            let result = self
+                .client
+                .send_raw_transaction(Bytes(tx.raw_tx))
+                .rpc_context("send_raw_transaction")
+                .with_arg("tx_hash", &tx_hash)
+                .await;
+            match result {
+                Err(err) if !err.is_transient() => {
+                    tracing::info!(
+                        "Main node rejected resubmitted tx {tx_hash:?}: {err}; removing it from the proxy queue"
+                    );
+                    self.queue.remove(tx_hash).await?;
+                    self.tx_cache.remove(tx_hash).await;
+                    TX_PROXY_METRICS.dropped_txs[&DroppedTxReason::Rejected].inc();
+                }
+                result => {
+                    if let Err(err) = result {
+                        tracing::warn!(
+                            "Failed resubmitting tx {tx_hash:?} to the main node: {err}"
+                        );
+                    }
+                    let delay = self.queue.config.resubmission_delay(tx.attempts + 1);
+                    let mut storage = self.queue.pool.connection_tagged("api").await?;
+                    storage
+                        .proxied_transactions_dal()
+                        .reschedule_transaction(tx_hash, delay)
+                        .await?;
+                }
+            }
+        }
+
+        let mut storage = self.queue.pool.connection_tagged("api").await?;
+        let queue_depth = storage.proxied_transactions_dal().get_queue_size().await?;
+        TX_PROXY_METRICS.queue_depth.set(queue_depth);
+        self.health_updater.update(
+            Health::from(HealthStatus::Ready).with_details(TxProxyQueueHealth { queue_depth }),
+        );
+        Ok(())
+    }
+
+    /// Runs this task until a stop signal is received.
+    pub async fn run(self, mut stop_receiver: watch::Receiver<bool>) -> anyhow::Result<()> {
+        while !*stop_receiver.borrow_and_update() {
+            self.step().await?;
+            if tokio::time::timeout(self.queue.config.poll_interval, stop_receiver.changed())
+                .await
+                .is_ok()
+            {
+                break;
+            }
+        }
+        tracing::info!("Stop signal received, tx proxy resubmission task is shutting down");
+        Ok(())
+    }
+}
+
 /// Used by external node to proxy transaction to the main node
-/// and store them while they're not synced back yet
+/// and store them while they're not synced back yet.
+///
+/// If a [resubmission queue](Self::with_resubmission_queue()) is configured, proxied transactions are additionally
+/// persisted in Postgres and periodically resubmitted to the main node by [`TxResubmissionTask`]. In this case,
+/// transient main node errors (e.g., the main node being unreachable) don't fail transaction submission.
 #[derive(Debug)]
 pub struct TxProxy {
     tx_cache: TxCache,
     client: Box<DynClient<L2>>,
+    queue: Option<ResubmissionQueue>,
 }
 
 impl TxProxy {
@@ -218,12 +442,38 @@ impl TxProxy {
         Self {
             tx_cache: TxCache::default(),
             client: client.for_component("tx_proxy"),
+            queue: None,
         }
     }
 
+    /// Enables the persistent resubmission queue for proxied transactions. The queue is processed
+    /// by [`TxResubmissionTask`] that can be obtained using [`Self::resubmission_task()`].
+    #[must_use]
+    pub fn with_resubmission_queue(
+        mut self,
+        pool: ConnectionPool<Core>,
+        config: TxProxyQueueConfig,
+    ) -> Self {
+        self.queue = Some(ResubmissionQueue { pool, config });
+        self
+    }
+
+    /// Returns a task processing the resubmission queue, or `None` if the queue is not enabled.
+    /// Only a single task should be run for a proxy.
+    pub fn resubmission_task(&self) -> Option<TxResubmissionTask> {
+        let queue = self.queue.clone()?;
+        let (_, health_updater) = ReactiveHealthCheck::new("tx_proxy_queue");
+        Some(TxResubmissionTask {
+            tx_cache: self.tx_cache.clone(),
+            client: self.client.clone(),
+            queue,
+            health_updater,
+        })
+    }
+
     async fn submit_tx_impl(&self, tx: &L2Tx) -> EnrichedClientResult<H256> {
         let input_data = tx.common_data.input_data().expect("raw tx is absent");
-        let raw_tx = zksync_types::web3::Bytes(input_data.to_vec());
+        let raw_tx = Bytes(input_data.to_vec());
         let tx_hash = tx.hash();
         tracing::info!("Proxying tx {tx_hash:?}");
         self.client
@@ -303,10 +553,27 @@ impl TxSink for TxProxy {
         // But before we do that, save the tx to cache in case someone will request it
         // Before it reaches the main node.
         self.tx_cache.push(tx.clone()).await;
-        if let Err(err) = self.submit_tx_impl(tx).await {
-            // Remove the transaction from the cache on failure so that it doesn't occupy space in the cache indefinitely.
-            self.tx_cache.remove(tx.hash()).await;
-            return Err(err.into());
+        if let Some(queue) = &self.queue {
+            queue.push(tx).await?;
+        }
+
+        match self.submit_tx_impl(tx).await {
+            Ok(_) => { /* the transaction is accepted by the main node */ }
+            Err(err) if err.is_transient() && self.queue.is_some() => {
+                tracing::warn!(
+                    "Failed proxying tx {:?} to the main node: {err}; it will be resubmitted later",
+                    tx.hash()
+                );
+                TX_PROXY_METRICS.deferred_submissions.inc();
+            }
+            Err(err) => {
+                // Remove the transaction from the cache on failure so that it doesn't occupy space in the cache indefinitely.
+                self.tx_cache.remove(tx.hash()).await;
+                if let Some(queue) = &self.queue {
+                    queue.remove(tx.hash()).await?;
+                }
+                return Err(err.into());
+            }
         }
         APP_METRICS.processed_txs[&TxStage::Proxied].inc();
         Ok(L2TxSubmissionResult::Proxied)
@@ -372,13 +639,17 @@ impl TxSink for TxProxy {
 
 #[cfg(test)]
 mod tests {
-    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 
     use test_casing::test_casing;
+    use zksync_health_check::CheckHealth;
     use zksync_node_genesis::{insert_genesis_batch, mock_genesis_config, GenesisParams};
     use zksync_node_test_utils::{create_l2_block, create_l2_transaction};
-    use zksync_types::{get_nonce_key, web3::Bytes, L2BlockNumber, StorageLog};
-    use zksync_web3_decl::{client::MockClient, jsonrpsee::core::ClientError};
+    use zksync_types::{get_nonce_key, L2BlockNumber, StorageLog};
+    use zksync_web3_decl::{
+        client::MockClient,
+        jsonrpsee::{core::ClientError, types::ErrorObject},
+    };
 
     use super::*;
 
@@ -522,6 +793,143 @@ mod tests {
         assert!(found_tx.is_none(), "{found_tx:?}");
     }
 
+    fn immediate_resubmission_config() -> TxProxyQueueConfig {
+        TxProxyQueueConfig {
+            resubmission_interval: Duration::ZERO,
+            ..TxProxyQueueConfig::default()
+        }
+    }
+
+    #[test]
+    fn resubmission_delays() {
+        let config = TxProxyQueueConfig::default();
+        assert_eq!(config.resubmission_delay(0), Duration::from_secs(10));
+        assert_eq!(config.resubmission_delay(1), Duration::from_secs(20));
+        assert_eq!(config.resubmission_delay(3), Duration::from_secs(80));
+        assert_eq!(config.resubmission_delay(10), Duration::from_secs(300));
+        assert_eq!(
+            config.resubmission_delay(u32::MAX),
+            Duration::from_secs(300)
+        );
+    }
+
+    #[tokio::test]
+    async fn transaction_is_resubmitted_after_transient_main_node_failure() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut storage = pool.connection().await.unwrap();
+        let params = GenesisParams::load_genesis_params(mock_genesis_config()).unwrap();
+        insert_genesis_batch(&mut storage, &params).await.unwrap();
+
+        let tx = create_l2_transaction(10, 100);
+        let send_tx_calls = Arc::new(AtomicUsize::new(0));
+        let main_node_client = MockClient::builder(L2::default())
+            .method("eth_sendRawTransaction", {
+                let send_tx_calls = send_tx_calls.clone();
+                let tx_hash = tx.hash();
+                move |_bytes: Bytes| {
+                    if send_tx_calls.fetch_add(1, Ordering::Relaxed) == 0 {
+                        Err(ClientError::RequestTimeout)
+                    } else {
+                        Ok(tx_hash)
+                    }
+                }
+            })
+            .build();
+
+        let proxy = TxProxy::new(Box::new(main_node_client))
+            .with_resubmission_queue(pool.clone(), immediate_resubmission_config());
+        proxy
+            .submit_tx(&tx, TransactionExecutionMetrics::default())
+            .await
+            .unwrap();
+        assert_eq!(send_tx_calls.load(Ordering::Relaxed), 1);
+        let found_tx = proxy.find_tx(&mut storage, tx.hash()).await.unwrap();
+        assert!(found_tx.is_some());
+        let queue_size = storage
+            .proxied_transactions_dal()
+            .get_queue_size()
+            .await
+            .unwrap();
+        assert_eq!(queue_size, 1);
+
+        let task = proxy.resubmission_task().unwrap();
+        let health_check = task.health_check();
+        task.step().await.unwrap();
+        assert_eq!(send_tx_calls.load(Ordering::Relaxed), 2);
+        // The transaction isn't included yet, so it should remain in the queue.
+        let health = health_check.check_health().await;
+        assert_eq!(
+            health.details().unwrap(),
+            &serde_json::json!({ "queue_depth": 1 })
+        );
+
+        // Emulate the transaction getting sealed.
+        storage
+            .blocks_dal()
+            .insert_l2_block(&create_l2_block(1))
+            .await
+            .unwrap();
+        let nonce_key = get_nonce_key(&tx.initiator_account());
+        let nonce_log = StorageLog::new_write_log(nonce_key, H256::from_low_u64_be(1));
+        storage
+            .storage_logs_dal()
+            .insert_storage_logs(L2BlockNumber(1), &[(H256::zero(), vec![nonce_log])])
+            .await
+            .unwrap();
+
+        task.step().await.unwrap();
+        assert_eq!(send_tx_calls.load(Ordering::Relaxed), 2);
+        let health = health_check.check_health().await;
+        assert_eq!(
+            health.details().unwrap(),
+            &serde_json::json!({ "queue_depth": 0 })
+        );
+    }
+
+    #[tokio::test]
+    async fn rejected_transaction_is_removed_from_queue() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut storage = pool.connection().await.unwrap();
+        let params = GenesisParams::load_genesis_params(mock_genesis_config()).unwrap();
+        insert_genesis_batch(&mut storage, &params).await.unwrap();
+
+        let tx = create_l2_transaction(10, 100);
+        let send_tx_calls = Arc::new(AtomicUsize::new(0));
+        let main_node_client = MockClient::builder(L2::default())
+            .method("eth_sendRawTransaction", {
+                let send_tx_calls = send_tx_calls.clone();
+                let tx_hash = tx.hash();
+                move |_bytes: Bytes| {
+                    if send_tx_calls.fetch_add(1, Ordering::Relaxed) == 0 {
+                        Ok(tx_hash)
+                    } else {
+                        let err = ErrorObject::owned(3, "nonce too low", None::<()>);
+                        Err(ClientError::Call(err))
+                    }
+                }
+            })
+            .build();
+
+        let proxy = TxProxy::new(Box::new(main_node_client))
+            .with_resubmission_queue(pool.clone(), immediate_resubmission_config());
+        proxy
+            .submit_tx(&tx, TransactionExecutionMetrics::default())
+            .await
+            .unwrap();
+        let task = proxy.resubmission_task().unwrap();
+        task.step().await.unwrap();
+        assert_eq!(send_tx_calls.load(Ordering::Relaxed), 2);
+
+        let queue_size = storage
+            .proxied_transactions_dal()
+            .get_queue_size()
+            .await
+            .unwrap();
+        assert_eq!(queue_size, 0);
+        let found_tx = proxy.find_tx(&mut storage, tx.hash()).await.unwrap();
+        assert!(found_tx.is_none(), "{found_tx:?}");
+    }
+
     #[derive(Debug, Clone, Copy)]
     enum CacheUpdateMethod {
         BackgroundTask,
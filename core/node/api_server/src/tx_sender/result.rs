@@ -72,6 +72,14 @@ pub enum SubmitTxError {
     ProxyError(#[from] EnrichedClientError),
     #[error("not enough gas to publish compressed bytecodes")]
     FailedToPublishCompressedBytecodes,
+    #[error("EVM bytecode deployments are disabled on this chain")]
+    EvmDeploymentsDisabled,
+    #[error("transactions from this initiator are not accepted")]
+    InitiatorDenied,
+    #[error("max fee per gas less than the minimum accepted by the node: {0}")]
+    MaxFeePerGasBelowMinimum(u64),
+    #[error("too many pending transactions from the initiator; at most {0} are allowed")]
+    TooManyPendingTransactions(u32),
     /// Catch-all internal error (e.g., database error) that should not be exposed to the caller.
     #[error("internal error")]
     Internal(#[from] anyhow::Error),
@@ -107,6 +115,10 @@ impl SubmitTxError {
             Self::IntrinsicGas => "intrinsic-gas",
             Self::ProxyError(_) => "proxy-error",
             Self::FailedToPublishCompressedBytecodes => "failed-to-publish-compressed-bytecodes",
+            Self::EvmDeploymentsDisabled => "evm-deployments-disabled",
+            Self::InitiatorDenied => "initiator-denied",
+            Self::MaxFeePerGasBelowMinimum(_) => "max-fee-per-gas-below-minimum",
+            Self::TooManyPendingTransactions(_) => "too-many-pending-transactions",
             Self::Internal(_) => "internal",
         }
     }
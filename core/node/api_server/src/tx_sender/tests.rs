@@ -158,3 +158,78 @@ async fn submitting_tx_requires_one_connection() {
         .unwrap()
         .expect("transaction is not persisted");
 }
+
+#[tokio::test]
+async fn evm_deployments_are_rejected_if_emulator_is_disabled() {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let mut storage = pool.connection().await.unwrap();
+    insert_genesis_batch(&mut storage, &GenesisParams::mock())
+        .await
+        .unwrap();
+    drop(storage);
+
+    let fee_input = MockBatchFeeParamsProvider::default()
+        .get_batch_fee_input_scaled(1.0, 1.0)
+        .await
+        .unwrap();
+    let (base_fee, gas_per_pubdata) =
+        derive_base_fee_and_gas_per_pubdata(fee_input, ProtocolVersionId::latest().into());
+    let mut tx = create_l2_transaction(base_fee, gas_per_pubdata);
+    tx.execute.contract_address = CONTRACT_DEPLOYER_ADDRESS;
+    tx.execute.calldata = keccak256(b"createEVM(bytes)")[..4].to_vec();
+    assert!(is_evm_deployment(&tx.execute));
+
+    let tx_executor = MockTransactionExecutor::default().into();
+    let (tx_sender, _) =
+        create_test_tx_sender(pool.clone(), L2ChainId::default(), tx_executor).await;
+    assert!(!tx_sender.0.sender_config.evm_emulator_enabled);
+
+    let err = tx_sender.submit_tx(tx).await.unwrap_err();
+    assert_matches!(err, SubmitTxError::EvmDeploymentsDisabled);
+}
+
+#[tokio::test]
+async fn mempool_admission_rules() {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let mut storage = pool.connection().await.unwrap();
+    insert_genesis_batch(&mut storage, &GenesisParams::mock())
+        .await
+        .unwrap();
+
+    let fee_input = MockBatchFeeParamsProvider::default()
+        .get_batch_fee_input_scaled(1.0, 1.0)
+        .await
+        .unwrap();
+    let (base_fee, gas_per_pubdata) =
+        derive_base_fee_and_gas_per_pubdata(fee_input, ProtocolVersionId::latest().into());
+    let tx = create_l2_transaction(base_fee, gas_per_pubdata);
+
+    let tx_executor = MockTransactionExecutor::default().into();
+    let (mut tx_sender, _) =
+        create_test_tx_sender(pool.clone(), L2ChainId::default(), tx_executor).await;
+    let sender_config = &mut Arc::get_mut(&mut tx_sender.0).unwrap().sender_config;
+    sender_config
+        .denied_initiators
+        .insert(tx.initiator_account());
+    let err = tx_sender.submit_tx(tx.clone()).await.unwrap_err();
+    assert_matches!(err, SubmitTxError::InitiatorDenied);
+
+    let sender_config = &mut Arc::get_mut(&mut tx_sender.0).unwrap().sender_config;
+    sender_config.denied_initiators.clear();
+    sender_config.min_max_fee_per_gas = Some(base_fee * 2);
+    let err = tx_sender.submit_tx(tx.clone()).await.unwrap_err();
+    assert_matches!(err, SubmitTxError::MaxFeePerGasBelowMinimum(fee) if fee == base_fee * 2);
+
+    let sender_config = &mut Arc::get_mut(&mut tx_sender.0).unwrap().sender_config;
+    sender_config.min_max_fee_per_gas = None;
+    sender_config.max_pending_txs_per_initiator = Some(1);
+    tx_sender.validate_pending_txs_count(&tx).await.unwrap();
+
+    storage
+        .transactions_dal()
+        .insert_transaction_l2(&tx, TransactionExecutionMetrics::default())
+        .await
+        .unwrap();
+    let err = tx_sender.validate_pending_txs_count(&tx).await.unwrap_err();
+    assert_matches!(err, SubmitTxError::TooManyPendingTransactions(1));
+}
@@ -0,0 +1,60 @@
+//! Updater for the list of tokens whitelisted for paymasters on the external node.
+
+use std::{sync::Arc, time::Duration};
+
+use tokio::sync::{watch, RwLock};
+use zksync_types::Address;
+use zksync_web3_decl::{
+    client::{DynClient, L2},
+    jsonrpsee,
+    namespaces::EnNamespaceClient,
+};
+
+/// Interval between updates of the whitelisted tokens.
+const UPDATE_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Periodically fetches tokens whitelisted for AA from the main node and stores them in the cache
+/// passed to [`TxSenderBuilder::with_whitelisted_tokens_for_aa()`](super::TxSenderBuilder::with_whitelisted_tokens_for_aa()).
+#[derive(Debug)]
+pub struct WhitelistedTokensForAaUpdater {
+    client: Box<DynClient<L2>>,
+    cache: Arc<RwLock<Vec<Address>>>,
+}
+
+impl WhitelistedTokensForAaUpdater {
+    pub fn new(client: Box<DynClient<L2>>) -> Self {
+        Self {
+            client: client.for_component("whitelisted_tokens_for_aa_updater"),
+            cache: Arc::default(),
+        }
+    }
+
+    /// Returns the cache updated by this task.
+    pub fn cache(&self) -> Arc<RwLock<Vec<Address>>> {
+        self.cache.clone()
+    }
+
+    pub async fn run(self, mut stop_receiver: watch::Receiver<bool>) -> anyhow::Result<()> {
+        while !*stop_receiver.borrow_and_update() {
+            match self.client.whitelisted_tokens_for_aa().await {
+                Ok(tokens) => {
+                    *self.cache.write().await = tokens;
+                }
+                Err(jsonrpsee::core::client::Error::Call(error))
+                    if error.code() == jsonrpsee::types::error::METHOD_NOT_FOUND_CODE =>
+                {
+                    // Method is not supported by the main node, do nothing.
+                }
+                Err(err) => {
+                    tracing::error!("Failed to query `whitelisted_tokens_for_aa`, error: {err:?}");
+                }
+            }
+
+            // Error here corresponds to a timeout w/o `stop_receiver` changed; we're OK with this.
+            tokio::time::timeout(UPDATE_INTERVAL, stop_receiver.changed())
+                .await
+                .ok();
+        }
+        Ok(())
+    }
+}
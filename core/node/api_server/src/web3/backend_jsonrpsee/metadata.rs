@@ -11,12 +11,13 @@ use zksync_web3_decl::{
 
 #[cfg(test)]
 use super::testonly::RecordedMethodCalls;
-use crate::web3::metrics::{ObservedRpcParams, API_METRICS};
+use crate::web3::metrics::{ApiTransportLabel, ObservedRpcParams, API_METRICS};
 
 /// Metadata assigned to a JSON-RPC method call.
 #[derive(Debug, Clone)]
 pub(crate) struct MethodMetadata {
     pub name: &'static str,
+    pub transport: ApiTransportLabel,
     pub started_at: Instant,
     /// Block ID requested by the call.
     pub block_id: Option<api::BlockId>,
@@ -24,12 +25,16 @@ pub(crate) struct MethodMetadata {
     pub block_diff: Option<u32>,
     /// Did this call return an app-level error?
     pub has_app_error: bool,
+    /// ID of the request, used in logs.
+    pub request_id: Option<vlog::RequestId>,
 }
 
 impl MethodMetadata {
-    fn new(name: &'static str) -> Self {
+    fn new(name: &'static str, transport: ApiTransportLabel) -> Self {
         Self {
             name,
+            transport,
+            request_id: vlog::RequestId::current(),
             started_at: Instant::now(),
             block_id: None,
             block_diff: None,
@@ -91,12 +96,13 @@ impl MethodTracer {
     pub(super) fn new_call<'a>(
         self: &Arc<Self>,
         name: &'static str,
+        transport: ApiTransportLabel,
         raw_params: ObservedRpcParams<'a>,
     ) -> MethodCall<'a> {
         MethodCall {
             tracer: self.clone(),
             params: raw_params,
-            meta: MethodMetadata::new(name),
+            meta: MethodMetadata::new(name, transport),
             is_completed: false,
         }
     }
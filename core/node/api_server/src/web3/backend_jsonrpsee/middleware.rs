@@ -1,5 +1,4 @@
 use std::{
-    cell::RefCell,
     collections::HashSet,
     future::Future,
     num::NonZeroU32,
@@ -9,6 +8,7 @@ use std::{
     time::{Duration, Instant},
 };
 
+use futures::future;
 use governor::{
     clock::DefaultClock,
     middleware::NoOpMiddleware,
@@ -17,12 +17,12 @@ use governor::{
 };
 use once_cell::sync::OnceCell;
 use pin_project_lite::pin_project;
-use rand::{rngs::SmallRng, RngCore, SeedableRng};
 use tokio::sync::watch;
 use tracing::instrument::{Instrument, Instrumented};
 use vise::{
     Buckets, Counter, EncodeLabelSet, EncodeLabelValue, Family, GaugeGuard, Histogram, Metrics,
 };
+use vlog::{RequestId, WithRequestId};
 use zksync_web3_decl::jsonrpsee::{
     server::middleware::rpc::{layer::ResponseFuture, RpcServiceT},
     types::{error::ErrorCode, ErrorObject, Request},
@@ -30,7 +30,11 @@ use zksync_web3_decl::jsonrpsee::{
 };
 
 use super::metadata::{MethodCall, MethodTracer};
-use crate::web3::metrics::{ObservedRpcParams, API_METRICS};
+use crate::web3::{
+    metrics::{ApiTransportLabel, ObservedRpcParams, API_METRICS},
+    rate_limit::{ClientLimitKind, ClientRateLimiter},
+    MethodFilter,
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EncodeLabelValue, EncodeLabelSet)]
 #[metrics(label = "transport", rename_all = "snake_case")]
@@ -53,6 +57,16 @@ struct LimitMiddlewareMetrics {
 #[vise::register]
 static METRICS: vise::Global<LimitMiddlewareMetrics> = vise::Global::new();
 
+#[derive(Debug, Metrics)]
+#[metrics(prefix = "api_jsonrpc_backend_client")]
+struct ClientRateLimitMetrics {
+    /// Number of HTTP requests throttled because of exceeding per-client rate limits.
+    throttled: Family<ClientLimitKind, Counter>,
+}
+
+#[vise::register]
+static CLIENT_RATE_LIMIT_METRICS: vise::Global<ClientRateLimitMetrics> = vise::Global::new();
+
 /// A rate-limiting middleware.
 ///
 /// `jsonrpsee` will allocate the instance of this struct once per session.
@@ -105,6 +119,35 @@ where
     }
 }
 
+/// RPC-level middleware rejecting calls to methods disabled by a [`MethodFilter`].
+#[derive(Debug)]
+pub(crate) struct MethodFilterMiddleware<S> {
+    inner: S,
+    filter: Arc<MethodFilter>,
+}
+
+impl<S> MethodFilterMiddleware<S> {
+    pub(crate) fn new(inner: S, filter: Arc<MethodFilter>) -> Self {
+        Self { inner, filter }
+    }
+}
+
+impl<'a, S> RpcServiceT<'a> for MethodFilterMiddleware<S>
+where
+    S: Send + Sync + RpcServiceT<'a>,
+{
+    type Future = ResponseFuture<S::Future>;
+
+    fn call(&self, request: Request<'a>) -> Self::Future {
+        if !self.filter.is_allowed(request.method_name()) {
+            let rp =
+                MethodResponse::error(request.id, ErrorObject::from(ErrorCode::MethodNotFound));
+            return ResponseFuture::ready(rp);
+        }
+        ResponseFuture::future(self.inner.call(request))
+    }
+}
+
 /// RPC-level middleware that adds [`MethodCall`] metadata to method logic. Method handlers can then access this metadata
 /// using [`MethodTracer`], which is a part of `RpcState`. When the handler completes or is dropped, the results are reported
 /// as metrics.
@@ -120,6 +163,7 @@ pub(crate) struct MetadataMiddleware<S, const TRACE_PARAMS: bool> {
     inner: S,
     registered_method_names: Arc<HashSet<&'static str>>,
     method_tracer: Arc<MethodTracer>,
+    transport: ApiTransportLabel,
 }
 
 impl<'a, S, const TRACE_PARAMS: bool> RpcServiceT<'a> for MetadataMiddleware<S, TRACE_PARAMS>
@@ -136,13 +180,20 @@ where
             .get(request.method_name())
             .copied()
             .unwrap_or("");
+        let request_size = request
+            .params
+            .as_ref()
+            .map_or(0, |params| params.get().len());
+        API_METRICS.observe_request(self.transport, method_name, request_size);
 
         let observed_params = if TRACE_PARAMS {
             ObservedRpcParams::new(request.params.as_ref())
         } else {
             ObservedRpcParams::Unknown
         };
-        let call = self.method_tracer.new_call(method_name, observed_params);
+        let call = self
+            .method_tracer
+            .new_call(method_name, self.transport, observed_params);
         WithMethodCall::new(self.inner.call(request), call)
     }
 }
@@ -190,16 +241,19 @@ impl<F: Future<Output = MethodResponse>> Future for WithMethodCall<'_, F> {
 pub(crate) struct MetadataLayer<const TRACE_PARAMS: bool> {
     registered_method_names: Arc<HashSet<&'static str>>,
     method_tracer: Arc<MethodTracer>,
+    transport: ApiTransportLabel,
 }
 
 impl MetadataLayer<false> {
     pub fn new(
         registered_method_names: Arc<HashSet<&'static str>>,
         method_tracer: Arc<MethodTracer>,
+        transport: ApiTransportLabel,
     ) -> Self {
         Self {
             registered_method_names,
             method_tracer,
+            transport,
         }
     }
 
@@ -207,6 +261,7 @@ impl MetadataLayer<false> {
         MetadataLayer {
             registered_method_names: self.registered_method_names,
             method_tracer: self.method_tracer,
+            transport: self.transport,
         }
     }
 }
@@ -219,10 +274,41 @@ impl<Svc, const TRACE_PARAMS: bool> tower::Layer<Svc> for MetadataLayer<TRACE_PA
             inner,
             registered_method_names: self.registered_method_names.clone(),
             method_tracer: self.method_tracer.clone(),
+            transport: self.transport,
         }
     }
 }
 
+/// Middleware assigning a [`RequestId`] to each RPC call. The ID is taken from the HTTP request if it's set
+/// by [`TraceContextLayer`]; otherwise, it's generated. The ID is then available to method handlers
+/// (e.g., it's recorded for DB queries and propagated in requests to the main node).
+#[derive(Debug)]
+pub(crate) struct RequestIdMiddleware<S> {
+    inner: S,
+}
+
+impl<S> RequestIdMiddleware<S> {
+    pub fn new(inner: S) -> Self {
+        Self { inner }
+    }
+}
+
+impl<'a, S> RpcServiceT<'a> for RequestIdMiddleware<S>
+where
+    S: RpcServiceT<'a>,
+{
+    type Future = WithRequestId<S::Future>;
+
+    fn call(&self, request: Request<'a>) -> Self::Future {
+        let request_id = RequestId::current().unwrap_or_else(RequestId::generate);
+        let inner = {
+            let _guard = request_id.enter();
+            self.inner.call(request)
+        };
+        request_id.scope(inner)
+    }
+}
+
 /// Middleware that adds tracing spans to each RPC call, so that logs belonging to the same call
 /// can be easily filtered.
 #[derive(Debug)]
@@ -243,27 +329,34 @@ where
     type Future = Instrumented<S::Future>;
 
     fn call(&self, request: Request<'a>) -> Self::Future {
-        thread_local! {
-            static CORRELATION_ID_RNG: RefCell<SmallRng> = RefCell::new(SmallRng::from_entropy());
-        }
-
         // Unlike `MetadataMiddleware`, we don't need to extend the method lifetime to `'static`;
         // `tracing` span instantiation allocates a `String` for supplied `&str`s in any case.
         let method = request.method_name();
-        // Wrap a call into a span with unique correlation ID, so that events occurring in the span can be easily filtered.
-        // This works as a cheap alternative to Open Telemetry tracing with its trace / span IDs.
-        let correlation_id = CORRELATION_ID_RNG.with(|rng| rng.borrow_mut().next_u64());
-        let call_span = tracing::debug_span!("rpc_call", method, correlation_id);
+        // Wrap a call into a span with the request ID set by `RequestIdMiddleware`, so that events occurring in the span
+        // can be easily filtered. This works as a cheap alternative to Open Telemetry tracing with its trace / span IDs.
+        let request_id = RequestId::current().map(tracing::field::display);
+        let call_span = tracing::debug_span!("rpc_call", method, request_id);
         self.inner.call(request).instrument(call_span)
     }
 }
 
-/// Tracks the timestamp of the last call to the RPC. Used during server shutdown to start dropping new traffic
-/// only after this is coordinated by the external load balancer.
-#[derive(Debug, Clone, Default)]
+/// Tracks the timestamp of the last call to the RPC and the number of in-flight calls. Used during server shutdown
+/// to start dropping new traffic only after this is coordinated by the external load balancer, and to drain
+/// calls that are still being processed.
+#[derive(Debug, Clone)]
 pub(crate) struct TrafficTracker {
     // We use `OnceCell` to not track requests before the server starts shutting down.
     last_call_sender: Arc<OnceCell<watch::Sender<Instant>>>,
+    in_flight_calls_sender: Arc<watch::Sender<usize>>,
+}
+
+impl Default for TrafficTracker {
+    fn default() -> Self {
+        Self {
+            last_call_sender: Arc::default(),
+            in_flight_calls_sender: Arc::new(watch::channel(0).0),
+        }
+    }
 }
 
 impl TrafficTracker {
@@ -273,6 +366,19 @@ impl TrafficTracker {
         }
     }
 
+    fn start_call(&self) -> InFlightCallGuard {
+        self.in_flight_calls_sender.send_modify(|count| *count += 1);
+        InFlightCallGuard {
+            in_flight_calls_sender: self.in_flight_calls_sender.clone(),
+        }
+    }
+
+    /// Subscribes to the number of in-flight calls. The subscription is closed once all middleware instances
+    /// (i.e., the server) are dropped.
+    pub fn subscribe_to_in_flight_calls(&self) -> watch::Receiver<usize> {
+        self.in_flight_calls_sender.subscribe()
+    }
+
     /// Waits until no new requests are received during the specified interval.
     pub async fn wait_for_no_requests(self, interval_without_requests: Duration) {
         let mut last_call_subscriber = self
@@ -322,11 +428,169 @@ impl<'a, S> RpcServiceT<'a> for ShutdownMiddleware<S>
 where
     S: Send + Sync + RpcServiceT<'a>,
 {
-    type Future = S::Future;
+    type Future = WithInFlightGuard<S::Future>;
 
     fn call(&self, request: Request<'a>) -> Self::Future {
         self.traffic_tracker.reset();
-        self.inner.call(request)
+        let guard = self.traffic_tracker.start_call();
+        WithInFlightGuard {
+            inner: self.inner.call(request),
+            _guard: guard,
+        }
+    }
+}
+
+/// Guard decrementing the number of in-flight calls in [`TrafficTracker`] when dropped.
+#[derive(Debug)]
+struct InFlightCallGuard {
+    in_flight_calls_sender: Arc<watch::Sender<usize>>,
+}
+
+impl Drop for InFlightCallGuard {
+    fn drop(&mut self) {
+        self.in_flight_calls_sender
+            .send_modify(|count| *count = count.saturating_sub(1));
+    }
+}
+
+pin_project! {
+    #[derive(Debug)]
+    pub(crate) struct WithInFlightGuard<F> {
+        #[pin]
+        inner: F,
+        _guard: InFlightCallGuard,
+    }
+}
+
+impl<F: Future> Future for WithInFlightGuard<F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.project().inner.poll(cx)
+    }
+}
+
+/// HTTP middleware wrapping each request into a span. If the request has W3C Trace Context headers,
+/// the span (and thus all spans created when serving the request, e.g. for DB queries) is exported
+/// via OpenTelemetry as a part of the caller's trace.
+///
+/// The middleware also assigns a [`RequestId`] to the request, taking it from the `x-request-id` header
+/// if it's present and valid (e.g., if the request is proxied by an external node).
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct TraceContextLayer;
+
+impl<S> tower::Layer<S> for TraceContextLayer {
+    type Service = TraceContextService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        TraceContextService { inner }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct TraceContextService<S> {
+    inner: S,
+}
+
+impl<S, B> tower::Service<http::Request<B>> for TraceContextService<S>
+where
+    S: tower::Service<http::Request<B>>,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = WithRequestId<Instrumented<S::Future>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: http::Request<B>) -> Self::Future {
+        let request_id = request
+            .headers()
+            .get(RequestId::HEADER)
+            .and_then(|value| value.to_str().ok()?.parse().ok())
+            .unwrap_or_else(RequestId::generate);
+        let span = tracing::info_span!("http_request", %request_id);
+        vlog::set_remote_parent(&span, |name| request.headers().get(name)?.to_str().ok());
+        let inner = {
+            let _guard = request_id.enter();
+            self.inner.call(request)
+        };
+        request_id.scope(inner.instrument(span))
+    }
+}
+
+/// HTTP middleware enforcing per-client rate limits. Throttled requests are responded to with the 429 HTTP status code
+/// and a JSON-RPC error.
+#[derive(Debug, Clone)]
+pub(crate) struct ClientRateLimitLayer {
+    limiter: Arc<ClientRateLimiter>,
+}
+
+impl ClientRateLimitLayer {
+    pub(crate) fn new(limiter: Arc<ClientRateLimiter>) -> Self {
+        Self { limiter }
+    }
+}
+
+impl<S> tower::Layer<S> for ClientRateLimitLayer {
+    type Service = ClientRateLimitService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        ClientRateLimitService {
+            inner,
+            limiter: self.limiter.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct ClientRateLimitService<S> {
+    inner: S,
+    limiter: Arc<ClientRateLimiter>,
+}
+
+impl<S> ClientRateLimitService<S> {
+    fn throttled_response<B: From<String>>() -> http::Response<B> {
+        let error = ErrorObject::borrowed(
+            ErrorCode::ServerError(http::StatusCode::TOO_MANY_REQUESTS.as_u16().into()).code(),
+            "Too many requests",
+            None,
+        );
+        let body = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": null,
+            "error": error,
+        });
+        let mut response = http::Response::new(B::from(body.to_string()));
+        *response.status_mut() = http::StatusCode::TOO_MANY_REQUESTS;
+        response.headers_mut().insert(
+            http::header::CONTENT_TYPE,
+            http::HeaderValue::from_static("application/json"),
+        );
+        response
+    }
+}
+
+impl<S, B, ResBody> tower::Service<http::Request<B>> for ClientRateLimitService<S>
+where
+    S: tower::Service<http::Request<B>, Response = http::Response<ResBody>>,
+    ResBody: From<String>,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = future::Either<future::Ready<Result<Self::Response, Self::Error>>, S::Future>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: http::Request<B>) -> Self::Future {
+        if let Err(kind) = self.limiter.check(request.headers()) {
+            CLIENT_RATE_LIMIT_METRICS.throttled[&kind].inc();
+            return future::Either::Left(future::ready(Ok(Self::throttled_response())));
+        }
+        future::Either::Right(self.inner.call(request))
     }
 }
 
@@ -375,7 +639,7 @@ mod tests {
 
             WithMethodCall::new(
                 inner,
-                method_tracer.new_call("test", ObservedRpcParams::None),
+                method_tracer.new_call("test", ApiTransportLabel::Http, ObservedRpcParams::None),
             )
         });
 
@@ -412,4 +676,24 @@ mod tests {
         let elapsed = now.elapsed();
         assert!(elapsed >= Duration::from_millis(15), "{elapsed:?}");
     }
+
+    #[tokio::test]
+    async fn traffic_tracker_tracks_in_flight_calls() {
+        let traffic_tracker = TrafficTracker::default();
+        let mut in_flight_calls = traffic_tracker.subscribe_to_in_flight_calls();
+        let first_guard = traffic_tracker.start_call();
+        let second_guard = traffic_tracker.start_call();
+        assert_eq!(*in_flight_calls.borrow_and_update(), 2);
+
+        drop(first_guard);
+        assert_eq!(*in_flight_calls.borrow_and_update(), 1);
+        let wait = tokio::spawn(async move {
+            in_flight_calls.wait_for(|&count| count == 0).await.ok();
+        });
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        assert!(!wait.is_finished());
+
+        drop(second_guard);
+        wait.await.unwrap();
+    }
 }
@@ -10,7 +10,9 @@ use zksync_web3_decl::{
 pub(crate) use self::{
     metadata::{MethodMetadata, MethodTracer},
     middleware::{
-        CorrelationMiddleware, LimitMiddleware, MetadataLayer, ShutdownMiddleware, TrafficTracker,
+        ClientRateLimitLayer, CorrelationMiddleware, LimitMiddleware, MetadataLayer,
+        MethodFilterMiddleware, RequestIdMiddleware, ShutdownMiddleware, TraceContextLayer,
+        TrafficTracker,
     },
 };
 use crate::tx_sender::SubmitTxError;
@@ -39,7 +41,12 @@ impl MethodTracer {
             | Web3Error::TooManyTopics
             | Web3Error::FilterNotFound
             | Web3Error::InvalidFilterBlockHash
-            | Web3Error::LogsLimitExceeded(_, _, _) => ErrorCode::InvalidParams.code(),
+            | Web3Error::LogsLimitExceeded(_, _, _)
+            | Web3Error::LogsBlockRangeExceeded(_)
+            | Web3Error::InvalidStateOverride(_)
+            | Web3Error::InvalidRewardPercentiles(_)
+            | Web3Error::TooManyCalls(_)
+            | Web3Error::UnsupportedProtocolVersion(_) => ErrorCode::InvalidParams.code(),
             Web3Error::SubmitTransactionError(_, _)
             | Web3Error::SerializationError(_)
             | Web3Error::ProxyError(_) => 3,
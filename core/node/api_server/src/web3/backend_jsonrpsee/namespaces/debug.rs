@@ -1,5 +1,8 @@
 use zksync_types::{
-    api::{BlockId, BlockNumber, DebugCall, ResultDebugCall, TracerConfig},
+    api::{
+        BlockId, BlockNumber, CallOptions, DebugCall, DebugOpcodeTrace, ResultDebugCall,
+        TraceCallManyOptions, TracerConfig,
+    },
     debug_flat_call::DebugCallFlat,
     transaction_request::CallRequest,
     H256,
@@ -54,6 +57,28 @@ impl DebugNamespaceServer for DebugNamespace {
             .map_err(|err| self.current_method().map_err(err))
     }
 
+    async fn trace_call_opcodes(
+        &self,
+        request: CallRequest,
+        block: Option<BlockId>,
+        options: Option<CallOptions>,
+    ) -> RpcResult<DebugOpcodeTrace> {
+        self.debug_trace_call_opcodes_impl(request, block, options)
+            .await
+            .map_err(|err| self.current_method().map_err(err))
+    }
+
+    async fn trace_call_many(
+        &self,
+        requests: Vec<CallRequest>,
+        block: Option<BlockId>,
+        options: Option<TraceCallManyOptions>,
+    ) -> RpcResult<Vec<DebugCall>> {
+        self.debug_trace_call_many_impl(requests, block, options)
+            .await
+            .map_err(|err| self.current_method().map_err(err))
+    }
+
     async fn trace_transaction(
         &self,
         tx_hash: H256,
@@ -25,6 +25,15 @@ impl EnNamespaceServer for EnNamespace {
             .map_err(|err| self.current_method().map_err(err))
     }
 
+    async fn block_certificate(
+        &self,
+        block_number: L2BlockNumber,
+    ) -> RpcResult<Option<en::BlockCertificate>> {
+        self.block_certificate_impl(block_number)
+            .await
+            .map_err(|err| self.current_method().map_err(err))
+    }
+
     async fn sync_tokens(&self, block_number: Option<L2BlockNumber>) -> RpcResult<Vec<TokenInfo>> {
         self.sync_tokens_impl(block_number)
             .await
@@ -3,8 +3,10 @@ use std::collections::HashMap;
 use itertools::Itertools;
 use zksync_types::{
     api::{
-        ApiStorageLog, BlockDetails, BridgeAddresses, L1BatchDetails, L2ToL1LogProof, Log, Proof,
+        state_override::StateOverride, ApiStorageLog, BlockDetails, BlockIdVariant,
+        BridgeAddresses, L1BatchDetails, L2ToL1LogProof, Log, LogsCursor, LogsPage, Proof,
         ProtocolVersion, TransactionDetailedResult, TransactionDetails,
+        TransactionSimulationResult,
     },
     fee::Fee,
     fee_model::{FeeParams, PubdataIndependentBatchFeeModelInput},
@@ -15,7 +17,7 @@ use zksync_types::{
 use zksync_web3_decl::{
     jsonrpsee::core::{async_trait, RpcResult},
     namespaces::ZksNamespaceServer,
-    types::Token,
+    types::{Filter, Token},
 };
 
 use crate::web3::ZksNamespace;
@@ -136,6 +138,23 @@ impl ZksNamespaceServer for ZksNamespace {
             .map_err(|err| self.current_method().map_err(err))
     }
 
+    async fn get_l1_batch_pubdata(&self, batch_number: L1BatchNumber) -> RpcResult<Option<Bytes>> {
+        self.get_l1_batch_pubdata_impl(batch_number)
+            .await
+            .map_err(|err| self.current_method().map_err(err))
+    }
+
+    async fn get_logs_paginated(
+        &self,
+        filter: Filter,
+        cursor: Option<LogsCursor>,
+        limit: Option<u32>,
+    ) -> RpcResult<LogsPage> {
+        self.get_logs_paginated_impl(filter, cursor, limit)
+            .await
+            .map_err(|err| self.current_method().map_err(err))
+    }
+
     async fn get_bytecode_by_hash(&self, hash: H256) -> RpcResult<Option<Vec<u8>>> {
         self.get_bytecode_by_hash_impl(hash)
             .await
@@ -215,4 +234,15 @@ impl ZksNamespaceServer for ZksNamespace {
             })
             .map_err(|err| self.current_method().map_err(err))
     }
+
+    async fn simulate_transaction(
+        &self,
+        req: CallRequest,
+        block: Option<BlockIdVariant>,
+        state_override: Option<StateOverride>,
+    ) -> RpcResult<TransactionSimulationResult> {
+        self.simulate_transaction_impl(req, block.map(Into::into), state_override)
+            .await
+            .map_err(|err| self.current_method().map_err(err))
+    }
 }
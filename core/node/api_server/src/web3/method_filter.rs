@@ -0,0 +1,129 @@
+//! Filtering of JSON-RPC methods exposed by the API server.
+
+use std::fmt;
+
+/// Pattern matching JSON-RPC method names. Either an exact method name (e.g., `zks_sendRawTransactionWithDetailedOutput`)
+/// or a prefix followed by `*` (e.g., `debug_*`).
+#[derive(Clone, PartialEq, Eq)]
+enum MethodPattern {
+    Exact(String),
+    Prefix(String),
+}
+
+impl fmt::Debug for MethodPattern {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Exact(name) => formatter.write_str(name),
+            Self::Prefix(prefix) => write!(formatter, "{prefix}*"),
+        }
+    }
+}
+
+impl MethodPattern {
+    fn new(pattern: &str) -> Self {
+        match pattern.strip_suffix('*') {
+            Some(prefix) => Self::Prefix(prefix.to_owned()),
+            None => Self::Exact(pattern.to_owned()),
+        }
+    }
+
+    fn matches(&self, method: &str) -> bool {
+        match self {
+            Self::Exact(name) => name == method,
+            Self::Prefix(prefix) => method.starts_with(prefix.as_str()),
+        }
+    }
+}
+
+/// Filter for JSON-RPC methods served by the API server. Calls to methods rejected by the filter are responded to
+/// with the "method not found" error, as if the methods were not registered at all.
+///
+/// Methods are specified using patterns, which are either exact method names (e.g., `zks_sendRawTransactionWithDetailedOutput`),
+/// or prefixes followed by `*` (e.g., `debug_*`). The denylist takes precedence over the allowlist.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MethodFilter {
+    allowlist: Option<Vec<MethodPattern>>,
+    denylist: Vec<MethodPattern>,
+}
+
+impl MethodFilter {
+    /// Creates a filter only allowing methods matching the provided patterns.
+    pub fn allow_only<S: AsRef<str>>(patterns: impl IntoIterator<Item = S>) -> Self {
+        Self {
+            allowlist: Some(
+                patterns
+                    .into_iter()
+                    .map(|pattern| MethodPattern::new(pattern.as_ref()))
+                    .collect(),
+            ),
+            denylist: vec![],
+        }
+    }
+
+    /// Additionally denies methods matching the provided patterns.
+    #[must_use]
+    pub fn deny<S: AsRef<str>>(mut self, patterns: impl IntoIterator<Item = S>) -> Self {
+        self.denylist.extend(
+            patterns
+                .into_iter()
+                .map(|pattern| MethodPattern::new(pattern.as_ref())),
+        );
+        self
+    }
+
+    /// Checks whether this filter allows all methods.
+    pub fn is_trivial(&self) -> bool {
+        self.allowlist.is_none() && self.denylist.is_empty()
+    }
+
+    /// Checks whether the specified method is allowed by this filter.
+    pub fn is_allowed(&self, method: &str) -> bool {
+        if self.denylist.iter().any(|pattern| pattern.matches(method)) {
+            return false;
+        }
+        match &self.allowlist {
+            Some(allowlist) => allowlist.iter().any(|pattern| pattern.matches(method)),
+            None => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_filter_allows_all_methods() {
+        let filter = MethodFilter::default();
+        assert!(filter.is_trivial());
+        assert!(filter.is_allowed("eth_call"));
+        assert!(filter.is_allowed("debug_traceCall"));
+    }
+
+    #[test]
+    fn filtering_methods_with_denylist() {
+        let filter =
+            MethodFilter::default().deny(["debug_*", "zks_sendRawTransactionWithDetailedOutput"]);
+        assert!(!filter.is_trivial());
+        assert!(filter.is_allowed("eth_call"));
+        assert!(filter.is_allowed("zks_getBlockDetails"));
+        assert!(!filter.is_allowed("debug_traceCall"));
+        assert!(!filter.is_allowed("debug_traceBlockByNumber"));
+        assert!(!filter.is_allowed("zks_sendRawTransactionWithDetailedOutput"));
+    }
+
+    #[test]
+    fn filtering_methods_with_allowlist() {
+        let filter =
+            MethodFilter::allow_only(["eth_*", "net_version"]).deny(["eth_sendRawTransaction"]);
+        assert!(filter.is_allowed("eth_call"));
+        assert!(filter.is_allowed("eth_getBalance"));
+        assert!(filter.is_allowed("net_version"));
+        assert!(!filter.is_allowed("net_peerCount"));
+        assert!(!filter.is_allowed("zks_getBlockDetails"));
+        assert!(!filter.is_allowed("eth_sendRawTransaction"));
+
+        let filter = MethodFilter::allow_only::<&str>([]);
+        assert!(!filter.is_allowed("eth_call"));
+    }
+}
@@ -6,6 +6,7 @@ use vise::{
     Buckets, Counter, DurationAsSecs, EncodeLabelSet, EncodeLabelValue, Family, Gauge, Histogram,
     Info, LabeledFamily, Metrics, Unit,
 };
+use zksync_health_check::LatencyWindow;
 use zksync_types::api;
 use zksync_web3_decl::error::Web3Error;
 
@@ -95,6 +96,27 @@ impl From<&ApiTransport> for ApiTransportLabel {
     }
 }
 
+/// Returns the namespace of a JSON-RPC method (e.g., `eth` for `eth_call`), or an empty string if the method
+/// doesn't have a namespace (e.g., if it's unknown).
+fn method_namespace(method: &'static str) -> &'static str {
+    method
+        .split_once('_')
+        .map_or("", |(namespace, _)| namespace)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EncodeLabelSet)]
+struct RequestLabels {
+    scheme: ApiTransportLabel,
+    namespace: &'static str,
+    method: &'static str,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EncodeLabelSet)]
+struct NamespaceLabels {
+    scheme: ApiTransportLabel,
+    namespace: &'static str,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EncodeLabelValue)]
 #[metrics(rename_all = "snake_case")]
 enum BlockIdLabel {
@@ -167,7 +189,12 @@ enum Web3ErrorKind {
     TooManyTopics,
     FilterNotFound,
     LogsLimitExceeded,
+    LogsBlockRangeExceeded,
     InvalidFilterBlockHash,
+    InvalidStateOverride,
+    InvalidRewardPercentiles,
+    TooManyCalls,
+    UnsupportedProtocolVersion,
     TreeApiUnavailable,
     Internal,
 }
@@ -183,7 +210,12 @@ impl Web3ErrorKind {
             Web3Error::TooManyTopics => Self::TooManyTopics,
             Web3Error::FilterNotFound => Self::FilterNotFound,
             Web3Error::LogsLimitExceeded(..) => Self::LogsLimitExceeded,
+            Web3Error::LogsBlockRangeExceeded(_) => Self::LogsBlockRangeExceeded,
             Web3Error::InvalidFilterBlockHash => Self::InvalidFilterBlockHash,
+            Web3Error::InvalidStateOverride(_) => Self::InvalidStateOverride,
+            Web3Error::InvalidRewardPercentiles(_) => Self::InvalidRewardPercentiles,
+            Web3Error::TooManyCalls(_) => Self::TooManyCalls,
+            Web3Error::UnsupportedProtocolVersion(_) => Self::UnsupportedProtocolVersion,
             Web3Error::TreeApiUnavailable => Self::TreeApiUnavailable,
             Web3Error::InternalError(_) | Web3Error::MethodNotImplemented => Self::Internal,
         }
@@ -218,6 +250,9 @@ struct Web3ConfigLabels {
     fee_history_limit: u64,
     filters_limit: Option<usize>,
     subscriptions_limit: Option<usize>,
+    subscriptions_per_connection_limit: Option<usize>,
+    subscription_backlog_size: Option<usize>,
+    max_logs_block_range: Option<u32>,
     #[metrics(unit = Unit::Bytes)]
     batch_request_size_limit: Option<usize>,
     #[metrics(unit = Unit::Bytes)]
@@ -251,6 +286,15 @@ pub(crate) struct ApiMetrics {
     /// Serialized response size in bytes. Only recorded for successful responses.
     #[metrics(buckets = RESPONSE_SIZE_BUCKETS, labels = ["method"], unit = Unit::Bytes)]
     web3_call_response_size: LabeledFamily<&'static str, Histogram<usize>>,
+    /// Number of received Web3 calls grouped by transport, namespace and method. Calls to unknown methods
+    /// have empty namespace and method labels.
+    web3_requests: Family<RequestLabels, Counter>,
+    /// Size of serialized call params in bytes.
+    #[metrics(buckets = RESPONSE_SIZE_BUCKETS, labels = ["method"], unit = Unit::Bytes)]
+    web3_call_request_size: LabeledFamily<&'static str, Histogram<usize>>,
+    /// Latency of Web3 calls aggregated by transport and namespace.
+    #[metrics(buckets = Buckets::LATENCIES, unit = Unit::Seconds)]
+    web3_namespace_call: Family<NamespaceLabels, Histogram<Duration>>,
 
     /// Number of application errors grouped by error kind and method name. Only collected for errors that were successfully routed
     /// to a method (i.e., this method is defined).
@@ -283,6 +327,9 @@ impl ApiMetrics {
             fee_history_limit: config.fee_history_limit,
             filters_limit: optional.filters_limit,
             subscriptions_limit: optional.subscriptions_limit,
+            subscriptions_per_connection_limit: optional.subscriptions_per_connection_limit,
+            subscription_backlog_size: optional.subscription_backlog_size,
+            max_logs_block_range: config.max_logs_block_range,
             batch_request_size_limit: optional.batch_request_size_limit,
             response_body_size_limit: optional
                 .response_body_size_limit
@@ -298,6 +345,22 @@ impl ApiMetrics {
         }
     }
 
+    /// Observes a received RPC call.
+    pub(super) fn observe_request(
+        &self,
+        transport: ApiTransportLabel,
+        method: &'static str,
+        request_size: usize,
+    ) {
+        let labels = RequestLabels {
+            scheme: transport,
+            namespace: method_namespace(method),
+            method,
+        };
+        self.web3_requests[&labels].inc();
+        self.web3_call_request_size[&method].observe(request_size);
+    }
+
     /// Observes latency of a finished RPC call.
     pub(super) fn observe_latency(
         &self,
@@ -309,11 +372,21 @@ impl ApiMetrics {
 
         let latency = meta.started_at.elapsed();
         self.web3_call[&MethodLabels::from(meta)].observe(latency);
+        let namespace_labels = NamespaceLabels {
+            scheme: meta.transport,
+            namespace: method_namespace(meta.name),
+        };
+        self.web3_namespace_call[&namespace_labels].observe(latency);
+        RECENT_CALL_LATENCIES.observe(latency);
         if let Some(block_diff) = meta.block_diff {
             self.web3_call_block_diff[&meta.name].observe(block_diff.into());
         }
         if latency >= MIN_REPORTED_LATENCY && FILTER.should_report() {
-            tracing::info!("Long call to `{}`{raw_params}: {latency:?}", meta.name);
+            tracing::info!(
+                request_id = meta.request_id.map(tracing::field::display),
+                "Long call to `{}`{raw_params}: {latency:?}",
+                meta.name
+            );
         }
     }
 
@@ -329,6 +402,7 @@ impl ApiMetrics {
         self.web3_dropped_call_latency[&MethodLabels::from(meta)].observe(latency);
         if FILTER.should_report() {
             tracing::info!(
+                request_id = meta.request_id.map(tracing::field::display),
                 "Call to `{}`{raw_params} was dropped by client after {latency:?}",
                 meta.name
             );
@@ -385,12 +459,16 @@ impl ApiMetrics {
 
     pub(super) fn observe_web3_error(&self, method: &'static str, err: &Web3Error) {
         // Log internal error details.
+        let request_id = vlog::RequestId::current().map(tracing::field::display);
         match err {
             Web3Error::InternalError(err) => {
-                tracing::error!("Internal error in method `{method}`: {err:#}");
+                tracing::error!(request_id, "Internal error in method `{method}`: {err:#}");
             }
             Web3Error::ProxyError(err) => {
-                tracing::warn!("Error proxying call to main node in method `{method}`: {err}");
+                tracing::warn!(
+                    request_id,
+                    "Error proxying call to main node in method `{method}`: {err}"
+                );
             }
             _ => { /* do nothing */ }
         }
@@ -413,6 +491,10 @@ impl ApiMetrics {
 #[vise::register]
 pub(crate) static API_METRICS: vise::Global<ApiMetrics> = vise::Global::new();
 
+/// Latencies of recently finished RPC calls (across all methods and transports) used in the API latency SLO check.
+pub(super) static RECENT_CALL_LATENCIES: LatencyWindow =
+    LatencyWindow::new(Duration::from_secs(300), 4_096);
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EncodeLabelValue, EncodeLabelSet)]
 #[metrics(label = "subscription_type", rename_all = "snake_case")]
 pub enum SubscriptionType {
@@ -434,6 +516,8 @@ pub(super) struct PubSubMetrics {
     pub notify: Family<SubscriptionType, Counter>,
     /// Number of currently active subscribers split by the subscription type.
     pub active_subscribers: Family<SubscriptionType, Gauge>,
+    /// Total number of subscriptions created split by the subscription type.
+    pub subscriptions: Family<SubscriptionType, Counter>,
     /// Lifetime of a subscriber of a certain type.
     #[metrics(buckets = Buckets::LATENCIES)]
     pub subscriber_lifetime: Family<SubscriptionType, Histogram<Duration>>,
@@ -445,6 +529,13 @@ pub(super) struct PubSubMetrics {
     pub skipped_broadcast_messages: Family<SubscriptionType, Histogram<u64>>,
     /// Number of subscribers dropped because of a send timeout.
     pub subscriber_send_timeouts: Family<SubscriptionType, Counter>,
+    /// Total number of subscriptions resumed with `zks_resubscribe` split by the subscription type.
+    pub resumed_subscriptions: Family<SubscriptionType, Counter>,
+    /// Current number of closed subscriptions that can be resumed.
+    pub detached_subscriptions: Gauge<usize>,
+    /// Number of L2 blocks for which loading logs was skipped because their logs bloom
+    /// didn't match filters of any active `logs` subscription.
+    pub bloom_skipped_blocks: Counter,
 }
 
 #[vise::register]
@@ -551,4 +642,12 @@ mod tests {
             "{rpc_params_str}"
         );
     }
+
+    #[test]
+    fn getting_method_namespace() {
+        assert_eq!(method_namespace("eth_call"), "eth");
+        assert_eq!(method_namespace("zks_getBlockDetails"), "zks");
+        assert_eq!(method_namespace("en_syncL2Block"), "en");
+        assert_eq!(method_namespace(""), "");
+    }
 }
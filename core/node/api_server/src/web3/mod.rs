@@ -9,7 +9,10 @@ use tokio::{
     task::JoinHandle,
 };
 use tower_http::{cors::CorsLayer, metrics::InFlightRequestsLayer};
-use zksync_config::configs::api::{MaxResponseSize, MaxResponseSizeOverrides};
+use zksync_config::configs::{
+    api::{MaxResponseSize, MaxResponseSizeOverrides},
+    ReloadableConfig,
+};
 use zksync_dal::{helpers::wait_for_l1_batch, ConnectionPool, Core};
 use zksync_health_check::{HealthStatus, HealthUpdater, ReactiveHealthCheck};
 use zksync_metadata_calculator::api_server::TreeApiClient;
@@ -25,24 +28,28 @@ use zksync_web3_decl::{
     namespaces::{
         DebugNamespaceServer, EnNamespaceServer, EthNamespaceServer, EthPubSubServer,
         NetNamespaceServer, SnapshotsNamespaceServer, Web3NamespaceServer, ZksNamespaceServer,
+        ZksPubSubServer,
     },
     types::Filter,
 };
 
 use self::{
     backend_jsonrpsee::{
-        CorrelationMiddleware, LimitMiddleware, MetadataLayer, MethodTracer, ShutdownMiddleware,
-        TrafficTracker,
+        ClientRateLimitLayer, CorrelationMiddleware, LimitMiddleware, MetadataLayer,
+        MethodFilterMiddleware, MethodTracer, RequestIdMiddleware, ShutdownMiddleware,
+        TraceContextLayer, TrafficTracker,
     },
     mempool_cache::MempoolCache,
     metrics::API_METRICS,
     namespaces::{
-        DebugNamespace, EnNamespace, EthNamespace, NetNamespace, SnapshotsNamespace, Web3Namespace,
-        ZksNamespace,
+        DebugNamespace, DebugNamespaceConfig, EnNamespace, EthNamespace, NetNamespace,
+        SnapshotsNamespace, Web3Namespace, ZksNamespace,
     },
     pubsub::{EthSubscribe, EthSubscriptionIdProvider, PubSubEvent},
+    rate_limit::ClientRateLimiter,
     state::{Filters, InternalApiConfig, RpcState, SealedL2BlockNumber},
 };
+pub use self::{method_filter::MethodFilter, rate_limit::ClientRateLimit};
 use crate::{
     execution_sandbox::{BlockStartInfo, VmConcurrencyBarrier},
     tx_sender::TxSender,
@@ -50,9 +57,11 @@ use crate::{
 
 pub mod backend_jsonrpsee;
 pub mod mempool_cache;
+mod method_filter;
 pub(super) mod metrics;
 pub mod namespaces;
 mod pubsub;
+mod rate_limit;
 pub mod state;
 pub mod testonly;
 #[cfg(test)]
@@ -61,14 +70,20 @@ pub(crate) mod tests;
 /// Timeout for graceful shutdown logic within API servers.
 const GRACEFUL_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(5);
 
-/// Interval to wait for the traffic to be stopped to the API server (e.g., by a load balancer) before
-/// the server will cease processing any further traffic. If this interval is exceeded, the server will start
-/// shutting down anyway.
-const NO_REQUESTS_WAIT_TIMEOUT: Duration = Duration::from_secs(30);
+/// Default drain period for API servers. Within this period, the server waits for the traffic to be stopped
+/// (e.g., by a load balancer) and then for in-flight calls to complete. If the period is exceeded, the server
+/// will shut down anyway, dropping the remaining calls.
+const DEFAULT_SHUTDOWN_DRAIN_TIMEOUT: Duration = Duration::from_secs(30);
 /// Time interval with no requests sent to the API server to declare that traffic to the server is ceased,
 /// and start gracefully shutting down the server.
 const SHUTDOWN_INTERVAL_WITHOUT_REQUESTS: Duration = Duration::from_millis(500);
 
+/// Returns the 99th percentile of latencies of RPC calls finished during the last 5 minutes, or `None`
+/// if no calls were served. Used in the API latency SLO check.
+pub fn recent_call_latency_p99() -> Option<Duration> {
+    metrics::RECENT_CALL_LATENCIES.percentile(0.99)
+}
+
 /// Represents all kinds of `Filter`.
 #[derive(Debug, Clone)]
 pub(crate) enum TypedFilter {
@@ -126,12 +141,19 @@ struct OptionalApiParams {
     sync_state: Option<SyncState>,
     filters_limit: Option<usize>,
     subscriptions_limit: Option<usize>,
+    subscriptions_per_connection_limit: Option<usize>,
+    subscription_backlog_size: Option<usize>,
     batch_request_size_limit: Option<usize>,
     response_body_size_limit: Option<MaxResponseSize>,
     websocket_requests_per_minute_limit: Option<NonZeroU32>,
+    config_updates: Option<watch::Receiver<ReloadableConfig>>,
     tree_api: Option<Arc<dyn TreeApiClient>>,
     mempool_cache: Option<MempoolCache>,
+    debug_namespace: Option<DebugNamespaceConfig>,
+    method_filter: Option<Arc<MethodFilter>>,
+    client_rate_limit: Option<ClientRateLimit>,
     extended_tracing: bool,
+    shutdown_drain_timeout: Option<Duration>,
     pub_sub_events_sender: Option<mpsc::UnboundedSender<PubSubEvent>>,
 }
 
@@ -227,6 +249,21 @@ impl ApiBuilder {
         self
     }
 
+    /// Sets the maximum number of subscriptions that can be open on a single WebSocket connection.
+    /// Ignored for the HTTP transport.
+    pub fn with_subscriptions_per_connection_limit(mut self, limit: usize) -> Self {
+        self.optional.subscriptions_per_connection_limit = Some(limit);
+        self
+    }
+
+    /// Sets the number of recent notifications of each subscription type retained in order to resume
+    /// subscriptions with `zks_resubscribe`. If not set or set to 0, subscriptions cannot be resumed.
+    /// Ignored for the HTTP transport.
+    pub fn with_subscription_backlog_size(mut self, size: usize) -> Self {
+        self.optional.subscription_backlog_size = Some(size);
+        self
+    }
+
     pub fn with_batch_request_size_limit(mut self, batch_request_size_limit: usize) -> Self {
         self.optional.batch_request_size_limit = Some(batch_request_size_limit);
         self
@@ -246,6 +283,13 @@ impl ApiBuilder {
         self
     }
 
+    /// Makes the server take the WebSocket requests limit from the reloadable config. Changes to the limit
+    /// apply to the sessions opened after the change.
+    pub fn with_config_updates(mut self, updates: watch::Receiver<ReloadableConfig>) -> Self {
+        self.optional.config_updates = Some(updates);
+        self
+    }
+
     pub fn with_sync_state(mut self, sync_state: SyncState) -> Self {
         self.optional.sync_state = Some(sync_state);
         self
@@ -277,11 +321,48 @@ impl ApiBuilder {
         self
     }
 
+    /// Enables the `debug` namespace with the specified config, regardless of [`Self::enable_api_namespaces()`].
+    pub fn with_debug_namespace(mut self, config: DebugNamespaceConfig) -> Self {
+        self.optional.debug_namespace = Some(config);
+        self
+    }
+
+    /// Restricts JSON-RPC methods served by the server. Unlike [`Self::enable_api_namespaces()`], this allows
+    /// disabling individual methods.
+    pub fn with_method_filter(mut self, filter: MethodFilter) -> Self {
+        if filter.is_trivial() {
+            self.optional.method_filter = None;
+        } else {
+            tracing::info!("Using JSON-RPC method filter: {filter:?}");
+            self.optional.method_filter = Some(Arc::new(filter));
+        }
+        self
+    }
+
+    /// Limits the number of requests per client. Only applies to the HTTP transport; for WebSocket,
+    /// use [`Self::with_websocket_requests_per_minute_limit()`].
+    pub fn with_client_rate_limit(mut self, rate_limit: ClientRateLimit) -> Self {
+        if rate_limit.is_trivial() {
+            self.optional.client_rate_limit = None;
+        } else {
+            tracing::info!("Using per-client rate limit: {rate_limit:?}");
+            self.optional.client_rate_limit = Some(rate_limit);
+        }
+        self
+    }
+
     pub fn with_extended_tracing(mut self, extended_tracing: bool) -> Self {
         self.optional.extended_tracing = extended_tracing;
         self
     }
 
+    /// Sets the maximum period the server waits on shutdown for the traffic to stop and for in-flight calls
+    /// (including calls over WS sessions) to complete.
+    pub fn with_shutdown_drain_timeout(mut self, timeout: Duration) -> Self {
+        self.optional.shutdown_drain_timeout = Some(timeout);
+        self
+    }
+
     // Intended for tests only.
     #[doc(hidden)]
     fn with_pub_sub_events(mut self, sender: mpsc::UnboundedSender<PubSubEvent>) -> Self {
@@ -305,6 +386,15 @@ impl ApiBuilder {
             ApiTransport::WebSocket(_) => "ws_api",
         };
         let (_, health_updater) = ReactiveHealthCheck::new(health_check_name);
+        let mut namespaces = self.namespaces.unwrap_or_else(|| {
+            tracing::warn!(
+                "debug_ and snapshots_ API namespace will be disabled by default in ApiBuilder"
+            );
+            Namespace::DEFAULT.to_vec()
+        });
+        if self.optional.debug_namespace.is_some() && !namespaces.contains(&Namespace::Debug) {
+            namespaces.push(Namespace::Debug);
+        }
 
         Ok(ApiServer {
             pool: self.pool,
@@ -315,12 +405,7 @@ impl ApiBuilder {
             tx_sender: self.tx_sender.context("Transaction sender not set")?,
             polling_interval: self.polling_interval,
             pruning_info_refresh_interval: self.pruning_info_refresh_interval,
-            namespaces: self.namespaces.unwrap_or_else(|| {
-                tracing::warn!(
-                    "debug_ and snapshots_ API namespace will be disabled by default in ApiBuilder"
-                );
-                Namespace::DEFAULT.to_vec()
-            }),
+            namespaces,
             method_tracer: self.method_tracer,
             optional: self.optional,
         })
@@ -371,19 +456,26 @@ impl ApiServer {
         last_sealed_l2_block: SealedL2BlockNumber,
     ) -> anyhow::Result<RpcModule<()>> {
         let namespaces = self.namespaces.clone();
+        let debug_config = self.optional.debug_namespace.clone().unwrap_or_default();
         let zksync_network_id = self.config.l2_chain_id;
         let rpc_state = self.build_rpc_state(last_sealed_l2_block).await?;
 
         // Collect all the methods into a single RPC module.
         let mut rpc = RpcModule::new(());
         if let Some(pub_sub) = pub_sub {
-            rpc.merge(pub_sub.into_rpc())
+            rpc.merge(EthPubSubServer::into_rpc(pub_sub.clone()))
                 .context("cannot merge eth pubsub namespace")?;
+            rpc.merge(ZksPubSubServer::into_rpc(pub_sub))
+                .context("cannot merge zks pubsub namespace")?;
         }
 
         if namespaces.contains(&Namespace::Debug) {
-            rpc.merge(DebugNamespace::new(rpc_state.clone()).await?.into_rpc())
-                .context("cannot merge debug namespace")?;
+            rpc.merge(
+                DebugNamespace::new(rpc_state.clone(), debug_config)
+                    .await?
+                    .into_rpc(),
+            )
+            .context("cannot merge debug namespace")?;
         }
         if namespaces.contains(&Namespace::Eth) {
             rpc.merge(EthNamespace::new(rpc_state.clone()).into_rpc())
@@ -445,6 +537,20 @@ impl ApiServer {
             }
             _ => {}
         }
+        if matches!(&self.transport, ApiTransport::Http(_))
+            && self.optional.subscriptions_per_connection_limit.is_some()
+        {
+            tracing::warn!(
+                "`subscriptions_per_connection_limit` is ignored for HTTP transport, use WebSocket instead"
+            );
+        }
+        if matches!(&self.transport, ApiTransport::WebSocket(_))
+            && self.optional.client_rate_limit.is_some()
+        {
+            tracing::warn!(
+                "Per-client rate limit is ignored for WebSocket transport, use `websocket_requests_per_minute_limit` instead"
+            );
+        }
 
         self.build_jsonrpsee(stop_receiver).await
     }
@@ -485,6 +591,9 @@ impl ApiServer {
             && self.namespaces.contains(&Namespace::Pubsub)
         {
             let mut pub_sub = EthSubscribe::new();
+            if let Some(size) = self.optional.subscription_backlog_size {
+                pub_sub.set_backlog_size(size);
+            }
             if let Some(sender) = &self.optional.pub_sub_events_sender {
                 pub_sub.set_events_sender(sender.clone());
             }
@@ -623,10 +732,17 @@ impl ApiServer {
                 (u32::MAX, MaxResponseSizeOverrides::empty())
             };
         let websocket_requests_per_minute_limit = self.optional.websocket_requests_per_minute_limit;
+        let config_updates = self.optional.config_updates.clone();
         let subscriptions_limit = self.optional.subscriptions_limit;
+        let subscriptions_per_connection_limit = self.optional.subscriptions_per_connection_limit;
         let vm_barrier = self.optional.vm_barrier.clone();
         let health_updater = self.health_updater.clone();
         let method_tracer = self.method_tracer.clone();
+        let method_filter = self.optional.method_filter.clone();
+        let shutdown_drain_timeout = self
+            .optional
+            .shutdown_drain_timeout
+            .unwrap_or(DEFAULT_SHUTDOWN_DRAIN_TIMEOUT);
 
         let extended_tracing = self.optional.extended_tracing;
         if extended_tracing {
@@ -658,10 +774,17 @@ impl ApiServer {
                 future::ready(())
             }),
         );
+        let client_rate_limit = is_http
+            .then_some(self.optional.client_rate_limit.as_ref())
+            .flatten()
+            .map(|rate_limit| ClientRateLimitLayer::new(ClientRateLimiter::spawn(rate_limit)));
         // Assemble server middleware.
         let middleware = tower::ServiceBuilder::new()
             .layer(in_flight_requests)
-            .option_layer(cors);
+            .option_layer(cors)
+            .option_layer(is_http.then_some(TraceContextLayer))
+            // Throttled requests are still wrapped in a span, so that they are visible in traces.
+            .option_layer(client_rate_limit);
 
         // Settings shared by HTTP and WS servers.
         let max_connections = !is_http
@@ -669,7 +792,8 @@ impl ApiServer {
             .flatten()
             .unwrap_or(5_000);
 
-        let metadata_layer = MetadataLayer::new(registered_method_names, method_tracer);
+        let metadata_layer =
+            MetadataLayer::new(registered_method_names, method_tracer, transport_label);
         let metadata_layer = if extended_tracing {
             Either::Left(metadata_layer.with_param_tracing())
         } else {
@@ -684,15 +808,25 @@ impl ApiServer {
             .layer_fn(move |svc| {
                 ShutdownMiddleware::new(svc, traffic_tracker_for_middleware.clone())
             })
+            // Request IDs must be assigned before other middleware so that they can be used in logs etc.
+            .layer_fn(RequestIdMiddleware::new)
             // We want to output method logs with a correlation ID; hence, `CorrelationMiddleware` must precede `metadata_layer`.
             .option_layer(
                 extended_tracing.then(|| tower::layer::layer_fn(CorrelationMiddleware::new)),
             )
             .layer(metadata_layer)
+            // We want to capture calls to filtered methods with `metadata_layer`; hence, `MethodFilterMiddleware` is placed after it.
+            .option_layer(method_filter.map(|filter| {
+                tower::layer::layer_fn(move |svc| MethodFilterMiddleware::new(svc, filter.clone()))
+            }))
             // We want to capture limit middleware errors with `metadata_layer`; hence, `LimitMiddleware` is placed after it.
             .option_layer((!is_http).then(|| {
                 tower::layer::layer_fn(move |svc| {
-                    LimitMiddleware::new(svc, websocket_requests_per_minute_limit)
+                    let limit = match &config_updates {
+                        Some(updates) => updates.borrow().websocket_requests_per_minute_limit,
+                        None => websocket_requests_per_minute_limit,
+                    };
+                    LimitMiddleware::new(svc, limit)
                 })
             }));
 
@@ -713,6 +847,11 @@ impl ApiServer {
             (server.local_addr(), server.start(rpc))
         } else {
             // WS-specific settings
+            let server_builder = if let Some(limit) = subscriptions_per_connection_limit {
+                server_builder.max_subscriptions_per_connection(limit as u32)
+            } else {
+                server_builder
+            };
             let server = server_builder
                 .set_id_provider(EthSubscriptionIdProvider)
                 .build(addr)
@@ -735,6 +874,7 @@ impl ApiServer {
         // TODO (QIT-26): While `Arc<HealthUpdater>` is stored in `self`, we rely on the fact that `self` is consumed and
         // dropped by `self.build_rpc_module` above, so we should still have just one strong reference.
         let closing_health_updater = Arc::downgrade(&health_updater);
+        let (drain_expired_sender, drain_expired_receiver) = oneshot::channel();
         tokio::spawn(async move {
             if stop_receiver.changed().await.is_err() {
                 tracing::warn!(
@@ -746,8 +886,11 @@ impl ApiServer {
                 health_updater.update(HealthStatus::ShuttingDown.into());
             }
             tracing::info!(
-                "Stop signal received, {transport_str} JSON-RPC server is shutting down"
+                "Stop signal received, {transport_str} JSON-RPC server is shutting down; \
+                 drain timeout: {shutdown_drain_timeout:?}"
             );
+            let drain_deadline = tokio::time::Instant::now() + shutdown_drain_timeout;
+            let mut in_flight_calls = traffic_tracker.subscribe_to_in_flight_calls();
 
             // Wait some time until the traffic to the server stops. This may be necessary if the API server
             // is behind a load balancer which is not immediately aware of API server termination. In this case,
@@ -757,25 +900,44 @@ impl ApiServer {
             //
             // If the load balancer *is* aware of the API server termination, we'll wait for `SHUTDOWN_INTERVAL_WITHOUT_REQUESTS`,
             // which is fairly short.
-            let wait_result = tokio::time::timeout(
-                NO_REQUESTS_WAIT_TIMEOUT,
+            let wait_result = tokio::time::timeout_at(
+                drain_deadline,
                 traffic_tracker.wait_for_no_requests(SHUTDOWN_INTERVAL_WITHOUT_REQUESTS),
             )
             .await;
-
             if wait_result.is_err() {
                 tracing::warn!(
-                    "Timed out waiting {NO_REQUESTS_WAIT_TIMEOUT:?} for traffic to be stopped by load balancer"
+                    "Timed out waiting {shutdown_drain_timeout:?} for traffic to be stopped by load balancer"
                 );
             }
+
+            // Stop accepting new connections and requests. Existing connections (including WS sessions)
+            // are closed by the server once their in-flight calls are completed.
             tracing::info!("Stopping serving new {transport_str} traffic");
+            close_handle.stop().ok();
+
+            // The subscription is closed if the server is dropped, which is fine; there are no calls to wait for in this case.
+            let wait_result = tokio::time::timeout_at(drain_deadline, async {
+                in_flight_calls.wait_for(|&count| count == 0).await.ok();
+            })
+            .await;
             if let Some(closing_vm_barrier) = closing_vm_barrier {
                 closing_vm_barrier.close();
             }
-            close_handle.stop().ok();
+            if wait_result.is_err() {
+                tracing::warn!(
+                    "{} in-flight calls to {transport_str} JSON-RPC server were not completed \
+                     in {shutdown_drain_timeout:?}; they will be dropped",
+                    *in_flight_calls.borrow()
+                );
+                drain_expired_sender.send(()).ok();
+            }
         });
 
-        server_handle.stopped().await;
+        tokio::select! {
+            () = server_handle.stopped() => {}
+            Ok(()) = drain_expired_receiver => {}
+        }
         drop(health_updater);
         tracing::info!("{transport_str} JSON-RPC server stopped");
         if let Some(vm_barrier) = vm_barrier {
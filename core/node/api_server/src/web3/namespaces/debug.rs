@@ -1,12 +1,18 @@
 use std::sync::Arc;
 
 use anyhow::Context as _;
-use multivm::{interface::ExecutionResult, vm_latest::constants::BATCH_COMPUTATIONAL_GAS_LIMIT};
+use multivm::{
+    interface::{ExecutionResult, VmExecutionResultAndLogs},
+    vm_latest::constants::BATCH_COMPUTATIONAL_GAS_LIMIT,
+};
 use once_cell::sync::OnceCell;
 use zksync_dal::{CoreDal, DalError};
 use zksync_system_constants::MAX_ENCODED_TX_SIZE;
 use zksync_types::{
-    api::{BlockId, BlockNumber, DebugCall, ResultDebugCall, TracerConfig},
+    api::{
+        state_override::StateOverride, BlockId, BlockNumber, CallOptions, DebugCall,
+        DebugOpcodeTrace, ResultDebugCall, TraceCallManyOptions, TracerConfig,
+    },
     debug_flat_call::{flatten_debug_calls, DebugCallFlat},
     fee_model::BatchFeeInput,
     l2::L2Tx,
@@ -17,20 +23,42 @@ use zksync_types::{
 use zksync_web3_decl::error::Web3Error;
 
 use crate::{
-    execution_sandbox::{ApiTracer, TxSharedArgs},
+    execution_sandbox::{validate_state_override, ApiTracer, TxSharedArgs},
     tx_sender::{ApiContracts, TxSenderConfig},
-    web3::{backend_jsonrpsee::MethodTracer, state::RpcState},
+    web3::{
+        backend_jsonrpsee::MethodTracer, namespaces::eth::parse_protocol_version, state::RpcState,
+    },
 };
 
+/// Maximum number of calls that can be executed in a single `debug_traceCallMany` request.
+const MAX_CALLS_IN_BUNDLE: usize = 100;
+
+/// Configuration of the `debug` namespace.
+#[derive(Debug, Clone)]
+pub struct DebugNamespaceConfig {
+    /// Maximum number of opcodes recorded by `debug_traceCall.opcodeTracer`. Opcodes executed after
+    /// the limit is reached are not recorded, and the trace is marked as truncated.
+    pub max_opcode_trace_steps: usize,
+}
+
+impl Default for DebugNamespaceConfig {
+    fn default() -> Self {
+        Self {
+            max_opcode_trace_steps: 100_000,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub(crate) struct DebugNamespace {
     batch_fee_input: BatchFeeInput,
     state: RpcState,
     api_contracts: ApiContracts,
+    config: DebugNamespaceConfig,
 }
 
 impl DebugNamespace {
-    pub async fn new(state: RpcState) -> anyhow::Result<Self> {
+    pub async fn new(state: RpcState, config: DebugNamespaceConfig) -> anyhow::Result<Self> {
         let api_contracts = ApiContracts::load_from_disk().await?;
         let fee_input_provider = &state.tx_sender.0.batch_fee_input_provider;
         let batch_fee_input = fee_input_provider
@@ -46,6 +74,7 @@ impl DebugNamespace {
             batch_fee_input,
             state,
             api_contracts,
+            config,
         })
     }
 
@@ -129,16 +158,111 @@ impl DebugNamespace {
 
     pub async fn debug_trace_call_impl(
         &self,
-        mut request: CallRequest,
+        request: CallRequest,
         block_id: Option<BlockId>,
         options: Option<TracerConfig>,
     ) -> Result<DebugCall, Web3Error> {
-        let block_id = block_id.unwrap_or(BlockId::Number(BlockNumber::Pending));
-        self.current_method().set_block_id(block_id);
-
         let only_top_call = options
+            .as_ref()
             .map(|options| options.tracer_config.only_top_call)
             .unwrap_or(false);
+        let protocol_version = options.and_then(|options| options.protocol_version);
+
+        // We don't need properly trace if we only need top call
+        let call_tracer_result = Arc::new(OnceCell::default());
+        let custom_tracers = if only_top_call {
+            vec![]
+        } else {
+            vec![ApiTracer::CallTracer(call_tracer_result.clone())]
+        };
+        let (tx, result) = self
+            .execute_traced_call(request, block_id, protocol_version, None, custom_tracers)
+            .await?;
+
+        let (output, revert_reason) = match result.result {
+            ExecutionResult::Success { output, .. } => (output, None),
+            ExecutionResult::Revert { output } => (vec![], Some(output.to_string())),
+            ExecutionResult::Halt { reason } => {
+                return Err(Web3Error::SubmitTransactionError(
+                    reason.to_string(),
+                    vec![],
+                ))
+            }
+        };
+
+        // We had only one copy of Arc this arc is already dropped it's safe to unwrap
+        let trace = Arc::try_unwrap(call_tracer_result)
+            .unwrap()
+            .take()
+            .unwrap_or_default();
+        let call = Call::new_high_level(
+            tx.common_data.fee.gas_limit.as_u64(),
+            result.statistics.gas_used,
+            tx.execute.value,
+            tx.execute.calldata,
+            output,
+            revert_reason,
+            trace,
+        );
+        Ok(call.into())
+    }
+
+    pub async fn debug_trace_call_opcodes_impl(
+        &self,
+        request: CallRequest,
+        block_id: Option<BlockId>,
+        options: Option<CallOptions>,
+    ) -> Result<DebugOpcodeTrace, Web3Error> {
+        let options = options.unwrap_or_default();
+        let opcode_tracer_result = Arc::new(OnceCell::default());
+        let custom_tracers = vec![ApiTracer::OpcodeTracer(
+            self.config.max_opcode_trace_steps,
+            opcode_tracer_result.clone(),
+        )];
+        let (_, result) = self
+            .execute_traced_call(
+                request,
+                block_id,
+                options.protocol_version,
+                options.state_override,
+                custom_tracers,
+            )
+            .await?;
+
+        let (failed, return_value) = match result.result {
+            ExecutionResult::Success { output, .. } => (false, output),
+            ExecutionResult::Revert { output } => (true, output.encoded_data()),
+            ExecutionResult::Halt { .. } => (true, vec![]),
+        };
+        // All tracers are dropped after execution, so it's safe to unwrap
+        let trace = Arc::try_unwrap(opcode_tracer_result)
+            .unwrap()
+            .take()
+            .unwrap_or_default();
+        Ok(DebugOpcodeTrace {
+            gas: result.statistics.gas_used.into(),
+            failed,
+            return_value: return_value.into(),
+            truncated: trace.truncated,
+            struct_logs: trace.steps,
+        })
+    }
+
+    /// Executes a call with the specified tracers. Returns the executed transaction together with the execution result.
+    async fn execute_traced_call(
+        &self,
+        mut request: CallRequest,
+        block_id: Option<BlockId>,
+        protocol_version: Option<u16>,
+        state_override: Option<StateOverride>,
+        custom_tracers: Vec<ApiTracer>,
+    ) -> Result<(L2Tx, VmExecutionResultAndLogs), Web3Error> {
+        let block_id = block_id.unwrap_or(BlockId::Number(BlockNumber::Pending));
+        self.current_method().set_block_id(block_id);
+        let protocol_version = parse_protocol_version(protocol_version)?;
+        if let Some(state_override) = &state_override {
+            validate_state_override(state_override).map_err(Web3Error::InvalidStateOverride)?;
+        }
 
         let mut connection = self.state.acquire_connection().await?;
         let block_args = self
@@ -164,7 +288,8 @@ impl DebugNamespace {
             )
         }
 
-        let call_overrides = request.get_call_overrides()?;
+        let mut call_overrides = request.get_call_overrides()?;
+        call_overrides.protocol_version = protocol_version;
         let tx = L2Tx::from_request(request.into(), MAX_ENCODED_TX_SIZE)?;
 
         let shared_args = self.shared_args().await;
@@ -176,14 +301,6 @@ impl DebugNamespace {
             .await;
         let vm_permit = vm_permit.context("cannot acquire VM permit")?;
 
-        // We don't need properly trace if we only need top call
-        let call_tracer_result = Arc::new(OnceCell::default());
-        let custom_tracers = if only_top_call {
-            vec![]
-        } else {
-            vec![ApiTracer::CallTracer(call_tracer_result.clone())]
-        };
-
         let executor = &self.state.tx_sender.0.executor;
         let result = executor
             .execute_tx_eth_call(
@@ -195,35 +312,144 @@ impl DebugNamespace {
                 block_args,
                 self.sender_config().vm_execution_cache_misses_limit,
                 custom_tracers,
+                state_override,
             )
             .await?;
+        Ok((tx, result))
+    }
 
-        let (output, revert_reason) = match result.result {
-            ExecutionResult::Success { output, .. } => (output, None),
-            ExecutionResult::Revert { output } => (vec![], Some(output.to_string())),
-            ExecutionResult::Halt { reason } => {
-                return Err(Web3Error::SubmitTransactionError(
-                    reason.to_string(),
-                    vec![],
-                ))
-            }
+    pub async fn debug_trace_call_many_impl(
+        &self,
+        requests: Vec<CallRequest>,
+        block_id: Option<BlockId>,
+        options: Option<TraceCallManyOptions>,
+    ) -> Result<Vec<DebugCall>, Web3Error> {
+        let block_id = block_id.unwrap_or(BlockId::Number(BlockNumber::Pending));
+        self.current_method().set_block_id(block_id);
+        if requests.len() > MAX_CALLS_IN_BUNDLE {
+            return Err(Web3Error::TooManyCalls(MAX_CALLS_IN_BUNDLE));
+        }
+
+        let options = options.unwrap_or_default();
+        let only_top_call = options.tracer_config.only_top_call;
+        if let Some(state_override) = &options.state_override {
+            validate_state_override(state_override).map_err(Web3Error::InvalidStateOverride)?;
+        }
+
+        let mut connection = self.state.acquire_connection().await?;
+        let block_args = self
+            .state
+            .resolve_block_args(&mut connection, block_id)
+            .await?;
+        drop(connection);
+
+        self.current_method().set_block_diff(
+            self.state
+                .last_sealed_l2_block
+                .diff_with_block_args(&block_args),
+        );
+
+        // The base fee is shared by all calls in the bundle, so it's taken from the first call
+        // unless overridden explicitly.
+        let call_overrides = match requests.first() {
+            Some(request) => request.get_call_overrides()?,
+            None => return Ok(vec![]),
         };
+        let mut default_gas = None;
+        let mut txs = Vec::with_capacity(requests.len());
+        for mut request in requests {
+            if request.gas.is_none() {
+                if default_gas.is_none() {
+                    let gas = self
+                        .state
+                        .tx_sender
+                        .get_default_eth_call_gas(block_args)
+                        .await
+                        .map_err(Web3Error::InternalError)?;
+                    default_gas = Some(gas);
+                }
+                request.gas = default_gas.map(Into::into);
+            }
+            txs.push(L2Tx::from_request(request.into(), MAX_ENCODED_TX_SIZE)?);
+        }
 
-        // We had only one copy of Arc this arc is already dropped it's safe to unwrap
-        let trace = Arc::try_unwrap(call_tracer_result)
-            .unwrap()
-            .take()
-            .unwrap_or_default();
-        let call = Call::new_high_level(
-            tx.common_data.fee.gas_limit.as_u64(),
-            result.statistics.gas_used,
-            tx.execute.value,
-            tx.execute.calldata,
-            output,
-            revert_reason,
-            trace,
+        let call_tracer_results: Vec<_> =
+            txs.iter().map(|_| Arc::new(OnceCell::default())).collect();
+        let custom_tracers = call_tracer_results
+            .iter()
+            .map(|call_tracer_result| {
+                // We don't need properly trace if we only need top call
+                if only_top_call {
+                    vec![]
+                } else {
+                    vec![ApiTracer::CallTracer(call_tracer_result.clone())]
+                }
+            })
+            .collect();
+
+        let shared_args = self.shared_args().await;
+        let vm_permit = self
+            .state
+            .tx_sender
+            .vm_concurrency_limiter()
+            .acquire()
+            .await;
+        let vm_permit = vm_permit.context("cannot acquire VM permit")?;
+
+        // Only the gas limit, value and calldata of each call are needed to build the response,
+        // so the transactions themselves can be moved into the executor.
+        let call_params: Vec<_> = txs
+            .iter()
+            .map(|tx| {
+                let gas_limit = tx.common_data.fee.gas_limit.as_u64();
+                (gas_limit, tx.execute.value, tx.execute.calldata.clone())
+            })
+            .collect();
+        let executor = &self.state.tx_sender.0.executor;
+        let results = executor
+            .execute_tx_bundle_eth_call(
+                vm_permit,
+                shared_args,
+                self.state.connection_pool.clone(),
+                call_overrides,
+                txs,
+                block_args,
+                self.sender_config().vm_execution_cache_misses_limit,
+                custom_tracers,
+                options.state_override,
+                options.block_override,
+            )
+            .await?;
+
+        let calls = call_params
+            .into_iter()
+            .zip(results)
+            .zip(call_tracer_results);
+        let calls = calls.map(
+            |(((gas_limit, value, calldata), result), call_tracer_result)| {
+                let (output, revert_reason) = match result.result {
+                    ExecutionResult::Success { output, .. } => (output, None),
+                    ExecutionResult::Revert { output } => (vec![], Some(output.to_string())),
+                    ExecutionResult::Halt { reason } => (vec![], Some(reason.to_string())),
+                };
+                // All tracers are dropped after execution, so it's safe to unwrap
+                let trace = Arc::try_unwrap(call_tracer_result)
+                    .unwrap()
+                    .take()
+                    .unwrap_or_default();
+                let call = Call::new_high_level(
+                    gas_limit,
+                    result.statistics.gas_used,
+                    value,
+                    calldata,
+                    output,
+                    revert_reason,
+                    trace,
+                );
+                call.into()
+            },
         );
-        Ok(call.into())
+        Ok(calls.collect())
     }
 
     async fn shared_args(&self) -> TxSharedArgs {
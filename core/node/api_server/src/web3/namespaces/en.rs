@@ -1,5 +1,6 @@
 use anyhow::Context as _;
 use zksync_config::{configs::EcosystemContracts, GenesisConfig};
+use zksync_consensus_roles::validator;
 use zksync_dal::{CoreDal, DalError};
 use zksync_types::{
     api::en, protocol_version::ProtocolSemanticVersion, tokens::TokenInfo, Address, L1BatchNumber,
@@ -36,6 +37,24 @@ impl EnNamespace {
         )))
     }
 
+    pub async fn block_certificate_impl(
+        &self,
+        block_number: L2BlockNumber,
+    ) -> Result<Option<en::BlockCertificate>, Web3Error> {
+        let mut storage = self.state.acquire_connection().await?;
+        let Some(cert) = storage
+            .consensus_dal()
+            .certificate(validator::BlockNumber(block_number.0.into()))
+            .await
+            .map_err(DalError::generalize)?
+        else {
+            return Ok(None);
+        };
+        Ok(Some(en::BlockCertificate(
+            zksync_protobuf::serde::serialize(&cert, serde_json::value::Serializer).unwrap(),
+        )))
+    }
+
     pub(crate) fn current_method(&self) -> &MethodTracer {
         &self.state.current_method
     }
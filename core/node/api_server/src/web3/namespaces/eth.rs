@@ -1,24 +1,26 @@
 use anyhow::Context as _;
-use zksync_dal::{CoreDal, DalError};
+use zksync_dal::{Connection, Core, CoreDal, DalError};
 use zksync_system_constants::DEFAULT_L2_TX_GAS_PER_PUBDATA_BYTE;
 use zksync_types::{
     api::{
-        BlockId, BlockNumber, GetLogsFilter, Transaction, TransactionId, TransactionReceipt,
+        BlockId, BlockNumber, CallOptions, Transaction, TransactionId, TransactionReceipt,
         TransactionVariant,
     },
+    get_code_key,
     l2::{L2Tx, TransactionType},
     transaction_request::CallRequest,
     utils::decompose_full_nonce,
     web3::{self, Bytes, FeeHistory, SyncInfo, SyncState},
-    AccountTreeId, L2BlockNumber, StorageKey, H256, L2_BASE_TOKEN_ADDRESS, U256,
+    AccountTreeId, L2BlockNumber, ProtocolVersionId, StorageKey, H256, L2_BASE_TOKEN_ADDRESS, U256,
 };
-use zksync_utils::u256_to_h256;
+use zksync_utils::{bytecode::BytecodeMarker, u256_to_h256};
 use zksync_web3_decl::{
     error::Web3Error,
     types::{Address, Block, Filter, FilterChanges, Log, U64},
 };
 
 use crate::{
+    execution_sandbox::validate_state_override,
     utils::open_readonly_transaction,
     web3::{backend_jsonrpsee::MethodTracer, metrics::API_METRICS, state::RpcState, TypedFilter},
 };
@@ -26,6 +28,67 @@ use crate::{
 pub const EVENT_TOPIC_NUMBER_LIMIT: usize = 4;
 pub const PROTOCOL_VERSION: &str = "zks/1";
 
+/// Checks that `eth_feeHistory` reward percentiles are within `[0, 100]` and are monotonically increasing.
+fn validate_reward_percentiles(reward_percentiles: &[f32]) -> Result<(), Web3Error> {
+    for (i, &percentile) in reward_percentiles.iter().enumerate() {
+        if !(0.0..=100.0).contains(&percentile) {
+            return Err(Web3Error::InvalidRewardPercentiles(format!(
+                "percentile {percentile} is not in [0, 100]"
+            )));
+        }
+        if i > 0 && percentile < reward_percentiles[i - 1] {
+            return Err(Web3Error::InvalidRewardPercentiles(format!(
+                "percentile {percentile} is less than the preceding percentile {}",
+                reward_percentiles[i - 1]
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Computes reward percentiles for a block the same way as Geth does: transactions are sorted by their effective
+/// priority fee, and each percentile is the priority fee of the transaction at which the cumulative gas used reaches
+/// the percentile of the total gas used by the block transactions. `transactions` are `(priority_fee, gas_used)` tuples.
+pub(crate) fn compute_block_rewards(
+    transactions: &mut [(U256, u64)],
+    reward_percentiles: &[f32],
+) -> Vec<U256> {
+    if transactions.is_empty() {
+        return vec![U256::zero(); reward_percentiles.len()];
+    }
+    transactions.sort_unstable_by_key(|&(priority_fee, _)| priority_fee);
+    let total_gas_used: u64 = transactions.iter().map(|&(_, gas_used)| gas_used).sum();
+
+    let mut tx_index = 0;
+    let mut cumulative_gas_used = transactions[0].1;
+    reward_percentiles
+        .iter()
+        .map(|&percentile| {
+            let threshold = (total_gas_used as f64 * f64::from(percentile) / 100.0) as u64;
+            while cumulative_gas_used < threshold && tx_index < transactions.len() - 1 {
+                tx_index += 1;
+                cumulative_gas_used += transactions[tx_index].1;
+            }
+            transactions[tx_index].0
+        })
+        .collect()
+}
+
+/// Parses a protocol version override for VM simulations. Versions newer than the latest supported one are rejected.
+pub(crate) fn parse_protocol_version(
+    version: Option<u16>,
+) -> Result<Option<ProtocolVersionId>, Web3Error> {
+    let Some(version) = version else {
+        return Ok(None);
+    };
+    match ProtocolVersionId::try_from(version) {
+        Ok(protocol_version) if protocol_version <= ProtocolVersionId::latest() => {
+            Ok(Some(protocol_version))
+        }
+        _ => Err(Web3Error::UnsupportedProtocolVersion(version)),
+    }
+}
+
 #[derive(Debug)]
 pub(crate) struct EthNamespace {
     state: RpcState,
@@ -55,6 +118,7 @@ impl EthNamespace {
         &self,
         mut request: CallRequest,
         block_id: Option<BlockId>,
+        options: Option<CallOptions>,
     ) -> Result<Bytes, Web3Error> {
         let block_id = block_id.unwrap_or(BlockId::Number(BlockNumber::Pending));
         self.current_method().set_block_id(block_id);
@@ -81,14 +145,19 @@ impl EthNamespace {
                     .into(),
             )
         }
-        let call_overrides = request.get_call_overrides()?;
+        let options = options.unwrap_or_default();
+        if let Some(state_override) = &options.state_override {
+            validate_state_override(state_override).map_err(Web3Error::InvalidStateOverride)?;
+        }
+        let mut call_overrides = request.get_call_overrides()?;
+        call_overrides.protocol_version = parse_protocol_version(options.protocol_version)?;
         let tx = L2Tx::from_request(request.into(), self.state.api_config.max_tx_size)?;
 
         // It is assumed that the previous checks has already enforced that the `max_fee_per_gas` is at most u64.
         let call_result: Vec<u8> = self
             .state
             .tx_sender
-            .eth_call(block_args, call_overrides, tx)
+            .eth_call(block_args, call_overrides, tx, options.state_override)
             .await?;
         Ok(call_result.into())
     }
@@ -181,6 +250,12 @@ impl EthNamespace {
     pub async fn get_logs_impl(&self, mut filter: Filter) -> Result<Vec<Log>, Web3Error> {
         self.state.resolve_filter_block_hash(&mut filter).await?;
         let (from_block, to_block) = self.state.resolve_filter_block_range(&filter).await?;
+        if let Some(max_range) = self.state.api_config.max_logs_block_range {
+            // The range is inclusive; an empty range (`from_block > to_block`) is always allowed.
+            if to_block.0.saturating_sub(from_block.0) >= max_range {
+                return Err(Web3Error::LogsBlockRangeExceeded(max_range));
+            }
+        }
 
         filter.to_block = Some(BlockNumber::Number(to_block.0.into()));
         let changes = self
@@ -365,9 +440,37 @@ impl EthNamespace {
             .await
             .with_context(|| format!("get_transaction_receipts({block_number})"))?;
         receipts.sort_unstable_by_key(|receipt| receipt.transaction_index);
+        self.set_contract_bytecode_kinds(&mut storage, &mut receipts)
+            .await?;
         Ok(Some(receipts))
     }
 
+    /// Marks contracts deployed by the receipted transactions with their bytecode kind. This is only performed
+    /// if the EVM emulator is enabled; otherwise, all deployed contracts are EraVM ones.
+    async fn set_contract_bytecode_kinds(
+        &self,
+        storage: &mut Connection<'_, Core>,
+        receipts: &mut [TransactionReceipt],
+    ) -> Result<(), Web3Error> {
+        if !self.state.api_config.evm_emulator_enabled {
+            return Ok(());
+        }
+
+        for receipt in receipts {
+            let Some(contract_address) = receipt.contract_address else {
+                continue;
+            };
+            let block_number = L2BlockNumber(receipt.block_number.as_u32());
+            let code_hash = storage
+                .storage_web3_dal()
+                .get_historical_value_unchecked(&get_code_key(&contract_address), block_number)
+                .await
+                .map_err(DalError::generalize)?;
+            receipt.contract_bytecode_kind = BytecodeMarker::new(code_hash);
+        }
+        Ok(())
+    }
+
     pub async fn get_code_impl(
         &self,
         address: Address,
@@ -511,11 +614,13 @@ impl EthNamespace {
         hash: H256,
     ) -> Result<Option<TransactionReceipt>, Web3Error> {
         let mut storage = self.state.acquire_connection().await?;
-        let receipts = storage
+        let mut receipts = storage
             .transactions_web3_dal()
             .get_transaction_receipts(&[hash])
             .await
             .context("get_transaction_receipts")?;
+        self.set_contract_bytecode_kinds(&mut storage, &mut receipts)
+            .await?;
         Ok(receipts.into_iter().next())
     }
 
@@ -657,6 +762,7 @@ impl EthNamespace {
     ) -> Result<FeeHistory, Web3Error> {
         self.current_method()
             .set_block_id(BlockId::Number(newest_block));
+        validate_reward_percentiles(&reward_percentiles)?;
 
         // Limit `block_count`.
         let block_count = block_count
@@ -671,23 +777,50 @@ impl EthNamespace {
             .await?;
         self.set_block_diff(newest_l2_block);
 
-        let mut base_fee_per_gas = connection
+        let mut blocks = connection
             .blocks_web3_dal()
             .get_fee_history(newest_l2_block, block_count)
             .await
             .map_err(DalError::generalize)?;
-        // DAL method returns fees in DESC order while we need ASC.
-        base_fee_per_gas.reverse();
-
-        let oldest_block = newest_l2_block.0 + 1 - base_fee_per_gas.len() as u32;
-        // We do not store gas used ratio for blocks, returns array of zeroes as a placeholder.
-        let gas_used_ratio = vec![0.0; base_fee_per_gas.len()];
-        // Effective priority gas price is currently 0.
-        let reward = Some(vec![
-            vec![U256::zero(); reward_percentiles.len()];
-            base_fee_per_gas.len()
-        ]);
+        // DAL method returns blocks in DESC order while we need ASC.
+        blocks.reverse();
+        let oldest_block = newest_l2_block.0 + 1 - blocks.len() as u32;
+
+        let transactions = connection
+            .blocks_web3_dal()
+            .get_fee_history_transactions(L2BlockNumber(oldest_block)..=newest_l2_block)
+            .await
+            .map_err(DalError::generalize)?;
+        // `(priority_fee, gas_used)` tuples for each block.
+        let mut block_transactions = vec![vec![]; blocks.len()];
+        for tx in transactions {
+            let block_idx = (tx.block_number.0 - oldest_block) as usize;
+            let base_fee = blocks[block_idx].base_fee_per_gas;
+            let priority_fee = tx.effective_gas_price.saturating_sub(base_fee);
+            block_transactions[block_idx].push((priority_fee, tx.gas_used));
+        }
 
+        let gas_used_ratio = blocks
+            .iter()
+            .zip(&block_transactions)
+            .map(|(block, transactions)| {
+                let gas_used: u64 = transactions.iter().map(|&(_, gas_used)| gas_used).sum();
+                if block.gas_limit == 0 {
+                    0.0
+                } else {
+                    (gas_used as f64 / block.gas_limit as f64).min(1.0)
+                }
+            })
+            .collect();
+        let reward = (!reward_percentiles.is_empty()).then(|| {
+            block_transactions
+                .iter_mut()
+                .map(|transactions| compute_block_rewards(transactions, &reward_percentiles))
+                .collect()
+        });
+
+        let mut base_fee_per_gas: Vec<_> =
+            blocks.iter().map(|block| block.base_fee_per_gas).collect();
         // `base_fee_per_gas` for next L2 block cannot be calculated, appending last fee as a placeholder.
         base_fee_per_gas.push(*base_fee_per_gas.last().unwrap());
         Ok(FeeHistory {
@@ -753,42 +886,11 @@ impl EthNamespace {
             }
 
             TypedFilter::Events(filter, from_block) => {
-                let addresses = if let Some(addresses) = &filter.address {
-                    addresses.0.clone()
-                } else {
-                    vec![]
-                };
-                let topics = if let Some(topics) = &filter.topics {
-                    if topics.len() > EVENT_TOPIC_NUMBER_LIMIT {
-                        return Err(Web3Error::TooManyTopics);
-                    }
-                    let topics_by_idx = topics.iter().enumerate().filter_map(|(idx, topics)| {
-                        Some((idx as u32 + 1, topics.as_ref()?.0.clone()))
-                    });
-                    topics_by_idx.collect::<Vec<_>>()
-                } else {
-                    vec![]
-                };
-
-                let mut to_block = self
+                let get_logs_filter = self
                     .state
-                    .resolve_filter_block_number(filter.to_block)
+                    .resolve_get_logs_filter(filter, *from_block)
                     .await?;
-
-                if matches!(filter.to_block, Some(BlockNumber::Number(_))) {
-                    to_block = to_block.min(
-                        self.state
-                            .resolve_filter_block_number(Some(BlockNumber::Latest))
-                            .await?,
-                    );
-                }
-
-                let get_logs_filter = GetLogsFilter {
-                    from_block: *from_block,
-                    to_block,
-                    addresses,
-                    topics,
-                };
+                let to_block = get_logs_filter.to_block;
 
                 let mut storage = self.state.acquire_connection().await?;
 
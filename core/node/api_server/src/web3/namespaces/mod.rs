@@ -9,6 +9,7 @@ mod snapshots;
 mod web3;
 mod zks;
 
+pub use self::debug::DebugNamespaceConfig;
 pub(super) use self::{
     debug::DebugNamespace, en::EnNamespace, eth::EthNamespace, net::NetNamespace,
     snapshots::SnapshotsNamespace, web3::Web3Namespace, zks::ZksNamespace,
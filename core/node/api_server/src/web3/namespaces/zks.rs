@@ -1,15 +1,17 @@
 use std::{collections::HashMap, convert::TryInto};
 
 use anyhow::Context as _;
-use multivm::interface::VmExecutionResultAndLogs;
+use multivm::interface::{ExecutionResult, VmExecutionResultAndLogs};
 use zksync_dal::{Connection, Core, CoreDal, DalError};
 use zksync_metadata_calculator::api_server::TreeApiError;
 use zksync_mini_merkle_tree::MiniMerkleTree;
 use zksync_system_constants::DEFAULT_L2_TX_GAS_PER_PUBDATA_BYTE;
 use zksync_types::{
     api::{
-        BlockDetails, BridgeAddresses, GetLogsFilter, L1BatchDetails, L2ToL1LogProof, Proof,
-        ProtocolVersion, StorageProof, TransactionDetails,
+        state_override::StateOverride, BlockDetails, BlockId, BlockNumber, BridgeAddresses,
+        GetLogsFilter, L1BatchDetails, L2ToL1LogProof, Log, LogsCursor, LogsPage, Proof,
+        ProtocolVersion, SimulatedGasBreakdown, StorageProof, TransactionDetails,
+        TransactionSimulationResult,
     },
     fee::Fee,
     fee_model::{FeeParams, PubdataIndependentBatchFeeModelInput},
@@ -26,10 +28,11 @@ use zksync_types::{
 use zksync_utils::{address_to_h256, h256_to_u256};
 use zksync_web3_decl::{
     error::Web3Error,
-    types::{Address, Token, H256},
+    types::{Address, Filter, Token, H256},
 };
 
 use crate::{
+    execution_sandbox::validate_state_override,
     utils::open_readonly_transaction,
     web3::{backend_jsonrpsee::MethodTracer, metrics::API_METRICS, RpcState},
 };
@@ -437,6 +440,73 @@ impl ZksNamespace {
             .map_err(DalError::generalize)?)
     }
 
+    pub async fn get_l1_batch_pubdata_impl(
+        &self,
+        batch_number: L1BatchNumber,
+    ) -> Result<Option<Bytes>, Web3Error> {
+        let mut storage = self.state.acquire_connection().await?;
+        self.state
+            .start_info
+            .ensure_not_pruned(batch_number, &mut storage)
+            .await?;
+
+        let pubdata = storage
+            .blocks_dal()
+            .get_l1_batch_pubdata(batch_number)
+            .await
+            .map_err(DalError::generalize)?;
+        Ok(pubdata.map(Bytes))
+    }
+
+    pub async fn get_logs_paginated_impl(
+        &self,
+        mut filter: Filter,
+        cursor: Option<LogsCursor>,
+        limit: Option<u32>,
+    ) -> Result<LogsPage, Web3Error> {
+        self.state.resolve_filter_block_hash(&mut filter).await?;
+        let from_block = self
+            .state
+            .resolve_filter_block_number(filter.from_block)
+            .await?;
+        let get_logs_filter = self
+            .state
+            .resolve_get_logs_filter(&filter, from_block)
+            .await?;
+        let max_limit = self.state.api_config.req_entities_limit;
+        let limit = limit
+            .map_or(max_limit, |limit| (limit as usize).min(max_limit))
+            .max(1);
+        let after = cursor.map(|cursor| {
+            let log_index = cursor.log_index.min(u32::MAX.into()).as_u32();
+            (
+                RpcState::u64_to_block_number(cursor.block_number),
+                log_index,
+            )
+        });
+
+        let mut storage = self.state.acquire_connection().await?;
+        // Fetch an extra log to check whether there are more logs after the page.
+        let mut logs = storage
+            .events_web3_dal()
+            .get_logs_after(get_logs_filter, after, limit + 1)
+            .await
+            .map_err(DalError::generalize)?;
+        let next_cursor = if logs.len() > limit {
+            logs.truncate(limit);
+            // `unwrap()`s are safe: the page is non-empty, and logs loaded from storage always have
+            // block numbers and indices.
+            let last_log = logs.last().unwrap();
+            Some(LogsCursor {
+                block_number: last_log.block_number.unwrap(),
+                log_index: last_log.log_index.unwrap().as_u64().into(),
+            })
+        } else {
+            None
+        };
+        Ok(LogsPage { logs, next_cursor })
+    }
+
     pub async fn get_bytecode_by_hash_impl(
         &self,
         hash: H256,
@@ -576,4 +646,69 @@ impl ZksNamespace {
             err.into()
         })
     }
+
+    pub async fn simulate_transaction_impl(
+        &self,
+        mut request: CallRequest,
+        block_id: Option<BlockId>,
+        state_override: Option<StateOverride>,
+    ) -> Result<TransactionSimulationResult, Web3Error> {
+        let block_id = block_id.unwrap_or(BlockId::Number(BlockNumber::Pending));
+        self.current_method().set_block_id(block_id);
+        if let Some(state_override) = &state_override {
+            validate_state_override(state_override).map_err(Web3Error::InvalidStateOverride)?;
+        }
+
+        let mut connection = self.state.acquire_connection().await?;
+        let block_args = self
+            .state
+            .resolve_block_args(&mut connection, block_id)
+            .await?;
+        self.current_method().set_block_diff(
+            self.state
+                .last_sealed_l2_block
+                .diff_with_block_args(&block_args),
+        );
+        drop(connection);
+
+        if request.gas.is_none() {
+            request.gas = Some(
+                self.state
+                    .tx_sender
+                    .get_default_eth_call_gas(block_args)
+                    .await
+                    .map_err(Web3Error::InternalError)?
+                    .into(),
+            )
+        }
+        let call_overrides = request.get_call_overrides()?;
+        let tx = L2Tx::from_request(request.into(), self.state.api_config.max_tx_size)?;
+        let gas_limit = tx.common_data.fee.gas_limit;
+
+        let result = self
+            .state
+            .tx_sender
+            .simulate_call(block_args, call_overrides, tx, state_override)
+            .await?;
+        let (success, output, revert_reason) = match result.result {
+            ExecutionResult::Success { output } => (true, output, None),
+            ExecutionResult::Revert { output } => {
+                (false, output.encoded_data(), Some(output.to_string()))
+            }
+            ExecutionResult::Halt { reason } => (false, vec![], Some(reason.to_string())),
+        };
+        Ok(TransactionSimulationResult {
+            success,
+            output: output.into(),
+            revert_reason,
+            gas: SimulatedGasBreakdown {
+                gas_limit,
+                gas_used: result.statistics.gas_used.into(),
+                computational_gas_used: result.statistics.computational_gas_used.into(),
+                pubdata_published: result.statistics.pubdata_published.into(),
+                gas_refunded: result.refunds.gas_refunded.into(),
+            },
+            events: result.logs.events.iter().map(Log::from).collect(),
+        })
+    }
 }
@@ -1,5 +1,10 @@
 //! (Largely) backend-agnostic logic for dealing with Web3 subscriptions.
 
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, Mutex, MutexGuard},
+};
+
 use chrono::NaiveDateTime;
 use futures::FutureExt;
 use tokio::{
@@ -9,7 +14,7 @@ use tokio::{
 };
 use tracing::Instrument as _;
 use zksync_dal::{ConnectionPool, Core, CoreDal};
-use zksync_types::{L2BlockNumber, H128, H256};
+use zksync_types::{L2BlockNumber, H128, H2048, H256};
 use zksync_web3_decl::{
     jsonrpsee::{
         core::{server::SubscriptionMessage, SubscriptionResult},
@@ -17,7 +22,7 @@ use zksync_web3_decl::{
         types::{error::ErrorCode, ErrorObject, SubscriptionId},
         PendingSubscriptionSink, SendTimeoutError, SubscriptionSink,
     },
-    namespaces::EthPubSubServer,
+    namespaces::{EthPubSubServer, ZksPubSubServer},
     types::{BlockHeader, Log, PubSubFilter, PubSubResult},
 };
 
@@ -28,6 +33,7 @@ use super::{
 
 const BROADCAST_CHANNEL_CAPACITY: usize = 1024;
 const SUBSCRIPTION_SINK_SEND_TIMEOUT: Duration = Duration::from_secs(1);
+const INVALID_PARAMS_MESSAGE: &str = "Rejecting subscription - invalid parameters provided.";
 
 #[derive(Debug, Clone, Copy)]
 pub struct EthSubscriptionIdProvider;
@@ -43,14 +49,219 @@ impl IdProvider for EthSubscriptionIdProvider {
 #[derive(Debug)]
 pub enum PubSubEvent {
     Subscribed(SubscriptionType),
+    /// Subscription was closed and can be resumed with `zks_resubscribe`.
+    Detached(SubscriptionType),
     NotifyIterationFinished(SubscriptionType),
     L2BlockAdvanced(SubscriptionType, L2BlockNumber),
 }
 
+/// Notification together with its sequence number among notifications of the same subscription type.
+#[derive(Debug, Clone)]
+struct Notification {
+    seq: u64,
+    result: PubSubResult,
+}
+
+/// Recent notifications of a certain subscription type retained in order to resume subscriptions.
+#[derive(Debug, Default)]
+struct NotificationBacklog {
+    capacity: usize,
+    next_seq: u64,
+    items: VecDeque<Notification>,
+}
+
+impl NotificationBacklog {
+    /// Returns the sequence number of the earliest retained notification.
+    fn first_seq(&self) -> u64 {
+        self.next_seq - self.items.len() as u64
+    }
+
+    fn push(&mut self, results: Vec<PubSubResult>) -> Vec<Notification> {
+        let notifications: Vec<_> = results
+            .into_iter()
+            .map(|result| {
+                let seq = self.next_seq;
+                self.next_seq += 1;
+                Notification { seq, result }
+            })
+            .collect();
+
+        let skipped_count = notifications.len().saturating_sub(self.capacity);
+        self.items
+            .extend(notifications[skipped_count..].iter().cloned());
+        let evicted_count = self.items.len().saturating_sub(self.capacity);
+        self.items.drain(..evicted_count);
+        notifications
+    }
+
+    /// Returns all notifications starting from the specified sequence number, or `None` if some of them
+    /// were evicted from the backlog.
+    fn notifications_since(&self, seq: u64) -> Option<Vec<Notification>> {
+        let first_seq = self.first_seq();
+        if seq < first_seq {
+            return None;
+        }
+        let skipped_count = usize::try_from(seq - first_seq).unwrap_or(usize::MAX);
+        Some(self.items.iter().skip(skipped_count).cloned().collect())
+    }
+}
+
+/// Broadcast channel for notifications of a certain type coupled with the backlog of recent notifications.
+#[derive(Debug, Clone)]
+struct NotificationChannel {
+    sender: broadcast::Sender<Vec<Notification>>,
+    backlog: Arc<Mutex<NotificationBacklog>>,
+}
+
+impl NotificationChannel {
+    fn new() -> Self {
+        let (sender, _) = broadcast::channel(BROADCAST_CHANNEL_CAPACITY);
+        Self {
+            sender,
+            backlog: Arc::default(),
+        }
+    }
+
+    fn backlog(&self) -> MutexGuard<'_, NotificationBacklog> {
+        self.backlog
+            .lock()
+            .expect("notification backlog is poisoned")
+    }
+
+    fn set_backlog_capacity(&self, capacity: usize) {
+        self.backlog().capacity = capacity;
+    }
+
+    /// Broadcasts notifications to subscribers. Returns the sequence number of the earliest notification
+    /// retained in the backlog.
+    fn send(&self, results: Vec<PubSubResult>) -> u64 {
+        // The backlog is locked while broadcasting, so that notifications returned by `resubscribe()`
+        // and notifications received via the broadcast channel never overlap or leave gaps.
+        let mut backlog = self.backlog();
+        let notifications = backlog.push(results);
+        // Errors only on 0 receivers, but we want to go on if we have 0 subscribers so ignore the error.
+        self.sender.send(notifications).ok();
+        backlog.first_seq()
+    }
+
+    /// Subscribes to new notifications. Returns the receiver together with the sequence number
+    /// of the first notification it will receive.
+    fn subscribe(&self) -> (broadcast::Receiver<Vec<Notification>>, u64) {
+        let backlog = self.backlog();
+        (self.sender.subscribe(), backlog.next_seq)
+    }
+
+    /// Subscribes to new notifications, returning all notifications starting from `next_seq` that were
+    /// broadcast previously. Returns `None` if some of these notifications were evicted from the backlog.
+    fn resubscribe(
+        &self,
+        next_seq: u64,
+    ) -> Option<(broadcast::Receiver<Vec<Notification>>, Vec<Notification>)> {
+        let backlog = self.backlog();
+        let missed_notifications = backlog.notifications_since(next_seq)?;
+        Some((self.sender.subscribe(), missed_notifications))
+    }
+}
+
+/// State of a subscription necessary to resume it.
+#[derive(Debug)]
+struct SubscriptionState {
+    sub_type: SubscriptionType,
+    filter: Option<PubSubFilter>,
+    /// Sequence number of the first notification not delivered to the subscriber.
+    next_seq: u64,
+    /// Ensures that logs matching the filter are loaded even if the subscription is detached.
+    _filter_guard: Option<LogFilterGuard>,
+}
+
+/// Closed subscriptions that can be resumed with `zks_resubscribe`, keyed by the resumption cursor
+/// (i.e., the subscription ID).
+#[derive(Debug, Clone, Default)]
+struct DetachedSubscriptions(Arc<Mutex<HashMap<String, SubscriptionState>>>);
+
+impl DetachedSubscriptions {
+    fn lock(&self) -> MutexGuard<'_, HashMap<String, SubscriptionState>> {
+        self.0.lock().expect("detached subscriptions are poisoned")
+    }
+
+    fn insert(&self, cursor: String, state: SubscriptionState) {
+        let mut inner = self.lock();
+        inner.insert(cursor, state);
+        PUB_SUB_METRICS.detached_subscriptions.set(inner.len());
+    }
+
+    fn take(&self, cursor: &str) -> Option<SubscriptionState> {
+        let mut inner = self.lock();
+        let state = inner.remove(cursor);
+        PUB_SUB_METRICS.detached_subscriptions.set(inner.len());
+        state
+    }
+
+    /// Removes subscriptions that cannot be resumed anymore because some notifications for them
+    /// were evicted from the backlog.
+    fn prune(&self, sub_type: SubscriptionType, first_retained_seq: u64) {
+        let mut inner = self.lock();
+        inner.retain(|_, state| state.sub_type != sub_type || state.next_seq >= first_retained_seq);
+        PUB_SUB_METRICS.detached_subscriptions.set(inner.len());
+    }
+}
+
+#[derive(Debug, Default)]
+struct LogFiltersInner {
+    next_id: u64,
+    filters: HashMap<u64, PubSubFilter>,
+}
+
+/// Filters of active `logs` subscriptions. Used by the logs notifier to skip loading logs for L2 blocks
+/// which cannot contain logs matching any of the filters according to their logs bloom.
+#[derive(Debug, Clone, Default)]
+struct LogFilters(Arc<Mutex<LogFiltersInner>>);
+
+impl LogFilters {
+    fn register(&self, filter: PubSubFilter) -> LogFilterGuard {
+        let mut inner = self.0.lock().expect("log filters are poisoned");
+        let id = inner.next_id;
+        inner.next_id += 1;
+        inner.filters.insert(id, filter);
+        LogFilterGuard {
+            filters: self.clone(),
+            id,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        let inner = self.0.lock().expect("log filters are poisoned");
+        inner.filters.is_empty()
+    }
+
+    fn may_match_bloom(&self, bloom: &H2048) -> bool {
+        let inner = self.0.lock().expect("log filters are poisoned");
+        inner
+            .filters
+            .values()
+            .any(|filter| filter.may_match_bloom(bloom))
+    }
+}
+
+/// Removes a filter from [`LogFilters`] when dropped.
+#[derive(Debug)]
+struct LogFilterGuard {
+    filters: LogFilters,
+    id: u64,
+}
+
+impl Drop for LogFilterGuard {
+    fn drop(&mut self) {
+        let mut inner = self.filters.0.lock().expect("log filters are poisoned");
+        inner.filters.remove(&self.id);
+    }
+}
+
 /// Manager of notifications for a certain type of subscriptions.
 #[derive(Debug)]
 struct PubSubNotifier {
-    sender: broadcast::Sender<Vec<PubSubResult>>,
+    channel: NotificationChannel,
+    detached_subscriptions: DetachedSubscriptions,
     connection_pool: ConnectionPool<Core>,
     polling_interval: Duration,
     events_sender: Option<mpsc::UnboundedSender<PubSubEvent>>,
@@ -127,9 +338,10 @@ impl PubSubNotifier {
     }
 
     fn send_pub_sub_results(&self, results: Vec<PubSubResult>, sub_type: SubscriptionType) {
-        // Errors only on 0 receivers, but we want to go on if we have 0 subscribers so ignore the error.
-        self.sender.send(results).ok();
-        PUB_SUB_METRICS.broadcast_channel_len[&sub_type].set(self.sender.len());
+        let first_retained_seq = self.channel.send(results);
+        self.detached_subscriptions
+            .prune(sub_type, first_retained_seq);
+        PUB_SUB_METRICS.broadcast_channel_len[&sub_type].set(self.channel.sender.len());
     }
 
     async fn new_blocks(
@@ -185,7 +397,11 @@ impl PubSubNotifier {
             .map_err(Into::into)
     }
 
-    async fn notify_logs(self, mut stop_receiver: watch::Receiver<bool>) -> anyhow::Result<()> {
+    async fn notify_logs(
+        self,
+        log_filters: LogFilters,
+        mut stop_receiver: watch::Receiver<bool>,
+    ) -> anyhow::Result<()> {
         let Some(mut last_block_number) = self
             .get_starting_l2_block_number(&mut stop_receiver)
             .await?
@@ -203,13 +419,15 @@ impl PubSubNotifier {
             timer.tick().await;
 
             let db_latency = PUB_SUB_METRICS.db_poll_latency[&SubscriptionType::Logs].start();
-            let new_logs = self.new_logs(last_block_number).await?;
+            let new_logs = self.new_logs(last_block_number, &log_filters).await?;
             db_latency.observe();
 
-            if let Some(last_log) = new_logs.last() {
-                last_block_number = L2BlockNumber(last_log.block_number.unwrap().as_u32());
-                let new_logs = new_logs.into_iter().map(PubSubResult::Log).collect();
-                self.send_pub_sub_results(new_logs, SubscriptionType::Logs);
+            if let Some((new_last_block_number, new_logs)) = new_logs {
+                last_block_number = new_last_block_number;
+                if !new_logs.is_empty() {
+                    let new_logs = new_logs.into_iter().map(PubSubResult::Log).collect();
+                    self.send_pub_sub_results(new_logs, SubscriptionType::Logs);
+                }
                 self.emit_event(PubSubEvent::L2BlockAdvanced(
                     SubscriptionType::Logs,
                     last_block_number,
@@ -220,64 +438,192 @@ impl PubSubNotifier {
         Ok(())
     }
 
-    async fn new_logs(&self, last_block_number: L2BlockNumber) -> anyhow::Result<Vec<Log>> {
-        self.connection_pool
-            .connection_tagged("api")
-            .await?
+    /// Returns the last new L2 block number together with logs from new L2 blocks that may match active filters.
+    /// Logs are not loaded for L2 blocks whose logs bloom doesn't match any filter, or if there are no subscribers
+    /// (including detached subscriptions that can be resumed).
+    async fn new_logs(
+        &self,
+        last_block_number: L2BlockNumber,
+        log_filters: &LogFilters,
+    ) -> anyhow::Result<Option<(L2BlockNumber, Vec<Log>)>> {
+        let mut storage = self.connection_pool.connection_tagged("api").await?;
+        let blooms = storage
             .events_web3_dal()
-            .get_all_logs(last_block_number)
-            .await
-            .map_err(Into::into)
+            .get_logs_blooms(last_block_number)
+            .await?;
+        let Some(&(new_last_block_number, _)) = blooms.last() else {
+            return Ok(None);
+        };
+
+        let matched_blocks: Vec<_> = if log_filters.is_empty() {
+            vec![]
+        } else {
+            blooms
+                .iter()
+                .filter(|(_, bloom)| {
+                    // Blocks without a persisted bloom can contain any logs.
+                    bloom
+                        .as_ref()
+                        .map_or(true, |bloom| log_filters.may_match_bloom(bloom))
+                })
+                .map(|&(number, _)| number)
+                .collect()
+        };
+        PUB_SUB_METRICS
+            .bloom_skipped_blocks
+            .inc_by((blooms.len() - matched_blocks.len()) as u64);
+
+        let logs = if matched_blocks.is_empty() {
+            vec![]
+        } else {
+            storage
+                .events_web3_dal()
+                .get_logs_for_blocks(&matched_blocks)
+                .await?
+        };
+        Ok(Some((new_last_block_number, logs)))
     }
 }
 
 /// Subscription support for Web3 APIs.
+///
+/// If the notification backlog is enabled, closed subscriptions can be resumed with `zks_resubscribe`, which takes
+/// the ID of the closed subscription as a resumption cursor. A resumed subscription first receives all notifications
+/// missed since the subscription was closed, and then continues receiving new notifications. A subscription can only
+/// be resumed on the same server, and only while all missed notifications are retained in the backlog.
+#[derive(Clone)]
 pub(super) struct EthSubscribe {
-    blocks: broadcast::Sender<Vec<PubSubResult>>,
-    transactions: broadcast::Sender<Vec<PubSubResult>>,
-    logs: broadcast::Sender<Vec<PubSubResult>>,
+    blocks: NotificationChannel,
+    transactions: NotificationChannel,
+    logs: NotificationChannel,
+    log_filters: LogFilters,
+    detached_subscriptions: DetachedSubscriptions,
+    backlog_size: usize,
     events_sender: Option<mpsc::UnboundedSender<PubSubEvent>>,
 }
 
 impl EthSubscribe {
     pub fn new() -> Self {
-        let (blocks, _) = broadcast::channel(BROADCAST_CHANNEL_CAPACITY);
-        let (transactions, _) = broadcast::channel(BROADCAST_CHANNEL_CAPACITY);
-        let (logs, _) = broadcast::channel(BROADCAST_CHANNEL_CAPACITY);
-
         Self {
-            blocks,
-            transactions,
-            logs,
+            blocks: NotificationChannel::new(),
+            transactions: NotificationChannel::new(),
+            logs: NotificationChannel::new(),
+            log_filters: LogFilters::default(),
+            detached_subscriptions: DetachedSubscriptions::default(),
+            backlog_size: 0,
             events_sender: None,
         }
     }
 
+    /// Sets the number of recent notifications of each type retained in order to resume subscriptions.
+    /// 0 (the default) disables resuming subscriptions.
+    pub fn set_backlog_size(&mut self, size: usize) {
+        self.backlog_size = size;
+        for channel in [&self.blocks, &self.transactions, &self.logs] {
+            channel.set_backlog_capacity(size);
+        }
+    }
+
     pub fn set_events_sender(&mut self, sender: mpsc::UnboundedSender<PubSubEvent>) {
         self.events_sender = Some(sender);
     }
 
-    async fn reject(sink: PendingSubscriptionSink) {
+    fn emit_event(&self, event: PubSubEvent) {
+        if let Some(sender) = &self.events_sender {
+            sender.send(event).ok();
+        }
+    }
+
+    fn channel(&self, sub_type: SubscriptionType) -> &NotificationChannel {
+        match sub_type {
+            SubscriptionType::Blocks => &self.blocks,
+            SubscriptionType::Txs => &self.transactions,
+            SubscriptionType::Logs => &self.logs,
+        }
+    }
+
+    async fn reject(sink: PendingSubscriptionSink, message: &'static str) {
         sink.reject(ErrorObject::borrowed(
             ErrorCode::InvalidParams.code(),
-            "Rejecting subscription - invalid parameters provided.",
+            message,
             None,
         ))
         .await;
     }
 
+    fn subscription_cursor(sink: &SubscriptionSink) -> String {
+        match sink.subscription_id() {
+            SubscriptionId::Num(id) => id.to_string(),
+            SubscriptionId::Str(id) => id.into_owned(),
+        }
+    }
+
+    /// Spawns a task sending notifications to the subscriber. If the notification backlog is enabled,
+    /// the subscription state is retained after the subscription is closed, so that it can be resumed.
+    fn spawn_subscriber(
+        &self,
+        sink: SubscriptionSink,
+        mut state: SubscriptionState,
+        receiver: broadcast::Receiver<Vec<Notification>>,
+        missed_notifications: Vec<Notification>,
+    ) {
+        let cursor = Self::subscription_cursor(&sink);
+        let detached_subscriptions =
+            (self.backlog_size > 0).then(|| self.detached_subscriptions.clone());
+        let events_sender = self.events_sender.clone();
+        let subscriber = async move {
+            state.next_seq = Self::run_subscriber(
+                sink,
+                state.sub_type,
+                receiver,
+                state.filter.as_ref(),
+                state.next_seq,
+                missed_notifications,
+            )
+            .await;
+
+            if let Some(detached_subscriptions) = detached_subscriptions {
+                let sub_type = state.sub_type;
+                detached_subscriptions.insert(cursor, state);
+                if let Some(sender) = &events_sender {
+                    sender.send(PubSubEvent::Detached(sub_type)).ok();
+                }
+            }
+        };
+        tokio::spawn(subscriber.in_current_span());
+    }
+
+    /// Sends notifications to the subscriber until the subscription is closed. Returns the sequence number
+    /// of the first notification not delivered to the subscriber.
     async fn run_subscriber(
         sink: SubscriptionSink,
         subscription_type: SubscriptionType,
-        mut receiver: broadcast::Receiver<Vec<PubSubResult>>,
-        filter: Option<PubSubFilter>,
-    ) {
+        mut receiver: broadcast::Receiver<Vec<Notification>>,
+        filter: Option<&PubSubFilter>,
+        mut next_seq: u64,
+        missed_notifications: Vec<Notification>,
+    ) -> u64 {
+        PUB_SUB_METRICS.subscriptions[&subscription_type].inc();
         let _guard = PUB_SUB_METRICS.active_subscribers[&subscription_type].inc_guard(1);
         let lifetime_latency = PUB_SUB_METRICS.subscriber_lifetime[&subscription_type].start();
         let closed = sink.closed().fuse();
         tokio::pin!(closed);
 
-        loop {
+        // Missed notifications are only present for resumed subscriptions.
+        let mut send_result = if missed_notifications.is_empty() {
+            Ok(())
+        } else {
+            Self::handle_new_items(
+                &sink,
+                subscription_type,
+                missed_notifications,
+                filter,
+                &mut next_seq,
+            )
+            .await
+        };
+
+        while send_result.is_ok() {
             tokio::select! {
                 new_items_result = receiver.recv() => {
                     let new_items = match new_items_result {
@@ -295,50 +641,50 @@ impl EthSubscribe {
                         }
                     };
 
-                    let handle_result = Self::handle_new_items(
+                    send_result = Self::handle_new_items(
                         &sink,
                         subscription_type,
                         new_items,
-                        filter.as_ref()
+                        filter,
+                        &mut next_seq,
                     )
                     .await;
-                    if handle_result.is_err() {
-                        PUB_SUB_METRICS.subscriber_send_timeouts[&subscription_type].inc();
-                        break;
-                    }
                 }
                 _ = &mut closed => {
                     break;
                 }
             }
         }
+        if send_result.is_err() {
+            PUB_SUB_METRICS.subscriber_send_timeouts[&subscription_type].inc();
+        }
         lifetime_latency.observe();
+        next_seq
     }
 
     async fn handle_new_items(
         sink: &SubscriptionSink,
         subscription_type: SubscriptionType,
-        new_items: Vec<PubSubResult>,
+        new_items: Vec<Notification>,
         filter: Option<&PubSubFilter>,
+        next_seq: &mut u64,
     ) -> Result<(), SendTimeoutError> {
         let notify_latency = PUB_SUB_METRICS.notify_subscribers_latency[&subscription_type].start();
-        for item in new_items {
-            if let PubSubResult::Log(log) = &item {
-                if let Some(filter) = &filter {
-                    if !filter.matches(log) {
-                        continue;
-                    }
-                }
+        for notification in new_items {
+            let is_matching = match (&notification.result, filter) {
+                (PubSubResult::Log(log), Some(filter)) => filter.matches(log),
+                _ => true,
+            };
+            if is_matching {
+                sink.send_timeout(
+                    SubscriptionMessage::from_json(&notification.result)
+                        .expect("PubSubResult always serializable to json;qed"),
+                    SUBSCRIPTION_SINK_SEND_TIMEOUT,
+                )
+                .await?;
+                PUB_SUB_METRICS.notify[&subscription_type].inc();
             }
-
-            sink.send_timeout(
-                SubscriptionMessage::from_json(&item)
-                    .expect("PubSubResult always serializable to json;qed"),
-                SUBSCRIPTION_SINK_SEND_TIMEOUT,
-            )
-            .await?;
-
-            PUB_SUB_METRICS.notify[&subscription_type].inc();
+            *next_seq = notification.seq + 1;
         }
 
         notify_latency.observe();
@@ -357,23 +703,28 @@ impl EthSubscribe {
                 let Ok(sink) = pending_sink.accept().await else {
                     return;
                 };
-                let blocks_rx = self.blocks.subscribe();
-                tokio::spawn(
-                    Self::run_subscriber(sink, SubscriptionType::Blocks, blocks_rx, None)
-                        .in_current_span(),
-                );
-
+                let (blocks_rx, next_seq) = self.blocks.subscribe();
+                let state = SubscriptionState {
+                    sub_type: SubscriptionType::Blocks,
+                    filter: None,
+                    next_seq,
+                    _filter_guard: None,
+                };
+                self.spawn_subscriber(sink, state, blocks_rx, vec![]);
                 Some(SubscriptionType::Blocks)
             }
             "newPendingTransactions" => {
                 let Ok(sink) = pending_sink.accept().await else {
                     return;
                 };
-                let transactions_rx = self.transactions.subscribe();
-                tokio::spawn(
-                    Self::run_subscriber(sink, SubscriptionType::Txs, transactions_rx, None)
-                        .in_current_span(),
-                );
+                let (transactions_rx, next_seq) = self.transactions.subscribe();
+                let state = SubscriptionState {
+                    sub_type: SubscriptionType::Txs,
+                    filter: None,
+                    next_seq,
+                    _filter_guard: None,
+                };
+                self.spawn_subscriber(sink, state, transactions_rx, vec![]);
                 Some(SubscriptionType::Txs)
             }
             "logs" => {
@@ -381,17 +732,21 @@ impl EthSubscribe {
                 let topic_count = filter.topics.as_ref().map_or(0, Vec::len);
 
                 if topic_count > EVENT_TOPIC_NUMBER_LIMIT {
-                    Self::reject(pending_sink).await;
+                    Self::reject(pending_sink, INVALID_PARAMS_MESSAGE).await;
                     None
                 } else {
                     let Ok(sink) = pending_sink.accept().await else {
                         return;
                     };
-                    let logs_rx = self.logs.subscribe();
-                    tokio::spawn(
-                        Self::run_subscriber(sink, SubscriptionType::Logs, logs_rx, Some(filter))
-                            .in_current_span(),
-                    );
+                    let filter_guard = self.log_filters.register(filter.clone());
+                    let (logs_rx, next_seq) = self.logs.subscribe();
+                    let state = SubscriptionState {
+                        sub_type: SubscriptionType::Logs,
+                        filter: Some(filter),
+                        next_seq,
+                        _filter_guard: Some(filter_guard),
+                    };
+                    self.spawn_subscriber(sink, state, logs_rx, vec![]);
                     Some(SubscriptionType::Logs)
                 }
             }
@@ -410,18 +765,48 @@ impl EthSubscribe {
                 None
             }
             _ => {
-                Self::reject(pending_sink).await;
+                Self::reject(pending_sink, INVALID_PARAMS_MESSAGE).await;
                 None
             }
         };
 
         if let Some(sub_type) = sub_type {
-            if let Some(sender) = &self.events_sender {
-                sender.send(PubSubEvent::Subscribed(sub_type)).ok();
-            }
+            self.emit_event(PubSubEvent::Subscribed(sub_type));
         }
     }
 
+    #[tracing::instrument(level = "debug", skip(self, pending_sink))]
+    pub async fn resub(&self, pending_sink: PendingSubscriptionSink, cursor: String) {
+        let Some(state) = self.detached_subscriptions.take(&cursor) else {
+            Self::reject(
+                pending_sink,
+                "Rejecting resubscription - unknown or expired cursor provided.",
+            )
+            .await;
+            return;
+        };
+        let sub_type = state.sub_type;
+        let Some((receiver, missed_notifications)) =
+            self.channel(sub_type).resubscribe(state.next_seq)
+        else {
+            Self::reject(
+                pending_sink,
+                "Rejecting resubscription - missed notifications are evicted from the backlog.",
+            )
+            .await;
+            return;
+        };
+        let Ok(sink) = pending_sink.accept().await else {
+            // The client has disconnected; keep the subscription resumable.
+            self.detached_subscriptions.insert(cursor, state);
+            return;
+        };
+
+        PUB_SUB_METRICS.resumed_subscriptions[&sub_type].inc();
+        self.spawn_subscriber(sink, state, receiver, missed_notifications);
+        self.emit_event(PubSubEvent::Subscribed(sub_type));
+    }
+
     /// Spawns notifier tasks. This should be called once per instance.
     pub fn spawn_notifiers(
         &self,
@@ -432,7 +817,8 @@ impl EthSubscribe {
         let mut notifier_tasks = Vec::with_capacity(3);
 
         let notifier = PubSubNotifier {
-            sender: self.blocks.clone(),
+            channel: self.blocks.clone(),
+            detached_subscriptions: self.detached_subscriptions.clone(),
             connection_pool: connection_pool.clone(),
             polling_interval,
             events_sender: self.events_sender.clone(),
@@ -441,7 +827,8 @@ impl EthSubscribe {
         notifier_tasks.push(notifier_task);
 
         let notifier = PubSubNotifier {
-            sender: self.transactions.clone(),
+            channel: self.transactions.clone(),
+            detached_subscriptions: self.detached_subscriptions.clone(),
             connection_pool: connection_pool.clone(),
             polling_interval,
             events_sender: self.events_sender.clone(),
@@ -450,12 +837,14 @@ impl EthSubscribe {
         notifier_tasks.push(notifier_task);
 
         let notifier = PubSubNotifier {
-            sender: self.logs.clone(),
+            channel: self.logs.clone(),
+            detached_subscriptions: self.detached_subscriptions.clone(),
             connection_pool,
             polling_interval,
             events_sender: self.events_sender.clone(),
         };
-        let notifier_task = tokio::spawn(notifier.notify_logs(stop_receiver));
+        let notifier_task =
+            tokio::spawn(notifier.notify_logs(self.log_filters.clone(), stop_receiver));
 
         notifier_tasks.push(notifier_task);
         notifier_tasks
@@ -474,3 +863,73 @@ impl EthPubSubServer for EthSubscribe {
         Ok(())
     }
 }
+
+#[async_trait::async_trait]
+impl ZksPubSubServer for EthSubscribe {
+    async fn resubscribe(
+        &self,
+        pending: PendingSubscriptionSink,
+        cursor: String,
+    ) -> SubscriptionResult {
+        self.resub(pending, cursor).await;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tx_hashes(notifications: &[Notification]) -> Vec<(u64, H256)> {
+        notifications
+            .iter()
+            .map(|notification| match &notification.result {
+                PubSubResult::TxHash(hash) => (notification.seq, *hash),
+                other => panic!("Unexpected notification: {other:?}"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn notification_backlog_basics() {
+        let mut backlog = NotificationBacklog {
+            capacity: 3,
+            ..NotificationBacklog::default()
+        };
+        let hashes: Vec<_> = (0..5).map(H256::repeat_byte).collect();
+        let notifications = backlog.push(
+            hashes[..2]
+                .iter()
+                .copied()
+                .map(PubSubResult::TxHash)
+                .collect(),
+        );
+        assert_eq!(tx_hashes(&notifications), [(0, hashes[0]), (1, hashes[1])]);
+        assert_eq!(backlog.first_seq(), 0);
+        let missed = backlog.notifications_since(1).unwrap();
+        assert_eq!(tx_hashes(&missed), [(1, hashes[1])]);
+        assert!(backlog.notifications_since(2).unwrap().is_empty());
+
+        backlog.push(
+            hashes[2..]
+                .iter()
+                .copied()
+                .map(PubSubResult::TxHash)
+                .collect(),
+        );
+        assert_eq!(backlog.first_seq(), 2);
+        assert!(backlog.notifications_since(1).is_none());
+        let missed = backlog.notifications_since(3).unwrap();
+        assert_eq!(tx_hashes(&missed), [(3, hashes[3]), (4, hashes[4])]);
+    }
+
+    #[test]
+    fn notification_backlog_with_zero_capacity() {
+        let mut backlog = NotificationBacklog::default();
+        let notifications = backlog.push(vec![PubSubResult::Syncing(false); 2]);
+        assert_eq!(notifications.len(), 2);
+        assert_eq!(backlog.first_seq(), 2);
+        assert!(backlog.notifications_since(1).is_none());
+        assert!(backlog.notifications_since(2).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,238 @@
+//! Per-client rate limiting for the HTTP API server.
+
+use std::{
+    fmt,
+    net::IpAddr,
+    num::NonZeroU32,
+    sync::{Arc, Weak},
+    time::Duration,
+};
+
+use anyhow::Context as _;
+use governor::{clock::DefaultClock, state::keyed::DefaultKeyedStateStore, Quota, RateLimiter};
+use http::{header::HeaderName, HeaderMap};
+use vise::{EncodeLabelSet, EncodeLabelValue};
+
+type KeyedRateLimiter<K> = RateLimiter<K, DefaultKeyedStateStore<K>, DefaultClock>;
+
+/// Per-client limits on the number of HTTP requests. Clients are identified by their IP address and / or by an API key
+/// supplied in a request header; if both limits are set, a request must satisfy both of them.
+///
+/// The client IP address is taken from the first address in the `X-Forwarded-For` header or from the `X-Real-IP` header,
+/// so these headers must be set (and not passed through from the client) by a reverse proxy in front of the server.
+/// Requests without these headers are not subject to the per-IP limit. API keys are not validated; each distinct key value
+/// gets its own bucket.
+///
+/// Limits are applied to HTTP requests, so a batch JSON-RPC request counts as a single request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClientRateLimit {
+    requests_per_minute_per_ip: Option<NonZeroU32>,
+    requests_per_minute_per_api_key: Option<NonZeroU32>,
+    api_key_header: HeaderName,
+}
+
+impl Default for ClientRateLimit {
+    fn default() -> Self {
+        Self {
+            requests_per_minute_per_ip: None,
+            requests_per_minute_per_api_key: None,
+            api_key_header: HeaderName::from_static(Self::DEFAULT_API_KEY_HEADER),
+        }
+    }
+}
+
+impl ClientRateLimit {
+    /// Default name of the header containing the API key.
+    pub const DEFAULT_API_KEY_HEADER: &'static str = "x-api-key";
+
+    /// Sets the maximum number of requests per minute from a single IP address.
+    #[must_use]
+    pub fn per_ip(mut self, requests_per_minute: NonZeroU32) -> Self {
+        self.requests_per_minute_per_ip = Some(requests_per_minute);
+        self
+    }
+
+    /// Sets the maximum number of requests per minute with a single API key.
+    #[must_use]
+    pub fn per_api_key(mut self, requests_per_minute: NonZeroU32) -> Self {
+        self.requests_per_minute_per_api_key = Some(requests_per_minute);
+        self
+    }
+
+    /// Sets the name of the header containing the API key.
+    pub fn with_api_key_header(mut self, header: &str) -> anyhow::Result<Self> {
+        self.api_key_header = HeaderName::from_bytes(header.as_bytes())
+            .with_context(|| format!("invalid API key header name: {header:?}"))?;
+        Ok(self)
+    }
+
+    /// Checks whether this config doesn't limit any requests.
+    pub fn is_trivial(&self) -> bool {
+        self.requests_per_minute_per_ip.is_none() && self.requests_per_minute_per_api_key.is_none()
+    }
+}
+
+/// Kind of the per-client limit exceeded by a request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EncodeLabelValue, EncodeLabelSet)]
+#[metrics(label = "limit", rename_all = "snake_case")]
+pub(crate) enum ClientLimitKind {
+    Ip,
+    ApiKey,
+}
+
+/// Stateful rate limiter enforcing a [`ClientRateLimit`].
+pub(crate) struct ClientRateLimiter {
+    by_ip: Option<KeyedRateLimiter<IpAddr>>,
+    by_api_key: Option<KeyedRateLimiter<String>>,
+    api_key_header: HeaderName,
+}
+
+impl fmt::Debug for ClientRateLimiter {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("ClientRateLimiter")
+            .field("limits_ip", &self.by_ip.is_some())
+            .field("limits_api_key", &self.by_api_key.is_some())
+            .field("api_key_header", &self.api_key_header)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ClientRateLimiter {
+    /// Interval between removing state of clients that didn't send requests recently.
+    const CLEANUP_INTERVAL: Duration = Duration::from_secs(60);
+
+    pub(crate) fn new(config: &ClientRateLimit) -> Self {
+        Self {
+            by_ip: config
+                .requests_per_minute_per_ip
+                .map(|limit| RateLimiter::keyed(Quota::per_minute(limit))),
+            by_api_key: config
+                .requests_per_minute_per_api_key
+                .map(|limit| RateLimiter::keyed(Quota::per_minute(limit))),
+            api_key_header: config.api_key_header.clone(),
+        }
+    }
+
+    fn client_ip(headers: &HeaderMap) -> Option<IpAddr> {
+        if let Some(forwarded_for) = headers.get("x-forwarded-for") {
+            let first_addr = forwarded_for.to_str().ok()?.split(',').next()?;
+            return first_addr.trim().parse().ok();
+        }
+        headers.get("x-real-ip")?.to_str().ok()?.trim().parse().ok()
+    }
+
+    /// Checks whether a request with the specified headers is allowed, updating the limiter state.
+    pub(crate) fn check(&self, headers: &HeaderMap) -> Result<(), ClientLimitKind> {
+        if let Some(by_ip) = &self.by_ip {
+            if let Some(ip) = Self::client_ip(headers) {
+                by_ip.check_key(&ip).map_err(|_| ClientLimitKind::Ip)?;
+            }
+        }
+        if let Some(by_api_key) = &self.by_api_key {
+            let api_key = headers
+                .get(&self.api_key_header)
+                .and_then(|value| value.to_str().ok());
+            if let Some(api_key) = api_key {
+                by_api_key
+                    .check_key(&api_key.to_owned())
+                    .map_err(|_| ClientLimitKind::ApiKey)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn retain_recent(&self) {
+        if let Some(by_ip) = &self.by_ip {
+            by_ip.retain_recent();
+        }
+        if let Some(by_api_key) = &self.by_api_key {
+            by_api_key.retain_recent();
+        }
+    }
+
+    /// Periodically removes state of inactive clients so that it doesn't grow unbounded. Terminates once the limiter is dropped.
+    async fn run_cleanup(limiter: Weak<Self>) {
+        loop {
+            tokio::time::sleep(Self::CLEANUP_INTERVAL).await;
+            let Some(limiter) = limiter.upgrade() else {
+                return;
+            };
+            limiter.retain_recent();
+        }
+    }
+
+    /// Creates a limiter and spawns a background task cleaning up its state.
+    pub(crate) fn spawn(config: &ClientRateLimit) -> Arc<Self> {
+        let limiter = Arc::new(Self::new(config));
+        tokio::spawn(Self::run_cleanup(Arc::downgrade(&limiter)));
+        limiter
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::HeaderValue;
+
+    use super::*;
+
+    fn headers(pairs: &[(&'static str, &'static str)]) -> HeaderMap {
+        pairs
+            .iter()
+            .map(|&(name, value)| {
+                (
+                    HeaderName::from_static(name),
+                    HeaderValue::from_static(value),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn extracting_client_ip() {
+        let ip =
+            ClientRateLimiter::client_ip(&headers(&[("x-forwarded-for", "203.0.113.1, 10.0.0.1")]));
+        assert_eq!(ip, Some("203.0.113.1".parse().unwrap()));
+        let ip = ClientRateLimiter::client_ip(&headers(&[("x-real-ip", "2001:db8::1")]));
+        assert_eq!(ip, Some("2001:db8::1".parse().unwrap()));
+        let ip = ClientRateLimiter::client_ip(&headers(&[("x-forwarded-for", "unknown")]));
+        assert_eq!(ip, None);
+        assert_eq!(ClientRateLimiter::client_ip(&HeaderMap::new()), None);
+    }
+
+    #[test]
+    fn limiting_requests_per_ip() {
+        let config = ClientRateLimit::default().per_ip(NonZeroU32::new(2).unwrap());
+        let limiter = ClientRateLimiter::new(&config);
+        let client = headers(&[("x-forwarded-for", "203.0.113.1")]);
+        let other_client = headers(&[("x-forwarded-for", "203.0.113.2")]);
+
+        limiter.check(&client).unwrap();
+        limiter.check(&client).unwrap();
+        assert_eq!(limiter.check(&client), Err(ClientLimitKind::Ip));
+        limiter.check(&other_client).unwrap();
+        // Requests without the client IP are not limited.
+        for _ in 0..5 {
+            limiter.check(&HeaderMap::new()).unwrap();
+        }
+    }
+
+    #[test]
+    fn limiting_requests_per_api_key() {
+        let config = ClientRateLimit::default()
+            .per_ip(NonZeroU32::new(10).unwrap())
+            .per_api_key(NonZeroU32::new(1).unwrap())
+            .with_api_key_header("X-Custom-Key")
+            .unwrap();
+        let limiter = ClientRateLimiter::new(&config);
+        let client = headers(&[("x-forwarded-for", "203.0.113.1"), ("x-custom-key", "key")]);
+        let other_client = headers(&[
+            ("x-forwarded-for", "203.0.113.1"),
+            ("x-custom-key", "other"),
+        ]);
+
+        limiter.check(&client).unwrap();
+        assert_eq!(limiter.check(&client), Err(ClientLimitKind::ApiKey));
+        limiter.check(&other_client).unwrap();
+    }
+}
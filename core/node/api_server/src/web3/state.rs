@@ -29,6 +29,7 @@ use super::{
     backend_jsonrpsee::MethodTracer,
     mempool_cache::MempoolCache,
     metrics::{FilterType, FILTER_METRICS},
+    namespaces::eth::EVENT_TOPIC_NUMBER_LIMIT,
     TypedFilter,
 };
 use crate::{
@@ -108,10 +109,12 @@ pub struct InternalApiConfig {
     pub l2_testnet_paymaster_addr: Option<Address>,
     pub req_entities_limit: usize,
     pub fee_history_limit: u64,
+    pub max_logs_block_range: Option<u32>,
     pub base_token_address: Option<Address>,
     pub filters_disabled: bool,
     pub dummy_verifier: bool,
     pub l1_batch_commit_data_generator_mode: L1BatchCommitmentMode,
+    pub evm_emulator_enabled: bool,
 }
 
 impl InternalApiConfig {
@@ -151,10 +154,12 @@ impl InternalApiConfig {
             l2_testnet_paymaster_addr: contracts_config.l2_testnet_paymaster_addr,
             req_entities_limit: web3_config.req_entities_limit(),
             fee_history_limit: web3_config.fee_history_limit(),
+            max_logs_block_range: web3_config.max_logs_block_range,
             base_token_address: contracts_config.base_token_addr,
             filters_disabled: web3_config.filters_disabled,
             dummy_verifier: genesis_config.dummy_verifier,
             l1_batch_commit_data_generator_mode: genesis_config.l1_batch_commit_data_generator_mode,
+            evm_emulator_enabled: web3_config.evm_emulator_enabled,
         }
     }
 }
@@ -364,6 +369,47 @@ impl RpcState {
         Ok((from_block, to_block))
     }
 
+    /// Converts `filter` to the form used by the DAL, starting from the specified `from_block`. If the upper bound
+    /// of the filter block range is specified as a number, it's capped by the latest sealed block.
+    pub async fn resolve_get_logs_filter(
+        &self,
+        filter: &Filter,
+        from_block: L2BlockNumber,
+    ) -> Result<api::GetLogsFilter, Web3Error> {
+        let addresses = if let Some(addresses) = &filter.address {
+            addresses.0.clone()
+        } else {
+            vec![]
+        };
+        let topics = if let Some(topics) = &filter.topics {
+            if topics.len() > EVENT_TOPIC_NUMBER_LIMIT {
+                return Err(Web3Error::TooManyTopics);
+            }
+            let topics_by_idx = topics
+                .iter()
+                .enumerate()
+                .filter_map(|(idx, topics)| Some((idx as u32 + 1, topics.as_ref()?.0.clone())));
+            topics_by_idx.collect::<Vec<_>>()
+        } else {
+            vec![]
+        };
+
+        let mut to_block = self.resolve_filter_block_number(filter.to_block).await?;
+        if matches!(filter.to_block, Some(api::BlockNumber::Number(_))) {
+            to_block = to_block.min(
+                self.resolve_filter_block_number(Some(api::BlockNumber::Latest))
+                    .await?,
+            );
+        }
+
+        Ok(api::GetLogsFilter {
+            from_block,
+            to_block,
+            addresses,
+            topics,
+        })
+    }
+
     /// If filter has `block_hash` then it resolves block number by hash and sets it to `from_block` and `to_block`.
     pub async fn resolve_filter_block_hash(&self, filter: &mut Filter) -> Result<(), Web3Error> {
         match (filter.block_hash, filter.from_block, filter.to_block) {
@@ -155,7 +155,8 @@ async fn spawn_server(
         ApiTransportLabel::Ws => {
             let mut builder = ApiBuilder::jsonrpsee_backend(api_config, pool)
                 .ws(0)
-                .with_subscriptions_limit(100);
+                .with_subscriptions_limit(100)
+                .with_subscription_backlog_size(100);
             if let Some(websocket_requests_per_minute_limit) = websocket_requests_per_minute_limit {
                 builder = builder
                     .with_websocket_requests_per_minute_limit(websocket_requests_per_minute_limit);
@@ -324,3 +324,114 @@ impl HttpTest for DisableFiltersTest {
 async fn disable_filters() {
     test_http_server(DisableFiltersTest).await;
 }
+
+#[derive(Debug)]
+struct LogsBlockRangeLimitTest;
+
+#[async_trait]
+impl HttpTest for LogsBlockRangeLimitTest {
+    async fn test(
+        &self,
+        client: &DynClient<L2>,
+        _pool: &ConnectionPool<Core>,
+    ) -> anyhow::Result<()> {
+        let filter = Filter {
+            from_block: Some(api::BlockNumber::Number(0.into())),
+            to_block: Some(api::BlockNumber::Number(4.into())),
+            ..Filter::default()
+        };
+        let logs = client.get_logs(filter).await?;
+        assert!(logs.is_empty(), "{logs:?}");
+
+        let filter = Filter {
+            from_block: Some(api::BlockNumber::Number(0.into())),
+            to_block: Some(api::BlockNumber::Number(5.into())),
+            ..Filter::default()
+        };
+        let err = client.get_logs(filter).await.unwrap_err();
+        assert_matches!(err, Error::Call(e) => {
+            assert_eq!(e.code(), ErrorCode::InvalidParams.code());
+            assert!(e.message().contains("at most 5 blocks"), "{e:?}");
+        });
+
+        // Reversed ranges are empty and thus are not subject to the limit.
+        let filter = Filter {
+            from_block: Some(api::BlockNumber::Number(100.into())),
+            to_block: Some(api::BlockNumber::Number(0.into())),
+            ..Filter::default()
+        };
+        let logs = client.get_logs(filter).await?;
+        assert!(logs.is_empty(), "{logs:?}");
+        Ok(())
+    }
+
+    fn max_logs_block_range(&self) -> Option<u32> {
+        Some(5)
+    }
+}
+
+#[tokio::test]
+async fn logs_block_range_limit() {
+    test_http_server(LogsBlockRangeLimitTest).await;
+}
+
+#[derive(Debug)]
+struct PaginatedLogsTest;
+
+#[async_trait]
+impl HttpTest for PaginatedLogsTest {
+    async fn test(
+        &self,
+        client: &DynClient<L2>,
+        pool: &ConnectionPool<Core>,
+    ) -> anyhow::Result<()> {
+        let mut storage = pool.connection().await?;
+        let (_, events) = store_events(&mut storage, 1, 0).await?;
+        let (_, new_events) = store_events(&mut storage, 2, 4).await?;
+        drop(storage);
+        let all_events: Vec<_> = events.iter().chain(&new_events).collect();
+
+        let filter = Filter {
+            from_block: Some(api::BlockNumber::Number(0.into())),
+            to_block: Some(api::BlockNumber::Latest),
+            ..Filter::default()
+        };
+        let mut cursor = None;
+        let mut pages = vec![];
+        loop {
+            let page = client
+                .get_logs_paginated(filter.clone(), cursor, Some(3))
+                .await?;
+            pages.push(page.logs);
+            cursor = page.next_cursor;
+            if cursor.is_none() {
+                break;
+            }
+        }
+        let page_sizes: Vec<_> = pages.iter().map(Vec::len).collect();
+        assert_eq!(page_sizes, [3, 3, 2]);
+        assert_logs_match(&pages.concat(), &all_events);
+
+        let address_filter = Filter {
+            address: Some(Address::repeat_byte(23).into()),
+            ..filter
+        };
+        let page = client
+            .get_logs_paginated(address_filter.clone(), None, Some(3))
+            .await?;
+        assert_logs_match(&page.logs, &[&events[0], &events[3], &new_events[0]]);
+        let cursor = page.next_cursor.expect("no cursor");
+        assert_eq!(cursor.block_number, 2.into());
+        let page = client
+            .get_logs_paginated(address_filter, Some(cursor), Some(3))
+            .await?;
+        assert_logs_match(&page.logs, &[&new_events[3]]);
+        assert_eq!(page.next_cursor, None);
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn paginated_logs() {
+    test_http_server(PaginatedLogsTest).await;
+}
@@ -25,7 +25,7 @@ use zksync_node_test_utils::{
 };
 use zksync_types::{
     api,
-    block::L2BlockHeader,
+    block::{build_logs_bloom, L2BlockHeader},
     fee::TransactionExecutionMetrics,
     get_nonce_key,
     l2::L2Tx,
@@ -57,7 +57,10 @@ use zksync_web3_decl::{
 use super::*;
 use crate::{
     execution_sandbox::testonly::MockTransactionExecutor,
-    web3::testonly::{spawn_http_server, spawn_ws_server},
+    web3::{
+        namespaces::eth::compute_block_rewards,
+        testonly::{spawn_http_server, spawn_ws_server},
+    },
 };
 
 mod debug;
@@ -151,6 +154,11 @@ trait HttpTest: Send + Sync {
     fn filters_disabled(&self) -> bool {
         false
     }
+
+    /// Overrides the `max_logs_block_range` configuration parameter for HTTP server startup.
+    fn max_logs_block_range(&self) -> Option<u32> {
+        None
+    }
 }
 
 /// Storage initialization strategy.
@@ -228,6 +236,7 @@ async fn test_http_server(test: impl HttpTest) {
     let genesis = GenesisConfig::for_tests();
     let mut api_config = InternalApiConfig::new(&web3_config, &contracts_config, &genesis);
     api_config.filters_disabled = test.filters_disabled();
+    api_config.max_logs_block_range = test.max_logs_block_range();
     let mut server_handles = spawn_http_server(
         api_config,
         pool.clone(),
@@ -344,9 +353,7 @@ async fn store_events(
     l2_block_number: u32,
     start_idx: u32,
 ) -> anyhow::Result<(IncludedTxLocation, Vec<VmEvent>)> {
-    let new_l2_block = create_l2_block(l2_block_number);
     let l1_batch_number = L1BatchNumber(l2_block_number);
-    storage.blocks_dal().insert_l2_block(&new_l2_block).await?;
     let tx_location = IncludedTxLocation {
         tx_hash: H256::repeat_byte(1),
         tx_index_in_l2_block: 0,
@@ -382,13 +389,25 @@ async fn store_events(
             value: (start_idx + 3).to_le_bytes().to_vec(),
         },
     ];
-    storage
+    let new_l2_block = L2BlockHeader {
+        logs_bloom: build_logs_bloom(&events),
+        ..create_l2_block(l2_block_number)
+    };
+
+    // Store the L2 block together with its events, so that notifiers never observe a block without events.
+    let mut transaction = storage.start_transaction().await?;
+    transaction
+        .blocks_dal()
+        .insert_l2_block(&new_l2_block)
+        .await?;
+    transaction
         .events_dal()
         .save_events(
             L2BlockNumber(l2_block_number),
             &[(tx_location, events.iter().collect())],
         )
         .await?;
+    transaction.commit().await?;
     Ok((tx_location, events))
 }
 
@@ -557,6 +576,28 @@ impl HttpTest for L1BatchMethodsWithSnapshotRecovery {
             .unwrap_err();
         assert_pruned_l1_batch_error(&error, l1_batch_number);
 
+        // `get_l1_batch_pubdata` method
+        let pubdata = client
+            .get_l1_batch_pubdata(l1_batch_number)
+            .await?
+            .context("no pubdata for sealed L1 batch")?;
+        assert_eq!(
+            pubdata.0,
+            create_l1_batch(l1_batch_number.0).pubdata_input.unwrap()
+        );
+
+        let pubdata_for_future_batch = client.get_l1_batch_pubdata(l1_batch_number + 1).await?;
+        assert!(
+            pubdata_for_future_batch.is_none(),
+            "{pubdata_for_future_batch:?}"
+        );
+
+        let error = client
+            .get_l1_batch_pubdata(l1_batch_number - 1)
+            .await
+            .unwrap_err();
+        assert_pruned_l1_batch_error(&error, l1_batch_number);
+
         Ok(())
     }
 }
@@ -1075,3 +1116,97 @@ impl HttpTest for GenesisConfigTest {
 async fn tracing_genesis_config() {
     test_http_server(GenesisConfigTest).await;
 }
+
+#[test]
+fn computing_fee_history_rewards() {
+    let mut transactions = vec![
+        (U256::from(30), 100),
+        (U256::from(10), 50),
+        (U256::from(20), 50),
+    ];
+    let rewards = compute_block_rewards(&mut transactions, &[0.0, 25.0, 50.0, 75.0, 100.0]);
+    let expected_rewards: Vec<_> = [10, 10, 20, 30, 30].into_iter().map(U256::from).collect();
+    assert_eq!(rewards, expected_rewards);
+
+    let rewards = compute_block_rewards(&mut [], &[10.0, 90.0]);
+    assert_eq!(rewards, [U256::zero(); 2]);
+}
+
+#[derive(Debug)]
+struct FeeHistoryTest;
+
+#[async_trait]
+impl HttpTest for FeeHistoryTest {
+    async fn test(
+        &self,
+        client: &DynClient<L2>,
+        pool: &ConnectionPool<Core>,
+    ) -> anyhow::Result<()> {
+        let mut storage = pool.connection().await?;
+        let mut tx_results = vec![];
+        for _ in 0..3 {
+            let l2_tx = create_l2_transaction(200, 200);
+            storage
+                .transactions_dal()
+                .insert_transaction_l2(&l2_tx, TransactionExecutionMetrics::default())
+                .await?;
+            tx_results.push(execute_l2_transaction(l2_tx));
+        }
+        let tx_gas_limit = tx_results[0].transaction.gas_limit().as_u64();
+        let l2_block = L2BlockHeader {
+            base_fee_per_gas: 100,
+            gas_limit: tx_gas_limit * 4,
+            ..create_l2_block(1)
+        };
+        storage.blocks_dal().insert_l2_block(&l2_block).await?;
+        storage
+            .transactions_dal()
+            .mark_txs_as_executed_in_l2_block(
+                l2_block.number,
+                &tx_results,
+                150.into(),
+                ProtocolVersionId::latest(),
+                false,
+            )
+            .await?;
+
+        let history = client
+            .fee_history(2.into(), api::BlockNumber::Latest, vec![0.0, 50.0, 100.0])
+            .await?;
+        assert_eq!(
+            history.oldest_block,
+            zksync_types::web3::BlockNumber::Number(0.into())
+        );
+        assert_eq!(history.base_fee_per_gas.len(), 3);
+        assert_eq!(history.base_fee_per_gas[1], 100.into());
+        assert_eq!(history.gas_used_ratio, [0.0, 0.75]);
+        let reward = history.reward.context("no rewards")?;
+        assert_eq!(reward, [vec![U256::zero(); 3], vec![U256::from(50); 3]]);
+
+        let history = client
+            .fee_history(1.into(), api::BlockNumber::Latest, vec![])
+            .await?;
+        assert_eq!(
+            history.oldest_block,
+            zksync_types::web3::BlockNumber::Number(1.into())
+        );
+        assert_eq!(history.reward, None);
+
+        for invalid_percentiles in [vec![101.0], vec![-1.0], vec![50.0, 10.0]] {
+            let err = client
+                .fee_history(1.into(), api::BlockNumber::Latest, invalid_percentiles)
+                .await
+                .unwrap_err();
+            assert_matches!(
+                err,
+                ClientError::Call(err) if err.code() == ErrorCode::InvalidParams.code()
+            );
+        }
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn fee_history() {
+    test_http_server(FeeHistoryTest).await;
+}
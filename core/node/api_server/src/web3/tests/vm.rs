@@ -63,7 +63,9 @@ impl HttpTest for CallTest {
         client: &DynClient<L2>,
         _pool: &ConnectionPool<Core>,
     ) -> anyhow::Result<()> {
-        let call_result = client.call(Self::call_request(b"pending"), None).await?;
+        let call_result = client
+            .call(Self::call_request(b"pending"), None, None)
+            .await?;
         assert_eq!(call_result.0, b"output");
 
         let valid_block_numbers_and_calldata = [
@@ -74,7 +76,7 @@ impl HttpTest for CallTest {
         for (number, calldata) in valid_block_numbers_and_calldata {
             let number = api::BlockIdVariant::BlockNumber(number);
             let call_result = client
-                .call(Self::call_request(calldata), Some(number))
+                .call(Self::call_request(calldata), Some(number), None)
                 .await?;
             assert_eq!(call_result.0, b"output");
         }
@@ -82,7 +84,7 @@ impl HttpTest for CallTest {
         let invalid_block_number = api::BlockNumber::from(100);
         let number = api::BlockIdVariant::BlockNumber(invalid_block_number);
         let error = client
-            .call(Self::call_request(b"100"), Some(number))
+            .call(Self::call_request(b"100"), Some(number), None)
             .await
             .unwrap_err();
         if let ClientError::Call(error) = error {
@@ -91,6 +93,72 @@ impl HttpTest for CallTest {
             panic!("Unexpected error: {error:?}");
         }
 
+        let options = api::CallOptions {
+            protocol_version: Some(ProtocolVersionId::latest() as u16),
+            ..api::CallOptions::default()
+        };
+        let call_result = client
+            .call(Self::call_request(b"pending"), None, Some(options.into()))
+            .await?;
+        assert_eq!(call_result.0, b"output");
+
+        let options = api::CallOptions {
+            protocol_version: Some(ProtocolVersionId::next() as u16),
+            ..api::CallOptions::default()
+        };
+        let error = client
+            .call(Self::call_request(b"pending"), None, Some(options.into()))
+            .await
+            .unwrap_err();
+        if let ClientError::Call(error) = error {
+            assert_eq!(error.code(), ErrorCode::InvalidParams.code());
+        } else {
+            panic!("Unexpected error: {error:?}");
+        }
+
+        // State overrides can be specified either directly (as in Geth) or as a part of call options.
+        let state_override: api::state_override::StateOverride =
+            serde_json::from_value(serde_json::json!({
+                "0x0101010101010101010101010101010101010101": { "balance": "0x123456" },
+            }))?;
+        let call_result = client
+            .call(
+                Self::call_request(b"pending"),
+                None,
+                Some(api::CallOptionsVariant::StateOverride(
+                    state_override.clone(),
+                )),
+            )
+            .await?;
+        assert_eq!(call_result.0, b"output");
+        let options = api::CallOptions {
+            state_override: Some(state_override),
+            ..api::CallOptions::default()
+        };
+        let call_result = client
+            .call(Self::call_request(b"pending"), None, Some(options.into()))
+            .await?;
+        assert_eq!(call_result.0, b"output");
+
+        let invalid_override: api::state_override::StateOverride =
+            serde_json::from_value(serde_json::json!({
+                "0x0101010101010101010101010101010101010101": { "code": "0x0102" },
+            }))?;
+        let error = client
+            .call(
+                Self::call_request(b"pending"),
+                None,
+                Some(api::CallOptionsVariant::StateOverride(invalid_override)),
+            )
+            .await
+            .unwrap_err();
+        if let ClientError::Call(error) = error {
+            assert_eq!(error.code(), ErrorCode::InvalidParams.code());
+            assert!(error.message().contains("state override"), "{error:?}");
+        } else {
+            panic!("Unexpected error: {error:?}");
+        }
+
         Ok(())
     }
 }
@@ -100,6 +168,81 @@ async fn call_method_basics() {
     test_http_server(CallTest).await;
 }
 
+#[derive(Debug)]
+struct SimulateTransactionTest;
+
+#[async_trait]
+impl HttpTest for SimulateTransactionTest {
+    fn transaction_executor(&self) -> MockTransactionExecutor {
+        let mut tx_executor = MockTransactionExecutor::default();
+        tx_executor.set_call_responses(|tx, _| match tx.execute.calldata() {
+            b"success" => ExecutionResult::Success {
+                output: b"output".to_vec(),
+            },
+            b"revert" => ExecutionResult::Revert {
+                output: VmRevertReason::General {
+                    msg: "oops".to_owned(),
+                    data: vec![],
+                },
+            },
+            data => panic!("Unexpected calldata: {data:?}"),
+        });
+        tx_executor
+    }
+
+    async fn test(
+        &self,
+        client: &DynClient<L2>,
+        _pool: &ConnectionPool<Core>,
+    ) -> anyhow::Result<()> {
+        let state_override: api::state_override::StateOverride =
+            serde_json::from_value(serde_json::json!({
+                "0x0101010101010101010101010101010101010101": { "balance": "0x123456" },
+            }))?;
+        let result = client
+            .simulate_transaction(
+                CallTest::call_request(b"success"),
+                None,
+                Some(state_override),
+            )
+            .await?;
+        assert!(result.success);
+        assert_eq!(result.output.0, b"output");
+        assert_eq!(result.revert_reason, None);
+        assert_eq!(result.gas.gas_limit, 123.into());
+
+        let result = client
+            .simulate_transaction(CallTest::call_request(b"revert"), None, None)
+            .await?;
+        assert!(!result.success);
+        assert!(result.revert_reason.unwrap().contains("oops"));
+
+        let invalid_override: api::state_override::StateOverride =
+            serde_json::from_value(serde_json::json!({
+                "0x0101010101010101010101010101010101010101": { "code": "0x0102" },
+            }))?;
+        let error = client
+            .simulate_transaction(
+                CallTest::call_request(b"success"),
+                None,
+                Some(invalid_override),
+            )
+            .await
+            .unwrap_err();
+        if let ClientError::Call(error) = error {
+            assert_eq!(error.code(), ErrorCode::InvalidParams.code());
+        } else {
+            panic!("Unexpected error: {error:?}");
+        }
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn simulate_transaction_basics() {
+    test_http_server(SimulateTransactionTest).await;
+}
+
 #[derive(Debug)]
 struct CallTestAfterSnapshotRecovery;
 
@@ -120,7 +263,7 @@ impl HttpTest for CallTestAfterSnapshotRecovery {
         _pool: &ConnectionPool<Core>,
     ) -> anyhow::Result<()> {
         let call_result = client
-            .call(CallTest::call_request(b"pending"), None)
+            .call(CallTest::call_request(b"pending"), None, None)
             .await?;
         assert_eq!(call_result.0, b"output");
         let pending_block_number = api::BlockIdVariant::BlockNumber(api::BlockNumber::Pending);
@@ -128,6 +271,7 @@ impl HttpTest for CallTestAfterSnapshotRecovery {
             .call(
                 CallTest::call_request(b"pending"),
                 Some(pending_block_number),
+                None,
             )
             .await?;
         assert_eq!(call_result.0, b"output");
@@ -137,7 +281,7 @@ impl HttpTest for CallTestAfterSnapshotRecovery {
         for number in pruned_block_numbers {
             let number = api::BlockIdVariant::BlockNumber(number.into());
             let error = client
-                .call(CallTest::call_request(b"pruned"), Some(number))
+                .call(CallTest::call_request(b"pruned"), Some(number), None)
                 .await
                 .unwrap_err();
             assert_pruned_block_error(&error, first_local_l2_block);
@@ -147,7 +291,7 @@ impl HttpTest for CallTestAfterSnapshotRecovery {
         for number in first_l2_block_numbers {
             let number = api::BlockIdVariant::BlockNumber(number);
             let call_result = client
-                .call(CallTest::call_request(b"first"), Some(number))
+                .call(CallTest::call_request(b"first"), Some(number), None)
                 .await?;
             assert_eq!(call_result.0, b"output");
         }
@@ -477,6 +621,142 @@ async fn trace_call_basics() {
     test_http_server(TraceCallTest).await;
 }
 
+#[derive(Debug)]
+struct TraceCallOpcodesTest;
+
+#[async_trait]
+impl HttpTest for TraceCallOpcodesTest {
+    fn transaction_executor(&self) -> MockTransactionExecutor {
+        CallTest::create_executor(L2BlockNumber(0))
+    }
+
+    async fn test(
+        &self,
+        client: &DynClient<L2>,
+        _pool: &ConnectionPool<Core>,
+    ) -> anyhow::Result<()> {
+        let call_request = CallTest::call_request(b"pending");
+        let trace = client
+            .trace_call_opcodes(call_request.clone(), None, None)
+            .await?;
+        assert!(!trace.failed);
+        assert_eq!(trace.return_value.0, b"output");
+        // The mock executor doesn't run tracers.
+        assert!(!trace.truncated);
+        assert!(trace.struct_logs.is_empty());
+
+        let options = api::CallOptions {
+            protocol_version: Some(u16::MAX),
+            ..api::CallOptions::default()
+        };
+        let error = client
+            .trace_call_opcodes(call_request, None, Some(options))
+            .await
+            .unwrap_err();
+        if let ClientError::Call(error) = error {
+            assert_eq!(error.code(), ErrorCode::InvalidParams.code());
+        } else {
+            panic!("Unexpected error: {error:?}");
+        }
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn trace_call_with_opcode_tracer() {
+    test_http_server(TraceCallOpcodesTest).await;
+}
+
+#[derive(Debug)]
+struct TraceCallManyTest;
+
+#[async_trait]
+impl HttpTest for TraceCallManyTest {
+    fn transaction_executor(&self) -> MockTransactionExecutor {
+        CallTest::create_executor(L2BlockNumber(0))
+    }
+
+    async fn test(
+        &self,
+        client: &DynClient<L2>,
+        _pool: &ConnectionPool<Core>,
+    ) -> anyhow::Result<()> {
+        let call_requests = vec![
+            CallTest::call_request(b"pending"),
+            CallTest::call_request(b"pending"),
+        ];
+        let call_results = client
+            .trace_call_many(call_requests.clone(), None, None)
+            .await?;
+        assert_eq!(call_results.len(), call_requests.len());
+        for (call_request, call_result) in call_requests.iter().zip(&call_results) {
+            TraceCallTest::assert_debug_call(call_request, call_result);
+        }
+
+        let call_requests = vec![CallTest::call_request(b"first")];
+        let options = api::TraceCallManyOptions {
+            block_override: Some(api::state_override::BlockOverrides {
+                timestamp: Some(1_000.into()),
+                base_fee: None,
+            }),
+            ..api::TraceCallManyOptions::default()
+        };
+        let call_results = client
+            .trace_call_many(
+                call_requests.clone(),
+                Some(api::BlockId::Number(api::BlockNumber::Latest)),
+                Some(options),
+            )
+            .await?;
+        assert_eq!(call_results.len(), 1);
+        TraceCallTest::assert_debug_call(&call_requests[0], &call_results[0]);
+
+        let call_results = client.trace_call_many(vec![], None, None).await?;
+        assert!(call_results.is_empty());
+
+        let too_many_requests = vec![CallTest::call_request(b"pending"); 1_000];
+        let error = client
+            .trace_call_many(too_many_requests, None, None)
+            .await
+            .unwrap_err();
+        if let ClientError::Call(error) = error {
+            assert_eq!(error.code(), ErrorCode::InvalidParams.code());
+        } else {
+            panic!("Unexpected error: {error:?}");
+        }
+
+        let invalid_override: api::state_override::StateOverride =
+            serde_json::from_value(serde_json::json!({
+                "0x0101010101010101010101010101010101010101": { "code": "0x0102" },
+            }))?;
+        let options = api::TraceCallManyOptions {
+            state_override: Some(invalid_override),
+            ..api::TraceCallManyOptions::default()
+        };
+        let error = client
+            .trace_call_many(
+                vec![CallTest::call_request(b"pending")],
+                None,
+                Some(options),
+            )
+            .await
+            .unwrap_err();
+        if let ClientError::Call(error) = error {
+            assert_eq!(error.code(), ErrorCode::InvalidParams.code());
+            assert!(error.message().contains("state override"), "{error:?}");
+        } else {
+            panic!("Unexpected error: {error:?}");
+        }
+
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn trace_call_many_basics() {
+    test_http_server(TraceCallManyTest).await;
+}
+
 #[derive(Debug)]
 struct TraceCallTestAfterSnapshotRecovery;
 
@@ -510,7 +790,7 @@ impl HttpTest for TraceCallTestAfterSnapshotRecovery {
         for number in pruned_block_numbers {
             let number = api::BlockIdVariant::BlockNumber(number.into());
             let error = client
-                .call(CallTest::call_request(b"pruned"), Some(number))
+                .call(CallTest::call_request(b"pruned"), Some(number), None)
                 .await
                 .unwrap_err();
             assert_pruned_block_error(&error, first_local_l2_block);
@@ -13,11 +13,12 @@ use zksync_web3_decl::{
     client::{WsClient, L2},
     jsonrpsee::{
         core::{
-            client::{ClientT, Subscription, SubscriptionClientT},
+            client::{ClientT, Subscription, SubscriptionClientT, SubscriptionKind},
             params::BatchRequestBuilder,
             ClientError,
         },
         rpc_params,
+        types::SubscriptionId,
     },
     namespaces::{EthNamespaceClient, ZksNamespaceClient},
     types::{BlockHeader, Bytes, PubSubFilter},
@@ -49,6 +50,29 @@ async fn wait_for_subscription(
         .expect("Timed out waiting for subscription")
 }
 
+#[allow(clippy::needless_pass_by_ref_mut)] // false positive
+async fn wait_for_detached_subscription(
+    events: &mut mpsc::UnboundedReceiver<PubSubEvent>,
+    sub_type: SubscriptionType,
+) {
+    let wait_future = tokio::time::timeout(TEST_TIMEOUT, async {
+        loop {
+            let event = events
+                .recv()
+                .await
+                .expect("Events emitter unexpectedly dropped");
+            if matches!(event, PubSubEvent::Detached(ty) if ty == sub_type) {
+                break;
+            } else {
+                tracing::trace!(?event, "Skipping event");
+            }
+        }
+    });
+    wait_future
+        .await
+        .expect("Timed out waiting for subscription to be detached")
+}
+
 #[allow(clippy::needless_pass_by_ref_mut)] // false positive
 async fn wait_for_notifiers(
     events: &mut mpsc::UnboundedReceiver<PubSubEvent>,
@@ -351,6 +375,82 @@ async fn basic_subscriptions_after_snapshot_recovery() {
     .await;
 }
 
+#[derive(Debug)]
+struct ResumedSubscriptionTest;
+
+#[async_trait]
+impl WsTest for ResumedSubscriptionTest {
+    async fn test(
+        &self,
+        client: &WsClient<L2>,
+        pool: &ConnectionPool<Core>,
+        mut pub_sub_events: mpsc::UnboundedReceiver<PubSubEvent>,
+    ) -> anyhow::Result<()> {
+        wait_for_notifiers(&mut pub_sub_events, &[SubscriptionType::Blocks]).await;
+
+        let params = rpc_params!["newHeads"];
+        let blocks_subscription = client
+            .subscribe::<BlockHeader, _>("eth_subscribe", params, "eth_unsubscribe")
+            .await?;
+        wait_for_subscription(&mut pub_sub_events, SubscriptionType::Blocks).await;
+        let SubscriptionKind::Subscription(SubscriptionId::Str(cursor)) =
+            blocks_subscription.kind()
+        else {
+            panic!(
+                "Unexpected subscription kind: {:?}",
+                blocks_subscription.kind()
+            );
+        };
+        let cursor = cursor.to_string();
+        blocks_subscription.unsubscribe().await?;
+        wait_for_detached_subscription(&mut pub_sub_events, SubscriptionType::Blocks).await;
+
+        // Create an L2 block while the subscription is detached.
+        let mut storage = pool.connection().await?;
+        let new_l2_block = store_l2_block(&mut storage, L2BlockNumber(1), &[]).await?;
+        drop(storage);
+        wait_for_notifier_l2_block(
+            &mut pub_sub_events,
+            SubscriptionType::Blocks,
+            L2BlockNumber(1),
+        )
+        .await;
+
+        let mut resumed_subscription = client
+            .subscribe::<BlockHeader, _>(
+                "zks_resubscribe",
+                rpc_params![cursor.clone()],
+                "zks_unsubscribe",
+            )
+            .await?;
+        let received_block_header = tokio::time::timeout(TEST_TIMEOUT, resumed_subscription.next())
+            .await
+            .context("Timed out waiting for missed block header")?
+            .context("Resumed subscription terminated")??;
+        assert_eq!(
+            received_block_header.number,
+            Some(new_l2_block.number.0.into())
+        );
+        assert_eq!(received_block_header.hash, Some(new_l2_block.hash));
+
+        // The cursor cannot be reused while the resumed subscription is active.
+        let err = client
+            .subscribe::<BlockHeader, _>("zks_resubscribe", rpc_params![cursor], "zks_unsubscribe")
+            .await
+            .unwrap_err();
+        assert_matches!(
+            err,
+            ClientError::Call(error) if error.code() == ErrorCode::InvalidParams.code()
+        );
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn resumed_subscription() {
+    test_ws_server(ResumedSubscriptionTest).await;
+}
+
 #[derive(Debug)]
 struct LogSubscriptionsTest {
     snapshot_recovery: bool,
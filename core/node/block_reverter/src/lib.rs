@@ -5,7 +5,7 @@ use serde::Serialize;
 use tokio::{fs, sync::Semaphore};
 use zksync_config::{configs::chain::NetworkConfig, ContractsConfig, EthConfig};
 use zksync_contracts::hyperchain_contract;
-use zksync_dal::{ConnectionPool, Core, CoreDal};
+use zksync_dal::{blocks_dal::RollbackStats, Connection, ConnectionPool, Core, CoreDal};
 // Public re-export to simplify the API use.
 pub use zksync_eth_client as eth_client;
 use zksync_eth_client::{
@@ -24,7 +24,7 @@ use zksync_types::{
         SnapshotStorageLogsStorageKey,
     },
     web3::BlockNumber,
-    Address, L1BatchNumber, L2ChainId, H160, H256, U256,
+    Address, L1BatchNumber, L2BlockNumber, L2ChainId, H160, H256, U256,
 };
 
 #[cfg(test)]
@@ -97,6 +97,7 @@ pub struct BlockReverter {
     state_keeper_cache_path: Option<String>,
     merkle_tree_path: Option<String>,
     snapshots_object_store: Option<Arc<dyn ObjectStore>>,
+    audit_actor: String,
 }
 
 impl BlockReverter {
@@ -109,9 +110,17 @@ impl BlockReverter {
             state_keeper_cache_path: None,
             merkle_tree_path: None,
             snapshots_object_store: None,
+            audit_actor: "block_reverter".to_owned(),
         }
     }
 
+    /// Sets the actor recorded in the admin audit log for actions performed by this reverter
+    /// (e.g., `cli:alice`). Defaults to `block_reverter`.
+    pub fn set_audit_actor(&mut self, actor: impl Into<String>) -> &mut Self {
+        self.audit_actor = actor.into();
+        self
+    }
+
     /// Allows rolling back the state past the last batch finalized on L1. If this is disallowed (which is the default),
     /// block reverter will error upon such an attempt.
     ///
@@ -145,10 +154,13 @@ impl BlockReverter {
         self
     }
 
-    /// Rolls back previously enabled DBs (Postgres + RocksDB) and the snapshot object store to a previous state.
-    pub async fn roll_back(&self, last_l1_batch_to_keep: L1BatchNumber) -> anyhow::Result<()> {
+    /// Checks that the node state can be rolled back to the specified L1 batch.
+    async fn check_rollback_target(
+        &self,
+        storage: &mut Connection<'_, Core>,
+        last_l1_batch_to_keep: L1BatchNumber,
+    ) -> anyhow::Result<()> {
         if !self.allow_rolling_back_executed_batches {
-            let mut storage = self.connection_pool.connection().await?;
             let last_executed_l1_batch = storage
                 .blocks_dal()
                 .get_number_of_last_l1_batch_executed_on_eth()
@@ -159,6 +171,189 @@ impl BlockReverter {
             );
         }
 
+        let snapshot_recovery = storage
+            .snapshot_recovery_dal()
+            .get_applied_snapshot_status()
+            .await?;
+        if let Some(recovery) = snapshot_recovery {
+            anyhow::ensure!(
+                last_l1_batch_to_keep > recovery.l1_batch_number,
+                "Cannot roll back to L1 batch #{last_l1_batch_to_keep}: the node was recovered from a snapshot \
+                 for L1 batch #{}, so it has no data for earlier L1 batches",
+                recovery.l1_batch_number
+            );
+        }
+        Ok(())
+    }
+
+    /// Previews rolling back the node state to the specified L1 batch without changing anything (aka dry run).
+    /// The returned plan describes the data that would be removed by [`Self::roll_back()`] with the same params.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the rollback is impossible (e.g., the target L1 batch is executed on L1,
+    /// and rolling back executed batches is not allowed).
+    pub async fn plan_rollback(
+        &self,
+        last_l1_batch_to_keep: L1BatchNumber,
+    ) -> anyhow::Result<RollbackPlan> {
+        let mut storage = self.connection_pool.connection().await?;
+        self.check_rollback_target(&mut storage, last_l1_batch_to_keep)
+            .await?;
+
+        let sealed_l1_batch = storage
+            .blocks_dal()
+            .get_sealed_l1_batch_number()
+            .await?
+            .context("no L1 batches in storage")?;
+        let last_executed_l1_batch = storage
+            .blocks_dal()
+            .get_number_of_last_l1_batch_executed_on_eth()
+            .await?;
+        let snapshot_recovery_l1_batch = storage
+            .snapshot_recovery_dal()
+            .get_applied_snapshot_status()
+            .await?
+            .map(|recovery| recovery.l1_batch_number);
+        let (_, last_l2_block_to_keep) = storage
+            .blocks_dal()
+            .get_l2_block_range_of_l1_batch(last_l1_batch_to_keep)
+            .await?
+            .with_context(|| {
+                format!("L1 batch #{last_l1_batch_to_keep} doesn't contain L2 blocks")
+            })?;
+
+        let mut warnings = vec![];
+        let rolled_back_executed_l1_batch =
+            last_executed_l1_batch.filter(|&executed| executed > last_l1_batch_to_keep);
+        if let Some(executed) = rolled_back_executed_l1_batch {
+            warnings.push(format!(
+                "L1 batches up to #{executed} are executed on L1; rolling them back is only safe for external nodes"
+            ));
+        }
+
+        let postgres = if self.should_roll_back_postgres {
+            let stats = storage
+                .blocks_dal()
+                .get_rollback_stats(last_l1_batch_to_keep, last_l2_block_to_keep)
+                .await?;
+            if stats.snapshots > 0 && self.snapshots_object_store.is_none() {
+                warnings.push(format!(
+                    "metadata for {} snapshot(s) will be removed from Postgres, but snapshot files \
+                     will not be removed since the object store is not provided",
+                    stats.snapshots
+                ));
+            }
+            if self.merkle_tree_path.is_none() {
+                warnings.push(
+                    "Postgres is rolled back without the Merkle tree; if the tree is ahead of the target L1 batch, \
+                     it will need to be rebuilt from scratch"
+                        .to_owned(),
+                );
+            }
+            if self.node_role == NodeRole::Main {
+                warnings.push("consensus hard fork will be performed".to_owned());
+            }
+            Some(stats)
+        } else {
+            None
+        };
+        drop(storage);
+
+        let merkle_tree = if let Some(path) = &self.merkle_tree_path {
+            let path = path.clone();
+            let inspected_path = path.clone();
+            let next_l1_batch = Self::inspect_rocksdb(&path, &mut warnings, move || {
+                let db = RocksDB::new(Path::new(&inspected_path))
+                    .context("failed initializing RocksDB for Merkle tree")?;
+                let tree = ZkSyncTree::new_lightweight(db.into())
+                    .context("failed initializing Merkle tree")?;
+                Ok(tree.next_l1_batch_number())
+            })
+            .await;
+            Some(RocksdbRollbackPlan::new(
+                path,
+                next_l1_batch,
+                last_l1_batch_to_keep,
+            ))
+        } else {
+            None
+        };
+        let state_keeper_cache = if let Some(path) = &self.state_keeper_cache_path {
+            let next_l1_batch = if fs::try_exists(path).await.unwrap_or(false) {
+                match RocksdbStorage::builder(path.as_ref()).await {
+                    Ok(sk_cache) => sk_cache.l1_batch_number().await,
+                    Err(err) => {
+                        warnings.push(format!(
+                            "cannot inspect state keeper cache at `{path}` (is it used by a running node?): {err:#}"
+                        ));
+                        None
+                    }
+                }
+            } else {
+                warnings.push(format!(
+                    "state keeper cache doesn't exist at `{path}`; rollback will fail"
+                ));
+                None
+            };
+            Some(RocksdbRollbackPlan::new(
+                path.clone(),
+                next_l1_batch,
+                last_l1_batch_to_keep,
+            ))
+        } else {
+            None
+        };
+
+        Ok(RollbackPlan {
+            last_l1_batch_to_keep,
+            last_l2_block_to_keep,
+            sealed_l1_batch,
+            last_executed_l1_batch,
+            snapshot_recovery_l1_batch,
+            postgres,
+            merkle_tree,
+            state_keeper_cache,
+            removes_snapshot_files: self.snapshots_object_store.is_some(),
+            warnings,
+        })
+    }
+
+    /// Returns the next L1 batch for a RocksDB instance, or `None` if the instance doesn't exist or cannot be opened.
+    async fn inspect_rocksdb(
+        path: &str,
+        warnings: &mut Vec<String>,
+        inspect: impl FnOnce() -> anyhow::Result<L1BatchNumber> + Send + 'static,
+    ) -> Option<L1BatchNumber> {
+        if !fs::try_exists(path).await.unwrap_or(false) {
+            warnings.push(format!(
+                "RocksDB instance doesn't exist at `{path}`; skipping"
+            ));
+            return None;
+        }
+        let result = tokio::task::spawn_blocking(inspect)
+            .await
+            .context("RocksDB inspection panicked")
+            .and_then(|res| res);
+        match result {
+            Ok(next_l1_batch) => Some(next_l1_batch),
+            Err(err) => {
+                warnings.push(format!(
+                    "cannot inspect RocksDB instance at `{path}` (is it used by a running node?): {err:#}"
+                ));
+                None
+            }
+        }
+    }
+
+    /// Rolls back previously enabled DBs (Postgres + RocksDB) and the snapshot object store to a previous state.
+    /// Postgres data is rolled back in a single transaction. Use [`Self::plan_rollback()`] to preview the changes.
+    pub async fn roll_back(&self, last_l1_batch_to_keep: L1BatchNumber) -> anyhow::Result<()> {
+        let mut storage = self.connection_pool.connection().await?;
+        self.check_rollback_target(&mut storage, last_l1_batch_to_keep)
+            .await?;
+        drop(storage);
+
         // Tree needs to be rolled back first to keep the state recoverable
         self.roll_back_rocksdb_instances(last_l1_batch_to_keep)
             .await?;
@@ -177,6 +372,33 @@ impl BlockReverter {
             );
         }
 
+        let params = serde_json::json!({
+            "last_l1_batch_to_keep": last_l1_batch_to_keep.0,
+            "node_role": format!("{:?}", self.node_role),
+            "postgres": self.should_roll_back_postgres,
+            "merkle_tree": self.merkle_tree_path.is_some(),
+            "state_keeper_cache": self.state_keeper_cache_path.is_some(),
+            "deleted_snapshots": deleted_snapshots.len(),
+        });
+        self.record_audit_entry("revert_l1_batches", params).await
+    }
+
+    async fn record_audit_entry(
+        &self,
+        action: &str,
+        params: serde_json::Value,
+    ) -> anyhow::Result<()> {
+        let id = self
+            .connection_pool
+            .connection()
+            .await?
+            .admin_audit_log_dal()
+            .insert_entry(&self.audit_actor, action, &params)
+            .await?;
+        tracing::info!(
+            "Recorded `{action}` by `{}` in admin audit log with ID {id}",
+            self.audit_actor
+        );
         Ok(())
     }
 
@@ -522,6 +744,13 @@ impl BlockReverter {
             .await
             .context("failed sending revert transaction")?;
         tracing::info!("Sent revert transaction to L1 with hash {hash:?}");
+        let params = serde_json::json!({
+            "last_l1_batch_to_keep": last_l1_batch_to_keep.0,
+            "nonce": nonce,
+            "tx_hash": hash,
+        });
+        self.record_audit_entry("send_revert_transaction", params)
+            .await?;
 
         loop {
             let maybe_receipt = eth_client
@@ -623,7 +852,58 @@ impl BlockReverter {
             .eth_sender_dal()
             .clear_failed_transactions()
             .await?;
-        Ok(())
+        self.record_audit_entry("clear_failed_l1_transactions", serde_json::json!({}))
+            .await
+    }
+}
+
+/// Preview of rolling back the node state returned by [`BlockReverter::plan_rollback()`].
+#[derive(Debug, Clone, Serialize)]
+pub struct RollbackPlan {
+    pub last_l1_batch_to_keep: L1BatchNumber,
+    pub last_l2_block_to_keep: L2BlockNumber,
+    /// Latest sealed L1 batch in Postgres.
+    pub sealed_l1_batch: L1BatchNumber,
+    /// Latest L1 batch executed on L1 according to Postgres.
+    pub last_executed_l1_batch: Option<L1BatchNumber>,
+    /// L1 batch the node was recovered from if the node was recovered from a snapshot. The node cannot be
+    /// rolled back to this or earlier L1 batches.
+    pub snapshot_recovery_l1_batch: Option<L1BatchNumber>,
+    /// Numbers of removed or reset Postgres rows; `None` if rolling back Postgres is not enabled.
+    pub postgres: Option<RollbackStats>,
+    /// `None` if rolling back the Merkle tree is not enabled.
+    pub merkle_tree: Option<RocksdbRollbackPlan>,
+    /// `None` if rolling back the state keeper cache is not enabled.
+    pub state_keeper_cache: Option<RocksdbRollbackPlan>,
+    /// Whether files of the removed protocol snapshots are removed from the object store.
+    pub removes_snapshot_files: bool,
+    /// Potential issues with the rollback that the operator should be aware of.
+    pub warnings: Vec<String>,
+}
+
+/// Rollback of a RocksDB instance (the Merkle tree or the state keeper cache).
+#[derive(Debug, Clone, Serialize)]
+pub struct RocksdbRollbackPlan {
+    pub path: String,
+    /// Next L1 batch to be processed by the instance; `None` if the instance doesn't exist or cannot be inspected.
+    pub next_l1_batch: Option<L1BatchNumber>,
+    /// Number of rolled back L1 batches (for the Merkle tree, the number of removed tree versions).
+    pub rolled_back_l1_batches: u32,
+}
+
+impl RocksdbRollbackPlan {
+    fn new(
+        path: String,
+        next_l1_batch: Option<L1BatchNumber>,
+        last_l1_batch_to_keep: L1BatchNumber,
+    ) -> Self {
+        let rolled_back_l1_batches =
+            next_l1_batch.map_or(0, |next| next.0.saturating_sub(last_l1_batch_to_keep.0 + 1));
+        Self {
+            path,
+            next_l1_batch,
+            rolled_back_l1_batches,
+        }
     }
 }
 
@@ -62,6 +62,7 @@ async fn setup_storage(storage: &mut Connection<'_, Core>, storage_logs: &[Stora
             protocol_version: Some(ProtocolVersionId::latest()),
             virtual_blocks: 1,
             gas_limit: 0,
+            logs_bloom: Default::default(),
         };
         storage
             .blocks_dal()
@@ -146,10 +147,21 @@ async fn block_reverter_basics(sync_merkle_tree: bool) {
         .enable_rolling_back_postgres()
         .enable_rolling_back_merkle_tree(merkle_tree_path.to_str().unwrap().to_owned())
         .enable_rolling_back_state_keeper_cache(sk_cache_path.to_str().unwrap().to_owned())
+        .set_audit_actor("test")
         .roll_back(L1BatchNumber(5))
         .await
         .unwrap();
 
+    let audit_entries = storage
+        .admin_audit_log_dal()
+        .get_entries(None, 10)
+        .await
+        .unwrap();
+    assert_eq!(audit_entries.len(), 1);
+    assert_eq!(audit_entries[0].actor, "test");
+    assert_eq!(audit_entries[0].action, "revert_l1_batches");
+    assert_eq!(audit_entries[0].params["last_l1_batch_to_keep"], 5);
+
     let last_l1_batch_number = storage
         .blocks_dal()
         .get_sealed_l1_batch_number()
@@ -200,6 +212,66 @@ async fn block_reverter_basics(sync_merkle_tree: bool) {
     }
 }
 
+#[tokio::test]
+async fn planning_rollback() {
+    let storage_logs = gen_storage_logs();
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let mut storage = pool.connection().await.unwrap();
+    setup_storage(&mut storage, &storage_logs).await;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let merkle_tree_path = temp_dir.path().join("tree");
+    let l1_batch_hashes = initialize_merkle_tree(&merkle_tree_path, &storage_logs);
+    for (number, hash) in (0..).zip(l1_batch_hashes) {
+        storage
+            .blocks_dal()
+            .set_l1_batch_hash(L1BatchNumber(number), hash)
+            .await
+            .unwrap();
+    }
+
+    let mut block_reverter = BlockReverter::new(NodeRole::External, pool.clone());
+    block_reverter
+        .enable_rolling_back_postgres()
+        .enable_rolling_back_merkle_tree(merkle_tree_path.to_str().unwrap().to_owned());
+    let plan = block_reverter
+        .plan_rollback(L1BatchNumber(5))
+        .await
+        .unwrap();
+
+    assert_eq!(plan.sealed_l1_batch, L1BatchNumber(9));
+    assert_eq!(plan.last_l2_block_to_keep, L2BlockNumber(5));
+    assert_eq!(plan.snapshot_recovery_l1_batch, None);
+    let postgres = plan.postgres.unwrap();
+    assert_eq!(postgres.l1_batches, 4);
+    assert_eq!(postgres.l2_blocks, 4);
+    assert_eq!(postgres.storage_logs, 4);
+    assert_eq!(postgres.initial_writes, 4);
+    assert_eq!(postgres.snapshots, 0);
+    let merkle_tree = plan.merkle_tree.unwrap();
+    assert_eq!(merkle_tree.next_l1_batch, Some(L1BatchNumber(10)));
+    assert_eq!(merkle_tree.rolled_back_l1_batches, 4);
+    assert!(plan.state_keeper_cache.is_none());
+    assert!(plan.warnings.is_empty(), "{:?}", plan.warnings);
+
+    // Planning must not change the node state.
+    let last_l1_batch_number = storage
+        .blocks_dal()
+        .get_sealed_l1_batch_number()
+        .await
+        .unwrap();
+    assert_eq!(last_l1_batch_number, Some(L1BatchNumber(9)));
+    let db = RocksDB::new(&merkle_tree_path).unwrap();
+    let tree = ZkSyncTree::new(db.into()).unwrap();
+    assert_eq!(tree.next_l1_batch_number(), L1BatchNumber(10));
+    let audit_entries = storage
+        .admin_audit_log_dal()
+        .get_entries(None, 10)
+        .await
+        .unwrap();
+    assert!(audit_entries.is_empty());
+}
+
 async fn create_mock_snapshot(
     storage: &mut Connection<'_, Core>,
     object_store: &dyn ObjectStore,
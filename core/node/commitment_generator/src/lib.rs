@@ -1,10 +1,11 @@
-use std::{num::NonZeroU32, ops, sync::Arc, time::Duration};
+use std::{collections::HashMap, num::NonZeroU32, ops, sync::Arc, time::Duration};
 
 use anyhow::Context;
+use futures::{stream::FuturesOrdered, FutureExt, StreamExt};
 use itertools::Itertools;
 use multivm::zk_evm_latest::ethereum_types::U256;
 use tokio::{sync::watch, task::JoinHandle};
-use zksync_dal::{ConnectionPool, Core, CoreDal};
+use zksync_dal::{Connection, ConnectionPool, Core, CoreDal};
 use zksync_health_check::{Health, HealthStatus, HealthUpdater, ReactiveHealthCheck};
 use zksync_l1_contract_interface::i_executor::commit::kzg::pubdata_to_blob_commitments;
 use zksync_types::{
@@ -20,11 +21,13 @@ use zksync_types::{
 use zksync_utils::h256_to_u256;
 
 use crate::{
-    metrics::{CommitmentStage, METRICS},
+    metrics::{CommitmentStage, PreviousValuesSource, METRICS},
+    recent_writes::RecentWritesCache,
     utils::{CommitmentComputer, RealCommitmentComputer},
 };
 
 mod metrics;
+mod recent_writes;
 #[cfg(test)]
 mod tests;
 mod utils;
@@ -33,6 +36,11 @@ pub mod validation_task;
 const SLEEP_INTERVAL: Duration = Duration::from_millis(100);
 
 /// Component responsible for generating commitments for L1 batches.
+///
+/// Up to the configured number of L1 batches are processed concurrently in a sliding window: a new batch is scheduled
+/// as soon as the oldest in-flight one is finished, and results are persisted strictly in the L1 batch order.
+/// Final values of storage slots touched in recently processed batches are cached, so that previous values
+/// for the following batches can be mostly resolved without querying Postgres.
 #[derive(Debug)]
 pub struct CommitmentGenerator {
     computer: Arc<dyn CommitmentComputer>,
@@ -40,9 +48,13 @@ pub struct CommitmentGenerator {
     health_updater: HealthUpdater,
     commitment_mode: L1BatchCommitmentMode,
     parallelism: NonZeroU32,
+    recent_writes: RecentWritesCache,
 }
 
 impl CommitmentGenerator {
+    /// Number of recently processed L1 batches, the storage writes of which are cached.
+    const RECENT_WRITES_CAPACITY: usize = 32;
+
     /// Creates a commitment generator with the provided mode.
     pub fn new(
         connection_pool: ConnectionPool<Core>,
@@ -54,6 +66,7 @@ impl CommitmentGenerator {
             health_updater: ReactiveHealthCheck::new("commitment_generator").1,
             commitment_mode,
             parallelism: Self::default_parallelism(),
+            recent_writes: RecentWritesCache::new(Self::RECENT_WRITES_CAPACITY),
         }
     }
 
@@ -184,10 +197,14 @@ impl CommitmentGenerator {
             .await?;
         let touched_hashed_keys: Vec<_> =
             touched_slots.keys().map(|key| key.hashed_key()).collect();
-        let previous_values = connection
-            .storage_logs_dal()
-            .get_previous_storage_values(&touched_hashed_keys, l1_batch_number)
+        let previous_values = self
+            .previous_storage_values(&mut connection, &touched_hashed_keys, l1_batch_number)
             .await?;
+        let final_values = touched_slots
+            .iter()
+            .map(|(key, value)| (key.hashed_key(), *value))
+            .collect();
+        self.recent_writes.insert(l1_batch_number, final_values);
         let l1_batches_for_initial_writes = connection
             .storage_logs_dal()
             .get_l1_batches_and_indices_for_initial_writes(&touched_hashed_keys)
@@ -285,6 +302,31 @@ impl CommitmentGenerator {
         Ok(input)
     }
 
+    /// Resolves previous values of the touched slots, first using recently processed L1 batches and then falling back
+    /// to Postgres for the remaining slots.
+    async fn previous_storage_values(
+        &self,
+        connection: &mut Connection<'_, Core>,
+        hashed_keys: &[H256],
+        l1_batch_number: L1BatchNumber,
+    ) -> anyhow::Result<HashMap<H256, Option<H256>>> {
+        let (mut previous_values, unresolved_keys) = self
+            .recent_writes
+            .previous_values(hashed_keys, l1_batch_number);
+        METRICS.previous_values[&PreviousValuesSource::Cache].inc_by(previous_values.len() as u64);
+        METRICS.previous_values[&PreviousValuesSource::Postgres]
+            .inc_by(unresolved_keys.len() as u64);
+
+        if !unresolved_keys.is_empty() {
+            let values_from_postgres = connection
+                .storage_logs_dal()
+                .get_previous_storage_values(&unresolved_keys, l1_batch_number)
+                .await?;
+            previous_values.extend(values_from_postgres);
+        }
+        Ok(previous_values)
+    }
+
     async fn process_batch(
         &self,
         l1_batch_number: L1BatchNumber,
@@ -295,54 +337,49 @@ impl CommitmentGenerator {
         let latency = latency.observe();
         tracing::debug!("Prepared commitment input for L1 batch #{l1_batch_number} in {latency:?}");
 
-        let latency =
-            METRICS.generate_commitment_latency_stage[&CommitmentStage::Calculate].start();
-        let mut commitment = L1BatchCommitment::new(input);
-        self.post_process_commitment(&mut commitment);
-        let artifacts = commitment.artifacts();
-        let latency = latency.observe();
-        tracing::debug!(
-            "Generated commitment artifacts for L1 batch #{l1_batch_number} in {latency:?}"
-        );
-        Ok(artifacts)
+        // Commitment calculation is CPU-bound, so it's moved off the async runtime to not block preparing inputs
+        // for other in-flight batches.
+        let commitment_mode = self.commitment_mode;
+        let calculation_task = tokio::task::spawn_blocking(move || {
+            let latency =
+                METRICS.generate_commitment_latency_stage[&CommitmentStage::Calculate].start();
+            let mut commitment = L1BatchCommitment::new(input);
+            Self::post_process_commitment(commitment_mode, &mut commitment);
+            let artifacts = commitment.artifacts();
+            let latency = latency.observe();
+            tracing::debug!(
+                "Generated commitment artifacts for L1 batch #{l1_batch_number} in {latency:?}"
+            );
+            artifacts
+        });
+        calculation_task.await.with_context(|| {
+            format!("commitment calculation panicked for L1 batch #{l1_batch_number}")
+        })
     }
 
-    async fn step(
+    async fn save_artifacts(
         &self,
-        l1_batch_numbers: ops::RangeInclusive<L1BatchNumber>,
+        l1_batch_number: L1BatchNumber,
+        artifacts: &L1BatchCommitmentArtifacts,
     ) -> anyhow::Result<()> {
-        let iterable_numbers =
-            (l1_batch_numbers.start().0..=l1_batch_numbers.end().0).map(L1BatchNumber);
-        let batch_futures = iterable_numbers.map(|number| async move {
-            let artifacts = self
-                .process_batch(number)
-                .await
-                .with_context(|| format!("failed processing L1 batch #{number}"))?;
-            anyhow::Ok((number, artifacts))
-        });
-        let artifacts = futures::future::try_join_all(batch_futures).await?;
-
+        let latency =
+            METRICS.generate_commitment_latency_stage[&CommitmentStage::SaveResults].start();
         let mut connection = self
             .connection_pool
             .connection_tagged("commitment_generator")
             .await?;
-        // Saving changes atomically is not required here; since we save batches in order, if we encounter a DB error,
-        // the commitment generator will be able to recover gracefully.
-        for (l1_batch_number, artifacts) in artifacts {
-            let latency =
-                METRICS.generate_commitment_latency_stage[&CommitmentStage::SaveResults].start();
-            connection
-                .blocks_dal()
-                .save_l1_batch_commitment_artifacts(l1_batch_number, &artifacts)
-                .await?;
-            let latency = latency.observe();
-            tracing::debug!(
-                "Stored commitment artifacts for L1 batch #{l1_batch_number} in {latency:?}"
-            );
-        }
+        connection
+            .blocks_dal()
+            .save_l1_batch_commitment_artifacts(l1_batch_number, artifacts)
+            .await?;
+        drop(connection);
+        let latency = latency.observe();
+        tracing::debug!(
+            "Stored commitment artifacts for L1 batch #{l1_batch_number} in {latency:?}"
+        );
 
         let health_details = serde_json::json!({
-            "l1_batch_number": *l1_batch_numbers.end(),
+            "l1_batch_number": l1_batch_number,
         });
         self.health_updater
             .update(Health::from(HealthStatus::Ready).with_details(health_details));
@@ -367,8 +404,11 @@ impl CommitmentGenerator {
         }
     }
 
-    fn post_process_commitment(&self, commitment: &mut L1BatchCommitment) {
-        match (self.commitment_mode, &mut commitment.auxiliary_output) {
+    fn post_process_commitment(
+        commitment_mode: L1BatchCommitmentMode,
+        commitment: &mut L1BatchCommitment,
+    ) {
+        match (commitment_mode, &mut commitment.auxiliary_output) {
             (
                 L1BatchCommitmentMode::Validium,
                 L1BatchAuxiliaryOutput::PostBoojum {
@@ -384,7 +424,19 @@ impl CommitmentGenerator {
         }
     }
 
+    #[cfg(test)]
     async fn next_batch_range(&self) -> anyhow::Result<Option<ops::RangeInclusive<L1BatchNumber>>> {
+        self.next_batch_range_after(None, self.parallelism.get())
+            .await
+    }
+
+    /// Returns the range of L1 batches ready for commitment generation, starting after `last_scheduled_batch`
+    /// (if specified) and containing at most `max_count` batches.
+    async fn next_batch_range_after(
+        &self,
+        last_scheduled_batch: Option<L1BatchNumber>,
+        max_count: u32,
+    ) -> anyhow::Result<Option<ops::RangeInclusive<L1BatchNumber>>> {
         let mut connection = self
             .connection_pool
             .connection_tagged("commitment_generator")
@@ -409,8 +461,15 @@ impl CommitmentGenerator {
             "Unexpected node state: next L1 batch ready for commitment generation (#{next_batch_number}) is greater than \
              the last L1 batch ready for commitment generation (#{last_batch_number})"
         );
-        let last_batch_number =
-            last_batch_number.min(next_batch_number + self.parallelism.get() - 1);
+        // Batches scheduled for processing don't have commitment artifacts persisted yet, so they must be skipped explicitly.
+        let next_batch_number = match last_scheduled_batch {
+            Some(last_scheduled) => next_batch_number.max(last_scheduled + 1),
+            None => next_batch_number,
+        };
+        if next_batch_number > last_batch_number || max_count == 0 {
+            return Ok(None);
+        }
+        let last_batch_number = last_batch_number.min(next_batch_number + max_count - 1);
         Ok(Some(next_batch_number..=last_batch_number))
     }
 
@@ -432,24 +491,61 @@ impl CommitmentGenerator {
         }
         self.health_updater.update(HealthStatus::Ready.into());
 
+        let parallelism = self.parallelism.get() as usize;
+        let mut in_flight = FuturesOrdered::new();
+        let mut last_scheduled_batch = None;
         loop {
             if *stop_receiver.borrow() {
                 tracing::info!("Stop signal received, commitment generator is shutting down");
                 break;
             }
 
-            let Some(l1_batch_numbers) = self.next_batch_range().await? else {
+            let spare_capacity = parallelism - in_flight.len();
+            if spare_capacity > 0 {
+                // `unwrap()` is safe: `spare_capacity <= parallelism`, which is a `u32`
+                let max_count = u32::try_from(spare_capacity).unwrap();
+                if let Some(l1_batch_numbers) = self
+                    .next_batch_range_after(last_scheduled_batch, max_count)
+                    .await?
+                {
+                    tracing::info!(
+                        "Scheduled commitment generation for L1 batches #{l1_batch_numbers:?}"
+                    );
+                    for number in l1_batch_numbers.start().0..=l1_batch_numbers.end().0 {
+                        let number = L1BatchNumber(number);
+                        in_flight.push_back(self.process_batch(number).map(move |res| {
+                            let artifacts = res
+                                .with_context(|| format!("failed processing L1 batch #{number}"))?;
+                            anyhow::Ok((number, artifacts))
+                        }));
+                    }
+                    last_scheduled_batch = Some(*l1_batch_numbers.end());
+                    METRICS.in_flight_batches.set(in_flight.len());
+                }
+            }
+
+            let completed = if in_flight.is_empty() {
+                None
+            } else if in_flight.len() < parallelism {
+                // Check for new batches periodically while there is spare capacity.
+                tokio::select! {
+                    res = in_flight.next() => res,
+                    () = tokio::time::sleep(SLEEP_INTERVAL) => continue,
+                }
+            } else {
+                in_flight.next().await
+            };
+            let Some(completed) = completed else {
                 tokio::time::sleep(SLEEP_INTERVAL).await;
                 continue;
             };
 
-            tracing::info!("Started commitment generation for L1 batches #{l1_batch_numbers:?}");
-            let step_latency = METRICS.step_latency.start();
-            self.step(l1_batch_numbers.clone()).await?;
-            let step_latency = step_latency.observe();
-            let batch_count = l1_batch_numbers.end().0 - l1_batch_numbers.start().0 + 1;
-            METRICS.step_batch_count.observe(batch_count.into());
-            tracing::info!("Finished commitment generation for L1 batches #{l1_batch_numbers:?} in {step_latency:?} ({:?} per batch)", step_latency / batch_count);
+            let (l1_batch_number, artifacts) = completed?;
+            // Saving changes atomically is not required here; since we save batches in order, if we encounter a DB error,
+            // the commitment generator will be able to recover gracefully.
+            self.save_artifacts(l1_batch_number, &artifacts).await?;
+            METRICS.in_flight_batches.set(in_flight.len());
+            tracing::info!("Finished commitment generation for L1 batch #{l1_batch_number}");
         }
         Ok(())
     }
@@ -1,6 +1,8 @@
 use std::time::Duration;
 
-use vise::{Buckets, EncodeLabelSet, EncodeLabelValue, Family, Histogram, Metrics, Unit};
+use vise::{
+    Buckets, Counter, EncodeLabelSet, EncodeLabelValue, Family, Gauge, Histogram, Metrics, Unit,
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EncodeLabelValue, EncodeLabelSet)]
 #[metrics(label = "stage", rename_all = "snake_case")]
@@ -10,7 +12,14 @@ pub(super) enum CommitmentStage {
     SaveResults,
 }
 
-const BATCH_COUNT_BUCKETS: Buckets = Buckets::linear(1.0..=16.0, 1.0);
+/// Source of previous storage slot values used in state diffs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EncodeLabelValue, EncodeLabelSet)]
+#[metrics(label = "source", rename_all = "snake_case")]
+pub(super) enum PreviousValuesSource {
+    /// Values written in recently processed L1 batches.
+    Cache,
+    Postgres,
+}
 
 /// Metrics for the commitment generator.
 #[derive(Debug, Metrics)]
@@ -26,12 +35,10 @@ pub(super) struct CommitmentGeneratorMetrics {
     #[metrics(buckets = Buckets::LATENCIES, unit = Unit::Seconds)]
     pub events_queue_commitment_latency: Histogram<Duration>,
 
-    /// Latency of processing a continuous chunk of L1 batches during a single step of the generator.
-    #[metrics(buckets = Buckets::LATENCIES, unit = Unit::Seconds)]
-    pub step_latency: Histogram<Duration>,
-    /// Number of L1 batches processed during a single step.
-    #[metrics(buckets = BATCH_COUNT_BUCKETS)]
-    pub step_batch_count: Histogram<u64>,
+    /// Number of L1 batches currently being processed by the generator.
+    pub in_flight_batches: Gauge<usize>,
+    /// Number of previous storage slot values resolved from each source.
+    pub previous_values: Family<PreviousValuesSource, Counter>,
 }
 
 #[vise::register]
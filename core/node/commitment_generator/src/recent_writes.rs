@@ -0,0 +1,123 @@
+//! In-memory cache of storage writes in recently processed L1 batches.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::{Arc, Mutex},
+};
+
+use zksync_types::{L1BatchNumber, H256};
+
+/// Final values of storage slots touched in recently processed L1 batches (keyed by the hashed slot key).
+///
+/// Allows resolving previous values of slots for the following L1 batches without querying Postgres;
+/// this is effective since many slots (e.g., the fee account balance or system contract state) are touched
+/// in almost every L1 batch.
+#[derive(Debug)]
+pub(crate) struct RecentWritesCache {
+    capacity: usize,
+    batches: Mutex<BTreeMap<L1BatchNumber, Arc<HashMap<H256, H256>>>>,
+}
+
+impl RecentWritesCache {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            batches: Mutex::default(),
+        }
+    }
+
+    /// Inserts final values of all slots touched in the specified L1 batch, evicting the oldest batches if necessary.
+    pub fn insert(&self, l1_batch_number: L1BatchNumber, final_values: HashMap<H256, H256>) {
+        let mut batches = self.batches.lock().unwrap();
+        batches.insert(l1_batch_number, Arc::new(final_values));
+        while batches.len() > self.capacity {
+            batches.pop_first();
+        }
+    }
+
+    /// Resolves values of the specified slots before the start of `next_l1_batch`. Only L1 batches forming
+    /// a contiguous range ending at `next_l1_batch - 1` are considered; slots not touched in these batches
+    /// are returned as unresolved.
+    pub fn previous_values(
+        &self,
+        hashed_keys: &[H256],
+        next_l1_batch: L1BatchNumber,
+    ) -> (HashMap<H256, Option<H256>>, Vec<H256>) {
+        let contiguous_batches: Vec<_> = {
+            let batches = self.batches.lock().unwrap();
+            let mut expected_number = next_l1_batch;
+            batches
+                .range(..next_l1_batch)
+                .rev()
+                .take_while(|(&number, _)| {
+                    let is_contiguous = number + 1 == expected_number;
+                    expected_number = number;
+                    is_contiguous
+                })
+                .map(|(_, values)| values.clone())
+                .collect()
+        };
+
+        let mut resolved = HashMap::new();
+        let mut unresolved = vec![];
+        for &hashed_key in hashed_keys {
+            let value = contiguous_batches
+                .iter()
+                .find_map(|values| values.get(&hashed_key));
+            if let Some(&value) = value {
+                resolved.insert(hashed_key, Some(value));
+            } else {
+                unresolved.push(hashed_key);
+            }
+        }
+        (resolved, unresolved)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolving_previous_values() {
+        let cache = RecentWritesCache::new(3);
+        let key = H256::repeat_byte;
+        let value = H256::from_low_u64_be;
+
+        cache.insert(
+            L1BatchNumber(1),
+            HashMap::from([(key(1), value(1)), (key(2), value(1))]),
+        );
+        cache.insert(L1BatchNumber(2), HashMap::from([(key(1), value(2))]));
+
+        let keys = [key(1), key(2), key(3)];
+        let (resolved, unresolved) = cache.previous_values(&keys, L1BatchNumber(3));
+        assert_eq!(
+            resolved,
+            HashMap::from([(key(1), Some(value(2))), (key(2), Some(value(1)))])
+        );
+        assert_eq!(unresolved, [key(3)]);
+
+        // L1 batch #3 is missing, so nothing can be resolved for L1 batch #4.
+        let (resolved, unresolved) = cache.previous_values(&keys, L1BatchNumber(4));
+        assert!(resolved.is_empty());
+        assert_eq!(unresolved, keys);
+
+        // Only batches before the requested one are considered.
+        let (resolved, _) = cache.previous_values(&keys, L1BatchNumber(2));
+        assert_eq!(
+            resolved,
+            HashMap::from([(key(1), Some(value(1))), (key(2), Some(value(1)))])
+        );
+
+        cache.insert(L1BatchNumber(3), HashMap::from([(key(3), value(3))]));
+        cache.insert(L1BatchNumber(4), HashMap::new());
+        // L1 batch #1 is evicted.
+        let (resolved, unresolved) = cache.previous_values(&keys, L1BatchNumber(5));
+        assert_eq!(
+            resolved,
+            HashMap::from([(key(1), Some(value(2))), (key(3), Some(value(3)))])
+        );
+        assert_eq!(unresolved, [key(2)]);
+    }
+}
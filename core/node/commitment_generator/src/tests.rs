@@ -12,6 +12,11 @@ use zksync_types::{
 
 use super::*;
 
+/// Storage slot overwritten in every sealed L1 batch.
+fn shared_storage_key() -> StorageKey {
+    StorageKey::new(AccountTreeId::new(Address::repeat_byte(2)), H256::zero())
+}
+
 async fn seal_l1_batch(storage: &mut Connection<'_, Core>, number: L1BatchNumber) {
     let l2_block = create_l2_block(number.0);
     storage
@@ -23,15 +28,22 @@ async fn seal_l1_batch(storage: &mut Connection<'_, Core>, number: L1BatchNumber
         AccountTreeId::new(Address::repeat_byte(1)),
         H256::from_low_u64_be(number.0.into()),
     );
-    let storage_log = StorageLog::new_write_log(storage_key, H256::repeat_byte(0xff));
+    let storage_logs = vec![
+        StorageLog::new_write_log(storage_key, H256::repeat_byte(0xff)),
+        StorageLog::new_write_log(shared_storage_key(), H256::from_low_u64_be(number.0.into())),
+    ];
     storage
         .storage_logs_dal()
-        .insert_storage_logs(l2_block.number, &[(H256::zero(), vec![storage_log])])
+        .insert_storage_logs(l2_block.number, &[(H256::zero(), storage_logs)])
         .await
         .unwrap();
+    let mut initial_writes = vec![storage_key];
+    if number == L1BatchNumber(1) {
+        initial_writes.push(shared_storage_key());
+    }
     storage
         .storage_logs_dedup_dal()
-        .insert_initial_writes(number, &[storage_key])
+        .insert_initial_writes(number, &initial_writes)
         .await
         .unwrap();
 
@@ -161,6 +173,84 @@ async fn determining_batch_range() {
     );
 }
 
+#[tokio::test]
+async fn determining_batch_range_with_scheduled_batches() {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let mut storage = pool.connection().await.unwrap();
+    insert_genesis_batch(&mut storage, &GenesisParams::mock())
+        .await
+        .unwrap();
+    for number in 1..=5 {
+        seal_l1_batch(&mut storage, L1BatchNumber(number)).await;
+        save_l1_batch_tree_data(&mut storage, L1BatchNumber(number)).await;
+    }
+
+    let generator = create_commitment_generator(pool);
+    let range = generator.next_batch_range_after(None, 2).await.unwrap();
+    assert_eq!(range, Some(L1BatchNumber(1)..=L1BatchNumber(2)));
+    let range = generator
+        .next_batch_range_after(Some(L1BatchNumber(2)), 2)
+        .await
+        .unwrap();
+    assert_eq!(range, Some(L1BatchNumber(3)..=L1BatchNumber(4)));
+    let range = generator
+        .next_batch_range_after(Some(L1BatchNumber(4)), 2)
+        .await
+        .unwrap();
+    assert_eq!(range, Some(L1BatchNumber(5)..=L1BatchNumber(5)));
+    let range = generator
+        .next_batch_range_after(Some(L1BatchNumber(5)), 2)
+        .await
+        .unwrap();
+    assert_eq!(range, None);
+}
+
+#[tokio::test]
+async fn previous_values_are_reused_from_recent_batches() {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let mut storage = pool.connection().await.unwrap();
+    insert_genesis_batch(&mut storage, &GenesisParams::mock())
+        .await
+        .unwrap();
+    for number in 1..=3 {
+        seal_l1_batch(&mut storage, L1BatchNumber(number)).await;
+        save_l1_batch_tree_data(&mut storage, L1BatchNumber(number)).await;
+    }
+
+    let generator = create_commitment_generator(pool.clone());
+    let shared_key = shared_storage_key();
+    for number in 1..=3 {
+        let number = L1BatchNumber(number);
+        let input = generator.prepare_input(number).await.unwrap();
+        let CommitmentInput::PostBoojum { state_diffs, .. } = &input else {
+            panic!("unexpected input: {input:?}");
+        };
+        let shared_slot_diff = state_diffs
+            .iter()
+            .find(|diff| diff.address == *shared_key.address())
+            .expect("no diff for the shared slot");
+        let expected_initial_value = U256::from(number.0 - 1);
+        assert_eq!(shared_slot_diff.initial_value, expected_initial_value);
+        assert_eq!(shared_slot_diff.final_value, U256::from(number.0));
+
+        // Inputs must not depend on whether previous values are taken from the cache.
+        let reference_input = create_commitment_generator(pool.clone())
+            .prepare_input(number)
+            .await
+            .unwrap();
+        assert_eq!(input, reference_input);
+    }
+
+    let (resolved, unresolved) = generator
+        .recent_writes
+        .previous_values(&[shared_key.hashed_key()], L1BatchNumber(4));
+    assert_eq!(
+        resolved,
+        HashMap::from([(shared_key.hashed_key(), Some(H256::from_low_u64_be(3)))])
+    );
+    assert!(unresolved.is_empty());
+}
+
 #[tokio::test]
 async fn commitment_generator_normal_operation() {
     let pool = ConnectionPool::<Core>::test_pool().await;
@@ -0,0 +1,213 @@
+//! Attester signing L1 batches.
+use std::time::Instant;
+
+use anyhow::Context as _;
+use serde::Serialize;
+use zksync_concurrency::{ctx, error::Wrap as _, time};
+use zksync_config::configs::consensus::{ConsensusConfig, ConsensusSecrets};
+use zksync_dal::{consensus_dal::BatchAttestation, Core};
+use zksync_health_check::{Health, HealthStatus, HealthUpdater, ReactiveHealthCheck};
+use zksync_types::{
+    web3::keccak256, Address, K256PrivateKey, L1BatchNumber, PackedEthSignature, H256,
+};
+use zksync_web3_decl::client::{DynClient, L2};
+
+use crate::{
+    config, keystore,
+    metrics::ATTESTER_METRICS,
+    registry::{Epoch, Registry},
+    storage::ConnectionPool,
+};
+
+/// Computes the digest of an L1 batch signed by the attester.
+pub(crate) fn attestation_digest(number: L1BatchNumber, hash: H256) -> H256 {
+    let mut bytes = Vec::with_capacity(4 + H256::len_bytes());
+    bytes.extend_from_slice(&number.0.to_be_bytes());
+    bytes.extend_from_slice(hash.as_bytes());
+    H256(keccak256(&bytes))
+}
+
+#[derive(Debug, Serialize)]
+struct AttesterHealth {
+    attester: Address,
+    last_attested_batch: Option<L1BatchNumber>,
+}
+
+/// Attester of the node. Signs every sealed L1 batch (starting from the first one sealed
+/// after the attester has been enabled) with the configured key.
+///
+/// Attestations are persisted in the DB before being exposed to anyone. The DB keeps at most
+/// one attestation per L1 batch (and attestations outlive the batches themselves, e.g. if they are
+/// reverted), so the attester never signs 2 different hashes for the same batch, even across restarts.
+///
+/// NOTE: attestations are only persisted locally. The consensus executor used by the node doesn't support
+/// gossiping batch votes yet, so attestations are not submitted to the consensus network.
+#[derive(Debug)]
+pub struct Attester {
+    pool: ConnectionPool,
+    key: K256PrivateKey,
+    registry: Option<Registry>,
+    health_updater: HealthUpdater,
+}
+
+impl Attester {
+    /// Creates an attester. Returns `None` if the attester key is not configured.
+    pub fn new(
+        pool: zksync_dal::ConnectionPool<Core>,
+        secrets: &ConsensusSecrets,
+    ) -> anyhow::Result<Option<Self>> {
+        let secrets = keystore::resolve(secrets).context("keystore")?;
+        let Some(key) = config::attester_key(&secrets).context("attester_key")? else {
+            return Ok(None);
+        };
+        Ok(Some(Self {
+            pool: ConnectionPool(pool),
+            key,
+            registry: None,
+            health_updater: ReactiveHealthCheck::new("consensus_attester").1,
+        }))
+    }
+
+    /// Makes the attester sign only the L1 batches for which it belongs to the attester committee
+    /// read from the consensus registry (if the registry is configured). The committee is read
+    /// at the last L2 block of the attested batch.
+    pub fn with_registry(mut self, cfg: &ConsensusConfig, client: Box<DynClient<L2>>) -> Self {
+        self.registry = cfg
+            .registry_address
+            .map(|address| Registry::new(client, address));
+        self
+    }
+
+    /// Returns a health check for this attester.
+    pub fn health_check(&self) -> ReactiveHealthCheck {
+        self.health_updater.subscribe()
+    }
+
+    fn update_health(&self, last_attested_batch: Option<L1BatchNumber>) {
+        let health = Health::from(HealthStatus::Ready).with_details(AttesterHealth {
+            attester: self.key.address(),
+            last_attested_batch,
+        });
+        self.health_updater.update(health);
+    }
+
+    /// Runs the attester until the context is canceled.
+    pub async fn run(self, ctx: &ctx::Ctx) -> anyhow::Result<()> {
+        const POLL_INTERVAL: time::Duration = time::Duration::seconds(1);
+
+        let res = async {
+            let mut conn = self.pool.connection(ctx).await.wrap("connection()")?;
+            let last = conn
+                .last_batch_attestation_number(ctx)
+                .await
+                .wrap("last_batch_attestation_number()")?;
+            let mut next = match last {
+                Some(last) => last + 1,
+                // Attest batches starting from the last one with a computed hash.
+                None => conn
+                    .last_batch_with_hash(ctx)
+                    .await
+                    .wrap("last_batch_with_hash()")?
+                    .unwrap_or(L1BatchNumber(0)),
+            };
+            drop(conn);
+            self.update_health(last);
+
+            loop {
+                let hash = self
+                    .pool
+                    .connection(ctx)
+                    .await
+                    .wrap("connection()")?
+                    .batch_hash(ctx, next)
+                    .await
+                    .wrap("batch_hash()")?;
+                let Some(hash) = hash else {
+                    ctx.sleep(POLL_INTERVAL).await?;
+                    continue;
+                };
+                if self
+                    .is_in_committee(ctx, next)
+                    .await
+                    .wrap("is_in_committee()")?
+                {
+                    self.attest(ctx, next, hash).await.wrap("attest()")?;
+                    self.update_health(Some(next));
+                } else {
+                    tracing::debug!("Not in the attester committee of L1 batch #{next}, skipping");
+                }
+                next += 1;
+            }
+        }
+        .await;
+        match res {
+            Ok(()) | Err(ctx::Error::Canceled(_)) => Ok(()),
+            Err(ctx::Error::Internal(err)) => Err(err),
+        }
+    }
+
+    /// Checks whether this attester belongs to the attester committee of the L1 batch.
+    async fn is_in_committee(&self, ctx: &ctx::Ctx, number: L1BatchNumber) -> ctx::Result<bool> {
+        let Some(registry) = &self.registry else {
+            return Ok(true);
+        };
+        let (_, last_block) = self
+            .pool
+            .connection(ctx)
+            .await
+            .wrap("connection()")?
+            .batch_block_range(ctx, number)
+            .await
+            .wrap("batch_block_range()")?
+            .context("L1 batch has no L2 blocks")?;
+        let epoch = Epoch {
+            batch: number,
+            last_block,
+        };
+        let committee = registry
+            .attester_committee(ctx, epoch)
+            .await
+            .wrap("attester_committee()")?;
+        Ok(committee.contains_key(&self.key.address()))
+    }
+
+    /// Signs the L1 batch and persists the attestation.
+    /// Fails if a different attestation for this batch has been already signed.
+    async fn attest(&self, ctx: &ctx::Ctx, number: L1BatchNumber, hash: H256) -> ctx::Result<()> {
+        let mut conn = self.pool.connection(ctx).await.wrap("connection()")?;
+        if let Some(old) = conn
+            .batch_attestation(ctx, number)
+            .await
+            .wrap("batch_attestation()")?
+        {
+            if old.hash == hash && old.attester == self.key.address() {
+                return Ok(());
+            }
+            return Err(anyhow::format_err!(
+                "refusing to attest L1 batch #{number}: it has been already attested with hash {:?} \
+                 by {:?}, new hash {hash:?}",
+                old.hash,
+                old.attester
+            )
+            .into());
+        }
+        let started_at = Instant::now();
+        let signature = PackedEthSignature::sign_raw(&self.key, &attestation_digest(number, hash))
+            .context("sign_raw()")?;
+        ATTESTER_METRICS
+            .signing_latency
+            .observe(started_at.elapsed());
+        let attestation = BatchAttestation {
+            number,
+            hash,
+            attester: self.key.address(),
+            signature,
+        };
+        conn.insert_batch_attestation(ctx, &attestation)
+            .await
+            .wrap("insert_batch_attestation()")?;
+        ATTESTER_METRICS.signed_batches.inc();
+        tracing::info!("Attested L1 batch #{number} with hash {hash:?}");
+        Ok(())
+    }
+}
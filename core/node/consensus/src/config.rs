@@ -11,6 +11,7 @@ use zksync_config::{
 use zksync_consensus_crypto::{Text, TextFmt};
 use zksync_consensus_executor as executor;
 use zksync_consensus_roles::{node, validator};
+use zksync_types::{K256PrivateKey, H256};
 
 fn read_secret_text<T: TextFmt>(text: Option<&Secret<String>>) -> anyhow::Result<Option<T>> {
     text.map(|text| Text::new(text.expose_secret()).decode())
@@ -28,7 +29,7 @@ pub(super) fn validator_key(
 /// It is a digest of the `validator::Genesis`,
 /// which allows to initialize genesis (if not present)
 /// decide whether a hard fork is necessary (if present).
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub(super) struct GenesisSpec {
     pub(super) chain_id: validator::ChainId,
     pub(super) protocol_version: validator::ProtocolVersion,
@@ -70,6 +71,23 @@ impl GenesisSpec {
     }
 }
 
+/// Decodes the secp256k1 key used by the attester to sign L1 batches.
+pub(super) fn attester_key(secrets: &ConsensusSecrets) -> anyhow::Result<Option<K256PrivateKey>> {
+    secrets
+        .attester_key
+        .as_ref()
+        .map(|key| {
+            let key = key.0.expose_secret();
+            let bytes: H256 = key
+                .strip_prefix("0x")
+                .unwrap_or(key)
+                .parse()
+                .map_err(|_| anyhow::format_err!("invalid format"))?;
+            K256PrivateKey::from_bytes(bytes).context("K256PrivateKey::from_bytes()")
+        })
+        .transpose()
+}
+
 pub(super) fn node_key(secrets: &ConsensusSecrets) -> anyhow::Result<Option<node::SecretKey>> {
     read_secret_text(secrets.node_key.as_ref().map(|x| &x.0))
 }
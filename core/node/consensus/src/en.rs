@@ -1,8 +1,9 @@
 use anyhow::Context as _;
+use serde::Serialize;
 use zksync_concurrency::{ctx, error::Wrap as _, scope, time};
-use zksync_consensus_executor as executor;
 use zksync_consensus_roles::validator;
 use zksync_consensus_storage::BlockStore;
+use zksync_health_check::{Health, HealthStatus, HealthUpdater};
 use zksync_node_sync::{
     fetcher::FetchedBlock, sync_action::ActionQueueSender, MainNodeClient, SyncState,
 };
@@ -10,13 +11,29 @@ use zksync_types::L2BlockNumber;
 use zksync_web3_decl::client::{DynClient, L2};
 
 use super::{config, storage::Store, ConnectionPool, ConsensusConfig, ConsensusSecrets};
-use crate::storage;
+use crate::{keystore, peers::PeerManager, storage};
+
+/// Health details of the consensus node run by the external node.
+#[derive(Debug, Serialize)]
+struct GossipHealth {
+    /// Number of static outbound peers in the effective gossip config (i.e., including peers added at runtime,
+    /// and excluding banned ones). This is the configuration, not the number of established connections.
+    configured_static_outbound_peers: usize,
+    /// Number of static inbound peers in the effective gossip config.
+    configured_static_inbound_peers: usize,
+    /// Next block to be received.
+    next_block: Option<u64>,
+    /// Whether the gossip network has stopped delivering blocks, so that they are fetched using JSON-RPC.
+    gossip_stalled: bool,
+}
 
 /// External node.
 pub(super) struct EN {
     pub(super) pool: ConnectionPool,
     pub(super) sync_state: SyncState,
     pub(super) client: Box<DynClient<L2>>,
+    /// Reports whether the gossip network delivers blocks. Only updated if the consensus node is running.
+    pub(super) health_updater: HealthUpdater,
 }
 
 impl EN {
@@ -25,6 +42,13 @@ impl EN {
     ///
     /// NOTE: Before starting the consensus node if fetches all the blocks
     /// older than consensus genesis from the main node using json RPC.
+    /// Afterwards blocks are received and validated over the gossip network.
+    /// JSON-RPC is used only as a fallback, whenever gossip network stops
+    /// delivering blocks which are already available on the main node.
+    ///
+    /// Whenever the genesis of the main node changes (a hard fork occurred, e.g. because
+    /// the validator committee has been updated in the consensus registry), the consensus
+    /// state is reset and the consensus node is restarted with the new genesis.
     pub async fn run(
         self,
         ctx: &ctx::Ctx,
@@ -32,70 +56,93 @@ impl EN {
         cfg: ConsensusConfig,
         secrets: ConsensusSecrets,
     ) -> anyhow::Result<()> {
+        let secrets = keystore::resolve(&secrets).context("keystore")?;
+        let peers = PeerManager::new(&cfg).context("PeerManager::new()")?;
         let res: ctx::Result<()> = scope::run!(ctx, |ctx, s| async {
             // Update sync state in the background.
             s.spawn_bg(self.fetch_state_loop(ctx));
+            if let Some(addr) = cfg.admin_addr {
+                s.spawn_bg(peers.serve(ctx, addr));
+            }
+            s.spawn_bg(peers.run_gossip_guard(ctx));
 
-            // Initialize genesis.
-            let genesis = self.fetch_genesis(ctx).await.wrap("fetch_genesis()")?;
-            let mut conn = self.pool.connection(ctx).await.wrap("connection()")?;
-            conn.try_update_genesis(ctx, &genesis)
+            let mut payload_queue = self
+                .pool
+                .connection(ctx)
                 .await
-                .wrap("set_genesis()")?;
-            let mut payload_queue = conn
+                .wrap("connection()")?
                 .new_payload_queue(ctx, actions, self.sync_state.clone())
                 .await
                 .wrap("new_payload_queue()")?;
-            drop(conn);
+            loop {
+                // Initialize genesis.
+                let genesis = self.fetch_genesis(ctx).await.wrap("fetch_genesis()")?;
+                self.pool
+                    .connection(ctx)
+                    .await
+                    .wrap("connection()")?
+                    .try_update_genesis(ctx, &genesis)
+                    .await
+                    .wrap("set_genesis()")?;
 
-            // Fetch blocks before the genesis.
-            self.fetch_blocks(ctx, &mut payload_queue, Some(genesis.first_block))
-                .await?;
-            // Monitor the genesis of the main node.
-            // If it changes, it means that a hard fork occurred and we need to reset the consensus state.
-            s.spawn_bg::<()>(async {
-                let old = genesis;
-                loop {
-                    if let Ok(new) = self.fetch_genesis(ctx).await {
-                        if new != old {
-                            return Err(anyhow::format_err!(
-                                "genesis changed: old {old:?}, new {new:?}"
-                            )
-                            .into());
-                        }
-                    }
-                    ctx.sleep(time::Duration::seconds(5)).await?;
-                }
-            });
+                // Fetch blocks before the genesis.
+                self.fetch_blocks(ctx, &mut payload_queue, Some(genesis.first_block))
+                    .await?;
+                payload_queue = self
+                    .run_consensus(ctx, payload_queue, genesis, &peers, &secrets)
+                    .await
+                    .wrap("run_consensus()")?;
+            }
+        })
+        .await;
+        match res {
+            Ok(()) | Err(ctx::Error::Canceled(_)) => Ok(()),
+            Err(ctx::Error::Internal(err)) => Err(err),
+        }
+    }
 
-            // Run consensus component.
+    /// Runs the consensus node with the given genesis, until the genesis of the main node changes.
+    /// Returns the payload queue, so that it can be reused after the restart.
+    async fn run_consensus(
+        &self,
+        ctx: &ctx::Ctx,
+        payload_queue: storage::PayloadQueue,
+        genesis: validator::Genesis,
+        peers: &PeerManager,
+        secrets: &ConsensusSecrets,
+    ) -> ctx::Result<storage::PayloadQueue> {
+        let store = scope::run!(ctx, |ctx, s| async {
             let (store, runner) = Store::new(ctx, self.pool.clone(), Some(payload_queue))
                 .await
                 .wrap("Store::new()")?;
             s.spawn_bg(async { Ok(runner.run(ctx).await?) });
+            s.spawn_bg(self.fallback_fetch_loop(ctx, store.clone(), peers));
             let (block_store, runner) = BlockStore::new(ctx, Box::new(store.clone()))
                 .await
                 .wrap("BlockStore::new()")?;
             s.spawn_bg(async { Ok(runner.run(ctx).await?) });
-            let executor = executor::Executor {
-                config: config::executor(&cfg, &secrets)?,
-                block_store,
-                validator: config::validator_key(&secrets)
-                    .context("validator_key")?
-                    .map(|key| executor::Validator {
-                        key,
-                        replica_store: Box::new(store.clone()),
-                        payload_manager: Box::new(store.clone()),
-                    }),
-            };
-            executor.run(ctx).await?;
-            Ok(())
+            let validator_key = config::validator_key(secrets).context("validator_key")?;
+            s.spawn_bg(peers.run_executor(ctx, secrets, block_store, store.clone(), validator_key));
+
+            // Monitor the genesis of the main node.
+            loop {
+                if let Ok(new) = self.fetch_genesis(ctx).await {
+                    if new != genesis {
+                        tracing::info!(
+                            "genesis changed: old {genesis:?}, new {new:?}; restarting consensus"
+                        );
+                        return Ok(store);
+                    }
+                }
+                ctx.sleep(time::Duration::seconds(5)).await?;
+            }
         })
-        .await;
-        match res {
-            Ok(()) | Err(ctx::Error::Canceled(_)) => Ok(()),
-            Err(ctx::Error::Internal(err)) => Err(err),
-        }
+        .await?;
+        Ok(store
+            .take_payload_queue(ctx)
+            .await
+            .wrap("take_payload_queue()")?
+            .context("payload queue missing")?)
     }
 
     /// Task fetching L2 blocks using JSON-RPC endpoint of the main node.
@@ -143,6 +190,75 @@ impl EN {
         }
     }
 
+    /// Fetches blocks from the main node using JSON-RPC whenever the gossip network
+    /// fails to deliver any block for `GOSSIP_STALL_TIMEOUT`, while the main node is ahead.
+    /// Certificates for the fetched blocks are still expected to arrive via gossip.
+    /// Also reports whether the gossip network delivers blocks as the component health.
+    async fn fallback_fetch_loop(
+        &self,
+        ctx: &ctx::Ctx,
+        store: Store,
+        peers: &PeerManager,
+    ) -> ctx::Result<()> {
+        const GOSSIP_STALL_TIMEOUT: time::Duration = time::Duration::seconds(10);
+        loop {
+            let before = store
+                .next_queued_block(ctx)
+                .await
+                .wrap("next_queued_block()")?;
+            self.update_health(peers, before, false);
+            ctx.sleep(GOSSIP_STALL_TIMEOUT).await?;
+            let Some(mut next) = store
+                .next_queued_block(ctx)
+                .await
+                .wrap("next_queued_block()")?
+            else {
+                return Ok(());
+            };
+            let main_node_block =
+                validator::BlockNumber(self.sync_state.get_main_node_block().0.into());
+            if Some(next) != before || next > main_node_block {
+                continue;
+            }
+            self.update_health(peers, Some(next), true);
+            tracing::info!(
+                "gossip network has not delivered any block for {GOSSIP_STALL_TIMEOUT:?}; \
+                 fetching blocks [{next:?}..={main_node_block:?}] using JSON-RPC"
+            );
+            while next <= main_node_block {
+                let n = L2BlockNumber(next.0.try_into().context("overflow")?);
+                let block = self.fetch_block(ctx, n).await?;
+                store
+                    .queue_fetched_block(ctx, block)
+                    .await
+                    .wrap("queue_fetched_block()")?;
+                next = next.next();
+            }
+        }
+    }
+
+    fn update_health(
+        &self,
+        peers: &PeerManager,
+        next_block: Option<validator::BlockNumber>,
+        gossip_stalled: bool,
+    ) {
+        let cfg = peers.effective_config();
+        let health = GossipHealth {
+            configured_static_outbound_peers: cfg.gossip_static_outbound.len(),
+            configured_static_inbound_peers: cfg.gossip_static_inbound.len(),
+            next_block: next_block.map(|number| number.0),
+            gossip_stalled,
+        };
+        let status = if gossip_stalled {
+            HealthStatus::Affected
+        } else {
+            HealthStatus::Ready
+        };
+        self.health_updater
+            .update(Health::from(status).with_details(health));
+    }
+
     /// Fetches genesis from the main node.
     async fn fetch_genesis(&self, ctx: &ctx::Ctx) -> ctx::Result<validator::Genesis> {
         let genesis = ctx
@@ -4,25 +4,33 @@
 //! This module simply glues APIs that are already publicly exposed by the `consensus` module,
 //! so in case any custom behavior is needed, these APIs should be used directly.
 
+use anyhow::Context as _;
 use zksync_concurrency::ctx;
 use zksync_config::configs::consensus::{ConsensusConfig, ConsensusSecrets};
 use zksync_dal::Core;
+use zksync_health_check::HealthUpdater;
 use zksync_node_sync::{sync_action::ActionQueueSender, SyncState};
-use zksync_web3_decl::client::{DynClient, L2};
+use zksync_types::url::SensitiveUrl;
+use zksync_web3_decl::client::{Client, DynClient, L2};
 
 use super::{en, storage::ConnectionPool};
 
 /// Runs the consensus task in the main node mode.
+/// `registry_client` is used to read committees from the consensus registry contract
+/// (if it is configured); it should point to the L2 JSON-RPC API of the main node.
 pub async fn run_main_node(
     ctx: &ctx::Ctx,
     cfg: ConsensusConfig,
     secrets: ConsensusSecrets,
     pool: zksync_dal::ConnectionPool<Core>,
+    registry_client: Option<Box<DynClient<L2>>>,
 ) -> anyhow::Result<()> {
     // Consensus is a new component.
     // For now in case of error we just log it and allow the server
     // to continue running.
-    if let Err(err) = super::run_main_node(ctx, cfg, secrets, ConnectionPool(pool)).await {
+    if let Err(err) =
+        super::run_main_node(ctx, cfg, secrets, ConnectionPool(pool), registry_client).await
+    {
         tracing::error!("Consensus actor failed: {err:#}");
     } else {
         tracing::info!("Consensus actor stopped");
@@ -30,9 +38,33 @@ pub async fn run_main_node(
     Ok(())
 }
 
+/// Creates a client reading the consensus registry using the L2 JSON-RPC API at `url`.
+/// Returns `None` if the consensus registry is not configured.
+pub fn registry_client(
+    cfg: &ConsensusConfig,
+    url: &str,
+) -> anyhow::Result<Option<Box<DynClient<L2>>>> {
+    if cfg.registry_address.is_none() {
+        return Ok(None);
+    }
+    let chain_id = cfg
+        .genesis_spec
+        .as_ref()
+        .context("consensus registry requires genesis_spec to be configured")?
+        .chain_id;
+    let url: SensitiveUrl = url.parse().context("url")?;
+    let client = Client::http(url)
+        .context("Client::http()")?
+        .for_network(chain_id.into())
+        .build();
+    Ok(Some(Box::new(client)))
+}
+
 /// Runs the consensus node for the external node.
 /// If `cfg` is `None`, it will just fetch blocks from the main node
 /// using JSON RPC, without starting the consensus node.
+///
+/// `health_updater` reports whether the gossip network delivers blocks; it's only updated if the consensus node is started.
 pub async fn run_en(
     ctx: &ctx::Ctx,
     cfg: Option<(ConsensusConfig, ConsensusSecrets)>,
@@ -40,11 +72,13 @@ pub async fn run_en(
     sync_state: SyncState,
     main_node_client: Box<DynClient<L2>>,
     actions: ActionQueueSender,
+    health_updater: HealthUpdater,
 ) -> anyhow::Result<()> {
     let en = en::EN {
         pool: ConnectionPool(pool),
         sync_state: sync_state.clone(),
         client: main_node_client.for_component("block_fetcher"),
+        health_updater,
     };
     let res = match cfg {
         Some((cfg, secrets)) => en.run(ctx, actions, cfg, secrets).await,
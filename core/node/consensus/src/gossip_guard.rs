@@ -0,0 +1,333 @@
+//! Per-peer limits on the inbound gossip traffic.
+//!
+//! The gossip network actor doesn't limit the traffic of individual peers, so the limits are enforced
+//! by a TCP proxy in front of the gossip server: the executor listens on a local address, while
+//! the guard accepts the inbound connections on `server_addr` and forwards them to the executor.
+//! Peers are identified by their IP address, since node keys are only exchanged inside the encrypted stream.
+//!
+//! Every peer has a token bucket shared by all its connections. Reads exceeding the bucket are delayed
+//! (i.e., the peer is throttled), and every throttled read lowers the peer score. The score recovers over time.
+//! Once the score drops to zero, the peer is banned: its connections are closed on the next received message,
+//! and new connections are rejected until the ban expires. Connections over `max_connections_per_peer`
+//! are rejected and lower the score as well.
+use std::{
+    collections::HashMap,
+    net::{IpAddr, Ipv4Addr, SocketAddr},
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use anyhow::Context as _;
+use serde::Serialize;
+use tokio::{
+    io::{AsyncReadExt as _, AsyncWriteExt as _},
+    net::{TcpListener, TcpStream},
+};
+use zksync_concurrency::{ctx, scope};
+use zksync_config::configs::consensus::GossipRateLimitConfig;
+
+use crate::metrics::{RejectReason, GOSSIP_GUARD_METRICS};
+
+/// Score of a well-behaved peer.
+const MAX_SCORE: f64 = 100.0;
+/// Score recovered by a peer per second.
+const SCORE_RECOVERY_PER_SEC: f64 = 1.0;
+/// Score lost by a peer per throttled read.
+const THROTTLE_PENALTY: f64 = 1.0;
+/// Score lost by a peer per connection over the limit.
+const CONNECTION_PENALTY: f64 = 10.0;
+const READ_BUFFER_SIZE: usize = 16 << 10;
+
+/// Decision on the data received from a peer.
+#[derive(Debug, PartialEq)]
+pub(crate) enum Admission {
+    /// Data should be forwarded after the specified delay (zero if the peer is within its limits).
+    Forward(Duration),
+    /// Peer is banned; the connection should be closed.
+    Banned,
+}
+
+/// Rate limiting state of a single peer.
+#[derive(Debug)]
+pub(crate) struct PeerState {
+    connections: usize,
+    score: f64,
+    tokens: f64,
+    last_update: Instant,
+    banned_until: Option<Instant>,
+    inbound_bytes: u64,
+    throttled_reads: u64,
+}
+
+impl PeerState {
+    pub(crate) fn new(cfg: &GossipRateLimitConfig, now: Instant) -> Self {
+        Self {
+            connections: 0,
+            score: MAX_SCORE,
+            tokens: cfg.max_inbound_burst_bytes as f64,
+            last_update: now,
+            banned_until: None,
+            inbound_bytes: 0,
+            throttled_reads: 0,
+        }
+    }
+
+    pub(crate) fn is_banned(&self, now: Instant) -> bool {
+        self.banned_until.is_some_and(|until| until > now)
+    }
+
+    /// Refills the token bucket, recovers the score and lifts the expired ban.
+    fn update(&mut self, cfg: &GossipRateLimitConfig, now: Instant) {
+        let elapsed = now
+            .saturating_duration_since(self.last_update)
+            .as_secs_f64();
+        self.last_update = self.last_update.max(now);
+        self.tokens = (self.tokens + elapsed * cfg.max_inbound_bytes_per_sec as f64)
+            .min(cfg.max_inbound_burst_bytes as f64);
+        if self.banned_until.is_some_and(|until| until <= now) {
+            self.banned_until = None;
+            self.score = MAX_SCORE;
+            self.tokens = cfg.max_inbound_burst_bytes as f64;
+        }
+        if self.banned_until.is_none() {
+            self.score = (self.score + elapsed * SCORE_RECOVERY_PER_SEC).min(MAX_SCORE);
+        }
+    }
+
+    /// Lowers the score, banning the peer if the score drops to zero.
+    fn penalize(&mut self, cfg: &GossipRateLimitConfig, now: Instant, penalty: f64) {
+        self.score -= penalty;
+        if self.score <= 0.0 {
+            self.score = 0.0;
+            self.banned_until = Some(now + cfg.ban_duration());
+        }
+    }
+
+    /// Accounts a new connection from the peer.
+    pub(crate) fn connect(
+        &mut self,
+        cfg: &GossipRateLimitConfig,
+        now: Instant,
+    ) -> Result<(), RejectReason> {
+        self.update(cfg, now);
+        if self.is_banned(now) {
+            return Err(RejectReason::Banned);
+        }
+        if self.connections >= cfg.max_connections_per_peer {
+            self.penalize(cfg, now, CONNECTION_PENALTY);
+            return Err(RejectReason::TooManyConnections);
+        }
+        self.connections += 1;
+        Ok(())
+    }
+
+    /// Accounts `bytes` received from the peer.
+    pub(crate) fn receive(
+        &mut self,
+        cfg: &GossipRateLimitConfig,
+        now: Instant,
+        bytes: usize,
+    ) -> Admission {
+        self.update(cfg, now);
+        if self.is_banned(now) {
+            return Admission::Banned;
+        }
+        self.inbound_bytes += bytes as u64;
+        self.tokens -= bytes as f64;
+        if self.tokens >= 0.0 {
+            return Admission::Forward(Duration::ZERO);
+        }
+        self.throttled_reads += 1;
+        self.penalize(cfg, now, THROTTLE_PENALTY);
+        if self.is_banned(now) {
+            return Admission::Banned;
+        }
+        let rate = cfg.max_inbound_bytes_per_sec.max(1) as f64;
+        Admission::Forward(Duration::from_secs_f64(-self.tokens / rate))
+    }
+}
+
+/// Inbound peer as reported by the admin API.
+#[derive(Debug, Serialize)]
+pub(crate) struct InboundPeer {
+    ip: IpAddr,
+    connections: usize,
+    score: f64,
+    inbound_bytes: u64,
+    throttled_reads: u64,
+    /// Remaining duration of the ban, if the peer is banned.
+    banned_for_sec: Option<u64>,
+}
+
+/// TCP proxy enforcing the per-peer limits in front of the gossip server.
+#[derive(Debug)]
+pub(crate) struct GossipGuard {
+    cfg: GossipRateLimitConfig,
+    /// Local address the gossip server listens on.
+    internal_addr: SocketAddr,
+    peers: Mutex<HashMap<IpAddr, PeerState>>,
+}
+
+impl GossipGuard {
+    pub(crate) fn new(cfg: GossipRateLimitConfig) -> anyhow::Result<Self> {
+        // Reserve a free local port for the gossip server.
+        let internal_addr = std::net::TcpListener::bind((Ipv4Addr::LOCALHOST, 0))
+            .and_then(|listener| listener.local_addr())
+            .context("failed reserving a local port for the gossip server")?;
+        Ok(Self {
+            cfg,
+            internal_addr,
+            peers: Mutex::default(),
+        })
+    }
+
+    /// Address the gossip server should listen on.
+    pub(crate) fn internal_addr(&self) -> SocketAddr {
+        self.internal_addr
+    }
+
+    pub(crate) fn inbound_peers(&self) -> Vec<InboundPeer> {
+        let now = Instant::now();
+        let mut peers = self.peers.lock().unwrap();
+        let mut inbound_peers: Vec<_> = peers
+            .iter_mut()
+            .map(|(ip, peer)| {
+                peer.update(&self.cfg, now);
+                InboundPeer {
+                    ip: *ip,
+                    connections: peer.connections,
+                    score: peer.score,
+                    inbound_bytes: peer.inbound_bytes,
+                    throttled_reads: peer.throttled_reads,
+                    banned_for_sec: peer
+                        .banned_until
+                        .map(|until| until.saturating_duration_since(now).as_secs()),
+                }
+            })
+            .collect();
+        inbound_peers.sort_unstable_by_key(|peer| peer.ip);
+        inbound_peers
+    }
+
+    fn connect(&self, ip: IpAddr) -> Result<(), RejectReason> {
+        let now = Instant::now();
+        let mut peers = self.peers.lock().unwrap();
+        let peer = peers
+            .entry(ip)
+            .or_insert_with(|| PeerState::new(&self.cfg, now));
+        let was_banned = peer.is_banned(now);
+        let res = peer.connect(&self.cfg, now);
+        self.report_ban(ip, was_banned, peer, now);
+        res
+    }
+
+    fn disconnect(&self, ip: IpAddr) {
+        let now = Instant::now();
+        let mut peers = self.peers.lock().unwrap();
+        let Some(peer) = peers.get_mut(&ip) else {
+            return;
+        };
+        peer.connections -= 1;
+        peer.update(&self.cfg, now);
+        // Forget well-behaved peers without connections, so that the state doesn't grow indefinitely.
+        if peer.connections == 0 && !peer.is_banned(now) && peer.score >= MAX_SCORE {
+            peers.remove(&ip);
+        }
+    }
+
+    fn receive(&self, ip: IpAddr, bytes: usize) -> Admission {
+        let now = Instant::now();
+        let mut peers = self.peers.lock().unwrap();
+        let peer = peers
+            .entry(ip)
+            .or_insert_with(|| PeerState::new(&self.cfg, now));
+        let was_banned = peer.is_banned(now);
+        let admission = peer.receive(&self.cfg, now, bytes);
+        self.report_ban(ip, was_banned, peer, now);
+        match admission {
+            Admission::Forward(delay) => {
+                GOSSIP_GUARD_METRICS.inbound_bytes.inc_by(bytes as u64);
+                if !delay.is_zero() {
+                    GOSSIP_GUARD_METRICS.throttled_reads.inc();
+                }
+            }
+            Admission::Banned => {}
+        }
+        admission
+    }
+
+    fn report_ban(&self, ip: IpAddr, was_banned: bool, peer: &PeerState, now: Instant) {
+        if !was_banned && peer.is_banned(now) {
+            GOSSIP_GUARD_METRICS.bans.inc();
+            tracing::warn!(
+                "Banning gossip peer {ip} for {:?}: it has repeatedly exceeded its limits",
+                self.cfg.ban_duration()
+            );
+        }
+    }
+
+    /// Accepts inbound gossip connections on `server_addr` until the context is canceled.
+    pub(crate) async fn run(&self, ctx: &ctx::Ctx, server_addr: SocketAddr) -> ctx::Result<()> {
+        let listener = TcpListener::bind(server_addr)
+            .await
+            .with_context(|| format!("failed binding gossip server to {server_addr}"))?;
+        tracing::info!(
+            "Enforcing gossip rate limits on {server_addr}, forwarding to {}",
+            self.internal_addr
+        );
+        scope::run!(ctx, |ctx, s| async {
+            loop {
+                let (stream, addr) = ctx.wait(listener.accept()).await?.context("accept()")?;
+                let ip = addr.ip();
+                if let Err(reason) = self.connect(ip) {
+                    tracing::debug!("Rejected gossip connection from {addr}: {reason:?}");
+                    GOSSIP_GUARD_METRICS.rejected_connections[&reason].inc();
+                    continue;
+                }
+                GOSSIP_GUARD_METRICS.connections.inc_by(1);
+                s.spawn_bg(async move {
+                    if let Err(err) = self.forward(ctx, ip, stream).await {
+                        tracing::debug!("Gossip connection from {addr} terminated: {err:#}");
+                    }
+                    self.disconnect(ip);
+                    GOSSIP_GUARD_METRICS.connections.dec_by(1);
+                    Ok(())
+                });
+            }
+        })
+        .await
+    }
+
+    /// Forwards the connection to the gossip server until either side closes it or the context is canceled.
+    async fn forward(&self, ctx: &ctx::Ctx, ip: IpAddr, inbound: TcpStream) -> anyhow::Result<()> {
+        let outbound = TcpStream::connect(self.internal_addr)
+            .await
+            .context("failed connecting to the gossip server")?;
+        let (mut inbound_read, mut inbound_write) = inbound.into_split();
+        let (mut outbound_read, mut outbound_write) = outbound.into_split();
+        let upstream = async {
+            let mut buffer = vec![0; READ_BUFFER_SIZE];
+            loop {
+                let len = inbound_read.read(&mut buffer).await?;
+                if len == 0 {
+                    return anyhow::Ok(());
+                }
+                match self.receive(ip, len) {
+                    Admission::Forward(delay) if delay.is_zero() => {}
+                    Admission::Forward(delay) => tokio::time::sleep(delay).await,
+                    Admission::Banned => anyhow::bail!("peer is banned"),
+                }
+                outbound_write.write_all(&buffer[..len]).await?;
+            }
+        };
+        let downstream = async {
+            tokio::io::copy(&mut outbound_read, &mut inbound_write).await?;
+            anyhow::Ok(())
+        };
+        tokio::select! {
+            res = upstream => res,
+            res = downstream => res,
+            () = ctx.canceled() => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,207 @@
+//! Encrypted keystore holding the consensus secret keys.
+//!
+//! The keystore is a JSON file with the secret keys (in the same format as in the secrets config)
+//! encrypted with XChaCha20-Poly1305. The encryption key is derived from the password
+//! using PBKDF2-HMAC-SHA256.
+//!
+//! The keystore password is not stored in the config: it is read from the configured password file
+//! or from the `CONSENSUS_KEYSTORE_PASSWORD` env variable.
+//!
+//! The keystore is decrypted once on startup. If it cannot be read or decrypted, the fallback keystores
+//! (if any) are tried in order. If none of them can be read, the consensus component fails to start:
+//! there is no fallback to keys stored in plaintext.
+use std::{path::Path, time::Instant};
+
+use anyhow::Context as _;
+use chacha20poly1305::{
+    aead::{Aead as _, KeyInit as _},
+    Key, XChaCha20Poly1305, XNonce,
+};
+use rand::Rng as _;
+use secrecy::{ExposeSecret as _, Secret};
+use serde::{Deserialize, Serialize};
+use zksync_config::configs::consensus::{
+    AttesterSecretKey, ConsensusKeystore, ConsensusSecrets, NodeSecretKey, ValidatorSecretKey,
+};
+
+use crate::metrics::KEYSTORE_METRICS;
+
+const VERSION: u32 = 1;
+const KDF_ROUNDS: u32 = 600_000;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct KeystoreFile {
+    version: u32,
+    kdf_rounds: u32,
+    /// Hex-encoded.
+    salt: String,
+    /// Hex-encoded.
+    nonce: String,
+    /// Hex-encoded.
+    ciphertext: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Keys {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    validator_key: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    node_key: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    attester_key: Option<String>,
+}
+
+fn cipher(password: &str, salt: &[u8], rounds: u32) -> XChaCha20Poly1305 {
+    let mut key = Key::default();
+    pbkdf2::pbkdf2_hmac::<sha2::Sha256>(password.as_bytes(), salt, rounds, &mut key);
+    XChaCha20Poly1305::new(&key)
+}
+
+/// Encrypts the secret keys from `secrets` with `password`, returning the keystore file contents.
+pub fn encrypt(secrets: &ConsensusSecrets, password: &str) -> anyhow::Result<String> {
+    let keys = Keys {
+        validator_key: secrets
+            .validator_key
+            .as_ref()
+            .map(|x| x.0.expose_secret().clone()),
+        node_key: secrets
+            .node_key
+            .as_ref()
+            .map(|x| x.0.expose_secret().clone()),
+        attester_key: secrets
+            .attester_key
+            .as_ref()
+            .map(|x| x.0.expose_secret().clone()),
+    };
+    let plaintext = Secret::new(serde_json::to_vec(&keys).context("serialize keys")?);
+    let rng = &mut rand::thread_rng();
+    let salt: [u8; 32] = rng.gen();
+    let nonce: [u8; 24] = rng.gen();
+    let ciphertext = cipher(password, &salt, KDF_ROUNDS)
+        .encrypt(
+            XNonce::from_slice(&nonce),
+            plaintext.expose_secret().as_slice(),
+        )
+        .map_err(|_| anyhow::format_err!("encryption failed"))?;
+    let file = KeystoreFile {
+        version: VERSION,
+        kdf_rounds: KDF_ROUNDS,
+        salt: hex::encode(salt),
+        nonce: hex::encode(nonce),
+        ciphertext: hex::encode(ciphertext),
+    };
+    serde_json::to_string_pretty(&file).context("serialize keystore")
+}
+
+fn decrypt(contents: &str, password: &str) -> anyhow::Result<Keys> {
+    let file: KeystoreFile = serde_json::from_str(contents).context("parse keystore")?;
+    anyhow::ensure!(
+        file.version == VERSION,
+        "unsupported keystore version {}",
+        file.version
+    );
+    let salt = hex::decode(&file.salt).context("salt")?;
+    let nonce = hex::decode(&file.nonce).context("nonce")?;
+    anyhow::ensure!(nonce.len() == 24, "invalid nonce length");
+    anyhow::ensure!(file.kdf_rounds > 0, "invalid kdf_rounds");
+    let ciphertext = hex::decode(&file.ciphertext).context("ciphertext")?;
+    let plaintext = cipher(password, &salt, file.kdf_rounds)
+        .decrypt(XNonce::from_slice(&nonce), ciphertext.as_slice())
+        .map_err(|_| anyhow::format_err!("decryption failed, wrong password?"))?;
+    let plaintext = Secret::new(plaintext);
+    serde_json::from_slice(plaintext.expose_secret()).context("parse keys")
+}
+
+/// Reads the keystore password from the password file or the env variable.
+fn read_password(keystore: &ConsensusKeystore) -> anyhow::Result<Secret<String>> {
+    let password = match &keystore.password_file {
+        Some(path) => {
+            let password = std::fs::read_to_string(path)
+                .with_context(|| format!("failed reading keystore password file {path:?}"))?;
+            password.trim_end_matches(['\r', '\n']).to_owned()
+        }
+        None => std::env::var(ConsensusKeystore::PASSWORD_ENV_VAR).with_context(|| {
+            format!(
+                "keystore password is not configured: set `password_file` or the `{}` env variable",
+                ConsensusKeystore::PASSWORD_ENV_VAR
+            )
+        })?,
+    };
+    Ok(password.into())
+}
+
+fn read_keys(path: &Path, password: &Secret<String>) -> anyhow::Result<Keys> {
+    let started_at = Instant::now();
+    let contents = std::fs::read_to_string(path).context("failed reading keystore")?;
+    let keys =
+        decrypt(&contents, password.expose_secret()).context("failed decrypting keystore")?;
+    KEYSTORE_METRICS.read_latency.observe(started_at.elapsed());
+    Ok(keys)
+}
+
+/// Returns `secrets` with the keys read from the keystore (if configured).
+/// Fails if a key is present both in the keystore and in `secrets`.
+pub(crate) fn resolve(secrets: &ConsensusSecrets) -> anyhow::Result<ConsensusSecrets> {
+    let mut secrets = secrets.clone();
+    let Some(keystore) = secrets.keystore.take() else {
+        return Ok(secrets);
+    };
+    let password = read_password(&keystore)?;
+    let mut errors = vec![];
+    let mut keys = None;
+    for path in std::iter::once(&keystore.path).chain(&keystore.fallback_paths) {
+        match read_keys(path, &password) {
+            Ok(read) => {
+                tracing::info!("Read consensus keys from keystore {path:?}");
+                keys = Some(read);
+                break;
+            }
+            Err(err) => {
+                tracing::warn!("Failed reading keystore {path:?}, failing over: {err:#}");
+                KEYSTORE_METRICS.read_failures.inc();
+                errors.push(format!("{path:?}: {err:#}"));
+            }
+        }
+    }
+    let keys = keys.with_context(|| {
+        format!(
+            "failed reading all configured keystores: {}",
+            errors.join("; ")
+        )
+    })?;
+
+    fn set<T>(
+        dst: &mut Option<T>,
+        src: Option<String>,
+        f: fn(Secret<String>) -> T,
+        name: &str,
+    ) -> anyhow::Result<()> {
+        if let Some(src) = src {
+            anyhow::ensure!(
+                dst.is_none(),
+                "{name} is present both in the keystore and in secrets"
+            );
+            *dst = Some(f(src.into()));
+        }
+        Ok(())
+    }
+    set(
+        &mut secrets.validator_key,
+        keys.validator_key,
+        ValidatorSecretKey,
+        "validator_key",
+    )?;
+    set(
+        &mut secrets.node_key,
+        keys.node_key,
+        NodeSecretKey,
+        "node_key",
+    )?;
+    set(
+        &mut secrets.attester_key,
+        keys.attester_key,
+        AttesterSecretKey,
+        "attester_key",
+    )?;
+    Ok(secrets)
+}
@@ -5,15 +5,22 @@
 use anyhow::Context as _;
 use zksync_concurrency::{ctx, error::Wrap as _, scope};
 use zksync_config::configs::consensus::{ConsensusConfig, ConsensusSecrets};
-use zksync_consensus_executor as executor;
 use zksync_consensus_roles::validator;
 use zksync_consensus_storage::BlockStore;
+use zksync_web3_decl::client::{DynClient, L2};
 
+pub use crate::attester::Attester;
 use crate::storage::{ConnectionPool, Store};
 
+mod attester;
 mod config;
 mod en;
 pub mod era;
+mod gossip_guard;
+pub mod keystore;
+mod metrics;
+mod peers;
+mod registry;
 mod storage;
 #[cfg(test)]
 pub(crate) mod testonly;
@@ -23,47 +30,127 @@ mod tests;
 /// Task running a consensus validator for the main node.
 /// Main node is currently the only leader of the consensus - i.e. it proposes all the
 /// L2 blocks (generated by `Statekeeper`).
+///
+/// If the consensus registry is configured, the validator committee is read from the registry
+/// at every L1 batch boundary. Whenever the committee changes, consensus genesis is adjusted
+/// (which is a hard fork of consensus) and the consensus component is restarted in place.
 async fn run_main_node(
     ctx: &ctx::Ctx,
     cfg: ConsensusConfig,
     secrets: ConsensusSecrets,
     pool: ConnectionPool,
+    registry_client: Option<Box<DynClient<L2>>>,
 ) -> anyhow::Result<()> {
+    let secrets = keystore::resolve(&secrets).context("keystore")?;
     let validator_key = config::validator_key(&secrets)
         .context("validator_key")?
         .context("missing validator_key")?;
-    scope::run!(&ctx, |ctx, s| async {
-        if let Some(spec) = &cfg.genesis_spec {
-            let spec = config::GenesisSpec::parse(spec).context("GenesisSpec::parse()")?;
-            pool.connection(ctx)
-                .await
-                .wrap("connection()")?
-                .adjust_genesis(ctx, &spec)
-                .await
-                .wrap("adjust_genesis()")?;
+    let spec = cfg
+        .genesis_spec
+        .as_ref()
+        .map(config::GenesisSpec::parse)
+        .transpose()
+        .context("GenesisSpec::parse()")?;
+    let registry = match (cfg.registry_address, registry_client) {
+        (Some(address), Some(client)) => {
+            anyhow::ensure!(
+                spec.is_some(),
+                "consensus registry requires genesis_spec to be configured"
+            );
+            Some(registry::Registry::new(client, address))
         }
-        let (store, runner) = Store::new(ctx, pool, None).await.wrap("Store::new()")?;
-        s.spawn_bg(runner.run(ctx));
-        let (block_store, runner) = BlockStore::new(ctx, Box::new(store.clone()))
-            .await
-            .wrap("BlockStore::new()")?;
-        s.spawn_bg(runner.run(ctx));
-        anyhow::ensure!(
-            block_store.genesis().leader_selection
-                == validator::LeaderSelectionMode::Sticky(validator_key.public()),
-            "unsupported leader selection mode - main node has to be the leader"
-        );
+        (Some(_), None) => anyhow::bail!("consensus registry is configured, but client is missing"),
+        (None, _) => None,
+    };
 
-        let executor = executor::Executor {
-            config: config::executor(&cfg, &secrets)?,
-            block_store,
-            validator: Some(executor::Validator {
-                key: validator_key,
-                replica_store: Box::new(store.clone()),
-                payload_manager: Box::new(store.clone()),
-            }),
-        };
-        executor.run(ctx).await
+    let peers = peers::PeerManager::new(&cfg).context("PeerManager::new()")?;
+    let res: ctx::Result<()> = scope::run!(ctx, |ctx, s| async {
+        if let Some(addr) = cfg.admin_addr {
+            s.spawn_bg(peers.serve(ctx, addr));
+        }
+        s.spawn_bg(peers.run_gossip_guard(ctx));
+        loop {
+            let res: ctx::Result<()> = scope::run!(ctx, |ctx, s| async {
+                let epoch = match &registry {
+                    Some(_) => pool.last_epoch(ctx).await.wrap("last_epoch()")?,
+                    None => None,
+                };
+                if let Some(mut spec) = spec.clone() {
+                    if let (Some(registry), Some(epoch)) = (&registry, epoch) {
+                        spec.validators = registry
+                            .validator_committee(ctx, epoch)
+                            .await
+                            .wrap("validator_committee()")?;
+                    }
+                    pool.connection(ctx)
+                        .await
+                        .wrap("connection()")?
+                        .adjust_genesis(ctx, &spec)
+                        .await
+                        .wrap("adjust_genesis()")?;
+                }
+                let (store, runner) = Store::new(ctx, pool.clone(), None)
+                    .await
+                    .wrap("Store::new()")?;
+                s.spawn_bg(async { Ok(runner.run(ctx).await?) });
+                let (block_store, runner) = BlockStore::new(ctx, Box::new(store.clone()))
+                    .await
+                    .wrap("BlockStore::new()")?;
+                s.spawn_bg(async { Ok(runner.run(ctx).await?) });
+                if block_store.genesis().leader_selection
+                    != validator::LeaderSelectionMode::Sticky(validator_key.public())
+                {
+                    return Err(anyhow::format_err!(
+                        "unsupported leader selection mode - main node has to be the leader"
+                    )
+                    .into());
+                }
+                let committee = block_store.genesis().committee.clone();
+
+                let executor = peers.run_executor(
+                    ctx,
+                    &secrets,
+                    block_store,
+                    store.clone(),
+                    Some(validator_key.clone()),
+                );
+                let Some(registry) = &registry else {
+                    return executor.await;
+                };
+                s.spawn_bg(executor);
+
+                // Wait for the committee in the registry to change.
+                let mut epoch = epoch;
+                loop {
+                    let next = pool
+                        .wait_for_next_epoch(ctx, epoch)
+                        .await
+                        .wrap("wait_for_next_epoch()")?;
+                    let new = registry
+                        .validator_committee(ctx, next)
+                        .await
+                        .wrap("validator_committee()")?;
+                    if new != committee {
+                        tracing::info!(
+                            "Validator committee changed at L1 batch #{}, restarting consensus",
+                            next.batch
+                        );
+                        return Ok(());
+                    }
+                    epoch = Some(next);
+                }
+            })
+            .await;
+            match res {
+                // Consensus has been restarted due to a committee change.
+                Ok(()) if registry.is_some() => {}
+                res => return res,
+            }
+        }
     })
-    .await
+    .await;
+    match res {
+        Ok(()) | Err(ctx::Error::Canceled(_)) => Ok(()),
+        Err(ctx::Error::Internal(err)) => Err(err),
+    }
 }
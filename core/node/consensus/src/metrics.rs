@@ -0,0 +1,59 @@
+//! Metrics for the consensus component.
+
+use std::time::Duration;
+
+use vise::{Buckets, Counter, EncodeLabelSet, EncodeLabelValue, Family, Gauge, Histogram, Metrics};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EncodeLabelValue, EncodeLabelSet)]
+#[metrics(label = "reason", rename_all = "snake_case")]
+pub(crate) enum RejectReason {
+    Banned,
+    TooManyConnections,
+}
+
+/// Metrics of the per-peer limits on the inbound gossip traffic.
+#[derive(Debug, Metrics)]
+#[metrics(prefix = "consensus_gossip_guard")]
+pub(crate) struct GossipGuardMetrics {
+    /// Number of open inbound gossip connections.
+    pub connections: Gauge<usize>,
+    /// Number of inbound gossip connections rejected by the guard.
+    pub rejected_connections: Family<RejectReason, Counter>,
+    /// Number of bytes received from the peers and forwarded to the gossip server.
+    pub inbound_bytes: Counter,
+    /// Number of reads from peers delayed because the peer has exceeded its rate limit.
+    pub throttled_reads: Counter,
+    /// Number of times a peer has been banned because its score has dropped to zero.
+    pub bans: Counter,
+}
+
+#[vise::register]
+pub(crate) static GOSSIP_GUARD_METRICS: vise::Global<GossipGuardMetrics> = vise::Global::new();
+
+/// Metrics of reading the consensus keys from the encrypted keystore.
+#[derive(Debug, Metrics)]
+#[metrics(prefix = "consensus_keystore")]
+pub(crate) struct KeystoreMetrics {
+    /// Latency of reading and decrypting a keystore.
+    #[metrics(buckets = Buckets::LATENCIES)]
+    pub read_latency: Histogram<Duration>,
+    /// Number of keystores that could not be read or decrypted, so that the next keystore was tried.
+    pub read_failures: Counter,
+}
+
+#[vise::register]
+pub(crate) static KEYSTORE_METRICS: vise::Global<KeystoreMetrics> = vise::Global::new();
+
+/// Metrics of the attester.
+#[derive(Debug, Metrics)]
+#[metrics(prefix = "consensus_attester")]
+pub(crate) struct AttesterMetrics {
+    /// Latency of signing an L1 batch attestation.
+    #[metrics(buckets = Buckets::LATENCIES)]
+    pub signing_latency: Histogram<Duration>,
+    /// Number of signed L1 batch attestations.
+    pub signed_batches: Counter,
+}
+
+#[vise::register]
+pub(crate) static ATTESTER_METRICS: vise::Global<AttesterMetrics> = vise::Global::new();
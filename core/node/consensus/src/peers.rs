@@ -0,0 +1,262 @@
+//! Runtime management of the gossip network peers.
+//!
+//! Operators can ban/unban peers and add static outbound peers without editing the config
+//! and restarting the node. Changes are applied by restarting the consensus executor
+//! with the adjusted network config, which takes a fraction of a second.
+//!
+//! NOTE: the network actor doesn't expose the connected peers nor their traffic statistics,
+//! so the API only reports the effective gossip config (`GET /peers/config`) rather than the live connections.
+//! For the same reason a ban cannot be applied to the dynamic inbound connections.
+//! If gossip rate limits are configured, `GET /peers/inbound` reports the inbound peers (identified by IP)
+//! together with their scores and traffic, as seen by the [`GossipGuard`].
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    net::SocketAddr,
+    sync::Arc,
+};
+
+use anyhow::Context as _;
+use axum::{
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing, Json, Router,
+};
+use serde::{Deserialize, Serialize};
+use zksync_concurrency::{ctx, scope, sync};
+use zksync_config::configs::consensus::{ConsensusConfig, ConsensusSecrets, Host, NodePublicKey};
+use zksync_consensus_crypto::{Text, TextFmt as _};
+use zksync_consensus_executor as executor;
+use zksync_consensus_roles::{node, validator};
+use zksync_consensus_storage::BlockStore;
+
+use crate::{
+    config,
+    gossip_guard::{GossipGuard, InboundPeer},
+    storage::Store,
+};
+
+/// Adjustments of the gossip config made at runtime.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct Peers {
+    /// Static outbound peers added at runtime.
+    pub(crate) static_outbound: BTreeMap<NodePublicKey, Host>,
+    /// Banned peers.
+    pub(crate) banned: BTreeSet<NodePublicKey>,
+}
+
+impl Peers {
+    /// Returns the config adjusted according to `self`:
+    /// banned peers are removed from the static peers and runtime static peers are added.
+    pub(crate) fn apply(&self, cfg: &ConsensusConfig) -> ConsensusConfig {
+        let mut cfg = cfg.clone();
+        cfg.gossip_static_outbound
+            .extend(self.static_outbound.clone());
+        cfg.gossip_static_outbound
+            .retain(|key, _| !self.banned.contains(key));
+        cfg.gossip_static_inbound
+            .retain(|key| !self.banned.contains(key));
+        cfg
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct PeersConfigResponse {
+    static_inbound: BTreeSet<String>,
+    static_outbound: BTreeMap<String, String>,
+    banned: BTreeSet<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PeerRequest {
+    key: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct StaticPeerRequest {
+    key: String,
+    addr: String,
+}
+
+#[derive(Debug)]
+struct ApiError(anyhow::Error);
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        (StatusCode::BAD_REQUEST, format!("{:#}", self.0)).into_response()
+    }
+}
+
+/// Parses and normalizes a node public key.
+fn parse_key(key: &str) -> Result<NodePublicKey, ApiError> {
+    let key: node::PublicKey = Text::new(key)
+        .decode()
+        .context("invalid node key")
+        .map_err(ApiError)?;
+    Ok(NodePublicKey(key.encode()))
+}
+
+/// Shared handle to the runtime gossip config adjustments.
+#[derive(Debug, Clone)]
+pub(crate) struct PeerManager {
+    cfg: ConsensusConfig,
+    peers: Arc<sync::watch::Sender<Peers>>,
+    guard: Option<Arc<GossipGuard>>,
+}
+
+impl PeerManager {
+    pub(crate) fn new(cfg: &ConsensusConfig) -> anyhow::Result<Self> {
+        let guard = cfg
+            .gossip_rate_limit
+            .clone()
+            .map(GossipGuard::new)
+            .transpose()
+            .context("GossipGuard::new()")?;
+        Ok(Self {
+            cfg: cfg.clone(),
+            peers: Arc::new(sync::watch::channel(Peers::default()).0),
+            guard: guard.map(Arc::new),
+        })
+    }
+
+    pub(crate) fn peers(&self) -> Peers {
+        self.peers.borrow().clone()
+    }
+
+    /// Returns the config adjusted according to the current peers.
+    pub(crate) fn effective_config(&self) -> ConsensusConfig {
+        self.peers.borrow().apply(&self.cfg)
+    }
+
+    pub(crate) fn add_static_peer(&self, key: NodePublicKey, addr: Host) {
+        tracing::info!("Adding static peer {} at {}", key.0, addr.0);
+        self.peers.send_modify(|peers| {
+            peers.static_outbound.insert(key, addr);
+        });
+    }
+
+    pub(crate) fn ban(&self, key: NodePublicKey) {
+        tracing::info!("Banning peer {}", key.0);
+        self.peers
+            .send_if_modified(|peers| peers.banned.insert(key));
+    }
+
+    pub(crate) fn unban(&self, key: &NodePublicKey) {
+        tracing::info!("Unbanning peer {}", key.0);
+        self.peers
+            .send_if_modified(|peers| peers.banned.remove(key));
+    }
+
+    /// Runs the consensus executor, restarting it whenever the peers are adjusted.
+    pub(crate) async fn run_executor(
+        &self,
+        ctx: &ctx::Ctx,
+        secrets: &ConsensusSecrets,
+        block_store: Arc<BlockStore>,
+        store: Store,
+        validator_key: Option<validator::SecretKey>,
+    ) -> ctx::Result<()> {
+        let mut peers = self.peers.subscribe();
+        loop {
+            let mut cfg = peers.borrow_and_update().apply(&self.cfg);
+            if let Some(guard) = &self.guard {
+                cfg.server_addr = guard.internal_addr();
+            }
+            let executor = executor::Executor {
+                config: config::executor(&cfg, secrets)?,
+                block_store: block_store.clone(),
+                validator: validator_key.clone().map(|key| executor::Validator {
+                    key,
+                    replica_store: Box::new(store.clone()),
+                    payload_manager: Box::new(store.clone()),
+                }),
+            };
+            scope::run!(ctx, |ctx, s| async {
+                s.spawn_bg(async { Ok(executor.run(ctx).await?) });
+                sync::changed(ctx, &mut peers).await?;
+                tracing::info!("Gossip peers have been adjusted, restarting the executor");
+                Ok(())
+            })
+            .await?;
+        }
+    }
+
+    /// Enforces the gossip rate limits (if configured) until the context is canceled.
+    pub(crate) async fn run_gossip_guard(&self, ctx: &ctx::Ctx) -> ctx::Result<()> {
+        let Some(guard) = &self.guard else {
+            return Ok(());
+        };
+        guard.run(ctx, self.cfg.server_addr).await
+    }
+
+    /// Serves the peer management admin API until the context is canceled.
+    pub(crate) async fn serve(&self, ctx: &ctx::Ctx, addr: SocketAddr) -> ctx::Result<()> {
+        let app = Router::new()
+            .route("/peers/config", routing::get(Self::get_peers_config))
+            .route("/peers/inbound", routing::get(Self::get_inbound_peers))
+            .route("/peers/static", routing::post(Self::post_static_peer))
+            .route("/peers/ban", routing::post(Self::post_ban))
+            .route("/peers/unban", routing::post(Self::post_unban))
+            .with_state(self.clone());
+        tracing::info!("Starting consensus admin API on {addr}");
+        axum::Server::try_bind(&addr)
+            .with_context(|| format!("Failed binding consensus admin API to {addr}"))?
+            .serve(app.into_make_service())
+            .with_graceful_shutdown(ctx.canceled())
+            .await
+            .context("consensus admin API failed")?;
+        tracing::info!("Consensus admin API shut down");
+        Ok(())
+    }
+
+    async fn get_peers_config(State(this): State<Self>) -> Json<PeersConfigResponse> {
+        let peers = this.peers();
+        let cfg = peers.apply(&this.cfg);
+        Json(PeersConfigResponse {
+            static_inbound: cfg
+                .gossip_static_inbound
+                .into_iter()
+                .map(|key| key.0)
+                .collect(),
+            static_outbound: cfg
+                .gossip_static_outbound
+                .into_iter()
+                .map(|(key, addr)| (key.0, addr.0))
+                .collect(),
+            banned: peers.banned.into_iter().map(|key| key.0).collect(),
+        })
+    }
+
+    async fn get_inbound_peers(State(this): State<Self>) -> Json<Vec<InboundPeer>> {
+        Json(
+            this.guard
+                .as_ref()
+                .map(|guard| guard.inbound_peers())
+                .unwrap_or_default(),
+        )
+    }
+
+    async fn post_static_peer(
+        State(this): State<Self>,
+        Json(req): Json<StaticPeerRequest>,
+    ) -> Result<StatusCode, ApiError> {
+        this.add_static_peer(parse_key(&req.key)?, Host(req.addr));
+        Ok(StatusCode::NO_CONTENT)
+    }
+
+    async fn post_ban(
+        State(this): State<Self>,
+        Json(req): Json<PeerRequest>,
+    ) -> Result<StatusCode, ApiError> {
+        this.ban(parse_key(&req.key)?);
+        Ok(StatusCode::NO_CONTENT)
+    }
+
+    async fn post_unban(
+        State(this): State<Self>,
+        Json(req): Json<PeerRequest>,
+    ) -> Result<StatusCode, ApiError> {
+        this.unban(&parse_key(&req.key)?);
+        Ok(StatusCode::NO_CONTENT)
+    }
+}
@@ -0,0 +1,216 @@
+//! Reading validator and attester committees from the consensus registry contract.
+use std::collections::BTreeMap;
+
+use anyhow::Context as _;
+use zksync_concurrency::{ctx, error::Wrap as _, time};
+use zksync_consensus_crypto::Text;
+use zksync_consensus_roles::validator;
+use zksync_types::{
+    api::{BlockIdVariant, BlockNumber},
+    ethabi, Address, L1BatchNumber, L2BlockNumber, U64,
+};
+use zksync_web3_decl::{
+    client::{DynClient, L2},
+    error::ClientRpcContext as _,
+    namespaces::EthNamespaceClient as _,
+};
+
+use crate::storage::ConnectionPool;
+
+/// ABI of the registry view methods used by the node.
+/// Validator keys are stored in the `TextFmt` representation of `validator::PublicKey`.
+const REGISTRY_ABI: &str = r#"[
+  {
+    "type": "function",
+    "name": "getValidatorCommittee",
+    "inputs": [],
+    "outputs": [{
+      "name": "",
+      "type": "tuple[]",
+      "components": [{ "name": "key", "type": "string" }, { "name": "weight", "type": "uint64" }]
+    }],
+    "stateMutability": "view"
+  },
+  {
+    "type": "function",
+    "name": "getAttesterCommittee",
+    "inputs": [],
+    "outputs": [{
+      "name": "",
+      "type": "tuple[]",
+      "components": [{ "name": "attester", "type": "address" }, { "name": "weight", "type": "uint64" }]
+    }],
+    "stateMutability": "view"
+  }
+]"#;
+
+/// Attester committee: attester addresses with their weights.
+pub(crate) type AttesterCommittee = BTreeMap<Address, u64>;
+
+/// Committees are read from the registry at the last L2 block of an L1 batch,
+/// so that all nodes observe committee changes at the same L1 batch boundaries.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Epoch {
+    pub(crate) batch: L1BatchNumber,
+    pub(crate) last_block: L2BlockNumber,
+}
+
+impl ConnectionPool {
+    /// Returns the epoch at the last sealed L1 batch.
+    pub(crate) async fn last_epoch(&self, ctx: &ctx::Ctx) -> ctx::Result<Option<Epoch>> {
+        let mut conn = self.connection(ctx).await.wrap("connection()")?;
+        let Some(batch) = conn
+            .last_sealed_batch(ctx)
+            .await
+            .wrap("last_sealed_batch()")?
+        else {
+            return Ok(None);
+        };
+        let (_, last_block) = conn
+            .batch_block_range(ctx, batch)
+            .await
+            .wrap("batch_block_range()")?
+            .context("sealed L1 batch has no L2 blocks")?;
+        Ok(Some(Epoch { batch, last_block }))
+    }
+
+    /// Waits for an L1 batch newer than `epoch` to be sealed.
+    pub(crate) async fn wait_for_next_epoch(
+        &self,
+        ctx: &ctx::Ctx,
+        epoch: Option<Epoch>,
+    ) -> ctx::Result<Epoch> {
+        const POLL_INTERVAL: time::Duration = time::Duration::seconds(1);
+        loop {
+            if let Some(next) = self.last_epoch(ctx).await.wrap("last_epoch()")? {
+                if epoch.map_or(true, |epoch| epoch.batch < next.batch) {
+                    return Ok(next);
+                }
+            }
+            ctx.sleep(POLL_INTERVAL).await?;
+        }
+    }
+}
+
+/// Client of the consensus registry contract. Calls its view methods
+/// using the `eth_call` JSON-RPC method.
+#[derive(Debug)]
+pub(crate) struct Registry {
+    client: Box<DynClient<L2>>,
+    address: Address,
+    contract: ethabi::Contract,
+}
+
+impl Registry {
+    pub(crate) fn new(client: Box<DynClient<L2>>, address: Address) -> Self {
+        Self {
+            client: client.for_component("consensus_registry"),
+            address,
+            contract: ethabi::Contract::load(REGISTRY_ABI.as_bytes()).unwrap(),
+        }
+    }
+
+    /// Calls a parameterless view method of the registry, returning its only output.
+    async fn call(
+        &self,
+        ctx: &ctx::Ctx,
+        method: &str,
+        epoch: Epoch,
+    ) -> ctx::Result<Vec<ethabi::Token>> {
+        const RETRY_INTERVAL: time::Duration = time::Duration::seconds(5);
+        let function = self.contract.function(method).context("function()")?;
+        let req = zksync_types::transaction_request::CallRequest::builder()
+            .to(self.address)
+            .data(function.encode_input(&[]).context("encode_input()")?.into())
+            .build();
+        let block = BlockIdVariant::BlockNumber(BlockNumber::Number(U64::from(epoch.last_block.0)));
+        let output = loop {
+            let res = ctx
+                .wait(
+                    self.client
+                        .call(req.clone(), Some(block), None)
+                        .rpc_context("call"),
+                )
+                .await?;
+            match res {
+                Ok(output) => break output,
+                // The API server might not be ready yet.
+                Err(err) if err.is_transient() => {
+                    tracing::warn!("registry.{method}(): {err}");
+                    ctx.sleep(RETRY_INTERVAL).await?;
+                }
+                Err(err) => return Err(anyhow::Error::new(err).context(method.to_owned()).into()),
+            }
+        };
+        let mut tokens = function
+            .decode_output(&output.0)
+            .context("decode_output()")?;
+        if tokens.len() != 1 {
+            return Err(anyhow::format_err!("unexpected number of outputs").into());
+        }
+        Ok(tokens
+            .pop()
+            .unwrap()
+            .into_array()
+            .context("output is not an array")?)
+    }
+
+    /// Reads the validator committee at the given epoch.
+    pub(crate) async fn validator_committee(
+        &self,
+        ctx: &ctx::Ctx,
+        epoch: Epoch,
+    ) -> ctx::Result<validator::Committee> {
+        let entries = self
+            .call(ctx, "getValidatorCommittee", epoch)
+            .await
+            .wrap("getValidatorCommittee()")?;
+        let validators: Vec<_> = entries
+            .into_iter()
+            .enumerate()
+            .map(|(i, entry)| {
+                let (key, weight) = decode_entry(entry).context(i)?;
+                let key = key.into_string().context("key").context(i)?;
+                Ok(validator::WeightedValidator {
+                    key: Text::new(&key).decode().context("key").context(i)?,
+                    weight,
+                })
+            })
+            .collect::<anyhow::Result<_>>()
+            .context("validators")?;
+        Ok(validator::Committee::new(validators).context("validators")?)
+    }
+
+    /// Reads the attester committee at the given epoch.
+    pub(crate) async fn attester_committee(
+        &self,
+        ctx: &ctx::Ctx,
+        epoch: Epoch,
+    ) -> ctx::Result<AttesterCommittee> {
+        let entries = self
+            .call(ctx, "getAttesterCommittee", epoch)
+            .await
+            .wrap("getAttesterCommittee()")?;
+        let mut committee = AttesterCommittee::new();
+        for (i, entry) in entries.into_iter().enumerate() {
+            let (attester, weight) = decode_entry(entry).context(i)?;
+            let attester = attester.into_address().context("attester").context(i)?;
+            if committee.insert(attester, weight).is_some() {
+                return Err(anyhow::format_err!("duplicate attester {attester:?}").into());
+            }
+        }
+        Ok(committee)
+    }
+}
+
+/// Decodes a `(key, uint64 weight)` tuple.
+fn decode_entry(entry: ethabi::Token) -> anyhow::Result<(ethabi::Token, u64)> {
+    let [key, weight]: [ethabi::Token; 2] = entry
+        .into_tuple()
+        .context("entry is not a tuple")?
+        .try_into()
+        .map_err(|_| anyhow::format_err!("unexpected number of fields"))?;
+    let weight = weight.into_uint().context("weight")?;
+    anyhow::ensure!(weight <= u64::MAX.into(), "weight overflow");
+    Ok((key, weight.as_u64()))
+}
@@ -2,18 +2,21 @@
 use std::sync::Arc;
 
 use anyhow::Context as _;
-use zksync_concurrency::{ctx, error::Wrap as _, sync, time};
+use zksync_concurrency::{ctx, error::Wrap as _, scope, sync, time};
 use zksync_consensus_bft::PayloadManager;
 use zksync_consensus_roles::validator;
 use zksync_consensus_storage as storage;
-use zksync_dal::{consensus_dal::Payload, Core, CoreDal, DalError};
+use zksync_dal::{
+    consensus_dal::{BatchAttestation, Payload},
+    Core, CoreDal, DalError,
+};
 use zksync_node_sync::{
     fetcher::{FetchedBlock, FetchedTransaction, IoCursorExt as _},
     sync_action::ActionQueueSender,
     SyncState,
 };
 use zksync_state_keeper::io::common::IoCursor;
-use zksync_types::L2BlockNumber;
+use zksync_types::{L1BatchNumber, L2BlockNumber, H256};
 
 use super::config;
 
@@ -197,6 +200,91 @@ impl<'a> Connection<'a> {
             .await??)
     }
 
+    /// Wrapper for `blocks_dal().get_l1_batch_state_root()`.
+    pub async fn batch_hash(
+        &mut self,
+        ctx: &ctx::Ctx,
+        number: L1BatchNumber,
+    ) -> ctx::Result<Option<H256>> {
+        Ok(ctx
+            .wait(self.0.blocks_dal().get_l1_batch_state_root(number))
+            .await?
+            .map_err(DalError::generalize)?)
+    }
+
+    /// Wrapper for `blocks_dal().get_sealed_l1_batch_number()`.
+    pub async fn last_sealed_batch(
+        &mut self,
+        ctx: &ctx::Ctx,
+    ) -> ctx::Result<Option<L1BatchNumber>> {
+        Ok(ctx
+            .wait(self.0.blocks_dal().get_sealed_l1_batch_number())
+            .await?
+            .map_err(DalError::generalize)?)
+    }
+
+    /// Wrapper for `blocks_dal().get_l2_block_range_of_l1_batch()`.
+    pub async fn batch_block_range(
+        &mut self,
+        ctx: &ctx::Ctx,
+        number: L1BatchNumber,
+    ) -> ctx::Result<Option<(L2BlockNumber, L2BlockNumber)>> {
+        Ok(ctx
+            .wait(self.0.blocks_dal().get_l2_block_range_of_l1_batch(number))
+            .await?
+            .map_err(DalError::generalize)?)
+    }
+
+    /// Wrapper for `blocks_dal().get_last_l1_batch_number_with_tree_data()`.
+    pub async fn last_batch_with_hash(
+        &mut self,
+        ctx: &ctx::Ctx,
+    ) -> ctx::Result<Option<L1BatchNumber>> {
+        Ok(ctx
+            .wait(
+                self.0
+                    .blocks_dal()
+                    .get_last_l1_batch_number_with_tree_data(),
+            )
+            .await?
+            .map_err(DalError::generalize)?)
+    }
+
+    /// Wrapper for `consensus_dal().batch_attestation()`.
+    pub async fn batch_attestation(
+        &mut self,
+        ctx: &ctx::Ctx,
+        number: L1BatchNumber,
+    ) -> ctx::Result<Option<BatchAttestation>> {
+        Ok(ctx
+            .wait(self.0.consensus_dal().batch_attestation(number))
+            .await?
+            .map_err(DalError::generalize)?)
+    }
+
+    /// Wrapper for `consensus_dal().last_batch_attestation_number()`.
+    pub async fn last_batch_attestation_number(
+        &mut self,
+        ctx: &ctx::Ctx,
+    ) -> ctx::Result<Option<L1BatchNumber>> {
+        Ok(ctx
+            .wait(self.0.consensus_dal().last_batch_attestation_number())
+            .await?
+            .map_err(DalError::generalize)?)
+    }
+
+    /// Wrapper for `consensus_dal().insert_batch_attestation()`.
+    pub async fn insert_batch_attestation(
+        &mut self,
+        ctx: &ctx::Ctx,
+        attestation: &BatchAttestation,
+    ) -> ctx::Result<()> {
+        Ok(ctx
+            .wait(self.0.consensus_dal().insert_batch_attestation(attestation))
+            .await?
+            .map_err(DalError::generalize)?)
+    }
+
     /// Fetches and verifies consistency of certificates in storage.
     async fn certificates_range(
         &mut self,
@@ -414,25 +502,95 @@ impl Store {
     }
 }
 
+impl Store {
+    /// Number of the next block expected by the payload queue.
+    /// Returns `None` if the store has no payload queue (i.e. for the main node).
+    pub(super) async fn next_queued_block(
+        &self,
+        ctx: &ctx::Ctx,
+    ) -> ctx::Result<Option<validator::BlockNumber>> {
+        Ok(sync::lock(ctx, &self.payloads)
+            .await?
+            .as_ref()
+            .map(PayloadQueue::next))
+    }
+
+    /// Pushes a block fetched outside of the gossip network (i.e. via JSON-RPC) to the payload queue.
+    /// The block certificate is expected to be delivered later by the gossip network.
+    /// Does nothing if the block has been already queued.
+    pub(super) async fn queue_fetched_block(
+        &self,
+        ctx: &ctx::Ctx,
+        block: FetchedBlock,
+    ) -> ctx::Result<()> {
+        let mut payloads = sync::lock(ctx, &self.payloads).await?.into_async();
+        if let Some(payloads) = &mut *payloads {
+            payloads.send(block).await.context("payload_queue.send()")?;
+        }
+        Ok(())
+    }
+
+    /// Takes the payload queue out of the store, so that it can be reused
+    /// by a new store (i.e. after the consensus genesis changes).
+    pub(super) async fn take_payload_queue(
+        &self,
+        ctx: &ctx::Ctx,
+    ) -> ctx::Result<Option<PayloadQueue>> {
+        Ok(sync::lock(ctx, &self.payloads).await?.take())
+    }
+}
+
 impl StoreRunner {
-    pub async fn run(mut self, ctx: &ctx::Ctx) -> anyhow::Result<()> {
-        let res = async {
+    pub async fn run(self, ctx: &ctx::Ctx) -> anyhow::Result<()> {
+        let StoreRunner {
+            pool,
+            persisted,
+            mut certificates,
+        } = self;
+        let res: ctx::Result<()> = scope::run!(ctx, |ctx, s| async {
+            // Blocks (together with their certificates) are removed from storage by the DB pruner.
+            // Keep the persisted state in sync, so that they are not advertised to peers.
+            s.spawn::<()>(async {
+                const POLL_INTERVAL: time::Duration = time::Duration::seconds(5);
+                loop {
+                    let range = pool
+                        .connection(ctx)
+                        .await
+                        .wrap("connection()")?
+                        .block_range(ctx)
+                        .await
+                        .wrap("block_range()")?;
+                    persisted.send_if_modified(|p| {
+                        if p.first >= range.start {
+                            return false;
+                        }
+                        p.first = range.start;
+                        if p.last
+                            .as_ref()
+                            .map_or(false, |last| last.header().number < p.first)
+                        {
+                            p.last = None;
+                        }
+                        true
+                    });
+                    ctx.sleep(POLL_INTERVAL).await?;
+                }
+            });
+
             loop {
-                let cert = self.certificates.recv(ctx).await?;
-                self.pool
-                    .wait_for_payload(ctx, cert.header().number)
+                let cert = certificates.recv(ctx).await?;
+                pool.wait_for_payload(ctx, cert.header().number)
                     .await
                     .wrap("wait_for_payload()")?;
-                self.pool
-                    .connection(ctx)
+                pool.connection(ctx)
                     .await
                     .wrap("connection()")?
                     .insert_certificate(ctx, &cert)
                     .await
                     .wrap("insert_certificate()")?;
-                self.persisted.send_modify(|p| p.last = Some(cert));
+                persisted.send_modify(|p| p.last = Some(cert));
             }
-        }
+        })
         .await;
         match res {
             Err(ctx::Error::Canceled(_)) | Ok(()) => Ok(()),
@@ -3,8 +3,10 @@
 use anyhow::Context as _;
 use zksync_concurrency::{ctx, error::Wrap as _, time};
 use zksync_consensus_roles::validator;
+use zksync_dal::consensus_dal::BatchAttestation;
 use zksync_node_genesis::{insert_genesis_batch, GenesisParams};
 use zksync_node_test_utils::{recover, snapshot, Snapshot};
+use zksync_types::L1BatchNumber;
 
 use super::ConnectionPool;
 
@@ -30,6 +32,28 @@ impl ConnectionPool {
         Ok(())
     }
 
+    /// Waits for the `number` L1 batch to be attested.
+    pub async fn wait_for_batch_attestation(
+        &self,
+        ctx: &ctx::Ctx,
+        number: L1BatchNumber,
+    ) -> ctx::Result<BatchAttestation> {
+        const POLL_INTERVAL: time::Duration = time::Duration::milliseconds(100);
+        loop {
+            if let Some(attestation) = self
+                .connection(ctx)
+                .await
+                .wrap("connection()")?
+                .batch_attestation(ctx, number)
+                .await
+                .wrap("batch_attestation()")?
+            {
+                return Ok(attestation);
+            }
+            ctx.sleep(POLL_INTERVAL).await?;
+        }
+    }
+
     /// Takes a storage snapshot at the last sealed L1 batch.
     pub(crate) async fn snapshot(&self, ctx: &ctx::Ctx) -> ctx::Result<Snapshot> {
         let mut conn = self.connection(ctx).await.wrap("connection()")?;
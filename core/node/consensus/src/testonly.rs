@@ -10,6 +10,7 @@ use zksync_consensus_crypto::TextFmt as _;
 use zksync_consensus_network as network;
 use zksync_consensus_roles::validator;
 use zksync_dal::{CoreDal, DalError};
+use zksync_health_check::ReactiveHealthCheck;
 use zksync_node_api_server::web3::{state::InternalApiConfig, testonly::spawn_http_server};
 use zksync_node_genesis::GenesisParams;
 use zksync_node_sync::{
@@ -67,6 +68,9 @@ pub(super) fn config(cfg: &network::Config) -> (config::ConsensusConfig, config:
                 .iter()
                 .map(|(k, v)| (config::NodePublicKey(k.encode()), config::Host(v.0.clone())))
                 .collect(),
+            registry_address: None,
+            admin_addr: None,
+            gossip_rate_limit: None,
             genesis_spec: cfg.validator_key.as_ref().map(|key| config::GenesisSpec {
                 chain_id: L2ChainId::default(),
                 protocol_version: config::ProtocolVersion(validator::ProtocolVersion::CURRENT.0),
@@ -83,6 +87,8 @@ pub(super) fn config(cfg: &network::Config) -> (config::ConsensusConfig, config:
                 .validator_key
                 .as_ref()
                 .map(|k| config::ValidatorSecretKey(k.encode().into())),
+            attester_key: None,
+            keystore: None,
         },
     )
 }
@@ -209,6 +215,12 @@ impl StateKeeper {
         validator::BlockNumber(self.last_block.0.into())
     }
 
+    /// Last L1 batch that has been sealed via `ActionQueue`.
+    /// It might NOT be present in storage yet.
+    pub fn last_sealed_batch(&self) -> L1BatchNumber {
+        self.last_batch - (!self.batch_sealed) as u32
+    }
+
     /// Connects to the json RPC endpoint exposed by the state keeper.
     pub async fn connect(&self, ctx: &ctx::Ctx) -> ctx::Result<Box<DynClient<L2>>> {
         let addr = sync::wait_for(ctx, &mut self.addr.clone(), Option::is_some)
@@ -243,6 +255,7 @@ impl StateKeeper {
             pool: self.pool,
             client,
             sync_state: self.sync_state.clone(),
+            health_updater: ReactiveHealthCheck::new("consensus").1,
         }
         .run_fetcher(ctx, self.actions_sender)
         .await
@@ -260,6 +273,7 @@ impl StateKeeper {
             pool: self.pool,
             client,
             sync_state: self.sync_state.clone(),
+            health_updater: ReactiveHealthCheck::new("consensus").1,
         }
         .run(ctx, self.actions_sender, cfg, secrets)
         .await
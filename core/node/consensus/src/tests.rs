@@ -1,16 +1,22 @@
 use anyhow::Context as _;
+use rand::Rng as _;
 use test_casing::test_casing;
 use tracing::Instrument as _;
 use zksync_concurrency::{ctx, scope};
-use zksync_config::configs::consensus::{ValidatorPublicKey, WeightedValidator};
+use zksync_config::configs::consensus::{
+    AttesterSecretKey, ConsensusKeystore, Host, NodePublicKey, ValidatorPublicKey,
+    WeightedValidator,
+};
 use zksync_consensus_crypto::TextFmt as _;
 use zksync_consensus_network::testonly::{new_configs, new_fullnode};
 use zksync_consensus_roles::{
-    validator,
+    node, validator,
     validator::testonly::{Setup, SetupSpec},
 };
+use zksync_health_check::{CheckHealth as _, HealthStatus};
 use zksync_node_test_utils::Snapshot;
-use zksync_types::{L1BatchNumber, L2BlockNumber};
+use zksync_types::{K256PrivateKey, L1BatchNumber, L2BlockNumber, H256};
+use zksync_web3_decl::namespaces::EnNamespaceClient as _;
 
 use super::*;
 
@@ -118,7 +124,7 @@ async fn test_validator(from_snapshot: bool) {
                 tracing::info!("Start consensus actor");
                 // In the first iteration it will initialize genesis.
                 let (cfg,secrets) = testonly::config(&cfgs[0]);
-                s.spawn_bg(run_main_node(ctx, cfg, secrets, pool.clone()));
+                s.spawn_bg(run_main_node(ctx, cfg, secrets, pool.clone(), None));
 
                 tracing::info!("Generate couple more blocks and wait for consensus to catch up.");
                 sk.push_random_blocks(rng, 3).await;
@@ -168,7 +174,13 @@ async fn test_nodes_from_various_snapshots() {
             testonly::StateKeeper::new(ctx, validator_pool.clone()).await?;
         s.spawn_bg(runner.run(ctx).instrument(tracing::info_span!("validator")));
         let (cfg, secrets) = testonly::config(&validator_cfg);
-        s.spawn_bg(run_main_node(ctx, cfg, secrets, validator_pool.clone()));
+        s.spawn_bg(run_main_node(
+            ctx,
+            cfg,
+            secrets,
+            validator_pool.clone(),
+            None,
+        ));
 
         tracing::info!("produce some batches");
         validator.push_random_blocks(rng, 5).await;
@@ -275,7 +287,13 @@ async fn test_full_nodes(from_snapshot: bool) {
 
         tracing::info!("Run validator.");
         let (cfg, secrets) = testonly::config(&validator_cfgs[0]);
-        s.spawn_bg(run_main_node(ctx, cfg, secrets, validator_pool.clone()));
+        s.spawn_bg(run_main_node(
+            ctx,
+            cfg,
+            secrets,
+            validator_pool.clone(),
+            None,
+        ));
 
         tracing::info!("Run nodes.");
         let mut node_pools = vec![];
@@ -362,7 +380,13 @@ async fn test_en_validators(from_snapshot: bool) {
                 weight: 1,
             })
             .collect();
-        s.spawn_bg(run_main_node(ctx, cfg, secrets, main_node_pool.clone()));
+        s.spawn_bg(run_main_node(
+            ctx,
+            cfg,
+            secrets,
+            main_node_pool.clone(),
+            None,
+        ));
 
         tracing::info!("Run external nodes.");
         let mut ext_node_pools = vec![];
@@ -419,7 +443,13 @@ async fn test_p2p_fetcher_backfill_certs(from_snapshot: bool) {
             testonly::StateKeeper::new(ctx, validator_pool.clone()).await?;
         s.spawn_bg(runner.run(ctx));
         let (cfg, secrets) = testonly::config(&validator_cfg);
-        s.spawn_bg(run_main_node(ctx, cfg, secrets, validator_pool.clone()));
+        s.spawn_bg(run_main_node(
+            ctx,
+            cfg,
+            secrets,
+            validator_pool.clone(),
+            None,
+        ));
         // API server needs at least 1 L1 batch to start.
         validator.seal_batch().await;
         let client = validator.connect(ctx).await?;
@@ -515,3 +545,359 @@ async fn test_centralized_fetcher(from_snapshot: bool) {
     .await
     .unwrap();
 }
+
+// Test external node falling back to JSON-RPC when gossip network is unavailable.
+#[test_casing(2, [false, true])]
+#[tokio::test(flavor = "multi_thread")]
+async fn test_en_gossip_fallback(from_snapshot: bool) {
+    zksync_concurrency::testonly::abort_on_panic();
+    let ctx = &ctx::test_root(&ctx::AffineClock::new(10.));
+    let rng = &mut ctx.rng();
+    let setup = Setup::new(rng, 1);
+    let validator_cfg = new_configs(rng, &setup, 0)[0].clone();
+    // Node is connected only to a peer which is never started,
+    // so it cannot receive any blocks over the gossip network.
+    let node_cfg = new_fullnode(rng, &new_fullnode(rng, &validator_cfg));
+
+    scope::run!(ctx, |ctx, s| async {
+        tracing::info!("Spawn validator.");
+        let validator_pool = new_pool(from_snapshot).await;
+        let (mut validator, runner) =
+            testonly::StateKeeper::new(ctx, validator_pool.clone()).await?;
+        s.spawn_bg(runner.run(ctx).instrument(tracing::info_span!("validator")));
+        let (cfg, secrets) = testonly::config(&validator_cfg);
+        s.spawn_bg(run_main_node(
+            ctx,
+            cfg,
+            secrets,
+            validator_pool.clone(),
+            None,
+        ));
+        // API server needs at least 1 L1 batch to start.
+        validator.seal_batch().await;
+        let client = validator.connect(ctx).await?;
+
+        tracing::info!("Run isolated node.");
+        let node_pool = new_pool(from_snapshot).await;
+        let (node, runner) = testonly::StateKeeper::new(ctx, node_pool.clone()).await?;
+        s.spawn_bg(runner.run(ctx).instrument(tracing::info_span!("node")));
+        s.spawn_bg(node.run_consensus(ctx, client, &node_cfg));
+
+        tracing::info!("Produce some blocks and wait for node to fetch them");
+        validator.push_random_blocks(rng, 5).await;
+        let want = validator_pool
+            .wait_for_payload(ctx, validator.last_block())
+            .await?;
+        let got = node_pool
+            .wait_for_payload(ctx, validator.last_block())
+            .await?;
+        assert_eq!(want, got);
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
+
+// Test that the EN follows a change of the consensus genesis on the main node
+// (i.e. a validator committee update) without being restarted.
+#[test_casing(2, [false, true])]
+#[tokio::test(flavor = "multi_thread")]
+async fn test_en_genesis_change(from_snapshot: bool) {
+    zksync_concurrency::testonly::abort_on_panic();
+    let ctx = &ctx::test_root(&ctx::AffineClock::new(10.));
+    let rng = &mut ctx.rng();
+    let setup = Setup::new(rng, 1);
+    let validator_cfg = new_configs(rng, &setup, 0)[0].clone();
+    let node_cfg = new_fullnode(rng, &validator_cfg);
+
+    scope::run!(ctx, |ctx, s| async {
+        tracing::info!("Spawn validator.");
+        let validator_pool = new_pool(from_snapshot).await;
+        let (mut validator, runner) =
+            testonly::StateKeeper::new(ctx, validator_pool.clone()).await?;
+        s.spawn_bg(runner.run(ctx).instrument(tracing::info_span!("validator")));
+        let (cfg, secrets) = testonly::config(&validator_cfg);
+        // API server needs at least 1 L1 batch to start.
+        validator.seal_batch().await;
+        let client = validator.connect(ctx).await?;
+
+        tracing::info!("Run node.");
+        let node_pool = new_pool(from_snapshot).await;
+        let (node, runner) = testonly::StateKeeper::new(ctx, node_pool.clone()).await?;
+        s.spawn_bg(runner.run(ctx).instrument(tracing::info_span!("node")));
+        s.spawn_bg(node.run_consensus(ctx, client, &node_cfg));
+
+        scope::run!(ctx, |ctx, s| async {
+            s.spawn_bg(run_main_node(
+                ctx,
+                cfg.clone(),
+                secrets.clone(),
+                validator_pool.clone(),
+                None,
+            ));
+            validator.push_random_blocks(rng, 3).await;
+            node_pool
+                .wait_for_certificate(ctx, validator.last_block())
+                .await?;
+            Ok(())
+        })
+        .await?;
+
+        tracing::info!("Change the validator committee and restart the main node.");
+        let mut cfg = cfg;
+        cfg.genesis_spec.as_mut().unwrap().validators[0].weight = 2;
+        s.spawn_bg(run_main_node(
+            ctx,
+            cfg,
+            secrets,
+            validator_pool.clone(),
+            None,
+        ));
+        validator.push_random_blocks(rng, 3).await;
+        let want = validator_pool
+            .wait_for_payload(ctx, validator.last_block())
+            .await?;
+        node_pool
+            .wait_for_certificate(ctx, validator.last_block())
+            .await?;
+        let got = node_pool
+            .wait_for_payload(ctx, validator.last_block())
+            .await?;
+        assert_eq!(want, got);
+        let want = validator_pool.connection(ctx).await?.genesis(ctx).await?;
+        let got = node_pool.connection(ctx).await?.genesis(ctx).await?;
+        assert_eq!(want, got);
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
+
+// Test that the certificates served over the API can be verified against the consensus genesis.
+#[tokio::test(flavor = "multi_thread")]
+async fn test_block_certificate_api() {
+    zksync_concurrency::testonly::abort_on_panic();
+    let ctx = &ctx::test_root(&ctx::AffineClock::new(10.));
+    let rng = &mut ctx.rng();
+    let setup = Setup::new(rng, 1);
+    let validator_cfg = new_configs(rng, &setup, 0)[0].clone();
+
+    scope::run!(ctx, |ctx, s| async {
+        let pool = new_pool(false).await;
+        let (mut validator, runner) = testonly::StateKeeper::new(ctx, pool.clone()).await?;
+        s.spawn_bg(runner.run(ctx));
+        let (cfg, secrets) = testonly::config(&validator_cfg);
+        s.spawn_bg(run_main_node(ctx, cfg, secrets, pool.clone(), None));
+        // API server needs at least 1 L1 batch to start.
+        validator.seal_batch().await;
+        validator.push_random_blocks(rng, 3).await;
+        let want = pool
+            .wait_for_certificates_and_verify(ctx, validator.last_block())
+            .await?;
+
+        let client = validator.connect(ctx).await?;
+        for block in &want {
+            let n = L2BlockNumber(block.number().0.try_into().unwrap());
+            let got = ctx
+                .wait(client.block_certificate(n))
+                .await?
+                .context("block_certificate()")?
+                .context("certificate missing")?;
+            let got: validator::CommitQC =
+                zksync_protobuf::serde::deserialize(&got.0).context("deserialize()")?;
+            assert_eq!(block.justification, got);
+        }
+        let next = L2BlockNumber(validator.last_block().next().0.try_into().unwrap());
+        assert!(ctx
+            .wait(client.block_certificate(next))
+            .await?
+            .context("block_certificate()")?
+            .is_none());
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_attester() {
+    zksync_concurrency::testonly::abort_on_panic();
+    let ctx = &ctx::test_root(&ctx::AffineClock::new(10.));
+    let rng = &mut ctx.rng();
+    let key_bytes = H256(rng.gen());
+    let key = K256PrivateKey::from_bytes(key_bytes).unwrap();
+    let secrets = ConsensusSecrets {
+        validator_key: None,
+        node_key: None,
+        attester_key: Some(AttesterSecretKey(format!("{key_bytes:?}").into())),
+        keystore: None,
+    };
+    let pool = new_pool(false).await;
+
+    scope::run!(ctx, |ctx, s| async {
+        let (mut sk, runner) = testonly::StateKeeper::new(ctx, pool.clone()).await?;
+        s.spawn_bg(runner.run(ctx));
+        sk.push_random_blocks(rng, 3).await;
+        sk.seal_batch().await;
+
+        tracing::info!("Run attester.");
+        let attester = Attester::new(pool.0.clone(), &secrets)?.context("attester missing")?;
+        let health = attester.health_check();
+        s.spawn_bg(attester.run(ctx));
+
+        tracing::info!("Wait for the sealed batches to be attested.");
+        sk.push_random_blocks(rng, 5).await;
+        sk.seal_batch().await;
+        let number = sk.last_sealed_batch();
+        let attestation = pool.wait_for_batch_attestation(ctx, number).await?;
+        let hash = pool
+            .connection(ctx)
+            .await?
+            .batch_hash(ctx, number)
+            .await?
+            .context("batch hash missing")?;
+        assert_eq!(attestation.hash, hash);
+        assert_eq!(attestation.attester, key.address());
+        let signer = attestation
+            .signature
+            .signature_recover_signer(&attester::attestation_digest(number, hash))
+            .unwrap();
+        assert_eq!(signer, key.address());
+        assert_eq!(health.check_health().await.status(), HealthStatus::Ready);
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
+
+#[test]
+fn test_peers_apply() {
+    let rng = &mut rand::thread_rng();
+    let key = |rng: &mut rand::rngs::ThreadRng| {
+        let key: node::SecretKey = rng.gen();
+        NodePublicKey(key.public().encode())
+    };
+    let (inbound, outbound, added) = (key(rng), key(rng), key(rng));
+    let (cfg, _) = testonly::config(&new_configs(rng, &Setup::new(rng, 1), 0)[0]);
+    let mut cfg = cfg;
+    cfg.gossip_static_inbound = [inbound.clone()].into();
+    cfg.gossip_static_outbound = [(outbound.clone(), Host("127.0.0.1:1".into()))].into();
+
+    let mut peers = peers::Peers::default();
+    assert_eq!(cfg, peers.apply(&cfg));
+
+    peers
+        .static_outbound
+        .insert(added.clone(), Host("127.0.0.1:2".into()));
+    peers.banned.insert(inbound.clone());
+    peers.banned.insert(outbound.clone());
+    let got = peers.apply(&cfg);
+    assert!(got.gossip_static_inbound.is_empty());
+    assert_eq!(
+        got.gossip_static_outbound,
+        [(added, Host("127.0.0.1:2".into()))].into()
+    );
+}
+
+#[test]
+fn test_keystore() {
+    let rng = &mut rand::thread_rng();
+    let cfg = &new_configs(rng, &Setup::new(rng, 1), 0)[0];
+    let (_, secrets) = testonly::config(cfg);
+    let contents = keystore::encrypt(&secrets, "password").unwrap();
+    let dir = tempfile::TempDir::new().unwrap();
+    let path = dir.path().join("keystore.json");
+    std::fs::write(&path, contents).unwrap();
+    let password_file = dir.path().join("password");
+    std::fs::write(&password_file, "password\n").unwrap();
+
+    let mut keystore_secrets = ConsensusSecrets {
+        validator_key: None,
+        node_key: None,
+        attester_key: None,
+        keystore: Some(ConsensusKeystore {
+            path: path.clone(),
+            fallback_paths: vec![],
+            password_file: Some(password_file.clone()),
+        }),
+    };
+    assert_eq!(secrets, keystore::resolve(&keystore_secrets).unwrap());
+
+    // Keys cannot be set both in the keystore and in the secrets.
+    keystore_secrets.node_key = secrets.node_key.clone();
+    assert!(keystore::resolve(&keystore_secrets).is_err());
+    keystore_secrets.node_key = None;
+
+    // Failover to the fallback keystore if the primary one is unavailable.
+    let keystore = keystore_secrets.keystore.as_mut().unwrap();
+    keystore.path = dir.path().join("missing.json");
+    keystore.fallback_paths = vec![path];
+    assert_eq!(secrets, keystore::resolve(&keystore_secrets).unwrap());
+
+    // Wrong password.
+    std::fs::write(&password_file, "wrong").unwrap();
+    assert!(keystore::resolve(&keystore_secrets).is_err());
+}
+
+#[test]
+fn test_gossip_guard_peer_state() {
+    use std::time::{Duration, Instant};
+
+    use zksync_config::configs::consensus::GossipRateLimitConfig;
+
+    use crate::{
+        gossip_guard::{Admission, PeerState},
+        metrics::RejectReason,
+    };
+
+    let cfg = GossipRateLimitConfig {
+        max_inbound_bytes_per_sec: 1_000,
+        max_inbound_burst_bytes: 10_000,
+        max_connections_per_peer: 2,
+        ban_duration_sec: 60,
+    };
+    let start = Instant::now();
+    let mut peer = PeerState::new(&cfg, start);
+    peer.connect(&cfg, start).unwrap();
+    peer.connect(&cfg, start).unwrap();
+    assert_eq!(
+        peer.connect(&cfg, start),
+        Err(RejectReason::TooManyConnections)
+    );
+
+    // The burst is forwarded immediately; the traffic over the burst is throttled.
+    assert_eq!(
+        peer.receive(&cfg, start, 10_000),
+        Admission::Forward(Duration::ZERO)
+    );
+    assert_eq!(
+        peer.receive(&cfg, start, 500),
+        Admission::Forward(Duration::from_millis(500))
+    );
+    // Tokens are refilled over time.
+    let now = start + Duration::from_secs(2);
+    assert_eq!(
+        peer.receive(&cfg, now, 1_000),
+        Admission::Forward(Duration::ZERO)
+    );
+
+    // A peer exceeding its limits is eventually banned.
+    let mut admission = Admission::Forward(Duration::ZERO);
+    for _ in 0..100 {
+        admission = peer.receive(&cfg, now, 1_000);
+        if admission == Admission::Banned {
+            break;
+        }
+    }
+    assert_eq!(admission, Admission::Banned);
+    assert!(peer.is_banned(now));
+    assert_eq!(peer.connect(&cfg, now), Err(RejectReason::Banned));
+
+    // The ban expires after `ban_duration`.
+    let now = now + cfg.ban_duration();
+    assert!(!peer.is_banned(now));
+    assert_eq!(
+        peer.receive(&cfg, now, 1_000),
+        Admission::Forward(Duration::ZERO)
+    );
+}
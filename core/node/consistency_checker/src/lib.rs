@@ -75,12 +75,17 @@ struct ConsistencyCheckerDetails {
 
 impl ConsistencyCheckerDetails {
     fn health(&self) -> Health {
-        let status = if self.inconsistent_batches.is_empty() {
-            HealthStatus::Ready
+        let health = if self.inconsistent_batches.is_empty() {
+            Health::from(HealthStatus::Ready)
         } else {
-            HealthStatus::Affected
+            Health::from(HealthStatus::Affected).with_cause("inconsistent_batches")
         };
-        Health::from(status).with_details(self)
+        health
+            .with_counter(
+                "inconsistent_batches",
+                self.inconsistent_batches.len() as u64,
+            )
+            .with_details(self)
     }
 }
 
@@ -238,8 +243,16 @@ impl LocalL1BatchCommitData {
             );
         }
 
-        let local_token =
-            CommitBatchInfo::new(self.commitment_mode, &self.l1_batch, da).into_token();
+        // For `PubdataDA::Custom`, the inclusion data is produced by the DA layer and isn't available locally,
+        // so it's taken from the reference; the rest of the commitment is still checked.
+        let mut l1_batch = Cow::Borrowed(&self.l1_batch);
+        if matches!(da, PubdataDA::Custom) {
+            let inclusion_data = custom_da_inclusion_data(reference)
+                .context("cannot extract DA inclusion data from reference commitment token")?;
+            l1_batch.to_mut().metadata.da_inclusion_data = Some(inclusion_data.to_vec());
+        }
+
+        let local_token = CommitBatchInfo::new(self.commitment_mode, &l1_batch, da).into_token();
         anyhow::ensure!(
             local_token == *reference,
             "Locally reproduced commitment differs from the reference obtained from L1; \
@@ -262,6 +275,7 @@ pub fn detect_da(
     /// These are used by the L1 Contracts to indicate what DA layer is used for pubdata
     const PUBDATA_SOURCE_CALLDATA: u8 = 0;
     const PUBDATA_SOURCE_BLOBS: u8 = 1;
+    const PUBDATA_SOURCE_CUSTOM: u8 = 2;
 
     fn parse_error(message: impl Into<Cow<'static, str>>) -> ethabi::Error {
         ethabi::Error::Other(message.into())
@@ -292,14 +306,26 @@ pub fn detect_da(
     match last_reference_token.first() {
         Some(&byte) if byte == PUBDATA_SOURCE_CALLDATA => Ok(PubdataDA::Calldata),
         Some(&byte) if byte == PUBDATA_SOURCE_BLOBS => Ok(PubdataDA::Blobs),
+        Some(&byte) if byte == PUBDATA_SOURCE_CUSTOM => Ok(PubdataDA::Custom),
         Some(&byte) => Err(parse_error(format!(
-            "unexpected first byte of the last reference token; expected one of [{PUBDATA_SOURCE_CALLDATA}, {PUBDATA_SOURCE_BLOBS}], \
-                got {byte}"
+            "unexpected first byte of the last reference token; expected one of [{PUBDATA_SOURCE_CALLDATA}, {PUBDATA_SOURCE_BLOBS}, \
+                {PUBDATA_SOURCE_CUSTOM}], got {byte}"
         ))),
         None => Err(parse_error("last reference token is empty")),
     }
 }
 
+/// Extracts the DA inclusion data from the `reference` commitment that uses `PubdataDA::Custom`.
+pub fn custom_da_inclusion_data(reference: &Token) -> anyhow::Result<&[u8]> {
+    let Token::Tuple(tuple) = reference else {
+        anyhow::bail!("reference has unexpected shape; expected a tuple, got {reference:?}");
+    };
+    match tuple.last() {
+        Some(Token::Bytes(bytes)) if !bytes.is_empty() => Ok(&bytes[1..]),
+        other => anyhow::bail!("unexpected last reference token: {other:?}"),
+    }
+}
+
 #[derive(Debug)]
 pub struct ConsistencyChecker {
     /// ABI of the zkSync contract
@@ -443,8 +469,9 @@ impl ConsistencyChecker {
             .map_err(CheckError::Validation)
     }
 
+    /// Extracts the commitment for `batch_number` from the calldata of a commit transaction.
     /// All returned errors are validation errors.
-    fn extract_commit_data(
+    pub fn extract_commit_data(
         commit_tx_input_data: &[u8],
         commit_function: &ethabi::Function,
         batch_number: L1BatchNumber,
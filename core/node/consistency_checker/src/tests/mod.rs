@@ -171,6 +171,37 @@ fn build_commit_tx_input_data_is_correct(commitment_mode: L1BatchCommitmentMode)
     }
 }
 
+#[test]
+fn verifying_commitment_with_custom_da() {
+    let mut l1_batch = create_l1_batch_with_metadata(1);
+    l1_batch.metadata.da_inclusion_data = Some(vec![1, 2, 3]);
+    let reference = CommitBatchInfo::new(
+        L1BatchCommitmentMode::Validium,
+        &l1_batch,
+        PubdataDA::Custom,
+    )
+    .into_token();
+    let protocol_version = l1_batch.header.protocol_version.unwrap();
+    assert_matches!(
+        detect_da(protocol_version, &reference),
+        Ok(PubdataDA::Custom)
+    );
+
+    // The inclusion data is not persisted locally, so it must be taken from the reference.
+    l1_batch.metadata.da_inclusion_data = None;
+    let local = LocalL1BatchCommitData {
+        l1_batch,
+        commit_tx_hash: H256::zero(),
+        commitment_mode: L1BatchCommitmentMode::Validium,
+    };
+    local.verify_commitment(&reference).unwrap();
+
+    // Other parts of the commitment are still checked.
+    let mut local = local;
+    local.l1_batch.metadata.root_hash = H256::repeat_byte(0xff);
+    local.verify_commitment(&reference).unwrap_err();
+}
+
 #[test]
 fn extracting_commit_data_for_boojum_batch() {
     let contract = zksync_contracts::hyperchain_contract();
@@ -6,8 +6,11 @@ use axum::{
     Json,
 };
 use serde::Serialize;
-use zksync_dal::CoreDal;
-use zksync_types::{contract_verification_api::VerificationIncomingRequest, Address};
+use zksync_dal::{Connection, Core, CoreDal};
+use zksync_types::{
+    contract_verification_api::{CompilerType, CompilerVersions, VerificationIncomingRequest},
+    Address,
+};
 
 use super::{api_decl::RestApi, metrics::METRICS};
 
@@ -44,6 +47,43 @@ impl RestApi {
         Ok(())
     }
 
+    /// Checks that the requested pair of compiler versions is supported by the contract verifier.
+    async fn validate_compiler_versions(
+        storage: &mut Connection<'_, Core>,
+        versions: &CompilerVersions,
+    ) -> Result<(), Response<String>> {
+        let mut dal = storage.contract_verification_dal();
+        let (zk_compiler, zk_versions, compiler, compiler_versions) = match versions.compiler_type()
+        {
+            CompilerType::Solc => (
+                "zksolc",
+                dal.get_zksolc_versions().await.unwrap(),
+                "solc",
+                dal.get_solc_versions().await.unwrap(),
+            ),
+            CompilerType::Vyper => (
+                "zkvyper",
+                dal.get_zkvyper_versions().await.unwrap(),
+                "vyper",
+                dal.get_vyper_versions().await.unwrap(),
+            ),
+        };
+
+        let zk_version = versions.zk_compiler_version();
+        if !zk_versions.contains(&zk_version) {
+            return Err(bad_request(&format!(
+                "unsupported {zk_compiler} version: {zk_version}"
+            )));
+        }
+        let version = versions.compiler_version();
+        if !compiler_versions.contains(&version) {
+            return Err(bad_request(&format!(
+                "unsupported {compiler} version: {version}"
+            )));
+        }
+        Ok(())
+    }
+
     /// Add a contract verification job to the queue if the requested contract wasn't previously verified.
     #[tracing::instrument(skip(self_, request))]
     pub async fn verification(
@@ -59,6 +99,11 @@ impl RestApi {
             .connection_tagged("api")
             .await
             .unwrap();
+        if let Err(res) =
+            Self::validate_compiler_versions(&mut storage, &request.compiler_versions).await
+        {
+            return res;
+        }
 
         if !storage
             .storage_logs_dal()
@@ -0,0 +1,219 @@
+//! Client for Avail.
+//!
+//! The client submits data via the HTTP API of an Avail light client running in the app mode:
+//!
+//! - `GET /v2/status` is used to check that the light client is configured with the expected app ID.
+//! - `POST /v2/submit` submits a data extrinsic and returns the hash of the block it was included into
+//!   and the extrinsic index, which are used as the blob ID.
+//!
+//! Data proofs are fetched from the Avail bridge API (`GET /eth/proof/{block_hash}?index={index}`).
+//! A proof only becomes available once the Avail block is bridged to L1; the ABI-encoded proof
+//! is used as the inclusion data verified by the L1 contracts.
+
+use std::fmt;
+
+use anyhow::Context as _;
+use async_trait::async_trait;
+use ethabi::Token;
+use reqwest::StatusCode;
+use zksync_config::configs::da_client::avail::AvailConfig;
+use zksync_da_client::{
+    types::{DAError, DispatchResponse, InclusionData},
+    DataAvailabilityClient,
+};
+
+use self::types::{BridgeApiProof, LightClientStatus, SubmitRequest, SubmitResponse};
+use crate::utils::{check_status, to_da_error, to_non_retriable_da_error};
+
+#[cfg(test)]
+mod tests;
+mod types;
+
+/// Avail client.
+#[derive(Clone)]
+pub struct AvailClient {
+    config: AvailConfig,
+    client: reqwest::Client,
+}
+
+impl fmt::Debug for AvailClient {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("AvailClient")
+            .field("config", &self.config)
+            .finish_non_exhaustive()
+    }
+}
+
+impl AvailClient {
+    pub fn new(config: AvailConfig) -> anyhow::Result<Self> {
+        let client = reqwest::Client::builder()
+            .timeout(config.request_timeout())
+            .build()
+            .context("failed building HTTP client")?;
+        Ok(Self { config, client })
+    }
+
+    fn light_client_url(&self, path: &str) -> String {
+        format!("{}/{path}", self.config.api_node_url.trim_end_matches('/'))
+    }
+
+    fn bridge_api_url(&self, path: &str) -> String {
+        format!(
+            "{}/{path}",
+            self.config.bridge_api_url.trim_end_matches('/')
+        )
+    }
+
+    fn check_blob_size(&self, data: &[u8]) -> Result<(), DAError> {
+        if let Some(limit) = self.config.max_blob_size_bytes {
+            if data.len() > limit {
+                return Err(to_non_retriable_da_error(anyhow::anyhow!(
+                    "blob size ({} bytes) exceeds the limit ({limit} bytes)",
+                    data.len()
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks that the light client submits data with the configured app ID.
+    async fn check_app_id(&self) -> Result<(), DAError> {
+        let response = self
+            .client
+            .get(self.light_client_url("v2/status"))
+            .send()
+            .await
+            .map_err(to_da_error)?;
+        let status: LightClientStatus = check_status(response, "Avail API")
+            .await?
+            .json()
+            .await
+            .map_err(to_da_error)?;
+
+        if status.app_id != Some(self.config.app_id) {
+            return Err(to_non_retriable_da_error(anyhow::anyhow!(
+                "Avail light client is configured with app ID {:?}, expected {}",
+                status.app_id,
+                self.config.app_id
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl DataAvailabilityClient for AvailClient {
+    async fn dispatch_blob(
+        &self,
+        batch_number: u32,
+        data: Vec<u8>,
+    ) -> Result<DispatchResponse, DAError> {
+        self.check_blob_size(&data)?;
+        self.check_app_id().await?;
+
+        let response = self
+            .client
+            .post(self.light_client_url("v2/submit"))
+            .json(&SubmitRequest { data })
+            .send()
+            .await
+            .map_err(to_da_error)?;
+        let response: SubmitResponse = check_status(response, "Avail API")
+            .await?
+            .json()
+            .await
+            .map_err(to_da_error)?;
+
+        let blob_id = format_blob_id(&response.block_hash, response.index);
+        tracing::debug!(
+            "Submitted blob for L1 batch #{batch_number} to Avail in block #{}, blob ID: {blob_id}",
+            response.block_number
+        );
+        Ok(DispatchResponse::from(blob_id))
+    }
+
+    async fn get_inclusion_data(&self, blob_id: &str) -> Result<Option<InclusionData>, DAError> {
+        let (block_hash, index) = parse_blob_id(blob_id).map_err(to_non_retriable_da_error)?;
+        let response = self
+            .client
+            .get(self.bridge_api_url(&format!("eth/proof/{block_hash}")))
+            .query(&[("index", index)])
+            .send()
+            .await
+            .map_err(to_da_error)?;
+        if response.status() == StatusCode::NOT_FOUND {
+            // The block is not bridged to L1 yet.
+            return Ok(None);
+        }
+
+        let proof: BridgeApiProof = check_status(response, "Avail API")
+            .await?
+            .json()
+            .await
+            .map_err(to_da_error)?;
+        let data = encode_proof(&proof)
+            .context("bridge API returned a malformed data proof")
+            .map_err(to_non_retriable_da_error)?;
+        Ok(Some(InclusionData { data }))
+    }
+
+    fn client_type(&self) -> &'static str {
+        "avail"
+    }
+
+    fn clone_boxed(&self) -> Box<dyn DataAvailabilityClient> {
+        Box::new(self.clone())
+    }
+
+    fn blob_size_limit(&self) -> Option<usize> {
+        self.config.max_blob_size_bytes
+    }
+}
+
+fn format_blob_id(block_hash: &str, index: u32) -> String {
+    format!("{block_hash}:{index}")
+}
+
+fn parse_blob_id(blob_id: &str) -> anyhow::Result<(&str, u32)> {
+    let (block_hash, index) = blob_id
+        .split_once(':')
+        .with_context(|| format!("malformed blob ID `{blob_id}`"))?;
+    let index = index
+        .parse()
+        .with_context(|| format!("malformed extrinsic index in blob ID `{blob_id}`"))?;
+    Ok((block_hash, index))
+}
+
+/// ABI-encodes the data proof as the `MerkleProofInput` struct verified by the Avail bridge contract.
+fn encode_proof(proof: &BridgeApiProof) -> anyhow::Result<Vec<u8>> {
+    let data_root_proof = proof
+        .data_root_proof
+        .iter()
+        .map(|hash| bytes32(hash))
+        .collect::<anyhow::Result<_>>()
+        .context("data_root_proof")?;
+    let leaf_proof = proof
+        .leaf_proof
+        .iter()
+        .map(|hash| bytes32(hash))
+        .collect::<anyhow::Result<_>>()
+        .context("leaf_proof")?;
+
+    Ok(ethabi::encode(&[Token::Tuple(vec![
+        Token::Array(data_root_proof),
+        Token::Array(leaf_proof),
+        bytes32(&proof.range_hash).context("range_hash")?,
+        Token::Uint(proof.data_root_index.into()),
+        bytes32(&proof.blob_root).context("blob_root")?,
+        bytes32(&proof.bridge_root).context("bridge_root")?,
+        bytes32(&proof.leaf).context("leaf")?,
+        Token::Uint(proof.leaf_index.into()),
+    ])]))
+}
+
+fn bytes32(hash: &str) -> anyhow::Result<Token> {
+    let bytes = hex::decode(hash.strip_prefix("0x").unwrap_or(hash))?;
+    anyhow::ensure!(bytes.len() == 32, "expected 32 bytes, got {}", bytes.len());
+    Ok(Token::FixedBytes(bytes))
+}
@@ -0,0 +1,72 @@
+use super::*;
+
+fn mock_client() -> AvailClient {
+    AvailClient::new(AvailConfig {
+        api_node_url: "http://localhost:7007/".to_owned(),
+        bridge_api_url: "http://localhost:8080".to_owned(),
+        app_id: 1,
+        request_timeout_ms: 1_000,
+        max_blob_size_bytes: Some(16),
+    })
+    .unwrap()
+}
+
+fn mock_proof() -> BridgeApiProof {
+    let hash = format!("0x{}", "01".repeat(32));
+    serde_json::from_value(serde_json::json!({
+        "dataRootProof": [hash, hash],
+        "leafProof": [hash],
+        "rangeHash": hash,
+        "dataRootIndex": 5,
+        "blobRoot": hash,
+        "bridgeRoot": hash,
+        "leaf": hash,
+        "leafIndex": 0,
+    }))
+    .unwrap()
+}
+
+#[test]
+fn building_urls() {
+    let client = mock_client();
+    assert_eq!(
+        client.light_client_url("v2/submit"),
+        "http://localhost:7007/v2/submit"
+    );
+    assert_eq!(
+        client.bridge_api_url("eth/proof/0x01"),
+        "http://localhost:8080/eth/proof/0x01"
+    );
+}
+
+#[test]
+fn checking_blob_size() {
+    let client = mock_client();
+    client.check_blob_size(&[0; 16]).unwrap();
+    let err = client.check_blob_size(&[0; 17]).unwrap_err();
+    assert!(!err.is_transient());
+}
+
+#[test]
+fn blob_id_roundtrip() {
+    let blob_id = format_blob_id("0xabcd", 3);
+    assert_eq!(blob_id, "0xabcd:3");
+    assert_eq!(parse_blob_id(&blob_id).unwrap(), ("0xabcd", 3));
+
+    parse_blob_id("0xabcd").unwrap_err();
+    parse_blob_id("0xabcd:x").unwrap_err();
+}
+
+#[test]
+fn encoding_proof() {
+    let encoded = encode_proof(&mock_proof()).unwrap();
+    assert_eq!(encoded.len() % 32, 0);
+
+    let mut proof = mock_proof();
+    proof.leaf = "0x01".to_owned();
+    encode_proof(&proof).unwrap_err();
+
+    let mut proof = mock_proof();
+    proof.leaf_proof.push("not hex".to_owned());
+    encode_proof(&proof).unwrap_err();
+}
@@ -0,0 +1,44 @@
+//! Types of the Avail light client and bridge APIs.
+
+use serde::{Deserialize, Serialize};
+use serde_with::{base64::Base64, serde_as};
+
+/// Subset of the light client status (`GET /v2/status`).
+#[derive(Debug, Deserialize)]
+pub(super) struct LightClientStatus {
+    /// App ID the light client is configured with; absent if the client doesn't run in the app mode.
+    pub app_id: Option<u32>,
+}
+
+/// Request to submit data (`POST /v2/submit`).
+#[serde_as]
+#[derive(Debug, Serialize)]
+pub(super) struct SubmitRequest {
+    #[serde_as(as = "Base64")]
+    pub data: Vec<u8>,
+}
+
+/// Response to [`SubmitRequest`].
+#[derive(Debug, Deserialize)]
+pub(super) struct SubmitResponse {
+    pub block_number: u32,
+    /// Hex-encoded hash of the Avail block the data submission extrinsic was included into.
+    pub block_hash: String,
+    /// Index of the extrinsic in the block.
+    pub index: u32,
+}
+
+/// Data proof returned by the bridge API (`GET /eth/proof/{block_hash}?index={index}`).
+/// All hashes are hex-encoded (`0x`-prefixed).
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct BridgeApiProof {
+    pub data_root_proof: Vec<String>,
+    pub leaf_proof: Vec<String>,
+    pub range_hash: String,
+    pub data_root_index: u64,
+    pub blob_root: String,
+    pub bridge_root: String,
+    pub leaf: String,
+    pub leaf_index: u64,
+}
@@ -0,0 +1,20 @@
+//! Metrics for the Celestia client.
+
+use std::time::Duration;
+
+use vise::{Buckets, Gauge, Histogram, Metrics, Unit};
+
+#[derive(Debug, Metrics)]
+#[metrics(prefix = "server_da_clients_celestia")]
+pub(super) struct CelestiaClientMetrics {
+    /// Latency of a blob submission, including waiting for the transaction to be included into a block.
+    #[metrics(buckets = Buckets::LATENCIES, unit = Unit::Seconds)]
+    pub submission_latency: Histogram<Duration>,
+    /// Celestia height the last submitted blob was included at.
+    pub last_submission_height: Gauge<u64>,
+    /// Celestia height of the last blob considered included (i.e., with enough confirmations).
+    pub last_included_height: Gauge<u64>,
+}
+
+#[vise::register]
+pub(super) static METRICS: vise::Global<CelestiaClientMetrics> = vise::Global::new();
@@ -0,0 +1,303 @@
+//! Client for Celestia.
+//!
+//! The client communicates with a Celestia light or bridge node using its JSON-RPC API:
+//!
+//! - `blob.Submit` submits a blob and returns the height of the block it was included at.
+//!   The share commitment of the submitted blob is then looked up via `blob.GetAll`.
+//! - `header.LocalHead` is used to track the number of confirmations of the inclusion block.
+//! - `blob.Get` checks that the blob is retrievable from the network before it's reported as included.
+//!   It's also used to retrieve blobs by the inclusion data.
+//!
+//! The blob ID has the `{height}-{commitment}` format. The ABI-encoded `(height, namespace, commitment)`
+//! triple is used as the inclusion data verified by the L1 contracts.
+
+use std::fmt;
+
+use anyhow::Context as _;
+use async_trait::async_trait;
+use ethabi::{ParamType, Token};
+use serde::{de::DeserializeOwned, Serialize};
+use zksync_config::configs::da_client::celestia::CelestiaConfig;
+use zksync_da_client::{
+    types::{DAError, DispatchResponse, InclusionData},
+    DataAvailabilityClient,
+};
+
+use self::{
+    metrics::METRICS,
+    types::{Base64Bytes, Blob, ExtendedHeader, JsonRpcRequest, JsonRpcResponse},
+};
+use crate::utils::{check_status, to_da_error, to_non_retriable_da_error};
+
+mod metrics;
+#[cfg(test)]
+mod tests;
+mod types;
+
+/// Size of a Celestia namespace (1 version byte + 28 bytes of the ID).
+const NAMESPACE_SIZE: usize = 29;
+/// Maximum size of the user-specified part of a version 0 namespace ID.
+const NAMESPACE_V0_ID_SIZE: usize = 10;
+/// Error message returned by the node if the requested blob doesn't exist.
+const BLOB_NOT_FOUND_MESSAGE: &str = "blob: not found";
+/// Passing a negative gas price makes the node estimate the price itself.
+const DEFAULT_GAS_PRICE: f64 = -1.0;
+
+/// Celestia client.
+#[derive(Clone)]
+pub struct CelestiaClient {
+    config: CelestiaConfig,
+    namespace: Vec<u8>,
+    client: reqwest::Client,
+}
+
+impl fmt::Debug for CelestiaClient {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // `config` is not printed because it contains the auth token.
+        formatter
+            .debug_struct("CelestiaClient")
+            .field("api_node_url", &self.config.api_node_url)
+            .field("namespace", &hex::encode(&self.namespace))
+            .finish_non_exhaustive()
+    }
+}
+
+impl CelestiaClient {
+    pub fn new(config: CelestiaConfig) -> anyhow::Result<Self> {
+        let namespace = parse_namespace(&config.namespace).context("invalid namespace")?;
+        let client = reqwest::Client::builder()
+            .timeout(config.request_timeout())
+            .build()
+            .context("failed building HTTP client")?;
+        Ok(Self {
+            config,
+            namespace,
+            client,
+        })
+    }
+
+    fn check_blob_size(&self, data: &[u8]) -> Result<(), DAError> {
+        if let Some(limit) = self.config.max_blob_size_bytes {
+            if data.len() > limit {
+                return Err(to_non_retriable_da_error(anyhow::anyhow!(
+                    "blob size ({} bytes) exceeds the limit ({limit} bytes)",
+                    data.len()
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    async fn call<P: Serialize, R: DeserializeOwned>(
+        &self,
+        method: &str,
+        params: P,
+    ) -> Result<R, DAError> {
+        let mut request = self
+            .client
+            .post(&self.config.api_node_url)
+            .json(&JsonRpcRequest::new(method, params));
+        if let Some(token) = &self.config.auth_token {
+            request = request.bearer_auth(token);
+        }
+        let response = request.send().await.map_err(to_da_error)?;
+        let response: JsonRpcResponse<R> = check_status(response, "Celestia node")
+            .await?
+            .json()
+            .await
+            .map_err(to_da_error)?;
+
+        if let Some(err) = response.error {
+            return Err(DAError {
+                error: anyhow::anyhow!(
+                    "`{method}` call failed with code {}: {}",
+                    err.code,
+                    err.message
+                ),
+                is_transient: is_transient_rpc_error(err.code),
+            });
+        }
+        response
+            .result
+            .with_context(|| format!("`{method}` response has no result"))
+            .map_err(to_non_retriable_da_error)
+    }
+
+    /// Checks whether the inclusion block has enough confirmations on top of it.
+    fn is_confirmed(&self, inclusion_height: u64, head_height: u64) -> bool {
+        head_height >= inclusion_height.saturating_add(self.config.inclusion_confirmations)
+    }
+}
+
+#[async_trait]
+impl DataAvailabilityClient for CelestiaClient {
+    async fn dispatch_blob(
+        &self,
+        batch_number: u32,
+        data: Vec<u8>,
+    ) -> Result<DispatchResponse, DAError> {
+        self.check_blob_size(&data)?;
+
+        let blob = Blob {
+            namespace: self.namespace.clone(),
+            data,
+            share_version: 0,
+            commitment: vec![],
+        };
+        let gas_price = self.config.gas_price.unwrap_or(DEFAULT_GAS_PRICE);
+        let latency = METRICS.submission_latency.start();
+        let height: u64 = self
+            .call("blob.Submit", (std::slice::from_ref(&blob), gas_price))
+            .await?;
+        latency.observe();
+        METRICS.last_submission_height.set(height);
+
+        // The node doesn't return the share commitment of the submitted blob, so we look it up.
+        let namespace = Base64Bytes(self.namespace.clone());
+        let included_blobs: Option<Vec<Blob>> =
+            self.call("blob.GetAll", (height, [namespace])).await?;
+        let commitment = included_blobs
+            .unwrap_or_default()
+            .into_iter()
+            .find(|included| included.data == blob.data)
+            .map(|included| included.commitment)
+            .with_context(|| format!("submitted blob is not found at height {height}"))
+            .map_err(to_non_retriable_da_error)?;
+
+        let blob_id = format_blob_id(height, &commitment);
+        tracing::debug!(
+            "Submitted blob for L1 batch #{batch_number} to Celestia at height {height}, blob ID: {blob_id}"
+        );
+        Ok(DispatchResponse::from(blob_id))
+    }
+
+    async fn get_inclusion_data(&self, blob_id: &str) -> Result<Option<InclusionData>, DAError> {
+        let (height, commitment) = parse_blob_id(blob_id).map_err(to_non_retriable_da_error)?;
+
+        let head: ExtendedHeader = self.call("header.LocalHead", [(); 0]).await?;
+        if !self.is_confirmed(height, head.header.height) {
+            return Ok(None);
+        }
+
+        let namespace = Base64Bytes(self.namespace.clone());
+        let blob: Blob = self
+            .call(
+                "blob.Get",
+                (height, namespace, Base64Bytes(commitment.clone())),
+            )
+            .await?;
+        if blob.commitment != commitment {
+            return Err(to_non_retriable_da_error(anyhow::anyhow!(
+                "node returned a blob with unexpected commitment 0x{}",
+                hex::encode(&blob.commitment)
+            )));
+        }
+        METRICS.last_included_height.set(height);
+
+        let data = encode_inclusion_data(height, &self.namespace, &commitment);
+        Ok(Some(InclusionData { data }))
+    }
+
+    async fn get_blob(&self, inclusion_data: &[u8]) -> Result<Option<Vec<u8>>, DAError> {
+        let (height, namespace, commitment) =
+            decode_inclusion_data(inclusion_data).map_err(to_non_retriable_da_error)?;
+        let result: Result<Blob, _> = self
+            .call(
+                "blob.Get",
+                (
+                    height,
+                    Base64Bytes(namespace),
+                    Base64Bytes(commitment.clone()),
+                ),
+            )
+            .await;
+        let blob = match result {
+            Ok(blob) => blob,
+            Err(err) if err.error.to_string().contains(BLOB_NOT_FOUND_MESSAGE) => return Ok(None),
+            Err(err) => return Err(err),
+        };
+
+        if blob.commitment != commitment {
+            return Err(to_non_retriable_da_error(anyhow::anyhow!(
+                "node returned a blob with unexpected commitment 0x{}",
+                hex::encode(&blob.commitment)
+            )));
+        }
+        Ok(Some(blob.data))
+    }
+
+    fn client_type(&self) -> &'static str {
+        "celestia"
+    }
+
+    fn clone_boxed(&self) -> Box<dyn DataAvailabilityClient> {
+        Box::new(self.clone())
+    }
+
+    fn blob_size_limit(&self) -> Option<usize> {
+        self.config.max_blob_size_bytes
+    }
+}
+
+/// Parses a hex-encoded version 0 namespace ID into a full namespace.
+fn parse_namespace(namespace_id: &str) -> anyhow::Result<Vec<u8>> {
+    let namespace_id = namespace_id.strip_prefix("0x").unwrap_or(namespace_id);
+    let namespace_id =
+        hex::decode(namespace_id).context("namespace ID is not a valid hex string")?;
+    anyhow::ensure!(
+        !namespace_id.is_empty() && namespace_id.len() <= NAMESPACE_V0_ID_SIZE,
+        "namespace ID must have 1 to {NAMESPACE_V0_ID_SIZE} bytes, got {}",
+        namespace_id.len()
+    );
+
+    // The version byte and the leading bytes of the version 0 ID are zeros.
+    let mut namespace = vec![0; NAMESPACE_SIZE];
+    namespace[NAMESPACE_SIZE - namespace_id.len()..].copy_from_slice(&namespace_id);
+    Ok(namespace)
+}
+
+fn format_blob_id(height: u64, commitment: &[u8]) -> String {
+    format!("{height}-{}", hex::encode(commitment))
+}
+
+fn parse_blob_id(blob_id: &str) -> anyhow::Result<(u64, Vec<u8>)> {
+    let (height, commitment) = blob_id
+        .split_once('-')
+        .with_context(|| format!("malformed blob ID `{blob_id}`"))?;
+    let height = height
+        .parse()
+        .with_context(|| format!("malformed height in blob ID `{blob_id}`"))?;
+    let commitment = hex::decode(commitment)
+        .with_context(|| format!("malformed commitment in blob ID `{blob_id}`"))?;
+    Ok((height, commitment))
+}
+
+fn encode_inclusion_data(height: u64, namespace: &[u8], commitment: &[u8]) -> Vec<u8> {
+    ethabi::encode(&[
+        Token::Uint(height.into()),
+        Token::Bytes(namespace.to_vec()),
+        Token::Bytes(commitment.to_vec()),
+    ])
+}
+
+fn decode_inclusion_data(inclusion_data: &[u8]) -> anyhow::Result<(u64, Vec<u8>, Vec<u8>)> {
+    let tokens = ethabi::decode(
+        &[ParamType::Uint(64), ParamType::Bytes, ParamType::Bytes],
+        inclusion_data,
+    )
+    .context("malformed inclusion data")?;
+    match tokens.as_slice() {
+        [Token::Uint(height), Token::Bytes(namespace), Token::Bytes(commitment)] => {
+            anyhow::ensure!(*height <= u64::MAX.into(), "height overflow");
+            Ok((height.as_u64(), namespace.clone(), commitment.clone()))
+        }
+        _ => anyhow::bail!("unexpected inclusion data tokens: {tokens:?}"),
+    }
+}
+
+/// Errors with the "invalid request", "method not found" and "invalid params" codes are caused
+/// by the client itself; other errors (e.g., a transaction not included because of a sequence mismatch)
+/// are worth retrying.
+fn is_transient_rpc_error(code: i64) -> bool {
+    !matches!(code, -32600 | -32601 | -32602)
+}
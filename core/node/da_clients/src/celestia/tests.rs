@@ -0,0 +1,124 @@
+use assert_matches::assert_matches;
+
+use super::*;
+
+fn mock_config(inclusion_confirmations: u64) -> CelestiaConfig {
+    CelestiaConfig {
+        api_node_url: "http://localhost:26658".to_owned(),
+        auth_token: Some("secret".to_owned()),
+        namespace: "0x0102".to_owned(),
+        gas_price: None,
+        inclusion_confirmations,
+        request_timeout_ms: 1_000,
+        max_blob_size_bytes: Some(16),
+    }
+}
+
+#[test]
+fn parsing_namespace() {
+    let namespace = parse_namespace("0x0102").unwrap();
+    assert_eq!(namespace.len(), NAMESPACE_SIZE);
+    assert!(namespace[..NAMESPACE_SIZE - 2]
+        .iter()
+        .all(|&byte| byte == 0));
+    assert_eq!(namespace[NAMESPACE_SIZE - 2..], [1, 2]);
+
+    assert_eq!(parse_namespace("0102").unwrap(), namespace);
+    parse_namespace("").unwrap_err();
+    parse_namespace("0x0102030405060708090a0b").unwrap_err();
+    parse_namespace("not hex").unwrap_err();
+}
+
+#[test]
+fn blob_id_roundtrip() {
+    let blob_id = format_blob_id(123, &[0xab; 32]);
+    assert_eq!(blob_id, format!("123-{}", "ab".repeat(32)));
+    assert_eq!(parse_blob_id(&blob_id).unwrap(), (123, vec![0xab; 32]));
+
+    parse_blob_id("123").unwrap_err();
+    parse_blob_id("abc-0102").unwrap_err();
+    parse_blob_id("123-xyz").unwrap_err();
+}
+
+#[test]
+fn inclusion_data_roundtrip() {
+    let namespace = parse_namespace("0x0102").unwrap();
+    let data = encode_inclusion_data(123, &namespace, &[0xab; 32]);
+    let (height, decoded_namespace, commitment) = decode_inclusion_data(&data).unwrap();
+    assert_eq!(height, 123);
+    assert_eq!(decoded_namespace, namespace);
+    assert_eq!(commitment, [0xab; 32]);
+
+    decode_inclusion_data(&data[..32]).unwrap_err();
+}
+
+#[test]
+fn serializing_blob() {
+    let blob = Blob {
+        namespace: vec![0, 1],
+        data: b"test".to_vec(),
+        share_version: 0,
+        commitment: vec![],
+    };
+    let json = serde_json::to_value(&blob).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "namespace": "AAE=",
+            "data": "dGVzdA==",
+            "share_version": 0,
+            "commitment": "",
+        })
+    );
+    let restored: Blob = serde_json::from_value(json).unwrap();
+    assert_eq!(restored, blob);
+}
+
+#[test]
+fn deserializing_header() {
+    let header: ExtendedHeader = serde_json::from_value(serde_json::json!({
+        "header": { "height": "1000", "chain_id": "mocha-4" },
+        "commit": {},
+    }))
+    .unwrap();
+    assert_eq!(header.header.height, 1_000);
+}
+
+#[test]
+fn checking_confirmations() {
+    let client = CelestiaClient::new(mock_config(0)).unwrap();
+    assert!(client.is_confirmed(100, 100));
+    assert!(!client.is_confirmed(100, 99));
+
+    let client = CelestiaClient::new(mock_config(3)).unwrap();
+    assert!(!client.is_confirmed(100, 102));
+    assert!(client.is_confirmed(100, 103));
+}
+
+#[test]
+fn checking_blob_size() {
+    let client = CelestiaClient::new(mock_config(0)).unwrap();
+    client.check_blob_size(&[0; 16]).unwrap();
+    let err = client.check_blob_size(&[0; 17]).unwrap_err();
+    assert_matches!(
+        err,
+        DAError {
+            is_transient: false,
+            ..
+        }
+    );
+}
+
+#[test]
+fn classifying_rpc_errors() {
+    assert!(!is_transient_rpc_error(-32601));
+    assert!(!is_transient_rpc_error(-32602));
+    assert!(is_transient_rpc_error(1));
+}
+
+#[test]
+fn debug_output_hides_auth_token() {
+    let client = CelestiaClient::new(mock_config(0)).unwrap();
+    let debug = format!("{client:?}");
+    assert!(!debug.contains("secret"), "{debug}");
+}
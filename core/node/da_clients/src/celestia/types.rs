@@ -0,0 +1,73 @@
+//! Types of the celestia-node JSON-RPC API.
+
+use serde::{Deserialize, Serialize};
+use serde_with::{base64::Base64, serde_as, DisplayFromStr};
+
+/// JSON-RPC request envelope.
+#[derive(Debug, Serialize)]
+pub(super) struct JsonRpcRequest<'a, P> {
+    pub jsonrpc: &'static str,
+    pub id: u64,
+    pub method: &'a str,
+    pub params: P,
+}
+
+impl<'a, P: Serialize> JsonRpcRequest<'a, P> {
+    pub fn new(method: &'a str, params: P) -> Self {
+        Self {
+            jsonrpc: "2.0",
+            id: 1,
+            method,
+            params,
+        }
+    }
+}
+
+/// JSON-RPC response envelope.
+#[derive(Debug, Deserialize)]
+pub(super) struct JsonRpcResponse<T> {
+    pub result: Option<T>,
+    pub error: Option<JsonRpcError>,
+}
+
+/// JSON-RPC error object.
+#[derive(Debug, Deserialize)]
+pub(super) struct JsonRpcError {
+    pub code: i64,
+    pub message: String,
+}
+
+/// Blob as represented by the `blob` module of the node API.
+#[serde_as]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(super) struct Blob {
+    /// Full 29-byte namespace (version byte followed by the namespace ID).
+    #[serde_as(as = "Base64")]
+    pub namespace: Vec<u8>,
+    #[serde_as(as = "Base64")]
+    pub data: Vec<u8>,
+    pub share_version: u32,
+    /// Share commitment of the blob. Computed by the node on submission, so it's empty for submitted blobs.
+    #[serde_as(as = "Base64")]
+    #[serde(default)]
+    pub commitment: Vec<u8>,
+}
+
+/// Subset of the extended header returned by the `header` module of the node API.
+#[derive(Debug, Deserialize)]
+pub(super) struct ExtendedHeader {
+    pub header: RawHeader,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize)]
+pub(super) struct RawHeader {
+    /// Block height; encoded as a decimal string.
+    #[serde_as(as = "DisplayFromStr")]
+    pub height: u64,
+}
+
+/// Binary parameter (namespace or commitment) of the node API methods; encoded as base64.
+#[serde_as]
+#[derive(Debug, Clone, Serialize)]
+pub(super) struct Base64Bytes(#[serde_as(as = "Base64")] pub Vec<u8>);
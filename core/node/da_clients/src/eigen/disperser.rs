@@ -0,0 +1,221 @@
+//! Minimal gRPC client for the EigenDA disperser (`disperser.Disperser` service, API v1).
+//!
+//! Messages are declared by hand instead of being generated from `disperser.proto`; only the parts
+//! of the API used by [`EigenDAClient`](super::EigenDAClient) are covered. Field tags must match
+//! the upstream proto definitions.
+
+use tonic::{
+    codec::ProstCodec,
+    codegen::http::uri::PathAndQuery,
+    transport::{Channel, ClientTlsConfig, Endpoint},
+};
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub(super) struct DisperseBlobRequest {
+    #[prost(bytes = "vec", tag = "1")]
+    pub data: Vec<u8>,
+    #[prost(uint32, repeated, tag = "2")]
+    pub custom_quorum_numbers: Vec<u32>,
+    #[prost(string, tag = "3")]
+    pub account_id: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub(super) struct DisperseBlobReply {
+    #[prost(enumeration = "BlobStatus", tag = "1")]
+    pub result: i32,
+    #[prost(bytes = "vec", tag = "2")]
+    pub request_id: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub(super) struct BlobStatusRequest {
+    #[prost(bytes = "vec", tag = "1")]
+    pub request_id: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub(super) struct BlobStatusReply {
+    #[prost(enumeration = "BlobStatus", tag = "1")]
+    pub status: i32,
+    #[prost(message, optional, tag = "2")]
+    pub info: Option<BlobInfo>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub(super) struct RetrieveBlobRequest {
+    #[prost(bytes = "vec", tag = "1")]
+    pub batch_header_hash: Vec<u8>,
+    #[prost(uint32, tag = "2")]
+    pub blob_index: u32,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub(super) struct RetrieveBlobReply {
+    #[prost(bytes = "vec", tag = "1")]
+    pub data: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub(super) struct BlobInfo {
+    #[prost(message, optional, tag = "1")]
+    pub blob_header: Option<BlobHeader>,
+    #[prost(message, optional, tag = "2")]
+    pub blob_verification_proof: Option<BlobVerificationProof>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub(super) struct G1Commitment {
+    #[prost(bytes = "vec", tag = "1")]
+    pub x: Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub y: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub(super) struct BlobHeader {
+    #[prost(message, optional, tag = "1")]
+    pub commitment: Option<G1Commitment>,
+    #[prost(uint32, tag = "2")]
+    pub data_length: u32,
+    #[prost(message, repeated, tag = "3")]
+    pub blob_quorum_params: Vec<BlobQuorumParam>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub(super) struct BlobQuorumParam {
+    #[prost(uint32, tag = "1")]
+    pub quorum_number: u32,
+    #[prost(uint32, tag = "2")]
+    pub adversary_threshold_percentage: u32,
+    #[prost(uint32, tag = "3")]
+    pub confirmation_threshold_percentage: u32,
+    #[prost(uint32, tag = "4")]
+    pub chunk_length: u32,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub(super) struct BlobVerificationProof {
+    #[prost(uint32, tag = "1")]
+    pub batch_id: u32,
+    #[prost(uint32, tag = "2")]
+    pub blob_index: u32,
+    #[prost(message, optional, tag = "3")]
+    pub batch_metadata: Option<BatchMetadata>,
+    #[prost(bytes = "vec", tag = "4")]
+    pub inclusion_proof: Vec<u8>,
+    #[prost(bytes = "vec", tag = "5")]
+    pub quorum_indexes: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub(super) struct BatchMetadata {
+    #[prost(message, optional, tag = "1")]
+    pub batch_header: Option<BatchHeader>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub signatory_record_hash: Vec<u8>,
+    #[prost(bytes = "vec", tag = "3")]
+    pub fee: Vec<u8>,
+    #[prost(uint32, tag = "4")]
+    pub confirmation_block_number: u32,
+    #[prost(bytes = "vec", tag = "5")]
+    pub batch_header_hash: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub(super) struct BatchHeader {
+    #[prost(bytes = "vec", tag = "1")]
+    pub batch_root: Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub quorum_numbers: Vec<u8>,
+    #[prost(bytes = "vec", tag = "3")]
+    pub quorum_signed_percentages: Vec<u8>,
+    #[prost(uint32, tag = "4")]
+    pub reference_block_number: u32,
+}
+
+/// Status of a dispersed blob as reported by the disperser.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, prost::Enumeration)]
+#[repr(i32)]
+pub(super) enum BlobStatus {
+    Unknown = 0,
+    /// The blob is being processed by the disperser.
+    Processing = 1,
+    /// The blob batch is confirmed on L1, but the confirmation block may still be reorged.
+    Confirmed = 2,
+    /// The blob dispersal has failed.
+    Failed = 3,
+    /// The confirmation block of the blob batch is finalized on L1.
+    Finalized = 4,
+    /// The blob batch didn't collect enough signatures from the DA nodes.
+    InsufficientSignatures = 5,
+    /// The blob is being dispersed to the DA nodes.
+    Dispersing = 6,
+}
+
+/// Client for the `disperser.Disperser` gRPC service.
+#[derive(Debug, Clone)]
+pub(super) struct DisperserClient {
+    inner: tonic::client::Grpc<Channel>,
+}
+
+impl DisperserClient {
+    /// Creates a client for the specified disperser URL. The connection is established lazily.
+    pub fn new(url: &str, request_timeout: std::time::Duration) -> anyhow::Result<Self> {
+        let mut endpoint = Endpoint::from_shared(url.to_owned())?.timeout(request_timeout);
+        if url.starts_with("https://") {
+            endpoint = endpoint.tls_config(ClientTlsConfig::new())?;
+        }
+        Ok(Self {
+            inner: tonic::client::Grpc::new(endpoint.connect_lazy()),
+        })
+    }
+
+    pub async fn disperse_blob(
+        &mut self,
+        request: DisperseBlobRequest,
+    ) -> Result<DisperseBlobReply, tonic::Status> {
+        self.unary("/disperser.Disperser/DisperseBlob", request)
+            .await
+    }
+
+    pub async fn get_blob_status(
+        &mut self,
+        request: BlobStatusRequest,
+    ) -> Result<BlobStatusReply, tonic::Status> {
+        self.unary("/disperser.Disperser/GetBlobStatus", request)
+            .await
+    }
+
+    pub async fn retrieve_blob(
+        &mut self,
+        request: RetrieveBlobRequest,
+    ) -> Result<RetrieveBlobReply, tonic::Status> {
+        self.unary("/disperser.Disperser/RetrieveBlob", request)
+            .await
+    }
+
+    async fn unary<Req, Resp>(
+        &mut self,
+        path: &'static str,
+        request: Req,
+    ) -> Result<Resp, tonic::Status>
+    where
+        Req: prost::Message + 'static,
+        Resp: prost::Message + Default + 'static,
+    {
+        self.inner.ready().await.map_err(|err| {
+            tonic::Status::unavailable(format!("disperser service is not ready: {err}"))
+        })?;
+        let codec = ProstCodec::<Req, Resp>::default();
+        let response = self
+            .inner
+            .unary(
+                tonic::Request::new(request),
+                PathAndQuery::from_static(path),
+                codec,
+            )
+            .await?;
+        Ok(response.into_inner())
+    }
+}
@@ -0,0 +1,40 @@
+//! Metrics for the EigenDA client.
+
+use std::time::Duration;
+
+use vise::{Buckets, Counter, EncodeLabelSet, EncodeLabelValue, Family, Histogram, Metrics, Unit};
+
+/// Kind of the EigenDA client failure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EncodeLabelValue, EncodeLabelSet)]
+#[metrics(label = "kind", rename_all = "snake_case")]
+pub(super) enum FailureKind {
+    /// Transient gRPC failure (e.g., the disperser is unavailable or rate-limits the client).
+    Transient,
+    /// The disperser has rejected the request.
+    Rejected,
+    /// The blob dispersal has failed on the disperser side.
+    DispersalFailed,
+    /// The blob batch didn't collect enough signatures from the DA nodes.
+    InsufficientSignatures,
+    /// The disperser has returned a malformed response.
+    InvalidResponse,
+}
+
+#[derive(Debug, Metrics)]
+#[metrics(prefix = "server_da_clients_eigen")]
+pub(super) struct EigenDAClientMetrics {
+    /// Latency of a `DisperseBlob` call.
+    #[metrics(buckets = Buckets::LATENCIES, unit = Unit::Seconds)]
+    pub dispersal_latency: Histogram<Duration>,
+    /// Latency of a `GetBlobStatus` call.
+    #[metrics(buckets = Buckets::LATENCIES, unit = Unit::Seconds)]
+    pub blob_status_latency: Histogram<Duration>,
+    /// Size of the dispersed blob after encoding.
+    #[metrics(buckets = Buckets::exponential(1_024.0..=16.0 * 1_024.0 * 1_024.0, 2.0), unit = Unit::Bytes)]
+    pub encoded_blob_size: Histogram<usize>,
+    /// Number of failures of the client calls, grouped by the failure kind.
+    pub failures: Family<FailureKind, Counter>,
+}
+
+#[vise::register]
+pub(super) static METRICS: vise::Global<EigenDAClientMetrics> = vise::Global::new();
@@ -0,0 +1,389 @@
+//! Client for EigenDA.
+//!
+//! The client communicates with the EigenDA disperser over gRPC:
+//!
+//! - `DisperseBlob` disperses a blob and returns the dispersal request ID, which is used as the blob ID.
+//! - `GetBlobStatus` returns the blob status and, once the blob batch is confirmed on L1, the blob info
+//!   (the blob header and the proof of its inclusion into the confirmed batch).
+//!
+//! The ABI-encoded blob info is used as the inclusion data verified by the L1 contracts.
+//! `RetrieveBlob` is used to retrieve blobs by the inclusion data; the blob is identified by the hash
+//! of the batch header included into the blob info and the blob index in the batch.
+
+use std::fmt;
+
+use anyhow::Context as _;
+use async_trait::async_trait;
+use ethabi::{ethereum_types::U256, ParamType, Token};
+use zksync_basic_types::web3::keccak256;
+use zksync_config::configs::da_client::eigen::EigenDAConfig;
+use zksync_da_client::{
+    types::{DAError, DispatchResponse, InclusionData},
+    DataAvailabilityClient,
+};
+
+use self::{
+    disperser::{
+        BlobHeader, BlobInfo, BlobStatus, BlobStatusReply, BlobStatusRequest,
+        BlobVerificationProof, DisperseBlobRequest, DisperserClient, RetrieveBlobRequest,
+    },
+    metrics::{FailureKind, METRICS},
+};
+
+mod disperser;
+mod metrics;
+#[cfg(test)]
+mod tests;
+
+/// Number of payload bytes packed into a single 32-byte field element of an EigenDA blob.
+const BYTES_PER_FIELD_ELEMENT: usize = 31;
+
+/// EigenDA client.
+#[derive(Clone)]
+pub struct EigenDAClient {
+    config: EigenDAConfig,
+    disperser: DisperserClient,
+}
+
+impl fmt::Debug for EigenDAClient {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("EigenDAClient")
+            .field("config", &self.config)
+            .finish_non_exhaustive()
+    }
+}
+
+impl EigenDAClient {
+    pub fn new(config: EigenDAConfig) -> anyhow::Result<Self> {
+        let disperser = DisperserClient::new(&config.disperser_rpc, config.request_timeout())
+            .context("failed creating EigenDA disperser client")?;
+        Ok(Self { config, disperser })
+    }
+
+    fn check_blob_size(&self, data: &[u8]) -> Result<(), DAError> {
+        if let Some(limit) = self.config.max_blob_size_bytes {
+            if data.len() > limit {
+                return Err(to_non_retriable_da_error(anyhow::anyhow!(
+                    "blob size ({} bytes) exceeds the limit ({limit} bytes)",
+                    data.len()
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Converts the blob status into the inclusion data. Returns `Ok(None)` if the blob isn't included yet.
+    fn inclusion_data_from_status(
+        &self,
+        reply: BlobStatusReply,
+    ) -> Result<Option<InclusionData>, DAError> {
+        let status = BlobStatus::try_from(reply.status).unwrap_or(BlobStatus::Unknown);
+        let is_included = match status {
+            BlobStatus::Processing | BlobStatus::Dispersing => false,
+            BlobStatus::Confirmed => !self.config.wait_for_finalization,
+            BlobStatus::Finalized => true,
+            BlobStatus::Failed => {
+                METRICS.failures[&FailureKind::DispersalFailed].inc();
+                return Err(to_non_retriable_da_error(anyhow::anyhow!(
+                    "blob dispersal failed"
+                )));
+            }
+            BlobStatus::InsufficientSignatures => {
+                METRICS.failures[&FailureKind::InsufficientSignatures].inc();
+                return Err(to_non_retriable_da_error(anyhow::anyhow!(
+                    "blob batch didn't collect enough signatures"
+                )));
+            }
+            BlobStatus::Unknown => {
+                METRICS.failures[&FailureKind::InvalidResponse].inc();
+                return Err(to_non_retriable_da_error(anyhow::anyhow!(
+                    "disperser returned unknown blob status {}",
+                    reply.status
+                )));
+            }
+        };
+        if !is_included {
+            return Ok(None);
+        }
+
+        let blob_info = reply
+            .info
+            .context("blob info is missing for an included blob")
+            .and_then(|info| encode_blob_info(&info))
+            .map_err(|err| {
+                METRICS.failures[&FailureKind::InvalidResponse].inc();
+                to_non_retriable_da_error(err)
+            })?;
+        Ok(Some(InclusionData { data: blob_info }))
+    }
+}
+
+#[async_trait]
+impl DataAvailabilityClient for EigenDAClient {
+    async fn dispatch_blob(
+        &self,
+        batch_number: u32,
+        data: Vec<u8>,
+    ) -> Result<DispatchResponse, DAError> {
+        self.check_blob_size(&data)?;
+
+        let data = encode_blob(&data);
+        METRICS.encoded_blob_size.observe(data.len());
+        let request = DisperseBlobRequest {
+            data,
+            custom_quorum_numbers: self.config.custom_quorum_numbers.clone(),
+            account_id: self.config.account_id.clone().unwrap_or_default(),
+        };
+
+        let latency = METRICS.dispersal_latency.start();
+        let reply = self
+            .disperser
+            .clone()
+            .disperse_blob(request)
+            .await
+            .map_err(to_da_error)?;
+        latency.observe();
+
+        let status = BlobStatus::try_from(reply.result).unwrap_or(BlobStatus::Unknown);
+        if matches!(
+            status,
+            BlobStatus::Failed | BlobStatus::InsufficientSignatures | BlobStatus::Unknown
+        ) {
+            METRICS.failures[&FailureKind::DispersalFailed].inc();
+            return Err(to_non_retriable_da_error(anyhow::anyhow!(
+                "disperser rejected the blob for L1 batch #{batch_number} with status {status:?}"
+            )));
+        }
+
+        let request_id = hex::encode(&reply.request_id);
+        tracing::debug!(
+            "Dispersed blob for L1 batch #{batch_number} to EigenDA, request ID: {request_id}"
+        );
+        Ok(DispatchResponse::from(request_id))
+    }
+
+    async fn get_inclusion_data(&self, blob_id: &str) -> Result<Option<InclusionData>, DAError> {
+        let request_id = hex::decode(blob_id)
+            .context("blob ID is not a valid hex string")
+            .map_err(to_non_retriable_da_error)?;
+
+        let latency = METRICS.blob_status_latency.start();
+        let reply = self
+            .disperser
+            .clone()
+            .get_blob_status(BlobStatusRequest { request_id })
+            .await
+            .map_err(to_da_error)?;
+        latency.observe();
+
+        self.inclusion_data_from_status(reply)
+    }
+
+    async fn get_blob(&self, inclusion_data: &[u8]) -> Result<Option<Vec<u8>>, DAError> {
+        let request = retrieve_blob_request(inclusion_data).map_err(to_non_retriable_da_error)?;
+        let reply = match self.disperser.clone().retrieve_blob(request).await {
+            Ok(reply) => reply,
+            Err(status) if status.code() == tonic::Code::NotFound => return Ok(None),
+            Err(status) => return Err(to_da_error(status)),
+        };
+        Ok(Some(decode_blob(&reply.data)))
+    }
+
+    fn client_type(&self) -> &'static str {
+        "eigen_da"
+    }
+
+    fn clone_boxed(&self) -> Box<dyn DataAvailabilityClient> {
+        Box::new(self.clone())
+    }
+
+    fn blob_size_limit(&self) -> Option<usize> {
+        self.config.max_blob_size_bytes
+    }
+}
+
+/// Encodes the blob so that every 32-byte chunk is a valid BN254 field element, by prepending
+/// a zero byte to each 31-byte chunk of the data.
+fn encode_blob(data: &[u8]) -> Vec<u8> {
+    let chunk_count = data.len().div_ceil(BYTES_PER_FIELD_ELEMENT);
+    let mut encoded = Vec::with_capacity(chunk_count * (BYTES_PER_FIELD_ELEMENT + 1));
+    for chunk in data.chunks(BYTES_PER_FIELD_ELEMENT) {
+        encoded.push(0);
+        encoded.extend_from_slice(chunk);
+    }
+    encoded
+}
+
+/// Reverses [`encode_blob()`]. Blobs retrieved from EigenDA may be padded with zero bytes.
+fn decode_blob(encoded: &[u8]) -> Vec<u8> {
+    encoded
+        .chunks(BYTES_PER_FIELD_ELEMENT + 1)
+        .flat_map(|chunk| chunk.iter().skip(1).copied())
+        .collect()
+}
+
+/// Builds a request to retrieve the blob referenced by the ABI-encoded blob info (see [`encode_blob_info()`]).
+fn retrieve_blob_request(inclusion_data: &[u8]) -> anyhow::Result<RetrieveBlobRequest> {
+    let header_type = ParamType::Tuple(vec![
+        ParamType::Tuple(vec![ParamType::Uint(256), ParamType::Uint(256)]),
+        ParamType::Uint(32),
+        ParamType::Array(Box::new(ParamType::Tuple(vec![
+            ParamType::Uint(8),
+            ParamType::Uint(8),
+            ParamType::Uint(8),
+            ParamType::Uint(32),
+        ]))),
+    ]);
+    let batch_header_type = ParamType::Tuple(vec![
+        ParamType::FixedBytes(32),
+        ParamType::Bytes,
+        ParamType::Bytes,
+        ParamType::Uint(32),
+    ]);
+    let proof_type = ParamType::Tuple(vec![
+        ParamType::Uint(32),
+        ParamType::Uint(32),
+        ParamType::Tuple(vec![
+            batch_header_type,
+            ParamType::FixedBytes(32),
+            ParamType::Uint(32),
+        ]),
+        ParamType::Bytes,
+        ParamType::Bytes,
+    ]);
+
+    let tokens = ethabi::decode(&[header_type, proof_type], inclusion_data)
+        .context("malformed inclusion data")?;
+    let [_, Token::Tuple(proof)] = tokens.as_slice() else {
+        anyhow::bail!("unexpected inclusion data tokens: {tokens:?}");
+    };
+    let [_, Token::Uint(blob_index), Token::Tuple(batch_metadata), ..] = proof.as_slice() else {
+        anyhow::bail!("unexpected blob verification proof tokens: {proof:?}");
+    };
+    let batch_header = batch_metadata.first().context("batch metadata is empty")?;
+    anyhow::ensure!(*blob_index <= u32::MAX.into(), "blob index overflow");
+
+    Ok(RetrieveBlobRequest {
+        batch_header_hash: keccak256(&ethabi::encode(&[batch_header.clone()])).to_vec(),
+        blob_index: blob_index.as_u32(),
+    })
+}
+
+/// ABI-encodes the blob info as `(BlobHeader, BlobVerificationProof)` using the structs
+/// from the EigenDA service manager contracts.
+fn encode_blob_info(info: &BlobInfo) -> anyhow::Result<Vec<u8>> {
+    let header = info.blob_header.as_ref().context("blob_header")?;
+    let proof = info
+        .blob_verification_proof
+        .as_ref()
+        .context("blob_verification_proof")?;
+    Ok(ethabi::encode(&[
+        encode_blob_header(header).context("blob_header")?,
+        encode_verification_proof(proof).context("blob_verification_proof")?,
+    ]))
+}
+
+fn encode_blob_header(header: &BlobHeader) -> anyhow::Result<Token> {
+    let commitment = header.commitment.as_ref().context("commitment")?;
+    let quorum_params = header
+        .blob_quorum_params
+        .iter()
+        .map(|param| {
+            Ok(Token::Tuple(vec![
+                Token::Uint(u8::try_from(param.quorum_number)?.into()),
+                Token::Uint(u8::try_from(param.adversary_threshold_percentage)?.into()),
+                Token::Uint(u8::try_from(param.confirmation_threshold_percentage)?.into()),
+                Token::Uint(param.chunk_length.into()),
+            ]))
+        })
+        .collect::<anyhow::Result<_>>()
+        .context("blob_quorum_params")?;
+
+    Ok(Token::Tuple(vec![
+        Token::Tuple(vec![
+            Token::Uint(uint256(&commitment.x).context("commitment.x")?),
+            Token::Uint(uint256(&commitment.y).context("commitment.y")?),
+        ]),
+        Token::Uint(header.data_length.into()),
+        Token::Array(quorum_params),
+    ]))
+}
+
+fn encode_verification_proof(proof: &BlobVerificationProof) -> anyhow::Result<Token> {
+    let metadata = proof.batch_metadata.as_ref().context("batch_metadata")?;
+    let batch_header = metadata
+        .batch_header
+        .as_ref()
+        .context("batch_metadata.batch_header")?;
+
+    let batch_header = Token::Tuple(vec![
+        bytes32(&batch_header.batch_root).context("batch_root")?,
+        Token::Bytes(batch_header.quorum_numbers.clone()),
+        Token::Bytes(batch_header.quorum_signed_percentages.clone()),
+        Token::Uint(batch_header.reference_block_number.into()),
+    ]);
+    let batch_metadata = Token::Tuple(vec![
+        batch_header,
+        bytes32(&metadata.signatory_record_hash).context("signatory_record_hash")?,
+        Token::Uint(metadata.confirmation_block_number.into()),
+    ]);
+
+    Ok(Token::Tuple(vec![
+        Token::Uint(proof.batch_id.into()),
+        Token::Uint(proof.blob_index.into()),
+        batch_metadata,
+        Token::Bytes(proof.inclusion_proof.clone()),
+        Token::Bytes(proof.quorum_indexes.clone()),
+    ]))
+}
+
+fn uint256(bytes: &[u8]) -> anyhow::Result<U256> {
+    anyhow::ensure!(
+        bytes.len() <= 32,
+        "expected at most 32 bytes, got {}",
+        bytes.len()
+    );
+    Ok(U256::from_big_endian(bytes))
+}
+
+fn bytes32(bytes: &[u8]) -> anyhow::Result<Token> {
+    anyhow::ensure!(bytes.len() == 32, "expected 32 bytes, got {}", bytes.len());
+    Ok(Token::FixedBytes(bytes.to_vec()))
+}
+
+fn is_transient_code(code: tonic::Code) -> bool {
+    matches!(
+        code,
+        tonic::Code::Unavailable
+            | tonic::Code::DeadlineExceeded
+            | tonic::Code::ResourceExhausted
+            | tonic::Code::Aborted
+    )
+}
+
+fn to_da_error(status: tonic::Status) -> DAError {
+    let is_transient = is_transient_code(status.code());
+    let kind = if is_transient {
+        FailureKind::Transient
+    } else {
+        FailureKind::Rejected
+    };
+    METRICS.failures[&kind].inc();
+
+    DAError {
+        error: anyhow::anyhow!(
+            "EigenDA disperser responded with {:?}: {}",
+            status.code(),
+            status.message()
+        ),
+        is_transient,
+    }
+}
+
+fn to_non_retriable_da_error(error: anyhow::Error) -> DAError {
+    DAError {
+        error,
+        is_transient: false,
+    }
+}
@@ -0,0 +1,203 @@
+use assert_matches::assert_matches;
+
+use super::{disperser::*, *};
+
+// Creating the disperser client spawns a connection task, hence the async tests below.
+fn mock_client(wait_for_finalization: bool) -> EigenDAClient {
+    EigenDAClient::new(EigenDAConfig {
+        disperser_rpc: "http://localhost:51001".to_owned(),
+        request_timeout_ms: 1_000,
+        custom_quorum_numbers: vec![],
+        account_id: None,
+        wait_for_finalization,
+        max_blob_size_bytes: Some(16),
+    })
+    .unwrap()
+}
+
+fn mock_blob_info() -> BlobInfo {
+    BlobInfo {
+        blob_header: Some(BlobHeader {
+            commitment: Some(G1Commitment {
+                x: vec![1; 32],
+                y: vec![2; 32],
+            }),
+            data_length: 10,
+            blob_quorum_params: vec![BlobQuorumParam {
+                quorum_number: 0,
+                adversary_threshold_percentage: 33,
+                confirmation_threshold_percentage: 55,
+                chunk_length: 1,
+            }],
+        }),
+        blob_verification_proof: Some(BlobVerificationProof {
+            batch_id: 42,
+            blob_index: 3,
+            batch_metadata: Some(BatchMetadata {
+                batch_header: Some(BatchHeader {
+                    batch_root: vec![3; 32],
+                    quorum_numbers: vec![0],
+                    quorum_signed_percentages: vec![100],
+                    reference_block_number: 1_000,
+                }),
+                signatory_record_hash: vec![4; 32],
+                fee: vec![],
+                confirmation_block_number: 1_010,
+                batch_header_hash: vec![5; 32],
+            }),
+            inclusion_proof: vec![6; 64],
+            quorum_indexes: vec![0],
+        }),
+    }
+}
+
+fn status_reply(status: BlobStatus, info: Option<BlobInfo>) -> BlobStatusReply {
+    BlobStatusReply {
+        status: status as i32,
+        info,
+    }
+}
+
+#[test]
+fn encoding_blob() {
+    assert!(encode_blob(&[]).is_empty());
+
+    let data: Vec<u8> = (1..=40).collect();
+    let encoded = encode_blob(&data);
+    assert_eq!(encoded.len(), 32 + 10);
+    assert_eq!(encoded[0], 0);
+    assert_eq!(encoded[1..32], data[..31]);
+    assert_eq!(encoded[32], 0);
+    assert_eq!(encoded[33..], data[31..]);
+}
+
+#[test]
+fn decoding_blob() {
+    let data: Vec<u8> = (1..=40).collect();
+    assert_eq!(decode_blob(&encode_blob(&data)), data);
+    assert!(decode_blob(&[]).is_empty());
+}
+
+#[test]
+fn building_retrieve_blob_request() {
+    let info = mock_blob_info();
+    let request = retrieve_blob_request(&encode_blob_info(&info).unwrap()).unwrap();
+    assert_eq!(request.blob_index, 3);
+
+    let batch_header = info
+        .blob_verification_proof
+        .unwrap()
+        .batch_metadata
+        .unwrap()
+        .batch_header
+        .unwrap();
+    let expected_hash = keccak256(&ethabi::encode(&[Token::Tuple(vec![
+        Token::FixedBytes(batch_header.batch_root),
+        Token::Bytes(batch_header.quorum_numbers),
+        Token::Bytes(batch_header.quorum_signed_percentages),
+        Token::Uint(batch_header.reference_block_number.into()),
+    ])]));
+    assert_eq!(request.batch_header_hash, expected_hash);
+
+    retrieve_blob_request(&[0; 32]).unwrap_err();
+}
+
+#[test]
+fn encoding_blob_info() {
+    let encoded = encode_blob_info(&mock_blob_info()).unwrap();
+    assert_eq!(encoded.len() % 32, 0);
+
+    let mut info = mock_blob_info();
+    info.blob_verification_proof = None;
+    encode_blob_info(&info).unwrap_err();
+
+    let mut info = mock_blob_info();
+    info.blob_header.as_mut().unwrap().blob_quorum_params[0].quorum_number = 256;
+    encode_blob_info(&info).unwrap_err();
+
+    let mut info = mock_blob_info();
+    let metadata = info
+        .blob_verification_proof
+        .as_mut()
+        .unwrap()
+        .batch_metadata
+        .as_mut()
+        .unwrap();
+    metadata.signatory_record_hash = vec![4; 31];
+    encode_blob_info(&info).unwrap_err();
+}
+
+#[test]
+fn classifying_grpc_errors() {
+    let err = to_da_error(tonic::Status::unavailable("down"));
+    assert!(err.is_transient());
+    let err = to_da_error(tonic::Status::resource_exhausted("rate limited"));
+    assert!(err.is_transient());
+    let err = to_da_error(tonic::Status::invalid_argument("bad blob"));
+    assert!(!err.is_transient());
+}
+
+#[tokio::test]
+async fn checking_blob_size() {
+    let client = mock_client(false);
+    client.check_blob_size(&[0; 16]).unwrap();
+    let err = client.check_blob_size(&[0; 17]).unwrap_err();
+    assert!(!err.is_transient());
+}
+
+#[tokio::test]
+async fn inclusion_data_for_pending_blob() {
+    let client = mock_client(false);
+    for status in [BlobStatus::Processing, BlobStatus::Dispersing] {
+        let data = client
+            .inclusion_data_from_status(status_reply(status, None))
+            .unwrap();
+        assert!(data.is_none(), "{status:?}");
+    }
+}
+
+#[tokio::test]
+async fn inclusion_data_for_confirmed_blob() {
+    let client = mock_client(false);
+    let data = client
+        .inclusion_data_from_status(status_reply(BlobStatus::Confirmed, Some(mock_blob_info())))
+        .unwrap()
+        .expect("blob is confirmed");
+    assert_eq!(data.data, encode_blob_info(&mock_blob_info()).unwrap());
+
+    let client = mock_client(true);
+    let data = client
+        .inclusion_data_from_status(status_reply(BlobStatus::Confirmed, Some(mock_blob_info())))
+        .unwrap();
+    assert!(data.is_none());
+    let data = client
+        .inclusion_data_from_status(status_reply(BlobStatus::Finalized, Some(mock_blob_info())))
+        .unwrap();
+    assert!(data.is_some());
+}
+
+#[tokio::test]
+async fn inclusion_data_errors() {
+    let client = mock_client(false);
+    for status in [
+        BlobStatus::Failed,
+        BlobStatus::InsufficientSignatures,
+        BlobStatus::Unknown,
+    ] {
+        let err = client
+            .inclusion_data_from_status(status_reply(status, None))
+            .unwrap_err();
+        assert!(!err.is_transient(), "{status:?}");
+    }
+
+    let err = client
+        .inclusion_data_from_status(status_reply(BlobStatus::Finalized, None))
+        .unwrap_err();
+    assert_matches!(
+        err,
+        DAError {
+            is_transient: false,
+            ..
+        }
+    );
+}
@@ -0,0 +1,38 @@
+//! Implementations of the [`DataAvailabilityClient`](zksync_da_client::DataAvailabilityClient) trait
+//! for the supported DA layers.
+
+use zksync_config::configs::da_client::{DAClient, DAClientConfig};
+use zksync_da_client::{split::SplittingClient, DataAvailabilityClient};
+use zksync_object_store::ObjectStoreFactory;
+
+pub mod avail;
+pub mod celestia;
+pub mod eigen;
+pub mod mantle;
+pub mod object_store;
+mod utils;
+
+/// Creates a client for the DA layer specified in the config. The client is able to retrieve blobs split
+/// by the DA dispatcher (see [`SplittingClient`]). The shadow DA layer in the config is ignored.
+pub async fn create_client(
+    config: DAClientConfig,
+) -> anyhow::Result<Box<dyn DataAvailabilityClient>> {
+    let client = create_base_client(config.client).await?;
+    Ok(Box::new(SplittingClient::new(client)))
+}
+
+/// Creates a client for the specified DA layer without wrapping it into [`SplittingClient`].
+pub async fn create_base_client(
+    client: DAClient,
+) -> anyhow::Result<Box<dyn DataAvailabilityClient>> {
+    Ok(match client {
+        DAClient::Mantle(config) => Box::new(mantle::MantleDAClient::new(config)?),
+        DAClient::EigenDA(config) => Box::new(eigen::EigenDAClient::new(config)?),
+        DAClient::Celestia(config) => Box::new(celestia::CelestiaClient::new(config)?),
+        DAClient::Avail(config) => Box::new(avail::AvailClient::new(config)?),
+        DAClient::ObjectStore(config) => {
+            let store = ObjectStoreFactory::new(config).create_store().await?;
+            Box::new(object_store::ObjectStoreDAClient::new(store))
+        }
+    })
+}
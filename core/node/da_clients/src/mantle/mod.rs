@@ -0,0 +1,158 @@
+//! Client for Mantle DA.
+//!
+//! The client communicates with the Mantle DA proxy using the following endpoints:
+//!
+//! - `POST /blobs` disperses a blob and returns the dispersal request ID, which is used as the blob ID.
+//! - `GET /blobs/{request_id}` returns the blob status and, once the blob is confirmed, the blob certificate.
+//!
+//! The blob certificate is used as the inclusion data verified by the L1 contracts.
+
+use std::fmt;
+
+use anyhow::Context as _;
+use async_trait::async_trait;
+use zksync_config::configs::da_client::mantle::MantleDAConfig;
+use zksync_da_client::{
+    types::{DAError, DispatchResponse, InclusionData},
+    DataAvailabilityClient,
+};
+
+use self::types::{BlobStatus, BlobStatusResponse, DisperseBlobRequest, DisperseBlobResponse};
+use crate::utils::{check_status, to_da_error, to_non_retriable_da_error};
+
+#[cfg(test)]
+mod tests;
+mod types;
+
+/// Mantle DA client.
+#[derive(Clone)]
+pub struct MantleDAClient {
+    config: MantleDAConfig,
+    client: reqwest::Client,
+}
+
+impl fmt::Debug for MantleDAClient {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("MantleDAClient")
+            .field("config", &self.config)
+            .finish_non_exhaustive()
+    }
+}
+
+impl MantleDAClient {
+    pub fn new(config: MantleDAConfig) -> anyhow::Result<Self> {
+        let client = reqwest::Client::builder()
+            .timeout(config.request_timeout())
+            .build()
+            .context("failed building HTTP client")?;
+        Ok(Self { config, client })
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}/{path}", self.config.api_node_url.trim_end_matches('/'))
+    }
+
+    fn check_blob_size(&self, data: &[u8]) -> Result<(), DAError> {
+        if let Some(limit) = self.config.max_blob_size_bytes {
+            if data.len() > limit {
+                return Err(to_non_retriable_da_error(anyhow::anyhow!(
+                    "blob size ({} bytes) exceeds the limit ({limit} bytes)",
+                    data.len()
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Converts the blob status into the inclusion data. Returns `Ok(None)` if the blob isn't included yet.
+    fn inclusion_data_from_status(
+        &self,
+        response: BlobStatusResponse,
+    ) -> Result<Option<InclusionData>, DAError> {
+        let is_included = match response.status {
+            BlobStatus::Processing | BlobStatus::Dispersing => false,
+            BlobStatus::Confirmed => !self.config.wait_for_finalization,
+            BlobStatus::Finalized => true,
+            BlobStatus::Failed | BlobStatus::InsufficientSignatures | BlobStatus::Unknown => {
+                return Err(to_non_retriable_da_error(anyhow::anyhow!(
+                    "blob dispersal failed with status {:?}",
+                    response.status
+                )));
+            }
+        };
+        if !is_included {
+            return Ok(None);
+        }
+
+        let certificate = response
+            .blob_certificate
+            .context("blob certificate is missing for an included blob")
+            .map_err(to_non_retriable_da_error)?;
+        let certificate = certificate.strip_prefix("0x").unwrap_or(&certificate);
+        let data = hex::decode(certificate)
+            .context("blob certificate is not a valid hex string")
+            .map_err(to_non_retriable_da_error)?;
+        Ok(Some(InclusionData { data }))
+    }
+}
+
+#[async_trait]
+impl DataAvailabilityClient for MantleDAClient {
+    async fn dispatch_blob(
+        &self,
+        batch_number: u32,
+        data: Vec<u8>,
+    ) -> Result<DispatchResponse, DAError> {
+        self.check_blob_size(&data)?;
+
+        let request = DisperseBlobRequest {
+            data: format!("0x{}", hex::encode(&data)),
+        };
+        let response = self
+            .client
+            .post(self.url("blobs"))
+            .json(&request)
+            .send()
+            .await
+            .map_err(to_da_error)?;
+        let response: DisperseBlobResponse = check_status(response, "Mantle DA proxy")
+            .await?
+            .json()
+            .await
+            .map_err(to_da_error)?;
+
+        tracing::debug!(
+            "Dispersed blob for L1 batch #{batch_number} to Mantle DA, request ID: {}",
+            response.request_id
+        );
+        Ok(DispatchResponse::from(response.request_id))
+    }
+
+    async fn get_inclusion_data(&self, blob_id: &str) -> Result<Option<InclusionData>, DAError> {
+        let response = self
+            .client
+            .get(self.url(&format!("blobs/{blob_id}")))
+            .send()
+            .await
+            .map_err(to_da_error)?;
+        let response: BlobStatusResponse = check_status(response, "Mantle DA proxy")
+            .await?
+            .json()
+            .await
+            .map_err(to_da_error)?;
+        self.inclusion_data_from_status(response)
+    }
+
+    fn client_type(&self) -> &'static str {
+        "mantle"
+    }
+
+    fn clone_boxed(&self) -> Box<dyn DataAvailabilityClient> {
+        Box::new(self.clone())
+    }
+
+    fn blob_size_limit(&self) -> Option<usize> {
+        self.config.max_blob_size_bytes
+    }
+}
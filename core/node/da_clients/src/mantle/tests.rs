@@ -0,0 +1,93 @@
+use assert_matches::assert_matches;
+
+use super::*;
+
+fn mock_client(wait_for_finalization: bool) -> MantleDAClient {
+    MantleDAClient::new(MantleDAConfig {
+        api_node_url: "http://localhost:3100/".to_owned(),
+        request_timeout_ms: 1_000,
+        wait_for_finalization,
+        max_blob_size_bytes: Some(16),
+    })
+    .unwrap()
+}
+
+fn status_response(status: &str, certificate: Option<&str>) -> BlobStatusResponse {
+    let json = serde_json::json!({
+        "status": status,
+        "blob_certificate": certificate,
+    });
+    serde_json::from_value(json).unwrap()
+}
+
+#[test]
+fn building_urls() {
+    let client = mock_client(false);
+    assert_eq!(client.url("blobs/123"), "http://localhost:3100/blobs/123");
+}
+
+#[test]
+fn checking_blob_size() {
+    let client = mock_client(false);
+    client.check_blob_size(&[0; 16]).unwrap();
+    let err = client.check_blob_size(&[0; 17]).unwrap_err();
+    assert!(!err.is_transient());
+}
+
+#[test]
+fn parsing_unknown_status() {
+    let response = status_response("SOMETHING_NEW", None);
+    assert_eq!(response.status, BlobStatus::Unknown);
+}
+
+#[test]
+fn inclusion_data_for_pending_blob() {
+    let client = mock_client(false);
+    for status in ["PROCESSING", "DISPERSING"] {
+        let data = client
+            .inclusion_data_from_status(status_response(status, None))
+            .unwrap();
+        assert!(data.is_none(), "{status}");
+    }
+}
+
+#[test]
+fn inclusion_data_for_confirmed_blob() {
+    let client = mock_client(false);
+    let data = client
+        .inclusion_data_from_status(status_response("CONFIRMED", Some("0x0102ff")))
+        .unwrap()
+        .expect("blob is confirmed");
+    assert_eq!(data.data, [1, 2, 255]);
+
+    let client = mock_client(true);
+    let data = client
+        .inclusion_data_from_status(status_response("CONFIRMED", Some("0x0102ff")))
+        .unwrap();
+    assert!(data.is_none());
+    let data = client
+        .inclusion_data_from_status(status_response("FINALIZED", Some("0102ff")))
+        .unwrap()
+        .expect("blob is finalized");
+    assert_eq!(data.data, [1, 2, 255]);
+}
+
+#[test]
+fn inclusion_data_errors() {
+    let client = mock_client(false);
+    let err = client
+        .inclusion_data_from_status(status_response("FAILED", None))
+        .unwrap_err();
+    assert!(!err.is_transient());
+
+    let err = client
+        .inclusion_data_from_status(status_response("CONFIRMED", None))
+        .unwrap_err();
+    assert_matches!(
+        err,
+        DAError {
+            is_transient: false,
+            ..
+        }
+    );
+}
@@ -0,0 +1,47 @@
+//! Types of the Mantle DA proxy API.
+
+use serde::{Deserialize, Serialize};
+
+/// Request to disperse a blob.
+#[derive(Debug, Serialize)]
+pub(super) struct DisperseBlobRequest {
+    /// Hex-encoded blob data (`0x`-prefixed).
+    pub data: String,
+}
+
+/// Response to [`DisperseBlobRequest`].
+#[derive(Debug, Deserialize)]
+pub(super) struct DisperseBlobResponse {
+    /// ID of the dispersal request; it's used to poll the blob status.
+    pub request_id: String,
+}
+
+/// Status of a dispersed blob as reported by the proxy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub(super) enum BlobStatus {
+    /// The blob is being processed by the disperser.
+    Processing,
+    /// The blob is being dispersed to the DA nodes.
+    Dispersing,
+    /// The blob batch is confirmed on the DA network, but may still be reorged.
+    Confirmed,
+    /// The blob batch is finalized on the DA network.
+    Finalized,
+    /// The blob dispersal has failed.
+    Failed,
+    /// The blob batch didn't collect enough signatures from the DA nodes.
+    InsufficientSignatures,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Response to the blob status request.
+#[derive(Debug, Deserialize)]
+pub(super) struct BlobStatusResponse {
+    pub status: BlobStatus,
+    /// Hex-encoded certificate proving the blob inclusion (`0x`-prefixed). Only present
+    /// for confirmed and finalized blobs.
+    #[serde(default)]
+    pub blob_certificate: Option<String>,
+}
@@ -0,0 +1,132 @@
+//! "No-DA" client storing blobs in an object store. Intended for local development only.
+//!
+//! Blobs are stored in the [`Bucket::DataAvailability`] bucket under the hex-encoded keccak256 hash of the blob,
+//! which is also used as the blob ID. The inclusion data is fabricated: it's the 32-byte hash of the blob,
+//! so it doesn't prove anything to the L1 contracts.
+
+use std::sync::Arc;
+
+use anyhow::Context as _;
+use async_trait::async_trait;
+use zksync_basic_types::web3::keccak256;
+use zksync_da_client::{
+    types::{DAError, DispatchResponse, InclusionData},
+    DataAvailabilityClient,
+};
+use zksync_object_store::{Bucket, ObjectStore, ObjectStoreError};
+
+#[cfg(test)]
+mod tests;
+
+/// Client storing blobs in an object store.
+#[derive(Debug, Clone)]
+pub struct ObjectStoreDAClient {
+    store: Arc<dyn ObjectStore>,
+}
+
+impl ObjectStoreDAClient {
+    pub fn new(store: Arc<dyn ObjectStore>) -> Self {
+        Self { store }
+    }
+
+    /// Loads the blob with the specified hash. Returns `Ok(None)` if the blob is not in the store.
+    async fn load_blob(&self, hash: &[u8; 32]) -> Result<Option<Vec<u8>>, DAError> {
+        match self
+            .store
+            .get_raw(Bucket::DataAvailability, &hex::encode(hash))
+            .await
+        {
+            Ok(blob) => Ok(Some(blob)),
+            Err(ObjectStoreError::KeyNotFound(_)) => Ok(None),
+            Err(err) => Err(to_da_error(err)),
+        }
+    }
+}
+
+#[async_trait]
+impl DataAvailabilityClient for ObjectStoreDAClient {
+    async fn dispatch_blob(
+        &self,
+        batch_number: u32,
+        data: Vec<u8>,
+    ) -> Result<DispatchResponse, DAError> {
+        let blob_id = hex::encode(keccak256(&data));
+        self.store
+            .put_raw(Bucket::DataAvailability, &blob_id, data)
+            .await
+            .map_err(to_da_error)?;
+
+        tracing::debug!(
+            "Stored blob for L1 batch #{batch_number} in object store, blob ID: {blob_id}"
+        );
+        // Storing blobs is free.
+        Ok(DispatchResponse::from(blob_id).with_fee(0))
+    }
+
+    async fn get_inclusion_data(&self, blob_id: &str) -> Result<Option<InclusionData>, DAError> {
+        let hash = parse_hash(blob_id).map_err(to_non_retriable_da_error)?;
+        if self.load_blob(&hash).await?.is_none() {
+            return Err(to_non_retriable_da_error(anyhow::anyhow!(
+                "blob {blob_id} is missing from object store"
+            )));
+        }
+        Ok(Some(InclusionData {
+            data: hash.to_vec(),
+        }))
+    }
+
+    async fn get_blob(&self, inclusion_data: &[u8]) -> Result<Option<Vec<u8>>, DAError> {
+        let hash: [u8; 32] = inclusion_data
+            .try_into()
+            .map_err(|_| {
+                anyhow::anyhow!(
+                    "inclusion data must be a 32-byte hash, got {} bytes",
+                    inclusion_data.len()
+                )
+            })
+            .map_err(to_non_retriable_da_error)?;
+        let Some(blob) = self.load_blob(&hash).await? else {
+            return Ok(None);
+        };
+        if keccak256(&blob) != hash {
+            return Err(to_non_retriable_da_error(anyhow::anyhow!(
+                "blob stored under hash 0x{} is corrupted",
+                hex::encode(hash)
+            )));
+        }
+        Ok(Some(blob))
+    }
+
+    fn client_type(&self) -> &'static str {
+        "object_store"
+    }
+
+    fn clone_boxed(&self) -> Box<dyn DataAvailabilityClient> {
+        Box::new(self.clone())
+    }
+
+    fn blob_size_limit(&self) -> Option<usize> {
+        None
+    }
+}
+
+fn parse_hash(blob_id: &str) -> anyhow::Result<[u8; 32]> {
+    let bytes = hex::decode(blob_id).with_context(|| format!("malformed blob ID `{blob_id}`"))?;
+    bytes
+        .try_into()
+        .map_err(|_| anyhow::anyhow!("blob ID `{blob_id}` is not a 32-byte hash"))
+}
+
+fn to_da_error(err: ObjectStoreError) -> DAError {
+    DAError {
+        is_transient: err.is_transient(),
+        error: err.into(),
+    }
+}
+
+fn to_non_retriable_da_error(error: anyhow::Error) -> DAError {
+    DAError {
+        error,
+        is_transient: false,
+    }
+}
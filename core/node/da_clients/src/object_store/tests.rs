@@ -0,0 +1,58 @@
+use zksync_object_store::MockObjectStore;
+
+use super::*;
+
+#[tokio::test]
+async fn dispatching_and_retrieving_blob() {
+    let client = ObjectStoreDAClient::new(MockObjectStore::arc());
+    let data = b"pubdata".to_vec();
+
+    let response = client.dispatch_blob(1, data.clone()).await.unwrap();
+    assert_eq!(response.blob_id, hex::encode(keccak256(&data)));
+    assert_eq!(response.fee, Some(0));
+
+    let inclusion_data = client
+        .get_inclusion_data(&response.blob_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(inclusion_data.data, keccak256(&data));
+
+    let blob = client.get_blob(&inclusion_data.data).await.unwrap();
+    assert_eq!(blob, Some(data));
+    let missing_blob = client.get_blob(&[0; 32]).await.unwrap();
+    assert_eq!(missing_blob, None);
+}
+
+#[tokio::test]
+async fn missing_blob_errors() {
+    let client = ObjectStoreDAClient::new(MockObjectStore::arc());
+
+    let err = client
+        .get_inclusion_data(&hex::encode([1; 32]))
+        .await
+        .unwrap_err();
+    assert!(!err.is_transient);
+    let err = client.get_inclusion_data("not a hash").await.unwrap_err();
+    assert!(!err.is_transient);
+    let err = client.get_blob(&[1; 16]).await.unwrap_err();
+    assert!(!err.is_transient);
+}
+
+#[tokio::test]
+async fn detecting_corrupted_blob() {
+    let store = MockObjectStore::arc();
+    let client = ObjectStoreDAClient::new(store.clone());
+    let hash = keccak256(b"pubdata");
+    store
+        .put_raw(
+            Bucket::DataAvailability,
+            &hex::encode(hash),
+            b"other".to_vec(),
+        )
+        .await
+        .unwrap();
+
+    let err = client.get_blob(&hash).await.unwrap_err();
+    assert!(err.to_string().contains("corrupted"), "{err}");
+}
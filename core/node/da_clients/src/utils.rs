@@ -0,0 +1,43 @@
+//! Helpers shared by the HTTP-based DA clients.
+
+use reqwest::StatusCode;
+use zksync_da_client::types::DAError;
+
+/// Converts a non-successful HTTP response into a [`DAError`]. `service` is the human-readable name
+/// of the remote service used in the error message.
+pub(crate) async fn check_status(
+    response: reqwest::Response,
+    service: &str,
+) -> Result<reqwest::Response, DAError> {
+    let status = response.status();
+    if status.is_success() {
+        return Ok(response);
+    }
+
+    let body = response.text().await.unwrap_or_default();
+    let error = anyhow::anyhow!("{service} responded with {status}: {body}");
+    Err(DAError {
+        error,
+        is_transient: is_transient_status(status),
+    })
+}
+
+fn is_transient_status(status: StatusCode) -> bool {
+    status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS
+}
+
+pub(crate) fn to_da_error(err: reqwest::Error) -> DAError {
+    let is_transient =
+        err.is_timeout() || err.is_connect() || err.status().map_or(false, is_transient_status);
+    DAError {
+        error: err.into(),
+        is_transient,
+    }
+}
+
+pub(crate) fn to_non_retriable_da_error(error: anyhow::Error) -> DAError {
+    DAError {
+        error,
+        is_transient: false,
+    }
+}
@@ -0,0 +1,810 @@
+use std::{future::Future, sync::Mutex, time::Duration};
+
+use anyhow::Context;
+use chrono::{NaiveDateTime, Utc};
+use rand::Rng;
+use serde::Serialize;
+use tokio::sync::watch;
+use zksync_config::DADispatcherConfig;
+use zksync_da_client::{types::DAError, DataAvailabilityClient};
+use zksync_dal::{
+    data_availability_dal::{DACostSummary, DADeadLetter, DADeadLetterStage, L1BatchDA},
+    ConnectionPool, Core, CoreDal,
+};
+use zksync_health_check::{Health, HealthStatus, HealthUpdater, ReactiveHealthCheck};
+use zksync_types::L1BatchNumber;
+
+use crate::metrics::METRICS;
+
+/// Maximum number of dead letters reported in the health check details.
+const MAX_REPORTED_DEAD_LETTERS: usize = 10;
+
+/// L1 batch exceeding the maximum age at a certain processing stage.
+#[derive(Debug, Clone, Serialize)]
+struct OverdueL1Batch {
+    l1_batch_number: L1BatchNumber,
+    age_secs: u64,
+}
+
+#[derive(Debug, Serialize)]
+struct DeadLetterDetails {
+    l1_batch_number: L1BatchNumber,
+    stage: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    blob_id: Option<String>,
+    error: String,
+    attempts: u32,
+    created_at: NaiveDateTime,
+}
+
+impl From<DADeadLetter> for DeadLetterDetails {
+    fn from(letter: DADeadLetter) -> Self {
+        Self {
+            l1_batch_number: letter.l1_batch_number,
+            stage: letter.stage.as_str(),
+            blob_id: letter.blob_id,
+            error: letter.error,
+            attempts: letter.attempts,
+            created_at: letter.created_at,
+        }
+    }
+}
+
+/// DA layer spending exceeding the configured budget over the budget period.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct ExceededCostBudget {
+    client_type: String,
+    period_secs: u64,
+    dispatched_bytes: u64,
+    fee: u64,
+    l1_batches_with_unknown_fee: u64,
+}
+
+impl ExceededCostBudget {
+    fn check(config: &DADispatcherConfig, summary: &DACostSummary) -> Option<Self> {
+        let bytes_exceeded = config
+            .max_dispatched_bytes_per_period
+            .map_or(false, |max_bytes| summary.blob_size_bytes > max_bytes);
+        let fee_exceeded = config
+            .max_fee_per_period
+            .map_or(false, |max_fee| summary.fee > max_fee);
+        (bytes_exceeded || fee_exceeded).then(|| Self {
+            client_type: summary.client_type.clone(),
+            period_secs: config.cost_budget_period().as_secs(),
+            dispatched_bytes: summary.blob_size_bytes,
+            fee: summary.fee,
+            l1_batches_with_unknown_fee: summary.l1_batches_with_unknown_fee,
+        })
+    }
+}
+
+/// Health details reported by [`DataAvailabilityDispatcher`].
+#[derive(Debug, Default, Serialize)]
+struct DispatcherHealthDetails {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    overdue_dispatch: Option<OverdueL1Batch>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    overdue_inclusion: Option<OverdueL1Batch>,
+    /// First dead letters in the L1 batch number order.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    dead_letters: Vec<DeadLetterDetails>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    exceeded_cost_budgets: Vec<ExceededCostBudget>,
+}
+
+impl DispatcherHealthDetails {
+    fn overdue_mut(&mut self, stage: DADeadLetterStage) -> &mut Option<OverdueL1Batch> {
+        match stage {
+            DADeadLetterStage::Dispatch => &mut self.overdue_dispatch,
+            DADeadLetterStage::Inclusion => &mut self.overdue_inclusion,
+        }
+    }
+
+    /// Returns the most severe issue affecting the dispatcher, if any.
+    fn cause(&self) -> Option<&'static str> {
+        if !self.dead_letters.is_empty() {
+            Some("dead_letters")
+        } else if self.overdue_dispatch.is_some() {
+            Some("overdue_dispatch")
+        } else if self.overdue_inclusion.is_some() {
+            Some("overdue_inclusion")
+        } else if !self.exceeded_cost_budgets.is_empty() {
+            Some("exceeded_cost_budget")
+        } else {
+            None
+        }
+    }
+
+    fn health(&self) -> Health {
+        let health = if let Some(cause) = self.cause() {
+            Health::from(HealthStatus::Affected).with_cause(cause)
+        } else {
+            Health::from(HealthStatus::Ready)
+        };
+        health
+            .with_counter("dead_letters", self.dead_letters.len() as u64)
+            .with_details(self)
+    }
+}
+
+/// Dispatches the pubdata of sealed L1 batches to the DA layer and polls the layer
+/// for the inclusion data required by the commit transactions on L1.
+#[derive(Debug)]
+pub struct DataAvailabilityDispatcher {
+    client: Box<dyn DataAvailabilityClient>,
+    shadow_client: Option<Box<dyn DataAvailabilityClient>>,
+    pool: ConnectionPool<Core>,
+    config: DADispatcherConfig,
+    health_check: ReactiveHealthCheck,
+    health_updater: HealthUpdater,
+    health_details: Mutex<DispatcherHealthDetails>,
+}
+
+impl DataAvailabilityDispatcher {
+    pub fn new(
+        pool: ConnectionPool<Core>,
+        config: DADispatcherConfig,
+        client: Box<dyn DataAvailabilityClient>,
+    ) -> Self {
+        let (health_check, health_updater) = ReactiveHealthCheck::new("da_dispatcher");
+        Self {
+            pool,
+            config,
+            client,
+            shadow_client: None,
+            health_check,
+            health_updater,
+            health_details: Mutex::default(),
+        }
+    }
+
+    /// Enables the dual-write mode: pubdata is additionally dispatched to the shadow DA layer, and the shadow layer
+    /// is polled for inclusion. The shadow layer doesn't influence the primary one; divergences between them
+    /// are only reported via logs and metrics.
+    pub fn with_shadow_client(mut self, client: Box<dyn DataAvailabilityClient>) -> Self {
+        self.shadow_client = Some(client);
+        self
+    }
+
+    /// Returns the health check for this dispatcher. The check is affected if there are dead-lettered L1 batches,
+    /// L1 batches exceeding the maximum age, or DA layers exceeding the cost budget; the details list them.
+    pub fn health_check(&self) -> &ReactiveHealthCheck {
+        &self.health_check
+    }
+
+    pub async fn run(self, mut stop_receiver: watch::Receiver<bool>) -> anyhow::Result<()> {
+        loop {
+            if *stop_receiver.borrow() {
+                break;
+            }
+
+            if let Err(err) = self.refresh_dead_letters().await {
+                tracing::error!("Failed refreshing DA dead letters: {err:?}");
+            }
+            if let Err(err) = self.check_cost_budget().await {
+                tracing::error!("Failed checking DA cost budget: {err:?}");
+            }
+
+            let subtasks = futures::future::join3(
+                async {
+                    if let Err(err) = self.dispatch().await {
+                        tracing::error!("dispatch error {err:?}");
+                    }
+                },
+                async {
+                    if let Err(err) = self.poll_for_inclusion().await {
+                        tracing::error!("poll_for_inclusion error {err:?}");
+                    }
+                },
+                async {
+                    if let Err(err) = self.poll_for_shadow_inclusion().await {
+                        tracing::error!("poll_for_shadow_inclusion error {err:?}");
+                    }
+                },
+            );
+
+            tokio::select! {
+                _ = subtasks => {},
+                _ = stop_receiver.changed() => {
+                    break;
+                }
+            }
+
+            if tokio::time::timeout(self.config.polling_interval(), stop_receiver.changed())
+                .await
+                .is_ok()
+            {
+                break;
+            }
+        }
+
+        tracing::info!("Stop signal received, da_dispatcher is shutting down");
+        Ok(())
+    }
+
+    /// Dispatches the blobs to the data availability layer, and saves the blob ID in the database.
+    async fn dispatch(&self) -> anyhow::Result<()> {
+        let mut conn = self.pool.connection_tagged("da_dispatcher").await?;
+        let batches = conn
+            .data_availability_dal()
+            .get_ready_for_da_dispatch_l1_batches(self.config.max_rows_to_dispatch() as usize)
+            .await?;
+        drop(conn);
+
+        for batch in batches {
+            let dispatch_latency = METRICS.blob_dispatch_latency.start();
+            let dispatch_result = retry(&self.config, batch.l1_batch_number, || {
+                self.client
+                    .dispatch_blob(batch.l1_batch_number.0, batch.pubdata.clone())
+            })
+            .await;
+            let dispatch_response = match dispatch_result {
+                Ok(response) => response,
+                Err(err) if !err.error.is_transient() => {
+                    self.move_to_dead_letters(
+                        batch.l1_batch_number,
+                        DADeadLetterStage::Dispatch,
+                        None,
+                        &err.error,
+                        err.attempts,
+                    )
+                    .await?;
+                    continue;
+                }
+                Err(err) => {
+                    self.check_age(
+                        DADeadLetterStage::Dispatch,
+                        batch.l1_batch_number,
+                        batch.sealed_at,
+                    );
+                    return Err(anyhow::Error::from(err.error)).with_context(|| {
+                        format!(
+                            "failed to dispatch a blob with batch_number: {}, pubdata_len: {}",
+                            batch.l1_batch_number,
+                            batch.pubdata.len()
+                        )
+                    });
+                }
+            };
+            let dispatch_latency_duration = dispatch_latency.observe();
+            self.clear_overdue(DADeadLetterStage::Dispatch, batch.l1_batch_number);
+
+            let sent_at = Utc::now().naive_utc();
+
+            let mut conn = self.pool.connection_tagged("da_dispatcher").await?;
+            conn.data_availability_dal()
+                .insert_l1_batch_da(
+                    batch.l1_batch_number,
+                    dispatch_response.blob_id.as_str(),
+                    sent_at,
+                )
+                .await?;
+            let client_type = self.client.client_type();
+            let pubdata_len = batch.pubdata.len() as u64;
+            conn.data_availability_dal()
+                .insert_l1_batch_da_cost(
+                    batch.l1_batch_number,
+                    client_type,
+                    pubdata_len,
+                    dispatch_response.fee,
+                )
+                .await?;
+            drop(conn);
+
+            METRICS.dispatched_bytes[&client_type].inc_by(pubdata_len);
+            if let Some(fee) = dispatch_response.fee {
+                METRICS.dispatch_fee[&client_type].inc_by(fee);
+            }
+            METRICS
+                .last_dispatched_l1_batch
+                .set(batch.l1_batch_number.0 as usize);
+            METRICS.blob_size.observe(batch.pubdata.len());
+            tracing::info!(
+                "Dispatched a DA for batch_number: {}, pubdata_size: {}, dispatch_latency: {dispatch_latency_duration:?}",
+                batch.l1_batch_number,
+                batch.pubdata.len(),
+            );
+
+            if let Some(shadow_client) = &self.shadow_client {
+                self.dispatch_to_shadow(shadow_client.as_ref(), &batch)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Dispatches the L1 batch pubdata to the shadow DA layer after it was dispatched to the primary one.
+    /// Failures are recorded in the database and reported as divergences; they don't block the primary DA layer.
+    async fn dispatch_to_shadow(
+        &self,
+        shadow_client: &dyn DataAvailabilityClient,
+        batch: &L1BatchDA,
+    ) -> anyhow::Result<()> {
+        let client_type = shadow_client.client_type();
+        let dispatch_result = retry(&self.config, batch.l1_batch_number, || {
+            shadow_client.dispatch_blob(batch.l1_batch_number.0, batch.pubdata.clone())
+        })
+        .await;
+        let sent_at = Utc::now().naive_utc();
+
+        let mut conn = self.pool.connection_tagged("da_dispatcher").await?;
+        match dispatch_result {
+            Ok(response) => {
+                conn.data_availability_dal()
+                    .insert_shadow_blob(
+                        batch.l1_batch_number,
+                        client_type,
+                        Some(&response.blob_id),
+                        None,
+                        sent_at,
+                    )
+                    .await?;
+                let pubdata_len = batch.pubdata.len() as u64;
+                conn.data_availability_dal()
+                    .insert_l1_batch_da_cost(
+                        batch.l1_batch_number,
+                        client_type,
+                        pubdata_len,
+                        response.fee,
+                    )
+                    .await?;
+
+                METRICS.dispatched_bytes[&client_type].inc_by(pubdata_len);
+                if let Some(fee) = response.fee {
+                    METRICS.dispatch_fee[&client_type].inc_by(fee);
+                }
+                METRICS
+                    .last_shadow_dispatched_l1_batch
+                    .set(batch.l1_batch_number.0 as usize);
+                tracing::info!(
+                    "Dispatched a DA for batch_number: {} to shadow DA layer `{client_type}`, blob_id: {}",
+                    batch.l1_batch_number,
+                    response.blob_id
+                );
+            }
+            Err(err) => {
+                tracing::warn!(
+                    "Failed dispatching L1 batch #{} to shadow DA layer `{client_type}` after {} attempt(s), \
+                     while the primary DA layer has succeeded: {}",
+                    batch.l1_batch_number,
+                    err.attempts,
+                    err.error
+                );
+                conn.data_availability_dal()
+                    .insert_shadow_blob(
+                        batch.l1_batch_number,
+                        client_type,
+                        None,
+                        Some(&format!("{:#}", err.error.error)),
+                        sent_at,
+                    )
+                    .await?;
+                METRICS.shadow_divergences[&DADeadLetterStage::Dispatch.as_str()].inc();
+            }
+        }
+        Ok(())
+    }
+
+    /// Polls the shadow DA layer for inclusion data, and saves it in the database.
+    async fn poll_for_shadow_inclusion(&self) -> anyhow::Result<()> {
+        let Some(shadow_client) = &self.shadow_client else {
+            return Ok(());
+        };
+        let client_type = shadow_client.client_type();
+
+        let mut conn = self.pool.connection_tagged("da_dispatcher").await?;
+        let blob_info = conn
+            .data_availability_dal()
+            .get_first_shadow_blob_awaiting_inclusion(client_type)
+            .await?;
+        drop(conn);
+
+        let Some(blob_info) = blob_info else {
+            return Ok(());
+        };
+
+        let (inclusion_data, error) = match shadow_client
+            .get_inclusion_data(blob_info.blob_id.as_str())
+            .await
+        {
+            Ok(Some(inclusion_data)) => (Some(inclusion_data.data), None),
+            Ok(None) => return Ok(()),
+            Err(err) if !err.is_transient() => {
+                tracing::warn!(
+                    "Failed getting inclusion data for L1 batch #{} from shadow DA layer `{client_type}`: {err}",
+                    blob_info.l1_batch_number
+                );
+                METRICS.shadow_divergences[&DADeadLetterStage::Inclusion.as_str()].inc();
+                (None, Some(format!("{:#}", err.error)))
+            }
+            Err(err) => {
+                return Err(anyhow::Error::from(err)).with_context(|| {
+                    format!(
+                        "failed to get shadow inclusion data for blob_id: {}, batch_number: {}",
+                        blob_info.blob_id, blob_info.l1_batch_number
+                    )
+                });
+            }
+        };
+
+        let mut conn = self.pool.connection_tagged("da_dispatcher").await?;
+        conn.data_availability_dal()
+            .save_shadow_inclusion_result(
+                blob_info.l1_batch_number,
+                client_type,
+                inclusion_data.as_deref(),
+                error.as_deref(),
+            )
+            .await?;
+        drop(conn);
+
+        if inclusion_data.is_some() {
+            let inclusion_latency = Utc::now().naive_utc() - blob_info.sent_at;
+            if let Ok(latency) = inclusion_latency.to_std() {
+                METRICS.shadow_inclusion_latency.observe(latency);
+            }
+            METRICS
+                .last_shadow_included_l1_batch
+                .set(blob_info.l1_batch_number.0 as usize);
+        }
+        Ok(())
+    }
+
+    /// Polls the data availability layer for inclusion data, and saves it in the database.
+    async fn poll_for_inclusion(&self) -> anyhow::Result<()> {
+        let mut conn = self.pool.connection_tagged("da_dispatcher").await?;
+        let blob_info = conn
+            .data_availability_dal()
+            .get_first_da_blob_awaiting_inclusion()
+            .await?;
+        drop(conn);
+
+        let Some(blob_info) = blob_info else {
+            return Ok(());
+        };
+
+        let inclusion_data = match self
+            .client
+            .get_inclusion_data(blob_info.blob_id.as_str())
+            .await
+        {
+            Ok(Some(inclusion_data)) => inclusion_data,
+            Ok(None) => {
+                self.check_age(
+                    DADeadLetterStage::Inclusion,
+                    blob_info.l1_batch_number,
+                    blob_info.sent_at,
+                );
+                return Ok(());
+            }
+            Err(err) if !err.is_transient() => {
+                return self
+                    .move_to_dead_letters(
+                        blob_info.l1_batch_number,
+                        DADeadLetterStage::Inclusion,
+                        Some(blob_info.blob_id.as_str()),
+                        &err,
+                        1,
+                    )
+                    .await;
+            }
+            Err(err) => {
+                self.check_age(
+                    DADeadLetterStage::Inclusion,
+                    blob_info.l1_batch_number,
+                    blob_info.sent_at,
+                );
+                return Err(anyhow::Error::from(err)).with_context(|| {
+                    format!(
+                        "failed to get inclusion data for blob_id: {}, batch_number: {}",
+                        blob_info.blob_id, blob_info.l1_batch_number
+                    )
+                });
+            }
+        };
+        self.clear_overdue(DADeadLetterStage::Inclusion, blob_info.l1_batch_number);
+
+        let mut conn = self.pool.connection_tagged("da_dispatcher").await?;
+        conn.data_availability_dal()
+            .save_l1_batch_inclusion_data(blob_info.l1_batch_number, inclusion_data.data.as_slice())
+            .await?;
+        drop(conn);
+
+        let inclusion_latency = Utc::now().naive_utc() - blob_info.sent_at;
+        if let Ok(latency) = inclusion_latency.to_std() {
+            METRICS.inclusion_latency.observe(latency);
+        }
+        METRICS
+            .last_included_l1_batch
+            .set(blob_info.l1_batch_number.0 as usize);
+
+        tracing::info!(
+            "Received inclusion data for batch_number: {}, inclusion_latency_seconds: {}",
+            blob_info.l1_batch_number,
+            inclusion_latency.num_seconds()
+        );
+
+        Ok(())
+    }
+
+    /// Persists the dead letter for an L1 batch that has failed with a non-retriable error.
+    /// The L1 batch is skipped by the dispatcher until the dead letter is removed from the database.
+    async fn move_to_dead_letters(
+        &self,
+        l1_batch_number: L1BatchNumber,
+        stage: DADeadLetterStage,
+        blob_id: Option<&str>,
+        err: &DAError,
+        attempts: u32,
+    ) -> anyhow::Result<()> {
+        tracing::error!(
+            "L1 batch #{l1_batch_number} has failed at the DA {stage} stage after {attempts} attempt(s) \
+             with a non-retriable error, moving it to dead letters: {err}"
+        );
+
+        let mut conn = self.pool.connection_tagged("da_dispatcher").await?;
+        conn.data_availability_dal()
+            .insert_dead_letter(
+                l1_batch_number,
+                stage,
+                blob_id,
+                &format!("{:#}", err.error),
+                attempts,
+            )
+            .await?;
+        drop(conn);
+
+        METRICS.dead_lettered_l1_batches[&stage.as_str()].inc();
+        self.clear_overdue(stage, l1_batch_number);
+        self.refresh_dead_letters().await
+    }
+
+    /// Reloads dead letters from the database so that they are reflected in the health check
+    /// (including the ones persisted before a restart or removed by an operator).
+    async fn refresh_dead_letters(&self) -> anyhow::Result<()> {
+        let mut conn = self.pool.connection_tagged("da_dispatcher").await?;
+        let dead_letters = conn
+            .data_availability_dal()
+            .get_dead_letters(MAX_REPORTED_DEAD_LETTERS)
+            .await?;
+        drop(conn);
+
+        self.update_health(|details| {
+            details.dead_letters = dead_letters.into_iter().map(Into::into).collect();
+        });
+        Ok(())
+    }
+
+    /// Compares the DA costs over the budget period with the configured thresholds and reflects
+    /// the DA layers exceeding them in the health check.
+    async fn check_cost_budget(&self) -> anyhow::Result<()> {
+        let period = self.config.cost_budget_period();
+        let since = Utc::now().naive_utc()
+            - chrono::Duration::from_std(period).context("cost budget period is too large")?;
+        let mut conn = self.pool.connection_tagged("da_dispatcher").await?;
+        let costs = conn
+            .data_availability_dal()
+            .get_da_costs_since(since)
+            .await?;
+        drop(conn);
+
+        let mut exceeded_budgets = vec![];
+        for summary in &costs {
+            METRICS.period_dispatched_bytes[&summary.client_type].set(summary.blob_size_bytes);
+            METRICS.period_fee[&summary.client_type].set(summary.fee);
+            if let Some(exceeded) = ExceededCostBudget::check(&self.config, summary) {
+                tracing::warn!(
+                    "DA layer `{}` has exceeded the cost budget over the last {period:?}: \
+                     dispatched {} bytes, paid {} in fees ({} L1 batches with unknown fee)",
+                    exceeded.client_type,
+                    exceeded.dispatched_bytes,
+                    exceeded.fee,
+                    exceeded.l1_batches_with_unknown_fee
+                );
+                exceeded_budgets.push(exceeded);
+            }
+        }
+
+        self.update_health(|details| {
+            details.exceeded_cost_budgets = exceeded_budgets;
+        });
+        Ok(())
+    }
+
+    /// Flags the L1 batch as critical if it has been at the processing stage for longer than the configured maximum age.
+    fn check_age(
+        &self,
+        stage: DADeadLetterStage,
+        l1_batch_number: L1BatchNumber,
+        since: NaiveDateTime,
+    ) {
+        let age = (Utc::now().naive_utc() - since)
+            .to_std()
+            .unwrap_or_default();
+        let max_age = self.config.max_l1_batch_age();
+        if age <= max_age {
+            return;
+        }
+
+        tracing::error!(
+            "CRITICAL: L1 batch #{l1_batch_number} has been awaiting DA {stage} for {age:?}, \
+             which exceeds the maximum age {max_age:?}"
+        );
+        METRICS.overdue_l1_batch[&stage.as_str()].set(l1_batch_number.0 as usize);
+        self.update_health(|details| {
+            *details.overdue_mut(stage) = Some(OverdueL1Batch {
+                l1_batch_number,
+                age_secs: age.as_secs(),
+            });
+        });
+    }
+
+    /// Clears the critical flag for the stage if it was set for the specified L1 batch.
+    fn clear_overdue(&self, stage: DADeadLetterStage, l1_batch_number: L1BatchNumber) {
+        self.update_health(|details| {
+            let overdue = details.overdue_mut(stage);
+            if overdue
+                .as_ref()
+                .map_or(false, |overdue| overdue.l1_batch_number == l1_batch_number)
+            {
+                *overdue = None;
+                METRICS.overdue_l1_batch[&stage.as_str()].set(0);
+            }
+        });
+    }
+
+    fn update_health(&self, update: impl FnOnce(&mut DispatcherHealthDetails)) {
+        let mut details = self
+            .health_details
+            .lock()
+            .expect("health details are poisoned");
+        update(&mut details);
+        self.health_updater.update(details.health());
+    }
+}
+
+/// Error returned by [`retry()`] if the call fails with a non-retriable error or exhausts the retries.
+#[derive(Debug)]
+struct RetryError {
+    error: DAError,
+    attempts: u32,
+}
+
+async fn retry<T, Fut, F>(
+    config: &DADispatcherConfig,
+    batch_number: L1BatchNumber,
+    mut f: F,
+) -> Result<T, RetryError>
+where
+    Fut: Future<Output = Result<T, DAError>>,
+    F: FnMut() -> Fut,
+{
+    let max_retries = config.max_retries();
+    let max_backoff = config.max_retry_backoff();
+    let mut retries = 1;
+    let mut backoff = config.initial_retry_backoff().min(max_backoff);
+    loop {
+        match f().await {
+            Ok(result) => {
+                METRICS.dispatch_call_retries.observe(retries as usize);
+                return Ok(result);
+            }
+            Err(error) => {
+                if !error.is_transient() || retries > max_retries {
+                    return Err(RetryError {
+                        error,
+                        attempts: retries.into(),
+                    });
+                }
+
+                retries += 1;
+                let sleep_duration = backoff.mul_f32(rand::thread_rng().gen_range(0.8..1.2));
+                tracing::warn!(%error, "Failed DA dispatch request {retries}/{max_retries} for batch {batch_number}, retrying in {} milliseconds.", sleep_duration.as_millis());
+                tokio::time::sleep(sleep_duration).await;
+
+                backoff = (backoff * 2).min(max_backoff);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use super::*;
+
+    fn da_error(is_transient: bool) -> DAError {
+        DAError {
+            error: anyhow::anyhow!("test error"),
+            is_transient,
+        }
+    }
+
+    fn cost_summary(blob_size_bytes: u64, fee: u64) -> DACostSummary {
+        DACostSummary {
+            client_type: "test".to_owned(),
+            l1_batch_count: 10,
+            blob_size_bytes,
+            fee,
+            l1_batches_with_unknown_fee: 0,
+        }
+    }
+
+    #[test]
+    fn checking_cost_budget() {
+        let config = DADispatcherConfig::for_tests();
+        assert_eq!(
+            ExceededCostBudget::check(&config, &cost_summary(u64::MAX, u64::MAX)),
+            None
+        );
+
+        let config = DADispatcherConfig {
+            max_dispatched_bytes_per_period: Some(1_000),
+            max_fee_per_period: Some(100),
+            ..DADispatcherConfig::for_tests()
+        };
+        assert_eq!(
+            ExceededCostBudget::check(&config, &cost_summary(1_000, 100)),
+            None
+        );
+        let exceeded = ExceededCostBudget::check(&config, &cost_summary(1_001, 0)).unwrap();
+        assert_eq!(exceeded.dispatched_bytes, 1_001);
+        assert_eq!(exceeded.period_secs, 86_400);
+        let exceeded = ExceededCostBudget::check(&config, &cost_summary(0, 101)).unwrap();
+        assert_eq!(exceeded.fee, 101);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn retry_stops_on_non_retriable_error() {
+        let config = DADispatcherConfig::for_tests();
+        let calls = AtomicU32::new(0);
+        let err = retry(&config, L1BatchNumber(1), || async {
+            calls.fetch_add(1, Ordering::Relaxed);
+            Err::<(), _>(da_error(false))
+        })
+        .await
+        .unwrap_err();
+
+        assert_eq!(err.attempts, 1);
+        assert_eq!(calls.load(Ordering::Relaxed), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn retry_exhausts_retries_on_transient_errors() {
+        let config = DADispatcherConfig {
+            max_retries: Some(3),
+            ..DADispatcherConfig::for_tests()
+        };
+        let calls = AtomicU32::new(0);
+        let err = retry(&config, L1BatchNumber(1), || async {
+            calls.fetch_add(1, Ordering::Relaxed);
+            Err::<(), _>(da_error(true))
+        })
+        .await
+        .unwrap_err();
+
+        assert!(err.error.is_transient());
+        assert_eq!(err.attempts, 4);
+        assert_eq!(calls.load(Ordering::Relaxed), 4);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn retry_recovers_after_transient_error() {
+        let config = DADispatcherConfig::for_tests();
+        let calls = AtomicU32::new(0);
+        let result = retry(&config, L1BatchNumber(1), || async {
+            if calls.fetch_add(1, Ordering::Relaxed) == 0 {
+                Err(da_error(true))
+            } else {
+                Ok(42)
+            }
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(result, 42);
+        assert_eq!(calls.load(Ordering::Relaxed), 2);
+    }
+}
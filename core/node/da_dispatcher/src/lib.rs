@@ -0,0 +1,4 @@
+pub use self::da_dispatcher::DataAvailabilityDispatcher;
+
+mod da_dispatcher;
+mod metrics;
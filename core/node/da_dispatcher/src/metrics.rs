@@ -0,0 +1,62 @@
+use std::time::Duration;
+
+use vise::{Buckets, Counter, Gauge, Histogram, LabeledFamily, Metrics, Unit};
+
+/// Buckets for `blob_dispatch_latency` (from 0.1 to 120 seconds).
+const DISPATCH_LATENCIES: Buckets =
+    Buckets::values(&[0.1, 0.5, 1.0, 2.0, 5.0, 10.0, 20.0, 30.0, 60.0, 120.0]);
+
+#[derive(Debug, Metrics)]
+#[metrics(prefix = "server_da_dispatcher")]
+pub(super) struct DataAvailabilityDispatcherMetrics {
+    /// Latency of the dispatch of the blob.
+    #[metrics(buckets = DISPATCH_LATENCIES, unit = Unit::Seconds)]
+    pub blob_dispatch_latency: Histogram<Duration>,
+    /// The duration between the moment when the blob is dispatched and the moment when it is included.
+    #[metrics(buckets = Buckets::LATENCIES, unit = Unit::Seconds)]
+    pub inclusion_latency: Histogram<Duration>,
+    /// Size of the dispatched blob.
+    /// Buckets are bytes ranging from 1 KB to 16 MB, which has to satisfy all blob size values.
+    #[metrics(buckets = Buckets::exponential(1_024.0..=16.0 * 1_024.0 * 1_024.0, 2.0), unit = Unit::Bytes)]
+    pub blob_size: Histogram<usize>,
+    /// Number of retries of the DA client calls needed to dispatch a blob.
+    #[metrics(buckets = Buckets::linear(0.0..=10.0, 1.0))]
+    pub dispatch_call_retries: Histogram<usize>,
+    /// Last L1 batch that was dispatched to the DA layer.
+    pub last_dispatched_l1_batch: Gauge<usize>,
+    /// Last L1 batch that has its inclusion finalized by the DA layer.
+    pub last_included_l1_batch: Gauge<usize>,
+    /// Number of L1 batches moved to the dead letter state, grouped by the processing stage.
+    #[metrics(labels = ["stage"])]
+    pub dead_lettered_l1_batches: LabeledFamily<&'static str, Counter>,
+    /// Number of the L1 batch that exceeds the maximum age at the processing stage, or 0 if there is no such batch.
+    #[metrics(labels = ["stage"])]
+    pub overdue_l1_batch: LabeledFamily<&'static str, Gauge<usize>>,
+    /// Total size of the pubdata dispatched to the DA layer.
+    #[metrics(labels = ["client"], unit = Unit::Bytes)]
+    pub dispatched_bytes: LabeledFamily<&'static str, Counter>,
+    /// Total fee paid to the DA layer, in the smallest units of the layer's native token.
+    /// Only includes the dispatches with the fee reported by the DA client.
+    #[metrics(labels = ["client"])]
+    pub dispatch_fee: LabeledFamily<&'static str, Counter>,
+    /// Last L1 batch that was dispatched to the shadow DA layer.
+    pub last_shadow_dispatched_l1_batch: Gauge<usize>,
+    /// Last L1 batch that has its inclusion finalized by the shadow DA layer.
+    pub last_shadow_included_l1_batch: Gauge<usize>,
+    /// The duration between the moment when the blob is dispatched to the shadow DA layer and the moment when it is included.
+    #[metrics(buckets = Buckets::LATENCIES, unit = Unit::Seconds)]
+    pub shadow_inclusion_latency: Histogram<Duration>,
+    /// Number of L1 batches processed by the primary DA layer, but failed by the shadow DA layer,
+    /// grouped by the processing stage.
+    #[metrics(labels = ["stage"])]
+    pub shadow_divergences: LabeledFamily<&'static str, Counter>,
+    /// Size of the pubdata dispatched to the DA layer over the cost budget period.
+    #[metrics(labels = ["client"], unit = Unit::Bytes)]
+    pub period_dispatched_bytes: LabeledFamily<String, Gauge<u64>>,
+    /// Fee paid to the DA layer over the cost budget period.
+    #[metrics(labels = ["client"])]
+    pub period_fee: LabeledFamily<String, Gauge<u64>>,
+}
+
+#[vise::register]
+pub(super) static METRICS: vise::Global<DataAvailabilityDispatcherMetrics> = vise::Global::new();
@@ -0,0 +1,6 @@
+//! DA inclusion verifier for external nodes.
+
+pub use self::verifier::DAInclusionVerifier;
+
+mod metrics;
+mod verifier;
@@ -0,0 +1,18 @@
+use std::time::Duration;
+
+use vise::{Buckets, Counter, Histogram, Metrics, Unit};
+
+#[derive(Debug, Metrics)]
+#[metrics(prefix = "external_node_da_verifier")]
+pub(super) struct DAVerifierMetrics {
+    /// Latency of retrieving a blob from the DA layer.
+    #[metrics(buckets = Buckets::LATENCIES, unit = Unit::Seconds)]
+    pub blob_retrieval_latency: Histogram<Duration>,
+    /// Number of L1 batches with the custom DA source that were successfully verified.
+    pub verified_l1_batches: Counter,
+    /// Number of L1 batches with the custom DA source that failed verification.
+    pub failed_l1_batches: Counter,
+}
+
+#[vise::register]
+pub(super) static METRICS: vise::Global<DAVerifierMetrics> = vise::Global::new();
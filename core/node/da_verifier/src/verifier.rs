@@ -0,0 +1,407 @@
+use std::time::Duration;
+
+use anyhow::Context as _;
+use serde::Serialize;
+use tokio::sync::watch;
+use zksync_consistency_checker::{custom_da_inclusion_data, detect_da, ConsistencyChecker};
+use zksync_da_client::{types::DAError, DataAvailabilityClient};
+use zksync_dal::{Connection, ConnectionPool, Core, CoreDal};
+use zksync_eth_client::{
+    clients::{DynClient, L1},
+    EnrichedClientError, EthInterface,
+};
+use zksync_health_check::{Health, HealthStatus, HealthUpdater, ReactiveHealthCheck};
+use zksync_shared_metrics::{CheckerComponent, EN_METRICS};
+use zksync_types::{ethabi, pubdata_da::PubdataDA, L1BatchNumber, ProtocolVersionId, H256};
+
+use crate::metrics::METRICS;
+
+#[cfg(test)]
+mod tests;
+
+/// Maximum number of failed L1 batches reported in the health check details.
+const MAX_REPORTED_FAILED_BATCHES: usize = 10;
+
+#[derive(Debug, thiserror::Error)]
+enum VerifyError {
+    #[error("Web3 error communicating with L1")]
+    Web3(#[from] EnrichedClientError),
+    #[error("error communicating with the DA layer")]
+    DataAvailability(#[source] DAError),
+    /// Error caused by the data committed on L1 or published to the DA layer not matching local data.
+    #[error("failed verifying DA inclusion")]
+    Validation(anyhow::Error),
+    /// Error caused by violating invariants internal to *this* node.
+    #[error("internal error")]
+    Internal(anyhow::Error),
+}
+
+impl VerifyError {
+    fn is_transient(&self) -> bool {
+        match self {
+            Self::Web3(err) => err.is_transient(),
+            Self::DataAvailability(err) => err.is_transient,
+            _ => false,
+        }
+    }
+}
+
+impl From<DAError> for VerifyError {
+    fn from(err: DAError) -> Self {
+        if err.is_transient {
+            Self::DataAvailability(err)
+        } else {
+            Self::Validation(err.error)
+        }
+    }
+}
+
+/// Outcome of verifying a single L1 batch.
+#[derive(Debug, PartialEq)]
+enum VerificationOutcome {
+    Verified,
+    /// The L1 batch was committed using a DA source other than the custom one.
+    Skipped,
+}
+
+#[derive(Debug, Serialize)]
+struct FailedL1Batch {
+    l1_batch_number: L1BatchNumber,
+    error: String,
+}
+
+/// Health details reported by [`DAInclusionVerifier`].
+#[derive(Debug, Default, Serialize)]
+struct DAVerifierDetails {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    last_verified_batch: Option<L1BatchNumber>,
+    /// Latest L1 batches that failed verification.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    failed_batches: Vec<FailedL1Batch>,
+}
+
+impl DAVerifierDetails {
+    fn health(&self) -> Health {
+        let health = if self.failed_batches.is_empty() {
+            Health::from(HealthStatus::Ready)
+        } else {
+            Health::from(HealthStatus::Affected).with_cause("failed_verification")
+        };
+        health
+            .with_counter("failed_batches", self.failed_batches.len() as u64)
+            .with_details(self)
+    }
+
+    fn report_failed_batch(&mut self, l1_batch_number: L1BatchNumber, err: &anyhow::Error) {
+        if self.failed_batches.len() == MAX_REPORTED_FAILED_BATCHES {
+            self.failed_batches.remove(0);
+        }
+        self.failed_batches.push(FailedL1Batch {
+            l1_batch_number,
+            error: format!("{err:#}"),
+        });
+    }
+}
+
+/// Local data necessary to verify DA inclusion of an L1 batch.
+#[derive(Debug)]
+struct LocalL1BatchData {
+    commit_tx_hash: H256,
+    protocol_version: ProtocolVersionId,
+    pubdata: Vec<u8>,
+}
+
+impl LocalL1BatchData {
+    /// Returns `Ok(None)` if Postgres doesn't contain all data necessary to verify the batch yet.
+    async fn new(
+        storage: &mut Connection<'_, Core>,
+        batch_number: L1BatchNumber,
+    ) -> anyhow::Result<Option<Self>> {
+        let Some(commit_tx_id) = storage
+            .blocks_dal()
+            .get_eth_commit_tx_id(batch_number)
+            .await?
+        else {
+            return Ok(None);
+        };
+        let commit_tx_hash = storage
+            .eth_sender_dal()
+            .get_confirmed_tx_hash_by_eth_tx_id(commit_tx_id as u32)
+            .await?
+            .with_context(|| {
+                format!("Commit tx hash not found in the database for tx id {commit_tx_id}")
+            })?;
+
+        let Some(l1_batch) = storage
+            .blocks_dal()
+            .get_l1_batch_metadata(batch_number)
+            .await?
+        else {
+            return Ok(None);
+        };
+        let protocol_version = l1_batch
+            .header
+            .protocol_version
+            .with_context(|| format!("L1 batch #{batch_number} has no protocol version"))?;
+        let pubdata = l1_batch
+            .header
+            .pubdata_input
+            .clone()
+            .unwrap_or_else(|| l1_batch.construct_pubdata());
+
+        Ok(Some(Self {
+            commit_tx_hash,
+            protocol_version,
+            pubdata,
+        }))
+    }
+}
+
+/// Checks that the blob retrieved from the DA layer matches the locally derived pubdata. The blob may be padded
+/// with zero bytes by the DA layer.
+fn check_blob(blob: &[u8], pubdata: &[u8]) -> anyhow::Result<()> {
+    anyhow::ensure!(
+        blob.len() >= pubdata.len(),
+        "blob retrieved from the DA layer is shorter than the local pubdata ({} vs {} bytes)",
+        blob.len(),
+        pubdata.len()
+    );
+    let (blob, padding) = blob.split_at(pubdata.len());
+    if let Some(pos) = blob.iter().zip(pubdata).position(|(a, b)| a != b) {
+        anyhow::bail!(
+            "blob retrieved from the DA layer differs from the local pubdata at byte {pos}"
+        );
+    }
+    anyhow::ensure!(
+        padding.iter().all(|&byte| byte == 0),
+        "blob retrieved from the DA layer has unexpected trailing data ({} bytes)",
+        padding.len()
+    );
+    Ok(())
+}
+
+/// External node component verifying that the pubdata of L1 batches committed with the custom DA source
+/// is available on the DA layer and matches the pubdata derived locally.
+#[derive(Debug)]
+pub struct DAInclusionVerifier {
+    /// ABI of the zkSync contract
+    contract: ethabi::Contract,
+    /// How many past batches to check when starting
+    max_batches_to_recheck: u32,
+    sleep_interval: Duration,
+    pool: ConnectionPool<Core>,
+    l1_client: Box<DynClient<L1>>,
+    da_client: Box<dyn DataAvailabilityClient>,
+    health_check: ReactiveHealthCheck,
+    health_updater: HealthUpdater,
+    details: DAVerifierDetails,
+}
+
+impl DAInclusionVerifier {
+    const DEFAULT_SLEEP_INTERVAL: Duration = Duration::from_secs(5);
+
+    pub fn new(
+        l1_client: Box<DynClient<L1>>,
+        da_client: Box<dyn DataAvailabilityClient>,
+        max_batches_to_recheck: u32,
+        pool: ConnectionPool<Core>,
+    ) -> Self {
+        let (health_check, health_updater) = ReactiveHealthCheck::new("da_verifier");
+        Self {
+            contract: zksync_contracts::hyperchain_contract(),
+            max_batches_to_recheck,
+            sleep_interval: Self::DEFAULT_SLEEP_INTERVAL,
+            pool,
+            l1_client,
+            da_client,
+            health_check,
+            health_updater,
+            details: DAVerifierDetails::default(),
+        }
+    }
+
+    /// Returns the health check for this component.
+    pub fn health_check(&self) -> &ReactiveHealthCheck {
+        &self.health_check
+    }
+
+    async fn verify_batch(
+        &self,
+        batch_number: L1BatchNumber,
+        local: &LocalL1BatchData,
+    ) -> Result<VerificationOutcome, VerifyError> {
+        if local.protocol_version.is_pre_shared_bridge() {
+            // Custom DA is not supported by the corresponding L1 contracts.
+            return Ok(VerificationOutcome::Skipped);
+        }
+
+        let commit_tx_hash = local.commit_tx_hash;
+        let commit_tx_status = self
+            .l1_client
+            .get_tx_status(commit_tx_hash)
+            .await?
+            .with_context(|| format!("receipt for tx {commit_tx_hash:?} not found on L1"))
+            .map_err(VerifyError::Validation)?;
+        if !commit_tx_status.success {
+            let err = anyhow::anyhow!("main node gave us a failed commit tx {commit_tx_hash:?}");
+            return Err(VerifyError::Validation(err));
+        }
+        // We can't get tx calldata from the DB because it can be fake.
+        let commit_tx = self
+            .l1_client
+            .get_tx(commit_tx_hash)
+            .await?
+            .with_context(|| format!("commit transaction {commit_tx_hash:?} not found on L1"))
+            .map_err(VerifyError::Internal)?;
+
+        let commit_function = self
+            .contract
+            .function("commitBatchesSharedBridge")
+            .context("L1 contract does not have `commitBatchesSharedBridge` function")
+            .map_err(VerifyError::Internal)?;
+        let commitment = ConsistencyChecker::extract_commit_data(
+            &commit_tx.input.0,
+            commit_function,
+            batch_number,
+        )
+        .with_context(|| {
+            format!("failed extracting commit data for transaction {commit_tx_hash:?}")
+        })
+        .map_err(VerifyError::Validation)?;
+
+        let da = detect_da(local.protocol_version, &commitment)
+            .context("cannot detect DA source from reference commitment token")
+            .map_err(VerifyError::Validation)?;
+        if da != PubdataDA::Custom {
+            return Ok(VerificationOutcome::Skipped);
+        }
+        let inclusion_data =
+            custom_da_inclusion_data(&commitment).map_err(VerifyError::Validation)?;
+
+        let latency = METRICS.blob_retrieval_latency.start();
+        let blob = self
+            .da_client
+            .get_blob(inclusion_data)
+            .await?
+            .context("blob referenced by the inclusion data is not found on the DA layer")
+            .map_err(VerifyError::Validation)?;
+        latency.observe();
+
+        check_blob(&blob, &local.pubdata).map_err(VerifyError::Validation)?;
+        Ok(VerificationOutcome::Verified)
+    }
+
+    async fn first_batch_to_check(&self) -> anyhow::Result<Option<L1BatchNumber>> {
+        let mut storage = self.pool.connection().await?;
+        let Some(earliest_l1_batch_number) = storage
+            .blocks_dal()
+            .get_earliest_l1_batch_number_with_metadata()
+            .await?
+        else {
+            return Ok(None);
+        };
+        let last_committed_batch = storage
+            .blocks_dal()
+            .get_number_of_last_l1_batch_committed_on_eth()
+            .await?
+            .unwrap_or(earliest_l1_batch_number);
+        let first_batch_to_check = L1BatchNumber(
+            last_committed_batch
+                .0
+                .saturating_sub(self.max_batches_to_recheck),
+        );
+        // The genesis batch is not committed on L1.
+        Ok(Some(
+            first_batch_to_check
+                .max(earliest_l1_batch_number)
+                .max(L1BatchNumber(1)),
+        ))
+    }
+
+    pub async fn run(mut self, mut stop_receiver: watch::Receiver<bool>) -> anyhow::Result<()> {
+        tracing::info!(
+            "Starting DA inclusion verifier with DA client {:?}, sleep interval: {:?}, \
+             max historic L1 batches to check: {}",
+            self.da_client,
+            self.sleep_interval,
+            self.max_batches_to_recheck
+        );
+        self.health_updater.update(self.details.health());
+
+        let mut batch_number = loop {
+            if *stop_receiver.borrow() {
+                tracing::info!("Stop signal received, DA inclusion verifier is shutting down");
+                return Ok(());
+            }
+            if let Some(number) = self.first_batch_to_check().await? {
+                break number;
+            }
+            tokio::time::timeout(self.sleep_interval, stop_receiver.changed())
+                .await
+                .ok();
+        };
+        tracing::info!("Starting DA inclusion checks from L1 batch #{batch_number}");
+
+        while !*stop_receiver.borrow_and_update() {
+            let mut storage = self.pool.connection().await?;
+            let local = LocalL1BatchData::new(&mut storage, batch_number).await?;
+            drop(storage);
+            let Some(local) = local else {
+                if tokio::time::timeout(self.sleep_interval, stop_receiver.changed())
+                    .await
+                    .is_ok()
+                {
+                    break;
+                }
+                continue;
+            };
+
+            match self.verify_batch(batch_number, &local).await {
+                Ok(outcome) => {
+                    if outcome == VerificationOutcome::Verified {
+                        tracing::info!("Verified DA inclusion of L1 batch #{batch_number}");
+                        METRICS.verified_l1_batches.inc();
+                    } else {
+                        tracing::debug!(
+                            "L1 batch #{batch_number} is not committed with custom DA; skipping"
+                        );
+                    }
+                    EN_METRICS.last_correct_batch[&CheckerComponent::DaVerifier]
+                        .set(batch_number.0.into());
+                    self.details.last_verified_batch = Some(batch_number);
+                    self.health_updater.update(self.details.health());
+                    batch_number += 1;
+                }
+                Err(VerifyError::Validation(err)) => {
+                    tracing::warn!(
+                        "Failed verifying DA inclusion of L1 batch #{batch_number}: {err:?}"
+                    );
+                    METRICS.failed_l1_batches.inc();
+                    self.details.report_failed_batch(batch_number, &err);
+                    self.health_updater.update(self.details.health());
+                    batch_number += 1;
+                }
+                Err(err) if err.is_transient() => {
+                    tracing::warn!(
+                        "Transient error while verifying L1 batch #{batch_number}; will retry after a delay: {:#}",
+                        anyhow::Error::from(err)
+                    );
+                    if tokio::time::timeout(self.sleep_interval, stop_receiver.changed())
+                        .await
+                        .is_ok()
+                    {
+                        break;
+                    }
+                }
+                Err(err) => {
+                    let context =
+                        format!("failed verifying DA inclusion of L1 batch #{batch_number}");
+                    return Err(anyhow::Error::from(err).context(context));
+                }
+            }
+        }
+
+        tracing::info!("Stop signal received, DA inclusion verifier is shutting down");
+        Ok(())
+    }
+}
@@ -0,0 +1,51 @@
+use assert_matches::assert_matches;
+
+use super::*;
+
+#[test]
+fn checking_blob_against_pubdata() {
+    let pubdata = [1, 2, 3, 0, 4];
+    check_blob(&pubdata, &pubdata).unwrap();
+    check_blob(&[1, 2, 3, 0, 4, 0, 0], &pubdata).unwrap();
+
+    let err = check_blob(&[1, 2, 3], &pubdata).unwrap_err().to_string();
+    assert!(err.contains("shorter"), "{err}");
+    let err = check_blob(&[1, 2, 5, 0, 4], &pubdata)
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("at byte 2"), "{err}");
+    let err = check_blob(&[1, 2, 3, 0, 4, 0, 1], &pubdata)
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("trailing data"), "{err}");
+}
+
+#[test]
+fn converting_da_errors() {
+    let err = VerifyError::from(DAError {
+        error: anyhow::anyhow!("timeout"),
+        is_transient: true,
+    });
+    assert!(err.is_transient());
+    assert_matches!(err, VerifyError::DataAvailability(_));
+
+    let err = VerifyError::from(DAError {
+        error: anyhow::anyhow!("malformed inclusion data"),
+        is_transient: false,
+    });
+    assert!(!err.is_transient());
+    assert_matches!(err, VerifyError::Validation(_));
+}
+
+#[test]
+fn reporting_failed_batches() {
+    let mut details = DAVerifierDetails::default();
+    assert_matches!(details.health().status(), HealthStatus::Ready);
+
+    for number in 1..=(MAX_REPORTED_FAILED_BATCHES as u32 + 2) {
+        details.report_failed_batch(L1BatchNumber(number), &anyhow::anyhow!("blob mismatch"));
+    }
+    assert_eq!(details.failed_batches.len(), MAX_REPORTED_FAILED_BATCHES);
+    assert_eq!(details.failed_batches[0].l1_batch_number, L1BatchNumber(3));
+    assert_matches!(details.health().status(), HealthStatus::Affected);
+}
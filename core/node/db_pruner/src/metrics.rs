@@ -20,6 +20,8 @@ enum PrunedEntityType {
     Event,
     L2ToL1Log,
     CallTrace,
+    L2BlockCertificate,
+    BatchAttestation,
 }
 
 const ENTITY_COUNT_BUCKETS: Buckets = Buckets::values(&[
@@ -49,6 +51,8 @@ impl DbPrunerMetrics {
             deleted_events,
             deleted_call_traces,
             deleted_l2_to_l1_logs,
+            deleted_l2_block_certificates,
+            deleted_batch_attestations,
         } = stats;
         let deleted_storage_logs =
             deleted_storage_logs_from_past_batches + deleted_storage_logs_from_pruned_batches;
@@ -56,7 +60,8 @@ impl DbPrunerMetrics {
             "Performed pruning of database, deleted {deleted_l1_batches} L1 batches, {deleted_l2_blocks} L2 blocks, \
              {deleted_storage_logs} storage logs ({deleted_storage_logs_from_pruned_batches} from pruned batches + \
              {deleted_storage_logs_from_past_batches} from past batches), \
-             {deleted_events} events, {deleted_call_traces} call traces, {deleted_l2_to_l1_logs} L2-to-L1 logs"
+             {deleted_events} events, {deleted_call_traces} call traces, {deleted_l2_to_l1_logs} L2-to-L1 logs, \
+             {deleted_l2_block_certificates} L2 block certificates, {deleted_batch_attestations} L1 batch attestations"
         );
 
         self.deleted_entities[&PrunedEntityType::L1Batch].observe(deleted_l1_batches);
@@ -68,6 +73,10 @@ impl DbPrunerMetrics {
         self.deleted_entities[&PrunedEntityType::Event].observe(deleted_events);
         self.deleted_entities[&PrunedEntityType::L2ToL1Log].observe(deleted_l2_to_l1_logs);
         self.deleted_entities[&PrunedEntityType::CallTrace].observe(deleted_call_traces);
+        self.deleted_entities[&PrunedEntityType::L2BlockCertificate]
+            .observe(deleted_l2_block_certificates);
+        self.deleted_entities[&PrunedEntityType::BatchAttestation]
+            .observe(deleted_batch_attestations);
     }
 }
 
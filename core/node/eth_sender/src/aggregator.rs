@@ -3,12 +3,20 @@ use std::sync::Arc;
 use zksync_config::configs::eth_sender::{ProofSendingMode, SenderConfig};
 use zksync_contracts::BaseSystemContractsHashes;
 use zksync_dal::{Connection, Core, CoreDal};
-use zksync_l1_contract_interface::i_executor::methods::{ExecuteBatches, ProveBatches};
+use zksync_l1_contract_interface::{
+    i_executor::{
+        methods::{ExecuteBatches, ProveBatches},
+        structures::CommitBatchInfo,
+    },
+    Tokenizable,
+};
+use zksync_node_fee_model::l1_gas_price::L1TxParamsProvider;
 use zksync_object_store::{ObjectStore, ObjectStoreError};
 use zksync_prover_interface::outputs::L1BatchProofForL1;
 use zksync_types::{
     aggregated_operations::AggregatedActionType,
     commitment::{L1BatchCommitmentMode, L1BatchWithMetadata},
+    ethabi,
     helpers::unix_timestamp_ms,
     protocol_version::{L1VerifierConfig, ProtocolSemanticVersion},
     pubdata_da::PubdataDA,
@@ -17,15 +25,18 @@ use zksync_types::{
 
 use super::{
     aggregated_operations::AggregatedOperation,
+    metrics::METRICS,
     publish_criterion::{
         DataSizeCriterion, GasCriterion, L1BatchPublishCriterion, NumberCriterion,
-        TimestampDeadlineCriterion,
+        TimestampDeadlineCriterion, STORED_BLOCK_INFO_SIZE,
     },
 };
 
 #[derive(Debug)]
 pub struct Aggregator {
     commit_criteria: Vec<Box<dyn L1BatchPublishCriterion>>,
+    /// Commit criteria used when commit operations fall back from blobs to calldata.
+    calldata_commit_criteria: Vec<Box<dyn L1BatchPublishCriterion>>,
     proof_criteria: Vec<Box<dyn L1BatchPublishCriterion>>,
     execute_criteria: Vec<Box<dyn L1BatchPublishCriterion>>,
     config: SenderConfig,
@@ -38,6 +49,9 @@ pub struct Aggregator {
     operate_4844_mode: bool,
     pubdata_da: PubdataDA,
     commitment_mode: L1BatchCommitmentMode,
+    /// Source of the current blob base fee; required for falling back to calldata
+    /// if the blob base fee exceeds the configured threshold.
+    l1_tx_params: Option<Arc<dyn L1TxParamsProvider>>,
 }
 
 impl Aggregator {
@@ -50,27 +64,12 @@ impl Aggregator {
         let pubdata_da = config.pubdata_sending_mode.into();
 
         Self {
-            commit_criteria: vec![
-                Box::from(NumberCriterion {
-                    op: AggregatedActionType::Commit,
-                    limit: config.max_aggregated_blocks_to_commit,
-                }),
-                Box::from(GasCriterion::new(
-                    AggregatedActionType::Commit,
-                    config.max_aggregated_tx_gas,
-                )),
-                Box::from(DataSizeCriterion {
-                    op: AggregatedActionType::Commit,
-                    data_limit: config.max_eth_tx_data_size,
-                    pubdata_da,
-                    commitment_mode,
-                }),
-                Box::from(TimestampDeadlineCriterion {
-                    op: AggregatedActionType::Commit,
-                    deadline_seconds: config.aggregated_block_commit_deadline,
-                    max_allowed_lag: Some(config.timestamp_criteria_max_allowed_lag),
-                }),
-            ],
+            commit_criteria: Self::commit_criteria(&config, pubdata_da, commitment_mode),
+            calldata_commit_criteria: Self::commit_criteria(
+                &config,
+                PubdataDA::Calldata,
+                commitment_mode,
+            ),
             proof_criteria: vec![
                 Box::from(NumberCriterion {
                     op: AggregatedActionType::PublishProofOnchain,
@@ -109,9 +108,45 @@ impl Aggregator {
             operate_4844_mode,
             pubdata_da,
             commitment_mode,
+            l1_tx_params: None,
         }
     }
 
+    /// Sets the source of the blob base fee used to decide whether commit operations
+    /// should fall back to calldata (see `SenderConfig::blob_base_fee_calldata_fallback_threshold`).
+    pub fn with_l1_tx_params(mut self, l1_tx_params: Arc<dyn L1TxParamsProvider>) -> Self {
+        self.l1_tx_params = Some(l1_tx_params);
+        self
+    }
+
+    fn commit_criteria(
+        config: &SenderConfig,
+        pubdata_da: PubdataDA,
+        commitment_mode: L1BatchCommitmentMode,
+    ) -> Vec<Box<dyn L1BatchPublishCriterion>> {
+        vec![
+            Box::from(NumberCriterion {
+                op: AggregatedActionType::Commit,
+                limit: config.max_aggregated_blocks_to_commit,
+            }),
+            Box::from(GasCriterion::new(
+                AggregatedActionType::Commit,
+                config.max_aggregated_tx_gas,
+            )),
+            Box::from(DataSizeCriterion {
+                op: AggregatedActionType::Commit,
+                data_limit: config.max_eth_tx_data_size,
+                pubdata_da,
+                commitment_mode,
+            }),
+            Box::from(TimestampDeadlineCriterion {
+                op: AggregatedActionType::Commit,
+                deadline_seconds: config.aggregated_block_commit_deadline,
+                max_allowed_lag: Some(config.timestamp_criteria_max_allowed_lag),
+            }),
+        ]
+    }
+
     pub async fn get_next_ready_operation(
         &mut self,
         storage: &mut Connection<'_, Core>,
@@ -221,6 +256,14 @@ impl Aggregator {
                 .unwrap()
         };
 
+        // With a custom DA layer, the batch pubdata must be included in the DA layer before the commit,
+        // since the commit transaction carries the inclusion data.
+        let ready_for_commit_l1_batches = if self.pubdata_da == PubdataDA::Custom {
+            Self::attach_da_inclusion_data(storage, ready_for_commit_l1_batches).await
+        } else {
+            ready_for_commit_l1_batches
+        };
+
         // Check that the L1 batches that are selected are sequential
         ready_for_commit_l1_batches
             .iter()
@@ -232,19 +275,99 @@ impl Aggregator {
                 }
             });
 
+        let pubdata_da = self.commit_pubdata_da(ready_for_commit_l1_batches.first());
+        let commit_criteria = if pubdata_da == self.pubdata_da {
+            &mut self.commit_criteria
+        } else {
+            &mut self.calldata_commit_criteria
+        };
         let batches = extract_ready_subrange(
             storage,
-            &mut self.commit_criteria,
+            commit_criteria,
             ready_for_commit_l1_batches,
             last_sealed_batch,
         )
         .await;
 
         batches.map(|batches| {
-            AggregatedOperation::Commit(last_committed_l1_batch, batches, self.pubdata_da)
+            if pubdata_da != self.pubdata_da {
+                METRICS.commit_calldata_fallbacks.inc();
+            }
+            AggregatedOperation::Commit(last_committed_l1_batch, batches, pubdata_da)
         })
     }
 
+    /// Returns the pubdata DA to commit L1 batches starting from `first_l1_batch` with. This is the configured DA,
+    /// except for the blobs mode, in which commits fall back to calldata if the blob base fee exceeds the configured
+    /// threshold and the first L1 batch fits into a calldata commit transaction.
+    pub(super) fn commit_pubdata_da(
+        &self,
+        first_l1_batch: Option<&L1BatchWithMetadata>,
+    ) -> PubdataDA {
+        if self.pubdata_da != PubdataDA::Blobs {
+            return self.pubdata_da;
+        }
+        let (Some(threshold), Some(l1_tx_params), Some(first_l1_batch)) = (
+            self.config.blob_base_fee_calldata_fallback_threshold,
+            &self.l1_tx_params,
+            first_l1_batch,
+        ) else {
+            return self.pubdata_da;
+        };
+
+        let blob_base_fee = l1_tx_params.get_blob_base_fee();
+        if blob_base_fee <= threshold {
+            return self.pubdata_da;
+        }
+
+        let commit_token =
+            CommitBatchInfo::new(self.commitment_mode, first_l1_batch, PubdataDA::Calldata)
+                .into_token();
+        let calldata_size = ethabi::encode(&[commit_token]).len() + STORED_BLOCK_INFO_SIZE;
+        if calldata_size > self.config.max_eth_tx_data_size {
+            tracing::warn!(
+                "Blob base fee {blob_base_fee} exceeds calldata fallback threshold {threshold}, but L1 batch #{} \
+                 requires {calldata_size} bytes of calldata (limit: {}); committing it with blobs",
+                first_l1_batch.header.number,
+                self.config.max_eth_tx_data_size
+            );
+            return self.pubdata_da;
+        }
+
+        tracing::info!(
+            "Blob base fee {blob_base_fee} exceeds calldata fallback threshold {threshold}; \
+             committing L1 batches starting from #{} with calldata",
+            first_l1_batch.header.number
+        );
+        PubdataDA::Calldata
+    }
+
+    /// Attaches the DA inclusion data to the provided L1 batches. Stops at the first batch for which
+    /// the inclusion data is not available yet, so that only a prefix of `l1_batches` is returned.
+    async fn attach_da_inclusion_data(
+        storage: &mut Connection<'_, Core>,
+        l1_batches: Vec<L1BatchWithMetadata>,
+    ) -> Vec<L1BatchWithMetadata> {
+        let mut included_l1_batches = Vec::with_capacity(l1_batches.len());
+        for mut batch in l1_batches {
+            let inclusion_data = storage
+                .data_availability_dal()
+                .get_l1_batch_inclusion_data(batch.header.number)
+                .await
+                .unwrap();
+            let Some(inclusion_data) = inclusion_data else {
+                tracing::debug!(
+                    "L1 batch #{} is not included in the DA layer yet",
+                    batch.header.number
+                );
+                break;
+            };
+            batch.metadata.da_inclusion_data = Some(inclusion_data);
+            included_l1_batches.push(batch);
+        }
+        included_l1_batches
+    }
+
     async fn load_dummy_proof_operations(
         storage: &mut Connection<'_, Core>,
         limit: usize,
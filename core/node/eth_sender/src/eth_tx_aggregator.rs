@@ -455,7 +455,7 @@ impl EthTxAggregator {
                     )
                 };
 
-                let l1_batch_for_sidecar = if PubdataDA::Blobs == self.aggregator.pubdata_da() {
+                let l1_batch_for_sidecar = if PubdataDA::Blobs == *pubdata_da {
                     Some(l1_batches[0].clone())
                 } else {
                     None
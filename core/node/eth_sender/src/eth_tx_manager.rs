@@ -92,6 +92,7 @@ impl EthTxManager {
         None
     }
 
+    #[tracing::instrument(skip_all, fields(eth_tx.id = tx.id, eth_tx.tx_type = %tx.tx_type))]
     pub(crate) async fn send_eth_tx(
         &mut self,
         storage: &mut Connection<'_, Core>,
@@ -211,6 +212,7 @@ impl EthTxManager {
         Ok(signed_tx.hash)
     }
 
+    #[tracing::instrument(skip(self, storage, raw_tx))]
     async fn send_raw_transaction(
         &self,
         storage: &mut Connection<'_, Core>,
@@ -484,6 +486,7 @@ impl EthTxManager {
         METRICS
             .track_eth_tx_metrics(storage, BlockL1Stage::Mined, tx)
             .await;
+        METRICS.track_finality_lag(storage, tx).await;
 
         if gas_used > U256::from(tx.predicted_gas_cost) {
             tracing::error!(
@@ -2,7 +2,10 @@
 
 use std::{fmt, time::Duration};
 
-use vise::{Buckets, Counter, EncodeLabelSet, EncodeLabelValue, Family, Gauge, Histogram, Metrics};
+use chrono::Utc;
+use vise::{
+    Buckets, Counter, EncodeLabelSet, EncodeLabelValue, Family, Gauge, Histogram, Metrics, Unit,
+};
 use zksync_dal::{Connection, Core, CoreDal};
 use zksync_shared_metrics::{BlockL1Stage, BlockStage, APP_METRICS};
 use zksync_types::{aggregated_operations::AggregatedActionType, eth_sender::EthTx};
@@ -71,6 +74,12 @@ impl From<(AggregatedActionType, &'static str)> for AggregationReasonLabels {
 const FEE_BUCKETS: Buckets = Buckets::values(&[
     1e7, 2e7, 5e7, 1e8, 2e8, 5e8, 1e9, 2e9, 5e9, 1e10, 2e10, 5e10, 1e11, 2e11, 5e11,
 ]);
+/// Buckets for the L1 batch finality lag (10s – 1 day). Execution can be delayed by several hours on purpose,
+/// so the buckets are quite coarse.
+const FINALITY_LAG_BUCKETS: Buckets = Buckets::values(&[
+    10.0, 30.0, 60.0, 120.0, 300.0, 600.0, 1_200.0, 1_800.0, 3_600.0, 7_200.0, 10_800.0, 21_600.0,
+    43_200.0, 86_400.0,
+]);
 /// Roughly exponential buckets for gas (10k – 50M).
 const GAS_BUCKETS: Buckets =
     Buckets::values(&[1e4, 2e4, 5e4, 1e5, 2e5, 5e5, 1e6, 2e6, 5e6, 1e7, 2e7, 5e7]);
@@ -89,6 +98,8 @@ pub(super) struct EthSenderMetrics {
     pub block_range_size: Family<ActionTypeLabel, Histogram<u64>>,
     /// Number of transactions resent by the Ethereum sender.
     pub transaction_resent: Counter,
+    /// Number of commit operations sent with calldata instead of blobs because of a high blob base fee.
+    pub commit_calldata_fallbacks: Counter,
     #[metrics(buckets = FEE_BUCKETS)]
     pub used_base_fee_per_gas: Family<TransactionType, Histogram<u64>>,
     #[metrics(buckets = FEE_BUCKETS)]
@@ -107,6 +118,9 @@ pub(super) struct EthSenderMetrics {
     pub l1_blocks_waited_in_mempool: Family<ActionTypeLabel, Histogram<u64>>,
     /// Number of L1 batches aggregated for publishing with a specific reason.
     pub block_aggregation_reason: Family<AggregationReasonLabels, Counter>,
+    /// Delay between sealing an L1 batch and mining the L1 transaction committing, proving or executing it.
+    #[metrics(buckets = FINALITY_LAG_BUCKETS, unit = Unit::Seconds)]
+    pub l1_batch_finality_lag: Family<ActionTypeLabel, Histogram<Duration>>,
 }
 
 impl EthSenderMetrics {
@@ -151,6 +165,26 @@ impl EthSenderMetrics {
         }
         metrics_latency.observe();
     }
+
+    /// Observes finality lag for L1 batches affected by the mined `tx`.
+    pub async fn track_finality_lag(&self, connection: &mut Connection<'_, Core>, tx: &EthTx) {
+        let seal_times = connection
+            .blocks_dal()
+            .get_l1_batch_seal_times_for_eth_tx_id(tx.id)
+            .await
+            .unwrap();
+
+        let now = Utc::now().naive_utc();
+        for (l1_batch_number, sealed_at) in seal_times {
+            let Ok(lag) = (now - sealed_at).to_std() else {
+                tracing::warn!(
+                    "L1 batch #{l1_batch_number} has seal time in the future: {sealed_at}"
+                );
+                continue;
+            };
+            self.l1_batch_finality_lag[&tx.tx_type.into()].observe(lag);
+        }
+    }
 }
 
 #[vise::register]
@@ -14,6 +14,9 @@ use zksync_types::{
 
 use super::{metrics::METRICS, utils::agg_l1_batch_base_cost};
 
+/// Size of the `StoredBlockInfo` Solidity struct included into commit calldata.
+pub(crate) const STORED_BLOCK_INFO_SIZE: usize = 96;
+
 #[async_trait]
 pub trait L1BatchPublishCriterion: fmt::Debug + Send + Sync {
     #[allow(dead_code)]
@@ -218,7 +221,6 @@ impl L1BatchPublishCriterion for DataSizeCriterion {
         consecutive_l1_batches: &[L1BatchWithMetadata],
         _last_sealed_l1_batch: L1BatchNumber,
     ) -> Option<L1BatchNumber> {
-        const STORED_BLOCK_INFO_SIZE: usize = 96; // size of `StoredBlockInfo` solidity struct
         let mut data_size_left = self.data_limit - STORED_BLOCK_INFO_SIZE;
 
         for (index, l1_batch) in consecutive_l1_batches.iter().enumerate() {
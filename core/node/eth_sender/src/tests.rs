@@ -11,7 +11,7 @@ use zksync_contracts::BaseSystemContractsHashes;
 use zksync_dal::{Connection, ConnectionPool, Core, CoreDal};
 use zksync_eth_client::clients::MockEthereum;
 use zksync_l1_contract_interface::i_executor::methods::{ExecuteBatches, ProveBatches};
-use zksync_node_fee_model::l1_gas_price::GasAdjuster;
+use zksync_node_fee_model::l1_gas_price::{GasAdjuster, L1TxParamsProvider};
 use zksync_node_test_utils::{create_l1_batch, l1_batch_metadata_to_commitment_artifacts};
 use zksync_object_store::MockObjectStore;
 use zksync_types::{
@@ -252,9 +252,81 @@ fn default_l1_batch_metadata() -> L1BatchMetadata {
         events_queue_commitment: Some(H256::zero()),
         bootloader_initial_content_commitment: Some(H256::zero()),
         state_diffs_compressed: vec![],
+        da_inclusion_data: None,
     }
 }
 
+#[derive(Debug)]
+struct MockL1TxParams {
+    blob_base_fee: u64,
+}
+
+impl L1TxParamsProvider for MockL1TxParams {
+    fn get_base_fee(&self, _time_in_mempool: u32) -> u64 {
+        1
+    }
+
+    fn get_blob_base_fee(&self) -> u64 {
+        self.blob_base_fee
+    }
+
+    fn get_priority_fee(&self) -> u64 {
+        1
+    }
+
+    fn get_next_block_minimal_base_fee(&self) -> u64 {
+        1
+    }
+}
+
+#[test]
+fn falling_back_to_calldata_on_high_blob_base_fee() {
+    let config = SenderConfig {
+        pubdata_sending_mode: PubdataSendingMode::Blobs,
+        blob_base_fee_calldata_fallback_threshold: Some(1_000),
+        ..EthConfig::for_tests().sender.unwrap()
+    };
+    let aggregator = |config: &SenderConfig, blob_base_fee| {
+        Aggregator::new(
+            config.clone(),
+            MockObjectStore::arc(),
+            true,
+            L1BatchCommitmentMode::Rollup,
+        )
+        .with_l1_tx_params(Arc::new(MockL1TxParams { blob_base_fee }))
+    };
+
+    let mut header = mock_l1_batch_header(1);
+    header.pubdata_input = Some(vec![1; 1_000]);
+    let l1_batch = l1_batch_with_metadata(header);
+    assert_eq!(
+        aggregator(&config, 1_000).commit_pubdata_da(Some(&l1_batch)),
+        PubdataDA::Blobs
+    );
+    assert_eq!(
+        aggregator(&config, 1_001).commit_pubdata_da(Some(&l1_batch)),
+        PubdataDA::Calldata
+    );
+
+    let no_fallback_config = SenderConfig {
+        blob_base_fee_calldata_fallback_threshold: None,
+        ..config.clone()
+    };
+    assert_eq!(
+        aggregator(&no_fallback_config, u64::MAX).commit_pubdata_da(Some(&l1_batch)),
+        PubdataDA::Blobs
+    );
+
+    // An L1 batch that doesn't fit into a calldata commit transaction must still be committed with blobs.
+    let mut header = mock_l1_batch_header(2);
+    header.pubdata_input = Some(vec![1; config.max_eth_tx_data_size]);
+    let large_l1_batch = l1_batch_with_metadata(header);
+    assert_eq!(
+        aggregator(&config, 1_001).commit_pubdata_da(Some(&large_l1_batch)),
+        PubdataDA::Blobs
+    );
+}
+
 // Tests that we send multiple transactions and confirm them all in one iteration.
 #[test_casing(4, Product(([false, true], COMMITMENT_MODES)))]
 #[tokio::test]
@@ -0,0 +1,150 @@
+use std::{
+    collections::HashMap,
+    time::{Duration, SystemTime},
+};
+
+use anyhow::Context as _;
+use serde::Deserialize;
+use zksync_config::configs::base_token_price::CoingeckoSourceConfig;
+use zksync_types::Address;
+
+use super::{PriceQuote, PriceSource};
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Deserialize)]
+struct TokenPrice {
+    eth: f64,
+    last_updated_at: Option<u64>,
+}
+
+/// [`PriceSource`] querying a Coingecko-compatible API for the price of the base token in ETH.
+#[derive(Debug)]
+pub struct CoingeckoPriceSource {
+    client: reqwest::Client,
+    url: String,
+    api_key: Option<String>,
+    token_address: Address,
+    /// Multiplier converting the number of whole tokens per ETH into base token units per wei.
+    unit_multiplier: f64,
+}
+
+impl CoingeckoPriceSource {
+    pub fn new(config: &CoingeckoSourceConfig, base_token_decimals: u8) -> anyhow::Result<Self> {
+        let client = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .build()
+            .context("failed building HTTP client")?;
+        let url = format!(
+            "{}/api/v3/simple/token_price/{}",
+            config.api_url().trim_end_matches('/'),
+            config.platform_id()
+        );
+        Ok(Self {
+            client,
+            url,
+            api_key: config.api_key.clone(),
+            token_address: config.token_address,
+            unit_multiplier: 10_f64.powi(i32::from(base_token_decimals) - 18),
+        })
+    }
+
+    fn parse_response(
+        &self,
+        mut response: HashMap<String, TokenPrice>,
+    ) -> anyhow::Result<PriceQuote> {
+        let key = format!("{:?}", self.token_address);
+        let price = response
+            .remove(&key)
+            .with_context(|| format!("no price for token {key} in response"))?;
+        anyhow::ensure!(
+            price.eth.is_finite() && price.eth > 0.0,
+            "invalid token price: {}",
+            price.eth
+        );
+
+        let timestamp = match price.last_updated_at {
+            Some(secs) => SystemTime::UNIX_EPOCH + Duration::from_secs(secs),
+            None => SystemTime::now(),
+        };
+        Ok(PriceQuote {
+            ratio: self.unit_multiplier / price.eth,
+            timestamp,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl PriceSource for CoingeckoPriceSource {
+    fn name(&self) -> &'static str {
+        "coingecko"
+    }
+
+    async fn fetch_quote(&self) -> anyhow::Result<PriceQuote> {
+        let mut request = self.client.get(&self.url).query(&[
+            ("contract_addresses", format!("{:?}", self.token_address)),
+            ("vs_currencies", "eth".to_owned()),
+            ("include_last_updated_at", "true".to_owned()),
+        ]);
+        if let Some(api_key) = &self.api_key {
+            request = request.header("x-cg-pro-api-key", api_key);
+        }
+
+        let response = request
+            .send()
+            .await
+            .context("failed sending request")?
+            .error_for_status()?
+            .json()
+            .await
+            .context("failed parsing response")?;
+        self.parse_response(response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parsing_response() {
+        let config = CoingeckoSourceConfig {
+            api_url: None,
+            api_key: None,
+            platform_id: None,
+            token_address: Address::repeat_byte(0xab),
+        };
+        let source = CoingeckoPriceSource::new(&config, 18).unwrap();
+        assert_eq!(
+            source.url,
+            "https://api.coingecko.com/api/v3/simple/token_price/ethereum"
+        );
+
+        let response = serde_json::json!({
+            "0xabababababababababababababababababababab": {
+                "eth": 0.0002,
+                "last_updated_at": 1_700_000_000,
+            },
+        });
+        let quote = source
+            .parse_response(serde_json::from_value(response).unwrap())
+            .unwrap();
+        assert!((quote.ratio - 5_000.0).abs() < 1e-9, "{quote:?}");
+        assert_eq!(
+            quote.timestamp,
+            SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000)
+        );
+
+        let source = CoingeckoPriceSource::new(&config, 6).unwrap();
+        let response = serde_json::json!({
+            "0xabababababababababababababababababababab": { "eth": 0.0002 },
+        });
+        let quote = source
+            .parse_response(serde_json::from_value(response).unwrap())
+            .unwrap();
+        assert!((quote.ratio - 5e-9).abs() < 1e-18, "{quote:?}");
+
+        let err = source.parse_response(HashMap::new()).unwrap_err();
+        assert!(err.to_string().contains("no price"), "{err}");
+    }
+}
@@ -0,0 +1,32 @@
+//! Base token price fetcher metrics.
+
+use std::time::Duration;
+
+use vise::{Counter, Gauge, LabeledFamily, Metrics, Unit};
+
+#[derive(Debug, Metrics)]
+#[metrics(prefix = "server_base_token_price")]
+pub(super) struct BaseTokenPriceMetrics {
+    /// Ratio between the base token and ETH currently used to convert fees.
+    pub ratio: Gauge<f64>,
+    /// Time since the ratio was last updated.
+    #[metrics(unit = Unit::Seconds)]
+    pub ratio_age: Gauge<Duration>,
+    /// Latest quote received from each source.
+    #[metrics(labels = ["source"])]
+    pub source_quote: LabeledFamily<&'static str, Gauge<f64>>,
+    /// Number of failed requests to each source.
+    #[metrics(labels = ["source"])]
+    pub source_errors: LabeledFamily<&'static str, Counter>,
+    /// Number of quotes from each source discarded because they were stale.
+    #[metrics(labels = ["source"])]
+    pub stale_quotes: LabeledFamily<&'static str, Counter>,
+    /// Number of quotes from each source discarded because they deviated too much from the median.
+    #[metrics(labels = ["source"])]
+    pub deviating_quotes: LabeledFamily<&'static str, Counter>,
+    /// Number of iterations in which the ratio wasn't updated because too few sources agreed on it.
+    pub rejected_updates: Counter,
+}
+
+#[vise::register]
+pub(super) static METRICS: vise::Global<BaseTokenPriceMetrics> = vise::Global::new();
@@ -0,0 +1,314 @@
+//! Base token price fetching for chains with a non-ETH base token.
+//!
+//! Quotes are fetched from several independent [`PriceSource`]s, cross-checked against each other,
+//! and aggregated into a [`BaseTokenRatio`] used to convert fees from wei into the base token.
+//! The ratio is persisted in Postgres by [`BaseTokenRatioPersister`] and read by [`DbBaseTokenRatioProvider`].
+
+use std::{
+    fmt,
+    num::NonZeroU64,
+    sync::{Arc, RwLock},
+    time::{Duration, SystemTime},
+};
+
+use anyhow::Context as _;
+use tokio::sync::watch;
+use zksync_config::BaseTokenPriceConfig;
+use zksync_web3_decl::client::{DynClient, L1};
+
+use self::metrics::METRICS;
+pub use self::{
+    coingecko::CoingeckoPriceSource,
+    fixed::FixedPriceSource,
+    persistence::{BaseTokenRatioPersister, DbBaseTokenRatioProvider},
+    uniswap_v3::UniswapV3TwapPriceSource,
+};
+
+mod coingecko;
+mod fixed;
+mod metrics;
+mod persistence;
+#[cfg(test)]
+mod tests;
+mod uniswap_v3;
+
+/// Ratio between the smallest units of the base token and wei: `numerator` base token units
+/// are worth `denominator` wei.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BaseTokenRatio {
+    pub numerator: NonZeroU64,
+    pub denominator: NonZeroU64,
+}
+
+impl BaseTokenRatio {
+    /// Ratio for chains with ETH as the base token.
+    pub const ONE: Self = Self {
+        numerator: NonZeroU64::MIN,
+        denominator: NonZeroU64::MIN,
+    };
+
+    /// Number of significant digits kept when converting from a floating-point value.
+    const PRECISION_DIGITS: i32 = 9;
+
+    /// Approximates a positive floating-point ratio with a fraction.
+    pub fn from_f64(value: f64) -> anyhow::Result<Self> {
+        anyhow::ensure!(
+            value.is_finite() && value > 0.0,
+            "ratio must be a positive number, got {value}"
+        );
+        let exponent = value.log10().floor() as i32;
+        let denominator_exponent = (Self::PRECISION_DIGITS - 1 - exponent).clamp(0, 18);
+        let denominator = 10_u64.pow(denominator_exponent as u32);
+        let numerator = (value * denominator as f64).round();
+        anyhow::ensure!(numerator < u64::MAX as f64, "ratio {value} is too large");
+
+        Ok(Self {
+            numerator: NonZeroU64::new(numerator as u64)
+                .with_context(|| format!("ratio {value} is too small"))?,
+            denominator: NonZeroU64::new(denominator).unwrap(),
+        })
+    }
+
+    pub fn to_f64(self) -> f64 {
+        self.numerator.get() as f64 / self.denominator.get() as f64
+    }
+
+    /// Converts an amount in wei into the smallest units of the base token, saturating on overflow.
+    pub fn convert(self, wei: u64) -> u64 {
+        let converted =
+            u128::from(wei) * u128::from(self.numerator.get()) / u128::from(self.denominator.get());
+        converted.try_into().unwrap_or(u64::MAX)
+    }
+}
+
+/// Quote returned by a [`PriceSource`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PriceQuote {
+    /// Number of the smallest base token units per wei.
+    pub ratio: f64,
+    /// When the quote was produced by the source.
+    pub timestamp: SystemTime,
+}
+
+/// Source of base token prices.
+#[async_trait::async_trait]
+pub trait PriceSource: fmt::Debug + Send + Sync + 'static {
+    /// Name of the source used in logs and metrics.
+    fn name(&self) -> &'static str;
+
+    /// Fetches the current quote.
+    async fn fetch_quote(&self) -> anyhow::Result<PriceQuote>;
+}
+
+/// Provider of the ratio used to convert fees into the base token.
+pub trait BaseTokenRatioProvider: fmt::Debug + Send + Sync + 'static {
+    fn ratio(&self) -> BaseTokenRatio;
+}
+
+/// Creates price sources according to the configuration. `l1_client` is used by on-chain sources.
+pub fn create_price_sources(
+    config: &BaseTokenPriceConfig,
+    l1_client: Box<DynClient<L1>>,
+) -> anyhow::Result<Vec<Box<dyn PriceSource>>> {
+    let mut sources: Vec<Box<dyn PriceSource>> = vec![];
+    if let Some(fixed) = &config.fixed {
+        sources.push(Box::new(FixedPriceSource::new(fixed)?));
+    }
+    if let Some(coingecko) = &config.coingecko {
+        sources.push(Box::new(CoingeckoPriceSource::new(
+            coingecko,
+            config.base_token_decimals(),
+        )?));
+    }
+    if let Some(twap) = &config.uniswap_v3_twap {
+        sources.push(Box::new(UniswapV3TwapPriceSource::new(twap, l1_client)));
+    }
+    anyhow::ensure!(
+        !sources.is_empty(),
+        "no base token price sources configured"
+    );
+    Ok(sources)
+}
+
+/// Periodically fetches quotes from the configured sources and maintains the base token ratio.
+///
+/// A quote is discarded if it's older than the configured staleness limit, or if it deviates from
+/// the median of all fresh quotes by more than the configured limit. The ratio is only updated if
+/// enough sources agree on it; otherwise, the previous ratio is kept and flagged once it becomes stale.
+#[derive(Debug)]
+pub struct BaseTokenRatioFetcher {
+    sources: Vec<Box<dyn PriceSource>>,
+    polling_interval: Duration,
+    max_deviation_bps: u32,
+    max_staleness: Duration,
+    min_agreeing_sources: usize,
+    current: RwLock<(BaseTokenRatio, SystemTime)>,
+}
+
+impl BaseTokenRatioFetcher {
+    /// Creates a fetcher and fetches the initial ratio. Fails if the initial ratio cannot be established,
+    /// since fees must not be computed with an unknown ratio.
+    pub async fn new(
+        config: &BaseTokenPriceConfig,
+        sources: Vec<Box<dyn PriceSource>>,
+    ) -> anyhow::Result<Self> {
+        let min_agreeing_sources = config.min_agreeing_sources().max(1) as usize;
+        anyhow::ensure!(
+            min_agreeing_sources <= sources.len(),
+            "`min_agreeing_sources` ({min_agreeing_sources}) exceeds the number of configured sources ({})",
+            sources.len()
+        );
+
+        let mut this = Self {
+            sources,
+            polling_interval: config.polling_interval(),
+            max_deviation_bps: config.max_deviation_bps(),
+            max_staleness: config.max_staleness(),
+            min_agreeing_sources,
+            current: RwLock::new((BaseTokenRatio::ONE, SystemTime::UNIX_EPOCH)),
+        };
+        let ratio = this
+            .fetch_ratio()
+            .await
+            .context("failed fetching initial base token ratio")?;
+        *this.current.get_mut().unwrap() = (ratio, SystemTime::now());
+        METRICS.ratio.set(ratio.to_f64());
+        tracing::info!("Initial base token ratio: {ratio:?}");
+        Ok(this)
+    }
+
+    pub async fn run(
+        self: Arc<Self>,
+        mut stop_receiver: watch::Receiver<bool>,
+    ) -> anyhow::Result<()> {
+        while !*stop_receiver.borrow_and_update() {
+            match self.fetch_ratio().await {
+                Ok(ratio) => {
+                    *self.current.write().unwrap() = (ratio, SystemTime::now());
+                    METRICS.ratio.set(ratio.to_f64());
+                    tracing::debug!("Updated base token ratio: {ratio:?}");
+                }
+                Err(err) => {
+                    METRICS.rejected_updates.inc();
+                    tracing::warn!("Cannot update base token ratio: {err:#}");
+                }
+            }
+            self.check_staleness();
+
+            if tokio::time::timeout(self.polling_interval, stop_receiver.changed())
+                .await
+                .is_ok()
+            {
+                break;
+            }
+        }
+
+        tracing::info!("Stop signal received, base token ratio fetcher is shutting down");
+        Ok(())
+    }
+
+    fn check_staleness(&self) {
+        let (ratio, updated_at) = *self.current.read().unwrap();
+        let age = SystemTime::now()
+            .duration_since(updated_at)
+            .unwrap_or_default();
+        METRICS.ratio_age.set(age);
+        if age > self.max_staleness {
+            tracing::error!(
+                "Base token ratio {ratio:?} was last updated {age:?} ago, which exceeds the staleness limit of {:?}",
+                self.max_staleness
+            );
+        }
+    }
+
+    async fn fetch_ratio(&self) -> anyhow::Result<BaseTokenRatio> {
+        let now = SystemTime::now();
+        let mut quotes = Vec::with_capacity(self.sources.len());
+        for source in &self.sources {
+            let name = source.name();
+            let quote = match source.fetch_quote().await {
+                Ok(quote) => quote,
+                Err(err) => {
+                    METRICS.source_errors[&name].inc();
+                    tracing::warn!("Failed fetching base token price from `{name}`: {err:#}");
+                    continue;
+                }
+            };
+            if !quote.ratio.is_finite() || quote.ratio <= 0.0 {
+                METRICS.source_errors[&name].inc();
+                tracing::warn!("Source `{name}` returned invalid base token ratio: {quote:?}");
+                continue;
+            }
+            METRICS.source_quote[&name].set(quote.ratio);
+
+            let age = now.duration_since(quote.timestamp).unwrap_or_default();
+            if age > self.max_staleness {
+                METRICS.stale_quotes[&name].inc();
+                tracing::warn!(
+                    "Discarding stale base token quote from `{name}` ({age:?} old): {quote:?}"
+                );
+                continue;
+            }
+            quotes.push((name, quote.ratio));
+        }
+
+        let ratio = aggregate_quotes(&quotes, self.max_deviation_bps, self.min_agreeing_sources)?;
+        BaseTokenRatio::from_f64(ratio)
+    }
+}
+
+impl BaseTokenRatioFetcher {
+    /// Returns the current ratio together with the time it was last updated.
+    pub fn ratio_with_timestamp(&self) -> (BaseTokenRatio, SystemTime) {
+        *self.current.read().unwrap()
+    }
+}
+
+impl BaseTokenRatioProvider for BaseTokenRatioFetcher {
+    fn ratio(&self) -> BaseTokenRatio {
+        self.current.read().unwrap().0
+    }
+}
+
+fn median(values: &mut [f64]) -> f64 {
+    values.sort_unstable_by(f64::total_cmp);
+    let mid = values.len() / 2;
+    if values.len() % 2 == 0 {
+        (values[mid - 1] + values[mid]) / 2.0
+    } else {
+        values[mid]
+    }
+}
+
+/// Returns the median of quotes that lie within `max_deviation_bps` of the median of all quotes.
+fn aggregate_quotes(
+    quotes: &[(&'static str, f64)],
+    max_deviation_bps: u32,
+    min_agreeing_sources: usize,
+) -> anyhow::Result<f64> {
+    anyhow::ensure!(!quotes.is_empty(), "no fresh base token quotes");
+    let mut all_ratios: Vec<_> = quotes.iter().map(|&(_, ratio)| ratio).collect();
+    let overall_median = median(&mut all_ratios);
+
+    let max_deviation = overall_median * f64::from(max_deviation_bps) / 10_000.0;
+    let mut agreeing_ratios = vec![];
+    for &(name, ratio) in quotes {
+        if (ratio - overall_median).abs() > max_deviation {
+            METRICS.deviating_quotes[&name].inc();
+            tracing::warn!(
+                "Base token quote from `{name}` ({ratio}) deviates from the median ({overall_median}) \
+                 by more than {max_deviation_bps} bps"
+            );
+        } else {
+            agreeing_ratios.push(ratio);
+        }
+    }
+
+    anyhow::ensure!(
+        agreeing_ratios.len() >= min_agreeing_sources,
+        "only {} base token price source(s) agree within {max_deviation_bps} bps, while at least \
+         {min_agreeing_sources} are required",
+        agreeing_ratios.len()
+    );
+    Ok(median(&mut agreeing_ratios))
+}
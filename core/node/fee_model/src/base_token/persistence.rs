@@ -0,0 +1,148 @@
+//! Persistence of the base token ratio in Postgres, so that it's shared among all components
+//! (and server instances) computing fees.
+
+use std::{
+    sync::{Arc, RwLock},
+    time::{Duration, SystemTime},
+};
+
+use anyhow::Context as _;
+use tokio::sync::watch;
+use zksync_dal::{ConnectionPool, Core, CoreDal};
+
+use super::{BaseTokenRatio, BaseTokenRatioFetcher, BaseTokenRatioProvider};
+
+/// Persists the ratio maintained by a [`BaseTokenRatioFetcher`] each time it's updated.
+#[derive(Debug)]
+pub struct BaseTokenRatioPersister {
+    pool: ConnectionPool<Core>,
+    fetcher: Arc<BaseTokenRatioFetcher>,
+    polling_interval: Duration,
+    last_persisted_at: Option<SystemTime>,
+}
+
+impl BaseTokenRatioPersister {
+    pub fn new(
+        pool: ConnectionPool<Core>,
+        fetcher: Arc<BaseTokenRatioFetcher>,
+        polling_interval: Duration,
+    ) -> Self {
+        Self {
+            pool,
+            fetcher,
+            polling_interval,
+            last_persisted_at: None,
+        }
+    }
+
+    /// Persists the current ratio if it wasn't persisted yet.
+    pub async fn persist_current_ratio(&mut self) -> anyhow::Result<()> {
+        let (ratio, updated_at) = self.fetcher.ratio_with_timestamp();
+        if self.last_persisted_at == Some(updated_at) {
+            return Ok(());
+        }
+
+        let mut storage = self
+            .pool
+            .connection_tagged("base_token_ratio_persister")
+            .await?;
+        let id = storage
+            .base_token_dal()
+            .insert_token_ratio(ratio.numerator, ratio.denominator, updated_at)
+            .await?;
+        self.last_persisted_at = Some(updated_at);
+        tracing::debug!("Persisted base token ratio {ratio:?} with ID {id}");
+        Ok(())
+    }
+
+    pub async fn run(mut self, mut stop_receiver: watch::Receiver<bool>) -> anyhow::Result<()> {
+        while !*stop_receiver.borrow_and_update() {
+            self.persist_current_ratio()
+                .await
+                .context("failed persisting base token ratio")?;
+
+            if tokio::time::timeout(self.polling_interval, stop_receiver.changed())
+                .await
+                .is_ok()
+            {
+                break;
+            }
+        }
+
+        tracing::info!("Stop signal received, base token ratio persister is shutting down");
+        Ok(())
+    }
+}
+
+/// Provides the base token ratio persisted by [`BaseTokenRatioPersister`], periodically reloading it from Postgres.
+#[derive(Debug)]
+pub struct DbBaseTokenRatioProvider {
+    pool: ConnectionPool<Core>,
+    update_interval: Duration,
+    latest: RwLock<BaseTokenRatio>,
+}
+
+impl DbBaseTokenRatioProvider {
+    /// Creates a provider and loads the initial ratio. Fails if no ratio is persisted yet, since fees
+    /// must not be computed with an unknown ratio.
+    pub async fn new(
+        pool: ConnectionPool<Core>,
+        update_interval: Duration,
+    ) -> anyhow::Result<Self> {
+        let ratio = Self::load_ratio(&pool).await?.context(
+            "no base token ratio is persisted; the base token ratio persister must run at least once",
+        )?;
+        tracing::info!("Loaded initial base token ratio: {ratio:?}");
+        Ok(Self {
+            pool,
+            update_interval,
+            latest: RwLock::new(ratio),
+        })
+    }
+
+    async fn load_ratio(pool: &ConnectionPool<Core>) -> anyhow::Result<Option<BaseTokenRatio>> {
+        let mut storage = pool.connection_tagged("base_token_ratio_provider").await?;
+        let stored = storage.base_token_dal().get_latest_ratio().await?;
+        Ok(stored.map(|stored| BaseTokenRatio {
+            numerator: stored.numerator,
+            denominator: stored.denominator,
+        }))
+    }
+
+    pub async fn run(
+        self: Arc<Self>,
+        mut stop_receiver: watch::Receiver<bool>,
+    ) -> anyhow::Result<()> {
+        while !*stop_receiver.borrow_and_update() {
+            match Self::load_ratio(&self.pool).await {
+                Ok(Some(ratio)) => {
+                    *self.latest.write().unwrap() = ratio;
+                }
+                Ok(None) => {
+                    tracing::warn!(
+                        "Persisted base token ratio disappeared; keeping the previous ratio"
+                    );
+                }
+                Err(err) => {
+                    tracing::warn!("Failed reloading base token ratio: {err:#}");
+                }
+            }
+
+            if tokio::time::timeout(self.update_interval, stop_receiver.changed())
+                .await
+                .is_ok()
+            {
+                break;
+            }
+        }
+
+        tracing::info!("Stop signal received, base token ratio provider is shutting down");
+        Ok(())
+    }
+}
+
+impl BaseTokenRatioProvider for DbBaseTokenRatioProvider {
+    fn ratio(&self) -> BaseTokenRatio {
+        *self.latest.read().unwrap()
+    }
+}
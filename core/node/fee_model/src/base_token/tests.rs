@@ -0,0 +1,210 @@
+//! Tests for the base token price fetcher.
+
+use std::sync::Mutex;
+
+use anyhow::Context as _;
+use zksync_config::configs::base_token_price::FixedRatioSourceConfig;
+use zksync_dal::{ConnectionPool, Core};
+
+use super::*;
+
+/// Source returning pre-configured quotes; errors if none is set.
+#[derive(Debug)]
+struct MockPriceSource {
+    name: &'static str,
+    quote: Mutex<Option<PriceQuote>>,
+}
+
+impl MockPriceSource {
+    fn new(name: &'static str, ratio: f64) -> Arc<Self> {
+        Arc::new(Self {
+            name,
+            quote: Mutex::new(Some(PriceQuote {
+                ratio,
+                timestamp: SystemTime::now(),
+            })),
+        })
+    }
+
+    fn set_quote(&self, quote: Option<PriceQuote>) {
+        *self.quote.lock().unwrap() = quote;
+    }
+}
+
+#[async_trait::async_trait]
+impl PriceSource for Arc<MockPriceSource> {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    async fn fetch_quote(&self) -> anyhow::Result<PriceQuote> {
+        self.quote.lock().unwrap().context("source is unavailable")
+    }
+}
+
+fn test_config(min_agreeing_sources: u32) -> BaseTokenPriceConfig {
+    BaseTokenPriceConfig {
+        min_agreeing_sources: Some(min_agreeing_sources),
+        max_deviation_bps: Some(500),
+        max_staleness_secs: Some(60),
+        ..BaseTokenPriceConfig::for_tests()
+    }
+}
+
+#[test]
+fn converting_ratio_from_f64() {
+    let ratio = BaseTokenRatio::from_f64(1.5).unwrap();
+    assert_eq!(ratio.numerator.get(), 150_000_000);
+    assert_eq!(ratio.denominator.get(), 100_000_000);
+    assert_eq!(ratio.convert(1_000), 1_500);
+
+    let ratio = BaseTokenRatio::from_f64(2.5e12).unwrap();
+    assert_eq!(ratio.numerator.get(), 2_500_000_000_000);
+    assert_eq!(ratio.denominator.get(), 1);
+
+    let ratio = BaseTokenRatio::from_f64(3e-12).unwrap();
+    assert_eq!(ratio.numerator.get(), 3_000_000);
+    assert_eq!(ratio.denominator.get(), 10_u64.pow(18));
+    assert_eq!(ratio.convert(10_u64.pow(15)), 3_000);
+
+    // Conversion saturates instead of overflowing.
+    let ratio = BaseTokenRatio::from_f64(1e12).unwrap();
+    assert_eq!(ratio.convert(u64::MAX / 2), u64::MAX);
+
+    BaseTokenRatio::from_f64(0.0).unwrap_err();
+    BaseTokenRatio::from_f64(-1.0).unwrap_err();
+    BaseTokenRatio::from_f64(f64::NAN).unwrap_err();
+    BaseTokenRatio::from_f64(1e-25).unwrap_err();
+    BaseTokenRatio::from_f64(1e25).unwrap_err();
+}
+
+#[test]
+fn aggregating_quotes() {
+    let quotes = [("a", 100.0), ("b", 102.0), ("c", 99.0)];
+    let ratio = aggregate_quotes(&quotes, 500, 3).unwrap();
+    assert_eq!(ratio, 100.0);
+
+    // The outlier is discarded.
+    let quotes = [("a", 100.0), ("b", 102.0), ("c", 150.0)];
+    let ratio = aggregate_quotes(&quotes, 500, 2).unwrap();
+    assert_eq!(ratio, 101.0);
+    let err = aggregate_quotes(&quotes, 500, 3).unwrap_err();
+    assert!(err.to_string().contains("only 2"), "{err}");
+
+    // With two diverging sources, neither is trusted.
+    let quotes = [("a", 100.0), ("b", 150.0)];
+    aggregate_quotes(&quotes, 500, 1).unwrap_err();
+
+    aggregate_quotes(&[], 500, 1).unwrap_err();
+}
+
+#[tokio::test]
+async fn fetcher_requires_initial_ratio() {
+    let config = test_config(2);
+    let source = MockPriceSource::new("a", 2.0);
+    let err = BaseTokenRatioFetcher::new(&config, vec![Box::new(source)])
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("min_agreeing_sources"), "{err}");
+
+    let source = MockPriceSource::new("a", 2.0);
+    source.set_quote(None);
+    BaseTokenRatioFetcher::new(&test_config(1), vec![Box::new(source)])
+        .await
+        .unwrap_err();
+}
+
+#[tokio::test]
+async fn fetcher_discards_stale_and_deviating_quotes() {
+    let sources = [
+        MockPriceSource::new("a", 2.0),
+        MockPriceSource::new("b", 2.02),
+        MockPriceSource::new("c", 1.98),
+    ];
+    let boxed_sources = sources
+        .iter()
+        .map(|source| Box::new(source.clone()) as Box<dyn PriceSource>)
+        .collect();
+    let fetcher = BaseTokenRatioFetcher::new(&test_config(2), boxed_sources)
+        .await
+        .unwrap();
+    assert_eq!(fetcher.ratio(), BaseTokenRatio::from_f64(2.0).unwrap());
+
+    // A single deviating source doesn't affect the ratio.
+    sources[2].set_quote(Some(PriceQuote {
+        ratio: 5.0,
+        timestamp: SystemTime::now(),
+    }));
+    let ratio = fetcher.fetch_ratio().await.unwrap();
+    assert_eq!(ratio, BaseTokenRatio::from_f64(2.01).unwrap());
+
+    // Stale quotes are discarded, so only one source remains.
+    sources[1].set_quote(Some(PriceQuote {
+        ratio: 2.02,
+        timestamp: SystemTime::now() - Duration::from_secs(3_600),
+    }));
+    fetcher.fetch_ratio().await.unwrap_err();
+
+    // Invalid quotes are discarded as well.
+    sources[1].set_quote(Some(PriceQuote {
+        ratio: f64::INFINITY,
+        timestamp: SystemTime::now(),
+    }));
+    fetcher.fetch_ratio().await.unwrap_err();
+}
+
+#[tokio::test]
+async fn fetcher_with_fixed_source() {
+    let config = BaseTokenPriceConfig {
+        fixed: Some(FixedRatioSourceConfig {
+            numerator: 3,
+            denominator: 2,
+        }),
+        ..BaseTokenPriceConfig::for_tests()
+    };
+    let sources = vec![
+        Box::new(FixedPriceSource::new(config.fixed.as_ref().unwrap()).unwrap())
+            as Box<dyn PriceSource>,
+    ];
+    let fetcher = BaseTokenRatioFetcher::new(&config, sources).await.unwrap();
+    assert_eq!(fetcher.ratio(), BaseTokenRatio::from_f64(1.5).unwrap());
+    assert_eq!(fetcher.ratio().convert(1_000_000), 1_500_000);
+}
+
+#[tokio::test]
+async fn persisting_and_loading_ratio() {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let update_interval = Duration::from_millis(10);
+    let err = DbBaseTokenRatioProvider::new(pool.clone(), update_interval)
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("no base token ratio"), "{err}");
+
+    let source = MockPriceSource::new("a", 2.0);
+    let fetcher = BaseTokenRatioFetcher::new(&test_config(1), vec![Box::new(source)])
+        .await
+        .unwrap();
+    let fetcher = Arc::new(fetcher);
+    let mut persister =
+        BaseTokenRatioPersister::new(pool.clone(), fetcher.clone(), update_interval);
+    persister.persist_current_ratio().await.unwrap();
+
+    let provider = DbBaseTokenRatioProvider::new(pool.clone(), update_interval)
+        .await
+        .unwrap();
+    let provider = Arc::new(provider);
+    assert_eq!(provider.ratio(), BaseTokenRatio::from_f64(2.0).unwrap());
+
+    let (stop_sender, stop_receiver) = watch::channel(false);
+    let provider_task = tokio::spawn(provider.clone().run(stop_receiver));
+
+    let new_ratio = BaseTokenRatio::from_f64(3.0).unwrap();
+    *fetcher.current.write().unwrap() = (new_ratio, SystemTime::now());
+    persister.persist_current_ratio().await.unwrap();
+    while provider.ratio() != new_ratio {
+        tokio::time::sleep(update_interval).await;
+    }
+
+    stop_sender.send_replace(true);
+    provider_task.await.unwrap().unwrap();
+}
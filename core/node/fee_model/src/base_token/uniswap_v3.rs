@@ -0,0 +1,140 @@
+use std::time::SystemTime;
+
+use anyhow::Context as _;
+use zksync_config::configs::base_token_price::UniswapV3TwapSourceConfig;
+use zksync_eth_client::CallFunctionArgs;
+use zksync_types::{
+    ethabi::{self, Token},
+    web3::contract::{Detokenize, Error as ContractError},
+    Address, U256,
+};
+use zksync_web3_decl::client::{DynClient, L1};
+
+use super::{PriceQuote, PriceSource};
+
+const POOL_ABI: &str = r#"[{
+    "name": "observe",
+    "type": "function",
+    "stateMutability": "view",
+    "inputs": [{ "name": "secondsAgos", "type": "uint32[]" }],
+    "outputs": [
+        { "name": "tickCumulatives", "type": "int56[]" },
+        { "name": "secondsPerLiquidityCumulativeX128s", "type": "uint160[]" }
+    ]
+}]"#;
+
+/// Output of the `observe()` pool method; only tick accumulators are used.
+#[derive(Debug)]
+struct TickCumulatives(Vec<i64>);
+
+impl Detokenize for TickCumulatives {
+    fn from_tokens(tokens: Vec<Token>) -> Result<Self, ContractError> {
+        let Some(Token::Array(ticks)) = tokens.into_iter().next() else {
+            return Err(ContractError::InvalidOutputType(
+                "expected array of tick cumulatives".to_owned(),
+            ));
+        };
+        ticks
+            .into_iter()
+            .map(|token| match token {
+                // `int56` values are sign-extended to 256 bits, so the lowest 64 bits represent them exactly.
+                Token::Int(value) => Ok(value.low_u64() as i64),
+                _ => Err(ContractError::InvalidOutputType(format!(
+                    "expected int56, got {token:?}"
+                ))),
+            })
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+}
+
+/// [`PriceSource`] computing the time-weighted average price of the base token from a Uniswap V3 pool
+/// paired with WETH on L1.
+#[derive(Debug)]
+pub struct UniswapV3TwapPriceSource {
+    client: Box<DynClient<L1>>,
+    pool_abi: ethabi::Contract,
+    pool_address: Address,
+    base_token_is_token0: bool,
+    window_secs: u32,
+}
+
+impl UniswapV3TwapPriceSource {
+    pub fn new(config: &UniswapV3TwapSourceConfig, client: Box<DynClient<L1>>) -> Self {
+        Self {
+            client: client.for_component("base_token_price"),
+            pool_abi: ethabi::Contract::load(POOL_ABI.as_bytes()).expect("invalid pool ABI"),
+            pool_address: config.pool_address,
+            base_token_is_token0: config.base_token_is_token0,
+            window_secs: config.window_secs(),
+        }
+    }
+}
+
+/// Converts the average tick over the window into the number of base token units per wei.
+/// The pool price (i.e., the amount of `token1` units per a `token0` unit) is `1.0001^tick`.
+fn ratio_from_ticks(
+    tick_cumulatives: &[i64],
+    window_secs: u32,
+    base_token_is_token0: bool,
+) -> anyhow::Result<f64> {
+    let [start, end] = tick_cumulatives else {
+        anyhow::bail!("unexpected number of tick cumulatives: {tick_cumulatives:?}");
+    };
+    let average_tick = (end - start).div_euclid(i64::from(window_secs));
+    let price = 1.0001_f64.powf(average_tick as f64);
+    Ok(if base_token_is_token0 {
+        1.0 / price
+    } else {
+        price
+    })
+}
+
+#[async_trait::async_trait]
+impl PriceSource for UniswapV3TwapPriceSource {
+    fn name(&self) -> &'static str {
+        "uniswap_v3_twap"
+    }
+
+    async fn fetch_quote(&self) -> anyhow::Result<PriceQuote> {
+        let seconds_agos = Token::Array(vec![
+            Token::Uint(U256::from(self.window_secs)),
+            Token::Uint(U256::zero()),
+        ]);
+        let TickCumulatives(tick_cumulatives) = CallFunctionArgs::new("observe", seconds_agos)
+            .for_contract(self.pool_address, &self.pool_abi)
+            .call(self.client.as_ref())
+            .await
+            .context("observe()")?;
+        Ok(PriceQuote {
+            ratio: ratio_from_ticks(
+                &tick_cumulatives,
+                self.window_secs,
+                self.base_token_is_token0,
+            )?,
+            timestamp: SystemTime::now(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn computing_ratio_from_ticks() {
+        // Average tick is 69_081, which corresponds to the price of ~1_000.
+        let ticks = [1_000_000, 1_000_000 + 69_081 * 600];
+        let ratio = ratio_from_ticks(&ticks, 600, false).unwrap();
+        assert!((ratio - 1_000.0).abs() < 1.0, "{ratio}");
+        let ratio = ratio_from_ticks(&ticks, 600, true).unwrap();
+        assert!((ratio - 0.001).abs() < 1e-6, "{ratio}");
+
+        // Negative ticks are rounded towards negative infinity, like in the Uniswap oracle library.
+        let ticks = [0, -69_081 * 600 - 1];
+        let ratio = ratio_from_ticks(&ticks, 600, false).unwrap();
+        assert!((ratio - 1.0001_f64.powi(-69_082)).abs() < 1e-12, "{ratio}");
+
+        ratio_from_ticks(&[0], 600, false).unwrap_err();
+    }
+}
@@ -200,6 +200,11 @@ impl GasAdjuster {
             PubdataSendingMode::Calldata => {
                 self.estimate_effective_gas_price() * self.pubdata_byte_gas()
             }
+            PubdataSendingMode::Custom => {
+                // Pubdata is paid for on the DA layer, so L1 only sees the inclusion data.
+                // The DA layer fees aren't accounted for yet.
+                0
+            }
         }
     }
 
@@ -1,6 +1,8 @@
 use std::{fmt, sync::Arc};
 
 use anyhow::Context as _;
+use tokio::sync::watch;
+use zksync_config::configs::{ReloadableConfig, ReloadableFeeParams};
 use zksync_dal::{ConnectionPool, Core, CoreDal};
 use zksync_types::{
     fee_model::{
@@ -11,8 +13,12 @@ use zksync_types::{
 };
 use zksync_utils::ceil_div_u256;
 
-use crate::l1_gas_price::GasAdjuster;
+use crate::{
+    base_token::{BaseTokenRatio, BaseTokenRatioProvider},
+    l1_gas_price::GasAdjuster,
+};
 
+pub mod base_token;
 pub mod l1_gas_price;
 
 /// Trait responsible for providing fee info for a batch
@@ -60,11 +66,13 @@ impl dyn BatchFeeModelInputProvider {
 pub struct MainNodeFeeInputProvider {
     provider: Arc<GasAdjuster>,
     config: FeeModelConfig,
+    config_updates: Option<watch::Receiver<ReloadableConfig>>,
+    base_token_ratio: Option<Arc<dyn BaseTokenRatioProvider>>,
 }
 
 impl BatchFeeModelInputProvider for MainNodeFeeInputProvider {
     fn get_fee_model_params(&self) -> FeeParams {
-        match self.config {
+        let params = match self.config() {
             FeeModelConfig::V1(config) => FeeParams::V1(FeeParamsV1 {
                 config,
                 l1_gas_price: self.provider.estimate_effective_gas_price(),
@@ -74,13 +82,85 @@ impl BatchFeeModelInputProvider for MainNodeFeeInputProvider {
                 l1_gas_price: self.provider.estimate_effective_gas_price(),
                 l1_pubdata_price: self.provider.estimate_effective_pubdata_price(),
             }),
+        };
+        match &self.base_token_ratio {
+            Some(ratio) => convert_to_base_token(params, ratio.ratio()),
+            None => params,
         }
     }
 }
 
 impl MainNodeFeeInputProvider {
     pub fn new(provider: Arc<GasAdjuster>, config: FeeModelConfig) -> Self {
-        Self { provider, config }
+        Self {
+            provider,
+            config,
+            config_updates: None,
+            base_token_ratio: None,
+        }
+    }
+
+    /// Makes the provider convert all prices from wei into the base token using the provided ratio.
+    /// Should be used for chains with a non-ETH base token; the minimal L2 gas price in the config
+    /// is then denominated in wei as well.
+    #[must_use]
+    pub fn with_base_token_ratio(mut self, ratio: Arc<dyn BaseTokenRatioProvider>) -> Self {
+        self.base_token_ratio = Some(ratio);
+        self
+    }
+
+    /// Makes the provider use fee params from the reloadable config, overriding the ones in the initial config.
+    /// The fee model version is still taken from the initial config.
+    #[must_use]
+    pub fn with_config_updates(mut self, updates: watch::Receiver<ReloadableConfig>) -> Self {
+        self.config_updates = Some(updates);
+        self
+    }
+
+    fn config(&self) -> FeeModelConfig {
+        let fee_params = self
+            .config_updates
+            .as_ref()
+            .and_then(|updates| updates.borrow().fee_params);
+        match fee_params {
+            Some(fee_params) => apply_reloadable_fee_params(self.config, &fee_params),
+            None => self.config,
+        }
+    }
+}
+
+fn apply_reloadable_fee_params(
+    config: FeeModelConfig,
+    fee_params: &ReloadableFeeParams,
+) -> FeeModelConfig {
+    match config {
+        FeeModelConfig::V1(mut config) => {
+            config.minimal_l2_gas_price = fee_params.minimal_l2_gas_price;
+            FeeModelConfig::V1(config)
+        }
+        FeeModelConfig::V2(mut config) => {
+            config.minimal_l2_gas_price = fee_params.minimal_l2_gas_price;
+            config.compute_overhead_part = fee_params.compute_overhead_part;
+            config.pubdata_overhead_part = fee_params.pubdata_overhead_part;
+            config.batch_overhead_l1_gas = fee_params.batch_overhead_l1_gas;
+            FeeModelConfig::V2(config)
+        }
+    }
+}
+
+fn convert_to_base_token(params: FeeParams, ratio: BaseTokenRatio) -> FeeParams {
+    match params {
+        FeeParams::V1(mut params) => {
+            params.config.minimal_l2_gas_price = ratio.convert(params.config.minimal_l2_gas_price);
+            params.l1_gas_price = ratio.convert(params.l1_gas_price);
+            FeeParams::V1(params)
+        }
+        FeeParams::V2(mut params) => {
+            params.config.minimal_l2_gas_price = ratio.convert(params.config.minimal_l2_gas_price);
+            params.l1_gas_price = ratio.convert(params.l1_gas_price);
+            params.l1_pubdata_price = ratio.convert(params.l1_pubdata_price);
+            FeeParams::V2(params)
+        }
     }
 }
 
@@ -235,6 +315,8 @@ impl BatchFeeModelInputProvider for MockBatchFeeParamsProvider {
 
 #[cfg(test)]
 mod tests {
+    use zksync_types::fee_model::FeeModelConfigV1;
+
     use super::*;
 
     // To test that overflow never happens, we'll use giant L1 gas price, i.e.
@@ -458,4 +540,74 @@ mod tests {
             "Max pubdata increase lowers pubdata price"
         );
     }
+
+    #[test]
+    fn applying_reloadable_fee_params() {
+        let fee_params = ReloadableFeeParams {
+            minimal_l2_gas_price: 250_000_000,
+            compute_overhead_part: 0.5,
+            pubdata_overhead_part: 0.5,
+            batch_overhead_l1_gas: 1_000_000,
+        };
+
+        let config = FeeModelConfig::V1(FeeModelConfigV1 {
+            minimal_l2_gas_price: 100_000_000,
+        });
+        let FeeModelConfig::V1(config) = apply_reloadable_fee_params(config, &fee_params) else {
+            panic!("fee model version must not change");
+        };
+        assert_eq!(config.minimal_l2_gas_price, 250_000_000);
+
+        let config = FeeModelConfig::V2(FeeModelConfigV2 {
+            minimal_l2_gas_price: 100_000_000,
+            compute_overhead_part: 0.0,
+            pubdata_overhead_part: 1.0,
+            batch_overhead_l1_gas: 800_000,
+            max_gas_per_batch: 200_000_000,
+            max_pubdata_per_batch: 100_000,
+        });
+        let FeeModelConfig::V2(config) = apply_reloadable_fee_params(config, &fee_params) else {
+            panic!("fee model version must not change");
+        };
+        assert_eq!(config.minimal_l2_gas_price, 250_000_000);
+        assert_eq!(config.compute_overhead_part, 0.5);
+        assert_eq!(config.pubdata_overhead_part, 0.5);
+        assert_eq!(config.batch_overhead_l1_gas, 1_000_000);
+        // Non-reloadable params are retained.
+        assert_eq!(config.max_gas_per_batch, 200_000_000);
+        assert_eq!(config.max_pubdata_per_batch, 100_000);
+    }
+
+    #[test]
+    fn converting_fee_params_to_base_token() {
+        // 1 wei is worth 2.5 base token units.
+        let ratio = BaseTokenRatio::from_f64(2.5).unwrap();
+
+        let params = FeeParams::sensible_v1_default();
+        let FeeParams::V1(params) = convert_to_base_token(params, ratio) else {
+            panic!("fee model version must not change");
+        };
+        assert_eq!(params.config.minimal_l2_gas_price, 250_000_000);
+        assert_eq!(params.l1_gas_price, 2_500_000_000);
+
+        let params = FeeParams::V2(FeeParamsV2 {
+            config: FeeModelConfigV2 {
+                minimal_l2_gas_price: 100_000_000,
+                compute_overhead_part: 0.0,
+                pubdata_overhead_part: 1.0,
+                batch_overhead_l1_gas: 800_000,
+                max_gas_per_batch: 200_000_000,
+                max_pubdata_per_batch: 100_000,
+            },
+            l1_gas_price: 1_000_000_000,
+            l1_pubdata_price: 17_000_000_000,
+        });
+        let FeeParams::V2(params) = convert_to_base_token(params, ratio) else {
+            panic!("fee model version must not change");
+        };
+        assert_eq!(params.config.minimal_l2_gas_price, 250_000_000);
+        assert_eq!(params.config.batch_overhead_l1_gas, 800_000);
+        assert_eq!(params.l1_gas_price, 2_500_000_000);
+        assert_eq!(params.l1_pubdata_price, 42_500_000_000);
+    }
 }
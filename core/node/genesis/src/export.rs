@@ -0,0 +1,74 @@
+//! Portable representation of the genesis state that can be exported to a file and used to initialize
+//! a fresh database.
+
+use std::{fs, path::Path};
+
+use anyhow::Context as _;
+use serde::{Deserialize, Serialize};
+use zksync_config::GenesisConfig;
+use zksync_contracts::BaseSystemContracts;
+use zksync_types::{block::DeployedContract, StorageLog};
+
+use crate::{utils::get_storage_logs, GenesisError, GenesisParams};
+
+/// Full genesis state of a chain: chain parameters, system contracts and initial storage logs.
+///
+/// Initial storage logs are fully determined by system contracts; they are included for inspection
+/// and are checked for consistency on import.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GenesisExport {
+    pub config: GenesisConfig,
+    pub base_system_contracts: BaseSystemContracts,
+    pub system_contracts: Vec<DeployedContract>,
+    pub storage_logs: Vec<StorageLog>,
+}
+
+impl GenesisExport {
+    /// Reads an export from a JSON file at the specified `path`.
+    pub fn read_from_file(path: &Path) -> anyhow::Result<Self> {
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("failed reading genesis export from `{}`", path.display()))?;
+        serde_json::from_str(&contents)
+            .with_context(|| format!("failed parsing genesis export at `{}`", path.display()))
+    }
+
+    /// Writes this export as a JSON file to the specified `path`.
+    pub fn write_to_file(&self, path: &Path) -> anyhow::Result<()> {
+        let contents =
+            serde_json::to_string_pretty(self).context("failed serializing genesis export")?;
+        fs::write(path, contents)
+            .with_context(|| format!("failed writing genesis export to `{}`", path.display()))
+    }
+}
+
+impl GenesisParams {
+    /// Exports these params into a portable format.
+    pub fn export(&self) -> GenesisExport {
+        let storage_logs = get_storage_logs(&self.system_contracts)
+            .into_iter()
+            .flat_map(|(_, logs)| logs)
+            .collect();
+        GenesisExport {
+            config: self.config.clone(),
+            base_system_contracts: self.base_system_contracts.clone(),
+            system_contracts: self.system_contracts.clone(),
+            storage_logs,
+        }
+    }
+
+    /// Imports params from the portable format, checking their consistency.
+    pub fn import(export: GenesisExport) -> Result<Self, GenesisError> {
+        let params = Self::from_genesis_config(
+            export.config,
+            export.base_system_contracts,
+            export.system_contracts,
+        )?;
+        let expected_storage_logs = params.export().storage_logs;
+        if expected_storage_logs != export.storage_logs {
+            return Err(GenesisError::Other(anyhow::anyhow!(
+                "storage logs in genesis export do not correspond to system contracts"
+            )));
+        }
+        Ok(params)
+    }
+}
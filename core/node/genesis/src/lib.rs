@@ -25,12 +25,14 @@ use zksync_types::{
 };
 use zksync_utils::{bytecode::hash_bytecode, u256_to_h256};
 
+pub use crate::export::GenesisExport;
 use crate::utils::{
     add_eth_token, get_deduped_log_queries, get_storage_logs,
     insert_base_system_contracts_to_factory_deps, insert_system_contracts,
     save_genesis_l1_batch_metadata,
 };
 
+mod export;
 #[cfg(test)]
 mod tests;
 mod utils;
@@ -364,6 +366,7 @@ pub async fn create_genesis_l1_batch(
         protocol_version: Some(protocol_version.minor),
         virtual_blocks: 0,
         gas_limit: 0,
+        logs_bloom: Default::default(),
     };
 
     let mut transaction = storage.start_transaction().await?;
@@ -64,3 +64,32 @@ async fn running_genesis_with_non_latest_protocol_version() {
     insert_genesis_batch(&mut conn, &params).await.unwrap();
     assert!(!conn.blocks_dal().is_genesis_needed().await.unwrap());
 }
+
+#[tokio::test]
+async fn running_genesis_from_export() {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let mut conn = pool.connection().await.unwrap();
+    conn.blocks_dal().delete_genesis().await.unwrap();
+
+    let params = GenesisParams::mock();
+    let export = params.export();
+    let serialized_export = serde_json::to_string(&export).unwrap();
+    let imported_export: GenesisExport = serde_json::from_str(&serialized_export).unwrap();
+    assert_eq!(imported_export.storage_logs, export.storage_logs);
+    let imported_params = GenesisParams::import(imported_export).unwrap();
+    assert_eq!(imported_params.config(), params.config());
+    assert_eq!(
+        imported_params.base_system_contracts().hashes(),
+        params.base_system_contracts().hashes()
+    );
+
+    insert_genesis_batch(&mut conn, &imported_params)
+        .await
+        .unwrap();
+    assert!(!conn.blocks_dal().is_genesis_needed().await.unwrap());
+
+    let mut tampered_export = params.export();
+    tampered_export.storage_logs.pop();
+    let err = GenesisParams::import(tampered_export).unwrap_err();
+    assert!(matches!(err, GenesisError::Other(_)), "{err:?}");
+}
@@ -1,6 +1,6 @@
 //! Primitive Merkle tree API used internally to fetch proofs.
 
-use std::{fmt, future::Future, net::SocketAddr, pin::Pin};
+use std::{collections::HashMap, fmt, future::Future, net::SocketAddr, pin::Pin, str::FromStr};
 
 use anyhow::Context as _;
 use async_trait::async_trait;
@@ -10,11 +10,14 @@ use axum::{
     response::{IntoResponse, Response},
     routing, Json, Router,
 };
-use serde::{Deserialize, Serialize};
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize, Serializer};
 use tokio::sync::watch;
 use zksync_health_check::{CheckHealth, Health, HealthStatus};
-use zksync_merkle_tree::NoVersionError;
-use zksync_types::{L1BatchNumber, H256, U256};
+use zksync_merkle_tree::{
+    unstable::{ChildRef, InternalNode, LeafNode, NodeKey, RawNode},
+    NoVersionError,
+};
+use zksync_types::{web3, L1BatchNumber, H256, U256};
 
 use self::metrics::{MerkleTreeApiMethod, API_METRICS};
 use crate::{AsyncTreeReader, LazyAsyncTreeReader, MerkleTreeInfo};
@@ -61,6 +64,102 @@ impl TreeEntryWithProof {
     }
 }
 
+/// Node key serialized as a string in the `{version}:{nibbles}` format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct HexNodeKey(NodeKey);
+
+impl Serialize for HexNodeKey {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for HexNodeKey {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        NodeKey::from_str(&s).map(Self).map_err(D::Error::custom)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct TreeNodesRequest {
+    keys: Vec<HexNodeKey>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct TreeNodesResponse {
+    nodes: HashMap<HexNodeKey, ApiRawNode>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ApiLeafNode {
+    full_key: U256,
+    value_hash: H256,
+    leaf_index: u64,
+}
+
+impl From<LeafNode> for ApiLeafNode {
+    fn from(node: LeafNode) -> Self {
+        Self {
+            full_key: node.full_key,
+            value_hash: node.value_hash,
+            leaf_index: node.leaf_index,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ApiChildRef {
+    hash: H256,
+    version: u64,
+    is_leaf: bool,
+}
+
+impl From<&ChildRef> for ApiChildRef {
+    fn from(child_ref: &ChildRef) -> Self {
+        Self {
+            hash: child_ref.hash,
+            version: child_ref.version,
+            is_leaf: child_ref.is_leaf,
+        }
+    }
+}
+
+/// Children of an internal node keyed by a hex nibble (`0`..`f`).
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+struct ApiInternalNode(HashMap<char, ApiChildRef>);
+
+impl From<InternalNode> for ApiInternalNode {
+    fn from(node: InternalNode) -> Self {
+        let children = node.children().map(|(nibble, child_ref)| {
+            let nibble = char::from_digit(nibble.into(), 16).unwrap();
+            // ^ `unwrap()` is safe: nibbles are always less than 16
+            (nibble, child_ref.into())
+        });
+        Self(children.collect())
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ApiRawNode {
+    raw: web3::Bytes,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    leaf: Option<ApiLeafNode>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    internal: Option<ApiInternalNode>,
+}
+
+impl From<RawNode> for ApiRawNode {
+    fn from(node: RawNode) -> Self {
+        Self {
+            raw: web3::Bytes(node.raw),
+            leaf: node.leaf.map(ApiLeafNode::from),
+            internal: node.internal.map(ApiInternalNode::from),
+        }
+    }
+}
+
 /// Server-side tree API error.
 #[derive(Debug)]
 enum TreeApiServerError {
@@ -327,6 +426,23 @@ impl AsyncTreeReader {
         Ok(Json(response))
     }
 
+    async fn get_nodes_handler(
+        State(this): State<Self>,
+        Json(request): Json<TreeNodesRequest>,
+    ) -> Json<TreeNodesResponse> {
+        let latency = API_METRICS.latency[&MerkleTreeApiMethod::GetNodes].start();
+        let keys: Vec<_> = request.keys.iter().map(|key| key.0).collect();
+        let nodes = this.raw_nodes(keys).await;
+        let nodes = request
+            .keys
+            .into_iter()
+            .zip(nodes)
+            .filter_map(|(key, node)| Some((key, node?.into())))
+            .collect();
+        latency.observe();
+        Json(TreeNodesResponse { nodes })
+    }
+
     fn create_api_server(
         self,
         bind_address: &SocketAddr,
@@ -337,6 +453,7 @@ impl AsyncTreeReader {
         let app = Router::new()
             .route("/", routing::get(Self::info_handler))
             .route("/proofs", routing::post(Self::get_proofs_handler))
+            .route("/debug/nodes", routing::post(Self::get_nodes_handler))
             .with_state(self);
 
         let server = axum::Server::try_bind(bind_address)
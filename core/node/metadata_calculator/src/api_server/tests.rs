@@ -71,6 +71,38 @@ async fn merkle_tree_api() {
     assert_eq!(err.version_count, 6);
     assert_eq!(err.missing_version, 10);
 
+    // Query raw tree nodes.
+    let nodes_url = format!("http://{local_addr}/debug/nodes");
+    let http_client = reqwest::Client::new();
+    let request = serde_json::json!({ "keys": ["5:", "100:"] });
+    let response: TreeNodesResponse = http_client
+        .post(&nodes_url)
+        .json(&request)
+        .send()
+        .await
+        .unwrap()
+        .error_for_status()
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(response.nodes.len(), 1, "{response:?}");
+    let root = &response.nodes[&HexNodeKey("5:".parse().unwrap())];
+    assert!(!root.raw.0.is_empty());
+    assert!(root.leaf.is_none());
+    let root_children = &root.internal.as_ref().unwrap().0;
+    assert!(!root_children.is_empty());
+    assert!(root_children.values().all(|child| child.version <= 5));
+
+    let request = serde_json::json!({ "keys": ["not a key"] });
+    let response = http_client
+        .post(&nodes_url)
+        .json(&request)
+        .send()
+        .await
+        .unwrap();
+    assert!(response.status().is_client_error(), "{response:?}");
+
     // Stop the calculator and the tree API server.
     stop_sender.send_replace(true);
     api_server_task.await.unwrap().unwrap();
@@ -50,6 +50,11 @@ pub struct MetadataCalculatorRecoveryConfig {
     ///
     /// If set to `None`, parallel persistence will be disabled.
     pub parallel_persistence_buffer: Option<NonZeroUsize>,
+    /// Maximum number of chunks loaded from Postgres concurrently. The effective concurrency is additionally capped
+    /// by the size of the recovery connection pool.
+    ///
+    /// If set to `None`, the concurrency is equal to the recovery pool size.
+    pub concurrency_limit: Option<NonZeroUsize>,
 }
 
 impl Default for MetadataCalculatorRecoveryConfig {
@@ -57,6 +62,16 @@ impl Default for MetadataCalculatorRecoveryConfig {
         Self {
             desired_chunk_size: 200_000,
             parallel_persistence_buffer: NonZeroUsize::new(4),
+            concurrency_limit: None,
+        }
+    }
+}
+
+impl MetadataCalculatorRecoveryConfig {
+    fn effective_concurrency_limit(&self, pool_size: usize) -> usize {
+        match self.concurrency_limit {
+            Some(limit) => limit.get().min(pool_size),
+            None => pool_size,
         }
     }
 }
@@ -89,8 +104,15 @@ pub struct MetadataCalculatorConfig {
     pub memtable_capacity: usize,
     /// Timeout to wait for the Merkle tree database to run compaction on stalled writes.
     pub stalled_writes_timeout: Duration,
+    /// Number of threads in a dedicated thread pool used for parallel hash computations. 0 means the number
+    /// of logical CPUs. If not specified, the global `rayon` thread pool is used.
+    pub thread_pool_size: Option<usize>,
     /// Configuration specific to the Merkle tree recovery.
     pub recovery: MetadataCalculatorRecoveryConfig,
+    /// Whether protective reads are persisted by the state keeper when sealing L1 batches. If not, protective reads
+    /// are written asynchronously by the protective reads writer (VM runner), and the tree in the full mode
+    /// only processes L1 batches already handled by the writer. Irrelevant for the lightweight tree.
+    pub sealed_batches_have_protective_reads: bool,
 }
 
 impl MetadataCalculatorConfig {
@@ -109,8 +131,10 @@ impl MetadataCalculatorConfig {
             include_indices_and_filters_in_block_cache: false,
             memtable_capacity: merkle_tree_config.memtable_capacity(),
             stalled_writes_timeout: merkle_tree_config.stalled_writes_timeout(),
+            thread_pool_size: merkle_tree_config.thread_pool_size,
             // The main node isn't supposed to be recovered yet, so this value doesn't matter much
             recovery: MetadataCalculatorRecoveryConfig::default(),
+            sealed_batches_have_protective_reads: true,
         }
     }
 }
@@ -231,6 +255,12 @@ impl MetadataCalculator {
         let Some(mut tree) = tree else {
             return Ok(()); // recovery was aborted because a stop signal was received
         };
+        if let Some(thread_count) = self.config.thread_pool_size {
+            tracing::info!(
+                "Using dedicated thread pool with {thread_count} threads for the Merkle tree"
+            );
+            tree.use_dedicated_thread_pool(thread_count);
+        }
         // Set a tree reader before the tree is fully initialized to not wait for the first L1 batch to appear in Postgres.
         let tree_reader = tree.reader();
         self.tree_reader.send_replace(Some(tree_reader));
@@ -248,7 +278,12 @@ impl MetadataCalculator {
         self.health_updater
             .update(MerkleTreeHealth::MainLoop(tree_info).into());
 
-        let updater = TreeUpdater::new(tree, self.max_l1_batches_per_iter, self.object_store);
+        let updater = TreeUpdater::new(
+            tree,
+            self.max_l1_batches_per_iter,
+            self.config.sealed_batches_have_protective_reads,
+            self.object_store,
+        );
         updater
             .loop_updating_tree(self.delayer, &self.pool, stop_receiver)
             .await
@@ -14,7 +14,8 @@
 //! and feeding each chunk to the tree. Chunks are loaded concurrently since this is the most
 //! I/O-heavy operation; the concurrency is naturally limited by the number of connections to
 //! Postgres in the supplied connection pool, but we explicitly use a [`Semaphore`] to control it
-//! in order to not run into DB timeout errors. Before starting recovery in chunks, we filter out
+//! in order to not run into DB timeout errors. The concurrency can be further limited via
+//! [`MetadataCalculatorRecoveryConfig::concurrency_limit`]. Before starting recovery in chunks, we filter out
 //! chunks that have already been recovered by checking if the first key in a chunk is present
 //! in the tree. (Note that for this to work, chunks **must** always be defined in the same way.)
 //!
@@ -204,7 +205,8 @@ impl GenericAsyncTree {
         );
         let recovery_options = RecoveryOptions {
             chunk_count: snapshot.chunk_count(),
-            concurrency_limit: recovery_pool.max_size() as usize,
+            concurrency_limit: config
+                .effective_concurrency_limit(recovery_pool.max_size() as usize),
             events: Box::new(RecoveryHealthUpdater::new(health_updater)),
         };
         let tree = tree
@@ -1,6 +1,6 @@
 //! Tests for metadata calculator snapshot recovery.
 
-use std::{path::Path, sync::Mutex};
+use std::{num::NonZeroUsize, path::Path, sync::Mutex};
 
 use assert_matches::assert_matches;
 use tempfile::TempDir;
@@ -27,6 +27,15 @@ use crate::{
     MetadataCalculator, MetadataCalculatorConfig,
 };
 
+#[test]
+fn calculating_effective_concurrency_limit() {
+    let mut config = MetadataCalculatorRecoveryConfig::default();
+    assert_eq!(config.effective_concurrency_limit(10), 10);
+    config.concurrency_limit = NonZeroUsize::new(4);
+    assert_eq!(config.effective_concurrency_limit(10), 4);
+    assert_eq!(config.effective_concurrency_limit(2), 2);
+}
+
 #[test]
 fn calculating_chunk_count() {
     let mut snapshot = SnapshotParameters {
@@ -53,8 +62,9 @@ async fn create_tree_recovery(
     AsyncTreeRecovery::with_handle(db, l1_batch.0.into(), MerkleTreeMode::Full, config).unwrap()
 }
 
+#[test_casing(2, [1, 4])]
 #[tokio::test]
-async fn basic_recovery_workflow() {
+async fn basic_recovery_workflow(concurrency_limit: usize) {
     let pool = ConnectionPool::<Core>::test_pool().await;
     let temp_dir = TempDir::new().expect("failed get temporary directory for RocksDB");
     let snapshot_recovery = prepare_recovery_snapshot_with_genesis(pool.clone(), &temp_dir).await;
@@ -74,7 +84,7 @@ async fn basic_recovery_workflow() {
         let (health_check, health_updater) = ReactiveHealthCheck::new("tree");
         let recovery_options = RecoveryOptions {
             chunk_count,
-            concurrency_limit: 1,
+            concurrency_limit,
             events: Box::new(RecoveryHealthUpdater::new(&health_updater)),
         };
         let tree = tree
@@ -57,7 +57,9 @@ pub(super) fn mock_config(db_path: &Path) -> MetadataCalculatorConfig {
         include_indices_and_filters_in_block_cache: false,
         memtable_capacity: 16 << 20,            // 16 MiB
         stalled_writes_timeout: Duration::ZERO, // writes should never be stalled in tests
+        thread_pool_size: None,
         recovery: MetadataCalculatorRecoveryConfig::default(),
+        sealed_batches_have_protective_reads: true,
     }
 }
 
@@ -250,6 +252,48 @@ async fn basic_workflow() {
     assert_eq!(tree.next_l1_batch_number(), L1BatchNumber(2));
 }
 
+#[tokio::test]
+async fn workflow_waiting_for_protective_reads_writer() {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let temp_dir = TempDir::new().expect("failed get temporary directory for RocksDB");
+    let (mut calculator, _) = setup_calculator(temp_dir.path(), pool.clone()).await;
+    calculator.config.sealed_batches_have_protective_reads = false;
+    reset_db_state(&pool, 5).await;
+    pool.connection()
+        .await
+        .unwrap()
+        .vm_runner_dal()
+        .mark_protective_reads_batch_as_completed(L1BatchNumber(2))
+        .await
+        .unwrap();
+    run_calculator(calculator).await;
+
+    // The tree must not process L1 batches not handled by the protective reads writer.
+    let (calculator, _) = setup_calculator(temp_dir.path(), pool).await;
+    let tree = calculator.create_tree().await.unwrap();
+    let GenericAsyncTree::Ready(tree) = tree else {
+        panic!("Unexpected tree state: {tree:?}");
+    };
+    assert_eq!(tree.next_l1_batch_number(), L1BatchNumber(3));
+}
+
+#[tokio::test]
+async fn workflow_with_dedicated_thread_pool() {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let temp_dir = TempDir::new().expect("failed get temporary directory for RocksDB");
+    let (mut merkle_tree_config, operation_config) =
+        create_config(temp_dir.path(), MerkleTreeMode::Lightweight);
+    merkle_tree_config.thread_pool_size = Some(2);
+    let calculator =
+        setup_calculator_with_options(&merkle_tree_config, &operation_config, pool.clone(), None)
+            .await;
+    reset_db_state(&pool, 5).await;
+    let merkle_tree_hash = run_calculator(calculator).await;
+
+    let expected_tree_hash = expected_tree_hash(&pool).await;
+    assert_eq!(merkle_tree_hash, expected_tree_hash);
+}
+
 async fn expected_tree_hash(pool: &ConnectionPool<Core>) -> H256 {
     let mut storage = pool.connection().await.unwrap();
     let sealed_l1_batch_number = storage
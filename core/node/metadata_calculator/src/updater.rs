@@ -5,6 +5,7 @@ use std::{ops, sync::Arc, time::Instant};
 use anyhow::Context as _;
 use futures::{future, FutureExt};
 use tokio::sync::watch;
+use zksync_config::configs::database::MerkleTreeMode;
 use zksync_dal::{helpers::wait_for_l1_batch, Connection, ConnectionPool, Core, CoreDal};
 use zksync_merkle_tree::domain::TreeMetadata;
 use zksync_object_store::ObjectStore;
@@ -23,6 +24,7 @@ use super::{
 pub(super) struct TreeUpdater {
     tree: AsyncTree,
     max_l1_batches_per_iter: usize,
+    sealed_batches_have_protective_reads: bool,
     object_store: Option<Arc<dyn ObjectStore>>,
 }
 
@@ -30,11 +32,13 @@ impl TreeUpdater {
     pub fn new(
         tree: AsyncTree,
         max_l1_batches_per_iter: usize,
+        sealed_batches_have_protective_reads: bool,
         object_store: Option<Arc<dyn ObjectStore>>,
     ) -> Self {
         Self {
             tree,
             max_l1_batches_per_iter,
+            sealed_batches_have_protective_reads,
             object_store,
         }
     }
@@ -181,9 +185,24 @@ impl TreeUpdater {
             tracing::trace!("No L1 batches to seal: Postgres storage is empty");
             return Ok(());
         };
+        let last_l1_batch_with_protective_reads = if self.tree.mode() == MerkleTreeMode::Lightweight
+            || self.sealed_batches_have_protective_reads
+        {
+            last_sealed_l1_batch
+        } else {
+            // Protective reads are written by the protective reads writer; the tree cannot process L1 batches
+            // before they are written, since it would silently skip the reads.
+            storage
+                .vm_runner_dal()
+                .get_protective_reads_latest_processed_batch(L1BatchNumber(0))
+                .await
+                .context("failed loading latest L1 batch number with protective reads")?
+        };
         let last_requested_l1_batch =
             next_l1_batch_to_seal.0 + self.max_l1_batches_per_iter as u32 - 1;
-        let last_requested_l1_batch = last_requested_l1_batch.min(last_sealed_l1_batch.0);
+        let last_requested_l1_batch = last_requested_l1_batch
+            .min(last_sealed_l1_batch.0)
+            .min(last_l1_batch_with_protective_reads.0);
         let l1_batch_numbers = next_l1_batch_to_seal.0..=last_requested_l1_batch;
         if l1_batch_numbers.is_empty() {
             tracing::trace!(
@@ -251,6 +251,7 @@ impl MainNodeBuilder {
             subscriptions_limit: Some(rpc_config.subscriptions_limit()),
             batch_request_size_limit: Some(rpc_config.max_batch_request_size()),
             response_body_size_limit: Some(rpc_config.max_response_body_size()),
+            shutdown_drain_timeout: Some(rpc_config.shutdown_drain_timeout()),
             ..Default::default()
         };
         self.node.add_layer(Web3ServerLayer::http(
@@ -280,12 +281,18 @@ impl MainNodeBuilder {
             namespaces: Some(namespaces),
             filters_limit: Some(rpc_config.filters_limit()),
             subscriptions_limit: Some(rpc_config.subscriptions_limit()),
+            subscriptions_per_connection_limit: Some(
+                rpc_config.subscriptions_per_connection_limit(),
+            ),
+            subscription_backlog_size: Some(rpc_config.subscription_backlog_size()),
             batch_request_size_limit: Some(rpc_config.max_batch_request_size()),
             response_body_size_limit: Some(rpc_config.max_response_body_size()),
             websocket_requests_per_minute_limit: Some(
                 rpc_config.websocket_requests_per_minute_limit(),
             ),
+            shutdown_drain_timeout: Some(rpc_config.shutdown_drain_timeout()),
             replication_lag_limit: circuit_breaker_config.replication_lag_limit(),
+            ..Default::default()
         };
         self.node.add_layer(Web3ServerLayer::ws(
             rpc_config.ws_port,
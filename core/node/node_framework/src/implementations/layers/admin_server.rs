@@ -0,0 +1,129 @@
+use std::sync::Arc;
+
+use zksync_config::configs::{GeneralConfig, ReloadableConfig};
+use zksync_node_api_server::admin::{AdminServer, ConfigDiffer};
+use zksync_protobuf_config::diff::ConfigDiff;
+
+use crate::{
+    implementations::resources::{
+        pools::{MasterPool, PoolResource},
+        reloadable_config::ReloadableConfigResource,
+    },
+    resource::Updatable,
+    service::{ServiceContext, StopReceiver},
+    task::{TaskId, UnconstrainedTask},
+    wiring_layer::{WiringError, WiringLayer},
+};
+
+/// Builder for an admin server allowing to change log directives at runtime and, optionally,
+/// to capture CPU profiles and async task dumps, and to compare the effective general config with candidate ones.
+///
+/// The server is bound to the loopback interface only. Changes made via the server are recorded
+/// in the admin audit log.
+///
+/// ## Requests resources
+///
+/// - `PoolResource<MasterPool>`
+/// - `ReloadableConfigResource` (optional; used to account for hot-reloaded values in config diffs)
+///
+/// ## Effects
+///
+/// - Adds `admin_server` to the node.
+#[derive(Debug)]
+pub struct AdminServerLayer {
+    port: u16,
+    profiling_enabled: bool,
+    general_config: Option<GeneralConfig>,
+}
+
+impl AdminServerLayer {
+    pub fn new(port: u16) -> Self {
+        Self {
+            port,
+            profiling_enabled: false,
+            general_config: None,
+        }
+    }
+
+    /// Enables the config diff endpoint comparing the provided general config (with hot-reloaded values applied)
+    /// with candidate config files.
+    #[must_use]
+    pub fn with_config_diff(mut self, general_config: GeneralConfig) -> Self {
+        self.general_config = Some(general_config);
+        self
+    }
+
+    /// Enables or disables profiling endpoints.
+    #[must_use]
+    pub fn with_profiling(mut self, enabled: bool) -> Self {
+        self.profiling_enabled = enabled;
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl WiringLayer for AdminServerLayer {
+    fn layer_name(&self) -> &'static str {
+        "admin_server_layer"
+    }
+
+    async fn wire(self: Box<Self>, mut node: ServiceContext<'_>) -> Result<(), WiringError> {
+        let pool_resource = node.get_resource::<PoolResource<MasterPool>>().await?;
+        let pool = pool_resource.get_singleton().await?;
+        let mut server = AdminServer::new(([127, 0, 0, 1], self.port).into())
+            .with_profiling(self.profiling_enabled)
+            .with_audit_log(pool);
+
+        if let Some(config) = self.general_config {
+            let reloadable_config = match node.get_resource::<ReloadableConfigResource>().await {
+                Ok(ReloadableConfigResource(config)) => Some(config),
+                Err(WiringError::ResourceLacking { .. }) => None,
+                Err(err) => return Err(err),
+            };
+            let differ = GeneralConfigDiffer {
+                config,
+                reloadable_config,
+            };
+            server = server.with_config_differ(Arc::new(differ));
+        }
+        let task = AdminServerTask { server };
+        // The server doesn't depend on other tasks, and it should be available as soon as possible.
+        node.add_unconstrained_task(Box::new(task));
+        Ok(())
+    }
+}
+
+/// Compares the general config of the node with candidate general YAML configs.
+#[derive(Debug)]
+struct GeneralConfigDiffer {
+    config: GeneralConfig,
+    /// Set if the node hot-reloads its config file.
+    reloadable_config: Option<Updatable<ReloadableConfig>>,
+}
+
+impl ConfigDiffer for GeneralConfigDiffer {
+    fn diff(&self, candidate: &str) -> anyhow::Result<serde_json::Value> {
+        let mut config = self.config.clone();
+        if let Some(reloadable_config) = &self.reloadable_config {
+            reloadable_config.get().apply_to(&mut config);
+        }
+        let diff = ConfigDiff::new(&config, candidate, self.reloadable_config.is_some())?;
+        Ok(serde_json::to_value(diff)?)
+    }
+}
+
+#[derive(Debug)]
+struct AdminServerTask {
+    server: AdminServer,
+}
+
+#[async_trait::async_trait]
+impl UnconstrainedTask for AdminServerTask {
+    fn id(&self) -> TaskId {
+        "admin_server".into()
+    }
+
+    async fn run_unconstrained(self: Box<Self>, stop_receiver: StopReceiver) -> anyhow::Result<()> {
+        self.server.run(stop_receiver.0).await
+    }
+}
@@ -0,0 +1,107 @@
+use std::sync::Arc;
+
+use anyhow::Context as _;
+use zksync_config::BaseTokenPriceConfig;
+use zksync_node_fee_model::base_token::{
+    create_price_sources, BaseTokenRatioFetcher, BaseTokenRatioPersister,
+};
+
+use crate::{
+    implementations::resources::{
+        eth_interface::EthInterfaceResource,
+        pools::{MasterPool, PoolResource},
+    },
+    service::{ServiceContext, StopReceiver},
+    task::{Task, TaskId},
+    wiring_layer::{WiringError, WiringLayer},
+};
+
+/// Wiring layer for the base token ratio fetcher and persister.
+///
+/// Fetches the base token / ETH ratio from the configured price sources and persists it in Postgres,
+/// from where it's read by [`BaseTokenRatioProviderLayer`](super::base_token_ratio_provider::BaseTokenRatioProviderLayer).
+/// The initial ratio is persisted during wiring, so that providers in the same node can load it.
+///
+/// ## Requests resources
+///
+/// - `PoolResource<MasterPool>`
+/// - `EthInterfaceResource`
+///
+/// ## Adds tasks
+///
+/// - `BaseTokenRatioFetcherTask`
+/// - `BaseTokenRatioPersisterTask`
+#[derive(Debug)]
+pub struct BaseTokenRatioPersisterLayer {
+    config: BaseTokenPriceConfig,
+}
+
+impl BaseTokenRatioPersisterLayer {
+    pub fn new(config: BaseTokenPriceConfig) -> Self {
+        Self { config }
+    }
+}
+
+#[async_trait::async_trait]
+impl WiringLayer for BaseTokenRatioPersisterLayer {
+    fn layer_name(&self) -> &'static str {
+        "base_token_ratio_persister_layer"
+    }
+
+    async fn wire(self: Box<Self>, mut context: ServiceContext<'_>) -> Result<(), WiringError> {
+        let master_pool_resource = context.get_resource::<PoolResource<MasterPool>>().await?;
+        let master_pool = master_pool_resource.get_singleton().await?;
+        let client = context.get_resource::<EthInterfaceResource>().await?.0;
+
+        let sources = create_price_sources(&self.config, client)?;
+        let fetcher = BaseTokenRatioFetcher::new(&self.config, sources)
+            .await
+            .context("BaseTokenRatioFetcher::new()")?;
+        let fetcher = Arc::new(fetcher);
+        let mut persister = BaseTokenRatioPersister::new(
+            master_pool,
+            fetcher.clone(),
+            self.config.polling_interval(),
+        );
+        persister
+            .persist_current_ratio()
+            .await
+            .context("failed persisting initial base token ratio")?;
+
+        context.add_task(Box::new(BaseTokenRatioFetcherTask { fetcher }));
+        context.add_task(Box::new(BaseTokenRatioPersisterTask { persister }));
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+struct BaseTokenRatioFetcherTask {
+    fetcher: Arc<BaseTokenRatioFetcher>,
+}
+
+#[async_trait::async_trait]
+impl Task for BaseTokenRatioFetcherTask {
+    fn id(&self) -> TaskId {
+        "base_token_ratio_fetcher".into()
+    }
+
+    async fn run(self: Box<Self>, stop_receiver: StopReceiver) -> anyhow::Result<()> {
+        self.fetcher.run(stop_receiver.0).await
+    }
+}
+
+#[derive(Debug)]
+struct BaseTokenRatioPersisterTask {
+    persister: BaseTokenRatioPersister,
+}
+
+#[async_trait::async_trait]
+impl Task for BaseTokenRatioPersisterTask {
+    fn id(&self) -> TaskId {
+        "base_token_ratio_persister".into()
+    }
+
+    async fn run(self: Box<Self>, stop_receiver: StopReceiver) -> anyhow::Result<()> {
+        self.persister.run(stop_receiver.0).await
+    }
+}
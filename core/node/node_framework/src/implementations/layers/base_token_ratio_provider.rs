@@ -0,0 +1,78 @@
+use std::sync::Arc;
+
+use anyhow::Context as _;
+use zksync_config::BaseTokenPriceConfig;
+use zksync_node_fee_model::base_token::DbBaseTokenRatioProvider;
+
+use crate::{
+    implementations::resources::{
+        base_token_ratio_provider::BaseTokenRatioResource,
+        pools::{MasterPool, PoolResource},
+    },
+    service::{ServiceContext, StopReceiver},
+    task::{Task, TaskId},
+    wiring_layer::{WiringError, WiringLayer},
+};
+
+/// Wiring layer for the base token ratio provider, which periodically loads the ratio persisted
+/// by [`BaseTokenRatioPersisterLayer`](super::base_token_ratio_persister::BaseTokenRatioPersisterLayer).
+/// The ratio is used by the fee model to convert fees into the base token.
+///
+/// ## Requests resources
+///
+/// - `PoolResource<MasterPool>`
+///
+/// ## Adds resources
+///
+/// - `BaseTokenRatioResource`
+///
+/// ## Adds tasks
+///
+/// - `BaseTokenRatioProviderTask`
+#[derive(Debug)]
+pub struct BaseTokenRatioProviderLayer {
+    config: BaseTokenPriceConfig,
+}
+
+impl BaseTokenRatioProviderLayer {
+    pub fn new(config: BaseTokenPriceConfig) -> Self {
+        Self { config }
+    }
+}
+
+#[async_trait::async_trait]
+impl WiringLayer for BaseTokenRatioProviderLayer {
+    fn layer_name(&self) -> &'static str {
+        "base_token_ratio_provider_layer"
+    }
+
+    async fn wire(self: Box<Self>, mut context: ServiceContext<'_>) -> Result<(), WiringError> {
+        let master_pool_resource = context.get_resource::<PoolResource<MasterPool>>().await?;
+        let master_pool = master_pool_resource.get_singleton().await?;
+
+        let provider =
+            DbBaseTokenRatioProvider::new(master_pool, self.config.ratio_cache_update_interval())
+                .await
+                .context("DbBaseTokenRatioProvider::new()")?;
+        let provider = Arc::new(provider);
+        context.insert_resource(BaseTokenRatioResource(provider.clone()))?;
+        context.add_task(Box::new(BaseTokenRatioProviderTask { provider }));
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+struct BaseTokenRatioProviderTask {
+    provider: Arc<DbBaseTokenRatioProvider>,
+}
+
+#[async_trait::async_trait]
+impl Task for BaseTokenRatioProviderTask {
+    fn id(&self) -> TaskId {
+        "base_token_ratio_provider".into()
+    }
+
+    async fn run(self: Box<Self>, stop_receiver: StopReceiver) -> anyhow::Result<()> {
+        self.provider.run(stop_receiver.0).await
+    }
+}
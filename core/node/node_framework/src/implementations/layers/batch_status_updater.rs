@@ -0,0 +1,72 @@
+use zksync_node_sync::batch_status_updater::BatchStatusUpdater;
+
+use crate::{
+    implementations::resources::{
+        healthcheck::AppHealthCheckResource,
+        main_node_client::MainNodeClientResource,
+        pools::{MasterPool, PoolResource},
+    },
+    service::{ServiceContext, StopReceiver},
+    task::{Task, TaskId},
+    wiring_layer::{WiringError, WiringLayer},
+};
+
+/// Wiring layer for the batch status updater, which mirrors commit / prove / execute L1 transactions
+/// of L1 batches from the main node.
+///
+/// The updater polls the main node for L1 batch details and persists L1 transaction hashes and timestamps
+/// for each finality stage, so that `zks_getBlockDetails` and `zks_getL1BatchDetails` on the external node
+/// return the same finality metadata as the main node.
+///
+/// ## Requests resources
+///
+/// - `MainNodeClientResource`
+/// - `PoolResource<MasterPool>`
+/// - `AppHealthCheckResource` (adds a health check)
+///
+/// ## Adds tasks
+///
+/// - `BatchStatusUpdaterTask`
+#[derive(Debug)]
+pub struct BatchStatusUpdaterLayer;
+
+#[async_trait::async_trait]
+impl WiringLayer for BatchStatusUpdaterLayer {
+    fn layer_name(&self) -> &'static str {
+        "batch_status_updater_layer"
+    }
+
+    async fn wire(self: Box<Self>, mut context: ServiceContext<'_>) -> Result<(), WiringError> {
+        // Get resources.
+        let MainNodeClientResource(main_node_client) = context.get_resource().await?;
+        let pool_resource = context.get_resource::<PoolResource<MasterPool>>().await?;
+        let singleton_pool = pool_resource.get_singleton().await?;
+
+        let updater = BatchStatusUpdater::new(main_node_client, singleton_pool);
+
+        let AppHealthCheckResource(app_health) = context.get_resource_or_default().await;
+        app_health
+            .insert_component(updater.health_check())
+            .map_err(WiringError::internal)?;
+
+        // Create and add tasks.
+        context.add_task(Box::new(BatchStatusUpdaterTask { updater }));
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub struct BatchStatusUpdaterTask {
+    updater: BatchStatusUpdater,
+}
+
+#[async_trait::async_trait]
+impl Task for BatchStatusUpdaterTask {
+    fn id(&self) -> TaskId {
+        "batch_status_updater".into()
+    }
+
+    async fn run(self: Box<Self>, stop_receiver: StopReceiver) -> anyhow::Result<()> {
+        self.updater.run(stop_receiver.0).await
+    }
+}
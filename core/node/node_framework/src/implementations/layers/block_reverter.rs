@@ -0,0 +1,89 @@
+use std::sync::Arc;
+
+use zksync_block_reverter::{BlockReverter, NodeRole};
+
+use crate::{
+    implementations::resources::{
+        pools::{MasterPool, PoolResource},
+        reverter::BlockReverterResource,
+    },
+    service::ServiceContext,
+    wiring_layer::{WiringError, WiringLayer},
+};
+
+/// Wiring layer providing a block reverter, which is used to roll back the node state (e.g., after a reorg
+/// is detected by [`ReorgDetectorLayer`](super::reorg_detector::ReorgDetectorLayer)).
+///
+/// Postgres is always rolled back; the Merkle tree and the state keeper cache are only rolled back
+/// if their paths are specified.
+///
+/// ## Requests resources
+///
+/// - `PoolResource<MasterPool>`
+///
+/// ## Adds resources
+///
+/// - `BlockReverterResource`
+#[derive(Debug)]
+pub struct BlockReverterLayer {
+    node_role: NodeRole,
+    allow_rolling_back_executed_batches: bool,
+    merkle_tree_path: Option<String>,
+    state_keeper_cache_path: Option<String>,
+}
+
+impl BlockReverterLayer {
+    pub fn new(node_role: NodeRole) -> Self {
+        Self {
+            node_role,
+            allow_rolling_back_executed_batches: false,
+            merkle_tree_path: None,
+            state_keeper_cache_path: None,
+        }
+    }
+
+    /// Allows rolling back L1 batches executed on L1. This is more-or-less safe for external nodes.
+    pub fn allow_rolling_back_executed_batches(mut self) -> Self {
+        self.allow_rolling_back_executed_batches = true;
+        self
+    }
+
+    pub fn enable_rolling_back_merkle_tree(mut self, path: String) -> Self {
+        self.merkle_tree_path = Some(path);
+        self
+    }
+
+    pub fn enable_rolling_back_state_keeper_cache(mut self, path: String) -> Self {
+        self.state_keeper_cache_path = Some(path);
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl WiringLayer for BlockReverterLayer {
+    fn layer_name(&self) -> &'static str {
+        "block_reverter_layer"
+    }
+
+    async fn wire(self: Box<Self>, mut context: ServiceContext<'_>) -> Result<(), WiringError> {
+        let pool_resource = context.get_resource::<PoolResource<MasterPool>>().await?;
+        let pool = pool_resource.get().await?;
+
+        let mut reverter = BlockReverter::new(self.node_role, pool);
+        reverter
+            .enable_rolling_back_postgres()
+            .set_audit_actor("block_reverter_layer");
+        if self.allow_rolling_back_executed_batches {
+            reverter.allow_rolling_back_executed_batches();
+        }
+        if let Some(path) = self.merkle_tree_path {
+            reverter.enable_rolling_back_merkle_tree(path);
+        }
+        if let Some(path) = self.state_keeper_cache_path {
+            reverter.enable_rolling_back_state_keeper_cache(path);
+        }
+
+        context.insert_resource(BlockReverterResource(Arc::new(reverter)))?;
+        Ok(())
+    }
+}
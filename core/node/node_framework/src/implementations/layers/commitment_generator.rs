@@ -1,3 +1,5 @@
+use std::num::NonZeroU32;
+
 use zksync_commitment_generator::CommitmentGenerator;
 use zksync_types::commitment::L1BatchCommitmentMode;
 
@@ -14,11 +16,22 @@ use crate::{
 #[derive(Debug)]
 pub struct CommitmentGeneratorLayer {
     mode: L1BatchCommitmentMode,
+    max_parallelism: Option<NonZeroU32>,
 }
 
 impl CommitmentGeneratorLayer {
     pub fn new(mode: L1BatchCommitmentMode) -> Self {
-        Self { mode }
+        Self {
+            mode,
+            max_parallelism: None,
+        }
+    }
+
+    /// Sets the maximum number of L1 batches processed concurrently. If not set, the generator determines it
+    /// based on the number of CPU cores available.
+    pub fn with_max_parallelism(mut self, max_parallelism: NonZeroU32) -> Self {
+        self.max_parallelism = Some(max_parallelism);
+        self
     }
 }
 
@@ -30,10 +43,13 @@ impl WiringLayer for CommitmentGeneratorLayer {
 
     async fn wire(self: Box<Self>, mut context: ServiceContext<'_>) -> Result<(), WiringError> {
         let pool_resource = context.get_resource::<PoolResource<MasterPool>>().await?;
-        let pool_size = CommitmentGenerator::default_parallelism().get();
-        let main_pool = pool_resource.get_custom(pool_size).await?;
+        let parallelism = self
+            .max_parallelism
+            .unwrap_or_else(CommitmentGenerator::default_parallelism);
+        let main_pool = pool_resource.get_custom(parallelism.get()).await?;
 
-        let commitment_generator = CommitmentGenerator::new(main_pool, self.mode);
+        let mut commitment_generator = CommitmentGenerator::new(main_pool, self.mode);
+        commitment_generator.set_max_parallelism(parallelism);
 
         let AppHealthCheckResource(app_health) = context.get_resource_or_default().await;
         app_health
@@ -0,0 +1,218 @@
+use std::{path::PathBuf, time::Duration};
+
+use anyhow::Context as _;
+use zksync_config::configs::ReloadableConfig;
+use zksync_dal::{ConnectionPool, Core, CoreDal};
+
+use crate::{
+    implementations::resources::{
+        pools::{MasterPool, PoolResource},
+        reloadable_config::ReloadableConfigResource,
+    },
+    resource::Updatable,
+    service::{ServiceContext, StopReceiver},
+    task::{TaskId, UnconstrainedTask},
+    wiring_layer::{WiringError, WiringLayer},
+};
+
+/// Parses the reloadable part of the config from the config file contents.
+pub type ReloadableConfigParser = fn(&str) -> anyhow::Result<ReloadableConfig>;
+
+/// Wiring layer for the config reloader, which watches the config file for changes and applies changes
+/// to the [`ReloadableConfig`] values at runtime. Changes to other config values are ignored until the node is restarted.
+///
+/// Each applied change is recorded in the admin audit log.
+///
+/// ## Requests resources
+///
+/// - `PoolResource<MasterPool>` (optional; used to record changes in the audit log)
+///
+/// ## Adds resources
+///
+/// - `ReloadableConfigResource`
+///
+/// ## Adds tasks
+///
+/// - `ConfigReloaderTask`
+#[derive(Debug)]
+pub struct ConfigReloaderLayer {
+    config_path: PathBuf,
+    initial_config: ReloadableConfig,
+    parser: ReloadableConfigParser,
+    poll_interval: Duration,
+}
+
+impl ConfigReloaderLayer {
+    const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+    pub fn new(
+        config_path: PathBuf,
+        initial_config: ReloadableConfig,
+        parser: ReloadableConfigParser,
+    ) -> Self {
+        Self {
+            config_path,
+            initial_config,
+            parser,
+            poll_interval: Self::DEFAULT_POLL_INTERVAL,
+        }
+    }
+
+    /// Sets the interval between checks of the config file.
+    #[must_use]
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl WiringLayer for ConfigReloaderLayer {
+    fn layer_name(&self) -> &'static str {
+        "config_reloader_layer"
+    }
+
+    async fn wire(self: Box<Self>, mut context: ServiceContext<'_>) -> Result<(), WiringError> {
+        let audit_pool = match context.get_resource::<PoolResource<MasterPool>>().await {
+            Ok(pool_resource) => Some(pool_resource.get_singleton().await?),
+            Err(WiringError::ResourceLacking { .. }) => None,
+            Err(err) => return Err(err),
+        };
+        let config = Updatable::new(self.initial_config);
+        context.insert_resource(ReloadableConfigResource(config.clone()))?;
+
+        let task = ConfigReloaderTask {
+            config_path: self.config_path,
+            parser: self.parser,
+            poll_interval: self.poll_interval,
+            config,
+            audit_pool,
+        };
+        // Reloading doesn't depend on other tasks, and it shouldn't prevent the node from starting.
+        context.add_unconstrained_task(Box::new(task));
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+struct ConfigReloaderTask {
+    config_path: PathBuf,
+    parser: ReloadableConfigParser,
+    poll_interval: Duration,
+    config: Updatable<ReloadableConfig>,
+    audit_pool: Option<ConnectionPool<Core>>,
+}
+
+impl ConfigReloaderTask {
+    const AUDIT_ACTOR: &'static str = "config_reloader";
+
+    async fn reload(&self, contents: &str) -> anyhow::Result<()> {
+        let mut new_config = (self.parser)(contents).context("failed parsing config")?;
+        let current_config = self.config.get();
+
+        if new_config.log_directives != current_config.log_directives {
+            self.apply_log_directives(&mut new_config, &current_config);
+        }
+        let changed_fields = current_config.changed_fields(&new_config);
+        if changed_fields.is_empty() {
+            tracing::info!(
+                "Config file `{}` has changed, but reloadable values are unchanged; other changes require a restart",
+                self.config_path.display()
+            );
+            return Ok(());
+        }
+
+        tracing::info!(
+            "Applying changes to reloadable config values {changed_fields:?} from `{}`: {new_config:?}",
+            self.config_path.display()
+        );
+        let params = serde_json::json!({
+            "path": self.config_path.display().to_string(),
+            "changed_fields": changed_fields,
+            "config": new_config,
+        });
+        self.config.update(new_config);
+
+        if let Some(pool) = &self.audit_pool {
+            let mut storage = pool.connection_tagged("config_reloader").await?;
+            storage
+                .admin_audit_log_dal()
+                .insert_entry(Self::AUDIT_ACTOR, "reload_config", &params)
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Applies the new log directives. If the directives cannot be applied, retains the current ones in `new_config`.
+    fn apply_log_directives(
+        &self,
+        new_config: &mut ReloadableConfig,
+        current_config: &ReloadableConfig,
+    ) {
+        let Some(directives) = &new_config.log_directives else {
+            tracing::warn!("Log directives were removed from the config; this requires a restart");
+            new_config.log_directives = current_config.log_directives.clone();
+            return;
+        };
+        let Some(handle) = vlog::LogDirectivesHandle::global() else {
+            tracing::warn!(
+                "Cannot update log directives: observability subsystem is not initialized"
+            );
+            new_config.log_directives = current_config.log_directives.clone();
+            return;
+        };
+        if let Err(err) = handle.update(directives) {
+            tracing::warn!("Cannot update log directives to `{directives}`: {err}");
+            new_config.log_directives = current_config.log_directives.clone();
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl UnconstrainedTask for ConfigReloaderTask {
+    fn id(&self) -> TaskId {
+        "config_reloader".into()
+    }
+
+    async fn run_unconstrained(
+        self: Box<Self>,
+        mut stop_receiver: StopReceiver,
+    ) -> anyhow::Result<()> {
+        // The file is polled rather than watched using OS notifications, since notifications are unreliable
+        // for some deployment setups (e.g., config maps in Kubernetes are updated by swapping symlinks).
+        let mut last_contents = tokio::fs::read_to_string(&self.config_path).await.ok();
+        while !*stop_receiver.0.borrow() {
+            if tokio::time::timeout(self.poll_interval, stop_receiver.0.changed())
+                .await
+                .is_ok()
+            {
+                break;
+            }
+
+            let contents = match tokio::fs::read_to_string(&self.config_path).await {
+                Ok(contents) => contents,
+                Err(err) => {
+                    tracing::warn!(
+                        "Failed reading config file `{}`: {err}",
+                        self.config_path.display()
+                    );
+                    continue;
+                }
+            };
+            if last_contents.as_ref() == Some(&contents) {
+                continue;
+            }
+            last_contents = Some(contents);
+
+            let contents = last_contents.as_deref().unwrap();
+            if let Err(err) = self.reload(contents).await {
+                tracing::warn!(
+                    "Failed reloading config from `{}`, keeping the current values: {err:#}",
+                    self.config_path.display()
+                );
+            }
+        }
+        tracing::info!("Stop signal received, config reloader is shutting down");
+        Ok(())
+    }
+}
@@ -2,6 +2,7 @@ use anyhow::Context as _;
 use zksync_concurrency::{ctx, scope};
 use zksync_config::configs::consensus::{ConsensusConfig, ConsensusSecrets};
 use zksync_dal::{ConnectionPool, Core};
+use zksync_health_check::{HealthUpdater, ReactiveHealthCheck};
 use zksync_node_consensus as consensus;
 use zksync_node_sync::{ActionQueueSender, SyncState};
 use zksync_web3_decl::client::{DynClient, L2};
@@ -9,6 +10,7 @@ use zksync_web3_decl::client::{DynClient, L2};
 use crate::{
     implementations::resources::{
         action_queue::ActionQueueSenderResource,
+        healthcheck::AppHealthCheckResource,
         main_node_client::MainNodeClientResource,
         pools::{MasterPool, PoolResource},
         sync_state::SyncStateResource,
@@ -29,6 +31,9 @@ pub struct ConsensusLayer {
     pub mode: Mode,
     pub config: Option<ConsensusConfig>,
     pub secrets: Option<ConsensusSecrets>,
+    /// MAIN NODE ONLY: URL of the L2 JSON-RPC API used to read the consensus registry
+    /// (if it is configured). Normally, it is the HTTP API of the main node itself.
+    pub registry_rpc_url: Option<String>,
 }
 
 #[async_trait::async_trait]
@@ -52,10 +57,24 @@ impl WiringLayer for ConsensusLayer {
                 let secrets = self.secrets.ok_or_else(|| {
                     WiringError::Configuration("Missing private consensus config".to_string())
                 })?;
+                let registry_client = match &self.registry_rpc_url {
+                    Some(url) => consensus::era::registry_client(&config, url)
+                        .map_err(WiringError::Internal)?,
+                    None => None,
+                };
+                add_attester_task(
+                    &mut context,
+                    &pool,
+                    &config,
+                    &secrets,
+                    registry_client.clone(),
+                )
+                .await?;
                 let task = MainNodeConsensusTask {
                     config,
                     secrets,
                     pool,
+                    registry_client,
                 };
                 context.add_task(Box::new(task));
             }
@@ -87,12 +106,31 @@ impl WiringLayer for ConsensusLayer {
                     }
                 };
 
+                if let Some((cfg, secrets)) = &config {
+                    add_attester_task(
+                        &mut context,
+                        &pool,
+                        cfg,
+                        secrets,
+                        Some(main_node_client.clone()),
+                    )
+                    .await?;
+                }
+                let (health_check, health_updater) = ReactiveHealthCheck::new("consensus");
+                if config.is_some() {
+                    let AppHealthCheckResource(app_health) =
+                        context.get_resource_or_default().await;
+                    app_health
+                        .insert_component(health_check)
+                        .map_err(WiringError::internal)?;
+                }
                 let task = FetcherTask {
                     config,
                     pool,
                     main_node_client,
                     sync_state,
                     action_queue_sender,
+                    health_updater,
                 };
                 context.add_task(Box::new(task));
             }
@@ -101,11 +139,36 @@ impl WiringLayer for ConsensusLayer {
     }
 }
 
+/// Adds the attester task if the attester key is configured.
+async fn add_attester_task(
+    context: &mut ServiceContext<'_>,
+    pool: &ConnectionPool<Core>,
+    config: &ConsensusConfig,
+    secrets: &ConsensusSecrets,
+    registry_client: Option<Box<DynClient<L2>>>,
+) -> Result<(), WiringError> {
+    let Some(mut attester) =
+        consensus::Attester::new(pool.clone(), secrets).map_err(WiringError::Internal)?
+    else {
+        return Ok(());
+    };
+    if let Some(client) = registry_client {
+        attester = attester.with_registry(config, client);
+    }
+    let AppHealthCheckResource(app_health) = context.get_resource_or_default().await;
+    app_health
+        .insert_component(attester.health_check())
+        .map_err(WiringError::internal)?;
+    context.add_task(Box::new(AttesterTask { attester }));
+    Ok(())
+}
+
 #[derive(Debug)]
 pub struct MainNodeConsensusTask {
     config: ConsensusConfig,
     secrets: ConsensusSecrets,
     pool: ConnectionPool<Core>,
+    registry_client: Option<Box<DynClient<L2>>>,
 }
 
 #[async_trait::async_trait]
@@ -128,6 +191,7 @@ impl Task for MainNodeConsensusTask {
                 self.config,
                 self.secrets,
                 self.pool,
+                self.registry_client,
             ));
             let _ = stop_receiver.0.wait_for(|stop| *stop).await?;
             Ok(())
@@ -143,6 +207,7 @@ pub struct FetcherTask {
     main_node_client: Box<DynClient<L2>>,
     sync_state: SyncState,
     action_queue_sender: ActionQueueSender,
+    health_updater: HealthUpdater,
 }
 
 #[async_trait::async_trait]
@@ -167,6 +232,7 @@ impl Task for FetcherTask {
                 self.sync_state,
                 self.main_node_client,
                 self.action_queue_sender,
+                self.health_updater,
             ));
             ctx.wait(stop_receiver.0.wait_for(|stop| *stop)).await??;
             Ok(())
@@ -175,3 +241,26 @@ impl Task for FetcherTask {
         .context("consensus actor")
     }
 }
+
+#[derive(Debug)]
+pub struct AttesterTask {
+    attester: consensus::Attester,
+}
+
+#[async_trait::async_trait]
+impl Task for AttesterTask {
+    fn id(&self) -> TaskId {
+        "consensus_attester".into()
+    }
+
+    async fn run(self: Box<Self>, mut stop_receiver: StopReceiver) -> anyhow::Result<()> {
+        let root_ctx = ctx::root();
+        scope::run!(&root_ctx, |ctx, s| async {
+            s.spawn_bg(self.attester.run(ctx));
+            ctx.wait(stop_receiver.0.wait_for(|stop| *stop)).await??;
+            Ok(())
+        })
+        .await
+        .context("consensus attester")
+    }
+}
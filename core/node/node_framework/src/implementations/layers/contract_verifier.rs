@@ -0,0 +1,84 @@
+use zksync_config::{ContractVerifierConfig, ObjectStoreConfig};
+use zksync_contract_verifier_lib::ContractVerifier;
+
+use crate::{
+    implementations::resources::pools::{MasterPool, PoolResource},
+    service::{ServiceContext, StopReceiver},
+    task::{Task, TaskId},
+    wiring_layer::{WiringError, WiringLayer},
+};
+
+/// Wiring layer for the contract verifier, which processes verification requests submitted
+/// via the contract verification API.
+///
+/// Compilers are expected to be placed in the `etc/{zksolc,solc,zkvyper,vyper}-bin` directories
+/// of the workspace; available compiler versions are persisted in Postgres on the verifier start.
+///
+/// ## Requests resources
+///
+/// - `PoolResource<MasterPool>`
+///
+/// ## Adds tasks
+///
+/// - `ContractVerifierTask`
+#[derive(Debug)]
+pub struct ContractVerifierLayer {
+    config: ContractVerifierConfig,
+    artifacts_cache_config: Option<ObjectStoreConfig>,
+}
+
+impl ContractVerifierLayer {
+    pub fn new(config: ContractVerifierConfig) -> Self {
+        Self {
+            config,
+            artifacts_cache_config: None,
+        }
+    }
+
+    /// Enables caching of compilation artifacts in an object store with the specified config. The object store
+    /// is separate from the one provided by `ObjectStoreResource`, since they generally use different buckets.
+    pub fn with_artifacts_cache(mut self, object_store_config: ObjectStoreConfig) -> Self {
+        self.artifacts_cache_config = Some(object_store_config);
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl WiringLayer for ContractVerifierLayer {
+    fn layer_name(&self) -> &'static str {
+        "contract_verifier_layer"
+    }
+
+    async fn wire(self: Box<Self>, mut context: ServiceContext<'_>) -> Result<(), WiringError> {
+        let pool = context
+            .get_resource::<PoolResource<MasterPool>>()
+            .await?
+            .get()
+            .await?;
+        let mut verifier = ContractVerifier::new(self.config, pool);
+        if let Some(object_store_config) = self.artifacts_cache_config {
+            verifier = verifier
+                .with_artifacts_cache_config(object_store_config)
+                .await?;
+        }
+
+        context.add_task(Box::new(ContractVerifierTask(verifier)));
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub struct ContractVerifierTask(ContractVerifier);
+
+#[async_trait::async_trait]
+impl Task for ContractVerifierTask {
+    fn id(&self) -> TaskId {
+        "contract_verifier".into()
+    }
+
+    async fn run(self: Box<Self>, stop_receiver: StopReceiver) -> anyhow::Result<()> {
+        self.0
+            .update_compiler_versions_and_run(stop_receiver.0)
+            .await
+    }
+}
@@ -0,0 +1,34 @@
+use zksync_config::configs::da_client::avail::AvailConfig;
+use zksync_da_clients::avail::AvailClient;
+
+use crate::{
+    implementations::resources::da_client::DAClientResource,
+    service::ServiceContext,
+    wiring_layer::{WiringError, WiringLayer},
+};
+
+/// Wiring layer for the Avail client.
+#[derive(Debug)]
+pub struct AvailWiringLayer {
+    config: AvailConfig,
+}
+
+impl AvailWiringLayer {
+    pub fn new(config: AvailConfig) -> Self {
+        Self { config }
+    }
+}
+
+#[async_trait::async_trait]
+impl WiringLayer for AvailWiringLayer {
+    fn layer_name(&self) -> &'static str {
+        "avail_client_layer"
+    }
+
+    async fn wire(self: Box<Self>, mut context: ServiceContext<'_>) -> Result<(), WiringError> {
+        let client = AvailClient::new(self.config).map_err(WiringError::internal)?;
+        context.insert_resource(DAClientResource(Box::new(client)))?;
+
+        Ok(())
+    }
+}
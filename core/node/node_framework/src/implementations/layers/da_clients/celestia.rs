@@ -0,0 +1,34 @@
+use zksync_config::configs::da_client::celestia::CelestiaConfig;
+use zksync_da_clients::celestia::CelestiaClient;
+
+use crate::{
+    implementations::resources::da_client::DAClientResource,
+    service::ServiceContext,
+    wiring_layer::{WiringError, WiringLayer},
+};
+
+/// Wiring layer for the Celestia client.
+#[derive(Debug)]
+pub struct CelestiaWiringLayer {
+    config: CelestiaConfig,
+}
+
+impl CelestiaWiringLayer {
+    pub fn new(config: CelestiaConfig) -> Self {
+        Self { config }
+    }
+}
+
+#[async_trait::async_trait]
+impl WiringLayer for CelestiaWiringLayer {
+    fn layer_name(&self) -> &'static str {
+        "celestia_client_layer"
+    }
+
+    async fn wire(self: Box<Self>, mut context: ServiceContext<'_>) -> Result<(), WiringError> {
+        let client = CelestiaClient::new(self.config).map_err(WiringError::internal)?;
+        context.insert_resource(DAClientResource(Box::new(client)))?;
+
+        Ok(())
+    }
+}
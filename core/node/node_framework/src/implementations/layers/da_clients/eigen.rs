@@ -0,0 +1,34 @@
+use zksync_config::configs::da_client::eigen::EigenDAConfig;
+use zksync_da_clients::eigen::EigenDAClient;
+
+use crate::{
+    implementations::resources::da_client::DAClientResource,
+    service::ServiceContext,
+    wiring_layer::{WiringError, WiringLayer},
+};
+
+/// Wiring layer for the EigenDA client.
+#[derive(Debug)]
+pub struct EigenWiringLayer {
+    config: EigenDAConfig,
+}
+
+impl EigenWiringLayer {
+    pub fn new(config: EigenDAConfig) -> Self {
+        Self { config }
+    }
+}
+
+#[async_trait::async_trait]
+impl WiringLayer for EigenWiringLayer {
+    fn layer_name(&self) -> &'static str {
+        "eigen_client_layer"
+    }
+
+    async fn wire(self: Box<Self>, mut context: ServiceContext<'_>) -> Result<(), WiringError> {
+        let client = EigenDAClient::new(self.config).map_err(WiringError::internal)?;
+        context.insert_resource(DAClientResource(Box::new(client)))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,34 @@
+use zksync_config::configs::da_client::mantle::MantleDAConfig;
+use zksync_da_clients::mantle::MantleDAClient;
+
+use crate::{
+    implementations::resources::da_client::DAClientResource,
+    service::ServiceContext,
+    wiring_layer::{WiringError, WiringLayer},
+};
+
+/// Wiring layer for the Mantle DA client.
+#[derive(Debug)]
+pub struct MantleWiringLayer {
+    config: MantleDAConfig,
+}
+
+impl MantleWiringLayer {
+    pub fn new(config: MantleDAConfig) -> Self {
+        Self { config }
+    }
+}
+
+#[async_trait::async_trait]
+impl WiringLayer for MantleWiringLayer {
+    fn layer_name(&self) -> &'static str {
+        "mantle_client_layer"
+    }
+
+    async fn wire(self: Box<Self>, mut context: ServiceContext<'_>) -> Result<(), WiringError> {
+        let client = MantleDAClient::new(self.config).map_err(WiringError::internal)?;
+        context.insert_resource(DAClientResource(Box::new(client)))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,6 @@
+pub mod avail;
+pub mod celestia;
+pub mod eigen;
+pub mod mantle;
+pub mod object_store;
+pub mod shadow;
@@ -0,0 +1,38 @@
+use zksync_config::ObjectStoreConfig;
+use zksync_da_clients::object_store::ObjectStoreDAClient;
+use zksync_object_store::ObjectStoreFactory;
+
+use crate::{
+    implementations::resources::da_client::DAClientResource,
+    service::ServiceContext,
+    wiring_layer::{WiringError, WiringLayer},
+};
+
+/// Wiring layer for the object store "no-DA" client.
+///
+/// The object store is created from the dedicated config, so blobs may be stored separately from other artifacts.
+#[derive(Debug)]
+pub struct ObjectStorageClientWiringLayer {
+    config: ObjectStoreConfig,
+}
+
+impl ObjectStorageClientWiringLayer {
+    pub fn new(config: ObjectStoreConfig) -> Self {
+        Self { config }
+    }
+}
+
+#[async_trait::async_trait]
+impl WiringLayer for ObjectStorageClientWiringLayer {
+    fn layer_name(&self) -> &'static str {
+        "object_store_da_client_layer"
+    }
+
+    async fn wire(self: Box<Self>, mut context: ServiceContext<'_>) -> Result<(), WiringError> {
+        let store = ObjectStoreFactory::new(self.config).create_store().await?;
+        let client = ObjectStoreDAClient::new(store);
+        context.insert_resource(DAClientResource(Box::new(client)))?;
+
+        Ok(())
+    }
+}
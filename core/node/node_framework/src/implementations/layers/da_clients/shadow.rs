@@ -0,0 +1,33 @@
+use zksync_config::configs::da_client::DAClient;
+
+use crate::{
+    implementations::resources::da_client::ShadowDAClientResource,
+    service::ServiceContext,
+    wiring_layer::{WiringError, WiringLayer},
+};
+
+/// Wiring layer for the client of the shadow DA layer used by the DA dispatcher in the dual-write mode.
+#[derive(Debug)]
+pub struct ShadowDAClientWiringLayer {
+    client: DAClient,
+}
+
+impl ShadowDAClientWiringLayer {
+    pub fn new(client: DAClient) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait::async_trait]
+impl WiringLayer for ShadowDAClientWiringLayer {
+    fn layer_name(&self) -> &'static str {
+        "shadow_da_client_layer"
+    }
+
+    async fn wire(self: Box<Self>, mut context: ServiceContext<'_>) -> Result<(), WiringError> {
+        let client = zksync_da_clients::create_base_client(self.client).await?;
+        context.insert_resource(ShadowDAClientResource(client))?;
+
+        Ok(())
+    }
+}
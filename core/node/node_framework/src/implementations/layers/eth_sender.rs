@@ -125,6 +125,11 @@ impl WiringLayer for EthTxAggregatorLayer {
             Err(err) => return Err(err),
         };
         let object_store = context.get_resource::<ObjectStoreResource>().await?.0;
+        let l1_tx_params = match context.get_resource::<L1TxParamsResource>().await {
+            Ok(L1TxParamsResource(l1_tx_params)) => Some(l1_tx_params),
+            Err(WiringError::ResourceLacking { .. }) => None,
+            Err(err) => return Err(err),
+        };
 
         // Create and add tasks.
         let eth_client_blobs_addr = eth_client_blobs
@@ -132,12 +137,15 @@ impl WiringLayer for EthTxAggregatorLayer {
             .map(BoundEthInterface::sender_account);
 
         let config = self.eth_sender_config.sender.context("sender")?;
-        let aggregator = Aggregator::new(
+        let mut aggregator = Aggregator::new(
             config.clone(),
             object_store,
             eth_client_blobs_addr.is_some(),
             self.l1_batch_commit_data_generator_mode,
         );
+        if let Some(l1_tx_params) = l1_tx_params {
+            aggregator = aggregator.with_l1_tx_params(l1_tx_params);
+        }
 
         let eth_tx_aggregator_actor = EthTxAggregator::new(
             master_pool.clone(),
@@ -14,7 +14,9 @@ use crate::{
 /// Builder for a health check server.
 ///
 /// Spawned task collects all the health checks added by different tasks to the
-/// corresponding resource collection and spawns an HTTP server exposing them.
+/// corresponding resource collection and spawns an HTTP server exposing them. The server distinguishes
+/// readiness (`/health/ready`; whether the node can receive traffic) and liveness (`/health/live`; whether the node
+/// should be restarted). Both endpoints return per-component health details.
 ///
 /// This layer expects other tasks to add health checks to the `ResourceCollection<HealthCheckResource>`.
 ///
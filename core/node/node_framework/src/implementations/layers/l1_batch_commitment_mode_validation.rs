@@ -0,0 +1,71 @@
+use zksync_commitment_generator::validation_task::L1BatchCommitmentModeValidationTask;
+use zksync_types::{commitment::L1BatchCommitmentMode, Address};
+
+use crate::{
+    implementations::resources::eth_interface::EthInterfaceResource,
+    service::{ServiceContext, StopReceiver},
+    task::{Task, TaskId},
+    wiring_layer::{WiringError, WiringLayer},
+};
+
+/// Wiring layer for the task validating that the configured L1 batch commitment mode matches the mode
+/// used by the L1 diamond proxy contract.
+///
+/// The validation runs asynchronously, so that the node start doesn't depend on the L1 client availability.
+///
+/// ## Requests resources
+///
+/// - `EthInterfaceResource`
+///
+/// ## Adds tasks
+///
+/// - `CommitmentModeValidationTask`
+#[derive(Debug)]
+pub struct L1BatchCommitmentModeValidationLayer {
+    diamond_proxy_addr: Address,
+    l1_batch_commit_data_generator_mode: L1BatchCommitmentMode,
+}
+
+impl L1BatchCommitmentModeValidationLayer {
+    pub fn new(
+        diamond_proxy_addr: Address,
+        l1_batch_commit_data_generator_mode: L1BatchCommitmentMode,
+    ) -> Self {
+        Self {
+            diamond_proxy_addr,
+            l1_batch_commit_data_generator_mode,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl WiringLayer for L1BatchCommitmentModeValidationLayer {
+    fn layer_name(&self) -> &'static str {
+        "l1_batch_commitment_mode_validation_layer"
+    }
+
+    async fn wire(self: Box<Self>, mut context: ServiceContext<'_>) -> Result<(), WiringError> {
+        let EthInterfaceResource(query_client) = context.get_resource().await?;
+        let task = L1BatchCommitmentModeValidationTask::new(
+            self.diamond_proxy_addr,
+            self.l1_batch_commit_data_generator_mode,
+            query_client,
+        );
+        context.add_task(Box::new(CommitmentModeValidationTask(task)));
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+struct CommitmentModeValidationTask(L1BatchCommitmentModeValidationTask);
+
+#[async_trait::async_trait]
+impl Task for CommitmentModeValidationTask {
+    fn id(&self) -> TaskId {
+        "l1_batch_commitment_mode_validation".into()
+    }
+
+    async fn run(self: Box<Self>, stop_receiver: StopReceiver) -> anyhow::Result<()> {
+        self.0.run(stop_receiver.0).await
+    }
+}
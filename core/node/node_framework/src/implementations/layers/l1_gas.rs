@@ -10,8 +10,9 @@ use zksync_types::fee_model::FeeModelConfig;
 
 use crate::{
     implementations::resources::{
-        eth_interface::EthInterfaceResource, fee_input::FeeInputResource,
-        l1_tx_params::L1TxParamsResource,
+        base_token_ratio_provider::BaseTokenRatioResource, eth_interface::EthInterfaceResource,
+        fee_input::FeeInputResource, l1_tx_params::L1TxParamsResource,
+        reloadable_config::ReloadableConfigResource,
     },
     service::{ServiceContext, StopReceiver},
     task::{Task, TaskId},
@@ -60,10 +61,26 @@ impl WiringLayer for SequencerL1GasLayer {
         .context("GasAdjuster::new()")?;
         let gas_adjuster = Arc::new(adjuster);
 
-        let batch_fee_input_provider = Arc::new(MainNodeFeeInputProvider::new(
+        let mut batch_fee_input_provider = MainNodeFeeInputProvider::new(
             gas_adjuster.clone(),
             FeeModelConfig::from_state_keeper_config(&self.state_keeper_config),
-        ));
+        );
+        match context.get_resource::<ReloadableConfigResource>().await {
+            Ok(ReloadableConfigResource(config)) => {
+                batch_fee_input_provider =
+                    batch_fee_input_provider.with_config_updates(config.subscribe());
+            }
+            Err(WiringError::ResourceLacking { .. }) => { /* fee params are not reloadable */ }
+            Err(err) => return Err(err),
+        }
+        match context.get_resource::<BaseTokenRatioResource>().await {
+            Ok(BaseTokenRatioResource(ratio)) => {
+                batch_fee_input_provider = batch_fee_input_provider.with_base_token_ratio(ratio);
+            }
+            Err(WiringError::ResourceLacking { .. }) => { /* ETH is the base token */ }
+            Err(err) => return Err(err),
+        }
+        let batch_fee_input_provider = Arc::new(batch_fee_input_provider);
         context.insert_resource(FeeInputResource(batch_fee_input_provider))?;
 
         context.insert_resource(L1TxParamsResource(gas_adjuster.clone()))?;
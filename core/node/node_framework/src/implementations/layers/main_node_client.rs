@@ -1,20 +1,28 @@
-use std::num::NonZeroUsize;
+use std::{iter, num::NonZeroUsize};
 
 use anyhow::Context;
 use zksync_types::{url::SensitiveUrl, L2ChainId};
-use zksync_web3_decl::client::{Client, DynClient, L2};
+use zksync_web3_decl::client::{DynClient, FailoverClient, FailoverClientProber, Network, L2};
 
 use crate::{
-    implementations::resources::main_node_client::MainNodeClientResource,
-    service::ServiceContext,
+    implementations::resources::{
+        healthcheck::AppHealthCheckResource, main_node_client::MainNodeClientResource,
+    },
+    service::{ServiceContext, StopReceiver},
+    task::{Task, TaskId},
     wiring_layer::{WiringError, WiringLayer},
 };
 
+/// Layer providing a JSON-RPC client for the main node.
+///
+/// If fallback endpoints are specified, the client fails over among all endpoints; endpoints are periodically
+/// health-probed, and the currently active endpoint is reported via the `main_node_client` health check component.
 #[derive(Debug)]
 pub struct MainNodeClientLayer {
     url: SensitiveUrl,
     rate_limit_rps: NonZeroUsize,
     l2_chain_id: L2ChainId,
+    fallback_endpoints: Vec<(SensitiveUrl, NonZeroUsize)>,
 }
 
 impl MainNodeClientLayer {
@@ -23,8 +31,20 @@ impl MainNodeClientLayer {
             url,
             rate_limit_rps,
             l2_chain_id,
+            fallback_endpoints: vec![],
         }
     }
+
+    /// Adds a fallback main node endpoint with its own rate limit. Fallback endpoints are used in the order
+    /// they are added.
+    pub fn with_fallback_endpoint(
+        mut self,
+        url: SensitiveUrl,
+        rate_limit_rps: NonZeroUsize,
+    ) -> Self {
+        self.fallback_endpoints.push((url, rate_limit_rps));
+        self
+    }
 }
 
 #[async_trait::async_trait]
@@ -34,11 +54,22 @@ impl WiringLayer for MainNodeClientLayer {
     }
 
     async fn wire(self: Box<Self>, mut context: ServiceContext<'_>) -> Result<(), WiringError> {
-        let main_node_client = Client::http(self.url)
-            .context("failed creating JSON-RPC client for main node")?
-            .for_network(self.l2_chain_id.into())
-            .with_allowed_requests_per_second(self.rate_limit_rps)
-            .build();
+        let endpoints = iter::once((self.url, self.rate_limit_rps))
+            .chain(self.fallback_endpoints)
+            .map(|(url, rate_limit_rps)| (url, Some(rate_limit_rps)));
+        let main_node_client = FailoverClient::http(self.l2_chain_id.into(), endpoints)
+            .context("failed creating JSON-RPC client for main node")?;
+
+        if let Some(prober) = main_node_client.prober("main_node_client") {
+            let AppHealthCheckResource(app_health) = context.get_resource_or_default().await;
+            app_health
+                .insert_component(prober.health_check())
+                .map_err(WiringError::internal)?;
+            context.add_task(Box::new(FailoverClientProbingTask {
+                task_id: "main_node_client_probing",
+                prober,
+            }));
+        }
 
         context.insert_resource(MainNodeClientResource(
             Box::new(main_node_client) as Box<DynClient<L2>>
@@ -46,3 +77,21 @@ impl WiringLayer for MainNodeClientLayer {
         Ok(())
     }
 }
+
+/// Task periodically probing endpoints of a failover client. Used both for the main node client and for the L1 client.
+#[derive(Debug)]
+pub(super) struct FailoverClientProbingTask<Net: Network> {
+    pub task_id: &'static str,
+    pub prober: FailoverClientProber<Net>,
+}
+
+#[async_trait::async_trait]
+impl<Net: Network> Task for FailoverClientProbingTask<Net> {
+    fn id(&self) -> TaskId {
+        self.task_id.into()
+    }
+
+    async fn run(self: Box<Self>, stop_receiver: StopReceiver) -> anyhow::Result<()> {
+        self.prober.run(stop_receiver.0).await
+    }
+}
@@ -1,12 +1,13 @@
 use std::{
     net::{Ipv4Addr, SocketAddr},
     sync::Arc,
+    time::Duration,
 };
 
 use anyhow::Context as _;
 use zksync_config::configs::{api::MerkleTreeApiConfig, database::MerkleTreeMode};
 use zksync_metadata_calculator::{
-    LazyAsyncTreeReader, MetadataCalculator, MetadataCalculatorConfig,
+    LazyAsyncTreeReader, MerkleTreePruningTask, MetadataCalculator, MetadataCalculatorConfig,
 };
 use zksync_storage::RocksDB;
 
@@ -31,10 +32,14 @@ use crate::{
 /// - Resolves `ObjectStoreResource` (optional).
 /// - Adds `tree_health_check` to the `ResourceCollection<HealthCheckResource>`.
 /// - Adds `metadata_calculator` to the node.
+/// - Adds `tree_api` to the node if the tree API config is specified.
+/// - Adds `merkle_tree_pruning` to the node (with a health check) if the pruning config is specified.
 #[derive(Debug)]
 pub struct MetadataCalculatorLayer {
     config: MetadataCalculatorConfig,
     tree_api_config: Option<MerkleTreeApiConfig>,
+    pruning_removal_delay: Option<Duration>,
+    recovery_pool_size: Option<u32>,
 }
 
 impl MetadataCalculatorLayer {
@@ -42,6 +47,8 @@ impl MetadataCalculatorLayer {
         Self {
             config,
             tree_api_config: None,
+            pruning_removal_delay: None,
+            recovery_pool_size: None,
         }
     }
 
@@ -49,6 +56,20 @@ impl MetadataCalculatorLayer {
         self.tree_api_config = Some(tree_api_config);
         self
     }
+
+    /// Enables pruning of the tree according to the pruning logs in Postgres. `removal_delay` is the delay
+    /// configured for the Postgres pruner; the tree polls for pruning logs twice as often.
+    pub fn with_pruning_config(mut self, removal_delay: Duration) -> Self {
+        self.pruning_removal_delay = Some(removal_delay);
+        self
+    }
+
+    /// Sets the size of the connection pool used for tree recovery. By default, the size is based
+    /// on the recovery concurrency limit.
+    pub fn with_recovery_pool_size(mut self, size: u32) -> Self {
+        self.recovery_pool_size = Some(size);
+        self
+    }
 }
 
 #[async_trait::async_trait]
@@ -60,12 +81,18 @@ impl WiringLayer for MetadataCalculatorLayer {
     async fn wire(self: Box<Self>, mut context: ServiceContext<'_>) -> Result<(), WiringError> {
         let pool = context.get_resource::<PoolResource<MasterPool>>().await?;
         let main_pool = pool.get().await?;
-        // The number of connections in a recovery pool is based on the mainnet recovery runs. It doesn't need
+        // The default number of connections in a recovery pool is based on the mainnet recovery runs. It doesn't need
         // to be particularly accurate at this point, since the main node isn't expected to recover from a snapshot.
+        let recovery_pool_size = self.recovery_pool_size.unwrap_or_else(|| {
+            self.config
+                .recovery
+                .concurrency_limit
+                .map_or(10, |limit| limit.get() as u32)
+        });
         let recovery_pool = context
             .get_resource::<PoolResource<ReplicaPool>>()
             .await?
-            .get_custom(10)
+            .get_custom(recovery_pool_size)
             .await?;
 
         let object_store = match self.config.mode {
@@ -76,7 +103,7 @@ impl WiringLayer for MetadataCalculatorLayer {
             }
         };
 
-        let metadata_calculator = MetadataCalculator::new(
+        let mut metadata_calculator = MetadataCalculator::new(
             self.config,
             object_store.map(|store_resource| store_resource.0),
             main_pool,
@@ -89,6 +116,14 @@ impl WiringLayer for MetadataCalculatorLayer {
             .insert_custom_component(Arc::new(metadata_calculator.tree_health_check()))
             .map_err(WiringError::internal)?;
 
+        if let Some(removal_delay) = self.pruning_removal_delay {
+            let pruning_task = metadata_calculator.pruning_task(removal_delay / 2);
+            app_health
+                .insert_component(pruning_task.health_check())
+                .map_err(WiringError::internal)?;
+            context.add_task(Box::new(TreePruningTask(pruning_task)));
+        }
+
         if let Some(tree_api_config) = self.tree_api_config {
             let bind_addr = (Ipv4Addr::UNSPECIFIED, tree_api_config.port).into();
             let tree_reader = metadata_calculator.tree_reader();
@@ -154,3 +189,17 @@ impl Task for TreeApiTask {
             .await
     }
 }
+
+#[derive(Debug)]
+struct TreePruningTask(MerkleTreePruningTask);
+
+#[async_trait::async_trait]
+impl Task for TreePruningTask {
+    fn id(&self) -> TaskId {
+        "merkle_tree_pruning".into()
+    }
+
+    async fn run(self: Box<Self>, stop_receiver: StopReceiver) -> anyhow::Result<()> {
+        self.0.run(stop_receiver.0).await
+    }
+}
@@ -0,0 +1,100 @@
+use anyhow::Context as _;
+use zksync_config::configs::database::MigrationsMode;
+use zksync_dal::{
+    migrations::{apply_migrations, migrations_status},
+    ConnectionPool, Core,
+};
+
+use crate::{
+    implementations::resources::pools::{MasterPool, PoolResource},
+    precondition::Precondition,
+    service::{ServiceContext, StopReceiver},
+    task::TaskId,
+    wiring_layer::{WiringError, WiringLayer},
+};
+
+/// Wiring layer checking Postgres migrations on node startup and optionally applying pending ones.
+///
+/// No tasks depending on preconditions are started until the check is complete. The node refuses to start
+/// if the database schema is newer than the node supports (i.e., the database contains migrations unknown
+/// to the node). In the [`MigrationsMode::Check`] mode, the node also refuses to start if there are pending
+/// migrations; in the [`MigrationsMode::Apply`] mode, pending migrations are applied under a Postgres advisory lock,
+/// so that multiple nodes sharing the database can start concurrently.
+///
+/// ## Requests resources
+///
+/// - `PoolResource<MasterPool>`
+///
+/// ## Adds preconditions
+///
+/// - `MigrationsPrecondition`
+#[derive(Debug)]
+pub struct MigrationsLayer {
+    mode: MigrationsMode,
+}
+
+impl MigrationsLayer {
+    pub fn new(mode: MigrationsMode) -> Self {
+        Self { mode }
+    }
+}
+
+#[async_trait::async_trait]
+impl WiringLayer for MigrationsLayer {
+    fn layer_name(&self) -> &'static str {
+        "migrations_layer"
+    }
+
+    async fn wire(self: Box<Self>, mut context: ServiceContext<'_>) -> Result<(), WiringError> {
+        let pool = context
+            .get_resource::<PoolResource<MasterPool>>()
+            .await?
+            .get_singleton()
+            .await?;
+        context.add_precondition(Box::new(MigrationsPrecondition {
+            mode: self.mode,
+            pool,
+        }));
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub struct MigrationsPrecondition {
+    mode: MigrationsMode,
+    pool: ConnectionPool<Core>,
+}
+
+#[async_trait::async_trait]
+impl Precondition for MigrationsPrecondition {
+    fn id(&self) -> TaskId {
+        "migrations".into()
+    }
+
+    async fn check(self: Box<Self>, _stop_receiver: StopReceiver) -> anyhow::Result<()> {
+        let mut storage = self.pool.connection_tagged("migrations").await?;
+        match self.mode {
+            MigrationsMode::Check => {
+                let status = migrations_status(&mut storage).await?;
+                status.ensure_compatible()?;
+                anyhow::ensure!(
+                    status.pending.is_empty(),
+                    "database has pending migrations: {:?}; apply them or enable automatic migrations",
+                    status.pending
+                );
+                tracing::info!("Database schema is up to date");
+            }
+            MigrationsMode::Apply => {
+                let status = apply_migrations(&mut storage)
+                    .await
+                    .context("failed applying migrations")?;
+                if status.pending.is_empty() {
+                    tracing::info!("Database schema is up to date");
+                } else {
+                    tracing::info!("Applied {} pending migrations", status.pending.len());
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -1,25 +1,43 @@
+pub mod admin_server;
+pub mod base_token_ratio_persister;
+pub mod base_token_ratio_provider;
+pub mod batch_status_updater;
+pub mod block_reverter;
 pub mod circuit_breaker_checker;
 pub mod commitment_generator;
+pub mod config_reloader;
 pub mod consensus;
 pub mod consistency_checker;
 pub mod contract_verification_api;
+pub mod contract_verifier;
+pub mod da_clients;
+pub mod da_dispatcher;
 pub mod eth_sender;
 pub mod eth_watch;
 pub mod healtcheck_server;
 pub mod house_keeper;
+pub mod l1_batch_commitment_mode_validation;
 pub mod l1_gas;
 pub mod main_node_client;
+pub mod main_node_fee_params_fetcher;
 pub mod metadata_calculator;
+pub mod migrations;
 pub mod object_store;
 pub mod pk_signing_eth_client;
 pub mod pools_layer;
 pub mod prometheus_exporter;
 pub mod proof_data_handler;
+pub mod pruning;
 pub mod query_eth_client;
-pub mod reorg_detector_checker;
-pub mod reorg_detector_runner;
+pub mod reorg_detector;
+pub mod secrets_rotation;
 pub mod sigint;
+pub mod slo_monitor;
+pub mod snapshot_recovery;
+pub mod snapshots_creator;
 pub mod state_keeper;
+pub mod sync_state;
 pub mod tee_verifier_input_producer;
+pub mod tree_data_fetcher;
 pub mod vm_runner;
 pub mod web3_api;
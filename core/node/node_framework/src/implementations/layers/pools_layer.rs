@@ -2,7 +2,9 @@ use std::sync::Arc;
 
 use zksync_config::configs::{DatabaseSecrets, PostgresConfig};
 use zksync_dal::{ConnectionPool, Core};
-use zksync_db_connection::healthcheck::ConnectionPoolHealthCheck;
+use zksync_db_connection::{
+    connection_pool::ConnectionPoolBuilder, healthcheck::ConnectionPoolHealthCheck,
+};
 
 use crate::{
     implementations::resources::{
@@ -20,6 +22,8 @@ pub struct PoolsLayerBuilder {
     with_replica: bool,
     with_prover: bool,
     secrets: DatabaseSecrets,
+    master_pool_builder: Option<ConnectionPoolBuilder<Core>>,
+    replica_pool_builder: Option<ConnectionPoolBuilder<Core>>,
 }
 
 impl PoolsLayerBuilder {
@@ -30,6 +34,8 @@ impl PoolsLayerBuilder {
             with_replica: false,
             with_prover: false,
             secrets: database_secrets,
+            master_pool_builder: None,
+            replica_pool_builder: None,
         }
     }
 
@@ -48,6 +54,19 @@ impl PoolsLayerBuilder {
         self
     }
 
+    /// Configures the master pool with the provided builder instead of the Postgres config and secrets.
+    pub fn with_custom_master_pool(mut self, builder: ConnectionPoolBuilder<Core>) -> Self {
+        self.master_pool_builder = Some(builder);
+        self
+    }
+
+    /// Configures the replica pool with the provided builder instead of the Postgres config and secrets.
+    /// This allows setting timeouts, connection thresholds and read replicas specific to the API servers.
+    pub fn with_custom_replica_pool(mut self, builder: ConnectionPoolBuilder<Core>) -> Self {
+        self.replica_pool_builder = Some(builder);
+        self
+    }
+
     pub fn build(self) -> PoolsLayer {
         PoolsLayer {
             config: self.config,
@@ -55,6 +74,8 @@ impl PoolsLayerBuilder {
             with_master: self.with_master,
             with_replica: self.with_replica,
             with_prover: self.with_prover,
+            master_pool_builder: self.master_pool_builder,
+            replica_pool_builder: self.replica_pool_builder,
         }
     }
 }
@@ -66,6 +87,8 @@ pub struct PoolsLayer {
     with_master: bool,
     with_replica: bool,
     with_prover: bool,
+    master_pool_builder: Option<ConnectionPoolBuilder<Core>>,
+    replica_pool_builder: Option<ConnectionPoolBuilder<Core>>,
 }
 
 #[async_trait::async_trait]
@@ -91,26 +114,39 @@ impl WiringLayer for PoolsLayer {
         }
 
         if self.with_master {
-            let pool_size = self.config.max_connections()?;
-            let pool_size_master = self.config.max_connections_master().unwrap_or(pool_size);
-
-            context.insert_resource(PoolResource::<MasterPool>::new(
-                self.secrets.master_url()?,
-                pool_size_master,
-                None,
-                None,
-            ))?;
+            let pool = if let Some(builder) = self.master_pool_builder {
+                PoolResource::<MasterPool>::from_builder(builder)
+            } else {
+                let pool_size = self.config.max_connections()?;
+                let pool_size_master = self.config.max_connections_master().unwrap_or(pool_size);
+                PoolResource::<MasterPool>::new(
+                    self.secrets.master_url()?,
+                    pool_size_master,
+                    None,
+                    None,
+                )
+            };
+            context.insert_resource(pool)?;
         }
 
         if self.with_replica {
-            // We're most interested in setting acquire / statement timeouts for the API server, which puts the most load
-            // on Postgres.
-            context.insert_resource(PoolResource::<ReplicaPool>::new(
-                self.secrets.replica_url()?,
-                self.config.max_connections()?,
-                self.config.statement_timeout(),
-                self.config.acquire_timeout(),
-            ))?;
+            let pool = if let Some(builder) = self.replica_pool_builder {
+                PoolResource::<ReplicaPool>::from_builder(builder)
+            } else {
+                // We're most interested in setting acquire / statement timeouts for the API server, which puts the most load
+                // on Postgres.
+                PoolResource::<ReplicaPool>::new(
+                    self.secrets.replica_url()?,
+                    self.config.max_connections()?,
+                    self.config.statement_timeout(),
+                    self.config.acquire_timeout(),
+                )
+                .with_api_replicas(
+                    self.secrets.server_api_replica_urls.clone(),
+                    self.config.api_replica_max_lag(),
+                )
+            };
+            context.insert_resource(pool)?;
         }
 
         if self.with_prover {
@@ -0,0 +1,93 @@
+use std::time::Duration;
+
+use zksync_node_db_pruner::{DbPruner, DbPrunerConfig};
+
+use crate::{
+    implementations::resources::{
+        healthcheck::AppHealthCheckResource,
+        pools::{MasterPool, PoolResource},
+    },
+    service::{ServiceContext, StopReceiver},
+    task::{Task, TaskId},
+    wiring_layer::{WiringError, WiringLayer},
+};
+
+/// Wiring layer for Postgres pruning.
+///
+/// Periodically removes old L1 batches, L2 blocks and the associated data (storage logs, events etc.) from Postgres.
+/// Only data that is older than the configured retention period, was executed on L1 and was processed by the Merkle tree
+/// is pruned, so pruning never removes data needed by the tree (including data required to finish tree recovery
+/// from a snapshot).
+///
+/// ## Requests resources
+///
+/// - `PoolResource<MasterPool>`
+/// - `AppHealthCheckResource` (adds a health check)
+///
+/// ## Adds tasks
+///
+/// - `DbPrunerTask`
+#[derive(Debug)]
+pub struct PruningLayer {
+    pruning_removal_delay: Duration,
+    pruning_chunk_size: u32,
+    minimum_l1_batch_age: Duration,
+}
+
+impl PruningLayer {
+    pub fn new(
+        pruning_removal_delay: Duration,
+        pruning_chunk_size: u32,
+        minimum_l1_batch_age: Duration,
+    ) -> Self {
+        Self {
+            pruning_removal_delay,
+            pruning_chunk_size,
+            minimum_l1_batch_age,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl WiringLayer for PruningLayer {
+    fn layer_name(&self) -> &'static str {
+        "pruning_layer"
+    }
+
+    async fn wire(self: Box<Self>, mut context: ServiceContext<'_>) -> Result<(), WiringError> {
+        let pool_resource = context.get_resource::<PoolResource<MasterPool>>().await?;
+        let main_pool = pool_resource.get().await?;
+
+        let db_pruner = DbPruner::new(
+            DbPrunerConfig {
+                removal_delay: self.pruning_removal_delay,
+                pruned_batch_chunk_size: self.pruning_chunk_size,
+                minimum_l1_batch_age: self.minimum_l1_batch_age,
+            },
+            main_pool,
+        );
+
+        let AppHealthCheckResource(app_health) = context.get_resource_or_default().await;
+        app_health
+            .insert_component(db_pruner.health_check())
+            .map_err(WiringError::internal)?;
+
+        context.add_task(Box::new(DbPrunerTask(db_pruner)));
+
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+struct DbPrunerTask(DbPruner);
+
+#[async_trait::async_trait]
+impl Task for DbPrunerTask {
+    fn id(&self) -> TaskId {
+        "db_pruner".into()
+    }
+
+    async fn run(self: Box<Self>, stop_receiver: StopReceiver) -> anyhow::Result<()> {
+        self.0.run(stop_receiver.0).await
+    }
+}
@@ -1,22 +1,52 @@
+use std::{iter, time::Duration};
+
 use anyhow::Context;
 use zksync_types::{url::SensitiveUrl, L1ChainId};
-use zksync_web3_decl::client::Client;
+use zksync_web3_decl::client::{FailoverClient, L1};
 
+use super::main_node_client::FailoverClientProbingTask;
 use crate::{
-    implementations::resources::eth_interface::EthInterfaceResource,
+    implementations::resources::{
+        eth_interface::EthInterfaceResource, healthcheck::AppHealthCheckResource,
+    },
     service::ServiceContext,
     wiring_layer::{WiringError, WiringLayer},
 };
 
+/// Layer providing a JSON-RPC client for L1.
+///
+/// If fallback URLs are specified, the client fails over among all L1 providers on transport errors
+/// and rate limiting; providers are periodically health-probed, and the currently active provider is reported
+/// via the `l1_client` health check component. If response caching is enabled, responses for immutable data
+/// (blocks queried by hash or number, transaction receipts) are cached for the specified TTL.
 #[derive(Debug)]
 pub struct QueryEthClientLayer {
     chain_id: L1ChainId,
     web3_url: SensitiveUrl,
+    fallback_urls: Vec<SensitiveUrl>,
+    response_cache_ttl: Option<Duration>,
 }
 
 impl QueryEthClientLayer {
     pub fn new(chain_id: L1ChainId, web3_url: SensitiveUrl) -> Self {
-        Self { chain_id, web3_url }
+        Self {
+            chain_id,
+            web3_url,
+            fallback_urls: vec![],
+            response_cache_ttl: None,
+        }
+    }
+
+    /// Adds fallback L1 RPC URLs, listed in the priority order.
+    pub fn with_fallback_urls(mut self, urls: impl IntoIterator<Item = SensitiveUrl>) -> Self {
+        self.fallback_urls.extend(urls);
+        self
+    }
+
+    /// Enables caching of responses for immutable data with the specified TTL.
+    pub fn with_response_cache(mut self, ttl: Duration) -> Self {
+        self.response_cache_ttl = Some(ttl);
+        self
     }
 }
 
@@ -27,11 +57,25 @@ impl WiringLayer for QueryEthClientLayer {
     }
 
     async fn wire(self: Box<Self>, mut context: ServiceContext<'_>) -> Result<(), WiringError> {
-        let query_client = Client::http(self.web3_url.clone())
-            .context("Client::new()")?
-            .for_network(self.chain_id.into())
-            .build();
-        context.insert_resource(EthInterfaceResource(Box::new(query_client)))?;
+        let endpoints = iter::once(self.web3_url).chain(self.fallback_urls);
+        // A failover client with a single endpoint behaves identically to the endpoint client.
+        let query_client =
+            FailoverClient::<L1>::http(self.chain_id.into(), endpoints.map(|url| (url, None)))
+                .context("failed creating L1 client")?;
+
+        if let Some(prober) = query_client.prober("l1_client") {
+            let AppHealthCheckResource(app_health) = context.get_resource_or_default().await;
+            app_health
+                .insert_component(prober.health_check())
+                .map_err(WiringError::internal)?;
+            context.add_task(Box::new(FailoverClientProbingTask {
+                task_id: "l1_client_probing",
+                prober,
+            }));
+        }
+
+        let query_client = query_client.into_boxed(self.response_cache_ttl);
+        context.insert_resource(EthInterfaceResource(query_client))?;
         Ok(())
     }
 }
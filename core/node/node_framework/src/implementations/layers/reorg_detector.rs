@@ -0,0 +1,205 @@
+use std::{num::NonZeroU32, sync::Arc, time::Duration};
+
+use anyhow::Context as _;
+use zksync_block_reverter::BlockReverter;
+use zksync_dal::{ConnectionPool, Core};
+use zksync_reorg_detector::{self, ReorgDetector};
+
+use crate::{
+    implementations::resources::{
+        healthcheck::AppHealthCheckResource,
+        main_node_client::MainNodeClientResource,
+        pools::{MasterPool, PoolResource},
+        reverter::BlockReverterResource,
+    },
+    precondition::Precondition,
+    service::{ServiceContext, StopReceiver},
+    task::{Task, TaskId},
+    wiring_layer::{WiringError, WiringLayer},
+};
+
+/// Interval between reorg checks on startup if the node state cannot be rolled back automatically.
+const REORG_DETECTED_SLEEP_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Wiring layer for reorg detection with automatic rollback.
+///
+/// On startup, the node state is compared with the main node. If a reorg is detected and a block reverter
+/// is provided (e.g., by [`BlockReverterLayer`](super::block_reverter::BlockReverterLayer)), the node state
+/// is rolled back to the last correct L1 batch before any other tasks are started. Without a block reverter,
+/// other tasks are not started until the reorg is resolved (e.g., by rolling back the node manually). Afterwards, the reorg detector
+/// continuously monitors the main node; if it detects a reorg, the task exits with an error so that the node
+/// is stopped. The node state is then rolled back on the next startup, after which syncing resumes normally.
+///
+/// ## Requests resources
+///
+/// - `MainNodeClientResource`
+/// - `PoolResource<MasterPool>`
+/// - `BlockReverterResource` (optional; without it, reorgs are detected but not rolled back)
+/// - `AppHealthCheckResource` (adds a health check)
+///
+/// ## Adds preconditions
+///
+/// - `ReorgRollbackPrecondition` (if a block reverter is provided)
+/// - `ReorgCheckerPrecondition` (otherwise)
+///
+/// ## Adds tasks
+///
+/// - `ReorgDetectorTask`
+#[derive(Debug, Default)]
+pub struct ReorgDetectorLayer {
+    auto_rollback_max_depth: Option<NonZeroU32>,
+}
+
+impl ReorgDetectorLayer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Limits the number of L1 batches that can be rolled back automatically. If a reorg requires rolling back
+    /// more batches, the node will not start.
+    pub fn with_auto_rollback_max_depth(mut self, max_depth: NonZeroU32) -> Self {
+        self.auto_rollback_max_depth = Some(max_depth);
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl WiringLayer for ReorgDetectorLayer {
+    fn layer_name(&self) -> &'static str {
+        "reorg_detector_layer"
+    }
+
+    async fn wire(self: Box<Self>, mut context: ServiceContext<'_>) -> Result<(), WiringError> {
+        let main_node_client = context.get_resource::<MainNodeClientResource>().await?.0;
+        let pool_resource = context.get_resource::<PoolResource<MasterPool>>().await?;
+        let pool = pool_resource.get().await?;
+        let reverter = match context.get_resource::<BlockReverterResource>().await {
+            Ok(reverter) => Some(reverter.0),
+            Err(WiringError::ResourceLacking { .. }) => None,
+            Err(err) => return Err(err),
+        };
+
+        let startup_detector = ReorgDetector::new(main_node_client.clone(), pool.clone());
+        if let Some(reverter) = reverter {
+            context.add_precondition(Box::new(ReorgRollbackPrecondition {
+                reorg_detector: startup_detector,
+                reverter,
+                pool: pool.clone(),
+                max_depth: self.auto_rollback_max_depth,
+            }));
+        } else {
+            tracing::warn!(
+                "Block reverter is not provided; reorgs will be detected, but not rolled back automatically"
+            );
+            context.add_precondition(Box::new(ReorgCheckerPrecondition {
+                reorg_detector: startup_detector,
+            }));
+        }
+
+        let reorg_detector = ReorgDetector::new(main_node_client, pool);
+        let AppHealthCheckResource(app_health) = context.get_resource_or_default().await;
+        app_health
+            .insert_component(reorg_detector.health_check().clone())
+            .map_err(WiringError::internal)?;
+        context.add_task(Box::new(ReorgDetectorTask { reorg_detector }));
+        Ok(())
+    }
+}
+
+/// Rolls back the node state if a reorg is detected on startup.
+pub struct ReorgRollbackPrecondition {
+    reorg_detector: ReorgDetector,
+    reverter: Arc<BlockReverter>,
+    pool: ConnectionPool<Core>,
+    max_depth: Option<NonZeroU32>,
+}
+
+#[async_trait::async_trait]
+impl Precondition for ReorgRollbackPrecondition {
+    fn id(&self) -> TaskId {
+        "reorg_rollback".into()
+    }
+
+    async fn check(mut self: Box<Self>, stop_receiver: StopReceiver) -> anyhow::Result<()> {
+        match self.reorg_detector.run_once(stop_receiver.0).await {
+            Ok(()) => Ok(()),
+            Err(zksync_reorg_detector::Error::ReorgDetected(last_correct_l1_batch)) => {
+                if let Some(max_depth) = self.max_depth {
+                    zksync_reorg_detector::check_rollback_depth(
+                        &self.pool,
+                        last_correct_l1_batch,
+                        max_depth,
+                    )
+                    .await?;
+                }
+                tracing::info!("Reverting to L1 batch #{last_correct_l1_batch}");
+                self.reverter.roll_back(last_correct_l1_batch).await?;
+                tracing::info!("Revert successfully completed");
+                Ok(())
+            }
+            Err(err) => Err(err).context("reorg_detector.run_once()"),
+        }
+    }
+}
+
+/// Waits until the node state is consistent with the main node on startup.
+pub struct ReorgCheckerPrecondition {
+    reorg_detector: ReorgDetector,
+}
+
+#[async_trait::async_trait]
+impl Precondition for ReorgCheckerPrecondition {
+    fn id(&self) -> TaskId {
+        "reorg_checker".into()
+    }
+
+    async fn check(mut self: Box<Self>, mut stop_receiver: StopReceiver) -> anyhow::Result<()> {
+        loop {
+            match self.reorg_detector.run_once(stop_receiver.0.clone()).await {
+                Ok(()) => return Ok(()),
+                Err(zksync_reorg_detector::Error::ReorgDetected(last_correct_l1_batch)) => {
+                    tracing::warn!(
+                        "Reorg detected, last correct L1 batch #{last_correct_l1_batch}. Waiting until it's resolved; \
+                         retrying in {REORG_DETECTED_SLEEP_INTERVAL:?}"
+                    );
+                    if tokio::time::timeout(
+                        REORG_DETECTED_SLEEP_INTERVAL,
+                        stop_receiver.0.changed(),
+                    )
+                    .await
+                    .is_ok()
+                    {
+                        return Ok(());
+                    }
+                }
+                Err(err) => return Err(err).context("reorg_detector.run_once()"),
+            }
+        }
+    }
+}
+
+/// Continuously checks the node state against the main node. Exits with an error if a reorg is detected.
+#[derive(Debug)]
+pub struct ReorgDetectorTask {
+    reorg_detector: ReorgDetector,
+}
+
+#[async_trait::async_trait]
+impl Task for ReorgDetectorTask {
+    fn id(&self) -> TaskId {
+        "reorg_detector".into()
+    }
+
+    async fn run(self: Box<Self>, stop_receiver: StopReceiver) -> anyhow::Result<()> {
+        match self.reorg_detector.run(stop_receiver.0).await {
+            Ok(()) => Ok(()),
+            Err(zksync_reorg_detector::Error::ReorgDetected(last_correct_l1_batch)) => {
+                anyhow::bail!(
+                    "Reorg detected; last correct L1 batch is #{last_correct_l1_batch}. The node state will be \
+                     rolled back on restart"
+                )
+            }
+            Err(err) => Err(err).context("reorg_detector.run()"),
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use anyhow::Context as _;
+use futures::FutureExt as _;
+use secrecy::Secret;
+use tokio::sync::watch;
+use zksync_secrets::{SecretRef, SecretsResolver};
+
+use crate::{
+    service::{ServiceContext, StopReceiver},
+    task::{TaskId, UnconstrainedTask},
+    wiring_layer::{WiringError, WiringLayer},
+};
+
+/// Wiring layer tracking rotation of secrets fetched from secret managers (e.g., Vault or AWS Secrets Manager).
+///
+/// Secrets resolved on node start (database URLs, L1 RPC URL, operator and consensus keys) cannot be applied
+/// at runtime. Thus, once any of these secrets is rotated, the task exits with an error, stopping the node so that
+/// it's restarted with the new secret values by the orchestrator. Secrets are refetched with the interval
+/// configured for the resolver. If no secrets are fetched from secret managers, the layer does nothing.
+///
+/// ## Adds tasks
+///
+/// - `SecretsRotationTask`
+#[derive(Debug)]
+pub struct SecretsRotationLayer {
+    resolver: SecretsResolver,
+}
+
+impl SecretsRotationLayer {
+    pub fn new(resolver: SecretsResolver) -> Self {
+        Self { resolver }
+    }
+}
+
+#[async_trait::async_trait]
+impl WiringLayer for SecretsRotationLayer {
+    fn layer_name(&self) -> &'static str {
+        "secrets_rotation_layer"
+    }
+
+    async fn wire(self: Box<Self>, mut context: ServiceContext<'_>) -> Result<(), WiringError> {
+        let mut subscriptions = vec![];
+        for reference in self.resolver.resolved_references() {
+            let receiver = self
+                .resolver
+                .subscribe(reference.clone())
+                .await
+                .with_context(|| format!("failed subscribing to secret `{reference}`"))?;
+            subscriptions.push((reference, receiver));
+        }
+        if subscriptions.is_empty() {
+            tracing::info!("No secrets are fetched from secret managers; rotation is not tracked");
+            return Ok(());
+        }
+
+        context.add_unconstrained_task(Box::new(SecretsRotationTask {
+            resolver: self.resolver,
+            subscriptions,
+        }));
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+struct SecretsRotationTask {
+    resolver: SecretsResolver,
+    subscriptions: Vec<(SecretRef, watch::Receiver<Secret<String>>)>,
+}
+
+#[async_trait::async_trait]
+impl UnconstrainedTask for SecretsRotationTask {
+    fn id(&self) -> TaskId {
+        "secrets_rotation".into()
+    }
+
+    async fn run_unconstrained(self: Box<Self>, stop_receiver: StopReceiver) -> anyhow::Result<()> {
+        tracing::info!(
+            "Tracking rotation of {} secrets with interval {:?}",
+            self.subscriptions.len(),
+            self.resolver.rotation_interval()
+        );
+
+        let rotated_secrets = self
+            .subscriptions
+            .into_iter()
+            .map(|(reference, mut receiver)| {
+                async move {
+                    if receiver.changed().await.is_err() {
+                        // The subscription can only be closed when the resolver is dropped; this shouldn't happen
+                        // while the rotation is running.
+                        futures::future::pending::<()>().await;
+                    }
+                    reference
+                }
+                .boxed()
+            });
+        let rotated_secret = futures::future::select_all(rotated_secrets);
+        tokio::select! {
+            (reference, ..) = rotated_secret => {
+                anyhow::bail!(
+                    "secret `{reference}` was rotated; the node is stopped to be restarted with the new value"
+                );
+            }
+            result = self.resolver.run_rotation(stop_receiver.0) => result,
+        }
+    }
+}
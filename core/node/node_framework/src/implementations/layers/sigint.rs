@@ -6,8 +6,9 @@ use crate::{
     wiring_layer::{WiringError, WiringLayer},
 };
 
-/// Layer that changes the handling of SIGINT signal, preventing an immediate shutdown.
+/// Layer that changes the handling of SIGINT and SIGTERM signals, preventing an immediate shutdown.
 /// Instead, it would propagate the signal to the rest of the node, allowing it to shut down gracefully.
+/// E.g., API servers stop accepting new connections and drain in-flight requests before the node exits.
 #[derive(Debug)]
 pub struct SigintHandlerLayer;
 
@@ -18,7 +19,7 @@ impl WiringLayer for SigintHandlerLayer {
     }
 
     async fn wire(self: Box<Self>, mut node: ServiceContext<'_>) -> Result<(), WiringError> {
-        // SIGINT may happen at any time, so we must handle it as soon as it happens.
+        // SIGINT / SIGTERM may happen at any time, so we must handle it as soon as it happens.
         node.add_unconstrained_task(Box::new(SigintHandlerTask));
         Ok(())
     }
@@ -40,6 +41,7 @@ impl UnconstrainedTask for SigintHandlerTask {
         let (sigint_sender, sigint_receiver) = oneshot::channel();
         let mut sigint_sender = Some(sigint_sender); // Has to be done this way since `set_handler` requires `FnMut`.
         ctrlc::set_handler(move || {
+            // With the `termination` feature, the handler is invoked on SIGTERM and SIGHUP as well.
             if let Some(sigint_sender) = sigint_sender.take() {
                 sigint_sender.send(()).ok();
                 // ^ The send fails if `sigint_receiver` is dropped. We're OK with this,
@@ -49,9 +51,11 @@ impl UnconstrainedTask for SigintHandlerTask {
         })
         .expect("Error setting Ctrl+C handler");
 
-        // Wait for either SIGINT or stop signal.
+        // Wait for either SIGINT / SIGTERM or stop signal.
         tokio::select! {
-            _ = sigint_receiver => {},
+            _ = sigint_receiver => {
+                tracing::info!("Received termination signal, shutting down the node gracefully");
+            },
             _ = stop_receiver.0.changed() => {},
         };
 
@@ -0,0 +1,107 @@
+use zksync_config::configs::SloConfig;
+use zksync_health_check::SloMonitor;
+use zksync_node_api_server::web3::recent_call_latency_p99;
+
+use crate::{
+    implementations::resources::{
+        healthcheck::AppHealthCheckResource,
+        pools::{MasterPool, PoolResource},
+        sync_state::SyncStateResource,
+    },
+    service::{ServiceContext, StopReceiver},
+    task::{Task, TaskId},
+    wiring_layer::{WiringError, WiringLayer},
+};
+
+/// Wiring layer for built-in SLO checks. Each threshold set in [`SloConfig`] is exposed as a health component
+/// that is degraded while the threshold is exceeded.
+///
+/// ## Requests resources
+///
+/// - `PoolResource<MasterPool>` (if the pool saturation threshold is set)
+/// - `SyncStateResource` (if the sync lag threshold is set)
+/// - `AppHealthCheckResource` (adds health checks)
+///
+/// ## Adds tasks
+///
+/// - `SloMonitorTask` (if at least one threshold is set)
+#[derive(Debug)]
+pub struct SloMonitorLayer {
+    config: SloConfig,
+}
+
+impl SloMonitorLayer {
+    pub fn new(config: SloConfig) -> Self {
+        Self { config }
+    }
+}
+
+#[async_trait::async_trait]
+impl WiringLayer for SloMonitorLayer {
+    fn layer_name(&self) -> &'static str {
+        "slo_monitor_layer"
+    }
+
+    async fn wire(self: Box<Self>, mut context: ServiceContext<'_>) -> Result<(), WiringError> {
+        let mut monitor = SloMonitor::new(self.config.check_interval());
+        let mut health_checks = vec![];
+
+        if let Some(max_sync_lag) = self.config.max_sync_lag {
+            let SyncStateResource(sync_state) = context.get_resource().await?;
+            health_checks.push(
+                monitor.add_check("slo_sync_lag", max_sync_lag.into(), move || {
+                    let lag = sync_state
+                        .get_main_node_block()
+                        .0
+                        .saturating_sub(sync_state.get_local_block().0);
+                    Some(lag.into())
+                }),
+            );
+        }
+        if let Some(max_saturation) = self.config.max_pool_saturation {
+            let pool_resource = context.get_resource::<PoolResource<MasterPool>>().await?;
+            let pool = pool_resource.get().await?;
+            health_checks.push(monitor.add_check(
+                "slo_pool_saturation",
+                max_saturation,
+                move || Some(pool.saturation()),
+            ));
+        }
+        if let Some(max_latency) = self.config.max_api_p99_latency() {
+            health_checks.push(monitor.add_check(
+                "slo_api_p99_latency",
+                max_latency.as_secs_f64(),
+                || recent_call_latency_p99().map(|latency| latency.as_secs_f64()),
+            ));
+        }
+
+        if monitor.is_empty() {
+            return Ok(());
+        }
+        let AppHealthCheckResource(app_health) = context.get_resource_or_default().await;
+        for health_check in health_checks {
+            app_health
+                .insert_component(health_check)
+                .map_err(WiringError::internal)?;
+        }
+        context.add_task(Box::new(SloMonitorTask { monitor }));
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+struct SloMonitorTask {
+    monitor: SloMonitor,
+}
+
+#[async_trait::async_trait]
+impl Task for SloMonitorTask {
+    fn id(&self) -> TaskId {
+        "slo_monitor".into()
+    }
+
+    async fn run(self: Box<Self>, stop_receiver: StopReceiver) -> anyhow::Result<()> {
+        self.monitor.run(stop_receiver.0).await;
+        Ok(())
+    }
+}
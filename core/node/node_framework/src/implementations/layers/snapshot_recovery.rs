@@ -0,0 +1,97 @@
+use zksync_node_sync::storage_init::NodeStorageInitializer;
+pub use zksync_node_sync::storage_init::SnapshotRecoveryConfig;
+use zksync_snapshots_applier::SnapshotsApplierConfig;
+use zksync_types::L2ChainId;
+
+use crate::{
+    implementations::resources::{
+        healthcheck::AppHealthCheckResource,
+        main_node_client::MainNodeClientResource,
+        object_store::ObjectStoreResource,
+        pools::{MasterPool, PoolResource},
+    },
+    precondition::Precondition,
+    service::{ServiceContext, StopReceiver},
+    task::TaskId,
+    wiring_layer::{WiringError, WiringLayer},
+};
+
+/// Wiring layer for external node storage initialization.
+///
+/// If the node database is empty, the node is initialized either from genesis (fetched from the main node),
+/// or from a snapshot in the object store if snapshot recovery is enabled. Initialization is performed
+/// as a precondition, so that tasks relying on Postgres state (e.g., the consistency checker or the Merkle tree,
+/// which in turn recovers from Postgres data) are only started after recovery is finished. If the database
+/// is already initialized, only checks that it is consistent with the chosen initialization strategy.
+///
+/// ## Requests resources
+///
+/// - `PoolResource<MasterPool>`
+/// - `MainNodeClientResource`
+/// - `ObjectStoreResource` (only if snapshot recovery is enabled)
+/// - `AppHealthCheckResource` (adds a health check if snapshot recovery is performed)
+///
+/// ## Adds preconditions
+///
+/// - `StorageInitializationPrecondition`
+#[derive(Debug)]
+pub struct SnapshotRecoveryLayer {
+    l2_chain_id: L2ChainId,
+    recovery_config: Option<SnapshotRecoveryConfig>,
+}
+
+impl SnapshotRecoveryLayer {
+    /// Creates a layer that initializes the node from genesis only.
+    pub fn new(l2_chain_id: L2ChainId) -> Self {
+        Self {
+            l2_chain_id,
+            recovery_config: None,
+        }
+    }
+
+    /// Enables recovery from a snapshot if the node database is empty.
+    pub fn with_snapshot_recovery(mut self, config: SnapshotRecoveryConfig) -> Self {
+        self.recovery_config = Some(config);
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl WiringLayer for SnapshotRecoveryLayer {
+    fn layer_name(&self) -> &'static str {
+        "snapshot_recovery_layer"
+    }
+
+    async fn wire(self: Box<Self>, mut context: ServiceContext<'_>) -> Result<(), WiringError> {
+        let pool_resource = context.get_resource::<PoolResource<MasterPool>>().await?;
+        let pool = pool_resource
+            .get_custom(SnapshotsApplierConfig::default().max_concurrency.get() as u32)
+            .await?;
+        let MainNodeClientResource(main_node_client) = context.get_resource().await?;
+        let AppHealthCheckResource(app_health) = context.get_resource_or_default().await;
+
+        let mut initializer =
+            NodeStorageInitializer::new(pool, main_node_client, app_health, self.l2_chain_id);
+        if let Some(config) = self.recovery_config {
+            let ObjectStoreResource(object_store) = context.get_resource().await?;
+            initializer = initializer.with_snapshot_recovery(config, object_store);
+        }
+
+        context.add_precondition(Box::new(StorageInitializationPrecondition(initializer)));
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub struct StorageInitializationPrecondition(NodeStorageInitializer);
+
+#[async_trait::async_trait]
+impl Precondition for StorageInitializationPrecondition {
+    fn id(&self) -> TaskId {
+        "storage_initialization".into()
+    }
+
+    async fn check(self: Box<Self>, stop_receiver: StopReceiver) -> anyhow::Result<()> {
+        self.0.run(stop_receiver.0).await
+    }
+}
@@ -0,0 +1,96 @@
+use std::sync::Arc;
+
+use anyhow::Context as _;
+use zksync_config::SnapshotsCreatorConfig;
+use zksync_object_store::{ObjectStore, ObjectStoreFactory};
+use zksync_snapshots_creator::{SnapshotCreator, MIN_CHUNK_COUNT};
+
+use crate::{
+    implementations::resources::{
+        object_store::ObjectStoreResource,
+        pools::{MasterPool, PoolResource, ReplicaPool},
+    },
+    service::{ServiceContext, StopReceiver},
+    task::{Task, TaskId},
+    wiring_layer::{WiringError, WiringLayer},
+};
+
+/// Wiring layer for the snapshot creator, which periodically exports storage logs and factory dependencies
+/// into chunked object store files, so that other nodes can recover from them.
+///
+/// Snapshots are stored in the object store specified in the creator config; if it's not specified,
+/// the object store provided by `ObjectStoreResource` is used. Snapshot creation is resumed after
+/// an interruption (e.g., a node restart). The creator must run on a single node for the chain at a time.
+///
+/// ## Requests resources
+///
+/// - `PoolResource<MasterPool>`
+/// - `PoolResource<ReplicaPool>`
+/// - `ObjectStoreResource` (only if the object store is not specified in the config)
+///
+/// ## Adds tasks
+///
+/// - `SnapshotsCreatorTask`
+#[derive(Debug)]
+pub struct SnapshotsCreatorLayer {
+    config: SnapshotsCreatorConfig,
+}
+
+impl SnapshotsCreatorLayer {
+    pub fn new(config: SnapshotsCreatorConfig) -> Self {
+        Self { config }
+    }
+}
+
+#[async_trait::async_trait]
+impl WiringLayer for SnapshotsCreatorLayer {
+    fn layer_name(&self) -> &'static str {
+        "snapshots_creator_layer"
+    }
+
+    async fn wire(self: Box<Self>, mut context: ServiceContext<'_>) -> Result<(), WiringError> {
+        let master_pool = context
+            .get_resource::<PoolResource<MasterPool>>()
+            .await?
+            .get_singleton()
+            .await?;
+        let replica_pool = context
+            .get_resource::<PoolResource<ReplicaPool>>()
+            .await?
+            .get_custom(self.config.concurrent_queries_count)
+            .await?;
+        let blob_store: Arc<dyn ObjectStore> = if let Some(config) = &self.config.object_store {
+            ObjectStoreFactory::new(config.clone())
+                .create_store()
+                .await
+                .context("failed creating snapshots object store")?
+        } else {
+            context.get_resource::<ObjectStoreResource>().await?.0
+        };
+
+        context.add_task(Box::new(SnapshotsCreatorTask {
+            creator: SnapshotCreator::new(blob_store, master_pool, replica_pool),
+            config: self.config,
+        }));
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub struct SnapshotsCreatorTask {
+    creator: SnapshotCreator,
+    config: SnapshotsCreatorConfig,
+}
+
+#[async_trait::async_trait]
+impl Task for SnapshotsCreatorTask {
+    fn id(&self) -> TaskId {
+        "snapshots_creator".into()
+    }
+
+    async fn run(self: Box<Self>, stop_receiver: StopReceiver) -> anyhow::Result<()> {
+        self.creator
+            .run_periodically(self.config, MIN_CHUNK_COUNT, stop_receiver.0)
+            .await
+    }
+}
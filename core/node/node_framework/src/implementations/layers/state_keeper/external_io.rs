@@ -0,0 +1,130 @@
+use std::sync::Arc;
+
+use anyhow::Context as _;
+use zksync_node_sync::{ActionQueue, ExternalIO};
+use zksync_state_keeper::{
+    io::seal_logic::l2_block_seal_subtasks::L2BlockSealProcess, seal_criteria::NoopSealer,
+    OutputHandler, StateKeeperPersistence, TreeWritesPersistence,
+};
+use zksync_types::{Address, L2ChainId};
+
+use super::mempool_io::L2BlockSealerTask;
+use crate::{
+    implementations::resources::{
+        action_queue::ActionQueueSenderResource,
+        main_node_client::MainNodeClientResource,
+        pools::{MasterPool, PoolResource},
+        state_keeper::{ConditionalSealerResource, OutputHandlerResource, StateKeeperIOResource},
+        sync_state::SyncStateResource,
+    },
+    resource::Unique,
+    service::ServiceContext,
+    wiring_layer::{WiringError, WiringLayer},
+};
+
+/// Wiring layer for the external node state keeper I/O.
+///
+/// The external node doesn't have a mempool; instead, L2 blocks with transactions are fetched from the main node
+/// (by the consensus fetcher) and are passed to the state keeper via the action queue. L1 batches are sealed
+/// as directed by the main node, so no seal criteria are applied.
+///
+/// ## Requests resources
+///
+/// - `PoolResource<MasterPool>`
+/// - `MainNodeClientResource`
+/// - `SyncStateResource`
+///
+/// ## Adds resources
+///
+/// - `ActionQueueSenderResource`
+/// - `StateKeeperIOResource`
+/// - `OutputHandlerResource`
+/// - `ConditionalSealerResource`
+///
+/// ## Adds tasks
+///
+/// - `L2BlockSealerTask`
+#[derive(Debug)]
+pub struct ExternalIOLayer {
+    chain_id: L2ChainId,
+    l2_shared_bridge_addr: Address,
+    l2_block_seal_queue_capacity: usize,
+    protective_reads_persistence_enabled: bool,
+}
+
+impl ExternalIOLayer {
+    pub fn new(
+        chain_id: L2ChainId,
+        l2_shared_bridge_addr: Address,
+        l2_block_seal_queue_capacity: usize,
+    ) -> Self {
+        Self {
+            chain_id,
+            l2_shared_bridge_addr,
+            l2_block_seal_queue_capacity,
+            protective_reads_persistence_enabled: true,
+        }
+    }
+
+    /// Disables persisting protective reads. This is only sound if the node will never run a full Merkle tree.
+    pub fn without_protective_reads(mut self) -> Self {
+        self.protective_reads_persistence_enabled = false;
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl WiringLayer for ExternalIOLayer {
+    fn layer_name(&self) -> &'static str {
+        "external_io_layer"
+    }
+
+    async fn wire(self: Box<Self>, mut context: ServiceContext<'_>) -> Result<(), WiringError> {
+        // Fetch required resources.
+        let master_pool = context.get_resource::<PoolResource<MasterPool>>().await?;
+        let MainNodeClientResource(main_node_client) = context.get_resource().await?;
+        let SyncStateResource(sync_state) = context.get_resource().await?;
+
+        // Create L2 block sealer task and output handler.
+        // L2 Block sealing process is parallelized, so we have to provide enough pooled connections.
+        let persistence_pool = master_pool
+            .get_custom(L2BlockSealProcess::subtasks_len())
+            .await
+            .context("Get master pool")?;
+        let (persistence, l2_block_sealer) = StateKeeperPersistence::new(
+            persistence_pool.clone(),
+            self.l2_shared_bridge_addr,
+            self.l2_block_seal_queue_capacity,
+        );
+        let mut persistence = persistence.with_tx_insertion();
+        if !self.protective_reads_persistence_enabled {
+            tracing::warn!("Disabling persisting protective reads; this should be safe, but is considered an experimental option at the moment");
+            persistence = persistence.without_protective_reads();
+        }
+        let tree_writes_persistence = TreeWritesPersistence::new(persistence_pool);
+        let output_handler = OutputHandler::new(Box::new(persistence))
+            .with_handler(Box::new(tree_writes_persistence))
+            .with_handler(Box::new(sync_state));
+        context.insert_resource(OutputHandlerResource(Unique::new(output_handler)))?;
+        context.add_task(Box::new(L2BlockSealerTask(l2_block_sealer)));
+
+        // Create the action queue and IO resource.
+        let (action_queue_sender, action_queue) = ActionQueue::new();
+        context.insert_resource(ActionQueueSenderResource(Unique::new(action_queue_sender)))?;
+        let io_pool = master_pool.get().await.context("Get master pool")?;
+        let io = ExternalIO::new(
+            io_pool,
+            action_queue,
+            Box::new(main_node_client.for_component("external_io")),
+            self.chain_id,
+        )
+        .await
+        .context("Failed initializing I/O for external node state keeper")?;
+        context.insert_resource(StateKeeperIOResource(Unique::new(Box::new(io))))?;
+
+        // The main node is the source of truth for L1 batch sealing.
+        context.insert_resource(ConditionalSealerResource(Arc::new(NoopSealer)))?;
+
+        Ok(())
+    }
+}
@@ -10,15 +10,29 @@ use crate::{
 
 #[derive(Debug)]
 pub struct MainBatchExecutorLayer {
-    state_keeper_config: StateKeeperConfig,
+    save_call_traces: bool,
+    optional_bytecode_compression: bool,
 }
 
 impl MainBatchExecutorLayer {
     pub fn new(state_keeper_config: StateKeeperConfig) -> Self {
+        Self::with_call_traces(state_keeper_config.save_call_traces)
+    }
+
+    /// Creates a layer for a batch executor that saves call traces if `save_call_traces` is set.
+    pub fn with_call_traces(save_call_traces: bool) -> Self {
         Self {
-            state_keeper_config,
+            save_call_traces,
+            optional_bytecode_compression: false,
         }
     }
+
+    /// Allows executing transactions for which bytecode compression fails. This is required for the external node,
+    /// since it must execute all transactions included by the main node.
+    pub fn with_optional_bytecode_compression(mut self) -> Self {
+        self.optional_bytecode_compression = true;
+        self
+    }
 }
 
 #[async_trait::async_trait]
@@ -28,7 +42,8 @@ impl WiringLayer for MainBatchExecutorLayer {
     }
 
     async fn wire(self: Box<Self>, mut context: ServiceContext<'_>) -> Result<(), WiringError> {
-        let builder = MainBatchExecutor::new(self.state_keeper_config.save_call_traces, false);
+        let builder =
+            MainBatchExecutor::new(self.save_call_traces, self.optional_bytecode_compression);
 
         context.insert_resource(BatchExecutorResource(Unique::new(Box::new(builder))))?;
         Ok(())
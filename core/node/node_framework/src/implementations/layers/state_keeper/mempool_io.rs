@@ -9,8 +9,9 @@ use zksync_config::{
     ContractsConfig,
 };
 use zksync_state_keeper::{
-    io::seal_logic::l2_block_seal_subtasks::L2BlockSealProcess, MempoolFetcher, MempoolGuard,
-    MempoolIO, OutputHandler, SequencerSealer, StateKeeperPersistence, TreeWritesPersistence,
+    io::seal_logic::l2_block_seal_subtasks::L2BlockSealProcess, seal_criteria::SealCriterion,
+    MempoolFetcher, MempoolGuard, MempoolIO, OutputHandler, SequencerSealer,
+    StateKeeperPersistence, TreeWritesPersistence,
 };
 use zksync_types::L2ChainId;
 
@@ -33,6 +34,7 @@ pub struct MempoolIOLayer {
     state_keeper_config: StateKeeperConfig,
     mempool_config: MempoolConfig,
     wallets: wallets::StateKeeper,
+    custom_seal_criteria: Vec<Box<dyn SealCriterion>>,
 }
 
 impl MempoolIOLayer {
@@ -49,9 +51,17 @@ impl MempoolIOLayer {
             state_keeper_config,
             mempool_config,
             wallets,
+            custom_seal_criteria: Vec::new(),
         }
     }
 
+    /// Registers a custom L1 batch seal criterion in addition to the default ones.
+    #[must_use]
+    pub fn with_seal_criterion(mut self, criterion: Box<dyn SealCriterion>) -> Self {
+        self.custom_seal_criteria.push(criterion);
+        self
+    }
+
     async fn build_mempool_guard(
         &self,
         master_pool: &PoolResource<MasterPool>,
@@ -87,11 +97,18 @@ impl WiringLayer for MempoolIOLayer {
             .get_custom(L2BlockSealProcess::subtasks_len())
             .await
             .context("Get master pool")?;
-        let (persistence, l2_block_sealer) = StateKeeperPersistence::new(
+        let (mut persistence, l2_block_sealer) = StateKeeperPersistence::new(
             persistence_pool.clone(),
             self.contracts_config.l2_shared_bridge_addr.unwrap(),
             self.state_keeper_config.l2_block_seal_queue_capacity,
         );
+        if !self
+            .state_keeper_config
+            .protective_reads_persistence_enabled
+        {
+            // Protective reads are computed asynchronously by the protective reads writer (VM runner).
+            persistence = persistence.without_protective_reads();
+        }
         let tree_writes_persistence = TreeWritesPersistence::new(persistence_pool);
         let output_handler = OutputHandler::new(Box::new(persistence))
             .with_handler(Box::new(tree_writes_persistence));
@@ -130,7 +147,14 @@ impl WiringLayer for MempoolIOLayer {
         context.insert_resource(StateKeeperIOResource(Unique::new(Box::new(io))))?;
 
         // Create sealer.
-        let sealer = SequencerSealer::new(self.state_keeper_config);
+        let mut sealer = SequencerSealer::new(self.state_keeper_config);
+        for criterion in self.custom_seal_criteria {
+            sealer = sealer.with_criterion(criterion);
+        }
+        tracing::info!(
+            "Using L1 batch seal criteria: {:?}",
+            sealer.criterion_names()
+        );
         context.insert_resource(ConditionalSealerResource(Arc::new(sealer)))?;
 
         Ok(())
@@ -138,7 +162,7 @@ impl WiringLayer for MempoolIOLayer {
 }
 
 #[derive(Debug)]
-struct L2BlockSealerTask(zksync_state_keeper::L2BlockSealerTask);
+pub(super) struct L2BlockSealerTask(pub(super) zksync_state_keeper::L2BlockSealerTask);
 
 #[async_trait::async_trait]
 impl Task for L2BlockSealerTask {
@@ -2,13 +2,17 @@ use std::sync::Arc;
 
 use anyhow::Context;
 use zksync_config::DBConfig;
-use zksync_state::{AsyncCatchupTask, ReadStorageFactory, RocksdbStorageOptions};
+use zksync_state::{
+    AsyncCatchupTask, ReadStorageFactory, RocksdbMaintenanceConfig, RocksdbMaintenanceTask,
+    RocksdbStorageOptions,
+};
 use zksync_state_keeper::{
     seal_criteria::ConditionalSealer, AsyncRocksdbCache, BatchExecutor, OutputHandler,
     StateKeeperIO, ZkSyncStateKeeper,
 };
 use zksync_storage::RocksDB;
 
+pub mod external_io;
 pub mod main_batch_executor;
 pub mod mempool_io;
 
@@ -75,6 +79,7 @@ impl WiringLayer for StateKeeperLayer {
                 .experimental
                 .state_keeper_db_block_cache_capacity(),
             max_open_files: self.db_config.experimental.state_keeper_db_max_open_files,
+            periodic_compaction_ttl: self.db_config.experimental.state_keeper_db_compaction_ttl(),
         };
         let (storage_factory, task) = AsyncRocksdbCache::new(
             master_pool.get_custom(2).await?,
@@ -82,6 +87,17 @@ impl WiringLayer for StateKeeperLayer {
             cache_options,
         );
         context.add_task(Box::new(RocksdbCatchupTask(task)));
+        let maintenance_config = RocksdbMaintenanceConfig {
+            compaction_interval: self
+                .db_config
+                .experimental
+                .state_keeper_db_compaction_interval(),
+            max_size_bytes: self.db_config.experimental.state_keeper_db_max_size(),
+            ..RocksdbMaintenanceConfig::default()
+        };
+        context.add_task(Box::new(RocksdbCacheMaintenanceTask(
+            storage_factory.maintenance_task(maintenance_config),
+        )));
 
         context.add_task(Box::new(StateKeeperTask {
             io,
@@ -144,3 +160,17 @@ impl Task for RocksdbCatchupTask {
         Ok(())
     }
 }
+
+#[derive(Debug)]
+struct RocksdbCacheMaintenanceTask(RocksdbMaintenanceTask);
+
+#[async_trait::async_trait]
+impl Task for RocksdbCacheMaintenanceTask {
+    fn id(&self) -> TaskId {
+        "state_keeper/rocksdb_maintenance_task".into()
+    }
+
+    async fn run(self: Box<Self>, stop_receiver: StopReceiver) -> anyhow::Result<()> {
+        self.0.run(stop_receiver.0).await
+    }
+}
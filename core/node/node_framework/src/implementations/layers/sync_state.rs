@@ -0,0 +1,100 @@
+use std::sync::Arc;
+
+use zksync_dal::{ConnectionPool, Core};
+use zksync_node_sync::SyncState;
+use zksync_web3_decl::client::{DynClient, L2};
+
+use crate::{
+    implementations::resources::{
+        healthcheck::AppHealthCheckResource,
+        main_node_client::MainNodeClientResource,
+        pools::{MasterPool, PoolResource},
+        sync_state::SyncStateResource,
+    },
+    service::{ServiceContext, StopReceiver},
+    task::{Task, TaskId},
+    wiring_layer::{WiringError, WiringLayer},
+};
+
+/// Wiring layer for the external node sync state.
+///
+/// The sync state tracks the last L2 block on the main node (updated by the fetcher) and the last L2 block
+/// processed locally (updated by the state keeper), so that the node can report whether it's synced.
+/// If the node doesn't run the state keeper, the layer can be configured to update the sync state itself
+/// (see [`Self::with_updater()`]).
+///
+/// ## Requests resources
+///
+/// - `AppHealthCheckResource` (adds a health check)
+/// - `MainNodeClientResource` (only if the updater is enabled)
+/// - `PoolResource<MasterPool>` (only if the updater is enabled)
+///
+/// ## Adds resources
+///
+/// - `SyncStateResource`
+///
+/// ## Adds tasks
+///
+/// - `SyncStateUpdaterTask` (only if the updater is enabled)
+#[derive(Debug, Default)]
+pub struct SyncStateLayer {
+    with_updater: bool,
+}
+
+impl SyncStateLayer {
+    /// Enables a task updating the sync state based on the main node and local Postgres data.
+    /// Should be used if the node doesn't run the state keeper.
+    pub fn with_updater(mut self) -> Self {
+        self.with_updater = true;
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl WiringLayer for SyncStateLayer {
+    fn layer_name(&self) -> &'static str {
+        "sync_state_layer"
+    }
+
+    async fn wire(self: Box<Self>, mut context: ServiceContext<'_>) -> Result<(), WiringError> {
+        let sync_state = SyncState::default();
+
+        let AppHealthCheckResource(app_health) = context.get_resource_or_default().await;
+        app_health
+            .insert_custom_component(Arc::new(sync_state.clone()))
+            .map_err(WiringError::internal)?;
+
+        if self.with_updater {
+            let MainNodeClientResource(main_node_client) = context.get_resource().await?;
+            let pool_resource = context.get_resource::<PoolResource<MasterPool>>().await?;
+            context.add_task(Box::new(SyncStateUpdaterTask {
+                sync_state: sync_state.clone(),
+                pool: pool_resource.get().await?,
+                main_node_client,
+            }));
+        }
+
+        context.insert_resource(SyncStateResource(sync_state))?;
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+struct SyncStateUpdaterTask {
+    sync_state: SyncState,
+    pool: ConnectionPool<Core>,
+    main_node_client: Box<DynClient<L2>>,
+}
+
+#[async_trait::async_trait]
+impl Task for SyncStateUpdaterTask {
+    fn id(&self) -> TaskId {
+        "sync_state_updater".into()
+    }
+
+    async fn run(self: Box<Self>, stop_receiver: StopReceiver) -> anyhow::Result<()> {
+        self.sync_state
+            .run_updater(self.pool, self.main_node_client, stop_receiver.0)
+            .await
+    }
+}
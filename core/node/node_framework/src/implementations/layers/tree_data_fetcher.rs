@@ -0,0 +1,93 @@
+use zksync_node_sync::tree_data_fetcher::TreeDataFetcher;
+use zksync_types::Address;
+
+use crate::{
+    implementations::resources::{
+        eth_interface::EthInterfaceResource,
+        healthcheck::AppHealthCheckResource,
+        main_node_client::MainNodeClientResource,
+        pools::{MasterPool, PoolResource},
+    },
+    service::{ServiceContext, StopReceiver},
+    task::{Task, TaskId},
+    wiring_layer::{WiringError, WiringLayer},
+};
+
+/// Wiring layer for the tree data fetcher, which fetches state root hashes of L1 batches from external sources
+/// and persists them to Postgres, allowing the node to operate without a Merkle tree.
+///
+/// Root hashes are fetched from the main node. If the diamond proxy address is specified, root hashes are also
+/// fetched from L1 `BlockCommit` events while catching up, and if the main node is unreachable or returns data
+/// inconsistent with the local state. The source of the root hash is recorded for each L1 batch.
+///
+/// ## Requests resources
+///
+/// - `MainNodeClientResource`
+/// - `EthInterfaceResource` (only if the diamond proxy address is specified)
+/// - `PoolResource<MasterPool>`
+/// - `AppHealthCheckResource` (adds a health check)
+///
+/// ## Adds tasks
+///
+/// - `TreeDataFetcherTask`
+#[derive(Debug)]
+pub struct TreeDataFetcherLayer {
+    diamond_proxy_addr: Option<Address>,
+}
+
+impl TreeDataFetcherLayer {
+    pub fn new(diamond_proxy_addr: Option<Address>) -> Self {
+        Self { diamond_proxy_addr }
+    }
+}
+
+#[async_trait::async_trait]
+impl WiringLayer for TreeDataFetcherLayer {
+    fn layer_name(&self) -> &'static str {
+        "tree_data_fetcher_layer"
+    }
+
+    async fn wire(self: Box<Self>, mut context: ServiceContext<'_>) -> Result<(), WiringError> {
+        // Get resources.
+        let MainNodeClientResource(main_node_client) = context.get_resource().await?;
+        let pool_resource = context.get_resource::<PoolResource<MasterPool>>().await?;
+        let singleton_pool = pool_resource.get_singleton().await?;
+
+        tracing::warn!(
+            "Running tree data fetcher (allows a node to operate w/o a Merkle tree or w/o waiting the tree to catch up). \
+             This is an experimental feature; do not use unless you know what you're doing"
+        );
+        let mut fetcher = TreeDataFetcher::new(main_node_client, singleton_pool);
+        if let Some(diamond_proxy_addr) = self.diamond_proxy_addr {
+            let EthInterfaceResource(eth_client) = context.get_resource().await?;
+            fetcher = fetcher
+                .with_l1_data(eth_client, diamond_proxy_addr)
+                .map_err(WiringError::Internal)?;
+        }
+
+        let AppHealthCheckResource(app_health) = context.get_resource_or_default().await;
+        app_health
+            .insert_component(fetcher.health_check())
+            .map_err(WiringError::internal)?;
+
+        // Create and add tasks.
+        context.add_task(Box::new(TreeDataFetcherTask { fetcher }));
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub struct TreeDataFetcherTask {
+    fetcher: TreeDataFetcher,
+}
+
+#[async_trait::async_trait]
+impl Task for TreeDataFetcherTask {
+    fn id(&self) -> TaskId {
+        "tree_data_fetcher".into()
+    }
+
+    async fn run(self: Box<Self>, stop_receiver: StopReceiver) -> anyhow::Result<()> {
+        self.fetcher.run(stop_receiver.0).await
+    }
+}
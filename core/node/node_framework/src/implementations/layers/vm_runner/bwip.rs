@@ -0,0 +1,90 @@
+use zksync_config::configs::vm_runner::BasicWitnessInputProducerConfig;
+use zksync_types::L2ChainId;
+use zksync_vm_runner::BasicWitnessInputProducer;
+
+use crate::{
+    implementations::resources::{
+        object_store::ObjectStoreResource,
+        pools::{MasterPool, PoolResource},
+    },
+    service::{ServiceContext, StopReceiver},
+    task::{Task, TaskId},
+    wiring_layer::{WiringError, WiringLayer},
+};
+
+/// Wiring layer for the VM runner producing basic witness inputs (BWIP), i.e. data required by the witness
+/// generator to produce basic circuits for L1 batches.
+///
+/// ## Requests resources
+///
+/// - `PoolResource<MasterPool>`
+/// - `ObjectStoreResource`
+///
+/// ## Adds tasks
+///
+/// - `StorageSyncTask`
+/// - `ConcurrentOutputHandlerFactoryTask`
+/// - `BasicWitnessInputProducerTask`
+#[derive(Debug)]
+pub struct VmRunnerBwipLayer {
+    config: BasicWitnessInputProducerConfig,
+    zksync_network_id: L2ChainId,
+}
+
+impl VmRunnerBwipLayer {
+    pub fn new(config: BasicWitnessInputProducerConfig, zksync_network_id: L2ChainId) -> Self {
+        Self {
+            config,
+            zksync_network_id,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl WiringLayer for VmRunnerBwipLayer {
+    fn layer_name(&self) -> &'static str {
+        "vm_runner_bwip"
+    }
+
+    async fn wire(self: Box<Self>, mut context: ServiceContext<'_>) -> Result<(), WiringError> {
+        let master_pool = context.get_resource::<PoolResource<MasterPool>>().await?;
+        let ObjectStoreResource(object_store) = context.get_resource().await?;
+
+        let (basic_witness_input_producer, tasks) = BasicWitnessInputProducer::new(
+            // One for `StorageSyncTask`, one for `ConcurrentOutputHandlerFactoryTask` / `VmRunner`,
+            // and `window_size` for output handlers that load bytecodes of contracts used in a batch.
+            master_pool.get_custom(self.config.window_size + 2).await?,
+            object_store,
+            self.config.db_path,
+            self.zksync_network_id,
+            self.config.first_processed_batch,
+            self.config.window_size,
+        )
+        .await?;
+
+        context.add_task(Box::new(tasks.loader_task));
+        context.add_task(Box::new(tasks.output_handler_factory_task));
+        context.add_task(Box::new(BasicWitnessInputProducerTask {
+            basic_witness_input_producer,
+        }));
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+struct BasicWitnessInputProducerTask {
+    basic_witness_input_producer: BasicWitnessInputProducer,
+}
+
+#[async_trait::async_trait]
+impl Task for BasicWitnessInputProducerTask {
+    fn id(&self) -> TaskId {
+        "vm_runner/bwip".into()
+    }
+
+    async fn run(self: Box<Self>, stop_receiver: StopReceiver) -> anyhow::Result<()> {
+        self.basic_witness_input_producer
+            .run(&stop_receiver.0)
+            .await
+    }
+}
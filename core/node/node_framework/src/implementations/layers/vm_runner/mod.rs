@@ -5,6 +5,8 @@ use crate::{
     task::{Task, TaskId},
 };
 
+pub mod bwip;
+pub mod playground;
 pub mod protective_reads;
 
 #[async_trait::async_trait]
@@ -0,0 +1,94 @@
+use zksync_config::configs::vm_runner::VmPlaygroundConfig;
+use zksync_types::L2ChainId;
+use zksync_vm_runner::VmPlayground;
+
+use crate::{
+    implementations::resources::{
+        healthcheck::AppHealthCheckResource,
+        pools::{MasterPool, PoolResource},
+    },
+    service::{ServiceContext, StopReceiver},
+    task::{Task, TaskId},
+    wiring_layer::{WiringError, WiringLayer},
+};
+
+/// Wiring layer for the VM playground, i.e. a VM runner re-executing L1 batches (possibly with a VM version
+/// different from the one used by the state keeper) and comparing the outcome with the persisted data.
+///
+/// ## Requests resources
+///
+/// - `PoolResource<MasterPool>`
+/// - `AppHealthCheckResource` (adds a health check)
+///
+/// ## Adds tasks
+///
+/// - `StorageSyncTask`
+/// - `ConcurrentOutputHandlerFactoryTask`
+/// - `VmPlaygroundTask`
+#[derive(Debug)]
+pub struct VmPlaygroundLayer {
+    config: VmPlaygroundConfig,
+    zksync_network_id: L2ChainId,
+}
+
+impl VmPlaygroundLayer {
+    pub fn new(config: VmPlaygroundConfig, zksync_network_id: L2ChainId) -> Self {
+        Self {
+            config,
+            zksync_network_id,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl WiringLayer for VmPlaygroundLayer {
+    fn layer_name(&self) -> &'static str {
+        "vm_runner_playground"
+    }
+
+    async fn wire(self: Box<Self>, mut context: ServiceContext<'_>) -> Result<(), WiringError> {
+        let master_pool = context.get_resource::<PoolResource<MasterPool>>().await?;
+        let vm_version = self
+            .config
+            .vm_version()
+            .map_err(|err| WiringError::Configuration(format!("{err:#}")))?;
+
+        let (vm_playground, tasks) = VmPlayground::new(
+            // One for `StorageSyncTask`, one for `ConcurrentOutputHandlerFactoryTask` / `VmRunner`,
+            // and `window_size` for output handlers that load persisted L1 batch data.
+            master_pool.get_custom(self.config.window_size + 2).await?,
+            self.config.db_path,
+            self.zksync_network_id,
+            self.config.first_processed_batch,
+            self.config.window_size,
+            vm_version,
+        )
+        .await?;
+
+        let AppHealthCheckResource(app_health) = context.get_resource_or_default().await;
+        app_health
+            .insert_component(vm_playground.health_check())
+            .map_err(WiringError::internal)?;
+
+        context.add_task(Box::new(tasks.loader_task));
+        context.add_task(Box::new(tasks.output_handler_factory_task));
+        context.add_task(Box::new(VmPlaygroundTask { vm_playground }));
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+struct VmPlaygroundTask {
+    vm_playground: VmPlayground,
+}
+
+#[async_trait::async_trait]
+impl Task for VmPlaygroundTask {
+    fn id(&self) -> TaskId {
+        "vm_runner/playground".into()
+    }
+
+    async fn run(self: Box<Self>, stop_receiver: StopReceiver) -> anyhow::Result<()> {
+        self.vm_playground.run(&stop_receiver.0).await
+    }
+}
@@ -0,0 +1,35 @@
+use zksync_node_api_server::web3::namespaces::DebugNamespaceConfig;
+
+use crate::{
+    implementations::resources::web3_api::DebugNamespaceResource,
+    service::ServiceContext,
+    wiring_layer::{WiringError, WiringLayer},
+};
+
+/// Wiring layer enabling the `debug` namespace (`debug_traceCall`, `debug_traceTransaction` etc.)
+/// on the Web3 API servers.
+///
+/// ## Adds resources
+/// - `DebugNamespaceResource`
+#[derive(Debug, Default)]
+pub struct DebugNamespaceLayer {
+    config: DebugNamespaceConfig,
+}
+
+impl DebugNamespaceLayer {
+    pub fn new(config: DebugNamespaceConfig) -> Self {
+        Self { config }
+    }
+}
+
+#[async_trait::async_trait]
+impl WiringLayer for DebugNamespaceLayer {
+    fn layer_name(&self) -> &'static str {
+        "debug_namespace_layer"
+    }
+
+    async fn wire(self: Box<Self>, mut context: ServiceContext<'_>) -> Result<(), WiringError> {
+        context.insert_resource(DebugNamespaceResource(self.config))?;
+        Ok(())
+    }
+}
@@ -1,5 +1,7 @@
 pub mod caches;
+pub mod debug;
 pub mod server;
 pub mod tree_api_client;
+pub mod tx_proxy;
 pub mod tx_sender;
 pub mod tx_sink;
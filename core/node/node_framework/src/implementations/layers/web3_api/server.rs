@@ -3,15 +3,20 @@ use std::{num::NonZeroU32, time::Duration};
 use tokio::{sync::oneshot, task::JoinHandle};
 use zksync_circuit_breaker::replication_lag::ReplicationLagChecker;
 use zksync_config::configs::api::MaxResponseSize;
-use zksync_node_api_server::web3::{state::InternalApiConfig, ApiBuilder, ApiServer, Namespace};
+use zksync_node_api_server::web3::{
+    state::InternalApiConfig, ApiBuilder, ApiServer, ClientRateLimit, MethodFilter, Namespace,
+};
 
 use crate::{
     implementations::resources::{
         circuit_breakers::CircuitBreakersResource,
         healthcheck::AppHealthCheckResource,
         pools::{PoolResource, ReplicaPool},
+        reloadable_config::ReloadableConfigResource,
         sync_state::SyncStateResource,
-        web3_api::{MempoolCacheResource, TreeApiClientResource, TxSenderResource},
+        web3_api::{
+            DebugNamespaceResource, MempoolCacheResource, TreeApiClientResource, TxSenderResource,
+        },
     },
     service::{ServiceContext, StopReceiver},
     task::{Task, TaskId},
@@ -22,11 +27,19 @@ use crate::{
 #[derive(Debug, Default)]
 pub struct Web3ServerOptionalConfig {
     pub namespaces: Option<Vec<Namespace>>,
+    pub method_filter: Option<MethodFilter>,
     pub filters_limit: Option<usize>,
     pub subscriptions_limit: Option<usize>,
+    pub subscriptions_per_connection_limit: Option<usize>,
+    pub subscription_backlog_size: Option<usize>,
     pub batch_request_size_limit: Option<usize>,
     pub response_body_size_limit: Option<MaxResponseSize>,
     pub websocket_requests_per_minute_limit: Option<NonZeroU32>,
+    pub shutdown_drain_timeout: Option<Duration>,
+    pub polling_interval: Option<Duration>,
+    pub pruning_info_refresh_interval: Option<Duration>,
+    pub client_rate_limit: Option<ClientRateLimit>,
+    pub extended_tracing: bool,
     // used by circuit breaker.
     pub replication_lag_limit: Option<Duration>,
 }
@@ -36,12 +49,21 @@ impl Web3ServerOptionalConfig {
         if let Some(namespaces) = self.namespaces {
             api_builder = api_builder.enable_api_namespaces(namespaces);
         }
+        if let Some(method_filter) = self.method_filter {
+            api_builder = api_builder.with_method_filter(method_filter);
+        }
         if let Some(filters_limit) = self.filters_limit {
             api_builder = api_builder.with_filter_limit(filters_limit);
         }
         if let Some(subscriptions_limit) = self.subscriptions_limit {
             api_builder = api_builder.with_subscriptions_limit(subscriptions_limit);
         }
+        if let Some(limit) = self.subscriptions_per_connection_limit {
+            api_builder = api_builder.with_subscriptions_per_connection_limit(limit);
+        }
+        if let Some(size) = self.subscription_backlog_size {
+            api_builder = api_builder.with_subscription_backlog_size(size);
+        }
         if let Some(batch_request_size_limit) = self.batch_request_size_limit {
             api_builder = api_builder.with_batch_request_size_limit(batch_request_size_limit);
         }
@@ -53,7 +75,19 @@ impl Web3ServerOptionalConfig {
             api_builder = api_builder
                 .with_websocket_requests_per_minute_limit(websocket_requests_per_minute_limit);
         }
-        api_builder
+        if let Some(shutdown_drain_timeout) = self.shutdown_drain_timeout {
+            api_builder = api_builder.with_shutdown_drain_timeout(shutdown_drain_timeout);
+        }
+        if let Some(polling_interval) = self.polling_interval {
+            api_builder = api_builder.with_polling_interval(polling_interval);
+        }
+        if let Some(interval) = self.pruning_info_refresh_interval {
+            api_builder = api_builder.with_pruning_info_refresh_interval(interval);
+        }
+        if let Some(rate_limit) = self.client_rate_limit {
+            api_builder = api_builder.with_client_rate_limit(rate_limit);
+        }
+        api_builder.with_extended_tracing(self.extended_tracing)
     }
 }
 
@@ -113,7 +147,7 @@ impl WiringLayer for Web3ServerLayer {
         // Get required resources.
         let replica_resource_pool = context.get_resource::<PoolResource<ReplicaPool>>().await?;
         let updaters_pool = replica_resource_pool.get_custom(2).await?;
-        let replica_pool = replica_resource_pool.get().await?;
+        let replica_pool = replica_resource_pool.get_api().await?;
         let tx_sender = context.get_resource::<TxSenderResource>().await?.0;
         let sync_state = match context.get_resource::<SyncStateResource>().await {
             Ok(sync_state) => Some(sync_state.0),
@@ -125,7 +159,17 @@ impl WiringLayer for Web3ServerLayer {
             Err(WiringError::ResourceLacking { .. }) => None,
             Err(err) => return Err(err),
         };
+        let debug_namespace = match context.get_resource::<DebugNamespaceResource>().await {
+            Ok(config) => Some(config.0),
+            Err(WiringError::ResourceLacking { .. }) => None,
+            Err(err) => return Err(err),
+        };
         let MempoolCacheResource(mempool_cache) = context.get_resource().await?;
+        let reloadable_config = match context.get_resource::<ReloadableConfigResource>().await {
+            Ok(config) => Some(config.0),
+            Err(WiringError::ResourceLacking { .. }) => None,
+            Err(err) => return Err(err),
+        };
 
         // Build server.
         let mut api_builder =
@@ -142,11 +186,17 @@ impl WiringLayer for Web3ServerLayer {
             }
             Transport::Ws => {
                 api_builder = api_builder.ws(self.port);
+                if let Some(config) = &reloadable_config {
+                    api_builder = api_builder.with_config_updates(config.subscribe());
+                }
             }
         }
         if let Some(sync_state) = sync_state {
             api_builder = api_builder.with_sync_state(sync_state);
         }
+        if let Some(config) = debug_namespace {
+            api_builder = api_builder.with_debug_namespace(config);
+        }
         let replication_lag_limit = self.optional_config.replication_lag_limit;
         api_builder = self.optional_config.apply(api_builder);
         let server = api_builder.build()?;
@@ -0,0 +1,115 @@
+use std::sync::Arc;
+
+use zksync_dal::{ConnectionPool, Core};
+use zksync_node_api_server::tx_sender::proxy::{TxProxy, TxProxyQueueConfig, TxResubmissionTask};
+
+use crate::{
+    implementations::resources::{
+        healthcheck::AppHealthCheckResource,
+        main_node_client::MainNodeClientResource,
+        pools::{MasterPool, PoolResource},
+        web3_api::TxSinkResource,
+    },
+    service::{ServiceContext, StopReceiver},
+    task::{Task, TaskId},
+    wiring_layer::{WiringError, WiringLayer},
+};
+
+/// Wiring layer for the transaction proxy used by the external node to forward transactions to the main node.
+///
+/// Proxied transactions are persisted in a Postgres-backed queue and resubmitted to the main node
+/// with exponential backoff until they are included into a block, rejected by the main node, or expire.
+///
+/// ## Requests resources
+///
+/// - `MainNodeClientResource`
+/// - `PoolResource<MasterPool>`
+/// - `AppHealthCheckResource` (adds a health check)
+///
+/// ## Adds resources
+///
+/// - `TxSinkResource`
+///
+/// ## Adds tasks
+///
+/// - `TxProxyNonceSweeperTask`
+/// - `TxProxyResubmissionTask`
+#[derive(Debug)]
+pub struct TxProxyLayer {
+    queue_config: TxProxyQueueConfig,
+}
+
+impl TxProxyLayer {
+    pub fn new(queue_config: TxProxyQueueConfig) -> Self {
+        Self { queue_config }
+    }
+}
+
+#[async_trait::async_trait]
+impl WiringLayer for TxProxyLayer {
+    fn layer_name(&self) -> &'static str {
+        "tx_proxy_layer"
+    }
+
+    async fn wire(self: Box<Self>, mut context: ServiceContext<'_>) -> Result<(), WiringError> {
+        let MainNodeClientResource(client) = context.get_resource().await?;
+        let pool_resource = context.get_resource::<PoolResource<MasterPool>>().await?;
+        let queue_pool = pool_resource.get().await?;
+        let sweeper_pool = pool_resource.get_singleton().await?;
+
+        let proxy = TxProxy::new(client).with_resubmission_queue(queue_pool, self.queue_config);
+        let resubmission_task = proxy
+            .resubmission_task()
+            .expect("resubmission queue is enabled");
+        let AppHealthCheckResource(app_health) = context.get_resource_or_default().await;
+        app_health
+            .insert_component(resubmission_task.health_check())
+            .map_err(WiringError::internal)?;
+
+        let proxy = Arc::new(proxy);
+        context.insert_resource(TxSinkResource(proxy.clone()))?;
+        context.add_task(Box::new(TxProxyNonceSweeperTask {
+            proxy,
+            pool: sweeper_pool,
+        }));
+        context.add_task(Box::new(TxProxyResubmissionTask {
+            task: resubmission_task,
+        }));
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+struct TxProxyNonceSweeperTask {
+    proxy: Arc<TxProxy>,
+    pool: ConnectionPool<Core>,
+}
+
+#[async_trait::async_trait]
+impl Task for TxProxyNonceSweeperTask {
+    fn id(&self) -> TaskId {
+        "tx_proxy_nonce_sweeper".into()
+    }
+
+    async fn run(self: Box<Self>, stop_receiver: StopReceiver) -> anyhow::Result<()> {
+        self.proxy
+            .run_account_nonce_sweeper(self.pool, stop_receiver.0)
+            .await
+    }
+}
+
+#[derive(Debug)]
+struct TxProxyResubmissionTask {
+    task: TxResubmissionTask,
+}
+
+#[async_trait::async_trait]
+impl Task for TxProxyResubmissionTask {
+    fn id(&self) -> TaskId {
+        "tx_proxy_resubmission".into()
+    }
+
+    async fn run(self: Box<Self>, stop_receiver: StopReceiver) -> anyhow::Result<()> {
+        self.task.run(stop_receiver.0).await
+    }
+}
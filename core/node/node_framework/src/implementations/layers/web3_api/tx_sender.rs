@@ -2,13 +2,17 @@ use std::{fmt, sync::Arc};
 
 use zksync_node_api_server::{
     execution_sandbox::{VmConcurrencyBarrier, VmConcurrencyLimiter},
-    tx_sender::{ApiContracts, TxSenderBuilder, TxSenderConfig},
+    tx_sender::{
+        whitelisted_tokens_for_aa::WhitelistedTokensForAaUpdater, ApiContracts, TxSenderBuilder,
+        TxSenderConfig,
+    },
 };
 use zksync_state::PostgresStorageCaches;
 
 use crate::{
     implementations::resources::{
         fee_input::FeeInputResource,
+        main_node_client::MainNodeClientResource,
         pools::{PoolResource, ReplicaPool},
         state_keeper::ConditionalSealerResource,
         web3_api::{TxSenderResource, TxSinkResource},
@@ -31,6 +35,7 @@ pub struct TxSenderLayer {
     postgres_storage_caches_config: PostgresStorageCachesConfig,
     max_vm_concurrency: usize,
     api_contracts: ApiContracts,
+    whitelisted_tokens_for_aa_updates: bool,
 }
 
 impl TxSenderLayer {
@@ -45,8 +50,16 @@ impl TxSenderLayer {
             postgres_storage_caches_config,
             max_vm_concurrency,
             api_contracts,
+            whitelisted_tokens_for_aa_updates: false,
         }
     }
+
+    /// Enables periodic updates of tokens whitelisted for AA from the main node. Requires `MainNodeClientResource`;
+    /// should only be used on the external node.
+    pub fn with_whitelisted_tokens_for_aa_updates(mut self) -> Self {
+        self.whitelisted_tokens_for_aa_updates = true;
+        self
+    }
 }
 
 #[async_trait::async_trait]
@@ -59,7 +72,7 @@ impl WiringLayer for TxSenderLayer {
         // Get required resources.
         let tx_sink = context.get_resource::<TxSinkResource>().await?.0;
         let pool_resource = context.get_resource::<PoolResource<ReplicaPool>>().await?;
-        let replica_pool = pool_resource.get().await?;
+        let replica_pool = pool_resource.get_api().await?;
         let sealer = match context.get_resource::<ConditionalSealerResource>().await {
             Ok(sealer) => Some(sealer.0),
             Err(WiringError::ResourceLacking { .. }) => None,
@@ -96,6 +109,12 @@ impl WiringLayer for TxSenderLayer {
         if let Some(sealer) = sealer {
             tx_sender = tx_sender.with_sealer(sealer);
         }
+        if self.whitelisted_tokens_for_aa_updates {
+            let MainNodeClientResource(main_node_client) = context.get_resource().await?;
+            let updater = WhitelistedTokensForAaUpdater::new(main_node_client);
+            tx_sender = tx_sender.with_whitelisted_tokens_for_aa(updater.cache());
+            context.add_task(Box::new(WhitelistedTokensForAaUpdateTask(updater)));
+        }
         let tx_sender = tx_sender.build(
             fee_input,
             Arc::new(vm_concurrency_limiter),
@@ -130,6 +149,20 @@ impl Task for PostgresStorageCachesTask {
     }
 }
 
+#[derive(Debug)]
+struct WhitelistedTokensForAaUpdateTask(WhitelistedTokensForAaUpdater);
+
+#[async_trait::async_trait]
+impl Task for WhitelistedTokensForAaUpdateTask {
+    fn id(&self) -> TaskId {
+        "whitelisted_tokens_for_aa_updater".into()
+    }
+
+    async fn run(self: Box<Self>, stop_receiver: StopReceiver) -> anyhow::Result<()> {
+        self.0.run(stop_receiver.0).await
+    }
+}
+
 struct VmConcurrencyBarrierTask {
     barrier: VmConcurrencyBarrier,
 }
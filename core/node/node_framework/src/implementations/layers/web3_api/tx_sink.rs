@@ -1,10 +1,9 @@
 use std::sync::Arc;
 
-use zksync_node_api_server::tx_sender::{master_pool_sink::MasterPoolSink, proxy::TxProxy};
+use zksync_node_api_server::tx_sender::master_pool_sink::MasterPoolSink;
 
 use crate::{
     implementations::resources::{
-        main_node_client::MainNodeClientResource,
         pools::{MasterPool, PoolResource},
         web3_api::TxSinkResource,
     },
@@ -12,11 +11,12 @@ use crate::{
     wiring_layer::{WiringError, WiringLayer},
 };
 
+/// Wiring layer for the main node transaction sink. The external node should use
+/// [`TxProxyLayer`](super::tx_proxy::TxProxyLayer) instead.
 #[derive(Debug)]
 #[non_exhaustive]
 pub enum TxSinkLayer {
     MasterPoolSink,
-    ProxySink,
 }
 
 #[async_trait::async_trait]
@@ -35,10 +35,6 @@ impl WiringLayer for TxSinkLayer {
                     .await?;
                 TxSinkResource(Arc::new(MasterPoolSink::new(pool)))
             }
-            TxSinkLayer::ProxySink => {
-                let MainNodeClientResource(client) = context.get_resource().await?;
-                TxSinkResource(Arc::new(TxProxy::new(client)))
-            }
         };
         context.insert_resource(tx_sink)?;
         Ok(())
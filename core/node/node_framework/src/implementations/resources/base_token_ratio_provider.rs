@@ -0,0 +1,15 @@
+use std::sync::Arc;
+
+use zksync_node_fee_model::base_token::BaseTokenRatioProvider;
+
+use crate::resource::Resource;
+
+/// A resource that provides the ratio used to convert fees into the base token.
+#[derive(Debug, Clone)]
+pub struct BaseTokenRatioResource(pub Arc<dyn BaseTokenRatioProvider>);
+
+impl Resource for BaseTokenRatioResource {
+    fn name() -> String {
+        "common/base_token_ratio_provider".into()
+    }
+}
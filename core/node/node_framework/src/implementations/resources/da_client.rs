@@ -0,0 +1,23 @@
+use zksync_da_client::DataAvailabilityClient;
+
+use crate::resource::Resource;
+
+/// Represents a client of a certain DA solution.
+#[derive(Debug, Clone)]
+pub struct DAClientResource(pub Box<dyn DataAvailabilityClient>);
+
+impl Resource for DAClientResource {
+    fn name() -> String {
+        "common/da_client".into()
+    }
+}
+
+/// Represents a client of the shadow DA layer that pubdata is additionally dispatched to.
+#[derive(Debug, Clone)]
+pub struct ShadowDAClientResource(pub Box<dyn DataAvailabilityClient>);
+
+impl Resource for ShadowDAClientResource {
+    fn name() -> String {
+        "common/shadow_da_client".into()
+    }
+}
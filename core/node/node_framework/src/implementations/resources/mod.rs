@@ -1,5 +1,7 @@
 pub mod action_queue;
+pub mod base_token_ratio_provider;
 pub mod circuit_breakers;
+pub mod da_client;
 pub mod eth_interface;
 pub mod fee_input;
 pub mod healthcheck;
@@ -7,6 +9,7 @@ pub mod l1_tx_params;
 pub mod main_node_client;
 pub mod object_store;
 pub mod pools;
+pub mod reloadable_config;
 pub mod reverter;
 pub mod state_keeper;
 pub mod sync_state;
@@ -18,11 +18,12 @@ use crate::resource::Resource;
 #[derive(Debug, Clone)]
 pub struct PoolResource<P: PoolKind> {
     connections_count: Arc<AtomicU32>,
-    url: SensitiveUrl,
+    builder: ConnectionPoolBuilder<P::DbMarker>,
     max_connections: u32,
-    statement_timeout: Option<Duration>,
-    acquire_timeout: Option<Duration>,
+    api_replica_urls: Vec<SensitiveUrl>,
+    max_replica_lag: Option<Duration>,
     unbound_pool: Arc<Mutex<Option<ConnectionPool<P::DbMarker>>>>,
+    unbound_api_pool: Arc<Mutex<Option<ConnectionPool<P::DbMarker>>>>,
     _kind: std::marker::PhantomData<P>,
 }
 
@@ -39,22 +40,44 @@ impl<P: PoolKind> PoolResource<P> {
         statement_timeout: Option<Duration>,
         acquire_timeout: Option<Duration>,
     ) -> Self {
+        let mut builder = ConnectionPool::builder(url, max_connections);
+        builder.set_statement_timeout(statement_timeout);
+        builder.set_acquire_timeout(acquire_timeout);
+        Self::from_builder(builder)
+    }
+
+    /// Creates a resource with pools configured by the provided builder (e.g., to set connection thresholds
+    /// specific to this pool). Read replicas set in the builder are only used by the pool returned
+    /// from [`Self::get_api()`].
+    pub fn from_builder(mut builder: ConnectionPoolBuilder<P::DbMarker>) -> Self {
+        let api_replica_urls = builder.replica_urls().to_vec();
+        let max_replica_lag = Some(builder.max_replica_lag());
+        builder.set_replica_urls(vec![]);
         Self {
             connections_count: Arc::new(AtomicU32::new(0)),
-            url,
-            max_connections,
-            statement_timeout,
-            acquire_timeout,
+            max_connections: builder.max_size(),
+            builder,
+            api_replica_urls,
+            max_replica_lag,
             unbound_pool: Arc::new(Mutex::new(None)),
+            unbound_api_pool: Arc::new(Mutex::new(None)),
             _kind: std::marker::PhantomData,
         }
     }
 
+    /// Sets read replicas used by the pool returned from [`Self::get_api()`].
+    pub fn with_api_replicas(
+        mut self,
+        urls: Vec<SensitiveUrl>,
+        max_replica_lag: Option<Duration>,
+    ) -> Self {
+        self.api_replica_urls = urls;
+        self.max_replica_lag = max_replica_lag;
+        self
+    }
+
     fn builder(&self) -> ConnectionPoolBuilder<P::DbMarker> {
-        let mut builder = ConnectionPool::builder(self.url.clone(), self.max_connections);
-        builder.set_statement_timeout(self.statement_timeout);
-        builder.set_acquire_timeout(self.acquire_timeout);
-        builder
+        self.builder.clone()
     }
 
     pub async fn get(&self) -> anyhow::Result<ConnectionPool<P::DbMarker>> {
@@ -81,6 +104,44 @@ impl<P: PoolKind> PoolResource<P> {
         Ok(pool)
     }
 
+    /// Returns a pool for the API servers. If API read replicas are configured, the pool distributes connections
+    /// among them, so it must only be used for reads. Otherwise, returns the same pool as [`Self::get()`].
+    pub async fn get_api(&self) -> anyhow::Result<ConnectionPool<P::DbMarker>> {
+        if self.api_replica_urls.is_empty() {
+            return self.get().await;
+        }
+
+        let mut unbound_pool = self.unbound_api_pool.lock().await;
+        if let Some(pool) = unbound_pool.as_ref() {
+            tracing::info!(
+                "Provided a new copy of an existing {} API pool",
+                P::kind_str()
+            );
+            return Ok(pool.clone());
+        }
+        let pool = self
+            .builder()
+            .set_replica_urls(self.api_replica_urls.clone())
+            .set_max_replica_lag(self.max_replica_lag)
+            .build()
+            .await?;
+        *unbound_pool = Some(pool.clone());
+
+        // The pool allocates up to `max_connections` connections to the main database and to each of the replicas.
+        let new_connections = self.max_connections * (self.api_replica_urls.len() as u32 + 1);
+        let old_count = self
+            .connections_count
+            .fetch_add(new_connections, Ordering::Relaxed);
+        let total_connections = old_count + new_connections;
+        tracing::info!(
+            "Created a new {} API pool with {} read replicas. Total connections count: {total_connections}",
+            P::kind_str(),
+            self.api_replica_urls.len()
+        );
+
+        Ok(pool)
+    }
+
     pub async fn get_singleton(&self) -> anyhow::Result<ConnectionPool<P::DbMarker>> {
         self.get_custom(1).await
     }
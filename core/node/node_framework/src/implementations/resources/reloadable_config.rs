@@ -0,0 +1,13 @@
+use zksync_config::configs::ReloadableConfig;
+
+use crate::resource::{Resource, Updatable};
+
+/// Wrapper for the part of the node configuration that can be changed at runtime.
+#[derive(Debug, Clone)]
+pub struct ReloadableConfigResource(pub Updatable<ReloadableConfig>);
+
+impl Resource for ReloadableConfigResource {
+    fn name() -> String {
+        "common/reloadable_config".into()
+    }
+}
@@ -3,7 +3,7 @@ use std::sync::Arc;
 use zksync_metadata_calculator::api_server::TreeApiClient;
 use zksync_node_api_server::{
     tx_sender::{tx_sink::TxSink, TxSender},
-    web3::mempool_cache::MempoolCache,
+    web3::{mempool_cache::MempoolCache, namespaces::DebugNamespaceConfig},
 };
 
 use crate::resource::Resource;
@@ -43,3 +43,13 @@ impl Resource for MempoolCacheResource {
         "api/mempool_cache".into()
     }
 }
+
+/// Configuration of the `debug` namespace. If provided, the namespace is enabled on all Web3 API servers.
+#[derive(Debug, Clone)]
+pub struct DebugNamespaceResource(pub DebugNamespaceConfig);
+
+impl Resource for DebugNamespaceResource {
+    fn name() -> String {
+        "api/debug_namespace".into()
+    }
+}
@@ -2,13 +2,14 @@ use std::{any::TypeId, fmt};
 
 pub use self::{
     lazy_resource::LazyResource, resource_collection::ResourceCollection, resource_id::ResourceId,
-    unique::Unique,
+    unique::Unique, updatable::Updatable,
 };
 
 mod lazy_resource;
 mod resource_collection;
 mod resource_id;
 mod unique;
+mod updatable;
 
 /// A trait for anything that can be stored (and retrieved) as a resource.
 /// Typically, the type that implements this trait also should implement `Clone`
@@ -0,0 +1,87 @@
+use std::sync::Arc;
+
+use tokio::sync::watch;
+
+/// Wrapper for resources that can be updated while the node is running, e.g. parts of the config
+/// that support hot reload.
+///
+/// All clones of the wrapper share the same value. Consumers can either read the current value when they need it,
+/// or subscribe to updates.
+#[derive(Debug)]
+pub struct Updatable<T> {
+    sender: Arc<watch::Sender<T>>,
+}
+
+impl<T> Clone for Updatable<T> {
+    fn clone(&self) -> Self {
+        Self {
+            sender: self.sender.clone(),
+        }
+    }
+}
+
+impl<T: Clone + PartialEq> Updatable<T> {
+    /// Creates a new updatable resource with the specified initial value.
+    pub fn new(value: T) -> Self {
+        let (sender, _) = watch::channel(value);
+        Self {
+            sender: Arc::new(sender),
+        }
+    }
+
+    /// Returns the current value.
+    pub fn get(&self) -> T {
+        self.sender.borrow().clone()
+    }
+
+    /// Subscribes to the value updates. The returned receiver is marked as having seen the current value.
+    pub fn subscribe(&self) -> watch::Receiver<T> {
+        self.sender.subscribe()
+    }
+
+    /// Updates the value. Returns `false` if the new value is equal to the current one, in which case
+    /// subscribers are not notified.
+    pub fn update(&self, value: T) -> bool {
+        let updated = self.sender.send_if_modified(|current| {
+            if *current == value {
+                return false;
+            }
+            *current = value;
+            true
+        });
+
+        if updated {
+            tracing::info!(
+                "Resource {} has been updated",
+                std::any::type_name::<Updatable<T>>()
+            );
+        }
+        updated
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn updating_resource() {
+        let resource = Updatable::new(1_u32);
+        let clone = resource.clone();
+        let mut receiver = resource.subscribe();
+        assert!(!receiver.has_changed().unwrap());
+
+        assert!(!clone.update(1));
+        assert!(!receiver.has_changed().unwrap());
+
+        assert!(clone.update(2));
+        assert_eq!(resource.get(), 2);
+        receiver.changed().await.unwrap();
+        assert_eq!(*receiver.borrow_and_update(), 2);
+
+        // Receivers are not closed if the resource is dropped by some of the holders.
+        drop(clone);
+        assert!(resource.update(3));
+        assert_eq!(*receiver.borrow(), 3);
+    }
+}
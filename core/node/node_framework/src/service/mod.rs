@@ -10,6 +10,7 @@ pub use self::{context::ServiceContext, error::ZkStackServiceError, stop_receive
 use crate::{
     resource::{ResourceId, StoredResource},
     service::runnables::TaskReprs,
+    task::TaskId,
     wiring_layer::{WiringError, WiringLayer},
 };
 
@@ -21,18 +22,31 @@ mod stop_receiver;
 mod tests;
 
 // A reasonable amount of time for any task to finish the shutdown process
-const TASK_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(30);
+const DEFAULT_TASK_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(30);
 
 /// A builder for [`ZkStackService`].
 #[derive(Default, Debug)]
 pub struct ZkStackServiceBuilder {
     /// List of wiring layers.
     layers: Vec<Box<dyn WiringLayer>>,
+    /// Time given to the remaining tasks to finish once the service starts shutting down.
+    task_shutdown_timeout: Option<Duration>,
 }
 
 impl ZkStackServiceBuilder {
     pub fn new() -> Self {
-        Self { layers: Vec::new() }
+        Self {
+            layers: Vec::new(),
+            task_shutdown_timeout: None,
+        }
+    }
+
+    /// Sets the time given to the remaining tasks to finish after the stop signal is sent. Tasks not finished
+    /// in this time are dropped. This should be increased if some tasks (e.g., API servers draining in-flight
+    /// requests) may legitimately take longer to shut down than the default 30 seconds.
+    pub fn with_task_shutdown_timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.task_shutdown_timeout = Some(timeout);
+        self
     }
 
     /// Adds a wiring layer.
@@ -71,6 +85,9 @@ impl ZkStackServiceBuilder {
             runnables: Default::default(),
             stop_sender,
             runtime,
+            task_shutdown_timeout: self
+                .task_shutdown_timeout
+                .unwrap_or(DEFAULT_TASK_SHUTDOWN_TIMEOUT),
         })
     }
 }
@@ -90,39 +107,28 @@ pub struct ZkStackService {
     stop_sender: watch::Sender<bool>,
     /// Tokio runtime used to spawn tasks.
     runtime: Runtime,
+    /// Time given to the remaining tasks to finish after the stop signal is sent.
+    task_shutdown_timeout: Duration,
 }
 
 impl ZkStackService {
-    /// Runs the system.
-    pub fn run(mut self) -> Result<(), ZkStackServiceError> {
-        // Initialize tasks.
-        let wiring_layers = std::mem::take(&mut self.layers);
-
-        let mut errors: Vec<(String, WiringError)> = Vec::new();
-
-        let runtime_handle = self.runtime.handle().clone();
-        for layer in wiring_layers {
-            let name = layer.layer_name().to_string();
-            // We must process wiring layers sequentially and in the same order as they were added.
-            let task_result =
-                runtime_handle.block_on(layer.wire(ServiceContext::new(&name, &mut self)));
-            if let Err(err) = task_result {
-                // We don't want to bail on the first error, since it'll provide worse DevEx:
-                // People likely want to fix as much problems as they can in one go, rather than have
-                // to fix them one by one.
-                errors.push((name, err));
-                continue;
-            };
-        }
-
-        // Report all the errors we've met during the init.
-        if !errors.is_empty() {
-            for (layer, error) in &errors {
-                tracing::error!("Wiring layer {layer} can't be initialized: {error}");
-            }
-            return Err(ZkStackServiceError::Wiring(errors));
+    /// Wires all layers without running any tasks and returns IDs of the tasks that would be started.
+    /// Can be used to check that the layers are compatible with each other (e.g., that all requested resources
+    /// are provided) before running the service.
+    ///
+    /// Layers are wired in the same way as in [`Self::run()`], so wiring must not have side effects
+    /// beyond creating resources and tasks.
+    pub fn validate(mut self) -> Result<Vec<TaskId>, ZkStackServiceError> {
+        self.wire()?;
+        if self.runnables.is_empty() {
+            return Err(ZkStackServiceError::NoTasks);
         }
+        Ok(self.runnables.task_ids())
+    }
 
+    /// Runs the system.
+    pub fn run(mut self) -> Result<(), ZkStackServiceError> {
+        self.wire()?;
         if self.runnables.is_empty() {
             return Err(ZkStackServiceError::NoTasks);
         }
@@ -177,9 +183,10 @@ impl ZkStackService {
             }
         };
 
+        let task_shutdown_timeout = self.task_shutdown_timeout;
         let remaining_tasks_with_timeout: Vec<_> = remaining
             .into_iter()
-            .map(|task| async { tokio::time::timeout(TASK_SHUTDOWN_TIMEOUT, task).await })
+            .map(|task| async move { tokio::time::timeout(task_shutdown_timeout, task).await })
             .collect();
 
         // Send stop signal to remaining tasks and wait for them to finish.
@@ -191,7 +198,7 @@ impl ZkStackService {
         let execution_timeouts_count = execution_results.iter().filter(|&r| r.is_err()).count();
         if execution_timeouts_count > 0 {
             tracing::warn!(
-                "{execution_timeouts_count} tasks didn't finish in {TASK_SHUTDOWN_TIMEOUT:?} and were dropped"
+                "{execution_timeouts_count} tasks didn't finish in {task_shutdown_timeout:?} and were dropped"
             );
         } else {
             tracing::info!("Remaining tasks finished without reaching timeouts");
@@ -201,6 +208,36 @@ impl ZkStackService {
         result?;
         Ok(())
     }
+
+    /// Initializes resources and tasks by wiring all layers.
+    fn wire(&mut self) -> Result<(), ZkStackServiceError> {
+        let wiring_layers = std::mem::take(&mut self.layers);
+
+        let mut errors: Vec<(String, WiringError)> = Vec::new();
+
+        let runtime_handle = self.runtime.handle().clone();
+        for layer in wiring_layers {
+            let name = layer.layer_name().to_string();
+            // We must process wiring layers sequentially and in the same order as they were added.
+            let task_result = runtime_handle.block_on(layer.wire(ServiceContext::new(&name, self)));
+            if let Err(err) = task_result {
+                // We don't want to bail on the first error, since it'll provide worse DevEx:
+                // People likely want to fix as much problems as they can in one go, rather than have
+                // to fix them one by one.
+                errors.push((name, err));
+                continue;
+            };
+        }
+
+        // Report all the errors we've met during the init.
+        if !errors.is_empty() {
+            for (layer, error) in &errors {
+                tracing::error!("Wiring layer {layer} can't be initialized: {error}");
+            }
+            return Err(ZkStackServiceError::Wiring(errors));
+        }
+        Ok(())
+    }
 }
 
 fn oneshot_runner_task(
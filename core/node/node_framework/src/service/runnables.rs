@@ -1,4 +1,4 @@
-use std::{fmt, sync::Arc};
+use std::{fmt, future::Future, sync::Arc};
 
 use anyhow::Context as _;
 use futures::future::BoxFuture;
@@ -7,7 +7,7 @@ use tokio::sync::Barrier;
 use super::StopReceiver;
 use crate::{
     precondition::Precondition,
-    task::{OneshotTask, Task, UnconstrainedOneshotTask, UnconstrainedTask},
+    task::{OneshotTask, Task, TaskId, UnconstrainedOneshotTask, UnconstrainedTask},
 };
 
 /// A collection of different flavors of tasks.
@@ -79,6 +79,24 @@ impl Runnables {
         self.tasks.is_empty() && self.unconstrained_tasks.is_empty()
     }
 
+    /// Returns IDs of all tasks (including preconditions) in the collection.
+    pub(super) fn task_ids(&self) -> Vec<TaskId> {
+        let preconditions = self.preconditions.iter().map(|task| task.id());
+        let tasks = self.tasks.iter().map(|task| task.id());
+        let oneshot_tasks = self.oneshot_tasks.iter().map(|task| task.id());
+        let unconstrained_tasks = self.unconstrained_tasks.iter().map(|task| task.id());
+        let unconstrained_oneshot_tasks = self
+            .unconstrained_oneshot_tasks
+            .iter()
+            .map(|task| task.id());
+        preconditions
+            .chain(tasks)
+            .chain(oneshot_tasks)
+            .chain(unconstrained_tasks)
+            .chain(unconstrained_oneshot_tasks)
+            .collect()
+    }
+
     /// Prepares a barrier that should be shared between tasks and preconditions.
     /// The barrier is configured to wait for all the participants to be ready.
     /// Barrier does not assume the existence of unconstrained tasks.
@@ -129,7 +147,7 @@ impl Runnables {
         for task in std::mem::take(&mut self.unconstrained_tasks) {
             let name = task.id();
             let stop_receiver = stop_receiver.clone();
-            let task_future = Box::pin(async move {
+            let task_future = with_sentry_reporting(name.to_string(), async move {
                 task.run_unconstrained(stop_receiver)
                     .await
                     .with_context(|| format!("Task {name} failed"))
@@ -148,7 +166,7 @@ impl Runnables {
             let name = task.id();
             let stop_receiver = stop_receiver.clone();
             let task_barrier = task_barrier.clone();
-            let task_future = Box::pin(async move {
+            let task_future = with_sentry_reporting(name.to_string(), async move {
                 task.run_with_barrier(stop_receiver, task_barrier)
                     .await
                     .with_context(|| format!("Task {name} failed"))
@@ -167,7 +185,7 @@ impl Runnables {
             let name = precondition.id();
             let stop_receiver = stop_receiver.clone();
             let task_barrier = task_barrier.clone();
-            let task_future = Box::pin(async move {
+            let task_future = with_sentry_reporting(name.to_string(), async move {
                 precondition
                     .check_with_barrier(stop_receiver, task_barrier)
                     .await
@@ -187,7 +205,7 @@ impl Runnables {
             let name = oneshot_task.id();
             let stop_receiver = stop_receiver.clone();
             let task_barrier = task_barrier.clone();
-            let task_future = Box::pin(async move {
+            let task_future = with_sentry_reporting(name.to_string(), async move {
                 oneshot_task
                     .run_oneshot_with_barrier(stop_receiver, task_barrier)
                     .await
@@ -205,7 +223,7 @@ impl Runnables {
         for unconstrained_oneshot_task in std::mem::take(&mut self.unconstrained_oneshot_tasks) {
             let name = unconstrained_oneshot_task.id();
             let stop_receiver = stop_receiver.clone();
-            let task_future = Box::pin(async move {
+            let task_future = with_sentry_reporting(name.to_string(), async move {
                 unconstrained_oneshot_task
                     .run_unconstrained_oneshot(stop_receiver)
                     .await
@@ -215,3 +233,17 @@ impl Runnables {
         }
     }
 }
+
+/// Binds the task to a Sentry scope tagged with the task name, and reports task errors to Sentry.
+fn with_sentry_reporting(
+    component: String,
+    task_future: impl Future<Output = anyhow::Result<()>> + Send + 'static,
+) -> BoxFuture<'static, anyhow::Result<()>> {
+    Box::pin(vlog::with_sentry_component(&component, async move {
+        let result = task_future.await;
+        if let Err(err) = &result {
+            vlog::capture_error(err.as_ref());
+        }
+        result
+    }))
+}
@@ -144,6 +144,29 @@ fn test_run_with_failed_tasks() {
     assert_matches!(result.unwrap_err(), ZkStackServiceError::Task(_));
 }
 
+// `ZkStack` Service's `validate()` method has to report tasks without running them.
+#[test]
+fn test_validate_does_not_run_tasks() {
+    let mut zk_stack_service = ZkStackServiceBuilder::new();
+    zk_stack_service.add_layer(TaskErrorLayer);
+    let task_ids = zk_stack_service.build().unwrap().validate().unwrap();
+    assert_eq!(task_ids, [TaskId::from("error_task")]);
+}
+
+// `ZkStack` Service's `validate()` method has to report errors of all layers on wiring step.
+#[test]
+fn test_validate_with_error_tasks() {
+    let mut zk_stack_service = ZkStackServiceBuilder::new();
+    zk_stack_service
+        .add_layer(WireErrorLayer)
+        .add_layer(TaskErrorLayer);
+    let result = zk_stack_service.build().unwrap().validate();
+    let errors =
+        assert_matches!(result.unwrap_err(), ZkStackServiceError::Wiring(errors) => errors);
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].0, "wire_error_layer");
+}
+
 #[derive(Debug)]
 struct TasksLayer {
     successful_task_was_run: Arc<Mutex<bool>>,
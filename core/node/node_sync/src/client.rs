@@ -34,6 +34,10 @@ pub trait MainNodeClient: 'static + Send + Sync + fmt::Debug {
         protocol_version: ProtocolVersionId,
     ) -> EnrichedClientResult<Option<api::ProtocolVersion>>;
 
+    async fn fetch_latest_protocol_version(
+        &self,
+    ) -> EnrichedClientResult<Option<api::ProtocolVersion>>;
+
     async fn fetch_l2_block_number(&self) -> EnrichedClientResult<L2BlockNumber>;
 
     async fn fetch_l2_block(
@@ -105,6 +109,14 @@ impl MainNodeClient for Box<DynClient<L2>> {
             .await
     }
 
+    async fn fetch_latest_protocol_version(
+        &self,
+    ) -> EnrichedClientResult<Option<api::ProtocolVersion>> {
+        self.get_protocol_version(None)
+            .rpc_context("fetch_latest_protocol_version")
+            .await
+    }
+
     async fn fetch_genesis_config(&self) -> EnrichedClientResult<GenesisConfig> {
         self.genesis_config().rpc_context("genesis_config").await
     }
@@ -16,14 +16,14 @@ use zksync_state_keeper::{
     updates::UpdatesManager,
 };
 use zksync_types::{
-    protocol_upgrade::ProtocolUpgradeTx,
-    protocol_version::{ProtocolSemanticVersion, VersionPatch},
-    L1BatchNumber, L2BlockNumber, L2ChainId, ProtocolVersionId, Transaction, H256,
+    protocol_upgrade::ProtocolUpgradeTx, L1BatchNumber, L2BlockNumber, L2ChainId,
+    ProtocolVersionId, Transaction, H256,
 };
 use zksync_utils::bytes_to_be_words;
 
 use super::{
     client::MainNodeClient,
+    protocol_versions::save_protocol_version,
     sync_action::{ActionQueue, SyncAction},
 };
 
@@ -136,6 +136,11 @@ impl StateKeeperIO for ExternalIO {
 
     async fn initialize(&mut self) -> anyhow::Result<(IoCursor, Option<PendingBatchData>)> {
         let mut storage = self.pool.connection_tagged("sync_layer").await?;
+        // The IO may be created before the node storage is initialized (e.g., recovered from a snapshot),
+        // so we reload the params provider to pick up the snapshot recovery status.
+        self.l1_batch_params_provider = L1BatchParamsProvider::new(&mut storage)
+            .await
+            .context("failed initializing L1 batch params provider")?;
         let cursor = IoCursor::new(&mut storage).await?;
         tracing::info!(
             "Initialized the ExternalIO: current L1 batch number {}, current L2 block number {}",
@@ -317,56 +322,31 @@ impl StateKeeperIO for ExternalIO {
         protocol_version: ProtocolVersionId,
         cursor: &IoCursor,
     ) -> anyhow::Result<BaseSystemContracts> {
-        let base_system_contracts = self
-            .pool
-            .connection_tagged("sync_layer")
-            .await?
-            .protocol_versions_dal()
-            .load_base_system_contracts_by_version_id(protocol_version as u16)
-            .await
-            .context("failed loading base system contracts")?;
-
-        if let Some(contracts) = base_system_contracts {
-            return Ok(contracts);
-        }
-        tracing::info!("Fetching protocol version {protocol_version:?} from the main node");
-
-        let protocol_version = self
-            .main_node_client
-            .fetch_protocol_version(protocol_version)
-            .await
-            .context("failed to fetch protocol version from the main node")?
-            .context("protocol version is missing on the main node")?;
-        let minor = protocol_version
-            .minor_version()
-            .context("Missing minor protocol version")?;
-        let bootloader_code_hash = protocol_version
-            .bootloader_code_hash()
-            .context("Missing bootloader code hash")?;
-        let default_account_code_hash = protocol_version
-            .default_account_code_hash()
-            .context("Missing default account code hash")?;
-        let l2_system_upgrade_tx_hash = protocol_version.l2_system_upgrade_tx_hash();
-        self.pool
-            .connection_tagged("sync_layer")
-            .await?
+        let mut storage = self.pool.connection_tagged("sync_layer").await?;
+        let stored_version = storage
             .protocol_versions_dal()
-            .save_protocol_version(
-                ProtocolSemanticVersion {
-                    minor: minor
-                        .try_into()
-                        .context("cannot convert protocol version")?,
-                    patch: VersionPatch(0),
-                },
-                protocol_version.timestamp,
-                Default::default(), // verification keys are unused for EN
-                BaseSystemContractsHashes {
-                    bootloader: bootloader_code_hash,
-                    default_aa: default_account_code_hash,
-                },
-                l2_system_upgrade_tx_hash,
-            )
+            .get_protocol_version_with_latest_patch(protocol_version)
             .await?;
+        // Base system contracts may be missing even if the version is persisted, e.g. if it was persisted
+        // by `ProtocolVersionsTracker` before the node has synced to it. In this case, contracts are fetched
+        // from the main node below.
+        let base_system_contracts_hashes = if let Some(version) = stored_version {
+            version.base_system_contracts_hashes
+        } else {
+            tracing::info!("Fetching protocol version {protocol_version:?} from the main node");
+            let protocol_version = self
+                .main_node_client
+                .fetch_protocol_version(protocol_version)
+                .await
+                .context("failed to fetch protocol version from the main node")?
+                .context("protocol version is missing on the main node")?;
+            save_protocol_version(&mut storage, &protocol_version).await?
+        };
+        drop(storage);
+        let BaseSystemContractsHashes {
+            bootloader: bootloader_code_hash,
+            default_aa: default_account_code_hash,
+        } = base_system_contracts_hashes;
 
         let bootloader = self
             .get_base_system_contract(bootloader_code_hash, cursor.next_l2_block)
@@ -4,6 +4,8 @@ pub mod external_io;
 pub mod fetcher;
 pub mod genesis;
 mod metrics;
+pub mod protocol_versions;
+pub mod storage_init;
 pub mod sync_action;
 mod sync_state;
 pub mod testonly;
@@ -15,6 +17,7 @@ pub mod validate_chain_ids_task;
 pub use self::{
     client::{MainNodeClient, MainNodeHealthCheck},
     external_io::ExternalIO,
+    protocol_versions::ProtocolVersionsTracker,
     sync_action::{ActionQueue, ActionQueueSender},
     sync_state::SyncState,
 };
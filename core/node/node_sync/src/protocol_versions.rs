@@ -0,0 +1,155 @@
+//! Tracking protocol versions published by the main node.
+
+use std::{collections::HashSet, time::Duration};
+
+use anyhow::Context as _;
+use tokio::sync::watch;
+use zksync_contracts::BaseSystemContractsHashes;
+use zksync_dal::{Connection, ConnectionPool, Core, CoreDal};
+use zksync_types::{
+    api,
+    protocol_version::{ProtocolSemanticVersion, VersionPatch},
+    ProtocolVersionId,
+};
+use zksync_web3_decl::error::EnrichedClientError;
+
+use crate::client::MainNodeClient;
+
+/// Persists a protocol version fetched from the main node. Returns hashes of base system contracts
+/// for the version. Does nothing if the version is already persisted.
+pub(crate) async fn save_protocol_version(
+    storage: &mut Connection<'_, Core>,
+    protocol_version: &api::ProtocolVersion,
+) -> anyhow::Result<BaseSystemContractsHashes> {
+    let minor = protocol_version
+        .minor_version()
+        .context("Missing minor protocol version")?;
+    let bootloader_code_hash = protocol_version
+        .bootloader_code_hash()
+        .context("Missing bootloader code hash")?;
+    let default_account_code_hash = protocol_version
+        .default_account_code_hash()
+        .context("Missing default account code hash")?;
+    let base_system_contracts_hashes = BaseSystemContractsHashes {
+        bootloader: bootloader_code_hash,
+        default_aa: default_account_code_hash,
+    };
+    let l2_system_upgrade_tx_hash = protocol_version.l2_system_upgrade_tx_hash();
+    storage
+        .protocol_versions_dal()
+        .save_protocol_version(
+            ProtocolSemanticVersion {
+                minor: minor
+                    .try_into()
+                    .context("cannot convert protocol version")?,
+                patch: VersionPatch(0),
+            },
+            protocol_version.timestamp,
+            Default::default(), // verification keys are unused for EN
+            base_system_contracts_hashes,
+            l2_system_upgrade_tx_hash,
+        )
+        .await?;
+    Ok(base_system_contracts_hashes)
+}
+
+/// Component persisting the full history of protocol versions published by the main node, including versions
+/// the node hasn't synced through (e.g., because it was recovered from a snapshot). Together with the version
+/// metadata, the hash of the L2 system upgrade transaction is persisted; the transaction itself is persisted
+/// once the node syncs the L2 block containing it.
+///
+/// Persisted versions are served by the `zks_getProtocolVersion` RPC method.
+#[derive(Debug)]
+pub struct ProtocolVersionsTracker {
+    client: Box<dyn MainNodeClient>,
+    pool: ConnectionPool<Core>,
+    poll_interval: Duration,
+}
+
+impl ProtocolVersionsTracker {
+    const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(60);
+
+    pub fn new(client: Box<dyn MainNodeClient>, pool: ConnectionPool<Core>) -> Self {
+        Self {
+            client,
+            pool,
+            poll_interval: Self::DEFAULT_POLL_INTERVAL,
+        }
+    }
+
+    /// Fetches and persists all protocol versions known to the main node, but not to this node.
+    /// Returns the number of persisted versions.
+    pub(crate) async fn sync_versions(&self) -> anyhow::Result<usize> {
+        let latest_version = self
+            .client
+            .fetch_latest_protocol_version()
+            .await?
+            .context("main node returned no latest protocol version")?;
+        let latest_minor = latest_version
+            .minor_version()
+            .context("Missing minor protocol version")?;
+
+        let mut storage = self.pool.connection_tagged("sync_layer").await?;
+        let known_versions: HashSet<_> = storage
+            .protocol_versions_dal()
+            .all_versions()
+            .await
+            .into_iter()
+            .map(|version| version.minor as u16)
+            .collect();
+        drop(storage);
+
+        let mut persisted_count = 0;
+        for minor in 0..=latest_minor {
+            if known_versions.contains(&minor) {
+                continue;
+            }
+            let Ok(version_id) = ProtocolVersionId::try_from(minor) else {
+                continue; // The version is not supported by this node; the node will crash on reaching it anyway
+            };
+            let protocol_version = if minor == latest_minor {
+                Some(latest_version.clone())
+            } else {
+                self.client.fetch_protocol_version(version_id).await?
+            };
+            let Some(protocol_version) = protocol_version else {
+                continue; // The version was never used on the chain
+            };
+
+            let mut storage = self.pool.connection_tagged("sync_layer").await?;
+            save_protocol_version(&mut storage, &protocol_version).await?;
+            tracing::info!("Persisted protocol version {version_id:?} fetched from the main node");
+            persisted_count += 1;
+        }
+        Ok(persisted_count)
+    }
+
+    pub async fn run(self, mut stop_receiver: watch::Receiver<bool>) -> anyhow::Result<()> {
+        while !*stop_receiver.borrow_and_update() {
+            match self.sync_versions().await {
+                Ok(0) => { /* no new versions */ }
+                Ok(count) => tracing::info!("Persisted {count} new protocol versions"),
+                Err(err) if Self::is_transient(&err) => {
+                    tracing::warn!(
+                        "Transient error syncing protocol versions, will retry after a delay: {err:#}"
+                    );
+                }
+                Err(err) => return Err(err.context("failed syncing protocol versions")),
+            }
+
+            if tokio::time::timeout(self.poll_interval, stop_receiver.changed())
+                .await
+                .is_ok()
+            {
+                break;
+            }
+        }
+        tracing::info!("Stop signal received, protocol versions tracker is shutting down");
+        Ok(())
+    }
+
+    fn is_transient(err: &anyhow::Error) -> bool {
+        err.downcast_ref::<EnrichedClientError>()
+            .map_or(false, EnrichedClientError::is_transient)
+    }
+}
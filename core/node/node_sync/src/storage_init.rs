@@ -0,0 +1,175 @@
+//! Initialization of the node storage, either from genesis or from a snapshot.
+
+use std::{sync::Arc, time::Instant};
+
+use anyhow::Context as _;
+use tokio::sync::watch;
+use zksync_dal::{ConnectionPool, Core, CoreDal};
+use zksync_health_check::AppHealthCheck;
+use zksync_object_store::ObjectStore;
+use zksync_shared_metrics::{SnapshotRecoveryStage, APP_METRICS};
+use zksync_snapshots_applier::{SnapshotsApplierConfig, SnapshotsApplierTask};
+use zksync_types::{L1BatchNumber, L2ChainId};
+use zksync_web3_decl::client::{DynClient, L2};
+
+use crate::genesis::perform_genesis_if_needed;
+
+/// Configuration of snapshot recovery.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SnapshotRecoveryConfig {
+    /// L1 batch of the snapshot to recover from. If not specified, the latest snapshot will be used.
+    pub snapshot_l1_batch_override: Option<L1BatchNumber>,
+}
+
+#[derive(Debug)]
+enum InitDecision {
+    /// Perform or check genesis.
+    Genesis,
+    /// Perform or check snapshot recovery.
+    SnapshotRecovery,
+}
+
+/// Initializes the node storage if it's empty. The storage is initialized either from genesis (fetched from
+/// the main node), or from a snapshot in the object store if snapshot recovery is enabled. If the storage
+/// is already initialized, only checks that it is consistent with the chosen initialization strategy.
+#[derive(Debug)]
+pub struct NodeStorageInitializer {
+    pool: ConnectionPool<Core>,
+    main_node_client: Box<DynClient<L2>>,
+    app_health: Arc<AppHealthCheck>,
+    l2_chain_id: L2ChainId,
+    recovery: Option<(SnapshotRecoveryConfig, Arc<dyn ObjectStore>)>,
+}
+
+impl NodeStorageInitializer {
+    /// Creates an initializer that initializes the node from genesis only.
+    pub fn new(
+        pool: ConnectionPool<Core>,
+        main_node_client: Box<DynClient<L2>>,
+        app_health: Arc<AppHealthCheck>,
+        l2_chain_id: L2ChainId,
+    ) -> Self {
+        Self {
+            pool,
+            main_node_client,
+            app_health,
+            l2_chain_id,
+            recovery: None,
+        }
+    }
+
+    /// Enables recovery from a snapshot in the provided object store if the node storage is empty.
+    pub fn with_snapshot_recovery(
+        mut self,
+        config: SnapshotRecoveryConfig,
+        object_store: Arc<dyn ObjectStore>,
+    ) -> Self {
+        self.recovery = Some((config, object_store));
+        self
+    }
+
+    async fn decide(&self) -> anyhow::Result<InitDecision> {
+        let mut storage = self
+            .pool
+            .connection_tagged("storage_initialization")
+            .await?;
+        let genesis_l1_batch = storage
+            .blocks_dal()
+            .get_l1_batch_header(L1BatchNumber(0))
+            .await?;
+        let snapshot_recovery = storage
+            .snapshot_recovery_dal()
+            .get_applied_snapshot_status()
+            .await?;
+
+        Ok(match (genesis_l1_batch, snapshot_recovery) {
+            (Some(batch), Some(snapshot_recovery)) => {
+                anyhow::bail!(
+                    "Node has both genesis L1 batch: {batch:?} and snapshot recovery information: {snapshot_recovery:?}. \
+                     This is not supported and can be caused by broken snapshot recovery."
+                );
+            }
+            (Some(batch), None) => {
+                tracing::info!(
+                    "Node has a genesis L1 batch: {batch:?} and no snapshot recovery info"
+                );
+                InitDecision::Genesis
+            }
+            (None, Some(snapshot_recovery)) => {
+                tracing::info!("Node has no genesis L1 batch and snapshot recovery information: {snapshot_recovery:?}");
+                InitDecision::SnapshotRecovery
+            }
+            (None, None) => {
+                tracing::info!("Node has neither genesis L1 batch, nor snapshot recovery info");
+                if self.recovery.is_some() {
+                    InitDecision::SnapshotRecovery
+                } else {
+                    InitDecision::Genesis
+                }
+            }
+        })
+    }
+
+    /// Initializes the storage. If a stop signal is received during snapshot recovery, returns early;
+    /// recovery will be resumed on the next run.
+    pub async fn run(self, mut stop_receiver: watch::Receiver<bool>) -> anyhow::Result<()> {
+        let decision = self.decide().await?;
+        tracing::info!("Chosen node initialization strategy: {decision:?}");
+
+        match decision {
+            InitDecision::Genesis => {
+                let mut storage = self
+                    .pool
+                    .connection_tagged("storage_initialization")
+                    .await?;
+                perform_genesis_if_needed(
+                    &mut storage,
+                    self.l2_chain_id,
+                    &self.main_node_client.for_component("genesis"),
+                )
+                .await
+                .context("performing genesis failed")?;
+            }
+            InitDecision::SnapshotRecovery => {
+                let (config, object_store) = self.recovery.context(
+                    "Snapshot recovery is required to proceed, but it is not enabled. Enable snapshot recovery \
+                     (e.g., by setting `EN_SNAPSHOTS_RECOVERY_ENABLED=true` env variable to the node binary), \
+                     or use a Postgres dump for recovery",
+                )?;
+
+                tracing::warn!("Proceeding with snapshot recovery. This is an experimental feature; use at your own risk");
+                let mut snapshots_applier_task = SnapshotsApplierTask::new(
+                    SnapshotsApplierConfig::default(),
+                    self.pool,
+                    Box::new(self.main_node_client.for_component("snapshot_recovery")),
+                    object_store,
+                );
+                if let Some(snapshot_l1_batch) = config.snapshot_l1_batch_override {
+                    tracing::info!(
+                        "Using a specific snapshot with L1 batch #{snapshot_l1_batch}; this may not work \
+                         if the snapshot is too old (order of several weeks old) or non-existent"
+                    );
+                    snapshots_applier_task.set_snapshot_l1_batch(snapshot_l1_batch);
+                }
+                self.app_health
+                    .insert_component(snapshots_applier_task.health_check())?;
+
+                let recovery_started_at = Instant::now();
+                let stats = tokio::select! {
+                    result = snapshots_applier_task.run() => result.context("snapshot recovery failed")?,
+                    _ = stop_receiver.changed() => {
+                        tracing::info!("Stop signal received during snapshot recovery; recovery will be resumed on restart");
+                        return Ok(());
+                    }
+                };
+                if stats.done_work {
+                    let latency = recovery_started_at.elapsed();
+                    APP_METRICS.snapshot_recovery_latency[&SnapshotRecoveryStage::Postgres]
+                        .set(latency);
+                    tracing::info!("Recovered Postgres from snapshot in {latency:?}");
+                }
+            }
+        }
+        Ok(())
+    }
+}
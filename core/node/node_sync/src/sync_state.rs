@@ -1,4 +1,7 @@
-use std::{sync::Arc, time::Duration};
+use std::{
+    sync::Arc,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
 
 use async_trait::async_trait;
 use serde::Serialize;
@@ -118,6 +121,12 @@ impl StateKeeperOutputHandler for SyncState {
     async fn handle_l2_block(&mut self, updates_manager: &UpdatesManager) -> anyhow::Result<()> {
         let sealed_block_number = updates_manager.l2_block.number;
         self.set_local_block(sealed_block_number);
+
+        let block_timestamp = Duration::from_secs(updates_manager.l2_block.timestamp);
+        if let Ok(now) = SystemTime::now().duration_since(UNIX_EPOCH) {
+            let lag = now.saturating_sub(block_timestamp);
+            EN_METRICS.l2_block_application_lag.observe(lag);
+        }
         Ok(())
     }
 
@@ -208,14 +217,17 @@ impl From<&SyncStateInner> for Health {
         }
 
         let (is_synced, block_diff) = state.is_synced();
-        let status = if is_synced {
-            HealthStatus::Ready
-        } else if block_diff.is_some() {
-            HealthStatus::Affected
+        let health = if is_synced {
+            Health::from(HealthStatus::Ready)
+        } else if let Some(block_diff) = block_diff {
+            Health::from(HealthStatus::Affected)
+                .with_cause("not_synced")
+                .with_counter("lagging_blocks", block_diff.into())
         } else {
-            return HealthStatus::NotReady.into(); // `state` isn't initialized yet
+            // `state` isn't initialized yet
+            return Health::from(HealthStatus::NotReady).with_cause("not_initialized");
         };
-        Health::from(status).with_details(SyncStateHealthDetails {
+        health.with_details(SyncStateHealthDetails {
             is_synced,
             main_node_block: state.main_node_block,
             local_block: state.local_block,
@@ -43,6 +43,13 @@ impl MainNodeClient for MockMainNodeClient {
         Ok(self.protocol_versions.get(&protocol_version).cloned())
     }
 
+    async fn fetch_latest_protocol_version(
+        &self,
+    ) -> EnrichedClientResult<Option<api::ProtocolVersion>> {
+        let latest_version = self.protocol_versions.keys().max();
+        Ok(latest_version.and_then(|version| self.protocol_versions.get(version).cloned()))
+    }
+
     async fn fetch_l2_block_number(&self) -> EnrichedClientResult<L2BlockNumber> {
         if let Some(number) = self.l2_blocks.len().checked_sub(1) {
             Ok(L2BlockNumber(number as u32))
@@ -2,7 +2,7 @@
 
 use std::{iter, sync::Arc, time::Duration};
 
-use test_casing::test_casing;
+use test_casing::{test_casing, Product};
 use tokio::{sync::watch, task::JoinHandle};
 use zksync_contracts::BaseSystemContractsHashes;
 use zksync_dal::{Connection, ConnectionPool, Core, CoreDal};
@@ -271,9 +271,12 @@ async fn external_io_basics(snapshot_recovery: bool) {
     assert_eq!(tx_receipt.transaction_index, 0.into());
 }
 
-#[test_casing(2, [false, true])]
+#[test_casing(4, Product(([false, true], [false, true])))]
 #[tokio::test]
-async fn external_io_works_without_local_protocol_version(snapshot_recovery: bool) {
+async fn external_io_works_without_local_protocol_version(
+    snapshot_recovery: bool,
+    persisted_by_tracker: bool,
+) {
     let pool = ConnectionPool::<Core>::test_pool().await;
     let mut storage = pool.connection().await.unwrap();
     let snapshot = if snapshot_recovery {
@@ -308,6 +311,13 @@ async fn external_io_works_without_local_protocol_version(snapshot_recovery: boo
         ..api::ProtocolVersion::default()
     };
     client.insert_protocol_version(next_protocol_version.clone());
+    if persisted_by_tracker {
+        // The tracker persists the protocol version, but not base system contracts for it.
+        let mut tracker_client = MockMainNodeClient::default();
+        tracker_client.insert_protocol_version(next_protocol_version.clone());
+        let tracker = ProtocolVersionsTracker::new(Box::new(tracker_client), pool.clone());
+        tracker.sync_versions().await.unwrap();
+    }
 
     let state_keeper = StateKeeperHandles::new(
         pool.clone(),
@@ -630,3 +640,51 @@ async fn external_io_with_multiple_l1_batches() {
     assert_eq!(fictive_l2_block.timestamp, 2);
     assert_eq!(fictive_l2_block.l2_tx_count, 0);
 }
+
+#[tokio::test]
+async fn protocol_versions_tracker_persists_missing_versions() {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let mut storage = pool.connection().await.unwrap();
+    ensure_genesis(&mut storage).await;
+
+    let mut client = MockMainNodeClient::default();
+    let next_protocol_version = api::ProtocolVersion {
+        minor_version: Some(ProtocolVersionId::next() as u16),
+        timestamp: 1_000,
+        bootloader_code_hash: Some(H256::repeat_byte(1)),
+        default_account_code_hash: Some(H256::repeat_byte(2)),
+        l2_system_upgrade_tx_hash_new: Some(H256::repeat_byte(3)),
+        ..api::ProtocolVersion::default()
+    };
+    client.insert_protocol_version(next_protocol_version.clone());
+    let tracker = ProtocolVersionsTracker::new(Box::new(client), pool.clone());
+
+    let persisted_count = tracker.sync_versions().await.unwrap();
+    assert_eq!(persisted_count, 1);
+    let persisted_protocol_version = storage
+        .protocol_versions_dal()
+        .get_protocol_version_with_latest_patch(ProtocolVersionId::next())
+        .await
+        .unwrap()
+        .expect("next protocol version not persisted");
+    assert_eq!(persisted_protocol_version.timestamp, 1_000);
+    assert_eq!(
+        persisted_protocol_version.base_system_contracts_hashes,
+        BaseSystemContractsHashes {
+            bootloader: H256::repeat_byte(1),
+            default_aa: H256::repeat_byte(2),
+        }
+    );
+    let persisted_tx_hash = storage
+        .protocol_versions_web3_dal()
+        .get_protocol_version_by_id(ProtocolVersionId::next() as u16)
+        .await
+        .unwrap()
+        .expect("next protocol version not persisted")
+        .l2_system_upgrade_tx_hash();
+    assert_eq!(persisted_tx_hash, Some(H256::repeat_byte(3)));
+
+    // Repeated syncing should be a no-op.
+    let persisted_count = tracker.sync_versions().await.unwrap();
+    assert_eq!(persisted_count, 0);
+}
@@ -124,8 +124,11 @@ impl TreeDataFetcher {
     }
 
     /// Attempts to fetch root hashes from L1 (namely, `BlockCommit` events emitted by the diamond proxy) if possible.
-    /// The main node will still be used as a fallback in case communicating with L1 fails, or for newer batches,
-    /// which may not be committed on L1.
+    /// The main node will still be used as a fallback in case communicating with L1 fails, and as the primary source for newer batches,
+    /// which may not be committed on L1. Conversely, L1 is queried for such batches if the main node is unreachable
+    /// or returns data inconsistent with the local state.
+    ///
+    /// The source of the root hash is persisted for each processed L1 batch.
     pub fn with_l1_data(
         mut self,
         eth_client: Box<DynClient<L1>>,
@@ -211,7 +214,7 @@ impl TreeDataFetcher {
             .batch_details(l1_batch_to_fetch, &last_l2_block_header)
             .await?;
         stage_latency.observe();
-        let root_hash = match root_hash_result {
+        let (root_hash, source) = match root_hash_result {
             Ok(output) => {
                 tracing::debug!(
                     "Received root hash for L1 batch #{l1_batch_to_fetch} from {source:?}: {root_hash:?}",
@@ -219,7 +222,7 @@ impl TreeDataFetcher {
                     root_hash = output.root_hash
                 );
                 self.metrics.root_hash_sources[&output.source].inc();
-                output.root_hash
+                (output.root_hash, output.source)
             }
             Err(MissingData::Batch) => {
                 let err = anyhow::anyhow!(
@@ -244,7 +247,8 @@ impl TreeDataFetcher {
 
         let stage_latency = self.metrics.stage_latency[&ProcessingStage::Persistence].start();
         let mut storage = self.pool.connection_tagged("tree_data_fetcher").await?;
-        let rollup_last_leaf_index = storage
+        let mut transaction = storage.start_transaction().await?;
+        let rollup_last_leaf_index = transaction
             .storage_logs_dedup_dal()
             .max_enumeration_index_by_l1_batch(l1_batch_to_fetch)
             .await?
@@ -254,12 +258,19 @@ impl TreeDataFetcher {
             hash: root_hash,
             rollup_last_leaf_index,
         };
-        storage
+        transaction
             .blocks_dal()
             .save_l1_batch_tree_data(l1_batch_to_fetch, &tree_data)
             .await?;
+        transaction
+            .blocks_dal()
+            .set_l1_batch_tree_data_source(l1_batch_to_fetch, source.as_str())
+            .await?;
+        transaction.commit().await?;
         stage_latency.observe();
-        tracing::debug!("Updated L1 batch #{l1_batch_to_fetch} with tree data: {tree_data:?}");
+        tracing::debug!(
+            "Updated L1 batch #{l1_batch_to_fetch} with tree data from {source:?}: {tree_data:?}"
+        );
         Ok(StepOutcome::UpdatedBatch(l1_batch_to_fetch))
     }
 
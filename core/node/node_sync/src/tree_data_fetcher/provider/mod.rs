@@ -12,7 +12,7 @@ use zksync_web3_decl::{
     namespaces::ZksNamespaceClient,
 };
 
-use super::{metrics::METRICS, TreeDataFetcherResult};
+use super::{metrics::METRICS, TreeDataFetcherError, TreeDataFetcherResult};
 
 #[cfg(test)]
 mod tests;
@@ -36,6 +36,16 @@ pub(super) enum TreeDataProviderSource {
     BatchDetailsRpc,
 }
 
+impl TreeDataProviderSource {
+    /// Returns the string representation of the source persisted in Postgres.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::L1CommitEvent => "l1_commit_event",
+            Self::BatchDetailsRpc => "batch_details_rpc",
+        }
+    }
+}
+
 #[derive(Debug)]
 pub(super) struct TreeDataProviderOutput {
     pub root_hash: H256,
@@ -209,6 +219,7 @@ impl L1DataProvider {
     pub fn with_fallback(self, fallback: Box<dyn TreeDataProvider>) -> CombinedDataProvider {
         CombinedDataProvider {
             l1: Some(self),
+            l1_is_primary: true,
             fallback,
         }
     }
@@ -321,13 +332,47 @@ impl TreeDataProvider for L1DataProvider {
     }
 }
 
-/// Data provider combining [`L1DataProvider`] with a fallback provider.
+/// Data provider combining [`L1DataProvider`] with a fallback provider (usually, the main node).
+///
+/// Initially, L1 is used as the primary data source; this is efficient when the node is catching up (e.g., after snapshot recovery).
+/// Once L1 lacks data for a requested batch, the fallback provider becomes the primary one, and L1 is only queried
+/// if the fallback provider is unreachable or returns data inconsistent with the local state. The L1 provider is disabled
+/// completely if it returns a non-transient error.
 #[derive(Debug)]
 pub(super) struct CombinedDataProvider {
     l1: Option<L1DataProvider>,
+    l1_is_primary: bool,
     fallback: Box<dyn TreeDataProvider>,
 }
 
+impl CombinedDataProvider {
+    fn handle_l1_error(&mut self, number: L1BatchNumber, err: &TreeDataFetcherError) {
+        if err.is_transient() {
+            tracing::info!(
+                number = number.0,
+                "Transient error calling L1 data provider: {err}"
+            );
+        } else {
+            tracing::warn!(
+                number = number.0,
+                "Fatal error calling L1 data provider: {err}"
+            );
+            self.l1 = None;
+        }
+    }
+
+    /// Returns the reason to query L1 if the fallback provider output cannot be used.
+    fn l1_fallback_reason(fallback_result: &TreeDataProviderResult) -> Option<&'static str> {
+        match fallback_result {
+            Err(TreeDataFetcherError::Rpc(_)) => Some("fallback provider is unreachable"),
+            Ok(Err(MissingData::PossibleReorg)) => {
+                Some("fallback provider returned data inconsistent with the local state")
+            }
+            _ => None,
+        }
+    }
+}
+
 #[async_trait]
 impl TreeDataProvider for CombinedDataProvider {
     async fn batch_details(
@@ -335,34 +380,49 @@ impl TreeDataProvider for CombinedDataProvider {
         number: L1BatchNumber,
         last_l2_block: &L2BlockHeader,
     ) -> TreeDataProviderResult {
-        if let Some(l1) = &mut self.l1 {
-            match l1.batch_details(number, last_l2_block).await {
-                Err(err) => {
-                    if err.is_transient() {
-                        tracing::info!(
-                            number = number.0,
-                            "Transient error calling L1 data provider: {err}"
-                        );
-                    } else {
-                        tracing::warn!(
+        if self.l1_is_primary {
+            if let Some(l1) = &mut self.l1 {
+                match l1.batch_details(number, last_l2_block).await {
+                    Err(err) => self.handle_l1_error(number, &err),
+                    Ok(Ok(output)) => return Ok(Ok(output)),
+                    Ok(Err(missing_data)) => {
+                        tracing::debug!(
                             number = number.0,
-                            "Fatal error calling L1 data provider: {err}"
+                            "L1 data provider misses batch data: {missing_data}"
                         );
-                        self.l1 = None;
+                        // The L2 provider will very likely get information about batches significantly faster,
+                        // so it becomes the primary provider; L1 is only used as a fallback from now on.
+                        self.l1_is_primary = false;
                     }
                 }
-                Ok(Ok(root_hash)) => return Ok(Ok(root_hash)),
-                Ok(Err(missing_data)) => {
-                    tracing::debug!(
-                        number = number.0,
-                        "L1 data provider misses batch data: {missing_data}"
-                    );
-                    // No sense of calling the L1 provider in the future; the L2 provider will very likely get information
-                    // about batches significantly faster.
-                    self.l1 = None;
-                }
+            }
+            return self.fallback.batch_details(number, last_l2_block).await;
+        }
+
+        let fallback_result = self.fallback.batch_details(number, last_l2_block).await;
+        let Some(reason) = Self::l1_fallback_reason(&fallback_result) else {
+            return fallback_result;
+        };
+        let Some(l1) = &mut self.l1 else {
+            return fallback_result;
+        };
+        tracing::info!(
+            number = number.0,
+            "Querying L1 data provider for L1 batch #{number}: {reason}"
+        );
+        match l1.batch_details(number, last_l2_block).await {
+            Ok(Ok(output)) => Ok(Ok(output)),
+            Ok(Err(missing_data)) => {
+                tracing::debug!(
+                    number = number.0,
+                    "L1 data provider misses batch data: {missing_data}"
+                );
+                fallback_result
+            }
+            Err(err) => {
+                self.handle_l1_error(number, &err);
+                fallback_result
             }
         }
-        self.fallback.batch_details(number, last_l2_block).await
     }
 }
@@ -1,5 +1,7 @@
 //! Tests for tree data providers.
 
+use std::sync::atomic::Ordering;
+
 use assert_matches::assert_matches;
 use once_cell::sync::Lazy;
 use test_casing::test_casing;
@@ -379,5 +381,67 @@ async fn combined_data_provider_errors() {
         .expect("no root hash");
     assert_eq!(output.root_hash, H256::repeat_byte(2));
     assert_matches!(output.source, TreeDataProviderSource::BatchDetailsRpc);
-    assert!(provider.l1.is_none());
+    // L1 provider is retained as a fallback.
+    assert!(provider.l1.is_some());
+    assert!(!provider.l1_is_primary);
+}
+
+#[tokio::test]
+async fn combined_data_provider_falling_back_to_l1() {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let mut storage = pool.connection().await.unwrap();
+    insert_genesis_batch(&mut storage, &GenesisParams::mock())
+        .await
+        .unwrap();
+
+    let mut eth_params = EthereumParameters::new(1_000_000);
+    let mut main_node_client = MockMainNodeClient::default();
+    for number in 1..=3 {
+        let timestamp = 50_000 + u64::from(number) * 1_000;
+        seal_l1_batch_with_timestamp(&mut storage, L1BatchNumber(number), timestamp).await;
+        eth_params.push_commit(timestamp + 500);
+        main_node_client.insert_batch(L1BatchNumber(number), H256::repeat_byte(number as u8));
+    }
+    let transient_error = main_node_client.transient_error_flag();
+    let mut provider = L1DataProvider::new(Box::new(eth_params.client()), DIAMOND_PROXY_ADDRESS)
+        .unwrap()
+        .with_fallback(Box::new(main_node_client));
+    // Emulate the provider having switched to the main node as the primary data source.
+    provider.l1_is_primary = false;
+
+    let output = provider
+        .batch_details(
+            L1BatchNumber(1),
+            &get_last_l2_block(&mut storage, L1BatchNumber(1)).await,
+        )
+        .await
+        .unwrap()
+        .expect("no root hash");
+    assert_matches!(output.source, TreeDataProviderSource::BatchDetailsRpc);
+
+    // Emulate the main node being unreachable.
+    transient_error.store(true, Ordering::Relaxed);
+    let output = provider
+        .batch_details(
+            L1BatchNumber(2),
+            &get_last_l2_block(&mut storage, L1BatchNumber(2)).await,
+        )
+        .await
+        .unwrap()
+        .expect("no root hash");
+    assert_eq!(output.root_hash, H256::repeat_byte(2));
+    assert_matches!(output.source, TreeDataProviderSource::L1CommitEvent);
+    assert!(provider.l1.is_some());
+
+    // The main node is used again once it's reachable.
+    let output = provider
+        .batch_details(
+            L1BatchNumber(3),
+            &get_last_l2_block(&mut storage, L1BatchNumber(3)).await,
+        )
+        .await
+        .unwrap()
+        .expect("no root hash");
+    assert_eq!(output.root_hash, H256::repeat_byte(3));
+    assert_matches!(output.source, TreeDataProviderSource::BatchDetailsRpc);
 }
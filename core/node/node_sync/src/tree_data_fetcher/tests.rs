@@ -32,6 +32,11 @@ impl MockMainNodeClient {
     pub fn insert_batch(&mut self, number: L1BatchNumber, root_hash: H256) {
         self.batch_details_responses.insert(number, root_hash);
     }
+
+    /// Returns a flag that, if set, makes the next call to the client fail with a transient error.
+    pub fn transient_error_flag(&self) -> Arc<AtomicBool> {
+        self.transient_error.clone()
+    }
 }
 
 #[async_trait]
@@ -168,6 +173,14 @@ async fn tree_data_fetcher_steps() {
 
     // Check tree data in updated batches.
     assert_batch_tree_data(&mut storage, 1..=5, genesis.rollup_last_leaf_index).await;
+    for number in 1..=5 {
+        let source = storage
+            .blocks_dal()
+            .get_l1_batch_tree_data_source(L1BatchNumber(number))
+            .await
+            .unwrap();
+        assert_eq!(source.as_deref(), Some("batch_details_rpc"));
+    }
 }
 
 async fn assert_batch_tree_data(
@@ -1,4 +1,4 @@
-use std::{fmt, future::Future, time::Duration};
+use std::{fmt, future::Future, num::NonZeroU32, time::Duration};
 
 use anyhow::Context as _;
 use async_trait::async_trait;
@@ -217,7 +217,10 @@ impl HandleReorgDetectorEvent for HealthUpdater {
         let health_details = serde_json::json!({
             "diverged_l1_batch": diverged_l1_batch,
         });
-        self.update(Health::from(HealthStatus::Affected).with_details(health_details));
+        let health = Health::from(HealthStatus::Affected)
+            .with_cause("reorg_detected")
+            .with_details(health_details);
+        self.update(health);
     }
 
     fn start_shutting_down(&mut self) {
@@ -482,6 +485,39 @@ impl ReorgDetector {
     }
 }
 
+/// Checks that rolling back the node state to `last_correct_l1_batch` after a reorg reverts at most `max_depth`
+/// sealed L1 batches. A deeper rollback is unlikely to be caused by a legitimate reorg (e.g., the node may be connected
+/// to a wrong network), so it should not be performed automatically.
+pub async fn check_rollback_depth(
+    pool: &ConnectionPool<Core>,
+    last_correct_l1_batch: L1BatchNumber,
+    max_depth: NonZeroU32,
+) -> anyhow::Result<()> {
+    let mut storage = pool.connection_tagged("reorg_detector").await?;
+    let sealed_l1_batch = storage
+        .blocks_dal()
+        .get_sealed_l1_batch_number()
+        .await?
+        .context("no L1 batches in storage")?;
+    drop(storage);
+    ensure_rollback_depth(sealed_l1_batch, last_correct_l1_batch, max_depth)
+}
+
+fn ensure_rollback_depth(
+    sealed_l1_batch: L1BatchNumber,
+    last_correct_l1_batch: L1BatchNumber,
+    max_depth: NonZeroU32,
+) -> anyhow::Result<()> {
+    let depth = sealed_l1_batch.0.saturating_sub(last_correct_l1_batch.0);
+    anyhow::ensure!(
+        depth <= max_depth.get(),
+        "Reorg requires rolling back {depth} L1 batches (from #{sealed_l1_batch} to #{last_correct_l1_batch}), \
+         which exceeds the auto-rollback limit of {max_depth} L1 batches. Make sure that the node is connected \
+         to the right network, and roll back the node manually using the block reverter"
+    );
+    Ok(())
+}
+
 /// Fallible and async predicate for binary search.
 #[async_trait]
 trait BinarySearchPredicate: Send {
@@ -31,6 +31,17 @@ async fn test_binary_search() {
     }
 }
 
+#[test]
+fn checking_rollback_depth() {
+    let max_depth = NonZeroU32::new(3).unwrap();
+    ensure_rollback_depth(L1BatchNumber(10), L1BatchNumber(10), max_depth).unwrap();
+    ensure_rollback_depth(L1BatchNumber(10), L1BatchNumber(7), max_depth).unwrap();
+    let err = ensure_rollback_depth(L1BatchNumber(10), L1BatchNumber(6), max_depth)
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("exceeds the auto-rollback limit"), "{err}");
+}
+
 async fn store_l2_block(storage: &mut Connection<'_, Core>, number: u32, hash: H256) {
     let header = L2BlockHeader {
         hash,
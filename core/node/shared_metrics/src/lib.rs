@@ -174,8 +174,14 @@ pub enum InteractionType {
 pub enum CheckerComponent {
     ConsistencyChecker,
     ReorgDetector,
+    DaVerifier,
 }
 
+/// Buckets for the L2 block application lag (0.5s – 1 hour).
+const L2_BLOCK_APPLICATION_LAG_BUCKETS: Buckets = Buckets::values(&[
+    0.5, 1.0, 2.0, 5.0, 10.0, 20.0, 30.0, 60.0, 120.0, 300.0, 600.0, 1_800.0, 3_600.0,
+]);
+
 /// General-purpose external node metrics.
 #[derive(Debug, Metrics)]
 #[metrics(prefix = "external_node")]
@@ -188,10 +194,14 @@ pub struct ExternalNodeMetrics {
     pub synced: Gauge<u64>,
     /// Current sync lag of the external node.
     pub sync_lag: Gauge<u64>,
-    /// Number of the last L1 batch checked by the re-org detector or consistency checker.
+    /// Number of the last L1 batch checked by the re-org detector, consistency checker or DA inclusion verifier.
     pub last_correct_batch: Family<CheckerComponent, Gauge<u64>>,
     /// Number of the last L2 block checked by the re-org detector.
     pub last_correct_l2_block: Family<CheckerComponent, Gauge<u64>>,
+    /// Delay between creating an L2 block on the main node (i.e., the block timestamp) and applying it locally.
+    /// Includes the catch-up time if the node is not synced.
+    #[metrics(buckets = L2_BLOCK_APPLICATION_LAG_BUCKETS, unit = Unit::Seconds)]
+    pub l2_block_application_lag: Histogram<Duration>,
 }
 
 #[vise::register]
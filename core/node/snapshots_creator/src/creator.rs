@@ -3,7 +3,7 @@
 use std::sync::Arc;
 
 use anyhow::Context as _;
-use tokio::sync::Semaphore;
+use tokio::sync::{watch, Semaphore};
 use zksync_config::SnapshotsCreatorConfig;
 use zksync_dal::{Connection, ConnectionPool, Core, CoreDal, DalResult};
 use zksync_object_store::ObjectStore;
@@ -56,17 +56,31 @@ impl SnapshotProgress {
     }
 }
 
-/// Creator of a single storage snapshot.
+/// Creator of storage snapshots. Each [run](Self::run()) creates a single snapshot (or finishes creating a pending one).
 #[derive(Debug)]
-pub(crate) struct SnapshotCreator {
-    pub blob_store: Arc<dyn ObjectStore>,
-    pub master_pool: ConnectionPool<Core>,
-    pub replica_pool: ConnectionPool<Core>,
+pub struct SnapshotCreator {
+    pub(crate) blob_store: Arc<dyn ObjectStore>,
+    pub(crate) master_pool: ConnectionPool<Core>,
+    pub(crate) replica_pool: ConnectionPool<Core>,
     #[cfg(test)]
-    pub event_listener: Box<dyn HandleEvent>,
+    pub(crate) event_listener: Box<dyn HandleEvent>,
 }
 
 impl SnapshotCreator {
+    pub fn new(
+        blob_store: Arc<dyn ObjectStore>,
+        master_pool: ConnectionPool<Core>,
+        replica_pool: ConnectionPool<Core>,
+    ) -> Self {
+        Self {
+            blob_store,
+            master_pool,
+            replica_pool,
+            #[cfg(test)]
+            event_listener: Box::new(()),
+        }
+    }
+
     async fn connect_to_replica(&self) -> DalResult<Connection<'_, Core>> {
         self.replica_pool
             .connection_tagged("snapshots_creator")
@@ -273,8 +287,10 @@ impl SnapshotCreator {
         }
     }
 
+    /// Creates a snapshot at the L1 batch preceding the latest sealed one, or resumes creating a pending snapshot
+    /// if there is one. Does nothing if the snapshot at the expected L1 batch is already created.
     pub async fn run(
-        self,
+        &self,
         config: SnapshotsCreatorConfig,
         min_chunk_count: u64,
     ) -> anyhow::Result<()> {
@@ -355,4 +371,35 @@ impl SnapshotCreator {
         );
         Ok(())
     }
+
+    /// Periodically creates snapshots with the interval specified in the config until a stop signal is received.
+    /// Snapshot creation is resumable, so it is interrupted on the stop signal.
+    pub async fn run_periodically(
+        self,
+        config: SnapshotsCreatorConfig,
+        min_chunk_count: u64,
+        mut stop_receiver: watch::Receiver<bool>,
+    ) -> anyhow::Result<()> {
+        let interval = config.creation_interval();
+        while !*stop_receiver.borrow_and_update() {
+            let creation_result = tokio::select! {
+                result = self.run(config.clone(), min_chunk_count) => result,
+                _ = stop_receiver.changed() => break,
+            };
+            // Snapshots may fail to be created for legitimate reasons (e.g., if the chain has no L1 batches
+            // besides the genesis one yet), so errors are logged rather than propagated.
+            if let Err(err) = creation_result {
+                tracing::warn!("Failed creating snapshot, will retry in {interval:?}: {err:#}");
+            }
+
+            if tokio::time::timeout(interval, stop_receiver.changed())
+                .await
+                .is_ok()
+            {
+                break;
+            }
+        }
+        tracing::info!("Stop signal received, snapshots creator is shutting down");
+        Ok(())
+    }
 }
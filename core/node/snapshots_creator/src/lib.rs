@@ -0,0 +1,22 @@
+//! Creator of app-level storage snapshots. Each run of the creator produces a new snapshot at the L1 batch
+//! preceding the latest sealed one: storage logs are exported into chunked, compressed object store files,
+//! and factory dependencies into a single file; snapshot metadata is persisted in Postgres.
+//!
+//! # Assumptions
+//!
+//! The snapshot creator is fault-tolerant; if it stops in the middle of creating a snapshot,
+//! this snapshot will be continued from roughly the same point after the restart. If this is
+//! undesired, remove the `snapshots` table record corresponding to the pending snapshot.
+//!
+//! It is assumed that the snapshot creator is run as a singleton process (no more than 1 instance
+//! at a time).
+
+pub use self::creator::SnapshotCreator;
+
+mod creator;
+mod metrics;
+#[cfg(test)]
+mod tests;
+
+/// Minimum number of storage log chunks to produce.
+pub const MIN_CHUNK_COUNT: u64 = 10;
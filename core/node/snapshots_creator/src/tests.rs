@@ -7,10 +7,13 @@ use std::{
         atomic::{AtomicUsize, Ordering},
         Arc,
     },
+    time::Duration,
 };
 
 use rand::{thread_rng, Rng};
-use zksync_dal::{Connection, CoreDal};
+use tokio::sync::watch;
+use zksync_config::SnapshotsCreatorConfig;
+use zksync_dal::{Connection, ConnectionPool, Core, CoreDal};
 use zksync_object_store::{MockObjectStore, ObjectStore};
 use zksync_types::{
     block::{L1BatchHeader, L1BatchTreeData, L2BlockHeader},
@@ -28,11 +31,13 @@ const TEST_CONFIG: SnapshotsCreatorConfig = SnapshotsCreatorConfig {
     storage_logs_chunk_size: 1_000_000,
     concurrent_queries_count: 10,
     object_store: None,
+    creation_interval_sec: None,
 };
 const SEQUENTIAL_TEST_CONFIG: SnapshotsCreatorConfig = SnapshotsCreatorConfig {
     storage_logs_chunk_size: 1_000_000,
     concurrent_queries_count: 1,
     object_store: None,
+    creation_interval_sec: None,
 };
 
 #[derive(Debug)]
@@ -152,6 +157,7 @@ async fn create_l2_block(
         protocol_version: Some(Default::default()),
         virtual_blocks: 0,
         gas_limit: 0,
+        logs_bloom: Default::default(),
     };
 
     conn.blocks_dal()
@@ -458,3 +464,57 @@ async fn recovery_workflow_with_varying_chunk_size() {
 
     assert_storage_logs(&*object_store, snapshot_l1_batch_number, &expected_outputs).await;
 }
+
+#[tokio::test]
+async fn creating_snapshots_periodically() {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let mut rng = thread_rng();
+    let object_store = MockObjectStore::arc();
+    let mut conn = pool.connection().await.unwrap();
+    prepare_postgres(&mut rng, &mut conn, 3).await;
+
+    let config = SnapshotsCreatorConfig {
+        creation_interval_sec: Some(0),
+        ..SEQUENTIAL_TEST_CONFIG
+    };
+    let (stop_sender, stop_receiver) = watch::channel(false);
+    let creator_task = tokio::spawn(
+        SnapshotCreator::for_tests(object_store, pool.clone()).run_periodically(
+            config,
+            MIN_CHUNK_COUNT,
+            stop_receiver,
+        ),
+    );
+
+    wait_for_complete_snapshot(&mut conn, L1BatchNumber(1)).await;
+    // Seal more L1 batches; the creator should produce a snapshot for the new L1 batch.
+    for block_number in 3..5 {
+        let logs = gen_storage_logs(&mut rng, 10);
+        create_l2_block(&mut conn, L2BlockNumber(block_number), logs.clone()).await;
+        create_l1_batch(&mut conn, L1BatchNumber(block_number), &logs).await;
+    }
+    wait_for_complete_snapshot(&mut conn, L1BatchNumber(3)).await;
+
+    stop_sender.send_replace(true);
+    creator_task.await.unwrap().unwrap();
+}
+
+async fn wait_for_complete_snapshot(
+    conn: &mut Connection<'_, Core>,
+    l1_batch_number: L1BatchNumber,
+) {
+    loop {
+        let snapshots = conn
+            .snapshots_dal()
+            .get_all_complete_snapshots()
+            .await
+            .unwrap();
+        if snapshots
+            .snapshots_l1_batch_numbers
+            .contains(&l1_batch_number)
+        {
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+}
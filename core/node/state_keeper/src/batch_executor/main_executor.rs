@@ -18,7 +18,7 @@ use tokio::{
 };
 use zksync_shared_metrics::{InteractionType, TxStage, APP_METRICS};
 use zksync_state::{ReadStorage, ReadStorageFactory, StorageView, WriteStorage};
-use zksync_types::{vm_trace::Call, Transaction};
+use zksync_types::{vm_trace::Call, Transaction, VmVersion};
 use zksync_utils::bytecode::CompressedBytecodeInfo;
 
 use super::{BatchExecutor, BatchExecutorHandle, Command, TxExecutionResult};
@@ -33,6 +33,7 @@ use crate::{
 pub struct MainBatchExecutor {
     save_call_traces: bool,
     optional_bytecode_compression: bool,
+    vm_version: Option<VmVersion>,
 }
 
 impl MainBatchExecutor {
@@ -40,8 +41,16 @@ impl MainBatchExecutor {
         Self {
             save_call_traces,
             optional_bytecode_compression,
+            vm_version: None,
         }
     }
+
+    /// Overrides the VM version used to execute batches. By default, the VM version is chosen based on the protocol version
+    /// of each batch. Overriding the version is only useful for re-executing batches, e.g. to check a new VM version
+    /// against the stored execution results.
+    pub fn set_vm_version(&mut self, vm_version: VmVersion) {
+        self.vm_version = Some(vm_version);
+    }
 }
 
 #[async_trait]
@@ -59,11 +68,15 @@ impl BatchExecutor for MainBatchExecutor {
         let executor = CommandReceiver {
             save_call_traces: self.save_call_traces,
             optional_bytecode_compression: self.optional_bytecode_compression,
+            vm_version: self.vm_version,
             commands: commands_receiver,
         };
 
         let stop_receiver = stop_receiver.clone();
+        // Spans aren't propagated to blocking tasks automatically, so the batch span is made a child of the caller span.
+        let batch_span = tracing::info_span!("execute_l1_batch", number = %l1_batch_params.number);
         let handle = tokio::task::spawn_blocking(move || {
+            let _entered = batch_span.enter();
             if let Some(storage) = Handle::current()
                 .block_on(
                     storage_factory.access_storage(&stop_receiver, l1_batch_params.number - 1),
@@ -90,6 +103,7 @@ impl BatchExecutor for MainBatchExecutor {
 struct CommandReceiver {
     save_call_traces: bool,
     optional_bytecode_compression: bool,
+    vm_version: Option<VmVersion>,
     commands: mpsc::Receiver<Command>,
 }
 
@@ -104,7 +118,17 @@ impl CommandReceiver {
 
         let storage_view = StorageView::new(secondary_storage).to_rc_ptr();
 
-        let mut vm = VmInstance::new(l1_batch_params, system_env, storage_view.clone());
+        let mut vm = if let Some(vm_version) = self.vm_version {
+            tracing::info!("Using overridden VM version {vm_version:?}");
+            VmInstance::new_with_specific_version(
+                l1_batch_params,
+                system_env,
+                storage_view.clone(),
+                vm_version,
+            )
+        } else {
+            VmInstance::new(l1_batch_params, system_env, storage_view.clone())
+        };
 
         while let Some(cmd) = self.commands.blocking_recv() {
             match cmd {
@@ -152,6 +176,7 @@ impl CommandReceiver {
         tx: &Transaction,
         vm: &mut VmInstance<S, HistoryEnabled>,
     ) -> TxExecutionResult {
+        let _span = tracing::debug_span!("execute_tx", tx.hash = ?tx.hash()).entered();
         // Save pre-`execute_next_tx` VM snapshot.
         vm.make_snapshot();
 
@@ -204,6 +229,7 @@ impl CommandReceiver {
         &self,
         vm: &mut VmInstance<S, HistoryEnabled>,
     ) -> FinishedL1Batch {
+        let _span = tracing::debug_span!("finish_batch").entered();
         // The vm execution was paused right after the last transaction was executed.
         // There is some post-processing work that the VM needs to do before the block is fully processed.
         let result = vm.finish_batch();
@@ -12,7 +12,7 @@ use multivm::utils::{get_max_batch_gas_limit, get_max_gas_per_pubdata_byte};
 use zksync_dal::{Connection, ConnectionPool, Core, CoreDal};
 use zksync_shared_metrics::{BlockStage, L2BlockStage, APP_METRICS};
 use zksync_types::{
-    block::{L1BatchHeader, L2BlockHeader},
+    block::{build_logs_bloom, L1BatchHeader, L2BlockHeader},
     event::extract_long_l2_to_l1_messages,
     helpers::unix_timestamp_ms,
     l2_to_l1_log::UserL2ToL1Log,
@@ -388,6 +388,7 @@ impl L2BlockSealCommand {
             gas_per_pubdata_limit: get_max_gas_per_pubdata_byte(definite_vm_version),
             virtual_blocks: self.l2_block.virtual_blocks,
             gas_limit: get_max_batch_gas_limit(definite_vm_version),
+            logs_bloom: build_logs_bloom(&self.l2_block.events),
         };
 
         let mut connection = strategy.connection().await?;
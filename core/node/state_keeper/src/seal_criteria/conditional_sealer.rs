@@ -115,11 +115,28 @@ impl ConditionalSealer for SequencerSealer {
 }
 
 impl SequencerSealer {
+    /// Creates a sealer with the default set of criteria.
     pub fn new(config: StateKeeperConfig) -> Self {
         let sealers = Self::default_sealers(&config);
         Self { config, sealers }
     }
 
+    /// Registers an additional seal criterion. It's checked after the default criteria; the final resolution
+    /// is the [strictest](SealResolution::stricter()) one among all criteria.
+    #[must_use]
+    pub fn with_criterion(mut self, criterion: Box<dyn SealCriterion>) -> Self {
+        self.sealers.push(criterion);
+        self
+    }
+
+    /// Returns names of all registered criteria.
+    pub fn criterion_names(&self) -> Vec<&'static str> {
+        self.sealers
+            .iter()
+            .map(|sealer| sealer.prom_criterion_name())
+            .collect()
+    }
+
     #[cfg(test)]
     pub(crate) fn with_sealers(
         config: StateKeeperConfig,
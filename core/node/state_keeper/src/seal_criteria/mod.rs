@@ -186,9 +186,38 @@ impl SealData {
             gas_remaining: tx_metrics.gas_remaining,
         }
     }
+
+    pub fn execution_metrics(&self) -> &ExecutionMetrics {
+        &self.execution_metrics
+    }
+
+    pub fn gas_count(&self) -> &BlockGasCount {
+        &self.gas_count
+    }
+
+    /// Returns the cumulative bootloader encoding size of transactions.
+    pub fn cumulative_size(&self) -> usize {
+        self.cumulative_size
+    }
+
+    pub fn writes_metrics(&self) -> &DeduplicatedWritesMetrics {
+        &self.writes_metrics
+    }
+
+    pub fn gas_remaining(&self) -> u32 {
+        self.gas_remaining
+    }
 }
 
-pub(super) trait SealCriterion: fmt::Debug + Send + Sync + 'static {
+/// Deterministic criterion deciding whether an L1 batch should be sealed after executing a transaction.
+///
+/// Criteria are combined by [`SequencerSealer`]; besides the default criteria, custom ones can be registered
+/// using [`SequencerSealer::with_criterion()`]. Since the sealer is also used by the API server to reject
+/// unexecutable transactions, criteria must only depend on the provided arguments.
+pub trait SealCriterion: fmt::Debug + Send + Sync + 'static {
+    /// Returns the seal resolution for an L1 batch opened at `block_open_timestamp_ms` with `tx_count` transactions
+    /// (including the last executed transaction). `block_data` contains cumulative data for the batch,
+    /// and `tx_data` for the last executed transaction only.
     fn should_seal(
         &self,
         config: &StateKeeperConfig,
@@ -199,6 +228,7 @@ pub(super) trait SealCriterion: fmt::Debug + Send + Sync + 'static {
         protocol_version: ProtocolVersionId,
     ) -> SealResolution;
 
+    /// Returns the criterion name used in logs and metrics.
     // We need self here only for rust restrictions for creating an object from trait
     // https://doc.rust-lang.org/reference/items/traits.html#object-safety
     fn prom_criterion_name(&self) -> &'static str;
@@ -327,6 +357,57 @@ mod tests {
         );
     }
 
+    /// Custom criterion sealing the batch once it reaches the specified number of transactions.
+    #[derive(Debug)]
+    struct TxCountCriterion(usize);
+
+    impl SealCriterion for TxCountCriterion {
+        fn should_seal(
+            &self,
+            _config: &StateKeeperConfig,
+            _block_open_timestamp_ms: u128,
+            tx_count: usize,
+            _block_data: &SealData,
+            _tx_data: &SealData,
+            _protocol_version: ProtocolVersionId,
+        ) -> SealResolution {
+            if tx_count >= self.0 {
+                SealResolution::IncludeAndSeal
+            } else {
+                SealResolution::NoSeal
+            }
+        }
+
+        fn prom_criterion_name(&self) -> &'static str {
+            "tx_count"
+        }
+    }
+
+    #[test]
+    fn registering_custom_seal_criterion() {
+        let config = StateKeeperConfig::for_tests();
+        let sealer =
+            SequencerSealer::new(config.clone()).with_criterion(Box::new(TxCountCriterion(3)));
+        let criterion_names = sealer.criterion_names();
+        assert!(criterion_names.contains(&"slots"), "{criterion_names:?}");
+        assert_eq!(criterion_names.last(), Some(&"tx_count"));
+
+        let sealer = SequencerSealer::with_sealers(config, vec![])
+            .with_criterion(Box::new(TxCountCriterion(3)));
+
+        let data = SealData::default();
+        let protocol_version = ProtocolVersionId::latest();
+        let resolution = sealer.should_seal_l1_batch(1, 0, 2, &data, &data, protocol_version);
+        assert_eq!(resolution, SealResolution::NoSeal);
+        let resolution = sealer.should_seal_l1_batch(1, 0, 3, &data, &data, protocol_version);
+        assert_eq!(resolution, SealResolution::IncludeAndSeal);
+        // Custom criteria shouldn't influence transaction executability unless they return `Unexecutable`.
+        assert_eq!(
+            sealer.find_unexecutable_reason(&data, protocol_version),
+            None
+        );
+    }
+
     #[test]
     fn max_size_l2_block_sealer() {
         let tx = create_transaction(10, 100);
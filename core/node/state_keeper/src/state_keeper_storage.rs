@@ -6,8 +6,8 @@ use once_cell::sync::OnceCell;
 use tokio::sync::watch;
 use zksync_dal::{ConnectionPool, Core};
 use zksync_state::{
-    AsyncCatchupTask, PgOrRocksdbStorage, ReadStorageFactory, RocksdbStorageOptions,
-    StateKeeperColumnFamily,
+    AsyncCatchupTask, PgOrRocksdbStorage, ReadStorageFactory, RocksdbMaintenanceConfig,
+    RocksdbMaintenanceTask, RocksdbStorageOptions, StateKeeperColumnFamily,
 };
 use zksync_storage::RocksDB;
 use zksync_types::L1BatchNumber;
@@ -67,6 +67,11 @@ impl AsyncRocksdbCache {
         );
         (Self { pool, rocksdb_cell }, task)
     }
+
+    /// Creates a task compacting and monitoring size of the RocksDB cache once it's initialized.
+    pub fn maintenance_task(&self, config: RocksdbMaintenanceConfig) -> RocksdbMaintenanceTask {
+        RocksdbMaintenanceTask::new(self.rocksdb_cell.clone(), config)
+    }
 }
 
 #[async_trait]
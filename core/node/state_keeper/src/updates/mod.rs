@@ -66,7 +66,7 @@ impl UpdatesManager {
         self.batch_timestamp
     }
 
-    pub(crate) fn base_system_contract_hashes(&self) -> BaseSystemContractsHashes {
+    pub fn base_system_contract_hashes(&self) -> BaseSystemContractsHashes {
         self.base_system_contract_hashes
     }
 
@@ -98,7 +98,7 @@ impl UpdatesManager {
         }
     }
 
-    pub(crate) fn protocol_version(&self) -> ProtocolVersionId {
+    pub fn protocol_version(&self) -> ProtocolVersionId {
         self.protocol_version
     }
 
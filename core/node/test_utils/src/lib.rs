@@ -40,6 +40,7 @@ pub fn create_l2_block(number: u32) -> L2BlockHeader {
         protocol_version: Some(ProtocolVersionId::latest()),
         virtual_blocks: 1,
         gas_limit: 0,
+        logs_bloom: Default::default(),
     }
 }
 
@@ -74,6 +75,7 @@ pub fn create_l1_batch_metadata(number: u32) -> L1BatchMetadata {
         events_queue_commitment: Some(H256::zero()),
         bootloader_initial_content_commitment: Some(H256::zero()),
         state_diffs_compressed: vec![],
+        da_inclusion_data: None,
     }
 }
 
@@ -188,6 +190,7 @@ impl Snapshot {
             protocol_version: Some(genesis_params.minor_protocol_version()),
             virtual_blocks: 1,
             gas_limit: 0,
+            logs_bloom: Default::default(),
         };
         Snapshot {
             l1_batch,
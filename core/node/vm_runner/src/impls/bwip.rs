@@ -0,0 +1,247 @@
+use std::{collections::HashSet, sync::Arc};
+
+use anyhow::Context;
+use async_trait::async_trait;
+use tokio::sync::watch;
+use zksync_dal::{Connection, ConnectionPool, Core, CoreDal};
+use zksync_object_store::ObjectStore;
+use zksync_prover_interface::inputs::VMRunWitnessInputData;
+use zksync_state_keeper::{MainBatchExecutor, StateKeeperOutputHandler, UpdatesManager};
+use zksync_types::{L1BatchNumber, L2ChainId, H256};
+use zksync_utils::{bytes_to_chunks, h256_to_u256, u256_to_h256};
+
+use crate::{
+    storage::StorageSyncTask, ConcurrentOutputHandlerFactory, ConcurrentOutputHandlerFactoryTask,
+    OutputHandlerFactory, VmRunner, VmRunnerIo, VmRunnerStorage,
+};
+
+/// A standalone component that re-executes L1 batches and writes the data required to generate basic circuits
+/// for them (aka basic witness inputs) to the object store.
+#[derive(Debug)]
+pub struct BasicWitnessInputProducer {
+    vm_runner: VmRunner,
+}
+
+impl BasicWitnessInputProducer {
+    /// Create a new basic witness input producer from the provided DB parameters and window size which
+    /// regulates how many batches this component can handle at the same time.
+    pub async fn new(
+        pool: ConnectionPool<Core>,
+        object_store: Arc<dyn ObjectStore>,
+        rocksdb_path: String,
+        chain_id: L2ChainId,
+        first_processed_batch: L1BatchNumber,
+        window_size: u32,
+    ) -> anyhow::Result<(Self, BasicWitnessInputProducerTasks)> {
+        let io = BasicWitnessInputProducerIo {
+            first_processed_batch,
+            window_size,
+        };
+        let (loader, loader_task) =
+            VmRunnerStorage::new(pool.clone(), rocksdb_path, io.clone(), chain_id).await?;
+        let output_handler_factory = BasicWitnessInputProducerOutputHandlerFactory {
+            pool: pool.clone(),
+            object_store,
+        };
+        let (output_handler_factory, output_handler_factory_task) =
+            ConcurrentOutputHandlerFactory::new(pool.clone(), io.clone(), output_handler_factory);
+        let batch_processor = MainBatchExecutor::new(false, false);
+        let vm_runner = VmRunner::new(
+            pool,
+            Box::new(io),
+            Arc::new(loader),
+            Box::new(output_handler_factory),
+            Box::new(batch_processor),
+        );
+        Ok((
+            Self { vm_runner },
+            BasicWitnessInputProducerTasks {
+                loader_task,
+                output_handler_factory_task,
+            },
+        ))
+    }
+
+    /// Continuously loads new available batches and writes the corresponding basic witness inputs
+    /// produced by that batch.
+    ///
+    /// # Errors
+    ///
+    /// Propagates RocksDB, Postgres and object store errors.
+    pub async fn run(self, stop_receiver: &watch::Receiver<bool>) -> anyhow::Result<()> {
+        self.vm_runner.run(stop_receiver).await
+    }
+}
+
+/// A collections of tasks that need to be run in order for basic witness input producer to work as
+/// intended.
+#[derive(Debug)]
+pub struct BasicWitnessInputProducerTasks {
+    /// Task that synchronizes storage with new available batches.
+    pub loader_task: StorageSyncTask<BasicWitnessInputProducerIo>,
+    /// Task that handles output from processed batches.
+    pub output_handler_factory_task:
+        ConcurrentOutputHandlerFactoryTask<BasicWitnessInputProducerIo>,
+}
+
+#[derive(Debug, Clone)]
+pub struct BasicWitnessInputProducerIo {
+    first_processed_batch: L1BatchNumber,
+    window_size: u32,
+}
+
+#[async_trait]
+impl VmRunnerIo for BasicWitnessInputProducerIo {
+    fn name(&self) -> &'static str {
+        "basic_witness_input_producer"
+    }
+
+    async fn latest_processed_batch(
+        &self,
+        conn: &mut Connection<'_, Core>,
+    ) -> anyhow::Result<L1BatchNumber> {
+        Ok(conn
+            .vm_runner_dal()
+            .get_bwip_latest_processed_batch(self.first_processed_batch)
+            .await?)
+    }
+
+    async fn last_ready_to_be_loaded_batch(
+        &self,
+        conn: &mut Connection<'_, Core>,
+    ) -> anyhow::Result<L1BatchNumber> {
+        Ok(conn
+            .vm_runner_dal()
+            .get_bwip_last_ready_batch(self.window_size)
+            .await?)
+    }
+
+    async fn mark_l1_batch_as_completed(
+        &self,
+        conn: &mut Connection<'_, Core>,
+        l1_batch_number: L1BatchNumber,
+    ) -> anyhow::Result<()> {
+        Ok(conn
+            .vm_runner_dal()
+            .mark_bwip_batch_as_completed(l1_batch_number)
+            .await?)
+    }
+}
+
+#[derive(Debug)]
+struct BasicWitnessInputProducerOutputHandler {
+    pool: ConnectionPool<Core>,
+    object_store: Arc<dyn ObjectStore>,
+}
+
+#[async_trait]
+impl StateKeeperOutputHandler for BasicWitnessInputProducerOutputHandler {
+    async fn handle_l2_block(&mut self, _updates_manager: &UpdatesManager) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    async fn handle_l1_batch(
+        &mut self,
+        updates_manager: Arc<UpdatesManager>,
+    ) -> anyhow::Result<()> {
+        let l1_batch_number = updates_manager.l1_batch.number;
+        let mut connection = self
+            .pool
+            .connection_tagged("basic_witness_input_producer")
+            .await?;
+        let input = get_witness_input_data(&mut connection, &updates_manager).await?;
+        drop(connection);
+
+        let blob_url = self
+            .object_store
+            .put(l1_batch_number, &input)
+            .await
+            .context("failed saving VM run data to object store")?;
+        tracing::info!(%l1_batch_number, "Saved VM run data to {blob_url}");
+        Ok(())
+    }
+}
+
+async fn get_witness_input_data(
+    connection: &mut Connection<'_, Core>,
+    updates_manager: &UpdatesManager,
+) -> anyhow::Result<VMRunWitnessInputData> {
+    let l1_batch_number = updates_manager.l1_batch.number;
+    let finished_batch = updates_manager
+        .l1_batch
+        .finished
+        .as_ref()
+        .context("L1 batch is not actually finished")?;
+    let initial_heap_content = finished_batch
+        .final_bootloader_memory
+        .clone()
+        .context("bootloader memory is not available for the VM version")?;
+    let execution_state = &finished_batch.final_execution_state;
+
+    let base_system_contracts_hashes = updates_manager.base_system_contract_hashes();
+    let bootloader_code_bytes = connection
+        .factory_deps_dal()
+        .get_sealed_factory_dep(base_system_contracts_hashes.bootloader)
+        .await?
+        .context("bootloader bytecode is missing")?;
+    let account_bytecode_bytes = connection
+        .factory_deps_dal()
+        .get_sealed_factory_dep(base_system_contracts_hashes.default_aa)
+        .await?
+        .context("default account bytecode is missing")?;
+    let bootloader_code_hash = h256_to_u256(base_system_contracts_hashes.bootloader);
+    let account_code_hash = h256_to_u256(base_system_contracts_hashes.default_aa);
+
+    let hashes: HashSet<H256> = execution_state
+        .used_contract_hashes
+        .iter()
+        // The bootloader bytecode is passed to the witness generator separately.
+        .filter(|&&hash| hash != bootloader_code_hash)
+        .map(|&hash| u256_to_h256(hash))
+        .collect();
+    let mut used_bytecodes = connection
+        .factory_deps_dal()
+        .get_factory_deps(&hashes)
+        .await;
+    if execution_state
+        .used_contract_hashes
+        .contains(&account_code_hash)
+    {
+        used_bytecodes.insert(account_code_hash, bytes_to_chunks(&account_bytecode_bytes));
+    }
+    anyhow::ensure!(
+        hashes.len() == used_bytecodes.len(),
+        "{} bytecodes used in L1 batch #{l1_batch_number} are not found in Postgres",
+        hashes.len() - used_bytecodes.len()
+    );
+
+    Ok(VMRunWitnessInputData {
+        l1_batch_number,
+        used_bytecodes,
+        initial_heap_content,
+        protocol_version: updates_manager.protocol_version(),
+        bootloader_code: bytes_to_chunks(&bootloader_code_bytes),
+        default_account_code_hash: account_code_hash,
+        storage_refunds: execution_state.storage_refunds.clone(),
+        pubdata_costs: execution_state.pubdata_costs.clone(),
+    })
+}
+
+#[derive(Debug)]
+struct BasicWitnessInputProducerOutputHandlerFactory {
+    pool: ConnectionPool<Core>,
+    object_store: Arc<dyn ObjectStore>,
+}
+
+#[async_trait]
+impl OutputHandlerFactory for BasicWitnessInputProducerOutputHandlerFactory {
+    async fn create_handler(
+        &mut self,
+        _l1_batch_number: L1BatchNumber,
+    ) -> anyhow::Result<Box<dyn StateKeeperOutputHandler>> {
+        Ok(Box::new(BasicWitnessInputProducerOutputHandler {
+            pool: self.pool.clone(),
+            object_store: self.object_store.clone(),
+        }))
+    }
+}
@@ -1,3 +1,10 @@
+mod bwip;
+mod playground;
 mod protective_reads;
 
+pub use bwip::{BasicWitnessInputProducer, BasicWitnessInputProducerTasks};
+#[cfg(test)]
+pub(crate) use playground::find_divergences;
+pub(crate) use playground::DivergenceKind;
+pub use playground::{VmPlayground, VmPlaygroundTasks};
 pub use protective_reads::{ProtectiveReadsWriter, ProtectiveReadsWriterTasks};
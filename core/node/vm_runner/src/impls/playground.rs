@@ -0,0 +1,352 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use anyhow::Context;
+use async_trait::async_trait;
+use multivm::interface::CurrentExecutionState;
+use serde::Serialize;
+use tokio::sync::watch;
+use vise::{EncodeLabelSet, EncodeLabelValue};
+use zksync_dal::{Connection, ConnectionPool, Core, CoreDal};
+use zksync_health_check::{Health, HealthStatus, HealthUpdater, ReactiveHealthCheck};
+use zksync_state_keeper::{MainBatchExecutor, StateKeeperOutputHandler, UpdatesManager};
+use zksync_types::{
+    block::L1BatchHeader, AccountTreeId, L1BatchNumber, L2ChainId, StorageKey, VmVersion, H256,
+};
+use zksync_utils::u256_to_h256;
+
+use crate::{
+    metrics::PLAYGROUND_METRICS, storage::StorageSyncTask, ConcurrentOutputHandlerFactory,
+    ConcurrentOutputHandlerFactoryTask, OutputHandlerFactory, VmRunner, VmRunnerIo,
+    VmRunnerStorage,
+};
+
+/// Kind of divergence between the outcome of re-executing an L1 batch and the outcome persisted by the state keeper.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EncodeLabelValue, EncodeLabelSet, Serialize)]
+#[metrics(label = "kind", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum DivergenceKind {
+    UserL2ToL1Logs,
+    SystemLogs,
+    UsedContractHashes,
+    StorageWrites,
+    PubdataInput,
+}
+
+/// Compares the outcome of re-executing an L1 batch with the persisted L1 batch header and the final values
+/// of storage slots touched in the batch. Returns all detected divergence kinds.
+///
+/// Pubdata input is only compared if it is available both for the re-executed and the persisted batch.
+pub(crate) fn find_divergences(
+    header: &L1BatchHeader,
+    touched_slots: &HashMap<StorageKey, H256>,
+    execution_state: &CurrentExecutionState,
+    pubdata_input: Option<&[u8]>,
+) -> Vec<DivergenceKind> {
+    let mut divergences = vec![];
+    if execution_state.user_l2_to_l1_logs != header.l2_to_l1_logs {
+        divergences.push(DivergenceKind::UserL2ToL1Logs);
+    }
+    if execution_state.system_logs != header.system_logs {
+        divergences.push(DivergenceKind::SystemLogs);
+    }
+
+    let mut used_contract_hashes = execution_state.used_contract_hashes.clone();
+    used_contract_hashes.sort_unstable();
+    let mut expected_contract_hashes = header.used_contract_hashes.clone();
+    expected_contract_hashes.sort_unstable();
+    if used_contract_hashes != expected_contract_hashes {
+        divergences.push(DivergenceKind::UsedContractHashes);
+    }
+
+    let has_divergent_writes = execution_state
+        .deduplicated_storage_log_queries
+        .iter()
+        .filter(|log_query| log_query.rw_flag)
+        .any(|write| {
+            let key = StorageKey::new(AccountTreeId::new(write.address), u256_to_h256(write.key));
+            touched_slots.get(&key) != Some(&u256_to_h256(write.written_value))
+        });
+    if has_divergent_writes {
+        divergences.push(DivergenceKind::StorageWrites);
+    }
+
+    if let (Some(pubdata_input), Some(expected_pubdata_input)) =
+        (pubdata_input, header.pubdata_input.as_deref())
+    {
+        if pubdata_input != expected_pubdata_input {
+            divergences.push(DivergenceKind::PubdataInput);
+        }
+    }
+    divergences
+}
+
+/// A standalone component that continuously re-executes L1 batches, possibly with a VM version different from
+/// the one used by the state keeper, and compares the outcome with the data persisted by the state keeper.
+/// Divergences are reported via metrics and the `vm_playground` health check.
+#[derive(Debug)]
+pub struct VmPlayground {
+    vm_runner: VmRunner,
+    health_check: ReactiveHealthCheck,
+}
+
+impl VmPlayground {
+    /// Create a new VM playground from the provided DB parameters and window size which
+    /// regulates how many batches this component can handle at the same time. If `vm_version` is specified,
+    /// it overrides the VM version derived from the protocol version of each batch.
+    pub async fn new(
+        pool: ConnectionPool<Core>,
+        rocksdb_path: String,
+        chain_id: L2ChainId,
+        first_processed_batch: L1BatchNumber,
+        window_size: u32,
+        vm_version: Option<VmVersion>,
+    ) -> anyhow::Result<(Self, VmPlaygroundTasks)> {
+        let io = VmPlaygroundIo {
+            first_processed_batch,
+            window_size,
+        };
+        let (loader, loader_task) =
+            VmRunnerStorage::new(pool.clone(), rocksdb_path, io.clone(), chain_id).await?;
+
+        let (health_check, health_updater) = ReactiveHealthCheck::new("vm_playground");
+        let health = VmPlaygroundHealth {
+            vm_version: vm_version.map(|version| format!("{version:?}")),
+            ..VmPlaygroundHealth::default()
+        };
+        health_updater.update(health.clone().into());
+        let output_handler_factory = VmPlaygroundOutputHandlerFactory {
+            pool: pool.clone(),
+            state: Arc::new(VmPlaygroundState {
+                health_updater,
+                health: Mutex::new(health),
+            }),
+        };
+        let (output_handler_factory, output_handler_factory_task) =
+            ConcurrentOutputHandlerFactory::new(pool.clone(), io.clone(), output_handler_factory);
+
+        let mut batch_processor = MainBatchExecutor::new(false, false);
+        if let Some(vm_version) = vm_version {
+            batch_processor.set_vm_version(vm_version);
+        }
+        let vm_runner = VmRunner::new(
+            pool,
+            Box::new(io),
+            Arc::new(loader),
+            Box::new(output_handler_factory),
+            Box::new(batch_processor),
+        );
+        Ok((
+            Self {
+                vm_runner,
+                health_check,
+            },
+            VmPlaygroundTasks {
+                loader_task,
+                output_handler_factory_task,
+            },
+        ))
+    }
+
+    /// Returns a health check for this component.
+    pub fn health_check(&self) -> ReactiveHealthCheck {
+        self.health_check.clone()
+    }
+
+    /// Continuously loads new available batches, re-executes them and compares the outcome with
+    /// the persisted data.
+    ///
+    /// # Errors
+    ///
+    /// Propagates RocksDB and Postgres errors.
+    pub async fn run(self, stop_receiver: &watch::Receiver<bool>) -> anyhow::Result<()> {
+        self.vm_runner.run(stop_receiver).await
+    }
+}
+
+/// A collections of tasks that need to be run in order for VM playground to work as intended.
+#[derive(Debug)]
+pub struct VmPlaygroundTasks {
+    /// Task that synchronizes storage with new available batches.
+    pub loader_task: StorageSyncTask<VmPlaygroundIo>,
+    /// Task that handles output from processed batches.
+    pub output_handler_factory_task: ConcurrentOutputHandlerFactoryTask<VmPlaygroundIo>,
+}
+
+#[derive(Debug, Clone)]
+pub struct VmPlaygroundIo {
+    first_processed_batch: L1BatchNumber,
+    window_size: u32,
+}
+
+#[async_trait]
+impl VmRunnerIo for VmPlaygroundIo {
+    fn name(&self) -> &'static str {
+        "vm_playground"
+    }
+
+    async fn latest_processed_batch(
+        &self,
+        conn: &mut Connection<'_, Core>,
+    ) -> anyhow::Result<L1BatchNumber> {
+        Ok(conn
+            .vm_runner_dal()
+            .get_vm_playground_latest_processed_batch(self.first_processed_batch)
+            .await?)
+    }
+
+    async fn last_ready_to_be_loaded_batch(
+        &self,
+        conn: &mut Connection<'_, Core>,
+    ) -> anyhow::Result<L1BatchNumber> {
+        Ok(conn
+            .vm_runner_dal()
+            .get_vm_playground_last_ready_batch(self.window_size)
+            .await?)
+    }
+
+    async fn mark_l1_batch_as_completed(
+        &self,
+        conn: &mut Connection<'_, Core>,
+        l1_batch_number: L1BatchNumber,
+    ) -> anyhow::Result<()> {
+        Ok(conn
+            .vm_runner_dal()
+            .mark_vm_playground_batch_as_completed(l1_batch_number)
+            .await?)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct VmPlaygroundDivergence {
+    l1_batch_number: L1BatchNumber,
+    kinds: Vec<DivergenceKind>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+struct VmPlaygroundHealth {
+    vm_version: Option<String>,
+    last_processed_batch: Option<L1BatchNumber>,
+    divergent_batch_count: u64,
+    last_divergence: Option<VmPlaygroundDivergence>,
+}
+
+impl From<VmPlaygroundHealth> for Health {
+    fn from(details: VmPlaygroundHealth) -> Self {
+        let status = if details.divergent_batch_count > 0 {
+            HealthStatus::Affected
+        } else {
+            HealthStatus::Ready
+        };
+        Self::from(status).with_details(details)
+    }
+}
+
+/// State shared among output handlers.
+#[derive(Debug)]
+struct VmPlaygroundState {
+    health_updater: HealthUpdater,
+    health: Mutex<VmPlaygroundHealth>,
+}
+
+impl VmPlaygroundState {
+    fn report_batch(&self, l1_batch_number: L1BatchNumber, divergences: Vec<DivergenceKind>) {
+        PLAYGROUND_METRICS.processed_batches.inc();
+        for &kind in &divergences {
+            PLAYGROUND_METRICS.divergences[&kind].inc();
+        }
+
+        let mut health = self
+            .health
+            .lock()
+            .expect("VM playground health is poisoned");
+        health.last_processed_batch = health.last_processed_batch.max(Some(l1_batch_number));
+        if !divergences.is_empty() {
+            PLAYGROUND_METRICS
+                .last_divergent_batch
+                .set(l1_batch_number.0.into());
+            health.divergent_batch_count += 1;
+            health.last_divergence = Some(VmPlaygroundDivergence {
+                l1_batch_number,
+                kinds: divergences,
+            });
+        }
+        self.health_updater.update(health.clone().into());
+    }
+}
+
+#[derive(Debug)]
+struct VmPlaygroundOutputHandler {
+    pool: ConnectionPool<Core>,
+    state: Arc<VmPlaygroundState>,
+}
+
+#[async_trait]
+impl StateKeeperOutputHandler for VmPlaygroundOutputHandler {
+    async fn handle_l2_block(&mut self, _updates_manager: &UpdatesManager) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    async fn handle_l1_batch(
+        &mut self,
+        updates_manager: Arc<UpdatesManager>,
+    ) -> anyhow::Result<()> {
+        let l1_batch_number = updates_manager.l1_batch.number;
+        let finished_batch = updates_manager
+            .l1_batch
+            .finished
+            .as_ref()
+            .context("L1 batch is not actually finished")?;
+
+        let mut connection = self.pool.connection_tagged("vm_playground").await?;
+        let header = connection
+            .blocks_dal()
+            .get_l1_batch_header(l1_batch_number)
+            .await?
+            .with_context(|| format!("L1 batch #{l1_batch_number} is not persisted"))?;
+        let touched_slots = connection
+            .storage_logs_dal()
+            .get_touched_slots_for_l1_batch(l1_batch_number)
+            .await?;
+        drop(connection);
+
+        let divergences = find_divergences(
+            &header,
+            &touched_slots,
+            &finished_batch.final_execution_state,
+            finished_batch.pubdata_input.as_deref(),
+        );
+        if divergences.is_empty() {
+            tracing::info!(%l1_batch_number, "Re-executed L1 batch matches persisted data");
+        } else {
+            tracing::warn!(
+                %l1_batch_number,
+                ?divergences,
+                "Re-executed L1 batch diverges from persisted data"
+            );
+        }
+        self.state.report_batch(l1_batch_number, divergences);
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+struct VmPlaygroundOutputHandlerFactory {
+    pool: ConnectionPool<Core>,
+    state: Arc<VmPlaygroundState>,
+}
+
+#[async_trait]
+impl OutputHandlerFactory for VmPlaygroundOutputHandlerFactory {
+    async fn create_handler(
+        &mut self,
+        _l1_batch_number: L1BatchNumber,
+    ) -> anyhow::Result<Box<dyn StateKeeperOutputHandler>> {
+        Ok(Box::new(VmPlaygroundOutputHandler {
+            pool: self.pool.clone(),
+            state: self.state.clone(),
+        }))
+    }
+}
@@ -150,15 +150,25 @@ impl StateKeeperOutputHandler for ProtectiveReadsOutputHandler {
             .pool
             .connection_tagged("protective_reads_writer")
             .await?;
-        let mut expected_protective_reads = connection
+        let mut written_protective_reads = connection
             .storage_logs_dedup_dal()
             .get_protective_reads_for_l1_batch(updates_manager.l1_batch.number)
             .await?;
 
+        if written_protective_reads.is_empty() {
+            // Protective reads were not persisted by the state keeper (or by a previous run of this component),
+            // so we are responsible for persisting them.
+            connection
+                .storage_logs_dedup_dal()
+                .insert_protective_reads(updates_manager.l1_batch.number, &protective_reads)
+                .await?;
+            return Ok(());
+        }
+
         for protective_read in protective_reads {
             let address = AccountTreeId::new(protective_read.address);
             let key = u256_to_h256(protective_read.key);
-            if !expected_protective_reads.remove(&StorageKey::new(address, key)) {
+            if !written_protective_reads.remove(&StorageKey::new(address, key)) {
                 tracing::error!(
                     l1_batch_number = %updates_manager.l1_batch.number,
                     address = %protective_read.address,
@@ -167,7 +177,7 @@ impl StateKeeperOutputHandler for ProtectiveReadsOutputHandler {
                 );
             }
         }
-        for remaining_read in expected_protective_reads {
+        for remaining_read in written_protective_reads {
             tracing::error!(
                 l1_batch_number = %updates_manager.l1_batch.number,
                 address = %remaining_read.address(),
@@ -13,7 +13,10 @@ mod metrics;
 #[cfg(test)]
 mod tests;
 
-pub use impls::{ProtectiveReadsWriter, ProtectiveReadsWriterTasks};
+pub use impls::{
+    BasicWitnessInputProducer, BasicWitnessInputProducerTasks, ProtectiveReadsWriter,
+    ProtectiveReadsWriterTasks, VmPlayground, VmPlaygroundTasks,
+};
 pub use io::VmRunnerIo;
 pub use output_handler::{
     ConcurrentOutputHandlerFactory, ConcurrentOutputHandlerFactoryTask, OutputHandlerFactory,
@@ -2,7 +2,9 @@
 
 use std::time::Duration;
 
-use vise::{Buckets, Gauge, Histogram, Metrics};
+use vise::{Buckets, Counter, Family, Gauge, Histogram, Metrics};
+
+use crate::impls::DivergenceKind;
 
 #[derive(Debug, Metrics)]
 #[metrics(prefix = "vm_runner")]
@@ -26,3 +28,17 @@ pub(super) struct VmRunnerMetrics {
 
 #[vise::register]
 pub(super) static METRICS: vise::Global<VmRunnerMetrics> = vise::Global::new();
+
+#[derive(Debug, Metrics)]
+#[metrics(prefix = "vm_playground")]
+pub(super) struct VmPlaygroundMetrics {
+    /// Number of L1 batches re-executed by the VM playground.
+    pub processed_batches: Counter,
+    /// Number of divergences from the persisted data detected by the VM playground, grouped by kind.
+    pub divergences: Family<DivergenceKind, Counter>,
+    /// Last L1 batch for which a divergence has been detected.
+    pub last_divergent_batch: Gauge<u64>,
+}
+
+#[vise::register]
+pub(super) static PLAYGROUND_METRICS: vise::Global<VmPlaygroundMetrics> = vise::Global::new();
@@ -25,6 +25,7 @@ use zksync_utils::u256_to_h256;
 use super::{OutputHandlerFactory, VmRunnerIo};
 
 mod output_handler;
+mod playground;
 mod process;
 mod storage;
 
@@ -0,0 +1,97 @@
+use std::collections::HashMap;
+
+use multivm::interface::CurrentExecutionState;
+use zksync_contracts::BaseSystemContractsHashes;
+use zksync_types::{
+    block::L1BatchHeader,
+    l2_to_l1_log::{L2ToL1Log, UserL2ToL1Log},
+    zk_evm_types::{LogQuery, Timestamp},
+    AccountTreeId, Address, L1BatchNumber, ProtocolVersionId, StorageKey, U256,
+};
+use zksync_utils::u256_to_h256;
+
+use crate::impls::{find_divergences, DivergenceKind};
+
+fn storage_write(address: Address, key: U256, value: U256) -> LogQuery {
+    LogQuery {
+        timestamp: Timestamp(0),
+        tx_number_in_block: 0,
+        aux_byte: 0,
+        shard_id: 0,
+        address,
+        key,
+        read_value: U256::zero(),
+        written_value: value,
+        rw_flag: true,
+        rollback: false,
+        is_service: false,
+    }
+}
+
+fn execution_state(header: &L1BatchHeader, writes: Vec<LogQuery>) -> CurrentExecutionState {
+    CurrentExecutionState {
+        events: vec![],
+        deduplicated_storage_log_queries: writes,
+        used_contract_hashes: header.used_contract_hashes.clone(),
+        system_logs: header.system_logs.clone(),
+        user_l2_to_l1_logs: header.l2_to_l1_logs.clone(),
+        total_log_queries: 0,
+        cycles_used: 0,
+        deduplicated_events_logs: vec![],
+        storage_refunds: vec![],
+        pubdata_costs: vec![],
+    }
+}
+
+#[test]
+fn finding_divergences() {
+    let mut header = L1BatchHeader::new(
+        L1BatchNumber(1),
+        0,
+        BaseSystemContractsHashes::default(),
+        ProtocolVersionId::latest(),
+    );
+    header.used_contract_hashes = vec![U256::from(1), U256::from(2)];
+    header.l2_to_l1_logs = vec![UserL2ToL1Log(L2ToL1Log::default())];
+    header.pubdata_input = Some(vec![1, 2, 3]);
+
+    let address = Address::repeat_byte(1);
+    let write = storage_write(address, U256::from(1), U256::from(42));
+    let touched_slots = HashMap::from([(
+        StorageKey::new(AccountTreeId::new(address), u256_to_h256(U256::from(1))),
+        u256_to_h256(U256::from(42)),
+    )]);
+
+    let mut state = execution_state(&header, vec![write]);
+    state.used_contract_hashes.reverse();
+    let divergences = find_divergences(&header, &touched_slots, &state, Some([1, 2, 3].as_slice()));
+    assert!(divergences.is_empty(), "{divergences:?}");
+    // Pubdata input is not compared if it's not available.
+    let divergences = find_divergences(&header, &touched_slots, &state, None);
+    assert!(divergences.is_empty(), "{divergences:?}");
+
+    let other_write = storage_write(address, U256::from(1), U256::from(23));
+    let mut state = execution_state(&header, vec![other_write]);
+    state.user_l2_to_l1_logs.clear();
+    let divergences = find_divergences(&header, &touched_slots, &state, Some([3, 2, 1].as_slice()));
+    assert_eq!(
+        divergences,
+        [
+            DivergenceKind::UserL2ToL1Logs,
+            DivergenceKind::StorageWrites,
+            DivergenceKind::PubdataInput,
+        ]
+    );
+
+    let unknown_write = storage_write(address, U256::from(2), U256::zero());
+    let mut state = execution_state(&header, vec![unknown_write]);
+    state.used_contract_hashes.push(U256::from(3));
+    let divergences = find_divergences(&header, &touched_slots, &state, None);
+    assert_eq!(
+        divergences,
+        [
+            DivergenceKind::UsedContractHashes,
+            DivergenceKind::StorageWrites,
+        ]
+    );
+}
@@ -18,7 +18,7 @@ use crate::{
     account_pool::{AddressPool, TestWallet},
     command::{ExpectedOutcome, IncorrectnessModifier, TxCommand, TxType},
     config::{LoadtestConfig, RequestLimiters},
-    constants::{MAX_L1_TRANSACTIONS, POLLING_INTERVAL},
+    constants::MAX_L1_TRANSACTIONS,
     report::{Report, ReportBuilder, ReportLabel},
     sdk::{error::ClientError, operations::SyncTransactionHandle},
     utils::format_gwei,
@@ -44,6 +44,8 @@ struct InflightTx {
     tx_hash: H256,
     attempt: usize,
     start: Instant,
+    /// When the transaction was accepted by the server (for L1 transactions, when the priority operation was created).
+    submitted_at: Instant,
     command: TxCommand,
 }
 
@@ -132,7 +134,7 @@ impl AccountLifespan {
         self.execute_command(deploy_command.clone()).await?;
         self.wait_for_all_inflight_tx().await?;
 
-        let mut timer = tokio::time::interval(POLLING_INTERVAL);
+        let mut timer = tokio::time::interval(self.config.receipt_polling_interval());
         let mut l1_tx_count = 0;
         loop {
             let command = self.generate_command();
@@ -157,7 +159,7 @@ impl AccountLifespan {
     }
 
     async fn wait_for_all_inflight_tx(&mut self) -> Result<(), Aborted> {
-        let mut timer = tokio::time::interval(POLLING_INTERVAL);
+        let mut timer = tokio::time::interval(self.config.receipt_polling_interval());
         while !self.inflight_txs.is_empty() {
             timer.tick().await;
             self.check_inflight_txs().await?;
@@ -197,8 +199,15 @@ impl AccountLifespan {
                         tx.start.elapsed(),
                         tx.attempt,
                     );
-                    self.report(label, tx.start.elapsed(), tx.attempt, tx.command)
-                        .await?;
+                    let sealing_latency = tx.submitted_at.elapsed();
+                    self.report(
+                        label,
+                        tx.start.elapsed(),
+                        Some(sealing_latency),
+                        tx.attempt,
+                        tx.command,
+                    )
+                    .await?;
                 }
                 other => {
                     tracing::trace!(
@@ -289,6 +298,7 @@ impl AccountLifespan {
                     self.inflight_txs.push_back(InflightTx {
                         tx_hash,
                         start,
+                        submitted_at: Instant::now(),
                         attempt,
                         command: command.clone(),
                     });
@@ -296,7 +306,7 @@ impl AccountLifespan {
                 }
                 SubmitResult::ReportLabel(label) => {
                     // Make a report if there was some problems sending tx
-                    self.report(label, start.elapsed(), attempt, command)
+                    self.report(label, start.elapsed(), None, attempt, command)
                         .await?;
                 }
             }
@@ -326,6 +336,7 @@ impl AccountLifespan {
         &mut self,
         label: ReportLabel,
         time: Duration,
+        sealing_latency: Option<Duration>,
         retries: usize,
         command: TxCommand,
     ) -> Result<(), Aborted> {
@@ -340,6 +351,7 @@ impl AccountLifespan {
             .label(label)
             .reporter(self.wallet.wallet.address())
             .time(time)
+            .sealing_latency(sealing_latency)
             .retries(retries)
             .action(command)
             .finish();
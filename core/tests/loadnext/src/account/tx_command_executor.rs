@@ -5,7 +5,7 @@ use zksync_system_constants::MAX_L1_TRANSACTION_GAS_LIMIT;
 use zksync_types::{
     api::{BlockNumber, TransactionReceipt},
     l2::L2Tx,
-    Address, H256, U256,
+    Address, H256, L2_BASE_TOKEN_ADDRESS, U256,
 };
 
 use crate::{
@@ -44,6 +44,11 @@ impl AccountLifespan {
                 self.execute_withdraw(command).await
             }
             TxType::Deposit => self.execute_deposit(command).await,
+            TxType::Transfer => self.execute_transfer(command, L2_BASE_TOKEN_ADDRESS).await,
+            TxType::Erc20Transfer => {
+                let token = self.main_l2_token;
+                self.execute_transfer(command, token).await
+            }
             TxType::DeployContract => self.execute_deploy_contract(command).await,
             TxType::L2Execute => {
                 self.execute_loadnext_contract(command, ExecutionType::L2)
@@ -254,6 +259,55 @@ impl AccountLifespan {
         Ok(self.apply_modifier(tx, command.modifier).await)
     }
 
+    async fn execute_transfer(
+        &mut self,
+        command: &TxCommand,
+        token: Address,
+    ) -> Result<SubmitResult, ClientError> {
+        let tx = self.build_transfer(command, token).await?;
+        self.execute_submit(tx, command.modifier).await
+    }
+
+    async fn build_transfer(
+        &self,
+        command: &TxCommand,
+        token: Address,
+    ) -> Result<L2Tx, ClientError> {
+        let wallet = self.wallet.wallet.clone();
+
+        let mut builder = wallet
+            .start_transfer()
+            .to(command.to)
+            .amount(command.amount)
+            .token(token);
+
+        let fee = builder
+            .estimate_fee(Some(get_approval_based_paymaster_input_for_estimation(
+                self.paymaster_address,
+                self.main_l2_token,
+                MIN_ALLOWANCE_FOR_PAYMASTER_ESTIMATE.into(),
+            )))
+            .await?;
+        builder = builder.fee(fee.clone());
+
+        let paymaster_params = get_approval_based_paymaster_input(
+            self.paymaster_address,
+            self.main_l2_token,
+            fee.max_total_fee(),
+            Vec::new(),
+        );
+        builder = builder.fee(fee);
+        builder = builder.paymaster_params(paymaster_params);
+
+        if let Some(nonce) = self.current_nonce {
+            builder = builder.nonce(nonce);
+        }
+
+        let tx = builder.tx().await.map_err(Self::tx_creation_error)?;
+
+        Ok(self.apply_modifier(tx, command.modifier).await)
+    }
+
     async fn execute_deploy_contract(
         &mut self,
         command: &TxCommand,
@@ -10,13 +10,17 @@ use crate::{
     rng::{LoadtestRng, WeightedRandom},
 };
 
-static WEIGHTS: OnceCell<[(TxType, f32); 5]> = OnceCell::new();
+static WEIGHTS: OnceCell<[(TxType, f32); 8]> = OnceCell::new();
 
 /// Type of transaction. It doesn't copy the zkSync operation list, because
 /// it divides some transactions in subcategories (e.g. to new account / to existing account; to self / to other; etc)/
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum TxType {
     Deposit,
+    /// Transfer of the base token to another account.
+    Transfer,
+    /// Transfer of the main ERC-20 token to another account.
+    Erc20Transfer,
     WithdrawToSelf,
     WithdrawToOther,
     DeployContract,
@@ -36,6 +40,9 @@ impl TxType {
                     TxType::WithdrawToOther,
                     transaction_weights.withdrawal / 2.0,
                 ),
+                (TxType::Transfer, transaction_weights.transfers),
+                (TxType::Erc20Transfer, transaction_weights.erc20_transfers),
+                (TxType::DeployContract, transaction_weights.deployments),
             ])
             .unwrap();
     }
@@ -57,8 +64,11 @@ impl TxType {
     const fn const_all() -> &'static [Self] {
         &[
             Self::Deposit,
+            Self::Transfer,
+            Self::Erc20Transfer,
             Self::WithdrawToSelf,
             Self::WithdrawToOther,
+            Self::DeployContract,
             Self::L1Execute,
             Self::L2Execute,
         ]
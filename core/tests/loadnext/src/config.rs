@@ -137,6 +137,16 @@ pub struct LoadtestConfig {
     /// in an eventual test failure anyway (e.g., a failure processing transactions).
     #[serde(default)]
     pub fail_fast: bool,
+
+    /// Interval between checks of transaction receipts by each account. Determines the resolution of measured
+    /// transaction latencies; lowering it increases the load on the L2 node API.
+    #[serde(default = "default_receipt_polling_interval_ms")]
+    pub receipt_polling_interval_ms: u64,
+
+    /// Path to write the JSON report with the test summary and transaction latencies to. If not set,
+    /// the report is only logged.
+    #[serde(default)]
+    pub report_path: Option<PathBuf>,
 }
 
 fn default_max_inflight_txs() -> usize {
@@ -250,6 +260,12 @@ fn default_expected_tx_count() -> Option<usize> {
     result
 }
 
+fn default_receipt_polling_interval_ms() -> u64 {
+    let result = 3_000;
+    tracing::info!("Using default RECEIPT_POLLING_INTERVAL_MS: {result}");
+    result
+}
+
 fn default_prometheus_label() -> String {
     let result = "unset".to_string();
     tracing::info!("Using default PROMETHEUS_LABEL: {result:?}");
@@ -264,6 +280,10 @@ impl LoadtestConfig {
     pub fn duration(&self) -> Duration {
         Duration::from_secs(self.duration_sec)
     }
+
+    pub fn receipt_polling_interval(&self) -> Duration {
+        Duration::from_millis(self.receipt_polling_interval_ms)
+    }
 }
 
 /// Configuration for the weights of loadtest operations
@@ -296,6 +316,15 @@ pub struct TransactionWeights {
     pub withdrawal: f32,
     pub l1_transactions: f32,
     pub l2_transactions: f32,
+    /// Base token transfers between test accounts.
+    #[serde(default)]
+    pub transfers: f32,
+    /// Transfers of the main ERC-20 token between test accounts.
+    #[serde(default)]
+    pub erc20_transfers: f32,
+    /// Deployments of the test contract (in addition to the initial deployment performed by every account).
+    #[serde(default)]
+    pub deployments: f32,
 }
 
 impl TransactionWeights {
@@ -311,6 +340,9 @@ impl Default for TransactionWeights {
             withdrawal: 0.5,
             l1_transactions: 0.05,
             l2_transactions: 1.0,
+            transfers: 0.0,
+            erc20_transfers: 0.0,
+            deployments: 0.0,
         }
     }
 }
@@ -512,6 +512,7 @@ impl Executor {
             self.config.duration(),
             self.config.prometheus_label.clone(),
             self.config.fail_fast,
+            self.config.report_path.clone(),
         );
         let report_collector_future = tokio::spawn(report_collector.run());
 
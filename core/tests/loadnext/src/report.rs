@@ -27,6 +27,9 @@ pub struct Report {
     pub retries: usize,
     /// Duration of the latest execution attempt.
     pub time: Duration,
+    /// Time between the transaction submission and its inclusion into a sealed L2 block, as observed
+    /// by the account. Only set for transactions that were included.
+    pub sealing_latency: Option<Duration>,
 }
 
 /// Builder structure for `Report`.
@@ -44,6 +47,7 @@ impl Default for ReportBuilder {
                 action: ActionType::Tx(TxActionType::Execute(ExecutionType::L2)),
                 retries: 0,
                 time: Duration::ZERO,
+                sealing_latency: None,
             },
         }
     }
@@ -75,6 +79,11 @@ impl ReportBuilder {
         self
     }
 
+    pub fn sealing_latency(mut self, sealing_latency: Option<Duration>) -> Self {
+        self.report.sealing_latency = sealing_latency;
+        self
+    }
+
     pub fn finish(self) -> Report {
         self.report
     }
@@ -86,6 +95,7 @@ impl ReportBuilder {
             action: ActionType::InitComplete,
             retries: 0,
             time: Duration::ZERO,
+            sealing_latency: None,
         }
     }
 }
@@ -121,6 +131,8 @@ impl ReportLabel {
 pub enum TxActionType {
     Withdraw,
     Deposit,
+    Transfer,
+    Erc20Transfer,
     DeployContract,
     Execute(ExecutionType),
 }
@@ -130,6 +142,8 @@ impl All for TxActionType {
         const ALL: &[TxActionType] = &[
             TxActionType::Withdraw,
             TxActionType::Deposit,
+            TxActionType::Transfer,
+            TxActionType::Erc20Transfer,
             TxActionType::DeployContract,
             TxActionType::Execute(ExecutionType::L2),
             TxActionType::Execute(ExecutionType::L1),
@@ -143,6 +157,8 @@ impl From<TxType> for TxActionType {
     fn from(command: TxType) -> Self {
         match command {
             TxType::Deposit => Self::Deposit,
+            TxType::Transfer => Self::Transfer,
+            TxType::Erc20Transfer => Self::Erc20Transfer,
             TxType::WithdrawToSelf | TxType::WithdrawToOther => Self::Withdraw,
             TxType::L2Execute => Self::Execute(ExecutionType::L2),
             TxType::L1Execute => Self::Execute(ExecutionType::L1),
@@ -0,0 +1,126 @@
+use std::{collections::HashMap, time::Duration};
+
+use serde::Serialize;
+
+use crate::report::TxActionType;
+
+/// Statistics of transaction latencies from submission to inclusion into a sealed L2 block.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LatencyStats {
+    pub count: usize,
+    pub mean_ms: u64,
+    pub p50_ms: u64,
+    pub p90_ms: u64,
+    pub p99_ms: u64,
+    pub max_ms: u64,
+}
+
+impl LatencyStats {
+    fn new(latencies: &mut [Duration]) -> Option<Self> {
+        if latencies.is_empty() {
+            return None;
+        }
+        latencies.sort_unstable();
+        let total: Duration = latencies.iter().sum();
+        let millis = |duration: Duration| duration.as_millis() as u64;
+        let percentile = |percentile: usize| {
+            // Nearest-rank method: the smallest value such that at least `percentile`% of values are not greater.
+            let rank = (latencies.len() * percentile).div_ceil(100).max(1);
+            millis(latencies[rank - 1])
+        };
+
+        Some(Self {
+            count: latencies.len(),
+            mean_ms: millis(total / latencies.len() as u32),
+            p50_ms: percentile(50),
+            p90_ms: percentile(90),
+            p99_ms: percentile(99),
+            max_ms: millis(*latencies.last().unwrap()),
+        })
+    }
+}
+
+/// Collector of exact transaction sealing latencies.
+///
+/// Unlike [`MetricsCollector`](super::metrics_collector::MetricsCollector), it stores all observed values,
+/// so that the reported percentiles are exact rather than histogram windows. The resolution of latencies
+/// is still bounded by the receipt polling interval used by accounts.
+#[derive(Debug, Default)]
+pub struct LatencyCollector {
+    latencies: HashMap<TxActionType, Vec<Duration>>,
+}
+
+impl LatencyCollector {
+    pub fn add_latency(&mut self, action: TxActionType, latency: Duration) {
+        self.latencies.entry(action).or_default().push(latency);
+    }
+
+    /// Returns stats for all transaction types, as well as for all transactions combined.
+    pub fn stats(&self) -> (Option<LatencyStats>, HashMap<TxActionType, LatencyStats>) {
+        let mut all_latencies: Vec<_> = self.latencies.values().flatten().copied().collect();
+        let per_type = self
+            .latencies
+            .iter()
+            .filter_map(|(&action, latencies)| {
+                let stats = LatencyStats::new(&mut latencies.clone())?;
+                Some((action, stats))
+            })
+            .collect();
+        (LatencyStats::new(&mut all_latencies), per_type)
+    }
+
+    pub fn report(&self) {
+        let (overall, per_type) = self.stats();
+        let Some(overall) = overall else {
+            tracing::info!("No transaction sealing latencies were collected");
+            return;
+        };
+        tracing::info!("Transaction sealing latency: {overall:?}");
+        for (action, stats) in &per_type {
+            tracing::info!("{action:?} sealing latency: {stats:?}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn computing_latency_stats() {
+        let mut latencies: Vec<_> = (1..=100).rev().map(Duration::from_millis).collect();
+        let stats = LatencyStats::new(&mut latencies).unwrap();
+        assert_eq!(
+            stats,
+            LatencyStats {
+                count: 100,
+                mean_ms: 50,
+                p50_ms: 50,
+                p90_ms: 90,
+                p99_ms: 99,
+                max_ms: 100,
+            }
+        );
+
+        let stats = LatencyStats::new(&mut [Duration::from_millis(42)]).unwrap();
+        assert_eq!(stats.p50_ms, 42);
+        assert_eq!(stats.p99_ms, 42);
+        assert!(LatencyStats::new(&mut []).is_none());
+    }
+
+    #[test]
+    fn collecting_latencies_per_tx_type() {
+        let mut collector = LatencyCollector::default();
+        collector.add_latency(TxActionType::Transfer, Duration::from_millis(100));
+        collector.add_latency(TxActionType::Transfer, Duration::from_millis(300));
+        collector.add_latency(TxActionType::DeployContract, Duration::from_millis(1_000));
+
+        let (overall, per_type) = collector.stats();
+        let overall = overall.unwrap();
+        assert_eq!(overall.count, 3);
+        assert_eq!(overall.max_ms, 1_000);
+        assert_eq!(per_type.len(), 2);
+        assert_eq!(per_type[&TxActionType::Transfer].mean_ms, 200);
+        assert_eq!(per_type[&TxActionType::DeployContract].p50_ms, 1_000);
+    }
+}
@@ -1,14 +1,22 @@
-use std::time::{Duration, Instant};
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
 
+use anyhow::Context as _;
 use futures::{channel::mpsc::Receiver, StreamExt};
 use operation_results_collector::OperationResultsCollector;
+use serde::Serialize;
 
+pub use self::latency_collector::LatencyStats;
 use crate::{
     metrics::LOADTEST_METRICS,
     report::{ActionType, Report, ReportLabel},
-    report_collector::metrics_collector::MetricsCollector,
+    report_collector::{latency_collector::LatencyCollector, metrics_collector::MetricsCollector},
 };
 
+mod latency_collector;
 mod metrics_collector;
 mod operation_results_collector;
 
@@ -19,11 +27,37 @@ pub enum LoadtestResult {
     TestFailed,
 }
 
+/// Summary of a finished loadtest written to the report file.
+#[derive(Debug, Serialize)]
+pub struct LoadtestSummary {
+    pub passed: bool,
+    pub requested_duration_secs: u64,
+    pub actual_duration_secs: f64,
+    /// Number of successfully processed transactions per second of the actual test duration.
+    pub tps: f64,
+    pub successful_txs: u64,
+    pub skipped_txs: u64,
+    pub failed_txs: u64,
+    /// Latency from submission to inclusion into a sealed L2 block for all transactions.
+    pub sealing_latency: Option<LatencyStats>,
+    /// Same as `sealing_latency`, but split by transaction type.
+    pub sealing_latency_by_tx_type: BTreeMap<String, LatencyStats>,
+}
+
+impl LoadtestSummary {
+    fn write(&self, path: &Path) -> anyhow::Result<()> {
+        let json = serde_json::to_string_pretty(self).context("failed serializing summary")?;
+        std::fs::write(path, json)
+            .with_context(|| format!("failed writing summary to {}", path.display()))
+    }
+}
+
 #[derive(Debug)]
 struct Collectors {
     start: Instant,
     is_aborted: bool,
     metrics: MetricsCollector,
+    latencies: LatencyCollector,
     operation_results: OperationResultsCollector,
 }
 
@@ -33,6 +67,7 @@ impl Collectors {
             start: Instant::now(),
             is_aborted: false,
             metrics: MetricsCollector::default(),
+            latencies: LatencyCollector::default(),
             operation_results: OperationResultsCollector::new(loadtest_duration),
         }
     }
@@ -40,12 +75,33 @@ impl Collectors {
     fn report(&self, prometheus_label: String) {
         let actual_duration = self.start.elapsed();
         self.metrics.report();
+        self.latencies.report();
         if !self.is_aborted {
             LOADTEST_METRICS.tps[&prometheus_label].set(self.operation_results.nominal_tps());
         }
         self.operation_results.report(actual_duration);
     }
 
+    fn summary(&self, result: LoadtestResult) -> LoadtestSummary {
+        let actual_duration = self.start.elapsed();
+        let tx_results = &self.operation_results.tx_results;
+        let (sealing_latency, latencies_by_type) = self.latencies.stats();
+        LoadtestSummary {
+            passed: matches!(result, LoadtestResult::TestPassed),
+            requested_duration_secs: self.operation_results.loadtest_duration().as_secs(),
+            actual_duration_secs: actual_duration.as_secs_f64(),
+            tps: self.operation_results.tps(actual_duration),
+            successful_txs: tx_results.successes(),
+            skipped_txs: tx_results.skipped(),
+            failed_txs: tx_results.failures(),
+            sealing_latency,
+            sealing_latency_by_tx_type: latencies_by_type
+                .into_iter()
+                .map(|(action, stats)| (format!("{action:?}"), stats))
+                .collect(),
+        }
+    }
+
     fn final_resolution(&self, expected_tx_count: Option<usize>) -> LoadtestResult {
         let is_tx_count_acceptable = expected_tx_count.map_or(true, |expected_count| {
             const MIN_ACCEPTABLE_DELTA: f64 = -10.0;
@@ -100,6 +156,7 @@ pub struct ReportCollector {
     loadtest_duration: Duration,
     prometheus_label: String,
     fail_fast: bool,
+    report_path: Option<PathBuf>,
 }
 
 impl ReportCollector {
@@ -109,6 +166,7 @@ impl ReportCollector {
         loadtest_duration: Duration,
         prometheus_label: String,
         fail_fast: bool,
+        report_path: Option<PathBuf>,
     ) -> Self {
         Self {
             reports_stream,
@@ -116,6 +174,7 @@ impl ReportCollector {
             loadtest_duration,
             prometheus_label,
             fail_fast,
+            report_path,
         }
     }
 
@@ -138,6 +197,11 @@ impl ReportCollector {
                 if matches!(&report.label, ReportLabel::ActionDone) {
                     // We only count successfully created statistics.
                     collectors.metrics.add_metric(report.action, report.time);
+                    if let (ActionType::Tx(action), Some(latency)) =
+                        (report.action, report.sealing_latency)
+                    {
+                        collectors.latencies.add_latency(action, latency);
+                    }
                 }
                 collectors
                     .operation_results
@@ -175,7 +239,14 @@ impl ReportCollector {
         // Now we can output the statistics.
         if let Some(collectors) = collectors {
             collectors.report(self.prometheus_label);
-            collectors.final_resolution(self.expected_tx_count)
+            let result = collectors.final_resolution(self.expected_tx_count);
+            if let Some(report_path) = &self.report_path {
+                match collectors.summary(result).write(report_path) {
+                    Ok(()) => tracing::info!("Wrote loadtest report to {}", report_path.display()),
+                    Err(err) => tracing::error!("Failed writing loadtest report: {err:#}"),
+                }
+            }
+            result
         } else {
             tracing::error!("Test failed before initialization was completed");
             LoadtestResult::TestFailed
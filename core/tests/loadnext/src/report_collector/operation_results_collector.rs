@@ -33,6 +33,10 @@ impl ResultCollector {
         self.successes
     }
 
+    pub fn skipped(&self) -> u64 {
+        self.skipped
+    }
+
     pub fn failures(&self) -> u64 {
         self.failures
     }
@@ -76,6 +80,10 @@ impl OperationResultsCollector {
         self.tx_results.successes() as f64 / duration.as_secs_f64()
     }
 
+    pub fn loadtest_duration(&self) -> Duration {
+        self.loadtest_duration
+    }
+
     pub fn nominal_tps(&self) -> f64 {
         self.tps(self.loadtest_duration)
     }
@@ -8,17 +8,17 @@ use zksync_config::{
         },
         fri_prover_group::FriProverGroupConfig,
         house_keeper::HouseKeeperConfig,
-        DatabaseSecrets, FriProofCompressorConfig, FriProverConfig, FriProverGatewayConfig,
-        FriWitnessGeneratorConfig, FriWitnessVectorGeneratorConfig, GeneralConfig,
-        ObjectStoreConfig, ObservabilityConfig, PrometheusConfig, ProofDataHandlerConfig,
-        ProtectiveReadsWriterConfig,
+        BasicWitnessInputProducerConfig, DatabaseSecrets, FriProofCompressorConfig,
+        FriProverConfig, FriProverGatewayConfig, FriWitnessGeneratorConfig,
+        FriWitnessVectorGeneratorConfig, GeneralConfig, ObjectStoreConfig, ObservabilityConfig,
+        PrometheusConfig, ProofDataHandlerConfig, ProtectiveReadsWriterConfig, VmPlaygroundConfig,
     },
     ApiConfig, ContractVerifierConfig, DBConfig, EthConfig, EthWatchConfig, GasAdjusterConfig,
     PostgresConfig, SnapshotsCreatorConfig,
 };
 use zksync_core_leftovers::temp_config_store::{decode_yaml_repr, TempConfigStore};
 use zksync_env_config::FromEnv;
-use zksync_protobuf_config::proto::secrets::Secrets;
+use zksync_protobuf_config::{interpolation::read_yaml_config, proto::secrets::Secrets};
 
 fn load_env_config() -> anyhow::Result<TempConfigStore> {
     Ok(TempConfigStore {
@@ -49,14 +49,20 @@ fn load_env_config() -> anyhow::Result<TempConfigStore> {
         observability: ObservabilityConfig::from_env().ok(),
         snapshot_creator: SnapshotsCreatorConfig::from_env().ok(),
         protective_reads_writer_config: ProtectiveReadsWriterConfig::from_env().ok(),
+        basic_witness_input_producer_config: BasicWitnessInputProducerConfig::from_env().ok(),
+        vm_playground_config: VmPlaygroundConfig::from_env().ok(),
         core_object_store: ObjectStoreConfig::from_env().ok(),
+        da_dispatcher_config: None,
+        da_client_config: None,
+        base_token_price_config: None,
+        commitment_generator_config: None,
     })
 }
 
 pub fn load_general_config(path: Option<std::path::PathBuf>) -> anyhow::Result<GeneralConfig> {
     match path {
         Some(path) => {
-            let yaml = std::fs::read_to_string(path).context("Failed to read general config")?;
+            let yaml = read_yaml_config(&path).context("Failed to read general config")?;
             decode_yaml_repr::<zksync_protobuf_config::proto::general::GeneralConfig>(&yaml)
         }
         None => Ok(load_env_config()
@@ -68,7 +74,7 @@ pub fn load_general_config(path: Option<std::path::PathBuf>) -> anyhow::Result<G
 pub fn load_database_secrets(path: Option<std::path::PathBuf>) -> anyhow::Result<DatabaseSecrets> {
     match path {
         Some(path) => {
-            let yaml = std::fs::read_to_string(path).context("Failed to read secrets")?;
+            let yaml = read_yaml_config(&path).context("Failed to read secrets")?;
             let secrets = decode_yaml_repr::<Secrets>(&yaml).context("Failed to parse secrets")?;
             Ok(secrets
                 .database
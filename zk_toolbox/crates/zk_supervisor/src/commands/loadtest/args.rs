@@ -0,0 +1,49 @@
+use std::path::PathBuf;
+
+use clap::Parser;
+
+use crate::messages::{
+    MSG_LOADTEST_ACCOUNTS_HELP, MSG_LOADTEST_DEPLOY_WEIGHT_HELP, MSG_LOADTEST_DURATION_HELP,
+    MSG_LOADTEST_ERC20_TRANSFER_WEIGHT_HELP, MSG_LOADTEST_EXECUTE_WEIGHT_HELP,
+    MSG_LOADTEST_L1_WEIGHT_HELP, MSG_LOADTEST_L2_RPC_URL_HELP, MSG_LOADTEST_L2_WS_RPC_URL_HELP,
+    MSG_LOADTEST_MAIN_TOKEN_HELP, MSG_LOADTEST_MASTER_WALLET_PK_HELP,
+    MSG_LOADTEST_MAX_INFLIGHT_TXS_HELP, MSG_LOADTEST_RECEIPT_POLLING_INTERVAL_HELP,
+    MSG_LOADTEST_REPORT_PATH_HELP, MSG_LOADTEST_TRANSFER_WEIGHT_HELP,
+    MSG_LOADTEST_WITHDRAWAL_WEIGHT_HELP,
+};
+
+#[derive(Debug, Parser)]
+pub struct LoadtestArgs {
+    #[clap(long, default_value_t = 300, help = MSG_LOADTEST_DURATION_HELP)]
+    pub duration_secs: u64,
+    #[clap(long, default_value_t = 80, help = MSG_LOADTEST_ACCOUNTS_HELP)]
+    pub accounts: usize,
+    #[clap(long, default_value_t = 5, help = MSG_LOADTEST_MAX_INFLIGHT_TXS_HELP)]
+    pub max_inflight_txs: usize,
+    #[clap(long, default_value = "http://127.0.0.1:3050", help = MSG_LOADTEST_L2_RPC_URL_HELP)]
+    pub l2_rpc_url: String,
+    #[clap(long, default_value = "ws://127.0.0.1:3051", help = MSG_LOADTEST_L2_WS_RPC_URL_HELP)]
+    pub l2_ws_rpc_url: String,
+    #[clap(long, help = MSG_LOADTEST_MASTER_WALLET_PK_HELP)]
+    pub master_wallet_pk: Option<String>,
+    #[clap(long, help = MSG_LOADTEST_MAIN_TOKEN_HELP)]
+    pub main_token: Option<String>,
+
+    #[clap(long, default_value_t = 1.0, help = MSG_LOADTEST_TRANSFER_WEIGHT_HELP)]
+    pub transfer_weight: f32,
+    #[clap(long, default_value_t = 1.0, help = MSG_LOADTEST_ERC20_TRANSFER_WEIGHT_HELP)]
+    pub erc20_transfer_weight: f32,
+    #[clap(long, default_value_t = 0.1, help = MSG_LOADTEST_DEPLOY_WEIGHT_HELP)]
+    pub deploy_weight: f32,
+    #[clap(long, default_value_t = 1.0, help = MSG_LOADTEST_EXECUTE_WEIGHT_HELP)]
+    pub execute_weight: f32,
+    #[clap(long, default_value_t = 0.0, help = MSG_LOADTEST_WITHDRAWAL_WEIGHT_HELP)]
+    pub withdrawal_weight: f32,
+    #[clap(long, default_value_t = 0.0, help = MSG_LOADTEST_L1_WEIGHT_HELP)]
+    pub l1_weight: f32,
+
+    #[clap(long, default_value_t = 500, help = MSG_LOADTEST_RECEIPT_POLLING_INTERVAL_HELP)]
+    pub receipt_polling_interval_ms: u64,
+    #[clap(long, help = MSG_LOADTEST_REPORT_PATH_HELP)]
+    pub report_path: Option<PathBuf>,
+}
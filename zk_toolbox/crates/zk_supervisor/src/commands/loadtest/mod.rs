@@ -0,0 +1,88 @@
+use anyhow::Context;
+use common::{cmd::Cmd, config::global_config, logger, spinner::Spinner};
+use config::EcosystemConfig;
+use xshell::{cmd, Shell};
+
+use self::args::LoadtestArgs;
+use crate::messages::{
+    msg_loadtest_report_written, MSG_CHAIN_NOT_FOUND_ERR, MSG_LOADTEST_BUILDING,
+    MSG_LOADTEST_REPORT_NOTE, MSG_LOADTEST_RUN_INFO, MSG_LOADTEST_RUN_SUCCESS,
+};
+
+pub mod args;
+
+const LOADTEST_REPORT_FILE: &str = "loadtest-report.json";
+
+pub fn run(shell: &Shell, args: LoadtestArgs) -> anyhow::Result<()> {
+    let ecosystem_config = EcosystemConfig::from_file(shell)?;
+    let chain_config = ecosystem_config
+        .load_chain(global_config().chain_name.clone())
+        .context(MSG_CHAIN_NOT_FOUND_ERR)?;
+    let secrets = chain_config.get_secrets_config()?;
+    let report_path = args
+        .report_path
+        .clone()
+        .unwrap_or_else(|| ecosystem_config.link_to_code.join(LOADTEST_REPORT_FILE));
+
+    let _dir_guard = shell.push_dir(&ecosystem_config.link_to_code);
+    let spinner = Spinner::new(MSG_LOADTEST_BUILDING);
+    Cmd::new(cmd!(shell, "cargo build --release --bin loadnext")).run()?;
+    spinner.finish();
+
+    logger::info(MSG_LOADTEST_RUN_INFO);
+    let mut command = cmd!(shell, "cargo run --release --bin loadnext")
+        .env("L1_RPC_ADDRESS", &secrets.l1.l1_rpc_url)
+        .env("L2_RPC_ADDRESS", &args.l2_rpc_url)
+        .env("L2_WS_RPC_ADDRESS", &args.l2_ws_rpc_url)
+        .env("L2_CHAIN_ID", chain_config.chain_id.to_string())
+        .env("DURATION_SEC", args.duration_secs.to_string())
+        .env("ACCOUNTS_AMOUNT", args.accounts.to_string())
+        .env("MAX_INFLIGHT_TXS", args.max_inflight_txs.to_string())
+        .env(
+            "RECEIPT_POLLING_INTERVAL_MS",
+            args.receipt_polling_interval_ms.to_string(),
+        )
+        .env("REPORT_PATH", &report_path)
+        .env(
+            "TRANSACTION_WEIGHTS_TRANSFERS",
+            args.transfer_weight.to_string(),
+        )
+        .env(
+            "TRANSACTION_WEIGHTS_ERC20_TRANSFERS",
+            args.erc20_transfer_weight.to_string(),
+        )
+        .env(
+            "TRANSACTION_WEIGHTS_DEPLOYMENTS",
+            args.deploy_weight.to_string(),
+        )
+        .env(
+            "TRANSACTION_WEIGHTS_L2_TRANSACTIONS",
+            args.execute_weight.to_string(),
+        )
+        .env(
+            "TRANSACTION_WEIGHTS_WITHDRAWAL",
+            args.withdrawal_weight.to_string(),
+        )
+        .env("TRANSACTION_WEIGHTS_DEPOSIT", args.l1_weight.to_string())
+        .env(
+            "TRANSACTION_WEIGHTS_L1_TRANSACTIONS",
+            args.l1_weight.to_string(),
+        );
+    if let Some(master_wallet_pk) = &args.master_wallet_pk {
+        command = command.env("MASTER_WALLET_PK", master_wallet_pk);
+    }
+    if let Some(main_token) = &args.main_token {
+        command = command.env("MAIN_TOKEN", main_token);
+    }
+    // The loadtest may fail, but the report is still useful to investigate the failure, so it's printed regardless.
+    let result = Cmd::new(command).with_force_run().run();
+
+    if let Ok(report) = shell.read_file(&report_path) {
+        logger::note(MSG_LOADTEST_REPORT_NOTE, report);
+        logger::info(msg_loadtest_report_written(&report_path));
+    }
+    result?;
+
+    logger::outro(MSG_LOADTEST_RUN_SUCCESS);
+    Ok(())
+}
@@ -1,5 +1,5 @@
 use clap::{Parser, Subcommand};
-use commands::database::DatabaseCommands;
+use commands::{database::DatabaseCommands, loadtest::args::LoadtestArgs};
 use common::{
     check_prerequisites,
     config::{global_config, init_global_config, GlobalConfig},
@@ -8,7 +8,7 @@ use common::{
 use config::EcosystemConfig;
 use messages::{
     msg_global_chain_does_not_exist, MSG_SUBCOMMAND_DATABASE_ABOUT,
-    MSG_SUBCOMMAND_INTEGRATION_TESTS_ABOUT,
+    MSG_SUBCOMMAND_INTEGRATION_TESTS_ABOUT, MSG_SUBCOMMAND_LOADTEST_ABOUT,
 };
 use xshell::Shell;
 
@@ -31,6 +31,8 @@ enum SupervisorSubcommands {
     Database(DatabaseCommands),
     #[command(about = MSG_SUBCOMMAND_INTEGRATION_TESTS_ABOUT)]
     IntegrationTests,
+    #[command(about = MSG_SUBCOMMAND_LOADTEST_ABOUT)]
+    Loadtest(LoadtestArgs),
 }
 
 #[derive(Parser, Debug)]
@@ -94,6 +96,7 @@ async fn run_subcommand(args: Supervisor, shell: &Shell) -> anyhow::Result<()> {
     match args.command {
         SupervisorSubcommands::Database(command) => commands::database::run(shell, command).await?,
         SupervisorSubcommands::IntegrationTests => commands::integration_tests::run(shell)?,
+        SupervisorSubcommands::Loadtest(args) => commands::loadtest::run(shell, args)?,
     }
     Ok(())
 }
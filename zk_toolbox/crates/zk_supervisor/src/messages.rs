@@ -1,3 +1,5 @@
+use std::path::Path;
+
 // Ecosystem related messages
 pub(super) const MSG_CHAIN_NOT_FOUND_ERR: &str = "Chain not found";
 pub(super) fn msg_global_chain_does_not_exist(chain: &str, available_chains: &str) -> String {
@@ -7,6 +9,8 @@ pub(super) fn msg_global_chain_does_not_exist(chain: &str, available_chains: &st
 // Subcommands help
 pub(super) const MSG_SUBCOMMAND_DATABASE_ABOUT: &str = "Database related commands";
 pub(super) const MSG_SUBCOMMAND_INTEGRATION_TESTS_ABOUT: &str = "Run integration tests";
+pub(super) const MSG_SUBCOMMAND_LOADTEST_ABOUT: &str =
+    "Run a loadtest against a node and report transaction latencies and TPS";
 
 // Database related messages
 pub(super) const MSG_NO_DATABASES_SELECTED: &str = "No databases selected";
@@ -68,3 +72,37 @@ pub(super) const MSG_INTEGRATION_TESTS_RUN_SUCCESS: &str = "Integration tests ra
 pub(super) const MSG_INTEGRATION_TESTS_BUILDING_DEPENDENCIES: &str =
     "Building repository dependencies...";
 pub(super) const MSG_INTEGRATION_TESTS_BUILDING_CONTRACTS: &str = "Building test contracts...";
+
+// Loadtest related messages
+pub(super) const MSG_LOADTEST_BUILDING: &str = "Building loadnext...";
+pub(super) const MSG_LOADTEST_RUN_INFO: &str = "Running loadtest";
+pub(super) const MSG_LOADTEST_RUN_SUCCESS: &str = "Loadtest passed";
+pub(super) const MSG_LOADTEST_REPORT_NOTE: &str = "Loadtest report";
+pub(super) fn msg_loadtest_report_written(path: &Path) -> String {
+    format!("Loadtest report is written to {}", path.display())
+}
+
+pub(super) const MSG_LOADTEST_DURATION_HELP: &str = "Duration of the loadtest in seconds";
+pub(super) const MSG_LOADTEST_ACCOUNTS_HELP: &str = "Number of test accounts";
+pub(super) const MSG_LOADTEST_MAX_INFLIGHT_TXS_HELP: &str =
+    "Maximum number of transactions per account awaiting inclusion";
+pub(super) const MSG_LOADTEST_L2_RPC_URL_HELP: &str = "HTTP JSON-RPC URL of the target L2 node";
+pub(super) const MSG_LOADTEST_L2_WS_RPC_URL_HELP: &str =
+    "WebSocket JSON-RPC URL of the target L2 node";
+pub(super) const MSG_LOADTEST_MASTER_WALLET_PK_HELP: &str =
+    "Private key of the wallet funding test accounts; the local rich wallet is used by default";
+pub(super) const MSG_LOADTEST_MAIN_TOKEN_HELP: &str =
+    "Address of the L1 ERC-20 token used in the loadtest; the first local test token is used by default";
+pub(super) const MSG_LOADTEST_TRANSFER_WEIGHT_HELP: &str =
+    "Relative weight of base token transfers";
+pub(super) const MSG_LOADTEST_ERC20_TRANSFER_WEIGHT_HELP: &str =
+    "Relative weight of ERC-20 token transfers";
+pub(super) const MSG_LOADTEST_DEPLOY_WEIGHT_HELP: &str = "Relative weight of contract deployments";
+pub(super) const MSG_LOADTEST_EXECUTE_WEIGHT_HELP: &str = "Relative weight of contract calls";
+pub(super) const MSG_LOADTEST_WITHDRAWAL_WEIGHT_HELP: &str = "Relative weight of withdrawals";
+pub(super) const MSG_LOADTEST_L1_WEIGHT_HELP: &str =
+    "Relative weight of deposits and L1 -> L2 contract calls (each)";
+pub(super) const MSG_LOADTEST_RECEIPT_POLLING_INTERVAL_HELP: &str =
+    "Interval between transaction receipt checks in milliseconds; determines the resolution of measured latencies";
+pub(super) const MSG_LOADTEST_REPORT_PATH_HELP: &str =
+    "Path to write the JSON report to; defaults to `loadtest-report.json` in the repository root";